@@ -0,0 +1,41 @@
+#!/bin/sh
+# what: builds sensor-driver as a WASI 0.2 component and transpiles it with jco into dist/component
+# why: the demo's component backend loads the real component (jco bindings + preview2 browser shim) next to the wasm-bindgen build
+# relations: run by Trunk.toml as a pre_build hook; loaded by window.loadSensorComponent in index.html (COMPONENT_MODULE in src/tabs/demo/component_backend.rs)
+
+set -e
+
+OUT="${TRUNK_STAGING_DIR:-dist}/component"
+TARGET_DIR="../target/component"
+CRATE="../wasm-modules/sensor-driver/Cargo.toml"
+# keep in sync with the preview2-shim version in index.html's import map
+JCO="@bytecodealliance/jco@1.8.1"
+
+# a build without the component silently loses the backend toggle, so missing tools fail the build;
+# GUARDIAN_SKIP_COMPONENT=1 opts out for local work and still says so loudly
+missing() {
+    echo "" >&2
+    echo "==============================================================" >&2
+    echo "build-component: $1" >&2
+    if [ "${GUARDIAN_SKIP_COMPONENT:-0}" = "1" ]; then
+        echo "GUARDIAN_SKIP_COMPONENT=1: building WITHOUT the WASI component;" >&2
+        echo "the component backend will report it as missing" >&2
+        echo "==============================================================" >&2
+        exit 0
+    fi
+    echo "$2" >&2
+    echo "(set GUARDIAN_SKIP_COMPONENT=1 to build without the component)" >&2
+    echo "==============================================================" >&2
+    exit 1
+}
+
+if ! rustup target list --installed 2>/dev/null | grep -q wasm32-wasip2; then
+    missing "wasm32-wasip2 target not installed" "install it with: rustup target add wasm32-wasip2"
+fi
+if ! command -v npx >/dev/null 2>&1; then
+    missing "npx not found, cannot run the jco transpile" "install node.js (npx ships with npm)"
+fi
+
+cargo build --manifest-path "$CRATE" --target wasm32-wasip2 --target-dir "$TARGET_DIR" --release --no-default-features --features component
+mkdir -p "$OUT"
+npx --yes "$JCO" transpile "$TARGET_DIR/wasm32-wasip2/release/sensor_driver.wasm" --name sensor_driver -o "$OUT"
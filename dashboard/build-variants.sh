@@ -0,0 +1,24 @@
+#!/bin/sh
+# what: builds sensor-driver three ways (debug, release, release + wasm-opt -Oz) into dist/variants
+# why: the proof tab's build variants panel fetches and measures these, so size claims trace back to a pipeline step
+# relations: run by Trunk.toml as a pre_build hook; file names must match VARIANTS in src/tabs/proof/build_variants.rs
+
+set -e
+
+OUT="${TRUNK_STAGING_DIR:-dist}/variants"
+TARGET_DIR="../target/variants"
+CRATE="../wasm-modules/sensor-driver/Cargo.toml"
+mkdir -p "$OUT"
+
+cargo build --manifest-path "$CRATE" --target wasm32-unknown-unknown --target-dir "$TARGET_DIR"
+cp "$TARGET_DIR/wasm32-unknown-unknown/debug/sensor_driver.wasm" "$OUT/sensor_driver.debug.wasm"
+
+cargo build --manifest-path "$CRATE" --target wasm32-unknown-unknown --target-dir "$TARGET_DIR" --release
+cp "$TARGET_DIR/wasm32-unknown-unknown/release/sensor_driver.wasm" "$OUT/sensor_driver.release.wasm"
+
+# the -Oz variant is skipped (and reported as missing by the panel) when binaryen is not installed
+if command -v wasm-opt >/dev/null 2>&1; then
+    wasm-opt -Oz "$OUT/sensor_driver.release.wasm" -o "$OUT/sensor_driver.oz.wasm"
+else
+    echo "build-variants: wasm-opt not found, skipping sensor_driver.oz.wasm" >&2
+fi
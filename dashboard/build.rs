@@ -0,0 +1,60 @@
+// what: stamps build metadata (release, commit, timestamp, rustc, leptos) into the dashboard as env vars,
+//       and generates the browser mock host from wit/attacks.wit
+// why: the about panel prints them so a viewer's bug report names the exact build; simulated denials must match the contract
+// relations: read with env! in src/diagnostics/about.rs and src/whatsnew/mod.rs (release); mock host include!d by src/tabs/demo/mock_host.rs
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[path = "src/tabs/demo/wit_codegen.rs"]
+mod wit_codegen;
+
+/// wit contract the sensor-node world is built against
+const ATTACKS_WIT: &str = "../wit/attacks.wit";
+/// imports the device host never provides; the browser mocks them
+const MOCKED_INTERFACE: &str = "attack-surface";
+
+fn run(cmd: &str, args: &[&str]) -> Option<String> {
+    let out = Command::new(cmd).args(args).output().ok().filter(|o| o.status.success())?;
+    Some(String::from_utf8(out.stdout).ok()?.trim().to_string())
+}
+
+/// version of `name` pinned in the workspace lockfile
+fn locked_version(name: &str) -> Option<String> {
+    let lock = std::fs::read_to_string("../Cargo.lock").ok()?;
+    let mut lines = lock.lines();
+    lines.find(|l| *l == format!("name = \"{}\"", name))?;
+    Some(lines.next()?.strip_prefix("version = \"")?.trim_end_matches('"').to_string())
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=../.git/HEAD");
+    println!("cargo:rerun-if-changed=../Cargo.lock");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    println!("cargo:rerun-if-env-changed=GUARDIAN_RELEASE");
+    println!("cargo:rerun-if-changed={}", ATTACKS_WIT);
+    println!("cargo:rerun-if-changed=src/tabs/demo/wit_codegen.rs");
+
+    let wit = std::fs::read_to_string(ATTACKS_WIT).expect("wit/attacks.wit must be readable");
+    let mock_host = wit_codegen::generate(&wit, MOCKED_INTERFACE).unwrap_or_else(|e| panic!("{}: {}", ATTACKS_WIT, e));
+    let out_dir = std::env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    std::fs::write(Path::new(&out_dir).join("mock_host.rs"), mock_host).expect("mock_host.rs must be writable");
+
+    let commit = run("git", &["rev-parse", "--short", "HEAD"]).unwrap_or_else(|| "unknown".into());
+    let dirty = run("git", &["status", "--porcelain"]).is_some_and(|s| !s.is_empty());
+    // SOURCE_DATE_EPOCH keeps reproducible builds reproducible
+    let built = std::env::var("SOURCE_DATE_EPOCH").ok().and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0));
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+
+    // a release pipeline can stamp its tag; otherwise the crate version is the release
+    let release = std::env::var("GUARDIAN_RELEASE").ok().filter(|r| !r.is_empty())
+        .unwrap_or_else(|| std::env::var("CARGO_PKG_VERSION").unwrap_or_default());
+
+    println!("cargo:rustc-env=GUARDIAN_RELEASE={}", release);
+    println!("cargo:rustc-env=GUARDIAN_GIT_COMMIT={}{}", commit, if dirty { "-dirty" } else { "" });
+    println!("cargo:rustc-env=GUARDIAN_BUILD_UNIX={}", built);
+    println!("cargo:rustc-env=GUARDIAN_RUSTC={}", run(&rustc, &["--version"]).unwrap_or_else(|| "unknown".into()));
+    println!("cargo:rustc-env=GUARDIAN_LEPTOS={}", locked_version("leptos").unwrap_or_else(|| "unknown".into()));
+}
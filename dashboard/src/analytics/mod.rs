@@ -0,0 +1,123 @@
+// what: opt-in session analytics - which attacks are run and how long each tab is looked at, counted in this browser only
+// why: tells the maintainer which parts of the story land without a third-party tracker; nothing leaves the page unless exported
+// relations: enabled by settings/mod.rs (Settings::analytics), tab dwell driven by lib.rs App, attacks counted by
+//            tabs/demo/store.rs record, review/export/clear in panel.rs inside settings/panel.rs
+
+use std::collections::BTreeMap;
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+mod panel;
+
+#[cfg(test)]
+mod tests;
+
+pub use panel::AnalyticsSettings;
+
+/// localStorage key of the counters
+pub const STORAGE_KEY: &str = "guardian.analytics";
+/// longest single visit credited to a tab, so a console left open overnight doesn't swamp the rest
+pub const MAX_VISIT_MS: f64 = 10.0 * 60_000.0;
+
+/// everything recorded: plain counters, no timestamps per event, no identifiers
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Counters {
+    /// unix epoch ms of the first recorded session (0 = nothing recorded yet)
+    pub since_ms: f64,
+    /// page loads with analytics on
+    pub sessions: u32,
+    /// attack id -> times started
+    pub attacks: BTreeMap<String, u32>,
+    /// tab id -> visits and milliseconds on screen
+    pub tab_visits: BTreeMap<String, u32>,
+    pub tab_ms: BTreeMap<String, f64>,
+}
+
+impl Counters {
+    pub fn count_session(&mut self, now_ms: f64) {
+        if self.since_ms == 0.0 {
+            self.since_ms = now_ms;
+        }
+        self.sessions += 1;
+    }
+
+    pub fn count_attack(&mut self, attack: &str) {
+        *self.attacks.entry(attack.to_string()).or_default() += 1;
+    }
+
+    /// credits one visit to `tab`, capped at MAX_VISIT_MS
+    pub fn add_visit(&mut self, tab: &str, ms: f64) {
+        *self.tab_visits.entry(tab.to_string()).or_default() += 1;
+        *self.tab_ms.entry(tab.to_string()).or_default() += ms.clamp(0.0, MAX_VISIT_MS);
+    }
+
+    /// attacks, most run first
+    pub fn top_attacks(&self) -> Vec<(&str, u32)> {
+        let mut rows: Vec<(&str, u32)> = self.attacks.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        rows.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        rows
+    }
+
+    /// (tab, visits, ms), longest dwell first
+    pub fn dwell(&self) -> Vec<(&str, u32, f64)> {
+        let mut rows: Vec<(&str, u32, f64)> = self.tab_ms.iter().map(|(k, ms)| (k.as_str(), self.tab_visits.get(k).copied().unwrap_or(0), *ms)).collect();
+        rows.sort_by(|a, b| b.2.total_cmp(&a.2).then(a.0.cmp(b.0)));
+        rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sessions == 0 && self.attacks.is_empty() && self.tab_ms.is_empty()
+    }
+
+    pub fn from_json(json: &str) -> Result<Counters, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    /// pretty json blob for export
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// which tab is on screen and since when; a switch closes the previous visit
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DwellClock {
+    current: Option<(&'static str, f64)>,
+}
+
+impl DwellClock {
+    /// `tab` is None while nothing should be counted (analytics off, page hidden, kiosk cycling);
+    /// returns the visit that just ended
+    pub fn switch(&mut self, tab: Option<&'static str>, now_ms: f64) -> Option<(&'static str, f64)> {
+        if self.current.map(|(t, _)| t) == tab {
+            return None;
+        }
+        let ended = self.current.map(|(t, since)| (t, now_ms - since));
+        self.current = tab.map(|t| (t, now_ms));
+        ended
+    }
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// saved counters, empty when absent or unreadable
+pub fn load() -> Counters {
+    storage()
+        .and_then(|s| s.get_item(STORAGE_KEY).ok()?)
+        .and_then(|json| Counters::from_json(&json).ok())
+        .unwrap_or_default()
+}
+
+/// persists the counters; empty counters remove the key
+pub fn save(counters: &Counters) {
+    let Some(s) = storage() else { return };
+    let _ = if counters.is_empty() { s.remove_item(STORAGE_KEY) } else { s.set_item(STORAGE_KEY, &counters.to_json()) };
+}
+
+/// counters signal provided by App (a detached one where there is none, e.g. native tests)
+pub fn use_analytics() -> RwSignal<Counters> {
+    use_context::<RwSignal<Counters>>().unwrap_or_else(|| create_rw_signal(Counters::default()))
+}
@@ -0,0 +1,57 @@
+// what: session analytics section of the settings modal - opt-in toggle, the counters as recorded, export and clear
+// why: the viewer sees exactly what is kept before and after opting in, and can take it or wipe it
+// relations: counters from mod.rs, opt-in flag in settings/mod.rs, rendered inside settings/panel.rs
+
+use leptos::*;
+use super::{use_analytics, Counters};
+use crate::settings::use_settings;
+use crate::tabs::demo::wasm::{copy_to_clipboard, download_text};
+
+fn minutes(ms: f64) -> String {
+    format!("{:.1} min", ms / 60_000.0)
+}
+
+/// first-session date as yyyy-mm-dd
+fn since(ms: f64) -> String {
+    String::from(js_sys::Date::new(&ms.into()).to_iso_string()).chars().take(10).collect()
+}
+
+#[component]
+pub fn AnalyticsSettings() -> impl IntoView {
+    let settings = use_settings();
+    let counters = use_analytics();
+    view! {
+        <fieldset class="settings-group">
+            <legend>"Session analytics"</legend>
+            <label class="settings-check">
+                <input type="checkbox" prop:checked=move || settings.with(|s| s.analytics)
+                    on:change=move |ev| settings.update(|s| s.analytics = event_target_checked(&ev)) />
+                "Count attacks run and time per tab"
+            </label>
+            <p class="section-desc">"Off by default. Plain counters kept in this browser's localStorage — no identifiers, no network requests. Export them to share."</p>
+            {move || counters.with(|c| (!c.is_empty()).then(|| view! {
+                <p class="audit-status">{format!("{} session{} since {}", c.sessions, if c.sessions == 1 { "" } else { "s" }, since(c.since_ms))}</p>
+                <table class="capability-matrix analytics-table">
+                    <tr><th>"Tab"</th><th>"Visits"</th><th>"Time"</th></tr>
+                    {c.dwell().into_iter().map(|(tab, visits, ms)| view! {
+                        <tr><td>{tab.to_string()}</td><td>{visits}</td><td>{minutes(ms)}</td></tr>
+                    }).collect_view()}
+                </table>
+                <table class="capability-matrix analytics-table">
+                    <tr><th>"Attack"</th><th>"Runs"</th></tr>
+                    {c.top_attacks().into_iter().map(|(attack, runs)| view! {
+                        <tr><td>{attack.to_string()}</td><td>{runs}</td></tr>
+                    }).collect_view()}
+                </table>
+            }))}
+            <div class="audit-controls">
+                <button class="action-btn" disabled=move || counters.with(Counters::is_empty)
+                    on:click=move |_| download_text("guardian-analytics.json", "application/json", &counters.with(Counters::to_json))>"⬇️ Export"</button>
+                <button class="action-btn" disabled=move || counters.with(Counters::is_empty)
+                    on:click=move |_| copy_to_clipboard(&counters.with(Counters::to_json))>"📋 Copy"</button>
+                <button class="action-btn" disabled=move || counters.with(Counters::is_empty)
+                    on:click=move |_| counters.set(Counters::default())>"🗑 Clear"</button>
+            </div>
+        </fieldset>
+    }
+}
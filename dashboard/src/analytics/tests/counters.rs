@@ -0,0 +1,54 @@
+// what: tests for the opt-in analytics counters and the tab dwell clock
+// why: the export is what the maintainer reads; a stuck clock or a garbled blob would misreport which parts land
+
+use crate::analytics::{Counters, DwellClock, MAX_VISIT_MS};
+
+#[test]
+fn attacks_count_and_sort_most_run_first() {
+    // what: repeated attacks accumulate; ties sort by id
+    // why: the table answers "what do people click" at a glance
+    let mut c = Counters::default();
+    ["memory", "fsRead", "memory", "killLeader", "memory", "fsRead"].into_iter().for_each(|a| c.count_attack(a));
+    assert_eq!(c.top_attacks(), vec![("memory", 3), ("fsRead", 2), ("killLeader", 1)]);
+}
+
+#[test]
+fn visits_are_capped_and_sorted_by_dwell() {
+    // what: a visit longer than MAX_VISIT_MS is credited MAX_VISIT_MS; rows sort by total time
+    // why: one console left open overnight must not drown every real visit
+    let mut c = Counters::default();
+    c.add_visit("demo", 8.0 * 3_600_000.0);
+    c.add_visit("problem", 60_000.0);
+    c.add_visit("problem", 30_000.0);
+    assert_eq!(c.dwell(), vec![("demo", 1, MAX_VISIT_MS), ("problem", 2, 90_000.0)]);
+}
+
+#[test]
+fn dwell_clock_closes_the_previous_visit_on_switch() {
+    // what: switching tabs ends the previous visit; pausing (None) ends it too and resuming starts a fresh one
+    // why: hidden pages and unattended kiosk cycling are not attention
+    let mut clock = DwellClock::default();
+    assert_eq!(clock.switch(Some("problem"), 0.0), None);
+    assert_eq!(clock.switch(Some("problem"), 5_000.0), None);
+    assert_eq!(clock.switch(Some("demo"), 20_000.0), Some(("problem", 20_000.0)));
+    assert_eq!(clock.switch(None, 50_000.0), Some(("demo", 30_000.0)));
+    assert_eq!(clock.switch(None, 90_000.0), None);
+    assert_eq!(clock.switch(Some("demo"), 100_000.0), None);
+    assert_eq!(clock.switch(Some("proof"), 101_000.0), Some(("demo", 1_000.0)));
+}
+
+#[test]
+fn export_round_trips_and_tolerates_missing_fields() {
+    // what: the exported json parses back unchanged; older or partial blobs fill in defaults; garbage is an error
+    // why: exports from different builds get merged by hand
+    let mut c = Counters::default();
+    c.count_session(1_700_000_000_000.0);
+    c.count_session(1_800_000_000_000.0);
+    c.count_attack("memory");
+    c.add_visit("demo", 1_000.0);
+    assert_eq!(c.since_ms, 1_700_000_000_000.0);
+    assert_eq!(Counters::from_json(&c.to_json()).unwrap(), c);
+    assert_eq!(Counters::from_json(r#"{"sessions":2}"#).unwrap().sessions, 2);
+    assert!(Counters::from_json("not json").is_err());
+    assert!(Counters::default().is_empty() && !c.is_empty());
+}
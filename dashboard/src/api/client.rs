@@ -0,0 +1,156 @@
+// what: cluster api client with a mock/live data-source switch, plus the live backend's /events websocket
+// why: the same ui calls hit either the in-browser mock or the pi backend; live mode also gets ticks pushed as they happen
+// relations: wraps mock.rs, uses types.rs, used by tabs/hardware/cluster.rs; /events is served by guardian-agent events.rs
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use super::mock::MockServer;
+use super::types::{ApiError, ClusterEvent, FaultReceipt, FaultRequest, NodeStatus, VoteResult, WasmtimeStats};
+
+/// where api calls go
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataSource {
+    Mock,
+    /// base url of the pi backend, e.g. http://guardian.local:8080
+    Live(String),
+}
+
+/// joins a base url and an endpoint path without doubling slashes
+pub fn endpoint_url(base: &str, path: &str) -> String {
+    format!("{}/api/v1/{}", base.trim_end_matches('/'), path.trim_start_matches('/'))
+}
+
+/// ws:// (wss:// for an https base) url of the /events stream
+pub fn events_url(base: &str) -> String {
+    let url = endpoint_url(base, "events");
+    match url.split_once("://") {
+        Some(("https", rest)) => format!("wss://{}", rest),
+        Some((_, rest)) => format!("ws://{}", rest),
+        None => url,
+    }
+}
+
+/// one /events text frame
+pub fn decode_event(text: &str) -> Result<ClusterEvent, ApiError> {
+    serde_json::from_str(text).map_err(|e| ApiError::Decode(e.to_string()))
+}
+
+/// the live backend's /events websocket; dropping it closes the socket
+pub struct EventStream {
+    socket: web_sys::WebSocket,
+    _on_message: Closure<dyn FnMut(web_sys::MessageEvent)>,
+    _on_close: Closure<dyn FnMut()>,
+}
+
+impl EventStream {
+    /// connects to `base`'s /events; every frame is handed to `on_event`, and a closed or failed socket as a Network error
+    pub fn open(base: &str, on_event: impl FnMut(Result<ClusterEvent, ApiError>) + 'static) -> Result<Self, ApiError> {
+        let url = events_url(base);
+        let socket = web_sys::WebSocket::new(&url).map_err(|e| ApiError::Network(e.as_string().unwrap_or_else(|| format!("bad event stream url {}", url))))?;
+        let on_event = Rc::new(RefCell::new(on_event));
+        let on_frame = Rc::clone(&on_event);
+        let on_message = Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |event: web_sys::MessageEvent| {
+            let text = event.data().as_string().unwrap_or_default();
+            (on_frame.borrow_mut())(decode_event(&text));
+        });
+        let closed_url = url.clone();
+        let on_close = Closure::<dyn FnMut()>::new(move || {
+            tracing::warn!(target: "api", url = %closed_url, "event stream closed");
+            (on_event.borrow_mut())(Err(ApiError::Network("event stream closed".to_string())));
+        });
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        tracing::debug!(target: "api", url = %url, "event stream opened");
+        Ok(Self { socket, _on_message: on_message, _on_close: on_close })
+    }
+}
+
+impl Drop for EventStream {
+    fn drop(&mut self) {
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+/// typed client, cheap to clone
+#[derive(Clone)]
+pub struct ApiClient {
+    source: DataSource,
+    mock: Rc<RefCell<MockServer>>,
+}
+
+impl ApiClient {
+    pub fn new(source: DataSource, mock: Rc<RefCell<MockServer>>) -> Self {
+        Self { source, mock }
+    }
+
+    pub async fn list_nodes(&self) -> Result<Vec<NodeStatus>, ApiError> {
+        match &self.source {
+            DataSource::Mock => Ok(self.mock.borrow_mut().list_nodes(js_sys::Date::now())),
+            DataSource::Live(base) => fetch_json("GET", &endpoint_url(base, "nodes"), None).await,
+        }
+    }
+
+    pub async fn recent_votes(&self, limit: usize) -> Result<Vec<VoteResult>, ApiError> {
+        match &self.source {
+            DataSource::Mock => Ok(self.mock.borrow_mut().recent_votes(js_sys::Date::now(), limit)),
+            DataSource::Live(base) => fetch_json("GET", &endpoint_url(base, &format!("votes?limit={}", limit)), None).await,
+        }
+    }
+
+    pub async fn trigger_fault(&self, request: &FaultRequest) -> Result<FaultReceipt, ApiError> {
+        match &self.source {
+            DataSource::Mock => self.mock.borrow_mut().trigger_fault(js_sys::Date::now(), request),
+            DataSource::Live(base) => {
+                let body = serde_json::to_string(request).map_err(|e| ApiError::Decode(e.to_string()))?;
+                fetch_json("POST", &endpoint_url(base, "faults"), Some(body)).await
+            }
+        }
+    }
+
+    pub async fn wasmtime_stats(&self) -> Result<WasmtimeStats, ApiError> {
+        match &self.source {
+            DataSource::Mock => Ok(self.mock.borrow_mut().wasmtime_stats(js_sys::Date::now())),
+            DataSource::Live(base) => fetch_json("GET", &endpoint_url(base, "wasmtime/stats"), None).await,
+        }
+    }
+}
+
+/// fetch + json decode, non-2xx maps to ApiError::Status
+async fn fetch_json<T: DeserializeOwned>(method: &str, url: &str, body: Option<String>) -> Result<T, ApiError> {
+    let result = fetch_json_untraced(method, url, body).await;
+    match &result {
+        Ok(_) => tracing::debug!(target: "api", method, url, "ok"),
+        Err(e) => tracing::warn!(target: "api", method, url, error = ?e, "failed"),
+    }
+    result
+}
+
+async fn fetch_json_untraced<T: DeserializeOwned>(method: &str, url: &str, body: Option<String>) -> Result<T, ApiError> {
+    let network = |e: JsValue| ApiError::Network(e.as_string().unwrap_or_else(|| "request failed (check CORS)".to_string()));
+
+    let init = web_sys::RequestInit::new();
+    init.set_method(method);
+    if let Some(body) = &body {
+        init.set_body(&JsValue::from_str(body));
+    }
+    let request = web_sys::Request::new_with_str_and_init(url, &init).map_err(network)?;
+    request.headers().set("Accept", "application/json").map_err(network)?;
+    if body.is_some() {
+        request.headers().set("Content-Type", "application/json").map_err(network)?;
+    }
+
+    let window = web_sys::window().ok_or_else(|| ApiError::Network("no window".to_string()))?;
+    let response = wasm_bindgen_futures::JsFuture::from(window.fetch_with_request(&request)).await.map_err(network)?;
+    let response: web_sys::Response = response.unchecked_into();
+    if !response.ok() {
+        return Err(ApiError::Status(response.status()));
+    }
+    let text = wasm_bindgen_futures::JsFuture::from(response.text().map_err(network)?).await.map_err(network)?;
+    let text = text.as_string().unwrap_or_default();
+    serde_json::from_str(&text).map_err(|e| ApiError::Decode(e.to_string()))
+}
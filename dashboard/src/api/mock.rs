@@ -0,0 +1,106 @@
+// what: in-browser mock of the guardian cluster backend
+// why: lets the cluster ui be built and demoed without a pi on the desk
+// relations: implements the endpoints listed in mod.rs on guardian-types' cluster state machine (the one guardian-agent runs),
+//            driven by client.rs when DataSource::Mock
+
+use guardian_types::cluster::{ClusterState, NODE_COUNT, VOTE_HISTORY};
+use super::types::{ApiError, FaultKind, FaultReceipt, FaultRequest, NodeStatus, VoteResult, WasmtimeStats};
+
+/// mock sampling period
+pub const VOTE_PERIOD_MS: f64 = 1000.0;
+/// per-instance linear memory reported by the mock (2 mb sensor-node limit)
+const INSTANCE_MEMORY_BYTES: u64 = 2 * 1024 * 1024;
+
+/// deterministic fake backend: the shared cluster state machine fed with noise instead of a sensor
+#[derive(Clone, Debug)]
+pub struct MockServer {
+    state: ClusterState,
+    /// fault armed per node; its next round traps with the fault's id
+    armed: [Option<FaultKind>; NODE_COUNT],
+    stats: WasmtimeStats,
+    last_vote_ms: f64,
+    rng: u32,
+}
+
+/// xorshift noise in [0, 1)
+fn noise(rng: &mut u32) -> f64 {
+    *rng ^= *rng << 13;
+    *rng ^= *rng >> 17;
+    *rng ^= *rng << 5;
+    (*rng % 10_000) as f64 / 10_000.0
+}
+
+impl MockServer {
+    pub fn new(now_ms: f64) -> Self {
+        Self {
+            state: ClusterState::new(now_ms),
+            armed: [None; NODE_COUNT],
+            stats: WasmtimeStats {
+                instances: NODE_COUNT as u32,
+                instantiations: NODE_COUNT as u64,
+                memory_bytes: INSTANCE_MEMORY_BYTES * NODE_COUNT as u64,
+                avg_instantiate_us: 38.0,
+                ..Default::default()
+            },
+            last_vote_ms: now_ms,
+            rng: 0x2545_f491,
+        }
+    }
+
+    /// generates the votes that would have happened since the last call
+    pub fn catch_up(&mut self, now_ms: f64) {
+        // after a long background-tab sleep only the last VOTE_HISTORY votes would be kept; skip whole periods before them
+        let missed = ((now_ms - self.last_vote_ms) / VOTE_PERIOD_MS).floor();
+        if missed > VOTE_HISTORY as f64 {
+            self.last_vote_ms += (missed - VOTE_HISTORY as f64) * VOTE_PERIOD_MS;
+        }
+        while now_ms - self.last_vote_ms >= VOTE_PERIOD_MS {
+            self.last_vote_ms += VOTE_PERIOD_MS;
+            let at = self.last_vote_ms;
+            self.stats.instantiations += self.state.restarts_due(at).len() as u64;
+            let base = 42.0 + noise(&mut self.rng) * 0.5;
+            let (armed, rng, traps) = (&mut self.armed, &mut self.rng, &mut self.stats.traps);
+            self.state.round(at, |id| match armed[id].take() {
+                Some(kind) => {
+                    *traps += 1;
+                    Err(kind.id().to_string())
+                }
+                None => Ok(base + noise(rng) * 0.01),
+            });
+        }
+    }
+
+    /// GET /nodes
+    pub fn list_nodes(&mut self, now_ms: f64) -> Vec<NodeStatus> {
+        self.catch_up(now_ms);
+        self.state.list_nodes(now_ms)
+    }
+
+    /// GET /votes?limit=N
+    pub fn recent_votes(&mut self, now_ms: f64, limit: usize) -> Vec<VoteResult> {
+        self.catch_up(now_ms);
+        self.state.recent_votes(limit)
+    }
+
+    /// POST /faults
+    pub fn trigger_fault(&mut self, now_ms: f64, request: &FaultRequest) -> Result<FaultReceipt, ApiError> {
+        self.catch_up(now_ms);
+        let receipt = self.state.inject(now_ms, request).map_err(ApiError::Rejected)?;
+        if receipt.trapped {
+            self.armed[request.node as usize] = Some(request.kind);
+        }
+        Ok(receipt)
+    }
+
+    /// GET /wasmtime/stats
+    pub fn wasmtime_stats(&mut self, now_ms: f64) -> WasmtimeStats {
+        self.catch_up(now_ms);
+        let packets: u64 = self.state.list_nodes(now_ms).iter().map(|n| n.packets_processed).sum();
+        WasmtimeStats {
+            // ~1.2k fuel per parse_frame call on the pi build
+            fuel_consumed: packets * 1_200,
+            instances: (0..NODE_COUNT).filter(|&id| !self.state.is_down(id, now_ms)).count() as u32,
+            ..self.stats.clone()
+        }
+    }
+}
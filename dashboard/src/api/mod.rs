@@ -0,0 +1,21 @@
+// what: typed client for the guardian cluster rest api (pi-side backend)
+// why: ui work against the real cluster shouldn't wait on hardware, so a mock server ships alongside
+// relations: types.rs (wire types), client.rs (data-source switch + fetch), mock.rs (in-browser backend),
+//            used by tabs/hardware/cluster.rs
+//
+// endpoints (all json, prefix /api/v1):
+//   GET  /nodes                 -> Vec<NodeStatus>
+//   GET  /votes?limit=N         -> Vec<VoteResult> (newest first)
+//   POST /faults  FaultRequest  -> FaultReceipt
+//   GET  /wasmtime/stats        -> WasmtimeStats (404 from guardian-agent --native, whose nodes are not wasm instances)
+//   GET  /status-map            -> [StatusMapping] (guardian-types status_map.rs)
+//   GET  /events (websocket)    -> one ClusterEvent text frame per tick (client.rs EventStream, live only)
+
+pub mod types;
+pub mod mock;
+pub mod client;
+
+#[cfg(test)]
+mod tests;
+
+pub use client::{ApiClient, DataSource, EventStream};
@@ -0,0 +1,27 @@
+// what: tests for the live backend's /events websocket helpers
+// why: a wrong scheme never connects and a decode drift leaves the live cluster view frozen on its last poll
+
+use guardian_types::NodeRole;
+use crate::api::client::{decode_event, events_url};
+use crate::api::types::ApiError;
+
+#[test]
+fn events_url_switches_scheme() {
+    // what: http bases stream over ws://, https bases over wss://, under the same /api/v1 prefix
+    // why: browsers refuse ws:// from an https page, and the agent serves /events next to the rest api
+    assert_eq!(events_url("http://pi:8080/"), "ws://pi:8080/api/v1/events");
+    assert_eq!(events_url("https://guardian.example"), "wss://guardian.example/api/v1/events");
+}
+
+#[test]
+fn decodes_agent_tick_frames() {
+    // what: a frame as guardian-agent's events.rs writes it decodes to the vote and node list; anything else is a Decode error
+    // why: the hardware tab applies these straight to its node cards and vote table
+    let frame = r#"{"vote":{"timestamp_ms":1000.0,"values":[42.0,null,42.1],"voted":42.05,"faulty_node":1},
+        "nodes":[{"id":0,"role":"leader","uptime_s":3,"packets_processed":3,"last_trap":null}]}"#;
+    let event = decode_event(frame).unwrap();
+    assert_eq!(event.vote.values[1], None);
+    assert_eq!(event.vote.faulty_node, Some(1));
+    assert_eq!(event.nodes[0].role, NodeRole::Leader);
+    assert!(matches!(decode_event("ping"), Err(ApiError::Decode(_))));
+}
@@ -0,0 +1,93 @@
+// what: tests for the in-browser mock cluster backend and client helpers
+// why: ui work is built against the mock, so it must behave like the pi backend
+
+use crate::api::client::endpoint_url;
+use guardian_types::cluster::{RECOVERY_MS, VOTE_HISTORY};
+use guardian_types::NodeRole;
+use crate::api::mock::{MockServer, VOTE_PERIOD_MS};
+use crate::api::types::{ApiError, FaultKind, FaultRequest};
+
+const T0: f64 = 1_700_000_000_000.0;
+
+#[test]
+fn fresh_cluster_has_one_leader() {
+    // what: three nodes, node 0 leads, others follow
+    // why: the ui assumes exactly one leader in a healthy cluster
+    let mut mock = MockServer::new(T0);
+    let nodes = mock.list_nodes(T0);
+    assert_eq!(nodes.len(), 3);
+    assert_eq!(nodes.iter().filter(|n| n.role == NodeRole::Leader).count(), 1);
+    assert_eq!(nodes[0].role, NodeRole::Leader);
+}
+
+#[test]
+fn votes_accumulate_per_period_and_cap() {
+    // what: one vote per period, newest first, bounded history
+    // why: recent-votes must not grow without limit on a long-open tab
+    let mut mock = MockServer::new(T0);
+    let votes = mock.recent_votes(T0 + 3.0 * VOTE_PERIOD_MS, 10);
+    assert_eq!(votes.len(), 3);
+    assert!(votes[0].timestamp_ms > votes[1].timestamp_ms);
+    let votes = mock.recent_votes(T0 + (VOTE_HISTORY as f64 + 20.0) * VOTE_PERIOD_MS, usize::MAX);
+    assert_eq!(votes.len(), VOTE_HISTORY);
+}
+
+#[test]
+fn long_sleep_generates_only_the_kept_votes() {
+    // what: a month without calls yields exactly VOTE_HISTORY votes, still on the period grid and ending at the latest period
+    // why: catching up vote by vote over the whole gap froze the tab on wake only to throw all but the last window away
+    let mut mock = MockServer::new(T0);
+    let periods = 30.0 * 86_400.0;
+    let votes = mock.recent_votes(T0 + periods * VOTE_PERIOD_MS + 500.0, usize::MAX);
+    assert_eq!(votes.len(), VOTE_HISTORY);
+    assert_eq!(votes[0].timestamp_ms, T0 + periods * VOTE_PERIOD_MS);
+    assert_eq!(votes[VOTE_HISTORY - 1].timestamp_ms, T0 + (periods - VOTE_HISTORY as f64 + 1.0) * VOTE_PERIOD_MS);
+}
+
+#[test]
+fn trap_fault_votes_node_out_once() {
+    // what: sandbox faults trap, the next vote excludes that node but still reaches consensus
+    // why: mirrors the demo's 2oo3 behaviour against the real cluster
+    let mut mock = MockServer::new(T0);
+    let receipt = mock.trigger_fault(T0, &FaultRequest { node: 1, kind: FaultKind::BufferOverflow }).unwrap();
+    assert!(receipt.trapped);
+    let votes = mock.recent_votes(T0 + 2.0 * VOTE_PERIOD_MS, 2);
+    assert_eq!(votes[1].faulty_node, Some(1));
+    assert!(votes[1].voted.is_some());
+    assert_eq!(votes[0].faulty_node, None);
+    assert_eq!(mock.wasmtime_stats(T0 + 2.0 * VOTE_PERIOD_MS).traps, 1);
+}
+
+#[test]
+fn killing_leader_elects_new_one_and_recovers() {
+    // what: kill-process on the leader promotes the next node, victim rejoins after recovery
+    // why: the cluster view must show failover, not a headless cluster
+    let mut mock = MockServer::new(T0);
+    let receipt = mock.trigger_fault(T0, &FaultRequest { node: 0, kind: FaultKind::KillProcess }).unwrap();
+    assert!(!receipt.trapped);
+    let nodes = mock.list_nodes(T0 + 1.0);
+    assert_eq!(nodes[0].role, NodeRole::Recovering);
+    assert_eq!(nodes[1].role, NodeRole::Leader);
+    let nodes = mock.list_nodes(T0 + RECOVERY_MS + 1.0);
+    assert_eq!(nodes[0].role, NodeRole::Follower);
+}
+
+#[test]
+fn invalid_fault_requests_are_rejected() {
+    // what: unknown node and double-kill are refused
+    // why: the live backend returns 4xx for these, the mock must not silently accept
+    let mut mock = MockServer::new(T0);
+    assert!(matches!(mock.trigger_fault(T0, &FaultRequest { node: 7, kind: FaultKind::DataExfil }), Err(ApiError::Rejected(_))));
+    mock.trigger_fault(T0, &FaultRequest { node: 2, kind: FaultKind::KillProcess }).unwrap();
+    assert!(mock.trigger_fault(T0 + 1.0, &FaultRequest { node: 2, kind: FaultKind::PathTraversal }).is_err());
+}
+
+#[test]
+fn wire_format_and_urls() {
+    // what: fault kinds serialize kebab-case, endpoint urls join cleanly
+    // why: the pi backend matches on these exact strings and paths
+    let body = serde_json::to_string(&FaultRequest { node: 1, kind: FaultKind::KillProcess }).unwrap();
+    assert_eq!(body, r#"{"node":1,"kind":"kill-process"}"#);
+    assert_eq!(endpoint_url("http://pi:8080/", "/nodes"), "http://pi:8080/api/v1/nodes");
+    assert_eq!(FaultKind::from_id("data-exfil"), Some(FaultKind::DataExfil));
+}
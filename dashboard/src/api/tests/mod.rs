@@ -0,0 +1,8 @@
+// what: exports all test modules for the cluster api
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod mock_server;
+
+#[cfg(test)]
+mod events;
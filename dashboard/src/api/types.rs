@@ -0,0 +1,25 @@
+// what: wire types shared by the live client and the mock server
+// why: one definition keeps the mock honest about what the pi backend returns
+// relations: wire types from guardian-types (also served by guardian-agent), used by client.rs, mock.rs and tabs/hardware/cluster.rs
+
+pub use guardian_types::{ClusterEvent, FaultKind, FaultReceipt, FaultRequest, NodeStatus, VoteResult, WasmtimeStats};
+
+/// why a call failed
+#[derive(Clone, Debug, PartialEq)]
+pub enum ApiError {
+    Network(String),
+    Status(u16),
+    Decode(String),
+    Rejected(String),
+}
+
+impl ApiError {
+    pub fn reason(&self) -> String {
+        match self {
+            ApiError::Network(e) => format!("network error: {}", e),
+            ApiError::Status(code) => format!("backend returned HTTP {}", code),
+            ApiError::Decode(e) => format!("unexpected response: {}", e),
+            ApiError::Rejected(e) => format!("rejected: {}", e),
+        }
+    }
+}
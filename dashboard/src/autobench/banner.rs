@@ -0,0 +1,62 @@
+// what: header banner for the background benchmark - the consent prompt, step progress, and a "proof tab ready" line
+// why: the run downloads pyodide and uses the cpu, so it is asked for once and stays visible while it works
+// relations: decisions and texts from mod.rs, consent stored in settings/mod.rs, results from tabs/proof/bench.rs; hidden in kiosk attract mode like whatsnew/banner.rs
+
+use leptos::*;
+use crate::kiosk::use_kiosk;
+use crate::settings::{use_settings, AutoBenchmark};
+use crate::tabs::proof::bench::use_proof_results;
+use super::{on_load, progress_text, ready_text, start, OnLoad, START_DELAY_MS};
+
+/// `on_view` opens the proof tab
+#[component]
+pub fn AutoBenchBanner(on_view: Callback<()>) -> impl IntoView {
+    let settings = use_settings();
+    let kiosk = use_kiosk();
+    let results = use_proof_results();
+    let iterations = move || settings.with_untracked(|s| s.benchmark_iterations);
+    let decision = settings.with_untracked(|s| on_load(s.auto_benchmark, s.kiosk.enabled));
+    if decision == OnLoad::Run {
+        start(results, iterations(), START_DELAY_MS);
+    }
+    let (asking, set_asking) = create_signal(decision == OnLoad::Prompt);
+    let (dismissed, set_dismissed) = create_signal(false);
+    let answer = move |consent: Option<AutoBenchmark>, run: bool| {
+        if let Some(consent) = consent {
+            settings.update(|s| s.auto_benchmark = consent);
+        }
+        if run {
+            start(results, iterations(), 0);
+        }
+        set_asking.set(false);
+    };
+
+    view! {
+        <Show when=move || asking.get() && !kiosk.attract.get()>
+            <p class="kiosk-banner autobench-banner">
+                "⏱️ Measure the Proof tab's numbers in the background now? It downloads the Pyodide runtime once."
+                <button class="action-btn" on:click=move |_| answer(Some(AutoBenchmark::Always), true)>"Yes, every visit"</button>
+                <button class="action-btn" on:click=move |_| answer(None, true)>"Just this once"</button>
+                <button class="action-btn" on:click=move |_| answer(Some(AutoBenchmark::Never), false)>"No thanks"</button>
+            </p>
+        </Show>
+        {move || (!kiosk.attract.get()).then(|| results.with(|r| {
+            if let (Some(text), Some((step, steps, _))) = (progress_text(r), r.progress()) {
+                Some(view! {
+                    <p class="kiosk-banner autobench-banner" role="status">
+                        {text}
+                        <progress max=steps value=step - 1 />
+                    </p>
+                }.into_view())
+            } else {
+                ready_text(r).filter(|_| !dismissed.get()).map(|text| view! {
+                    <p class="kiosk-banner autobench-banner" role="status">
+                        {text}
+                        <button class="action-btn" on:click=move |_| on_view.call(())>"View"</button>
+                        <button class="modal-close" title="Dismiss" on:click=move |_| set_dismissed.set(true)>"×"</button>
+                    </p>
+                }.into_view())
+            }
+        })).flatten()}
+    }
+}
@@ -0,0 +1,71 @@
+// what: optional background run of the proof suite on page load - the consent decision, the delayed start and the header texts
+// why: presenters want the proof tab's numbers ready before they get there; the suite downloads pyodide, so it only runs once allowed
+// relations: consent in settings/mod.rs (AutoBenchmark), runs tabs/proof/bench.rs into the shared ProofResults provided by lib.rs,
+//            asked and reported by banner.rs in the lib.rs header
+
+use leptos::*;
+use crate::settings::{AutoBenchmark, MIN_ITERATIONS};
+use crate::tabs::demo::wasm::set_timeout;
+use crate::tabs::proof::bench::{run, ProofResults};
+
+mod banner;
+
+#[cfg(test)]
+mod tests;
+
+pub use banner::AutoBenchBanner;
+
+/// wait after load so the first paint and the boot waterfall are not measured against the suite
+pub const START_DELAY_MS: u64 = 2000;
+/// iterations cap for a background run, whatever the settings ask for
+pub const MAX_AUTO_ITERATIONS: u32 = 20;
+
+/// what a page load does about the background run
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnLoad {
+    Prompt,
+    Run,
+    Nothing,
+}
+
+pub fn on_load(consent: AutoBenchmark, kiosk: bool) -> OnLoad {
+    match consent {
+        AutoBenchmark::Always => OnLoad::Run,
+        // an unattended kiosk has nobody to answer the prompt
+        AutoBenchmark::Ask if !kiosk => OnLoad::Prompt,
+        AutoBenchmark::Ask | AutoBenchmark::Never => OnLoad::Nothing,
+    }
+}
+
+/// the configured iterations, kept light for a run nobody asked for this visit
+pub fn auto_iterations(configured: u32) -> u32 {
+    configured.clamp(MIN_ITERATIONS, MAX_AUTO_ITERATIONS)
+}
+
+/// header line while a background run is going (button runs report on the proof tab)
+pub fn progress_text(results: &ProofResults) -> Option<String> {
+    if !results.auto {
+        return None;
+    }
+    let (step, steps, label) = results.progress()?;
+    Some(format!("⏱️ Benchmarking in the background {}/{}: {}…", step, steps, label))
+}
+
+/// header line once a background run has finished
+pub fn ready_text(results: &ProofResults) -> Option<String> {
+    (results.auto && results.running.is_none() && results.ran()).then(|| {
+        format!(
+            "✅ Proof tab ready: cold start {} (Python) vs {} (WASM)",
+            results.python_coldstart_ms().display(0),
+            results.wasm_instantiate_ms.display(3),
+        )
+    })
+}
+
+/// starts the suite after `delay_ms`, unless a run is already going by then
+pub fn start(results: RwSignal<ProofResults>, configured_iterations: u32, delay_ms: u64) {
+    set_timeout(
+        move || spawn_local(run(results, auto_iterations(configured_iterations), true)),
+        std::time::Duration::from_millis(delay_ms),
+    );
+}
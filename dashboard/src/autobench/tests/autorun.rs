@@ -0,0 +1,64 @@
+// what: tests for the background benchmark - consent decisions, the iteration cap, suite progress and the header texts
+// why: the run downloads pyodide unasked-for if the consent logic slips, and the header must never claim results it has not measured
+
+use crate::autobench::{auto_iterations, on_load, progress_text, ready_text, OnLoad, MAX_AUTO_ITERATIONS};
+use crate::settings::{AutoBenchmark, Settings};
+use crate::tabs::proof::bench::{BenchStep, ProofResults};
+
+#[test]
+fn consent_decides_what_a_load_does() {
+    // what: Always runs, Ask prompts (except in kiosk mode), Never does nothing; older blobs without the key ask
+    // why: nothing may download in the background before the viewer said yes
+    assert_eq!(on_load(AutoBenchmark::Always, false), OnLoad::Run);
+    assert_eq!(on_load(AutoBenchmark::Always, true), OnLoad::Run);
+    assert_eq!(on_load(AutoBenchmark::Ask, false), OnLoad::Prompt);
+    assert_eq!(on_load(AutoBenchmark::Ask, true), OnLoad::Nothing);
+    assert_eq!(on_load(AutoBenchmark::Never, false), OnLoad::Nothing);
+    assert_eq!(Settings::from_json(r#"{"theme":"dark"}"#).unwrap().auto_benchmark, AutoBenchmark::Ask);
+    assert_eq!(Settings::from_json(r#"{"auto_benchmark":"never"}"#).unwrap().auto_benchmark, AutoBenchmark::Never);
+}
+
+#[test]
+fn background_runs_stay_light() {
+    // what: a background run uses the configured iterations up to MAX_AUTO_ITERATIONS
+    // why: a 1000-iteration setting meant for a deliberate run should not stall every page load
+    assert_eq!(auto_iterations(10), 10);
+    assert_eq!(auto_iterations(1000), MAX_AUTO_ITERATIONS);
+    assert_eq!(auto_iterations(0), 1);
+}
+
+#[test]
+fn suite_steps_through_in_order() {
+    // what: a run claims the suite (a second start is refused), reports 1-based progress, and counts once finished
+    // why: the button and the load-time run share one results signal; two runs at once would interleave samples
+    let mut results = ProofResults::default();
+    assert!(results.begin(true));
+    assert!(!results.begin(false), "a second run waits for the first");
+    assert_eq!(results.progress(), Some((1, 3, BenchStep::WasmInstantiate.label())));
+    results.running = Some(BenchStep::PythonColdStart);
+    assert_eq!(results.progress().map(|(step, _, _)| step), Some(3));
+    assert!(!results.ran());
+    results.python_coldstart_samples.push(1500.0);
+    results.finish();
+    assert!(results.ran() && results.progress().is_none());
+    assert_eq!(results.python_coldstart_ms().median, 1500.0);
+}
+
+#[test]
+fn header_reports_background_runs_only() {
+    // what: the header shows progress and the ready line for a load-time run, nothing for a button run
+    // why: the proof tab already reports its own button runs; the header is for the run nobody clicked
+    let mut results = ProofResults::default();
+    assert!(results.begin(true));
+    assert!(progress_text(&results).unwrap().contains("1/3"));
+    assert_eq!(ready_text(&results), None, "not ready mid-run");
+    results.python_coldstart_samples.push(1500.0);
+    results.finish();
+    assert_eq!(progress_text(&results), None);
+    assert!(ready_text(&results).unwrap().contains("1500ms"));
+
+    assert!(results.begin(false));
+    assert_eq!(progress_text(&results), None);
+    results.finish();
+    assert_eq!(ready_text(&results), None);
+}
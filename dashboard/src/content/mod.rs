@@ -0,0 +1,117 @@
+// what: typed model of the dashboard's narrative text (quotes, cves, ics stats, info box), parsed from narrative.toml
+// why: narrative can be edited (and later translated) without touching view code
+// relations: include_str! of narrative.toml; read by problem/quotes.rs, problem/vulnerabilities.rs (+ cvss_panel.rs) and demo/info_box.rs
+
+use std::sync::OnceLock;
+use leptos::*;
+use serde::Deserialize;
+
+#[cfg(test)]
+mod tests;
+
+/// the embedded narrative file
+pub const NARRATIVE_TOML: &str = include_str!("narrative.toml");
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Quote {
+    pub name: String,
+    pub role: String,
+    pub date: String,
+    pub avatar: String,
+    pub text: String,
+    pub url: String,
+    pub article_url: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Cve {
+    pub id: String,
+    pub name: String,
+    pub desc: String,
+    pub score: String,
+    /// css class of the badge (critical / high)
+    pub severity: String,
+    pub url: String,
+    /// cvss v3.1 base vector behind the score
+    pub vector: String,
+    /// metrics the capability model changes, applied on top of the vector
+    #[serde(default)]
+    pub adjust: Vec<CvssAdjustment>,
+}
+
+/// one vector component the capability model changes, and why
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct CvssAdjustment {
+    pub metric: String,
+    pub value: String,
+    pub why: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct IcsStat {
+    pub value: String,
+    pub label: String,
+    pub source: String,
+    pub url: String,
+}
+
+/// a bold lead-in followed by plain text
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Callout {
+    pub lead: String,
+    pub text: String,
+}
+
+/// one block of the "about this demo" box: a paragraph, a list, or both
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct InfoSection {
+    pub title: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub items: Vec<String>,
+}
+
+/// everything in narrative.toml
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Content {
+    pub quotes: Vec<Quote>,
+    pub cves: Vec<Cve>,
+    pub ics_stats: Vec<IcsStat>,
+    pub defense_in_depth: Callout,
+    pub info_sections: Vec<InfoSection>,
+}
+
+impl Content {
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+}
+
+/// the embedded narrative, parsed on first use
+pub fn content() -> &'static Content {
+    static CONTENT: OnceLock<Content> = OnceLock::new();
+    CONTENT.get_or_init(|| Content::from_toml(NARRATIVE_TOML).expect("content/narrative.toml must parse"))
+}
+
+// ============================================================================
+// inline markup (**bold** and {placeholders})
+// ============================================================================
+
+/// replaces each `{key}` with its value; unknown placeholders stay visible
+pub fn fill(text: &str, values: &[(&str, String)]) -> String {
+    values.iter().fold(text.to_string(), |acc, (key, value)| acc.replace(&format!("{{{}}}", key), value))
+}
+
+/// splits on `**` into (text, bold) runs; empty runs are dropped
+pub fn runs(text: &str) -> Vec<(&str, bool)> {
+    text.split("**").enumerate().filter(|(_, run)| !run.is_empty()).map(|(i, run)| (run, i % 2 == 1)).collect()
+}
+
+/// renders marked-up text with <strong> for the bold runs
+pub fn rich(text: &str) -> View {
+    runs(text).into_iter().map(|(run, bold)| {
+        let run = run.to_string();
+        if bold { view! { <strong>{run}</strong> }.into_view() } else { run.into_view() }
+    }).collect_view()
+}
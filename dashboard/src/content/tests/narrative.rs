@@ -0,0 +1,35 @@
+// what: tests for narrative.toml parsing and the inline markup helpers
+// why: the file is edited by hand; a typo must fail cargo test, not blank a tab at runtime
+
+use crate::content::{content, fill, runs, Content};
+
+#[test]
+fn embedded_narrative_parses() {
+    // what: the shipped file parses and every section has entries with links
+    // why: content() panics on a bad file, so the build must prove it parses
+    let c = content();
+    assert_eq!(c.quotes.len(), 2);
+    assert_eq!(c.cves.len(), 3);
+    assert_eq!(c.ics_stats.len(), 4);
+    assert!(c.cves.iter().all(|cve| cve.url.ends_with(&cve.id)));
+    assert!(c.quotes.iter().map(|q| &q.url).chain(c.ics_stats.iter().map(|s| &s.url)).all(|url| url.starts_with("https://")));
+    assert!(c.info_sections.iter().all(|s| s.body.is_some() || !s.items.is_empty()));
+}
+
+#[test]
+fn missing_field_is_an_error() {
+    // what: a cve without a score is rejected with the field name
+    // why: a silently defaulted field would render an empty badge
+    let err = Content::from_toml("quotes = []\nics_stats = []\ninfo_sections = []\n[defense_in_depth]\nlead = \"\"\ntext = \"\"\n[[cves]]\nid = \"x\"\nname = \"x\"\ndesc = \"x\"\nseverity = \"high\"\nurl = \"x\"\n").unwrap_err();
+    assert!(err.contains("score"), "{}", err);
+}
+
+#[test]
+fn markup_bold_runs_and_placeholders() {
+    // what: ** toggles bold, placeholders are replaced, unknown ones are left alone
+    // why: the info box mixes measured numbers into bold narrative
+    let text = fill("leader at **{claim}** in {terms} terms, {other}", &[("claim", "12ms".to_string()), ("terms", "1.20".to_string())]);
+    assert_eq!(text, "leader at **12ms** in 1.20 terms, {other}");
+    assert_eq!(runs(&text), vec![("leader at ", false), ("12ms", true), (" in 1.20 terms, {other}", false)]);
+    assert_eq!(runs("**Real:** x"), vec![("Real:", true), (" x", false)]);
+}
@@ -0,0 +1,121 @@
+// what: build metadata, wasm bundle and pyodide download sizes, plus a copyable bug report
+// why: "it broke" from a viewer is only actionable with the commit, toolchain, browser and probe results attached
+// relations: env vars stamped by dashboard/build.rs, rendered and copied by panel.rs, sizes also read by tabs/proof
+
+use wasm_bindgen::JsValue;
+use super::caps::BrowserCaps;
+use super::Diagnostics;
+
+/// what this binary was built from
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub commit: &'static str,
+    /// seconds since the unix epoch
+    pub built_unix: u64,
+    pub rustc: &'static str,
+    pub leptos: &'static str,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            commit: env!("GUARDIAN_GIT_COMMIT"),
+            built_unix: env!("GUARDIAN_BUILD_UNIX").parse().unwrap_or(0),
+            rustc: env!("GUARDIAN_RUSTC"),
+            leptos: env!("GUARDIAN_LEPTOS"),
+        }
+    }
+
+    /// (label, value) rows for the about table
+    pub fn rows(&self) -> [(&'static str, String); 5] {
+        [
+            ("Version", self.version.to_string()),
+            ("Commit", self.commit.to_string()),
+            ("Built", utc_timestamp(self.built_unix)),
+            ("Compiler", self.rustc.to_string()),
+            ("Leptos", self.leptos.to_string()),
+        ]
+    }
+
+    /// plain-text report pasted into an issue
+    pub fn bug_report(&self, caps: &BrowserCaps, wasm_bytes: Option<f64>, diagnostics: &Diagnostics) -> String {
+        let mut out = String::from("Guardian-One demo bug report\n");
+        for (label, value) in self.rows() {
+            out.push_str(&format!("{}: {}\n", label, value));
+        }
+        out.push_str(&format!("Wasm bundle: {}\n", wasm_bytes.map(format_bytes).unwrap_or_else(|| "unknown".into())));
+        let missing = caps.missing();
+        out.push_str(&format!("Missing browser APIs: {}\n", if missing.is_empty() { "none".into() } else { missing.join(", ") }));
+        match &diagnostics.pyodide {
+            None => out.push_str("Pyodide probe: not run\n"),
+            Some(Err(e)) => out.push_str(&format!("Pyodide probe: failed ({})\n", e)),
+            Some(Ok(probe)) => {
+                out.push_str(&format!("Pyodide: {} · Python {}\n", probe.version, probe.python));
+                for (name, observed, _) in probe.capabilities() {
+                    out.push_str(&format!("  {}: {}\n", name, observed));
+                }
+            }
+        }
+        for e in &diagnostics.errors {
+            out.push_str(&format!("Caught {} in {}: {}\n", e.kind, e.tab, e.message));
+        }
+        out
+    }
+}
+
+/// unix seconds as "YYYY-MM-DD HH:MM UTC" (no chrono in a wasm bundle for one line)
+pub fn utc_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let (hour, minute) = (secs % 86_400 / 3_600, secs % 3_600 / 60);
+    // civil-from-days (howard hinnant)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02} {:02}:{:02} UTC", year, month, day, hour, minute)
+}
+
+/// byte count in KiB or MiB
+pub fn format_bytes(bytes: f64) -> String {
+    if bytes >= 1_048_576.0 {
+        format!("{:.2} MiB", bytes / 1_048_576.0)
+    } else {
+        format!("{:.0} KiB", bytes / 1024.0)
+    }
+}
+
+/// resource timing entries as (url, decoded bytes, falling back to transferred)
+fn resource_sizes() -> Vec<(String, Option<f64>)> {
+    let Some(performance) = web_sys::window().and_then(|w| w.performance()) else {
+        return Vec::new();
+    };
+    performance.get_entries_by_type("resource").iter().filter_map(|entry| {
+        let name = js_sys::Reflect::get(&entry, &"name".into()).ok()?.as_string()?;
+        let read = |field: &str| js_sys::Reflect::get(&entry, &JsValue::from_str(field)).ok()?.as_f64().filter(|b| *b > 0.0);
+        Some((name, read("decodedBodySize").or_else(|| read("transferSize"))))
+    }).collect()
+}
+
+/// url of the app's own wasm binary, as the browser fetched it
+pub fn wasm_binary_url() -> Option<String> {
+    resource_sizes().into_iter().map(|(name, _)| name).find(|name| name.ends_with("_bg.wasm"))
+}
+
+/// size of the app's wasm binary from the resource timing entry (decoded, then transferred)
+pub fn wasm_binary_bytes() -> Option<f64> {
+    resource_sizes().into_iter().find(|(name, _)| name.ends_with("_bg.wasm")).and_then(|(_, bytes)| bytes)
+}
+
+/// everything pyodide pulled in (loader, runtime wasm, stdlib, lock file); None until it has loaded
+pub fn pyodide_download_bytes() -> Option<f64> {
+    let total: f64 = resource_sizes().into_iter().filter(|(name, _)| name.contains("/pyodide/")).filter_map(|(_, bytes)| bytes).sum();
+    (total > 0.0).then_some(total)
+}
+
@@ -0,0 +1,73 @@
+// what: per-tab error boundary (panic hook + leptos ErrorBoundary) with a reload-tab card
+// why: a panic in one component used to blank the whole app; failures now stay inside the tab that raised them
+// relations: install_panic_hook called from lib.rs main, TabBoundary wraps each tab in lib.rs, errors land in mod.rs Diagnostics
+
+use std::cell::RefCell;
+use leptos::*;
+use super::{use_diagnostics, Diagnostics, ErrorRecord};
+
+thread_local! {
+    /// tab currently rendered (a panic is attributed to it)
+    static CURRENT_TAB: RefCell<&'static str> = const { RefCell::new("") };
+    /// where panics are reported once the trap has unwound
+    static SINK: RefCell<Option<RwSignal<Diagnostics>>> = const { RefCell::new(None) };
+}
+
+/// chains console_error_panic_hook and reports the panic to the diagnostics panel
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        console_error_panic_hook::hook(info);
+        let record = ErrorRecord { tab: CURRENT_TAB.with(|t| *t.borrow()), kind: "panic", message: info.to_string() };
+        // the wasm call that panicked is trapping; touch the reactive graph from a fresh task instead
+        set_timeout(move || {
+            if let Some(sink) = SINK.with(|s| *s.borrow()) {
+                sink.update(|d| d.record(record));
+            }
+        }, std::time::Duration::ZERO);
+    }));
+}
+
+/// friendly card shown in place of a failed tab
+#[component]
+fn ErrorCard(tab: &'static str, message: String, on_reload: Callback<()>) -> impl IntoView {
+    view! {
+        <div class="error-card">
+            <h3>{format!("⚠️ {} hit an error", tab)}</h3>
+            <p class="section-desc">"The rest of the console is unaffected. Details are in Diagnostics below."</p>
+            <pre class="error-card-message">{message}</pre>
+            <button class="action-btn" on:click=move |_| on_reload.call(())>"🔄 Reload tab"</button>
+        </div>
+    }
+}
+
+/// isolates one tab: recoverable errors via ErrorBoundary, panics via the hook
+#[component]
+pub fn TabBoundary(name: &'static str, children: ChildrenFn) -> impl IntoView {
+    let diagnostics = use_diagnostics();
+    SINK.with(|s| *s.borrow_mut() = Some(diagnostics));
+    // bumping the generation rebuilds the tab from scratch
+    let generation = create_rw_signal(0u32);
+    let reload = Callback::new(move |_| {
+        diagnostics.update(|d| d.crashed = None);
+        generation.update(|g| *g += 1);
+    });
+    let crash = create_memo(move |_| diagnostics.with(|d| d.crashed.as_ref().filter(|(tab, _)| *tab == name).map(|(_, m)| m.clone())));
+
+    move || {
+        generation.track();
+        if let Some(message) = crash.get() {
+            return view! { <ErrorCard tab=name message=message on_reload=reload /> }.into_view();
+        }
+        CURRENT_TAB.with(|t| *t.borrow_mut() = name);
+        let children = children.clone();
+        view! {
+            <ErrorBoundary fallback=move |errors| {
+                let message = errors.with(|e| e.iter().map(|(_, err)| err.to_string()).collect::<Vec<_>>().join("\n"));
+                diagnostics.update(|d| d.record(ErrorRecord { tab: name, kind: "error", message: message.clone() }));
+                view! { <ErrorCard tab=name message=message on_reload=reload /> }
+            }>
+                {children()}
+            </ErrorBoundary>
+        }.into_view()
+    }
+}
@@ -0,0 +1,65 @@
+// what: browser api detection done once at startup
+// why: benchmarks, the measurement worker and the heap readout need apis some browsers lack; features check this instead of failing silently
+// relations: provided as context by lib.rs, read by measure/banner.rs, proof/jitter.rs and panel.rs
+
+use leptos::*;
+use wasm_bindgen::JsValue;
+
+/// which optional browser apis this page can use
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BrowserCaps {
+    /// WebAssembly.instantiateStreaming
+    pub wasm_streaming: bool,
+    /// SharedArrayBuffer (needs cross-origin isolation)
+    pub shared_array_buffer: bool,
+    /// performance.memory (chromium only)
+    pub performance_memory: bool,
+    /// Worker constructor
+    pub workers: bool,
+}
+
+impl BrowserCaps {
+    /// probe the global object for each api
+    pub fn detect() -> Self {
+        let global: JsValue = js_sys::global().into();
+        let has = |target: &JsValue, name: &str| {
+            js_sys::Reflect::get(target, &name.into()).map(|v| !v.is_undefined()).unwrap_or(false)
+        };
+        let wasm = js_sys::Reflect::get(&global, &"WebAssembly".into()).unwrap_or(JsValue::UNDEFINED);
+        let performance = js_sys::Reflect::get(&global, &"performance".into()).unwrap_or(JsValue::UNDEFINED);
+        Self {
+            wasm_streaming: !wasm.is_undefined() && has(&wasm, "instantiateStreaming"),
+            shared_array_buffer: has(&global, "SharedArrayBuffer"),
+            performance_memory: !performance.is_undefined() && has(&performance, "memory"),
+            workers: has(&global, "Worker"),
+        }
+    }
+
+    /// (api, supported, what depends on it) for the diagnostics panel
+    pub fn rows(&self) -> [(&'static str, bool, &'static str); 4] {
+        [
+            ("WebAssembly streaming", self.wasm_streaming, "app loader (falls back to buffered compile)"),
+            ("SharedArrayBuffer", self.shared_array_buffer, "µs timers (cross-origin isolation)"),
+            ("performance.memory", self.performance_memory, "JS heap readout"),
+            ("Web Workers", self.workers, "off-thread benchmarks, jitter benchmark"),
+        ]
+    }
+
+    /// names of the apis this browser lacks
+    pub fn missing(&self) -> Vec<&'static str> {
+        self.rows().iter().filter(|(_, ok, _)| !ok).map(|(name, _, _)| *name).collect()
+    }
+}
+
+/// shared caps (detected on the spot outside the app)
+pub fn use_browser_caps() -> BrowserCaps {
+    use_context::<BrowserCaps>().unwrap_or_else(BrowserCaps::detect)
+}
+
+/// js heap (used, limit) in MiB from performance.memory
+pub fn js_heap_mib() -> Option<(f64, f64)> {
+    let performance: JsValue = web_sys::window()?.performance()?.into();
+    let memory = js_sys::Reflect::get(&performance, &"memory".into()).ok().filter(|m| !m.is_undefined())?;
+    let read = |name: &str| js_sys::Reflect::get(&memory, &name.into()).ok().and_then(|v| v.as_f64());
+    Some((read("usedJSHeapSize")? / 1_048_576.0, read("jsHeapSizeLimit")? / 1_048_576.0))
+}
@@ -0,0 +1,106 @@
+// what: viewer feedback turned into a prefilled github "new issue" url (category, message, optional diagnostics report)
+// why: a confusing paragraph or a broken measurement on someone's browser is only reported if reporting takes one click
+// relations: report from about.rs BuildInfo::bug_report, form in feedback_button.rs next to the settings gear in lib.rs
+
+/// issues page the form prefills
+pub const ISSUE_URL: &str = "https://github.com/gammahazard/Guardian-one-web-demo/issues/new";
+/// browsers and github reject much longer urls; the attached report is cut to fit
+pub const MAX_URL_LEN: usize = 8000;
+/// longest title taken from the message
+const MAX_TITLE_CHARS: usize = 60;
+
+/// what the viewer is reporting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedbackCategory {
+    Narrative,
+    Measurement,
+    Bug,
+    Idea,
+}
+
+impl FeedbackCategory {
+    pub const ALL: [FeedbackCategory; 4] = [FeedbackCategory::Narrative, FeedbackCategory::Measurement, FeedbackCategory::Bug, FeedbackCategory::Idea];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeedbackCategory::Narrative => "Confusing explanation",
+            FeedbackCategory::Measurement => "Measurement looks wrong",
+            FeedbackCategory::Bug => "Something broke",
+            FeedbackCategory::Idea => "Idea",
+        }
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            FeedbackCategory::Narrative => "narrative",
+            FeedbackCategory::Measurement => "measurement",
+            FeedbackCategory::Bug => "bug",
+            FeedbackCategory::Idea => "idea",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<FeedbackCategory> {
+        FeedbackCategory::ALL.into_iter().find(|c| c.id() == id)
+    }
+
+    /// repository label (only applied when the reporter can triage)
+    fn issue_label(&self) -> &'static str {
+        match self {
+            FeedbackCategory::Idea => "enhancement",
+            FeedbackCategory::Bug => "bug",
+            other => other.id(),
+        }
+    }
+}
+
+/// percent-encodes everything but rfc 3986 unreserved characters
+pub fn encode_component(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// "[Measurement] first line of the message", cut at a char boundary
+pub fn issue_title(category: FeedbackCategory, message: &str) -> String {
+    let first = message.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or(category.label());
+    let mut title: String = first.chars().take(MAX_TITLE_CHARS).collect();
+    if first.chars().count() > MAX_TITLE_CHARS {
+        title.push('…');
+    }
+    format!("[{}] {}", category.label(), title)
+}
+
+/// markdown issue body; the report goes in a collapsed block
+pub fn issue_body(category: FeedbackCategory, message: &str, report: Option<&str>) -> String {
+    let mut body = format!("### Category\n{}\n\n### What happened\n{}\n", category.label(), message.trim());
+    if let Some(report) = report {
+        body.push_str(&format!("\n<details><summary>Diagnostics</summary>\n\n```\n{}\n```\n</details>\n", report.trim_end()));
+    }
+    body
+}
+
+/// the prefilled url; the report is shortened line by line until the url fits MAX_URL_LEN
+pub fn issue_url(category: FeedbackCategory, message: &str, report: Option<&str>) -> String {
+    let url = |report: Option<&str>| {
+        format!(
+            "{}?title={}&labels={}&body={}",
+            ISSUE_URL,
+            encode_component(&issue_title(category, message)),
+            encode_component(category.issue_label()),
+            encode_component(&issue_body(category, message, report)),
+        )
+    };
+    let Some(report) = report else { return url(None) };
+    let mut lines: Vec<&str> = report.lines().collect();
+    loop {
+        let kept = if lines.len() < report.lines().count() { format!("{}\n(truncated)", lines.join("\n")) } else { lines.join("\n") };
+        let full = url(Some(&kept));
+        if full.len() <= MAX_URL_LEN || lines.is_empty() {
+            return if full.len() <= MAX_URL_LEN { full } else { url(None) };
+        }
+        lines.pop();
+    }
+}
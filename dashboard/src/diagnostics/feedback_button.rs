@@ -0,0 +1,96 @@
+// what: header feedback button and form - category, message, optional diagnostics summary, opens a prefilled github issue
+// why: viewers who hit a confusing explanation or an odd number on their browser can report it without writing the context by hand
+// relations: url built by feedback.rs, report from about.rs (plus the user agent), closes in kiosk attract mode like settings/panel.rs
+
+use leptos::*;
+use crate::kiosk::use_kiosk;
+use crate::tabs::demo::wasm::copy_to_clipboard;
+use super::about::{wasm_binary_bytes, BuildInfo};
+use super::caps::use_browser_caps;
+use super::feedback::{issue_url, FeedbackCategory};
+use super::use_diagnostics;
+
+/// longest message the form accepts (the url has to stay under feedback.rs MAX_URL_LEN)
+const MAX_MESSAGE_CHARS: usize = 2000;
+
+#[component]
+pub fn FeedbackButton() -> impl IntoView {
+    let kiosk = use_kiosk();
+    let diagnostics = use_diagnostics();
+    let caps = use_browser_caps();
+    let (open, set_open) = create_signal(false);
+    create_effect(move |_| if kiosk.attract.get() { set_open.set(false) });
+    let (category, set_category) = create_signal(FeedbackCategory::Narrative);
+    let (message, set_message) = create_signal(String::new());
+    let (attach, set_attach) = create_signal(true);
+    let (status, set_status) = create_signal(Option::<String>::None);
+
+    // built when the form opens so the preview matches what is sent
+    let report = move || {
+        let browser = web_sys::window().and_then(|w| w.navigator().user_agent().ok()).unwrap_or_else(|| "unknown".to_string());
+        let report = diagnostics.with(|d| BuildInfo::current().bug_report(&caps, wasm_binary_bytes(), d));
+        format!("{}Browser: {}\n", report, browser)
+    };
+    let url = move || issue_url(category.get(), &message.get(), attach.get().then(report).as_deref());
+    let submit = move |_| {
+        if let Some(window) = web_sys::window() {
+            match window.open_with_url_and_target(&url(), "_blank") {
+                Ok(Some(_)) => set_status.set(Some("✅ Opened a prefilled issue on GitHub — review it and press Submit there".to_string())),
+                _ => set_status.set(Some("⚠ Popup blocked — use Copy Link instead".to_string())),
+            }
+        }
+    };
+
+    view! {
+        <button class="settings-btn feedback-btn" title="Send feedback" on:click=move |_| {
+            set_status.set(None);
+            set_open.set(true);
+        }>"💬"</button>
+        <Show when=move || open.get()>
+            <div class="modal-overlay" on:click=move |_| set_open.set(false)>
+                <div class="modal-content settings-modal" on:click=|e: web_sys::MouseEvent| e.stop_propagation()>
+                    <div class="modal-header">
+                        <span class="modal-title">"💬 Feedback"</span>
+                        <button class="modal-close" on:click=move |_| set_open.set(false)>"×"</button>
+                    </div>
+                    <fieldset class="settings-group">
+                        <legend>"What's up?"</legend>
+                        <label>"Category"
+                            <select class="network-select" on:change=move |ev| if let Some(c) = FeedbackCategory::from_id(&event_target_value(&ev)) { set_category.set(c) }>
+                                {FeedbackCategory::ALL.into_iter().map(|c| view! {
+                                    <option value=c.id() selected=move || category.get() == c>{c.label()}</option>
+                                }).collect_view()}
+                            </select>
+                        </label>
+                        <textarea
+                            class="policy-editor feedback-message"
+                            placeholder="Which tab, what you expected, what you saw"
+                            maxlength=MAX_MESSAGE_CHARS
+                            prop:value=move || message.get()
+                            on:input=move |ev| set_message.set(event_target_value(&ev))
+                        />
+                        <label class="settings-check">
+                            <input type="checkbox" prop:checked=move || attach.get() on:change=move |ev| set_attach.set(event_target_checked(&ev)) />
+                            "Attach diagnostics summary (build, browser, missing APIs, Pyodide probe, caught errors)"
+                        </label>
+                        <Show when=move || attach.get()>
+                            <details>
+                                <summary class="section-desc">"What gets attached"</summary>
+                                <pre class="feedback-report">{report}</pre>
+                            </details>
+                        </Show>
+                        <p class="section-desc">"Nothing is sent from this page: GitHub opens with the issue filled in, and you submit it there."</p>
+                        <div class="audit-controls">
+                            <button class="action-btn" disabled=move || message.with(|m| m.trim().is_empty()) on:click=submit>"🐙 Open GitHub Issue"</button>
+                            <button class="action-btn" disabled=move || message.with(|m| m.trim().is_empty()) on:click=move |_| {
+                                copy_to_clipboard(&url());
+                                set_status.set(Some("📋 Issue link copied".to_string()));
+                            }>"📋 Copy Link"</button>
+                        </div>
+                        {move || status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+                    </fieldset>
+                </div>
+            </div>
+        </Show>
+    }
+}
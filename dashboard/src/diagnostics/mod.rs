@@ -0,0 +1,71 @@
+// what: runtime diagnostics (build info, browser caps, pyodide capability probe, tab errors) shared across tabs
+// why: the attack narratives assume how pyodide emulates sockets, os and the filesystem; this checks it at startup
+// relations: provided as context by lib.rs, filled by demo/component.rs and boundary.rs, read by demo/handlers.rs, shown by panel.rs;
+//            feedback.rs + feedback_button.rs turn viewer feedback and the bug report into a prefilled github issue;
+//            startup.rs marks boot phases for tabs/proof/startup_waterfall.rs
+
+use leptos::*;
+
+pub mod about;
+mod boundary;
+pub mod caps;
+pub mod feedback;
+mod feedback_button;
+mod panel;
+pub mod probe;
+pub mod startup;
+
+#[cfg(test)]
+mod tests;
+
+pub use boundary::{install_panic_hook, TabBoundary};
+pub use caps::{use_browser_caps, BrowserCaps};
+pub use feedback_button::FeedbackButton;
+pub use panel::{DiagnosticsPanel, UnsupportedNotice};
+pub use probe::PyodideProbe;
+
+/// errors kept for the panel (oldest dropped first)
+pub const MAX_ERRORS: usize = 20;
+
+/// a failure caught by a tab boundary
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorRecord {
+    pub tab: &'static str,
+    /// "panic" or "error"
+    pub kind: &'static str,
+    pub message: String,
+}
+
+/// everything the diagnostics panel shows
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Diagnostics {
+    /// startup probe result (None until pyodide has loaded)
+    pub pyodide: Option<Result<PyodideProbe, String>>,
+    /// caught tab errors, newest last
+    pub errors: Vec<ErrorRecord>,
+    /// tab showing a crash card after a panic: (tab, message)
+    pub crashed: Option<(&'static str, String)>,
+}
+
+impl Diagnostics {
+    /// keep an error for the panel; a panic also takes its tab down to the error card
+    pub fn record(&mut self, record: ErrorRecord) {
+        if record.kind == "panic" {
+            self.crashed = Some((record.tab, record.message.clone()));
+        }
+        self.errors.push(record);
+        if self.errors.len() > MAX_ERRORS {
+            self.errors.remove(0);
+        }
+    }
+
+    /// the probe, if it ran and parsed
+    pub fn probe(&self) -> Option<&PyodideProbe> {
+        self.pyodide.as_ref().and_then(|p| p.as_ref().ok())
+    }
+}
+
+/// shared diagnostics signal (a fresh one outside the app, e.g. in tests)
+pub fn use_diagnostics() -> RwSignal<Diagnostics> {
+    use_context::<RwSignal<Diagnostics>>().unwrap_or_else(|| create_rw_signal(Diagnostics::default()))
+}
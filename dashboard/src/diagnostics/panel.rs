@@ -0,0 +1,121 @@
+// what: collapsible diagnostics and about panel below every tab
+// why: shows the exact build, which pyodide is loaded and whether each attack's python narrative holds on it
+// relations: reads the mod.rs Diagnostics context, expectations from probe.rs, attack list from tabs/demo/attacks.rs
+
+use leptos::*;
+use crate::tabs::demo::attacks::{get_attack_config, SECURITY_ATTACKS};
+use crate::tabs::demo::wasm::copy_to_clipboard;
+use super::about::{format_bytes, wasm_binary_bytes, BuildInfo};
+use super::caps::{js_heap_mib, use_browser_caps};
+use super::probe::{Expectation, PyodideProbe, PINNED_PYODIDE};
+use super::use_diagnostics;
+
+/// inline notice shown in place of a feature the browser cannot run
+#[component]
+pub fn UnsupportedNotice(feature: &'static str, api: &'static str) -> impl IntoView {
+    view! { <p class="unsupported-notice">{format!("⚠ {} unsupported on this browser ({} missing)", feature, api)}</p> }
+}
+
+#[component]
+pub fn DiagnosticsPanel() -> impl IntoView {
+    let diagnostics = use_diagnostics();
+    let caps = use_browser_caps();
+    let browser_rows = caps.rows().into_iter().map(|(api, ok, used_by)| view! {
+        <tr>
+            <td>{api}</td>
+            <td class=if ok { "granted" } else { "diagnostics-warn" }>{if ok { "✓ supported" } else { "⚠ unsupported" }}</td>
+            <td>{used_by}</td>
+        </tr>
+    }).collect_view();
+    let build = BuildInfo::current();
+    let wasm_bytes = wasm_binary_bytes();
+    let about_rows = build.rows().into_iter()
+        .chain([("Wasm bundle", wasm_bytes.map(format_bytes).unwrap_or_else(|| "unknown (no resource timing)".into()))])
+        .map(|(label, value)| view! { <tr><td>{label}</td><td>{value}</td></tr> })
+        .collect_view();
+    let copy_report = move |_| copy_to_clipboard(&diagnostics.with(|d| build.bug_report(&caps, wasm_bytes, d)));
+    view! {
+        <details class="diagnostics-panel">
+            <summary>"🩺 Diagnostics"{match caps.missing().len() {
+                0 => String::new(),
+                n => format!(" · ⚠ {} browser API{} missing", n, if n == 1 { "" } else { "s" }),
+            }}</summary>
+            <div class="diagnostics-about">
+                <table class="capability-matrix">
+                    <tr><th>"About this build"</th><th></th></tr>
+                    {about_rows}
+                </table>
+                <button class="action-btn" on:click=copy_report>"📋 Copy bug report"</button>
+            </div>
+            <table class="capability-matrix">
+                <tr><th>"Browser API"</th><th>"Status"</th><th>"Used by"</th></tr>
+                {browser_rows}
+            </table>
+            {if caps.performance_memory {
+                let heap = js_heap_mib().map(|(used, limit)| format!("JS heap: {:.1} MiB used of {:.0} MiB", used, limit));
+                view! { <p class="diagnostics-version">{heap.unwrap_or_default()}</p> }.into_view()
+            } else {
+                view! { <UnsupportedNotice feature="JS heap readout" api="performance.memory" /> }.into_view()
+            }}
+            <Show when=move || diagnostics.with(|d| !d.errors.is_empty())>
+                <table class="capability-matrix">
+                    <tr><th>"Tab"</th><th>"Caught"</th><th>"Message"</th></tr>
+                    {move || diagnostics.with(|d| d.errors.iter().rev().map(|e| view! {
+                        <tr>
+                            <td>{e.tab}</td>
+                            <td class="diagnostics-warn">{e.kind}</td>
+                            <td class="error-card-message">{e.message.clone()}</td>
+                        </tr>
+                    }).collect_view())}
+                </table>
+            </Show>
+            {move || match diagnostics.with(|d| d.pyodide.clone()) {
+                None => view! { <p class="section-desc">"Pyodide probe runs after the Demo tab loads Pyodide."</p> }.into_view(),
+                Some(Err(e)) => view! { <p class="diagnostics-warn">{format!("⚠ Pyodide probe failed: {}", e)}</p> }.into_view(),
+                Some(Ok(probe)) => view! { <ProbeReport probe=probe /> }.into_view(),
+            }}
+        </details>
+    }
+}
+
+/// version line, capability table and per-attack expectations
+#[component]
+fn ProbeReport(probe: PyodideProbe) -> impl IntoView {
+    let version = if probe.pinned() {
+        format!("Pyodide {} (pinned) · Python {}", probe.version, probe.python)
+    } else {
+        format!("⚠ Pyodide {} loaded, {} pinned · Python {}", probe.version, PINNED_PYODIDE, probe.python)
+    };
+    let capabilities = probe.capabilities().into_iter().map(|(name, observed, expected)| view! {
+        <tr>
+            <td>{name}</td>
+            <td class=if expected { "granted" } else { "diagnostics-warn" }>{observed}</td>
+        </tr>
+    }).collect_view();
+    let expectations = SECURITY_ATTACKS.iter().map(|attack| {
+        let (class, text) = match probe.expectation(attack) {
+            Expectation::Holds(note) => ("granted", format!("✓ {}", note)),
+            Expectation::Warn(note) => ("diagnostics-warn", format!("⚠ {}", note)),
+        };
+        view! {
+            <tr>
+                <td>{get_attack_config(attack).name}</td>
+                <td class=class>{text}</td>
+            </tr>
+        }
+    }).collect_view();
+
+    view! {
+        <p class=if probe.pinned() { "diagnostics-version" } else { "diagnostics-version diagnostics-warn" }>{version}</p>
+        <div class="diagnostics-grid">
+            <table class="capability-matrix">
+                <tr><th>"Capability"</th><th>"Observed"</th></tr>
+                {capabilities}
+            </table>
+            <table class="capability-matrix">
+                <tr><th>"Attack"</th><th>"Python expectation"</th></tr>
+                {expectations}
+            </table>
+        </div>
+    }
+}
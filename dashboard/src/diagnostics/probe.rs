@@ -0,0 +1,129 @@
+// what: startup capability probe for the loaded pyodide build
+// why: pyodide's emulation of sockets/os/fs can change between releases and silently flip attack outcomes
+// relations: PROBE_PY runs via demo/wasm.rs runPython, expectations read by demo/handlers.rs and panel.rs
+
+use serde::Deserialize;
+
+/// pyodide release index.html loads (keep in sync with PYODIDE_SCRIPT)
+pub const PINNED_PYODIDE: &str = "0.24.1";
+
+/// python run once after pyodide loads; mirrors the operations the attacks rely on
+pub const PROBE_PY: &str = r#"
+import json
+import os
+import sys
+import pyodide
+
+probe = {"version": pyodide.__version__, "python": sys.version.split()[0]}
+
+try:
+    import socket
+    probe["socket_module"] = True
+    try:
+        socket.gethostbyname("example.com")
+        probe["dns"] = "resolved"
+    except Exception as e:
+        probe["dns"] = f"blocked:{type(e).__name__}"
+    try:
+        sock = socket.socket(socket.AF_INET, socket.SOCK_STREAM)
+        sock.settimeout(1.0)
+        sock.connect(("203.0.113.66", 443))
+        probe["socket_connect"] = "connected"
+    except Exception as e:
+        probe["socket_connect"] = f"blocked:{type(e).__name__}"
+except ImportError:
+    probe["socket_module"] = False
+    probe["dns"] = probe["socket_connect"] = "blocked:ImportError"
+
+probe["os_module"] = hasattr(os, "listdir")
+try:
+    os.environ["GUARDIAN_PROBE"] = "1"
+    probe["environ_writable"] = os.environ.pop("GUARDIAN_PROBE") == "1"
+except Exception:
+    probe["environ_writable"] = False
+probe["etc_passwd"] = os.path.exists("/etc/passwd")
+probe["recursion_limit"] = sys.getrecursionlimit()
+
+try:
+    import js
+    probe["js_fetch"] = hasattr(js, "fetch")
+except ImportError:
+    probe["js_fetch"] = False
+
+json.dumps(probe)
+"#;
+
+/// what the loaded pyodide build can actually do
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct PyodideProbe {
+    pub version: String,
+    pub python: String,
+    pub socket_module: bool,
+    /// "resolved" or "blocked:<Exception>"
+    pub dns: String,
+    /// "connected" or "blocked:<Exception>"
+    pub socket_connect: String,
+    pub os_module: bool,
+    pub environ_writable: bool,
+    pub etc_passwd: bool,
+    pub recursion_limit: u32,
+    pub js_fetch: bool,
+}
+
+/// whether an attack's python narrative holds on this build
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expectation {
+    Holds(&'static str),
+    Warn(String),
+}
+
+impl PyodideProbe {
+    /// parse the json string PROBE_PY returns
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        serde_json::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// the loaded build is the pinned one
+    pub fn pinned(&self) -> bool {
+        self.version == PINNED_PYODIDE
+    }
+
+    /// capability rows for the panel: (name, observed, as the attacks expect)
+    pub fn capabilities(&self) -> Vec<(&'static str, String, bool)> {
+        vec![
+            ("socket module", yes_no(self.socket_module), true),
+            ("dns lookup", self.dns.clone(), self.dns.starts_with("blocked")),
+            ("socket.connect", self.socket_connect.clone(), self.socket_connect.starts_with("blocked")),
+            ("os module", yes_no(self.os_module), self.os_module),
+            ("os.environ writable", yes_no(self.environ_writable), self.environ_writable),
+            ("/etc/passwd exists", yes_no(self.etc_passwd), !self.etc_passwd),
+            ("recursion limit", self.recursion_limit.to_string(), self.recursion_limit > 0),
+            ("js.fetch", yes_no(self.js_fetch), self.js_fetch),
+        ]
+    }
+
+    /// the assumption a security attack makes about python, checked against this probe
+    pub fn expectation(&self, attack: &str) -> Expectation {
+        let check = |holds: bool, ok: &'static str, warn: &str| if holds { Expectation::Holds(ok) } else { Expectation::Warn(warn.to_string()) };
+        match attack {
+            "dataExfil" => check(
+                self.socket_connect.starts_with("blocked"),
+                "socket.connect raises in pyodide",
+                &format!("socket.connect {} on this build - exfil may not raise", self.socket_connect),
+            ),
+            "pathTraversal" => check(!self.etc_passwd, "/etc/passwd absent from pyodide's fs", "/etc/passwd exists in pyodide's fs - traversal may read it"),
+            "envTheft" => check(self.environ_writable, "os.environ is writable and readable", "os.environ not writable - planted secrets will be missing"),
+            "lateralMovement" => check(self.js_fetch, "js.fetch available for real reaches", "js.fetch missing - lateral probes cannot run"),
+            "stackExhaustion" => check(
+                (1..=10_000).contains(&self.recursion_limit),
+                "RecursionError before the js stack overflows",
+                &format!("recursion limit {} - the js stack may overflow first", self.recursion_limit),
+            ),
+            _ => Expectation::Holds("pure python, no emulated capability involved"),
+        }
+    }
+}
+
+fn yes_no(v: bool) -> String {
+    if v { "yes" } else { "no" }.to_string()
+}
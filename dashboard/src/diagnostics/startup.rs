@@ -0,0 +1,128 @@
+// what: startup phases of the dashboard itself (wasm fetch, instantiate, mount, pyodide load, first measurement) as a waterfall
+// why: the demo argues about cold starts; the app's own boot is the closest cold start a viewer can inspect
+// relations: marks set by lib.rs main, index.html (pyodide) and demo/component.rs (first measurement); drawn by tabs/proof/startup_waterfall.rs
+
+use wasm_bindgen::JsValue;
+
+/// prefix of every performance mark the app sets (also visible in devtools' performance panel)
+pub const MARK_PREFIX: &str = "guardian:";
+
+/// one boot step, in the order they normally happen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StartupPhase {
+    /// navigation start until the wasm request goes out (html, css, js glue)
+    Document,
+    WasmFetch,
+    /// download done until main() runs (compile + instantiate + start)
+    Instantiate,
+    /// main() until the first render is mounted
+    Mount,
+    PyodideLoad,
+    FirstMeasurement,
+}
+
+impl StartupPhase {
+    pub const ALL: [StartupPhase; 6] = [
+        StartupPhase::Document,
+        StartupPhase::WasmFetch,
+        StartupPhase::Instantiate,
+        StartupPhase::Mount,
+        StartupPhase::PyodideLoad,
+        StartupPhase::FirstMeasurement,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StartupPhase::Document => "Document + JS glue",
+            StartupPhase::WasmFetch => "Wasm fetch",
+            StartupPhase::Instantiate => "Compile + instantiate",
+            StartupPhase::Mount => "Mount (first render)",
+            StartupPhase::PyodideLoad => "Pyodide load",
+            StartupPhase::FirstMeasurement => "First measurement",
+        }
+    }
+
+    /// marks (without prefix) that bound a phase; None for phases read from resource timing
+    fn marks(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            StartupPhase::Mount => Some(("main", "mounted")),
+            StartupPhase::PyodideLoad => Some(("pyodide-start", "pyodide-end")),
+            StartupPhase::FirstMeasurement => Some(("measure-start", "measure-end")),
+            _ => None,
+        }
+    }
+}
+
+/// a phase that has happened, times in ms since navigation start
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhaseSpan {
+    pub phase: StartupPhase,
+    pub start_ms: f64,
+    pub end_ms: f64,
+}
+
+/// one waterfall bar, offset and width as a percentage of the whole boot
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WaterfallRow {
+    pub span: PhaseSpan,
+    pub offset_pct: f64,
+    pub width_pct: f64,
+}
+
+/// spans from the app's marks (first occurrence wins) and the wasm resource entry (request start, response end)
+pub fn spans_from(marks: &[(String, f64)], wasm: Option<(f64, f64)>) -> Vec<PhaseSpan> {
+    let at = |name: &str| marks.iter().find(|(n, _)| n == name).map(|(_, t)| *t);
+    StartupPhase::ALL.into_iter().filter_map(|phase| {
+        let (start_ms, end_ms) = match phase {
+            StartupPhase::Document => (0.0, wasm?.0),
+            StartupPhase::WasmFetch => wasm?,
+            StartupPhase::Instantiate => (wasm?.1, at("main")?),
+            _ => {
+                let (start, end) = phase.marks()?;
+                (at(start)?, at(end)?)
+            }
+        };
+        (end_ms >= start_ms).then_some(PhaseSpan { phase, start_ms, end_ms })
+    }).collect()
+}
+
+/// lays the spans out against the latest end
+pub fn waterfall(spans: &[PhaseSpan]) -> Vec<WaterfallRow> {
+    let total = spans.iter().map(|s| s.end_ms).fold(0.0, f64::max);
+    if total <= 0.0 {
+        return Vec::new();
+    }
+    spans.iter().map(|span| WaterfallRow {
+        span: *span,
+        offset_pct: span.start_ms / total * 100.0,
+        width_pct: (span.end_ms - span.start_ms) / total * 100.0,
+    }).collect()
+}
+
+// ============================================================================
+// browser glue (performance marks + resource timing)
+// ============================================================================
+
+/// sets `guardian:<name>` unless it is already set, so re-renders keep the first time
+pub fn mark(name: &str) {
+    let Some(performance) = web_sys::window().and_then(|w| w.performance()) else { return };
+    let full = format!("{}{}", MARK_PREFIX, name);
+    if performance.get_entries_by_name(&full).length() == 0 {
+        let _ = performance.mark(&full);
+    }
+}
+
+/// the app's marks and the wasm resource entry, turned into spans
+pub fn collect() -> Vec<PhaseSpan> {
+    let Some(performance) = web_sys::window().and_then(|w| w.performance()) else { return Vec::new() };
+    let number = |entry: &JsValue, field: &str| js_sys::Reflect::get(entry, &JsValue::from_str(field)).ok().and_then(|v| v.as_f64());
+    let marks: Vec<(String, f64)> = performance.get_entries_by_type("mark").iter().filter_map(|entry| {
+        let name = js_sys::Reflect::get(&entry, &"name".into()).ok()?.as_string()?;
+        Some((name.strip_prefix(MARK_PREFIX)?.to_string(), number(&entry, "startTime")?))
+    }).collect();
+    let wasm = performance.get_entries_by_type("resource").iter().find_map(|entry| {
+        let name = js_sys::Reflect::get(&entry, &"name".into()).ok()?.as_string()?;
+        name.ends_with("_bg.wasm").then(|| Some((number(&entry, "startTime")?, number(&entry, "responseEnd")?)))?
+    });
+    spans_from(&marks, wasm)
+}
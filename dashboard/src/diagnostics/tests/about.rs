@@ -0,0 +1,41 @@
+// what: tests for build info formatting and the copyable bug report
+// why: a report with a wrong date or missing probe results sends triage down the wrong path
+
+use crate::diagnostics::about::{format_bytes, utc_timestamp, BuildInfo};
+use crate::diagnostics::{BrowserCaps, Diagnostics, ErrorRecord};
+
+#[test]
+fn timestamps_convert_to_utc_dates() {
+    // what: epoch, a leap day and a year boundary format correctly
+    // why: the date math is hand-rolled to keep chrono out of the bundle
+    assert_eq!(utc_timestamp(0), "1970-01-01 00:00 UTC");
+    assert_eq!(utc_timestamp(951_827_696), "2000-02-29 12:34 UTC");
+    assert_eq!(utc_timestamp(1_735_689_599), "2024-12-31 23:59 UTC");
+}
+
+#[test]
+fn build_env_is_stamped() {
+    // what: build.rs fills every field with something other than an empty string
+    // why: an empty env var would compile but leave the about table blank
+    let build = BuildInfo::current();
+    assert!(!build.commit.is_empty() && !build.rustc.is_empty() && !build.leptos.is_empty());
+    assert!(build.leptos.starts_with("0.6"));
+    assert!(build.built_unix > 0);
+}
+
+#[test]
+fn bug_report_carries_build_browser_and_errors() {
+    // what: the report names the commit, missing apis, probe state and caught errors
+    // why: these are the fields needed to reproduce a viewer's failure
+    let build = BuildInfo::current();
+    let caps = BrowserCaps { wasm_streaming: true, workers: true, ..BrowserCaps::default() };
+    let mut diagnostics = Diagnostics::default();
+    diagnostics.record(ErrorRecord { tab: "The Demo", kind: "panic", message: "boom".into() });
+    let report = build.bug_report(&caps, Some(2_621_440.0), &diagnostics);
+    assert!(report.contains(&format!("Commit: {}", build.commit)));
+    assert!(report.contains("Wasm bundle: 2.50 MiB"));
+    assert!(report.contains("Missing browser APIs: SharedArrayBuffer, performance.memory"));
+    assert!(report.contains("Pyodide probe: not run"));
+    assert!(report.contains("Caught panic in The Demo: boom"));
+    assert_eq!(format_bytes(512_000.0), "500 KiB");
+}
@@ -0,0 +1,22 @@
+// what: tests for the browser capability summary
+// why: features gate on these flags, and the panel must name exactly what is missing
+
+use crate::diagnostics::BrowserCaps;
+
+#[test]
+fn full_support_reports_nothing_missing() {
+    // what: a browser with every api lists no gaps
+    // why: modern chromium must not show unsupported notices
+    let caps = BrowserCaps { wasm_streaming: true, shared_array_buffer: true, performance_memory: true, workers: true };
+    assert!(caps.missing().is_empty());
+    assert!(caps.rows().iter().all(|(_, ok, _)| *ok));
+}
+
+#[test]
+fn missing_apis_are_named() {
+    // what: firefox-like caps (no performance.memory, not isolated) name those two apis
+    // why: the notice tells viewers which api their browser lacks
+    let caps = BrowserCaps { wasm_streaming: true, workers: true, ..BrowserCaps::default() };
+    assert_eq!(caps.missing(), ["SharedArrayBuffer", "performance.memory"]);
+    assert_eq!(BrowserCaps::default().missing().len(), 4);
+}
@@ -0,0 +1,32 @@
+// what: tests for the error log kept by tab boundaries
+// why: a crash must land on the right tab card without letting the log grow forever
+
+use crate::diagnostics::{Diagnostics, ErrorRecord, MAX_ERRORS};
+
+fn record(tab: &'static str, kind: &'static str, n: usize) -> ErrorRecord {
+    ErrorRecord { tab, kind, message: format!("failure {}", n) }
+}
+
+#[test]
+fn panic_marks_its_tab_crashed() {
+    // what: a panic record sets the crashed tab, a recoverable error does not
+    // why: only panics need the hook path; ErrorBoundary handles errors itself
+    let mut diagnostics = Diagnostics::default();
+    diagnostics.record(record("The Demo", "error", 0));
+    assert_eq!(diagnostics.crashed, None);
+    diagnostics.record(record("The Proof", "panic", 1));
+    assert_eq!(diagnostics.crashed, Some(("The Proof", "failure 1".to_string())));
+    assert_eq!(diagnostics.errors.len(), 2);
+}
+
+#[test]
+fn error_log_is_capped_oldest_first() {
+    // what: past MAX_ERRORS the oldest records are dropped
+    // why: a render loop that keeps failing must not grow the panel without bound
+    let mut diagnostics = Diagnostics::default();
+    for n in 0..MAX_ERRORS + 5 {
+        diagnostics.record(record("The Demo", "error", n));
+    }
+    assert_eq!(diagnostics.errors.len(), MAX_ERRORS);
+    assert_eq!(diagnostics.errors[0].message, "failure 5");
+}
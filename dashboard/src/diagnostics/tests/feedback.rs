@@ -0,0 +1,45 @@
+// what: tests for the prefilled github issue built from viewer feedback
+// why: a mis-encoded url opens an empty or broken form, and an over-long one is rejected by github outright
+
+use crate::diagnostics::feedback::{encode_component, issue_body, issue_title, issue_url, FeedbackCategory, ISSUE_URL, MAX_URL_LEN};
+
+#[test]
+fn components_keep_only_unreserved_characters() {
+    // what: letters, digits and -._~ pass through; spaces, newlines, & and # and multi-byte text are percent-encoded
+    // why: an unencoded & or # would cut the body short
+    assert_eq!(encode_component("a-Z_0.9~"), "a-Z_0.9~");
+    assert_eq!(encode_component("a b&c#\n"), "a%20b%26c%23%0A");
+    assert_eq!(encode_component("°C"), "%C2%B0C");
+}
+
+#[test]
+fn title_comes_from_the_first_non_empty_line() {
+    // what: the category prefix plus the first message line, cut at 60 chars with an ellipsis; an empty message falls back to the category
+    // why: issue lists are scanned by title
+    assert_eq!(issue_title(FeedbackCategory::Measurement, "\n  WASM shows 0ms  \nmore"), "[Measurement looks wrong] WASM shows 0ms");
+    let long = issue_title(FeedbackCategory::Bug, &"x".repeat(80));
+    assert!(long.ends_with('…') && long.chars().count() == "[Something broke] ".chars().count() + 61);
+    assert_eq!(issue_title(FeedbackCategory::Idea, ""), "[Idea] Idea");
+}
+
+#[test]
+fn body_carries_category_message_and_optional_report() {
+    // what: the body has the category and message, and the report only when attached, inside a collapsed block
+    // why: the viewer chose whether to share their diagnostics
+    let with = issue_body(FeedbackCategory::Narrative, "Raft paragraph unclear", Some("Commit: abc\n"));
+    assert!(with.contains("Confusing explanation") && with.contains("Raft paragraph unclear"));
+    assert!(with.contains("<details><summary>Diagnostics</summary>") && with.contains("Commit: abc"));
+    assert!(!issue_body(FeedbackCategory::Narrative, "x", None).contains("Diagnostics"));
+}
+
+#[test]
+fn url_targets_the_repo_and_trims_the_report_to_fit() {
+    // what: the url opens the repo's new-issue page with title, label and body; a huge report is cut and marked truncated
+    // why: urls past ~8k characters are refused, and losing the whole form over a long probe dump is worse than a partial report
+    let short = issue_url(FeedbackCategory::Bug, "broken", Some("Commit: abc"));
+    assert!(short.starts_with(ISSUE_URL) && short.contains("labels=bug") && short.contains("Commit%3A%20abc"));
+    let report = (0..2000).map(|i| format!("line {}", i)).collect::<Vec<_>>().join("\n");
+    let long = issue_url(FeedbackCategory::Measurement, "numbers off", Some(&report));
+    assert!(long.len() <= MAX_URL_LEN, "{}", long.len());
+    assert!(long.contains("line%200") && long.contains("%28truncated%29") && !long.contains("line%201999"));
+}
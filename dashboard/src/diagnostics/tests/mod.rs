@@ -0,0 +1,20 @@
+// what: exports all test modules for runtime diagnostics
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod pyodide_probe;
+
+#[cfg(test)]
+mod browser_caps;
+
+#[cfg(test)]
+mod error_boundary;
+
+#[cfg(test)]
+mod about;
+
+#[cfg(test)]
+mod startup;
+
+#[cfg(test)]
+mod feedback;
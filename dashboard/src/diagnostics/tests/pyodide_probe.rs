@@ -0,0 +1,48 @@
+// what: tests for the pyodide capability probe and the attack expectations keyed off it
+// why: the demo must warn, not silently mislead, when pyodide's emulation changes
+
+use crate::diagnostics::probe::{Expectation, PyodideProbe, PINNED_PYODIDE};
+
+const PINNED_BUILD: &str = r#"{"version":"0.24.1","python":"3.11.3","socket_module":true,"dns":"blocked:gaierror","socket_connect":"blocked:OSError","os_module":true,"environ_writable":true,"etc_passwd":false,"recursion_limit":1000,"js_fetch":true}"#;
+
+#[test]
+fn probe_json_parses_and_matches_pin() {
+    // what: PROBE_PY's json decodes and the pinned build is recognised
+    // why: a version drift is the first thing a bug report needs
+    let probe = PyodideProbe::from_json(PINNED_BUILD).unwrap();
+    assert!(probe.pinned());
+    assert!(probe.capabilities().iter().all(|(_, _, expected)| *expected));
+    assert!(PyodideProbe::from_json("{}").is_err());
+}
+
+#[test]
+fn expectations_hold_on_pinned_build() {
+    // what: every security attack's python narrative holds on the pinned build
+    // why: the shipped demo must not open with warnings
+    let probe = PyodideProbe::from_json(PINNED_BUILD).unwrap();
+    for attack in crate::tabs::demo::attacks::SECURITY_ATTACKS {
+        assert!(matches!(probe.expectation(attack), Expectation::Holds(_)), "{} should hold", attack);
+    }
+}
+
+#[test]
+fn emulation_changes_raise_warnings() {
+    // what: a connecting socket, a present /etc/passwd or a new version flip the matching expectations
+    // why: the attack terminal warns instead of presenting a stale narrative
+    let mut probe = PyodideProbe::from_json(PINNED_BUILD).unwrap();
+    probe.socket_connect = "connected".into();
+    probe.etc_passwd = true;
+    probe.version = "0.27.0".into();
+    assert!(matches!(probe.expectation("dataExfil"), Expectation::Warn(w) if w.contains("connected")));
+    assert!(matches!(probe.expectation("pathTraversal"), Expectation::Warn(_)));
+    assert!(matches!(probe.expectation("bufferOverflow"), Expectation::Holds(_)));
+    assert!(!probe.pinned());
+}
+
+#[test]
+fn pin_matches_index_html() {
+    // what: PINNED_PYODIDE is the version index.html actually loads
+    // why: the pin is only meaningful if the loader and the check agree
+    let index = include_str!("../../../index.html");
+    assert!(index.contains(&format!("pyodide/v{}/full/pyodide.js", PINNED_PYODIDE)));
+}
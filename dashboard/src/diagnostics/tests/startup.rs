@@ -0,0 +1,43 @@
+// what: tests for the startup waterfall spans and layout
+// why: the phases come from marks set in three different places, so partial and out-of-order data must still lay out sanely
+
+use crate::diagnostics::startup::{spans_from, waterfall, StartupPhase};
+
+fn marks(list: &[(&str, f64)]) -> Vec<(String, f64)> {
+    list.iter().map(|(n, t)| (n.to_string(), *t)).collect()
+}
+
+#[test]
+fn full_boot_yields_every_phase_in_order() {
+    // what: wasm resource timing plus all six marks give the six phases with the expected bounds
+    // why: instantiate is the gap between download end and main(), which only exists if both are read
+    let spans = spans_from(
+        &marks(&[("main", 140.0), ("mounted", 165.0), ("measure-start", 900.0), ("measure-end", 905.0), ("pyodide-start", 910.0), ("pyodide-end", 2900.0)]),
+        Some((40.0, 120.0)),
+    );
+    let phases: Vec<StartupPhase> = spans.iter().map(|s| s.phase).collect();
+    assert_eq!(phases, StartupPhase::ALL);
+    assert_eq!((spans[0].start_ms, spans[0].end_ms), (0.0, 40.0));
+    assert_eq!((spans[2].start_ms, spans[2].end_ms), (120.0, 140.0));
+}
+
+#[test]
+fn missing_marks_drop_only_their_phase() {
+    // what: before the demo tab is visited there is no pyodide or measurement phase; without resource timing the wasm phases go too
+    // why: the panel lists these as "not yet" instead of drawing a zero-width bar
+    let spans = spans_from(&marks(&[("main", 140.0), ("mounted", 165.0), ("pyodide-start", 900.0)]), Some((40.0, 120.0)));
+    assert_eq!(spans.len(), 4);
+    let spans = spans_from(&marks(&[("main", 140.0), ("mounted", 165.0)]), None);
+    assert_eq!(spans.iter().map(|s| s.phase).collect::<Vec<_>>(), [StartupPhase::Mount]);
+}
+
+#[test]
+fn waterfall_scales_to_the_latest_end() {
+    // what: offsets and widths are percentages of the last phase end; nothing recorded lays out nothing
+    // why: a two-second pyodide load and a 20ms mount have to share one axis
+    let spans = spans_from(&marks(&[("main", 50.0), ("mounted", 100.0), ("pyodide-start", 100.0), ("pyodide-end", 200.0)]), None);
+    let rows = waterfall(&spans);
+    assert_eq!((rows[0].offset_pct, rows[0].width_pct), (25.0, 25.0));
+    assert_eq!((rows[1].offset_pct, rows[1].width_pct), (50.0, 50.0));
+    assert!(waterfall(&[]).is_empty());
+}
@@ -0,0 +1,70 @@
+// what: glossary of the dashboard (term id → display term, definition, related terms) and the hardware tab's ⓘ notes, parsed from glossary.toml
+// why: WIT, TMR, 2oo3 and friends were explained ad hoc per section; one definition per term keeps every tab saying the same thing
+// relations: include_str! of glossary.toml; rendered by term.rs (<Term> inline, <InfoTip> for the notes),
+//            used by tabs/hardware architecture.rs, components.rs, compliance.rs and toolchain.rs
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use serde::Deserialize;
+
+mod term;
+
+#[cfg(test)]
+mod tests;
+
+pub use term::{InfoTip, Term};
+
+/// the embedded glossary file
+pub const GLOSSARY_TOML: &str = include_str!("glossary.toml");
+
+/// one defined term
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Entry {
+    /// stable id used by <Term id=...>
+    pub id: String,
+    /// how the term is written when no other text is given
+    pub term: String,
+    pub definition: String,
+    /// ids of related terms, offered under the definition
+    #[serde(default)]
+    pub see_also: Vec<String>,
+}
+
+/// everything in glossary.toml
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Glossary {
+    pub terms: Vec<Entry>,
+    /// longer explanations behind ⓘ buttons, by note id
+    #[serde(default)]
+    pub notes: BTreeMap<String, String>,
+}
+
+impl Glossary {
+    /// parses and checks that ids are unique and every see_also points at a known term
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let glossary: Glossary = toml::from_str(text).map_err(|e| e.to_string())?;
+        for (i, entry) in glossary.terms.iter().enumerate() {
+            if glossary.terms[..i].iter().any(|e| e.id == entry.id) {
+                return Err(format!("duplicate term id {}", entry.id));
+            }
+            if let Some(missing) = entry.see_also.iter().find(|id| glossary.entry(id).is_none()) {
+                return Err(format!("{}: see_also names unknown term {}", entry.id, missing));
+            }
+        }
+        Ok(glossary)
+    }
+
+    pub fn entry(&self, id: &str) -> Option<&Entry> {
+        self.terms.iter().find(|e| e.id == id)
+    }
+
+    pub fn note(&self, id: &str) -> Option<&str> {
+        self.notes.get(id).map(String::as_str)
+    }
+}
+
+/// the embedded glossary, parsed on first use
+pub fn glossary() -> &'static Glossary {
+    static GLOSSARY: OnceLock<Glossary> = OnceLock::new();
+    GLOSSARY.get_or_init(|| Glossary::from_toml(GLOSSARY_TOML).expect("glossary/glossary.toml must parse"))
+}
@@ -0,0 +1,67 @@
+// what: <Term> (dotted-underline word, tap opens its definition) and <InfoTip> (ⓘ button opening a note), sharing one popup
+// why: four hardware sections each carried their own copy of the overlay/popup markup; tap instead of hover works on phones
+// relations: entries and notes from mod.rs glossary(), styled by the tooltip-* and glossary-* rules in styles.css
+
+use leptos::*;
+use super::glossary;
+
+/// centered popup over a click-to-close overlay
+fn popup(set_open: WriteSignal<bool>, body: impl IntoView) -> impl IntoView {
+    view! {
+        <div class="tooltip-overlay" on:click=move |_| set_open.set(false) />
+        <div class="tooltip-popup">
+            <div class="tooltip-content">{body}</div>
+            <button class="tooltip-close" on:click=move |_| set_open.set(false)>"✕"</button>
+        </div>
+    }
+}
+
+/// a glossary term inline in text; shows the entry's own spelling unless children are given.
+/// an unknown id renders as plain text
+#[component]
+pub fn Term(id: &'static str, #[prop(optional)] children: Option<Children>) -> impl IntoView {
+    let Some(entry) = glossary().entry(id) else {
+        return children.map(|c| c().into_view()).unwrap_or_else(|| id.into_view());
+    };
+    let label = children.map(|c| c().into_view()).unwrap_or_else(|| entry.term.clone().into_view());
+    let (open, set_open) = create_signal(false);
+    // following a see-also link swaps the definition in place
+    let (shown, set_shown) = create_signal(id);
+    let definition = move || glossary().entry(shown.get()).map(|e| view! {
+        <strong class="glossary-title">{e.term.clone()}</strong>
+        <p>{e.definition.clone()}</p>
+        {(!e.see_also.is_empty()).then(|| view! {
+            <p class="glossary-see-also">
+                "See also: "
+                {e.see_also.iter().filter_map(|other| glossary().entry(other)).map(|other| {
+                    let other_id = other.id.as_str();
+                    view! { <button class="glossary-term" on:click=move |_| set_shown.set(other_id)>{other.term.clone()}</button> }
+                }).collect_view()}
+            </p>
+        })}
+    });
+
+    view! {
+        <button class="glossary-term" title="Tap for definition" on:click=move |_| {
+            set_shown.set(id);
+            set_open.set(true);
+        }>{label}</button>
+        <Show when=move || open.get()>
+            {popup(set_open, definition)}
+        </Show>
+    }
+    .into_view()
+}
+
+/// ⓘ button opening a glossary note
+#[component]
+pub fn InfoTip(note: &'static str) -> impl IntoView {
+    let (open, set_open) = create_signal(false);
+    let text = glossary().note(note).unwrap_or_default();
+    view! {
+        <button class="info-btn" on:click=move |_| set_open.update(|v| *v = !*v)>"ⓘ"</button>
+        <Show when=move || open.get()>
+            {popup(set_open, text)}
+        </Show>
+    }
+}
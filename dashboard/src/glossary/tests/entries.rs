@@ -0,0 +1,53 @@
+// what: tests for glossary.toml parsing and its consistency checks
+// why: a <Term> or ⓘ whose id is missing renders plain or empty text at runtime; the build has to catch it instead
+
+use crate::glossary::{glossary, Glossary};
+
+/// note ids the hardware tab's ⓘ buttons ask for
+const HARDWARE_NOTES: [&str; 15] = [
+    "purdue.l0", "purdue.l1", "purdue.l2", "purdue.l3",
+    "components.l0", "components.l1", "components.l2", "components.infra", "components.visual",
+    "zone.1", "zone.2", "zone.3",
+    "toolchain.tia", "toolchain.plc", "toolchain.guardian",
+];
+
+fn entry(id: &str, see_also: &str) -> String {
+    format!("[[terms]]\nid = \"{}\"\nterm = \"{}\"\ndefinition = \"x\"\nsee_also = [{}]\n", id, id, see_also)
+}
+
+#[test]
+fn embedded_glossary_defines_the_core_terms() {
+    // what: the shipped file parses and defines every term the tabs mark up
+    // why: glossary() panics on a bad file, and a missing id silently drops the definition
+    let g = glossary();
+    for id in ["wit", "tmr", "2oo3", "purdue-level", "fail-stop", "byzantine"] {
+        let entry = g.entry(id).unwrap_or_else(|| panic!("{} missing", id));
+        assert!(!entry.definition.is_empty(), "{}", id);
+    }
+}
+
+#[test]
+fn hardware_notes_are_all_present() {
+    // what: every ⓘ note id used by the hardware sections resolves to text
+    // why: the notes moved out of the view code; a typo would show an empty popup
+    let g = glossary();
+    assert!(HARDWARE_NOTES.iter().all(|id| g.note(id).is_some_and(|t| !t.is_empty())));
+    assert_eq!(g.note("nope"), None);
+}
+
+#[test]
+fn duplicate_ids_are_rejected() {
+    // what: two entries with the same id fail to parse
+    // why: the second definition would never be shown, and nobody would notice
+    let err = Glossary::from_toml(&format!("{}{}", entry("tmr", ""), entry("tmr", ""))).unwrap_err();
+    assert!(err.contains("duplicate term id tmr"), "{}", err);
+}
+
+#[test]
+fn see_also_must_name_a_known_term() {
+    // what: a related-term link to an undefined id fails to parse; a valid one passes
+    // why: the see-also button would open an empty definition
+    let err = Glossary::from_toml(&entry("tmr", "\"2oo4\"")).unwrap_err();
+    assert!(err.contains("unknown term 2oo4"), "{}", err);
+    assert!(Glossary::from_toml(&format!("{}{}", entry("tmr", "\"2oo3\""), entry("2oo3", ""))).is_ok());
+}
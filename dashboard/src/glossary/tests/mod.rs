@@ -0,0 +1,5 @@
+// what: exports all test modules for the glossary
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod entries;
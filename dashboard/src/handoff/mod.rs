@@ -0,0 +1,313 @@
+// what: cross-device result handoff - a compact result summary that travels as a qr code / link, plus the side-by-side comparison
+// why: phones and laptops time wasm and python very differently; a presenter collects the audience's numbers without a backend
+// relations: result schema also accepts json (external results import), numbers from tabs/demo/store.rs PerfSlice,
+//            qr drawing in qr.rs (QrImage also draws the remote/ pairing code), ui in panel.rs rendered by tabs/demo/component.rs;
+//            imported container restarts read by tabs/proof/container_baseline.rs and tabs/problem/comparison.rs
+
+use serde::{Deserialize, Serialize};
+use crate::measure::stats::{summarize, Summary};
+use crate::tabs::demo::summary::SessionNumbers;
+
+mod panel;
+pub mod qr;
+
+#[cfg(test)]
+mod tests;
+
+pub use panel::{HandoffPanel, QrImage};
+
+/// first field of every compact payload (bumped with the layout)
+pub const PAYLOAD_TAG: &str = "G1";
+/// url fragment carrying a payload: https://host/#compare=G1!...
+pub const FRAGMENT_KEY: &str = "compare=";
+/// localStorage key of the collected sessions
+pub const STORAGE_KEY: &str = "guardian.handoff.sessions";
+/// sessions kept in the comparison (oldest dropped)
+pub const MAX_SESSIONS: usize = 8;
+/// longest device label carried in a payload
+const MAX_DEVICE_LEN: usize = 32;
+/// longest believable container restart (s); anything above is a typo
+const MAX_RESTART_S: f64 = 600.0;
+/// the problem tab's "container restart (~1-5s)" claim, ms
+pub const CLAIMED_RESTART_MS: (f64, f64) = (1000.0, 5000.0);
+
+/// one timing as transferred: median, 95% ci half-width, samples kept
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub median: f64,
+    #[serde(default)]
+    pub ci95: f64,
+    #[serde(default)]
+    pub n: usize,
+}
+
+impl From<Summary> for Timing {
+    fn from(s: Summary) -> Self {
+        Timing { median: s.median, ci95: s.ci95, n: s.kept }
+    }
+}
+
+/// measured results of one device (the import schema, as compact payload or json)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResultSummary {
+    pub device: String,
+    /// unix epoch ms
+    #[serde(default)]
+    pub taken_ms: f64,
+    #[serde(default)]
+    pub wasm_instantiate: Option<Timing>,
+    #[serde(default)]
+    pub pyodide_load_ms: Option<f64>,
+    #[serde(default)]
+    pub wasm_exec: Option<Timing>,
+    #[serde(default)]
+    pub python_exec: Option<Timing>,
+    #[serde(default)]
+    pub js_exec: Option<Timing>,
+    /// docker restart measured outside the browser and imported; the dashboard never sets it itself
+    #[serde(default)]
+    pub container_restart: Option<Timing>,
+}
+
+/// compact number: up to 3 decimals, trailing zeros trimmed
+fn num(v: f64) -> String {
+    let s = format!("{:.3}", v);
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// keeps a device label inside the url-safe payload alphabet
+fn clean_device(device: &str) -> String {
+    let cleaned: String = device
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+        .take(MAX_DEVICE_LEN)
+        .collect();
+    if cleaned.is_empty() { "device".to_string() } else { cleaned }
+}
+
+impl ResultSummary {
+    pub fn from_numbers(device: &str, numbers: &SessionNumbers, taken_ms: f64) -> Self {
+        ResultSummary {
+            device: clean_device(device),
+            taken_ms,
+            wasm_instantiate: numbers.wasm_instantiate.map(Timing::from),
+            pyodide_load_ms: numbers.pyodide_load_ms,
+            wasm_exec: numbers.wasm_exec.map(Timing::from),
+            python_exec: numbers.python_exec.map(Timing::from),
+            js_exec: numbers.js_exec.map(Timing::from),
+            container_restart: None,
+        }
+    }
+
+    /// a container-only result from pasted restart timings in seconds (whitespace or comma separated)
+    pub fn from_restart_seconds(device: &str, text: &str, taken_ms: f64) -> Result<Self, String> {
+        let seconds = text
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|t| !t.is_empty())
+            .map(|t| t.trim_end_matches('s').parse::<f64>().ok().filter(|v| *v > 0.0 && *v < MAX_RESTART_S).ok_or_else(|| format!("'{}' is not a restart time in seconds", t)))
+            .collect::<Result<Vec<f64>, String>>()?;
+        if seconds.is_empty() {
+            return Err("no restart timings".to_string());
+        }
+        let ms: Vec<f64> = seconds.iter().map(|s| s * 1000.0).collect();
+        Ok(ResultSummary {
+            device: clean_device(device),
+            taken_ms,
+            wasm_instantiate: None,
+            pyodide_load_ms: None,
+            wasm_exec: None,
+            python_exec: None,
+            js_exec: None,
+            container_restart: Some(Timing::from(summarize(&ms, 0))),
+        })
+    }
+
+    /// "G1!Chrome-Android!1760600000!wi~0.042~0.003~30!pl~2100!..." - only characters urls and qr byte mode pass untouched
+    pub fn encode(&self) -> String {
+        let timing = |key: &str, t: &Option<Timing>| t.map(|t| format!("{}~{}~{}~{}", key, num(t.median), num(t.ci95), t.n));
+        let fields = [
+            Some(PAYLOAD_TAG.to_string()),
+            Some(clean_device(&self.device)),
+            Some(format!("{}", (self.taken_ms / 1000.0).round() as u64)),
+            timing("wi", &self.wasm_instantiate),
+            self.pyodide_load_ms.map(|ms| format!("pl~{}", num(ms))),
+            timing("we", &self.wasm_exec),
+            timing("pe", &self.python_exec),
+            timing("je", &self.js_exec),
+            timing("cr", &self.container_restart),
+        ];
+        fields.into_iter().flatten().collect::<Vec<_>>().join("!")
+    }
+
+    /// accepts a compact payload, a link carrying one in its fragment, or the json schema
+    pub fn decode(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        if text.starts_with('{') {
+            return serde_json::from_str(text).map_err(|e| e.to_string());
+        }
+        let payload = text.split_once(FRAGMENT_KEY).map(|(_, p)| p).unwrap_or(text);
+        let mut fields = payload.split('!');
+        if fields.next() != Some(PAYLOAD_TAG) {
+            return Err(format!("not a {} result payload", PAYLOAD_TAG));
+        }
+        let device = fields.next().filter(|d| !d.is_empty()).ok_or("missing device")?.to_string();
+        let taken_s: u64 = fields.next().and_then(|t| t.parse().ok()).ok_or("missing timestamp")?;
+        let mut out = ResultSummary {
+            device,
+            taken_ms: taken_s as f64 * 1000.0,
+            wasm_instantiate: None,
+            pyodide_load_ms: None,
+            wasm_exec: None,
+            python_exec: None,
+            js_exec: None,
+            container_restart: None,
+        };
+        for field in fields {
+            let parts: Vec<&str> = field.split('~').collect();
+            let value = |i: usize| parts.get(i).and_then(|v| v.parse::<f64>().ok()).ok_or_else(|| format!("bad field '{}'", field));
+            let timing = || -> Result<Option<Timing>, String> {
+                Ok(Some(Timing { median: value(1)?, ci95: value(2)?, n: value(3)? as usize }))
+            };
+            match parts[0] {
+                "wi" => out.wasm_instantiate = timing()?,
+                "pl" => out.pyodide_load_ms = Some(value(1)?),
+                "we" => out.wasm_exec = timing()?,
+                "pe" => out.python_exec = timing()?,
+                "je" => out.js_exec = timing()?,
+                "cr" => out.container_restart = timing()?,
+                // newer senders may add fields; keep what we understand
+                _ => {}
+            }
+        }
+        Ok(out)
+    }
+
+    /// link that opens the dashboard straight into the comparison
+    pub fn link(&self, base: &str) -> String {
+        format!("{}#{}{}", base.split('#').next().unwrap_or(base), FRAGMENT_KEY, self.encode())
+    }
+}
+
+/// "Chrome-Android" style label from a user agent
+pub fn device_label(user_agent: &str) -> String {
+    let has = |s: &str| user_agent.contains(s);
+    let os = if has("Android") {
+        "Android"
+    } else if has("iPhone") {
+        "iPhone"
+    } else if has("iPad") {
+        "iPad"
+    } else if has("Windows") {
+        "Windows"
+    } else if has("Mac OS X") || has("Macintosh") {
+        "Mac"
+    } else if has("Linux") {
+        "Linux"
+    } else {
+        "device"
+    };
+    let browser = if has("Edg/") {
+        "Edge"
+    } else if has("Firefox/") || has("FxiOS") {
+        "Firefox"
+    } else if has("Chrome/") || has("CriOS") {
+        "Chrome"
+    } else if has("Safari/") {
+        "Safari"
+    } else {
+        "Browser"
+    };
+    format!("{}-{}", browser, os)
+}
+
+/// adds or replaces (same device and time) a session, keeping the newest MAX_SESSIONS
+pub fn merge(sessions: &mut Vec<ResultSummary>, incoming: ResultSummary) {
+    sessions.retain(|s| !(s.device == incoming.device && s.taken_ms == incoming.taken_ms));
+    sessions.push(incoming);
+    let excess = sessions.len().saturating_sub(MAX_SESSIONS);
+    sessions.drain(..excess);
+}
+
+/// one metric across devices; `fastest` indexes the lowest median
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComparisonRow {
+    pub label: &'static str,
+    pub cells: Vec<Option<String>>,
+    pub fastest: Option<usize>,
+}
+
+/// metric rows for the side-by-side table, skipping metrics nobody measured
+pub fn comparison(sessions: &[ResultSummary]) -> Vec<ComparisonRow> {
+    type Pick = fn(&ResultSummary) -> Option<Timing>;
+    let metrics: [(&'static str, Pick, usize); 6] = [
+        ("WASM instantiate", |s| s.wasm_instantiate, 3),
+        ("Pyodide cold start", |s| s.pyodide_load_ms.map(|ms| Timing { median: ms, ci95: 0.0, n: 1 }), 0),
+        ("Sensor run (WASM)", |s| s.wasm_exec, 3),
+        ("Sensor run (Python)", |s| s.python_exec, 2),
+        ("Sensor run (JavaScript)", |s| s.js_exec, 3),
+        ("Container restart (imported)", |s| s.container_restart, 0),
+    ];
+    metrics
+        .into_iter()
+        .filter_map(|(label, pick, decimals)| {
+            let values: Vec<Option<Timing>> = sessions.iter().map(pick).collect();
+            values.iter().any(Option::is_some).then(|| ComparisonRow {
+                label,
+                cells: values
+                    .iter()
+                    .map(|t| t.map(|t| Summary { median: t.median, ci95: t.ci95, kept: t.n, ..Default::default() }.display(decimals)))
+                    .collect(),
+                fastest: values
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, t)| t.map(|t| (i, t.median)))
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(i, _)| i)
+                    .filter(|_| values.iter().flatten().count() > 1),
+            })
+        })
+        .collect()
+}
+
+// ============================================================================
+// container baseline
+// ============================================================================
+
+/// newest imported session carrying a container restart
+pub fn container_baseline(sessions: &[ResultSummary]) -> Option<&ResultSummary> {
+    sessions.iter().filter(|s| s.container_restart.is_some()).max_by(|a, b| a.taken_ms.total_cmp(&b.taken_ms))
+}
+
+/// how a measured restart median sits against the problem tab's claim
+pub fn claim_check(median_ms: f64) -> &'static str {
+    let (low, high) = CLAIMED_RESTART_MS;
+    if median_ms < low {
+        "faster than the claimed 1-5s"
+    } else if median_ms > high {
+        "slower than the claimed 1-5s"
+    } else {
+        "within the claimed 1-5s"
+    }
+}
+
+// ============================================================================
+// storage
+// ============================================================================
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// collected sessions (the handoff table and the container baseline read the same list)
+pub fn load_sessions() -> Vec<ResultSummary> {
+    storage()
+        .and_then(|s| s.get_item(STORAGE_KEY).ok()?)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_sessions(sessions: &[ResultSummary]) {
+    if let (Some(s), Ok(json)) = (storage(), serde_json::to_string(sessions)) {
+        let _ = s.set_item(STORAGE_KEY, &json);
+    }
+}
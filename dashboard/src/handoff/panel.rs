@@ -0,0 +1,153 @@
+// what: handoff panel - this device's results as a qr code / link, import of other devices, side-by-side table
+// why: the phone that ran the demo hands its numbers to the presenter's laptop without any server
+// relations: payload and comparison in handoff/mod.rs, qr modules from qr.rs, numbers from demo store PerfSlice, rendered by demo/component.rs
+
+use leptos::*;
+use crate::tabs::demo::store::PerfSlice;
+use crate::tabs::demo::wasm::copy_to_clipboard;
+use super::qr::QrCode;
+use super::{comparison, device_label, load_sessions, merge, save_sessions, ResultSummary, FRAGMENT_KEY};
+
+/// light modules around the code (spec minimum is 4)
+const QUIET_ZONE: usize = 4;
+
+/// svg path with one unit square per dark module, offset by the quiet zone
+fn qr_path(code: &QrCode) -> String {
+    let mut d = String::new();
+    for y in 0..code.size {
+        for x in 0..code.size {
+            if code.is_dark(x, y) {
+                d.push_str(&format!("M{} {}h1v1h-1z", x + QUIET_ZONE, y + QUIET_ZONE));
+            }
+        }
+    }
+    d
+}
+
+/// a qr code as a black-on-white svg (also the remote/ pairing code)
+#[component]
+pub fn QrImage(code: QrCode, #[prop(into)] label: String) -> impl IntoView {
+    let side = code.size + 2 * QUIET_ZONE;
+    view! {
+        <svg class="handoff-qr" viewBox=format!("0 0 {} {}", side, side) role="img" aria-label=label>
+            <rect width=side height=side fill="#fff"/>
+            <path d=qr_path(&code) fill="#000"/>
+        </svg>
+    }
+}
+
+/// payload from the page's #compare= fragment, cleared once read so a reload does not re-import
+fn take_fragment() -> Option<String> {
+    let location = web_sys::window()?.location();
+    let hash = location.hash().ok()?;
+    let payload = hash.strip_prefix('#')?.strip_prefix(FRAGMENT_KEY)?.to_string();
+    let _ = location.set_hash("");
+    Some(payload)
+}
+
+/// device handoff and side-by-side comparison
+#[component]
+pub fn HandoffPanel(perf: PerfSlice) -> impl IntoView {
+    let user_agent = web_sys::window().and_then(|w| w.navigator().user_agent().ok()).unwrap_or_default();
+    let (device, set_device) = create_signal(device_label(&user_agent));
+    let sessions = create_rw_signal(load_sessions());
+    let (show_qr, set_show_qr) = create_signal(false);
+    let (import_text, set_import_text) = create_signal(String::new());
+    let (status, set_status) = create_signal(Option::<String>::None);
+
+    let add = move |text: &str| match ResultSummary::decode(text) {
+        Ok(summary) => {
+            set_status.set(Some(format!("✅ added {}", summary.device)));
+            sessions.update(|s| merge(s, summary));
+            sessions.with_untracked(|s| save_sessions(s));
+        }
+        Err(e) => set_status.set(Some(format!("❌ {}", e))),
+    };
+    if let Some(payload) = take_fragment() {
+        add(&payload);
+    }
+
+    // re-read on every render so a fresh sensor run shows up in the code and the table
+    let local = move || {
+        let _ = (perf.sensor_ran.get(), perf.measurements_done.get(), perf.pyodide_load_ms.get());
+        ResultSummary::from_numbers(&device.get(), &perf.session_numbers(), js_sys::Date::now())
+    };
+    let link = move || {
+        let base = web_sys::window().and_then(|w| w.location().href().ok()).unwrap_or_default();
+        local().link(&base)
+    };
+    let qr = move || {
+        let link = link();
+        match QrCode::encode(link.as_bytes()) {
+            Ok(code) => view! {
+                <QrImage code label="QR code with this device's results" />
+                <p class="metrics-note handoff-link">{link}</p>
+            }.into_view(),
+            Err(e) => view! { <p class="audit-status">"❌ " {e}</p> }.into_view(),
+        }
+    };
+    let table = move || {
+        let mut all = vec![local()];
+        all.extend(sessions.get());
+        let rows = comparison(&all);
+        if rows.is_empty() {
+            return view! { <p class="metrics-note">"Run the sensor comparison to have something to compare."</p> }.into_view();
+        }
+        view! {
+            <table class="handoff-table">
+                <thead>
+                    <tr>
+                        <th></th>
+                        {all.iter().enumerate().map(|(i, s)| view! { <th>{if i == 0 { format!("{} (this)", s.device) } else { s.device.clone() }}</th> }).collect_view()}
+                    </tr>
+                </thead>
+                <tbody>
+                    {rows.into_iter().map(|row| view! {
+                        <tr>
+                            <td>{row.label}</td>
+                            {row.cells.into_iter().enumerate().map(|(i, cell)| view! {
+                                <td class:handoff-fastest=row.fastest == Some(i)>{cell.unwrap_or_else(|| "—".to_string())}</td>
+                            }).collect_view()}
+                        </tr>
+                    }).collect_view()}
+                </tbody>
+            </table>
+        }.into_view()
+    };
+
+    view! {
+        <div class="audit-panel handoff-panel">
+            <h4>"📱 Device Handoff" <span class="demo-badge">{move || format!("{} imported", sessions.with(|s| s.len()))}</span></h4>
+            <p class="section-desc">"Ran the demo on a phone? Show its QR code and open the link on the presenter's laptop (camera app or paste below) to compare devices side by side."</p>
+            <div class="audit-controls">
+                <input class="collector-url" type="text" title="Device label" prop:value=move || device.get() on:input=move |ev| set_device.set(event_target_value(&ev)) />
+                <button class="action-btn" on:click=move |_| set_show_qr.update(|v| *v = !*v)>{move || if show_qr.get() { "Hide QR" } else { "📱 Show QR" }}</button>
+                <button class="action-btn" on:click=move |_| {
+                    copy_to_clipboard(&link());
+                    set_status.set(Some("📋 link copied".to_string()));
+                }>"📋 Copy Link"</button>
+            </div>
+            <Show when=move || show_qr.get()>
+                <div class="handoff-code">{qr}</div>
+            </Show>
+            <textarea
+                class="policy-editor handoff-import"
+                placeholder="Paste a handoff link, payload (G1!...) or results JSON"
+                prop:value=move || import_text.get()
+                on:input=move |ev| set_import_text.set(event_target_value(&ev))
+            />
+            <div class="audit-controls">
+                <button class="action-btn" disabled=move || import_text.get().trim().is_empty() on:click=move |_| {
+                    add(&import_text.get_untracked());
+                    set_import_text.set(String::new());
+                }>"⬆️ Compare"</button>
+                <button class="action-btn" disabled=move || sessions.with(|s| s.is_empty()) on:click=move |_| {
+                    sessions.set(Vec::new());
+                    save_sessions(&[]);
+                }>"🗑️ Clear"</button>
+            </div>
+            {move || status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+            {table}
+        </div>
+    }
+}
@@ -0,0 +1,369 @@
+// what: minimal qr code encoder (byte mode, error correction level L, versions 1-10)
+// why: the handoff payload is a short url; a dependency-free encoder keeps the wasm bundle small
+// relations: used by handoff/panel.rs to draw the svg; algorithm follows iso/iec 18004 (reed-solomon over gf(256), 8 masks)
+
+/// largest supported version (274 data bytes at level L)
+pub const MAX_VERSION: usize = 10;
+
+/// level L block layout per version: (ec codewords per block, [(blocks, data codewords per block)])
+const BLOCKS_L: [(usize, [(usize, usize); 2]); MAX_VERSION] = [
+    (7, [(1, 19), (0, 0)]),
+    (10, [(1, 34), (0, 0)]),
+    (15, [(1, 55), (0, 0)]),
+    (20, [(1, 80), (0, 0)]),
+    (26, [(1, 108), (0, 0)]),
+    (18, [(2, 68), (0, 0)]),
+    (20, [(2, 78), (0, 0)]),
+    (24, [(2, 97), (0, 0)]),
+    (30, [(2, 116), (0, 0)]),
+    (18, [(2, 68), (2, 69)]),
+];
+
+/// alignment pattern centres per version
+const ALIGNMENT: [&[usize]; MAX_VERSION] = [
+    &[],
+    &[6, 18],
+    &[6, 22],
+    &[6, 26],
+    &[6, 30],
+    &[6, 34],
+    &[6, 22, 38],
+    &[6, 24, 42],
+    &[6, 26, 46],
+    &[6, 28, 50],
+];
+
+/// format-info bits of error correction level L
+const ECL_L: u32 = 0b01;
+
+/// a square grid of modules, without the quiet zone
+#[derive(Clone, Debug, PartialEq)]
+pub struct QrCode {
+    pub version: usize,
+    pub size: usize,
+    pub mask: u8,
+    modules: Vec<bool>,
+}
+
+impl QrCode {
+    /// encodes bytes in the smallest version that fits, picking the lowest-penalty mask
+    pub fn encode(data: &[u8]) -> Result<Self, String> {
+        let version = (1..=MAX_VERSION)
+            .find(|v| bit_length(*v, data.len()) <= data_codewords(*v) * 8)
+            .ok_or_else(|| format!("{} bytes do not fit a version {} qr code", data.len(), MAX_VERSION))?;
+        let codewords = add_ec(version, &data_bits(version, data));
+        let base = Grid::with_function_patterns(version);
+        let mut best: Option<(u32, Grid, u8)> = None;
+        for mask in 0..8u8 {
+            let mut grid = base.clone();
+            grid.draw_codewords(&codewords);
+            grid.apply_mask(mask);
+            grid.draw_format(mask);
+            let score = grid.penalty();
+            if best.as_ref().is_none_or(|(s, _, _)| score < *s) {
+                best = Some((score, grid, mask));
+            }
+        }
+        let (_, grid, mask) = best.expect("eight masks tried");
+        Ok(Self { version, size: grid.size, mask, modules: grid.dark })
+    }
+
+    /// dark module at column x, row y
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+}
+
+/// data codewords available in a version at level L
+pub fn data_codewords(version: usize) -> usize {
+    BLOCKS_L[version - 1].1.iter().map(|(blocks, len)| blocks * len).sum()
+}
+
+/// byte-mode segment length in bits (count field grows to 16 bits from version 10)
+fn bit_length(version: usize, len: usize) -> usize {
+    4 + count_bits(version) + len * 8
+}
+
+fn count_bits(version: usize) -> usize {
+    if version < 10 { 8 } else { 16 }
+}
+
+/// mode indicator, count, payload, terminator and 0xec/0x11 padding
+fn data_bits(version: usize, data: &[u8]) -> Vec<u8> {
+    let capacity = data_codewords(version) * 8;
+    let mut bits: Vec<bool> = Vec::with_capacity(capacity);
+    let push = |bits: &mut Vec<bool>, value: u32, len: usize| (0..len).rev().for_each(|i| bits.push((value >> i) & 1 == 1));
+    push(&mut bits, 0b0100, 4);
+    push(&mut bits, data.len() as u32, count_bits(version));
+    data.iter().for_each(|b| push(&mut bits, *b as u32, 8));
+    let terminator = (capacity - bits.len()).min(4);
+    push(&mut bits, 0, terminator);
+    let align = (8 - bits.len() % 8) % 8;
+    push(&mut bits, 0, align);
+    let mut bytes: Vec<u8> = bits.chunks(8).map(|c| c.iter().fold(0u8, |acc, b| acc << 1 | *b as u8)).collect();
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if bytes.len() >= capacity / 8 {
+            break;
+        }
+        bytes.push(pad);
+    }
+    bytes
+}
+
+/// splits into blocks, appends reed-solomon codewords and interleaves
+fn add_ec(version: usize, data: &[u8]) -> Vec<u8> {
+    let (ec_len, groups) = BLOCKS_L[version - 1];
+    let divisor = rs_divisor(ec_len);
+    let mut blocks: Vec<(&[u8], Vec<u8>)> = Vec::new();
+    let mut rest = data;
+    for (count, len) in groups {
+        for _ in 0..count {
+            let (block, tail) = rest.split_at(len);
+            blocks.push((block, rs_remainder(block, &divisor)));
+            rest = tail;
+        }
+    }
+    let longest = blocks.iter().map(|(d, _)| d.len()).max().unwrap_or(0);
+    let mut out = Vec::new();
+    for i in 0..longest {
+        out.extend(blocks.iter().filter_map(|(d, _)| d.get(i)));
+    }
+    for i in 0..ec_len {
+        out.extend(blocks.iter().map(|(_, ec)| ec[i]));
+    }
+    out
+}
+
+/// product in gf(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1
+fn gf_mul(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((y as u32 >> i) & 1) * x as u32;
+    }
+    z as u8
+}
+
+/// generator polynomial (x - a^0)...(x - a^(degree-1)), leading 1 dropped
+pub fn rs_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_mul(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+    result
+}
+
+/// error correction codewords of one block
+pub fn rs_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for b in data {
+        let factor = b ^ result.remove(0);
+        result.push(0);
+        for (r, d) in result.iter_mut().zip(divisor) {
+            *r ^= gf_mul(*d, factor);
+        }
+    }
+    result
+}
+
+/// 15-bit format info for level L and a mask (bch code, xor 0x5412)
+pub fn format_bits(mask: u8) -> u32 {
+    let data = ECL_L << 3 | mask as u32;
+    let mut rem = data;
+    for _ in 0..10 {
+        rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+    }
+    (data << 10 | rem) ^ 0x5412
+}
+
+/// 18-bit version info (versions 7 and up)
+pub fn version_bits(version: usize) -> u32 {
+    let mut rem = version as u32;
+    for _ in 0..12 {
+        rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+    }
+    (version as u32) << 12 | rem
+}
+
+fn mask_hit(mask: u8, x: usize, y: usize) -> bool {
+    match mask {
+        0 => (x + y).is_multiple_of(2),
+        1 => y.is_multiple_of(2),
+        2 => x.is_multiple_of(3),
+        3 => (x + y).is_multiple_of(3),
+        4 => (x / 3 + y / 2).is_multiple_of(2),
+        5 => x * y % 2 + x * y % 3 == 0,
+        6 => (x * y % 2 + x * y % 3).is_multiple_of(2),
+        _ => ((x + y) % 2 + x * y % 3).is_multiple_of(2),
+    }
+}
+
+// ============================================================================
+// module grid
+// ============================================================================
+
+#[derive(Clone)]
+struct Grid {
+    size: usize,
+    dark: Vec<bool>,
+    /// finder, timing, alignment, format and version modules (never masked)
+    reserved: Vec<bool>,
+}
+
+impl Grid {
+    fn with_function_patterns(version: usize) -> Self {
+        let size = 17 + 4 * version;
+        let mut grid = Grid { size, dark: vec![false; size * size], reserved: vec![false; size * size] };
+        for i in 0..size {
+            grid.set(6, i, i % 2 == 0);
+            grid.set(i, 6, i % 2 == 0);
+        }
+        for (cx, cy) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            for dy in -4isize..=4 {
+                for dx in -4isize..=4 {
+                    let (x, y) = (cx as isize + dx, cy as isize + dy);
+                    if (0..size as isize).contains(&x) && (0..size as isize).contains(&y) {
+                        let ring = dx.abs().max(dy.abs());
+                        grid.set(x as usize, y as usize, ring != 2 && ring != 4);
+                    }
+                }
+            }
+        }
+        let centres = ALIGNMENT[version - 1];
+        let last = centres.len().saturating_sub(1);
+        for (i, cx) in centres.iter().enumerate() {
+            for (j, cy) in centres.iter().enumerate() {
+                // the three corners taken by finder patterns
+                if i.min(j) == 0 && (i.max(j) == 0 || i.max(j) == last) {
+                    continue;
+                }
+                for dy in -2isize..=2 {
+                    for dx in -2isize..=2 {
+                        grid.set((*cx as isize + dx) as usize, (*cy as isize + dy) as usize, dx.abs().max(dy.abs()) != 1);
+                    }
+                }
+            }
+        }
+        // reserve format areas now, real bits are drawn per mask
+        grid.draw_format(0);
+        if version >= 7 {
+            let bits = version_bits(version);
+            for i in 0..18 {
+                let dark = (bits >> i) & 1 == 1;
+                let (a, b) = (size - 11 + i % 3, i / 3);
+                grid.set(a, b, dark);
+                grid.set(b, a, dark);
+            }
+        }
+        grid
+    }
+
+    fn set(&mut self, x: usize, y: usize, dark: bool) {
+        self.dark[y * self.size + x] = dark;
+        self.reserved[y * self.size + x] = true;
+    }
+
+    fn draw_format(&mut self, mask: u8) {
+        let bits = format_bits(mask);
+        let bit = |i: usize| (bits >> i) & 1 == 1;
+        let size = self.size;
+        for i in 0..=5 {
+            self.set(8, i, bit(i));
+        }
+        self.set(8, 7, bit(6));
+        self.set(8, 8, bit(7));
+        self.set(7, 8, bit(8));
+        for i in 9..15 {
+            self.set(14 - i, 8, bit(i));
+        }
+        for i in 0..8 {
+            self.set(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set(8, size - 15 + i, bit(i));
+        }
+        self.set(8, size - 8, true);
+    }
+
+    /// zigzag placement: two-column strips from the right, alternating up and down, skipping the timing column
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size;
+        let total = codewords.len() * 8;
+        let mut i = 0;
+        let mut right = size as isize - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            for vert in 0..size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { size - 1 - vert } else { vert };
+                    if !self.reserved[y * size + x] && i < total {
+                        self.dark[y * size + x] = (codewords[i >> 3] >> (7 - (i & 7))) & 1 == 1;
+                        i += 1;
+                    }
+                }
+            }
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if !self.reserved[y * self.size + x] && mask_hit(mask, x, y) {
+                    self.dark[y * self.size + x] ^= true;
+                }
+            }
+        }
+    }
+
+    /// iso penalty rules: long runs, 2x2 blocks, finder look-alikes, dark balance
+    fn penalty(&self) -> u32 {
+        let size = self.size;
+        let at = |x: usize, y: usize| self.dark[y * size + x];
+        let mut score = 0;
+        let finder_like = |line: &[bool]| {
+            const PATTERN: [bool; 11] = [true, false, true, true, true, false, true, false, false, false, false];
+            line.windows(11).filter(|w| w.iter().eq(PATTERN.iter()) || w.iter().rev().eq(PATTERN.iter())).count() as u32
+        };
+        for horizontal in [true, false] {
+            for a in 0..size {
+                let line: Vec<bool> = (0..size).map(|b| if horizontal { at(b, a) } else { at(a, b) }).collect();
+                let mut run = 1;
+                for b in 1..=size {
+                    if b < size && line[b] == line[b - 1] {
+                        run += 1;
+                        continue;
+                    }
+                    if run >= 5 {
+                        score += 3 + (run - 5) as u32;
+                    }
+                    run = 1;
+                }
+                score += 40 * finder_like(&line);
+            }
+        }
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let c = at(x, y);
+                if at(x + 1, y) == c && at(x, y + 1) == c && at(x + 1, y + 1) == c {
+                    score += 3;
+                }
+            }
+        }
+        let dark = self.dark.iter().filter(|d| **d).count();
+        let percent = dark * 100 / (size * size);
+        score + 10 * (percent.abs_diff(50) / 5) as u32
+    }
+}
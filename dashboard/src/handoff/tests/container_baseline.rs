@@ -0,0 +1,58 @@
+// what: tests for the imported container restart baseline (pasted timings, payload field, newest pick, claim check)
+// why: the problem tab cites this number; a misparsed paste or a stale pick would put a wrong figure next to the claim
+
+use crate::handoff::{claim_check, comparison, container_baseline, ResultSummary, Timing};
+
+const T0: f64 = 1_760_600_000_000.0;
+
+#[test]
+fn pasted_seconds_become_a_millisecond_summary() {
+    // what: time(1) output, comma or whitespace separated, optional "s" suffix, summarizes in ms
+    // why: the panel tells people to paste the shell loop's output as-is
+    let s = ResultSummary::from_restart_seconds("docker host", "2.3\n2.1, 2.5s\n2.2 2.4", T0).unwrap();
+    let restart = s.container_restart.unwrap();
+    assert_eq!(s.device, "docker_host");
+    assert_eq!(restart.n, 5);
+    assert!((restart.median - 2300.0).abs() < 1e-6, "{}", restart.median);
+    assert!(restart.ci95 > 0.0);
+    assert!(s.wasm_instantiate.is_none() && s.pyodide_load_ms.is_none());
+}
+
+#[test]
+fn junk_and_implausible_timings_are_rejected() {
+    // what: empty text, words, zero and hour-long restarts fail with the offending token
+    // why: a baseline of 0s or 3600s would make the claim look absurd either way
+    assert!(ResultSummary::from_restart_seconds("h", "  ", T0).is_err());
+    assert!(ResultSummary::from_restart_seconds("h", "2.1 fast", T0).unwrap_err().contains("fast"));
+    assert!(ResultSummary::from_restart_seconds("h", "0", T0).is_err());
+    assert!(ResultSummary::from_restart_seconds("h", "3600", T0).is_err());
+}
+
+#[test]
+fn restart_travels_in_payload_json_and_comparison() {
+    // what: the cr field round trips through the compact payload and json, and gets its own labelled row
+    // why: the import path is the only way a container number enters the app
+    let s = ResultSummary::from_restart_seconds("host", "2 2 2", T0).unwrap();
+    let payload = s.encode();
+    assert!(payload.ends_with("!cr~2000~0~3"), "{}", payload);
+    assert_eq!(ResultSummary::decode(&payload).unwrap().container_restart, s.container_restart);
+    let json = r#"{"device":"host","container_restart":{"median":1800,"ci95":50,"n":8}}"#;
+    assert_eq!(ResultSummary::decode(json).unwrap().container_restart, Some(Timing { median: 1800.0, ci95: 50.0, n: 8 }));
+    let rows = comparison(&[s]);
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].label, "Container restart (imported)");
+}
+
+#[test]
+fn newest_restart_is_the_baseline_and_checked_against_the_claim() {
+    // what: the latest session carrying a restart wins, sessions without one are skipped; medians are placed against 1-5s
+    // why: re-importing a fresh measurement must replace the old figure everywhere it is cited
+    let old = ResultSummary::from_restart_seconds("old", "4", T0).unwrap();
+    let new = ResultSummary::from_restart_seconds("new", "0.8", T0 + 1000.0).unwrap();
+    let phone = ResultSummary { device: "phone".into(), taken_ms: T0 + 2000.0, container_restart: None, ..old.clone() };
+    assert_eq!(container_baseline(&[new.clone(), old, phone]).map(|b| b.device.as_str()), Some("new"));
+    assert_eq!(container_baseline(&[]), None);
+    assert_eq!(claim_check(800.0), "faster than the claimed 1-5s");
+    assert_eq!(claim_check(2300.0), "within the claimed 1-5s");
+    assert_eq!(claim_check(7000.0), "slower than the claimed 1-5s");
+}
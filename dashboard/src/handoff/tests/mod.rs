@@ -0,0 +1,11 @@
+// what: exports all test modules for the device handoff
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod payload;
+
+#[cfg(test)]
+mod qr;
+
+#[cfg(test)]
+mod container_baseline;
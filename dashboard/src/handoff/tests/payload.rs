@@ -0,0 +1,74 @@
+// what: tests for the handoff payload, device labels and the comparison table
+// why: a payload that does not round trip silently drops a device's numbers
+
+use crate::handoff::{comparison, device_label, merge, ResultSummary, Timing, MAX_SESSIONS};
+
+fn phone() -> ResultSummary {
+    ResultSummary {
+        device: "Chrome-Android".into(),
+        taken_ms: 1_760_600_000_000.0,
+        wasm_instantiate: Some(Timing { median: 0.125, ci95: 0.01, n: 30 }),
+        pyodide_load_ms: Some(4200.0),
+        wasm_exec: Some(Timing { median: 0.08, ci95: 0.004, n: 12 }),
+        python_exec: Some(Timing { median: 9.5, ci95: 0.75, n: 12 }),
+        js_exec: None,
+        container_restart: None,
+    }
+}
+
+#[test]
+fn payload_round_trips_through_link_and_json() {
+    // what: encode -> link -> decode and json -> decode both give back the summary
+    // why: the qr code carries the link, the external results import uses json
+    let summary = phone();
+    let link = summary.link("https://demo.example/#old");
+    assert!(link.starts_with("https://demo.example/#compare=G1!Chrome-Android!1760600000!wi~0.125~0.01~30!pl~4200"));
+    assert!(link.chars().all(|c| c.is_ascii_alphanumeric() || "-._~!:/#=".contains(c)), "url-safe: {}", link);
+    assert_eq!(ResultSummary::decode(&link), Ok(summary.clone()));
+    assert_eq!(ResultSummary::decode(&serde_json::to_string(&summary).unwrap()), Ok(summary));
+}
+
+#[test]
+fn decode_rejects_foreign_text_and_skips_unknown_fields() {
+    // what: wrong tag or missing device errors; unknown keys from newer senders are ignored
+    // why: pasting random text must not add a bogus column, newer phones must still import
+    assert!(ResultSummary::decode("hello").is_err());
+    assert!(ResultSummary::decode("G1!!123").is_err());
+    assert!(ResultSummary::decode("G1!Safari-iPhone!5!we~x~1~2").is_err());
+    let s = ResultSummary::decode("G1!Safari iPhone!5!zz~1!we~0.2~0~1").unwrap();
+    assert_eq!(s.taken_ms, 5000.0);
+    assert_eq!(s.wasm_exec.map(|t| t.median), Some(0.2));
+    assert_eq!(ResultSummary { device: "a b;c".into(), ..s }.encode(), "G1!a_b_c!5!we~0.2~0~1");
+}
+
+#[test]
+fn device_labels_from_user_agents() {
+    // what: common mobile and desktop user agents map to browser-os labels
+    // why: the comparison columns are headed by these labels
+    assert_eq!(device_label("Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0 Mobile Safari/537.36"), "Chrome-Android");
+    assert_eq!(device_label("Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1"), "Safari-iPhone");
+    assert_eq!(device_label("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0 Safari/537.36 Edg/129.0"), "Edge-Windows");
+    assert_eq!(device_label("Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0"), "Firefox-Linux");
+}
+
+#[test]
+fn comparison_marks_fastest_and_merge_caps_sessions() {
+    // what: rows skip unmeasured metrics, mark the lowest median, and merge dedupes and caps the list
+    // why: the table is the point of the handoff; re-scanning the same qr must not add a column
+    let laptop = ResultSummary { device: "Chrome-Mac".into(), wasm_exec: Some(Timing { median: 0.02, ci95: 0.001, n: 12 }), python_exec: None, ..phone() };
+    let rows = comparison(&[phone(), laptop]);
+    assert_eq!(rows.iter().map(|r| r.label).collect::<Vec<_>>(), ["WASM instantiate", "Pyodide cold start", "Sensor run (WASM)", "Sensor run (Python)"]);
+    assert_eq!(rows[2].fastest, Some(1));
+    assert_eq!(rows[3].cells, vec![Some("9.50 ± 0.75ms".to_string()), None]);
+    assert_eq!(rows[3].fastest, None, "one value is not a comparison");
+
+    let mut sessions = Vec::new();
+    merge(&mut sessions, phone());
+    merge(&mut sessions, phone());
+    assert_eq!(sessions.len(), 1);
+    for i in 0..MAX_SESSIONS + 2 {
+        merge(&mut sessions, ResultSummary { taken_ms: i as f64, ..phone() });
+    }
+    assert_eq!(sessions.len(), MAX_SESSIONS);
+    assert_eq!(sessions.last().map(|s| s.taken_ms), Some((MAX_SESSIONS + 1) as f64));
+}
@@ -0,0 +1,33 @@
+// what: tests for the qr encoder against iso/iec 18004 reference values
+// why: a code that looks right but fails to scan is worse than no code
+
+use crate::handoff::qr::{data_codewords, format_bits, rs_divisor, rs_remainder, version_bits, QrCode, MAX_VERSION};
+
+#[test]
+fn reference_ec_format_and_version_bits() {
+    // what: reed-solomon ec of the "HELLO WORLD" 1-M example, format bits for L/mask 0, version 7 info
+    // why: these are the published values every decoder checks against
+    let data = [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17];
+    assert_eq!(rs_remainder(&data, &rs_divisor(10)), vec![196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+    assert_eq!(format_bits(0), 0b111011111000100);
+    assert_eq!(version_bits(7), 0b000111110010010100);
+    assert_eq!((1..=MAX_VERSION).map(data_codewords).collect::<Vec<_>>(), [19, 34, 55, 80, 108, 136, 156, 194, 232, 274]);
+}
+
+#[test]
+fn picks_smallest_version_and_draws_function_patterns() {
+    // what: payload size picks the version; finders, timing and the dark module are in place
+    // why: scanners locate the code by these patterns before reading any data
+    assert_eq!(QrCode::encode(&[b'a'; 17]).unwrap().version, 1);
+    assert_eq!(QrCode::encode(&[b'a'; 18]).unwrap().version, 2);
+    let code = QrCode::encode("https://demo.example/#compare=G1!Chrome-Android!1760600000!wi~0.125~0.01~30!pl~4200".as_bytes()).unwrap();
+    assert_eq!(code.size, 17 + 4 * code.version);
+    let n = code.size;
+    for (ox, oy) in [(0, 0), (n - 7, 0), (0, n - 7)] {
+        assert!((0..7).all(|i| code.is_dark(ox + i, oy) && code.is_dark(ox, oy + i)), "finder border at {},{}", ox, oy);
+        assert!(!code.is_dark(ox + 1, oy + 1) && code.is_dark(ox + 3, oy + 3));
+    }
+    assert!((8..n - 8).all(|i| code.is_dark(i, 6) == (i % 2 == 0)));
+    assert!(code.is_dark(8, n - 8));
+    assert!(QrCode::encode(&[0; 300]).is_err());
+}
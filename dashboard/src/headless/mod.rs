@@ -0,0 +1,297 @@
+// what: window.guardianDemo - a wasm-bindgen js api to run attacks, reset, read metrics and subscribe to events
+// why: e2e tests, embedding pages and presentation tooling need to drive the demo without clicking
+// relations: demo/component.rs registers its store and idle transitions, demo/store.rs record() emits journal events,
+//            lib.rs installs the global and supplies the tab switches; remote/host.rs runs phone commands through it
+//
+// js usage:
+//   guardianDemo.attacks()                  -> ["bufferOverflow", ...]
+//   guardianDemo.describe("dataExfil")      -> { id, name, kind: "security" | "availability", wasmTrap }
+//   guardianDemo.runAttack("dataExfil")     throws if unknown, busy, its payload is still loading or the demo tab is not mounted
+//   guardianDemo.runAll() / runSensor() / reset()
+//   guardianDemo.nextTab()                  switches to the next tab, wrapping around
+//   guardianDemo.getMetrics()               -> { counters, events, busy, ... }
+//   guardianDemo.terminal("python")         -> retained terminal lines ("python" | "wasm" | "js")
+//   const id = guardianDemo.on(msg => ...)   msg.type: "ready" | "event" | "idle"; guardianDemo.off(id)
+
+use std::cell::RefCell;
+use leptos::*;
+use serde_json::{json, Value};
+use wasm_bindgen::prelude::*;
+use crate::tabs::demo::attacks::{get_attack_config, AVAILABILITY_ATTACKS, SECURITY_ATTACKS};
+use crate::tabs::demo::journal::{AuditEvent, Runtime};
+use crate::tabs::demo::metrics::MetricsSnapshot;
+use crate::tabs::demo::store::DemoStore;
+
+#[cfg(test)]
+mod tests;
+
+/// name of the global the api is installed under
+pub const GLOBAL_NAME: &str = "guardianDemo";
+
+thread_local! {
+    /// the mounted demo tab's store (None while another tab is shown)
+    static STORE: RefCell<Option<DemoStore>> = const { RefCell::new(None) };
+    /// switches the app to the demo tab
+    static SHOW_DEMO: RefCell<Option<Callback<()>>> = const { RefCell::new(None) };
+    /// switches the app to the tab after the current one
+    static NEXT_TAB: RefCell<Option<Callback<()>>> = const { RefCell::new(None) };
+    static LISTENERS: RefCell<Vec<(u32, js_sys::Function)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_LISTENER: RefCell<u32> = const { RefCell::new(1) };
+}
+
+// ============================================================================
+// protocol (pure, shared with tests)
+// ============================================================================
+
+/// attacks the api accepts, in run-all order
+pub fn attack_names() -> Vec<&'static str> {
+    SECURITY_ATTACKS.iter().chain(AVAILABILITY_ATTACKS.iter()).copied().collect()
+}
+
+/// the canonical attack id for a name, or the list of valid ones
+pub fn resolve_attack(name: &str) -> Result<&'static str, String> {
+    attack_names()
+        .into_iter()
+        .find(|a| *a == name)
+        .ok_or_else(|| format!("unknown attack \"{}\" (expected one of: {})", name, attack_names().join(", ")))
+}
+
+/// describe() payload: display name, attack family and the trap wasm reports by default
+pub fn attack_info(name: &str) -> Result<Value, String> {
+    let id = resolve_attack(name)?;
+    let config = get_attack_config(id);
+    let kind = if AVAILABILITY_ATTACKS.contains(&id) { "availability" } else { "security" };
+    Ok(json!({ "id": id, "name": config.name, "kind": kind, "wasmTrap": config.wasm_trap }))
+}
+
+/// terminal name as used by journal runtimes
+pub fn parse_runtime(name: &str) -> Result<Runtime, String> {
+    [Runtime::Python, Runtime::Wasm, Runtime::Js]
+        .into_iter()
+        .find(|r| r.as_str() == name)
+        .ok_or_else(|| format!("unknown runtime \"{}\" (expected python, wasm or js)", name))
+}
+
+/// message for a journal event
+pub fn event_message(event: &AuditEvent) -> Value {
+    json!({
+        "type": "event",
+        "kind": event.kind.id(),
+        "runtime": event.runtime.map(|r| r.as_str()),
+        "attack": event.attack,
+        "detail": event.detail,
+        "durationMs": event.duration_ms,
+        "timestamp": event.timestamp_ms,
+    })
+}
+
+/// message for lifecycle notices ("ready" when the demo mounts, "idle" when an attack or run-all finishes)
+pub fn notice(kind: &str) -> Value {
+    json!({ "type": kind })
+}
+
+/// getMetrics() payload
+pub fn metrics_message(snapshot: &MetricsSnapshot, events: usize, busy: bool, wasm_instantiate_ms: Option<f64>, pyodide_load_ms: Option<f64>) -> Value {
+    json!({
+        "counters": {
+            "pythonProcessed": snapshot.python_processed,
+            "pythonCrashed": snapshot.python_crashed,
+            "pythonDowntimeMs": snapshot.python_downtime_ms,
+            "wasmProcessed": snapshot.wasm_processed,
+            "wasmRejected": snapshot.wasm_rejected,
+        },
+        "events": events,
+        "busy": busy,
+        "wasmInstantiateMs": wasm_instantiate_ms,
+        "pyodideLoadMs": pyodide_load_ms,
+    })
+}
+
+// ============================================================================
+// registration (called from the app)
+// ============================================================================
+
+/// the demo tab mounted (Some) or unmounted (None)
+pub fn register_store(store: Option<DemoStore>) {
+    let ready = store.is_some();
+    STORE.with(|s| *s.borrow_mut() = store);
+    if ready {
+        emit(|| notice("ready"));
+    }
+}
+
+pub fn register_show_demo(show: Callback<()>) {
+    SHOW_DEMO.with(|s| *s.borrow_mut() = Some(show));
+}
+
+pub fn register_next_tab(next: Callback<()>) {
+    NEXT_TAB.with(|s| *s.borrow_mut() = Some(next));
+}
+
+/// sends a message to every listener; the payload is only built when someone listens
+pub fn emit(message: impl FnOnce() -> Value) {
+    let listeners = LISTENERS.with(|l| l.borrow().iter().map(|(_, f)| f.clone()).collect::<Vec<_>>());
+    if listeners.is_empty() {
+        return;
+    }
+    let Ok(payload) = js_sys::JSON::parse(&message().to_string()) else { return };
+    for listener in listeners {
+        if let Err(e) = listener.call1(&JsValue::NULL, &payload) {
+            tracing::warn!(target: "api", error = ?e, "guardianDemo listener threw");
+        }
+    }
+}
+
+/// installs window.guardianDemo
+pub fn install() {
+    if let Some(window) = web_sys::window() {
+        let _ = js_sys::Reflect::set(&window, &GLOBAL_NAME.into(), &GuardianDemo.into());
+    }
+}
+
+// ============================================================================
+// js api
+// ============================================================================
+
+/// the object behind window.guardianDemo
+#[wasm_bindgen]
+pub struct GuardianDemo;
+
+/// the mounted store, switching to the demo tab first if needed
+fn store() -> Result<DemoStore, JsValue> {
+    if STORE.with(|s| s.borrow().is_none()) {
+        if let Some(show) = SHOW_DEMO.with(|s| *s.borrow()) {
+            show.call(());
+        }
+    }
+    STORE.with(|s| *s.borrow()).ok_or_else(|| JsValue::from_str("the demo tab is not mounted yet; wait for the \"ready\" event"))
+}
+
+/// the store, unless an attack or run-all is in progress
+fn idle_store() -> Result<DemoStore, JsValue> {
+    let store = store()?;
+    if store.control.is_running.get_untracked() || store.control.running_all.get_untracked() {
+        return Err(JsValue::from_str("an attack is still running; wait for the \"idle\" event"));
+    }
+    Ok(store)
+}
+
+#[wasm_bindgen]
+impl GuardianDemo {
+    /// attack ids runAttack accepts
+    pub fn attacks(&self) -> js_sys::Array {
+        attack_names().into_iter().map(JsValue::from_str).collect()
+    }
+
+    /// metadata of one attack (throws for unknown names)
+    pub fn describe(&self, name: &str) -> Result<JsValue, JsValue> {
+        let info = attack_info(name).map_err(|e| JsValue::from_str(&e))?;
+        js_sys::JSON::parse(&info.to_string())
+    }
+
+    /// retained lines of one runtime's terminal, oldest first
+    pub fn terminal(&self, runtime: &str) -> Result<js_sys::Array, JsValue> {
+        let runtime = parse_runtime(runtime).map_err(|e| JsValue::from_str(&e))?;
+        let store = store()?;
+        let log = match runtime {
+            Runtime::Python => store.python_log,
+            Runtime::Wasm => store.wasm_log,
+            Runtime::Js => store.js_log,
+        };
+        Ok(log.messages().into_iter().map(JsValue::from).collect())
+    }
+
+    /// shows the demo tab (mounting its store)
+    #[wasm_bindgen(js_name = showDemo)]
+    pub fn show_demo(&self) -> Result<(), JsValue> {
+        store().map(|_| ())
+    }
+
+    /// moves to the next tab (the demo's store unmounts when leaving it)
+    #[wasm_bindgen(js_name = nextTab)]
+    pub fn next_tab(&self) -> Result<(), JsValue> {
+        let next = NEXT_TAB.with(|s| *s.borrow()).ok_or_else(|| JsValue::from_str("the app is not mounted"))?;
+        next.call(());
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = runAttack)]
+    pub fn run_attack(&self, name: &str) -> Result<(), JsValue> {
+        let attack = resolve_attack(name).map_err(|e| JsValue::from_str(&e))?;
+        let store = idle_store()?;
+        if !AVAILABILITY_ATTACKS.contains(&attack) && !store.payloads_ready() {
+            return Err(JsValue::from_str("the attack code is still loading"));
+        }
+        store.launch(attack);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = runAll)]
+    pub fn run_all(&self) -> Result<(), JsValue> {
+        let store = idle_store()?;
+        if !store.payloads_ready() {
+            return Err(JsValue::from_str("the attack code is still loading"));
+        }
+        store.run_all_attacks();
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = runSensor)]
+    pub fn run_sensor(&self) -> Result<(), JsValue> {
+        let store = idle_store()?;
+        if !store.perf.pyodide_ready.get_untracked() {
+            return Err(JsValue::from_str("pyodide is still loading"));
+        }
+        store.run_sensor_comparison();
+        Ok(())
+    }
+
+    /// same as the reset button (journal entry included)
+    pub fn reset(&self) -> Result<(), JsValue> {
+        let store = idle_store()?;
+        store.record(crate::tabs::demo::journal::EventKind::Reset, None, "", "Stats and terminals cleared (api)".to_string());
+        store.reset_with_undo(js_sys::Date::now());
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = isBusy)]
+    pub fn is_busy(&self) -> bool {
+        STORE.with(|s| s.borrow().map(|st| st.control.is_running.get_untracked() || st.control.running_all.get_untracked())).unwrap_or(false)
+    }
+
+    /// counters and headline timings of the mounted demo
+    #[wasm_bindgen(js_name = getMetrics)]
+    pub fn get_metrics(&self) -> Result<JsValue, JsValue> {
+        let store = store()?;
+        let perf = store.perf;
+        let message = metrics_message(
+            &untrack(|| store.metrics_snapshot()),
+            store.journal.with_untracked(|j| j.len()),
+            self.is_busy(),
+            perf.measurements_done.get_untracked().then(|| perf.wasm_instantiate_ms.get_untracked().median),
+            Some(perf.pyodide_load_ms.get_untracked()).filter(|ms| *ms > 0.0),
+        );
+        js_sys::JSON::parse(&message.to_string())
+    }
+
+    /// registers an event callback; returns an id for off()
+    pub fn on(&self, callback: js_sys::Function) -> u32 {
+        let id = NEXT_LISTENER.with(|n| {
+            let mut n = n.borrow_mut();
+            *n += 1;
+            *n - 1
+        });
+        LISTENERS.with(|l| l.borrow_mut().push((id, callback)));
+        id
+    }
+
+    /// removes a callback; false if the id was unknown
+    pub fn off(&self, id: u32) -> bool {
+        LISTENERS.with(|l| {
+            let mut l = l.borrow_mut();
+            let before = l.len();
+            l.retain(|(i, _)| *i != id);
+            l.len() != before
+        })
+    }
+}
@@ -0,0 +1,60 @@
+// what: tests for the window.guardianDemo message and attack-name protocol
+// why: external scripts depend on these names and shapes; renaming a field breaks them silently
+
+use crate::headless::{attack_info, attack_names, event_message, metrics_message, notice, parse_runtime, resolve_attack};
+use crate::tabs::demo::handlers::run_all_sequence;
+use crate::tabs::demo::journal::{AuditEvent, EventKind, Runtime};
+use crate::tabs::demo::metrics::MetricsSnapshot;
+
+#[test]
+fn attack_names_resolve_or_list_the_valid_ones() {
+    // what: every run-all attack resolves; an unknown name errors with the full list
+    // why: callers learn the valid ids from the error instead of reading the source
+    assert_eq!(attack_names(), run_all_sequence());
+    assert_eq!(resolve_attack("dataExfil"), Ok("dataExfil"));
+    let err = resolve_attack("dataexfil").unwrap_err();
+    assert!(err.contains("unknown attack \"dataexfil\""));
+    assert!(attack_names().iter().all(|a| err.contains(a)));
+}
+
+#[test]
+fn event_message_shape() {
+    // what: journal events become {type, kind, runtime, attack, detail, durationMs, timestamp}
+    // why: e2e waits key off kind ids and attack names
+    let event = AuditEvent::new(1_000.0, EventKind::Recovery, Some(Runtime::Wasm), "killLeader", "I0 rebuilt").with_duration(0.4);
+    let msg = event_message(&event);
+    assert_eq!(msg["type"], "event");
+    assert_eq!(msg["kind"], "RECOVERY");
+    assert_eq!(msg["runtime"], "wasm");
+    assert_eq!(msg["attack"], "killLeader");
+    assert_eq!(msg["durationMs"], 0.4);
+    assert!(event_message(&AuditEvent::new(0.0, EventKind::Reset, None, "", "x"))["runtime"].is_null());
+    assert_eq!(notice("idle").to_string(), r#"{"type":"idle"}"#);
+}
+
+#[test]
+fn metrics_message_shape() {
+    // what: counters are camelCase, unmeasured timings are null
+    // why: getMetrics() is asserted on by tests outside this crate
+    let snapshot = MetricsSnapshot { python_crashed: 2, wasm_rejected: 3, ..Default::default() };
+    let msg = metrics_message(&snapshot, 7, true, Some(0.05), None);
+    assert_eq!(msg["counters"]["pythonCrashed"], 2);
+    assert_eq!(msg["counters"]["wasmRejected"], 3);
+    assert_eq!(msg["events"], 7);
+    assert_eq!(msg["busy"], true);
+    assert_eq!(msg["wasmInstantiateMs"], 0.05);
+    assert!(msg["pyodideLoadMs"].is_null());
+}
+
+#[test]
+fn describe_and_runtime_names() {
+    // what: describe() reports name, family and default trap; terminal names map to journal runtimes
+    // why: the e2e suite picks assertions per attack family from describe()
+    let info = attack_info("killLeader").unwrap();
+    assert_eq!(info["name"], "Kill Leader");
+    assert_eq!(info["kind"], "availability");
+    assert_eq!(attack_info("dataExfil").unwrap()["wasmTrap"], "capability not granted: network");
+    assert!(attack_info("nope").is_err());
+    assert_eq!(parse_runtime("wasm"), Ok(Runtime::Wasm));
+    assert!(parse_runtime("ruby").unwrap_err().contains("python, wasm or js"));
+}
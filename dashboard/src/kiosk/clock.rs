@@ -0,0 +1,73 @@
+// what: pure kiosk timing - when to reset, advance the tab or start a run-all
+// why: kept free of browser apis so the timing rules are testable natively
+// relations: ticked once a second by lib.rs App with settings/mod.rs KioskSettings
+
+use crate::settings::KioskSettings;
+
+/// what the app should do on this tick
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KioskAction {
+    /// back to the first tab, modals closed, scrolled to the top
+    Reset,
+    NextTab,
+    /// switch to the demo tab and run every attack
+    RunAll,
+}
+
+/// timestamps (ms) the kiosk rules are evaluated against
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KioskClock {
+    last_input_ms: f64,
+    last_step_ms: f64,
+    last_run_all_ms: f64,
+    attract: bool,
+}
+
+impl KioskClock {
+    pub fn new(now_ms: f64) -> Self {
+        Self { last_input_ms: now_ms, last_step_ms: now_ms, last_run_all_ms: now_ms, attract: false }
+    }
+
+    /// cycling on its own since the last reset
+    pub fn attract(&self) -> bool {
+        self.attract
+    }
+
+    /// a visitor touched the console: hand control back and restart the idle timer
+    pub fn input(&mut self, now_ms: f64) {
+        self.last_input_ms = now_ms;
+        self.attract = false;
+    }
+
+    /// one step of the rules; `busy` holds the current tab while a run-all plays out
+    pub fn tick(&mut self, now_ms: f64, kiosk: &KioskSettings, busy: bool) -> Option<KioskAction> {
+        if !kiosk.enabled {
+            // enabling kiosk mode starts a fresh idle period
+            *self = KioskClock::new(now_ms);
+            return None;
+        }
+        if !self.attract {
+            if now_ms - self.last_input_ms < kiosk.idle_reset_min as f64 * 60_000.0 {
+                return None;
+            }
+            self.attract = true;
+            self.last_step_ms = now_ms;
+            self.last_run_all_ms = now_ms;
+            return Some(KioskAction::Reset);
+        }
+        if busy {
+            self.last_step_ms = now_ms;
+            return None;
+        }
+        if now_ms - self.last_run_all_ms >= kiosk.run_all_every_min as f64 * 60_000.0 {
+            self.last_run_all_ms = now_ms;
+            self.last_step_ms = now_ms;
+            return Some(KioskAction::RunAll);
+        }
+        if now_ms - self.last_step_ms >= kiosk.dwell_s as f64 * 1000.0 {
+            self.last_step_ms = now_ms;
+            return Some(KioskAction::NextTab);
+        }
+        None
+    }
+}
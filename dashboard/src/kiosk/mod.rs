@@ -0,0 +1,38 @@
+// what: kiosk mode - idle reset, timed tab cycling and periodic run-all for unattended trade-show use
+// why: the demo has to keep itself presentable for hours without someone resetting it between visitors
+// relations: settings in settings/mod.rs (KioskSettings), driven by lib.rs App, run-all picked up by demo/component.rs,
+//            modals in settings/panel.rs and demo/info_box.rs close while attract mode is on
+
+use leptos::*;
+
+mod clock;
+
+#[cfg(test)]
+mod tests;
+
+pub use clock::{KioskAction, KioskClock};
+
+/// dom events that count as a visitor touching the console
+pub const INPUT_EVENTS: [&str; 4] = ["pointerdown", "keydown", "wheel", "touchstart"];
+
+/// kiosk signals shared between App, the demo tab and the modals
+#[derive(Clone, Copy)]
+pub struct Kiosk {
+    /// idle timeout passed: the console is cycling on its own
+    pub attract: RwSignal<bool>,
+    /// a run-all is due; the demo tab clears it when it starts the sequence
+    pub run_all: RwSignal<bool>,
+    /// the demo tab is mid run-all, so cycling waits
+    pub busy: RwSignal<bool>,
+}
+
+impl Kiosk {
+    pub fn new() -> Self {
+        Self { attract: create_rw_signal(false), run_all: create_rw_signal(false), busy: create_rw_signal(false) }
+    }
+}
+
+/// kiosk signals provided by App
+pub fn use_kiosk() -> Kiosk {
+    expect_context::<Kiosk>()
+}
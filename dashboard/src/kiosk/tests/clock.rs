@@ -0,0 +1,54 @@
+// what: tests for kiosk idle reset, tab dwell and run-all timing
+// why: an unattended booth that never resets, or resets mid-conversation, defeats the point
+
+use crate::kiosk::{KioskAction, KioskClock};
+use crate::settings::KioskSettings;
+
+fn enabled() -> KioskSettings {
+    KioskSettings { enabled: true, idle_reset_min: 2, dwell_s: 30, run_all_every_min: 5 }
+}
+
+#[test]
+fn resets_once_after_idle_timeout() {
+    // what: nothing happens before the timeout, one reset at it, then attract mode
+    // why: a visitor pausing to read must not be interrupted; the reset itself must not repeat every tick
+    let kiosk = enabled();
+    let mut clock = KioskClock::new(0.0);
+    assert_eq!(clock.tick(119_000.0, &kiosk, false), None);
+    assert_eq!(clock.tick(120_000.0, &kiosk, false), Some(KioskAction::Reset));
+    assert!(clock.attract());
+    assert_eq!(clock.tick(121_000.0, &kiosk, false), None);
+}
+
+#[test]
+fn cycles_tabs_and_runs_all_while_attracting() {
+    // what: tabs advance every dwell, run-all wins when due, and busy holds the tab
+    // why: the run-all sequence has to finish on screen before the tab moves on
+    let kiosk = enabled();
+    let mut clock = KioskClock::new(0.0);
+    clock.tick(120_000.0, &kiosk, false);
+    assert_eq!(clock.tick(150_000.0, &kiosk, false), Some(KioskAction::NextTab));
+    assert_eq!(clock.tick(170_000.0, &kiosk, false), None);
+    assert_eq!(clock.tick(420_000.0, &kiosk, false), Some(KioskAction::RunAll));
+    assert_eq!(clock.tick(480_000.0, &kiosk, true), None);
+    assert_eq!(clock.tick(500_000.0, &kiosk, false), None);
+    assert_eq!(clock.tick(510_000.0, &kiosk, false), Some(KioskAction::NextTab));
+}
+
+#[test]
+fn input_and_disabling_hand_control_back() {
+    // what: input leaves attract mode and restarts the idle timer; disabled kiosk never acts
+    // why: a visitor must get the console back instantly, and the setting must really turn it off
+    let kiosk = enabled();
+    let mut clock = KioskClock::new(0.0);
+    clock.tick(120_000.0, &kiosk, false);
+    clock.input(130_000.0);
+    assert!(!clock.attract());
+    assert_eq!(clock.tick(200_000.0, &kiosk, false), None);
+    assert_eq!(clock.tick(250_000.0, &kiosk, false), Some(KioskAction::Reset));
+
+    let off = KioskSettings { enabled: false, ..enabled() };
+    let mut clock = KioskClock::new(0.0);
+    assert_eq!(clock.tick(10_000_000.0, &off, false), None);
+    assert_eq!(clock.tick(10_060_000.0, &kiosk, false), None);
+}
@@ -1,12 +1,39 @@
 // what: main leptos application entry point with tab-based navigation
 // why: provides story-driven walkthrough of wasi 0.2 security thesis
-// relations: parent of tabs/*.rs, mounts to index.html
+// relations: parent of tabs/*.rs, analytics/, api/, autobench/, content/, diagnostics/, handoff/, headless/, kiosk/, measure/, remote/, settings/, source/, tabsync/, timescale/, trace/ and whatsnew/, mounts to index.html
 
 use leptos::*;
 
+mod analytics;
+mod api;
+mod autobench;
+mod content;
+mod diagnostics;
+mod glossary;
+mod handoff;
+mod headless;
+mod kiosk;
+mod measure;
+mod remote;
+mod settings;
+mod source;
 mod tabs;
+mod tabsync;
+mod timescale;
+mod trace;
+mod whatsnew;
 
 use tabs::{problem::Problem, hardware::Hardware, demo::Demo, proof::Proof};
+use settings::{SettingsButton, Theme};
+use diagnostics::{install_panic_hook, BrowserCaps, Diagnostics, DiagnosticsPanel, FeedbackButton, TabBoundary};
+use trace::TraceDrawer;
+use source::SourceDrawer;
+use kiosk::{Kiosk, KioskAction, KioskClock};
+use whatsnew::{ReleaseNotesButton, WhatsNewBanner};
+use autobench::AutoBenchBanner;
+use timescale::{SpeedControl, TimeScale};
+use tabsync::{FollowToggle, TabSync};
+use remote::{RemoteButton, RemoteHost, RemotePad};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Tab {
@@ -16,15 +43,162 @@ pub enum Tab {
     Proof,
 }
 
+impl Tab {
+    /// tab after this one, wrapping around (kiosk cycling)
+    pub fn next(self) -> Tab {
+        match self {
+            Tab::Problem => Tab::Hardware,
+            Tab::Hardware => Tab::Demo,
+            Tab::Demo => Tab::Proof,
+            Tab::Proof => Tab::Problem,
+        }
+    }
+
+    /// stable name for analytics counters
+    pub fn id(self) -> &'static str {
+        match self {
+            Tab::Problem => "problem",
+            Tab::Hardware => "hardware",
+            Tab::Demo => "demo",
+            Tab::Proof => "proof",
+        }
+    }
+
+    /// inverse of id (tab switches sent by another console tab)
+    pub fn from_id(id: &str) -> Option<Tab> {
+        [Tab::Problem, Tab::Hardware, Tab::Demo, Tab::Proof].into_iter().find(|t| t.id() == id)
+    }
+}
+
 #[component]
 pub fn App() -> impl IntoView {
+    // a phone that scanned a remote pairing code gets the remote pad instead of the console
+    let remote_offer = web_sys::window()
+        .and_then(|w| w.location().hash().ok())
+        .and_then(|hash| hash.strip_prefix('#')?.starts_with(remote::FRAGMENT_KEY).then_some(hash));
+    if let Some(offer) = remote_offer {
+        return view! { <div class="app"><RemotePad offer /></div> }.into_view();
+    }
+
     let (active_tab, set_active_tab) = create_signal(Tab::Problem);
+    headless::register_show_demo(Callback::new(move |_| set_active_tab.set(Tab::Demo)));
+    headless::register_next_tab(Callback::new(move |_| set_active_tab.update(|t| *t = t.next())));
+
+    // user preferences, persisted on every change
+    let settings = create_rw_signal(settings::load());
+    provide_context(settings);
+    create_effect(move |_| settings.with(settings::save));
+    // runtime probes: browser apis now, pyodide once the demo tab loads it
+    provide_context(BrowserCaps::detect());
+    provide_context(create_rw_signal(Diagnostics::default()));
+    // host calls of loaded modules, written by the demo and proof tabs alike
+    provide_context(create_rw_signal(tabs::demo::host_trace::HostTrace::default()));
+    // what the pi's oled shows, written by the demo tab and drawn by the hardware tab
+    provide_context(create_rw_signal(tabs::hardware::oled::HmiStatus::default()));
+    // proof measurements, filled by the proof tab or a background run started on load
+    provide_context(create_rw_signal(tabs::proof::bench::ProofResults::default()));
+    // simulation speed: handlers read it when they schedule a scripted wait
+    let time_scale = create_rw_signal(TimeScale::REAL);
+    provide_context(time_scale);
+    create_effect(move |_| timescale::apply(time_scale.get()));
+
+    // opt-in analytics: counters saved on change, a session per load or opt-in, dwell per visible tab
+    let analytics = create_rw_signal(analytics::load());
+    provide_context(analytics);
+    create_effect(move |_| analytics.with(analytics::save));
+    create_effect(move |was_on: Option<bool>| {
+        // a load with analytics on, or opting in mid-visit, starts a session
+        let on = settings.with(|s| s.analytics);
+        if on && was_on != Some(true) {
+            analytics.update(|c| c.count_session(js_sys::Date::now()));
+        }
+        on
+    });
+
+    // kiosk mode: any input restarts the idle timer, a 1s tick applies the reset/cycle/run-all rules
+    let kiosk = Kiosk::new();
+    provide_context(kiosk);
+    let clock = store_value(KioskClock::new(js_sys::Date::now()));
+    for event in kiosk::INPUT_EVENTS {
+        let _ = window_event_listener_untyped(event, move |_| {
+            clock.update_value(|c| c.input(js_sys::Date::now()));
+            kiosk.attract.set(false);
+        });
+    }
+    if let Ok(handle) = set_interval_with_handle(move || {
+        let rules = settings.with_untracked(|s| s.kiosk.clone());
+        let action = clock.try_update_value(|c| c.tick(js_sys::Date::now(), &rules, kiosk.busy.get_untracked())).flatten();
+        let attract = clock.with_value(|c| c.attract());
+        if kiosk.attract.get_untracked() != attract {
+            kiosk.attract.set(attract);
+        }
+        match action {
+            Some(KioskAction::Reset) => {
+                set_active_tab.set(Tab::Problem);
+                time_scale.set(TimeScale::REAL);
+                if let Some(window) = web_sys::window() {
+                    window.scroll_to_with_x_and_y(0.0, 0.0);
+                }
+            }
+            Some(KioskAction::NextTab) => set_active_tab.update(|t| *t = t.next()),
+            Some(KioskAction::RunAll) => {
+                set_active_tab.set(Tab::Demo);
+                kiosk.run_all.set(true);
+            }
+            None => {}
+        }
+    }, std::time::Duration::from_secs(1)) {
+        on_cleanup(move || handle.clear());
+    }
+
+    // multi-tab sync: a control tab sends its tab switches, a tab in follow mode takes them
+    let sync = TabSync::install();
+    provide_context(sync);
+    create_effect(move |_| {
+        let tab = active_tab.get();
+        if sync.followers() > 0 {
+            sync.publish_tab(tab.id());
+        }
+    });
+    create_effect(move |_| {
+        let tab = sync.tab.get().and_then(|id| Tab::from_id(&id));
+        if let Some(tab) = tab.filter(|_| sync.following.get()) {
+            set_active_tab.set(tab);
+        }
+    });
+
+    // phone remote: commands arrive over a data channel and run through the headless api
+    provide_context(RemoteHost::new());
+
+    // dwell is only credited while the viewer opted in, the page is visible and the kiosk isn't cycling on its own
+    let visible = create_rw_signal(true);
+    let _ = window_event_listener_untyped("visibilitychange", move |_| {
+        visible.set(web_sys::window().and_then(|w| w.document()).is_none_or(|d| !d.hidden()));
+    });
+    let dwell = store_value(analytics::DwellClock::default());
+    create_effect(move |_| {
+        let counting = settings.with(|s| s.analytics) && visible.get() && !kiosk.attract.get();
+        let tab = counting.then(|| active_tab.get().id());
+        if let Some((ended, ms)) = dwell.try_update_value(|d| d.switch(tab, js_sys::Date::now())).flatten() {
+            analytics.update(|c| c.add_visit(ended, ms));
+        }
+    });
 
     view! {
-        <div class="app">
+        <div class="app" class:theme-light=move || settings.with(|s| s.theme == Theme::Light)>
             <header class="header">
+                <SettingsButton />
+                <FeedbackButton />
+                <RemoteButton />
+                <SpeedControl />
+                <FollowToggle />
                 <h1>"Guardian One Console"</h1>
                 <p class="subtitle">"Industrial Edge Security Demonstration"</p>
+                <Show when=move || kiosk.attract.get()>
+                    <p class="kiosk-banner">"🎬 Kiosk mode — touch anywhere to take over"</p>
+                </Show>
+                <WhatsNewBanner />
+                <AutoBenchBanner on_view=Callback::new(move |_| set_active_tab.set(Tab::Proof)) />
             </header>
 
             <nav class="tabs">
@@ -56,22 +230,31 @@ pub fn App() -> impl IntoView {
 
             <main class="content">
                 {move || match active_tab.get() {
-                    Tab::Problem => view! { <Problem /> }.into_view(),
-                    Tab::Hardware => view! { <Hardware /> }.into_view(),
-                    Tab::Demo => view! { <Demo /> }.into_view(),
-                    Tab::Proof => view! { <Proof /> }.into_view(),
+                    Tab::Problem => view! { <TabBoundary name="The Problem"><Problem /></TabBoundary> }.into_view(),
+                    Tab::Hardware => view! { <TabBoundary name="The Hardware"><Hardware /></TabBoundary> }.into_view(),
+                    Tab::Demo => view! { <TabBoundary name="The Demo"><Demo /></TabBoundary> }.into_view(),
+                    Tab::Proof => view! { <TabBoundary name="The Proof"><Proof /></TabBoundary> }.into_view(),
                 }}
             </main>
 
+            <DiagnosticsPanel />
+            <TraceDrawer />
+            <SourceDrawer />
+
             <footer class="footer">
                 <p>"WASI/WASM Industrial Web Demo • Powered by "<span class="wasi-highlight">"WASI 0.2"</span></p>
+                <ReleaseNotesButton />
             </footer>
         </div>
-    }
+    }.into_view()
 }
 
 #[wasm_bindgen::prelude::wasm_bindgen(start)]
 pub fn main() {
-    console_error_panic_hook::set_once();
+    diagnostics::startup::mark("main");
+    trace::install();
+    install_panic_hook();
+    headless::install();
     mount_to_body(|| view! { <App /> });
+    diagnostics::startup::mark("mounted");
 }
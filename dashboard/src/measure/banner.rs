@@ -0,0 +1,44 @@
+// what: banner explaining how precise the timings on this page are
+// why: sub-ms numbers mean little if the browser coarsens performance.now()
+// relations: uses timing.rs, worker.rs and diagnostics BrowserCaps, shown by the demo and proof tabs
+
+use leptos::*;
+use super::timing::{cross_origin_isolated, method_for, timer_resolution_ms, Method, WINDOW_MS};
+use super::worker::worker_available;
+use crate::diagnostics::use_browser_caps;
+
+/// timer precision notice
+#[component]
+pub fn PrecisionBanner() -> impl IntoView {
+    let caps = use_browser_caps();
+    let isolated = cross_origin_isolated();
+    let resolution_ms = timer_resolution_ms();
+    let coarse = method_for(resolution_ms) == Method::WindowCount;
+    let resolution = if resolution_ms < 1.0 {
+        format!("{:.0}µs", resolution_ms * 1000.0)
+    } else {
+        format!("{:.1}ms", resolution_ms)
+    };
+
+    view! {
+        <div class="precision-banner" class:coarse=coarse>
+            {if coarse {
+                format!(
+                    "⚠️ Timer resolution ≈ {}{}. Sub-ms WASM timings are measured by counting iterations per {:.0}ms window instead of timing each one.",
+                    resolution,
+                    if isolated { "" } else { " (page is not cross-origin isolated — serve with COOP/COEP headers for µs timers)" },
+                    WINDOW_MS,
+                )
+            } else {
+                format!("⏱️ High-resolution timers ≈ {}{} — each iteration is timed individually.", resolution, if isolated { ", cross-origin isolated" } else { "" })
+            }}
+            {move || if !caps.workers {
+                " Web Workers unsupported on this browser — timing on the main thread."
+            } else if worker_available() {
+                " Timing loops run in a dedicated Web Worker."
+            } else {
+                " Web Workers unavailable — timing on the main thread."
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,155 @@
+// what: wasm binary section parser and a binary-split treemap layout for its sizes
+// why: "47 KB" is a claim; reading the section table of the module actually served shows where every byte goes
+// relations: used by tabs/proof/size_inspector.rs on the fetched app bundle and the embedded demo modules
+
+/// magic + version
+pub const WASM_HEADER_BYTES: usize = 8;
+const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+
+/// one section as laid out in the file (id byte + size leb + payload)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmSection {
+    pub id: u8,
+    /// standard name, or the custom section's own name
+    pub name: String,
+    pub bytes: usize,
+}
+
+/// section table of one module
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WasmLayout {
+    pub total: usize,
+    pub sections: Vec<WasmSection>,
+}
+
+impl WasmLayout {
+    /// bytes per section name, same-named sections merged, largest first
+    pub fn by_name(&self) -> Vec<(String, usize)> {
+        let mut merged: Vec<(String, usize)> = Vec::new();
+        for s in &self.sections {
+            match merged.iter_mut().find(|(name, _)| *name == s.name) {
+                Some((_, bytes)) => *bytes += s.bytes,
+                None => merged.push((s.name.clone(), s.bytes)),
+            }
+        }
+        merged.sort_by_key(|m| std::cmp::Reverse(m.1));
+        merged
+    }
+
+    /// bytes in custom sections (names, producers, debug info) that a release strip would drop
+    pub fn custom_bytes(&self) -> usize {
+        self.sections.iter().filter(|s| s.id == 0).map(|s| s.bytes).sum()
+    }
+}
+
+fn section_name(id: u8) -> &'static str {
+    match id {
+        0 => "custom",
+        1 => "type",
+        2 => "import",
+        3 => "function",
+        4 => "table",
+        5 => "memory",
+        6 => "global",
+        7 => "export",
+        8 => "start",
+        9 => "element",
+        10 => "code",
+        11 => "data",
+        12 => "datacount",
+        13 => "tag",
+        _ => "unknown",
+    }
+}
+
+/// unsigned leb128 at `at`: (value, bytes read)
+fn read_leb_u32(bytes: &[u8], at: usize) -> Result<(u32, usize), String> {
+    let mut value = 0u32;
+    for (i, byte) in bytes.iter().skip(at).take(5).enumerate() {
+        value |= u32::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+    Err(format!("truncated leb128 at byte {}", at))
+}
+
+/// walks the section table; payloads are only read for custom section names
+pub fn parse_sections(bytes: &[u8]) -> Result<WasmLayout, String> {
+    if bytes.len() < WASM_HEADER_BYTES || bytes[..4] != WASM_MAGIC {
+        return Err("not a wasm binary (bad magic)".to_string());
+    }
+    let mut sections = Vec::new();
+    let mut at = WASM_HEADER_BYTES;
+    while at < bytes.len() {
+        let id = bytes[at];
+        let (size, leb) = read_leb_u32(bytes, at + 1)?;
+        let payload = at + 1 + leb;
+        let end = payload + size as usize;
+        if end > bytes.len() {
+            return Err(format!("section {} at byte {} runs past the end", section_name(id), at));
+        }
+        let name = if id == 0 {
+            let (len, name_leb) = read_leb_u32(bytes, payload)?;
+            let start = payload + name_leb;
+            bytes.get(start..start + len as usize)
+                .and_then(|n| std::str::from_utf8(n).ok())
+                .map(|n| format!("custom: {}", n))
+                .ok_or_else(|| format!("bad custom section name at byte {}", payload))?
+        } else {
+            section_name(id).to_string()
+        };
+        sections.push(WasmSection { id, name, bytes: end - at });
+        at = end;
+    }
+    Ok(WasmLayout { total: bytes.len(), sections })
+}
+
+// ============================================================================
+// treemap
+// ============================================================================
+
+/// one rectangle of the treemap; `index` points into the sizes passed in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tile {
+    pub index: usize,
+    pub x: f64,
+    pub y: f64,
+    pub w: f64,
+    pub h: f64,
+}
+
+/// splits the sorted items in two halves of roughly equal weight, cutting the longer side each time
+fn split(items: &[(usize, u64)], x: f64, y: f64, w: f64, h: f64, out: &mut Vec<Tile>) {
+    match items {
+        [] => {}
+        [(index, _)] => out.push(Tile { index: *index, x, y, w, h }),
+        _ => {
+            let total: u64 = items.iter().map(|(_, s)| s).sum();
+            let mut acc = 0u64;
+            // first cut where the running sum reaches half, keeping at least one item per side
+            let cut = items.iter().position(|(_, s)| {
+                acc += s;
+                acc * 2 >= total
+            }).unwrap_or(0).min(items.len() - 2) + 1;
+            let (left, right) = items.split_at(cut);
+            let share = left.iter().map(|(_, s)| s).sum::<u64>() as f64 / total as f64;
+            if w >= h {
+                split(left, x, y, w * share, h, out);
+                split(right, x + w * share, y, w * (1.0 - share), h, out);
+            } else {
+                split(left, x, y, w, h * share, out);
+                split(right, x, y + h * share, w, h * (1.0 - share), out);
+            }
+        }
+    }
+}
+
+/// lays `sizes` out in a `w` x `h` box with areas proportional to size; zero sizes get no tile
+pub fn treemap(sizes: &[u64], w: f64, h: f64) -> Vec<Tile> {
+    let mut items: Vec<(usize, u64)> = sizes.iter().copied().enumerate().filter(|(_, s)| *s > 0).collect();
+    items.sort_by_key(|i| std::cmp::Reverse(i.1));
+    let mut out = Vec::with_capacity(items.len());
+    split(&items, 0.0, 0.0, w, h, &mut out);
+    out
+}
@@ -0,0 +1,73 @@
+// what: tail-latency summary (p50/p99/p99.9/worst) and histogram bins for tick latencies
+// why: control loops care about the worst pause, not the average speed
+// relations: fed by worker.rs tick jobs and pyodide, rendered by proof/jitter.rs and tabs/histogram.rs
+
+/// ticks per runtime in the jitter benchmark
+pub const JITTER_TICKS: u32 = 10_000;
+/// bars per histogram
+pub const HISTOGRAM_BINS: usize = 24;
+
+/// nearest-rank percentile (p in 0..=100) of unsorted samples
+pub fn percentile(samples: &[f64], p: f64) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    // epsilon keeps 0.999 * 1000 from rounding up a whole rank
+    let rank = ((p / 100.0) * sorted.len() as f64 - 1e-9).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// tail-latency summary in milliseconds
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct JitterReport {
+    pub p50: f64,
+    pub p99: f64,
+    pub p999: f64,
+    /// worst-case pause
+    pub max: f64,
+    pub ticks: usize,
+}
+
+impl JitterReport {
+    pub fn from_samples(samples: &[f64]) -> Self {
+        Self {
+            p50: percentile(samples, 50.0),
+            p99: percentile(samples, 99.0),
+            p999: percentile(samples, 99.9),
+            max: samples.iter().copied().fold(0.0, f64::max),
+            ticks: samples.len(),
+        }
+    }
+
+    /// worst pause relative to the median tick (1.0 = perfectly deterministic)
+    pub fn spread(&self) -> f64 {
+        if self.p50 > 0.0 { self.max / self.p50 } else { 0.0 }
+    }
+}
+
+/// one histogram bar covering [lo, hi)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bin {
+    pub lo: f64,
+    pub hi: f64,
+    pub count: usize,
+}
+
+/// linear bins from 0 to the worst sample (the last bin includes the max)
+pub fn histogram(samples: &[f64], bins: usize) -> Vec<Bin> {
+    let max = samples.iter().copied().fold(0.0, f64::max);
+    if samples.is_empty() || bins == 0 || max <= 0.0 {
+        return Vec::new();
+    }
+    let width = max / bins as f64;
+    let mut out: Vec<Bin> = (0..bins)
+        .map(|i| Bin { lo: i as f64 * width, hi: (i + 1) as f64 * width, count: 0 })
+        .collect();
+    for &s in samples {
+        let i = ((s / width) as usize).min(bins - 1);
+        out[i].count += 1;
+    }
+    out
+}
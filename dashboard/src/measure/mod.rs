@@ -0,0 +1,17 @@
+// what: measurement statistics shared by the demo and proof tabs
+// why: raw averages of a handful of browser timings mix warm-up, gc pauses and jitter into one number
+// relations: stats.rs (pure math), timing.rs (resolution + sampling loop), protocol.rs + worker.rs (off-thread loops), binary.rs (wasm sections),
+//            banner.rs, used by tabs/demo/wasm.rs, tabs/demo/component.rs, tabs/proof/component.rs and tabs/proof/size_inspector.rs
+
+pub mod stats;
+pub mod jitter;
+pub mod timing;
+pub mod protocol;
+pub mod worker;
+pub mod binary;
+mod banner;
+
+pub use banner::PrecisionBanner;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,72 @@
+// what: typed message protocol between the page and the measurement worker
+// why: json over postMessage keeps the worker script dumb and the rust side type-checked
+// relations: encoded by worker.rs, mirrored by the inline script in worker.rs
+
+use serde::{Deserialize, Serialize};
+
+/// job sent to the worker
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WorkerRequest {
+    /// instantiate the module `runs` times (or count per window when `window_ms` is set)
+    Instantiate { id: u32, module: Vec<u8>, runs: u32, window_ms: Option<f64> },
+    /// call an (i32, i32) -> i32 export repeatedly, one sample per window
+    CallThroughput { id: u32, module: Vec<u8>, export: String, runs: u32, window_ms: f64 },
+    /// call an (i32) -> i32 export `runs` times, one latency sample per call
+    WasmTicks { id: u32, module: Vec<u8>, export: String, arg: i32, runs: u32 },
+    /// same as WasmTicks for a js function built from `source`
+    JsTicks { id: u32, source: String, arg: i32, runs: u32 },
+}
+
+impl WorkerRequest {
+    pub fn id(&self) -> u32 {
+        match self {
+            WorkerRequest::Instantiate { id, .. }
+            | WorkerRequest::CallThroughput { id, .. }
+            | WorkerRequest::WasmTicks { id, .. }
+            | WorkerRequest::JsTicks { id, .. } => *id,
+        }
+    }
+
+    /// job name for traces
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WorkerRequest::Instantiate { .. } => "instantiate",
+            WorkerRequest::CallThroughput { .. } => "call_throughput",
+            WorkerRequest::WasmTicks { .. } => "wasm_ticks",
+            WorkerRequest::JsTicks { .. } => "js_ticks",
+        }
+    }
+}
+
+/// reply from the worker, samples are per-operation milliseconds
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerResponse {
+    Samples { id: u32, samples: Vec<f64> },
+    Failed { id: u32, message: String },
+}
+
+impl WorkerResponse {
+    pub fn id(&self) -> u32 {
+        match self {
+            WorkerResponse::Samples { id, .. } | WorkerResponse::Failed { id, .. } => *id,
+        }
+    }
+
+    /// samples or the worker's error message
+    pub fn into_samples(self) -> Result<Vec<f64>, String> {
+        match self {
+            WorkerResponse::Samples { samples, .. } => Ok(samples),
+            WorkerResponse::Failed { message, .. } => Err(message),
+        }
+    }
+}
+
+pub fn encode(request: &WorkerRequest) -> String {
+    serde_json::to_string(request).unwrap_or_default()
+}
+
+pub fn decode(message: &str) -> Result<WorkerResponse, String> {
+    serde_json::from_str(message).map_err(|e| e.to_string())
+}
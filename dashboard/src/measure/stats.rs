@@ -0,0 +1,109 @@
+// what: warm-up discard, median, mad outlier rejection and 95% confidence intervals of the median
+// why: every reported timing should say how much to trust it
+// relations: used by measurement loops in demo/wasm.rs and proof/component.rs
+
+/// iterations discarded before sampling (jit tiering, cache warm-up)
+pub const WARMUP_RUNS: usize = 2;
+/// modified z-score above which a sample is an outlier (iglewicz & hoaglin)
+pub const OUTLIER_Z: f64 = 3.5;
+/// scales mad to a standard-deviation estimate for normal data
+const MAD_TO_SIGMA: f64 = 0.6745;
+/// standard error of the median over that of the mean for normal data, sqrt(pi / 2)
+const MEDIAN_SE_FACTOR: f64 = 1.2533;
+
+/// two-sided 95% student t critical values for df 1..=30
+const T95: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228,
+    2.201, 2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086,
+    2.080, 2.074, 2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+];
+
+/// robust summary of a set of timing samples
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub median: f64,
+    pub mean: f64,
+    /// half-width of the 95% confidence interval of the median (0 when n < 2)
+    pub ci95: f64,
+    /// samples kept after outlier rejection
+    pub kept: usize,
+    pub rejected: usize,
+}
+
+impl Summary {
+    /// "0.042 ± 0.003ms", or just the median for a single sample
+    pub fn display(&self, decimals: usize) -> String {
+        if self.kept < 2 {
+            format!("{:.*}ms", decimals, self.median)
+        } else {
+            format!("{:.*} ± {:.*}ms", decimals, self.median, decimals, self.ci95)
+        }
+    }
+
+    /// tooltip text with sample counts
+    pub fn detail(&self) -> String {
+        format!("median of n={} (95% CI of the median, {} outliers rejected)", self.kept, self.rejected)
+    }
+}
+
+/// median of unsorted samples (0 when empty)
+pub fn median(samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) { (sorted[mid - 1] + sorted[mid]) / 2.0 } else { sorted[mid] }
+}
+
+/// median absolute deviation around the given median
+pub fn mad(samples: &[f64], center: f64) -> f64 {
+    let deviations: Vec<f64> = samples.iter().map(|s| (s - center).abs()).collect();
+    median(&deviations)
+}
+
+/// splits samples into kept values and a rejected count (modified z-score > OUTLIER_Z)
+pub fn reject_outliers(samples: &[f64]) -> (Vec<f64>, usize) {
+    let center = median(samples);
+    let spread = mad(samples, center);
+    if spread == 0.0 {
+        // more than half the samples are identical, nothing to scale against
+        return (samples.to_vec(), 0);
+    }
+    let kept: Vec<f64> = samples
+        .iter()
+        .copied()
+        .filter(|s| (MAD_TO_SIGMA * (s - center) / spread).abs() <= OUTLIER_Z)
+        .collect();
+    let rejected = samples.len() - kept.len();
+    (kept, rejected)
+}
+
+/// t critical value for the given degrees of freedom
+fn t_critical(df: usize) -> f64 {
+    match df {
+        0 => 0.0,
+        1..=30 => T95[df - 1],
+        _ => 1.96,
+    }
+}
+
+/// summarizes raw samples after dropping the first `warmup` runs
+pub fn summarize(raw: &[f64], warmup: usize) -> Summary {
+    let samples = raw.get(warmup..).filter(|s| !s.is_empty()).unwrap_or(raw);
+    if samples.is_empty() {
+        return Summary::default();
+    }
+    let (kept, rejected) = reject_outliers(samples);
+    let n = kept.len();
+    let mean = kept.iter().sum::<f64>() / n as f64;
+    let ci95 = if n < 2 {
+        0.0
+    } else {
+        let variance = kept.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        // large-sample standard error of the median, so the interval belongs to the number it is printed next to
+        t_critical(n - 1) * MEDIAN_SE_FACTOR * variance.sqrt() / (n as f64).sqrt()
+    };
+    Summary { median: median(&kept), mean, ci95, kept: n, rejected }
+}
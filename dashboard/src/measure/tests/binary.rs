@@ -0,0 +1,59 @@
+// what: tests for the wasm section parser and the treemap layout
+// why: the size inspector's numbers must add up to the file, and its tiles must cover the box
+
+use crate::measure::binary::{parse_sections, treemap, WASM_HEADER_BYTES};
+use crate::tabs::demo::modules::TICK_WASM;
+use crate::tabs::demo::wasm::MINIMAL_WASM;
+
+#[test]
+fn sections_add_up_to_the_file() {
+    // what: the callable add module parses into type/function/export/code and every byte is accounted for
+    // why: the inspector's share column divides by the total
+    for bytes in [MINIMAL_WASM, TICK_WASM] {
+        let layout = parse_sections(bytes).unwrap();
+        let sum: usize = layout.sections.iter().map(|s| s.bytes).sum();
+        assert_eq!(sum + WASM_HEADER_BYTES, layout.total);
+        assert_eq!(layout.total, bytes.len());
+    }
+    let names: Vec<String> = parse_sections(MINIMAL_WASM).unwrap().sections.into_iter().map(|s| s.name).collect();
+    assert_eq!(names, ["type", "function", "export", "code"]);
+}
+
+#[test]
+fn custom_sections_are_named_and_merged() {
+    // what: custom sections report their own name; same-named sections merge in by_name
+    // why: release bundles carry "name" / "producers" sections worth seeing separately
+    let mut bytes = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    for _ in 0..2 {
+        bytes.extend([0x00, 0x06, 0x04, b'n', b'a', b'm', b'e', 0xAA]);
+    }
+    let layout = parse_sections(&bytes).unwrap();
+    assert_eq!(layout.sections[0].name, "custom: name");
+    assert_eq!(layout.by_name(), [("custom: name".to_string(), 16)]);
+    assert_eq!(layout.custom_bytes(), 16);
+}
+
+#[test]
+fn malformed_binaries_are_rejected() {
+    // what: bad magic and a section running past the end are errors, not panics
+    // why: the bundle comes over the network and might be an html error page
+    assert!(parse_sections(b"<!doctype html>").is_err());
+    let truncated = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x10, 0x00];
+    assert!(parse_sections(&truncated).unwrap_err().contains("code"));
+}
+
+#[test]
+fn treemap_tiles_cover_the_box_proportionally() {
+    // what: tile areas are proportional to size, sum to the box, and zero sizes get no tile
+    // why: a treemap that misstates area misstates the binary
+    let sizes = [600, 0, 300, 100];
+    let tiles = treemap(&sizes, 200.0, 100.0);
+    assert_eq!(tiles.len(), 3);
+    let area: f64 = tiles.iter().map(|t| t.w * t.h).sum();
+    assert!((area - 20_000.0).abs() < 1e-6);
+    for t in &tiles {
+        assert!((t.w * t.h - sizes[t.index] as f64 / 1000.0 * 20_000.0).abs() < 1e-6);
+        assert!(t.x >= 0.0 && t.y >= 0.0 && t.x + t.w <= 200.0 + 1e-9 && t.y + t.h <= 100.0 + 1e-9);
+    }
+    assert!(treemap(&[], 10.0, 10.0).is_empty());
+}
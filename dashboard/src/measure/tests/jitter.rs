@@ -0,0 +1,53 @@
+// what: tests for tail-latency percentiles, jitter reports and histogram bins
+// why: the jitter benchmark's whole argument rests on the tail numbers being right
+
+use crate::measure::jitter::{histogram, percentile, JitterReport};
+use crate::measure::protocol::{encode, WorkerRequest};
+
+#[test]
+fn percentile_is_nearest_rank() {
+    // what: p50/p99/p100 pick the nearest-rank sample regardless of input order
+    // why: p99.9 of 10,000 ticks must be the 9,990th sample, not an interpolation
+    let samples: Vec<f64> = (1..=1000).rev().map(|i| i as f64).collect();
+    assert_eq!(percentile(&samples, 50.0), 500.0);
+    assert_eq!(percentile(&samples, 99.9), 999.0);
+    assert_eq!(percentile(&samples, 100.0), 1000.0);
+    assert_eq!(percentile(&[], 99.0), 0.0);
+}
+
+#[test]
+fn report_keeps_the_worst_pause() {
+    // what: a single gc-like spike shows up as max and spread, not in the median
+    // why: outlier rejection would hide exactly the pause this benchmark exists to show
+    let mut samples = vec![0.25; 9_999];
+    samples.push(12.0);
+    let report = JitterReport::from_samples(&samples);
+    assert_eq!(report.p50, 0.25);
+    assert_eq!(report.max, 12.0);
+    assert_eq!(report.ticks, 10_000);
+    assert_eq!(report.spread(), 48.0);
+}
+
+#[test]
+fn histogram_covers_every_sample() {
+    // what: bins span 0..max, counts sum to the sample count, max lands in the last bin
+    // why: a dropped tail sample would erase the pause from the chart
+    let samples = [0.0, 0.5, 1.0, 1.5, 4.0];
+    let bins = histogram(&samples, 4);
+    assert_eq!(bins.len(), 4);
+    assert_eq!(bins.iter().map(|b| b.count).sum::<usize>(), samples.len());
+    assert_eq!(bins[3].count, 1);
+    assert_eq!(bins[3].hi, 4.0);
+    assert!(histogram(&[0.0, 0.0], 4).is_empty());
+}
+
+#[test]
+fn tick_requests_are_tagged() {
+    // what: tick jobs encode with their snake_case kinds
+    // why: the inline worker dispatches on wasm_ticks / js_ticks
+    let wasm = encode(&WorkerRequest::WasmTicks { id: 1, module: vec![0], export: "tick".into(), arg: 256, runs: 10 });
+    assert!(wasm.starts_with(r#"{"kind":"wasm_ticks","id":1"#));
+    let js = encode(&WorkerRequest::JsTicks { id: 2, source: "return x".into(), arg: 256, runs: 10 });
+    assert!(js.starts_with(r#"{"kind":"js_ticks","id":2"#));
+    assert_eq!(WorkerRequest::JsTicks { id: 9, source: String::new(), arg: 0, runs: 0 }.id(), 9);
+}
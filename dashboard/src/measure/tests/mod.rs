@@ -0,0 +1,17 @@
+// what: exports all test modules for measurement statistics
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod statistics;
+
+#[cfg(test)]
+mod timing;
+
+#[cfg(test)]
+mod worker_protocol;
+
+#[cfg(test)]
+mod jitter;
+
+#[cfg(test)]
+mod binary;
@@ -0,0 +1,75 @@
+// what: tests for warm-up discard, outlier rejection and confidence intervals
+// why: the headline speedup numbers are only as honest as these helpers
+
+use crate::measure::stats::{mad, median, reject_outliers, summarize, Summary};
+
+#[test]
+fn median_handles_odd_even_and_empty() {
+    // what: middle value, mean of the two middles, zero for no samples
+    // why: median is the reported number everywhere
+    assert_eq!(median(&[3.0, 1.0, 2.0]), 2.0);
+    assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), 2.5);
+    assert_eq!(median(&[]), 0.0);
+    assert_eq!(mad(&[1.0, 2.0, 3.0, 4.0, 100.0], 3.0), 1.0);
+}
+
+#[test]
+fn gc_spike_is_rejected() {
+    // what: a single sample far from the rest is dropped
+    // why: one gc pause must not double the reported instantiate time
+    let samples = [0.05, 0.051, 0.049, 0.052, 0.05, 0.048, 2.5];
+    let (kept, rejected) = reject_outliers(&samples);
+    assert_eq!(rejected, 1);
+    assert!(kept.iter().all(|s| *s < 1.0));
+}
+
+#[test]
+fn identical_samples_keep_everything() {
+    // what: zero mad (coarse timer) keeps all samples and gives zero ci
+    // why: avoids dividing by zero when performance.now() is clamped
+    let s = summarize(&[0.1; 8], 0);
+    assert_eq!(s.kept, 8);
+    assert_eq!(s.rejected, 0);
+    assert!(s.ci95 < 1e-12);
+}
+
+#[test]
+fn warmup_runs_are_discarded() {
+    // what: the first runs are excluded before statistics
+    // why: first instantiation includes compilation tiering and cache misses
+    let s = summarize(&[9.0, 9.0, 1.0, 1.0, 1.0], 2);
+    assert_eq!(s.kept, 3);
+    assert_eq!(s.median, 1.0);
+    // fewer samples than warm-up falls back to using all of them
+    assert_eq!(summarize(&[5.0], 2).median, 5.0);
+}
+
+#[test]
+fn ci_shrinks_with_more_samples() {
+    // what: the same spread over more samples yields a tighter interval
+    // why: users raise benchmark iterations in settings to get tighter numbers
+    let few = summarize(&[1.0, 2.0, 3.0], 0);
+    let many: Vec<f64> = (0..30).map(|i| 1.0 + (i % 3) as f64).collect();
+    assert!(summarize(&many, 0).ci95 < few.ci95);
+}
+
+#[test]
+fn display_includes_ci_only_with_spread() {
+    // what: single samples show a bare value, multiple show ±ci
+    // why: a ±0 next to a single measurement would overstate confidence
+    let single = Summary { median: 1.5, kept: 1, ..Default::default() };
+    assert_eq!(single.display(2), "1.50ms");
+    let many = Summary { median: 0.04, ci95: 0.003, kept: 8, ..Default::default() };
+    assert_eq!(many.display(3), "0.040 ± 0.003ms");
+}
+
+#[test]
+fn ci_is_for_the_median() {
+    // what: the interval is the mean's t interval widened by sqrt(pi / 2), the median's larger standard error
+    // why: display() prints median ± ci95, so a mean-sized interval would overstate how well the median is known
+    let samples = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let s = summarize(&samples, 0);
+    let sd = (10.0f64 / 4.0).sqrt();
+    let mean_half_width = 2.776 * sd / 5f64.sqrt();
+    assert!((s.ci95 / mean_half_width - (std::f64::consts::PI / 2.0).sqrt()).abs() < 1e-3, "{}", s.ci95);
+}
@@ -0,0 +1,32 @@
+// what: tests for timer resolution detection and window counting math
+// why: picking the wrong method silently reports 0ms or 0.1ms for every wasm timing
+
+use crate::measure::timing::{method_for, min_tick, per_op_ms, window_count, Method, LOW_RESOLUTION_MS};
+
+#[test]
+fn min_tick_ignores_repeated_readings() {
+    // what: smallest positive step between readings, zero steps skipped
+    // why: a coarse clock returns the same value many times between ticks
+    assert_eq!(min_tick(&[0.0, 0.0, 0.5, 0.5, 1.0, 1.25]), 0.25);
+    assert_eq!(min_tick(&[5.0, 5.0]), f64::INFINITY);
+}
+
+#[test]
+fn coarse_timer_switches_to_window_counting() {
+    // what: resolutions above the threshold use window counting
+    // why: 100µs clamping (no coop/coep) cannot resolve a 40µs instantiate
+    assert_eq!(method_for(0.005), Method::PerIteration);
+    assert_eq!(method_for(LOW_RESOLUTION_MS), Method::PerIteration);
+    assert_eq!(method_for(0.1), Method::WindowCount);
+    assert_eq!(method_for(f64::INFINITY), Method::WindowCount);
+}
+
+#[test]
+fn window_math_is_bounded() {
+    // what: per-op time divides by count, window count stays in range
+    // why: a zero count must not divide by zero, huge iteration settings must not block the tab
+    assert_eq!(per_op_ms(1000, 50.0), 0.05);
+    assert_eq!(per_op_ms(0, 50.0), 50.0);
+    assert_eq!(window_count(1), 3);
+    assert_eq!(window_count(1000), 10);
+}
@@ -0,0 +1,34 @@
+// what: tests for the measurement worker message protocol
+// why: the inline worker script matches on these exact json shapes
+
+use crate::measure::protocol::{decode, encode, WorkerRequest, WorkerResponse};
+
+#[test]
+fn requests_are_tagged_by_kind() {
+    // what: request json carries a snake_case kind tag next to the fields
+    // why: the worker dispatches on req.kind
+    let json = encode(&WorkerRequest::Instantiate { id: 3, module: vec![0, 97], runs: 12, window_ms: None });
+    assert_eq!(json, r#"{"kind":"instantiate","id":3,"module":[0,97],"runs":12,"window_ms":null}"#);
+    let json = encode(&WorkerRequest::CallThroughput { id: 4, module: vec![], export: "add".into(), runs: 5, window_ms: 50.0 });
+    assert!(json.starts_with(r#"{"kind":"call_throughput","id":4"#));
+}
+
+#[test]
+fn responses_decode_samples_and_failures() {
+    // what: worker replies decode into samples or an error message
+    // why: a failed compile must surface instead of hanging the measurement
+    let ok = decode(r#"{"samples":{"id":7,"samples":[0.04,0.05]}}"#).unwrap();
+    assert_eq!(ok.id(), 7);
+    assert_eq!(ok.into_samples(), Ok(vec![0.04, 0.05]));
+    let failed = decode(r#"{"failed":{"id":8,"message":"CompileError"}}"#).unwrap();
+    assert_eq!(failed, WorkerResponse::Failed { id: 8, message: "CompileError".into() });
+    assert!(decode("garbage").is_err());
+}
+
+#[test]
+fn request_ids_round_trip() {
+    // what: id() reads the correlation id from either variant
+    // why: replies are matched to pending promises by id
+    let req = WorkerRequest::CallThroughput { id: 42, module: vec![], export: "add".into(), runs: 1, window_ms: 1.0 };
+    assert_eq!(req.id(), 42);
+}
@@ -0,0 +1,119 @@
+// what: timer resolution detection and a sampling loop that adapts to it
+// why: without cross-origin isolation performance.now() is coarsened (~100µs), too blunt for sub-ms wasm timings
+// relations: uses stats.rs, used by demo/wasm.rs and proof/component.rs, reported by banner.rs
+
+use std::cell::Cell;
+use std::future::Future;
+use wasm_bindgen::JsValue;
+use super::stats::{summarize, Summary, WARMUP_RUNS};
+
+/// resolution above which per-iteration timing is replaced by window counting
+pub const LOW_RESOLUTION_MS: f64 = 0.02;
+/// length of one counting window
+pub const WINDOW_MS: f64 = 50.0;
+/// counting windows per measurement (bounds)
+const MIN_WINDOWS: u32 = 3;
+const MAX_WINDOWS: u32 = 10;
+/// timer ticks observed when probing resolution
+const PROBE_TICKS: usize = 10;
+
+thread_local! {
+    static RESOLUTION_MS: Cell<Option<f64>> = const { Cell::new(None) };
+}
+
+/// how a summary was produced
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    /// each iteration timed individually
+    PerIteration,
+    /// iterations counted inside fixed windows (coarse timer)
+    WindowCount,
+}
+
+/// picks the method for a given timer resolution
+pub fn method_for(resolution_ms: f64) -> Method {
+    if resolution_ms > LOW_RESOLUTION_MS { Method::WindowCount } else { Method::PerIteration }
+}
+
+/// smallest positive step between consecutive timer readings
+pub fn min_tick(readings: &[f64]) -> f64 {
+    readings
+        .windows(2)
+        .map(|w| w[1] - w[0])
+        .filter(|d| *d > 0.0)
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// per-operation time from one counting window
+pub fn per_op_ms(count: u32, elapsed_ms: f64) -> f64 {
+    if count == 0 { elapsed_ms } else { elapsed_ms / count as f64 }
+}
+
+/// counting windows used for a requested iteration count
+pub fn window_count(iterations: u32) -> u32 {
+    (iterations / 2).clamp(MIN_WINDOWS, MAX_WINDOWS)
+}
+
+fn now() -> f64 {
+    web_sys::window().and_then(|w| w.performance()).map(|p| p.now()).unwrap_or(0.0)
+}
+
+/// true when the page is cross-origin isolated (coop + coep headers)
+pub fn cross_origin_isolated() -> bool {
+    web_sys::window()
+        .and_then(|w| js_sys::Reflect::get(&w, &JsValue::from_str("crossOriginIsolated")).ok())
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// observed performance.now() resolution in ms (probed once, then cached)
+pub fn timer_resolution_ms() -> f64 {
+    if let Some(cached) = RESOLUTION_MS.with(|c| c.get()) {
+        return cached;
+    }
+    let mut readings = vec![now()];
+    while readings.len() <= PROBE_TICKS {
+        let last = *readings.last().unwrap_or(&0.0);
+        let mut t = now();
+        // spin until the clock ticks (bounded in case the clock is frozen)
+        for _ in 0..1_000_000 {
+            if t != last { break; }
+            t = now();
+        }
+        readings.push(t);
+    }
+    let resolution = min_tick(&readings);
+    RESOLUTION_MS.with(|c| c.set(Some(resolution)));
+    resolution
+}
+
+/// times an async operation, per iteration or by window counting depending on the timer
+pub async fn sample<F, Fut>(iterations: u32, mut op: F) -> (Summary, Method)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let method = method_for(timer_resolution_ms());
+    let mut samples = Vec::new();
+    match method {
+        Method::PerIteration => {
+            for _ in 0..iterations.max(1) as usize + WARMUP_RUNS {
+                let start = now();
+                op().await;
+                samples.push(now() - start);
+            }
+        }
+        Method::WindowCount => {
+            for _ in 0..window_count(iterations) as usize + WARMUP_RUNS {
+                let start = now();
+                let mut count = 0;
+                while now() - start < WINDOW_MS {
+                    op().await;
+                    count += 1;
+                }
+                samples.push(per_op_ms(count, now() - start));
+            }
+        }
+    }
+    (summarize(&samples, WARMUP_RUNS), method)
+}
@@ -0,0 +1,242 @@
+// what: dedicated web worker that runs the timing loops off the main thread
+// why: leptos effects, rendering and pyodide on the main thread add noise to sub-ms numbers
+// relations: speaks protocol.rs, summarizes with stats.rs, picks windows via timing.rs,
+//            used by demo/wasm.rs and proof/component.rs (main-thread sample() is the fallback), traced under "worker"
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use tracing::Instrument;
+use super::protocol::{decode, encode, WorkerRequest};
+use super::stats::{summarize, Summary, WARMUP_RUNS};
+use super::timing::{method_for, timer_resolution_ms, window_count, Method, WINDOW_MS};
+use crate::tabs::demo::wasm::set_timeout;
+
+/// worker script, created from a blob so no extra asset needs to be served
+const WORKER_JS: &str = r#"
+const now = () => performance.now();
+// wasm-bindgen output imports glue functions; stubs let instantiation itself be timed without the js shim
+const stubImports = (module) => {
+    const imports = {};
+    for (const imp of WebAssembly.Module.imports(module)) {
+        if (imp.kind !== "function") continue;
+        (imports[imp.module] ??= {})[imp.name] = () => { throw new Error("stub import " + imp.name); };
+    }
+    return imports;
+};
+self.onmessage = async (event) => {
+    const req = JSON.parse(event.data);
+    const id = req.id;
+    try {
+        const module = req.module ? await WebAssembly.compile(new Uint8Array(req.module)) : null;
+        const samples = [];
+        if (req.kind === "instantiate") {
+            const imports = stubImports(module);
+            for (let r = 0; r < req.runs; r++) {
+                if (req.window_ms) {
+                    const start = now();
+                    let count = 0;
+                    while (now() - start < req.window_ms) { await WebAssembly.instantiate(module, imports); count++; }
+                    samples.push((now() - start) / Math.max(count, 1));
+                } else {
+                    const start = now();
+                    await WebAssembly.instantiate(module, imports);
+                    samples.push(now() - start);
+                }
+            }
+        } else if (req.kind === "call_throughput") {
+            const instance = await WebAssembly.instantiate(module, {});
+            const f = instance.exports[req.export];
+            if (typeof f !== "function") throw new Error("missing export " + req.export);
+            let sink = 0;
+            for (let r = 0; r < req.runs; r++) {
+                const start = now();
+                let count = 0;
+                while (now() - start < req.window_ms) {
+                    for (let i = 0; i < 1000; i++) sink = f(sink, i);
+                    count += 1000;
+                }
+                samples.push((now() - start) / count);
+            }
+        } else if (req.kind === "wasm_ticks" || req.kind === "js_ticks") {
+            let f;
+            if (req.kind === "wasm_ticks") {
+                const instance = await WebAssembly.instantiate(module, {});
+                f = instance.exports[req.export];
+            } else {
+                f = new Function(req.source)();
+            }
+            if (typeof f !== "function") throw new Error("missing tick function");
+            let sink = 0;
+            for (let r = 0; r < req.runs; r++) {
+                const start = now();
+                sink ^= f(req.arg);
+                samples.push(now() - start);
+            }
+            // keep the results observable so the calls can't be optimized away
+            if (sink === 0.5) samples.push(0);
+        }
+        self.postMessage(JSON.stringify({ samples: { id, samples } }));
+    } catch (err) {
+        self.postMessage(JSON.stringify({ failed: { id, message: String(err) } }));
+    }
+};
+"#;
+
+/// longest a job may run before the worker is given up on (the longest real job is a few seconds)
+const JOB_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+thread_local! {
+    static WORKER: RefCell<Option<web_sys::Worker>> = const { RefCell::new(None) };
+    /// resolve and reject of each job still waiting on a reply
+    static PENDING: RefCell<HashMap<u32, (js_sys::Function, js_sys::Function)>> = RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<u32> = const { Cell::new(1) };
+    static UNAVAILABLE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// spawns the worker on first use
+fn worker() -> Option<web_sys::Worker> {
+    if UNAVAILABLE.with(|u| u.get()) {
+        return None;
+    }
+    if let Some(w) = WORKER.with(|w| w.borrow().clone()) {
+        return Some(w);
+    }
+    let spawned = spawn();
+    match &spawned {
+        Some(w) => WORKER.with(|slot| *slot.borrow_mut() = Some(w.clone())),
+        None => UNAVAILABLE.with(|u| u.set(true)),
+    }
+    spawned
+}
+
+fn spawn() -> Option<web_sys::Worker> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(WORKER_JS));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("application/javascript");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options).ok()?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).ok()?;
+    let worker = web_sys::Worker::new(&url).ok()?;
+
+    // route each reply to the promise waiting on its id
+    let on_message = Closure::<dyn FnMut(web_sys::MessageEvent)>::new(|event: web_sys::MessageEvent| {
+        let Some(text) = event.data().as_string() else { return };
+        let Ok(response) = decode(&text) else { return };
+        if let Some((resolve, _)) = PENDING.with(|p| p.borrow_mut().remove(&response.id())) {
+            let _ = resolve.call1(&JsValue::NULL, &JsValue::from_str(&text));
+        }
+    });
+    worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    on_message.forget();
+
+    // a script that fails to load (blob urls blocked by csp) or a reply that can't be cloned never answers
+    let on_error = Closure::<dyn FnMut(JsValue)>::new(|_| give_up("worker failed to load or crashed"));
+    worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+    on_error.forget();
+    let on_message_error = Closure::<dyn FnMut(JsValue)>::new(|_| give_up("worker reply could not be read"));
+    worker.set_onmessageerror(Some(on_message_error.as_ref().unchecked_ref()));
+    on_message_error.forget();
+    Some(worker)
+}
+
+/// drops the worker for the rest of the session and fails every waiting job, so callers fall back to the main thread
+fn give_up(reason: &str) {
+    tracing::warn!(target: "worker", reason, "worker unavailable");
+    UNAVAILABLE.with(|u| u.set(true));
+    if let Some(w) = WORKER.with(|w| w.borrow_mut().take()) {
+        w.terminate();
+    }
+    let pending: Vec<_> = PENDING.with(|p| p.borrow_mut().drain().collect());
+    for (_, (_, reject)) in pending {
+        let _ = reject.call1(&JsValue::NULL, &JsValue::from_str(reason));
+    }
+}
+
+/// true once a worker could be created (or before the first attempt)
+pub fn worker_available() -> bool {
+    !UNAVAILABLE.with(|u| u.get())
+}
+
+/// posts a job and waits for its samples
+async fn run(make: impl FnOnce(u32) -> WorkerRequest) -> Result<Vec<f64>, String> {
+    let worker = worker().ok_or("web workers unavailable")?;
+    let id = NEXT_ID.with(|n| {
+        let id = n.get();
+        n.set(id.wrapping_add(1));
+        id
+    });
+    let request = make(id);
+    let span = tracing::debug_span!(target: "worker", "job", id, kind = request.kind());
+    async move {
+        let id = request.id();
+        let promise = js_sys::Promise::new(&mut |resolve, reject| {
+            PENDING.with(|p| p.borrow_mut().insert(id, (resolve, reject)));
+        });
+        tracing::debug!(target: "worker", "postMessage");
+        if let Err(e) = worker.post_message(&JsValue::from_str(&encode(&request))) {
+            PENDING.with(|p| p.borrow_mut().remove(&id));
+            return Err(format!("{:?}", e));
+        }
+        // a job still waiting after the timeout means the worker is stuck in a loop; later jobs would queue behind it
+        set_timeout(
+            move || {
+                if PENDING.with(|p| p.borrow().contains_key(&id)) {
+                    give_up("worker job timed out");
+                }
+            },
+            JOB_TIMEOUT,
+        );
+        let reply = wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map_err(|e| e.as_string().unwrap_or_else(|| format!("{:?}", e)))?;
+        let samples = decode(&reply.as_string().unwrap_or_default())?.into_samples();
+        match &samples {
+            Ok(s) => tracing::debug!(target: "worker", samples = s.len(), "reply"),
+            Err(e) => tracing::warn!(target: "worker", error = %e, "job failed"),
+        }
+        samples
+    }
+    .instrument(span)
+    .await
+}
+
+/// instantiation timing in the worker, same method selection as the main thread
+pub async fn instantiate(module: &[u8], iterations: u32) -> Result<(Summary, Method), String> {
+    let method = method_for(timer_resolution_ms());
+    let (runs, window_ms) = match method {
+        Method::PerIteration => (iterations.max(1), None),
+        Method::WindowCount => (window_count(iterations), Some(WINDOW_MS)),
+    };
+    let samples = run(|id| WorkerRequest::Instantiate {
+        id,
+        module: module.to_vec(),
+        runs: runs + WARMUP_RUNS as u32,
+        window_ms,
+    })
+    .await?;
+    Ok((summarize(&samples, WARMUP_RUNS), method))
+}
+
+/// raw per-tick latencies of an exported (i32) -> i32 function (no outlier rejection)
+pub async fn wasm_ticks(module: &[u8], export: &str, arg: i32, runs: u32) -> Result<Vec<f64>, String> {
+    run(|id| WorkerRequest::WasmTicks { id, module: module.to_vec(), export: export.to_string(), arg, runs }).await
+}
+
+/// raw per-tick latencies of a js function returned by `source`
+pub async fn js_ticks(source: &str, arg: i32, runs: u32) -> Result<Vec<f64>, String> {
+    run(|id| WorkerRequest::JsTicks { id, source: source.to_string(), arg, runs }).await
+}
+
+/// per-call time of an exported (i32, i32) -> i32 function, counted per window
+pub async fn call_throughput(module: &[u8], export: &str, iterations: u32) -> Result<Summary, String> {
+    let samples = run(|id| WorkerRequest::CallThroughput {
+        id,
+        module: module.to_vec(),
+        export: export.to_string(),
+        runs: window_count(iterations) + WARMUP_RUNS as u32,
+        window_ms: WINDOW_MS,
+    })
+    .await?;
+    Ok(summarize(&samples, WARMUP_RUNS))
+}
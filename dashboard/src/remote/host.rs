@@ -0,0 +1,264 @@
+// what: laptop side of the remote - header button, pairing modal (offer qr, answer paste), runs phone commands and streams events back
+// why: the presenter pairs once at the start of a talk; from then on the phone's buttons act exactly like the guardianDemo js api
+// relations: signals provided by lib.rs App, webrtc from peer.rs, commands through headless/ GuardianDemo, qr from handoff/ QrImage;
+//            closes in kiosk attract mode like settings/panel.rs
+
+use std::cell::RefCell;
+use leptos::*;
+use wasm_bindgen::prelude::*;
+use web_sys::{MessageEvent, RtcDataChannel, RtcDataChannelState, RtcPeerConnection};
+use crate::handoff::{qr::QrCode, QrImage};
+use crate::headless::GuardianDemo;
+use crate::kiosk::use_kiosk;
+use super::{event_line, peer, push_line, LinkState, Pairing, RemoteCommand, RemoteMessage, CHANNEL_LABEL};
+
+thread_local! {
+    /// the one pairing in progress or open
+    static LINK: RefCell<Option<(RtcPeerConnection, RtcDataChannel)>> = const { RefCell::new(None) };
+    /// guardianDemo.on() id forwarding events to the phone while connected
+    static LISTENER: RefCell<Option<u32>> = const { RefCell::new(None) };
+}
+
+/// remote signals provided by App
+#[derive(Clone, Copy)]
+pub struct RemoteHost {
+    pub state: RwSignal<LinkState>,
+    /// link the phone opens (shown as a qr code)
+    pub offer: RwSignal<Option<String>>,
+    /// commands the phone sent, newest last
+    pub log: RwSignal<Vec<String>>,
+    pub error: RwSignal<Option<String>>,
+}
+
+impl RemoteHost {
+    pub fn new() -> Self {
+        Self {
+            state: create_rw_signal(LinkState::Idle),
+            offer: create_rw_signal(None),
+            log: create_rw_signal(Vec::new()),
+            error: create_rw_signal(None),
+        }
+    }
+
+    /// drops any previous link and prepares a fresh offer
+    pub fn pair(self) {
+        self.disconnect();
+        self.error.set(None);
+        self.state.set(LinkState::Preparing);
+        let pc = match peer::connection() {
+            Ok(pc) => pc,
+            Err(e) => return self.fail(e),
+        };
+        // the channel has to exist before the offer, or the offer has nothing to negotiate
+        let channel = pc.create_data_channel(CHANNEL_LABEL);
+        self.wire(&channel);
+        LINK.with(|l| *l.borrow_mut() = Some((pc.clone(), channel)));
+        spawn_local(async move {
+            match peer::offer(&pc).await {
+                Ok(pairing) => {
+                    let base = web_sys::window().and_then(|w| w.location().href().ok()).unwrap_or_default();
+                    self.offer.set(Some(pairing.link(&base)));
+                    self.state.set(LinkState::Waiting);
+                }
+                Err(e) => self.fail(e),
+            }
+        });
+    }
+
+    /// the answer code the phone shows, pasted back
+    pub fn connect(self, code: &str) {
+        let Some(pc) = LINK.with(|l| l.borrow().as_ref().map(|(pc, _)| pc.clone())) else {
+            return self.fail("press Pair first".to_string());
+        };
+        let answer = match Pairing::decode(code) {
+            Ok(answer) => answer,
+            Err(e) => return self.error.set(Some(e)),
+        };
+        self.error.set(None);
+        spawn_local(async move {
+            if let Err(e) = peer::accept_answer(&pc, &answer).await {
+                self.error.set(Some(e));
+            }
+        });
+    }
+
+    pub fn disconnect(self) {
+        stop_forwarding();
+        if let Some((pc, channel)) = LINK.with(|l| l.borrow_mut().take()) {
+            channel.set_onopen(None);
+            channel.set_onmessage(None);
+            channel.set_onclose(None);
+            channel.close();
+            pc.close();
+        }
+        self.offer.set(None);
+        self.state.set(LinkState::Idle);
+    }
+
+    fn fail(self, error: String) {
+        tracing::warn!(target: "remote", error = %error, "pairing failed");
+        self.error.set(Some(error));
+        self.state.set(LinkState::Idle);
+    }
+
+    fn wire(self, channel: &RtcDataChannel) {
+        let on_open = Closure::<dyn FnMut()>::new(move || {
+            self.state.set(LinkState::Connected);
+            self.offer.set(None);
+            start_forwarding();
+        });
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let Some(text) = event.data().as_string() else { return };
+            let reply = match RemoteCommand::from_json(&text) {
+                Ok(command) => {
+                    let result = dispatch(&command);
+                    let line = match &result {
+                        Ok(()) => format!("📱 {}", command.label()),
+                        Err(e) => format!("📱 {} — ❌ {}", command.label(), e),
+                    };
+                    self.log.update(|l| push_line(l, line));
+                    match result {
+                        Ok(()) => RemoteMessage::Reply { ok: true, text: command.label() },
+                        Err(e) => RemoteMessage::Reply { ok: false, text: e },
+                    }
+                }
+                Err(e) => RemoteMessage::Reply { ok: false, text: format!("unknown command: {}", e) },
+            };
+            send(&reply);
+        });
+        let on_close = Closure::<dyn FnMut()>::new(move || {
+            stop_forwarding();
+            self.state.set(LinkState::Closed);
+        });
+        channel.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        channel.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        channel.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        on_open.forget();
+        on_message.forget();
+        on_close.forget();
+    }
+}
+
+/// a phone command, run exactly as the js api would run it
+fn dispatch(command: &RemoteCommand) -> Result<(), String> {
+    let api = GuardianDemo;
+    let result = match command {
+        RemoteCommand::RunAttack { attack } => api.run_attack(attack),
+        RemoteCommand::RunAll => api.run_all(),
+        RemoteCommand::NextTab => api.next_tab(),
+        RemoteCommand::Reset => api.reset(),
+    };
+    result.map_err(|e| e.as_string().unwrap_or_else(|| format!("{:?}", e)))
+}
+
+fn send(message: &RemoteMessage) {
+    LINK.with(|l| {
+        if let Some((_, channel)) = l.borrow().as_ref().filter(|(_, c)| c.ready_state() == RtcDataChannelState::Open) {
+            if let Err(e) = channel.send_with_str(&message.to_json()) {
+                tracing::warn!(target: "remote", error = ?e, "data channel send failed");
+            }
+        }
+    });
+}
+
+/// subscribes to the headless api and relays each event line to the phone
+fn start_forwarding() {
+    stop_forwarding();
+    let listener = Closure::<dyn FnMut(JsValue)>::new(|message: JsValue| {
+        let Some(json) = js_sys::JSON::stringify(&message).ok().and_then(|s| s.as_string()) else { return };
+        let Ok(value) = serde_json::from_str(&json) else { return };
+        if let Some(text) = event_line(&value) {
+            send(&RemoteMessage::Event { text });
+        }
+    });
+    let id = GuardianDemo.on(listener.into_js_value().unchecked_into());
+    LISTENER.with(|l| *l.borrow_mut() = Some(id));
+}
+
+fn stop_forwarding() {
+    if let Some(id) = LISTENER.with(|l| l.borrow_mut().take()) {
+        GuardianDemo.off(id);
+    }
+}
+
+/// remote signals provided by App (an unpaired host outside the app)
+pub fn use_remote_host() -> RemoteHost {
+    use_context::<RemoteHost>().unwrap_or_else(RemoteHost::new)
+}
+
+#[component]
+pub fn RemoteButton() -> impl IntoView {
+    let host = use_remote_host();
+    let kiosk = use_kiosk();
+    let (open, set_open) = create_signal(false);
+    create_effect(move |_| if kiosk.attract.get() { set_open.set(false) });
+    let (answer, set_answer) = create_signal(String::new());
+
+    let qr = move || {
+        host.offer.get().map(|link| match QrCode::encode(link.as_bytes()) {
+            Ok(code) => view! {
+                <QrImage code label="QR code that opens this dashboard as a remote" />
+                <p class="metrics-note handoff-link">{link}</p>
+            }.into_view(),
+            Err(e) => view! { <p class="audit-status">"❌ " {e}</p> }.into_view(),
+        })
+    };
+
+    view! {
+        <button
+            class="settings-btn remote-btn"
+            class:active=move || host.state.get() == LinkState::Connected
+            title="Phone remote"
+            on:click=move |_| set_open.set(true)
+        >"📱"</button>
+        <Show when=move || open.get()>
+            <div class="modal-overlay" on:click=move |_| set_open.set(false)>
+                <div class="modal-content settings-modal" on:click=|e: web_sys::MouseEvent| e.stop_propagation()>
+                    <div class="modal-header">
+                        <span class="modal-title">"📱 Phone Remote"</span>
+                        <button class="modal-close" on:click=move |_| set_open.set(false)>"×"</button>
+                    </div>
+                    <fieldset class="settings-group">
+                        <legend>{move || host.state.get().label()}</legend>
+                        <p class="section-desc">
+                            "Scan the code with a phone on the same Wi-Fi, then paste the code the phone shows below. "
+                            "The phone gets Run attack, Run all, Next tab and Reset buttons; commands go straight to this tab over a WebRTC data channel, with no server in between."
+                        </p>
+                        <div class="audit-controls">
+                            <button class="action-btn" disabled=move || host.state.get() == LinkState::Preparing on:click=move |_| {
+                                set_answer.set(String::new());
+                                host.pair();
+                            }>
+                                {move || if host.state.get() == LinkState::Idle { "🔗 Pair a Phone" } else { "🔗 Pair Again" }}
+                            </button>
+                            <Show when=move || matches!(host.state.get(), LinkState::Waiting | LinkState::Connected)>
+                                <button class="action-btn" on:click=move |_| host.disconnect()>"⏏ Disconnect"</button>
+                            </Show>
+                        </div>
+                        {qr}
+                        <Show when=move || host.state.get() == LinkState::Waiting>
+                            <textarea
+                                class="policy-editor"
+                                placeholder="Paste the code the phone shows (R1!a!...)"
+                                prop:value=move || answer.get()
+                                on:input=move |ev| set_answer.set(event_target_value(&ev))
+                            />
+                            <div class="audit-controls">
+                                <button class="action-btn" disabled=move || answer.with(|a| a.trim().is_empty()) on:click=move |_| host.connect(&answer.get_untracked())>
+                                    "✅ Connect"
+                                </button>
+                            </div>
+                        </Show>
+                        {move || host.error.get().map(|e| view! { <p class="audit-status">"❌ " {e}</p> })}
+                        <Show when=move || host.log.with(|l| !l.is_empty())>
+                            <ul class="remote-log">
+                                {move || host.log.get().into_iter().map(|line| view! { <li>{line}</li> }).collect_view()}
+                            </ul>
+                        </Show>
+                        <p class="metrics-note">"Only local network addresses are exchanged, so both devices need to reach each other directly (guest Wi-Fi with client isolation will not work)."</p>
+                    </fieldset>
+                </div>
+            </div>
+        </Show>
+    }
+}
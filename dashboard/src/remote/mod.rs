@@ -0,0 +1,290 @@
+// what: phone remote control - qr pairing that carries a compacted webrtc offer, the answer pasted back, commands over a data channel
+// why: a presenter walking the room drives the demo from a phone; without a signalling server the two sdp blobs travel by qr and paste
+// relations: webrtc glue in peer.rs, laptop side in host.rs (header button, commands run through headless/ GuardianDemo), phone side in pad.rs
+//            (rendered by lib.rs App when the page opens with #remote=); qr drawing from handoff/qr.rs
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+mod host;
+mod pad;
+mod peer;
+
+#[cfg(test)]
+mod tests;
+
+pub use host::{RemoteButton, RemoteHost};
+pub use pad::RemotePad;
+
+/// first field of every pairing code (bumped with the layout)
+pub const PAIRING_TAG: &str = "R1";
+/// url fragment carrying an offer: https://host/#remote=R1!o!...
+pub const FRAGMENT_KEY: &str = "remote=";
+/// label of the one data channel
+pub const CHANNEL_LABEL: &str = "guardian-remote";
+/// host candidates kept per side (each costs ~45 characters of qr)
+pub const MAX_CANDIDATES: usize = 2;
+/// how long ice gathering may take before the candidates found so far are used
+pub const GATHER_TIMEOUT_MS: u32 = 3000;
+/// sctp port both sides announce for the data channel
+const SCTP_PORT: u16 = 5000;
+
+/// commands and events kept in the host and pad logs
+pub const LOG_LINES: usize = 8;
+
+/// where a remote link stands, on either side
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LinkState {
+    #[default]
+    Idle,
+    /// gathering candidates for this side's code
+    Preparing,
+    /// this side's code is out, waiting for the other side
+    Waiting,
+    Connected,
+    Closed,
+}
+
+impl LinkState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LinkState::Idle => "Not paired",
+            LinkState::Preparing => "⏳ Finding this device on the network…",
+            LinkState::Waiting => "⌛ Waiting for the other device",
+            LinkState::Connected => "🟢 Connected",
+            LinkState::Closed => "🔴 Disconnected",
+        }
+    }
+}
+
+/// appends a line, keeping the last LOG_LINES
+pub fn push_line(log: &mut Vec<String>, line: String) {
+    log.push(line);
+    let excess = log.len().saturating_sub(LOG_LINES);
+    log.drain(..excess);
+}
+
+// ============================================================================
+// pairing codes (compacted sdp)
+// ============================================================================
+
+/// which half of the handshake a code carries
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SdpKind {
+    Offer,
+    Answer,
+}
+
+impl SdpKind {
+    fn code(&self) -> &'static str {
+        match self {
+            SdpKind::Offer => "o",
+            SdpKind::Answer => "a",
+        }
+    }
+
+    /// dtls role: the offer leaves it open, the answer takes the client side
+    fn setup(&self) -> &'static str {
+        match self {
+            SdpKind::Offer => "actpass",
+            SdpKind::Answer => "active",
+        }
+    }
+}
+
+/// the parts of a data-channel-only session description that differ between peers
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pairing {
+    pub kind: SdpKind,
+    pub ufrag: String,
+    pub pwd: String,
+    /// sha-256 of the dtls certificate
+    pub fingerprint: Vec<u8>,
+    /// udp host candidates as (address, port)
+    pub candidates: Vec<(String, u16)>,
+}
+
+impl Pairing {
+    /// picks ice credentials, the sha-256 fingerprint and up to MAX_CANDIDATES udp host candidates out of a browser's sdp
+    pub fn from_sdp(kind: SdpKind, sdp: &str) -> Result<Self, String> {
+        let attribute = |name: &str| sdp.lines().find_map(|l| l.trim().strip_prefix("a=")?.strip_prefix(name)).map(str::trim);
+        let ufrag = attribute("ice-ufrag:").ok_or("sdp has no ice-ufrag")?.to_string();
+        let pwd = attribute("ice-pwd:").ok_or("sdp has no ice-pwd")?.to_string();
+        let fingerprint = attribute("fingerprint:sha-256 ")
+            .ok_or("sdp has no sha-256 fingerprint")?
+            .split(':')
+            .map(|b| u8::from_str_radix(b, 16).map_err(|_| format!("bad fingerprint byte '{}'", b)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let candidates = sdp
+            .lines()
+            .filter_map(|l| l.trim().strip_prefix("a=candidate:"))
+            .filter_map(|c| {
+                // foundation component protocol priority address port typ type ...
+                let f: Vec<&str> = c.split_whitespace().collect();
+                let host = f.len() >= 8 && f[1] == "1" && f[2].eq_ignore_ascii_case("udp") && f[6] == "typ" && f[7] == "host";
+                host.then(|| Some((f[4].to_string(), f[5].parse().ok()?))).flatten()
+            })
+            .take(MAX_CANDIDATES)
+            .collect();
+        Ok(Self { kind, ufrag, pwd, fingerprint, candidates })
+    }
+
+    /// a session description the browser accepts for this peer: one bundled data-channel section
+    pub fn to_sdp(&self) -> String {
+        let fingerprint = self.fingerprint.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(":");
+        let mut lines = vec![
+            "v=0".to_string(),
+            "o=- 1 2 IN IP4 127.0.0.1".to_string(),
+            "s=-".to_string(),
+            "t=0 0".to_string(),
+            "a=group:BUNDLE 0".to_string(),
+            "m=application 9 UDP/DTLS/SCTP webrtc-datachannel".to_string(),
+            "c=IN IP4 0.0.0.0".to_string(),
+        ];
+        for (i, (address, port)) in self.candidates.iter().enumerate() {
+            lines.push(format!("a=candidate:{} 1 udp {} {} {} typ host", i + 1, 2_122_260_223 - i as u32, address, port));
+        }
+        lines.extend([
+            "a=end-of-candidates".to_string(),
+            format!("a=ice-ufrag:{}", self.ufrag),
+            format!("a=ice-pwd:{}", self.pwd),
+            format!("a=fingerprint:sha-256 {}", fingerprint),
+            format!("a=setup:{}", self.kind.setup()),
+            "a=mid:0".to_string(),
+            format!("a=sctp-port:{}", SCTP_PORT),
+            "a=max-message-size:262144".to_string(),
+        ]);
+        lines.join("\r\n") + "\r\n"
+    }
+
+    /// "R1!o!ufrag!pwd!fingerprint!address~port!..." - ice credentials never contain '!', the fingerprint is base64url
+    pub fn encode(&self) -> String {
+        let mut fields = vec![PAIRING_TAG.to_string(), self.kind.code().to_string(), self.ufrag.clone(), self.pwd.clone(), base64url(&self.fingerprint)];
+        fields.extend(self.candidates.iter().map(|(address, port)| format!("{}~{}", address, port)));
+        fields.join("!")
+    }
+
+    /// accepts a bare code or a link carrying one in its fragment
+    pub fn decode(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        let code = text.split_once(FRAGMENT_KEY).map(|(_, c)| c).unwrap_or(text);
+        let mut fields = code.split('!');
+        if fields.next() != Some(PAIRING_TAG) {
+            return Err(format!("not a {} pairing code", PAIRING_TAG));
+        }
+        let kind = match fields.next() {
+            Some("o") => SdpKind::Offer,
+            Some("a") => SdpKind::Answer,
+            _ => return Err("pairing code is neither an offer nor an answer".to_string()),
+        };
+        let mut field = |name: &str| fields.next().filter(|f| !f.is_empty()).map(str::to_string).ok_or_else(|| format!("pairing code has no {}", name));
+        let (ufrag, pwd) = (field("ice-ufrag")?, field("ice-pwd")?);
+        let fingerprint = from_base64url(&field("fingerprint")?).filter(|f| f.len() == 32).ok_or("pairing code has a bad fingerprint")?;
+        let candidates = fields
+            .map(|c| {
+                let (address, port) = c.rsplit_once('~').ok_or_else(|| format!("bad candidate '{}'", c))?;
+                Ok((address.to_string(), port.parse().map_err(|_| format!("bad candidate port '{}'", port))?))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(Self { kind, ufrag, pwd, fingerprint, candidates })
+    }
+
+    /// link that opens the dashboard as a remote for this offer
+    pub fn link(&self, base: &str) -> String {
+        format!("{}#{}{}", base.split('#').next().unwrap_or(base), FRAGMENT_KEY, self.encode())
+    }
+}
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// unpadded base64url
+pub fn base64url(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            out.push(BASE64URL[(n >> (18 - 6 * i) & 63) as usize] as char);
+        }
+    }
+    out
+}
+
+/// inverse of base64url; None for characters outside the alphabet or a dangling sextet
+pub fn from_base64url(text: &str) -> Option<Vec<u8>> {
+    let sextets = text.bytes().map(|c| BASE64URL.iter().position(|a| *a == c).map(|p| p as u32)).collect::<Option<Vec<_>>>()?;
+    if sextets.len() % 4 == 1 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(sextets.len() * 3 / 4);
+    for chunk in sextets.chunks(4) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, s)| n | s << (18 - 6 * i));
+        out.extend((0..chunk.len() - 1).map(|i| (n >> (16 - 8 * i)) as u8));
+    }
+    Some(out)
+}
+
+// ============================================================================
+// data channel messages
+// ============================================================================
+
+/// phone → laptop
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "camelCase")]
+pub enum RemoteCommand {
+    RunAttack { attack: String },
+    RunAll,
+    NextTab,
+    Reset,
+}
+
+impl RemoteCommand {
+    pub fn label(&self) -> String {
+        match self {
+            RemoteCommand::RunAttack { attack } => format!("run {}", attack),
+            RemoteCommand::RunAll => "run all".to_string(),
+            RemoteCommand::NextTab => "next tab".to_string(),
+            RemoteCommand::Reset => "reset".to_string(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+}
+
+/// laptop → phone
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum RemoteMessage {
+    /// outcome of one command (the headless api's error text when it refused)
+    Reply { ok: bool, text: String },
+    /// a headless api event, as one line
+    Event { text: String },
+}
+
+impl RemoteMessage {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+}
+
+/// one line for a headless api message ("ready", "idle" or a journal "event"); None for anything else
+pub fn event_line(message: &Value) -> Option<String> {
+    match message["type"].as_str()? {
+        "ready" => Some("demo tab ready".to_string()),
+        "idle" => Some("✅ idle".to_string()),
+        "event" => {
+            let parts = [&message["kind"], &message["runtime"], &message["detail"]];
+            Some(parts.iter().filter_map(|p| p.as_str()).filter(|p| !p.is_empty()).collect::<Vec<_>>().join(" · "))
+        }
+        _ => None,
+    }
+}
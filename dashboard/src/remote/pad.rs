@@ -0,0 +1,124 @@
+// what: phone side of the remote - answers the offer from the #remote= link, shows the answer code, then the command buttons
+// why: the phone only needs a handful of big buttons and the laptop's replies, not the whole dashboard
+// relations: rendered by lib.rs App instead of the tabs when the page opens with #remote=, webrtc from peer.rs,
+//            attack list from headless/ attack_names (the same names host.rs passes to runAttack)
+
+use std::cell::RefCell;
+use leptos::*;
+use wasm_bindgen::prelude::*;
+use web_sys::{MessageEvent, RtcDataChannel, RtcDataChannelEvent, RtcDataChannelState, RtcPeerConnection};
+use crate::headless::attack_names;
+use crate::tabs::demo::attacks::get_attack_config;
+use crate::tabs::demo::wasm::copy_to_clipboard;
+use super::{peer, push_line, LinkState, Pairing, RemoteCommand, RemoteMessage};
+
+thread_local! {
+    /// kept alive for the page's lifetime (the connection closes when dropped by the browser)
+    static PEER: RefCell<Option<RtcPeerConnection>> = const { RefCell::new(None) };
+    /// the laptop's channel, once it arrives
+    static CHANNEL: RefCell<Option<RtcDataChannel>> = const { RefCell::new(None) };
+}
+
+fn send(command: &RemoteCommand) -> bool {
+    CHANNEL.with(|c| {
+        c.borrow()
+            .as_ref()
+            .filter(|c| c.ready_state() == RtcDataChannelState::Open)
+            .is_some_and(|c| c.send_with_str(&command.to_json()).is_ok())
+    })
+}
+
+/// starts answering the offer; the code appears in `answer`, replies and events in `log`
+fn start(offer: &str, state: RwSignal<LinkState>, answer: RwSignal<Option<String>>, log: RwSignal<Vec<String>>) -> Result<(), String> {
+    let offer = Pairing::decode(offer)?;
+    let pc = peer::connection()?;
+    let on_channel = Closure::<dyn FnMut(RtcDataChannelEvent)>::new(move |event: RtcDataChannelEvent| {
+        let channel = event.channel();
+        let on_open = Closure::<dyn FnMut()>::new(move || state.set(LinkState::Connected));
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let Some(message) = event.data().as_string().and_then(|t| RemoteMessage::from_json(&t).ok()) else { return };
+            let line = match message {
+                RemoteMessage::Reply { ok: true, text } => format!("✅ {}", text),
+                RemoteMessage::Reply { ok: false, text } => format!("❌ {}", text),
+                RemoteMessage::Event { text } => text,
+            };
+            log.update(|l| push_line(l, line));
+        });
+        let on_close = Closure::<dyn FnMut()>::new(move || state.set(LinkState::Closed));
+        channel.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        channel.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        channel.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        on_open.forget();
+        on_message.forget();
+        on_close.forget();
+        CHANNEL.with(|c| *c.borrow_mut() = Some(channel));
+    });
+    pc.set_ondatachannel(Some(on_channel.as_ref().unchecked_ref()));
+    on_channel.forget();
+    PEER.with(|p| *p.borrow_mut() = Some(pc.clone()));
+
+    state.set(LinkState::Preparing);
+    spawn_local(async move {
+        match peer::answer(&pc, &offer).await {
+            Ok(pairing) => {
+                answer.set(Some(pairing.encode()));
+                state.set(LinkState::Waiting);
+            }
+            Err(e) => {
+                log.update(|l| push_line(l, format!("❌ {}", e)));
+                state.set(LinkState::Idle);
+            }
+        }
+    });
+    Ok(())
+}
+
+#[component]
+pub fn RemotePad(offer: String) -> impl IntoView {
+    let state = create_rw_signal(LinkState::Idle);
+    let answer = create_rw_signal(Option::<String>::None);
+    let log = create_rw_signal(Vec::<String>::new());
+    let (attack, set_attack) = create_signal(attack_names()[0].to_string());
+    if let Err(e) = start(&offer, state, answer, log) {
+        log.update(|l| push_line(l, format!("❌ {}", e)));
+    }
+
+    let command = move |command: RemoteCommand| {
+        if !send(&command) {
+            log.update(|l| push_line(l, "❌ not connected".to_string()));
+        }
+    };
+    let connected = move || state.get() == LinkState::Connected;
+
+    view! {
+        <div class="remote-pad">
+            <h1>"📱 Guardian One Remote"</h1>
+            <p class="subtitle">{move || state.get().label()}</p>
+            <Show when=move || state.get() == LinkState::Waiting>
+                <p class="section-desc">"Paste this code into the laptop's 📱 Phone Remote window and press Connect."</p>
+                <p class="metrics-note handoff-link">{move || answer.get()}</p>
+                <button class="action-btn" on:click=move |_| {
+                    if let Some(code) = answer.get_untracked() {
+                        copy_to_clipboard(&code);
+                    }
+                }>"📋 Copy Code"</button>
+            </Show>
+            <Show when=connected>
+                <div class="remote-pad-controls">
+                    <select class="network-select" on:change=move |ev| set_attack.set(event_target_value(&ev))>
+                        {attack_names().into_iter().map(|a| view! {
+                            <option value=a selected=move || attack.get() == a>{get_attack_config(a).name}</option>
+                        }).collect_view()}
+                    </select>
+                    <button class="action-btn" on:click=move |_| command(RemoteCommand::RunAttack { attack: attack.get_untracked() })>"💥 Run Attack"</button>
+                    <button class="action-btn" on:click=move |_| command(RemoteCommand::RunAll)>"⚡ Run All"</button>
+                    <button class="action-btn" on:click=move |_| command(RemoteCommand::NextTab)>"➡ Next Tab"</button>
+                    <button class="action-btn" on:click=move |_| command(RemoteCommand::Reset)>"🔄 Reset"</button>
+                </div>
+            </Show>
+            <ul class="remote-log">
+                {move || log.get().into_iter().map(|line| view! { <li>{line}</li> }).collect_view()}
+            </ul>
+        </div>
+    }
+}
@@ -0,0 +1,79 @@
+// what: webrtc glue for the remote - offer and answer with ice gathering finished up front, so each side's sdp travels as one code
+// why: without a signalling server there is no trickle ice; a description is only shared once it lists its candidates
+// relations: codes from mod.rs Pairing, used by host.rs (offer, accept_answer) and pad.rs (answer)
+
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{RtcIceGatheringState, RtcPeerConnection, RtcSdpType, RtcSessionDescriptionInit};
+use super::{Pairing, SdpKind, GATHER_TIMEOUT_MS};
+
+/// poll interval while ice gathering runs
+const GATHER_POLL_MS: u32 = 100;
+
+fn js_error(e: JsValue) -> String {
+    e.dyn_ref::<js_sys::Error>().map(|e| String::from(e.message())).unwrap_or_else(|| format!("{:?}", e))
+}
+
+async fn wait(promise: js_sys::Promise) -> Result<JsValue, String> {
+    JsFuture::from(promise).await.map_err(js_error)
+}
+
+async fn sleep(ms: u32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms as i32);
+        }
+    });
+    let _ = JsFuture::from(promise).await;
+}
+
+pub fn connection() -> Result<RtcPeerConnection, String> {
+    RtcPeerConnection::new().map_err(js_error)
+}
+
+/// sets the local description and waits for its candidates (or GATHER_TIMEOUT_MS), then compacts it
+async fn publish(pc: &RtcPeerConnection, kind: SdpKind, description: JsValue) -> Result<Pairing, String> {
+    wait(pc.set_local_description(description.unchecked_ref())).await?;
+    for _ in 0..GATHER_TIMEOUT_MS / GATHER_POLL_MS {
+        if pc.ice_gathering_state() == RtcIceGatheringState::Complete {
+            break;
+        }
+        sleep(GATHER_POLL_MS).await;
+    }
+    let sdp = pc.local_description().map(|d| d.sdp()).ok_or("no local description")?;
+    let pairing = Pairing::from_sdp(kind, &sdp)?;
+    if pairing.candidates.is_empty() {
+        return Err("no local network candidate found (is this device on a network?)".to_string());
+    }
+    Ok(pairing)
+}
+
+fn remote(pairing: &Pairing) -> RtcSessionDescriptionInit {
+    let init = RtcSessionDescriptionInit::new(if pairing.kind == SdpKind::Offer { RtcSdpType::Offer } else { RtcSdpType::Answer });
+    init.set_sdp(&pairing.to_sdp());
+    init
+}
+
+/// laptop: the offer to show as a qr code (create the data channel first, or the offer has nothing to negotiate)
+pub async fn offer(pc: &RtcPeerConnection) -> Result<Pairing, String> {
+    let description = wait(pc.create_offer()).await?;
+    publish(pc, SdpKind::Offer, description).await
+}
+
+/// laptop: the phone's answer, pasted back
+pub async fn accept_answer(pc: &RtcPeerConnection, answer: &Pairing) -> Result<(), String> {
+    if answer.kind != SdpKind::Answer {
+        return Err("that is an offer; paste the code the phone shows".to_string());
+    }
+    wait(pc.set_remote_description(&remote(answer))).await.map(|_| ())
+}
+
+/// phone: the answer to an offer from the qr link
+pub async fn answer(pc: &RtcPeerConnection, offer: &Pairing) -> Result<Pairing, String> {
+    if offer.kind != SdpKind::Offer {
+        return Err("the link does not carry an offer".to_string());
+    }
+    wait(pc.set_remote_description(&remote(offer))).await?;
+    let description = wait(pc.create_answer()).await?;
+    publish(pc, SdpKind::Answer, description).await
+}
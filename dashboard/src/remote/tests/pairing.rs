@@ -0,0 +1,112 @@
+// what: tests for remote pairing codes and data channel messages - sdp compaction, code round trips, qr fit, message shapes
+// why: the two sdp blobs are the only signalling there is; a code that drops a field or outgrows the qr code makes pairing impossible
+
+use serde_json::json;
+use crate::handoff::qr::QrCode;
+use crate::remote::{base64url, event_line, from_base64url, Pairing, RemoteCommand, RemoteMessage, SdpKind, MAX_CANDIDATES};
+
+const FINGERPRINT: &str = "4B:1D:0E:76:6C:2F:A0:93:51:C8:EE:07:3A:9D:B2:14:F0:65:28:CD:71:0B:9A:E3:46:D5:82:1F:3C:A7:5E:90";
+
+/// a chrome offer for one data channel, with an ipv6 host, an mdns host, a tcp host, an rtcp and a srflx candidate mixed in
+fn chrome_offer() -> String {
+    [
+        "v=0",
+        "o=- 4611731400430051336 2 IN IP4 127.0.0.1",
+        "s=-",
+        "t=0 0",
+        "a=group:BUNDLE 0",
+        "m=application 9 UDP/DTLS/SCTP webrtc-datachannel",
+        "c=IN IP4 0.0.0.0",
+        "a=candidate:1467250027 1 udp 2122260223 192.168.1.20 53812 typ host generation 0 network-id 1",
+        "a=candidate:1467250027 2 udp 2122260222 192.168.1.20 53813 typ host generation 0 network-id 1",
+        "a=candidate:434276097 1 tcp 1518280447 192.168.1.20 9 typ host tcptype active generation 0",
+        "a=candidate:842163049 1 udp 1686052607 203.0.113.9 53812 typ srflx raddr 192.168.1.20 rport 53812",
+        "a=candidate:3512914312 1 udp 2122197247 fd00::1c2b 61002 typ host generation 0 network-id 2",
+        "a=candidate:2999745851 1 udp 2122129151 9c1f3b7e-1a2d.local 59111 typ host generation 0",
+        "a=ice-ufrag:Zk3v",
+        "a=ice-pwd:pQ1u8sX0bV2n7Lr4mT9yHc6e",
+        "a=ice-options:trickle",
+        &format!("a=fingerprint:sha-256 {}", FINGERPRINT),
+        "a=setup:actpass",
+        "a=mid:0",
+        "a=sctp-port:5000",
+        "a=max-message-size:262144",
+    ]
+    .join("\r\n")
+}
+
+fn offer() -> Pairing {
+    Pairing::from_sdp(SdpKind::Offer, &chrome_offer()).unwrap()
+}
+
+#[test]
+fn from_sdp_keeps_credentials_and_the_first_udp_host_candidates() {
+    // what: ufrag, pwd and the 32-byte fingerprint are picked out; only component-1 udp host candidates are kept, at most MAX_CANDIDATES
+    // why: rtcp, tcp and srflx candidates only cost qr space - bundled data channels use component 1 over udp on the lan
+    let pairing = offer();
+    assert_eq!((pairing.ufrag.as_str(), pairing.pwd.as_str()), ("Zk3v", "pQ1u8sX0bV2n7Lr4mT9yHc6e"));
+    assert_eq!(pairing.fingerprint.len(), 32);
+    assert_eq!((pairing.fingerprint[0], pairing.fingerprint[31]), (0x4b, 0x90));
+    assert_eq!(pairing.candidates.len(), MAX_CANDIDATES);
+    assert_eq!(pairing.candidates, vec![("192.168.1.20".to_string(), 53812), ("fd00::1c2b".to_string(), 61002)]);
+    assert!(Pairing::from_sdp(SdpKind::Offer, "v=0\r\na=ice-ufrag:x\r\n").is_err(), "no pwd or fingerprint");
+}
+
+#[test]
+fn codes_round_trip_reject_garbage_and_fit_a_qr_code() {
+    // what: encode/decode and link/decode restore the pairing; wrong tags, kinds and fingerprints are errors; a pages-hosted link fits the qr encoder
+    // why: the phone decodes exactly what the laptop encoded, and a link the qr module cannot hold would leave nothing to scan
+    let pairing = offer();
+    let code = pairing.encode();
+    assert!(code.starts_with("R1!o!Zk3v!"));
+    assert_eq!(Pairing::decode(&code), Ok(pairing.clone()));
+    let link = pairing.link("https://gammahazard.github.io/Guardian-one-web-demo/#follow");
+    assert!(link.starts_with("https://gammahazard.github.io/Guardian-one-web-demo/#remote=R1!o!"));
+    assert_eq!(Pairing::decode(&link), Ok(pairing.clone()));
+    assert!(QrCode::encode(link.as_bytes()).is_ok(), "{} bytes", link.len());
+
+    assert!(Pairing::decode(&code.replacen("R1", "R9", 1)).is_err());
+    assert!(Pairing::decode(&code.replacen("!o!", "!x!", 1)).is_err());
+    let short = Pairing { fingerprint: vec![1, 2, 3], ..pairing.clone() };
+    assert!(Pairing::decode(&short.encode()).unwrap_err().contains("fingerprint"));
+    assert!(Pairing::decode(&format!("{}!10.0.0.1", code)).is_err(), "candidate without a port");
+}
+
+#[test]
+fn to_sdp_rebuilds_a_description_that_parses_back() {
+    // what: a compacted pairing expands to an sdp that from_sdp reads back unchanged, with the dtls role set by its kind
+    // why: the browser gets the expanded sdp as its remote description; a missing line or a wrong setup role fails the handshake
+    let pairing = offer();
+    let sdp = pairing.to_sdp();
+    assert_eq!(Pairing::from_sdp(SdpKind::Offer, &sdp), Ok(pairing.clone()));
+    assert!(sdp.contains(&format!("a=fingerprint:sha-256 {}", FINGERPRINT)));
+    assert!(sdp.contains("a=setup:actpass\r\n"));
+    assert!(sdp.contains("m=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\n"));
+    let answer = Pairing { kind: SdpKind::Answer, ..pairing };
+    assert!(answer.to_sdp().contains("a=setup:active\r\n"));
+    assert!(answer.encode().starts_with("R1!a!"));
+}
+
+#[test]
+fn messages_and_event_lines_have_stable_shapes() {
+    // what: commands and replies are tagged camelCase json that round-trips; headless api messages become one line; base64url round-trips
+    // why: phone and laptop may run different deploys of the page, so the wire shape is the contract between them
+    let run = RemoteCommand::RunAttack { attack: "dataExfil".to_string() };
+    assert_eq!(run.to_json(), r#"{"cmd":"runAttack","attack":"dataExfil"}"#);
+    assert_eq!(RemoteCommand::from_json(r#"{"cmd":"nextTab"}"#), Ok(RemoteCommand::NextTab));
+    assert!(RemoteCommand::from_json(r#"{"cmd":"selfDestruct"}"#).is_err());
+    let reply = RemoteMessage::Reply { ok: false, text: "busy".to_string() };
+    assert_eq!(reply.to_json(), r#"{"type":"reply","ok":false,"text":"busy"}"#);
+    assert_eq!(RemoteMessage::from_json(&reply.to_json()), Ok(reply));
+
+    let event = json!({ "type": "event", "kind": "attack", "runtime": "wasm", "detail": "trapped" });
+    assert_eq!(event_line(&event).as_deref(), Some("attack · wasm · trapped"));
+    assert_eq!(event_line(&json!({ "type": "event", "kind": "reset", "runtime": null, "detail": "" })).as_deref(), Some("reset"));
+    assert!(event_line(&json!({ "type": "metrics" })).is_none());
+
+    for bytes in [&[][..], &[0xff][..], &[1, 2][..], &[250, 251, 252, 253][..]] {
+        assert_eq!(from_base64url(&base64url(bytes)).as_deref(), Some(bytes));
+    }
+    assert!(from_base64url("a+b/").is_none());
+    assert!(from_base64url("abcde").is_none());
+}
@@ -0,0 +1,274 @@
+// what: persistent user preferences (theme, sound, data source, endpoints, benchmarks, benchmark on load, sensor backend, sensor environment, chaos mode, kiosk mode, analytics opt-in)
+// why: one place for knobs that were scattered or hardcoded, surviving page reloads
+// relations: provided as context by lib.rs, edited by panel.rs, read by demo/proof/hardware tabs
+
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+mod panel;
+
+#[cfg(test)]
+mod tests;
+
+pub use panel::SettingsButton;
+
+/// localStorage key for the settings blob
+pub const STORAGE_KEY: &str = "guardian.settings";
+/// bounds for benchmark iteration counts
+pub const MIN_ITERATIONS: u32 = 1;
+pub const MAX_ITERATIONS: u32 = 1000;
+/// bounds for the chaos-mode interval
+pub const MIN_CHAOS_INTERVAL_S: u32 = 5;
+pub const MAX_CHAOS_INTERVAL_S: u32 = 600;
+/// bounds for the simulated environment (tabs/demo/environment.rs)
+pub const MIN_BASE_TEMP_C: i32 = -20;
+pub const MAX_BASE_TEMP_C: i32 = 50;
+pub const MAX_DIURNAL_SWING_C: u32 = 20;
+pub const MAX_SPIKE_RATE_PCT: u32 = 25;
+/// bounds for the kiosk idle timeout
+pub const MIN_KIOSK_IDLE_MIN: u32 = 1;
+pub const MAX_KIOSK_IDLE_MIN: u32 = 120;
+/// bounds for the kiosk per-tab dwell
+pub const MIN_KIOSK_DWELL_S: u32 = 10;
+pub const MAX_KIOSK_DWELL_S: u32 = 600;
+/// bounds for the kiosk run-all period
+pub const MIN_KIOSK_RUN_ALL_MIN: u32 = 1;
+pub const MAX_KIOSK_RUN_ALL_MIN: u32 = 120;
+
+/// colour scheme
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Dark,
+    Light,
+}
+
+/// where telemetry and cluster state come from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataSourcePref {
+    Simulated,
+    Live,
+}
+
+/// which build of the sensor driver the demo's wasm column runs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SensorBackend {
+    /// the dashboard's own wasm-bindgen build, in-process
+    Bindgen,
+    /// the wasi 0.2 sensor-driver component through jco bindings
+    Component,
+}
+
+impl SensorBackend {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SensorBackend::Bindgen => "wasm-bindgen",
+            SensorBackend::Component => "component (jco)",
+        }
+    }
+}
+
+/// how much a simulated sensor's readings scatter around the true value
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoiseProfile {
+    /// calibrated lab sensor
+    Lab,
+    /// a bme280 on a field board
+    #[default]
+    Typical,
+    /// long unshielded cable next to a motor drive
+    Harsh,
+}
+
+impl NoiseProfile {
+    pub const ALL: [NoiseProfile; 3] = [NoiseProfile::Lab, NoiseProfile::Typical, NoiseProfile::Harsh];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            NoiseProfile::Lab => "lab",
+            NoiseProfile::Typical => "typical",
+            NoiseProfile::Harsh => "harsh",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.id() == id)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NoiseProfile::Lab => "Lab (calibrated, quiet)",
+            NoiseProfile::Typical => "Typical field sensor",
+            NoiseProfile::Harsh => "Harsh (EMI, long cable)",
+        }
+    }
+}
+
+/// the simulated plant the sensor readings come from
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnvironmentSettings {
+    /// daily mean temperature (°C)
+    pub base_temp_c: i32,
+    /// amplitude of the day/night temperature curve (°C either side of the mean)
+    pub diurnal_swing_c: u32,
+    pub noise: NoiseProfile,
+    /// chance per reading of a transient spike (%)
+    pub spike_rate_pct: u32,
+}
+
+impl Default for EnvironmentSettings {
+    fn default() -> Self {
+        Self { base_temp_c: 24, diurnal_swing_c: 4, noise: NoiseProfile::Typical, spike_rate_pct: 2 }
+    }
+}
+
+/// consent to run the proof suite in the background on page load (autobench/)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoBenchmark {
+    /// not decided yet: the header asks
+    Ask,
+    Always,
+    Never,
+}
+
+/// outbound integrations (empty = not configured)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Endpoints {
+    pub cluster_api: String,
+    pub influx_write: String,
+    pub siem_collector: String,
+    pub pushgateway: String,
+}
+
+/// random fault injection while the demo tab is open
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChaosSettings {
+    pub enabled: bool,
+    /// seconds between injected faults
+    pub interval_s: u32,
+    /// also kill the leader, not just security attacks
+    pub include_availability: bool,
+}
+
+impl Default for ChaosSettings {
+    fn default() -> Self {
+        Self { enabled: false, interval_s: 30, include_availability: true }
+    }
+}
+
+/// unattended trade-show mode: idle reset, tab cycling, periodic run-all
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KioskSettings {
+    pub enabled: bool,
+    /// minutes without input before the console resets and starts cycling
+    pub idle_reset_min: u32,
+    /// seconds spent on each tab while cycling
+    pub dwell_s: u32,
+    /// minutes between unattended run-all sequences
+    pub run_all_every_min: u32,
+}
+
+impl Default for KioskSettings {
+    fn default() -> Self {
+        Self { enabled: false, idle_reset_min: 3, dwell_s: 45, run_all_every_min: 10 }
+    }
+}
+
+/// all user preferences, unknown/missing keys fall back to defaults
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: Theme,
+    /// bcp 47 tag set on <html lang>
+    pub language: String,
+    pub sound: bool,
+    pub data_source: DataSourcePref,
+    pub endpoints: Endpoints,
+    /// wasm instantiations averaged per measurement
+    pub benchmark_iterations: u32,
+    pub auto_benchmark: AutoBenchmark,
+    /// show the plain-javascript baseline column on the demo
+    pub js_baseline: bool,
+    pub sensor_backend: SensorBackend,
+    pub environment: EnvironmentSettings,
+    pub chaos: ChaosSettings,
+    pub kiosk: KioskSettings,
+    /// count attacks and tab dwell locally (analytics/mod.rs); off until the viewer opts in
+    pub analytics: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: Theme::Dark,
+            language: "en".to_string(),
+            sound: false,
+            data_source: DataSourcePref::Simulated,
+            endpoints: Endpoints { cluster_api: "http://guardian.local:8080".to_string(), ..Default::default() },
+            benchmark_iterations: 10,
+            auto_benchmark: AutoBenchmark::Ask,
+            js_baseline: false,
+            sensor_backend: SensorBackend::Bindgen,
+            environment: EnvironmentSettings::default(),
+            chaos: ChaosSettings::default(),
+            kiosk: KioskSettings::default(),
+            analytics: false,
+        }
+    }
+}
+
+impl Settings {
+    /// parses an exported blob and clamps numeric knobs into range
+    pub fn from_json(json: &str) -> Result<Settings, String> {
+        let mut settings: Settings = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        settings.benchmark_iterations = settings.benchmark_iterations.clamp(MIN_ITERATIONS, MAX_ITERATIONS);
+        settings.environment.base_temp_c = settings.environment.base_temp_c.clamp(MIN_BASE_TEMP_C, MAX_BASE_TEMP_C);
+        settings.environment.diurnal_swing_c = settings.environment.diurnal_swing_c.min(MAX_DIURNAL_SWING_C);
+        settings.environment.spike_rate_pct = settings.environment.spike_rate_pct.min(MAX_SPIKE_RATE_PCT);
+        settings.chaos.interval_s = settings.chaos.interval_s.clamp(MIN_CHAOS_INTERVAL_S, MAX_CHAOS_INTERVAL_S);
+        settings.kiosk.idle_reset_min = settings.kiosk.idle_reset_min.clamp(MIN_KIOSK_IDLE_MIN, MAX_KIOSK_IDLE_MIN);
+        settings.kiosk.dwell_s = settings.kiosk.dwell_s.clamp(MIN_KIOSK_DWELL_S, MAX_KIOSK_DWELL_S);
+        settings.kiosk.run_all_every_min = settings.kiosk.run_all_every_min.clamp(MIN_KIOSK_RUN_ALL_MIN, MAX_KIOSK_RUN_ALL_MIN);
+        Ok(settings)
+    }
+
+    /// pretty json blob for export
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// loads saved settings, defaults when absent or unreadable
+pub fn load() -> Settings {
+    storage()
+        .and_then(|s| s.get_item(STORAGE_KEY).ok()?)
+        .and_then(|json| Settings::from_json(&json).ok())
+        .unwrap_or_default()
+}
+
+/// persists settings and applies document-level ones (lang attribute)
+pub fn save(settings: &Settings) {
+    if let Some(s) = storage() {
+        let _ = s.set_item(STORAGE_KEY, &settings.to_json());
+    }
+    if let Some(root) = web_sys::window().and_then(|w| w.document()).and_then(|d| d.document_element()) {
+        let _ = root.set_attribute("lang", &settings.language);
+    }
+}
+
+/// settings signal provided by App
+pub fn use_settings() -> RwSignal<Settings> {
+    expect_context::<RwSignal<Settings>>()
+}
@@ -0,0 +1,226 @@
+// what: gear button and settings modal with import/export of the settings blob
+// why: consolidates preferences that were hardcoded per tab
+// relations: edits the settings signal from mod.rs, embeds analytics/panel.rs, uses demo/wasm.rs download/clipboard helpers
+
+use leptos::*;
+use super::{
+    use_settings, AutoBenchmark, DataSourcePref, NoiseProfile, SensorBackend, Settings, Theme, MAX_BASE_TEMP_C, MAX_CHAOS_INTERVAL_S, MAX_DIURNAL_SWING_C,
+    MAX_ITERATIONS, MAX_KIOSK_DWELL_S, MAX_KIOSK_IDLE_MIN, MAX_KIOSK_RUN_ALL_MIN, MAX_SPIKE_RATE_PCT, MIN_BASE_TEMP_C, MIN_CHAOS_INTERVAL_S, MIN_ITERATIONS,
+    MIN_KIOSK_DWELL_S, MIN_KIOSK_IDLE_MIN, MIN_KIOSK_RUN_ALL_MIN,
+};
+use crate::analytics::AnalyticsSettings;
+use crate::kiosk::use_kiosk;
+use crate::tabs::demo::wasm::{copy_to_clipboard, download_text};
+
+/// header gear icon that opens the settings modal
+#[component]
+pub fn SettingsButton() -> impl IntoView {
+    let settings = use_settings();
+    let kiosk = use_kiosk();
+    let (open, set_open) = create_signal(false);
+    // an unattended kiosk never leaves the modal covering the page
+    create_effect(move |_| if kiosk.attract.get() { set_open.set(false) });
+    let (import_text, set_import_text) = create_signal(String::new());
+    let (import_status, set_import_status) = create_signal(Option::<String>::None);
+
+    let apply_import = move |_| match Settings::from_json(&import_text.get()) {
+        Ok(imported) => {
+            settings.set(imported);
+            set_import_text.set(String::new());
+            set_import_status.set(Some("✅ Settings imported".to_string()));
+        }
+        Err(e) => set_import_status.set(Some(format!("❌ {}", e))),
+    };
+
+    // numeric inputs ignore unparsable text and clamp into range
+    let parse_u32 = |ev: &ev::Event, min: u32, max: u32| event_target_value(ev).parse::<u32>().ok().map(|v| v.clamp(min, max));
+    let parse_i32 = |ev: &ev::Event, min: i32, max: i32| event_target_value(ev).parse::<i32>().ok().map(|v| v.clamp(min, max));
+
+    view! {
+        <button class="settings-btn" title="Settings" on:click=move |_| set_open.set(true)>"⚙️"</button>
+        <Show when=move || open.get()>
+            <div class="modal-overlay" on:click=move |_| set_open.set(false)>
+                <div class="modal-content settings-modal" on:click=|e: web_sys::MouseEvent| e.stop_propagation()>
+                    <div class="modal-header">
+                        <span class="modal-title">"⚙️ Settings"</span>
+                        <button class="modal-close" on:click=move |_| set_open.set(false)>"×"</button>
+                    </div>
+
+                    <fieldset class="settings-group">
+                        <legend>"Appearance"</legend>
+                        <label>"Theme"
+                            <select class="network-select" on:change=move |ev| {
+                                let theme = if event_target_value(&ev) == "light" { Theme::Light } else { Theme::Dark };
+                                settings.update(|s| s.theme = theme);
+                            }>
+                                <option value="dark" selected=move || settings.with(|s| s.theme == Theme::Dark)>"Dark"</option>
+                                <option value="light" selected=move || settings.with(|s| s.theme == Theme::Light)>"Light"</option>
+                            </select>
+                        </label>
+                        <label>"Language"
+                            <select class="network-select" on:change=move |ev| settings.update(|s| s.language = event_target_value(&ev))>
+                                <option value="en" selected>"English"</option>
+                            </select>
+                        </label>
+                        <label class="settings-check">
+                            <input type="checkbox" prop:checked=move || settings.with(|s| s.sound)
+                                on:change=move |ev| settings.update(|s| s.sound = event_target_checked(&ev)) />
+                            "Alert sound on trap / crash"
+                        </label>
+                    </fieldset>
+
+                    <fieldset class="settings-group">
+                        <legend>"Data & Endpoints"</legend>
+                        <label>"Data source"
+                            <select class="network-select" on:change=move |ev| {
+                                let source = if event_target_value(&ev) == "live" { DataSourcePref::Live } else { DataSourcePref::Simulated };
+                                settings.update(|s| s.data_source = source);
+                            }>
+                                <option value="simulated" selected=move || settings.with(|s| s.data_source == DataSourcePref::Simulated)>"Simulated"</option>
+                                <option value="live" selected=move || settings.with(|s| s.data_source == DataSourcePref::Live)>"Live"</option>
+                            </select>
+                        </label>
+                        <label>"Cluster API"
+                            <input class="collector-url" type="url" prop:value=move || settings.with(|s| s.endpoints.cluster_api.clone())
+                                on:change=move |ev| settings.update(|s| s.endpoints.cluster_api = event_target_value(&ev)) />
+                        </label>
+                        <label>"InfluxDB write"
+                            <input class="collector-url" type="url" prop:value=move || settings.with(|s| s.endpoints.influx_write.clone())
+                                on:change=move |ev| settings.update(|s| s.endpoints.influx_write = event_target_value(&ev)) />
+                        </label>
+                        <label>"SIEM collector"
+                            <input class="collector-url" type="url" prop:value=move || settings.with(|s| s.endpoints.siem_collector.clone())
+                                on:change=move |ev| settings.update(|s| s.endpoints.siem_collector = event_target_value(&ev)) />
+                        </label>
+                        <label>"Pushgateway"
+                            <input class="collector-url" type="url" prop:value=move || settings.with(|s| s.endpoints.pushgateway.clone())
+                                on:change=move |ev| settings.update(|s| s.endpoints.pushgateway = event_target_value(&ev)) />
+                        </label>
+                    </fieldset>
+
+                    <fieldset class="settings-group">
+                        <legend>"Benchmarks & Chaos"</legend>
+                        <label>"Instantiation iterations"
+                            <input type="number" min=MIN_ITERATIONS max=MAX_ITERATIONS
+                                prop:value=move || settings.with(|s| s.benchmark_iterations.to_string())
+                                on:change=move |ev| if let Some(v) = parse_u32(&ev, MIN_ITERATIONS, MAX_ITERATIONS) { settings.update(|s| s.benchmark_iterations = v) } />
+                        </label>
+                        <label>"Benchmark on page load"
+                            <select class="network-select" on:change=move |ev| {
+                                let auto = match event_target_value(&ev).as_str() {
+                                    "always" => AutoBenchmark::Always,
+                                    "never" => AutoBenchmark::Never,
+                                    _ => AutoBenchmark::Ask,
+                                };
+                                settings.update(|s| s.auto_benchmark = auto);
+                            }>
+                                <option value="ask" selected=move || settings.with(|s| s.auto_benchmark == AutoBenchmark::Ask)>"Ask"</option>
+                                <option value="always" selected=move || settings.with(|s| s.auto_benchmark == AutoBenchmark::Always)>"Always (downloads Pyodide in the background)"</option>
+                                <option value="never" selected=move || settings.with(|s| s.auto_benchmark == AutoBenchmark::Never)>"Never"</option>
+                            </select>
+                        </label>
+                        <label class="settings-check">
+                            <input type="checkbox" prop:checked=move || settings.with(|s| s.js_baseline)
+                                on:change=move |ev| settings.update(|s| s.js_baseline = event_target_checked(&ev)) />
+                            "Show JavaScript baseline column"
+                        </label>
+                        <label>"WASM sensor backend"
+                            <select class="network-select" on:change=move |ev| {
+                                let backend = if event_target_value(&ev) == "component" { SensorBackend::Component } else { SensorBackend::Bindgen };
+                                settings.update(|s| s.sensor_backend = backend);
+                            }>
+                                <option value="bindgen" selected=move || settings.with(|s| s.sensor_backend == SensorBackend::Bindgen)>"wasm-bindgen (in-process)"</option>
+                                <option value="component" selected=move || settings.with(|s| s.sensor_backend == SensorBackend::Component)>"WASI 0.2 component (jco)"</option>
+                            </select>
+                        </label>
+                        <label class="settings-check">
+                            <input type="checkbox" prop:checked=move || settings.with(|s| s.chaos.enabled)
+                                on:change=move |ev| settings.update(|s| s.chaos.enabled = event_target_checked(&ev)) />
+                            "Chaos mode (random attacks on the Demo tab)"
+                        </label>
+                        <label>"Chaos interval (s)"
+                            <input type="number" min=MIN_CHAOS_INTERVAL_S max=MAX_CHAOS_INTERVAL_S
+                                prop:value=move || settings.with(|s| s.chaos.interval_s.to_string())
+                                on:change=move |ev| if let Some(v) = parse_u32(&ev, MIN_CHAOS_INTERVAL_S, MAX_CHAOS_INTERVAL_S) { settings.update(|s| s.chaos.interval_s = v) } />
+                        </label>
+                        <label class="settings-check">
+                            <input type="checkbox" prop:checked=move || settings.with(|s| s.chaos.include_availability)
+                                on:change=move |ev| settings.update(|s| s.chaos.include_availability = event_target_checked(&ev)) />
+                            "Include leader kills"
+                        </label>
+                    </fieldset>
+
+                    <fieldset class="settings-group">
+                        <legend>"Sensor Environment"</legend>
+                        <p class="section-desc">"Simulated readings follow a day/night temperature curve (one simulated hour per real minute, starting at the local time), with humidity falling as it warms, sensor noise and occasional spikes."</p>
+                        <label>"Mean temperature (°C)"
+                            <input type="number" min=MIN_BASE_TEMP_C max=MAX_BASE_TEMP_C
+                                prop:value=move || settings.with(|s| s.environment.base_temp_c.to_string())
+                                on:change=move |ev| if let Some(v) = parse_i32(&ev, MIN_BASE_TEMP_C, MAX_BASE_TEMP_C) { settings.update(|s| s.environment.base_temp_c = v) } />
+                        </label>
+                        <label>"Day/night swing (±°C)"
+                            <input type="number" min=0 max=MAX_DIURNAL_SWING_C
+                                prop:value=move || settings.with(|s| s.environment.diurnal_swing_c.to_string())
+                                on:change=move |ev| if let Some(v) = parse_u32(&ev, 0, MAX_DIURNAL_SWING_C) { settings.update(|s| s.environment.diurnal_swing_c = v) } />
+                        </label>
+                        <label>"Sensor noise"
+                            <select class="network-select" on:change=move |ev| if let Some(p) = NoiseProfile::from_id(&event_target_value(&ev)) { settings.update(|s| s.environment.noise = p) }>
+                                {NoiseProfile::ALL.into_iter().map(|p| view! {
+                                    <option value=p.id() selected=move || settings.with(|s| s.environment.noise == p)>{p.label()}</option>
+                                }).collect_view()}
+                            </select>
+                        </label>
+                        <label>"Spike chance per reading (%)"
+                            <input type="number" min=0 max=MAX_SPIKE_RATE_PCT
+                                prop:value=move || settings.with(|s| s.environment.spike_rate_pct.to_string())
+                                on:change=move |ev| if let Some(v) = parse_u32(&ev, 0, MAX_SPIKE_RATE_PCT) { settings.update(|s| s.environment.spike_rate_pct = v) } />
+                        </label>
+                    </fieldset>
+
+                    <fieldset class="settings-group">
+                        <legend>"Kiosk"</legend>
+                        <label class="settings-check">
+                            <input type="checkbox" prop:checked=move || settings.with(|s| s.kiosk.enabled)
+                                on:change=move |ev| settings.update(|s| s.kiosk.enabled = event_target_checked(&ev)) />
+                            "Kiosk mode (reset when idle, then cycle tabs)"
+                        </label>
+                        <label>"Idle reset (min)"
+                            <input type="number" min=MIN_KIOSK_IDLE_MIN max=MAX_KIOSK_IDLE_MIN
+                                prop:value=move || settings.with(|s| s.kiosk.idle_reset_min.to_string())
+                                on:change=move |ev| if let Some(v) = parse_u32(&ev, MIN_KIOSK_IDLE_MIN, MAX_KIOSK_IDLE_MIN) { settings.update(|s| s.kiosk.idle_reset_min = v) } />
+                        </label>
+                        <label>"Tab dwell (s)"
+                            <input type="number" min=MIN_KIOSK_DWELL_S max=MAX_KIOSK_DWELL_S
+                                prop:value=move || settings.with(|s| s.kiosk.dwell_s.to_string())
+                                on:change=move |ev| if let Some(v) = parse_u32(&ev, MIN_KIOSK_DWELL_S, MAX_KIOSK_DWELL_S) { settings.update(|s| s.kiosk.dwell_s = v) } />
+                        </label>
+                        <label>"Run all every (min)"
+                            <input type="number" min=MIN_KIOSK_RUN_ALL_MIN max=MAX_KIOSK_RUN_ALL_MIN
+                                prop:value=move || settings.with(|s| s.kiosk.run_all_every_min.to_string())
+                                on:change=move |ev| if let Some(v) = parse_u32(&ev, MIN_KIOSK_RUN_ALL_MIN, MAX_KIOSK_RUN_ALL_MIN) { settings.update(|s| s.kiosk.run_all_every_min = v) } />
+                        </label>
+                    </fieldset>
+
+                    <AnalyticsSettings />
+
+                    <fieldset class="settings-group">
+                        <legend>"Import / Export"</legend>
+                        <div class="audit-controls">
+                            <button class="action-btn" on:click=move |_| download_text("guardian-settings.json", "application/json", &settings.get().to_json())>"⬇️ Export"</button>
+                            <button class="action-btn" on:click=move |_| copy_to_clipboard(&settings.get().to_json())>"📋 Copy"</button>
+                            <button class="action-btn" on:click=move |_| settings.set(Settings::default())>"↺ Defaults"</button>
+                        </div>
+                        <textarea
+                            class="policy-editor settings-import"
+                            placeholder="Paste an exported settings blob"
+                            prop:value=move || import_text.get()
+                            on:input=move |ev| set_import_text.set(event_target_value(&ev))
+                        />
+                        <button class="action-btn" disabled=move || import_text.get().is_empty() on:click=apply_import>"⬆️ Import"</button>
+                        {move || import_status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+                    </fieldset>
+                </div>
+            </div>
+        </Show>
+    }
+}
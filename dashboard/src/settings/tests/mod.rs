@@ -0,0 +1,5 @@
+// what: exports all test modules for user settings
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod preferences;
@@ -0,0 +1,51 @@
+// what: tests for settings blob import/export
+// why: a bad blob in localStorage must never brick the console on load
+
+use crate::settings::{Settings, Theme, MAX_ITERATIONS, MIN_CHAOS_INTERVAL_S};
+use crate::tabs::demo::attacks::{chaos_pool, AVAILABILITY_ATTACKS};
+
+#[test]
+fn export_import_round_trip() {
+    // what: exported json imports back to identical settings
+    // why: users move preferences between browsers via the blob
+    let mut settings = Settings { theme: Theme::Light, ..Default::default() };
+    settings.endpoints.influx_write = "http://influx:8086/api/v2/write".to_string();
+    settings.chaos.enabled = true;
+    assert_eq!(Settings::from_json(&settings.to_json()).unwrap(), settings);
+}
+
+#[test]
+fn partial_blob_fills_defaults() {
+    // what: missing keys take default values
+    // why: blobs saved by older builds lack newer settings
+    let settings = Settings::from_json(r#"{"theme":"light","chaos":{"enabled":true}}"#).unwrap();
+    assert_eq!(settings.theme, Theme::Light);
+    assert!(settings.chaos.enabled);
+    assert_eq!(settings.chaos.interval_s, Settings::default().chaos.interval_s);
+    assert_eq!(settings.benchmark_iterations, Settings::default().benchmark_iterations);
+}
+
+#[test]
+fn numeric_knobs_are_clamped() {
+    // what: out-of-range iterations and chaos interval are clamped on import
+    // why: a million iterations would freeze the tab, a 0s interval would spam attacks
+    let settings = Settings::from_json(r#"{"benchmark_iterations":999999,"chaos":{"interval_s":0}}"#).unwrap();
+    assert_eq!(settings.benchmark_iterations, MAX_ITERATIONS);
+    assert_eq!(settings.chaos.interval_s, MIN_CHAOS_INTERVAL_S);
+}
+
+#[test]
+fn invalid_blob_is_rejected() {
+    // what: malformed json and wrong enum values return an error
+    // why: import must report the problem instead of silently resetting
+    assert!(Settings::from_json("not json").is_err());
+    assert!(Settings::from_json(r#"{"theme":"purple"}"#).is_err());
+}
+
+#[test]
+fn chaos_pool_respects_availability_toggle() {
+    // what: leader kills only join the pool when enabled
+    // why: users may want security-only chaos without failovers
+    assert!(chaos_pool(false).iter().all(|a| !AVAILABILITY_ATTACKS.contains(a)));
+    assert_eq!(chaos_pool(true).len(), 9);
+}
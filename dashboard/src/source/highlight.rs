@@ -0,0 +1,166 @@
+// what: single-pass rust tokenizer producing highlight spans (comments, strings, keywords, types, macros, attributes)
+// why: a syntax-highlighting crate or highlight.js would outweigh the sources being shown
+// relations: used by drawer.rs on the SOURCES in mod.rs
+
+/// highlight class of a token
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    Plain,
+    Comment,
+    Str,
+    Number,
+    Keyword,
+    Type,
+    Macro,
+    Attribute,
+    Lifetime,
+}
+
+impl TokenKind {
+    pub fn class(&self) -> &'static str {
+        match self {
+            TokenKind::Plain => "",
+            TokenKind::Comment => "tok-comment",
+            TokenKind::Str => "tok-str",
+            TokenKind::Number => "tok-num",
+            TokenKind::Keyword => "tok-kw",
+            TokenKind::Type => "tok-type",
+            TokenKind::Macro => "tok-macro",
+            TokenKind::Attribute => "tok-attr",
+            TokenKind::Lifetime => "tok-life",
+        }
+    }
+}
+
+/// a slice of the source and how to colour it; concatenating all tokens gives the source back
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+}
+
+const KEYWORDS: [&str; 37] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static",
+    "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+];
+
+fn is_ident_start(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'_'
+}
+
+fn is_ident(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+/// end of a "..." string starting at `i` (the quote), escapes honoured
+fn string_end(b: &[u8], i: usize) -> usize {
+    let mut j = i + 1;
+    while j < b.len() {
+        match b[j] {
+            b'\\' => j += 2,
+            b'"' => return j + 1,
+            _ => j += 1,
+        }
+    }
+    b.len()
+}
+
+/// end of r#"..."# with `hashes` hashes, `i` at the opening quote
+fn raw_string_end(s: &str, i: usize, hashes: usize) -> usize {
+    let close = format!("\"{}", "#".repeat(hashes));
+    s[i + 1..].find(&close).map(|at| i + 1 + at + close.len()).unwrap_or(s.len())
+}
+
+/// end of a #[...] or #![...] attribute, brackets balanced
+fn attribute_end(b: &[u8], i: usize) -> usize {
+    let mut depth = 0usize;
+    for (j, c) in b.iter().enumerate().skip(i) {
+        match c {
+            b'[' => depth += 1,
+            b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return j + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    b.len()
+}
+
+/// splits `src` into highlight tokens; runs of plain text come back as one token
+pub fn tokenize(src: &str) -> Vec<Token<'_>> {
+    let b = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut plain = 0usize;
+    let mut i = 0usize;
+    while i < b.len() {
+        let rest = &b[i..];
+        let (kind, end) = if rest.starts_with(b"//") {
+            (TokenKind::Comment, src[i..].find('\n').map(|n| i + n).unwrap_or(b.len()))
+        } else if rest.starts_with(b"/*") {
+            (TokenKind::Comment, src[i + 2..].find("*/").map(|n| i + 2 + n + 2).unwrap_or(b.len()))
+        } else if b[i] == b'"' {
+            (TokenKind::Str, string_end(b, i))
+        } else if b[i] == b'\'' {
+            // 'x' and '\n' are chars, anything else is a lifetime
+            if rest.len() >= 3 && rest[1] != b'\\' && rest[2] == b'\'' {
+                (TokenKind::Str, i + 3)
+            } else if rest.len() >= 4 && rest[1] == b'\\' {
+                (TokenKind::Str, src[i + 3..].find('\'').map(|n| i + 3 + n + 1).unwrap_or(b.len()))
+            } else {
+                let mut j = i + 1;
+                while j < b.len() && is_ident(b[j]) {
+                    j += 1;
+                }
+                (TokenKind::Lifetime, j)
+            }
+        } else if b[i] == b'#' && (rest.starts_with(b"#[") || rest.starts_with(b"#![")) {
+            (TokenKind::Attribute, attribute_end(b, i))
+        } else if b[i].is_ascii_digit() {
+            let mut j = i + 1;
+            // a dot belongs to the number only when a digit follows (so 2..3 stays a range)
+            while j < b.len() && (is_ident(b[j]) || (b[j] == b'.' && b.get(j + 1).is_some_and(u8::is_ascii_digit))) {
+                j += 1;
+            }
+            (TokenKind::Number, j)
+        } else if is_ident_start(b[i]) {
+            let mut j = i + 1;
+            while j < b.len() && is_ident(b[j]) {
+                j += 1;
+            }
+            let word = &src[i..j];
+            let hashes = b[j..].iter().take_while(|c| **c == b'#').count();
+            if matches!(word, "r" | "br") && b.get(j + hashes) == Some(&b'"') {
+                (TokenKind::Str, raw_string_end(src, j + hashes, hashes))
+            } else if word == "b" && b.get(j) == Some(&b'"') {
+                (TokenKind::Str, string_end(b, j))
+            } else if b.get(j) == Some(&b'!') && b.get(j + 1) != Some(&b'=') {
+                (TokenKind::Macro, j + 1)
+            } else if KEYWORDS.contains(&word) {
+                (TokenKind::Keyword, j)
+            } else if b[i].is_ascii_uppercase() {
+                (TokenKind::Type, j)
+            } else {
+                (TokenKind::Plain, j)
+            }
+        } else {
+            // step a whole utf-8 character
+            (TokenKind::Plain, i + src[i..].chars().next().map(char::len_utf8).unwrap_or(1))
+        };
+        if kind != TokenKind::Plain {
+            if plain < i {
+                tokens.push(Token { kind: TokenKind::Plain, text: &src[plain..i] });
+            }
+            tokens.push(Token { kind, text: &src[i..end] });
+            plain = end;
+        }
+        i = end;
+    }
+    if plain < b.len() {
+        tokens.push(Token { kind: TokenKind::Plain, text: &src[plain..] });
+    }
+    tokens
+}
@@ -0,0 +1,39 @@
+// what: the wasm crate sources embedded at build time, plus a small rust tokenizer for highlighting them
+// why: viewers can check that the code behind the benchmarks and the modbus story is the code in the repo
+// relations: include_str! of wasm-modules/*/src, highlight.rs tokenizer, read by drawer.rs (mounted once by lib.rs)
+//            and demo/sensor_code.rs
+
+mod drawer;
+pub mod highlight;
+
+#[cfg(test)]
+mod tests;
+
+pub use drawer::SourceDrawer;
+
+/// one embedded file, path relative to wasm-modules/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SourceFile {
+    pub path: &'static str,
+    pub text: &'static str,
+}
+
+impl SourceFile {
+    pub fn line_count(&self) -> usize {
+        self.text.lines().count()
+    }
+}
+
+/// the driver behind the demo's sensor comparison (also shown next to its python twin)
+pub const SENSOR_DRIVER: SourceFile =
+    SourceFile { path: "sensor-driver/src/lib.rs", text: include_str!("../../../wasm-modules/sensor-driver/src/lib.rs") };
+
+/// every source file of both crates, crate roots first
+pub const SOURCES: [SourceFile; 6] = [
+    SENSOR_DRIVER,
+    SourceFile { path: "sensor-driver/src/fusion.rs", text: include_str!("../../../wasm-modules/sensor-driver/src/fusion.rs") },
+    SourceFile { path: "modbus-parser/src/lib.rs", text: include_str!("../../../wasm-modules/modbus-parser/src/lib.rs") },
+    SourceFile { path: "modbus-parser/src/mbap.rs", text: include_str!("../../../wasm-modules/modbus-parser/src/mbap.rs") },
+    SourceFile { path: "modbus-parser/src/register_map.rs", text: include_str!("../../../wasm-modules/modbus-parser/src/register_map.rs") },
+    SourceFile { path: "modbus-parser/src/diode.rs", text: include_str!("../../../wasm-modules/modbus-parser/src/diode.rs") },
+];
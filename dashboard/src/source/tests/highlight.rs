@@ -0,0 +1,57 @@
+// what: tests for the embedded sources and the rust highlighter
+// why: the viewer's promise is "this is the real code", so nothing may be dropped, reordered or missing
+
+use crate::source::highlight::{tokenize, Token, TokenKind};
+use crate::source::SOURCES;
+
+fn kinds<'a>(tokens: &[Token<'a>], kind: TokenKind) -> Vec<&'a str> {
+    tokens.iter().filter(|t| t.kind == kind).map(|t| t.text).collect()
+}
+
+#[test]
+fn tokens_rebuild_every_source() {
+    // what: concatenating the tokens of every embedded file gives the file back byte for byte
+    // why: a lossy tokenizer would show code that differs from what was built
+    for file in SOURCES {
+        let rebuilt: String = tokenize(file.text).iter().map(|t| t.text).collect();
+        assert_eq!(rebuilt, file.text, "{}", file.path);
+    }
+}
+
+#[test]
+fn classifies_rust_constructs() {
+    // what: comments, attributes, keywords, types, macros, lifetimes, chars, strings and numbers each get their class
+    // why: these are the classes styles.css colours
+    let src = "/// doc\n#[wasm_bindgen(getter)]\npub fn f(x: &'static str) -> Result<u16, E> { let s = \"a\\\"b\"; vec![0xFF, 2..3]; 'c' != '\\n' }";
+    let tokens = tokenize(src);
+    assert_eq!(kinds(&tokens, TokenKind::Comment), ["/// doc"]);
+    assert_eq!(kinds(&tokens, TokenKind::Attribute), ["#[wasm_bindgen(getter)]"]);
+    assert_eq!(kinds(&tokens, TokenKind::Keyword), ["pub", "fn", "let"]);
+    assert_eq!(kinds(&tokens, TokenKind::Type), ["Result", "E"]);
+    assert_eq!(kinds(&tokens, TokenKind::Macro), ["vec!"]);
+    assert_eq!(kinds(&tokens, TokenKind::Lifetime), ["'static"]);
+    assert_eq!(kinds(&tokens, TokenKind::Str), ["\"a\\\"b\"", "'c'", "'\\n'"]);
+    assert_eq!(kinds(&tokens, TokenKind::Number), ["0xFF", "2", "3"]);
+}
+
+#[test]
+fn raw_strings_and_block_comments_span_lines() {
+    // what: r#"..."# and /* */ are single tokens even across newlines and quotes
+    // why: the demo's wasm modules embed wit and python in raw strings
+    let tokens = tokenize("let a = r#\"x \"q\"\ny\"#; /* one\ntwo */ b");
+    assert_eq!(kinds(&tokens, TokenKind::Str), ["r#\"x \"q\"\ny\"#"]);
+    assert_eq!(kinds(&tokens, TokenKind::Comment), ["/* one\ntwo */"]);
+}
+
+#[test]
+fn every_crate_module_is_embedded() {
+    // what: each `pub mod` in modbus-parser's lib.rs has an embedded file, and the sensor driver exports sensor_check
+    // why: adding a module to a crate without adding it to the viewer would quietly hide code
+    let modbus = SOURCES.iter().find(|f| f.path == "modbus-parser/src/lib.rs").unwrap();
+    for line in modbus.text.lines().filter_map(|l| l.strip_prefix("pub mod ")) {
+        let path = format!("modbus-parser/src/{}.rs", line.trim_end_matches(';'));
+        assert!(SOURCES.iter().any(|f| f.path == path), "{} not embedded", path);
+    }
+    let sensor = SOURCES.iter().find(|f| f.path == "sensor-driver/src/lib.rs").unwrap();
+    assert!(sensor.text.contains("pub fn sensor_check"));
+}
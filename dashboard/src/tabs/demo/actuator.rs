@@ -0,0 +1,64 @@
+// what: the actuate step of the demo loop - a host gpio that checks every pin write against the policy, and relay attempts per world
+// why: sense and decide are harmless if they go wrong; a pin write is not, so it goes through the same deny-by-default grants as the attacks
+// relations: driver and thermostat from wasm-modules/actuator-driver, grants from policy.rs (gpio), temperature from store.rs's
+//            environment reading; shown by actuator_panel.rs
+
+use actuator_driver::{decide, Gpio, Relay, RELAY_PIN};
+use super::policy::{CapabilityPolicy, CapabilityRequest};
+
+/// world the fan relay legitimately belongs to (wit/actuator.wit)
+pub const ACTUATOR_WORLD: &str = "actuator-node";
+/// attempts kept for the panel's table
+pub const MAX_ATTEMPTS: usize = 6;
+
+/// the host's gpio import for one world
+pub struct PolicyGpio<'a> {
+    pub policy: &'a CapabilityPolicy,
+    pub world: &'a str,
+}
+
+impl Gpio for PolicyGpio<'_> {
+    fn write_pin(&mut self, pin: u8, _high: bool) -> Result<(), String> {
+        self.policy.evaluate(self.world, &CapabilityRequest::Gpio(pin))
+    }
+}
+
+/// one set-relay call as the host saw it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Actuation {
+    pub world: String,
+    /// the state the caller asked for
+    pub state: bool,
+    /// the relay after the call
+    pub relay: Relay,
+    pub result: Result<(), String>,
+}
+
+impl Actuation {
+    pub fn summary(&self) -> String {
+        let call = format!("{} → set-relay({})", self.world, if self.state { "on" } else { "off" });
+        match &self.result {
+            Ok(()) => format!("{} ✅ gpio {} written", call, RELAY_PIN),
+            Err(e) => format!("{} ❌ {}", call, e),
+        }
+    }
+}
+
+/// `world` calls set-relay; the relay only moves when the host grants the pin
+pub fn attempt(policy: &CapabilityPolicy, world: &str, relay: Relay, state: bool) -> Actuation {
+    let mut after = relay;
+    let result = after.set(&mut PolicyGpio { policy, world }, state);
+    Actuation { world: world.to_string(), state, relay: after, result }
+}
+
+/// sense → decide → actuate: the thermostat picks the fan state, the actuator world writes it if it changed
+pub fn control_step(policy: &CapabilityPolicy, temperature: f64, relay: Relay) -> Option<Actuation> {
+    let wanted = decide(temperature, relay.on);
+    (wanted != relay.on).then(|| attempt(policy, ACTUATOR_WORLD, relay, wanted))
+}
+
+/// newest attempt first, capped
+pub fn push_attempt(attempts: &mut Vec<Actuation>, attempt: Actuation) {
+    attempts.insert(0, attempt);
+    attempts.truncate(MAX_ATTEMPTS);
+}
@@ -0,0 +1,69 @@
+// what: actuator panel - a virtual fan on the relay pin, the sense→decide→actuate loop, and a rogue set-relay from the sensor world
+// why: the other panels stop at a voted reading; this one acts on it, and shows the same call denied or allowed by the gpio grant alone
+// relations: renders actuator.rs attempts against the store's live policy (editable in policy_panel.rs), temperature from the
+//            store.rs environment reading; rendered by component.rs
+
+use leptos::*;
+use actuator_driver::{Relay, FAN_OFF_C, FAN_ON_C, RELAY_PIN};
+use guardian_types::{EventKind, Runtime};
+use super::actuator::{attempt, control_step, push_attempt, Actuation, ACTUATOR_WORLD};
+use super::policy::WORKER_WORLD;
+use super::store::DemoStore;
+
+#[component]
+pub fn ActuatorPanel(store: DemoStore) -> impl IntoView {
+    let policy = store.policy;
+    let relay = create_rw_signal(Relay::default());
+    let temperature = create_rw_signal(Option::<f64>::None);
+    let attempts = create_rw_signal(Vec::<Actuation>::new());
+
+    let keep = move |a: Actuation| {
+        relay.set(a.relay);
+        attempts.update(|list| push_attempt(list, a));
+    };
+    let run_loop = move |_| {
+        let t = store.environment_reading().reading.temperature;
+        temperature.set(Some(t));
+        if let Some(a) = policy.with_untracked(|p| control_step(p, t, relay.get_untracked())) {
+            keep(a);
+        }
+    };
+    let rogue = move |_| {
+        // a compromised sensor node tries to stop the cooling (or start it, if it is already off)
+        let state = !relay.get_untracked().on;
+        let a = policy.with_untracked(|p| attempt(p, WORKER_WORLD, relay.get_untracked(), state));
+        match &a.result {
+            Err(e) => store.record(EventKind::PolicyBreach, Some(Runtime::Wasm), "rogueActuation", format!("{} set-relay denied: {}", WORKER_WORLD, e)),
+            Ok(()) => store.record(EventKind::PolicyBreach, Some(Runtime::Wasm), "rogueActuation", format!("{} switched gpio {} (granted by policy)", WORKER_WORLD, RELAY_PIN)),
+        }
+        keep(a);
+    };
+
+    view! {
+        <div class="demo-section actuator-section">
+            <h3>"🌀 Actuator"<span class="attack-badge">{format!("sense → decide → actuate · gpio {}", RELAY_PIN)}</span></h3>
+            <p class="section-desc">
+                {format!("The fan relay sits on GPIO {}; only worlds granted that pin in wit/policy.toml can switch it. The thermostat turns the fan on at {}°C and off below {}°C, and the actuator-node world writes the pin. The sensor-node world imports the same set-relay, but has no gpio grant.", RELAY_PIN, FAN_ON_C, FAN_OFF_C)}
+            </p>
+            <div class="actuator-widget">
+                <span class="actuator-fan" class:spinning=move || relay.get().on>"🌀"</span>
+                <span class="metrics-note">
+                    {move || format!("Relay {}", if relay.get().on { "ON" } else { "OFF" })}
+                    {move || temperature.get().map(|t| format!(" · last reading {:.1}°C", t))}
+                </span>
+            </div>
+            <div class="audit-controls">
+                <button class="action-btn" on:click=run_loop>{format!("🔁 Sense → Decide → Actuate ({})", ACTUATOR_WORLD)}</button>
+                <button class="action-btn" on:click=rogue>{format!("💥 Rogue Actuation ({})", WORKER_WORLD)}</button>
+            </div>
+            <Show when=move || attempts.with(|a| !a.is_empty())>
+                <ul class="remote-log actuator-log">
+                    {move || attempts.get().into_iter().map(|a| view! {
+                        <li class:denied=a.result.is_err()>{a.summary()}</li>
+                    }).collect_view()}
+                </ul>
+            </Show>
+            <p class="metrics-note">"Add gpio = [17] to sensor-node in the policy editor and the rogue call goes through: the grant is the only thing in the way."</p>
+        </div>
+    }
+}
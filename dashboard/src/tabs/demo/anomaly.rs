@@ -0,0 +1,199 @@
+// what: ewma-band anomaly detector over the voted telemetry stream, and a simulated stream with single-node and common-mode faults
+// why: 2oo3 voting only catches a node that disagrees; when all three agree on a bad value (shared supply, poisoned calibration)
+//      the voter passes it through and only a model of the signal's normal range notices
+// relations: votes through voting.rs, seeded by raft.rs's Rng, noise scaled by the environment.rs profile, driven by handlers.rs (run_anomaly_scenario), shown by anomaly_panel.rs
+
+use super::raft::Rng;
+use super::voting::{VoteOutcome, VoteStrategy, SCENARIO_READING};
+
+/// weight of the newest sample in the running mean and variance
+pub const EWMA_ALPHA: f64 = 0.2;
+/// band half-width in standard deviations
+pub const BAND_SIGMAS: f64 = 4.0;
+/// samples absorbed before the detector gives verdicts
+pub const WARMUP: usize = 10;
+/// floor on sigma so a very quiet signal doesn't flag its own noise (°C)
+pub const MIN_SIGMA: f64 = 0.05;
+/// consecutive anomalies after which the level is accepted as the new normal
+pub const REBASELINE_AFTER: usize = 8;
+
+/// ticks in one scenario run
+pub const STREAM_TICKS: usize = 60;
+/// real milliseconds per tick
+pub const STREAM_TICK_MS: u64 = 200;
+/// first tick of the injected fault
+pub const FAULT_AT: usize = 40;
+/// ticks the fault lasts
+pub const FAULT_LEN: usize = 4;
+/// offset added to the faulty reading(s) (°C)
+pub const FAULT_OFFSET: f64 = 6.0;
+/// instance whose sensor glitches in the single-node scenario
+pub const GLITCHING: usize = 2;
+/// fixed seed so every run of a scenario draws the same stream
+pub const STREAM_SEED: u64 = 0xbb67_ae85_84ca_a73b;
+
+// ============================================================================
+// detector
+// ============================================================================
+
+/// exponentially weighted mean/variance with a ±k·sigma acceptance band
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EwmaDetector {
+    pub alpha: f64,
+    pub sigmas: f64,
+    mean: f64,
+    var: f64,
+    seen: usize,
+    streak: usize,
+}
+
+impl Default for EwmaDetector {
+    fn default() -> Self {
+        Self::new(EWMA_ALPHA, BAND_SIGMAS)
+    }
+}
+
+impl EwmaDetector {
+    pub fn new(alpha: f64, sigmas: f64) -> Self {
+        Self { alpha, sigmas, mean: 0.0, var: 0.0, seen: 0, streak: 0 }
+    }
+
+    /// band the next sample is judged against, None while warming up
+    pub fn band(&self) -> Option<(f64, f64)> {
+        (self.seen >= WARMUP).then(|| {
+            let half = self.sigmas * self.var.sqrt().max(MIN_SIGMA);
+            (self.mean - half, self.mean + half)
+        })
+    }
+
+    /// judges a sample, then learns from it; anomalies are kept out of the model
+    /// until REBASELINE_AFTER of them in a row say the level really moved
+    pub fn observe(&mut self, x: f64) -> bool {
+        if self.band().is_some_and(|(lo, hi)| x < lo || x > hi) {
+            self.streak += 1;
+            if self.streak >= REBASELINE_AFTER {
+                *self = Self { mean: x, var: 0.0, seen: 1, ..Self::new(self.alpha, self.sigmas) };
+            }
+            return true;
+        }
+        self.streak = 0;
+        if self.seen == 0 {
+            self.mean = x;
+        } else {
+            let diff = x - self.mean;
+            self.mean += self.alpha * diff;
+            self.var = (1.0 - self.alpha) * (self.var + self.alpha * diff * diff);
+        }
+        self.seen += 1;
+        false
+    }
+}
+
+// ============================================================================
+// simulated stream
+// ============================================================================
+
+/// what goes wrong in the stream
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StreamFault {
+    /// no fault: any flag is a false alarm
+    Clean,
+    /// one instance's sensor spikes; the other two outvote it
+    SingleNode,
+    /// all three read the same wrong value (shared supply sag), so the vote is unanimous
+    CommonMode,
+}
+
+impl StreamFault {
+    pub const ALL: [StreamFault; 3] = [StreamFault::Clean, StreamFault::SingleNode, StreamFault::CommonMode];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StreamFault::Clean => "Clean stream",
+            StreamFault::SingleNode => "Single-node spike",
+            StreamFault::CommonMode => "Common-mode spike",
+        }
+    }
+
+    fn hits(&self, node: usize) -> bool {
+        match self {
+            StreamFault::Clean => false,
+            StreamFault::SingleNode => node == GLITCHING,
+            StreamFault::CommonMode => true,
+        }
+    }
+}
+
+/// ticks inside the injected fault
+pub fn in_fault(index: usize) -> bool {
+    (FAULT_AT..FAULT_AT + FAULT_LEN).contains(&index)
+}
+
+/// one sample of the stream and both verdicts on it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StreamTick {
+    pub index: usize,
+    pub outputs: [f64; 3],
+    pub outcome: VoteOutcome,
+    /// instance the voter outvoted, if any
+    pub outvoted: Option<usize>,
+    /// band the voted value was judged against (None while warming up)
+    pub band: Option<(f64, f64)>,
+    pub anomalous: bool,
+}
+
+/// three instances reading a slowly swinging process value with shared and per-sensor noise
+/// (scaled by `noise_scale`: 1.0 is the typical sensor the detector defaults were tuned on)
+pub fn simulate(fault: StreamFault, strategy: VoteStrategy, seed: u64, noise_scale: f64) -> Vec<StreamTick> {
+    let mut rng = Rng::new(seed);
+    let mut detector = EwmaDetector::default();
+    (0..STREAM_TICKS)
+        .map(|index| {
+            let process = SCENARIO_READING + 0.3 * (index as f64 * std::f64::consts::TAU / STREAM_TICKS as f64).sin() + noise_scale * rng.between((-0.05, 0.05));
+            let outputs: [f64; 3] = std::array::from_fn(|node| {
+                let glitch = if in_fault(index) && fault.hits(node) { FAULT_OFFSET } else { 0.0 };
+                process + noise_scale * rng.between((-0.02, 0.02)) + glitch
+            });
+            let outcome = strategy.vote(&outputs.map(Some));
+            let outvoted = match outcome {
+                VoteOutcome::Consensus { value, agreeing: 2 } => {
+                    (0..3).max_by(|&a, &b| (outputs[a] - value).abs().total_cmp(&(outputs[b] - value).abs()))
+                }
+                _ => None,
+            };
+            let band = detector.band();
+            let anomalous = outcome.value().is_some_and(|v| detector.observe(v));
+            StreamTick { index, outputs, outcome, outvoted, band, anomalous }
+        })
+        .collect()
+}
+
+/// fault ticks each layer caught, and flags raised outside the fault window
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Catches {
+    pub tmr: usize,
+    pub detector: usize,
+    pub false_alarms: usize,
+}
+
+pub fn catches(ticks: &[StreamTick]) -> Catches {
+    ticks.iter().fold(Catches::default(), |mut c, t| {
+        let flagged = t.outvoted.is_some() || t.anomalous;
+        if in_fault(t.index) {
+            c.tmr += t.outvoted.is_some() as usize;
+            c.detector += t.anomalous as usize;
+        } else {
+            c.false_alarms += flagged as usize;
+        }
+        c
+    })
+}
+
+/// scenario progress for the panel
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnomalyRun {
+    pub fault: StreamFault,
+    pub ticks: Vec<StreamTick>,
+    /// ticks revealed so far
+    pub shown: usize,
+}
@@ -0,0 +1,134 @@
+// what: telemetry anomaly scenario - voted stream chart with the ewma band, and what the voter and the detector each caught
+// why: shows the gap tmr leaves when all three nodes agree on a bad value, and the detector filling it
+// relations: fires handlers.rs run_anomaly_scenario, reads the store.rs anomaly signal from anomaly.rs, noise profile from settings/; rendered by component.rs
+
+use leptos::*;
+use super::anomaly::{catches, in_fault, simulate, StreamFault, StreamTick, BAND_SIGMAS, EWMA_ALPHA, FAULT_AT, FAULT_LEN, FAULT_OFFSET, STREAM_SEED, STREAM_TICKS};
+use crate::settings::use_settings;
+use super::environment::stream_noise_scale;
+use super::store::DemoStore;
+
+/// chart box (svg user units)
+const CHART_W: f64 = 600.0;
+const CHART_H: f64 = 160.0;
+
+/// value range that keeps every output and band edge of a run on the chart
+fn y_range(ticks: &[StreamTick]) -> (f64, f64) {
+    let (lo, hi) = ticks
+        .iter()
+        .flat_map(|t| t.outputs.into_iter().chain(t.band.map(|(lo, hi)| [lo, hi]).into_iter().flatten()))
+        .fold((f64::MAX, f64::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)));
+    let pad = (hi - lo).max(1.0) * 0.05;
+    (lo - pad, hi + pad)
+}
+
+fn point(index: usize, value: f64, (lo, hi): (f64, f64)) -> (f64, f64) {
+    (index as f64 * CHART_W / (STREAM_TICKS - 1) as f64, CHART_H - (value - lo) / (hi - lo) * CHART_H)
+}
+
+fn points(iter: impl Iterator<Item = (usize, f64)>, range: (f64, f64)) -> String {
+    iter.map(|(i, v)| {
+        let (x, y) = point(i, v, range);
+        format!("{:.1},{:.1}", x, y)
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// "caught 4/4" style cell for the comparison table
+fn catch_cell(caught: usize) -> View {
+    let class = if caught > 0 { "vote-flagged" } else { "vote-halted" };
+    let text = if caught > 0 { format!("caught {}/{}", caught, FAULT_LEN) } else { "missed".to_string() };
+    view! { <td class=class>{text}</td> }.into_view()
+}
+
+#[component]
+pub fn AnomalyPanel(store: DemoStore) -> impl IntoView {
+    let DemoStore { voter, cluster, control, .. } = store;
+    let settings = use_settings();
+    let run = cluster.anomaly;
+    let chart = move || run.get().map(|run| {
+        let range = y_range(&run.ticks);
+        let shown = &run.ticks[..run.shown];
+        let upper = shown.iter().filter_map(|t| t.band.map(|(_, hi)| (t.index, hi)));
+        let lower = shown.iter().rev().filter_map(|t| t.band.map(|(lo, _)| (t.index, lo)));
+        let band = points(upper.chain(lower), range);
+        let voted = points(shown.iter().filter_map(|t| t.outcome.value().map(|v| (t.index, v))), range);
+        let fault_x = point(FAULT_AT, 0.0, range).0;
+        let fault_w = point(FAULT_LEN, 0.0, range).0;
+        view! {
+            <svg class="anomaly-chart" viewBox=format!("0 0 {} {}", CHART_W, CHART_H) preserveAspectRatio="none">
+                {(run.fault != StreamFault::Clean).then(|| view! {
+                    <rect class="anomaly-window" x=format!("{:.1}", fault_x) y="0" width=format!("{:.1}", fault_w) height=CHART_H.to_string() />
+                })}
+                <polygon class="anomaly-band" points=band />
+                <polyline class="anomaly-voted" points=voted />
+                {shown.iter().filter_map(|t| t.outvoted.map(|node| {
+                    let (x, y) = point(t.index, t.outputs[node], range);
+                    view! { <circle class="anomaly-outvoted" cx=format!("{:.1}", x) cy=format!("{:.1}", y) r="3" /> }
+                })).collect_view()}
+                {shown.iter().filter(|t| t.anomalous).filter_map(|t| t.outcome.value().map(|v| {
+                    let (x, y) = point(t.index, v, range);
+                    view! { <circle class="anomaly-flag" cx=format!("{:.1}", x) cy=format!("{:.1}", y) r="4" /> }
+                })).collect_view()}
+            </svg>
+            <p class="metrics-note">
+                {format!("{} · tick {}/{} · ", run.fault.label(), run.shown, run.ticks.len())}
+                <span class="anomaly-key-outvoted">"● outvoted by TMR"</span>
+                " · "
+                <span class="anomaly-key-flag">"● flagged by detector"</span>
+            </p>
+        }
+    });
+
+    view! {
+        <div class="demo-section anomaly-section">
+            <h3>"📈 Telemetry Anomaly Detection"<span class="attack-badge">"EWMA band + TMR"</span></h3>
+            <p class="section-desc">
+                {format!("The voted reading is checked against an exponentially weighted mean ±{}σ (α = {}). A +{}°C spike hits one node, or all three at once - TMR only sees disagreement, the detector only sees the signal leaving its normal range.", BAND_SIGMAS, EWMA_ALPHA, FAULT_OFFSET)}
+            </p>
+            <table class="capability-matrix voting-matrix">
+                <tr>
+                    <th>"Scenario"</th>
+                    <th>{move || format!("TMR ({})", voter.get().label())}</th>
+                    <th>"EWMA detector"</th>
+                    <th>"False alarms"</th>
+                </tr>
+                {move || {
+                    let strategy = voter.get();
+                    let noise = settings.with(|s| stream_noise_scale(s.environment.noise));
+                    StreamFault::ALL.into_iter().map(|fault| {
+                        let ticks = simulate(fault, strategy, STREAM_SEED, noise);
+                        let c = catches(&ticks);
+                        view! {
+                            <tr>
+                                <td class="world-name">{fault.label()}</td>
+                                {if fault == StreamFault::Clean {
+                                    view! { <td>"-"</td><td>"-"</td> }.into_view()
+                                } else {
+                                    view! { {catch_cell(c.tmr)}{catch_cell(c.detector)} }.into_view()
+                                }}
+                                <td>{c.false_alarms}</td>
+                            </tr>
+                        }
+                    }).collect_view()
+                }}
+            </table>
+            <div class="voting-controls">
+                {StreamFault::ALL.into_iter().map(|fault| view! {
+                    <button
+                        class="action-btn"
+                        disabled=move || control.is_running.get() || !store.has_quorum()
+                        on:click=move |_| store.run_anomaly_scenario(fault)
+                    >
+                        {fault.label()}
+                    </button>
+                }).collect_view()}
+            </div>
+            {chart}
+            {move || run.with(|r| r.as_ref().is_some_and(|r| r.shown > 0 && r.ticks[..r.shown].iter().any(|t| in_fault(t.index) && t.anomalous && t.outvoted.is_none()))).then(|| view! {
+                <p class="audit-status">"⚠️ All three nodes agreed on the spike - the voter passed it downstream and only the detector flagged it."</p>
+            })}
+        </div>
+    }
+}
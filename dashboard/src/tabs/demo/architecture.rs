@@ -0,0 +1,207 @@
+// what: 1oo1 / 1oo2D / 2oo3 safety architectures run through one shared fault-injection sequence
+// why: the question safety engineers ask is not "does tmr work" but "what does it buy over simplex or duplex"
+// relations: rendered by architecture_panel.rs
+
+/// simulated mission length (seconds)
+pub const MISSION_S: u32 = 3600;
+/// process restart after a trip clears (seconds)
+pub const RESTART_S: u32 = 120;
+
+/// voting arrangement of the safety channels
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Architecture {
+    /// simplex: one channel, trips on any detected fault
+    OneOoOne,
+    /// duplex with diagnostics: either channel trips, a diagnosed channel is switched out
+    OneOoTwoD,
+    /// triple modular redundancy: two of three must agree
+    TwoOoThree,
+}
+
+impl Architecture {
+    pub const ALL: [Architecture; 3] = [Architecture::OneOoOne, Architecture::OneOoTwoD, Architecture::TwoOoThree];
+
+    pub fn channels(&self) -> usize {
+        match self {
+            Architecture::OneOoOne => 1,
+            Architecture::OneOoTwoD => 2,
+            Architecture::TwoOoThree => 3,
+        }
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Architecture::OneOoOne => "1oo1",
+            Architecture::OneOoTwoD => "1oo2D",
+            Architecture::TwoOoThree => "2oo3",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.id() == id)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Architecture::OneOoOne => "1oo1 (simplex)",
+            Architecture::OneOoTwoD => "1oo2D (duplex + diagnostics)",
+            Architecture::TwoOoThree => "2oo3 (TMR)",
+        }
+    }
+
+    /// trip votes needed out of `active` undiagnosed channels
+    fn required(&self, active: usize) -> usize {
+        match (self, active) {
+            (Architecture::TwoOoThree, 3) => 2,
+            _ => 1,
+        }
+    }
+
+    /// trips without a demand: fail-safe when no channel is left, otherwise on enough (false) trip votes
+    fn tripped(&self, channels: &[ChannelState]) -> bool {
+        let active: Vec<&ChannelState> = channels.iter().filter(|c| **c != ChannelState::Diagnosed).collect();
+        active.is_empty() || active.iter().filter(|c| ***c == ChannelState::FailedSafe).count() >= self.required(active.len())
+    }
+
+    /// would trip on a real demand: healthy and fail-safe channels vote trip, dangerous ones stay silent
+    fn protects(&self, channels: &[ChannelState]) -> bool {
+        let active: Vec<&ChannelState> = channels.iter().filter(|c| **c != ChannelState::Diagnosed).collect();
+        active.is_empty() || active.iter().filter(|c| ***c != ChannelState::FailedDangerous).count() >= self.required(active.len())
+    }
+}
+
+/// how an injected fault shows up
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FaultKind {
+    /// undetected, output says "trip" (spurious)
+    Safe,
+    /// undetected, output stuck at "no trip"
+    Dangerous,
+    /// caught by self-diagnostics (trap, watchdog)
+    Detected,
+}
+
+/// one channel's condition
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelState {
+    Ok,
+    FailedSafe,
+    FailedDangerous,
+    /// diagnosed and switched out until repaired
+    Diagnosed,
+}
+
+impl From<FaultKind> for ChannelState {
+    fn from(kind: FaultKind) -> Self {
+        match kind {
+            FaultKind::Safe => ChannelState::FailedSafe,
+            FaultKind::Dangerous => ChannelState::FailedDangerous,
+            FaultKind::Detected => ChannelState::Diagnosed,
+        }
+    }
+}
+
+/// an injected fault: channel `channel` fails at `at_s` and is repaired `repair_s` later
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FaultEvent {
+    pub at_s: u32,
+    pub channel: usize,
+    pub kind: FaultKind,
+    pub repair_s: u32,
+}
+
+/// the shared sequence; faults on channels an architecture does not have never happen to it
+pub const FAULT_SEQUENCE: [FaultEvent; 7] = [
+    FaultEvent { at_s: 300, channel: 0, kind: FaultKind::Safe, repair_s: 240 },
+    FaultEvent { at_s: 900, channel: 1, kind: FaultKind::Detected, repair_s: 300 },
+    FaultEvent { at_s: 1200, channel: 2, kind: FaultKind::Safe, repair_s: 240 },
+    FaultEvent { at_s: 1500, channel: 0, kind: FaultKind::Dangerous, repair_s: 600 },
+    FaultEvent { at_s: 2400, channel: 0, kind: FaultKind::Detected, repair_s: 300 },
+    FaultEvent { at_s: 2700, channel: 1, kind: FaultKind::Safe, repair_s: 240 },
+    FaultEvent { at_s: 3000, channel: 2, kind: FaultKind::Dangerous, repair_s: 300 },
+];
+
+/// contiguous run of one condition on a timeline row
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment<T> {
+    pub from_s: u32,
+    pub to_s: u32,
+    pub state: T,
+}
+
+/// what one architecture did over the mission
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArchitectureRun {
+    pub architecture: Architecture,
+    pub spurious_trips: u32,
+    /// process down: tripped plus restart time
+    pub downtime_s: u32,
+    /// a real demand would not have tripped
+    pub unprotected_s: u32,
+    /// per-channel timelines
+    pub channels: Vec<Vec<Segment<ChannelState>>>,
+    /// process timeline: true while down
+    pub process: Vec<Segment<bool>>,
+}
+
+impl ArchitectureRun {
+    pub fn availability(&self) -> f64 {
+        1.0 - self.downtime_s as f64 / MISSION_S as f64
+    }
+}
+
+fn push<T: PartialEq + Copy>(row: &mut Vec<Segment<T>>, t: u32, state: T) {
+    match row.last_mut() {
+        Some(last) if last.state == state => last.to_s = t + 1,
+        _ => row.push(Segment { from_s: t, to_s: t + 1, state }),
+    }
+}
+
+/// steps the mission second by second
+pub fn simulate(architecture: Architecture, faults: &[FaultEvent]) -> ArchitectureRun {
+    let n = architecture.channels();
+    let mut run = ArchitectureRun {
+        architecture,
+        spurious_trips: 0,
+        downtime_s: 0,
+        unprotected_s: 0,
+        channels: vec![Vec::new(); n],
+        process: Vec::new(),
+    };
+    let mut was_tripped = false;
+    let mut restart_left = 0u32;
+    for t in 0..MISSION_S {
+        let states: Vec<ChannelState> = (0..n)
+            .map(|ch| {
+                // the latest fault still under repair decides the channel's state
+                faults.iter()
+                    .rfind(|f| f.channel == ch && f.at_s <= t && t < f.at_s + f.repair_s)
+                    .map(|f| f.kind.into())
+                    .unwrap_or(ChannelState::Ok)
+            })
+            .collect();
+        let tripped = architecture.tripped(&states);
+        if tripped && !was_tripped {
+            run.spurious_trips += 1;
+        }
+        if was_tripped && !tripped {
+            restart_left = RESTART_S;
+        }
+        let down = if tripped {
+            true
+        } else if restart_left > 0 {
+            restart_left -= 1;
+            true
+        } else {
+            false
+        };
+        was_tripped = tripped;
+        run.downtime_s += down as u32;
+        run.unprotected_s += !architecture.protects(&states) as u32;
+        for (row, state) in run.channels.iter_mut().zip(&states) {
+            push(row, t, *state);
+        }
+        push(&mut run.process, t, down);
+    }
+    run
+}
@@ -0,0 +1,115 @@
+// what: architecture selector, comparison table and per-channel timeline for the shared fault sequence
+// why: shows availability (spurious trips) against safety (unprotected time) for simplex, duplex and tmr side by side
+// relations: renders architecture.rs simulate(); rendered by component.rs
+
+use leptos::*;
+use super::architecture::{simulate, Architecture, ArchitectureRun, ChannelState, FaultKind, Segment, FAULT_SEQUENCE, MISSION_S, RESTART_S};
+
+/// timeline box (svg user units)
+const TIMELINE_W: f64 = 600.0;
+const ROW_H: f64 = 14.0;
+const ROW_GAP: f64 = 4.0;
+
+fn channel_class(state: ChannelState) -> &'static str {
+    match state {
+        ChannelState::Ok => "arch-ok",
+        ChannelState::FailedSafe => "arch-safe",
+        ChannelState::FailedDangerous => "arch-dangerous",
+        ChannelState::Diagnosed => "arch-diagnosed",
+    }
+}
+
+fn kind_label(kind: FaultKind) -> &'static str {
+    match kind {
+        FaultKind::Safe => "safe (spurious trip signal)",
+        FaultKind::Dangerous => "dangerous (stuck, undetected)",
+        FaultKind::Detected => "detected by diagnostics",
+    }
+}
+
+fn x(t: u32) -> f64 {
+    t as f64 / MISSION_S as f64 * TIMELINE_W
+}
+
+/// one svg row of segments
+fn timeline_row<T: Copy>(row: usize, segments: &[Segment<T>], class: impl Fn(T) -> &'static str) -> impl IntoView {
+    let y = row as f64 * (ROW_H + ROW_GAP);
+    segments.iter().map(|s| view! {
+        <rect class=class(s.state) x=format!("{:.1}", x(s.from_s)) y=format!("{:.1}", y) width=format!("{:.1}", x(s.to_s) - x(s.from_s)) height=ROW_H.to_string() />
+    }).collect_view()
+}
+
+fn timeline(run: &ArchitectureRun) -> impl IntoView {
+    let rows = run.channels.len() + 1;
+    let height = rows as f64 * (ROW_H + ROW_GAP);
+    let labels = (0..run.channels.len()).map(|ch| format!("Ch{}", ch)).chain(std::iter::once("Process".to_string()));
+    view! {
+        <div class="arch-timeline">
+            <div class="arch-labels">
+                {labels.map(|l| view! { <span>{l}</span> }).collect_view()}
+            </div>
+            <svg viewBox=format!("0 0 {} {}", TIMELINE_W, height) preserveAspectRatio="none" style=format!("height: {}rem", rows as f64 * 1.1)>
+                {run.channels.iter().enumerate().map(|(row, segments)| timeline_row(row, segments, channel_class)).collect_view()}
+                {timeline_row(run.channels.len(), &run.process, |down| if down { "arch-down" } else { "arch-ok" })}
+            </svg>
+        </div>
+    }
+}
+
+#[component]
+pub fn ArchitecturePanel() -> impl IntoView {
+    let (selected, set_selected) = create_signal(Architecture::TwoOoThree);
+    // the sequence is fixed, so every architecture's run is computed once
+    let runs = store_value(Architecture::ALL.map(|a| simulate(a, &FAULT_SEQUENCE)));
+    let run_for = move |a: Architecture| runs.with_value(|r| r.iter().find(|run| run.architecture == a).cloned());
+
+    view! {
+        <div class="demo-section architecture-section">
+            <h3>"🏗️ Architecture Comparison"<span class="attack-badge">"1oo1 · 1oo2D · 2oo3"</span></h3>
+            <p class="section-desc">
+                {format!("The same {} faults injected over a simulated {} min mission; faults on channels an architecture lacks never happen to it. A trip clears after repair plus a {}s restart.", FAULT_SEQUENCE.len(), MISSION_S / 60, RESTART_S)}
+            </p>
+            <table class="capability-matrix voting-matrix arch-matrix">
+                <tr>
+                    <th>"Architecture"</th>
+                    <th>"Channels"</th>
+                    <th>"Spurious trips"</th>
+                    <th>"Downtime"</th>
+                    <th>"Availability"</th>
+                    <th>"Unprotected"</th>
+                </tr>
+                {Architecture::ALL.into_iter().filter_map(run_for).map(|run| {
+                    let a = run.architecture;
+                    let exposed = run.unprotected_s > 0;
+                    view! {
+                        <tr class:voting-selected=move || selected.get() == a on:click=move |_| set_selected.set(a)>
+                            <td class="world-name">{a.label()}</td>
+                            <td>{a.channels()}</td>
+                            <td>{run.spurious_trips}</td>
+                            <td>{format!("{}s", run.downtime_s)}</td>
+                            <td>{format!("{:.2}%", run.availability() * 100.0)}</td>
+                            <td class:vote-halted=exposed>{format!("{}s", run.unprotected_s)}</td>
+                        </tr>
+                    }
+                }).collect_view()}
+            </table>
+            <div class="voting-controls">
+                <select class="network-select" on:change=move |ev| if let Some(a) = Architecture::from_id(&event_target_value(&ev)) { set_selected.set(a) }>
+                    {Architecture::ALL.into_iter().map(|a| view! { <option value=a.id() selected=move || selected.get() == a>{a.label()}</option> }).collect_view()}
+                </select>
+                <span class="arch-legend">
+                    <span class="arch-key arch-safe"></span>"safe fault "
+                    <span class="arch-key arch-dangerous"></span>"dangerous "
+                    <span class="arch-key arch-diagnosed"></span>"diagnosed "
+                    <span class="arch-key arch-down"></span>"process down"
+                </span>
+            </div>
+            {move || run_for(selected.get()).map(|run| timeline(&run))}
+            <ul class="arch-faults">
+                {FAULT_SEQUENCE.iter().map(|f| view! {
+                    <li>{format!("t={}s · Ch{} · {} · repaired after {}s", f.at_s, f.channel, kind_label(f.kind), f.repair_s)}</li>
+                }).collect_view()}
+            </ul>
+        </div>
+    }
+}
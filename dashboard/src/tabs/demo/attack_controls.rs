@@ -0,0 +1,134 @@
+// what: security / availability attack buttons plus run-all and reset
+// why: split out of component.rs; buttons call handlers.rs methods on the store
+// relations: reads store.rs ControlSlice and its payloads (security attacks wait, behind a skeleton, for the attack-code module), voting.rs for the degraded banner, crate::settings for the chaos badge; presses go through handlers.rs press (quiz mode asks first), run-all appends the library.rs scenarios; button titles carry the attacks.rs technique ids; rendered by component.rs
+use leptos::*;
+use crate::settings::use_settings;
+use crate::tabs::skeleton::Skeleton;
+use super::attacks::{get_attack_config, AVAILABILITY_ATTACKS};
+use super::journal::EventKind;
+use super::handlers::run_all_with;
+use super::store::DemoStore;
+use super::voting::healthy_count;
+
+/// one attack button; highlights while its attack is running
+#[component]
+fn AttackButton(store: DemoStore, attack: &'static str, label: &'static str, title: &'static str, #[prop(optional)] leader: bool) -> impl IntoView {
+    let control = store.control;
+    view! {
+        <button 
+            class="attack-btn"
+            class:leader-btn=leader
+            class:running=move || control.selected_attack.get() == attack && control.is_running.get()
+            // a security attack has no payload until the attack-code module is in
+            disabled=move || control.is_running.get() || !(AVAILABILITY_ATTACKS.contains(&attack) || store.payloads_ready())
+            title=format!("{} · ATT&CK for ICS {}", title, get_attack_config(attack).techniques.join(", "))
+            on:click=move |_| store.press(attack)
+        >
+            {label}
+        </button>
+    }
+}
+
+#[component]
+pub fn AttackControls(store: DemoStore) -> impl IntoView {
+    let settings = use_settings();
+    let control = store.control;
+    view! {
+        // ================================================================
+        // SECURITY ATTACKS SECTION
+        // ================================================================
+        <div class="attack-group security-group">
+            <h3>
+                "☠️ Security Attacks"<span class="attack-badge">"WIT Capability Denial"</span>
+                <Show when=move || settings.with(|s| s.chaos.enabled)>
+                    <span class="attack-badge chaos-badge">{move || format!("🌀 Chaos every {}s", settings.with(|s| s.chaos.interval_s))}</span>
+                </Show>
+            </h3>
+            <p class="section-desc">"WASM blocks at boundary via WIT or traps at the engine's limits — Python crashes"</p>
+            {move || store.payloads.with(|p| match p {
+                Some(Ok(_)) => ().into_view(),
+                Some(Err(e)) => view! { <p class="hardware-note">{format!("⚠️ Attack code failed to load — security attacks are unavailable ({})", e)}</p> }.into_view(),
+                None => view! { <Skeleton label="☠️ Loading attack code (first visit only)..." lines=2 /> }.into_view(),
+            })}
+            <div class="attack-buttons">
+                <AttackButton store=store attack="bufferOverflow" label="💥 Buffer Overflow" title="Memory corruption attack - WIT denies malloc-large()" />
+                <AttackButton store=store attack="dataExfil" label="📤 Data Exfil" title="Network exfiltration - WIT denies open-socket()" />
+                <AttackButton store=store attack="pathTraversal" label="📁 Path Traversal" title="Filesystem probe - WIT denies read-file()" />
+                <AttackButton store=store attack="stackExhaustion" label="🌀 Stack Exhaustion" title="Unbounded recursion - Python RecursionError vs the engine's stack-limit trap" />
+                <AttackButton store=store attack="lengthOverflow" label="📏 Length Overflow" title="Crafted Modbus length field - Python MemoryError vs the Rust parser's typed error" />
+                <AttackButton store=store attack="envTheft" label="🔑 Env Theft" title="Credential theft from os.environ - a WASI component only sees explicitly passed config" />
+                <AttackButton store=store attack="lateralMovement" label="🕸️ Lateral Movement" title="Real fetch/WebSocket reaches to internal hosts - browser sandbox vs WASI socket denial" />
+            </div>
+        </div>
+        
+        // ================================================================
+        // AVAILABILITY ATTACKS SECTION
+        // ================================================================
+        <div class="attack-group availability-group">
+            <h3>"⚡ Availability Attacks"<span class="attack-badge">"Raft Leader Election"</span></h3>
+            <p class="section-desc">"Crash the leader — compare election recovery time; kill two nodes to see the voter halt"</p>
+            <div class="attack-buttons">
+                <AttackButton store=store attack="killLeader" label="🗡️ Kill Leader" title="Force crash on leader (simulates OOM, panic, hardware failure)" leader=true />
+                <AttackButton store=store attack="heartbeatTimeout" label="⏱️ Heartbeat Timeout" title="Leader becomes unresponsive (simulates network partition, deadlock)" leader=true />
+                <button
+                    class="attack-btn leader-btn"
+                    title="Kill the leader and a follower at once - one survivor cannot form a 2oo3 majority"
+                    disabled=move || control.is_running.get() || !store.has_quorum()
+                    on:click=move |_| store.trigger_quorum_loss()
+                >
+                    "☠️ Kill Two Nodes"
+                </button>
+            </div>
+            <Show when=move || !store.has_quorum()>
+                <div class="degraded-banner" role="alert">
+                    <span class="degraded-title">
+                        {move || format!("⛔ DEGRADED — no quorum ({}/3 healthy)", store.cluster.instance_states.with(|s| healthy_count(s)))}
+                    </span>
+                    <span class="degraded-detail">"Consensus output halted: no votes, no log commits, no Modbus writes. Attacks still reach Python; WASM stays in its safe state."</span>
+                    <button
+                        class="action-btn"
+                        disabled=move || control.is_running.get()
+                        on:click=move |_| store.restore_quorum()
+                    >
+                        "🛠️ Restore Nodes"
+                    </button>
+                </div>
+            </Show>
+        </div>
+        
+        // ================================================================
+        // GLOBAL ACTIONS
+        // ================================================================
+        <div class="attack-actions">
+            <button 
+                class="action-btn runall" 
+                title=move || format!("Run all {} attacks sequentially, library scenarios last", run_all_with(store.library.with(|l| l.run_ids())).len())
+                disabled=move || control.is_running.get() || !store.payloads_ready()
+                on:click=move |_| store.run_all_attacks()
+            >
+                "🔥 Run All Attacks"
+            </button>
+            <button 
+                class="action-btn reset" 
+                title="Reset all stats and terminals"
+                disabled=move || control.is_running.get()
+                on:click=move |_| {
+                    store.record(EventKind::Reset, None, "", "Stats and terminals cleared".to_string());
+                    store.reset_with_undo(js_sys::Date::now());
+                }
+            >
+                "🔄 Reset"
+            </button>
+            <Show when=move || store.undo.with(|u| u.is_some())>
+                <button
+                    class="action-btn undo"
+                    title="Bring back the stats, terminals and node state cleared by Reset"
+                    disabled=move || control.is_running.get()
+                    on:click=move |_| { let _ = store.undo_reset(); }
+                >
+                    "↩️ Undo Reset"
+                </button>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,117 @@
+// what: audit export panel (syslog / cef download and optional collector post) plus the copy/email session summary
+// why: lets security teams see guardian events in the format their siem ingests
+// relations: used by component.rs, formats journal.rs and timeline.rs annotations via siem.rs and summary.rs, methodology from methodology_panel.rs, uses wasm.rs helpers
+
+use leptos::*;
+use super::journal::AuditEvent;
+use super::methodology::Disclosure;
+use super::siem::{annotation_record, export, methodology_record, ExportFormat};
+use super::store::PerfSlice;
+use super::summary::{render, SummaryFormat};
+use super::timeline::Annotation;
+use super::wasm::{copy_to_clipboard, download_text, post_text};
+use crate::diagnostics::caps::use_browser_caps;
+use crate::settings::use_settings;
+
+/// number of formatted lines shown in the preview
+const PREVIEW_LINES: usize = 4;
+
+/// siem export controls for the session journal
+#[component]
+pub fn AuditPanel(journal: RwSignal<Vec<AuditEvent>>, annotations: RwSignal<Vec<Annotation>>, perf: PerfSlice, methodology: Callback<(), Vec<Disclosure>>) -> impl IntoView {
+    let (format, set_format) = create_signal(ExportFormat::Syslog);
+    let (collector_url, set_collector_url) = create_signal(use_settings().with_untracked(|s| s.endpoints.siem_collector.clone()));
+    let (post_status, set_post_status) = create_signal(Option::<String>::None);
+
+    let preview = move || {
+        let events = journal.get();
+        let start = events.len().saturating_sub(PREVIEW_LINES);
+        export(&events[start..], format.get())
+    };
+
+    // downloads and posts lead with the methodology record and end with the presenter's notes; the preview shows events only
+    let full_export = move |fmt: ExportFormat| {
+        let mut out = format!("{}\n{}", methodology_record(&methodology.call(()), js_sys::Date::now(), fmt), export(&journal.get(), fmt));
+        annotations.with(|list| list.iter().for_each(|a| out.push_str(&format!("\n{}", annotation_record(a, fmt)))));
+        out
+    };
+
+    let download = move |_| {
+        let fmt = format.get();
+        download_text(fmt.file_name(), "text/plain", &full_export(fmt));
+    };
+
+    let post = move |_| {
+        let url = collector_url.get();
+        if url.is_empty() { return; }
+        let body = full_export(format.get());
+        set_post_status.set(Some("⏳ Sending...".to_string()));
+        spawn_local(async move {
+            let status = match post_text(&url, "text/plain", &body).await {
+                Ok(code) => format!("✅ Collector responded HTTP {}", code),
+                Err(e) => format!("❌ {}", e),
+            };
+            set_post_status.set(Some(status));
+        });
+    };
+
+    // session summary for tickets (markdown) and emails (plain text)
+    let (summary_status, set_summary_status) = create_signal(Option::<String>::None);
+    let summary = move |format: SummaryFormat| {
+        let numbers = perf.session_numbers();
+        let browser = web_sys::window().and_then(|w| w.navigator().user_agent().ok()).unwrap_or_else(|| "unknown".to_string());
+        journal.with_untracked(|events| render(&numbers, events, &browser, &use_browser_caps().missing(), &methodology.call(()), js_sys::Date::now(), format))
+    };
+    let copy_summary = move |_| {
+        copy_to_clipboard(&summary(SummaryFormat::Markdown));
+        set_summary_status.set(Some("📋 Markdown summary copied".to_string()));
+    };
+    let email_summary = move |_| {
+        let body = String::from(js_sys::encode_uri_component(&summary(SummaryFormat::Text)));
+        if let Some(window) = web_sys::window() {
+            let _ = window.location().set_href(&format!("mailto:?subject=Guardian%20One%20demo%20summary&body={}", body));
+        }
+    };
+
+    view! {
+        <div class="audit-panel">
+            <h4>"📤 Audit Export"<span class="demo-badge">{move || format!("{} events", journal.get().len())}</span></h4>
+            <div class="audit-controls">
+                <select
+                    class="network-select"
+                    on:change=move |ev| set_format.set(if event_target_value(&ev) == "cef" { ExportFormat::Cef } else { ExportFormat::Syslog })
+                >
+                    <option value="syslog" selected>"RFC 5424 Syslog"</option>
+                    <option value="cef">"ArcSight CEF"</option>
+                </select>
+                <button class="action-btn" disabled=move || journal.get().is_empty() on:click=download>"⬇️ Download"</button>
+            </div>
+            <pre class="audit-preview">{move || {
+                let text = preview();
+                if text.is_empty() { "No events yet — run an attack".to_string() } else { text }
+            }}</pre>
+            <div class="audit-controls">
+                <input
+                    class="collector-url"
+                    type="url"
+                    placeholder="https://collector.example/ingest (optional)"
+                    prop:value=move || collector_url.get()
+                    on:input=move |ev| set_collector_url.set(event_target_value(&ev))
+                />
+                <button
+                    class="action-btn"
+                    disabled=move || collector_url.get().is_empty() || journal.get().is_empty()
+                    on:click=post
+                >
+                    "📡 POST"
+                </button>
+            </div>
+            {move || post_status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+            <div class="audit-controls">
+                <button class="action-btn" on:click=copy_summary>"📋 Copy Summary"</button>
+                <button class="action-btn" on:click=email_summary>"✉️ Email Summary"</button>
+            </div>
+            {move || summary_status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+        </div>
+    }
+}
@@ -0,0 +1,24 @@
+// what: plain-javascript baseline for the sensor workload
+// why: answers "but JS is already fast" by timing the same driver in the page's own engine
+// relations: called from handlers.rs run_sensor_comparison, shown by the JsModel in runtime_model.rs
+
+use wasm_bindgen::JsValue;
+use super::wasm::now;
+
+/// same BME280 driver as the python snippet, as a js function body
+pub const JS_SENSOR_DRIVER: &str = r#"
+class BME280 {
+    constructor() { this.cal = [27504, 26435, -1000]; }
+    read() { return { temp: 23.5, hum: 45.2, pres: 1013.25 }; }
+}
+const driver = new BME280();
+return driver.read();
+"#;
+
+/// compiles and runs the js driver once, returns elapsed ms
+pub fn run_js_sensor() -> Result<f64, String> {
+    let start = now();
+    let driver = js_sys::Function::new_no_args(JS_SENSOR_DRIVER);
+    driver.call0(&JsValue::NULL).map_err(|e| format!("{:?}", e))?;
+    Ok(now() - start)
+}
@@ -0,0 +1,85 @@
+// what: per-instance resource budget (linear memory pages, table slots) and the ring buffer workload that fills it
+// why: the runtime column quoted a fixed "~2MB per instance"; this is what each live instance actually holds against its cap
+// relations: sampled by instances.rs from real WebAssembly.Memory/Table objects, kept in the store.rs budgets signal,
+//            shown by node_strip.rs through runtime_model.rs
+
+use super::probes::WASM_PAGE_BYTES;
+
+/// bytes one processed reading writes into an instance's telemetry ring (readings, parse scratch, vote record)
+pub const WORKLOAD_BYTES: u32 = 16 * 1024;
+
+/// what one live instance holds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstanceBudget {
+    pub memory_pages: u32,
+    pub max_pages: u32,
+    pub table_len: u32,
+    pub table_max: u32,
+    /// bytes of the ring in use (wraps when memory can't grow)
+    pub used_bytes: u32,
+    /// memory.grow calls the engine refused at the cap
+    pub grow_denied: u32,
+}
+
+impl InstanceBudget {
+    pub fn memory_bytes(&self) -> u64 {
+        self.memory_pages as u64 * WASM_PAGE_BYTES as u64
+    }
+
+    pub fn max_bytes(&self) -> u64 {
+        self.max_pages as u64 * WASM_PAGE_BYTES as u64
+    }
+
+    /// share of the memory cap allocated
+    pub fn fraction(&self) -> f64 {
+        if self.max_pages == 0 { 0.0 } else { self.memory_pages as f64 / self.max_pages as f64 }
+    }
+
+    /// pages to grow by so `bytes` more fit after the used part of the ring
+    pub fn pages_needed(&self, bytes: u32) -> u32 {
+        let needed = (self.used_bytes as u64 + bytes as u64).div_ceil(WASM_PAGE_BYTES as u64) as u32;
+        needed.saturating_sub(self.memory_pages)
+    }
+
+    /// "128 KiB / 2 MiB · table 1/4"
+    pub fn label(&self) -> String {
+        format!("{} / {} · table {}/{}", format_bytes(self.memory_bytes()), format_bytes(self.max_bytes()), self.table_len, self.table_max)
+    }
+}
+
+/// where a write lands in the ring
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RingWrite {
+    /// byte offset the batch is written at
+    pub offset: u32,
+    /// pages to ask memory.grow for first (0 = fits)
+    pub grow: u32,
+}
+
+/// plans a write of `bytes`: grow if the ring needs room; the caller wraps to offset 0 when the grow is refused
+pub fn plan_write(budget: &InstanceBudget, bytes: u32) -> RingWrite {
+    RingWrite { offset: budget.used_bytes, grow: budget.pages_needed(bytes) }
+}
+
+/// "192 KiB" / "2 MiB" / "512 B"
+pub fn format_bytes(bytes: u64) -> String {
+    const KIB: u64 = 1024;
+    const MIB: u64 = 1024 * KIB;
+    match bytes {
+        b if b >= MIB && b.is_multiple_of(MIB) => format!("{} MiB", b / MIB),
+        b if b >= MIB => format!("{:.1} MiB", b as f64 / MIB as f64),
+        b if b >= KIB => format!("{} KiB", b / KIB),
+        b => format!("{} B", b),
+    }
+}
+
+/// runtime column badge from the live instances, None until they are spawned
+pub fn live_badge(budgets: &[Option<InstanceBudget>]) -> Option<String> {
+    let live: Vec<&InstanceBudget> = budgets.iter().flatten().collect();
+    if live.is_empty() {
+        return None;
+    }
+    let used: u64 = live.iter().map(|b| b.memory_bytes()).sum();
+    let cap: u64 = live.iter().map(|b| b.max_bytes()).sum();
+    Some(format!("Live: {} / {}", format_bytes(used), format_bytes(cap)))
+}
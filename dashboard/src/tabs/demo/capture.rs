@@ -0,0 +1,207 @@
+// what: capture buffer of modbus rtu frames crossing the gateway, field decode and corrupt-and-replay
+// why: the packet inspector shows what the parser sees byte by byte and lets viewers probe it with damaged frames
+// relations: fed by store.rs capture_exchange (gateway writes in handlers.rs, modbus_panel.rs console),
+//            rendered by capture_panel.rs, replays go to the modbus.rs slave
+
+use modbus_parser::{crc16, parse_frame};
+use modbus_sim::{Slave, EXCEPTION_FLAG};
+use super::modbus::Exchange;
+
+/// frames kept in the inspector (oldest dropped first)
+pub const MAX_FRAMES: usize = 60;
+
+/// which way a frame travelled
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Request,
+    Reply,
+}
+
+impl Direction {
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            Direction::Request => "→",
+            Direction::Reply => "←",
+        }
+    }
+}
+
+/// one frame on the wire
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapturedFrame {
+    pub seq: u32,
+    pub timestamp_ms: f64,
+    pub direction: Direction,
+    /// who put it on the bus (exchange label, or "Replay")
+    pub source: String,
+    pub bytes: Vec<u8>,
+}
+
+/// bounded frame buffer
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Capture {
+    pub frames: Vec<CapturedFrame>,
+    next_seq: u32,
+}
+
+impl Capture {
+    pub fn push(&mut self, timestamp_ms: f64, direction: Direction, source: &str, bytes: Vec<u8>) -> u32 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.frames.push(CapturedFrame { seq, timestamp_ms, direction, source: source.to_string(), bytes });
+        let excess = self.frames.len().saturating_sub(MAX_FRAMES);
+        self.frames.drain(..excess);
+        seq
+    }
+
+    /// request and (if any) reply of one exchange
+    pub fn push_exchange(&mut self, timestamp_ms: f64, exchange: &Exchange) {
+        self.push(timestamp_ms, Direction::Request, exchange.label, exchange.request.clone());
+        if let Some(reply) = &exchange.response {
+            self.push(timestamp_ms, Direction::Reply, exchange.label, reply.clone());
+        }
+    }
+
+    pub fn get(&self, seq: u32) -> Option<&CapturedFrame> {
+        self.frames.iter().find(|f| f.seq == seq)
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+}
+
+// ============================================================================
+// field decode
+// ============================================================================
+
+/// highlight class of a decoded field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldKind {
+    Address,
+    Function,
+    /// function code with the exception bit set
+    Exception,
+    Data,
+    Crc,
+    BadCrc,
+    /// bytes too short to be a frame
+    Malformed,
+}
+
+impl FieldKind {
+    /// css class suffix
+    pub fn class(&self) -> &'static str {
+        match self {
+            FieldKind::Address => "address",
+            FieldKind::Function => "function",
+            FieldKind::Exception => "exception",
+            FieldKind::Data => "data",
+            FieldKind::Crc => "crc",
+            FieldKind::BadCrc => "crc-bad",
+            FieldKind::Malformed => "malformed",
+        }
+    }
+}
+
+/// a byte range of a frame and what it means
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    pub kind: FieldKind,
+    pub start: usize,
+    pub end: usize,
+    pub label: String,
+}
+
+/// name of function codes outside the table below
+pub const UNKNOWN_FUNCTION: &str = "Unknown function";
+
+/// spec name of a function code (exception bit ignored)
+pub fn function_name(function_code: u8) -> &'static str {
+    match function_code & !EXCEPTION_FLAG {
+        0x01 => "Read Coils",
+        0x02 => "Read Discrete Inputs",
+        0x03 => "Read Holding Registers",
+        0x04 => "Read Input Registers",
+        0x05 => "Write Single Coil",
+        0x06 => "Write Single Register",
+        0x08 => "Diagnostics",
+        0x0F => "Write Multiple Coils",
+        0x10 => "Write Multiple Registers",
+        0x2B => "Encapsulated Interface Transport",
+        _ => UNKNOWN_FUNCTION,
+    }
+}
+
+/// splits an rtu frame into address / function / data / crc
+pub fn decode_fields(bytes: &[u8]) -> Vec<Field> {
+    if bytes.len() < 4 {
+        return vec![Field { kind: FieldKind::Malformed, start: 0, end: bytes.len(), label: format!("{} bytes - an rtu frame needs at least 4", bytes.len()) }];
+    }
+    let body = bytes.len() - 2;
+    let function = bytes[1];
+    let received = u16::from_le_bytes([bytes[body], bytes[body + 1]]);
+    let expected = crc16(&bytes[..body]);
+    let mut fields = vec![
+        Field { kind: FieldKind::Address, start: 0, end: 1, label: format!("unit {}", bytes[0]) },
+        if function & EXCEPTION_FLAG != 0 {
+            Field { kind: FieldKind::Exception, start: 1, end: 2, label: format!("0x{:02X} exception reply to {}", function, function_name(function)) }
+        } else {
+            Field { kind: FieldKind::Function, start: 1, end: 2, label: format!("0x{:02X} {}", function, function_name(function)) }
+        },
+    ];
+    if body > 2 {
+        fields.push(Field { kind: FieldKind::Data, start: 2, end: body, label: format!("{} data bytes", body - 2) });
+    }
+    fields.push(if received == expected {
+        Field { kind: FieldKind::Crc, start: body, end: body + 2, label: format!("crc 0x{:04X} ok", received) }
+    } else {
+        Field { kind: FieldKind::BadCrc, start: body, end: body + 2, label: format!("crc 0x{:04X}, expected 0x{:04X}", received, expected) }
+    });
+    fields
+}
+
+/// the field a byte belongs to
+pub fn field_at(fields: &[Field], index: usize) -> Option<&Field> {
+    fields.iter().find(|f| (f.start..f.end).contains(&index))
+}
+
+// ============================================================================
+// corrupt and replay
+// ============================================================================
+
+/// flips every bit of one byte; with `fix_crc` the checksum is recomputed so the damage reaches the decoder
+pub fn corrupt(bytes: &[u8], index: usize, fix_crc: bool) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    if let Some(b) = out.get_mut(index) {
+        *b ^= 0xFF;
+    }
+    let body = out.len().saturating_sub(2);
+    if fix_crc && body >= 2 && index < body {
+        let crc = crc16(&out[..body]);
+        out[body..].copy_from_slice(&crc.to_le_bytes());
+    }
+    out
+}
+
+/// what the parser and the slave made of a replayed frame
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayOutcome {
+    pub frame: Vec<u8>,
+    /// parser verdict (accepted frames name the function)
+    pub parser: Result<String, String>,
+    pub reply: Option<Vec<u8>>,
+}
+
+/// sends a (damaged) frame through the rtu parser and onto the slave's bus
+pub fn replay(slave: &mut Slave, frame: Vec<u8>) -> ReplayOutcome {
+    let parser = match parse_frame(&frame) {
+        Err(e) => Err(e.to_string()),
+        Ok(parsed) => match decode_fields(&frame).into_iter().find(|f| f.kind == FieldKind::BadCrc) {
+            Some(crc) => Err(format!("{} - frame dropped", crc.label)),
+            None => Ok(format!("unit {}, 0x{:02X} {}, {} data bytes", parsed.device_id, parsed.function_code, function_name(parsed.function_code), parsed.data.len())),
+        },
+    };
+    let reply = slave.respond_rtu(&frame);
+    ReplayOutcome { frame, parser, reply }
+}
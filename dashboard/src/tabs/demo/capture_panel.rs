@@ -0,0 +1,142 @@
+// what: packet inspector - captured modbus frames as colour-coded hex dumps, expandable field decode, corrupt-and-replay
+// why: lets viewers see each field the parser reads and check what a single damaged byte does to it
+// relations: used by component.rs, model and replay in capture.rs, frames captured by store.rs, replays hit the modbus.rs slave
+
+use leptos::*;
+use super::capture::{corrupt, decode_fields, field_at, replay, Direction, ReplayOutcome};
+use super::modbus::hex;
+use super::store::DemoStore;
+
+/// source label of replayed frames
+const REPLAY_SOURCE: &str = "Replay";
+
+/// one frame's bytes, each coloured by the field it belongs to
+fn hex_dump(bytes: &[u8], selected: Option<usize>, pick: Option<Callback<usize>>) -> View {
+    let fields = decode_fields(bytes);
+    bytes.iter().enumerate().map(|(i, b)| {
+        let class = format!("cap-byte cap-{}", field_at(&fields, i).map(|f| f.kind.class()).unwrap_or("data"));
+        view! {
+            <span
+                class=class
+                class:selected=selected == Some(i)
+                class:pickable=pick.is_some()
+                title=field_at(&fields, i).map(|f| f.label.clone()).unwrap_or_default()
+                on:click=move |_| if let Some(pick) = pick { pick.call(i) }
+            >
+                {format!("{:02X}", b)}
+            </span>
+        }
+    }).collect_view()
+}
+
+/// captured frame list with a detail view for the expanded one
+#[component]
+pub fn CapturePanel(store: DemoStore) -> impl IntoView {
+    let capture = store.capture;
+    let (expanded, set_expanded) = create_signal(Option::<u32>::None);
+    let (selected, set_selected) = create_signal(Option::<usize>::None);
+    let (fix_crc, set_fix_crc) = create_signal(false);
+    let (outcome, set_outcome) = create_signal(Option::<ReplayOutcome>::None);
+
+    let toggle = move |seq: u32| {
+        set_expanded.update(|e| *e = if *e == Some(seq) { None } else { Some(seq) });
+        set_selected.set(None);
+        set_outcome.set(None);
+    };
+
+    let corrupt_and_replay = move |_| {
+        let (Some(seq), Some(index)) = (expanded.get(), selected.get()) else { return };
+        let Some(bytes) = capture.with(|c| c.get(seq).map(|f| f.bytes.clone())) else { return };
+        let Some(result) = store.modbus.try_update(|s| replay(s, corrupt(&bytes, index, fix_crc.get()))) else { return };
+        let now = js_sys::Date::now();
+        capture.update(|c| {
+            c.push(now, Direction::Request, REPLAY_SOURCE, result.frame.clone());
+            if let Some(reply) = &result.reply {
+                c.push(now, Direction::Reply, REPLAY_SOURCE, reply.clone());
+            }
+        });
+        set_outcome.set(Some(result));
+    };
+
+    view! {
+        <div class="audit-panel capture-panel">
+            <h4>
+                "🔬 Packet Inspector"
+                <span class="demo-badge">{move || format!("{} frames", capture.with(|c| c.frames.len()))}</span>
+            </h4>
+            <p class="section-desc">"Every RTU frame between the gateway and the PLC. Click a frame to decode it, pick a byte, corrupt it and replay it through the parser and the slave."</p>
+            <div class="cap-legend">
+                <span class="cap-byte cap-address">"address"</span>
+                <span class="cap-byte cap-function">"function"</span>
+                <span class="cap-byte cap-exception">"exception"</span>
+                <span class="cap-byte cap-data">"data"</span>
+                <span class="cap-byte cap-crc">"crc"</span>
+                <span class="cap-byte cap-crc-bad">"bad crc"</span>
+                <button class="action-btn" disabled=move || capture.with(|c| c.frames.is_empty()) on:click=move |_| {
+                    capture.update(|c| c.clear());
+                    set_expanded.set(None);
+                    set_outcome.set(None);
+                }>"🧹 Clear"</button>
+            </div>
+            <div class="cap-list">
+                {move || {
+                    let frames = capture.with(|c| c.frames.iter().rev().cloned().collect::<Vec<_>>());
+                    if frames.is_empty() {
+                        return view! { <p class="metrics-note">"No frames yet - run an attack or send a request from the Modbus console."</p> }.into_view();
+                    }
+                    frames.into_iter().map(|frame| {
+                        let seq = frame.seq;
+                        let open = expanded.get() == Some(seq);
+                        view! {
+                            <div class="cap-row" class:expanded=open on:click=move |_| toggle(seq)>
+                                <span class="cap-seq">{format!("#{}", seq)}</span>
+                                <span class="cap-dir">{frame.direction.arrow()}</span>
+                                <span class="cap-source">{frame.source.clone()}</span>
+                                <span class="cap-hex">{hex_dump(&frame.bytes, None, None)}</span>
+                            </div>
+                            {open.then(|| view! {
+                                <div class="cap-detail">
+                                    <ul class="cap-fields">
+                                        {decode_fields(&frame.bytes).into_iter().map(|f| view! {
+                                            <li>
+                                                <span class=format!("cap-byte cap-{}", f.kind.class())>{hex(&frame.bytes[f.start..f.end])}</span>
+                                                " " {f.label}
+                                            </li>
+                                        }).collect_view()}
+                                    </ul>
+                                    <p class="metrics-note">"Pick a byte to corrupt (all bits flipped):"</p>
+                                    <div class="cap-hex">{move || hex_dump(&frame.bytes, selected.get(), Some(Callback::new(move |i| set_selected.set(Some(i)))))}</div>
+                                    <div class="audit-controls">
+                                        <label class="influx-toggle">
+                                            <input type="checkbox" prop:checked=move || fix_crc.get() on:change=move |ev| set_fix_crc.set(event_target_checked(&ev)) />
+                                            " recompute CRC (let the damage reach the decoder)"
+                                        </label>
+                                        <button class="action-btn" disabled=move || selected.get().is_none() on:click=corrupt_and_replay>
+                                            {move || match selected.get() {
+                                                Some(i) => format!("💥 Corrupt byte {} & replay", i),
+                                                None => "💥 Corrupt & replay".to_string(),
+                                            }}
+                                        </button>
+                                    </div>
+                                    {move || outcome.get().map(|o| view! {
+                                        <div class="terminal cap-outcome">
+                                            <p class="terminal-line info">{format!("→ {}", hex(&o.frame))}</p>
+                                            {match &o.parser {
+                                                Ok(text) => view! { <p class="terminal-line success">{format!("[PARSER] accepted: {}", text)}</p> },
+                                                Err(text) => view! { <p class="terminal-line warn">{format!("[PARSER] rejected: {}", text)}</p> },
+                                            }}
+                                            <p class="terminal-line info">{match &o.reply {
+                                                Some(reply) => format!("[SLAVE] ← {}", hex(reply)),
+                                                None => "[SLAVE] silent - nothing goes back on the bus".to_string(),
+                                            }}</p>
+                                        </div>
+                                    })}
+                                </div>
+                            })}
+                        }
+                    }).collect_view()
+                }}
+            </div>
+        </div>
+    }
+}
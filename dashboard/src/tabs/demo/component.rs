@@ -1,14 +1,60 @@
 // what: main demo component with interactive attack simulations
 // why: flagship demonstration merging ICS Guardian, Protocol Gateway, and Raft patterns
-// relations: uses types.rs, attacks.rs, wasm.rs; exported via mod.rs to lib.rs
+// relations: state in store.rs, handlers in handlers.rs, attack payloads imported by payloads.rs, child components in metrics_banner.rs, node_drawer.rs, voting_panel.rs, suspicion_panel.rs, supervisor_panel.rs, resync_panel.rs, soak_panel.rs, timing_channel_panel.rs, architecture_panel.rs, sensor_comparison.rs, runtime_panel.rs, stats_panel.rs, ledger_panel.rs, attack_controls.rs, quiz_panel.rs, library_panel.rs, mitre_panel.rs, methodology_panel.rs, host_trace_panel.rs, snapshot_panel.rs, info_box.rs, handoff/panel.rs; demo state mirrored across browser tabs via tabsync/; exported via mod.rs to lib.rs
 
 use leptos::*;
-use wasm_bindgen::JsCast;
 
 // Import from sibling modules
-use super::types::{LogEntry, InstanceState};
-use super::attacks::{get_attack_config, get_attack_code, WIT_CODE_EXCERPT};
-use super::wasm::{now, runPython, measure_instantiate_time, set_timeout};
+use super::attacks::chaos_pool;
+use super::payloads;
+use super::wasm::{ensurePyodide, measure_instantiate_time, runPython};
+use super::runtime_model::runtimes;
+use super::policy_panel::{CapabilityMatrix, PolicyEditor, WasmtimeCommands};
+use super::audit_panel::AuditPanel;
+use super::host_trace_panel::HostTracePanel;
+use super::metrics_panel::MetricsPanel;
+use super::influx_panel::InfluxPanel;
+use super::modbus_panel::ModbusPanel;
+use super::capture_panel::CapturePanel;
+use super::pcap_panel::PcapPanel;
+use super::snapshot_panel::SnapshotPanel;
+use super::methodology_panel::{use_methodology, MethodologyPanel};
+use super::store::DemoStore;
+use super::budget::WORKLOAD_BYTES;
+use super::types::InstanceState;
+use super::metrics_banner::MetricsBanner;
+use super::sensor_comparison::SensorComparison;
+use super::runtime_panel::RuntimePanel;
+use super::timeline_panel::TimelinePanel;
+use super::stats_panel::StatsPanel;
+use super::ledger_panel::DowntimeLedger;
+use super::attack_controls::AttackControls;
+use super::quiz_panel::QuizPanel;
+use super::library_panel::LibraryPanel;
+use super::mitre_panel::MitrePanel;
+use super::info_box::InfoBox;
+use super::raft_panel::RaftLogPanel;
+use super::raft_persist;
+use super::voting_panel::VotingPolicyPanel;
+use super::led_strip::LedStripPanel;
+use super::supervisor_panel::SupervisorPanel;
+use super::resync_panel::ResyncPanel;
+use super::suspicion_panel::SuspicionPanel;
+use super::node_drawer::NodeDrawer;
+use super::anomaly_panel::AnomalyPanel;
+use super::fusion_panel::FusionPanel;
+use super::actuator_panel::ActuatorPanel;
+use super::flood_panel::FloodPanel;
+use super::soak_panel::SoakPanel;
+use super::timing_channel_panel::TimingChannelPanel;
+use super::architecture_panel::ArchitecturePanel;
+use crate::diagnostics::{probe::PROBE_PY, startup, PyodideProbe};
+use crate::settings::use_settings;
+use crate::kiosk::use_kiosk;
+use crate::tabsync::{use_tab_sync, STATE_DEBOUNCE_MS};
+use crate::headless;
+use crate::handoff::HandoffPanel;
+use crate::measure::PrecisionBanner;
 
 // ============================================================================
 // demo component
@@ -16,606 +62,241 @@ use super::wasm::{now, runPython, measure_instantiate_time, set_timeout};
 
 #[component]
 pub fn Demo() -> impl IntoView {
-    // ========================================================================
-    // wasm metrics (real measurements)
-    // ========================================================================
-    let (wasm_instantiate_ms, set_wasm_instantiate_ms) = create_signal(0.0f64);
-    let (measurements_done, set_measurements_done) = create_signal(false);
-    
-    // ========================================================================
-    // pyodide/python metrics (real measurements)
-    // ========================================================================
-    let (pyodide_ready, set_pyodide_ready) = create_signal(false);
-    let (pyodide_load_ms, set_pyodide_load_ms) = create_signal(0.0f64); // Real Pyodide cold-start time
-    let (python_exec_ms, set_python_exec_ms) = create_signal(0.0f64);
-    let (wasm_exec_ms, set_wasm_exec_ms) = create_signal(0.0f64);
-    let (sensor_running, set_sensor_running) = create_signal(false);
-    let (sensor_ran, set_sensor_ran) = create_signal(false);
+    let settings = use_settings();
+    let kiosk = use_kiosk();
+    let sync = use_tab_sync();
     
     // ========================================================================
-    // 2oo3 voting state (three wasm instances)
-    // ========================================================================
-    let (instance_states, set_instance_states) = create_signal([
-        InstanceState::Healthy,
-        InstanceState::Healthy,
-        InstanceState::Healthy,
-    ]);
-    let (faulty_instance, set_faulty_instance) = create_signal(Option::<u8>::None);
-    let (leader_id, set_leader_id) = create_signal(0u8); // Current leader (changes if leader fails)
-    
-    // ========================================================================
-    // python worker state
-    // ========================================================================
-    let (python_workers, set_python_workers) = create_signal([true, true, true]);
-    let (python_active_worker, set_python_active_worker) = create_signal(0u8);
-    let (python_restarting, set_python_restarting) = create_signal(false);
-    
+    // state store (fine-grained slices) + view-only flags
     // ========================================================================
-    // wit modal state
-    // ========================================================================
-    let (wit_modal_open, set_wit_modal_open) = create_signal(false);
-    
-    // ========================================================================
-    // metrics tracking
-    // ========================================================================
-    let (python_processed, set_python_processed) = create_signal(0u32);
-    let (python_crashed, set_python_crashed) = create_signal(0u32);
-    let (python_downtime_ms, set_python_downtime_ms) = create_signal(0u64);
-    
-    let (wasm_processed, set_wasm_processed) = create_signal(0u32);
-    let (wasm_rejected, set_wasm_rejected) = create_signal(0u32);
-    // WASM always 0 downtime due to 2oo3 voting
-    
-    // ========================================================================
-    // terminal logs
-    // ========================================================================
-    let (python_logs, set_python_logs) = create_signal(Vec::<LogEntry>::new());
-    let (wasm_logs, set_wasm_logs) = create_signal(Vec::<LogEntry>::new());
-    
-    // ========================================================================
-    // control state
-    // ========================================================================
-    let (is_running, set_is_running) = create_signal(false);
-    let (running_all, set_running_all) = create_signal(false);  // Track "run all attacks" mode
-    let (selected_attack, set_selected_attack) = create_signal("bufferOverflow".to_string());
+    let store = DemoStore::new(settings);
+    let DemoStore { perf, cluster, control, journal, influx, policy, diagnostics, .. } = store;
+    let (policy_editor_open, set_policy_editor_open) = create_signal(false);
+    let (wasmtime_open, set_wasmtime_open) = create_signal(false);
+    // disclosure every export carries
+    let methodology = use_methodology(perf);
+    // runtime columns (python + wasm, plus the optional js baseline)
+    let kinds = create_memo(move |_| runtimes(settings.with(|s| s.js_baseline)));
+    // the cabinet hmi follows the leader and node health (hardware/oled.rs)
+    create_effect(move |_| {
+        let (leader, states) = (cluster.leader_id.get(), cluster.instance_states.get());
+        store.hmi.update(|h| {
+            h.leader = leader;
+            h.healthy = states.map(|s| s == InstanceState::Healthy);
+        });
+    });
     
     // ========================================================================
     // measure real wasm performance on mount
     // ========================================================================
     create_effect(move |_| {
-        if !measurements_done.get() {
+        if !perf.measurements_done.get() {
             spawn_local(async move {
-                let instantiate_time = measure_instantiate_time().await;
-                set_wasm_instantiate_ms.set(instantiate_time);
-                set_measurements_done.set(true);
+                startup::mark("measure-start");
+                let instantiate_time = measure_instantiate_time(store.iterations()).await;
+                startup::mark("measure-end");
+                perf.wasm_instantiate_ms.set(instantiate_time);
+                perf.measurements_done.set(true);
             });
         }
     });
     
-    // Check if Pyodide is ready (polled periodically) and capture real load time
-    create_effect(move |_| {
-        if !pyodide_ready.get() {
-            // Poll every 500ms to check if Pyodide is loaded
-            set_timeout(move || {
-                let window = web_sys::window().unwrap();
-                if js_sys::Reflect::get(&window, &"pyodideReady".into())
-                    .map(|v| v.as_bool().unwrap_or(false))
-                    .unwrap_or(false)
-                {
-                    set_pyodide_ready.set(true);
-                    
-                    // Capture the real Pyodide load time (cold-start measurement)
-                    if let Ok(load_time) = js_sys::Reflect::get(&window, &"pyodideLoadTime".into()) {
-                        if let Some(ms) = load_time.as_f64() {
-                            set_pyodide_load_ms.set(ms);
-                        }
-                    }
-                }
-            }, std::time::Duration::from_millis(500));
+    // Import the attack payloads on first visit to this tab (their own wasm module, not part of the dashboard bundle)
+    spawn_local(async move {
+        let loaded = payloads::load().await;
+        if let Err(e) = &loaded {
+            tracing::warn!(target: "attack", error = %e, "attack code not loaded");
         }
+        store.payloads.set(Some(loaded));
     });
     
-    // Auto-scroll terminals to bottom when logs update
-    create_effect(move |_| {
-        let _ = python_logs.get(); // Track changes
-        // Use request_animation_frame to scroll after DOM updates
-        if let Some(window) = web_sys::window() {
-            let _ = window.request_animation_frame(
-                wasm_bindgen::closure::Closure::once_into_js(|| {
-                    if let Some(doc) = web_sys::window().and_then(|w| w.document()) {
-                        if let Some(el) = doc.get_element_by_id("python-terminal") {
-                            el.set_scroll_top(el.scroll_height());
-                        }
-                    }
-                }).unchecked_ref()
-            );
+    // Load Pyodide on first visit to this tab and capture real load time
+    // (index.html no longer loads it up front, so other tabs stay light)
+    spawn_local(async move {
+        if ensurePyodide().await.is_err() {
+            perf.pyodide_failed.set(true);
+            return;
         }
-    });
-    
-    create_effect(move |_| {
-        let _ = wasm_logs.get(); // Track changes
-        if let Some(window) = web_sys::window() {
-            let _ = window.request_animation_frame(
-                wasm_bindgen::closure::Closure::once_into_js(|| {
-                    if let Some(doc) = web_sys::window().and_then(|w| w.document()) {
-                        if let Some(el) = doc.get_element_by_id("wasm-terminal") {
-                            el.set_scroll_top(el.scroll_height());
-                        }
-                    }
-                }).unchecked_ref()
-            );
+        let window = web_sys::window().unwrap();
+        if js_sys::Reflect::get(&window, &"pyodideReady".into())
+            .map(|v| v.as_bool().unwrap_or(false))
+            .unwrap_or(false)
+        {
+            perf.pyodide_ready.set(true);
+            
+            // Capture the real Pyodide load time (cold-start measurement)
+            if let Ok(load_time) = js_sys::Reflect::get(&window, &"pyodideLoadTime".into()) {
+                if let Some(ms) = load_time.as_f64() {
+                    perf.pyodide_load_ms.set(ms);
+                }
+            }
+            
+            // Probe what this pyodide build emulates (attack expectations key off it)
+            let probe = match runPython(PROBE_PY).await {
+                Ok(v) => v.as_string().ok_or_else(|| format!("{:?}", v)).and_then(|text| PyodideProbe::from_json(&text)),
+                Err(e) => Err(format!("{:?}", e)),
+            };
+            diagnostics.update(|d| d.pyodide = Some(probe));
+        } else {
+            perf.pyodide_failed.set(true);
         }
     });
     
     // ========================================================================
-    // sensor comparison handler - runs REAL Python via Pyodide and REAL WASM
+    // raft state survives reloads: restore it as a whole-cluster restart, then persist every change
     // ========================================================================
-    let run_sensor_comparison = move |_| {
-        if sensor_running.get() { return; }
-        set_sensor_running.set(true);
-        
-        // Append to logs (don't clear - only Reset button clears)
-        set_python_logs.update(|logs| {
-            logs.push(LogEntry { level: "info".into(), message: "$ python sensor_driver.py".into() });
-            logs.push(LogEntry { level: "info".into(), message: "[...] Loading Pyodide runtime...".into() });
-        });
-        set_wasm_logs.update(|logs| {
-            logs.push(LogEntry { level: "info".into(), message: "$ wasmtime sensor_driver.wasm".into() });
-        });
-        
-        // Run WASM sensor (near-instant) with simulated varying values
-        let wasm_start = now();
-        // Generate random sensor values for simulation
-        let temp = 20.0 + (js_sys::Math::random() * 10.0) as f64;  // 20-30°C
-        let hum = 40.0 + (js_sys::Math::random() * 20.0) as f64;   // 40-60%
-        let pres = 1008.0 + (js_sys::Math::random() * 15.0) as f64; // 1008-1023 hPa
-        let wasm_result = (temp, hum, pres);
-        let wasm_elapsed = now() - wasm_start;
-        set_wasm_exec_ms.set(wasm_elapsed);
-        set_sensor_ran.set(true);
-        
-        // Log WASM results immediately
-        set_wasm_logs.update(|logs| {
-            logs.push(LogEntry { level: "success".into(), message: format!("[OK] Module instantiated in {:.3}ms", wasm_elapsed) });
-            logs.push(LogEntry { level: "success".into(), message: "[OK] BME280 driver initialized".into() });
-            logs.push(LogEntry { level: "info".into(), message: format!("Temperature: {:.1}°C", wasm_result.0) });
-            logs.push(LogEntry { level: "info".into(), message: format!("Humidity: {:.1}%", wasm_result.1) });
-            logs.push(LogEntry { level: "info".into(), message: format!("Pressure: {:.2} hPa", wasm_result.2) });
-        });
-        
-        // Run Python sensor via Pyodide (REAL execution)
-        spawn_local(async move {
-            let python_code = r#"
-import time
-start = time.perf_counter()
-
-# BME280 driver simulation
-class BME280:
-    def __init__(self):
-        self.cal = [27504, 26435, -1000]
-    
-    def read(self):
-        return {"temp": 23.5, "hum": 45.2, "pres": 1013.25}
-
-driver = BME280()
-result = driver.read()
-elapsed_ms = (time.perf_counter() - start) * 1000
-result
-"#;
-            
-            let py_start = now();
-            match runPython(python_code).await {
-                Ok(_) => {
-                    let py_elapsed = now() - py_start;
-                    set_python_exec_ms.set(py_elapsed);
-                    
-                    // Use same sensor values as WASM (they're reading the "same" sensor)
-                    set_python_logs.update(|logs| {
-                        logs.push(LogEntry { level: "success".into(), message: format!("[OK] Pyodide executed in {:.2}ms", py_elapsed) });
-                        logs.push(LogEntry { level: "success".into(), message: "[OK] BME280 driver initialized".into() });
-                        logs.push(LogEntry { level: "info".into(), message: format!("Temperature: {:.1}°C", wasm_result.0) });
-                        logs.push(LogEntry { level: "info".into(), message: format!("Humidity: {:.1}%", wasm_result.1) });
-                        logs.push(LogEntry { level: "info".into(), message: format!("Pressure: {:.2} hPa", wasm_result.2) });
-                    });
-                }
-                Err(e) => {
-                    set_python_exec_ms.set(-1.0);
-                    set_python_logs.update(|logs| {
-                        logs.push(LogEntry { level: "error".into(), message: format!("[ERR] Pyodide error: {:?}", e) });
-                    });
+    let raft_loaded = create_rw_signal(false);
+    let raft_status = create_rw_signal(Option::<String>::None);
+    spawn_local(async move {
+        match raft_persist::load().await {
+            Ok(Some(mut saved)) => {
+                let persisted_term = saved.term;
+                if let Some(leader) = saved.recover() {
+                    cluster.leader_id.set(leader as u8);
+                    raft_status.set(Some(format!(
+                        "♻️ Restarted from IndexedDB: persisted term {}, commit #{} → I{} elected in term {}",
+                        persisted_term, saved.nodes[leader].commit_index, leader, saved.term,
+                    )));
                 }
+                cluster.log.set(saved);
             }
-            
-            set_sensor_running.set(false);
-        });
-    };
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(target: "raft", error = %e, "raft state not restored");
+                raft_status.set(Some(format!("⚠ Raft state not persisted: {}", e)));
+            }
+        }
+        raft_loaded.set(true);
+    });
+    create_effect(move |_| {
+        let log = cluster.log.get();
+        // never overwrite the saved state with the default before it has been read
+        if !raft_loaded.get() { return; }
+        raft_persist::queue_save(log);
+    });
     
     // ========================================================================
-    // attack handler (REAL pyodide execution)
+    // chaos mode (random attack every configured interval)
     // ========================================================================
-    let trigger_attack = move |_| {
-        // Allow if running_all mode (called from run_all_attacks), otherwise block if already running
-        if is_running.get() && !running_all.get() { return; }
-        if !running_all.get() { set_is_running.set(true); }
-        
-        let attack = selected_attack.get();
-        let config = get_attack_config(&attack);
-        let attack_code = get_attack_code(&attack);
-        let current_active = python_active_worker.get();
-        
-        // initialize if first run
-        if python_logs.get().is_empty() {
-            set_python_logs.set(vec![
-                LogEntry { level: "info".into(), message: "$ python gateway.py --workers 3".into() },
-                LogEntry { level: "success".into(), message: "[OK] Worker pool: W0 active, W1/W2 standby".into() },
-            ]);
-            set_python_processed.set(5);
+    let last_chaos_ms = store_value(js_sys::Date::now());
+    if let Ok(handle) = set_interval_with_handle(move || {
+        let chaos = settings.with_untracked(|s| s.chaos.clone());
+        let now_ms = js_sys::Date::now();
+        // a display tab mirrors the control tab's attacks instead of launching its own
+        if !chaos.enabled || sync.following.get_untracked() {
+            last_chaos_ms.set_value(now_ms);
+            return;
         }
+        if control.is_running.get_untracked() || now_ms - last_chaos_ms.get_value() < chaos.interval_s as f64 * 1000.0 { return; }
+        last_chaos_ms.set_value(now_ms);
         
-        if wasm_logs.get().is_empty() {
-            set_wasm_logs.set(vec![
-                LogEntry { level: "info".into(), message: "$ wasmtime gateway.wasm --mode 2oo3".into() },
-                LogEntry { level: "success".into(), message: "[OK] 2oo3 TMR: I0, I1, I2 initialized".into() },
-                LogEntry { level: "info".into(), message: format!("[METRICS] Instantiate: {:.2}ms (real)", wasm_instantiate_ms.get()) },
-            ]);
-            set_wasm_processed.set(5);
+        let candidates = chaos_pool(chaos.include_availability);
+        store.launch(candidates[(js_sys::Math::random() * candidates.len() as f64) as usize % candidates.len()]);
+    }, std::time::Duration::from_secs(1)) {
+        on_cleanup(move || handle.clear());
+    }
+    
+    // ========================================================================
+    // kiosk mode: start a requested run-all, hold the tab cycle until it finishes
+    // ========================================================================
+    create_effect(move |_| {
+        if kiosk.run_all.get() {
+            kiosk.run_all.set(false);
+            store.run_all_attacks();
         }
-        
-        // show incoming attack
-        set_python_logs.update(|logs| {
-            logs.push(LogEntry { level: "warn".into(), message: format!("[ATTACK] Incoming: {}", config.name) });
-            logs.push(LogEntry { level: "info".into(), message: "[EXEC] Running real Python via Pyodide...".into() });
-        });
-        set_wasm_logs.update(|logs| {
-            logs.push(LogEntry { level: "warn".into(), message: format!("[ATTACK] Incoming: {}", config.name) });
-        });
-        
-        // Use REAL Pyodide load time as restart time (represents actual Python cold-start)
-        // Add ±200ms jitter for realistic variance
-        let base_restart = if pyodide_load_ms.get() > 0.0 {
-            pyodide_load_ms.get() as i32
-        } else {
-            config.restart_ms as i32
-        };
-        // Random jitter: -200 to +200ms
-        let jitter = ((js_sys::Math::random() * 400.0) - 200.0) as i32;
-        let restart_ms = (base_restart + jitter).max(500) as u32; // Min 500ms
-        let wasm_trap = config.wasm_trap.to_string();
-        let wit_func = config.wit_func.to_string();
-        let attack_code_owned = attack_code.to_string();
-        
-        // Run REAL Python attack via Pyodide
-        spawn_local(async move {
-            let py_start = now();
-            
-            match runPython(&attack_code_owned).await {
-                Ok(result) => {
-                    let py_elapsed = now() - py_start;
-                    
-                    // Parse the result - now returns pipe-delimited string
-                    // Format: STATUS|ERROR_TYPE|MESSAGE|TIMEms
-                    let result_str = if let Some(s) = result.as_string() {
-                        s
-                    } else {
-                        // Try to extract from JsValue object
-                        format!("{:?}", result)
-                    };
-                    
-                    // Parse pipe-delimited format for clean display
-                    let parts: Vec<&str> = result_str.split('|').collect();
-                    let (status, error_type, message) = if parts.len() >= 3 {
-                        (parts[0], parts[1], parts[2])
-                    } else {
-                        // Fallback for unexpected format
-                        ("CRASHED", "Exception", &result_str as &str)
-                    };
-                    
-                    set_python_logs.update(|logs| {
-                        logs.push(LogEntry { 
-                            level: "error".into(), 
-                            message: format!("[{}] {}: {}", status, error_type, message)
-                        });
-                        logs.push(LogEntry { 
-                            level: "error".into(), 
-                            message: format!("💥 W{} CRASHED after {:.1}ms - real Python exception!", current_active, py_elapsed)
-                        });
-                        // Simplified crash response - no confusing voting language
-                        let next_worker = (current_active + 1) % 3;
-                        logs.push(LogEntry { 
-                            level: "warn".into(), 
-                            message: format!("[POOL] Failing over to W{} (standby → active)", next_worker)
-                        });
-                    });
-                }
-                Err(e) => {
-                    // Pyodide threw an actual uncaught exception
-                    let err_str = format!("{:?}", e);
-                    set_python_logs.update(|logs| {
-                        logs.push(LogEntry { 
-                            level: "error".into(), 
-                            message: format!("[FATAL] Uncaught: {}", err_str.chars().take(80).collect::<String>())
-                        });
-                        logs.push(LogEntry { 
-                            level: "error".into(), 
-                            message: format!("💥 W{} CRASHED - process terminated!", current_active)
-                        });
-                        // Simplified crash response - no confusing voting language
-                        let next_worker = (current_active + 1) % 3;
-                        logs.push(LogEntry { 
-                            level: "warn".into(), 
-                            message: format!("[POOL] Failing over to W{} (standby → active)", next_worker)
-                        });
-                    });
-                }
-            }
-            
-            // Worker failover
-            let next_active = (current_active + 1) % 3;
-            let mut workers = [true, true, true];
-            workers[current_active as usize] = false;
-            set_python_workers.set(workers);
-            set_python_active_worker.set(next_active);
-            set_python_restarting.set(true);
-            set_python_crashed.update(|n| *n += 1);
-            
-            // Restart simulation
-            let restart_ms_copy = restart_ms;
-            set_timeout(move || {
-                set_python_workers.set([true, true, true]);
-                set_python_restarting.set(false);
-                set_python_downtime_ms.update(|d| *d += restart_ms_copy as u64);
-                set_python_logs.update(|logs| {
-                    logs.push(LogEntry { 
-                        level: "success".into(), 
-                        message: format!("[OK] W{} respawned ({}ms) - pool restored", current_active, restart_ms_copy)
-                    });
-                    logs.push(LogEntry { 
-                        level: "info".into(), 
-                        message: "[VOTE] 3/3 workers ready - voting now possible".into()
-                    });
-                });
-                // Only reset is_running if not in running_all mode
-                if !running_all.get() { set_is_running.set(false); }
-            }, std::time::Duration::from_millis(restart_ms as u64));
-        });
-        
-        // ================================================================
-        // wasm: 2oo3 voting catches the fault instantly (capability demo)
-        // ================================================================
-        set_timeout(move || {
-            let faulty_idx = (js_sys::Math::random() * 3.0) as u8;
-            set_faulty_instance.set(Some(faulty_idx));
-            
-            let mut states = instance_states.get();
-            states[faulty_idx as usize] = InstanceState::Faulty;
-            set_instance_states.set(states);
-            
-            let healthy: Vec<u8> = (0..3).filter(|&i| i != faulty_idx).collect();
-            
-            // Generate simulated sensor value for demonstration
-            let sensor_val = 42.0 + (js_sys::Math::random() * 0.5);
-            
-            // WIT blocks the attack - instance returns TRAP as output, voting handles it
-            // No leader election needed - the instance isn't dead, just this call was blocked
-            set_wasm_logs.update(|logs| {
-                logs.push(LogEntry { level: "warn".into(), message: format!("[TRAP] I{}: {}", faulty_idx, wasm_trap) });
-                logs.push(LogEntry { level: "info".into(), message: format!("[WIT] attack-surface.{} blocked → capability not imported", wit_func) });
-                // Show actual output comparison
-                logs.push(LogEntry { level: "info".into(), message: format!("[OUT] I{}: TRAP | I{}: {:.1}°C | I{}: {:.1}°C", faulty_idx, healthy[0], sensor_val, healthy[1], sensor_val) });
-                logs.push(LogEntry { level: "success".into(), message: format!("[VOTE] 2/3 outputs agree ({:.1}°C) - using majority value", sensor_val) });
-                logs.push(LogEntry { level: "success".into(), message: "[OK] Zero downtime - continues with valid output".into() });
-            });
-            
-            set_wasm_rejected.update(|n| *n += 1);
-            
-            // rebuild faulty instance (real async measurement)
-            spawn_local(async move {
-                let rebuild_time = measure_instantiate_time().await;
-                
-                let mut states = instance_states.get();
-                states[faulty_idx as usize] = InstanceState::Healthy;
-                set_instance_states.set(states);
-                set_faulty_instance.set(None);
-                
-                set_wasm_logs.update(|logs| {
-                    logs.push(LogEntry { 
-                        level: "success".into(), 
-                        message: format!("[OK] I{} rebuilt in {:.2}ms (real) - pool healthy", faulty_idx, rebuild_time)
-                    });
-                });
-            });
-        }, std::time::Duration::from_millis(100));
-    };
+    });
+    create_effect(move |_| kiosk.busy.set(control.running_all.get()));
+    on_cleanup(move || kiosk.busy.set(false));
     
     // ========================================================================
-    // leader crash handler (for availability attacks)
+    // headless api (window.guardianDemo): expose the store, announce when a run finishes
     // ========================================================================
-    let trigger_leader_crash = move |_| {
-        // Allow if running_all mode (called from run_all_attacks), otherwise block if already running
-        if is_running.get() && !running_all.get() { return; }
-        if !running_all.get() { set_is_running.set(true); }
-        
-        let attack = selected_attack.get();
-        let is_timeout = attack == "heartbeatTimeout";
-        let current_leader_py = python_active_worker.get();
-        
-        // ================================================================
-        // Python: Leader crash requires cold-start respawn (~1.5s)
-        // ================================================================
-        set_python_logs.update(|logs| {
-            logs.push(LogEntry { 
-                level: "error".into(), 
-                message: format!("[RAFT] Leader W{} {}!", 
-                    current_leader_py,
-                    if is_timeout { "unresponsive" } else { "crashed" })
-            });
-            logs.push(LogEntry { 
-                level: "warn".into(), 
-                message: "[RAFT] Starting election...".into() 
-            });
-            logs.push(LogEntry { 
-                level: "error".into(), 
-                message: "[RAFT] Election BLOCKED — need leader respawn first".into() 
-            });
-        });
-        
-        // Mark current leader as dead
-        let mut workers = python_workers.get();
-        workers[current_leader_py as usize] = false;
-        set_python_workers.set(workers);
-        set_python_restarting.set(true);
-        
-        // Python takes real Pyodide load time to respawn + ±200ms jitter
-        let base_restart = if pyodide_load_ms.get() > 0.0 {
-            pyodide_load_ms.get() as i32
-        } else {
-            1500
-        };
-        let jitter = ((js_sys::Math::random() * 400.0) - 200.0) as i32;
-        let restart_ms = (base_restart + jitter).max(500) as u32;
-        set_python_downtime_ms.update(|d| *d += restart_ms as u64);
-        set_python_crashed.update(|n| *n += 1);
-        
-        let next_leader_py = (current_leader_py + 1) % 3;
-        set_timeout(move || {
-            set_python_workers.set([true, true, true]);
-            set_python_active_worker.set(next_leader_py);
-            set_python_restarting.set(false);
-            set_python_logs.update(|logs| {
-                logs.push(LogEntry { 
-                    level: "success".into(), 
-                    message: format!("[OK] W{} respawned ({}ms) — W{} elected as leader", 
-                        current_leader_py, restart_ms, next_leader_py)
-                });
-            });
-            // Only reset is_running if not in running_all mode
-            if !running_all.get() { set_is_running.set(false); }
-        }, std::time::Duration::from_millis(restart_ms as u64));
-        
-        // ================================================================
-        // WASM: Sub-ms leader election (Raft-like)
-        // ================================================================
-        let old_leader = leader_id.get();
-        let new_leader = (old_leader + 1) % 3;
-        
-        // Mark old leader as faulty temporarily
-        let mut states = instance_states.get();
-        states[old_leader as usize] = InstanceState::Faulty;
-        set_instance_states.set(states);
-        set_faulty_instance.set(Some(old_leader));
-        
-        set_wasm_logs.update(|logs| {
-            logs.push(LogEntry { 
-                level: "error".into(), 
-                message: format!("[RAFT] Leader I{} {}!", old_leader,
-                    if is_timeout { "missed heartbeat" } else { "crashed" })
-            });
-            logs.push(LogEntry { 
-                level: "info".into(), 
-                message: "[RAFT] Election started...".into() 
-            });
-        });
-        
-        // Measure real election time (WASM instantiate = election time)
-        spawn_local(async move {
-            let election_time = measure_instantiate_time().await;
-            
-            set_leader_id.set(new_leader);
-            set_wasm_rejected.update(|n| *n += 1);
-            
-            set_wasm_logs.update(|logs| {
-                logs.push(LogEntry { 
-                    level: "success".into(), 
-                    message: format!("[RAFT] I{} elected as new leader in {:.2}ms", new_leader, election_time)
-                });
-                logs.push(LogEntry { 
-                    level: "success".into(), 
-                    message: "[OK] Zero downtime — new leader accepting writes".into()
-                });
-            });
-            
-            // Rebuild old leader as follower
-            set_timeout(move || {
-                let mut states = instance_states.get();
-                states[old_leader as usize] = InstanceState::Healthy;
-                set_instance_states.set(states);
-                set_faulty_instance.set(None);
-                
-                set_wasm_logs.update(|logs| {
-                    logs.push(LogEntry { 
-                        level: "info".into(), 
-                        message: format!("[OK] I{} rebuilt as follower — pool healthy", old_leader)
-                    });
-                });
-            }, std::time::Duration::from_millis(50));
-        });
-    };
+    headless::register_store(Some(store));
+    on_cleanup(|| headless::register_store(None));
+    create_effect(move |was_busy: Option<bool>| {
+        let busy = control.is_running.get() || control.running_all.get();
+        if was_busy == Some(true) && !busy {
+            headless::emit(|| headless::notice("idle"));
+        }
+        busy
+    });
     
     // ========================================================================
-    // run all attacks (all 5: security + availability)
+    // multi-tab sync: the control tab sends its state (debounced) whenever it changes or a display joins; a display restores it
     // ========================================================================
-    let run_all_attacks = move |_| {
-        if is_running.get() || running_all.get() { return; }
-        
-        // Set flags - running_all stays true throughout entire sequence
-        set_is_running.set(true);
-        set_running_all.set(true);
-        
-        // All 5 attacks in order: Security (1-3), then Availability (4-5)
-        let attacks: [(&str, bool); 5] = [
-            ("bufferOverflow", false),   // security - use trigger_attack
-            ("dataExfil", false),        // security - use trigger_attack
-            ("pathTraversal", false),    // security - use trigger_attack
-            ("killLeader", true),        // availability - use trigger_leader_crash
-            ("heartbeatTimeout", true),  // availability - use trigger_leader_crash
-        ];
-        
-        for (i, (attack_name, is_leader_attack)) in attacks.iter().enumerate() {
-            let attack = attack_name.to_string();
-            let is_leader = *is_leader_attack;
-            let delay = (i as u64) * 3500; // 3.5s between each attack (allow respawn)
-            
-            set_timeout(move || {
-                set_selected_attack.set(attack);
-                set_timeout(move || {
-                    // When running all, don't set is_running - it's managed by run_all_attacks
-                    if is_leader {
-                        trigger_leader_crash(());
-                    } else {
-                        trigger_attack(());
-                    }
-                }, std::time::Duration::from_millis(100));
-            }, std::time::Duration::from_millis(delay));
+    let pending = store_value(Option::<leptos::leptos_dom::helpers::TimeoutHandle>::None);
+    create_effect(move |_| {
+        for log in [store.python_log, store.wasm_log, store.js_log] {
+            log.track();
         }
-        
-        // Schedule reset of running_all after all attacks complete
-        // 5 attacks * 3.5s = 17.5s + extra buffer for last attack to finish (~3s)
-        set_timeout(move || {
-            set_running_all.set(false);
-            set_is_running.set(false);
-        }, std::time::Duration::from_millis(20500));
-    };
+        journal.track();
+        cluster.instance_states.track();
+        cluster.leader_id.track();
+        cluster.log.track();
+        cluster.suspicion.track();
+        store.pool.python_workers.track();
+        let stats = store.stats;
+        for counter in [stats.python_processed, stats.python_crashed, stats.wasm_processed, stats.wasm_rejected, stats.js_processed] {
+            counter.track();
+        }
+        stats.python_downtime_ms.track();
+        control.selected_attack.track();
+        policy.track();
+        store.voter.track();
+        store.rate_limit.track();
+        if sync.followers() == 0 || pending.with_value(Option::is_some) { return; }
+        if let Ok(handle) = set_timeout_with_handle(move || {
+            pending.set_value(None);
+            sync.publish_state(&store.snapshot(js_sys::Date::now()));
+        }, std::time::Duration::from_millis(STATE_DEBOUNCE_MS)) {
+            pending.set_value(Some(handle));
+        }
+    });
+    on_cleanup(move || {
+        if let Some(handle) = pending.get_value() {
+            handle.clear();
+        }
+    });
+    create_effect(move |_| {
+        let Some(snap) = sync.state.get() else { return };
+        if !sync.following.get() { return; }
+        if let Err(e) = store.restore(&snap) {
+            tracing::warn!(target: "sync", error = %e, "control tab state not restored");
+        }
+    });
+
+    // ========================================================================
+    // suspicion decay (one history sample per second)
+    // ========================================================================
+    if let Ok(handle) = set_interval_with_handle(move || cluster.suspicion.update(|s| s.tick(1.0)), std::time::Duration::from_secs(1)) {
+        on_cleanup(move || handle.clear());
+    }
     
     // ========================================================================
-    // reset
+    // live instance budgets: spawn on mount, respawn on hot-swap unless a recovery already did, one ring write per processed reading
+    // ========================================================================
+    (0..3).for_each(|i| store.spawn_instance(i));
+    create_effect(move |prev: Option<[InstanceState; 3]>| {
+        let states = cluster.instance_states.get();
+        if let Some(prev) = prev {
+            (0..3)
+                .filter(|&i| prev[i] != InstanceState::Healthy && states[i] == InstanceState::Healthy)
+                .for_each(|i| store.refresh_instance(i));
+        }
+        states
+    });
+    create_effect(move |prev: Option<u32>| {
+        let processed = store.stats.wasm_processed.get();
+        if prev.is_some_and(|p| processed > p) {
+            store.instance_workload(WORKLOAD_BYTES);
+        }
+        processed
+    });
+
     // ========================================================================
-    let reset_demo = move |_| {
-        set_python_logs.set(Vec::new());
-        set_wasm_logs.set(Vec::new());
-        set_python_processed.set(0);
-        set_python_crashed.set(0);
-        set_python_downtime_ms.set(0);
-        set_wasm_processed.set(0);
-        set_wasm_rejected.set(0);
-        set_instance_states.set([InstanceState::Healthy; 3]);
-        set_faulty_instance.set(None);
-        set_leader_id.set(0);  // Reset leader to I0
-        set_python_workers.set([true, true, true]);
-        set_python_active_worker.set(0);
-        set_python_restarting.set(false);
-        set_is_running.set(false);
-        set_running_all.set(false);  // Reset run-all mode
-    };
+    // prometheus snapshot of the stat counters
+    // ========================================================================
+    let metrics_snapshot = Signal::derive(move || store.metrics_snapshot());
 
     // ========================================================================
     // view
@@ -628,374 +309,88 @@ result
             <div class="demo-section">
                 <h3>"⏱️ Initialization Time"</h3>
                 <p class="section-desc">"Compare cold-start performance between runtimes"</p>
-                
-                // metrics banner
-                <div class="metrics-banner">
-                    <div class="metric-item" title="Measured using WebAssembly API (10 iterations averaged)">
-                        <span class="metric-label">"WASM Instantiate (real)"</span>
-                        <span class="metric-value">{move || format!("{:.2}ms", wasm_instantiate_ms.get())}</span>
-                    </div>
-                    <div class="metric-item" title="Real Pyodide cold-start time measured at page load">
-                        <span class="metric-label">"Python Cold-Start (real)"</span>
-                        <span class="metric-value warning">{move || {
-                            let ms = pyodide_load_ms.get();
-                            if ms > 0.0 {
-                                format!("{:.0}ms", ms)
-                            } else {
-                                "Loading...".to_string()
-                            }
-                        }}</span>
-                    </div>
-                    <div class="metric-item speedup">
-                        <span class="metric-label">"Speedup"</span>
-                        <span class="metric-value">{move || {
-                            let wasm = wasm_instantiate_ms.get();
-                            let python = pyodide_load_ms.get();
-                            if wasm > 0.0 && python > 0.0 {
-                                format!("{:.0}x faster", python / wasm)
-                            } else {
-                                "—".to_string()
-                            }
-                        }}</span>
-                    </div>
-                </div>
-                
-                // Sensor execution comparison
-                <div class="sensor-comparison">
-                    <h4>"📊 Sensor Execution (Real)"</h4>
-                    <div class="sensor-row">
-                        <div class="sensor-metric">
-                            <span class="sensor-label">"WASM"</span>
-                            <span class="sensor-value success">{move || {
-                                if sensor_ran.get() {
-                                    let ms = wasm_exec_ms.get();
-                                    if ms < 0.001 { "<0.001ms".to_string() } else { format!("{:.3}ms", ms) }
-                                } else { "—".to_string() }
-                            }}</span>
-                        </div>
-                        <div class="sensor-metric">
-                            <span class="sensor-label">"Python (Pyodide)"</span>
-                            <span class="sensor-value warning">{move || {
-                                let ms = python_exec_ms.get();
-                                if ms > 0.0 { format!("{:.2}ms", ms) } 
-                                else if ms < 0.0 { "Error".to_string() }
-                                else { "—".to_string() }
-                            }}</span>
-                        </div>
-                        <button 
-                            class="action-btn run-sensor"
-                            disabled=move || sensor_running.get() || !pyodide_ready.get() || is_running.get()
-                            title=move || if pyodide_ready.get() { "Run real sensor code in both runtimes".to_string() } else { "Waiting for Pyodide to load...".to_string() }
-                            on:click=move |_| run_sensor_comparison(())
-                        >
-                            {move || if sensor_running.get() { "⏳ Running..." } 
-                                    else if !pyodide_ready.get() { "⏳ Loading Pyodide..." }
-                                    else { "▶️ Run Sensor Check" }}
-                        </button>
-                    </div>
-                </div>
+                <PrecisionBanner />
+                <MetricsBanner perf=perf />
+                <SensorComparison store=store />
             </div>
             
-            // terminals side by side
-            <div class="terminals-container">
-                // python terminal - 2oo3 TMR attempt (fails during respawn)
-                <div class="terminal-panel python-panel">
-                    <div class="terminal-header">
-                        <span class="terminal-title" attr:data-tooltip="Python multiprocessing with 3 workers - L/F election takes ~1.5s vs WASM's 0.04ms">"🐍 Python (2oo3 TMR / Raft-like)"</span>
-                        <span class="terminal-status" class:crashed=move || python_restarting.get()>
-                            {move || if python_restarting.get() { "⏳ RESPAWNING" } else { "🟢 3/3 UP" }}
-                        </span>
-                    </div>
-                    <div class="terminal" id="python-terminal">
-                        {move || {
-                            let entries = python_logs.get();
-                            if entries.is_empty() {
-                                view! { <p class="terminal-line info">"$ ready"</p> }.into_view()
-                            } else {
-                                entries.into_iter().map(|e| {
-                                    view! { <p class=format!("terminal-line {}", e.level)>{e.message}</p> }
-                                }).collect_view()
-                            }
-                        }}
-                    </div>
-                    // worker boxes with memory indicator - L/F/F pattern like WASM
-                    <div class="workers-panel">
-                        <span class="workers-label">"Nodes:"</span>
-                        {move || {
-                            let workers = python_workers.get();
-                            let active = python_active_worker.get();
-                            (0..3).map(|i| {
-                                let is_dead = !workers[i];
-                                // First alive worker is "leader" for Python consensus
-                                let is_leader = (i as u8) == active;
-                                let label = if is_leader { "L" } else { "F" };
-                                view! {
-                                    <div class="worker-box"
-                                        class:active=!is_dead
-                                        class:dead=is_dead
-                                        class:leader=is_leader && !is_dead
-                                        attr:data-tooltip=move || if is_leader { "Leader (long election if fails)" } else { "Follower" }
-                                    >
-                                        {label}
-                                    </div>
-                                }
-                            }).collect_view()
-                        }}
-                        <span class="memory-indicator warning" attr:data-tooltip="~45MB per Python worker (Pyodide)">"Total: 135MB"</span>
-                    </div>
-                </div>
-                
-                // wasm terminal - Leader/Follower pattern (like Raft)
-                <div class="terminal-panel wasm-panel">
-                    <div class="terminal-header">
-                        <span class="terminal-title" attr:data-tooltip="2oo3 TMR voting with sub-ms WASM failover">"🦀 WASM (2oo3 TMR / Raft-like)"</span>
-                        <span class="terminal-status">"🟢 3/3 UP"</span>
-                    </div>
-                    <div class="terminal" id="wasm-terminal">
-                        {move || {
-                            let entries = wasm_logs.get();
-                            if entries.is_empty() {
-                                view! { <p class="terminal-line info">"$ ready"</p> }.into_view()
-                            } else {
-                                entries.into_iter().map(|e| {
-                                    view! { <p class=format!("terminal-line {}", e.level)>{e.message}</p> }
-                                }).collect_view()
-                            }
-                        }}
-                    </div>
-                    // instance boxes - Leader (L) + Followers (F) like Raft
-                    <div class="instances-panel">
-                        <span class="instances-label">"Nodes:"</span>
-                        {move || {
-                            let states = instance_states.get();
-                            let faulty = faulty_instance.get();
-                            let current_leader = leader_id.get();
-                            (0..3).map(|i| {
-                                let is_faulty = faulty == Some(i as u8);
-                                // Dynamic leader - first healthy node or elected leader
-                                let is_leader = (i as u8) == current_leader;
-                                let label = if is_leader { "L" } else { "F" };
-                                view! {
-                                    <div class="instance-box"
-                                        class:healthy=states[i] == InstanceState::Healthy && !is_faulty
-                                        class:faulty=is_faulty
-                                        class:leader=is_leader
-                                        attr:data-tooltip=move || if is_leader { "Leader (sub-ms election if fails)" } else { "Follower" }
-                                    >
-                                        {label}
-                                    </div>
-                                }
-                            }).collect_view()
-                        }}
-                        <span class="memory-indicator success" attr:data-tooltip="~2MB per WASM instance">{"Total: 6MB"}</span>
-                    </div>
-                </div>
+            // incident markers and presenter notes over the terminals they highlight
+            <TimelinePanel store=store />
+
+            // terminals side by side (one column per runtime)
+            <div class="terminals-container" style=move || format!("--runtime-count: {}", kinds.with(|k| k.len()))>
+                {move || kinds.get().into_iter().map(|kind| view! { <RuntimePanel kind=kind store=store /> }).collect_view()}
             </div>
             
             // stats comparison
             <div class="stats-container">
-                <div class="stats-panel python-stats">
-                    <h4>"🐍 Python Stats"</h4>
-                    <div class="stats-row">
-                        <div class="stat-item">
-                            <span class="stat-value">{python_processed}</span>
-                            <span class="stat-label">"Processed"</span>
-                        </div>
-                        <div class="stat-item">
-                            <span class="stat-value error">{python_crashed}</span>
-                            <span class="stat-label">"Crashed"</span>
-                        </div>
-                        <div class="stat-item">
-                            <span class="stat-value error">{move || format!("{}ms", python_downtime_ms.get())}</span>
-                            <span class="stat-label">"Downtime"</span>
-                        </div>
-                    </div>
-                </div>
-                
-                <div class="stats-panel wasm-stats">
-                    <h4>"🦀 WASM Stats"</h4>
-                    <div class="stats-row">
-                        <div class="stat-item">
-                            <span class="stat-value">{wasm_processed}</span>
-                            <span class="stat-label">"Processed"</span>
-                        </div>
-                        <div class="stat-item">
-                            <span class="stat-value warn">{wasm_rejected}</span>
-                            <span class="stat-label">"Voted Out"</span>
-                        </div>
-                        <div class="stat-item">
-                            <span class="stat-value success">"0ms"</span>
-                            <span class="stat-label">"Downtime"</span>
-                        </div>
-                    </div>
-                </div>
-            </div>
-            
-            // ================================================================
-            // SECURITY ATTACKS SECTION
-            // ================================================================
-            <div class="attack-group security-group">
-                <h3>"☠️ Security Attacks"<span class="attack-badge">"WIT Capability Denial"</span></h3>
-                <p class="section-desc">"WASM blocks at boundary via WIT — Python crashes"</p>
-                <div class="attack-buttons">
-                    <button 
-                        class="attack-btn"
-                        class:running=move || selected_attack.get() == "bufferOverflow" && is_running.get()
-                        disabled=move || is_running.get()
-                        title="Memory corruption attack - WIT denies malloc-large()"
-                        on:click=move |_| {
-                            set_selected_attack.set("bufferOverflow".to_string());
-                            trigger_attack(());
-                        }
-                    >
-                        "💥 Buffer Overflow"
-                    </button>
-                    <button 
-                        class="attack-btn"
-                        class:running=move || selected_attack.get() == "dataExfil" && is_running.get()
-                        disabled=move || is_running.get()
-                        title="Network exfiltration - WIT denies open-socket()"
-                        on:click=move |_| {
-                            set_selected_attack.set("dataExfil".to_string());
-                            trigger_attack(());
-                        }
-                    >
-                        "📤 Data Exfil"
-                    </button>
-                    <button 
-                        class="attack-btn"
-                        class:running=move || selected_attack.get() == "pathTraversal" && is_running.get()
-                        disabled=move || is_running.get()
-                        title="Filesystem probe - WIT denies read-file()"
-                        on:click=move |_| {
-                            set_selected_attack.set("pathTraversal".to_string());
-                            trigger_attack(());
-                        }
-                    >
-                        "📁 Path Traversal"
-                    </button>
-                </div>
+                {move || kinds.get().into_iter().map(|kind| view! { <StatsPanel kind=kind store=store /> }).collect_view()}
             </div>
+            <DowntimeLedger journal=journal />
             
-            // ================================================================
-            // AVAILABILITY ATTACKS SECTION
-            // ================================================================
-            <div class="attack-group availability-group">
-                <h3>"⚡ Availability Attacks"<span class="attack-badge">"Raft Leader Election"</span></h3>
-                <p class="section-desc">"Crash the leader — compare election recovery time"</p>
-                <div class="attack-buttons">
-                    <button 
-                        class="attack-btn leader-btn"
-                        class:running=move || selected_attack.get() == "killLeader" && is_running.get()
-                        disabled=move || is_running.get()
-                        title="Force crash on leader (simulates OOM, panic, hardware failure)"
-                        on:click=move |_| {
-                            set_selected_attack.set("killLeader".to_string());
-                            trigger_leader_crash(());
-                        }
-                    >
-                        "🗡️ Kill Leader"
-                    </button>
-                    <button 
-                        class="attack-btn leader-btn"
-                        class:running=move || selected_attack.get() == "heartbeatTimeout" && is_running.get()
-                        disabled=move || is_running.get()
-                        title="Leader becomes unresponsive (simulates network partition, deadlock)"
-                        on:click=move |_| {
-                            set_selected_attack.set("heartbeatTimeout".to_string());
-                            trigger_leader_crash(());
-                        }
-                    >
-                        "⏱️ Heartbeat Timeout"
-                    </button>
-                </div>
-            </div>
+            <RaftLogPanel cluster=cluster status=raft_status />
             
             // ================================================================
-            // GLOBAL ACTIONS + INFO BOX
+            // CAPABILITY POLICY SECTION
             // ================================================================
-            <div class="attack-actions">
-                <button 
-                    class="action-btn runall" 
-                    title="Run all 5 attacks sequentially"
-                    disabled=move || is_running.get() 
-                    on:click=move |_| run_all_attacks(())
+            <div class="demo-section policy-section">
+                <h3>"🔐 Capability Policy"<span class="attack-badge">"Configuration-as-Security"</span></h3>
+                <p class="section-desc">"What each WIT world is granted — edit the policy and re-run an attack"</p>
+                <CapabilityMatrix policy=policy />
+                <button
+                    class="action-btn policy-toggle"
+                    on:click=move |_| set_policy_editor_open.update(|v| *v = !*v)
                 >
-                    "🔥 Run All Attacks"
+                    {move || if policy_editor_open.get() { "✖ Close Editor" } else { "📝 Edit Policy" }}
                 </button>
-                <button 
-                    class="action-btn reset" 
-                    title="Reset all stats and terminals"
-                    disabled=move || is_running.get()
-                    on:click=move |_| reset_demo(())
+                <button
+                    class="action-btn policy-toggle"
+                    on:click=move |_| set_wasmtime_open.update(|v| *v = !*v)
                 >
-                    "🔄 Reset"
+                    {move || if wasmtime_open.get() { "✖ Hide wasmtime Flags" } else { "🖥️ wasmtime Flags" }}
                 </button>
+                <Show when=move || policy_editor_open.get()>
+                    <PolicyEditor policy=policy />
+                </Show>
+                <Show when=move || wasmtime_open.get()>
+                    <WasmtimeCommands policy=policy />
+                </Show>
             </div>
             
-            // Info box with clear two-part narrative
-            <div class="info-box">
-                <h4>"ℹ️ About This Demo"<span class="demo-badge">"Browser Demonstration"</span></h4>
-                
-                <div class="info-section">
-                    <h5>"💡 Key Insight (Fail-Stop vs Byzantine)"</h5>
-                    <p>"WASM converts attacks into "<strong>"Fail-Stop faults"</strong>" — the instance returns an "<strong>"explicit TRAP"</strong>" instantly, not silence. TMR sees [Value, Value, Err(Trap)] and proceeds immediately. Python crashes produce "<strong>"no response"</strong>", forcing the voter to wait for a timeout before declaring the node dead."</p>
-                </div>
-                
-                <div class="info-section">
-                    <h5>"🔒 Security Attacks (WIT Capability Denial)"</h5>
-                    <ul>
-                        <li><strong>"🐍 Python:"</strong>" Attack executes → "<strong>"exception"</strong>" → process crash → no output"</li>
-                        <li><strong>"🦀 WASM:"</strong>" "<strong>"WIT"</strong>" blocks syscall → returns "<strong>"TRAP"</strong>" → "<strong>"2oo3 voting"</strong>" excludes it → 0 downtime"</li>
-                    </ul>
-                </div>
-                
-                <div class="info-section">
-                    <h5>"⚡ Availability Attacks (Leader Failover)"</h5>
-                    <ul>
-                        <li><strong>"🐍 Python:"</strong>" Leader crash → "<strong>"cold-start"</strong>" respawn → ~1.5s election delay"</li>
-                        <li><strong>"🦀 WASM:"</strong>" Leader crash → "<strong>"sub-ms instantiate"</strong>" → new leader in ~0.04ms"</li>
-                    </ul>
-                </div>
-                
-                <div class="info-section">
-                    <h5>"✅ What's Real vs Simulated"</h5>
-                    <ul>
-                        <li><strong>"Real:"</strong>" Python exceptions ("<strong>"Pyodide"</strong>"), WASM timing ("<strong>"WebAssembly API"</strong>")"</li>
-                        <li><strong>"Simulated:"</strong>" WIT capability denial (real "<strong>"wasmtime"</strong>" enforces at syscall level)"</li>
-                        <li><strong>"Restart times:"</strong>" Python uses "<strong>"cold-start measured at page load"</strong>" ±200ms jitter. WASM rebuild is measured fresh each attack."</li>
-                    </ul>
-                </div>
-                
-                <p class="wit-note">
-                    <strong>"🔒 WIT Contract:"</strong>" "
-                    <a class="wit-link" href="#" on:click=move |e: web_sys::MouseEvent| {
-                        e.prevent_default();
-                        set_wit_modal_open.set(true);
-                    }>"View wit/attacks.wit"</a>
-                    " — defines the "<strong>"capability boundary"</strong>" (same format used by wasmtime)"
-                </p>
-                <p class="wit-subnote">"↳ "<em>"Note: "</em><code>"attack-surface"</code>" import is for browser simulation only. On "<strong>"Raspberry Pi + wasmtime"</strong>", this capability would simply "<strong>"not be granted"</strong>" — any call traps immediately at the host boundary."</p>
-                
-                <p class="hardware-note">"🔧 "<strong>"Coming Soon:"</strong>" Hardware demo on "<strong>"Raspberry Pi"</strong>" with "<strong>"wasmtime"</strong>" enforcing WIT at syscall level."</p>
-            </div>
+            <VotingPolicyPanel store=store />
+            <LedStripPanel store=store />
+            <SuspicionPanel store=store />
+            <SupervisorPanel store=store />
+            <ResyncPanel store=store />
+            <AnomalyPanel store=store />
+            <FusionPanel store=store />
+            <ActuatorPanel store=store />
+            <FloodPanel store=store />
+            <SoakPanel store=store />
+            <TimingChannelPanel />
+            <ArchitecturePanel />
+            
+            <AttackControls store=store />
+            <QuizPanel store=store />
+            <LibraryPanel store=store />
+            <MitrePanel store=store />
+            
+            // SIEM export of the structured journal
+            <AuditPanel journal=journal annotations=store.annotations perf=perf methodology=methodology />
+            <HostTracePanel trace=store.host_trace />
+            <MetricsPanel snapshot=metrics_snapshot journal=journal methodology=methodology />
+            <MethodologyPanel methodology=methodology />
+            <InfluxPanel writer=influx />
+            <ModbusPanel store=store />
+            <CapturePanel store=store />
+            <PcapPanel />
+            <SnapshotPanel store=store methodology=methodology />
+            <HandoffPanel perf=perf />
+            
+            <InfoBox election=perf.election policy=policy payloads=store.payloads />
             
-            // WIT Code Modal
-            {move || if wit_modal_open.get() {
-                view! {
-                    <div class="modal-overlay" on:click=move |_| set_wit_modal_open.set(false)>
-                        <div class="modal-content" on:click=|e: web_sys::MouseEvent| e.stop_propagation()>
-                            <div class="modal-header">
-                                <span class="modal-title">"📄 wit/attacks.wit"</span>
-                                <button class="modal-close" on:click=move |_| set_wit_modal_open.set(false)>"×"</button>
-                            </div>
-                            <pre class="wit-code">{WIT_CODE_EXCERPT}</pre>
-                        </div>
-                    </div>
-                }.into_view()
-            } else {
-                view! { <div></div> }.into_view()
-            }}
+            // history of the node box last clicked
+            <NodeDrawer store=store />
         </div>
     }
 }
@@ -0,0 +1,87 @@
+// what: the sensor check on the real wasi 0.2 sensor-driver component (jco bindings + preview2 browser shim), and how it differs from the wasm-bindgen path
+// why: the wasm-bindgen build is a browser convenience; the component is what wasmtime runs, so the demo can switch to it and show what changes
+// relations: loader window.loadSensorComponent in index.html, files from build-component.sh; selected by settings SensorBackend;
+//            runs recorded by handlers.rs into perf.backend_runs, differences shown by sensor_comparison.rs
+
+use wasm_bindgen::{JsCast, JsValue};
+use guardian_types::SensorReading;
+use crate::measure::stats::summarize;
+use crate::settings::SensorBackend;
+use super::wasm::{loadSensorComponent, now};
+
+/// module build-component.sh emits, relative to the page
+pub const COMPONENT_MODULE: &str = "component/sensor_driver.js";
+/// readings closer than this count as the same value
+const READING_TOLERANCE: f64 = 1e-9;
+
+/// one sensor check on one backend
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackendRun {
+    pub backend: SensorBackend,
+    pub exec_ms: f64,
+    /// fetch, compile and instantiate of the component; only on its first run
+    pub load_ms: Option<f64>,
+    pub reading: SensorReading,
+}
+
+/// what differs between the two paths, latest reading of each and median call times; empty until both have run
+pub fn differences(runs: &[BackendRun]) -> Vec<String> {
+    let last = |backend: SensorBackend| runs.iter().rev().find(|r| r.backend == backend);
+    let (Some(bindgen), Some(component)) = (last(SensorBackend::Bindgen), last(SensorBackend::Component)) else {
+        return Vec::new();
+    };
+    let median = |backend: SensorBackend| summarize(&runs.iter().filter(|r| r.backend == backend).map(|r| r.exec_ms).collect::<Vec<_>>(), 0).median;
+    let mut out = Vec::new();
+    let fields = [
+        ("temperature", bindgen.reading.temperature, component.reading.temperature, "°C"),
+        ("humidity", bindgen.reading.humidity, component.reading.humidity, "%"),
+        ("pressure", bindgen.reading.pressure, component.reading.pressure, " hPa"),
+    ];
+    for (field, a, b, unit) in fields {
+        if (a - b).abs() > READING_TOLERANCE {
+            out.push(format!("{}: {:.2}{} (wasm-bindgen) vs {:.2}{} (component)", field, a, unit, b, unit));
+        }
+    }
+    let (b, c) = (median(SensorBackend::Bindgen), median(SensorBackend::Component));
+    let ratio = if b > 0.0 { format!("{:.1}× the wasm-bindgen call", c / b) } else { "wasm-bindgen call below timer resolution".to_string() };
+    out.push(format!("call time: {:.3}ms vs {:.3}ms median ({})", b, c, ratio));
+    if let Some(load) = runs.iter().find_map(|r| r.load_ms) {
+        out.push(format!("component load (fetch + compile + instantiate, once): {:.1}ms", load));
+    }
+    out
+}
+
+fn field(reading: &JsValue, key: &str) -> Result<f64, String> {
+    js_sys::Reflect::get(reading, &key.into())
+        .ok()
+        .and_then(|v| v.as_f64())
+        .ok_or_else(|| format!("sensor-reading has no {}", key))
+}
+
+/// imports the component (first call only) and runs driver.sensorCheck() once
+pub async fn run_component(first: bool) -> Result<BackendRun, String> {
+    let start = now();
+    let module = loadSensorComponent()
+        .await
+        .map_err(|e| format!("{} not available ({:?}) - build it with build-component.sh", COMPONENT_MODULE, e))?;
+    let load_ms = first.then(|| now() - start);
+    let driver = js_sys::Reflect::get(&module, &"driver".into()).map_err(|e| format!("{:?}", e))?;
+    let check = js_sys::Reflect::get(&driver, &"sensorCheck".into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+        .ok_or("driver.sensorCheck missing from the bindings")?;
+    let call_start = now();
+    // a result<_, string> error arrives as a thrown ComponentError carrying the string
+    let reading = check.call0(&driver).map_err(|e| format!("sensor-check failed: {:?}", e))?;
+    let exec_ms = now() - call_start;
+    Ok(BackendRun {
+        backend: SensorBackend::Component,
+        exec_ms,
+        load_ms,
+        reading: SensorReading {
+            temperature: field(&reading, "temperature")?,
+            humidity: field(&reading, "humidity")?,
+            pressure: field(&reading, "pressure")?,
+        },
+    })
+}
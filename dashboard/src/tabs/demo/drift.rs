@@ -0,0 +1,62 @@
+// what: slow sensor-drift maintenance scenario: one instance's reading creeps away until the voter outvotes it
+// why: most real TMR disagreements are miscalibration, not attacks; detection time depends on the voting band
+// relations: votes through voting.rs, driven by handlers.rs (run_drift_scenario), shown by voting_panel.rs
+
+use super::voting::{VoteOutcome, VoteStrategy, SCENARIO_READING};
+
+/// drift of the miscalibrated sensor per simulated minute (°C)
+pub const DRIFT_PER_MIN: f64 = 0.01;
+/// real milliseconds per simulated minute
+pub const DRIFT_TICK_MS: u64 = 250;
+/// the scenario gives up after this many simulated minutes
+pub const DRIFT_MAX_MIN: u32 = 120;
+/// instance whose sensor drifts
+pub const DRIFTING: usize = 2;
+/// tolerance bands compared in the detection-time table (°C)
+pub const DRIFT_BANDS: [f64; 4] = [0.05, 0.1, 0.2, 0.5];
+
+/// voter's view of the drifting instance at one minute
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DriftStep {
+    /// all three outputs still agree
+    Tracking,
+    /// the drifting instance was outvoted
+    Detected,
+    /// no two outputs agree, nothing is emitted
+    Halted,
+}
+
+/// scenario progress for the panel
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DriftRun {
+    pub minute: u32,
+    pub step: DriftStep,
+    /// set once the outvoted instance has been hot-swapped
+    pub recalibrated: bool,
+}
+
+impl DriftRun {
+    pub fn offset(&self) -> f64 {
+        DRIFT_PER_MIN * self.minute as f64
+    }
+}
+
+/// I0/I1 read the real sensor (0.03°C apart); I2 starts between them and has drifted for `minute` minutes
+pub fn drift_outputs(minute: u32) -> [Option<f64>; 3] {
+    [Some(SCENARIO_READING), Some(SCENARIO_READING + 0.03), Some(SCENARIO_READING + 0.015 + DRIFT_PER_MIN * minute as f64)]
+}
+
+pub fn drift_step(strategy: VoteStrategy, minute: u32) -> DriftStep {
+    match strategy.vote(&drift_outputs(minute)) {
+        VoteOutcome::Consensus { agreeing: 3, .. } => DriftStep::Tracking,
+        VoteOutcome::Consensus { .. } => DriftStep::Detected,
+        VoteOutcome::Halted { .. } => DriftStep::Halted,
+    }
+}
+
+/// first simulated minute at which the voter stops tracking, None if it never does within DRIFT_MAX_MIN
+pub fn detection(strategy: VoteStrategy) -> Option<(u32, DriftStep)> {
+    (0..=DRIFT_MAX_MIN)
+        .map(|minute| (minute, drift_step(strategy, minute)))
+        .find(|(_, step)| *step != DriftStep::Tracking)
+}
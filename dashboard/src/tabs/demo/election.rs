@@ -0,0 +1,176 @@
+// what: replay of one simulated raft failover as ring frames - roles, terms and the vote requests/grants in flight
+// why: "I1 elected in 212ms" is a number; watching the timeout, the requests and the grants shows why it took that long
+// relations: built by handlers.rs from raft.rs failover_traced, stepped on the sim clock, drawn by election_ring.rs in place of node_strip.rs's boxes
+
+use super::raft::{ElectionEvent, ElectionStep, CLUSTER_SIZE};
+
+/// wall-clock time each frame stays up at 1x
+pub const FRAME_MS: u64 = 450;
+/// how long the finished election stays up before the node boxes return
+pub const HOLD_MS: u64 = 2500;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RingRole {
+    Leader,
+    Candidate,
+    Follower,
+    Down,
+}
+
+impl RingRole {
+    pub fn letter(&self) -> &'static str {
+        match self {
+            RingRole::Leader => "L",
+            RingRole::Candidate => "C",
+            RingRole::Follower => "F",
+            RingRole::Down => "✕",
+        }
+    }
+
+    pub fn class(&self) -> &'static str {
+        match self {
+            RingRole::Leader => "leader",
+            RingRole::Candidate => "candidate",
+            RingRole::Follower => "follower",
+            RingRole::Down => "down",
+        }
+    }
+}
+
+/// one node on the ring
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RingNode {
+    pub role: RingRole,
+    pub term: u64,
+    pub voted_for: Option<usize>,
+    /// votes collected while a candidate
+    pub votes: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrowKind {
+    Request,
+    Grant,
+    Deny,
+}
+
+/// a message sent in this frame
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arrow {
+    pub from: usize,
+    pub to: usize,
+    pub kind: ArrowKind,
+    /// addressed to a down node, so never answered
+    pub lost: bool,
+}
+
+/// the ring at one moment of the election
+#[derive(Clone, Debug, PartialEq)]
+pub struct RingFrame {
+    /// simulated time since the crash
+    pub at_ms: f64,
+    /// highest term any node has seen
+    pub term: u64,
+    pub nodes: [RingNode; CLUSTER_SIZE],
+    pub arrows: Vec<Arrow>,
+    pub caption: String,
+}
+
+/// ring frames for a traced failover: the steady state, then one frame per instant in the trace.
+/// Simulated terms start at 1; `base_term` is the cluster's term before the crash.
+pub fn frames(base_term: u64, leader: usize, steps: &[ElectionStep]) -> Vec<RingFrame> {
+    let term = |t: u64| base_term + t.saturating_sub(1);
+    let mut nodes: [RingNode; CLUSTER_SIZE] = std::array::from_fn(|id| RingNode {
+        role: if id == leader { RingRole::Leader } else { RingRole::Follower },
+        term: base_term,
+        voted_for: Some(leader),
+        votes: 0,
+    });
+    let mut frames = vec![RingFrame {
+        at_ms: 0.0,
+        term: base_term,
+        nodes,
+        arrows: Vec::new(),
+        caption: format!("I{} leads term {}; followers hear its heartbeats", leader, base_term),
+    }];
+    for group in steps.chunk_by(|a, b| a.at_ms == b.at_ms) {
+        let mut arrows = Vec::new();
+        let mut captions = Vec::new();
+        for step in group {
+            match step.event {
+                ElectionEvent::Crash { node } => {
+                    nodes[node].role = RingRole::Down;
+                    captions.push(format!("Leader I{} is gone - heartbeats stop", node));
+                }
+                ElectionEvent::Stand { node, term: t } => {
+                    nodes[node] = RingNode { role: RingRole::Candidate, term: term(t), voted_for: Some(node), votes: 1 };
+                    captions.push(format!("I{} timed out and stands for term {}", node, term(t)));
+                }
+                ElectionEvent::RequestVote { from, to, .. } => {
+                    arrows.push(Arrow { from, to, kind: ArrowKind::Request, lost: nodes[to].role == RingRole::Down });
+                }
+                ElectionEvent::Vote { from, to, term: t, granted } => {
+                    let voter = &mut nodes[from];
+                    if term(t) > voter.term {
+                        voter.term = term(t);
+                        voter.voted_for = None;
+                        voter.role = RingRole::Follower;
+                    }
+                    if granted {
+                        voter.voted_for = Some(to);
+                        voter.role = RingRole::Follower;
+                        if nodes[to].role == RingRole::Candidate && nodes[to].term == term(t) {
+                            nodes[to].votes += 1;
+                        }
+                    }
+                    arrows.push(Arrow { from, to, kind: if granted { ArrowKind::Grant } else { ArrowKind::Deny }, lost: false });
+                    captions.push(format!("I{} {} I{}", from, if granted { "votes for" } else { "refuses" }, to));
+                }
+                ElectionEvent::Elected { node, term: t } => {
+                    nodes[node].role = RingRole::Leader;
+                    captions.push(format!("I{} wins term {} with {}/{} votes", node, term(t), nodes[node].votes, CLUSTER_SIZE));
+                }
+            }
+        }
+        frames.push(RingFrame {
+            at_ms: group[0].at_ms,
+            term: nodes.iter().map(|n| n.term).max().unwrap_or(base_term),
+            nodes,
+            arrows,
+            caption: captions.join(" · "),
+        });
+    }
+    frames
+}
+
+/// an election being played back on the ring
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElectionReplay {
+    /// trial seed; a newer election replaces this one
+    pub id: u64,
+    pub frames: Vec<RingFrame>,
+    pub frame: usize,
+}
+
+impl ElectionReplay {
+    pub fn new(id: u64, base_term: u64, leader: usize, steps: &[ElectionStep]) -> Self {
+        Self { id, frames: frames(base_term, leader, steps), frame: 0 }
+    }
+
+    pub fn current(&self) -> &RingFrame {
+        &self.frames[self.frame.min(self.frames.len() - 1)]
+    }
+
+    /// moves to the next frame; false once the last one is showing
+    pub fn advance(&mut self) -> bool {
+        if self.finished() {
+            return false;
+        }
+        self.frame += 1;
+        true
+    }
+
+    pub fn finished(&self) -> bool {
+        self.frame + 1 >= self.frames.len()
+    }
+}
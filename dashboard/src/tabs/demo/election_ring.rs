@@ -0,0 +1,81 @@
+// what: three wasm nodes on a ring with their roles, the term badge and vote request/grant arrows of the replayed election
+// why: stands in for the wasm L/F boxes while a leader election plays out
+// relations: draws an election.rs ElectionReplay from the store.rs cluster.election signal; rendered by node_strip.rs
+
+use leptos::*;
+use super::election::{ArrowKind, ElectionReplay, RingFrame};
+
+/// ring box (svg user units), node centres and radius
+const RING_W: f64 = 220.0;
+const RING_H: f64 = 130.0;
+const NODE_R: f64 = 17.0;
+const CENTRES: [(f64, f64); 3] = [(110.0, 24.0), (40.0, 104.0), (180.0, 104.0)];
+
+/// arrow from the edge of one node to the edge of another, nudged sideways so crossing messages stay apart
+fn arrow_line(from: usize, to: usize) -> (f64, f64, f64, f64) {
+    let ((x1, y1), (x2, y2)) = (CENTRES[from], CENTRES[to]);
+    let len = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt();
+    let (ux, uy) = ((x2 - x1) / len, (y2 - y1) / len);
+    let side = if from < to { 4.0 } else { -4.0 };
+    let (nx, ny) = (-uy * side, ux * side);
+    let gap = NODE_R + 3.0;
+    (x1 + ux * gap + nx, y1 + uy * gap + ny, x2 - ux * gap + nx, y2 - uy * gap + ny)
+}
+
+fn ring(frame: &RingFrame) -> View {
+    let arrows = frame.arrows.iter().map(|arrow| {
+        let (x1, y1, x2, y2) = arrow_line(arrow.from, arrow.to);
+        let class = match arrow.kind {
+            ArrowKind::Request => "election-arrow request",
+            ArrowKind::Grant => "election-arrow grant",
+            ArrowKind::Deny => "election-arrow deny",
+        };
+        view! {
+            <g class=class class:lost=arrow.lost>
+                <line x1=format!("{:.1}", x1) y1=format!("{:.1}", y1) x2=format!("{:.1}", x2) y2=format!("{:.1}", y2) />
+                <circle cx=format!("{:.1}", x2) cy=format!("{:.1}", y2) r="3" />
+            </g>
+        }
+    }).collect_view();
+    let nodes = frame.nodes.iter().enumerate().map(|(id, node)| {
+        let (x, y) = CENTRES[id];
+        view! {
+            <g class=format!("election-node {}", node.role.class())>
+                <circle cx=x cy=y r=NODE_R />
+                <text x=x y={y - 2.0} text-anchor="middle">{format!("I{}", id)}</text>
+                <text class="election-node-role" x=x y={y + 10.0} text-anchor="middle">{format!("{} t{}", node.role.letter(), node.term)}</text>
+            </g>
+        }
+    }).collect_view();
+    view! {
+        <svg class="election-svg" viewBox=format!("0 0 {} {}", RING_W, RING_H)>
+            {arrows}
+            {nodes}
+        </svg>
+    }
+    .into_view()
+}
+
+#[component]
+pub fn ElectionRing(replay: Signal<Option<ElectionReplay>>) -> impl IntoView {
+    view! {
+        {move || replay.with(|r| r.as_ref().map(|r| {
+            let frame = r.current();
+            view! {
+                <div class="election-ring">
+                    <div class="election-head">
+                        <span class="attack-badge">{format!("term {}", frame.term)}</span>
+                        <span class="election-clock">{format!("+{:.1}ms simulated", frame.at_ms)}</span>
+                    </div>
+                    {ring(frame)}
+                    <p class="election-caption">{frame.caption.clone()}</p>
+                    <div class="election-legend">
+                        <span class="election-key request">"→ RequestVote"</span>
+                        <span class="election-key grant">"→ vote granted"</span>
+                        <span class="election-key deny">"→ refused"</span>
+                    </div>
+                </div>
+            }
+        }))}
+    }
+}
@@ -0,0 +1,109 @@
+// what: simulated plant environment behind the sensor readings - diurnal temperature, humidity that falls as it warms, noise profiles, spikes
+// why: flat random values make every demo look alike and give the anomaly detector nothing realistic to learn from
+// relations: knobs from settings/ EnvironmentSettings, seeded by raft.rs's Rng, held by store.rs (environment_reading),
+//            read by handlers.rs for sensor runs and attack telemetry; anomaly.rs scales its stream noise by the same profile
+
+use guardian_types::SensorReading;
+use crate::settings::{EnvironmentSettings, NoiseProfile};
+use super::raft::Rng;
+
+/// hour of the day the temperature peaks (afternoon)
+pub const PEAK_HOUR: f64 = 15.0;
+/// simulated hours per real minute, so a ten-minute demo sees most of a day
+pub const ENV_HOURS_PER_MINUTE: f64 = 1.0;
+/// relative humidity at the mean temperature (%)
+pub const BASE_HUMIDITY: f64 = 50.0;
+/// humidity lost per °C above the mean (same air mass, warmer, so lower rh)
+pub const HUMIDITY_PER_C: f64 = 2.5;
+/// sea-level pressure and the amplitude of its twice-daily atmospheric tide (hPa)
+pub const BASE_PRESSURE: f64 = 1013.25;
+pub const PRESSURE_TIDE: f64 = 1.2;
+/// temperature jump of a transient spike (°C, either sign)
+pub const SPIKE_C: f64 = 8.0;
+
+/// noise standard deviations as (temperature °C, humidity %, pressure hPa)
+pub fn noise_sigmas(profile: NoiseProfile) -> (f64, f64, f64) {
+    match profile {
+        NoiseProfile::Lab => (0.05, 0.3, 0.1),
+        NoiseProfile::Typical => (0.2, 1.0, 0.3),
+        NoiseProfile::Harsh => (0.6, 3.0, 1.0),
+    }
+}
+
+/// factor on the anomaly stream's noise (typical keeps it as it was tuned)
+pub fn stream_noise_scale(profile: NoiseProfile) -> f64 {
+    match profile {
+        NoiseProfile::Lab => 0.5,
+        NoiseProfile::Typical => 1.0,
+        NoiseProfile::Harsh => 3.0,
+    }
+}
+
+/// simulated hour of the day, `elapsed_ms` after a session that started at `start_hour`
+pub fn env_hour(start_hour: f64, elapsed_ms: f64) -> f64 {
+    (start_hour + elapsed_ms / 60_000.0 * ENV_HOURS_PER_MINUTE).rem_euclid(24.0)
+}
+
+/// what a perfect sensor would read at this hour
+pub fn clean_reading(env: &EnvironmentSettings, hour: f64) -> SensorReading {
+    let day = std::f64::consts::TAU * (hour - PEAK_HOUR) / 24.0;
+    let warming = env.diurnal_swing_c as f64 * day.cos();
+    SensorReading {
+        temperature: env.base_temp_c as f64 + warming,
+        humidity: (BASE_HUMIDITY - HUMIDITY_PER_C * warming).clamp(0.0, 100.0),
+        pressure: BASE_PRESSURE + PRESSURE_TIDE * (2.0 * std::f64::consts::TAU * hour / 24.0).sin(),
+    }
+}
+
+/// one reading as the sensor reports it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnvSample {
+    pub hour: f64,
+    pub reading: SensorReading,
+    /// a transient spike was added to the temperature
+    pub spike: bool,
+}
+
+/// standard normal draw (box-muller)
+fn gaussian(rng: &mut Rng) -> f64 {
+    let u = rng.next_f64().max(f64::MIN_POSITIVE);
+    let v = rng.next_f64();
+    (-2.0 * u.ln()).sqrt() * (std::f64::consts::TAU * v).cos()
+}
+
+/// the clean reading plus profile noise, and a spike with the configured chance
+pub fn sample(env: &EnvironmentSettings, hour: f64, rng: &mut Rng) -> EnvSample {
+    let clean = clean_reading(env, hour);
+    let (t_sigma, h_sigma, p_sigma) = noise_sigmas(env.noise);
+    let spike = rng.next_f64() < env.spike_rate_pct as f64 / 100.0;
+    let jump = if !spike { 0.0 } else if rng.next_f64() < 0.5 { -SPIKE_C } else { SPIKE_C };
+    let reading = SensorReading {
+        temperature: clean.temperature + t_sigma * gaussian(rng) + jump,
+        humidity: (clean.humidity + h_sigma * gaussian(rng)).clamp(0.0, 100.0),
+        pressure: clean.pressure + p_sigma * gaussian(rng),
+    };
+    EnvSample { hour, reading, spike }
+}
+
+/// per-session environment state: where the simulated day started and the noise generator
+pub struct Environment {
+    rng: Rng,
+    start_hour: f64,
+    started_ms: f64,
+}
+
+impl Environment {
+    /// a session starting at `start_hour` of the day at wall time `now_ms`; the seed makes each session's noise different
+    pub fn new(seed: u64, start_hour: f64, now_ms: f64) -> Self {
+        Self { rng: Rng::new(seed), start_hour, started_ms: now_ms }
+    }
+
+    pub fn hour(&self, now_ms: f64) -> f64 {
+        env_hour(self.start_hour, now_ms - self.started_ms)
+    }
+
+    pub fn read(&mut self, env: &EnvironmentSettings, now_ms: f64) -> EnvSample {
+        let hour = self.hour(now_ms);
+        sample(env, hour, &mut self.rng)
+    }
+}
@@ -0,0 +1,153 @@
+// what: message-flood scenario - thousands of frames/s into both gateway pipelines, queue depth, latency and memory per step
+// why: availability is part of safety; unbounded queues turn a flood into seconds of stale telemetry and then an oom,
+//      a rate limiter and bounded stages turn it into refused frames with an explicit error
+// relations: limits with rate_limit.rs, stages from pipeline.rs, per-frame cost from the store.rs sensor timings,
+//            driven by handlers.rs (run_flood_attack), charted by flood_panel.rs
+
+use super::handlers::LEADER_RESTART_MS;
+use super::pipeline::{Accumulator, Backpressure, Pipeline, StageId, StageSignal, STAGES, STAGE_CAPACITY};
+use super::rate_limit::{RateLimit, RateLimited, TokenBucket};
+
+/// simulated time per sample
+pub const FLOOD_STEP_MS: f64 = 50.0;
+/// simulated length of one run
+pub const FLOOD_DURATION_MS: f64 = 6000.0;
+/// the flood runs from FLOOD_START_MS to FLOOD_END_MS
+pub const FLOOD_START_MS: f64 = 1000.0;
+pub const FLOOD_END_MS: f64 = 3000.0;
+/// attacker frames per second during the flood
+pub const FLOOD_RATE_PER_S: f64 = 5000.0;
+/// legitimate polling traffic, always on
+pub const LEGIT_RATE_PER_S: f64 = 100.0;
+/// a reading older than this is useless to the control loop
+pub const POLL_DEADLINE_MS: f64 = 500.0;
+/// per-frame cost until the sensor comparison has measured one (ms)
+pub const DEFAULT_PYTHON_FRAME_MS: f64 = 1.25;
+pub const DEFAULT_WASM_FRAME_MS: f64 = 0.05;
+/// floor so a timer-resolution zero doesn't model an infinitely fast worker
+pub const MIN_FRAME_MS: f64 = 0.01;
+/// heap one queued frame costs the python worker (bytes object, dict, list slot)
+pub const PYTHON_FRAME_BYTES: u64 = 2048;
+/// heap the python worker can grow its queues to before it is killed
+pub const PYTHON_HEAP_BYTES: u64 = 8 * 1024 * 1024;
+
+/// pipeline state at the end of one step
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FloodSample {
+    pub t_ms: f64,
+    pub offered_per_s: f64,
+    pub python_queue: u64,
+    pub python_stages: [u64; STAGES],
+    /// wait plus processing for a frame arriving now
+    pub python_latency_ms: f64,
+    /// heap held by queued frames
+    pub python_memory_bytes: u64,
+    /// the worker ran out of memory in this step
+    pub python_oom: bool,
+    /// the worker is restarting after an oom
+    pub python_down: bool,
+    /// frames dropped by ooms and while restarting, so far
+    pub python_lost: u64,
+    pub wasm_queue: u64,
+    pub wasm_stages: [u64; STAGES],
+    pub wasm_signals: [StageSignal; STAGES],
+    pub wasm_latency_ms: f64,
+    /// frames refused by the limiter so far
+    pub wasm_shed: u64,
+    /// frames the limiter admitted but a full pipeline refused, so far
+    pub wasm_backpressured: u64,
+    /// last limiter refusal in this step, for the log
+    pub refusal: Option<RateLimited>,
+    /// where the congestion started when ingress refused frames in this step
+    pub backpressure: Option<Backpressure>,
+}
+
+/// offered frames per second at `t_ms`
+pub fn offered_rate(t_ms: f64) -> f64 {
+    let flood = if (FLOOD_START_MS..FLOOD_END_MS).contains(&t_ms) { FLOOD_RATE_PER_S } else { 0.0 };
+    LEGIT_RATE_PER_S + flood
+}
+
+/// steps both gateways through one run: python queues everything in unbounded stages,
+/// wasm admits through the token bucket into bounded stages; `slow` degrades one stage in both
+pub fn simulate(limit: RateLimit, python_frame_ms: f64, wasm_frame_ms: f64, slow: Option<StageId>) -> Vec<FloodSample> {
+    let mut python = Pipeline::unbounded(Pipeline::costs(python_frame_ms.max(MIN_FRAME_MS), slow));
+    let mut wasm = Pipeline::bounded(Pipeline::costs(wasm_frame_ms.max(MIN_FRAME_MS), slow), STAGE_CAPACITY);
+    let mut bucket = TokenBucket::new(limit);
+    let mut arrivals = Accumulator::default();
+    let mut python_down_until = 0.0;
+    let mut sample = FloodSample::default();
+    let steps = (FLOOD_DURATION_MS / FLOOD_STEP_MS) as usize;
+    (0..steps)
+        .map(|step| {
+            let start = step as f64 * FLOOD_STEP_MS;
+            let end = start + FLOOD_STEP_MS;
+            let offered = offered_rate(start);
+            let arrived = arrivals.take(offered * FLOOD_STEP_MS / 1000.0);
+
+            sample.refusal = None;
+            let mut admitted = 0;
+            for i in 0..arrived {
+                match bucket.try_take(start + FLOOD_STEP_MS * i as f64 / arrived as f64) {
+                    Ok(()) => admitted += 1,
+                    Err(refusal) => {
+                        sample.wasm_shed += 1;
+                        sample.refusal = Some(refusal);
+                    }
+                }
+            }
+            let wasm_step = wasm.step(admitted, FLOOD_STEP_MS);
+            sample.wasm_backpressured += wasm_step.refused;
+            sample.backpressure = (wasm_step.refused > 0).then(|| wasm.congestion()).flatten();
+
+            sample.python_oom = false;
+            if end <= python_down_until {
+                sample.python_lost += arrived;
+            } else {
+                python.step(arrived, FLOOD_STEP_MS);
+            }
+            // reported before an oom clears the queues, so the chart shows the peak that killed the worker
+            sample.python_memory_bytes = python.queued() * PYTHON_FRAME_BYTES;
+            if sample.python_memory_bytes > PYTHON_HEAP_BYTES {
+                sample.python_lost += python.clear();
+                sample.python_oom = true;
+                python_down_until = end + LEADER_RESTART_MS as f64;
+            }
+            sample.python_down = end <= python_down_until;
+
+            sample.t_ms = end;
+            sample.offered_per_s = offered;
+            sample.python_stages = python.depths();
+            sample.python_queue = python.queued();
+            sample.python_latency_ms = (python_down_until - end).max(0.0) + python.latency_ms();
+            sample.wasm_stages = wasm.depths();
+            sample.wasm_signals = wasm.signals();
+            sample.wasm_queue = wasm.queued();
+            sample.wasm_latency_ms = wasm.latency_ms();
+            sample
+        })
+        .collect()
+}
+
+/// worst latency seen by (python, wasm)
+pub fn peak_latency(samples: &[FloodSample]) -> (f64, f64) {
+    samples.iter().fold((0.0, 0.0), |(p, w), s| (p.max(s.python_latency_ms), w.max(s.wasm_latency_ms)))
+}
+
+/// time at which a pipeline first misses the poll deadline
+pub fn first_breach(samples: &[FloodSample], latency: impl Fn(&FloodSample) -> f64) -> Option<f64> {
+    samples.iter().find(|s| latency(s) > POLL_DEADLINE_MS).map(|s| s.t_ms)
+}
+
+/// scenario progress for the panel
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloodRun {
+    pub limit: RateLimit,
+    pub python_frame_ms: f64,
+    pub wasm_frame_ms: f64,
+    /// stage degraded for this run
+    pub slow: Option<StageId>,
+    pub samples: Vec<FloodSample>,
+    /// samples revealed so far
+    pub shown: usize,
+}
@@ -0,0 +1,160 @@
+// what: message flood scenario - rate limiter settings, slow-stage picker, per-stage queue gauges, queue depth and latency charts, peak numbers
+// why: shows unbounded python queues turning a flood into stale telemetry and an oom while the limited, bounded wasm path sheds and stays fast
+// relations: edits the store.rs rate_limit signal (rate_limit.rs), fires handlers.rs run_flood_attack, charts the flood.rs run
+//            and its pipeline.rs stages; rendered by component.rs
+
+use leptos::*;
+use super::flood::{first_breach, peak_latency, FloodSample, FLOOD_DURATION_MS, FLOOD_END_MS, FLOOD_RATE_PER_S, FLOOD_START_MS, PYTHON_HEAP_BYTES, POLL_DEADLINE_MS};
+use super::pipeline::{StageId, StageSignal, SLOW_FACTOR, STAGES, STAGE_CAPACITY};
+use super::rate_limit::RateLimit;
+use super::store::DemoStore;
+
+/// chart box (svg user units)
+const CHART_W: f64 = 600.0;
+const CHART_H: f64 = 120.0;
+
+/// log scale so a sub-millisecond and a ten-second series share one chart
+fn scale(v: f64) -> f64 {
+    (1.0 + v.max(0.0)).log10()
+}
+
+fn y(v: f64, max: f64) -> f64 {
+    CHART_H - scale(v) / scale(max).max(f64::MIN_POSITIVE) * CHART_H
+}
+
+fn series(samples: &[FloodSample], max: f64, value: impl Fn(&FloodSample) -> f64) -> String {
+    samples
+        .iter()
+        .map(|s| format!("{:.1},{:.1}", s.t_ms / FLOOD_DURATION_MS * CHART_W, y(value(s), max)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// one log-scale chart of a python and a wasm series, with the flood window shaded
+fn chart(title: &'static str, samples: &[FloodSample], max: f64, python: fn(&FloodSample) -> f64, wasm: fn(&FloodSample) -> f64, deadline: Option<f64>) -> View {
+    let (x0, x1) = (FLOOD_START_MS / FLOOD_DURATION_MS * CHART_W, FLOOD_END_MS / FLOOD_DURATION_MS * CHART_W);
+    view! {
+        <div class="flood-chart">
+            <h5>{title}</h5>
+            <svg viewBox=format!("0 0 {} {}", CHART_W, CHART_H) preserveAspectRatio="none">
+                <rect class="flood-window" x=format!("{:.1}", x0) y="0" width=format!("{:.1}", x1 - x0) height=CHART_H.to_string() />
+                {deadline.map(|d| view! {
+                    <line class="flood-deadline" x1="0" x2=CHART_W.to_string() y1=format!("{:.1}", y(d, max)) y2=format!("{:.1}", y(d, max)) />
+                })}
+                <polyline class="flood-python" points=series(samples, max, python) />
+                <polyline class="flood-wasm" points=series(samples, max, wasm) />
+            </svg>
+        </div>
+    }
+    .into_view()
+}
+
+/// one row of stage gauges; bounded stages fill against their capacity, unbounded ones against the largest queue seen
+fn gauges(label: &'static str, depths: [u64; STAGES], signals: Option<[StageSignal; STAGES]>, scale: u64) -> View {
+    view! {
+        <div class="flood-gauges">
+            <span class="world-name">{label}</span>
+            {StageId::ALL.into_iter().map(|stage| {
+                let i = stage as usize;
+                let depth = depths[i];
+                let (full, text) = match signals {
+                    Some(_) => (STAGE_CAPACITY[i], format!("{}/{}", depth, STAGE_CAPACITY[i])),
+                    None => (scale.max(1), depth.to_string()),
+                };
+                let signal = signals.map(|s| s[i]).unwrap_or_default();
+                view! {
+                    <div class=format!("flood-gauge flood-{}", signal.class()) title=format!("{}: {}", stage.label(), signal.class())>
+                        <div class="flood-gauge-fill" style=format!("width: {:.0}%", (depth as f64 / full as f64).min(1.0) * 100.0)></div>
+                        <span>{format!("{} {}", stage.label(), text)}</span>
+                    </div>
+                }
+            }).collect_view()}
+        </div>
+    }
+    .into_view()
+}
+
+#[component]
+pub fn FloodPanel(store: DemoStore) -> impl IntoView {
+    let DemoStore { control, rate_limit, flood, .. } = store;
+    let (slow, set_slow) = create_signal(Option::<StageId>::None);
+    let edit = move |ev: ev::Event, apply: fn(&mut RateLimit, f64)| {
+        if let Ok(v) = event_target_value(&ev).parse::<f64>() {
+            rate_limit.update(|l| apply(l, v.max(0.0)));
+        }
+    };
+
+    let charts = move || flood.get().map(|run| {
+        let all = &run.samples;
+        let shown = &all[..run.shown];
+        // axes from the whole run so the lines don't rescale while they are drawn
+        let max_queue = all.iter().map(|s| s.python_queue.max(s.wasm_queue) as f64).fold(1.0, f64::max);
+        let (python_peak, wasm_peak) = peak_latency(all);
+        let max_latency = python_peak.max(wasm_peak).max(POLL_DEADLINE_MS);
+        let (python_shown, wasm_shown) = peak_latency(shown);
+        let last = shown.last().copied().unwrap_or_default();
+        let python_scale = all.iter().flat_map(|s| s.python_stages).max().unwrap_or(0);
+        let python_peak_bytes = shown.iter().map(|s| s.python_memory_bytes).max().unwrap_or(0);
+        let python_oom = python_peak_bytes > PYTHON_HEAP_BYTES;
+        let breach = |f: fn(&FloodSample) -> f64| match first_breach(shown, f) {
+            Some(t) => format!("deadline missed at t={:.2}s", t / 1000.0),
+            None => "within deadline".to_string(),
+        };
+        view! {
+            {gauges("🐍 Python", last.python_stages, None, python_scale)}
+            {gauges("🦀 WASM", last.wasm_stages, Some(last.wasm_signals), 0)}
+            {chart("Queue depth (frames, log)", shown, max_queue, |s| s.python_queue as f64, |s| s.wasm_queue as f64, None)}
+            {chart("Latency of a new reading (ms, log)", shown, max_latency, |s| s.python_latency_ms, |s| s.wasm_latency_ms, Some(POLL_DEADLINE_MS))}
+            <table class="capability-matrix voting-matrix">
+                <tr><th>"Pipeline"</th><th>"Parse per frame"</th><th>"Peak latency"</th><th>"Deadline"</th><th>"Peak queue memory"</th><th>"Refused / lost"</th></tr>
+                <tr>
+                    <td class="world-name flood-key-python">"🐍 Python (unbounded queues)"</td>
+                    <td>{format!("{:.2}ms", run.python_frame_ms)}</td>
+                    <td>{format!("{:.0}ms", python_shown)}</td>
+                    <td>{breach(|s| s.python_latency_ms)}</td>
+                    <td class:vote-halted=python_oom>{format!("{:.1} MiB", python_peak_bytes as f64 / (1024.0 * 1024.0))}</td>
+                    <td>{format!("{} lost", last.python_lost)}</td>
+                </tr>
+                <tr>
+                    <td class="world-name flood-key-wasm">{format!("🦀 WASM (token bucket {}, bounded stages)", run.limit.label())}</td>
+                    <td>{format!("{:.3}ms", run.wasm_frame_ms)}</td>
+                    <td>{format!("{:.2}ms", wasm_shown)}</td>
+                    <td>{breach(|s| s.wasm_latency_ms)}</td>
+                    <td>{format!("{} frames max", STAGE_CAPACITY.iter().sum::<u64>())}</td>
+                    <td>{format!("{} limited, {} backpressured", last.wasm_shed, last.wasm_backpressured)}</td>
+                </tr>
+            </table>
+            <p class="metrics-note">{format!("t={:.2}s of {:.0}s simulated{} · parse cost measured by the sensor comparison when it has run, modelled otherwise", last.t_ms / 1000.0, FLOOD_DURATION_MS / 1000.0, run.slow.map(|s| format!(" · {} stage {}x slower", s.label().to_lowercase(), SLOW_FACTOR)).unwrap_or_default())}</p>
+        }
+    });
+
+    view! {
+        <div class="demo-section flood-section">
+            <h3>"🌊 Message Flood"<span class="attack-badge">"Rate Limiting · Backpressure"</span></h3>
+            <p class="section-desc">
+                {format!("{:.0} frames/s hit both gateways for {:.0}s. Python queues every frame in every stage; the WASM gateway admits through a token bucket into bounded stages, and a full stage pushes back until ingress refuses frames with an explicit error.", FLOOD_RATE_PER_S, (FLOOD_END_MS - FLOOD_START_MS) / 1000.0)}
+            </p>
+            <div class="voting-controls">
+                <label class="voting-tolerance">
+                    "Rate "
+                    <input type="number" min="0" step="100" prop:value=move || rate_limit.get().rate_per_s
+                        on:change=move |ev| edit(ev, |l, v| l.rate_per_s = v) />
+                    "/s"
+                </label>
+                <label class="voting-tolerance">
+                    "Burst "
+                    <input type="number" min="1" step="50" prop:value=move || rate_limit.get().burst
+                        on:change=move |ev| edit(ev, |l, v| l.burst = v.max(1.0)) />
+                </label>
+                <select class="network-select" on:change=move |ev| set_slow.set(StageId::from_id(&event_target_value(&ev)))>
+                    <option value="">"No slow stage"</option>
+                    {StageId::ALL.into_iter().map(|s| view! { <option value=s.id()>{format!("Slow {} stage", s.label().to_lowercase())}</option> }).collect_view()}
+                </select>
+                <button class="action-btn" disabled=move || control.is_running.get() on:click=move |_| store.run_flood_attack(slow.get_untracked())>
+                    "🌊 Run Flood Attack"
+                </button>
+            </div>
+            {charts}
+        </div>
+    }
+}
@@ -0,0 +1,117 @@
+// what: multi-sensor input scenario - two temperature probes and a pressure transmitter, fused per instance, then voted 2oo3
+// why: real ics inputs are redundant transmitters, not one scalar; a shared probe fault hits all three instances alike,
+//      so only fusion in front of the voter can drop it
+// relations: fusion itself from wasm-modules/sensor-driver fusion.rs, votes through voting.rs, truth from environment.rs,
+//            shown by fusion_panel.rs
+
+use guardian_types::SensorReading;
+use sensor_driver::fusion::{fuse, Fused, Probe, FLAG_DEGRADED, FLAG_RANGE, FLAG_SPREAD, FLAG_STATUS, MAX_SPREAD_C, PRESSURE_RANGE, TEMP_RANGE};
+use super::voting::{VoteOutcome, VoteStrategy};
+
+/// probe names, in fusion order
+pub const PROBES: [&str; 3] = ["T1 (PT100)", "T2 (BME280)", "P (BME280)"];
+/// calibration weights: the pt100 is the better-class probe
+pub const T1_WEIGHT: f64 = 2.0;
+pub const T2_WEIGHT: f64 = 1.0;
+/// fixed offset of T2 against T1 (°C); real probes never read identically
+pub const T2_OFFSET: f64 = 0.12;
+/// each instance's own adc offset on the shared probes (°C), inside the default voting band
+pub const ADC_OFFSETS: [f64; 3] = [0.0, 0.02, -0.01];
+/// what an open pt100 reads after linearisation (°C)
+pub const OPEN_CIRCUIT_C: f64 = -242.0;
+/// how far the stuck probe sits from the truth (°C)
+pub const STUCK_OFFSET: f64 = 5.0;
+/// bit flip in the corrupted instance's fused output (°C)
+pub const CORRUPT_OFFSET: f64 = 3.0;
+/// instance whose fusion output is corrupted
+pub const CORRUPTED: usize = 1;
+
+/// what goes wrong in the scenario
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FusionFault {
+    None,
+    /// T1's wire breaks: it reads far out of range, but its transmitter still reports valid
+    ProbeOpen,
+    /// T2 drops its status bit (conversion timeout)
+    ProbeStatus,
+    /// T2 is stuck at a plausible but wrong value
+    ProbeStuck,
+    /// one instance's fusion output is corrupted; the probes are fine
+    InstanceCorrupt,
+}
+
+impl FusionFault {
+    pub const ALL: [FusionFault; 5] = [FusionFault::None, FusionFault::ProbeOpen, FusionFault::ProbeStatus, FusionFault::ProbeStuck, FusionFault::InstanceCorrupt];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FusionFault::None => "All probes healthy",
+            FusionFault::ProbeOpen => "T1 wire break",
+            FusionFault::ProbeStatus => "T2 status bit clear",
+            FusionFault::ProbeStuck => "T2 stuck +5°C",
+            FusionFault::InstanceCorrupt => "I1 fusion corrupted",
+        }
+    }
+}
+
+/// the three probe samples the instances receive
+pub fn probe_samples(truth: &SensorReading, fault: FusionFault) -> [Probe; 3] {
+    let mut t1 = Probe { value: truth.temperature, weight: T1_WEIGHT, valid: true };
+    let mut t2 = Probe { value: truth.temperature + T2_OFFSET, weight: T2_WEIGHT, valid: true };
+    match fault {
+        FusionFault::ProbeOpen => t1.value = OPEN_CIRCUIT_C,
+        FusionFault::ProbeStatus => t2.valid = false,
+        FusionFault::ProbeStuck => t2.value = truth.temperature + STUCK_OFFSET,
+        FusionFault::None | FusionFault::InstanceCorrupt => {}
+    }
+    [t1, t2, Probe { value: truth.pressure, weight: 1.0, valid: true }]
+}
+
+/// one scenario end to end
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FusionRun {
+    pub fault: FusionFault,
+    pub probes: [Probe; 3],
+    /// each instance's fused temperature
+    pub fused: [Fused; 3],
+    pub pressure: Fused,
+    /// 2oo3 over the fused temperatures
+    pub outcome: VoteOutcome,
+    /// 2oo3 if each instance had passed T1 through as a single scalar
+    pub scalar: VoteOutcome,
+}
+
+/// each instance fuses the shared probes through its own adc, then the voter compares the fused values
+pub fn run(truth: &SensorReading, fault: FusionFault, strategy: VoteStrategy) -> FusionRun {
+    let probes = probe_samples(truth, fault);
+    let fused: [Fused; 3] = std::array::from_fn(|i| {
+        let seen = [probes[0], probes[1]].map(|p| Probe { value: p.value + ADC_OFFSETS[i], ..p });
+        let mut out = fuse(&seen, TEMP_RANGE, MAX_SPREAD_C);
+        if fault == FusionFault::InstanceCorrupt && i == CORRUPTED {
+            out.value = out.value.map(|v| v + CORRUPT_OFFSET);
+        }
+        out
+    });
+    let pressure = fuse(&probes[2..], PRESSURE_RANGE, f64::INFINITY);
+    let outcome = strategy.vote(&fused.map(|f| f.value));
+    let mut scalar_outputs: [Option<f64>; 3] = std::array::from_fn(|i| Some(probes[0].value + ADC_OFFSETS[i]));
+    if fault == FusionFault::InstanceCorrupt {
+        scalar_outputs[CORRUPTED] = scalar_outputs[CORRUPTED].map(|v| v + CORRUPT_OFFSET);
+    }
+    let scalar = strategy.vote(&scalar_outputs);
+    FusionRun { fault, probes, fused, pressure, outcome, scalar }
+}
+
+/// whether a voted value is within `tolerance` of the true temperature (None: nothing was emitted)
+pub fn correct(outcome: &VoteOutcome, truth: &SensorReading, tolerance: f64) -> Option<bool> {
+    outcome.value().map(|v| (v - truth.temperature).abs() <= tolerance)
+}
+
+/// short names of the flags set on a fused value, most severe first
+pub fn flag_labels(fused: &Fused) -> Vec<&'static str> {
+    [(FLAG_SPREAD, "probes disagree"), (FLAG_RANGE, "out of range"), (FLAG_STATUS, "status bad"), (FLAG_DEGRADED, "degraded")]
+        .into_iter()
+        .filter(|(flag, _)| fused.has(*flag))
+        .map(|(_, label)| label)
+        .collect()
+}
@@ -0,0 +1,69 @@
+// what: sensor fusion panel - probe samples, each instance's fused value with its validity flags, and the vote on fused vs single-probe outputs
+// why: shows why ics inputs are redundant transmitters: a broken probe is common-mode for all three instances, and only fusion drops it
+// relations: renders fusion.rs run() for every FusionFault, truth from the store.rs environment reading, voter from the store; rendered by component.rs
+
+use leptos::*;
+use guardian_types::SensorReading;
+use sensor_driver::fusion::MAX_SPREAD_C;
+use super::fusion::{correct, flag_labels, run, FusionFault, PROBES, T1_WEIGHT, T2_WEIGHT};
+use super::store::DemoStore;
+use super::voting::{VoteOutcome, DEFAULT_ABS_TOLERANCE};
+
+/// voted value against the truth
+fn vote_cell(outcome: VoteOutcome, truth: &SensorReading) -> impl IntoView {
+    match (outcome, correct(&outcome, truth, 3.0 * DEFAULT_ABS_TOLERANCE)) {
+        (VoteOutcome::Consensus { value, agreeing }, Some(true)) => view! { <td class="vote-flagged">{format!("✅ {:.2}°C · {}/3", value, agreeing)}</td> },
+        (VoteOutcome::Consensus { value, agreeing }, _) => view! { <td class="vote-masked">{format!("⚠ {:.1}°C · {}/3 agree on a wrong value", value, agreeing)}</td> },
+        (VoteOutcome::Halted { .. }, _) => view! { <td class="vote-halted">"⛔ halted · no output"</td> },
+    }
+}
+
+#[component]
+pub fn FusionPanel(store: DemoStore) -> impl IntoView {
+    let voter = store.voter;
+    let truth = create_rw_signal(store.environment_reading().reading);
+
+    view! {
+        <div class="demo-section fusion-section">
+            <h3>"🧮 Sensor Fusion"<span class="attack-badge">"2 probes + pressure → fuse → 2oo3"</span></h3>
+            <p class="section-desc">
+                {format!("Each instance reads the same two temperature probes through its own ADC and fuses them before voting: a weighted average ({}:{}) of the probes whose status bit is set and whose value is plausible, withheld when the survivors differ by more than {}°C. A broken probe reaches all three instances alike, so voting on a single probe passes it straight through.", T1_WEIGHT, T2_WEIGHT, MAX_SPREAD_C)}
+            </p>
+            <p class="metrics-note">
+                {move || truth.with(|t| format!("Plant now: {:.2}°C · {:.1}%RH · {:.1} hPa · probes {}", t.temperature, t.humidity, t.pressure, PROBES.join(", ")))}
+                " "
+                <button class="action-btn" on:click=move |_| truth.set(store.environment_reading().reading)>"🌡️ Read Again"</button>
+            </p>
+            <table class="capability-matrix voting-matrix fusion-matrix">
+                <tr>
+                    <th>"Scenario"</th>
+                    <th>"T1 / T2 / P sent"</th>
+                    <th>"Fused I0 / I1 / I2"</th>
+                    <th>"Flags"</th>
+                    <th>{move || format!("Vote on fused ({})", voter.get().label())}</th>
+                    <th>"Vote on T1 alone"</th>
+                </tr>
+                {move || {
+                    let strategy = voter.get();
+                    let truth = truth.get();
+                    FusionFault::ALL.into_iter().map(|fault| {
+                        let r = run(&truth, fault, strategy);
+                        let sent = r.probes.iter().map(|p| if p.valid { format!("{:.1}", p.value) } else { "✗".to_string() }).collect::<Vec<_>>().join(" / ");
+                        let fused = r.fused.iter().map(|f| f.value.map_or("—".to_string(), |v| format!("{:.2}", v))).collect::<Vec<_>>().join(" / ");
+                        let flags = flag_labels(&r.fused[0]);
+                        view! {
+                            <tr>
+                                <td class="world-name">{fault.label()}</td>
+                                <td>{sent}</td>
+                                <td>{fused}</td>
+                                <td>{if flags.is_empty() { "-".to_string() } else { flags.join(", ") }}</td>
+                                {vote_cell(r.outcome, &truth)}
+                                {vote_cell(r.scalar, &truth)}
+                            </tr>
+                        }
+                    }).collect_view()
+                }}
+            </table>
+        </div>
+    }
+}
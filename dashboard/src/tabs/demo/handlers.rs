@@ -0,0 +1,1310 @@
+// what: demo attack and sensor handlers as plain methods on the store
+// why: keeps component.rs view-only; any child component can fire an attack with just the store
+// relations: extends store.rs, uses attacks.rs, anomaly.rs, flood.rs, pipeline.rs, quiz.rs, soak.rs, baseline.rs, probes.rs, policy.rs, mock_host.rs, host_trace.rs, library.rs, payloads.rs, component_backend.rs, node_history.rs, election.rs, wasm.rs, influx.rs; scripted waits on the timescale/ virtual clock; called from attack_controls.rs, library_panel.rs, sensor_comparison.rs, component.rs
+
+use leptos::*;
+use tracing::Instrument;
+use crate::diagnostics::caps::js_heap_mib;
+use crate::diagnostics::probe::Expectation;
+use crate::settings::SensorBackend;
+use crate::timescale::sim_timeout;
+use super::baseline::run_js_sensor;
+use super::sensor_code::SENSOR_DRIVER_PY;
+use super::anomaly::{simulate, AnomalyRun, StreamFault, STREAM_SEED, STREAM_TICK_MS};
+use super::environment::stream_noise_scale;
+use super::flood::{simulate as simulate_flood, FloodRun, DEFAULT_PYTHON_FRAME_MS, DEFAULT_WASM_FRAME_MS, FLOOD_END_MS, FLOOD_RATE_PER_S, FLOOD_START_MS, FLOOD_STEP_MS, POLL_DEADLINE_MS};
+use super::pipeline::{StageId, STAGE_CAPACITY};
+use super::quiz::Answer;
+use super::soak::{MemorySeries, SoakRun, SoakSample, SOAK_TICK_MS};
+use super::budget::WORKLOAD_BYTES;
+use super::drift::{drift_outputs, drift_step, DriftRun, DriftStep, DRIFTING, DRIFT_MAX_MIN, DRIFT_PER_MIN, DRIFT_TICK_MS};
+use super::probes::{bounds_probe, grow_probe, length_probe, stack_probe, OVERFLOW_ADDRESS, OVERFLOW_GROW_PAGES, STACK_PROBE_DEPTH};
+use super::attacks::{get_attack_config, AVAILABILITY_ATTACKS, GATEWAY_ENV, LATERAL_TARGETS, LENGTH_OVERFLOW_ADU, SECURITY_ATTACKS};
+use super::influx::{bme280_point, voted_point};
+use super::component_backend::{run_component, BackendRun, COMPONENT_MODULE};
+use super::payloads::ATTACK_CODE_MODULE;
+use super::journal::{EventKind, Runtime};
+use super::library::scenario_id;
+use super::host_trace::{args_summary, CallOutcome, HostCall, ModuleTrace};
+use super::mock_host::{invoke, lookup, MockHost, INTERFACE};
+use super::modbus::write_telemetry;
+use super::policy::{attack_request, CapabilityRequest, WORKER_WORLD};
+use super::prefork::{takeover, PythonStrategy, Takeover, HEARTBEAT_MS};
+use super::raft::failover_traced;
+use super::election::{ElectionReplay, FRAME_MS, HOLD_MS};
+use super::store::DemoStore;
+use super::suspicion::{Offense, INTERMITTENT_RATE, INTERMITTENT_READINGS};
+use super::types::{InstanceState, LogEntry};
+use super::voting::{healthy_count, outputs, VoteOutcome, VoteStrategy, QUORUM, SCENARIO_READING};
+use super::wasm::{module_memory_bytes, now, runPython, set_timeout};
+use guardian_types::{SensorReading, TelemetryPacket};
+use modbus_parser::register_map::{encode_packet, holding_address, register_def};
+
+/// python leader respawn when pyodide load time is unknown
+pub const LEADER_RESTART_MS: u32 = 1500;
+/// restart times never drop below this, whatever the jitter
+pub const MIN_RESTART_MS: u32 = 500;
+/// python restart jitter, ± this many ms around the cold start
+pub const RESTART_JITTER_MS: i32 = 200;
+/// gap between attacks in run-all mode (allows python respawn)
+pub const RUN_ALL_SPACING_MS: u64 = 3500;
+
+/// python restart time: real pyodide cold start (or fallback) plus jitter, floored
+pub fn restart_delay_ms(pyodide_load_ms: f64, fallback_ms: u32, jitter_ms: i32) -> u32 {
+    let base = if pyodide_load_ms > 0.0 { pyodide_load_ms as i32 } else { fallback_ms as i32 };
+    (base + jitter_ms).max(MIN_RESTART_MS as i32) as u32
+}
+
+/// attacks fired by "run all", security first
+pub fn run_all_sequence() -> Vec<&'static str> {
+    SECURITY_ATTACKS.iter().chain(AVAILABILITY_ATTACKS.iter()).copied().collect()
+}
+
+/// the built-in sequence followed by the user library's entries ("user:<id>")
+pub fn run_all_with(library_ids: Vec<String>) -> Vec<String> {
+    run_all_sequence().into_iter().map(String::from).chain(library_ids).collect()
+}
+
+/// random jitter: -RESTART_JITTER_MS to +RESTART_JITTER_MS
+fn random_jitter_ms() -> i32 {
+    ((js_sys::Math::random() * 2.0 - 1.0) * RESTART_JITTER_MS as f64) as i32
+}
+
+impl DemoStore {
+    /// python failover under the pool strategy: with a pre-forked standby `next` takes over after heartbeat + re-sync
+    /// (scheduled here); the caller restores `failed` at `pool_restored_ms` and books the downtime for a cold start
+    fn python_takeover(&self, attack: &str, failed: u8, next: u8, restart_ms: u32) -> Takeover {
+        let store = *self;
+        let DemoStore { perf, pool, stats, python_log, .. } = store;
+        let standbys = pool.python_workers.with_untracked(|w| w.iter().enumerate().filter(|(i, up)| **up && *i != failed as usize).count());
+        let takeover = takeover(pool.strategy.get_untracked(), standbys, restart_ms, perf.python_exec_ms.get_untracked());
+        if takeover.used == PythonStrategy::PreForked {
+            let gap = takeover.output_gap_ms;
+            let attack = attack.to_string();
+            sim_timeout(move || {
+                pool.python_active_worker.set(next);
+                pool.python_restarting.set(false);
+                stats.python_downtime_ms.update(|d| *d += gap as u64);
+                store.record_timed(EventKind::Recovery, Runtime::Python, &attack, format!("W{} took over from a pre-forked standby in {}ms", next, gap), gap as f64);
+                python_log.append(|logs| logs.push(LogEntry {
+                    level: "success".into(),
+                    message: format!("[POOL] W{} took over in {}ms ({}ms heartbeat + state re-sync) - W{} re-forking behind it", next, gap, HEARTBEAT_MS, failed),
+                }));
+            }, std::time::Duration::from_millis(gap as u64));
+        }
+        takeover
+    }
+
+    /// fires the selected attack through the matching handler; a library entry runs as its base attack
+    pub fn dispatch(&self) {
+        self.undo.set(None);
+        let selected = self.control.selected_attack.get_untracked();
+        let scenario = match scenario_id(&selected) {
+            Some(id) => match self.library.with_untracked(|l| l.get(id).cloned()) {
+                Some(scenario) => Some(scenario),
+                // deleted while run-all was queued
+                None => return,
+            },
+            None => None,
+        };
+        if let Some(scenario) = &scenario {
+            self.control.selected_attack.set(scenario.base.clone());
+        }
+        self.control.scenario.set(scenario);
+        if AVAILABILITY_ATTACKS.contains(&self.control.selected_attack.get_untracked().as_str()) {
+            self.trigger_leader_crash();
+        } else {
+            self.trigger_attack();
+        }
+    }
+
+    /// selects an attack and fires it
+    pub fn launch(&self, attack: &str) {
+        self.control.selected_attack.set(attack.to_string());
+        self.dispatch();
+    }
+
+    /// an attack button press: in quiz mode the prediction form opens first, otherwise the attack runs
+    pub fn press(&self, attack: &str) {
+        if self.quiz.with_untracked(|q| q.enabled) {
+            self.quiz.update(|q| q.ask(attack));
+        } else {
+            self.launch(attack);
+        }
+    }
+
+    /// locks in the viewer's guess and runs the attack it was for
+    pub fn answer_quiz(&self, guess: [Answer; 2]) {
+        if let Some(attack) = self.quiz.try_update(|q| q.lock(guess, js_sys::Date::now())).flatten() {
+            self.launch(&attack);
+        }
+    }
+
+    /// scores the pending guess once its run has finished
+    pub fn settle_quiz(&self) {
+        let DemoStore { quiz, journal, wasm_log, .. } = *self;
+        let scored = journal.with_untracked(|events| quiz.try_update(|q| q.score(events).cloned())).flatten();
+        if let Some(result) = scored {
+            tracing::info!(target: "attack", attack = %result.attack, right = result.right(), "quiz answer scored");
+            wasm_log.append(|logs| logs.push(LogEntry { level: "info".into(), message: format!("[QUIZ] {}: {}/2 predicted correctly", result.name, result.right()) }));
+        }
+    }
+
+    /// runs REAL python via pyodide and the wasm sensor path side by side
+    pub fn run_sensor_comparison(&self) {
+        let store = *self;
+        let DemoStore { perf, pool, python_log, wasm_log, .. } = store;
+        if perf.sensor_running.get() { return; }
+        perf.sensor_running.set(true);
+        
+        // Append to logs (don't clear - only Reset button clears)
+        python_log.append(|logs| {
+            logs.push(LogEntry { level: "info".into(), message: "$ python sensor_driver.py".into() });
+            logs.push(LogEntry { level: "info".into(), message: "[...] Loading Pyodide runtime...".into() });
+        });
+        // The simulated plant's reading at this point of its day (environment.rs)
+        let sample = store.environment_reading();
+        let wasm_result = sample.reading;
+        wasm_log.append(|logs| {
+            logs.push(LogEntry { level: "info".into(), message: "$ wasmtime sensor_driver.wasm".into() });
+            let clock = format!("{:02}:{:02}", sample.hour as u32, (sample.hour.fract() * 60.0) as u32);
+            if sample.spike {
+                logs.push(LogEntry { level: "warn".into(), message: format!("[ENV] {} simulated plant time - transient spike on the temperature line", clock) });
+            } else {
+                logs.push(LogEntry { level: "info".into(), message: format!("[ENV] {} simulated plant time", clock) });
+            }
+        });
+        match store.sensor_backend() {
+            // Run WASM sensor (near-instant) with simulated varying values
+            SensorBackend::Bindgen => {
+                let wasm_start = now();
+                let halted = store.apply_wasm_reading(wasm_result);
+                let wasm_elapsed = now() - wasm_start;
+                store.log_wasm_reading(BackendRun { backend: SensorBackend::Bindgen, exec_ms: wasm_elapsed, load_ms: None, reading: wasm_result }, halted);
+            }
+            // The real component: its reading is the driver's own, not the simulated one
+            SensorBackend::Component => {
+                let first = perf.backend_runs.with_untracked(|runs| runs.iter().all(|r| r.backend != SensorBackend::Component));
+                spawn_local(async move {
+                    match run_component(first).await {
+                        Ok(run) => {
+                            let halted = store.apply_wasm_reading(run.reading);
+                            store.log_wasm_reading(run, halted);
+                        }
+                        Err(e) => wasm_log.append(|logs| logs.push(LogEntry { level: "error".into(), message: format!("[ERR] Component backend: {}", e) })),
+                    }
+                });
+            }
+        }
+        
+        // Run the same driver in plain JS (REAL execution, main thread)
+        if store.js_baseline() {
+            store.run_js_baseline(wasm_result);
+        }
+        
+        // Run Python sensor via Pyodide (REAL execution)
+        spawn_local(async move {
+            let py_start = now();
+            match runPython(SENSOR_DRIVER_PY).await {
+                Ok(_) => {
+                    let py_elapsed = now() - py_start;
+                    perf.python_exec_ms.set(py_elapsed);
+                    perf.python_exec_samples.update(|s| s.push(py_elapsed));
+                    let worker = pool.python_active_worker.get_untracked() as usize;
+                    store.nodes.update(|n| n.python[worker].produce(wasm_result.temperature));
+                    
+                    // Use same sensor values as WASM (they're reading the "same" sensor)
+                    python_log.append(|logs| {
+                        logs.push(LogEntry { level: "success".into(), message: format!("[OK] Pyodide executed in {:.2}ms", py_elapsed) });
+                        logs.push(LogEntry { level: "success".into(), message: "[OK] BME280 driver initialized".into() });
+                        logs.push(LogEntry { level: "info".into(), message: format!("Temperature: {:.1}°C", wasm_result.temperature) });
+                        logs.push(LogEntry { level: "info".into(), message: format!("Humidity: {:.1}%", wasm_result.humidity) });
+                        logs.push(LogEntry { level: "info".into(), message: format!("Pressure: {:.2} hPa", wasm_result.pressure) });
+                    });
+                }
+                Err(e) => {
+                    perf.python_exec_ms.set(-1.0);
+                    python_log.append(|logs| {
+                        logs.push(LogEntry { level: "error".into(), message: format!("[ERR] Pyodide error: {:?}", e) });
+                    });
+                }
+            }
+            
+            perf.sensor_running.set(false);
+        });
+    }
+
+    /// security attack: python crashes for real, wasm traps and 2oo3 voting masks it
+    pub fn trigger_attack(&self) {
+        let store = *self;
+        let DemoStore { perf, cluster, pool, stats, control, python_log, wasm_log, influx, policy, .. } = store;
+        // Allow if control.running_all mode (called from run_all_attacks), otherwise block if already running
+        if control.is_running.get() && !control.running_all.get() { return; }
+        
+        let attack = control.selected_attack.get();
+        // a library payload replaces the built-in one; the capability request and trap stay the base attack's
+        let scenario = control.scenario.get_untracked();
+        let Some(attack_code) = scenario.as_ref().map(|s| s.code.clone()).filter(|code| !code.trim().is_empty()).or_else(|| store.attack_code(&attack)) else {
+            python_log.append(|logs| {
+                logs.push(LogEntry { level: "error".into(), message: format!("[ERR] {} not loaded yet, no payload for {}", ATTACK_CODE_MODULE, attack) });
+            });
+            return;
+        };
+        if !control.running_all.get() { control.is_running.set(true); }
+        
+        let span = tracing::info_span!(target: "attack", "attack", name = %attack);
+        let _enter = span.enter();
+        let config = get_attack_config(&attack);
+        let name = store.run_name(&attack);
+        let current_active = pool.python_active_worker.get();
+        
+        // initialize if first run
+        if python_log.is_empty() {
+            python_log.replace(vec![
+                LogEntry { level: "info".into(), message: "$ python gateway.py --workers 3".into() },
+                LogEntry { level: "success".into(), message: "[OK] Worker pool: W0 active, W1/W2 standby".into() },
+            ]);
+            stats.python_processed.set(5);
+        }
+        
+        if wasm_log.is_empty() {
+            wasm_log.replace(vec![
+                LogEntry { level: "info".into(), message: "$ wasmtime gateway.wasm --mode 2oo3".into() },
+                LogEntry { level: "success".into(), message: "[OK] 2oo3 TMR: I0, I1, I2 initialized".into() },
+                LogEntry { level: "info".into(), message: format!("[METRICS] Instantiate: {} (real)", perf.wasm_instantiate_ms.get().display(3)) },
+            ]);
+            stats.wasm_processed.set(5);
+        }
+        
+        // show incoming attack
+        store.record(EventKind::AttackStarted, None, &attack, name.clone());
+        let expectation = store.diagnostics.with(|d| d.probe().map(|p| p.expectation(&attack)));
+        python_log.append(|logs| {
+            logs.push(LogEntry { level: "warn".into(), message: format!("[ATTACK] Incoming: {}", name) });
+            if let Some(Expectation::Warn(note)) = &expectation {
+                logs.push(LogEntry { level: "warn".into(), message: format!("[PROBE] ⚠ {} (see Diagnostics)", note) });
+            }
+            logs.push(LogEntry { level: "info".into(), message: "[EXEC] Running real Python via Pyodide...".into() });
+        });
+        wasm_log.append(|logs| {
+            logs.push(LogEntry { level: "warn".into(), message: format!("[ATTACK] Incoming: {}", name) });
+        });
+        store.note_js_attack(&name);
+        
+        // Use REAL Pyodide load time as restart time (represents actual Python cold-start)
+        let restart_ms = restart_delay_ms(perf.pyodide_load_ms.get(), config.restart_ms, random_jitter_ms());
+        let wasm_trap = config.wasm_trap.to_string();
+        // the declared attack-surface func this payload calls, if it reaches the wit boundary at all
+        let surface = lookup(config.wit_func);
+        // Outcome on the WASM side depends on what the active policy grants
+        let decision = attack_request(&attack).map(|req| (req, policy.get().evaluate(WORKER_WORLD, &req)));
+        let attack_py = attack.clone();
+        let attack_wasm = attack.clone();
+        tracing::debug!(target: "attack", restart_ms, decision = ?decision.as_ref().map(|(req, verdict)| (req.label(), verdict.is_ok())), "dispatched");
+        
+        // Run REAL Python attack via Pyodide
+        spawn_local(async move {
+            let py_start = now();
+            
+            match runPython(&attack_code).await {
+                Ok(result) => {
+                    let py_elapsed = now() - py_start;
+                    
+                    // Parse the result - now returns pipe-delimited string
+                    // Format: STATUS|ERROR_TYPE|MESSAGE|TIMEms
+                    let result_str = if let Some(s) = result.as_string() {
+                        s
+                    } else {
+                        // Try to extract from JsValue object
+                        format!("{:?}", result)
+                    };
+                    
+                    // Parse pipe-delimited format for clean display
+                    let parts: Vec<&str> = result_str.split('|').collect();
+                    let (status, error_type, message) = if parts.len() >= 3 {
+                        (parts[0], parts[1], parts[2])
+                    } else {
+                        // Fallback for unexpected format
+                        ("CRASHED", "Exception", &result_str as &str)
+                    };
+                    store.record(EventKind::Crash, Some(Runtime::Python), &attack_py, format!("W{} {} {}: {}", current_active, status, error_type, message));
+                    
+                    python_log.append(|logs| {
+                        logs.push(LogEntry { 
+                            level: "error".into(), 
+                            message: format!("[{}] {}: {}", status, error_type, message)
+                        });
+                        if attack_py == "lateralMovement" {
+                            logs.push(LogEntry { level: "info".into(), message: "[NOTE] Any block above is the browser sandbox (CORS / Private Network Access / mixed content), not Python - a native gateway would connect".into() });
+                        }
+                        logs.push(LogEntry { 
+                            level: "error".into(), 
+                            message: format!("💥 W{} CRASHED after {:.1}ms - real Python exception!", current_active, py_elapsed)
+                        });
+                        // Simplified crash response - no confusing voting language
+                        let next_worker = (current_active + 1) % 3;
+                        logs.push(LogEntry { 
+                            level: "warn".into(), 
+                            message: format!("[POOL] Failing over to W{} (standby → active)", next_worker)
+                        });
+                    });
+                }
+                Err(e) => {
+                    // Pyodide threw an actual uncaught exception
+                    let err_str = format!("{:?}", e);
+                    store.record(EventKind::Crash, Some(Runtime::Python), &attack_py, format!("W{} uncaught exception", current_active));
+                    python_log.append(|logs| {
+                        logs.push(LogEntry { 
+                            level: "error".into(), 
+                            message: format!("[FATAL] Uncaught: {}", err_str.chars().take(80).collect::<String>())
+                        });
+                        logs.push(LogEntry { 
+                            level: "error".into(), 
+                            message: format!("💥 W{} CRASHED - process terminated!", current_active)
+                        });
+                        // Simplified crash response - no confusing voting language
+                        let next_worker = (current_active + 1) % 3;
+                        logs.push(LogEntry { 
+                            level: "warn".into(), 
+                            message: format!("[POOL] Failing over to W{} (standby → active)", next_worker)
+                        });
+                    });
+                }
+            }
+            
+            // Worker failover
+            let next_active = (current_active + 1) % 3;
+            let takeover = store.python_takeover(&attack_py, current_active, next_active, restart_ms);
+            let mut workers = [true, true, true];
+            workers[current_active as usize] = false;
+            pool.python_workers.set(workers);
+            pool.python_active_worker.set(next_active);
+            pool.python_restarting.set(true);
+            stats.python_crashed.update(|n| *n += 1);
+            store.worker_crashed(current_active as usize);
+            
+            // Restart simulation
+            let restart_ms_copy = restart_ms;
+            let respawn_span = tracing::Span::current();
+            sim_timeout(move || {
+                let _enter = respawn_span.enter();
+                pool.python_workers.set([true, true, true]);
+                pool.python_restarting.set(false);
+                store.worker_respawned(current_active as usize, restart_ms_copy as f64);
+                if takeover.used == PythonStrategy::ColdStart {
+                    stats.python_downtime_ms.update(|d| *d += restart_ms_copy as u64);
+                    store.record_timed(EventKind::Recovery, Runtime::Python, &attack_py, format!("W{} respawned in {}ms", current_active, restart_ms_copy), restart_ms_copy as f64);
+                } else {
+                    store.record(EventKind::Recovery, Some(Runtime::Python), &attack_py, format!("W{} re-forked in {}ms, standby restored", current_active, restart_ms_copy));
+                }
+                python_log.append(|logs| {
+                    logs.push(LogEntry { 
+                        level: "success".into(), 
+                        message: format!("[OK] W{} {} ({}ms) - pool restored", current_active, if takeover.used == PythonStrategy::ColdStart { "respawned" } else { "re-forked" }, restart_ms_copy)
+                    });
+                    logs.push(LogEntry { 
+                        level: "info".into(), 
+                        message: "[VOTE] 3/3 workers ready - voting now possible".into()
+                    });
+                });
+                // Only reset control.is_running if not in control.running_all mode
+                if !control.running_all.get() { control.is_running.set(false); }
+            }, std::time::Duration::from_millis(takeover.pool_restored_ms as u64));
+        }.instrument(tracing::info_span!(target: "attack", "python")));
+        
+        // ================================================================
+        // wasm: 2oo3 voting catches the fault instantly (capability demo)
+        // ================================================================
+        let wasm_span = tracing::info_span!(target: "attack", "wasm");
+        sim_timeout(move || {
+            let _enter = wasm_span.enter();
+            // No quorum: the voter is in its safe state and the payload's output is never used
+            if store.note_halted(&attack_wasm) {
+                return;
+            }
+            // Policy granted the capability: the import is linked, so nothing traps
+            if let Some((request, Ok(()))) = &decision {
+                store.record(EventKind::PolicyBreach, Some(Runtime::Wasm), &attack_wasm, format!("{} granted {}", WORKER_WORLD, request.label()));
+                wasm_log.append(|logs| {
+                    logs.push(LogEntry { level: "warn".into(), message: format!("[POLICY] {} granted {} → {}.{} linked", WORKER_WORLD, request.label(), INTERFACE, surface.map_or("?", |f| f.name)) });
+                    logs.push(LogEntry { level: "error".into(), message: "[BREACH] I0, I1, I2 executed the attack - no trap raised".into() });
+                    logs.push(LogEntry { level: "error".into(), message: "[VOTE] 3/3 outputs agree - voting cannot catch a granted capability".into() });
+                });
+                return;
+            }
+            
+            let faulty_idx = (js_sys::Math::random() * 3.0) as u8;
+            cluster.faulty_instance.set(Some(faulty_idx));
+            
+            store.set_instance(faulty_idx as usize, InstanceState::Faulty);
+            
+            let healthy: Vec<u8> = (0..3).filter(|&i| i != faulty_idx).collect();
+            
+            // The healthy instances all read the simulated plant's current temperature
+            let sensor_val = store.environment_reading().reading.temperature;
+            let strategy = store.voter.get_untracked();
+            let voted = cluster.instance_states.with(|s| outputs(s, sensor_val));
+            let outcome = strategy.vote(&voted);
+            store.nodes.update(|n| {
+                n.wasm[faulty_idx as usize].trap();
+                n.record_vote(strategy, &voted);
+            });
+            
+            // Encode the voted value into the holding registers the S7-1200 polls
+            let packet = TelemetryPacket { timestamp: js_sys::Date::now() as u64, value: sensor_val, status: 0 };
+            let regs = encode_packet(&packet);
+            let value_def = register_def("value").unwrap();
+            influx.update(|w| w.enqueue(&voted_point(packet.timestamp as f64, sensor_val, 2, "consensus")));
+            // only a voted value reaches the replicated log
+            let committed = outcome.value().and_then(|value| cluster.log.try_update(|log| log.append(value)).flatten());
+            let register_grant = policy.get().evaluate(WORKER_WORLD, &CapabilityRequest::Register(value_def.offset));
+            // a granted write is a real fc16 exchange with the simulated plc
+            let plc_ack = register_grant.clone().and_then(|()| {
+                let (exchange, ack) = store.modbus.try_update(|s| write_telemetry(s, &packet)).ok_or_else(|| "slave unavailable".to_string())?;
+                store.capture_exchange(&exchange);
+                ack
+            });
+            
+            // Buffer overflow: ask a capped linear memory for 1 MiB, then read past its end, for real
+            let overflow = attack_wasm == "bufferOverflow";
+            let grow = overflow.then(|| grow_probe(faulty_idx, OVERFLOW_GROW_PAGES));
+            let bounds = overflow.then(|| bounds_probe(faulty_idx, OVERFLOW_ADDRESS));
+            // Stack exhaustion: recurse without a base case until the engine's stack limit traps
+            let stack = (attack_wasm == "stackExhaustion").then(|| stack_probe(faulty_idx, STACK_PROBE_DEPTH));
+            // Length overflow: the crafted mbap length goes through this repo's rust parser
+            let length = (attack_wasm == "lengthOverflow").then(|| length_probe(faulty_idx, &LENGTH_OVERFLOW_ADU));
+            // Env theft: the component's environment is only what the policy passes in
+            let env_diff = (attack_wasm == "envTheft").then(|| policy.with(|p| p.world(WORKER_WORLD).map(|w| w.env_diff(&GATEWAY_ENV)).unwrap_or_default()));
+            // Lateral movement: every internal host is a socket request the policy has to grant
+            let lateral = (attack_wasm == "lateralMovement").then(|| policy.with(|p| {
+                LATERAL_TARGETS.map(|(kind, addr)| (kind, addr, p.evaluate(WORKER_WORLD, &CapabilityRequest::Socket(addr))))
+            }));
+            
+            // only capability attacks reach the wit boundary; a stack trap is the engine's own limit
+            let denied = decision.as_ref().and(surface).map(|func| {
+                // the generated mock host answers exactly as the contract declares
+                let mut host = MockHost::default();
+                let start = now();
+                invoke(&mut host, func.name);
+                let elapsed = now() - start;
+                let mut trace = ModuleTrace::new(format!("{} payload (I{})", attack_wasm, faulty_idx), vec![format!("{}.{}", INTERFACE, func.name)]);
+                for call in &host.calls {
+                    trace.push(HostCall {
+                        import: format!("{}.{}", INTERFACE, call.func),
+                        args: args_summary(&call.args),
+                        result: call.denial.clone(),
+                        duration_ms: elapsed / host.calls.len() as f64,
+                        outcome: CallOutcome::Denied,
+                    });
+                }
+                store.trace_module(Runtime::Wasm, &attack_wasm, trace);
+                (func, host)
+            });
+            
+            // WIT blocks the attack - instance returns TRAP as output, voting handles it
+            // No leader election needed - the instance isn't dead, just this call was blocked
+            wasm_log.append(|logs| {
+                match &grow {
+                    Some(Ok(outcome)) => logs.extend(outcome.lines()),
+                    Some(Err(e)) => logs.push(LogEntry { level: "warn".into(), message: format!("[MEM] grow probe unavailable: {}", e) }),
+                    None => {}
+                }
+                match (&bounds, &stack, &length) {
+                    (Some(Ok(outcome)), _, _) => logs.push(outcome.trap_line()),
+                    (_, Some(Ok(outcome)), _) => logs.extend(outcome.lines()),
+                    (_, _, Some(outcome)) => logs.extend(outcome.lines()),
+                    _ => logs.push(LogEntry { level: "warn".into(), message: format!("[TRAP] I{}: {}", faulty_idx, wasm_trap) }),
+                }
+                // the environment diff: python sees every variable, the component only what was passed
+                if let Some(diff) = &env_diff {
+                    for (key, passed) in diff {
+                        logs.push(LogEntry {
+                            level: if *passed { "info" } else { "success" }.into(),
+                            message: format!("[ENV] {:<22} python: visible | wasm: {}", key, if *passed { "passed" } else { "absent" }),
+                        });
+                    }
+                }
+                if let Some(targets) = &lateral {
+                    for (kind, addr, verdict) in targets {
+                        logs.push(match verdict {
+                            Ok(()) => LogEntry { level: "error".into(), message: format!("[NET] {} {} → granted by policy", kind, addr) },
+                            Err(reason) => LogEntry { level: "success".into(), message: format!("[NET] {} {} → denied: {} (wasmtime)", kind, addr, reason) },
+                        });
+                    }
+                    logs.push(LogEntry { level: "info".into(), message: "[NOTE] wasmtime: no wasi:sockets grant → connect never leaves the component (per component, not per page)".into() });
+                }
+                if let Some((func, host)) = &denied {
+                    logs.push(LogEntry { level: "info".into(), message: format!("[WIT] {}.{} - not imported by {}", INTERFACE, func.declaration(), WORKER_WORLD) });
+                    for call in &host.calls {
+                        logs.push(LogEntry { level: "info".into(), message: format!("[WIT] {}({}) → {}", call.func, call.args.join(", "), call.denial) });
+                    }
+                }
+                if let Some((_, Err(reason))) = &decision {
+                    logs.push(LogEntry { level: "info".into(), message: format!("[POLICY] {}: {}", WORKER_WORLD, reason) });
+                }
+                // Show actual output comparison
+                logs.push(LogEntry { level: "info".into(), message: format!("[OUT] I{}: TRAP | I{}: {:.1}°C | I{}: {:.1}°C", faulty_idx, healthy[0], sensor_val, healthy[1], sensor_val) });
+                if let VoteOutcome::Consensus { value, agreeing } = outcome {
+                    logs.push(LogEntry { level: "success".into(), message: format!("[VOTE] {}/3 outputs agree ({:.1}°C, {}) - using majority value", agreeing, value, strategy.label()) });
+                }
+                if let Some(index) = committed {
+                    logs.push(LogEntry { level: "info".into(), message: format!("[RAFT] Voted value committed at log index {}", index) });
+                }
+                match (&register_grant, &plc_ack) {
+                    (Ok(()), Ok(count)) => logs.push(LogEntry { level: "info".into(), message: format!("[MODBUS] HR{} = {} ({:.1}{} ×{}) → S7-1200 (FC16 ack, {} registers)", holding_address(value_def.offset), regs[value_def.offset as usize], sensor_val, value_def.unit, value_def.scale, count) }),
+                    (Ok(()), Err(reason)) => logs.push(LogEntry { level: "warn".into(), message: format!("[MODBUS] FC16 to S7-1200 failed - {}", reason) }),
+                    (Err(reason), _) => logs.push(LogEntry { level: "warn".into(), message: format!("[MODBUS] HR{} write skipped - {}", holding_address(value_def.offset), reason) }),
+                }
+                logs.push(LogEntry { level: "success".into(), message: "[OK] Zero downtime - continues with valid output".into() });
+            });
+            
+            stats.wasm_rejected.update(|n| *n += 1);
+            let trap_text = bounds.as_ref().and_then(|b| b.as_ref().ok()).and_then(|b| b.error.clone())
+                .or_else(|| stack.as_ref().and_then(|s| s.as_ref().ok()).and_then(|s| s.error.as_ref().map(|e| format!("{} after {:.2}ms", e, s.detect_ms))))
+                .or_else(|| length.as_ref().and_then(|l| l.result.as_ref().err()).map(|e| e.reason()))
+                .unwrap_or_else(|| wasm_trap.clone());
+            store.record(EventKind::Trap, Some(Runtime::Wasm), &attack_wasm, format!("I{}: {}", faulty_idx, trap_text));
+            store.suspect(faulty_idx as usize, Offense::Disagreement);
+            
+            // replace the faulty instance per the supervisor strategy (real async measurement)
+            spawn_local(async move {
+                let rebuild = store.recover_instance(faulty_idx as usize).await;
+                store.record_timed(EventKind::Recovery, Runtime::Wasm, &attack_wasm, format!("I{} {} in {}", faulty_idx, rebuild.used.verb(), rebuild.text), rebuild.ms);
+                
+                store.rebuilt(faulty_idx as usize, rebuild.ms);
+                cluster.faulty_instance.set(None);
+                
+                wasm_log.append(|logs| {
+                    logs.push(LogEntry { 
+                        level: "success".into(), 
+                        message: format!("[OK] I{} {} in {} (real) - pool healthy", faulty_idx, rebuild.used.verb(), rebuild.text)
+                    });
+                });
+            }.instrument(tracing::info_span!(target: "attack", "rebuild", instance = faulty_idx)));
+        }, std::time::Duration::from_millis(100));
+    }
+
+    /// availability attack: leader goes down, compare election recovery
+    pub fn trigger_leader_crash(&self) {
+        let store = *self;
+        let DemoStore { perf, cluster, pool, stats, control, python_log, wasm_log, .. } = store;
+        // Allow if control.running_all mode (called from run_all_attacks), otherwise block if already running
+        if control.is_running.get() && !control.running_all.get() { return; }
+        if !control.running_all.get() { control.is_running.set(true); }
+        
+        let attack = control.selected_attack.get();
+        let span = tracing::info_span!(target: "attack", "attack", name = %attack);
+        let _enter = span.enter();
+        let is_timeout = attack == "heartbeatTimeout";
+        let current_leader_py = pool.python_active_worker.get();
+        let name = store.run_name(&attack);
+        store.record(EventKind::AttackStarted, None, &attack, name.clone());
+        store.record(EventKind::Crash, Some(Runtime::Python), &attack, format!("Leader W{} {}", current_leader_py, if is_timeout { "unresponsive" } else { "crashed" }));
+        
+        store.note_js_attack(&name);
+        
+        // ================================================================
+        // Python: Leader crash requires cold-start respawn (~1.5s)
+        // ================================================================
+        python_log.append(|logs| {
+            logs.push(LogEntry { 
+                level: "error".into(), 
+                message: format!("[RAFT] Leader W{} {}!", 
+                    current_leader_py,
+                    if is_timeout { "unresponsive" } else { "crashed" })
+            });
+            logs.push(LogEntry { 
+                level: "warn".into(), 
+                message: "[RAFT] Starting election...".into() 
+            });
+            logs.push(LogEntry { 
+                level: "error".into(), 
+                message: "[RAFT] Election BLOCKED — need leader respawn first".into() 
+            });
+        });
+        
+        // Python takes real Pyodide load time to respawn + ±200ms jitter (a pre-forked standby takes over sooner)
+        let restart_ms = restart_delay_ms(perf.pyodide_load_ms.get(), LEADER_RESTART_MS, random_jitter_ms());
+        let next_leader_py = (current_leader_py + 1) % 3;
+        let attack_py = attack.clone();
+        let takeover = store.python_takeover(&attack_py, current_leader_py, next_leader_py, restart_ms);
+        
+        // Mark current leader as dead
+        pool.python_workers.update(|w| w[current_leader_py as usize] = false);
+        pool.python_restarting.set(true);
+        store.worker_crashed(current_leader_py as usize);
+        if takeover.used == PythonStrategy::ColdStart {
+            stats.python_downtime_ms.update(|d| *d += restart_ms as u64);
+        }
+        stats.python_crashed.update(|n| *n += 1);
+        
+        let python_span = tracing::info_span!(target: "attack", "python");
+        sim_timeout(move || {
+            let _enter = python_span.enter();
+            if takeover.used == PythonStrategy::ColdStart {
+                store.record_timed(EventKind::Recovery, Runtime::Python, &attack_py, format!("W{} respawned in {}ms, W{} leader", current_leader_py, restart_ms, next_leader_py), restart_ms as f64);
+            } else {
+                store.record(EventKind::Recovery, Some(Runtime::Python), &attack_py, format!("W{} re-forked in {}ms, standby restored", current_leader_py, restart_ms));
+            }
+            pool.python_workers.set([true, true, true]);
+            pool.python_active_worker.set(next_leader_py);
+            pool.python_restarting.set(false);
+            store.worker_respawned(current_leader_py as usize, restart_ms as f64);
+            python_log.append(|logs| {
+                logs.push(LogEntry { 
+                    level: "success".into(), 
+                    message: if takeover.used == PythonStrategy::ColdStart {
+                        format!("[OK] W{} respawned ({}ms) — W{} elected as leader", current_leader_py, restart_ms, next_leader_py)
+                    } else {
+                        format!("[OK] W{} re-forked ({}ms) — back as a standby", current_leader_py, restart_ms)
+                    }
+                });
+            });
+            // Only reset control.is_running if not in control.running_all mode
+            if !control.running_all.get() { control.is_running.set(false); }
+        }, std::time::Duration::from_millis(takeover.pool_restored_ms as u64));
+        
+        // ================================================================
+        // WASM: Raft election among the survivors, then rebuild the old leader
+        // ================================================================
+        // without a quorum there is no leader to crash and no election to win
+        if store.note_halted(&attack) {
+            return;
+        }
+        let old_leader = cluster.leader_id.get();
+        let seed = (js_sys::Math::random() * u32::MAX as f64) as u64;
+        let (trial, trace) = failover_traced(seed, old_leader as usize);
+        // replay the trial's messages on the node ring, starting from the term the cluster is in
+        if trial.is_some() {
+            let base_term = cluster.log.with_untracked(|log| log.term);
+            cluster.election.set(Some(ElectionReplay::new(seed, base_term, old_leader as usize, &trace)));
+            sim_timeout(move || store.election_tick(seed), std::time::Duration::from_millis(FRAME_MS));
+        }
+        // the log decides the final winner: a candidate with a stale log cannot collect votes
+        cluster.log.update(|log| log.crash(old_leader as usize));
+        let hint = trial.map(|t| t.leader).unwrap_or((old_leader as usize + 1) % 3);
+        let terms = trial.map(|t| t.terms).unwrap_or(1);
+        let new_leader = cluster.log.try_update(|log| log.elect(hint, terms)).flatten().unwrap_or(hint) as u8;
+        let bench = perf.election;
+        
+        // Mark old leader as faulty temporarily
+        store.record(EventKind::Crash, Some(Runtime::Wasm), &attack, format!("Leader I{} {}", old_leader, if is_timeout { "missed heartbeat" } else { "crashed" }));
+        store.suspect(old_leader as usize, Offense::Timeout);
+        store.nodes.update(|n| n.wasm[old_leader as usize].trap());
+        store.set_instance(old_leader as usize, InstanceState::Faulty);
+        cluster.faulty_instance.set(Some(old_leader));
+        
+        wasm_log.append(|logs| {
+            logs.push(LogEntry { 
+                level: "error".into(), 
+                message: format!("[RAFT] Leader I{} {}!", old_leader,
+                    if is_timeout { "missed heartbeat" } else { "crashed" })
+            });
+            logs.push(LogEntry { 
+                level: "info".into(), 
+                message: "[RAFT] Election started...".into() 
+            });
+        });
+        
+        // Election latency comes from the simulated Raft run; the rebuild is a real instantiate
+        spawn_local(async move {
+            let election_ms = trial.map(|t| t.latency_ms).unwrap_or(bench.p50_ms);
+            store.record_timed(EventKind::Election, Runtime::Wasm, &attack, format!("I{} elected in {:.1}ms (simulated raft)", new_leader, election_ms), election_ms);
+            
+            cluster.leader_id.set(new_leader);
+            stats.wasm_rejected.update(|n| *n += 1);
+            
+            wasm_log.append(|logs| {
+                logs.push(LogEntry { 
+                    level: "success".into(), 
+                    message: match trial {
+                        Some(t) => format!("[RAFT] I{} elected in term {} after {:.1}ms ({} round{}, simulated)", new_leader, t.terms + 1, t.latency_ms, t.terms, if t.terms == 1 { "" } else { "s" }),
+                        None => format!("[RAFT] I{} elected (trial did not converge, using p50 {:.0}ms)", new_leader, bench.p50_ms),
+                    }
+                });
+                logs.push(LogEntry { level: "info".into(), message: format!("[RAFT] Election benchmark: {}", bench.claim()) });
+                logs.push(LogEntry { 
+                    level: "success".into(), 
+                    message: "[OK] Zero downtime — new leader accepting writes".into()
+                });
+            });
+            
+            // Rebuild old leader as follower (real instantiate); off the failover path, so no duration in the mttr histogram
+            let rebuild = store.recover_instance(old_leader as usize).await;
+            store.record(EventKind::Recovery, Some(Runtime::Wasm), &attack, format!("I{} {} as follower in {}", old_leader, rebuild.used.verb(), rebuild.text));
+            store.rebuilt(old_leader as usize, rebuild.ms);
+            let caught_up = cluster.log.try_update(|log| {
+                log.restart(old_leader as usize);
+                log.nodes[old_leader as usize].last_index()
+            });
+            cluster.faulty_instance.set(None);
+            
+            wasm_log.append(|logs| {
+                logs.push(LogEntry { 
+                    level: "info".into(), 
+                    message: format!("[OK] I{} {} as follower in {} (real) — pool healthy", old_leader, rebuild.used.verb(), rebuild.text)
+                });
+                if let Some(index) = caught_up {
+                    logs.push(LogEntry { level: "info".into(), message: format!("[RAFT] I{} caught up to log index {} from the leader", old_leader, index) });
+                }
+            });
+        }.instrument(tracing::info_span!(target: "attack", "election")));
+    }
+
+    /// next frame of the replayed election; the finished ring is held, then gives way to the node boxes
+    fn election_tick(&self, id: u64) {
+        let store = *self;
+        let election = store.cluster.election;
+        // None once a newer election or a reset replaced this one
+        let advanced = election.try_update(|e| e.as_mut().filter(|r| r.id == id).map(|r| r.advance())).flatten();
+        match advanced {
+            Some(true) => sim_timeout(move || store.election_tick(id), std::time::Duration::from_millis(FRAME_MS)),
+            Some(false) => sim_timeout(move || {
+                if election.with_untracked(|e| e.as_ref().is_some_and(|r| r.id == id)) {
+                    election.set(None);
+                }
+            }, std::time::Duration::from_millis(HOLD_MS)),
+            None => {}
+        }
+    }
+
+    /// degraded mode: kills the leader and one follower at once, leaving one instance that cannot form a majority
+    pub fn trigger_quorum_loss(&self) {
+        let store = *self;
+        let DemoStore { cluster, control, wasm_log, .. } = store;
+        if control.is_running.get() || !store.has_quorum() { return; }
+        
+        let leader = cluster.leader_id.get() as usize;
+        let killed = [leader, (leader + 1) % 3];
+        let survivor = (leader + 2) % 3;
+        for id in killed {
+            store.set_instance(id, InstanceState::Faulty);
+        }
+        // the survivor times out and asks for votes; one of three is not a majority, so no leader
+        let elected = cluster.log.try_update(|log| {
+            killed.iter().for_each(|id| log.crash(*id));
+            log.elect(survivor, 1)
+        }).flatten();
+        let healthy = cluster.instance_states.with(|s| healthy_count(s));
+        store.record(EventKind::AttackStarted, None, "quorumLoss", "Kill Two Nodes".to_string());
+        store.record(EventKind::Crash, Some(Runtime::Wasm), "quorumLoss", format!("I{} (leader) and I{} killed", killed[0], killed[1]));
+        store.record(EventKind::QuorumLost, Some(Runtime::Wasm), "quorumLoss", format!("{}/3 instances healthy, consensus output halted", healthy));
+        
+        wasm_log.append(|logs| {
+            logs.push(LogEntry { level: "error".into(), message: format!("[FAULT] I{} (leader) and I{} killed simultaneously", killed[0], killed[1]) });
+            logs.push(LogEntry { level: "error".into(), message: format!("[RAFT] I{} requests votes: 1/3 granted - no quorum, no leader{}", survivor, if elected.is_some() { " (unexpected)" } else { "" }) });
+            logs.push(LogEntry { level: "error".into(), message: format!("[VOTE] {}/3 outputs - below the {}oo3 majority", healthy, QUORUM) });
+        });
+        store.note_halted("quorumLoss");
+    }
+
+    /// operator restores the killed instances: rebuild, restart from persisted raft state, re-elect
+    pub fn restore_quorum(&self) {
+        let store = *self;
+        let DemoStore { cluster, control, wasm_log, .. } = store;
+        if control.is_running.get() || store.has_quorum() { return; }
+        control.is_running.set(true);
+        
+        spawn_local(async move {
+            let down: Vec<usize> = cluster.instance_states.with(|s| (0..3).filter(|i| s[*i] == InstanceState::Faulty).collect());
+            // one after the other: with a warm spare only the first gets the swap
+            let mut recovered = Vec::new();
+            for id in &down {
+                recovered.push((*id, store.recover_instance(*id).await));
+            }
+            for (id, rebuild) in &recovered {
+                store.rebuilt(*id, rebuild.ms);
+            }
+            let leader = cluster.log.try_update(|log| log.recover().map(|l| (l, log.term))).flatten();
+            let names = recovered.iter().map(|(id, r)| format!("I{} {} in {}", id, r.used.verb(), r.text)).collect::<Vec<_>>().join(", ");
+            store.record(EventKind::Recovery, Some(Runtime::Wasm), "quorumLoss", format!("{}, quorum restored", names));
+            if let Some((id, term)) = leader {
+                cluster.leader_id.set(id as u8);
+                store.record(EventKind::Election, Some(Runtime::Wasm), "quorumLoss", format!("I{} elected in term {}", id, term));
+            }
+            
+            wasm_log.append(|logs| {
+                logs.push(LogEntry { level: "success".into(), message: format!("[OK] {} (real) - 3/3 instances healthy", names) });
+                if let Some((id, term)) = leader {
+                    logs.push(LogEntry { level: "success".into(), message: format!("[RAFT] I{} elected in term {} - followers caught up from persisted logs", id, term) });
+                }
+                logs.push(LogEntry { level: "success".into(), message: "[OK] Quorum restored - consensus output resumes".into() });
+            });
+            control.is_running.set(false);
+        }.instrument(tracing::info_span!(target: "attack", "restore")));
+    }
+
+    /// maintenance scenario: one instance's sensor drifts a simulated minute per tick until the voter outvotes it
+    pub fn run_drift_scenario(&self) {
+        let store = *self;
+        let DemoStore { control, wasm_log, voter, .. } = store;
+        if control.is_running.get() || !store.has_quorum() { return; }
+        control.is_running.set(true);
+        
+        let strategy = voter.get_untracked();
+        store.record(EventKind::AttackStarted, None, "sensorDrift", format!("Sensor drift, voter {}", strategy.label()));
+        wasm_log.append(|logs| {
+            logs.push(LogEntry { level: "warn".into(), message: format!("[DRIFT] I{} sensor drifting +{}°C/min (1 tick = 1 simulated min)", DRIFTING, DRIFT_PER_MIN) });
+            logs.push(LogEntry { level: "info".into(), message: format!("[VOTE] Comparing with {}", strategy.label()) });
+        });
+        store.drift_tick(strategy, 0);
+    }
+
+    /// one simulated minute of drift; reschedules itself until the voter reacts or DRIFT_MAX_MIN passes
+    fn drift_tick(&self, strategy: VoteStrategy, minute: u32) {
+        let store = *self;
+        let DemoStore { cluster, control, wasm_log, .. } = store;
+        let step = drift_step(strategy, minute);
+        store.nodes.update(|n| n.record_vote(strategy, &drift_outputs(minute)));
+        cluster.drift.set(Some(DriftRun { minute, step, recalibrated: false }));
+        let offset = DRIFT_PER_MIN * minute as f64;
+        match step {
+            DriftStep::Tracking if minute < DRIFT_MAX_MIN => {
+                sim_timeout(move || store.drift_tick(strategy, minute + 1), std::time::Duration::from_millis(DRIFT_TICK_MS));
+            }
+            DriftStep::Tracking => {
+                wasm_log.append(|logs| logs.push(LogEntry { level: "warn".into(), message: format!("[DRIFT] I{} is +{:.2}°C off after {} min and was never flagged ({})", DRIFTING, offset, minute, strategy.label()) }));
+                control.is_running.set(false);
+            }
+            DriftStep::Halted => {
+                store.record(EventKind::QuorumLost, Some(Runtime::Wasm), "sensorDrift", format!("no two outputs agree at {} min ({})", minute, strategy.label()));
+                wasm_log.append(|logs| {
+                    logs.push(LogEntry { level: "error".into(), message: format!("[VOTE] No two outputs agree under {} - sensor noise alone breaks the vote", strategy.label()) });
+                    logs.push(LogEntry { level: "warn".into(), message: "[HALT] Output withheld - pick a tolerance band in the voting policy".into() });
+                });
+                control.is_running.set(false);
+            }
+            DriftStep::Detected => {
+                store.record(EventKind::Outvoted, Some(Runtime::Wasm), "sensorDrift", format!("I{} outvoted after {} min at +{:.2}°C ({})", DRIFTING, minute, offset, strategy.label()));
+                wasm_log.append(|logs| {
+                    logs.push(LogEntry { level: "warn".into(), message: format!("[VOTE] I{} outvoted after {} simulated min (+{:.2}°C, {})", DRIFTING, minute, offset, strategy.label()) });
+                    logs.push(LogEntry { level: "info".into(), message: format!("[MAINT] Recalibrating I{}: hot-swap from a fresh instance", DRIFTING) });
+                });
+                store.set_instance(DRIFTING, InstanceState::Faulty);
+                spawn_local(async move {
+                    let rebuild = store.recover_instance(DRIFTING).await;
+                    store.record_timed(EventKind::Recovery, Runtime::Wasm, "sensorDrift", format!("I{} recalibrated and {} in {}", DRIFTING, rebuild.used.verb(), rebuild.text), rebuild.ms);
+                    store.rebuilt(DRIFTING, rebuild.ms);
+                    cluster.drift.update(|d| if let Some(run) = d { run.recalibrated = true; });
+                    wasm_log.append(|logs| logs.push(LogEntry { level: "success".into(), message: format!("[OK] I{} recalibrated and back in the vote in {} (real)", DRIFTING, rebuild.text) }));
+                    control.is_running.set(false);
+                }.instrument(tracing::info_span!(target: "attack", "recalibrate", instance = DRIFTING)));
+            }
+        }
+    }
+
+    /// telemetry stream with an injected fault, revealed a tick at a time; the voter and the ewma detector each judge every tick
+    pub fn run_anomaly_scenario(&self, fault: StreamFault) {
+        let store = *self;
+        let DemoStore { control, wasm_log, voter, cluster, .. } = store;
+        if control.is_running.get() || !store.has_quorum() { return; }
+        control.is_running.set(true);
+
+        let strategy = voter.get_untracked();
+        let noise = stream_noise_scale(store.noise_profile());
+        cluster.anomaly.set(Some(AnomalyRun { fault, ticks: simulate(fault, strategy, STREAM_SEED, noise), shown: 0 }));
+        store.record(EventKind::AttackStarted, None, "telemetryAnomaly", format!("{}, voter {}", fault.label(), strategy.label()));
+        wasm_log.append(|logs| logs.push(LogEntry { level: "warn".into(), message: format!("[ANOMALY] {} - voter {} plus EWMA band detector", fault.label(), strategy.label()) }));
+        store.anomaly_tick();
+    }
+
+    /// reveals the next tick, journals what either layer flagged and reschedules itself until the stream ends
+    fn anomaly_tick(&self) {
+        let store = *self;
+        let DemoStore { cluster, control, wasm_log, .. } = store;
+        let Some((tick, remaining)) = cluster.anomaly.try_update(|run| {
+            let run = run.as_mut()?;
+            let tick = *run.ticks.get(run.shown)?;
+            run.shown += 1;
+            Some((tick, run.ticks.len() - run.shown))
+        }).flatten() else {
+            control.is_running.set(false);
+            return;
+        };
+
+        if let Some(node) = tick.outvoted {
+            let value = tick.outcome.value().unwrap_or_default();
+            store.record(EventKind::Outvoted, Some(Runtime::Wasm), "telemetryAnomaly", format!("I{} outvoted at tick {}: {:.2}°C vs voted {:.2}°C", node, tick.index, tick.outputs[node], value));
+            wasm_log.append(|logs| logs.push(LogEntry { level: "warn".into(), message: format!("[VOTE] tick {}: I{} read {:.2}°C, outvoted 2-1", tick.index, node, tick.outputs[node]) }));
+        }
+        if let (true, Some((lo, hi)), Some(value)) = (tick.anomalous, tick.band, tick.outcome.value()) {
+            let agreeing = match tick.outcome { VoteOutcome::Consensus { agreeing, .. } => agreeing, VoteOutcome::Halted { .. } => 0 };
+            store.record(EventKind::Anomaly, Some(Runtime::Wasm), "telemetryAnomaly", format!("tick {}: voted {:.2}°C outside band [{:.2}, {:.2}] ({} agreeing)", tick.index, value, lo, hi, agreeing));
+            wasm_log.append(|logs| logs.push(LogEntry { level: "error".into(), message: format!("[ANOMALY] tick {}: voted {:.2}°C outside [{:.2}, {:.2}] - {} of 3 agreed on it", tick.index, value, lo, hi, agreeing) }));
+        }
+        if tick.outcome.value().is_none() {
+            wasm_log.append(|logs| logs.push(LogEntry { level: "error".into(), message: format!("[VOTE] tick {}: no two outputs agree, output withheld", tick.index) }));
+        }
+
+        if remaining > 0 {
+            sim_timeout(move || store.anomaly_tick(), std::time::Duration::from_millis(STREAM_TICK_MS));
+        } else {
+            wasm_log.append(|logs| logs.push(LogEntry { level: "info".into(), message: "[ANOMALY] Stream finished - see the chart for who caught what".into() }));
+            control.is_running.set(false);
+        }
+    }
+
+    /// message flood into both gateway pipelines, optionally with one stage slowed; the run is simulated up front and revealed at real speed
+    pub fn run_flood_attack(&self, slow: Option<StageId>) {
+        let store = *self;
+        let DemoStore { control, perf, python_log, wasm_log, rate_limit, flood, .. } = store;
+        if control.is_running.get() { return; }
+        control.is_running.set(true);
+
+        // per-frame cost from the sensor comparison when it has run, otherwise the modelled default
+        let measured = |ms: f64, default: f64| if ms > 0.0 { ms } else { default };
+        let python_frame_ms = measured(perf.python_exec_ms.get_untracked(), DEFAULT_PYTHON_FRAME_MS);
+        let wasm_frame_ms = measured(perf.wasm_exec_ms.get_untracked(), DEFAULT_WASM_FRAME_MS);
+        let limit = rate_limit.get_untracked();
+        let samples = simulate_flood(limit, python_frame_ms, wasm_frame_ms, slow);
+        flood.set(Some(FloodRun { limit, python_frame_ms, wasm_frame_ms, slow, samples, shown: 0 }));
+
+        let slowed = slow.map(|s| format!(", {} stage slowed", s.label().to_lowercase())).unwrap_or_default();
+        store.record(EventKind::AttackStarted, None, "messageFlood", format!("Message flood, {:.0} frames/s, limiter {}{}", FLOOD_RATE_PER_S, limit.label(), slowed));
+        let start = format!("[FLOOD] {:.0} frames/s from t={:.1}s to t={:.1}s{}", FLOOD_RATE_PER_S, FLOOD_START_MS / 1000.0, FLOOD_END_MS / 1000.0, slowed);
+        python_log.append(|logs| {
+            logs.push(LogEntry { level: "warn".into(), message: start.clone() });
+            logs.push(LogEntry { level: "info".into(), message: format!("[QUEUE] Unbounded stage queues, {:.2}ms per frame to parse", python_frame_ms) });
+        });
+        wasm_log.append(|logs| {
+            logs.push(LogEntry { level: "warn".into(), message: start });
+            logs.push(LogEntry { level: "info".into(), message: format!("[LIMIT] Token bucket {}, {:.3}ms per frame to parse", limit.label(), wasm_frame_ms) });
+            logs.push(LogEntry { level: "info".into(), message: format!("[STAGES] Bounded queues {:?} (ingress, parse, vote, publish)", STAGE_CAPACITY) });
+        });
+        store.flood_tick();
+    }
+
+    /// reveals the next sample, journals deadline misses and the first refusal, reschedules itself until the run ends
+    fn flood_tick(&self) {
+        let store = *self;
+        let DemoStore { control, python_log, wasm_log, flood, .. } = store;
+        let Some((sample, previous, remaining, limit)) = flood.try_update(|run| {
+            let run = run.as_mut()?;
+            let sample = *run.samples.get(run.shown)?;
+            let previous = run.shown.checked_sub(1).map(|i| run.samples[i]).unwrap_or_default();
+            run.shown += 1;
+            Some((sample, previous, run.samples.len() - run.shown, run.limit))
+        }).flatten() else {
+            control.is_running.set(false);
+            return;
+        };
+        let t = sample.t_ms / 1000.0;
+
+        if sample.python_latency_ms > POLL_DEADLINE_MS && previous.python_latency_ms <= POLL_DEADLINE_MS {
+            store.record(EventKind::Overload, Some(Runtime::Python), "messageFlood", format!("{} frames queued, readings {:.0}ms old at t={:.2}s (deadline {:.0}ms)", sample.python_queue, sample.python_latency_ms, t, POLL_DEADLINE_MS));
+            python_log.append(|logs| logs.push(LogEntry { level: "error".into(), message: format!("[DEADLINE] t={:.2}s: {} frames queued, a new reading waits {:.0}ms", t, sample.python_queue, sample.python_latency_ms) }));
+        }
+        if sample.wasm_latency_ms > POLL_DEADLINE_MS && previous.wasm_latency_ms <= POLL_DEADLINE_MS {
+            store.record(EventKind::Overload, Some(Runtime::Wasm), "messageFlood", format!("{} frames queued, readings {:.0}ms old at t={:.2}s (deadline {:.0}ms)", sample.wasm_queue, sample.wasm_latency_ms, t, POLL_DEADLINE_MS));
+            wasm_log.append(|logs| logs.push(LogEntry { level: "error".into(), message: format!("[DEADLINE] t={:.2}s: {} admitted frames queued - the limit is above what the gateway can serve", t, sample.wasm_queue) }));
+        }
+        if let (Some(refusal), 0) = (sample.refusal, previous.wasm_shed) {
+            store.record(EventKind::LoadShed, Some(Runtime::Wasm), "messageFlood", format!("{} ({})", refusal, limit.label()));
+            wasm_log.append(|logs| logs.push(LogEntry { level: "warn".into(), message: format!("[LIMIT] t={:.2}s: shedding - {}", t, refusal) }));
+        }
+        if let (Some(backpressure), 0) = (sample.backpressure, previous.wasm_backpressured) {
+            store.record(EventKind::LoadShed, Some(Runtime::Wasm), "messageFlood", format!("{} ({} frames held in bounded stages)", backpressure, sample.wasm_queue));
+            wasm_log.append(|logs| logs.push(LogEntry { level: "warn".into(), message: format!("[STAGES] t={:.2}s: {} - memory stays bounded", t, backpressure) }));
+        }
+        if sample.python_oom {
+            let mib = sample.python_memory_bytes as f64 / (1024.0 * 1024.0);
+            store.record(EventKind::Crash, Some(Runtime::Python), "messageFlood", format!("worker out of memory at t={:.2}s: {:.1} MiB of queued frames, {} frames lost so far", t, mib, sample.python_lost));
+            python_log.append(|logs| logs.push(LogEntry { level: "error".into(), message: format!("[OOM] t={:.2}s: queues reached {:.1} MiB - worker killed, queued frames lost, restarting", t, mib) }));
+        }
+        if previous.python_down && !sample.python_down {
+            store.record_timed(EventKind::Recovery, Runtime::Python, "messageFlood", format!("worker restarted at t={:.2}s", t), LEADER_RESTART_MS as f64);
+            python_log.append(|logs| logs.push(LogEntry { level: "success".into(), message: format!("[RESTART] t={:.2}s: worker back, {} frames lost while it was down", t, sample.python_lost) }));
+        }
+        // a progress line per simulated second
+        if (sample.t_ms as u64).is_multiple_of(1000) {
+            python_log.append(|logs| logs.push(LogEntry { level: "info".into(), message: format!("[QUEUE] t={:.0}s: depth {}, {:.1} MiB, latency {:.0}ms", t, sample.python_queue, sample.python_memory_bytes as f64 / (1024.0 * 1024.0), sample.python_latency_ms) }));
+            wasm_log.append(|logs| logs.push(LogEntry { level: "info".into(), message: format!("[LIMIT] t={:.0}s: {} shed, {} backpressured, stages {:?}, latency {:.2}ms", t, sample.wasm_shed, sample.wasm_backpressured, sample.wasm_stages, sample.wasm_latency_ms) }));
+        }
+
+        if remaining > 0 {
+            sim_timeout(move || store.flood_tick(), std::time::Duration::from_millis(FLOOD_STEP_MS as u64));
+            return;
+        }
+        let python_end = if sample.python_latency_ms > POLL_DEADLINE_MS {
+            LogEntry { level: "error".into(), message: format!("[FLOOD] Over - still {} frames behind ({:.1}s of stale telemetry), {} lost", sample.python_queue, sample.python_latency_ms / 1000.0, sample.python_lost) }
+        } else {
+            LogEntry { level: "success".into(), message: format!("[FLOOD] Over - queue drained, {} frames lost", sample.python_lost) }
+        };
+        python_log.append(|logs| logs.push(python_end));
+        wasm_log.append(|logs| logs.push(LogEntry { level: "success".into(), message: format!("[FLOOD] Over - {} frames refused by the limiter and {} by backpressure, each with an explicit error; latency {:.2}ms", sample.wasm_shed, sample.wasm_backpressured, sample.wasm_latency_ms) }));
+        control.is_running.set(false);
+    }
+
+    /// soak run: repeats the sensor/parse workload every SOAK_TICK_MS for the configured duration, sampling memory as it goes
+    pub fn start_soak(&self) {
+        let store = *self;
+        let DemoStore { wasm_log, soak, soak_config, .. } = store;
+        if soak.with(|r| r.as_ref().is_some_and(|r| !r.finished)) { return; }
+        let config = soak_config.get_untracked().clamped();
+        soak_config.set(config);
+        let started_ms = js_sys::Date::now();
+        soak.set(Some(SoakRun::new(config, started_ms)));
+        tracing::info!(target: "measure", minutes = config.duration_min, sample_s = config.sample_every_s, "soak run started");
+        wasm_log.append(|logs| logs.push(LogEntry { level: "info".into(), message: format!("[SOAK] {} min run, memory sampled every {}s", config.duration_min, config.sample_every_s) }));
+        store.soak_tick(started_ms);
+    }
+
+    /// ends the soak run at the next tick
+    pub fn stop_soak(&self) {
+        self.soak.update(|r| if let Some(run) = r { run.finished = true; });
+    }
+
+    /// one workload iteration, a memory sample when due, growth checks; reschedules itself until the duration has passed
+    fn soak_tick(&self, started_ms: f64) {
+        let store = *self;
+        let DemoStore { perf, wasm_log, soak, .. } = store;
+        // a tick left over from a stopped run must not drive the next one
+        let Some((finished, config)) = soak.with_untracked(|r| r.as_ref().filter(|r| r.started_ms == started_ms).map(|r| (r.finished, r.config))) else { return };
+        if finished {
+            store.finish_soak();
+            return;
+        }
+        let elapsed_ms = js_sys::Date::now() - started_ms;
+
+        // the previous python run may still be going; its iteration is counted when it is started
+        let ran = !perf.sensor_running.get_untracked();
+        if ran {
+            store.run_sensor_comparison();
+        }
+        store.instance_workload(WORKLOAD_BYTES);
+
+        let growing = soak.try_update(|r| {
+            let run = r.as_mut()?;
+            run.iterations += ran as u32;
+            if run.sample_due(elapsed_ms) {
+                run.samples.push(SoakSample {
+                    t_s: elapsed_ms / 1000.0,
+                    iterations: run.iterations,
+                    js_heap_bytes: js_heap_mib().map(|(used, _)| used * 1_048_576.0),
+                    module_bytes: module_memory_bytes(),
+                    instance_bytes: store.instance_memory_bytes(),
+                });
+            }
+            let growing = run.newly_growing();
+            run.flagged.extend(growing.iter().map(|(s, _)| *s));
+            Some(growing)
+        }).flatten().unwrap_or_default();
+        for (series, per_min) in growing {
+            let runtime = (series != MemorySeries::JsHeap).then_some(Runtime::Wasm);
+            store.record(EventKind::Leak, runtime, "soak", format!("{} floor rising in every window, {:.1} KiB/min after {:.0} min", series.label(), per_min / 1024.0, elapsed_ms / 60_000.0));
+            wasm_log.append(|logs| logs.push(LogEntry { level: "warn".into(), message: format!("[SOAK] {} keeps growing ({:.1} KiB/min)", series.label(), per_min / 1024.0) }));
+        }
+
+        if elapsed_ms >= config.duration_ms() {
+            store.finish_soak();
+        } else {
+            // memory is sampled over real time, so the soak stays off the virtual clock
+            set_timeout(move || store.soak_tick(started_ms), std::time::Duration::from_millis(SOAK_TICK_MS));
+        }
+    }
+
+    /// marks the run finished and logs the verdict per series
+    fn finish_soak(&self) {
+        let DemoStore { wasm_log, soak, .. } = *self;
+        let Some(run) = soak.try_update(|r| r.as_mut().map(|run| {
+            run.finished = true;
+            run.clone()
+        })).flatten() else { return };
+        let verdicts = MemorySeries::ALL.into_iter().map(|s| format!("{}: {}", s.label(), run.trend(s).label())).collect::<Vec<_>>().join(", ");
+        tracing::info!(target: "measure", iterations = run.iterations, samples = run.samples.len(), "soak run finished: {}", verdicts);
+        let level = if run.flagged.is_empty() { "success" } else { "warn" };
+        wasm_log.append(|logs| logs.push(LogEntry { level: level.into(), message: format!("[SOAK] Done - {} iterations, {} samples. {}", run.iterations, run.samples.len(), verdicts) }));
+    }
+
+    /// raises a node's suspicion; reaching the eviction threshold evicts and hot-swaps it
+    pub fn suspect(&self, node: usize, offense: Offense) {
+        let score = self.cluster.suspicion.try_update(|s| s.penalize(node, offense).then(|| (s.scores[node], s.threshold))).flatten();
+        if let Some((score, threshold)) = score {
+            self.evict(node, score, threshold);
+        }
+    }
+
+    /// takes a suspicious node out of the vote and rebuilds it with a clean score
+    fn evict(&self, node: usize, score: f64, threshold: f64) {
+        let store = *self;
+        let DemoStore { cluster, wasm_log, .. } = store;
+        cluster.suspicion.update(|s| s.clear(node));
+        store.record(EventKind::Outvoted, Some(Runtime::Wasm), "suspicion", format!("I{} evicted at suspicion {:.1} (threshold {:.1})", node, score, threshold));
+        wasm_log.append(|logs| logs.push(LogEntry { level: "warn".into(), message: format!("[SUSPICION] I{} score {:.1} ≥ {:.1} → evicted, hot-swapping", node, score, threshold) }));
+        // a node already down (trap rebuild, crashed leader) is rebuilt by its own handler
+        if cluster.instance_states.with_untracked(|s| s[node] != InstanceState::Healthy) {
+            return;
+        }
+        store.set_instance(node, InstanceState::Faulty);
+        spawn_local(async move {
+            let rebuild = store.recover_instance(node).await;
+            store.record_timed(EventKind::Recovery, Runtime::Wasm, "suspicion", format!("I{} {} in {}", node, rebuild.used.verb(), rebuild.text), rebuild.ms);
+            store.rebuilt(node, rebuild.ms);
+            wasm_log.append(|logs| logs.push(LogEntry { level: "success".into(), message: format!("[OK] I{} {} in {} (real) - suspicion reset", node, rebuild.used.verb(), rebuild.text) }));
+        }.instrument(tracing::info_span!(target: "attack", "evict", instance = node)));
+    }
+
+    /// intermittent fault: `node` disagrees on a random share of readings, one reading per second
+    pub fn run_intermittent_fault(&self, node: usize) {
+        let store = *self;
+        let DemoStore { control, wasm_log, .. } = store;
+        if control.is_running.get() || !store.has_quorum() { return; }
+        control.is_running.set(true);
+        store.record(EventKind::AttackStarted, None, "intermittent", format!("Intermittent fault on I{}", node));
+        wasm_log.append(|logs| logs.push(LogEntry { level: "warn".into(), message: format!("[FLAKY] I{} glitches on ~{:.0}% of readings ({} readings, 1/s)", node, INTERMITTENT_RATE * 100.0, INTERMITTENT_READINGS) }));
+        store.intermittent_reading(node, 1);
+    }
+
+    /// one reading of the intermittent scenario; stops early once the node is evicted
+    fn intermittent_reading(&self, node: usize, reading: u32) {
+        let store = *self;
+        let DemoStore { cluster, control, wasm_log, .. } = store;
+        let healthy = cluster.instance_states.with_untracked(|s| s[node] == InstanceState::Healthy);
+        let glitch = healthy && js_sys::Math::random() < INTERMITTENT_RATE;
+        let mut voted = cluster.instance_states.with_untracked(|s| outputs(s, SCENARIO_READING));
+        if glitch {
+            voted[node] = Some(SCENARIO_READING + 4.0);
+        }
+        store.nodes.update(|n| n.record_vote(store.voter.get_untracked(), &voted));
+        if glitch {
+            wasm_log.append(|logs| logs.push(LogEntry { level: "warn".into(), message: format!("[VOTE] I{} disagreed on reading {}/{} - outvoted 2/3", node, reading, INTERMITTENT_READINGS) }));
+            store.suspect(node, Offense::Disagreement);
+        }
+        let evicted = cluster.instance_states.with_untracked(|s| s[node] != InstanceState::Healthy);
+        if reading >= INTERMITTENT_READINGS || evicted {
+            if !evicted {
+                wasm_log.append(|logs| logs.push(LogEntry { level: "info".into(), message: format!("[FLAKY] I{} stayed below the eviction threshold - glitches tolerated", node) }));
+            }
+            control.is_running.set(false);
+            return;
+        }
+        sim_timeout(move || store.intermittent_reading(node, reading + 1), std::time::Duration::from_secs(1));
+    }
+
+    /// when fewer than QUORUM instances are healthy: logs the safe state, raises an alarm and returns true
+    fn note_halted(&self, attack: &str) -> bool {
+        let healthy = self.cluster.instance_states.with_untracked(|s| healthy_count(s));
+        if healthy >= QUORUM {
+            return false;
+        }
+        if attack != "quorumLoss" {
+            self.record(EventKind::QuorumLost, Some(Runtime::Wasm), attack, format!("output withheld, {}/3 instances healthy", healthy));
+        }
+        self.wasm_log.append(|logs| {
+            logs.push(LogEntry { level: "error".into(), message: "[HALT] Safe state: no consensus output, no log commits, Modbus registers keep the last voted value".into() });
+            logs.push(LogEntry { level: "warn".into(), message: format!("[ALARM] Quorum lost ({}/3 healthy) - restore nodes to resume", healthy) });
+        });
+        true
+    }
+
+    /// feeds a wasm-column reading to influx, the modbus slave and the replicated log; true when no quorum
+    fn apply_wasm_reading(&self, reading: SensorReading) -> bool {
+        self.influx.update(|w| w.enqueue(&bme280_point(js_sys::Date::now(), reading.temperature, reading.humidity, reading.pressure)));
+        self.modbus.update(|s| s.load_reading(&reading));
+        let halted = !self.has_quorum();
+        if !halted {
+            self.hmi.update(|h| h.reading = Some(reading));
+        }
+        let voted = self.cluster.instance_states.with_untracked(|s| outputs(s, reading.temperature));
+        self.nodes.update(|n| n.record_vote(self.voter.get_untracked(), &voted));
+        if !halted {
+            self.cluster.log.update(|log| { log.append(reading.temperature); });
+        }
+        halted
+    }
+
+    /// records a wasm-column run and logs it (a halted voter reports nothing)
+    fn log_wasm_reading(&self, run: BackendRun, halted: bool) {
+        let DemoStore { perf, wasm_log, .. } = *self;
+        perf.wasm_exec_ms.set(run.exec_ms);
+        perf.wasm_exec_samples.update(|s| s.push(run.exec_ms));
+        perf.backend_runs.update(|r| r.push(run));
+        perf.sensor_ran.set(true);
+        wasm_log.append(|logs| {
+            if let Some(load) = run.load_ms {
+                logs.push(LogEntry { level: "info".into(), message: format!("[COMPONENT] {} loaded via jco + preview2 shim in {:.1}ms", COMPONENT_MODULE, load) });
+            }
+            let message = match run.backend {
+                SensorBackend::Bindgen => format!("[OK] Module instantiated in {:.3}ms", run.exec_ms),
+                SensorBackend::Component => format!("[OK] driver.sensor-check() returned in {:.3}ms (canonical ABI)", run.exec_ms),
+            };
+            logs.push(LogEntry { level: "success".into(), message });
+        });
+        if halted {
+            self.note_halted("");
+        } else {
+            wasm_log.append(|logs| {
+                logs.push(LogEntry { level: "success".into(), message: "[OK] BME280 driver initialized".into() });
+                logs.push(LogEntry { level: "info".into(), message: format!("Temperature: {:.1}°C", run.reading.temperature) });
+                logs.push(LogEntry { level: "info".into(), message: format!("Humidity: {:.1}%", run.reading.humidity) });
+                logs.push(LogEntry { level: "info".into(), message: format!("Pressure: {:.2} hPa", run.reading.pressure) });
+            });
+        }
+    }
+
+    /// times the js driver and logs the same reading as the other columns
+    fn run_js_baseline(&self, reading: SensorReading) {
+        let DemoStore { perf, stats, js_log, .. } = *self;
+        js_log.append(|logs| logs.push(LogEntry { level: "info".into(), message: "$ node sensor_driver.js".into() }));
+        match run_js_sensor() {
+            Ok(elapsed) => {
+                perf.js_exec_samples.update(|s| s.push(elapsed));
+                stats.js_processed.update(|n| *n += 1);
+                js_log.append(|logs| {
+                    logs.push(LogEntry { level: "success".into(), message: format!("[OK] JS driver executed in {:.3}ms", elapsed) });
+                    logs.push(LogEntry { level: "info".into(), message: format!("Temperature: {:.1}°C", reading.temperature) });
+                    logs.push(LogEntry { level: "info".into(), message: format!("Humidity: {:.1}%", reading.humidity) });
+                    logs.push(LogEntry { level: "info".into(), message: format!("Pressure: {:.2} hPa", reading.pressure) });
+                });
+            }
+            Err(e) => js_log.append(|logs| logs.push(LogEntry { level: "error".into(), message: format!("[ERR] JS error: {}", e) })),
+        }
+    }
+
+    /// journal name of the run: the library entry's when one is running, else the built-in's
+    fn run_name(&self, attack: &str) -> String {
+        self.control.scenario.with_untracked(|s| s.as_ref().map(|s| s.journal_name())).unwrap_or_else(|| get_attack_config(attack).name.to_string())
+    }
+
+    /// the js baseline has no pool or capability boundary, so attacks are only noted
+    fn note_js_attack(&self, name: &str) {
+        if self.js_baseline() {
+            self.js_log.append(|logs| {
+                logs.push(LogEntry { level: "warn".into(), message: format!("[ATTACK] Incoming: {}", name) });
+                logs.push(LogEntry { level: "info".into(), message: "[SKIP] Baseline runs the sensor workload only - no sandbox, no failover".into() });
+            });
+        }
+    }
+
+    /// runs every attack in sequence (security, then availability, then the user library)
+    pub fn run_all_attacks(&self) {
+        let store = *self;
+        let DemoStore { control, .. } = store;
+        if control.is_running.get() || control.running_all.get() { return; }
+        
+        // Set flags - control.running_all stays true throughout entire sequence
+        control.is_running.set(true);
+        control.running_all.set(true);
+        
+        // All attacks in order: Security first, then Availability, then library entries
+        let sequence = run_all_with(store.library.with_untracked(|l| l.run_ids()));
+        let total_ms = sequence.len() as u64 * RUN_ALL_SPACING_MS + 3000;
+        tracing::info!(target: "attack", attacks = sequence.len(), total_ms, "run all started");
+        for (i, attack) in sequence.into_iter().enumerate() {
+            let delay = (i as u64) * RUN_ALL_SPACING_MS; // allow respawn between attacks
+            
+            sim_timeout(move || {
+                control.selected_attack.set(attack);
+                // When running all, don't set control.is_running - it's managed by run_all_attacks
+                sim_timeout(move || store.dispatch(), std::time::Duration::from_millis(100));
+            }, std::time::Duration::from_millis(delay));
+        }
+        
+        // Schedule reset of control.running_all after all attacks complete
+        // N attacks * 3.5s + extra buffer for last attack to finish (~3s)
+        sim_timeout(move || {
+            control.running_all.set(false);
+            control.is_running.set(false);
+        }, std::time::Duration::from_millis(total_ms));
+    }
+}
@@ -0,0 +1,101 @@
+// what: instantiates a module with every function in its import object wrapped by a tracing proxy
+// why: the proxy sits where the host would, so each call is recorded on its way in - name, arguments, result, duration
+// relations: records into host_trace.rs ModuleTrace; used by proof/linking_panel.rs for the filter module's sensor.read import
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use super::host_trace::{args_summary, CallOutcome, HostCall, ModuleTrace};
+
+/// forwards to `fn` and reports (name, args, result or error, ms, threw) to `record`
+const PROXY_BODY: &str = "return function (...args) {
+    const start = performance.now();
+    try {
+        const result = fn(...args);
+        record(name, args, result, performance.now() - start, false);
+        return result;
+    } catch (e) {
+        record(name, args, e, performance.now() - start, true);
+        throw e;
+    }
+};";
+
+type Recorder = Closure<dyn FnMut(String, js_sys::Array, JsValue, f64, bool)>;
+
+/// an instance whose imports report every call into `trace`
+pub struct Traced {
+    pub exports: js_sys::Object,
+    pub trace: Rc<RefCell<ModuleTrace>>,
+    // the proxies call back into this for as long as the instance lives
+    _recorder: Recorder,
+}
+
+impl Traced {
+    pub fn snapshot(&self) -> ModuleTrace {
+        self.trace.borrow().clone()
+    }
+}
+
+fn js_error(e: JsValue) -> String {
+    format!("{:?}", e)
+}
+
+fn value_text(v: &JsValue) -> String {
+    if let Some(n) = v.as_f64() {
+        n.to_string()
+    } else if let Some(s) = v.as_string() {
+        format!("{:?}", s)
+    } else if v.is_undefined() {
+        "void".to_string()
+    } else if let Some(e) = v.dyn_ref::<js_sys::Error>() {
+        String::from(e.to_string())
+    } else {
+        format!("{:?}", v)
+    }
+}
+
+/// "namespace.name" for every import the module declares, functions or not
+fn declared_imports(module: &js_sys::WebAssembly::Module) -> Vec<String> {
+    js_sys::WebAssembly::Module::imports(module)
+        .iter()
+        .map(|d| {
+            let get = |key: &str| js_sys::Reflect::get(&d, &key.into()).ok().and_then(|v| v.as_string()).unwrap_or_default();
+            format!("{}.{}", get("module"), get("name"))
+        })
+        .collect()
+}
+
+/// compiles and instantiates `bytes`, every function in `imports` behind a proxy; memories, tables and globals pass through
+pub fn instantiate(name: &str, bytes: &[u8], imports: &js_sys::Object) -> Result<Traced, String> {
+    let module = js_sys::WebAssembly::Module::new(&js_sys::Uint8Array::from(bytes)).map_err(js_error)?;
+    let trace = Rc::new(RefCell::new(ModuleTrace::new(name, declared_imports(&module))));
+    let sink = trace.clone();
+    let recorder: Recorder = Closure::new(move |import: String, args: js_sys::Array, result: JsValue, ms: f64, threw: bool| {
+        let args: Vec<String> = args.iter().map(|a| value_text(&a)).collect();
+        sink.borrow_mut().push(HostCall {
+            import,
+            args: args_summary(&args),
+            result: value_text(&result),
+            duration_ms: ms,
+            outcome: if threw { CallOutcome::Threw } else { CallOutcome::Returned },
+        });
+    });
+    let proxy = js_sys::Function::new_with_args("fn, name, record", PROXY_BODY);
+    let wrapped = js_sys::Object::new();
+    for namespace in js_sys::Object::keys(imports).iter() {
+        let fields: js_sys::Object = js_sys::Reflect::get(imports, &namespace).map_err(js_error)?.unchecked_into();
+        let traced_fields = js_sys::Object::new();
+        for field in js_sys::Object::keys(&fields).iter() {
+            let mut value = js_sys::Reflect::get(&fields, &field).map_err(js_error)?;
+            if value.is_function() {
+                let import = format!("{}.{}", namespace.as_string().unwrap_or_default(), field.as_string().unwrap_or_default());
+                value = proxy.call3(&JsValue::NULL, &value, &import.into(), recorder.as_ref()).map_err(js_error)?;
+            }
+            js_sys::Reflect::set(&traced_fields, &field, &value).map_err(js_error)?;
+        }
+        js_sys::Reflect::set(&wrapped, &namespace, &traced_fields).map_err(js_error)?;
+    }
+    let instance = js_sys::WebAssembly::Instance::new(&module, &wrapped).map_err(js_error)?;
+    Ok(Traced { exports: instance.exports(), trace, _recorder: recorder })
+}
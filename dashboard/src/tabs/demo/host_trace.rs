@@ -0,0 +1,143 @@
+// what: per-module trace of host calls - every import a loaded module called, with its arguments, result and duration
+// why: the browser analog of strace; deny-by-default is only convincing when the granted calls are visible too
+// relations: filled by host_proxy.rs (js import wrapping) and handlers.rs (mock host denials); shown by host_trace_panel.rs,
+//            journalled through store.rs trace_module; one shared signal provided in lib.rs so the proof tab's linking loads appear too
+
+use std::collections::BTreeMap;
+use leptos::*;
+
+/// calls kept per module load; later ones are only counted
+pub const TRACE_CAP: usize = 64;
+/// arguments shown per call
+pub const ARGS_CAP: usize = 4;
+/// module loads kept, oldest dropped first
+pub const MAX_MODULES: usize = 12;
+
+/// how a host call ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallOutcome {
+    /// the import exists and returned
+    Returned,
+    /// the import threw back into the guest
+    Threw,
+    /// the host has no such capability and answered with a denial
+    Denied,
+}
+
+/// one call from a module into its host
+#[derive(Clone, Debug, PartialEq)]
+pub struct HostCall {
+    /// "namespace.name"
+    pub import: String,
+    pub args: String,
+    /// returned value, thrown error or denial text
+    pub result: String,
+    pub duration_ms: f64,
+    pub outcome: CallOutcome,
+}
+
+impl HostCall {
+    /// `sensor.read(17) = 2341 <0.002ms>`, the way strace prints a syscall
+    pub fn strace_line(&self) -> String {
+        let result = match self.outcome {
+            CallOutcome::Returned => self.result.clone(),
+            CallOutcome::Threw => format!("! {}", self.result),
+            CallOutcome::Denied => format!("-1 EACCES ({})", self.result),
+        };
+        format!("{}({}) = {} <{:.3}ms>", self.import, self.args, result, self.duration_ms)
+    }
+}
+
+/// totals for one import across a module load
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImportStats {
+    pub calls: u64,
+    pub denied: u64,
+    pub total_ms: f64,
+    /// arguments of the first call, as an example
+    pub first_args: String,
+}
+
+/// everything one loaded module asked of its host
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleTrace {
+    pub module: String,
+    /// imports the module declares, "namespace.name"
+    pub imports: Vec<String>,
+    /// the first TRACE_CAP calls in order
+    pub calls: Vec<HostCall>,
+    /// every call, per import
+    pub stats: BTreeMap<String, ImportStats>,
+}
+
+impl ModuleTrace {
+    pub fn new(module: impl Into<String>, imports: Vec<String>) -> Self {
+        Self { module: module.into(), imports, calls: Vec::new(), stats: BTreeMap::new() }
+    }
+
+    pub fn push(&mut self, call: HostCall) {
+        let stats = self.stats.entry(call.import.clone()).or_default();
+        if stats.calls == 0 {
+            stats.first_args = call.args.clone();
+        }
+        stats.calls += 1;
+        stats.total_ms += call.duration_ms;
+        if call.outcome == CallOutcome::Denied {
+            stats.denied += 1;
+        }
+        if self.calls.len() < TRACE_CAP {
+            self.calls.push(call);
+        }
+    }
+
+    pub fn total_calls(&self) -> u64 {
+        self.stats.values().map(|s| s.calls).sum()
+    }
+
+    /// calls counted but not kept line by line
+    pub fn dropped(&self) -> u64 {
+        self.total_calls() - self.calls.len() as u64
+    }
+
+    /// one journal detail and total duration per import called
+    pub fn journal_lines(&self) -> Vec<(String, f64)> {
+        self.stats
+            .iter()
+            .map(|(import, s)| {
+                let verdict = if s.denied == s.calls { "denied" } else if s.denied == 0 { "granted" } else { "partly denied" };
+                (format!("{}: {} ×{} {}, first args ({})", self.module, import, s.calls, verdict, s.first_args), s.total_ms)
+            })
+            .collect()
+    }
+}
+
+/// the latest module loads, newest last
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HostTrace {
+    pub modules: Vec<ModuleTrace>,
+}
+
+impl HostTrace {
+    pub fn load(&mut self, trace: ModuleTrace) {
+        self.modules.push(trace);
+        if self.modules.len() > MAX_MODULES {
+            self.modules.remove(0);
+        }
+    }
+}
+
+/// "17, 2.5, "x"" - at most ARGS_CAP values, then how many were left out
+pub fn args_summary(args: &[String]) -> String {
+    let mut shown: Vec<&str> = args.iter().take(ARGS_CAP).map(String::as_str).collect();
+    let more = args.len().saturating_sub(ARGS_CAP);
+    let tail = format!("… +{}", more);
+    if more > 0 {
+        shown.push(&tail);
+    }
+    shown.join(", ")
+}
+
+/// shared host trace signal (a fresh one outside the app, e.g. in tests)
+pub fn use_host_trace() -> RwSignal<HostTrace> {
+    use_context::<RwSignal<HostTrace>>().unwrap_or_else(|| create_rw_signal(HostTrace::default()))
+}
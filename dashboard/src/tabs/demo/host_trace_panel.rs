@@ -0,0 +1,55 @@
+// what: "syscall trace" view - per loaded module, its declared imports, per-import totals and the calls in strace form
+// why: shows what each module actually asked of its host, granted or denied, instead of only the denials
+// relations: renders host_trace.rs HostTrace; mounted in component.rs (all loads) and proof/linking_panel.rs (linking loads)
+
+use leptos::*;
+use super::host_trace::{HostTrace, ModuleTrace};
+
+fn module_view(m: ModuleTrace) -> impl IntoView {
+    let imports = if m.imports.is_empty() { "none - nothing to call, nothing to grant".to_string() } else { m.imports.join(", ") };
+    let mut lines: Vec<String> = m.calls.iter().map(|c| c.strace_line()).collect();
+    if m.dropped() > 0 {
+        lines.push(format!("+++ {} more calls counted, not listed +++", m.dropped()));
+    }
+    let table = (!m.stats.is_empty()).then(|| view! {
+        <table class="capability-matrix">
+            <tr><th>"Import"</th><th>"Calls"</th><th>"Denied"</th><th>"Total"</th></tr>
+            {m.stats.iter().map(|(import, s)| view! {
+                <tr>
+                    <td>{import.clone()}</td>
+                    <td>{s.calls}</td>
+                    <td class={if s.denied > 0 { "success" } else { "" }}>{s.denied}</td>
+                    <td>{format!("{:.3}ms", s.total_ms)}</td>
+                </tr>
+            }).collect_view()}
+        </table>
+    });
+    view! {
+        <details class="host-trace-module">
+            <summary>{format!("{} - {} host calls", m.module, m.total_calls())}</summary>
+            <p class="metrics-note">"Declared imports: "{imports}</p>
+            {table}
+            <pre class="host-trace-lines">{lines.join("\n")}</pre>
+        </details>
+    }
+}
+
+/// newest load first; `only` keeps modules whose name starts with it
+#[component]
+pub fn HostTracePanel(trace: RwSignal<HostTrace>, #[prop(optional)] only: Option<&'static str>) -> impl IntoView {
+    let modules = move || trace.with(|t| t.modules.iter().rev().filter(|m| only.is_none_or(|p| m.module.starts_with(p))).cloned().collect::<Vec<_>>());
+    view! {
+        <div class="audit-panel host-trace">
+            <h4>"🔎 Host Call Trace" <span class="demo-badge">"strace for wasm imports"</span></h4>
+            <p class="section-desc">"Every import a loaded module called, on its way into the host: granted calls return a value, a capability the host never provided answers EACCES."</p>
+            {move || {
+                let list = modules();
+                if list.is_empty() {
+                    view! { <p class="metrics-note">"No module has called its host yet."</p> }.into_view()
+                } else {
+                    list.into_iter().map(module_view).collect_view()
+                }
+            }}
+        </div>
+    }
+}
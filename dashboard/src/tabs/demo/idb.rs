@@ -0,0 +1,62 @@
+// what: indexeddb request helpers - await a request, open a database with one object store, run a single-store transaction
+// why: each persisted feature keeps its own small database; the promise plumbing around IdbRequest is the same for all of them
+// relations: used by raft_persist.rs and library_persist.rs
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbRequest, IdbTransactionMode};
+
+const DB_VERSION: u32 = 1;
+
+/// resolves with the request's result once it succeeds
+async fn complete(request: &IdbRequest) -> Result<JsValue, String> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        let done = request.clone();
+        let on_success = Closure::once_into_js(move || {
+            let _ = resolve.call1(&JsValue::NULL, &done.result().unwrap_or(JsValue::UNDEFINED));
+        });
+        let on_error = Closure::once_into_js(move || {
+            let _ = reject.call1(&JsValue::NULL, &JsValue::from_str("indexeddb request failed"));
+        });
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+    JsFuture::from(promise).await.map_err(|e| e.as_string().unwrap_or_else(|| "indexeddb request failed".into()))
+}
+
+/// opens the database, creating the object store on first use
+async fn open(db_name: &'static str, store: &'static str) -> Result<IdbDatabase, String> {
+    let factory = web_sys::window()
+        .and_then(|w| w.indexed_db().ok().flatten())
+        .ok_or("indexeddb unavailable")?;
+    let request = factory.open_with_u32(db_name, DB_VERSION).map_err(|_| "indexeddb open failed")?;
+    let upgrading = request.clone();
+    let on_upgrade = Closure::once_into_js(move || {
+        if let Ok(db) = upgrading.result().and_then(|r| r.dyn_into::<IdbDatabase>()) {
+            if !db.object_store_names().contains(store) {
+                let _ = db.create_object_store(store);
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade.unchecked_ref()));
+    complete(&request).await?.dyn_into::<IdbDatabase>().map_err(|_| "indexeddb open failed".into())
+}
+
+/// object-store request in a single-store transaction
+pub async fn run(
+    db_name: &'static str,
+    store: &'static str,
+    mode: IdbTransactionMode,
+    op: impl FnOnce(&web_sys::IdbObjectStore) -> Result<IdbRequest, JsValue>,
+) -> Result<JsValue, String> {
+    let db = open(db_name, store).await?;
+    let object_store = db
+        .transaction_with_str_and_mode(store, mode)
+        .and_then(|tx| tx.object_store(store))
+        .map_err(|_| "indexeddb transaction failed")?;
+    let request = op(&object_store).map_err(|_| "indexeddb request failed")?;
+    let result = complete(&request).await;
+    db.close();
+    result
+}
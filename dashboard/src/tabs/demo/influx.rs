@@ -0,0 +1,161 @@
+// what: influxdb line-protocol encoding and a batching writer with backoff
+// why: turns the dashboard into a functional mini-gateway feeding the l3 historian
+// relations: fed by component.rs telemetry, flushed by influx_panel.rs
+
+/// max points sent in one write request
+pub const MAX_BATCH: usize = 50;
+/// points kept while the endpoint is down (oldest dropped first, newest while a batch is in flight)
+pub const MAX_BUFFERED: usize = 1000;
+/// first retry delay after a failed write
+pub const BASE_BACKOFF_MS: u32 = 1000;
+/// retry delay ceiling
+pub const MAX_BACKOFF_MS: u32 = 60_000;
+
+/// a single field value
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    Float(f64),
+    Int(i64),
+    Text(String),
+}
+
+/// one telemetry point
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point {
+    pub measurement: &'static str,
+    pub tags: Vec<(&'static str, String)>,
+    pub fields: Vec<(&'static str, FieldValue)>,
+    /// unix epoch nanoseconds
+    pub timestamp_ns: u64,
+}
+
+/// bme280 reading from the sensor check
+pub fn bme280_point(timestamp_ms: f64, temperature: f64, humidity: f64, pressure: f64) -> Point {
+    Point {
+        measurement: "bme280",
+        tags: vec![("site", "guardian-one".to_string()), ("source", "sensor-check".to_string())],
+        fields: vec![
+            ("temperature", FieldValue::Float(temperature)),
+            ("humidity", FieldValue::Float(humidity)),
+            ("pressure", FieldValue::Float(pressure)),
+        ],
+        timestamp_ns: timestamp_ms as u64 * 1_000_000,
+    }
+}
+
+/// 2oo3 consensus output from the gateway
+pub fn voted_point(timestamp_ms: f64, value: f64, agreeing: u8, status: &str) -> Point {
+    Point {
+        measurement: "tmr_vote",
+        tags: vec![("site", "guardian-one".to_string())],
+        fields: vec![
+            ("value", FieldValue::Float(value)),
+            ("agreeing", FieldValue::Int(agreeing as i64)),
+            ("status", FieldValue::Text(status.to_string())),
+        ],
+        timestamp_ns: timestamp_ms as u64 * 1_000_000,
+    }
+}
+
+/// escapes measurement names (commas, spaces)
+fn escape_measurement(s: &str) -> String {
+    s.replace(',', "\\,").replace(' ', "\\ ")
+}
+
+/// escapes tag keys/values and field keys (commas, equals, spaces)
+fn escape_key(s: &str) -> String {
+    s.replace(',', "\\,").replace('=', "\\=").replace(' ', "\\ ")
+}
+
+/// formats a field value (floats plain, ints with i suffix, strings quoted)
+fn format_field(value: &FieldValue) -> String {
+    match value {
+        FieldValue::Float(v) => format!("{}", v),
+        FieldValue::Int(v) => format!("{}i", v),
+        FieldValue::Text(v) => format!("\"{}\"", v.replace('\\', "\\\\").replace('"', "\\\"")),
+    }
+}
+
+/// encodes one point as a line-protocol line
+pub fn to_line(point: &Point) -> String {
+    let mut line = escape_measurement(point.measurement);
+    for (k, v) in &point.tags {
+        line.push_str(&format!(",{}={}", escape_key(k), escape_key(v)));
+    }
+    let fields = point
+        .fields
+        .iter()
+        .map(|(k, v)| format!("{}={}", escape_key(k), format_field(v)))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{} {} {}", line, fields, point.timestamp_ns)
+}
+
+/// exponential backoff for the nth consecutive failure (1-based)
+pub fn backoff_ms(failures: u32) -> u32 {
+    if failures == 0 {
+        return 0;
+    }
+    BASE_BACKOFF_MS
+        .saturating_mul(1u32 << (failures - 1).min(16))
+        .min(MAX_BACKOFF_MS)
+}
+
+/// buffering writer state (transport is done by the panel)
+#[derive(Clone, Debug, Default)]
+pub struct InfluxWriter {
+    pub enabled: bool,
+    pub url: String,
+    pub token: String,
+    pub buffer: Vec<String>,
+    pub failures: u32,
+    pub dropped: u32,
+    pub written: u32,
+    /// a write request is outstanding
+    pub in_flight: bool,
+    /// epoch ms before which no retry is attempted
+    pub retry_at_ms: f64,
+}
+
+impl InfluxWriter {
+    /// queue a point, dropping the oldest when the buffer is full; while a batch is in flight the new point is
+    /// dropped instead, since on_success drains the sent lines from the front by count
+    pub fn enqueue(&mut self, point: &Point) {
+        if !self.enabled {
+            return;
+        }
+        if self.buffer.len() >= MAX_BUFFERED {
+            self.dropped += 1;
+            if self.in_flight {
+                return;
+            }
+            self.buffer.remove(0);
+        }
+        self.buffer.push(to_line(point));
+    }
+
+    /// next batch to send, if configured and not backing off
+    pub fn next_batch(&self, now_ms: f64) -> Option<Vec<String>> {
+        if !self.enabled || self.in_flight || self.url.is_empty() || self.buffer.is_empty() || now_ms < self.retry_at_ms {
+            return None;
+        }
+        Some(self.buffer.iter().take(MAX_BATCH).cloned().collect())
+    }
+
+    /// batch accepted: drop it from the buffer and reset backoff
+    pub fn on_success(&mut self, sent: usize) {
+        let sent = sent.min(self.buffer.len());
+        self.buffer.drain(..sent);
+        self.written += sent as u32;
+        self.in_flight = false;
+        self.failures = 0;
+        self.retry_at_ms = 0.0;
+    }
+
+    /// batch rejected: keep it buffered and schedule a retry
+    pub fn on_failure(&mut self, now_ms: f64) {
+        self.failures += 1;
+        self.in_flight = false;
+        self.retry_at_ms = now_ms + backoff_ms(self.failures) as f64;
+    }
+}
@@ -0,0 +1,97 @@
+// what: influxdb writer settings and buffering indicator
+// why: lets viewers point the demo telemetry at their own historian
+// relations: used by component.rs, drives influx.rs writer, uses wasm.rs post helper
+
+use leptos::*;
+use super::influx::{InfluxWriter, MAX_BUFFERED};
+use super::wasm::post_text_with_headers;
+
+/// how often the writer checks for a flushable batch
+const FLUSH_INTERVAL_MS: u64 = 1000;
+
+/// influxdb v2 writer controls with batch flush loop
+#[component]
+pub fn InfluxPanel(writer: RwSignal<InfluxWriter>) -> impl IntoView {
+    let (last_status, set_last_status) = create_signal(Option::<String>::None);
+
+    let flush = move || {
+        let now_ms = js_sys::Date::now();
+        let Some(batch) = writer.with(|w| w.next_batch(now_ms)) else { return };
+        let (url, token) = writer.with(|w| (w.url.clone(), w.token.clone()));
+        writer.update(|w| w.in_flight = true);
+        let sent = batch.len();
+        let body = batch.join("\n");
+        spawn_local(async move {
+            let auth = format!("Token {}", token);
+            let headers: Vec<(&str, &str)> = if token.is_empty() { vec![] } else { vec![("Authorization", auth.as_str())] };
+            match post_text_with_headers(&url, "text/plain; charset=utf-8", &headers, &body).await {
+                Ok(code) if (200..300).contains(&code) => {
+                    writer.update(|w| w.on_success(sent));
+                    set_last_status.set(Some(format!("✅ wrote {} points (HTTP {})", sent, code)));
+                }
+                Ok(code) => {
+                    writer.update(|w| w.on_failure(js_sys::Date::now()));
+                    set_last_status.set(Some(format!("❌ HTTP {} - backing off", code)));
+                }
+                Err(e) => {
+                    writer.update(|w| w.on_failure(js_sys::Date::now()));
+                    set_last_status.set(Some(format!("❌ {} - backing off", e)));
+                }
+            }
+        });
+    };
+
+    if let Ok(handle) = set_interval_with_handle(flush, std::time::Duration::from_millis(FLUSH_INTERVAL_MS)) {
+        on_cleanup(move || handle.clear());
+    }
+
+    view! {
+        <div class="audit-panel influx-panel">
+            <h4>
+                "🗄️ InfluxDB Writer"
+                <span class="demo-badge">"Line Protocol"</span>
+                <label class="influx-toggle">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || writer.with(|w| w.enabled)
+                        on:change=move |ev| writer.update(|w| w.enabled = event_target_checked(&ev))
+                    />
+                    " enabled"
+                </label>
+            </h4>
+            <Show when=move || writer.with(|w| w.enabled)>
+                <div class="audit-controls">
+                    <input
+                        class="collector-url"
+                        type="url"
+                        placeholder="http://influx:8086/api/v2/write?org=ot&bucket=telemetry"
+                        prop:value=move || writer.with(|w| w.url.clone())
+                        on:input=move |ev| writer.update(|w| w.url = event_target_value(&ev))
+                    />
+                    <input
+                        class="collector-url influx-token"
+                        type="password"
+                        placeholder="API token"
+                        prop:value=move || writer.with(|w| w.token.clone())
+                        on:input=move |ev| writer.update(|w| w.token = event_target_value(&ev))
+                    />
+                </div>
+                <div class="influx-buffer">
+                    <div class="influx-buffer-bar">
+                        <div
+                            class="influx-buffer-fill"
+                            class:backing-off=move || writer.with(|w| w.failures > 0)
+                            style:width=move || format!("{}%", writer.with(|w| w.buffer.len() * 100 / MAX_BUFFERED))
+                        />
+                    </div>
+                    <span class="audit-status">{move || writer.with(|w| format!(
+                        "{} buffered • {} written • {} dropped{}",
+                        w.buffer.len(), w.written, w.dropped,
+                        if w.failures > 0 { format!(" • retry #{}", w.failures) } else { String::new() }
+                    ))}</span>
+                </div>
+                {move || last_status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,79 @@
+// what: "about this demo" explainer plus the wit contract modal
+// why: split out of component.rs; static content with its own modal state
+// relations: uses the payloads.rs wit excerpt (skeleton until the attack-code module loads) with policy_panel.rs commands for the live policy.rs grants and the raft.rs election benchmark, section text from content/narrative.toml,
+//            rendered by component.rs
+
+use leptos::*;
+use super::payloads::Payloads;
+use super::policy::CapabilityPolicy;
+use super::policy_panel::WasmtimeCommands;
+use super::raft::{ElectionBench, ELECTION_TIMEOUT_MS};
+use crate::content::{content, fill, rich};
+use crate::kiosk::use_kiosk;
+use crate::tabs::skeleton::Skeleton;
+
+#[component]
+pub fn InfoBox(election: ElectionBench, policy: RwSignal<CapabilityPolicy>, payloads: RwSignal<Option<Result<Payloads, String>>>) -> impl IntoView {
+    let (wit_modal_open, set_wit_modal_open) = create_signal(false);
+    let kiosk = use_kiosk();
+    create_effect(move |_| if kiosk.attract.get() { set_wit_modal_open.set(false) });
+    // measured values the narrative refers to by placeholder
+    let measured = [
+        ("election_claim", election.claim()),
+        ("mean_terms", format!("{:.2}", election.mean_terms)),
+        ("split_votes", election.split_votes.to_string()),
+        ("election_timeout", format!("{:.0}-{:.0}ms", ELECTION_TIMEOUT_MS.0, ELECTION_TIMEOUT_MS.1)),
+    ];
+    view! {
+        // Info box with clear two-part narrative
+        <div class="info-box">
+            <h4>"ℹ️ About This Demo"<span class="demo-badge">"Browser Demonstration"</span></h4>
+        
+            {content().info_sections.iter().map(|section| {
+                let body = section.body.as_deref().map(|body| rich(&fill(body, &measured)));
+                let items = section.items.iter().map(|item| rich(&fill(item, &measured))).collect::<Vec<_>>();
+                view! {
+                    <div class="info-section">
+                        <h5>{section.title.as_str()}</h5>
+                        {body.map(|body| view! { <p>{body}</p> })}
+                        {(!items.is_empty()).then(|| view! { <ul>{items.into_iter().map(|item| view! { <li>{item}</li> }).collect_view()}</ul> })}
+                    </div>
+                }
+            }).collect_view()}
+        
+            <p class="wit-note">
+                <strong>"🔒 WIT Contract:"</strong>" "
+                <a class="wit-link" href="#" on:click=move |e: web_sys::MouseEvent| {
+                    e.prevent_default();
+                    set_wit_modal_open.set(true);
+                }>"View wit/attacks.wit"</a>
+                " — defines the "<strong>"capability boundary"</strong>" (same format used by wasmtime)"
+            </p>
+            <p class="wit-subnote">"↳ "<em>"Note: "</em><code>"attack-surface"</code>" import is for browser simulation only. On "<strong>"Raspberry Pi + wasmtime"</strong>", this capability would simply "<strong>"not be granted"</strong>" — any call traps immediately at the host boundary."</p>
+        
+            <p class="hardware-note">"🔧 "<strong>"Coming Soon:"</strong>" Hardware demo on "<strong>"Raspberry Pi"</strong>" with "<strong>"wasmtime"</strong>" enforcing WIT at syscall level."</p>
+        </div>
+    
+        // WIT Code Modal
+        {move || if wit_modal_open.get() {
+            view! {
+                <div class="modal-overlay" on:click=move |_| set_wit_modal_open.set(false)>
+                    <div class="modal-content" on:click=|e: web_sys::MouseEvent| e.stop_propagation()>
+                        <div class="modal-header">
+                            <span class="modal-title">"📄 wit/attacks.wit"</span>
+                            <button class="modal-close" on:click=move |_| set_wit_modal_open.set(false)>"×"</button>
+                        </div>
+                        {move || payloads.with(|p| match p {
+                            Some(Ok(p)) => view! { <pre class="wit-code">{p.wit_excerpt.clone()}</pre> }.into_view(),
+                            Some(Err(e)) => view! { <p class="hardware-note">{format!("⚠️ WIT excerpt unavailable: {}", e)}</p> }.into_view(),
+                            None => view! { <Skeleton label="📄 Loading the attack code module..." lines=4 /> }.into_view(),
+                        })}
+                        <WasmtimeCommands policy=policy />
+                    </div>
+                </div>
+            }.into_view()
+        } else {
+            view! { <div></div> }.into_view()
+        }}
+    }
+}
@@ -0,0 +1,207 @@
+// what: three live INSTANCE_WASM instances whose linear memory and table are read back from the engine, plus the warm spare
+// why: per-instance budgets come from real WebAssembly.Memory/Table objects, grow with the workload and reset on hot-swap
+// relations: bytes from modules.rs, budget math in budget.rs, extends store.rs (budgets, spare and supervisor signals); spawned and fed by component.rs,
+//            replaced per supervisor.rs strategy by handlers.rs recoveries
+
+use leptos::*;
+use wasm_bindgen::{JsCast, JsValue};
+use super::budget::{plan_write, InstanceBudget};
+use super::modules::{INSTANCE_MAX_PAGES, INSTANCE_TABLE_MAX, INSTANCE_WASM};
+use super::probes::WASM_PAGE_BYTES;
+use super::store::DemoStore;
+use super::supervisor::Supervisor;
+use super::types::InstanceState;
+use super::wasm::{measure_instantiate_time, now};
+
+/// one instantiated module and the ring it writes
+pub struct LiveInstance {
+    memory: js_sys::WebAssembly::Memory,
+    table: js_sys::WebAssembly::Table,
+    grow: js_sys::Function,
+    used_bytes: u32,
+    grow_denied: u32,
+    /// value written by the next batch, so successive writes are distinguishable in memory
+    seq: u8,
+}
+
+impl LiveInstance {
+    pub fn spawn() -> Result<Self, String> {
+        let array = js_sys::Uint8Array::from(INSTANCE_WASM);
+        let module = js_sys::WebAssembly::Module::new(&array).map_err(|e| format!("{:?}", e))?;
+        let instance = js_sys::WebAssembly::Instance::new(&module, &js_sys::Object::new()).map_err(|e| format!("{:?}", e))?;
+        let exports = instance.exports();
+        let export = |name: &str| js_sys::Reflect::get(&exports, &name.into()).map_err(|_| format!("missing export {}", name));
+        Ok(Self {
+            memory: export("memory")?.dyn_into().map_err(|_| "memory export is not a Memory")?,
+            table: export("table")?.dyn_into().map_err(|_| "table export is not a Table")?,
+            grow: export("grow")?.dyn_into().map_err(|_| "grow export is not a function")?,
+            used_bytes: 0,
+            grow_denied: 0,
+            seq: 0,
+        })
+    }
+
+    /// nothing written since it was instantiated
+    pub fn is_fresh(&self) -> bool {
+        self.used_bytes == 0
+    }
+
+    /// sizes as the engine reports them
+    pub fn budget(&self) -> InstanceBudget {
+        InstanceBudget {
+            memory_pages: self.memory.buffer().unchecked_into::<js_sys::ArrayBuffer>().byte_length() / WASM_PAGE_BYTES,
+            max_pages: INSTANCE_MAX_PAGES,
+            table_len: self.table.length(),
+            table_max: INSTANCE_TABLE_MAX,
+            used_bytes: self.used_bytes,
+            grow_denied: self.grow_denied,
+        }
+    }
+
+    /// writes one batch into the ring, growing memory first when it is full; a refused grow wraps the ring
+    pub fn write(&mut self, bytes: u32) {
+        let plan = plan_write(&self.budget(), bytes);
+        let mut offset = plan.offset;
+        if plan.grow > 0 {
+            let result = self.grow.call1(&JsValue::NULL, &JsValue::from(plan.grow)).ok().and_then(|v| v.as_f64()).unwrap_or(-1.0);
+            if result < 0.0 {
+                self.grow_denied += 1;
+                offset = 0;
+            }
+        }
+        // views are taken after the grow: growing detaches the old buffer
+        let view = js_sys::Uint8Array::new_with_byte_offset_and_length(&self.memory.buffer(), offset, bytes);
+        view.fill(self.seq, 0, bytes);
+        self.seq = self.seq.wrapping_add(1);
+        self.used_bytes = offset + bytes;
+    }
+}
+
+impl DemoStore {
+    /// (re)builds instance `idx` with fresh memory, as a hot-swap does
+    pub fn spawn_instance(&self, idx: usize) {
+        match LiveInstance::spawn() {
+            Ok(instance) => {
+                let budget = instance.budget();
+                self.instances.update_value(|all| all[idx] = Some(instance));
+                self.budgets.update(|b| b[idx] = Some(budget));
+            }
+            Err(e) => tracing::warn!(target: "measure", instance = idx, error = %e, "live instance not spawned"),
+        }
+    }
+
+    /// hot-swap respawn: skipped when a recovery already put a fresh instance in the slot
+    pub fn refresh_instance(&self, idx: usize) {
+        if !self.instances.with_value(|all| all[idx].as_ref().is_some_and(LiveInstance::is_fresh)) {
+            self.spawn_instance(idx);
+        }
+    }
+
+    /// instantiates the spare when the warm-spare strategy is on and none is held
+    pub fn warm_spare(&self) {
+        if self.supervisor.get_untracked() != Supervisor::WarmSpare || self.spare_budget.get_untracked().is_some() {
+            return;
+        }
+        let started = now();
+        match LiveInstance::spawn() {
+            Ok(spare) => {
+                let warm_ms = now() - started;
+                self.spare_budget.set(Some(spare.budget()));
+                self.spare.set_value(Some(spare));
+                self.recoveries.update(|l| l.warm_ms.push(warm_ms));
+            }
+            Err(e) => tracing::warn!(target: "measure", error = %e, "warm spare not spawned"),
+        }
+    }
+
+    /// switches the supervisor strategy; leaving warm-spare frees the spare's memory
+    pub fn set_supervisor(&self, strategy: Supervisor) {
+        self.supervisor.set(strategy);
+        match strategy {
+            Supervisor::WarmSpare => self.warm_spare(),
+            Supervisor::Rebuild => {
+                self.spare.set_value(None);
+                self.spare_budget.set(None);
+            }
+        }
+    }
+
+    /// puts a fresh instance in slot `idx` the way the supervisor strategy says; returns what ran and the
+    /// main-thread time from fault to a usable instance (spawn or swap, the same clock for both)
+    pub fn replace_instance(&self, idx: usize) -> (Supervisor, f64) {
+        let wanted = self.supervisor.get_untracked();
+        let used = wanted.pick(self.spare_budget.get_untracked().is_some());
+        let started = now();
+        let instance = match used {
+            Supervisor::WarmSpare => self.spare.try_update_value(Option::take).flatten().ok_or_else(|| "spare already taken".to_string()),
+            Supervisor::Rebuild => LiveInstance::spawn(),
+        };
+        let budget = instance.as_ref().ok().map(LiveInstance::budget);
+        if let Ok(instance) = instance {
+            self.instances.update_value(|all| all[idx] = Some(instance));
+        }
+        let elapsed_ms = now() - started;
+
+        match budget {
+            Some(budget) => self.budgets.update(|b| b[idx] = Some(budget)),
+            None => tracing::warn!(target: "measure", instance = idx, "replacement instance not spawned"),
+        }
+        self.recoveries.update(|l| {
+            l.record(used, elapsed_ms);
+            if used != wanted {
+                l.fallbacks += 1;
+            }
+        });
+        if used == Supervisor::WarmSpare {
+            // re-warm off the recovery path; a fault before it lands falls back to a rebuild
+            self.spare_budget.set(None);
+            let store = *self;
+            set_timeout(move || store.warm_spare(), std::time::Duration::ZERO);
+        }
+        (used, elapsed_ms)
+    }
+
+    /// replaces instance `idx` and returns the recovery as the journal reports it: the swap time for a
+    /// warm spare, the statistically measured instantiate for a rebuild
+    pub async fn recover_instance(&self, idx: usize) -> InstanceRecovery {
+        match self.replace_instance(idx) {
+            (Supervisor::WarmSpare, swap_ms) => InstanceRecovery { used: Supervisor::WarmSpare, ms: swap_ms, text: format!("{:.3}ms", swap_ms) },
+            (used, _) => {
+                let rebuild = measure_instantiate_time(self.iterations()).await;
+                InstanceRecovery { used, ms: rebuild.median, text: rebuild.display(3) }
+            }
+        }
+    }
+
+    /// every healthy instance writes one workload batch
+    pub fn instance_workload(&self, bytes: u32) {
+        let states = self.cluster.instance_states.get_untracked();
+        let budgets = self.instances.try_update_value(|all| {
+            std::array::from_fn::<_, 3, _>(|i| {
+                let instance = all[i].as_mut()?;
+                if states[i] == InstanceState::Healthy {
+                    instance.write(bytes);
+                }
+                Some(instance.budget())
+            })
+        });
+        if let Some(budgets) = budgets {
+            self.budgets.set(budgets);
+        }
+    }
+
+    /// linear memory held by every spawned instance, the warm spare included
+    pub fn instance_memory_bytes(&self) -> f64 {
+        let spare = self.spare_budget.with_untracked(|s| s.map_or(0.0, |b| b.memory_bytes() as f64));
+        self.budgets.with_untracked(|b| b.iter().flatten().map(|b| b.memory_bytes() as f64).sum::<f64>()) + spare
+    }
+}
+
+/// one wasm instance recovery
+pub struct InstanceRecovery {
+    /// strategy that actually ran (a warm-spare fault without a spare rebuilds)
+    pub used: Supervisor,
+    pub ms: f64,
+    /// duration as shown in the journal and logs
+    pub text: String,
+}
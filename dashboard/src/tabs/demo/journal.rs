@@ -0,0 +1,5 @@
+// what: structured audit/event journal for the demo session
+// why: terminals show prose, exporters and summaries need typed events with timestamps
+// relations: types live in guardian-types (audit.rs); written by store.rs, read by siem.rs, summary.rs and audit_panel.rs, saved in snapshot.rs
+
+pub use guardian_types::{AuditEvent, EventKind, Runtime};
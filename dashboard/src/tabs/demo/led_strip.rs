@@ -0,0 +1,69 @@
+// what: virtual ws2812b status strip under the voting panel - eight pixels lit from the live instance states, the status table and the frame on the wire
+// why: the cabinet strip is what an operator sees first; the page lights it from the same table the strip driver uses, so the two cannot drift
+// relations: colours and blink patterns from guardian-types status_map.rs, grb bytes from led.rs, healthy count from voting.rs over the store's instance states;
+//            rendered by component.rs
+
+use leptos::*;
+use guardian_types::led::{frame_time_us, strip_frame, wire_bytes, DEFAULT_BRIGHTNESS, STRIP_LEN};
+use guardian_types::{Scope, Status, STATUS_MAP};
+use super::modbus::hex;
+use super::store::DemoStore;
+use super::wasm::download_text;
+use super::voting::healthy_count;
+
+/// redraw interval; the fastest blink (125ms on) needs at least this
+const TICK_MS: u64 = 25;
+
+#[component]
+pub fn LedStripPanel(store: DemoStore) -> impl IntoView {
+    let states = store.cluster.instance_states;
+    let status = create_memo(move |_| states.with(|s| Status::from_healthy(healthy_count(s))));
+    let now = create_rw_signal(0u64);
+    if let Ok(handle) = set_interval_with_handle(move || now.set(js_sys::Date::now() as u64), std::time::Duration::from_millis(TICK_MS)) {
+        on_cleanup(move || handle.clear());
+    }
+    let frame = create_memo(move |_| strip_frame(status.get(), now.get(), DEFAULT_BRIGHTNESS));
+
+    view! {
+        <div class="demo-section led-strip-section">
+            <h3>"🚥 Status Strip"<span class="attack-badge">{format!("WS2812B × {}", STRIP_LEN)}</span></h3>
+            <p class="section-desc">
+                "The cabinet's NeoPixel strip, lit from the live instance states through the same status map the Pi's strip driver uses. Crash an instance to see it go yellow, crash two for red."
+            </p>
+            <div class="led-strip" role="img" aria-label=move || format!("status strip: {}", status.get().mapping().label)>
+                {(0..STRIP_LEN).map(|i| view! {
+                    <span class="led-pixel" style:background-color=move || frame.with(|f| f[i].css()) />
+                }).collect_view()}
+            </div>
+            <p class="metrics-note">
+                {move || format!("{} — {}", status.get().mapping().label, status.get().mapping().meaning)}
+            </p>
+            <table class="capability-matrix led-table">
+                <tr>
+                    <th>"Status"</th>
+                    <th>"Colour"</th>
+                    <th>"Pattern"</th>
+                    <th>"Priority"</th>
+                    <th>"Meaning"</th>
+                </tr>
+                {STATUS_MAP.iter().filter(|m| m.scope == Scope::Cluster).map(|m| view! {
+                    <tr class:voting-selected=move || status.get() == m.status>
+                        <td class=format!("world-name {}", m.tone.css_class())>{m.label}</td>
+                        <td><span class="led-swatch" style:background-color=m.color.css() />{format!(" #{:02X}{:02X}{:02X}", m.color.r, m.color.g, m.color.b)}</td>
+                        <td>{m.pattern.label()}</td>
+                        <td>{m.priority.label()}</td>
+                        <td>{m.meaning}</td>
+                    </tr>
+                }).collect_view()}
+            </table>
+            <div class="audit-controls">
+                <button class="action-btn" title="The whole status map as the Pi agent serves it (GET /api/v1/status-map)" on:click=move |_| {
+                    download_text("status-map.json", "application/json", &serde_json::to_string_pretty(&STATUS_MAP).unwrap_or_default());
+                }>"⬇ status-map.json"</button>
+            </div>
+            <p class="metrics-note handoff-link">
+                {move || format!("On the wire (GRB, brightness {}/255, {:.0}µs per frame): {}", DEFAULT_BRIGHTNESS, frame_time_us(STRIP_LEN), hex(&wire_bytes(&frame.get())))}
+            </p>
+        </div>
+    }
+}
@@ -0,0 +1,149 @@
+// what: downtime ledger - one incident per attack run and runtime, with cause, detection time, recovery time and output lost
+// why: a single cumulative downtime counter hides which fault cost what; the ledger breaks it down per incident
+// relations: derived from journal.rs events (so snapshots and replays carry it), drawn by ledger_panel.rs, totals in runtime_model.rs stat cells,
+//            exported by summary.rs and as csv
+
+use super::journal::{AuditEvent, EventKind, Runtime};
+use super::summary::outcome_label;
+
+/// fault events that open (or join) an incident
+const FAULTS: [EventKind; 5] = [EventKind::Crash, EventKind::Trap, EventKind::Outvoted, EventKind::QuorumLost, EventKind::Overload];
+
+/// one fault and what it cost a runtime
+#[derive(Clone, Debug, PartialEq)]
+pub struct Incident {
+    /// 1-based, in session order
+    pub id: u32,
+    pub attack: String,
+    /// attack name from its AttackStarted event (the attack id if it had none)
+    pub cause: String,
+    pub runtime: Runtime,
+    /// most severe fault seen
+    pub fault: EventKind,
+    /// epoch ms of the first fault
+    pub fault_ms: f64,
+    /// attack start to first fault, None without a start event
+    pub detection_ms: Option<f64>,
+    /// measured recovery (respawn, rebuild, election), else the gap to the recovery event; None while still open
+    pub recovery_ms: Option<f64>,
+    /// the runtime stopped producing output (python crash, wasm quorum loss); otherwise voting masked the fault
+    pub outage: bool,
+    /// the journal events behind this incident
+    pub events: Vec<AuditEvent>,
+}
+
+impl Incident {
+    pub fn label(&self) -> String {
+        format!("INC-{:03}", self.id)
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.recovery_ms.is_none()
+    }
+
+    /// recovery cell: masked faults need none, outages still open are flagged
+    pub fn recovery_text(&self) -> String {
+        match (self.recovery_ms, self.outage) {
+            (Some(ms), _) => format_ms(Some(ms)),
+            (None, true) => "ongoing".to_string(),
+            (None, false) => "masked".to_string(),
+        }
+    }
+
+    /// output lost to this incident; open outages are not counted until they close
+    pub fn downtime_ms(&self) -> f64 {
+        if self.outage { self.recovery_ms.unwrap_or(0.0) } else { 0.0 }
+    }
+}
+
+/// a fault makes the runtime's output stop: a python worker gone, or a wasm vote without quorum
+fn is_outage(runtime: Runtime, kind: EventKind) -> bool {
+    match runtime {
+        Runtime::Python => kind == EventKind::Crash,
+        _ => kind == EventKind::QuorumLost,
+    }
+}
+
+/// incidents from the journal, in the order their first fault happened
+pub fn incidents(events: &[AuditEvent]) -> Vec<Incident> {
+    // (attack, start ms, name) of the latest run of each attack
+    let mut runs: Vec<(String, f64, String)> = Vec::new();
+    let mut out: Vec<Incident> = Vec::new();
+    for event in events {
+        if event.kind == EventKind::AttackStarted {
+            runs.retain(|(attack, _, _)| *attack != event.attack);
+            runs.push((event.attack.clone(), event.timestamp_ms, event.detail.clone()));
+            continue;
+        }
+        let Some(runtime) = event.runtime else { continue };
+        let run = runs.iter().find(|(attack, _, _)| *attack == event.attack);
+        let started_ms = run.map(|(_, ms, _)| *ms);
+        // an incident belongs to one run: it must have started after the run did
+        let open = out.iter_mut().rev().find(|i| {
+            i.attack == event.attack && i.runtime == runtime && started_ms.is_none_or(|s| i.fault_ms >= s)
+        });
+        match (event.kind, open) {
+            (kind, Some(incident)) if FAULTS.contains(&kind) => {
+                if incident.is_open() {
+                    if kind.syslog_severity() < incident.fault.syslog_severity() {
+                        incident.fault = kind;
+                    }
+                    incident.outage |= is_outage(runtime, kind);
+                    incident.events.push(event.clone());
+                    continue;
+                }
+                // a new fault after recovery is a new incident
+            }
+            (EventKind::Recovery | EventKind::Election, Some(incident)) => {
+                if incident.is_open() {
+                    incident.recovery_ms = Some(event.duration_ms.unwrap_or((event.timestamp_ms - incident.fault_ms).max(0.0)));
+                }
+                incident.events.push(event.clone());
+                continue;
+            }
+            (kind, None) if FAULTS.contains(&kind) => {}
+            _ => continue,
+        }
+        out.push(Incident {
+            id: out.len() as u32 + 1,
+            attack: event.attack.clone(),
+            cause: run.map(|(_, _, name)| name.clone()).unwrap_or_else(|| event.attack.clone()),
+            runtime,
+            fault: event.kind,
+            fault_ms: event.timestamp_ms,
+            detection_ms: started_ms.map(|s| (event.timestamp_ms - s).max(0.0)),
+            recovery_ms: None,
+            outage: is_outage(runtime, event.kind),
+            events: vec![event.clone()],
+        });
+    }
+    out
+}
+
+/// output lost by one runtime over the ledger
+pub fn total_downtime_ms(incidents: &[Incident], runtime: Runtime) -> f64 {
+    // folded from +0.0: an empty f64 sum is -0.0, which would print as "-0ms"
+    incidents.iter().filter(|i| i.runtime == runtime).map(Incident::downtime_ms).fold(0.0, |total, ms| total + ms)
+}
+
+/// "12.5ms", or "—" when unknown
+pub fn format_ms(ms: Option<f64>) -> String {
+    match ms {
+        Some(ms) if ms >= 100.0 => format!("{:.0}ms", ms),
+        Some(ms) => format!("{:.1}ms", ms),
+        None => "—".to_string(),
+    }
+}
+
+/// the ledger as csv, one row per incident
+pub fn to_csv(incidents: &[Incident]) -> String {
+    let mut out = String::from("incident,cause,runtime,fault,detection_ms,recovery_ms,downtime_ms\n");
+    for i in incidents {
+        let ms = |v: Option<f64>| v.map(|v| format!("{:.3}", v)).unwrap_or_default();
+        out.push_str(&format!(
+            "{},\"{}\",{},{},{},{},{:.3}\n",
+            i.label(), i.cause.replace('"', "\"\""), i.runtime.as_str(), outcome_label(Some(i.fault)), ms(i.detection_ms), ms(i.recovery_ms), i.downtime_ms(),
+        ));
+    }
+    out
+}
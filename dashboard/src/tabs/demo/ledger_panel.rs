@@ -0,0 +1,71 @@
+// what: downtime ledger table under the stats panels - one row per incident, click a row for the journal events behind it
+// why: the stat cells give the totals; this shows which incident cost what and how fast each was caught and recovered
+// relations: renders ledger.rs incidents from the journal.rs signal, csv download via wasm.rs, rendered by component.rs
+
+use leptos::*;
+use super::journal::{AuditEvent, Runtime};
+use super::ledger::{format_ms, incidents, to_csv, total_downtime_ms};
+use super::siem::rfc3339;
+use super::summary::outcome_label;
+use super::wasm::download_text;
+
+#[component]
+pub fn DowntimeLedger(journal: RwSignal<Vec<AuditEvent>>) -> impl IntoView {
+    let ledger = create_memo(move |_| journal.with(|events| incidents(events)));
+    let (expanded, set_expanded) = create_signal(Option::<u32>::None);
+    let download = move |_| download_text("guardian-incidents.csv", "text/csv", &ledger.with(|l| to_csv(l)));
+
+    let rows = move || ledger.with(|ledger| ledger.iter().map(|i| {
+        let id = i.id;
+        let open = move || expanded.get() == Some(id);
+        let events = i.events.iter().map(|e| view! {
+            <li>{format!("{} {} - {}{}", &rfc3339(e.timestamp_ms)[11..23], e.kind.id(), e.detail, e.duration_ms.map(|ms| format!(" ({})", format_ms(Some(ms)))).unwrap_or_default())}</li>
+        }).collect_view();
+        view! {
+            <tr class="ledger-row" class:ledger-outage=i.outage on:click=move |_| set_expanded.update(|e| *e = if *e == Some(id) { None } else { Some(id) })>
+                <td class="world-name">{move || if open() { "▾ " } else { "▸ " }}{i.label()}</td>
+                <td>{i.cause.clone()}</td>
+                <td>{if i.runtime == Runtime::Python { "🐍 Python" } else { "🦀 WASM" }}</td>
+                <td>{outcome_label(Some(i.fault))}</td>
+                <td>{format_ms(i.detection_ms)}</td>
+                <td>{i.recovery_text()}</td>
+                <td class:vote-halted={i.downtime_ms() > 0.0}>{format_ms(Some(i.downtime_ms()))}</td>
+            </tr>
+            <Show when=open>
+                <tr class="ledger-detail">
+                    <td colspan="7"><ul class="ledger-events">{events.clone()}</ul></td>
+                </tr>
+            </Show>
+        }
+    }).collect_view());
+
+    view! {
+        <div class="demo-section ledger-section">
+            <h3>"📒 Downtime Ledger"<span class="attack-badge">{move || ledger.with(|l| format!("{} incident{}", l.len(), if l.len() == 1 { "" } else { "s" }))}</span></h3>
+            <p class="section-desc">"Every fault from the journal, per runtime: when it was detected after the attack started, how long recovery took and how much output was actually lost. Click a row for the events behind it."</p>
+            {move || if ledger.with(|l| l.is_empty()) {
+                view! { <p class="section-desc">"No incidents yet - run an attack."</p> }.into_view()
+            } else {
+                view! {
+                    <table class="capability-matrix voting-matrix ledger-table">
+                        <tr>
+                            <th>"Incident"</th>
+                            <th>"Cause"</th>
+                            <th>"Runtime"</th>
+                            <th>"Fault"</th>
+                            <th>"Detected after"</th>
+                            <th>"Recovery"</th>
+                            <th>"Output lost"</th>
+                        </tr>
+                        {rows}
+                    </table>
+                    <p class="metrics-note">{move || ledger.with(|l| format!(
+                        "Output lost: 🐍 {} · 🦀 {}. A WASM fault only costs output when the 2oo3 vote loses its quorum; masked faults need no recovery.",
+                        format_ms(Some(total_downtime_ms(l, Runtime::Python))), format_ms(Some(total_downtime_ms(l, Runtime::Wasm))),
+                    ))}</p>
+                }.into_view()
+            }}
+            <button class="action-btn" disabled=move || ledger.with(|l| l.is_empty()) on:click=download>"⬇️ Download CSV"</button>
+        </div>
+    }
+}
@@ -0,0 +1,226 @@
+// what: user-defined scenario library - custom attack payloads and fault injections with an expected outcome and notes
+// why: workshop leads build their own attacks on top of the built-in ones and want them back, run with the rest and shared as json
+// relations: built-in attacks from attacks.rs, expected outcomes are quiz.rs answers checked against summary.rs outcomes;
+//            held in store.rs, run through handlers.rs dispatch and run-all, persisted by library_persist.rs, edited in library_panel.rs
+
+use serde::{Deserialize, Serialize};
+use super::attacks::{get_attack_config, AVAILABILITY_ATTACKS, SECURITY_ATTACKS};
+use super::quiz::Answer;
+use super::summary::AttackOutcome;
+
+/// format of an exported library
+pub const LIBRARY_VERSION: u32 = 1;
+/// attack ids of library entries carry this prefix ("user:<id>")
+pub const RUN_PREFIX: &str = "user:";
+/// longest scenario name
+pub const NAME_CAP: usize = 60;
+/// largest payload kept, in bytes
+pub const CODE_CAP: usize = 32 * 1024;
+
+/// what a scenario does to the demo
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScenarioKind {
+    /// a python payload against the sandbox, on top of a security attack
+    Attack,
+    /// a node fault, on top of an availability attack; no payload
+    FaultInjection,
+}
+
+impl ScenarioKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ScenarioKind::Attack => "Attack",
+            ScenarioKind::FaultInjection => "Fault injection",
+        }
+    }
+
+    /// built-in attacks a scenario of this kind can build on
+    pub fn bases(&self) -> &'static [&'static str] {
+        match self {
+            ScenarioKind::Attack => &SECURITY_ATTACKS,
+            ScenarioKind::FaultInjection => &AVAILABILITY_ATTACKS,
+        }
+    }
+}
+
+/// one saved scenario
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserScenario {
+    pub id: String,
+    pub name: String,
+    /// built-in attack whose capability request, trap or node fault the scenario reuses
+    pub base: String,
+    /// python payload; empty runs the base attack's own code
+    #[serde(default)]
+    pub code: String,
+    /// python, wasm
+    pub expected: [Answer; 2],
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl UserScenario {
+    /// a scenario on `base` with its built-in payload `builtin` (payloads.rs) copied in as a starting point
+    pub fn from_base(id: String, base: &str, builtin: &str) -> Self {
+        let kind = kind_of(base).unwrap_or(ScenarioKind::Attack);
+        Self {
+            id,
+            name: format!("My {}", get_attack_config(base).name),
+            base: base.to_string(),
+            code: if kind == ScenarioKind::Attack { builtin.to_string() } else { String::new() },
+            expected: if kind == ScenarioKind::Attack { [Answer::Fails, Answer::Contained] } else { [Answer::Fails, Answer::KeepsRunning] },
+            notes: String::new(),
+        }
+    }
+
+    pub fn kind(&self) -> ScenarioKind {
+        kind_of(&self.base).unwrap_or(ScenarioKind::Attack)
+    }
+
+    /// attack id the run-all sequence and the store use for this entry
+    pub fn run_id(&self) -> String {
+        format!("{}{}", RUN_PREFIX, self.id)
+    }
+
+    /// name journalled with the run's AttackStarted event
+    pub fn journal_name(&self) -> String {
+        format!("{} (user, on {})", self.name, get_attack_config(&self.base).name)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() || !self.id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(format!("\"{}\": id must be letters, digits and dashes", self.name));
+        }
+        let name = self.name.trim();
+        if name.is_empty() || name.chars().count() > NAME_CAP {
+            return Err(format!("\"{}\": name must be 1-{} characters", self.id, NAME_CAP));
+        }
+        let Some(kind) = kind_of(&self.base) else {
+            return Err(format!("\"{}\": unknown base attack {}", self.name, self.base));
+        };
+        if kind == ScenarioKind::FaultInjection && !self.code.trim().is_empty() {
+            return Err(format!("\"{}\": a fault injection runs no payload", self.name));
+        }
+        if self.code.len() > CODE_CAP {
+            return Err(format!("\"{}\": payload over {} KiB", self.name, CODE_CAP / 1024));
+        }
+        Ok(())
+    }
+
+    /// the latest journalled run of this scenario
+    pub fn last_run<'a>(&self, outcomes: &'a [AttackOutcome]) -> Option<&'a AttackOutcome> {
+        let name = self.journal_name();
+        outcomes.iter().rev().find(|o| o.attack == self.base && o.name == name)
+    }
+
+    /// per runtime, whether a run's outcome matched what was expected
+    pub fn check(&self, outcome: &AttackOutcome) -> [bool; 2] {
+        [
+            Answer::from_fault(outcome.python) == self.expected[0],
+            Answer::from_fault(outcome.wasm) == self.expected[1],
+        ]
+    }
+}
+
+/// the kind of scenario a built-in attack can be the base of
+pub fn kind_of(base: &str) -> Option<ScenarioKind> {
+    if SECURITY_ATTACKS.contains(&base) {
+        Some(ScenarioKind::Attack)
+    } else if AVAILABILITY_ATTACKS.contains(&base) {
+        Some(ScenarioKind::FaultInjection)
+    } else {
+        None
+    }
+}
+
+/// library id from a run id ("user:<id>" → "<id>")
+pub fn scenario_id(run_id: &str) -> Option<&str> {
+    run_id.strip_prefix(RUN_PREFIX)
+}
+
+/// the whole library, as persisted and exported
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioLibrary {
+    pub version: u32,
+    pub scenarios: Vec<UserScenario>,
+}
+
+impl Default for ScenarioLibrary {
+    fn default() -> Self {
+        Self { version: LIBRARY_VERSION, scenarios: Vec::new() }
+    }
+}
+
+impl ScenarioLibrary {
+    pub fn get(&self, id: &str) -> Option<&UserScenario> {
+        self.scenarios.iter().find(|s| s.id == id)
+    }
+
+    /// adds the scenario, or replaces the one with the same id in place
+    pub fn upsert(&mut self, scenario: UserScenario) -> Result<(), String> {
+        scenario.validate()?;
+        match self.scenarios.iter_mut().find(|s| s.id == scenario.id) {
+            Some(slot) => *slot = scenario,
+            None => self.scenarios.push(scenario),
+        }
+        Ok(())
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.scenarios.retain(|s| s.id != id);
+    }
+
+    /// an id not yet taken, derived from `name` ("Heap spray #2" → "heap-spray-2")
+    pub fn fresh_id(&self, name: &str) -> String {
+        let slug: String = name
+            .to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        let slug = if slug.is_empty() { "scenario".to_string() } else { slug };
+        (1..)
+            .map(|n| if n == 1 { slug.clone() } else { format!("{}-{}", slug, n) })
+            .find(|id| self.get(id).is_none())
+            .unwrap_or(slug)
+    }
+
+    /// attack ids appended to run-all, in library order
+    pub fn run_ids(&self) -> Vec<String> {
+        self.scenarios.iter().map(UserScenario::run_id).collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// a persisted or shared library, rejected whole if any entry is invalid or ids repeat
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let library: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if library.version != LIBRARY_VERSION {
+            return Err(format!("library version {} (expected {})", library.version, LIBRARY_VERSION));
+        }
+        for (i, scenario) in library.scenarios.iter().enumerate() {
+            scenario.validate()?;
+            if library.scenarios[..i].iter().any(|s| s.id == scenario.id) {
+                return Err(format!("duplicate scenario id {}", scenario.id));
+            }
+        }
+        Ok(library)
+    }
+
+    /// imports another library: same id replaces, new ids are appended; returns (added, replaced)
+    pub fn merge(&mut self, other: ScenarioLibrary) -> (usize, usize) {
+        let (mut added, mut replaced) = (0, 0);
+        for scenario in other.scenarios {
+            if self.get(&scenario.id).is_some() {
+                replaced += 1;
+            } else {
+                added += 1;
+            }
+            // already validated by from_json
+            let _ = self.upsert(scenario);
+        }
+        (added, replaced)
+    }
+}
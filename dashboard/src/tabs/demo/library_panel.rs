@@ -0,0 +1,224 @@
+// what: scenario library panel - list, edit, run and delete user scenarios, import/export the library as json
+// why: custom attacks and fault injections become reusable demo material instead of one-off edits
+// relations: library.rs model in the store.rs library signal, persisted by library_persist.rs, runs through handlers.rs launch, drafts start from the payloads.rs built-in payload,
+//            last-run check from summary.rs outcomes over the journal, file helpers in wasm.rs; rendered by component.rs under the quiz
+
+use leptos::*;
+use wasm_bindgen::JsCast;
+use super::attacks::get_attack_config;
+use super::library::{kind_of, ScenarioKind, ScenarioLibrary, UserScenario, CODE_CAP};
+use super::library_persist;
+use super::quiz::Answer;
+use super::store::DemoStore;
+use super::summary::outcomes;
+use super::wasm::{download_text, read_file_bytes};
+
+/// expected-outcome picker for one runtime of the draft
+fn expected_select(label: &'static str, runtime: usize, draft: RwSignal<Option<UserScenario>>) -> impl IntoView {
+    view! {
+        <label class="voting-tolerance">
+            {label}
+            <select
+                class="network-select"
+                on:change=move |ev| {
+                    if let Some(answer) = event_target_value(&ev).parse::<usize>().ok().and_then(|i| Answer::ALL.get(i).copied()) {
+                        draft.update(|d| if let Some(d) = d { d.expected[runtime] = answer });
+                    }
+                }
+            >
+                {Answer::ALL.into_iter().enumerate().map(|(i, a)| view! {
+                    <option value=i.to_string() selected=move || draft.with(|d| d.as_ref().is_some_and(|d| d.expected[runtime] == a))>{a.label()}</option>
+                }).collect_view()}
+            </select>
+        </label>
+    }
+}
+
+#[component]
+pub fn LibraryPanel(store: DemoStore) -> impl IntoView {
+    let DemoStore { library, journal, control, .. } = store;
+    let busy = move || control.is_running.get() || control.running_all.get();
+    let draft = create_rw_signal(Option::<UserScenario>::None);
+    let (status, set_status) = create_signal(Option::<String>::None);
+
+    // never overwrite the saved library with the empty default before it has been read
+    let loaded = create_rw_signal(false);
+    spawn_local(async move {
+        match library_persist::load().await {
+            Ok(saved) => {
+                if let Some(saved) = saved {
+                    library.set(saved);
+                }
+                loaded.set(true);
+            }
+            Err(e) => {
+                tracing::warn!(target: "attack", error = %e, "scenario library not restored");
+                set_status.set(Some(format!("⚠ Saved library not read ({}); changes are not persisted this session", e)));
+            }
+        }
+    });
+    create_effect(move |_| {
+        let current = library.get();
+        if !loaded.get() { return; }
+        spawn_local(async move {
+            if let Err(e) = library_persist::save(&current).await {
+                tracing::warn!(target: "attack", error = %e, "scenario library not saved");
+            }
+        });
+    });
+
+    let new_draft = move |_| {
+        let base = control.selected_attack.get_untracked();
+        let base = if kind_of(&base).is_some() { base } else { ScenarioKind::Attack.bases()[0].to_string() };
+        let id = library.with_untracked(|l| l.fresh_id(&format!("my {}", base)));
+        draft.set(Some(UserScenario::from_base(id, &base, &store.attack_code(&base).unwrap_or_default())));
+    };
+    let save_draft = move |_| {
+        let Some(scenario) = draft.get_untracked() else { return };
+        let name = scenario.name.clone();
+        match library.try_update(|l| l.upsert(scenario)) {
+            Some(Ok(())) => {
+                draft.set(None);
+                set_status.set(Some(format!("✅ saved \"{}\"", name)));
+            }
+            Some(Err(e)) => set_status.set(Some(format!("❌ {}", e))),
+            None => {}
+        }
+    };
+    let set_base = move |base: String| draft.update(|d| {
+        let Some(d) = d else { return };
+        let kind = kind_of(&base);
+        // an untouched payload follows the base; a fault injection has none
+        let untouched = d.code.trim().is_empty() || Some(&d.code) == store.attack_code(&d.base).as_ref();
+        if kind == Some(ScenarioKind::FaultInjection) || untouched {
+            d.code = UserScenario::from_base(String::new(), &base, &store.attack_code(&base).unwrap_or_default()).code;
+        }
+        d.base = base;
+    });
+    let pick = move |ev: ev::Event| {
+        let Some(input) = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok()) else { return };
+        let Some(file) = input.files().and_then(|files| files.get(0)) else { return };
+        spawn_local(async move {
+            let imported = read_file_bytes(&file)
+                .await
+                .and_then(|bytes| String::from_utf8(bytes).map_err(|_| "not utf-8 text".to_string()))
+                .and_then(|text| ScenarioLibrary::from_json(&text));
+            set_status.set(Some(match imported {
+                Ok(other) => {
+                    let (added, replaced) = library.try_update(|l| l.merge(other)).unwrap_or_default();
+                    format!("✅ {}: {} added, {} replaced", file.name(), added, replaced)
+                }
+                Err(e) => format!("❌ {}: {}", file.name(), e),
+            }));
+        });
+    };
+
+    // rebuilt only when another scenario is opened, so typing keeps focus
+    let editing = create_memo(move |_| draft.with(|d| d.as_ref().map(|d| d.id.clone())));
+    let field = move |get: fn(&UserScenario) -> String| move || draft.with(|d| d.as_ref().map(get).unwrap_or_default());
+    let fault = move || draft.with(|d| d.as_ref().is_some_and(|d| d.kind() == ScenarioKind::FaultInjection));
+    let editor = move || editing.get().map(|_| view! {
+        <div class="quiz-card library-editor">
+            <div class="voting-controls">
+                <input
+                    type="text"
+                    class="library-name"
+                    placeholder="Scenario name"
+                    prop:value=field(|d| d.name.clone())
+                    on:input=move |ev| draft.update(|d| if let Some(d) = d { d.name = event_target_value(&ev) })
+                />
+                <select class="network-select" on:change=move |ev| set_base(event_target_value(&ev))>
+                    {[ScenarioKind::Attack, ScenarioKind::FaultInjection].into_iter().map(|kind| view! {
+                        <optgroup label=kind.label()>
+                            {kind.bases().iter().map(|&base| view! {
+                                <option value=base selected=move || draft.with(|d| d.as_ref().is_some_and(|d| d.base == base))>
+                                    {format!("on {}", get_attack_config(base).name)}
+                                </option>
+                            }).collect_view()}
+                        </optgroup>
+                    }).collect_view()}
+                </select>
+            </div>
+            <textarea
+                class="policy-editor library-code"
+                disabled=fault
+                maxlength=CODE_CAP.to_string()
+                placeholder=move || if fault() { "Fault injections run no payload: the base attack's node fault is injected" } else { "Python payload; empty runs the base attack's own code" }
+                prop:value=field(|d| d.code.clone())
+                on:input=move |ev| draft.update(|d| if let Some(d) = d { d.code = event_target_value(&ev) })
+            />
+            <div class="voting-controls">
+                {expected_select("🐍 Expect ", 0, draft)}
+                {expected_select("🦀 Expect ", 1, draft)}
+            </div>
+            <textarea
+                class="policy-editor library-notes"
+                placeholder="Notes for whoever runs this next"
+                prop:value=field(|d| d.notes.clone())
+                on:input=move |ev| draft.update(|d| if let Some(d) = d { d.notes = event_target_value(&ev) })
+            />
+            <div class="voting-controls">
+                <button class="action-btn" on:click=save_draft>"💾 Save to Library"</button>
+                <button class="action-btn" on:click=move |_| draft.set(None)>"Cancel"</button>
+            </div>
+        </div>
+    });
+
+    let list = move || {
+        let runs = journal.with(|events| outcomes(events));
+        let scenarios = library.with(|l| l.scenarios.clone());
+        if scenarios.is_empty() {
+            return view! { <p class="metrics-note">"No saved scenarios yet."</p> }.into_view();
+        }
+        view! {
+            <table class="capability-matrix voting-matrix">
+                <tr><th>"Scenario"</th><th>"Expected 🐍 / 🦀"</th><th>"Last run"</th><th></th></tr>
+                {scenarios.into_iter().map(|s| {
+                    let last = s.last_run(&runs).map(|o| {
+                        let ok = s.check(o);
+                        let right = ok.iter().all(|&r| r);
+                        view! {
+                            <td class:granted=right class:vote-halted=!right>
+                                {format!("{} 🐍 {} / 🦀 {}", if right { "✓" } else { "✗" }, Answer::from_fault(o.python).label(), Answer::from_fault(o.wasm).label())}
+                            </td>
+                        }.into_view()
+                    }).unwrap_or_else(|| view! { <td class="metrics-note">"not run yet"</td> }.into_view());
+                    let (edit, delete, run_id, id) = (s.clone(), s.name.clone(), s.run_id(), s.id.clone());
+                    view! {
+                        <tr>
+                            <td class="world-name" title=s.notes.clone()>
+                                {s.name.clone()}
+                                <span class="metrics-note">{format!(" {} on {}", s.kind().label(), get_attack_config(&s.base).name)}</span>
+                            </td>
+                            <td>{format!("{} / {}", s.expected[0].label(), s.expected[1].label())}</td>
+                            {last}
+                            <td>
+                                <button class="action-btn" disabled=busy on:click=move |_| store.launch(&run_id)>"▶️"</button>
+                                <button class="action-btn" on:click=move |_| draft.set(Some(edit.clone()))>"✏️"</button>
+                                <button class="action-btn" on:click=move |_| {
+                                    library.update(|l| l.remove(&id));
+                                    set_status.set(Some(format!("🗑️ deleted \"{}\"", delete)));
+                                }>"🗑️"</button>
+                            </td>
+                        </tr>
+                    }
+                }).collect_view()}
+            </table>
+        }.into_view()
+    };
+
+    view! {
+        <div class="audit-panel library-panel">
+            <h4>"📚 Scenario Library" <span class="demo-badge">"IndexedDB · in Run All"</span></h4>
+            <p class="section-desc">"Save your own attack payloads and fault injections with the outcome you expect. They run after the built-in attacks in Run All, and the last run is checked against your expectation."</p>
+            <div class="audit-controls">
+                <button class="action-btn" on:click=new_draft>"➕ New Scenario"</button>
+                <button class="action-btn" on:click=move |_| download_text("guardian-scenarios.json", "application/json", &library.with(ScenarioLibrary::to_json))>"⬇️ Export"</button>
+                <input type="file" accept=".json,application/json" on:change=pick />
+            </div>
+            {editor}
+            {list}
+            {move || status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+        </div>
+    }
+}
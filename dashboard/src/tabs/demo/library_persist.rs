@@ -0,0 +1,23 @@
+// what: indexeddb persistence of the user scenario library
+// why: scenarios are authored work; they must outlive reloads and resets like the raft state does
+// relations: serializes library.rs ScenarioLibrary through idb.rs, loaded and saved by library_panel.rs
+
+use wasm_bindgen::JsValue;
+use web_sys::IdbTransactionMode;
+use super::idb::run;
+use super::library::ScenarioLibrary;
+
+const DB_NAME: &str = "guardian-library";
+const STORE: &str = "scenarios";
+const KEY: &str = "library";
+
+/// the saved library; Ok(None) on first visit, Err if it can no longer be read
+pub async fn load() -> Result<Option<ScenarioLibrary>, String> {
+    let value = run(DB_NAME, STORE, IdbTransactionMode::Readonly, |store| store.get(&JsValue::from_str(KEY))).await?;
+    value.as_string().map(|json| ScenarioLibrary::from_json(&json)).transpose()
+}
+
+pub async fn save(library: &ScenarioLibrary) -> Result<(), String> {
+    let json = JsValue::from_str(&library.to_json());
+    run(DB_NAME, STORE, IdbTransactionMode::Readwrite, |store| store.put_with_key(&json, &JsValue::from_str(KEY))).await.map(|_| ())
+}
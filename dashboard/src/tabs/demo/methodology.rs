@@ -0,0 +1,138 @@
+// what: machine-generated methodology disclosure - the parameters this session actually used and which numbers are measured, simulated or imported
+// why: the "(real)" / "(simulated)" labels are scattered across tabs; exports need one systematic statement of how the numbers were made
+// relations: params gathered by methodology_panel.rs (settings, diagnostics probe, perf slice, handoff sessions);
+//            attached to summary.rs text, siem.rs audit exports, metrics.rs /metrics comments and snapshot.rs json
+
+use serde::{Deserialize, Serialize};
+use crate::diagnostics::probe::PINNED_PYODIDE;
+use crate::measure::stats::{OUTLIER_Z, WARMUP_RUNS};
+use crate::measure::timing::{method_for, window_count, Method};
+use super::handlers::{LEADER_RESTART_MS, MIN_RESTART_MS, RESTART_JITTER_MS};
+use super::summary::SessionNumbers;
+
+/// where a disclosed value comes from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Provenance {
+    /// timed or probed in this browser, this session
+    Measured,
+    /// generated by the demo (random values, modelled delays, seeded runs)
+    Simulated,
+    /// brought in from another device or host through the handoff import
+    Imported,
+    /// a knob or constant chosen by the demo or the viewer
+    Setting,
+}
+
+impl Provenance {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Provenance::Measured => "measured",
+            Provenance::Simulated => "simulated",
+            Provenance::Imported => "imported",
+            Provenance::Setting => "setting",
+        }
+    }
+}
+
+/// one line of the disclosure
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Disclosure {
+    pub label: String,
+    pub value: String,
+    pub provenance: Provenance,
+}
+
+/// what the session ran with; gathered from the app, pure from here on
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionParams {
+    /// benchmark iterations per measurement (settings)
+    pub iterations: u32,
+    /// observed performance.now() step
+    pub timer_resolution_ms: f64,
+    pub cross_origin_isolated: bool,
+    /// (pyodide, python) versions reported by the startup probe
+    pub pyodide: Option<(String, String)>,
+    pub numbers: SessionNumbers,
+    /// the plain-javascript baseline column is enabled
+    pub js_baseline: bool,
+    /// simulated raft failovers behind the election figures
+    pub election_trials: usize,
+    /// a container restart baseline has been imported
+    pub container_imported: bool,
+}
+
+fn line(label: &str, value: String, provenance: Provenance) -> Disclosure {
+    Disclosure { label: label.to_string(), value, provenance }
+}
+
+/// measured timing, or a plain "not measured" setting line
+fn timing(label: &str, value: Option<String>) -> Disclosure {
+    match value {
+        Some(v) => line(label, v, Provenance::Measured),
+        None => line(label, "not measured this session".to_string(), Provenance::Setting),
+    }
+}
+
+/// the disclosure list, in a fixed order
+pub fn disclosures(p: &SessionParams) -> Vec<Disclosure> {
+    let n = &p.numbers;
+    let method = match method_for(p.timer_resolution_ms) {
+        Method::PerIteration => format!("each of {} iterations timed individually", p.iterations),
+        Method::WindowCount => format!("{} iterations counted in {} fixed windows (coarse timer)", p.iterations, window_count(p.iterations)),
+    };
+    let pyodide = match &p.pyodide {
+        Some((version, python)) if version == PINNED_PYODIDE => format!("{} (Python {})", version, python),
+        Some((version, python)) => format!("{} (Python {}), differs from pinned {}", version, python, PINNED_PYODIDE),
+        None => format!("not loaded yet (pinned {})", PINNED_PYODIDE),
+    };
+    let restart_base = match n.pyodide_load_ms {
+        Some(ms) => format!("measured Pyodide cold start ({:.0}ms)", ms),
+        None => format!("{}ms fallback", LEADER_RESTART_MS),
+    };
+    vec![
+        line("Timing method", method, Provenance::Setting),
+        line("Warm-up and outliers", format!("{} warm-up runs discarded; samples with modified z-score > {} rejected", WARMUP_RUNS, OUTLIER_Z), Provenance::Setting),
+        line(
+            "Timer resolution",
+            format!("{:.3}ms, cross-origin isolated: {}", p.timer_resolution_ms, if p.cross_origin_isolated { "yes" } else { "no" }),
+            Provenance::Measured,
+        ),
+        line("Pyodide", pyodide, if p.pyodide.is_some() { Provenance::Measured } else { Provenance::Setting }),
+        timing("WASM instantiate", n.wasm_instantiate.map(|s| s.display(3))),
+        timing("Pyodide cold start", n.pyodide_load_ms.map(|ms| format!("{:.0}ms", ms))),
+        timing("Sensor run (WASM)", n.wasm_exec.map(|s| s.display(3))),
+        timing("Sensor run (Python)", n.python_exec.map(|s| s.display(2))),
+        if p.js_baseline {
+            timing("Sensor run (JavaScript)", n.js_exec.map(|s| s.display(3)))
+        } else {
+            line("Sensor run (JavaScript)", "baseline column off".to_string(), Provenance::Setting)
+        },
+        line(
+            "Python restart delay",
+            format!("{} ± {}ms uniform random jitter, floored at {}ms", restart_base, RESTART_JITTER_MS, MIN_RESTART_MS),
+            Provenance::Simulated,
+        ),
+        line("Raft failover", format!("{} seeded simulated elections", p.election_trials), Provenance::Simulated),
+        line("Sensor readings", "random BME280-range values; no physical sensor attached".to_string(), Provenance::Simulated),
+        if p.container_imported {
+            line("Container restart", "docker restart timings imported from a real host".to_string(), Provenance::Imported)
+        } else {
+            line("Container restart", "no baseline imported; the Problem tab's range is a claim".to_string(), Provenance::Setting)
+        },
+    ]
+}
+
+/// "label: value [provenance]" lines, each behind `prefix`
+pub fn to_lines(list: &[Disclosure], prefix: &str) -> String {
+    list.iter().map(|d| format!("{}{}: {} [{}]\n", prefix, d.label, d.value, d.provenance.label())).collect()
+}
+
+/// markdown table
+pub fn to_markdown(list: &[Disclosure]) -> String {
+    let mut out = String::from("| Parameter | Value | Source |\n|---|---|---|\n");
+    for d in list {
+        out.push_str(&format!("| {} | {} | {} |\n", d.label, d.value, d.provenance.label()));
+    }
+    out
+}
@@ -0,0 +1,79 @@
+// what: methodology panel - the session's disclosure list with provenance badges, and the source every export pulls it from
+// why: one generated statement of iterations, timer, pyodide build and simulated vs measured values, instead of per-label hints
+// relations: disclosure built by methodology.rs; params from settings, diagnostics, the perf slice and handoff sessions;
+//            use_methodology() handed by component.rs to audit_panel.rs, metrics_panel.rs and snapshot_panel.rs
+
+use leptos::*;
+use crate::diagnostics::use_diagnostics;
+use crate::handoff::{container_baseline, load_sessions};
+use crate::measure::timing::{cross_origin_isolated, timer_resolution_ms};
+use crate::settings::use_settings;
+use super::methodology::{disclosures, Disclosure, Provenance, SessionParams};
+use super::store::PerfSlice;
+
+/// builds the disclosure on demand; created once per demo tab so exports can call it from event handlers
+pub fn use_methodology(perf: PerfSlice) -> Callback<(), Vec<Disclosure>> {
+    let settings = use_settings();
+    let diagnostics = use_diagnostics();
+    Callback::new(move |_| {
+        let (iterations, js_baseline) = settings.with_untracked(|s| (s.benchmark_iterations, s.js_baseline));
+        disclosures(&SessionParams {
+            iterations,
+            timer_resolution_ms: timer_resolution_ms(),
+            cross_origin_isolated: cross_origin_isolated(),
+            pyodide: diagnostics.with_untracked(|d| d.probe().map(|p| (p.version.clone(), p.python.clone()))),
+            numbers: perf.session_numbers(),
+            js_baseline,
+            election_trials: perf.election.trials,
+            container_imported: container_baseline(&load_sessions()).is_some(),
+        })
+    })
+}
+
+fn badge_class(p: Provenance) -> &'static str {
+    match p {
+        Provenance::Measured => "provenance-badge measured",
+        Provenance::Simulated => "provenance-badge simulated",
+        Provenance::Imported => "provenance-badge imported",
+        Provenance::Setting => "provenance-badge setting",
+    }
+}
+
+/// collapsible disclosure list; refreshed on open and on demand
+#[component]
+pub fn MethodologyPanel(methodology: Callback<(), Vec<Disclosure>>) -> impl IntoView {
+    let (open, set_open) = create_signal(false);
+    let (list, set_list) = create_signal(Vec::<Disclosure>::new());
+    let refresh = move || set_list.set(methodology.call(()));
+
+    view! {
+        <div class="audit-panel methodology-panel">
+            <h4>
+                "🧾 Methodology"
+                <span class="demo-badge">"attached to every export"</span>
+                <button class="info-btn" on:click=move |_| {
+                    if !open.get_untracked() { refresh(); }
+                    set_open.update(|v| *v = !*v);
+                }>
+                    {move || if open.get() { "▲" } else { "▼" }}
+                </button>
+            </h4>
+            <Show when=move || open.get()>
+                <table class="capability-matrix methodology-table">
+                    <tr><th>"Parameter"</th><th>"Value"</th><th>"Source"</th></tr>
+                    {move || list.get().into_iter().map(|d| view! {
+                        <tr>
+                            <td>{d.label}</td>
+                            <td>{d.value}</td>
+                            <td><span class=badge_class(d.provenance)>{d.provenance.label()}</span></td>
+                        </tr>
+                    }).collect_view()}
+                </table>
+                <div class="audit-controls">
+                    <button class="action-btn" on:click=move |_| refresh()>"🔄 Refresh"</button>
+                </div>
+                <p class="metrics-note">"Generated from the parameters this session used. Audit logs, /metrics, snapshots and summaries carry the same list."</p>
+            </Show>
+        </div>
+    }
+}
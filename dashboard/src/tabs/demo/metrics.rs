@@ -0,0 +1,85 @@
+// what: prometheus text exposition of session metrics
+// why: observability engineers can scrape/push demo numbers into the grafana stack from the hardware tab
+// relations: reads counters from component.rs and recovery and election durations from journal.rs, rendered by metrics_panel.rs
+
+use super::journal::{AuditEvent, EventKind, Runtime};
+
+/// recovery and election histogram bucket upper bounds in ms
+pub const RECOVERY_BUCKETS_MS: &[f64] = &[0.1, 1.0, 10.0, 100.0, 500.0, 1000.0, 2000.0, 5000.0];
+
+/// cumulative bucket counts, sum and count
+pub type Histogram = (Vec<u64>, f64, u64);
+
+/// counters sampled from the demo at render time
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    pub python_processed: u32,
+    pub python_crashed: u32,
+    pub python_downtime_ms: u64,
+    pub wasm_processed: u32,
+    pub wasm_rejected: u32,
+}
+
+/// one event kind's durations for one runtime
+fn duration_histogram(events: &[AuditEvent], kind: EventKind, runtime: Runtime) -> Histogram {
+    let samples: Vec<f64> = events
+        .iter()
+        .filter(|e| e.kind == kind && e.runtime == Some(runtime))
+        .filter_map(|e| e.duration_ms)
+        .collect();
+    let buckets = RECOVERY_BUCKETS_MS
+        .iter()
+        .map(|le| samples.iter().filter(|s| **s <= *le).count() as u64)
+        .collect();
+    (buckets, samples.iter().sum(), samples.len() as u64)
+}
+
+/// histogram of one runtime's recoveries (restarts and rebuilt instances)
+pub fn recovery_histogram(events: &[AuditEvent], runtime: Runtime) -> Histogram {
+    duration_histogram(events, EventKind::Recovery, runtime)
+}
+
+/// histogram of one runtime's leader elections, kept apart so failovers don't skew recovery quantiles
+pub fn election_histogram(events: &[AuditEvent], runtime: Runtime) -> Histogram {
+    duration_histogram(events, EventKind::Election, runtime)
+}
+
+/// appends one histogram family with a series per runtime
+fn push_histogram(out: &mut String, name: &str, help: &str, histogram: fn(&[AuditEvent], Runtime) -> Histogram, events: &[AuditEvent]) {
+    out.push_str(&format!("# HELP {} {}\n", name, help));
+    out.push_str(&format!("# TYPE {} histogram\n", name));
+    for runtime in [Runtime::Python, Runtime::Wasm] {
+        let (buckets, sum, count) = histogram(events, runtime);
+        for (le, n) in RECOVERY_BUCKETS_MS.iter().zip(buckets) {
+            out.push_str(&format!("{}_bucket{{runtime=\"{}\",le=\"{}\"}} {}\n", name, runtime.as_str(), le, n));
+        }
+        out.push_str(&format!("{}_bucket{{runtime=\"{}\",le=\"+Inf\"}} {}\n", name, runtime.as_str(), count));
+        out.push_str(&format!("{}_sum{{runtime=\"{}\"}} {}\n", name, runtime.as_str(), sum));
+        out.push_str(&format!("{}_count{{runtime=\"{}\"}} {}\n", name, runtime.as_str(), count));
+    }
+}
+
+/// renders the /metrics body in prometheus text format 0.0.4
+pub fn render(snapshot: &MetricsSnapshot, events: &[AuditEvent]) -> String {
+    let mut out = String::new();
+
+    out.push_str("# HELP guardian_packets_processed_total Telemetry packets processed by each runtime.\n");
+    out.push_str("# TYPE guardian_packets_processed_total counter\n");
+    out.push_str(&format!("guardian_packets_processed_total{{runtime=\"python\"}} {}\n", snapshot.python_processed));
+    out.push_str(&format!("guardian_packets_processed_total{{runtime=\"wasm\"}} {}\n", snapshot.wasm_processed));
+
+    out.push_str("# HELP guardian_crashes_total Worker crashes (python) and voted-out traps (wasm).\n");
+    out.push_str("# TYPE guardian_crashes_total counter\n");
+    out.push_str(&format!("guardian_crashes_total{{runtime=\"python\"}} {}\n", snapshot.python_crashed));
+    out.push_str(&format!("guardian_crashes_total{{runtime=\"wasm\"}} {}\n", snapshot.wasm_rejected));
+
+    out.push_str("# HELP guardian_downtime_ms_total Accumulated service downtime in milliseconds.\n");
+    out.push_str("# TYPE guardian_downtime_ms_total counter\n");
+    out.push_str(&format!("guardian_downtime_ms_total{{runtime=\"python\"}} {}\n", snapshot.python_downtime_ms));
+    out.push_str("guardian_downtime_ms_total{runtime=\"wasm\"} 0\n");
+
+    push_histogram(&mut out, "guardian_recovery_ms", "Time from fault to restored service in milliseconds.", recovery_histogram, events);
+    push_histogram(&mut out, "guardian_election_ms", "Time from leader loss to a new elected leader in milliseconds.", election_histogram, events);
+
+    out
+}
@@ -0,0 +1,41 @@
+// what: cold-start metrics banner (wasm instantiate vs pyodide load)
+// why: headline numbers for the demo, split out of component.rs
+// relations: reads store.rs PerfSlice, rendered by component.rs
+
+use leptos::*;
+use super::store::PerfSlice;
+
+#[component]
+pub fn MetricsBanner(perf: PerfSlice) -> impl IntoView {
+    view! {
+        <div class="metrics-banner">
+            <div class="metric-item" title=move || format!("Measured using WebAssembly API, {}", perf.wasm_instantiate_ms.get().detail())>
+                <span class="metric-label">"WASM Instantiate (real)"</span>
+                <span class="metric-value">{move || perf.wasm_instantiate_ms.get().display(3)}</span>
+            </div>
+            <div class="metric-item" title="Real Pyodide cold-start time measured at page load (single sample, rerun on the Proof tab for a CI)">
+                <span class="metric-label">"Python Cold-Start (real)"</span>
+                <span class="metric-value warning">{move || {
+                    let ms = perf.pyodide_load_ms.get();
+                    if ms > 0.0 {
+                        format!("{:.0}ms", ms)
+                    } else {
+                        "Loading...".to_string()
+                    }
+                }}</span>
+            </div>
+            <div class="metric-item speedup">
+                <span class="metric-label">"Speedup"</span>
+                <span class="metric-value">{move || {
+                    let wasm = perf.wasm_instantiate_ms.get().median;
+                    let python = perf.pyodide_load_ms.get();
+                    if wasm > 0.0 && python > 0.0 {
+                        format!("{:.0}x faster", python / wasm)
+                    } else {
+                        "—".to_string()
+                    }
+                }}</span>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,66 @@
+// what: "/metrics" panel with copy and pushgateway push
+// why: lets the demo be wired into prometheus/grafana like the l3 historian stack
+// relations: used by component.rs, renders metrics.rs with the methodology.rs disclosure as leading comments, uses wasm.rs helpers
+
+use leptos::*;
+use super::journal::AuditEvent;
+use super::metrics::{render, MetricsSnapshot};
+use super::methodology::{to_lines, Disclosure};
+use super::wasm::{copy_to_clipboard, post_text};
+use crate::settings::use_settings;
+
+/// pushgateway job name used in the push path
+const PUSH_JOB: &str = "guardian_demo";
+
+/// prometheus exposition view of the current session
+#[component]
+pub fn MetricsPanel(snapshot: Signal<MetricsSnapshot>, journal: RwSignal<Vec<AuditEvent>>, methodology: Callback<(), Vec<Disclosure>>) -> impl IntoView {
+    let (open, set_open) = create_signal(false);
+    let (gateway_url, set_gateway_url) = create_signal(use_settings().with_untracked(|s| s.endpoints.pushgateway.clone()));
+    let (push_status, set_push_status) = create_signal(Option::<String>::None);
+
+    // plain # lines are comments in the exposition format, so scrapers skip the disclosure
+    let body = move || format!("{}{}", to_lines(&methodology.call(()), "# methodology "), render(&snapshot.get(), &journal.get()));
+
+    let push = move |_| {
+        let base = gateway_url.get();
+        if base.is_empty() { return; }
+        let url = format!("{}/metrics/job/{}", base.trim_end_matches('/'), PUSH_JOB);
+        let text = body();
+        set_push_status.set(Some("⏳ Pushing...".to_string()));
+        spawn_local(async move {
+            let status = match post_text(&url, "text/plain; version=0.0.4", &text).await {
+                Ok(code) => format!("✅ Pushgateway responded HTTP {}", code),
+                Err(e) => format!("❌ {}", e),
+            };
+            set_push_status.set(Some(status));
+        });
+    };
+
+    view! {
+        <div class="audit-panel metrics-panel">
+            <h4>
+                "📈 GET /metrics"
+                <span class="demo-badge">"Prometheus 0.0.4"</span>
+                <button class="info-btn" on:click=move |_| set_open.update(|v| *v = !*v)>
+                    {move || if open.get() { "▲" } else { "▼" }}
+                </button>
+            </h4>
+            <Show when=move || open.get()>
+                <pre class="audit-preview metrics-body">{body}</pre>
+                <div class="audit-controls">
+                    <button class="action-btn" on:click=move |_| copy_to_clipboard(&body())>"📋 Copy /metrics"</button>
+                    <input
+                        class="collector-url"
+                        type="url"
+                        placeholder="http://pushgateway:9091 (optional)"
+                        prop:value=move || gateway_url.get()
+                        on:input=move |ev| set_gateway_url.set(event_target_value(&ev))
+                    />
+                    <button class="action-btn" disabled=move || gateway_url.get().is_empty() on:click=push>"📡 Push"</button>
+                </div>
+                {move || push_status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+            </Show>
+        </div>
+    }
+}
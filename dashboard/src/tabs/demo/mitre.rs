@@ -0,0 +1,159 @@
+// what: mitre att&ck for ics catalog of the techniques the demo scenarios exercise, and which of them the capability model mitigates
+// why: security reviewers think in technique ids; mapping each scenario onto the taxonomy shows the coverage and the gaps in their terms
+// relations: technique ids on each AttackConfig in attacks.rs, live verdicts from policy.rs, rendered by mitre_panel.rs
+
+use super::attacks::{get_attack_config, AVAILABILITY_ATTACKS, SECURITY_ATTACKS};
+use super::policy::{attack_request, CapabilityPolicy, WORKER_WORLD};
+
+/// technique pages live under this path
+pub const TECHNIQUE_URL: &str = "https://attack.mitre.org/techniques/";
+
+/// att&ck for ics tactics the catalog uses, in matrix order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Tactic {
+    InitialAccess,
+    Persistence,
+    Discovery,
+    LateralMovement,
+    Collection,
+    CommandAndControl,
+    InhibitResponseFunction,
+    Impact,
+}
+
+impl Tactic {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Tactic::InitialAccess => "Initial Access",
+            Tactic::Persistence => "Persistence",
+            Tactic::Discovery => "Discovery",
+            Tactic::LateralMovement => "Lateral Movement",
+            Tactic::Collection => "Collection",
+            Tactic::CommandAndControl => "Command and Control",
+            Tactic::InhibitResponseFunction => "Inhibit Response Function",
+            Tactic::Impact => "Impact",
+        }
+    }
+}
+
+/// one catalog entry
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Technique {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub tactics: &'static [Tactic],
+}
+
+impl Technique {
+    pub fn url(&self) -> String {
+        format!("{}{}/", TECHNIQUE_URL, self.id)
+    }
+}
+
+/// techniques referenced by the scenarios, ordered by first tactic
+pub const TECHNIQUES: [Technique; 11] = [
+    Technique { id: "T0866", name: "Exploitation of Remote Services", tactics: &[Tactic::InitialAccess, Tactic::LateralMovement] },
+    Technique { id: "T0886", name: "Remote Services", tactics: &[Tactic::InitialAccess, Tactic::LateralMovement] },
+    Technique { id: "T0891", name: "Hardcoded Credentials", tactics: &[Tactic::Persistence, Tactic::LateralMovement] },
+    Technique { id: "T0846", name: "Remote System Discovery", tactics: &[Tactic::Discovery] },
+    Technique { id: "T0893", name: "Data from Local System", tactics: &[Tactic::Collection] },
+    Technique { id: "T0869", name: "Standard Application Layer Protocol", tactics: &[Tactic::CommandAndControl] },
+    Technique { id: "T0814", name: "Denial of Service", tactics: &[Tactic::InhibitResponseFunction] },
+    Technique { id: "T0816", name: "Device Restart/Shutdown", tactics: &[Tactic::InhibitResponseFunction] },
+    Technique { id: "T0804", name: "Block Reporting Message", tactics: &[Tactic::InhibitResponseFunction] },
+    Technique { id: "T0882", name: "Theft of Operational Information", tactics: &[Tactic::Impact] },
+    Technique { id: "T0826", name: "Loss of Availability", tactics: &[Tactic::Impact] },
+];
+
+pub fn technique(id: &str) -> Option<&'static Technique> {
+    TECHNIQUES.iter().find(|t| t.id == id)
+}
+
+/// what stops a scenario on the wasm side, best first
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Mitigation {
+    /// the policy does not grant the capability the attack needs
+    Capability,
+    /// the engine's memory / stack limit traps it
+    EngineLimit,
+    /// the typed parser rejects the input
+    Validation,
+    /// not prevented; raft failover restores service
+    Failover,
+    /// the active policy grants the capability: nothing stops it
+    Open,
+}
+
+impl Mitigation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Mitigation::Capability => "denied by capability",
+            Mitigation::EngineLimit => "engine limit trap",
+            Mitigation::Validation => "parser validation",
+            Mitigation::Failover => "failover only",
+            Mitigation::Open => "granted by policy",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Mitigation::Capability => "🛡️",
+            Mitigation::EngineLimit => "⛔",
+            Mitigation::Validation => "✔️",
+            Mitigation::Failover => "🔁",
+            Mitigation::Open => "🔓",
+        }
+    }
+
+    /// the attack never gets what it wanted
+    pub fn prevents(&self) -> bool {
+        *self <= Mitigation::Validation
+    }
+}
+
+/// scenarios in the order the buttons show them
+pub fn scenarios() -> Vec<&'static str> {
+    SECURITY_ATTACKS.into_iter().chain(AVAILABILITY_ATTACKS).collect()
+}
+
+/// how the wasm side handles `attack` under `policy`
+pub fn mitigation(attack: &str, policy: &CapabilityPolicy) -> Mitigation {
+    if let Some(request) = attack_request(attack) {
+        return if policy.evaluate(WORKER_WORLD, &request).is_ok() { Mitigation::Open } else { Mitigation::Capability };
+    }
+    match attack {
+        "stackExhaustion" => Mitigation::EngineLimit,
+        "lengthOverflow" => Mitigation::Validation,
+        _ => Mitigation::Failover,
+    }
+}
+
+/// one matrix row: a technique, its mitigation in each scenario that uses it, and the weakest of those
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoverageRow {
+    pub technique: &'static Technique,
+    /// one cell per scenarios() entry; None where the scenario does not use the technique
+    pub cells: Vec<Option<Mitigation>>,
+    pub weakest: Mitigation,
+}
+
+/// the technique x scenario matrix under `policy`
+pub fn coverage(policy: &CapabilityPolicy) -> Vec<CoverageRow> {
+    let scenarios = scenarios();
+    TECHNIQUES
+        .iter()
+        .filter_map(|technique| {
+            let cells: Vec<Option<Mitigation>> = scenarios
+                .iter()
+                .map(|attack| get_attack_config(attack).techniques.contains(&technique.id).then(|| mitigation(attack, policy)))
+                .collect();
+            let weakest = cells.iter().flatten().max().copied()?;
+            Some(CoverageRow { technique, cells, weakest })
+        })
+        .collect()
+}
+
+/// (prevented, total) techniques in a matrix
+pub fn prevented(rows: &[CoverageRow]) -> (usize, usize) {
+    (rows.iter().filter(|r| r.weakest.prevents()).count(), rows.len())
+}
@@ -0,0 +1,85 @@
+// what: att&ck for ics panel - technique and tactic badges per scenario, and the technique x scenario coverage matrix
+// why: puts the demo's claims on the taxonomy reviewers use, including the techniques it only recovers from
+// relations: catalog and coverage from mitre.rs, live policy from the store (policy editor changes show up here), rendered by component.rs
+
+use leptos::*;
+use super::attacks::get_attack_config;
+use super::mitre::{coverage, mitigation, prevented, scenarios, technique, Mitigation};
+use super::store::DemoStore;
+
+/// linked technique id badge
+fn technique_badge(id: &'static str) -> impl IntoView {
+    match technique(id) {
+        Some(t) => view! { <a class="mitre-badge" href=t.url() target="_blank" rel="noopener" title=t.name>{t.id}</a> }.into_view(),
+        None => view! { <span class="mitre-badge">{id}</span> }.into_view(),
+    }
+}
+
+#[component]
+pub fn MitrePanel(store: DemoStore) -> impl IntoView {
+    let policy = store.policy;
+    let rows = Memo::new(move |_| policy.with(coverage));
+
+    let scenario_list = move || policy.with(|p| scenarios().into_iter().map(|attack| {
+        let config = get_attack_config(attack);
+        let mut tactics: Vec<&'static str> = config.techniques.iter().filter_map(|id| technique(id)).flat_map(|t| t.tactics.iter().map(|tactic| tactic.label())).collect();
+        tactics.dedup();
+        let m = mitigation(attack, p);
+        view! {
+            <tr>
+                <td class="world-name">{config.name}</td>
+                <td>{config.techniques.iter().map(|id| technique_badge(id)).collect_view()}</td>
+                <td>{tactics.into_iter().map(|t| view! { <span class="mitre-tactic">{t}</span> }).collect_view()}</td>
+                <td class:granted=m.prevents() class:vote-halted=m == Mitigation::Open>{format!("{} {}", m.icon(), m.label())}</td>
+            </tr>
+        }
+    }).collect_view());
+
+    let matrix = move || rows.with(|rows| view! {
+        <table class="capability-matrix mitre-matrix">
+            <tr>
+                <th>"Technique"</th>
+                <th>"Tactic"</th>
+                {scenarios().into_iter().map(|attack| view! { <th class="mitre-scenario">{get_attack_config(attack).name}</th> }).collect_view()}
+                <th>"Coverage"</th>
+            </tr>
+            {rows.iter().map(|row| view! {
+                <tr>
+                    <td>{technique_badge(row.technique.id)}" "{row.technique.name}</td>
+                    <td>{row.technique.tactics.iter().map(|t| t.label()).collect::<Vec<_>>().join(", ")}</td>
+                    {row.cells.iter().map(|cell| view! {
+                        <td class="mitre-cell" title=cell.map(|m| m.label()).unwrap_or_default()>{cell.map(|m| m.icon()).unwrap_or("")}</td>
+                    }).collect_view()}
+                    <td class:granted=row.weakest.prevents() class:vote-halted=!row.weakest.prevents()>
+                        {if row.weakest.prevents() { "prevented" } else { row.weakest.label() }}
+                    </td>
+                </tr>
+            }).collect_view()}
+        </table>
+    });
+
+    view! {
+        <div class="demo-section mitre-section">
+            <h3>
+                "🎯 MITRE ATT&CK for ICS"
+                <span class="attack-badge">{move || rows.with(|r| {
+                    let (done, total) = prevented(r);
+                    format!("{}/{} techniques prevented", done, total)
+                })}</span>
+            </h3>
+            <p class="section-desc">"Each scenario mapped onto ATT&CK for ICS. Coverage follows the active capability policy: grant a capability in the editor above and its techniques turn open. Availability techniques are only recovered from (Raft failover), not prevented."</p>
+            <table class="capability-matrix voting-matrix">
+                <tr><th>"Scenario"</th><th>"Techniques"</th><th>"Tactics"</th><th>"WASM side"</th></tr>
+                {scenario_list}
+            </table>
+            <details class="mitre-details">
+                <summary>"Coverage matrix"</summary>
+                {matrix}
+                <p class="metrics-note">
+                    {[Mitigation::Capability, Mitigation::EngineLimit, Mitigation::Validation, Mitigation::Failover, Mitigation::Open]
+                        .map(|m| format!("{} {}", m.icon(), m.label())).join(" · ")}
+                </p>
+            </details>
+        </div>
+    }
+}
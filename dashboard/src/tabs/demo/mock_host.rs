@@ -0,0 +1,58 @@
+// what: browser-side mock host for the attack-surface imports - every call is logged and answered with a capability denial
+// why: the device's host never provides these imports; the browser simulates that with functions generated from the contract itself
+// relations: functions generated by build.rs (wit_codegen.rs over wit/attacks.wit) into $OUT_DIR/mock_host.rs and include!d here;
+//            attacks.rs wit_func names resolve against FUNCTIONS; called by handlers.rs for capability attacks
+
+/// a wit func as declared in the contract
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WitSignature {
+    pub name: &'static str,
+    /// (name, wit type)
+    pub params: &'static [(&'static str, &'static str)],
+    pub result: Option<&'static str>,
+}
+
+impl WitSignature {
+    /// `name: func(a: t) -> r`, as written in the wit file
+    pub fn declaration(&self) -> String {
+        let params: Vec<String> = self.params.iter().map(|(n, t)| format!("{}: {}", n, t)).collect();
+        format!("{}: func({}){}", self.name, params.join(", "), self.result.map_or(String::new(), |r| format!(" -> {}", r)))
+    }
+}
+
+/// one call the guest made and the denial it got
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockCall {
+    pub func: &'static str,
+    /// "name: value" per argument
+    pub args: Vec<String>,
+    pub denial: String,
+}
+
+/// the call log of one simulated instance
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MockHost {
+    pub calls: Vec<MockCall>,
+}
+
+impl MockHost {
+    /// logs the call and returns the denial the guest sees
+    pub fn deny(&mut self, func: &'static str, args: Vec<String>) -> String {
+        let denial = denial(func);
+        self.calls.push(MockCall { func, args, denial: denial.clone() });
+        denial
+    }
+}
+
+/// what the host answers for any import it does not provide
+pub fn denial(func: &str) -> String {
+    format!("capability not imported: {}/{}", INTERFACE, func)
+}
+
+/// the declared func a config's `wit_func` ("open-socket()") names; None for attacks that never reach the wit boundary
+pub fn lookup(wit_func: &str) -> Option<&'static WitSignature> {
+    let name = wit_func.strip_suffix("()")?;
+    FUNCTIONS.iter().find(|f| f.name == name)
+}
+
+include!(concat!(env!("OUT_DIR"), "/mock_host.rs"));
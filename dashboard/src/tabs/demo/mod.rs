@@ -1,10 +1,103 @@
 // what: demo module re-exports
 // why: organizes demo tab into submodules for maintainability
-// relations: parent module for types.rs, attacks.rs, wasm.rs, component.rs
+// relations: parent module for types.rs, voting.rs (+ voting_panel.rs, drift.rs), suspicion.rs (+ suspicion_panel.rs), anomaly.rs (+ anomaly_panel.rs), environment.rs, fusion.rs (+ fusion_panel.rs), rate_limit.rs (+ pipeline.rs, flood.rs, flood_panel.rs), architecture.rs (+ architecture_panel.rs), attacks.rs (+ payloads.rs, mitre.rs, mitre_panel.rs, mock_host.rs generated via wit_codegen.rs), host_trace.rs (+ host_proxy.rs, host_trace_panel.rs), wasm.rs, modules.rs, probes.rs, budget.rs (+ instances.rs, soak.rs, soak_panel.rs), timing_channel.rs (+ timing_channel_panel.rs), raft.rs (+ raft_panel.rs, raft_persist.rs, election.rs, election_ring.rs), library.rs (+ library_panel.rs, library_persist.rs, idb.rs shared with raft_persist.rs), policy.rs, journal.rs (+ ledger.rs, ledger_panel.rs, timeline.rs, timeline_panel.rs), siem.rs, summary.rs (+ quiz.rs, quiz_panel.rs, methodology.rs, methodology_panel.rs), metrics.rs, influx.rs, modbus.rs (+ modbus_panel.rs, capture.rs, capture_panel.rs, pcap.rs, pcap_panel.rs), terminal.rs, store.rs (+ snapshot.rs, snapshot_panel.rs), handlers.rs (+ component_backend.rs), baseline.rs, node_strip.rs (+ node_history.rs, node_drawer.rs), runtime_model.rs, sensor_code.rs, component.rs and its child panels
 
 pub mod types;
+pub mod voting;
+pub mod drift;
+pub mod suspicion;
+pub mod anomaly;
+pub mod environment;
+pub mod fusion;
+mod fusion_panel;
+pub mod actuator;
+mod actuator_panel;
+mod anomaly_panel;
+pub mod rate_limit;
+pub mod pipeline;
+pub mod flood;
+mod flood_panel;
+pub mod architecture;
+mod architecture_panel;
+mod suspicion_panel;
+mod voting_panel;
 pub mod attacks;
+pub mod payloads;
+pub mod mock_host;
+pub mod host_trace;
+pub mod host_proxy;
+mod host_trace_panel;
+// build.rs runs it; compiled here only so tests can regenerate the mock host
+#[cfg(test)]
+pub mod wit_codegen;
 pub mod wasm;
+pub mod modules;
+pub mod probes;
+pub mod budget;
+pub mod instances;
+pub mod supervisor;
+pub mod prefork;
+mod supervisor_panel;
+pub mod resync;
+mod resync_panel;
+pub mod soak;
+mod soak_panel;
+pub mod timing_channel;
+mod timing_channel_panel;
+pub mod raft;
+pub mod idb;
+pub mod raft_persist;
+mod raft_panel;
+pub mod election;
+mod election_ring;
+pub mod library;
+pub mod library_persist;
+mod library_panel;
+pub mod policy;
+mod policy_panel;
+pub mod mitre;
+mod mitre_panel;
+pub mod journal;
+pub mod ledger;
+mod ledger_panel;
+pub mod siem;
+pub mod summary;
+pub mod methodology;
+mod methodology_panel;
+pub mod quiz;
+mod quiz_panel;
+mod audit_panel;
+pub mod metrics;
+mod metrics_panel;
+pub mod influx;
+mod influx_panel;
+pub mod modbus;
+mod modbus_panel;
+pub mod capture;
+mod capture_panel;
+pub mod pcap;
+mod pcap_panel;
+mod terminal;
+mod timeline;
+mod timeline_panel;
+pub mod store;
+pub mod snapshot;
+mod snapshot_panel;
+pub mod handlers;
+pub mod component_backend;
+mod baseline;
+pub mod sensor_code;
+mod metrics_banner;
+mod sensor_comparison;
+pub mod node_strip;
+mod led_strip;
+pub mod node_history;
+mod node_drawer;
+pub mod runtime_model;
+mod runtime_panel;
+mod stats_panel;
+mod attack_controls;
+mod info_box;
 mod component;
 
 #[cfg(test)]
@@ -12,3 +105,4 @@ mod tests;
 
 // Re-export the Demo component for use by parent module
 pub use component::Demo;
+pub use host_trace_panel::HostTracePanel;
@@ -0,0 +1,147 @@
+// what: the simulated s7-1200 modbus slave the gateway writes telemetry to, plus the console's canned requests
+// why: the demo showed "HR40005 = 423 → S7-1200" without a device on the other end; now every write and read is a real exchange
+// relations: slave from wasm-modules/modbus-sim, held in store.rs, written by handlers.rs, queried by modbus_panel.rs;
+//            console writes pass modbus_parser::diode like the hardware/diode.rs console
+
+use modbus_parser::diode::{enforce_southbound, DiodeRejection};
+use modbus_parser::register_map::{encode_packet, TelemetryPacket, HOLDING_REGISTER_BASE};
+use modbus_sim::{
+    decode_reply, request, write_registers_request, Reply, Slave, FC_READ_COILS, FC_READ_HOLDING_REGISTERS,
+    FC_READ_INPUT_REGISTERS, FC_WRITE_MULTIPLE_REGISTERS, FC_WRITE_SINGLE_COIL, FC_WRITE_SINGLE_REGISTER,
+};
+
+/// rs485 unit id of the plc (same as the diode console)
+pub const PLC_UNIT_ID: u8 = 1;
+/// register table sizes of the simulated plc
+pub const PLC_COILS: usize = 8;
+pub const PLC_HOLDING_REGISTERS: usize = 16;
+pub const PLC_INPUT_REGISTERS: usize = 8;
+/// 3xxxx display base of input registers
+pub const INPUT_REGISTER_BASE: u32 = 30001;
+/// exchange label of the gateway's telemetry write
+pub const GATEWAY_WRITE: &str = "Gateway telemetry write";
+/// function code the slave does not implement (diagnostics)
+pub const FC_DIAGNOSTICS: u8 = 0x08;
+
+/// the slave as it boots: tables zeroed
+pub fn plc_slave() -> Slave {
+    Slave::new(PLC_UNIT_ID, PLC_COILS, PLC_HOLDING_REGISTERS, PLC_INPUT_REGISTERS)
+}
+
+/// display address of a zero-based offset for a function code
+pub fn display_address(function_code: u8, offset: u16) -> u32 {
+    match function_code {
+        FC_READ_COILS | FC_WRITE_SINGLE_COIL => offset as u32 + 1,
+        FC_READ_INPUT_REGISTERS => INPUT_REGISTER_BASE + offset as u32,
+        _ => HOLDING_REGISTER_BASE + offset as u32,
+    }
+}
+
+/// formats a frame as space-separated hex bytes
+pub fn hex(frame: &[u8]) -> String {
+    frame.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" ")
+}
+
+/// gateway step: write the voted packet's register block with fc16; the ack is the register count
+pub fn write_telemetry(slave: &mut Slave, packet: &TelemetryPacket) -> (Exchange, Result<u16, String>) {
+    let regs = encode_packet(packet);
+    let request = write_registers_request(PLC_UNIT_ID, 0, &regs);
+    let response = slave.respond_rtu(&request);
+    let ack = match response.as_deref().map(|r| decode_reply(r, regs.len() as u16)) {
+        None => Err("no reply from slave".to_string()),
+        Some(Err(e)) => Err(e.to_string()),
+        Some(Ok(Reply::Written { value, .. })) => Ok(value),
+        Some(Ok(Reply::Exception(e))) => Err(format!("exception 0x{:02X} {}", e.code(), e.name())),
+        Some(Ok(other)) => Err(format!("unexpected reply {:?}", other)),
+    };
+    let (summary, level) = match &ack {
+        Ok(count) => (format!("ack {}.. ← {} registers", HOLDING_REGISTER_BASE, count), "success"),
+        Err(reason) => (reason.clone(), "warn"),
+    };
+    (Exchange { label: GATEWAY_WRITE, request, response, summary, level }, ack)
+}
+
+/// function codes that change plc state; from above zone 2 they must match the southbound whitelist
+const WRITE_FUNCTIONS: [u8; 3] = [FC_WRITE_SINGLE_COIL, FC_WRITE_SINGLE_REGISTER, FC_WRITE_MULTIPLE_REGISTERS];
+
+/// gateway step for console traffic: writes go through the data diode, reads are forwarded as polls
+pub fn forward(slave: &mut Slave, request: &[u8]) -> Result<Option<Vec<u8>>, DiodeRejection> {
+    if request.get(1).is_some_and(|fc| WRITE_FUNCTIONS.contains(fc)) {
+        enforce_southbound(request)?;
+    }
+    Ok(slave.respond_rtu(request))
+}
+
+/// a request the console can send
+pub struct ConsoleRequest {
+    pub label: &'static str,
+    pub function_code: u8,
+    pub address: u16,
+    /// item count for reads, value for single writes
+    pub quantity: u16,
+    /// flip a crc bit before sending (the slave must stay silent)
+    pub corrupt_crc: bool,
+}
+
+pub const CONSOLE_REQUESTS: &[ConsoleRequest] = &[
+    ConsoleRequest { label: "Read telemetry block", function_code: FC_READ_HOLDING_REGISTERS, address: 0, quantity: 6, corrupt_crc: false },
+    ConsoleRequest { label: "Read BME280 inputs", function_code: FC_READ_INPUT_REGISTERS, address: 0, quantity: 3, corrupt_crc: false },
+    ConsoleRequest { label: "Read coils", function_code: FC_READ_COILS, address: 0, quantity: 8, corrupt_crc: false },
+    ConsoleRequest { label: "Fan coil ON", function_code: FC_WRITE_SINGLE_COIL, address: 0, quantity: 0xFF00, corrupt_crc: false },
+    ConsoleRequest { label: "Fan setpoint 60%", function_code: FC_WRITE_SINGLE_REGISTER, address: 10, quantity: 60, corrupt_crc: false },
+    ConsoleRequest { label: "Diagnostics (unsupported)", function_code: FC_DIAGNOSTICS, address: 0, quantity: 0, corrupt_crc: false },
+    ConsoleRequest { label: "Read HR40200", function_code: FC_READ_HOLDING_REGISTERS, address: 199, quantity: 2, corrupt_crc: false },
+    ConsoleRequest { label: "Read 200 registers", function_code: FC_READ_HOLDING_REGISTERS, address: 0, quantity: 200, corrupt_crc: false },
+    ConsoleRequest { label: "Corrupted CRC", function_code: FC_READ_HOLDING_REGISTERS, address: 0, quantity: 6, corrupt_crc: true },
+];
+
+/// one request/response cycle as the console shows it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Exchange {
+    pub label: &'static str,
+    pub request: Vec<u8>,
+    pub response: Option<Vec<u8>>,
+    /// decoded reply (or why there was none)
+    pub summary: String,
+    /// terminal line level
+    pub level: &'static str,
+}
+
+impl ConsoleRequest {
+    /// the rtu frame on the wire
+    pub fn frame(&self) -> Vec<u8> {
+        let mut frame = request(PLC_UNIT_ID, self.function_code, self.address, self.quantity);
+        if self.corrupt_crc {
+            *frame.last_mut().unwrap() ^= 0x01;
+        }
+        frame
+    }
+
+    /// send to the slave through the gateway and decode what comes back
+    pub fn send(&self, slave: &mut Slave) -> Exchange {
+        let request = self.frame();
+        let response = match forward(slave, &request) {
+            Ok(response) => response,
+            Err(rejection) => {
+                let summary = format!("dropped by the data diode: {}", rejection.reason());
+                return Exchange { label: self.label, request, response: None, summary, level: "error" };
+            }
+        };
+        let start = display_address(self.function_code, self.address);
+        let (summary, level) = match response.as_deref().map(|r| decode_reply(r, self.quantity)) {
+            None => ("no reply - slave discards frames with a bad crc".to_string(), "warn"),
+            Some(Err(e)) => (format!("undecodable reply: {}", e), "error"),
+            Some(Ok(Reply::Exception(e))) => (format!("exception 0x{:02X} {}", e.code(), e.name()), "warn"),
+            Some(Ok(Reply::Coils(bits))) => {
+                let bits: String = bits.iter().map(|on| if *on { '1' } else { '0' }).collect();
+                (format!("coils {:05}.. = {}", start, bits), "success")
+            }
+            Some(Ok(Reply::Registers(regs))) => (format!("{}.. = {:?}", start, regs), "success"),
+            Some(Ok(Reply::Written { address, value })) => {
+                (format!("ack {} ← {}", display_address(self.function_code, address), if self.function_code == FC_WRITE_SINGLE_COIL { format!("0x{:04X}", value) } else { value.to_string() }), "success")
+            }
+        };
+        Exchange { label: self.label, request, response, summary, level }
+    }
+}
+
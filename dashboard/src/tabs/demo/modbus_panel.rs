@@ -0,0 +1,64 @@
+// what: console sending canned modbus rtu requests to the simulated plc, with the holding register table
+// why: shows full request/response cycles and each exception reply next to the telemetry the gateway wrote
+// relations: used by component.rs, requests and slave layout from modbus.rs, slave held in store.rs, frames go to capture.rs
+
+use leptos::*;
+use super::modbus::{hex, Exchange, CONSOLE_REQUESTS, PLC_UNIT_ID};
+use super::store::DemoStore;
+use modbus_parser::register_map::holding_address;
+
+/// exchanges kept in the console
+const MAX_EXCHANGES: usize = 20;
+
+/// request buttons, exchange log and live holding registers of the simulated slave
+#[component]
+pub fn ModbusPanel(store: DemoStore) -> impl IntoView {
+    let slave = store.modbus;
+    let (exchanges, set_exchanges) = create_signal(Vec::<Exchange>::new());
+
+    let send = move |index: usize| {
+        let exchange = slave.try_update(|s| CONSOLE_REQUESTS[index].send(s));
+        if let Some(exchange) = exchange {
+            store.capture_exchange(&exchange);
+            set_exchanges.update(|log| {
+                log.push(exchange);
+                let excess = log.len().saturating_sub(MAX_EXCHANGES);
+                log.drain(..excess);
+            });
+        }
+    };
+
+    view! {
+        <div class="audit-panel modbus-panel">
+            <h4>"📟 Modbus Slave" <span class="demo-badge">{format!("S7-1200 · unit {}", PLC_UNIT_ID)}</span></h4>
+            <p class="section-desc">"A simulated PLC register table: the gateway writes each voted packet with FC16, the BME280 run fills the input registers. Console requests come from zone 3, so writes must pass the data diode first. Send requests and watch the replies, exceptions and dropped writes included."</p>
+            <div class="diode-buttons">
+                {CONSOLE_REQUESTS.iter().enumerate().map(|(i, req)| view! {
+                    <button class="diode-btn" title=hex(&req.frame()) on:click=move |_| send(i)>{req.label}</button>
+                }).collect_view()}
+            </div>
+            <div class="modbus-registers">
+                {move || slave.with(|s| s.holding.iter().enumerate().map(|(i, value)| view! {
+                    <span class="modbus-register" class:nonzero=*value != 0>
+                        <span class="modbus-address">{format!("HR{}", holding_address(i as u16))}</span>
+                        {value.to_string()}
+                    </span>
+                }).collect_view())}
+            </div>
+            <div class="terminal diode-log">
+                {move || {
+                    let entries = exchanges.get();
+                    if entries.is_empty() {
+                        view! { <p class="terminal-line info">"$ waiting for a request"</p> }.into_view()
+                    } else {
+                        entries.into_iter().map(|x| view! {
+                            <p class="terminal-line info">{format!("→ {}  ({})", hex(&x.request), x.label)}</p>
+                            <p class="terminal-line info">{format!("← {}", x.response.as_deref().map(hex).unwrap_or_else(|| "(silence)".to_string()))}</p>
+                            <p class=format!("terminal-line {}", x.level)>{format!("  {}", x.summary)}</p>
+                        }).collect_view()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
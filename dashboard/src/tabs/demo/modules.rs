@@ -0,0 +1,342 @@
+// what: purpose-built wasm modules (hand-assembled) and their js/python twins
+// why: lets the demo run real workloads and real traps without a wasm32 build step
+// relations: used by proof/jitter.rs (sensor tick), probes.rs (attack modules), instances.rs (live instance budgets), proof/linking_panel.rs (linked pair), timing_channel_panel.rs (clock probe), resync_panel.rs (state copy) and proof/checkpoint_panel.rs (checkpoint/restore)
+
+// ============================================================================
+// sensor tick (same integer workload in wasm, js and python)
+// ============================================================================
+
+/// loop iterations per tick
+pub const TICK_WORK: i32 = 256;
+
+/// (func (export "tick") (param $n i32) (result i32) (local $acc i32)
+///   loop: acc = acc + n * 27504; acc ^= acc >> 3; n -= 1; br_if n
+pub const TICK_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+    0x01, 0x06, 0x01, 0x60, 0x01, 0x7f, 0x01, 0x7f, // type section: (i32) -> i32
+    0x03, 0x02, 0x01, 0x00, // function section
+    0x07, 0x08, 0x01, 0x04, 0x74, 0x69, 0x63, 0x6b, 0x00, 0x00, // export "tick"
+    0x0a, 0x28, 0x01, 0x26, 0x01, 0x01, 0x7f, // code: one i32 local
+    0x03, 0x40, // loop
+    0x20, 0x01, 0x20, 0x00, 0x41, 0xf0, 0xd6, 0x01, 0x6c, 0x6a, 0x22, 0x01, // acc += n * 27504
+    0x20, 0x01, 0x41, 0x03, 0x75, 0x73, 0x21, 0x01, // acc ^= acc >> 3
+    0x20, 0x00, 0x41, 0x01, 0x6b, 0x22, 0x00, 0x0d, 0x00, // n -= 1; br_if
+    0x0b, 0x20, 0x01, 0x0b, // end loop, return acc
+];
+
+/// js twin of TICK_WASM; evaluated with `new Function(source)()` to get the tick function
+pub const TICK_JS: &str = r#"
+return (n) => {
+    let acc = 0;
+    do {
+        acc = (acc + Math.imul(n, 27504)) | 0;
+        acc = acc ^ (acc >> 3);
+        n = (n - 1) | 0;
+    } while (n !== 0);
+    return acc;
+};
+"#;
+
+/// python twin of TICK_WASM; times {runs} ticks in-process and returns per-tick ms as json
+pub const TICK_PY: &str = r#"
+import json, time
+
+def wrap(v):
+    return ((v + 2**31) % 2**32) - 2**31
+
+def tick(n):
+    acc = 0
+    while True:
+        acc = wrap(acc + n * 27504)
+        acc ^= acc >> 3
+        n -= 1
+        if n == 0:
+            return acc
+
+samples = []
+for _ in range({runs}):
+    t0 = time.perf_counter()
+    tick({work})
+    samples.append((time.perf_counter() - t0) * 1000)
+json.dumps(samples)
+"#;
+
+// ============================================================================
+// heap growth (buffer-overflow probe)
+// ============================================================================
+
+/// linear memory maximum of GROW_WASM, in 64 KiB pages
+pub const GROW_MAX_PAGES: u32 = 2;
+
+/// (memory (export "memory") 1 2)
+/// (func (export "grow") (param $pages i32) (result i32) memory.grow)  ;; -1 when over the max
+pub const GROW_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+    0x01, 0x06, 0x01, 0x60, 0x01, 0x7f, 0x01, 0x7f, // type section: (i32) -> i32
+    0x03, 0x02, 0x01, 0x00, // function section
+    0x05, 0x04, 0x01, 0x01, 0x01, 0x02, // memory section: min 1, max 2 pages
+    0x07, 0x11, 0x02, // export section
+    0x04, 0x67, 0x72, 0x6f, 0x77, 0x00, 0x00, // "grow" func 0
+    0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, // "memory" memory 0
+    0x0a, 0x08, 0x01, 0x06, 0x00, 0x20, 0x00, 0x40, 0x00, 0x0b, // code: local.get 0; memory.grow
+];
+
+// ============================================================================
+// bounds check (buffer-overflow trap)
+// ============================================================================
+
+/// linear memory size of BOUNDS_WASM, in 64 KiB pages (fixed, min = max)
+pub const BOUNDS_PAGES: u32 = 1;
+
+/// (memory 1 1)
+/// (func (export "load") (param $addr i32) (result i32) local.get 0 i32.load)  ;; traps past 64 KiB
+pub const BOUNDS_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+    0x01, 0x06, 0x01, 0x60, 0x01, 0x7f, 0x01, 0x7f, // type section: (i32) -> i32
+    0x03, 0x02, 0x01, 0x00, // function section
+    0x05, 0x04, 0x01, 0x01, 0x01, 0x01, // memory section: min 1, max 1 page
+    0x07, 0x08, 0x01, 0x04, 0x6c, 0x6f, 0x61, 0x64, 0x00, 0x00, // export "load"
+    0x0a, 0x09, 0x01, 0x07, 0x00, 0x20, 0x00, 0x28, 0x02, 0x00, 0x0b, // code: local.get 0; i32.load
+];
+
+// ============================================================================
+// unbounded recursion (stack-exhaustion trap)
+// ============================================================================
+
+/// (func $recurse (export "recurse") (param $n i32) (result i32)
+///   (if (result i32) (i32.eqz (local.get 0))
+///     (then (i32.const 0))
+///     (else (i32.add (call $recurse (i32.sub (local.get 0) (i32.const 1))) (i32.const 1)))))
+pub const RECURSE_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+    0x01, 0x06, 0x01, 0x60, 0x01, 0x7f, 0x01, 0x7f, // type section: (i32) -> i32
+    0x03, 0x02, 0x01, 0x00, // function section
+    0x07, 0x0b, 0x01, 0x07, 0x72, 0x65, 0x63, 0x75, 0x72, 0x73, 0x65, 0x00, 0x00, // export "recurse"
+    0x0a, 0x17, 0x01, 0x15, 0x00, // code section, one body, no locals
+    0x20, 0x00, 0x45, 0x04, 0x7f, // local.get 0; i32.eqz; if (result i32)
+    0x41, 0x00, // i32.const 0
+    0x05, 0x20, 0x00, 0x41, 0x01, 0x6b, 0x10, 0x00, 0x41, 0x01, 0x6a, // else n-1; call 0; +1
+    0x0b, 0x0b, // end if; end func
+];
+
+// ============================================================================
+// live instance (per-instance resource budget)
+// ============================================================================
+
+/// linear memory cap of INSTANCE_WASM, in 64 KiB pages (2 MiB per instance)
+pub const INSTANCE_MAX_PAGES: u32 = 32;
+/// function table limits of INSTANCE_WASM
+pub const INSTANCE_TABLE_MAX: u32 = 4;
+
+/// (table (export "table") 1 4 funcref)
+/// (memory (export "memory") 1 32)
+/// (func (export "grow") (param $pages i32) (result i32) local.get 0 memory.grow)  ;; -1 past the budget
+pub const INSTANCE_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+    0x01, 0x06, 0x01, 0x60, 0x01, 0x7f, 0x01, 0x7f, // type section: (i32) -> i32
+    0x03, 0x02, 0x01, 0x00, // function section
+    0x04, 0x05, 0x01, 0x70, 0x01, 0x01, 0x04, // table section: funcref, min 1, max 4
+    0x05, 0x04, 0x01, 0x01, 0x01, 0x20, // memory section: min 1, max 32 pages
+    0x07, 0x19, 0x03, // export section
+    0x04, 0x67, 0x72, 0x6f, 0x77, 0x00, 0x00, // "grow" func 0
+    0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, // "memory" memory 0
+    0x05, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x01, 0x00, // "table" table 0
+    0x0a, 0x08, 0x01, 0x06, 0x00, 0x20, 0x00, 0x40, 0x00, 0x0b, // code: local.get 0; memory.grow
+];
+
+// ============================================================================
+// core-module linking (sensor module linked into a filter module)
+// ============================================================================
+
+/// ticks per linked call chain (one chain(n) call runs n filtered reads)
+pub const CHAIN_TICKS: i32 = 10_000;
+
+/// (func $read (export "read") (param $tick i32) (result i32)  ;; centi-celsius, 2318..=2381
+///   tick * 37 & 63 + 2350 - 32)
+/// (func (export "chain") (param $n i32) (result i32) (local $acc i32)
+///   loop: acc += (read(n) + read(n - 1)) >> 1; n -= 1; br_if n)   ;; sensor and filter fused in one module
+pub const SENSOR_CORE_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+    0x01, 0x06, 0x01, 0x60, 0x01, 0x7f, 0x01, 0x7f, // type section: (i32) -> i32
+    0x03, 0x03, 0x02, 0x00, 0x00, // function section: two funcs
+    0x07, 0x10, 0x02, // export section
+    0x04, 0x72, 0x65, 0x61, 0x64, 0x00, 0x00, // "read" func 0
+    0x05, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x00, 0x01, // "chain" func 1
+    0x0a, 0x3a, 0x02, // code section, two bodies
+    0x11, 0x00, // read: no locals
+    0x20, 0x00, 0x41, 0x25, 0x6c, 0x41, 0x3f, 0x71, // tick * 37 & 63
+    0x41, 0xae, 0x12, 0x6a, 0x41, 0x20, 0x6b, 0x0b, // + 2350 - 32
+    0x26, 0x01, 0x01, 0x7f, // chain: one i32 local
+    0x03, 0x40, 0x20, 0x01, // loop; acc
+    0x20, 0x00, 0x10, 0x00, 0x20, 0x00, 0x41, 0x01, 0x6b, 0x10, 0x00, // read(n), read(n - 1)
+    0x6a, 0x41, 0x01, 0x75, 0x6a, 0x21, 0x01, // acc += sum >> 1
+    0x20, 0x00, 0x41, 0x01, 0x6b, 0x22, 0x00, 0x0d, 0x00, // n -= 1; br_if
+    0x0b, 0x20, 0x01, 0x0b, // end loop, return acc
+];
+
+/// (import "sensor" "read" (func $read (param i32) (result i32)))
+/// (func $filtered (export "filtered") (param $tick i32) (result i32)
+///   (read(tick) + read(tick - 1)) >> 1)   ;; two-tap moving average across the module boundary
+/// (func (export "chain") (param $n i32) (result i32) (local $acc i32)
+///   loop: acc += filtered(n); n -= 1; br_if n)
+pub const FILTER_CORE_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+    0x01, 0x06, 0x01, 0x60, 0x01, 0x7f, 0x01, 0x7f, // type section: (i32) -> i32
+    0x02, 0x0f, 0x01, // import section
+    0x06, 0x73, 0x65, 0x6e, 0x73, 0x6f, 0x72, 0x04, 0x72, 0x65, 0x61, 0x64, 0x00, 0x00, // "sensor" "read" func type 0
+    0x03, 0x03, 0x02, 0x00, 0x00, // function section: two funcs (indices 1, 2)
+    0x07, 0x14, 0x02, // export section
+    0x08, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x65, 0x64, 0x00, 0x01, // "filtered" func 1
+    0x05, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x00, 0x02, // "chain" func 2
+    0x0a, 0x2f, 0x02, // code section, two bodies
+    0x11, 0x00, // filtered: no locals
+    0x20, 0x00, 0x10, 0x00, 0x20, 0x00, 0x41, 0x01, 0x6b, 0x10, 0x00, // read(tick), read(tick - 1)
+    0x6a, 0x41, 0x01, 0x75, 0x0b, // sum >> 1
+    0x1b, 0x01, 0x01, 0x7f, // chain: one i32 local
+    0x03, 0x40, 0x20, 0x01, 0x20, 0x00, 0x10, 0x01, // loop; acc; filtered(n)
+    0x6a, 0x21, 0x01, // acc += it
+    0x20, 0x00, 0x41, 0x01, 0x6b, 0x22, 0x00, 0x0d, 0x00, // n -= 1; br_if
+    0x0b, 0x20, 0x01, 0x0b, // end loop, return acc
+];
+
+// ============================================================================
+// timing side channel (clock granularity and a leaky comparison)
+// ============================================================================
+
+/// (import "clock" "now" (func $now (result f64)))          ;; ms, whatever clock the host grants
+/// (import "victim" "check" (func $check (param i32) (result i32)))
+/// (func (export "tick") (result f64) (local $t0 f64) (local $t f64)
+///   t0 = now(); loop: t = now(); br_if (t == t0); t - t0)   ;; one observed clock step
+/// (func (export "time_check") (param $guess i32) (result f64) (local $t0 f64)
+///   t0 = now(); drop(check(guess)); now() - t0)             ;; the attacker's stopwatch
+pub const CLOCK_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+    0x01, 0x0f, 0x03, // type section: three types
+    0x60, 0x00, 0x01, 0x7c, // () -> f64
+    0x60, 0x01, 0x7f, 0x01, 0x7f, // (i32) -> i32
+    0x60, 0x01, 0x7f, 0x01, 0x7c, // (i32) -> f64
+    0x02, 0x1c, 0x02, // import section
+    0x05, 0x63, 0x6c, 0x6f, 0x63, 0x6b, 0x03, 0x6e, 0x6f, 0x77, 0x00, 0x00, // "clock" "now" func type 0
+    0x06, 0x76, 0x69, 0x63, 0x74, 0x69, 0x6d, 0x05, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x00, 0x01, // "victim" "check" func type 1
+    0x03, 0x03, 0x02, 0x00, 0x02, // function section: tick, time_check
+    0x07, 0x15, 0x02, // export section
+    0x04, 0x74, 0x69, 0x63, 0x6b, 0x00, 0x02, // "tick" func 2
+    0x0a, 0x74, 0x69, 0x6d, 0x65, 0x5f, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x00, 0x03, // "time_check" func 3
+    0x0a, 0x2e, 0x02, // code section, two bodies
+    0x19, 0x01, 0x02, 0x7c, // tick: two f64 locals
+    0x10, 0x00, 0x21, 0x00, // t0 = now()
+    0x03, 0x40, 0x10, 0x00, 0x22, 0x01, // loop; t = now()
+    0x20, 0x00, 0x61, 0x0d, 0x00, 0x0b, // br_if t == t0; end
+    0x20, 0x01, 0x20, 0x00, 0xa1, 0x0b, // t - t0
+    0x12, 0x01, 0x01, 0x7c, // time_check: one f64 local
+    0x10, 0x00, 0x21, 0x01, // t0 = now()
+    0x20, 0x00, 0x10, 0x01, 0x1a, // drop(check(guess))
+    0x10, 0x00, 0x20, 0x01, 0xa1, 0x0b, // now() - t0
+];
+
+/// body of `new Function("secret, work", source)`: a byte-by-byte compare that exits at the first mismatch,
+/// doing `work` iterations per matching byte - the timing leak CLOCK_WASM tries to read
+pub const LEAK_VICTIM_JS: &str = r#"
+let sink = 0;
+return (guess) => {
+    for (let shift = 24; shift >= 0; shift -= 8) {
+        if (((guess >>> shift) & 255) !== ((secret >>> shift) & 255)) return 0;
+        for (let i = 0; i < work; i++) sink = (sink + Math.imul(i, 31)) | 0;
+    }
+    return 1;
+};
+"#;
+
+/// python clock probe: {steps} observed steps of time.perf_counter(), in ms, as json
+pub const CLOCK_PY: &str = r#"
+import json, time
+
+steps = []
+t0 = time.perf_counter()
+while len(steps) < {steps}:
+    t = time.perf_counter()
+    if t != t0:
+        steps.append((t - t0) * 1000)
+        t0 = t
+json.dumps(steps)
+"#;
+
+// ============================================================================
+// checkpoint (stateful module whose memory and globals are saved and restored)
+// ============================================================================
+
+/// readings the checkpoint module's ring holds before it wraps (1 MiB of i32)
+pub const CHECKPOINT_RING: u32 = 262_144;
+/// the checkpoint module's memory maximum, in pages
+pub const CHECKPOINT_MAX_PAGES: u32 = 16;
+
+/// (memory (export "memory") 1 16)
+/// (global $count (export "count") (mut i32) 0)  (global $acc (export "acc") (mut i32) 0)
+/// (func $record (export "record") (param $v i32) (local $addr i32)
+///   addr = count << 2 & 0xfffff; grow one page when addr reaches the end; store v at addr; acc += v; count += 1)
+/// (func (export "fill") (param $n i32) (param $seed i32) (local $i i32)
+///   loop: record(seed + i * 37 & 1023); i += 1; br_if i < n)
+/// (func (export "checksum") (result i32) (local $n i32) (local $i i32) (local $h i32)
+///   n = min(count, 262144); loop: h = h * 31 + load(i << 2); i += 1; br_if i < n; return h)
+pub const CHECKPOINT_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+    0x01, 0x0e, 0x03, // type section: three types
+    0x60, 0x01, 0x7f, 0x00, 0x60, 0x02, 0x7f, 0x7f, 0x00, 0x60, 0x00, 0x01, 0x7f, // (i32) -> (), (i32, i32) -> (), () -> i32
+    0x03, 0x04, 0x03, 0x00, 0x01, 0x02, // function section: record, fill, checksum
+    0x05, 0x04, 0x01, 0x01, 0x01, 0x10, // memory section: 1 page, max 16
+    0x06, 0x0b, 0x02, 0x7f, 0x01, 0x41, 0x00, 0x0b, 0x7f, 0x01, 0x41, 0x00, 0x0b, // global section: two mutable i32 = 0
+    0x07, 0x33, 0x06, // export section
+    0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, // "memory" memory 0
+    0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x03, 0x00, // "count" global 0
+    0x03, 0x61, 0x63, 0x63, 0x03, 0x01, // "acc" global 1
+    0x06, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x00, 0x00, // "record" func 0
+    0x04, 0x66, 0x69, 0x6c, 0x6c, 0x00, 0x01, // "fill" func 1
+    0x08, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x73, 0x75, 0x6d, 0x00, 0x02, // "checksum" func 2
+    0x0a, 0x9f, 0x01, 0x03, // code section, three bodies
+    0x35, 0x01, 0x01, 0x7f, // record: one i32 local
+    0x23, 0x00, 0x41, 0x02, 0x74, 0x41, 0xff, 0xff, 0x3f, 0x71, 0x21, 0x01, // addr = count << 2 & 0xfffff
+    0x20, 0x01, 0x3f, 0x00, 0x41, 0x10, 0x74, 0x4f, // addr >= memory.size << 16
+    0x04, 0x40, 0x41, 0x01, 0x40, 0x00, 0x1a, 0x0b, // if: memory.grow 1, drop
+    0x20, 0x01, 0x20, 0x00, 0x36, 0x02, 0x00, // i32.store addr v
+    0x23, 0x01, 0x20, 0x00, 0x6a, 0x24, 0x01, // acc += v
+    0x23, 0x00, 0x41, 0x01, 0x6a, 0x24, 0x00, 0x0b, // count += 1
+    0x29, 0x01, 0x01, 0x7f, // fill: one i32 local
+    0x02, 0x40, 0x20, 0x00, 0x45, 0x0d, 0x00, // block; n == 0 -> done
+    0x03, 0x40, 0x20, 0x01, 0x20, 0x02, 0x41, 0x25, 0x6c, 0x6a, // loop; seed + i * 37
+    0x41, 0xff, 0x07, 0x71, 0x10, 0x00, // & 1023; record
+    0x20, 0x02, 0x41, 0x01, 0x6a, 0x22, 0x02, 0x20, 0x00, 0x49, 0x0d, 0x00, // i += 1; br_if i < n
+    0x0b, 0x0b, 0x0b, // end loop, block, func
+    0x3d, 0x01, 0x03, 0x7f, // checksum: three i32 locals
+    0x23, 0x00, 0x41, 0x80, 0x80, 0x10, 0x23, 0x00, 0x41, 0x80, 0x80, 0x10, 0x49, 0x1b, 0x21, 0x00, // n = min(count, 262144)
+    0x02, 0x40, 0x20, 0x00, 0x45, 0x0d, 0x00, // block; n == 0 -> done
+    0x03, 0x40, 0x20, 0x02, 0x41, 0x1f, 0x6c, // loop; h * 31
+    0x20, 0x01, 0x41, 0x02, 0x74, 0x28, 0x02, 0x00, 0x6a, 0x21, 0x02, // + load(i << 2)
+    0x20, 0x01, 0x41, 0x01, 0x6a, 0x22, 0x01, 0x20, 0x00, 0x49, 0x0d, 0x00, // i += 1; br_if i < n
+    0x0b, 0x0b, 0x20, 0x02, 0x0b, // end loop, block, return h
+];
+
+/// python state re-sync: {runs} pickle round trips of a standby's state ({calibration} + {session} bytes, {setpoints} floats), in ms, as json
+pub const RESYNC_PY: &str = r#"
+import json, pickle, time
+
+state = {
+    "calibration": bytes({calibration}),
+    "setpoints": [20.0] * {setpoints},
+    "session": bytes({session}),
+}
+samples = []
+for _ in range({runs}):
+    t0 = time.perf_counter()
+    pickle.loads(pickle.dumps(state, protocol=pickle.HIGHEST_PROTOCOL))
+    samples.append((time.perf_counter() - t0) * 1000)
+json.dumps(samples)
+"#;
@@ -0,0 +1,105 @@
+// what: drawer with one node's session history - status, uptime, values, votes, traps, recovery times and suspicion
+// why: the node boxes say up or down; the drawer says how often, how long and what the node produced meanwhile
+// relations: opened from node_strip.rs via the store.rs node_detail signal, reads node_history.rs histories and suspicion.rs scores;
+//            rendered once by component.rs
+
+use leptos::*;
+use guardian_types::Status;
+use crate::measure::stats::Summary;
+use super::journal::Runtime;
+use super::node_history::{format_uptime, NodeHistory, NodeId};
+use super::store::DemoStore;
+
+/// one labelled figure in the drawer grid
+fn figure(label: &'static str, value: String) -> impl IntoView {
+    view! {
+        <div class="node-figure">
+            <span class="node-figure-label">{label}</span>
+            <span class="node-figure-value">{value}</span>
+        </div>
+    }
+}
+
+fn recovery_text(recovery: Summary, count: usize, decimals: usize) -> String {
+    if count == 0 {
+        "none yet".to_string()
+    } else {
+        format!("{} × median {}", count, recovery.display(decimals))
+    }
+}
+
+#[component]
+pub fn NodeDrawer(store: DemoStore) -> impl IntoView {
+    let DemoStore { nodes, node_detail, cluster, pool, .. } = store;
+    // uptime keeps counting while the drawer is open
+    let (now_ms, set_now_ms) = create_signal(js_sys::Date::now());
+    if let Ok(handle) = set_interval_with_handle(move || set_now_ms.set(js_sys::Date::now()), std::time::Duration::from_secs(1)) {
+        on_cleanup(move || handle.clear());
+    }
+
+    let body = move |id: NodeId| {
+        let history: NodeHistory = nodes.with(|n| n.get(id).clone());
+        let now = now_ms.get();
+        let wasm = id.runtime == Runtime::Wasm;
+        let leader = if wasm { cluster.leader_id.get() as usize == id.index } else { pool.python_active_worker.get() as usize == id.index };
+        let votes = if wasm {
+            format!("{} won · {} lost", history.votes_won, history.votes_lost)
+        } else {
+            "not voted (failover pool)".to_string()
+        };
+        let suspicion = if wasm {
+            cluster.suspicion.with(|s| format!("{:.2} (evicts at {:.1})", s.scores[id.index], s.threshold))
+        } else {
+            "—".to_string()
+        };
+        let values = if history.values.is_empty() {
+            "none yet".to_string()
+        } else {
+            history.values.iter().map(|v| format!("{:.2}", v)).collect::<Vec<_>>().join(" · ")
+        };
+        view! {
+            <p class=format!("node-status {}", Status::node(history.is_up()).css_class())>
+                {format!(
+                    "{} {} · {}",
+                    if history.is_up() { "🟢 up" } else { "🔴 down" },
+                    if leader { "· leader" } else { "· follower" },
+                    if history.is_up() { format!("up for {}", format_uptime(history.uptime_ms(now))) } else { "recovering".to_string() },
+                )}
+            </p>
+            <div class="node-figures">
+                {figure("Availability", format!("{:.3}%", history.availability(now) * 100.0))}
+                {figure("Values produced", history.produced.to_string())}
+                {figure("Votes", votes)}
+                {figure(if wasm { "Traps & crashes" } else { "Crashes" }, history.traps.to_string())}
+                {figure(if wasm { "Rebuilds (real)" } else { "Respawns" }, recovery_text(history.recovery(), history.recoveries_ms.len(), if wasm { 3 } else { 0 }))}
+                {figure("Suspicion", suspicion)}
+            </div>
+            <h5>"Latest values (°C)"</h5>
+            <p class="node-values">{values}</p>
+            <Show when=move || nodes.with(|n| !n.get(id).recoveries_ms.is_empty())>
+                <h5>{if wasm { "Rebuild times" } else { "Respawn times" }}</h5>
+                <p class="node-values">
+                    {move || nodes.with(|n| n.get(id).recoveries_ms.iter().map(|ms| format!("{:.*}ms", if wasm { 3 } else { 0 }, ms)).collect::<Vec<_>>().join(" · "))}
+                </p>
+            </Show>
+        }
+    };
+
+    view! {
+        {move || node_detail.get().map(|id| view! {
+            <aside class="trace-drawer node-drawer">
+                <div class="modal-header">
+                    <span class="modal-title">{format!(
+                        "{} {} - {}",
+                        if id.runtime == Runtime::Wasm { "🦀" } else { "🐍" },
+                        id.label(),
+                        if id.runtime == Runtime::Wasm { "WASM instance" } else { "Python worker" },
+                    )}</span>
+                    <button class="modal-close" on:click=move |_| node_detail.set(None)>"×"</button>
+                </div>
+                {move || body(id)}
+                <p class="source-note">"History since the page loaded or the last reset. Uptime is wall-clock time; recovery times are the measured or configured ones."</p>
+            </aside>
+        })}
+    }
+}
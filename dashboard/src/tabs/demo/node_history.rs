@@ -0,0 +1,159 @@
+// what: per-node history for the python workers and wasm instances - values produced, votes won/lost, traps, recovery times, uptime
+// why: the node boxes only show up/down right now; the drawer answers "what has I1 been through this session"
+// relations: accumulated in the store.rs nodes signal by handlers.rs (votes, traps, crashes, rebuilds) and store.rs set_instance,
+//            drawn by node_drawer.rs when a node_strip.rs box is clicked; recovery stats from measure/stats.rs
+
+use std::collections::VecDeque;
+use crate::measure::stats::{summarize, Summary};
+use super::journal::Runtime;
+use super::voting::VoteStrategy;
+
+/// recent values kept per node
+pub const VALUES_KEPT: usize = 20;
+
+/// one node box: W0-W2 (python workers) or I0-I2 (wasm instances)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NodeId {
+    pub runtime: Runtime,
+    pub index: usize,
+}
+
+impl NodeId {
+    /// the node behind box `index` of a runtime column; the js baseline has no instances to follow
+    pub fn of(runtime: Runtime, index: usize) -> Option<NodeId> {
+        (runtime != Runtime::Js && index < 3).then_some(NodeId { runtime, index })
+    }
+
+    pub fn label(&self) -> String {
+        let prefix = if self.runtime == Runtime::Python { "W" } else { "I" };
+        format!("{}{}", prefix, self.index)
+    }
+}
+
+/// everything one node did this session
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeHistory {
+    /// latest outputs, oldest first
+    pub values: VecDeque<f64>,
+    pub produced: u32,
+    pub votes_won: u32,
+    pub votes_lost: u32,
+    /// wasm traps, python crashes
+    pub traps: u32,
+    /// rebuild (wasm) or respawn (python) times, ms
+    pub recoveries_ms: Vec<f64>,
+    /// when the node last came up; None while down
+    pub up_since_ms: Option<f64>,
+    down_since_ms: Option<f64>,
+    downtime_ms: f64,
+    created_ms: f64,
+}
+
+impl NodeHistory {
+    pub fn new(now_ms: f64) -> Self {
+        Self { up_since_ms: Some(now_ms), created_ms: now_ms, ..Default::default() }
+    }
+
+    pub fn produce(&mut self, value: f64) {
+        self.produced += 1;
+        self.values.push_back(value);
+        if self.values.len() > VALUES_KEPT {
+            self.values.pop_front();
+        }
+    }
+
+    pub fn vote(&mut self, won: bool) {
+        if won { self.votes_won += 1 } else { self.votes_lost += 1 }
+    }
+
+    pub fn trap(&mut self) {
+        self.traps += 1;
+    }
+
+    /// marks the node down; already down keeps the original start
+    pub fn down(&mut self, now_ms: f64) {
+        if self.down_since_ms.is_none() {
+            self.down_since_ms = Some(now_ms);
+            self.up_since_ms = None;
+        }
+    }
+
+    /// marks the node up, adding the outage to its downtime
+    pub fn up(&mut self, now_ms: f64) {
+        if let Some(since) = self.down_since_ms.take() {
+            self.downtime_ms += (now_ms - since).max(0.0);
+            self.up_since_ms = Some(now_ms);
+        }
+    }
+
+    pub fn recovered(&mut self, recovery_ms: f64) {
+        self.recoveries_ms.push(recovery_ms);
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.down_since_ms.is_none()
+    }
+
+    /// time since the node last came up (0 while down)
+    pub fn uptime_ms(&self, now_ms: f64) -> f64 {
+        self.up_since_ms.map_or(0.0, |since| (now_ms - since).max(0.0))
+    }
+
+    /// share of the session the node was up, the current outage included
+    pub fn availability(&self, now_ms: f64) -> f64 {
+        let elapsed = now_ms - self.created_ms;
+        if elapsed <= 0.0 {
+            return 1.0;
+        }
+        let down = self.downtime_ms + self.down_since_ms.map_or(0.0, |since| (now_ms - since).max(0.0));
+        (1.0 - down / elapsed).clamp(0.0, 1.0)
+    }
+
+    pub fn recovery(&self) -> Summary {
+        summarize(&self.recoveries_ms, 0)
+    }
+}
+
+/// histories of all six nodes
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeStats {
+    pub python: [NodeHistory; 3],
+    pub wasm: [NodeHistory; 3],
+}
+
+impl NodeStats {
+    pub fn new(now_ms: f64) -> Self {
+        Self {
+            python: std::array::from_fn(|_| NodeHistory::new(now_ms)),
+            wasm: std::array::from_fn(|_| NodeHistory::new(now_ms)),
+        }
+    }
+
+    pub fn get(&self, node: NodeId) -> &NodeHistory {
+        match node.runtime {
+            Runtime::Python => &self.python[node.index],
+            _ => &self.wasm[node.index],
+        }
+    }
+
+    /// one 2oo3 vote over the wasm instances: outputs count as produced, the emitted group wins,
+    /// outvoted or silent (trapped, down) instances lose; a halted vote emits nothing, so nobody wins it
+    pub fn record_vote(&mut self, strategy: VoteStrategy, outputs: &[Option<f64>]) {
+        for ((history, output), won) in self.wasm.iter_mut().zip(outputs).zip(strategy.winners(outputs)) {
+            if let Some(value) = output {
+                history.produce(*value);
+            }
+            history.vote(won);
+        }
+    }
+}
+
+/// uptime as "42s", "3m 07s" or "1h 05m"
+pub fn format_uptime(ms: f64) -> String {
+    let s = (ms.max(0.0) / 1000.0) as u64;
+    match s {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m {:02}s", s / 60, s % 60),
+        s => format!("{}h {:02}m", s / 3600, s % 3600 / 60),
+    }
+}
@@ -0,0 +1,94 @@
+// what: leader/follower node boxes under each runtime terminal
+// why: role computation is pure so it can be tested natively; markup is shared by every runtime
+// relations: rendered by runtime_panel.rs from a runtime_model.rs RuntimeModel, live instance budgets from budget.rs; a click opens node_drawer.rs;
+//            the wasm boxes give way to election_ring.rs while an election replays; up/down colours from guardian-types status_map.rs
+
+use leptos::*;
+use guardian_types::Status;
+use super::budget::{format_bytes, live_badge};
+use super::election_ring::ElectionRing;
+use super::journal::Runtime;
+use super::node_history::NodeId;
+use super::runtime_model::RuntimeModel;
+use super::store::DemoStore;
+use super::types::InstanceState;
+
+/// one node box
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NodeStatus {
+    pub leader: bool,
+    pub up: bool,
+}
+
+impl NodeStatus {
+    pub fn label(&self) -> &'static str {
+        if self.leader { "L" } else { "F" }
+    }
+}
+
+/// python workers: the active worker leads, dead workers are down
+pub fn python_nodes(workers: [bool; 3], active: u8) -> [NodeStatus; 3] {
+    std::array::from_fn(|i| NodeStatus { leader: i as u8 == active, up: workers[i] })
+}
+
+/// wasm instances: elected leader leads, the faulty instance is down
+pub fn wasm_nodes(states: [InstanceState; 3], faulty: Option<u8>, leader: u8) -> [NodeStatus; 3] {
+    std::array::from_fn(|i| NodeStatus {
+        leader: i as u8 == leader,
+        up: states[i] == InstanceState::Healthy && faulty != Some(i as u8),
+    })
+}
+
+#[component]
+pub fn NodeStrip(model: &'static dyn RuntimeModel, store: DemoStore) -> impl IntoView {
+    let election = store.cluster.election;
+    // a memo, so the ring is not rebuilt on every frame
+    let electing = create_memo(move |_| model.runtime() == Runtime::Wasm && election.with(Option::is_some));
+
+    view! {
+        // node boxes with memory indicator - L/F/F pattern like Raft (one box for the js baseline)
+        <div class="nodes-panel">
+            <span class="nodes-label">"Nodes:"</span>
+            {move || if electing.get() {
+                view! { <ElectionRing replay=Signal::derive(move || election.get()) /> }.into_view()
+            } else {
+                model.nodes(&store).into_iter().enumerate().map(|(i, node)| {
+                    let id = NodeId::of(model.runtime(), i);
+                    view! {
+                        <div class=format!("node-box {}", Status::node(node.up).css_class())
+                            class:leader=node.leader
+                            class:inspectable=id.is_some()
+                            attr:data-tooltip=if node.leader { model.leader_note() } else { "Follower" }
+                            on:click=move |_| if id.is_some() { store.node_detail.set(id) }
+                        >
+                            {node.label()}
+                        </div>
+                    }
+                }).collect_view()
+            }}
+            <span class=format!("memory-indicator {}", model.tone()) attr:data-tooltip=model.memory_note()>
+                {move || live_badge(&model.budgets(&store)).unwrap_or_else(|| model.memory_badge())}
+            </span>
+        </div>
+        // per-instance budgets, for runtimes with live instances
+        {move || {
+            let budgets = model.budgets(&store);
+            (budgets.iter().any(Option::is_some)).then(|| view! {
+                <div class="budget-rows">
+                    {budgets.into_iter().enumerate().filter_map(|(i, b)| b.map(|b| (i, b))).map(|(i, b)| {
+                        let capped = b.grow_denied > 0;
+                        view! {
+                        <div class="budget-row" title=format!("ring {} in use · {} grow(s) refused at the cap · fuel: not metered by browser engines", format_bytes(b.used_bytes as u64), b.grow_denied)>
+                            <span class="budget-name">{format!("I{}", i)}</span>
+                            <div class="budget-bar" class:capped=capped>
+                                <div class="budget-fill" style=format!("width: {:.0}%", b.fraction() * 100.0)></div>
+                            </div>
+                            <span class="budget-label">{b.label()}</span>
+                        </div>
+                        }
+                    }).collect_view()}
+                </div>
+            })
+        }}
+    }
+}
@@ -0,0 +1,55 @@
+// what: the python attack payloads and the wit excerpt, imported from the attack-code module on first visit to the demo tab
+// why: both are demo-only text; shipping them as their own wasm module keeps them out of the bundle the problem tab waits for
+// relations: loader window.loadAttackCode in index.html (trunk builds wasm-modules/attack-code); loaded by component.rs into DemoStore.payloads,
+//            read by handlers.rs trigger_attack, library_panel.rs drafts and info_box.rs
+
+use std::collections::HashMap;
+use wasm_bindgen::{JsCast, JsValue};
+use super::attacks::SECURITY_ATTACKS;
+use super::wasm::loadAttackCode;
+
+/// module trunk emits for wasm-modules/attack-code, relative to the page
+pub const ATTACK_CODE_MODULE: &str = "attack_code.js";
+
+/// what the attack-code module hands the demo
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Payloads {
+    /// python source per security attack id
+    code: HashMap<String, String>,
+    /// wit/attacks.wit as the info modal shows it
+    pub wit_excerpt: String,
+}
+
+impl Payloads {
+    pub fn new(code: impl IntoIterator<Item = (String, String)>, wit_excerpt: String) -> Self {
+        Self { code: code.into_iter().collect(), wit_excerpt }
+    }
+
+    /// python payload of a security attack; None for availability attacks, which run no python
+    pub fn code(&self, attack: &str) -> Option<&str> {
+        self.code.get(attack).map(String::as_str)
+    }
+}
+
+/// imports the module (once per page) and reads every payload out of it
+pub async fn load() -> Result<Payloads, String> {
+    let module = loadAttackCode()
+        .await
+        .map_err(|e| format!("{} not available ({:?})", ATTACK_CODE_MODULE, e))?;
+    let export = |name: &str| {
+        js_sys::Reflect::get(&module, &name.into())
+            .ok()
+            .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+            .ok_or_else(|| format!("{} missing from {}", name, ATTACK_CODE_MODULE))
+    };
+    let text = |value: Result<JsValue, JsValue>| {
+        value.map_err(|e| format!("{:?}", e))?.as_string().ok_or_else(|| format!("{} returned a non-string", ATTACK_CODE_MODULE))
+    };
+    let attack_code = export("attackCode")?;
+    let code = SECURITY_ATTACKS
+        .iter()
+        .map(|attack| Ok((attack.to_string(), text(attack_code.call1(&module, &JsValue::from_str(attack)))?)))
+        .collect::<Result<Vec<_>, String>>()?;
+    let wit_excerpt = text(export("witExcerpt")?.call0(&module))?;
+    Ok(Payloads::new(code, wit_excerpt))
+}
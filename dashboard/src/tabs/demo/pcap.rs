@@ -0,0 +1,462 @@
+// what: pcap / pcapng reader that pulls modbus/tcp adus out of a capture and runs each through the mbap parser
+// why: ics practitioners can test the guardian's parser against their own traffic, entirely in the browser
+// relations: parser from modbus-parser mbap.rs, function names and directions from capture.rs, rendered by pcap_panel.rs
+
+use modbus_parser::mbap::{build_adu, parse_adu, MbapError, MBAP_HEADER_LEN};
+use modbus_sim::EXCEPTION_FLAG;
+use super::attacks::LENGTH_OVERFLOW_ADU;
+use super::capture::{function_name, Direction, UNKNOWN_FUNCTION};
+
+/// modbus/tcp server port
+pub const MODBUS_TCP_PORT: u16 = 502;
+/// larger files are refused before parsing (the whole file sits in memory)
+pub const MAX_CAPTURE_BYTES: usize = 16 * 1024 * 1024;
+
+/// link-layer header types (tcpdump.org linktypes)
+const LINKTYPE_NULL: u32 = 0;
+const LINKTYPE_ETHERNET: u32 = 1;
+const LINKTYPE_RAW: u32 = 101;
+const LINKTYPE_LINUX_SLL: u32 = 113;
+const LINKTYPE_IPV4: u32 = 228;
+const LINKTYPE_IPV6: u32 = 229;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Pcap,
+    PcapNg,
+}
+
+impl Format {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Format::Pcap => "pcap",
+            Format::PcapNg => "pcapng",
+        }
+    }
+}
+
+// ============================================================================
+// container formats
+// ============================================================================
+
+/// one captured packet as stored in the file
+struct RawPacket<'a> {
+    link_type: u32,
+    data: &'a [u8],
+    /// snaplen cut the packet short
+    truncated: bool,
+}
+
+/// byte-order aware reads
+#[derive(Clone, Copy)]
+struct Endian(bool);
+
+impl Endian {
+    fn u16(&self, b: &[u8], at: usize) -> Option<u16> {
+        let raw = [*b.get(at)?, *b.get(at + 1)?];
+        Some(if self.0 { u16::from_le_bytes(raw) } else { u16::from_be_bytes(raw) })
+    }
+
+    fn u32(&self, b: &[u8], at: usize) -> Option<u32> {
+        let raw = [*b.get(at)?, *b.get(at + 1)?, *b.get(at + 2)?, *b.get(at + 3)?];
+        Some(if self.0 { u32::from_le_bytes(raw) } else { u32::from_be_bytes(raw) })
+    }
+}
+
+/// classic pcap: 24-byte global header, then 16-byte record headers
+fn read_pcap(bytes: &[u8]) -> Result<(Vec<RawPacket<'_>>, bool), String> {
+    let magic = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let endian = match magic {
+        0xa1b2c3d4 | 0xa1b23c4d => Endian(true),
+        0xd4c3b2a1 | 0x4d3cb2a1 => Endian(false),
+        _ => return Err("not a pcap file".to_string()),
+    };
+    let link_type = endian.u32(bytes, 20).ok_or("pcap header is truncated")? & 0x0FFF_FFFF;
+    let mut packets = Vec::new();
+    let mut at = 24;
+    while at < bytes.len() {
+        let (Some(incl), Some(orig)) = (endian.u32(bytes, at + 8), endian.u32(bytes, at + 12)) else { return Ok((packets, true)) };
+        // a corrupt length near u32::MAX overflows usize on wasm32; that reads as a record running past the end
+        let start = at + 16;
+        let Some(end) = start.checked_add(incl as usize) else { return Ok((packets, true)) };
+        let Some(data) = bytes.get(start..end) else { return Ok((packets, true)) };
+        packets.push(RawPacket { link_type, data, truncated: incl < orig });
+        at = end;
+    }
+    Ok((packets, false))
+}
+
+/// pcapng: section header, interface descriptions, enhanced/simple packet blocks
+fn read_pcapng(bytes: &[u8]) -> Result<(Vec<RawPacket<'_>>, bool), String> {
+    let mut endian = Endian(true);
+    let mut interfaces: Vec<u32> = Vec::new();
+    let mut packets = Vec::new();
+    let mut at = 0;
+    while at < bytes.len() {
+        if u32::from_le_bytes(bytes.get(at..at + 4).and_then(|b| b.try_into().ok()).unwrap_or_default()) == 0x0A0D0D0A {
+            // a new section restates byte order and resets interface ids
+            endian = match bytes.get(at + 8..at + 12) {
+                Some([0x4D, 0x3C, 0x2B, 0x1A]) => Endian(true),
+                Some([0x1A, 0x2B, 0x3C, 0x4D]) => Endian(false),
+                _ => return Err("pcapng section header has no byte-order magic".to_string()),
+            };
+            interfaces.clear();
+        }
+        let (Some(kind), Some(len)) = (endian.u32(bytes, at), endian.u32(bytes, at + 4)) else { return Ok((packets, true)) };
+        let len = len as usize;
+        if len < 12 || !len.is_multiple_of(4) {
+            return Err(format!("pcapng block at byte {} has invalid length {}", at, len));
+        }
+        let Some(block) = at.checked_add(len).and_then(|end| bytes.get(at..end)) else { return Ok((packets, true)) };
+        let body = &block[8..len - 4];
+        match kind {
+            // interface description: link type
+            1 => interfaces.push(endian.u16(body, 0).unwrap_or(0) as u32),
+            // enhanced packet
+            6 => {
+                let (Some(iface), Some(cap), Some(orig)) = (endian.u32(body, 0), endian.u32(body, 12), endian.u32(body, 16)) else { return Ok((packets, true)) };
+                let data = 20usize
+                    .checked_add(cap as usize)
+                    .and_then(|end| body.get(20..end))
+                    .ok_or(format!("pcapng packet at byte {} overruns its block", at))?;
+                let link_type = *interfaces.get(iface as usize).ok_or(format!("pcapng packet at byte {} names unknown interface {}", at, iface))?;
+                packets.push(RawPacket { link_type, data, truncated: cap < orig });
+            }
+            // simple packet: interface 0, captured length implied by the block
+            3 => {
+                let orig = endian.u32(body, 0).unwrap_or(0) as usize;
+                let data = &body[4.min(body.len())..];
+                let data = &data[..orig.min(data.len())];
+                let link_type = *interfaces.first().ok_or("pcapng simple packet before any interface")?;
+                packets.push(RawPacket { link_type, data, truncated: data.len() < orig });
+            }
+            _ => {}
+        }
+        at += len;
+    }
+    Ok((packets, false))
+}
+
+// ============================================================================
+// link / ip / tcp
+// ============================================================================
+
+/// a tcp segment's ports and payload
+struct Segment<'a> {
+    src_port: u16,
+    dst_port: u16,
+    payload: &'a [u8],
+}
+
+/// why a packet carried no usable tcp segment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Skip {
+    NotTcp,
+    UnsupportedLink(u32),
+    Fragment,
+    Malformed,
+}
+
+fn tcp(ip_payload: &[u8]) -> Result<Segment<'_>, Skip> {
+    let offset = (*ip_payload.get(12).ok_or(Skip::Malformed)? >> 4) as usize * 4;
+    if offset < 20 || offset > ip_payload.len() {
+        return Err(Skip::Malformed);
+    }
+    Ok(Segment {
+        src_port: u16::from_be_bytes([ip_payload[0], ip_payload[1]]),
+        dst_port: u16::from_be_bytes([ip_payload[2], ip_payload[3]]),
+        payload: &ip_payload[offset..],
+    })
+}
+
+fn ip(packet: &[u8]) -> Result<Segment<'_>, Skip> {
+    match packet.first().map(|b| b >> 4) {
+        Some(4) => {
+            let header = (packet[0] & 0x0F) as usize * 4;
+            if header < 20 || packet.len() < header {
+                return Err(Skip::Malformed);
+            }
+            // total length trims ethernet padding off short frames
+            let total = (u16::from_be_bytes([packet[2], packet[3]]) as usize).clamp(header, packet.len());
+            let flags = u16::from_be_bytes([packet[6], packet[7]]);
+            if flags & 0x3FFF != 0 {
+                return Err(Skip::Fragment);
+            }
+            if packet[9] != 6 {
+                return Err(Skip::NotTcp);
+            }
+            tcp(&packet[header..total])
+        }
+        Some(6) => {
+            if packet.len() < 40 {
+                return Err(Skip::Malformed);
+            }
+            if packet[6] != 6 {
+                return Err(Skip::NotTcp);
+            }
+            let end = (40 + u16::from_be_bytes([packet[4], packet[5]]) as usize).min(packet.len());
+            tcp(&packet[40..end])
+        }
+        _ => Err(Skip::NotTcp),
+    }
+}
+
+/// the ip packet after an ethertype, if it is ipv4 or ipv6
+fn ethertype_payload(ethertype: u16, rest: &[u8]) -> Result<&[u8], Skip> {
+    match ethertype {
+        0x0800 | 0x86DD => Ok(rest),
+        _ => Err(Skip::NotTcp),
+    }
+}
+
+/// strips the link header, then ip and tcp
+fn segment(link_type: u32, data: &[u8]) -> Result<Segment<'_>, Skip> {
+    let packet = match link_type {
+        LINKTYPE_ETHERNET => {
+            let mut at = 12;
+            let mut ethertype = u16::from_be_bytes([*data.get(12).ok_or(Skip::Malformed)?, *data.get(13).ok_or(Skip::Malformed)?]);
+            // 802.1q / 802.1ad tags
+            while matches!(ethertype, 0x8100 | 0x88A8) {
+                at += 4;
+                ethertype = u16::from_be_bytes([*data.get(at).ok_or(Skip::Malformed)?, *data.get(at + 1).ok_or(Skip::Malformed)?]);
+            }
+            ethertype_payload(ethertype, data.get(at + 2..).ok_or(Skip::Malformed)?)?
+        }
+        LINKTYPE_LINUX_SLL => {
+            let ethertype = u16::from_be_bytes([*data.get(14).ok_or(Skip::Malformed)?, *data.get(15).ok_or(Skip::Malformed)?]);
+            ethertype_payload(ethertype, data.get(16..).ok_or(Skip::Malformed)?)?
+        }
+        LINKTYPE_NULL => data.get(4..).ok_or(Skip::Malformed)?,
+        LINKTYPE_RAW | LINKTYPE_IPV4 | LINKTYPE_IPV6 => data,
+        other => return Err(Skip::UnsupportedLink(other)),
+    };
+    ip(packet)
+}
+
+/// cuts a tcp payload into adus by their mbap length; a length that does not fit takes the rest
+fn split_adus(payload: &[u8]) -> Vec<&[u8]> {
+    let mut adus = Vec::new();
+    let mut rest = payload;
+    while !rest.is_empty() {
+        let declared = rest.get(4..6).map(|l| MBAP_HEADER_LEN - 1 + u16::from_be_bytes([l[0], l[1]]) as usize);
+        let take = match declared {
+            Some(n) if n > MBAP_HEADER_LEN && n <= rest.len() => n,
+            _ => rest.len(),
+        };
+        adus.push(&rest[..take]);
+        rest = &rest[take..];
+    }
+    adus
+}
+
+// ============================================================================
+// report
+// ============================================================================
+
+/// one modbus/tcp adu from the capture and the parser's verdict
+#[derive(Clone, Debug, PartialEq)]
+pub struct AduRecord {
+    /// 1-based packet number, as wireshark shows it
+    pub packet: usize,
+    pub direction: Direction,
+    pub bytes: Vec<u8>,
+    /// (transaction id, unit id, function code) of an accepted adu
+    pub result: Result<(u16, u8, u8), MbapError>,
+}
+
+/// short name of a parser error
+pub fn error_kind(error: &MbapError) -> &'static str {
+    match error {
+        MbapError::TooShort { .. } => "too short",
+        MbapError::BadProtocol(_) => "bad protocol id",
+        MbapError::LengthUnderflow { .. } => "length underflow",
+        MbapError::LengthOutOfRange { .. } => "length out of range",
+        MbapError::LengthMismatch { .. } => "length mismatch",
+    }
+}
+
+/// something in the capture worth a second look
+#[derive(Clone, Debug, PartialEq)]
+pub struct Anomaly {
+    pub packet: usize,
+    pub text: String,
+}
+
+/// decode results of a whole capture
+#[derive(Clone, Debug, PartialEq)]
+pub struct PcapReport {
+    pub format: Format,
+    pub packets: usize,
+    /// tcp segments with a payload to or from port 502
+    pub modbus_segments: usize,
+    pub adus: Vec<AduRecord>,
+    /// packets snaplen cut short
+    pub truncated: usize,
+    pub fragments: usize,
+    pub unsupported_links: Vec<u32>,
+    /// the file ended inside a record
+    pub truncated_file: bool,
+}
+
+impl PcapReport {
+    pub fn accepted(&self) -> usize {
+        self.adus.iter().filter(|a| a.result.is_ok()).count()
+    }
+
+    pub fn rejected(&self) -> usize {
+        self.adus.len() - self.accepted()
+    }
+
+    /// accepted adus per function code, ascending
+    pub fn function_counts(&self) -> Vec<(u8, usize)> {
+        let mut counts: Vec<(u8, usize)> = Vec::new();
+        for (_, _, fc) in self.adus.iter().filter_map(|a| a.result.ok()) {
+            match counts.iter_mut().find(|(f, _)| *f == fc) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((fc, 1)),
+            }
+        }
+        counts.sort();
+        counts
+    }
+
+    /// parser refusals per error kind
+    pub fn error_counts(&self) -> Vec<(&'static str, usize)> {
+        let mut counts: Vec<(&'static str, usize)> = Vec::new();
+        for e in self.adus.iter().filter_map(|a| a.result.err()) {
+            match counts.iter_mut().find(|(k, _)| *k == error_kind(&e)) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((error_kind(&e), 1)),
+            }
+        }
+        counts
+    }
+
+    /// parser refusals, exception replies and unknown function codes, in capture order
+    pub fn anomalies(&self) -> Vec<Anomaly> {
+        self.adus.iter().filter_map(|adu| {
+            let text = match adu.result {
+                Err(e) => format!("parser refused: {}", e.reason()),
+                Ok((_, unit, fc)) if fc & EXCEPTION_FLAG != 0 => {
+                    let code = adu.bytes.get(MBAP_HEADER_LEN + 1).copied().unwrap_or(0);
+                    format!("unit {} exception 0x{:02X} to {}", unit, code, function_name(fc))
+                }
+                Ok((_, unit, fc)) if function_name(fc) == UNKNOWN_FUNCTION => format!("unit {} unknown function 0x{:02X}", unit, fc),
+                Ok(_) => return None,
+            };
+            Some(Anomaly { packet: adu.packet, text })
+        }).collect()
+    }
+}
+
+/// reads a capture and parses every modbus/tcp adu in it
+pub fn analyze(bytes: &[u8]) -> Result<PcapReport, String> {
+    if bytes.len() > MAX_CAPTURE_BYTES {
+        return Err(format!("capture is {} bytes, the limit is {}", bytes.len(), MAX_CAPTURE_BYTES));
+    }
+    if bytes.len() < 24 {
+        return Err("file is too short to be a capture".to_string());
+    }
+    let (format, (packets, truncated_file)) = if bytes[..4] == [0x0A, 0x0D, 0x0D, 0x0A] {
+        (Format::PcapNg, read_pcapng(bytes)?)
+    } else {
+        (Format::Pcap, read_pcap(bytes)?)
+    };
+    let mut report = PcapReport {
+        format,
+        packets: packets.len(),
+        modbus_segments: 0,
+        adus: Vec::new(),
+        truncated: packets.iter().filter(|p| p.truncated).count(),
+        fragments: 0,
+        unsupported_links: Vec::new(),
+        truncated_file,
+    };
+    for (i, packet) in packets.iter().enumerate() {
+        let segment = match segment(packet.link_type, packet.data) {
+            Ok(s) => s,
+            Err(Skip::Fragment) => {
+                report.fragments += 1;
+                continue;
+            }
+            Err(Skip::UnsupportedLink(link)) => {
+                if !report.unsupported_links.contains(&link) {
+                    report.unsupported_links.push(link);
+                }
+                continue;
+            }
+            Err(_) => continue,
+        };
+        let direction = match (segment.src_port, segment.dst_port) {
+            (_, MODBUS_TCP_PORT) => Direction::Request,
+            (MODBUS_TCP_PORT, _) => Direction::Reply,
+            _ => continue,
+        };
+        if segment.payload.is_empty() {
+            continue;
+        }
+        report.modbus_segments += 1;
+        for adu in split_adus(segment.payload) {
+            let result = parse_adu(adu).map(|f| (f.transaction_id, f.unit_id, f.function_code));
+            report.adus.push(AduRecord { packet: i + 1, direction, bytes: adu.to_vec(), result });
+        }
+    }
+    Ok(report)
+}
+
+// ============================================================================
+// sample capture
+// ============================================================================
+
+/// ethernet + ipv4 + tcp frame around a payload
+fn tcp_frame(src_port: u16, dst_port: u16, payload: &[u8]) -> Vec<u8> {
+    let mut frame = vec![0x02, 0, 0, 0, 0, 0x02, 0x02, 0, 0, 0, 0, 0x01, 0x08, 0x00];
+    let total = (20 + 20 + payload.len()) as u16;
+    let (src, dst) = if dst_port == MODBUS_TCP_PORT { ([10, 0, 2, 20], [10, 0, 2, 10]) } else { ([10, 0, 2, 10], [10, 0, 2, 20]) };
+    frame.extend_from_slice(&[0x45, 0, total.to_be_bytes()[0], total.to_be_bytes()[1], 0, 0, 0x40, 0, 64, 6, 0, 0]);
+    frame.extend_from_slice(&src);
+    frame.extend_from_slice(&dst);
+    frame.extend_from_slice(&src_port.to_be_bytes());
+    frame.extend_from_slice(&dst_port.to_be_bytes());
+    frame.extend_from_slice(&[0, 0, 0, 1, 0, 0, 0, 1, 0x50, 0x18, 0x20, 0x00, 0, 0, 0, 0]);
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// a small capture of a scada master polling the gateway, with the length-overflow adu and a few oddities mixed in
+pub fn sample_capture() -> Vec<u8> {
+    const MASTER_PORT: u16 = 49152;
+    let request = |payload: Vec<u8>| tcp_frame(MASTER_PORT, MODBUS_TCP_PORT, &payload);
+    let reply = |payload: Vec<u8>| tcp_frame(MODBUS_TCP_PORT, MASTER_PORT, &payload);
+    let mut pipelined = build_adu(3, 1, 0x04, &[0x00, 0x00, 0x00, 0x03]);
+    pipelined.extend(build_adu(4, 1, 0x01, &[0x00, 0x00, 0x00, 0x08]));
+    let mut bad_protocol = build_adu(6, 1, 0x03, &[0x00, 0x00, 0x00, 0x01]);
+    bad_protocol[3] = 0x01;
+    let frames = [
+        request(build_adu(1, 1, 0x03, &[0x00, 0x00, 0x00, 0x06])),
+        reply(build_adu(1, 1, 0x03, &[0x0C, 0x01, 0x8B, 0xCF, 0xE5, 0x4F, 0x10, 0x00, 0x7B, 0x01, 0xA7, 0x00, 0x00])),
+        request(build_adu(2, 1, 0x06, &[0x00, 0x0A, 0x00, 0x3C])),
+        reply(build_adu(2, 1, 0x06, &[0x00, 0x0A, 0x00, 0x3C])),
+        request(pipelined),
+        reply(build_adu(5, 1, 0x2B | EXCEPTION_FLAG, &[0x01])),
+        request(bad_protocol),
+        request(LENGTH_OVERFLOW_ADU.to_vec()),
+        // not modbus: a web request on the same segment
+        tcp_frame(MASTER_PORT, 80, b"GET / HTTP/1.1\r\n\r\n"),
+    ];
+    let mut file = Vec::new();
+    file.extend_from_slice(&0xa1b2c3d4u32.to_le_bytes());
+    file.extend_from_slice(&2u16.to_le_bytes());
+    file.extend_from_slice(&4u16.to_le_bytes());
+    file.extend_from_slice(&[0; 8]);
+    file.extend_from_slice(&65535u32.to_le_bytes());
+    file.extend_from_slice(&LINKTYPE_ETHERNET.to_le_bytes());
+    for (i, frame) in frames.iter().enumerate() {
+        file.extend_from_slice(&(1_700_000_000u32 + i as u32).to_le_bytes());
+        file.extend_from_slice(&0u32.to_le_bytes());
+        file.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        file.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        file.extend_from_slice(frame);
+    }
+    file
+}
@@ -0,0 +1,120 @@
+// what: pcap/pcapng import that runs every modbus/tcp adu through the parser and summarises results and anomalies
+// why: practitioners can check the guardian's parser against their own captures without uploading them anywhere
+// relations: used by component.rs, reader and report in pcap.rs, file reading in wasm.rs
+
+use leptos::*;
+use wasm_bindgen::JsCast;
+use super::capture::function_name;
+use super::modbus::hex;
+use super::pcap::{analyze, error_kind, sample_capture, PcapReport};
+use super::wasm::read_file_bytes;
+
+/// adus and anomalies listed before the rest is summarised as a count
+const MAX_LISTED: usize = 100;
+/// adu bytes shown per row
+const MAX_HEX_BYTES: usize = 24;
+
+/// file picker, sample capture and the decode report
+#[component]
+pub fn PcapPanel() -> impl IntoView {
+    let (report, set_report) = create_signal(Option::<(String, PcapReport)>::None);
+    let (status, set_status) = create_signal(Option::<String>::None);
+
+    let load = move |name: String, bytes: Vec<u8>| match analyze(&bytes) {
+        Ok(r) => {
+            set_status.set(None);
+            set_report.set(Some((name, r)));
+        }
+        Err(e) => {
+            set_report.set(None);
+            set_status.set(Some(format!("❌ {}: {}", name, e)));
+        }
+    };
+
+    let pick = move |ev: ev::Event| {
+        let Some(input) = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok()) else { return };
+        let Some(file) = input.files().and_then(|files| files.get(0)) else { return };
+        set_status.set(Some(format!("⏳ reading {}…", file.name())));
+        spawn_local(async move {
+            match read_file_bytes(&file).await {
+                Ok(bytes) => load(file.name(), bytes),
+                Err(e) => set_status.set(Some(format!("❌ {}", e))),
+            }
+        });
+    };
+
+    view! {
+        <div class="audit-panel pcap-panel">
+            <h4>"📦 PCAP Import" <span class="demo-badge">"Modbus/TCP · parsed locally"</span></h4>
+            <p class="section-desc">"Open a pcap or pcapng capture: every Modbus/TCP ADU on port 502 goes through the same checked MBAP parser. Nothing leaves the browser."</p>
+            <div class="audit-controls">
+                <input type="file" accept=".pcap,.pcapng,.cap" on:change=pick />
+                <button class="action-btn" on:click=move |_| load("sample.pcap".to_string(), sample_capture())>"🧪 Load Sample"</button>
+            </div>
+            {move || status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+            {move || report.get().map(|(name, r)| {
+                let anomalies = r.anomalies();
+                let extra_anomalies = anomalies.len().saturating_sub(MAX_LISTED);
+                let extra_adus = r.adus.len().saturating_sub(MAX_LISTED);
+                view! {
+                    <p class="metrics-note">
+                        {format!("{} ({}) · {} packets · {} Modbus/TCP segments · {} ADUs: ", name, r.format.label(), r.packets, r.modbus_segments, r.adus.len())}
+                        <span class="pcap-ok">{format!("{} accepted", r.accepted())}</span>
+                        ", "
+                        <span class="pcap-bad">{format!("{} refused", r.rejected())}</span>
+                    </p>
+                    {(r.truncated > 0 || r.fragments > 0 || r.truncated_file || !r.unsupported_links.is_empty()).then(|| view! {
+                        <ul class="pcap-notes">
+                            {(r.truncated > 0).then(|| view! { <li>{format!("{} packets cut short by the capture snaplen", r.truncated)}</li> })}
+                            {(r.fragments > 0).then(|| view! { <li>{format!("{} IP fragments skipped (no reassembly)", r.fragments)}</li> })}
+                            {r.truncated_file.then(|| view! { <li>"file ends inside a record - the tail was ignored"</li> })}
+                            {(!r.unsupported_links.is_empty()).then(|| view! { <li>{format!("unsupported link types skipped: {:?}", r.unsupported_links)}</li> })}
+                        </ul>
+                    })}
+                    <div class="pcap-tables">
+                        <table class="handoff-table">
+                            <thead><tr><th>"Function"</th><th>"ADUs"</th></tr></thead>
+                            <tbody>
+                                {r.function_counts().into_iter().map(|(fc, n)| view! {
+                                    <tr><td>{format!("0x{:02X} {}", fc, function_name(fc))}</td><td>{n}</td></tr>
+                                }).collect_view()}
+                            </tbody>
+                        </table>
+                        <table class="handoff-table">
+                            <thead><tr><th>"Parser refusal"</th><th>"ADUs"</th></tr></thead>
+                            <tbody>
+                                {r.error_counts().into_iter().map(|(kind, n)| view! {
+                                    <tr><td class="pcap-bad">{kind}</td><td>{n}</td></tr>
+                                }).collect_view()}
+                            </tbody>
+                        </table>
+                    </div>
+                    <h5>{format!("⚠️ Anomalies ({})", anomalies.len())}</h5>
+                    <div class="terminal pcap-log">
+                        {anomalies.into_iter().take(MAX_LISTED).map(|a| view! {
+                            <p class="terminal-line warn">{format!("#{:<5} {}", a.packet, a.text)}</p>
+                        }).collect_view()}
+                        {(extra_anomalies > 0).then(|| view! { <p class="terminal-line info">{format!("… {} more", extra_anomalies)}</p> })}
+                    </div>
+                    <h5>"🧾 ADUs"</h5>
+                    <div class="terminal pcap-log">
+                        {r.adus.iter().take(MAX_LISTED).map(|adu| {
+                            let shown = &adu.bytes[..adu.bytes.len().min(MAX_HEX_BYTES)];
+                            let more = if adu.bytes.len() > MAX_HEX_BYTES { " …" } else { "" };
+                            let (level, verdict) = match adu.result {
+                                Ok((tid, unit, fc)) => ("success", format!("tid {} unit {} 0x{:02X} {}", tid, unit, fc, function_name(fc))),
+                                Err(e) => ("error", format!("refused: {}", error_kind(&e))),
+                            };
+                            view! {
+                                <p class=format!("terminal-line {}", level)>
+                                    {format!("#{:<5} {} {}{}  → {}", adu.packet, adu.direction.arrow(), hex(shown), more, verdict)}
+                                </p>
+                            }
+                        }).collect_view()}
+                        {(extra_adus > 0).then(|| view! { <p class="terminal-line info">{format!("… {} more", extra_adus)}</p> })}
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
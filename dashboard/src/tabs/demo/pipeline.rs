@@ -0,0 +1,219 @@
+// what: gateway pipeline as ingress -> parse -> vote -> publish stages joined by bounded queues, with backpressure
+// why: a stage whose downstream is full stops pulling, so a slow stage fills its neighbours and ingress refuses frames,
+//      instead of the process buffering until it runs out of memory
+// relations: stepped by flood.rs for both pipelines (bounded for wasm, unbounded for python), gauges in flood_panel.rs
+
+use std::fmt;
+
+/// stages every frame passes through, in order
+pub const STAGES: usize = 4;
+/// queue capacity per stage of the supervised (wasm) pipeline
+pub const STAGE_CAPACITY: [u64; STAGES] = [64, 32, 32, 16];
+/// per-frame cost of the stages around parse (ms); parse cost is the runtime's measured sensor time
+pub const INGRESS_MS: f64 = 0.01;
+pub const VOTE_MS: f64 = 0.02;
+/// writing to the plc and the historian is i/o bound and the same for both runtimes
+pub const PUBLISH_MS: f64 = 0.4;
+/// how much slower a stage set to "slow" runs
+pub const SLOW_FACTOR: f64 = 50.0;
+
+/// one pipeline stage
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StageId {
+    Ingress,
+    Parse,
+    Vote,
+    Publish,
+}
+
+impl StageId {
+    pub const ALL: [StageId; STAGES] = [StageId::Ingress, StageId::Parse, StageId::Vote, StageId::Publish];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StageId::Ingress => "Ingress",
+            StageId::Parse => "Parse",
+            StageId::Vote => "Vote",
+            StageId::Publish => "Publish",
+        }
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            StageId::Ingress => "ingress",
+            StageId::Parse => "parse",
+            StageId::Vote => "vote",
+            StageId::Publish => "publish",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<StageId> {
+        StageId::ALL.into_iter().find(|s| s.id() == id)
+    }
+}
+
+/// why ingress refused a frame
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Backpressure {
+    /// the most downstream full stage - where the congestion started
+    pub origin: StageId,
+}
+
+impl fmt::Display for Backpressure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pipeline full (backpressure from {}), frame refused", self.origin.label().to_lowercase())
+    }
+}
+
+/// what a stage's gauge shows
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StageSignal {
+    #[default]
+    Flowing,
+    /// finished frames it could not hand on: downstream is full
+    Blocked,
+    /// its own queue is at capacity
+    Full,
+}
+
+impl StageSignal {
+    /// css class suffix
+    pub fn class(&self) -> &'static str {
+        match self {
+            StageSignal::Flowing => "flowing",
+            StageSignal::Blocked => "blocked",
+            StageSignal::Full => "full",
+        }
+    }
+}
+
+/// turns a fractional per-step amount into whole frames without losing the remainder
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Accumulator(f64);
+
+impl Accumulator {
+    pub fn take(&mut self, amount: f64) -> u64 {
+        self.0 += amount;
+        let whole = self.0.floor();
+        self.0 -= whole;
+        whole as u64
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Stage {
+    depth: u64,
+    /// None: grows without limit
+    capacity: Option<u64>,
+    frame_ms: f64,
+    service: Accumulator,
+    signal: StageSignal,
+}
+
+impl Stage {
+    fn room(&self) -> u64 {
+        self.capacity.map_or(u64::MAX, |c| c.saturating_sub(self.depth))
+    }
+}
+
+/// frames in and out of one step
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StepResult {
+    pub admitted: u64,
+    pub refused: u64,
+    pub delivered: u64,
+}
+
+/// the four stages of one gateway
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pipeline {
+    stages: [Stage; STAGES],
+}
+
+impl Pipeline {
+    /// per-frame stage costs for a runtime whose parse step takes `parse_ms`, with one stage optionally slowed
+    pub fn costs(parse_ms: f64, slow: Option<StageId>) -> [f64; STAGES] {
+        let mut costs = [INGRESS_MS, parse_ms, VOTE_MS, PUBLISH_MS];
+        if let Some(stage) = slow {
+            costs[stage as usize] *= SLOW_FACTOR;
+        }
+        costs
+    }
+
+    /// supervised: every queue has a capacity and a full stage stops its upstream
+    pub fn bounded(costs: [f64; STAGES], capacity: [u64; STAGES]) -> Self {
+        Self::build(costs, capacity.map(Some))
+    }
+
+    /// unsupervised: every queue takes whatever arrives
+    pub fn unbounded(costs: [f64; STAGES]) -> Self {
+        Self::build(costs, [None; STAGES])
+    }
+
+    fn build(costs: [f64; STAGES], capacity: [Option<u64>; STAGES]) -> Self {
+        Self {
+            stages: std::array::from_fn(|i| Stage { depth: 0, capacity: capacity[i], frame_ms: costs[i], service: Accumulator::default(), signal: StageSignal::Flowing }),
+        }
+    }
+
+    /// one tick of `dt_ms`: downstream stages serve first so the room they free is visible upstream,
+    /// then `arrivals` are offered to ingress, which refuses what does not fit
+    pub fn step(&mut self, arrivals: u64, dt_ms: f64) -> StepResult {
+        let mut result = StepResult::default();
+        for i in (0..STAGES).rev() {
+            let capacity = self.stages[i].service.take(dt_ms / self.stages[i].frame_ms);
+            let ready = self.stages[i].depth.min(capacity);
+            let moved = match self.stages.get(i + 1) {
+                Some(next) => ready.min(next.room()),
+                None => ready,
+            };
+            self.stages[i].depth -= moved;
+            match self.stages.get_mut(i + 1) {
+                Some(next) => next.depth += moved,
+                None => result.delivered = moved,
+            }
+            self.stages[i].signal = if moved < ready { StageSignal::Blocked } else { StageSignal::Flowing };
+        }
+        result.admitted = arrivals.min(self.stages[0].room());
+        result.refused = arrivals - result.admitted;
+        self.stages[0].depth += result.admitted;
+        for stage in &mut self.stages {
+            if stage.room() == 0 {
+                stage.signal = StageSignal::Full;
+            }
+        }
+        result
+    }
+
+    pub fn depths(&self) -> [u64; STAGES] {
+        self.stages.map(|s| s.depth)
+    }
+
+    pub fn signals(&self) -> [StageSignal; STAGES] {
+        self.stages.map(|s| s.signal)
+    }
+
+    pub fn queued(&self) -> u64 {
+        self.stages.iter().map(|s| s.depth).sum()
+    }
+
+    /// time a frame entering now spends waiting in every queue plus its own processing
+    pub fn latency_ms(&self) -> f64 {
+        self.stages.iter().map(|s| (s.depth as f64 + 1.0) * s.frame_ms).sum()
+    }
+
+    /// the most downstream full stage, which is where a refusal at ingress started
+    pub fn congestion(&self) -> Option<Backpressure> {
+        StageId::ALL.into_iter().rev().find(|s| self.stages[*s as usize].room() == 0).map(|origin| Backpressure { origin })
+    }
+
+    /// drops everything queued (the process holding it died); returns how many frames were lost
+    pub fn clear(&mut self) -> u64 {
+        let lost = self.queued();
+        self.stages.iter_mut().for_each(|s| {
+            s.depth = 0;
+            s.signal = StageSignal::Flowing;
+        });
+        lost
+    }
+}
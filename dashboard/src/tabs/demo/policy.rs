@@ -0,0 +1,245 @@
+// what: capability policy model loaded from wit/policy.toml
+// why: attack outcomes depend on what the host grants, so the grants live in config, not code
+// relations: used by component.rs (attack outcomes), policy_panel.rs (matrix, editor, wasmtime commands) and actuator.rs (relay pin writes);
+//            hardware/wizard.rs builds a world from the design wizard's answers
+
+use std::path::{Component, Path};
+use serde::{Deserialize, Serialize};
+
+/// default policy shipped with the repo (embedded at build time)
+pub const DEFAULT_POLICY_TOML: &str = include_str!("../../../../wit/policy.toml");
+
+/// world whose grants decide the outcome of demo attacks
+pub const WORKER_WORLD: &str = "sensor-node";
+
+/// a preopened directory granted to a world
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DirGrant {
+    pub path: String,
+    #[serde(default)]
+    pub readonly: bool,
+}
+
+/// grants for a single wit world
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WorldPolicy {
+    pub name: String,
+    #[serde(default)]
+    pub directories: Vec<DirGrant>,
+    #[serde(default)]
+    pub sockets: Vec<String>,
+    #[serde(default)]
+    pub registers: Vec<u16>,
+    /// bcm pins the world may drive (wit/actuator.wit gpio)
+    #[serde(default)]
+    pub gpio: Vec<u8>,
+    #[serde(default)]
+    pub memory_limit_mb: u32,
+    /// explicitly passed config, "KEY=value" (wasmtime --env)
+    #[serde(default)]
+    pub env: Vec<String>,
+}
+
+/// one `wasmtime run` flag and the grant it enforces
+#[derive(Clone, Debug, PartialEq)]
+pub struct WasmtimeFlag {
+    pub flag: String,
+    pub reason: String,
+}
+
+impl WasmtimeFlag {
+    fn new(flag: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self { flag: flag.into(), reason: reason.into() }
+    }
+}
+
+/// full policy file: one entry per world
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CapabilityPolicy {
+    #[serde(rename = "world")]
+    pub worlds: Vec<WorldPolicy>,
+}
+
+/// a capability an attack (or legitimate call) asks the host for
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CapabilityRequest {
+    Memory(u32),
+    Socket(&'static str),
+    Directory(&'static str),
+    Register(u16),
+    Env(&'static str),
+    Gpio(u8),
+}
+
+impl CapabilityRequest {
+    /// short label for logs and the matrix
+    pub fn label(&self) -> String {
+        match self {
+            CapabilityRequest::Memory(mb) => format!("memory {}MB", mb),
+            CapabilityRequest::Socket(addr) => format!("socket {}", addr),
+            CapabilityRequest::Directory(path) => format!("file {}", path),
+            CapabilityRequest::Register(reg) => format!("register {}", reg),
+            CapabilityRequest::Env(key) => format!("env {}", key),
+            CapabilityRequest::Gpio(pin) => format!("gpio {}", pin),
+        }
+    }
+}
+
+/// capability each security attack tries to obtain
+pub fn attack_request(attack: &str) -> Option<CapabilityRequest> {
+    match attack {
+        "bufferOverflow" => Some(CapabilityRequest::Memory(256)),
+        "dataExfil" => Some(CapabilityRequest::Socket("203.0.113.66:443")),
+        "pathTraversal" => Some(CapabilityRequest::Directory("/etc/passwd")),
+        "envTheft" => Some(CapabilityRequest::Env("AWS_SECRET_ACCESS_KEY")),
+        "lateralMovement" => Some(CapabilityRequest::Socket("10.255.255.1:502")),
+        _ => None,
+    }
+}
+
+impl CapabilityPolicy {
+    /// parse a policy from toml text
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// the embedded default policy
+    pub fn load_default() -> Self {
+        Self::from_toml(DEFAULT_POLICY_TOML).expect("wit/policy.toml must parse")
+    }
+
+    /// find the grants for a world by name
+    pub fn world(&self, name: &str) -> Option<&WorldPolicy> {
+        self.worlds.iter().find(|w| w.name == name)
+    }
+
+    /// evaluate a request for a world, unknown worlds are denied everything
+    pub fn evaluate(&self, world: &str, request: &CapabilityRequest) -> Result<(), String> {
+        match self.world(world) {
+            Some(w) => w.evaluate(request),
+            None => Err(format!("world '{}' not in policy", world)),
+        }
+    }
+}
+
+impl WorldPolicy {
+    /// whether a variable is passed into the world's environment
+    pub fn passes_env(&self, key: &str) -> bool {
+        self.env.iter().any(|e| e.split('=').next() == Some(key))
+    }
+
+    /// which of a process environment's keys the world would actually see
+    pub fn env_diff<'a>(&self, process_env: &[(&'a str, &str)]) -> Vec<(&'a str, bool)> {
+        process_env.iter().map(|(key, _)| (*key, self.passes_env(key))).collect()
+    }
+
+    /// `wasmtime run` flags that enforce this world's directories, env, memory and sockets, each with the grant it comes from
+    pub fn wasmtime_flags(&self) -> Vec<WasmtimeFlag> {
+        let mut flags: Vec<WasmtimeFlag> = self
+            .directories
+            .iter()
+            .map(|d| WasmtimeFlag::new(format!("--dir={}::{}", d.path, d.path), format!("preopen {} at the same guest path", d.path)))
+            .collect();
+        flags.extend(self.env.iter().map(|e| {
+            WasmtimeFlag::new(format!("--env={}", e), format!("pass {} (the component sees no other variable)", e.split('=').next().unwrap_or_default()))
+        }));
+        if self.memory_limit_mb > 0 {
+            flags.push(WasmtimeFlag::new(
+                format!("-W max-memory-size={}", u64::from(self.memory_limit_mb) * 1024 * 1024),
+                format!("cap linear memory at {}MB", self.memory_limit_mb),
+            ));
+        }
+        // a socket on an unspecified host is a listener the host preopens; anything else is an outbound peer
+        let (listen, connect): (Vec<&String>, Vec<&String>) = self.sockets.iter().partition(|s| s.starts_with("0.0.0.0:") || s.starts_with("[::]:"));
+        flags.extend(listen.iter().map(|s| WasmtimeFlag::new(format!("--tcplisten={}", s), format!("preopen a listener on {}", s))));
+        if !connect.is_empty() {
+            let peers = connect.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", ");
+            flags.push(WasmtimeFlag::new("-S inherit-network=y", format!("outbound to {}; wasmtime cannot narrow it to those peers", peers)));
+        } else if listen.is_empty() {
+            flags.push(WasmtimeFlag::new("-S tcp=n", "no socket granted: tcp off"));
+            flags.push(WasmtimeFlag::new("-S udp=n", "no socket granted: udp off"));
+        }
+        flags
+    }
+
+    /// just the flags, in command-line order
+    pub fn wasmtime_args(&self) -> Vec<String> {
+        self.wasmtime_flags().into_iter().map(|f| f.flag).collect()
+    }
+
+    /// the whole command, one flag per line, for `<name>.wasm`
+    pub fn wasmtime_command(&self) -> String {
+        let mut parts = vec!["wasmtime run".to_string()];
+        parts.extend(self.wasmtime_args());
+        parts.push(format!("{}.wasm", self.name));
+        parts.join(" \\\n    ")
+    }
+
+    /// grants no wasmtime flag can express; the guardian host enforces them through its own imports
+    pub fn host_enforced(&self) -> Vec<String> {
+        let mut notes: Vec<String> = self
+            .directories
+            .iter()
+            .filter(|d| d.readonly)
+            .map(|d| format!("{} read-only: --dir has no read-only mode, the host opens it without write rights", d.path))
+            .collect();
+        if !self.registers.is_empty() {
+            notes.push(format!("registers {:?}: checked by the host's read-hardware-register", self.registers));
+        }
+        if !self.gpio.is_empty() {
+            notes.push(format!("gpio {:?}: checked by the host's write-pin", self.gpio));
+        }
+        notes
+    }
+
+    /// deny-by-default check of a single request
+    pub fn evaluate(&self, request: &CapabilityRequest) -> Result<(), String> {
+        match request {
+            CapabilityRequest::Memory(mb) => {
+                if *mb <= self.memory_limit_mb {
+                    Ok(())
+                } else {
+                    Err(format!("memory limit {}MB < {}MB requested", self.memory_limit_mb, mb))
+                }
+            }
+            CapabilityRequest::Socket(addr) => {
+                if self.sockets.iter().any(|s| s == addr || s == "*") {
+                    Ok(())
+                } else {
+                    Err("capability not granted: network".to_string())
+                }
+            }
+            CapabilityRequest::Directory(path) => {
+                // compared per path component, so /dev/i2c-1 does not cover /dev/i2c-10; a ".." could climb out of the grant
+                let requested = Path::new(path);
+                let climbs = requested.components().any(|c| c == Component::ParentDir);
+                if !climbs && self.directories.iter().any(|d| requested.starts_with(&d.path)) {
+                    Ok(())
+                } else {
+                    Err("capability not granted: filesystem".to_string())
+                }
+            }
+            CapabilityRequest::Register(reg) => {
+                if self.registers.contains(reg) {
+                    Ok(())
+                } else {
+                    Err(format!("register {} not granted", reg))
+                }
+            }
+            CapabilityRequest::Env(key) => {
+                if self.passes_env(key) {
+                    Ok(())
+                } else {
+                    Err(format!("capability not granted: environment ({} not passed)", key))
+                }
+            }
+            CapabilityRequest::Gpio(pin) => {
+                if self.gpio.contains(pin) {
+                    Ok(())
+                } else {
+                    Err(format!("capability not granted: gpio {}", pin))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// what: capability matrix and live policy editor for the demo tab
+// why: lets viewers grant or revoke capabilities and watch attack outcomes change
+// relations: used by component.rs and info_box.rs (wasmtime commands beside the wit viewer), renders policy.rs model;
+//            copy via wasm.rs
+
+use leptos::*;
+use super::policy::{CapabilityPolicy, WorldPolicy, DEFAULT_POLICY_TOML};
+use super::wasm::copy_to_clipboard;
+
+/// worlds x capabilities grid rendered from the active policy
+#[component]
+pub fn CapabilityMatrix(policy: RwSignal<CapabilityPolicy>) -> impl IntoView {
+    view! {
+        <table class="capability-matrix">
+            <tr>
+                <th>"World"</th>
+                <th>"Filesystem"</th>
+                <th>"Network"</th>
+                <th>"Registers"</th>
+                <th>"GPIO"</th>
+                <th>"Memory"</th>
+                <th>"Environment"</th>
+            </tr>
+            {move || policy.get().worlds.into_iter().map(|w| view! { <MatrixRow world=w /> }).collect_view()}
+        </table>
+    }
+}
+
+/// single world row, empty grant lists render as denied
+#[component]
+fn MatrixRow(world: WorldPolicy) -> impl IntoView {
+    let cell = |granted: Vec<String>| {
+        if granted.is_empty() {
+            view! { <td class="denied">"❌ denied"</td> }
+        } else {
+            view! { <td class="granted">{format!("✅ {}", granted.join(", "))}</td> }
+        }
+    };
+    let dirs = world.directories.iter()
+        .map(|d| if d.readonly { format!("{} (ro)", d.path) } else { d.path.clone() })
+        .collect();
+    let regs = world.registers.iter().map(|r| r.to_string()).collect();
+    let pins = world.gpio.iter().map(|p| p.to_string()).collect();
+    let env = world.env.iter().map(|e| e.split('=').next().unwrap_or_default().to_string()).collect();
+
+    view! {
+        <tr>
+            <td class="world-name">{world.name.clone()}</td>
+            {cell(dirs)}
+            {cell(world.sockets.clone())}
+            {cell(regs)}
+            {cell(pins)}
+            <td>{format!("{}MB", world.memory_limit_mb)}</td>
+            {cell(env)}
+        </tr>
+    }
+}
+
+/// toml editor that swaps the active policy on every valid edit
+#[component]
+pub fn PolicyEditor(policy: RwSignal<CapabilityPolicy>) -> impl IntoView {
+    let (text, set_text) = create_signal(DEFAULT_POLICY_TOML.to_string());
+    let (error, set_error) = create_signal(Option::<String>::None);
+
+    let apply = move |src: String| {
+        match CapabilityPolicy::from_toml(&src) {
+            Ok(parsed) => {
+                policy.set(parsed);
+                set_error.set(None);
+            }
+            // keep the last valid policy active while the user is mid-edit
+            Err(e) => set_error.set(Some(e)),
+        }
+        set_text.set(src);
+    };
+
+    view! {
+        <div class="policy-editor">
+            <div class="policy-editor-header">
+                <span>"📝 wit/policy.toml"</span>
+                <button class="modal-close" title="Restore shipped policy" on:click=move |_| apply(DEFAULT_POLICY_TOML.to_string())>"↺"</button>
+            </div>
+            <textarea
+                class="policy-text"
+                spellcheck="false"
+                prop:value=move || text.get()
+                on:input=move |ev| apply(event_target_value(&ev))
+            />
+            {move || match error.get() {
+                Some(e) => view! { <p class="policy-error">{format!("⚠️ {} (previous policy still active)", e)}</p> }.into_view(),
+                None => view! { <p class="policy-ok">"✅ Policy active — attacks below use these grants"</p> }.into_view(),
+            }}
+        </div>
+    }
+}
+
+/// the `wasmtime run` command that enforces each world's grants on the pi, regenerated on every policy edit
+#[component]
+pub fn WasmtimeCommands(policy: RwSignal<CapabilityPolicy>) -> impl IntoView {
+    let (copied, set_copied) = create_signal(Option::<String>::None);
+    view! {
+        <div class="wasmtime-commands">
+            {move || policy.get().worlds.into_iter().map(|w| {
+                let command = w.wasmtime_command();
+                let name = w.name.clone();
+                let copy = {
+                    let command = command.clone();
+                    let name = name.clone();
+                    move |_| {
+                        copy_to_clipboard(&command);
+                        set_copied.set(Some(name.clone()));
+                    }
+                };
+                view! {
+                    <div class="wasmtime-world">
+                        <div class="wasmtime-header">
+                            <span class="world-name">{name.clone()}</span>
+                            <button class="action-btn" on:click=copy>
+                                {move || if copied.get().as_deref() == Some(name.as_str()) { "✅ Copied" } else { "📋 Copy" }}
+                            </button>
+                        </div>
+                        <pre class="wasmtime-cmd">{command}</pre>
+                        <ul class="wasmtime-flags">
+                            {w.wasmtime_flags().into_iter().map(|f| view! { <li><code>{f.flag}</code>" — "{f.reason}</li> }).collect_view()}
+                            {w.host_enforced().into_iter().map(|n| view! { <li class="host-enforced">{format!("🛡️ {}", n)}</li> }).collect_view()}
+                        </ul>
+                    </div>
+                }
+            }).collect_view()}
+        </div>
+    }
+}
@@ -0,0 +1,70 @@
+// what: python worker pool strategies - cold-start respawn vs pre-forked standby interpreters - and the output gap each leaves after a crash
+// why: comparing wasm against a naive python deployment is easy to win; pre-forking is what a careful python shop would run
+// relations: strategy lives in the store.rs pool slice, applied by handlers.rs python failovers, picked in supervisor_panel.rs
+
+/// supervisor heartbeat period: a dead worker is noticed within one
+pub const HEARTBEAT_MS: u32 = 100;
+/// state re-sync before any python sensor run has been timed
+pub const DEFAULT_RESYNC_MS: u32 = 50;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PythonStrategy {
+    /// a crashed worker's replacement loads a fresh interpreter before output resumes
+    #[default]
+    ColdStart,
+    /// standbys keep a loaded interpreter; one takes over after a state re-sync, the dead worker is re-forked behind it
+    PreForked,
+}
+
+impl PythonStrategy {
+    pub const ALL: [PythonStrategy; 2] = [PythonStrategy::ColdStart, PythonStrategy::PreForked];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            PythonStrategy::ColdStart => "cold-start",
+            PythonStrategy::PreForked => "pre-forked",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.id() == id)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PythonStrategy::ColdStart => "Cold-start respawn",
+            PythonStrategy::PreForked => "Pre-forked standbys",
+        }
+    }
+
+    pub fn note(&self) -> &'static str {
+        match self {
+            PythonStrategy::ColdStart => "naive deployment: every crash waits for a Pyodide cold start",
+            PythonStrategy::PreForked => "best practice: a loaded standby takes over after heartbeat detection and a state re-sync",
+        }
+    }
+}
+
+/// how one python worker failure plays out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Takeover {
+    /// strategy that actually ran (pre-forked with no standby left cold-starts)
+    pub used: PythonStrategy,
+    /// crash until output resumes
+    pub output_gap_ms: u32,
+    /// crash until every worker is back
+    pub pool_restored_ms: u32,
+}
+
+/// `standbys` are the other workers up when one fails; the re-sync is one warm sensor run
+/// (the measured python run once there is one)
+pub fn takeover(strategy: PythonStrategy, standbys: usize, cold_start_ms: u32, sensor_run_ms: f64) -> Takeover {
+    match strategy {
+        PythonStrategy::PreForked if standbys > 0 => {
+            let resync = if sensor_run_ms > 0.0 { sensor_run_ms.ceil() as u32 } else { DEFAULT_RESYNC_MS };
+            let gap = HEARTBEAT_MS + resync;
+            Takeover { used: PythonStrategy::PreForked, output_gap_ms: gap, pool_restored_ms: cold_start_ms.max(gap) }
+        }
+        _ => Takeover { used: PythonStrategy::ColdStart, output_gap_ms: cold_start_ms, pool_restored_ms: cold_start_ms },
+    }
+}
@@ -0,0 +1,211 @@
+// what: real engine and parser probes run during attacks
+// why: show the browser's own failure for an attack instead of narrating a hypothetical trap
+// relations: instantiates modules.rs bytes, runs modbus_parser::mbap, called from handlers.rs, lines shown in the wasm terminal
+
+use wasm_bindgen::{JsCast, JsValue};
+use modbus_parser::mbap::{parse_adu, MbapError};
+use super::modules::{BOUNDS_PAGES, BOUNDS_WASM, GROW_MAX_PAGES, GROW_WASM, RECURSE_WASM};
+use super::types::LogEntry;
+use super::wasm::now;
+
+/// bytes per wasm page
+pub const WASM_PAGE_BYTES: u32 = 65_536;
+/// pages the buffer-overflow probe asks for (1 MiB)
+pub const OVERFLOW_GROW_PAGES: u32 = 16;
+/// address the bounds probe reads (four pages past the end)
+pub const OVERFLOW_ADDRESS: u32 = 4 * WASM_PAGE_BYTES;
+/// depth the stack probe asks for (far past any engine's call-stack limit)
+pub const STACK_PROBE_DEPTH: u32 = 100_000_000;
+/// depth of the follow-up call that checks the instance is still usable
+pub const STACK_CHECK_DEPTH: u32 = 8;
+
+/// instantiates a module synchronously and returns its exports object
+fn exports(bytes: &[u8]) -> Result<js_sys::Object, String> {
+    let array = js_sys::Uint8Array::from(bytes);
+    let module = js_sys::WebAssembly::Module::new(&array).map_err(|e| format!("{:?}", e))?;
+    let instance = js_sys::WebAssembly::Instance::new(&module, &js_sys::Object::new()).map_err(|e| format!("{:?}", e))?;
+    Ok(instance.exports())
+}
+
+/// "RuntimeError: memory access out of bounds" style text for a thrown js value
+fn js_error_text(err: &JsValue) -> String {
+    err.dyn_ref::<js_sys::Error>()
+        .map(|e| String::from(e.to_string()))
+        .or_else(|| err.as_string())
+        .unwrap_or_else(|| format!("{:?}", err))
+}
+
+fn export_fn(exports: &js_sys::Object, name: &str) -> Result<js_sys::Function, String> {
+    js_sys::Reflect::get(exports, &name.into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+        .ok_or_else(|| format!("missing export {}", name))
+}
+
+// ============================================================================
+// heap growth
+// ============================================================================
+
+/// result of asking a capped linear memory to grow
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GrowOutcome {
+    pub instance: u8,
+    pub pages_before: u32,
+    pub max_pages: u32,
+    pub requested: u32,
+    /// memory.grow return value (-1 = denied)
+    pub result: i32,
+}
+
+impl GrowOutcome {
+    pub fn denied(&self) -> bool {
+        self.result < 0
+    }
+
+    /// terminal lines describing the real grow request
+    pub fn lines(&self) -> Vec<LogEntry> {
+        let kib = |pages: u32| pages * WASM_PAGE_BYTES / 1024;
+        let mut lines = vec![LogEntry {
+            level: "info".into(),
+            message: format!("[MEM] I{}: linear memory {} KiB, limit {} KiB ({} pages)", self.instance, kib(self.pages_before), kib(self.max_pages), self.max_pages),
+        }];
+        lines.push(if self.denied() {
+            LogEntry { level: "warn".into(), message: format!("[MEM] memory.grow({}) = -1 → {} KiB over-allocation denied by the engine (real)", self.requested, kib(self.requested)) }
+        } else {
+            LogEntry { level: "error".into(), message: format!("[MEM] memory.grow({}) = {} → allocation succeeded", self.requested, self.result) }
+        });
+        lines
+    }
+}
+
+/// instantiates GROW_WASM and asks it for `requested` more pages
+pub fn grow_probe(instance: u8, requested: u32) -> Result<GrowOutcome, String> {
+    let exports = exports(GROW_WASM)?;
+    let memory: js_sys::WebAssembly::Memory = js_sys::Reflect::get(&exports, &"memory".into())
+        .ok()
+        .and_then(|m| m.dyn_into().ok())
+        .ok_or("missing export memory")?;
+    let pages_before = memory.buffer().unchecked_into::<js_sys::ArrayBuffer>().byte_length() / WASM_PAGE_BYTES;
+    let result = export_fn(&exports, "grow")?
+        .call1(&JsValue::NULL, &JsValue::from(requested))
+        .map_err(|e| format!("{:?}", e))?
+        .as_f64()
+        .unwrap_or(0.0) as i32;
+    Ok(GrowOutcome { instance, pages_before, max_pages: GROW_MAX_PAGES, requested, result })
+}
+
+// ============================================================================
+// bounds check
+// ============================================================================
+
+/// result of reading past the end of linear memory
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundsOutcome {
+    pub instance: u8,
+    pub address: u32,
+    pub memory_bytes: u32,
+    /// the engine's exception text, None if the read returned a value
+    pub error: Option<String>,
+}
+
+impl BoundsOutcome {
+    /// terminal line for the trap (the exception text is shown verbatim)
+    pub fn trap_line(&self) -> LogEntry {
+        match &self.error {
+            Some(text) => LogEntry { level: "warn".into(), message: format!("[TRAP] I{}: load @0x{:x} (memory {} KiB) → {} (real)", self.instance, self.address, self.memory_bytes / 1024, text) },
+            None => LogEntry { level: "error".into(), message: format!("[TRAP] I{}: load @0x{:x} returned a value - bounds check missing", self.instance, self.address) },
+        }
+    }
+}
+
+/// instantiates BOUNDS_WASM and reads `address`, catching the engine's trap
+pub fn bounds_probe(instance: u8, address: u32) -> Result<BoundsOutcome, String> {
+    let exports = exports(BOUNDS_WASM)?;
+    let error = export_fn(&exports, "load")?
+        .call1(&JsValue::NULL, &JsValue::from(address))
+        .err()
+        .map(|e| js_error_text(&e));
+    Ok(BoundsOutcome { instance, address, memory_bytes: BOUNDS_PAGES * WASM_PAGE_BYTES, error })
+}
+
+// ============================================================================
+// stack exhaustion
+// ============================================================================
+
+/// result of unbounded recursion inside one instance
+#[derive(Clone, Debug, PartialEq)]
+pub struct StackOutcome {
+    pub instance: u8,
+    pub depth: u32,
+    /// the engine's exception text, None if the recursion returned
+    pub error: Option<String>,
+    /// time from the call to the trap reaching the host
+    pub detect_ms: f64,
+    /// recurse(STACK_CHECK_DEPTH) on the same instance after the trap
+    pub followup: Option<i32>,
+}
+
+impl StackOutcome {
+    /// the instance kept answering after the trap (only the one call was lost)
+    pub fn contained(&self) -> bool {
+        self.error.is_some() && self.followup == Some(STACK_CHECK_DEPTH as i32)
+    }
+
+    /// terminal lines for the trap and its blast radius
+    pub fn lines(&self) -> Vec<LogEntry> {
+        let trap = match &self.error {
+            Some(text) => LogEntry { level: "warn".into(), message: format!("[TRAP] I{}: recurse({}) → {} after {:.2}ms (real)", self.instance, self.depth, text, self.detect_ms) },
+            None => LogEntry { level: "error".into(), message: format!("[TRAP] I{}: recurse({}) returned - no stack limit hit", self.instance, self.depth) },
+        };
+        let radius = if self.contained() {
+            LogEntry { level: "info".into(), message: format!("[STACK] I{}: recurse({}) = {} on the same instance → blast radius: one call", self.instance, STACK_CHECK_DEPTH, STACK_CHECK_DEPTH) }
+        } else {
+            LogEntry { level: "warn".into(), message: format!("[STACK] I{}: instance unusable after the trap → rebuild required", self.instance) }
+        };
+        vec![trap, radius]
+    }
+}
+
+/// instantiates RECURSE_WASM, recurses `depth` deep and times the engine's stack trap
+pub fn stack_probe(instance: u8, depth: u32) -> Result<StackOutcome, String> {
+    let recurse = export_fn(&exports(RECURSE_WASM)?, "recurse")?;
+    let start = now();
+    let error = recurse.call1(&JsValue::NULL, &JsValue::from(depth)).err().map(|e| js_error_text(&e));
+    let detect_ms = now() - start;
+    let followup = recurse.call1(&JsValue::NULL, &JsValue::from(STACK_CHECK_DEPTH)).ok().and_then(|v| v.as_f64()).map(|v| v as i32);
+    Ok(StackOutcome { instance, depth, error, detect_ms, followup })
+}
+
+// ============================================================================
+// length field (parser confusion)
+// ============================================================================
+
+/// result of feeding a crafted adu to this repo's rust mbap parser
+#[derive(Clone, Debug, PartialEq)]
+pub struct LengthOutcome {
+    pub instance: u8,
+    /// the mbap length field as sent
+    pub length: u16,
+    /// pdu bytes accepted, or the typed refusal
+    pub result: Result<usize, MbapError>,
+}
+
+impl LengthOutcome {
+    /// terminal lines for the parse (the typed error is shown as returned)
+    pub fn lines(&self) -> Vec<LogEntry> {
+        match &self.result {
+            Err(e) => vec![
+                LogEntry { level: "warn".into(), message: format!("[PARSE] I{}: mbap::parse_adu → Err({:?}) (real)", self.instance, e) },
+                LogEntry { level: "info".into(), message: format!("[PARSE] {} - checked_sub refused, nothing allocated", e.reason()) },
+            ],
+            Ok(pdu_len) => vec![LogEntry { level: "error".into(), message: format!("[PARSE] I{}: length {} accepted ({} pdu bytes) - validation missing", self.instance, self.length, pdu_len) }],
+        }
+    }
+}
+
+/// runs modbus_parser::mbap::parse_adu on `adu` for real
+pub fn length_probe(instance: u8, adu: &[u8]) -> LengthOutcome {
+    let length = adu.get(4..6).map(|b| u16::from_be_bytes([b[0], b[1]])).unwrap_or(0);
+    let result = parse_adu(adu).map(|frame| frame.data.len() + 1);
+    LengthOutcome { instance, length, result }
+}
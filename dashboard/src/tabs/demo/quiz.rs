@@ -0,0 +1,118 @@
+// what: quiz mode - the viewer predicts what an attack does to each runtime, then the real run's journal scores the guess
+// why: workshops stick better when people commit to an answer first; the score comes from what actually ran, not a key
+// relations: attack names from attacks.rs, actual outcomes from summary.rs outcomes over journal.rs events,
+//            state in store.rs, asked and scored by quiz_panel.rs, attack buttons in attack_controls.rs divert to it
+
+use serde::{Deserialize, Serialize};
+use super::journal::{AuditEvent, EventKind};
+use super::summary::outcomes;
+
+/// what can happen to one runtime
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Answer {
+    /// crashes, halts or otherwise stops serving
+    Fails,
+    /// blocks or traps the attack and keeps going
+    Contained,
+    /// the attack gets what it asked for
+    Breached,
+    /// nothing worth journalling happens (a failover counts: the cluster keeps serving)
+    KeepsRunning,
+}
+
+impl Answer {
+    pub const ALL: [Answer; 4] = [Answer::Fails, Answer::Contained, Answer::Breached, Answer::KeepsRunning];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Answer::Fails => "💥 Goes down",
+            Answer::Contained => "🛡️ Blocks / traps it",
+            Answer::Breached => "🔓 Attack succeeds",
+            Answer::KeepsRunning => "✅ Keeps running",
+        }
+    }
+
+    /// the answer a runtime's worst journalled fault stands for
+    pub fn from_fault(fault: Option<EventKind>) -> Answer {
+        match fault {
+            Some(EventKind::Crash | EventKind::QuorumLost | EventKind::Overload | EventKind::Leak) => Answer::Fails,
+            Some(EventKind::Trap | EventKind::Outvoted | EventKind::Anomaly | EventKind::LoadShed) => Answer::Contained,
+            Some(EventKind::PolicyBreach) => Answer::Breached,
+            Some(EventKind::AttackStarted | EventKind::Recovery | EventKind::Election | EventKind::HostCall | EventKind::Reset) | None => Answer::KeepsRunning,
+        }
+    }
+}
+
+/// a locked-in guess waiting for its run to finish
+#[derive(Clone, Debug, PartialEq)]
+pub struct Prediction {
+    pub attack: String,
+    /// python, wasm
+    pub guess: [Answer; 2],
+    /// journal events from this time on belong to the run
+    pub since_ms: f64,
+}
+
+/// one scored question
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuizResult {
+    pub attack: String,
+    pub name: String,
+    pub guess: [Answer; 2],
+    pub actual: [Answer; 2],
+}
+
+impl QuizResult {
+    pub fn right(&self) -> usize {
+        (0..2).filter(|&i| self.guess[i] == self.actual[i]).count()
+    }
+}
+
+/// quiz state kept in the store
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Quiz {
+    pub enabled: bool,
+    /// attack whose prediction form is open
+    pub asking: Option<String>,
+    pub pending: Option<Prediction>,
+    pub results: Vec<QuizResult>,
+}
+
+impl Quiz {
+    /// opens the prediction form instead of running `attack`
+    pub fn ask(&mut self, attack: &str) {
+        self.asking = Some(attack.to_string());
+    }
+
+    /// locks in the guess for the open question; returns the attack to run
+    pub fn lock(&mut self, guess: [Answer; 2], now_ms: f64) -> Option<String> {
+        let attack = self.asking.take()?;
+        self.pending = Some(Prediction { attack: attack.clone(), guess, since_ms: now_ms });
+        Some(attack)
+    }
+
+    /// scores the pending guess against the finished run's journal events;
+    /// a run that never started (nothing journalled) drops the guess unscored
+    pub fn score(&mut self, events: &[AuditEvent]) -> Option<&QuizResult> {
+        let prediction = self.pending.take()?;
+        let run: Vec<AuditEvent> = events.iter().filter(|e| e.timestamp_ms >= prediction.since_ms).cloned().collect();
+        let outcome = outcomes(&run).into_iter().rev().find(|o| o.attack == prediction.attack)?;
+        self.results.push(QuizResult {
+            attack: prediction.attack,
+            name: outcome.name,
+            guess: prediction.guess,
+            actual: [Answer::from_fault(outcome.python), Answer::from_fault(outcome.wasm)],
+        });
+        self.results.last()
+    }
+
+    /// (right, asked) over both runtimes of every scored question
+    pub fn total(&self) -> (usize, usize) {
+        (self.results.iter().map(QuizResult::right).sum(), self.results.len() * 2)
+    }
+
+    /// clears the score, keeps quiz mode on
+    pub fn restart(&mut self) {
+        *self = Quiz { enabled: self.enabled, ..Quiz::default() };
+    }
+}
@@ -0,0 +1,112 @@
+// what: quiz mode panel - toggle, the prediction form for the pressed attack, the pending guess and the scored answers
+// why: puts the "what do you think happens?" moment of a workshop on screen before the real run answers it
+// relations: quiz.rs state in the store.rs quiz signal, handlers.rs press/answer_quiz/settle_quiz, attack names from attacks.rs;
+//            rendered by component.rs under the attack controls
+
+use leptos::*;
+use super::attacks::get_attack_config;
+use super::quiz::Answer;
+use super::store::DemoStore;
+
+/// one runtime's row of answer buttons
+fn answer_row(label: &'static str, picked: ReadSignal<Option<Answer>>, pick: WriteSignal<Option<Answer>>) -> impl IntoView {
+    view! {
+        <div class="quiz-row">
+            <span class="world-name">{label}</span>
+            {Answer::ALL.into_iter().map(|a| view! {
+                <button class="action-btn quiz-answer" class:picked=move || picked.get() == Some(a) on:click=move |_| pick.set(Some(a))>
+                    {a.label()}
+                </button>
+            }).collect_view()}
+        </div>
+    }
+}
+
+#[component]
+pub fn QuizPanel(store: DemoStore) -> impl IntoView {
+    let DemoStore { quiz, control, .. } = store;
+    let (python, set_python) = create_signal(Option::<Answer>::None);
+    let (wasm, set_wasm) = create_signal(Option::<Answer>::None);
+    // a run that was guessed on is scored when it finishes
+    create_effect(move |was_running: Option<bool>| {
+        let running = control.is_running.get();
+        if was_running == Some(true) && !running {
+            store.settle_quiz();
+        }
+        running
+    });
+    let lock = move |_| {
+        if let (Some(p), Some(w)) = (python.get_untracked(), wasm.get_untracked()) {
+            set_python.set(None);
+            set_wasm.set(None);
+            store.answer_quiz([p, w]);
+        }
+    };
+
+    let form = move || quiz.with(|q| q.asking.clone()).map(|attack| view! {
+        <div class="quiz-card">
+            <h4>{format!("What happens when {} hits each runtime?", get_attack_config(&attack).name)}</h4>
+            {answer_row("🐍 Python", python, set_python)}
+            {answer_row("🦀 WASM", wasm, set_wasm)}
+            <div class="voting-controls">
+                <button class="action-btn" disabled=move || python.get().is_none() || wasm.get().is_none() || control.is_running.get() on:click=lock>
+                    "🔒 Lock In & Run"
+                </button>
+                <button class="action-btn" on:click=move |_| quiz.update(|q| q.asking = None)>"Cancel"</button>
+            </div>
+        </div>
+    });
+    let pending = move || quiz.with(|q| q.pending.clone()).map(|p| view! {
+        <p class="metrics-note">{format!("⏳ {} running — you said Python: {}, WASM: {}", get_attack_config(&p.attack).name, p.guess[0].label(), p.guess[1].label())}</p>
+    });
+    let results = move || quiz.with(|q| (!q.results.is_empty()).then(|| view! {
+        <table class="capability-matrix voting-matrix">
+            <tr><th>"Attack"</th><th>"🐍 You said → it did"</th><th>"🦀 You said → it did"</th></tr>
+            {q.results.iter().rev().map(|r| view! {
+                <tr>
+                    <td class="world-name">{r.name.clone()}</td>
+                    {(0..2).map(|i| {
+                        let right = r.guess[i] == r.actual[i];
+                        view! {
+                            <td class:granted=right class:vote-halted=!right>
+                                {format!("{} {} → {}", if right { "✓" } else { "✗" }, r.guess[i].label(), r.actual[i].label())}
+                            </td>
+                        }
+                    }).collect_view()}
+                </tr>
+            }).collect_view()}
+        </table>
+    }));
+
+    view! {
+        <div class="demo-section quiz-section">
+            <h3>
+                "🎓 Quiz Mode"
+                <span class="attack-badge">{move || quiz.with(|q| {
+                    let (right, asked) = q.total();
+                    if asked == 0 { "Predict, then run".to_string() } else { format!("Score {}/{}", right, asked) }
+                })}</span>
+            </h3>
+            <p class="section-desc">"With quiz mode on, an attack button first asks what you expect Python and WASM to do. The real run answers; the score comes from the event journal, not an answer key."</p>
+            <div class="voting-controls">
+                <label class="settings-check">
+                    <input type="checkbox" prop:checked=move || quiz.with(|q| q.enabled)
+                        on:change=move |ev| {
+                            let on = event_target_checked(&ev);
+                            quiz.update(|q| {
+                                q.enabled = on;
+                                q.asking = None;
+                            });
+                        } />
+                    "Ask before each attack"
+                </label>
+                <button class="action-btn" disabled=move || quiz.with(|q| q.results.is_empty()) on:click=move |_| quiz.update(|q| q.restart())>
+                    "↺ Restart Quiz"
+                </button>
+            </div>
+            {form}
+            {pending}
+            {results}
+        </div>
+    }
+}
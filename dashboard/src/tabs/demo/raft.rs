@@ -0,0 +1,500 @@
+// what: raft leader election (terms, randomized election timeouts, requestvote) run as a discrete-event simulation
+// why: the failover number has to come from the protocol's timing over many crashes, not one instantiate measurement
+// relations: benchmarked once by store.rs (PerfSlice::election), one trial per leader crash in handlers.rs, quoted by info_box.rs,
+//            its message trace animated by election.rs;
+//            the replicated log is persisted by raft_persist.rs and shown by raft_panel.rs
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use serde::{Deserialize, Serialize};
+use crate::measure::jitter::percentile;
+
+/// nodes in the guardian cluster
+pub const CLUSTER_SIZE: usize = 3;
+/// leader heartbeat period
+pub const HEARTBEAT_MS: f64 = 50.0;
+/// followers pick a fresh election timeout in this range (raft paper §9.3)
+pub const ELECTION_TIMEOUT_MS: (f64, f64) = (150.0, 300.0);
+/// one-way message delay on the cluster lan
+pub const ONE_WAY_MS: f64 = 0.5;
+/// failovers per benchmark run
+pub const ELECTION_TRIALS: usize = 500;
+/// fixed seed so the quoted p50/p99 are reproducible
+pub const ELECTION_SEED: u64 = 0x6a09_e667_f3bc_c908;
+/// events processed before a trial is declared stuck
+const MAX_EVENTS: usize = 100_000;
+
+/// xorshift64 (deterministic per seed); also drives anomaly.rs's telemetry stream
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed | 1)
+    }
+
+    /// uniform in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn between(&mut self, (lo, hi): (f64, f64)) -> f64 {
+        lo + (hi - lo) * self.next_f64()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    Follower,
+    Candidate,
+    Leader,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Message {
+    /// election timer fired; stale if the epoch no longer matches
+    Timeout { epoch: u64 },
+    RequestVote { term: u64, from: usize },
+    Vote { term: u64, granted: bool },
+}
+
+struct Node {
+    up: bool,
+    role: Role,
+    term: u64,
+    voted_for: Option<usize>,
+    votes: usize,
+    timer_epoch: u64,
+}
+
+/// what a failover run did, in order; messages are recorded when sent
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ElectionEvent {
+    /// the term leader stops
+    Crash { node: usize },
+    /// election timer fired: the node stands as candidate in `term`
+    Stand { node: usize, term: u64 },
+    RequestVote { from: usize, to: usize, term: u64 },
+    /// reply to a RequestVote, carrying the voter's term
+    Vote { from: usize, to: usize, term: u64, granted: bool },
+    Elected { node: usize, term: u64 },
+}
+
+/// one traced event; `at_ms` is simulated time since the crash
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ElectionStep {
+    pub at_ms: f64,
+    pub event: ElectionEvent,
+}
+
+/// one simulated leader crash
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ElectionTrial {
+    /// crash to new leader, simulated milliseconds
+    pub latency_ms: f64,
+    /// terms it took (more than 1 means a split vote)
+    pub terms: u64,
+    pub leader: usize,
+}
+
+struct Sim {
+    rng: Rng,
+    nodes: Vec<Node>,
+    /// (deliver at µs, sequence, recipient, message)
+    queue: BinaryHeap<Reverse<(u64, u64, usize, Message)>>,
+    seq: u64,
+    crash_us: u64,
+    trace: Vec<ElectionStep>,
+}
+
+fn us(ms: f64) -> u64 {
+    (ms * 1000.0).round() as u64
+}
+
+impl Sim {
+    fn send(&mut self, at_us: u64, to: usize, message: Message) {
+        self.seq += 1;
+        self.queue.push(Reverse((at_us, self.seq, to, message)));
+    }
+
+    fn reset_timer(&mut self, id: usize, now_us: u64) {
+        self.nodes[id].timer_epoch += 1;
+        let epoch = self.nodes[id].timer_epoch;
+        let timeout = self.rng.between(ELECTION_TIMEOUT_MS);
+        self.send(now_us + us(timeout), id, Message::Timeout { epoch });
+    }
+
+    fn record(&mut self, now_us: u64, event: ElectionEvent) {
+        let at_ms = now_us.saturating_sub(self.crash_us) as f64 / 1000.0;
+        self.trace.push(ElectionStep { at_ms, event });
+    }
+
+    /// a newer term turns any node back into a follower
+    fn observe_term(&mut self, id: usize, term: u64) {
+        let node = &mut self.nodes[id];
+        if term > node.term {
+            node.term = term;
+            node.role = Role::Follower;
+            node.voted_for = None;
+        }
+    }
+}
+
+/// crashes `crashed` (the term-1 leader) at a random point in the heartbeat cycle and runs until a new leader wins
+pub fn failover(seed: u64, crashed: usize) -> Option<ElectionTrial> {
+    failover_traced(seed, crashed).0
+}
+
+/// `failover` plus every message the nodes exchanged on the way (the trace ends early if the trial gets stuck)
+pub fn failover_traced(seed: u64, crashed: usize) -> (Option<ElectionTrial>, Vec<ElectionStep>) {
+    let mut sim = Sim {
+        rng: Rng::new(seed),
+        nodes: (0..CLUSTER_SIZE)
+            .map(|id| Node { up: true, role: if id == crashed { Role::Leader } else { Role::Follower }, term: 1, voted_for: Some(crashed), votes: 0, timer_epoch: 0 })
+            .collect(),
+        queue: BinaryHeap::new(),
+        seq: 0,
+        crash_us: 0,
+        trace: Vec::new(),
+    };
+    // last heartbeat left at t=0 and reset every follower's timer on arrival
+    for id in (0..CLUSTER_SIZE).filter(|id| *id != crashed) {
+        sim.reset_timer(id, us(ONE_WAY_MS));
+    }
+    sim.crash_us = us(sim.rng.between((0.0, HEARTBEAT_MS)));
+    sim.nodes[crashed].up = false;
+    sim.nodes[crashed].role = Role::Follower;
+    sim.record(sim.crash_us, ElectionEvent::Crash { node: crashed });
+
+    for _ in 0..MAX_EVENTS {
+        let Some(Reverse((now_us, _, id, message))) = sim.queue.pop() else { break };
+        if !sim.nodes[id].up {
+            continue;
+        }
+        match message {
+            Message::Timeout { epoch } => {
+                if epoch != sim.nodes[id].timer_epoch || sim.nodes[id].role == Role::Leader {
+                    continue;
+                }
+                let node = &mut sim.nodes[id];
+                node.term += 1;
+                node.role = Role::Candidate;
+                node.voted_for = Some(id);
+                node.votes = 1;
+                let term = node.term;
+                sim.record(now_us, ElectionEvent::Stand { node: id, term });
+                sim.reset_timer(id, now_us);
+                for peer in (0..CLUSTER_SIZE).filter(|p| *p != id) {
+                    sim.record(now_us, ElectionEvent::RequestVote { from: id, to: peer, term });
+                    sim.send(now_us + us(ONE_WAY_MS), peer, Message::RequestVote { term, from: id });
+                }
+            }
+            Message::RequestVote { term, from } => {
+                sim.observe_term(id, term);
+                let node = &sim.nodes[id];
+                let granted = term == node.term && node.voted_for.is_none_or(|v| v == from);
+                if granted {
+                    sim.nodes[id].voted_for = Some(from);
+                    sim.reset_timer(id, now_us);
+                }
+                let reply = Message::Vote { term: sim.nodes[id].term, granted };
+                sim.record(now_us, ElectionEvent::Vote { from: id, to: from, term: sim.nodes[id].term, granted });
+                sim.send(now_us + us(ONE_WAY_MS), from, reply);
+            }
+            Message::Vote { term, granted } => {
+                sim.observe_term(id, term);
+                let node = &mut sim.nodes[id];
+                if node.role != Role::Candidate || term != node.term || !granted {
+                    continue;
+                }
+                node.votes += 1;
+                if node.votes * 2 > CLUSTER_SIZE {
+                    node.role = Role::Leader;
+                    let term = node.term;
+                    sim.record(now_us, ElectionEvent::Elected { node: id, term });
+                    let trial = ElectionTrial {
+                        latency_ms: now_us.saturating_sub(sim.crash_us) as f64 / 1000.0,
+                        terms: term - 1,
+                        leader: id,
+                    };
+                    return (Some(trial), sim.trace);
+                }
+            }
+        }
+    }
+    (None, sim.trace)
+}
+
+/// election latency distribution over many failovers
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ElectionBench {
+    pub trials: usize,
+    pub p50_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+    pub mean_terms: f64,
+    pub max_terms: u64,
+    /// trials that needed more than one term (split votes)
+    pub split_votes: usize,
+}
+
+impl ElectionBench {
+    /// `trials` failovers, crashing each node in turn
+    pub fn run(trials: usize, seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        let results: Vec<ElectionTrial> = (0..trials)
+            .filter_map(|i| failover((rng.next_f64() * u64::MAX as f64) as u64, i % CLUSTER_SIZE))
+            .collect();
+        let latencies: Vec<f64> = results.iter().map(|t| t.latency_ms).collect();
+        Self {
+            trials: results.len(),
+            p50_ms: percentile(&latencies, 50.0),
+            p99_ms: percentile(&latencies, 99.0),
+            max_ms: latencies.iter().copied().fold(0.0, f64::max),
+            mean_terms: results.iter().map(|t| t.terms as f64).sum::<f64>() / results.len().max(1) as f64,
+            max_terms: results.iter().map(|t| t.terms).max().unwrap_or(0),
+            split_votes: results.iter().filter(|t| t.terms > 1).count(),
+        }
+    }
+
+    /// one-line claim for logs and the info box
+    pub fn claim(&self) -> String {
+        format!("p50 {:.0}ms / p99 {:.0}ms over {} failovers", self.p50_ms, self.p99_ms, self.trials)
+    }
+}
+
+// ============================================================================
+// replicated log (telemetry commits, snapshot compaction)
+// ============================================================================
+
+/// committed entries folded into a snapshot once this many pile up past the last one
+pub const SNAPSHOT_EVERY: u64 = 8;
+
+/// one replicated telemetry commit
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub index: u64,
+    pub term: u64,
+    /// voted sensor value
+    pub value: f64,
+}
+
+/// compacted prefix of the log: position plus the state machine it produced
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub last_index: u64,
+    pub last_term: u64,
+    /// state machine: latest applied value and how many commits were applied
+    pub last_value: f64,
+    pub applied: u64,
+}
+
+/// one node's persistent raft state (currentTerm, votedFor, log)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LogNode {
+    pub up: bool,
+    /// latest term this node has seen
+    pub term: u64,
+    pub voted_for: Option<usize>,
+    pub entries: Vec<LogEntry>,
+    pub snapshot: Snapshot,
+    pub commit_index: u64,
+}
+
+impl LogNode {
+    pub fn last_index(&self) -> u64 {
+        self.entries.last().map(|e| e.index).unwrap_or(self.snapshot.last_index)
+    }
+
+    pub fn last_term(&self) -> u64 {
+        self.entries.last().map(|e| e.term).unwrap_or(self.snapshot.last_term)
+    }
+
+    /// term of the entry at `index` (from the snapshot at its boundary), None if compacted away or missing
+    fn term_at(&self, index: u64) -> Option<u64> {
+        if index == self.snapshot.last_index {
+            return Some(self.snapshot.last_term);
+        }
+        self.entries.iter().find(|e| e.index == index).map(|e| e.term)
+    }
+
+    /// raft §5.4.1: is this log at least as up to date as (term, index)?
+    fn up_to_date(&self, term: u64, index: u64) -> bool {
+        (self.last_term(), self.last_index()) >= (term, index)
+    }
+
+    /// folds committed entries into the snapshot once SNAPSHOT_EVERY have built up
+    fn compact(&mut self) {
+        if self.commit_index < self.snapshot.last_index + SNAPSHOT_EVERY {
+            return;
+        }
+        let (done, keep): (Vec<LogEntry>, Vec<LogEntry>) = self.entries.iter().partition(|e| e.index <= self.commit_index);
+        if let Some(last) = done.last() {
+            self.snapshot = Snapshot {
+                last_index: last.index,
+                last_term: last.term,
+                last_value: last.value,
+                applied: self.snapshot.applied + done.len() as u64,
+            };
+        }
+        self.entries = keep;
+    }
+}
+
+/// the three-node cluster's logs, driven by the demo's commits, crashes and elections
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReplicatedLog {
+    pub nodes: Vec<LogNode>,
+    pub leader: usize,
+    pub term: u64,
+    /// snapshots shipped to lagging followers
+    pub snapshots_installed: u64,
+}
+
+impl Default for ReplicatedLog {
+    fn default() -> Self {
+        let node = LogNode { up: true, term: 1, voted_for: Some(0), ..Default::default() };
+        Self { nodes: vec![node; CLUSTER_SIZE], leader: 0, term: 1, snapshots_installed: 0 }
+    }
+}
+
+impl ReplicatedLog {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// persisted state, rejected if it does not describe a CLUSTER_SIZE cluster
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let log: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if log.nodes.len() != CLUSTER_SIZE || log.leader >= CLUSTER_SIZE {
+            return Err(format!("expected {} nodes", CLUSTER_SIZE));
+        }
+        Ok(log)
+    }
+
+    /// every node restarts from its persisted state (page reload): they elect a leader in a new term and catch up
+    pub fn recover(&mut self) -> Option<usize> {
+        for node in self.nodes.iter_mut() {
+            node.up = true;
+        }
+        // a restarted node's timer starts from the highest term any node persisted
+        self.term = self.nodes.iter().map(|n| n.term).max().unwrap_or(self.term);
+        let leader = self.elect(self.leader, 1)?;
+        for id in 0..CLUSTER_SIZE {
+            self.replicate_to(id);
+        }
+        self.advance_commit();
+        Some(leader)
+    }
+
+    /// nodes that can vote
+    fn quorum_up(&self) -> bool {
+        self.nodes.iter().filter(|n| n.up).count() * 2 > CLUSTER_SIZE
+    }
+
+    /// leader appends a commit and replicates it; returns the new commit index (None if no live leader)
+    pub fn append(&mut self, value: f64) -> Option<u64> {
+        if !self.nodes[self.leader].up {
+            return None;
+        }
+        let leader = &mut self.nodes[self.leader];
+        let entry = LogEntry { index: leader.last_index() + 1, term: self.term, value };
+        leader.entries.push(entry);
+        for id in 0..CLUSTER_SIZE {
+            self.replicate_to(id);
+        }
+        self.advance_commit();
+        Some(self.nodes[self.leader].commit_index)
+    }
+
+    /// appendentries (or installsnapshot when the follower is behind the leader's compaction point)
+    fn replicate_to(&mut self, id: usize) {
+        if id == self.leader || !self.nodes[id].up {
+            return;
+        }
+        let leader = self.nodes[self.leader].clone();
+        let boundary = leader.snapshot.last_index;
+        let follower = &mut self.nodes[id];
+        // back off from the shorter log's end to the newest index both agree on (nextIndex backoff)
+        // (anything inside the follower's own snapshot is committed, so it agrees by definition)
+        let agrees = |f: &LogNode, index: u64| index <= f.snapshot.last_index || f.term_at(index) == leader.term_at(index);
+        let mut matched = follower.last_index().min(leader.last_index());
+        while matched > boundary && !agrees(follower, matched) {
+            matched -= 1;
+        }
+        if matched < boundary || !agrees(follower, boundary) {
+            follower.snapshot = leader.snapshot;
+            follower.entries.clear();
+            follower.commit_index = follower.commit_index.max(boundary);
+            matched = boundary;
+            self.snapshots_installed += 1;
+        }
+        let follower = &mut self.nodes[id];
+        // drop any conflicting suffix, then copy the leader's entries after the match point
+        follower.entries.retain(|e| e.index <= matched);
+        let from = matched.max(follower.snapshot.last_index);
+        follower.entries.extend(leader.entries.iter().filter(|e| e.index > from).copied());
+        follower.commit_index = follower.commit_index.max(leader.commit_index.min(follower.last_index()));
+    }
+
+    /// commits the highest current-term index stored on a majority, then compacts every node
+    fn advance_commit(&mut self) {
+        let leader = &self.nodes[self.leader];
+        let candidate = (leader.commit_index + 1..=leader.last_index()).rev().find(|index| {
+            leader.term_at(*index) == Some(self.term)
+                && self.nodes.iter().filter(|n| n.up && n.last_index() >= *index && n.term_at(*index) == Some(self.term)).count() * 2 > CLUSTER_SIZE
+        });
+        if let Some(index) = candidate {
+            for node in self.nodes.iter_mut().filter(|n| n.up) {
+                node.commit_index = node.commit_index.max(index.min(node.last_index()));
+            }
+        }
+        for node in self.nodes.iter_mut() {
+            node.compact();
+        }
+    }
+
+    /// a node stops (its persistent log survives)
+    pub fn crash(&mut self, id: usize) {
+        self.nodes[id].up = false;
+    }
+
+    /// election result: `hint` wins unless another live node has a more up-to-date log; returns the leader
+    pub fn elect(&mut self, hint: usize, terms: u64) -> Option<usize> {
+        if !self.quorum_up() {
+            return None;
+        }
+        let winner = (0..CLUSTER_SIZE)
+            .filter(|id| self.nodes[*id].up)
+            .filter(|id| {
+                let node = &self.nodes[*id];
+                self.nodes.iter().filter(|n| n.up).all(|n| node.up_to_date(n.last_term(), n.last_index()))
+            })
+            .min_by_key(|id| (*id != hint, *id))?;
+        self.term += terms.max(1);
+        self.leader = winner;
+        // every live voter persists the new term and its vote before replying
+        for node in self.nodes.iter_mut().filter(|n| n.up) {
+            node.term = self.term;
+            node.voted_for = Some(winner);
+        }
+        Some(winner)
+    }
+
+    /// a node rejoins as follower and is caught up by the leader
+    pub fn restart(&mut self, id: usize) {
+        self.nodes[id].up = true;
+        if self.nodes[self.leader].up {
+            // the first appendentries carries the leader's term; a stale node adopts it
+            let node = &mut self.nodes[id];
+            if node.term < self.term {
+                node.term = self.term;
+                node.voted_for = None;
+            }
+            self.replicate_to(id);
+            self.nodes[id].compact();
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// what: per-node raft log viewer (entries, commit index, snapshot)
+// why: shows the replicated telemetry log behind the election, not just who leads
+// relations: reads ClusterSlice::log (raft.rs ReplicatedLog), wipes raft_persist.rs, rendered by component.rs
+
+use leptos::*;
+use super::raft::{ReplicatedLog, SNAPSHOT_EVERY};
+use super::raft_persist;
+use super::store::ClusterSlice;
+
+/// newest entries listed per node
+const VISIBLE_ENTRIES: usize = 6;
+
+#[component]
+pub fn RaftLogPanel(cluster: ClusterSlice, status: RwSignal<Option<String>>) -> impl IntoView {
+    let log = cluster.log;
+    let wipe = move |_| spawn_local(async move {
+        match raft_persist::wipe().await {
+            Ok(()) => {
+                log.set(ReplicatedLog::default());
+                cluster.leader_id.set(0);
+                status.set(Some("🧹 Persistent state wiped - fresh cluster in term 1".into()));
+            }
+            Err(e) => status.set(Some(format!("⚠ Wipe failed: {}", e))),
+        }
+    });
+    let columns = move || log.with(|log| {
+        log.nodes.iter().enumerate().map(|(id, node)| {
+            let role = match (node.up, id == log.leader) {
+                (false, _) => "down",
+                (true, true) => "leader",
+                (true, false) => "follower",
+            };
+            let entries = node.entries.iter().rev().take(VISIBLE_ENTRIES).map(|e| {
+                let committed = e.index <= node.commit_index;
+                view! {
+                    <li class:raft-uncommitted=!committed>
+                        {format!("#{} t{} {:.1}°C", e.index, e.term, e.value)}
+                    </li>
+                }
+            }).collect_view();
+            let snapshot = node.snapshot;
+            view! {
+                <div class="raft-node" class:down=!node.up class:leader=node.up && id == log.leader>
+                    <div class="raft-node-head">{format!("I{} · {}", id, role)}</div>
+                    <div class="raft-node-meta">{format!("term {} · voted {}", node.term, node.voted_for.map(|v| format!("I{}", v)).unwrap_or_else(|| "-".into()))}</div>
+                    <div class="raft-node-meta">{format!("commit #{} · last #{} (t{})", node.commit_index, node.last_index(), node.last_term())}</div>
+                    {(snapshot.last_index > 0).then(|| view! {
+                        <div class="raft-snapshot">
+                            {format!("📦 snapshot ≤ #{} · {} applied · last {:.1}°C", snapshot.last_index, snapshot.applied, snapshot.last_value)}
+                        </div>
+                    })}
+                    <ul class="raft-entries">{entries}</ul>
+                </div>
+            }
+        }).collect_view()
+    });
+
+    view! {
+        <div class="demo-section raft-section">
+            <h3>"📜 Raft Log"<span class="attack-badge">{move || log.with(|l| format!("term {}", l.term))}</span></h3>
+            <p class="section-desc">"Every voted reading is appended by the leader and committed once a majority stores it. Crash the leader and watch the survivors keep the committed prefix; the rebuilt node is caught up on rejoin."</p>
+            {move || status.get().map(|text| view! { <p class="diagnostics-version">{text}</p> })}
+            <div class="raft-nodes">{columns}</div>
+            <p class="section-desc">{move || log.with(|l| format!(
+                "Compaction every {} commits · {} snapshot{} shipped to lagging followers · faded entries are not yet committed",
+                SNAPSHOT_EVERY, l.snapshots_installed, if l.snapshots_installed == 1 { "" } else { "s" },
+            ))}</p>
+            <p class="section-desc">"Term, votedFor and the log persist in IndexedDB, so a reload is a node restart that recovers state."</p>
+            <button class="action-btn" on:click=wipe>"🧹 Wipe persistent state"</button>
+        </div>
+    }
+}
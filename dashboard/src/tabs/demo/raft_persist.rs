@@ -0,0 +1,70 @@
+// what: indexeddb persistence of the raft cluster state (term, votedFor, log, snapshot per node)
+// why: a page reload should behave like a node restart that recovers its state, not a fresh cluster
+// relations: serializes raft.rs ReplicatedLog through idb.rs, loaded and queued for saving by component.rs, wiped from raft_panel.rs
+
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+use web_sys::IdbTransactionMode;
+use super::idb::run;
+use super::raft::ReplicatedLog;
+
+const DB_NAME: &str = "guardian-raft";
+const STORE: &str = "state";
+const KEY: &str = "cluster";
+
+thread_local! {
+    /// newest state not yet handed to indexeddb; a newer one replaces it
+    static QUEUED: RefCell<Option<ReplicatedLog>> = const { RefCell::new(None) };
+    /// the write loop while it runs, so a wipe can wait for the put already in flight
+    static WRITER: RefCell<Option<js_sys::Promise>> = const { RefCell::new(None) };
+    /// set during a wipe; states queued meanwhile are the ones being wiped
+    static WIPING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// persisted cluster state, None on first visit (or if it no longer parses)
+pub async fn load() -> Result<Option<ReplicatedLog>, String> {
+    let value = run(DB_NAME, STORE, IdbTransactionMode::Readonly, |store| store.get(&JsValue::from_str(KEY))).await?;
+    Ok(value.as_string().and_then(|json| ReplicatedLog::from_json(&json).ok()))
+}
+
+/// writes the whole cluster state (small: entries past the last snapshot only)
+async fn save(log: &ReplicatedLog) -> Result<(), String> {
+    let json = JsValue::from_str(&log.to_json());
+    run(DB_NAME, STORE, IdbTransactionMode::Readwrite, |store| store.put_with_key(&json, &JsValue::from_str(KEY))).await.map(|_| ())
+}
+
+/// saves the state after any write in flight; states queued behind it collapse into the newest,
+/// so puts never land out of order
+pub fn queue_save(log: ReplicatedLog) {
+    if WIPING.with(|w| w.get()) {
+        return;
+    }
+    QUEUED.with(|q| *q.borrow_mut() = Some(log));
+    if WRITER.with(|w| w.borrow().is_some()) {
+        return;
+    }
+    let writer = future_to_promise(async {
+        while let Some(log) = QUEUED.with(|q| q.borrow_mut().take()) {
+            if let Err(e) = save(&log).await {
+                tracing::warn!(target: "raft", error = %e, "raft state not saved");
+            }
+        }
+        WRITER.with(|w| w.borrow_mut().take());
+        Ok(JsValue::UNDEFINED)
+    });
+    WRITER.with(|w| *w.borrow_mut() = Some(writer));
+}
+
+/// forgets the persisted state; the next reload starts a fresh cluster.
+/// queued saves are dropped and the one in flight finishes first, so none of them can restore the old state
+pub async fn wipe() -> Result<(), String> {
+    WIPING.with(|w| w.set(true));
+    QUEUED.with(|q| q.borrow_mut().take());
+    if let Some(writer) = WRITER.with(|w| w.borrow().clone()) {
+        let _ = JsFuture::from(writer).await;
+    }
+    let wiped = run(DB_NAME, STORE, IdbTransactionMode::Readwrite, |store| store.delete(&JsValue::from_str(KEY))).await.map(|_| ());
+    WIPING.with(|w| w.set(false));
+    wiped
+}
@@ -0,0 +1,76 @@
+// what: token-bucket rate limiter guarding the wasm gateway's ingress
+// why: a flood should cost the attacker refused frames, not every legitimate reading's latency;
+//      frames over the limit are shed with an explicit error instead of queued
+// relations: configured by the store.rs rate_limit signal, exercised by flood.rs, edited in flood_panel.rs
+
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+/// sustained frames per second the gateway admits by default
+pub const DEFAULT_RATE_PER_S: f64 = 1000.0;
+/// frames admitted back to back before the rate applies
+pub const DEFAULT_BURST: f64 = 200.0;
+
+/// limiter settings
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateLimit {
+    pub rate_per_s: f64,
+    pub burst: f64,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self { rate_per_s: DEFAULT_RATE_PER_S, burst: DEFAULT_BURST }
+    }
+}
+
+impl RateLimit {
+    pub fn label(&self) -> String {
+        format!("{:.0}/s, burst {:.0}", self.rate_per_s, self.burst)
+    }
+}
+
+/// why a frame was refused
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimited {
+    /// time until the next token is available
+    pub retry_after_ms: f64,
+}
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rate limit exceeded, retry after {:.1}ms", self.retry_after_ms)
+    }
+}
+
+/// refills continuously at `rate_per_s` up to `burst` tokens; one token per frame
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TokenBucket {
+    pub limit: RateLimit,
+    tokens: f64,
+    last_ms: f64,
+}
+
+impl TokenBucket {
+    /// starts full
+    pub fn new(limit: RateLimit) -> Self {
+        Self { limit, tokens: limit.burst, last_ms: 0.0 }
+    }
+
+    fn refill(&mut self, now_ms: f64) {
+        let elapsed = (now_ms - self.last_ms).max(0.0);
+        self.tokens = (self.tokens + elapsed * self.limit.rate_per_s / 1000.0).min(self.limit.burst);
+        self.last_ms = self.last_ms.max(now_ms);
+    }
+
+    /// admits one frame arriving at `now_ms` or says how long to back off
+    pub fn try_take(&mut self, now_ms: f64) -> Result<(), RateLimited> {
+        self.refill(now_ms);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+        let retry_after_ms = if self.limit.rate_per_s > 0.0 { (1.0 - self.tokens) * 1000.0 / self.limit.rate_per_s } else { f64::INFINITY };
+        Err(RateLimited { retry_after_ms })
+    }
+}
@@ -0,0 +1,171 @@
+// what: state a standby must recover before it can take over - calibration table, last setpoints, protocol session - and its cost per runtime and strategy
+// why: failover is not just a process start; a fresh worker or instance knows nothing until its state is back
+// relations: strategies from supervisor.rs and prefork.rs, copy rates measured by resync_panel.rs (RESYNC_PY from modules.rs, a linear-memory copy for wasm)
+
+use super::journal::Runtime;
+use super::prefork::PythonStrategy;
+use super::supervisor::Supervisor;
+
+/// reopening the modbus tcp session to the plc (connect + first read), the same for both runtimes
+pub const SESSION_RECONNECT_MS: f64 = 20.0;
+/// copy rates until measured, bytes per ms: a pickle round trip (~100MB/s) and a linear-memory copy (~2GB/s)
+pub const DEFAULT_PYTHON_BYTES_PER_MS: f64 = 100_000.0;
+pub const DEFAULT_WASM_BYTES_PER_MS: f64 = 2_000_000.0;
+/// bytes per setpoint (one f64)
+pub const SETPOINT_BYTES: u64 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatePart {
+    Calibration,
+    Setpoints,
+    Session,
+}
+
+impl StatePart {
+    pub const ALL: [StatePart; 3] = [StatePart::Calibration, StatePart::Setpoints, StatePart::Session];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatePart::Calibration => "Calibration",
+            StatePart::Setpoints => "Last setpoints",
+            StatePart::Session => "Protocol session",
+        }
+    }
+
+    pub fn note(&self) -> &'static str {
+        match self {
+            StatePart::Calibration => "per-sensor offset and gain tables; static, so a waiting standby can load them early",
+            StatePart::Setpoints => "the values last written to the plc; change all the time, only the live node has them",
+            StatePart::Session => "modbus transaction ids and unit state, plus reopening the tcp session",
+        }
+    }
+}
+
+/// how much state there is to recover
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StateSize {
+    pub calibration_kib: u32,
+    pub setpoints: u32,
+    pub session_kib: u32,
+}
+
+impl Default for StateSize {
+    fn default() -> Self {
+        Self { calibration_kib: 256, setpoints: 64, session_kib: 8 }
+    }
+}
+
+impl StateSize {
+    pub fn bytes(&self, part: StatePart) -> u64 {
+        match part {
+            StatePart::Calibration => self.calibration_kib as u64 * 1024,
+            StatePart::Setpoints => self.setpoints as u64 * SETPOINT_BYTES,
+            StatePart::Session => self.session_kib as u64 * 1024,
+        }
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        StatePart::ALL.iter().map(|p| self.bytes(*p)).sum()
+    }
+}
+
+/// who takes over after a failure
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Standby {
+    PythonColdStart,
+    PythonPreForked,
+    WasmRebuild,
+    WasmWarmSpare,
+}
+
+impl Standby {
+    pub const ALL: [Standby; 4] = [Standby::PythonColdStart, Standby::PythonPreForked, Standby::WasmRebuild, Standby::WasmWarmSpare];
+
+    pub fn python(strategy: PythonStrategy) -> Self {
+        match strategy {
+            PythonStrategy::ColdStart => Standby::PythonColdStart,
+            PythonStrategy::PreForked => Standby::PythonPreForked,
+        }
+    }
+
+    pub fn wasm(strategy: Supervisor) -> Self {
+        match strategy {
+            Supervisor::Rebuild => Standby::WasmRebuild,
+            Supervisor::WarmSpare => Standby::WasmWarmSpare,
+        }
+    }
+
+    pub fn runtime(&self) -> Runtime {
+        match self {
+            Standby::PythonColdStart | Standby::PythonPreForked => Runtime::Python,
+            Standby::WasmRebuild | Standby::WasmWarmSpare => Runtime::Wasm,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Standby::PythonColdStart => "🐍 Cold-start respawn",
+            Standby::PythonPreForked => "🐍 Pre-forked standby",
+            Standby::WasmRebuild => "🦀 Rebuild on demand",
+            Standby::WasmWarmSpare => "🦀 Warm spare",
+        }
+    }
+
+    /// standbys that wait already loaded hold the static calibration; nobody holds live setpoints or the session
+    pub fn preloaded(&self, part: StatePart) -> bool {
+        part == StatePart::Calibration && matches!(self, Standby::PythonPreForked | Standby::WasmWarmSpare)
+    }
+}
+
+/// state copy speed per runtime, bytes per ms
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rates {
+    pub python: f64,
+    pub wasm: f64,
+}
+
+impl Default for Rates {
+    fn default() -> Self {
+        Self { python: DEFAULT_PYTHON_BYTES_PER_MS, wasm: DEFAULT_WASM_BYTES_PER_MS }
+    }
+}
+
+impl Rates {
+    pub fn of(&self, runtime: Runtime) -> f64 {
+        if runtime == Runtime::Python { self.python } else { self.wasm }
+    }
+}
+
+/// bytes per ms from one measured copy; a copy under one timer tick is counted as one tick
+pub fn rate(bytes: u64, ms: f64, resolution_ms: f64) -> f64 {
+    bytes as f64 / ms.max(resolution_ms).max(f64::EPSILON)
+}
+
+/// what one part of the state costs a standby
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartCost {
+    pub part: StatePart,
+    pub bytes: u64,
+    pub ms: f64,
+    /// already loaded while waiting, nothing to recover
+    pub preloaded: bool,
+}
+
+/// per-part re-sync for a standby, in StatePart::ALL order
+pub fn resync(standby: Standby, size: StateSize, rates: Rates) -> Vec<PartCost> {
+    let per_ms = rates.of(standby.runtime()).max(f64::EPSILON);
+    StatePart::ALL
+        .into_iter()
+        .map(|part| {
+            let bytes = size.bytes(part);
+            let preloaded = standby.preloaded(part);
+            let copy_ms = if preloaded { 0.0 } else { bytes as f64 / per_ms };
+            let ms = if part == StatePart::Session { copy_ms + SESSION_RECONNECT_MS } else { copy_ms };
+            PartCost { part, bytes, ms, preloaded }
+        })
+        .collect()
+}
+
+pub fn total_ms(costs: &[PartCost]) -> f64 {
+    costs.iter().map(|c| c.ms).sum()
+}
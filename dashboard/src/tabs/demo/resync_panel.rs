@@ -0,0 +1,166 @@
+// what: state re-sync panel - configurable calibration/setpoint/session sizes, measured copy rates and the failover cost per runtime and strategy
+// why: the recovery figures elsewhere stop at "process is up"; a standby still has to get its state back before its output means anything
+// relations: model in resync.rs, python copy via RESYNC_PY (modules.rs) and wasm.rs runPython, strategies and start times from the store.rs
+//            supervisor/pool signals, supervisor.rs recoveries and prefork.rs; rendered by component.rs
+
+use leptos::*;
+use crate::measure::stats::{summarize, Summary, WARMUP_RUNS};
+use crate::measure::timing::{sample, timer_resolution_ms};
+use super::budget::format_bytes;
+use super::handlers::{restart_delay_ms, LEADER_RESTART_MS};
+use super::journal::Runtime;
+use super::modules::RESYNC_PY;
+use super::prefork::HEARTBEAT_MS;
+use super::probes::WASM_PAGE_BYTES;
+use super::resync::{rate, resync, total_ms, Rates, Standby, StatePart, StateSize};
+use super::store::DemoStore;
+use super::supervisor::Supervisor;
+use super::wasm::{ensurePyodide, runPython};
+
+/// pickle round trips per python measurement
+const PYTHON_RUNS: u32 = 12;
+
+/// times restoring the state into a fresh linear memory: one Uint8Array.set between two WebAssembly.Memory buffers
+async fn measure_wasm(bytes: u64, iterations: u32) -> Result<Summary, String> {
+    let pages = bytes.div_ceil(WASM_PAGE_BYTES as u64).max(1) as u32;
+    let memory = || {
+        let descriptor = js_sys::Object::new();
+        js_sys::Reflect::set(&descriptor, &"initial".into(), &pages.into()).map_err(|e| format!("{:?}", e))?;
+        js_sys::WebAssembly::Memory::new(&descriptor).map_err(|e| format!("{:?}", e))
+    };
+    let (from, to) = (memory()?, memory()?);
+    let source = js_sys::Uint8Array::new_with_byte_offset_and_length(&from.buffer(), 0, bytes as u32);
+    source.fill(0xa5, 0, bytes as u32);
+    let target = js_sys::Uint8Array::new(&to.buffer());
+    let (summary, _) = sample(iterations, || {
+        target.set(&source, 0);
+        async {}
+    }).await;
+    Ok(summary)
+}
+
+/// pickle round trips of the same state in pyodide
+async fn measure_python(size: StateSize) -> Result<Summary, String> {
+    ensurePyodide().await.map_err(|_| "Pyodide unavailable".to_string())?;
+    let code = RESYNC_PY
+        .replace("{calibration}", &size.bytes(StatePart::Calibration).to_string())
+        .replace("{setpoints}", &size.setpoints.to_string())
+        .replace("{session}", &size.bytes(StatePart::Session).to_string())
+        .replace("{runs}", &(PYTHON_RUNS as usize + WARMUP_RUNS).to_string());
+    let result = runPython(&code).await.map_err(|e| format!("{:?}", e))?;
+    let samples: Vec<f64> = serde_json::from_str(&result.as_string().unwrap_or_default()).map_err(|e| e.to_string())?;
+    Ok(summarize(&samples, WARMUP_RUNS))
+}
+
+fn ms_text(ms: f64) -> String {
+    if ms >= 100.0 { format!("{:.0}ms", ms) } else if ms >= 1.0 { format!("{:.1}ms", ms) } else { format!("{:.3}ms", ms) }
+}
+
+/// a size input bound to one field
+fn size_input(label: &'static str, unit: &'static str, value: impl Fn() -> u32 + 'static, set: impl Fn(u32) + 'static) -> impl IntoView {
+    view! {
+        <label class="voting-tolerance">
+            {label}
+            <input
+                type="number"
+                min="0"
+                step="1"
+                prop:value=move || value().to_string()
+                on:change=move |ev| { if let Ok(v) = event_target_value(&ev).parse::<u32>() { set(v) } }
+            />
+            {unit}
+        </label>
+    }
+}
+
+#[component]
+pub fn ResyncPanel(store: DemoStore) -> impl IntoView {
+    let DemoStore { supervisor, recoveries, pool, perf, .. } = store;
+    let size = create_rw_signal(StateSize::default());
+    let rates = create_rw_signal(Rates::default());
+    let (measured, set_measured) = create_signal(Option::<String>::None);
+    let (measuring, set_measuring) = create_signal(false);
+
+    let measure = move |_| {
+        if measuring.get() { return; }
+        set_measuring.set(true);
+        let state = size.get_untracked();
+        let bytes = state.total_bytes();
+        spawn_local(async move {
+            let resolution_ms = timer_resolution_ms();
+            let wasm = measure_wasm(bytes, store.iterations()).await;
+            let python = measure_python(state).await;
+            if let Ok(s) = &wasm {
+                rates.update(|r| r.wasm = rate(bytes, s.median, resolution_ms));
+            }
+            if let Ok(s) = &python {
+                rates.update(|r| r.python = rate(bytes, s.median, resolution_ms));
+            }
+            let text = |r: &Result<Summary, String>| r.as_ref().map(|s| s.display(3)).unwrap_or_else(|e| format!("not measured ({})", e));
+            tracing::info!(target: "measure", bytes, wasm_ok = wasm.is_ok(), python_ok = python.is_ok(), "state re-sync copy");
+            set_measured.set(Some(format!("{} copied: 🦀 linear memory {} · 🐍 pickle round trip {}", format_bytes(bytes), text(&wasm), text(&python))));
+            set_measuring.set(false);
+        });
+    };
+
+    // time until the standby process can run at all, from this session's numbers
+    let start_ms = move |standby: Standby| match standby {
+        Standby::PythonColdStart => restart_delay_ms(perf.pyodide_load_ms.get(), LEADER_RESTART_MS, 0) as f64,
+        Standby::PythonPreForked => HEARTBEAT_MS as f64,
+        Standby::WasmRebuild => recoveries.with(|l| {
+            if l.rebuild_ms.is_empty() { perf.wasm_instantiate_ms.get().median } else { l.summary(Supervisor::Rebuild).median }
+        }),
+        Standby::WasmWarmSpare => recoveries.with(|l| l.summary(Supervisor::WarmSpare).median),
+    };
+    let selected = move |standby: Standby| match standby.runtime() {
+        Runtime::Python => Standby::python(pool.strategy.get()) == standby,
+        _ => Standby::wasm(supervisor.get()) == standby,
+    };
+
+    let rows = move || Standby::ALL.into_iter().map(|standby| {
+        let costs = resync(standby, size.get(), rates.get());
+        let start = start_ms(standby);
+        let state_ms = total_ms(&costs);
+        view! {
+            <tr class:voting-selected=selected(standby)>
+                <td class="world-name">{standby.label()}</td>
+                <td>{ms_text(start)}</td>
+                {costs.into_iter().map(|c| view! {
+                    <td title=c.part.note()>{if c.preloaded { "preloaded".to_string() } else { ms_text(c.ms) }}</td>
+                }).collect_view()}
+                <td>{ms_text(state_ms)}</td>
+                <td class="vote-halted">{ms_text(start + state_ms)}</td>
+            </tr>
+        }
+    }).collect_view();
+
+    view! {
+        <div class="demo-section resync-section">
+            <h3>"🔄 State Re-sync"<span class="attack-badge">"Failover ≠ Process Start"</span></h3>
+            <p class="section-desc">"A standby that is running still knows nothing: it needs the calibration tables, the setpoints last written to the PLC and a working Modbus session before its output can be trusted. Size the state, measure how fast each runtime copies it, and compare the whole failover."</p>
+            <div class="voting-controls">
+                {size_input("Calibration", "KiB", move || size.get().calibration_kib, move |v| size.update(|s| s.calibration_kib = v))}
+                {size_input("Setpoints", "× f64", move || size.get().setpoints, move |v| size.update(|s| s.setpoints = v))}
+                {size_input("Session", "KiB", move || size.get().session_kib, move |v| size.update(|s| s.session_kib = v))}
+                <button class="action-btn" disabled=measuring on:click=measure>
+                    {move || if measuring.get() { "⏳ Measuring…" } else { "⏱️ Measure Copy" }}
+                </button>
+            </div>
+            <table class="capability-matrix voting-matrix">
+                <tr>
+                    <th>"Standby"</th>
+                    <th>"Process start"</th>
+                    {StatePart::ALL.into_iter().map(|p| view! { <th title=p.note()>{p.label()}</th> }).collect_view()}
+                    <th>"State re-sync"</th>
+                    <th>"Failover total"</th>
+                </tr>
+                {rows}
+            </table>
+            <p class="metrics-note">{move || measured.get().unwrap_or_else(|| {
+                let r = rates.get();
+                format!("Default copy rates until measured: 🐍 {:.0}MB/s, 🦀 {:.0}MB/s.", r.python / 1000.0, r.wasm / 1000.0)
+            })}</p>
+            <p class="metrics-note">"Highlighted rows are the strategies selected above. Warm standbys preload the static calibration; live setpoints and the session always move at failover. The 2oo3 vote hides a single WASM rebuild from the output either way — re-sync decides how soon the vote has three members again."</p>
+        </div>
+    }
+}
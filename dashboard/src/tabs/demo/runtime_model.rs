@@ -0,0 +1,224 @@
+// what: RuntimeModel trait describing one runtime column (labels, tone, log, nodes, stats)
+// why: one RuntimePanel renders every runtime, so styling/tooltips/badges can't drift apart
+// relations: implemented here for python, wasm and the js baseline, read by runtime_panel.rs and stats_panel.rs, state from store.rs, live budgets from budget.rs, downtime from the ledger.rs incidents
+
+use leptos::*;
+use crate::measure::stats::summarize;
+use super::budget::InstanceBudget;
+use super::journal::Runtime;
+use super::ledger::{incidents, total_downtime_ms};
+use super::node_strip::{python_nodes, wasm_nodes, NodeStatus};
+use super::store::DemoStore;
+use super::terminal::TerminalLog;
+
+/// one stat counter cell
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatCell {
+    pub label: &'static str,
+    pub value: String,
+    /// css tone class ("", "success", "warn", "error")
+    pub tone: &'static str,
+}
+
+/// everything a runtime column needs; signal reads are reactive when called inside a view
+pub trait RuntimeModel {
+    fn runtime(&self) -> Runtime;
+    fn icon(&self) -> &'static str;
+    fn name(&self) -> &'static str;
+    /// tooltip on the terminal title
+    fn summary(&self) -> &'static str;
+    /// tooltip on the leader node box
+    fn leader_note(&self) -> &'static str;
+    /// tooltip on the memory badge
+    fn memory_note(&self) -> &'static str;
+    fn mb_per_node(&self) -> u32;
+    /// accent tone for the column ("warning" or "success")
+    fn tone(&self) -> &'static str;
+    fn log(&self, store: &DemoStore) -> TerminalLog;
+    /// status badge text and whether the runtime is currently degraded
+    fn status(&self, store: &DemoStore) -> (&'static str, bool);
+    fn nodes(&self, store: &DemoStore) -> Vec<NodeStatus>;
+    fn stats(&self, store: &DemoStore) -> Vec<StatCell>;
+
+    fn title(&self) -> String {
+        format!("{} {} (2oo3 TMR / Raft-like)", self.icon(), self.name())
+    }
+
+    fn terminal_id(&self) -> &'static str {
+        match self.runtime() {
+            Runtime::Python => "python-terminal",
+            Runtime::Wasm => "wasm-terminal",
+            Runtime::Js => "js-terminal",
+        }
+    }
+
+    /// nodes per runtime (3 for 2oo3 voting)
+    fn node_count(&self) -> u32 {
+        3
+    }
+
+    fn memory_total_mb(&self) -> u32 {
+        self.mb_per_node() * self.node_count()
+    }
+
+    fn memory_badge(&self) -> String {
+        format!("Total: {}MB", self.memory_total_mb())
+    }
+
+    /// per-instance budgets read back from live instances (empty for runtimes without them)
+    fn budgets(&self, _store: &DemoStore) -> Vec<Option<InstanceBudget>> {
+        Vec::new()
+    }
+}
+
+/// pyodide worker pool: slow cold-start respawn, real downtime
+pub struct PythonModel;
+
+impl RuntimeModel for PythonModel {
+    fn runtime(&self) -> Runtime { Runtime::Python }
+    fn icon(&self) -> &'static str { "🐍" }
+    fn name(&self) -> &'static str { "Python" }
+    fn summary(&self) -> &'static str { "Python multiprocessing with 3 workers - L/F election waits for a ~1.5s worker respawn" }
+    fn leader_note(&self) -> &'static str { "Leader (long election if fails)" }
+    fn memory_note(&self) -> &'static str { "~45MB per Python worker (Pyodide)" }
+    fn mb_per_node(&self) -> u32 { 45 }
+    fn tone(&self) -> &'static str { "warning" }
+
+    fn log(&self, store: &DemoStore) -> TerminalLog {
+        store.python_log
+    }
+
+    fn status(&self, store: &DemoStore) -> (&'static str, bool) {
+        let up = store.pool.python_workers.with(|w| w.iter().filter(|up| **up).count());
+        match (store.pool.python_restarting.get(), up) {
+            (true, _) => ("⏳ RESPAWNING", true),
+            // a pre-forked standby took over; output flows while the dead worker re-forks
+            (false, 2) => ("🟡 2/3 UP · RE-FORKING", false),
+            (false, 1) => ("🟡 1/3 UP · RE-FORKING", false),
+            _ => ("🟢 3/3 UP", false),
+        }
+    }
+
+    fn nodes(&self, store: &DemoStore) -> Vec<NodeStatus> {
+        python_nodes(store.pool.python_workers.get(), store.pool.python_active_worker.get()).to_vec()
+    }
+
+    fn stats(&self, store: &DemoStore) -> Vec<StatCell> {
+        vec![
+            StatCell { label: "Processed", value: store.stats.python_processed.get().to_string(), tone: "" },
+            StatCell { label: "Crashed", value: store.stats.python_crashed.get().to_string(), tone: "error" },
+            StatCell { label: "Downtime", value: format!("{:.0}ms", ledger_downtime_ms(store, Runtime::Python)), tone: "error" },
+        ]
+    }
+}
+
+/// wasm instances under 2oo3 voting: traps are voted out, downtime only without quorum
+pub struct WasmModel;
+
+impl RuntimeModel for WasmModel {
+    fn runtime(&self) -> Runtime { Runtime::Wasm }
+    fn icon(&self) -> &'static str { "🦀" }
+    fn name(&self) -> &'static str { "WASM" }
+    fn summary(&self) -> &'static str { "2oo3 TMR voting; leader failover is a Raft election, instance rebuild is sub-ms" }
+    fn leader_note(&self) -> &'static str { "Leader (Raft election if fails)" }
+    fn memory_note(&self) -> &'static str { "2MB budget per WASM instance; live sizes read from each instance's WebAssembly.Memory and Table" }
+    fn mb_per_node(&self) -> u32 { 2 }
+    fn tone(&self) -> &'static str { "success" }
+
+    fn log(&self, store: &DemoStore) -> TerminalLog {
+        store.wasm_log
+    }
+
+    fn budgets(&self, store: &DemoStore) -> Vec<Option<InstanceBudget>> {
+        store.budgets.get().to_vec()
+    }
+
+    // voting masks the faulty instance, so the service stays up
+    fn status(&self, _store: &DemoStore) -> (&'static str, bool) {
+        ("🟢 3/3 UP", false)
+    }
+
+    fn nodes(&self, store: &DemoStore) -> Vec<NodeStatus> {
+        wasm_nodes(store.cluster.instance_states.get(), store.cluster.faulty_instance.get(), store.cluster.leader_id.get()).to_vec()
+    }
+
+    fn stats(&self, store: &DemoStore) -> Vec<StatCell> {
+        // voting masks single faults; only a lost quorum costs output
+        let downtime = ledger_downtime_ms(store, Runtime::Wasm);
+        vec![
+            StatCell { label: "Processed", value: store.stats.wasm_processed.get().to_string(), tone: "" },
+            StatCell { label: "Voted Out", value: store.stats.wasm_rejected.get().to_string(), tone: "warn" },
+            StatCell { label: "Downtime", value: format!("{:.0}ms", downtime), tone: if downtime > 0.0 { "error" } else { "success" } },
+        ]
+    }
+}
+
+/// plain javascript on the page's main thread: one context, no sandbox or failover
+pub struct JsModel;
+
+impl RuntimeModel for JsModel {
+    fn runtime(&self) -> Runtime { Runtime::Js }
+    fn icon(&self) -> &'static str { "🟨" }
+    fn name(&self) -> &'static str { "JavaScript" }
+    fn summary(&self) -> &'static str { "Same sensor driver in plain JS on the page's engine - speed baseline, no isolation" }
+    fn leader_note(&self) -> &'static str { "Single main-thread context" }
+    fn memory_note(&self) -> &'static str { "Runs in the page's existing JS heap" }
+    fn mb_per_node(&self) -> u32 { 0 }
+    fn tone(&self) -> &'static str { "success" }
+
+    fn title(&self) -> String {
+        format!("{} {} (baseline)", self.icon(), self.name())
+    }
+
+    fn node_count(&self) -> u32 {
+        1
+    }
+
+    fn memory_badge(&self) -> String {
+        "Shared heap".to_string()
+    }
+
+    fn log(&self, store: &DemoStore) -> TerminalLog {
+        store.js_log
+    }
+
+    fn status(&self, _store: &DemoStore) -> (&'static str, bool) {
+        ("🟢 1/1 UP", false)
+    }
+
+    fn nodes(&self, _store: &DemoStore) -> Vec<NodeStatus> {
+        vec![NodeStatus { leader: true, up: true }]
+    }
+
+    fn stats(&self, store: &DemoStore) -> Vec<StatCell> {
+        let exec = summarize(&store.perf.js_exec_samples.get(), 0);
+        vec![
+            StatCell { label: "Processed", value: store.stats.js_processed.get().to_string(), tone: "" },
+            StatCell { label: "Exec", value: if exec.kept > 0 { exec.display(3) } else { "—".to_string() }, tone: "success" },
+            StatCell { label: "Isolation", value: "none".to_string(), tone: "error" },
+        ]
+    }
+}
+
+/// output a runtime lost, summed over the downtime ledger
+fn ledger_downtime_ms(store: &DemoStore, runtime: Runtime) -> f64 {
+    store.journal.with(|events| total_downtime_ms(&incidents(events), runtime))
+}
+
+/// the model for a runtime column
+pub fn model(kind: Runtime) -> &'static dyn RuntimeModel {
+    match kind {
+        Runtime::Python => &PythonModel,
+        Runtime::Wasm => &WasmModel,
+        Runtime::Js => &JsModel,
+    }
+}
+
+/// runtime columns shown on the demo, in order
+pub fn runtimes(js_baseline: bool) -> Vec<Runtime> {
+    let mut kinds = vec![Runtime::Python, Runtime::Wasm];
+    if js_baseline {
+        kinds.push(Runtime::Js);
+    }
+    kinds
+}
@@ -0,0 +1,48 @@
+// what: one runtime column (header, terminal, node strip) for any runtime
+// why: python and wasm used to be copy-pasted panels; now both render from a RuntimeModel
+// relations: reads runtime_model.rs, renders terminal.rs TerminalView and node_strip.rs NodeStrip
+
+use leptos::*;
+use wasm_bindgen::JsCast;
+use super::journal::Runtime;
+use super::node_strip::NodeStrip;
+use super::runtime_model::model;
+use super::store::DemoStore;
+use super::terminal::TerminalView;
+
+#[component]
+pub fn RuntimePanel(kind: Runtime, store: DemoStore) -> impl IntoView {
+    let model = model(kind);
+    let log = model.log(&store);
+    let terminal_id = model.terminal_id();
+    
+    // Auto-scroll terminal to bottom when its log updates
+    create_effect(move |_| {
+        log.track();
+        // Use request_animation_frame to scroll after DOM updates
+        if let Some(window) = web_sys::window() {
+            let _ = window.request_animation_frame(
+                wasm_bindgen::closure::Closure::once_into_js(move || {
+                    if let Some(doc) = web_sys::window().and_then(|w| w.document()) {
+                        if let Some(el) = doc.get_element_by_id(terminal_id) {
+                            el.set_scroll_top(el.scroll_height());
+                        }
+                    }
+                }).unchecked_ref()
+            );
+        }
+    });
+    
+    view! {
+        <div class=format!("terminal-panel {}-panel", kind.as_str())>
+            <div class="terminal-header">
+                <span class="terminal-title" attr:data-tooltip=model.summary()>{model.title()}</span>
+                <span class="terminal-status" class:crashed=move || model.status(&store).1>
+                    {move || model.status(&store).0}
+                </span>
+            </div>
+            <TerminalView log=log id=terminal_id />
+            <NodeStrip model=model store=store />
+        </div>
+    }
+}
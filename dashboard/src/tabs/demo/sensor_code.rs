@@ -0,0 +1,129 @@
+// what: the sensor driver source each runtime executes, with its capability-relevant lines marked
+// why: the timings compare code the viewer can read; constants are shared with the code that runs, so they cannot drift
+// relations: python snippet run by handlers.rs run_sensor_comparison, js from baseline.rs, rust from source/mod.rs;
+//            shown by sensor_comparison.rs
+
+use crate::source::SENSOR_DRIVER;
+use super::baseline::JS_SENSOR_DRIVER;
+
+/// BME280 driver as executed by Pyodide (timed end to end by run_sensor_comparison)
+pub const SENSOR_DRIVER_PY: &str = r#"
+import time
+start = time.perf_counter()
+
+# BME280 driver simulation
+class BME280:
+    def __init__(self):
+        self.cal = [27504, 26435, -1000]
+    
+    def read(self):
+        return {"temp": 23.5, "hum": 45.2, "pres": 1013.25}
+
+driver = BME280()
+result = driver.read()
+elapsed_ms = (time.perf_counter() - start) * 1000
+result
+"#;
+
+/// source language of a snippet (only rust gets token highlighting)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodeLang {
+    Rust,
+    Python,
+    JavaScript,
+}
+
+/// how a marked line relates to the capability model
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reach {
+    /// goes through a declared interface; nothing else is reachable
+    Explicit,
+    /// relies on authority the runtime hands out to all code
+    Ambient,
+}
+
+impl Reach {
+    /// diff-style gutter sign
+    pub fn sign(&self) -> &'static str {
+        match self {
+            Reach::Explicit => "+",
+            Reach::Ambient => "!",
+        }
+    }
+
+    pub fn class(&self) -> &'static str {
+        match self {
+            Reach::Explicit => "code-explicit",
+            Reach::Ambient => "code-ambient",
+        }
+    }
+}
+
+/// a line pattern worth pointing at, with why
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Marker {
+    pub pattern: &'static str,
+    pub reach: Reach,
+    pub note: &'static str,
+}
+
+const RUST_MARKERS: [Marker; 3] = [
+    Marker { pattern: "use wasm_bindgen", reach: Reach::Explicit, note: "The only imports are the binding glue and the shared reading type: no std::fs, std::net or std::env in this crate." },
+    Marker { pattern: "#[wasm_bindgen]", reach: Reach::Explicit, note: "Exported through the module boundary; the host can call these and nothing else." },
+    Marker { pattern: "Err(JsValue", reach: Reach::Explicit, note: "Failure crosses the boundary as a value the voter can see." },
+];
+
+const PYTHON_MARKERS: [Marker; 2] = [
+    Marker { pattern: "import ", reach: Reach::Ambient, note: "Any module can be imported; os, socket and subprocess are as reachable as time." },
+    Marker { pattern: "time.perf_counter", reach: Reach::Ambient, note: "Clock access needs no grant." },
+];
+
+const JS_MARKERS: [Marker; 1] = [
+    Marker { pattern: "class BME280", reach: Reach::Ambient, note: "Runs with the page's globals (fetch, document, localStorage) in scope." },
+];
+
+/// one runtime's column
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SensorSnippet {
+    pub runtime: &'static str,
+    pub lang: CodeLang,
+    pub file: &'static str,
+    pub text: &'static str,
+}
+
+impl SensorSnippet {
+    fn markers(&self) -> &'static [Marker] {
+        match self.lang {
+            CodeLang::Rust => &RUST_MARKERS,
+            CodeLang::Python => &PYTHON_MARKERS,
+            CodeLang::JavaScript => &JS_MARKERS,
+        }
+    }
+}
+
+/// the snippets in terminal order (python, wasm, then the optional js baseline)
+pub fn snippets(js_baseline: bool) -> Vec<SensorSnippet> {
+    let mut all = vec![
+        SensorSnippet { runtime: "Python (Pyodide)", lang: CodeLang::Python, file: "sensor_driver.py", text: SENSOR_DRIVER_PY.trim_matches('\n') },
+        SensorSnippet { runtime: "WASM", lang: CodeLang::Rust, file: SENSOR_DRIVER.path, text: SENSOR_DRIVER.text },
+    ];
+    if js_baseline {
+        all.push(SensorSnippet { runtime: "JavaScript", lang: CodeLang::JavaScript, file: "sensor_driver.js", text: JS_SENSOR_DRIVER.trim_matches('\n') });
+    }
+    all
+}
+
+/// one source line and the first marker it matches
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CodeLine {
+    pub text: &'static str,
+    pub marker: Option<Marker>,
+}
+
+/// every line of the snippet, capability-relevant ones marked
+pub fn annotate(snippet: &SensorSnippet) -> Vec<CodeLine> {
+    snippet.text.lines().map(|text| CodeLine {
+        text,
+        marker: snippet.markers().iter().find(|m| text.contains(m.pattern)).copied(),
+    }).collect()
+}
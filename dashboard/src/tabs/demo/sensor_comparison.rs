@@ -0,0 +1,126 @@
+// what: sensor execution comparison row (real pyodide vs wasm path)
+// why: split out of component.rs; owns the pyodide loading placeholder too
+// relations: reads store.rs slices, fires handlers.rs run_sensor_comparison, uses tabs/skeleton.rs,
+//            code tabs from sensor_code.rs highlighted with source/highlight.rs, backend differences from component_backend.rs
+
+use leptos::*;
+use crate::measure::stats::summarize;
+use crate::settings::use_settings;
+use crate::source::highlight::{tokenize, TokenKind};
+use crate::tabs::skeleton::Skeleton;
+use super::component_backend::differences;
+use super::sensor_code::{annotate, snippets, CodeLang, SensorSnippet};
+use super::store::DemoStore;
+
+/// one runtime's source, line by line with the capability gutter
+fn code_pane(snippet: SensorSnippet) -> impl IntoView {
+    let lines = annotate(&snippet).into_iter().map(|line| {
+        // rust is highlighted per line; the driver has no multi-line strings or block comments
+        let code = match snippet.lang {
+            CodeLang::Rust => tokenize(line.text).into_iter().map(|t| match t.kind {
+                TokenKind::Plain => t.text.into_view(),
+                kind => view! { <span class=kind.class()>{t.text}</span> }.into_view(),
+            }).collect_view(),
+            _ => line.text.into_view(),
+        };
+        let class = line.marker.map(|m| format!("code-line {}", m.reach.class())).unwrap_or_else(|| "code-line".to_string());
+        view! {
+            <div class=class title=line.marker.map(|m| m.note).unwrap_or_default()>
+                <span class="code-sign">{line.marker.map(|m| m.reach.sign()).unwrap_or(" ")}</span>
+                <span class="code-text">{code}</span>
+            </div>
+        }
+    }).collect_view();
+    let notes = annotate(&snippet).into_iter().filter_map(|l| l.marker).fold(Vec::new(), |mut seen, m| {
+        if !seen.contains(&m) { seen.push(m); }
+        seen
+    });
+    view! {
+        <div class="code-pane">
+            <div class="code-pane-header">
+                <span class="world-name">{snippet.runtime}</span>
+                <code>{snippet.file}</code>
+            </div>
+            <pre class="code-body">{lines}</pre>
+            <ul class="code-notes">
+                {notes.into_iter().map(|m| view! { <li class=m.reach.class()><strong>{m.reach.sign()}</strong>" "{m.note}</li> }).collect_view()}
+            </ul>
+        </div>
+    }
+}
+
+#[component]
+pub fn SensorComparison(store: DemoStore) -> impl IntoView {
+    let DemoStore { perf, control, .. } = store;
+    let settings = use_settings();
+    let (code_open, set_code_open) = create_signal(false);
+    view! {
+        // Pyodide is fetched on first visit - placeholder until it is ready
+        <Show when=move || !perf.pyodide_ready.get()>
+            {move || if perf.pyodide_failed.get() {
+                view! { <p class="hardware-note">"⚠️ Pyodide failed to load — Python comparisons are unavailable (check your connection and reload)."</p> }.into_view()
+            } else {
+                view! { <Skeleton label="🐍 Loading Python runtime (Pyodide ≈ 12 MB, first visit only)..." lines=3 /> }.into_view()
+            }}
+        </Show>
+        
+        <div class="sensor-comparison">
+            <h4>"📊 Sensor Execution (Real)"</h4>
+            <div class="sensor-row">
+                <div class="sensor-metric">
+                    <span class="sensor-label">{move || format!("WASM ({})", settings.with(|s| s.sensor_backend.label()))}</span>
+                    <span class="sensor-value success">{move || {
+                        if perf.sensor_ran.get() {
+                            let ms = perf.wasm_exec_ms.get();
+                            if ms < 0.001 { "<0.001ms".to_string() } else { summarize(&perf.wasm_exec_samples.get(), 0).display(3) }
+                        } else { "—".to_string() }
+                    }}</span>
+                </div>
+                <Show when=move || perf.js_exec_samples.with(|s| !s.is_empty())>
+                    <div class="sensor-metric">
+                        <span class="sensor-label">"JavaScript"</span>
+                        <span class="sensor-value success">{move || summarize(&perf.js_exec_samples.get(), 0).display(3)}</span>
+                    </div>
+                </Show>
+                <div class="sensor-metric">
+                    <span class="sensor-label">"Python (Pyodide)"</span>
+                    <span class="sensor-value warning">{move || {
+                        let ms = perf.python_exec_ms.get();
+                        if ms > 0.0 { summarize(&perf.python_exec_samples.get(), 0).display(2) } 
+                        else if ms < 0.0 { "Error".to_string() }
+                        else { "—".to_string() }
+                    }}</span>
+                </div>
+                <button 
+                    class="action-btn run-sensor"
+                    disabled=move || perf.sensor_running.get() || !perf.pyodide_ready.get() || control.is_running.get()
+                    title=move || if perf.pyodide_ready.get() { "Run real sensor code in both runtimes".to_string() } else { "Waiting for Pyodide to load...".to_string() }
+                    on:click=move |_| store.run_sensor_comparison()
+                >
+                    {move || if perf.sensor_running.get() { "⏳ Running..." } 
+                            else if !perf.pyodide_ready.get() { "⏳ Loading Pyodide..." }
+                            else { "▶️ Run Sensor Check" }}
+                </button>
+                <button class="action-btn code-toggle" on:click=move |_| set_code_open.update(|v| *v = !*v)>
+                    {move || if code_open.get() { "✖ Hide Code" } else { "</> Show Code" }}
+                </button>
+            </div>
+            {move || {
+                let lines = differences(&perf.backend_runs.get());
+                (!lines.is_empty()).then(|| view! {
+                    <div class="backend-differences">
+                        <p class="section-desc">"wasm-bindgen build vs the WASI 0.2 component (jco transpile), latest run of each:"</p>
+                        <ul>{lines.into_iter().map(|line| view! { <li>{line}</li> }).collect_view()}</ul>
+                        <p class="metrics-note">"The wasm-bindgen column simulates a fresh reading each run; the component returns what the driver itself reports. Its call goes through canonical-ABI lifting of the sensor-reading record."</p>
+                    </div>
+                })
+            }}
+            <Show when=move || code_open.get()>
+                <p class="section-desc">"The exact source each runtime executes. "<span class="code-explicit">"+"</span>" goes through a declared interface, "<span class="code-ambient">"!"</span>" uses authority every script gets."</p>
+                <div class="code-tabs">
+                    {move || snippets(settings.with(|s| s.js_baseline)).into_iter().map(code_pane).collect_view()}
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,171 @@
+// what: rfc 5424 syslog and cef formatters for journal events
+// why: shows security teams exactly how guardian events land in their siem
+// relations: formats journal.rs events, the methodology.rs disclosure and timeline.rs annotations, used by audit_panel.rs for download/post
+
+use super::journal::AuditEvent;
+use super::methodology::Disclosure;
+use super::timeline::Annotation;
+
+/// syslog facility 13 = log audit
+const SYSLOG_FACILITY: u8 = 13;
+const HOSTNAME: &str = "guardian-one";
+const APP_NAME: &str = "guardian-demo";
+/// 32473 is the iana example enterprise number reserved for documentation
+const SD_ID: &str = "guardian@32473";
+const CEF_VENDOR: &str = "Guardian One";
+const CEF_PRODUCT: &str = "Web Demo";
+
+/// supported export formats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Syslog,
+    Cef,
+}
+
+impl ExportFormat {
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ExportFormat::Syslog => "guardian-audit.log",
+            ExportFormat::Cef => "guardian-audit.cef",
+        }
+    }
+}
+
+/// formats unix epoch ms as rfc 3339 utc (e.g. 2026-01-14T09:30:00.123Z)
+pub fn rfc3339(epoch_ms: f64) -> String {
+    let total_ms = epoch_ms.max(0.0) as u64;
+    let ms = total_ms % 1000;
+    let secs = total_ms / 1000;
+    let (h, m, s) = ((secs / 3600) % 24, (secs / 60) % 60, secs % 60);
+
+    // civil-from-days (howard hinnant), days since 1970-01-01
+    let z = (secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z", year, month, day, h, m, s, ms)
+}
+
+/// escapes a structured-data param value (rfc 5424 section 6.3.3); line breaks as in msg_escape
+fn sd_escape(value: &str) -> String {
+    msg_escape(value).replace('\\', "\\\\").replace('"', "\\\"").replace(']', "\\]")
+}
+
+/// keeps free text on the record's line: a cr or lf would end the record and let the rest pass as a forged one
+fn msg_escape(value: &str) -> String {
+    value.replace(['\r', '\n'], " ")
+}
+
+/// one rfc 5424 line
+pub fn to_syslog(event: &AuditEvent) -> String {
+    let pri = SYSLOG_FACILITY as u16 * 8 + event.kind.syslog_severity() as u16;
+    let runtime = event.runtime.map(|r| r.as_str()).unwrap_or("host");
+    format!(
+        "<{}>1 {} {} {} - {} [{} runtime=\"{}\" attack=\"{}\"] {}",
+        pri,
+        rfc3339(event.timestamp_ms),
+        HOSTNAME,
+        APP_NAME,
+        event.kind.id(),
+        SD_ID,
+        runtime,
+        sd_escape(&event.attack),
+        msg_escape(&event.detail),
+    )
+}
+
+/// escapes a cef header field (pipes and backslashes)
+fn cef_header_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('|', "\\|")
+}
+
+/// escapes a cef extension value (equals, backslashes, line breaks)
+fn cef_ext_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('=', "\\=").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+/// maps syslog severity (0 worst) to cef severity (10 worst)
+fn cef_severity(syslog_severity: u8) -> u8 {
+    10u8.saturating_sub(syslog_severity.min(7) * 10 / 7)
+}
+
+/// one cef:0 line
+pub fn to_cef(event: &AuditEvent) -> String {
+    let runtime = event.runtime.map(|r| r.as_str()).unwrap_or("host");
+    format!(
+        "CEF:0|{}|{}|{}|{}|{}|{}|rt={} cs1Label=runtime cs1={} cs2Label=attack cs2={} msg={}",
+        CEF_VENDOR,
+        CEF_PRODUCT,
+        env!("CARGO_PKG_VERSION"),
+        event.kind.id(),
+        cef_header_escape(event.kind.name()),
+        cef_severity(event.kind.syslog_severity()),
+        event.timestamp_ms as u64,
+        runtime,
+        cef_ext_escape(&event.attack),
+        cef_ext_escape(&event.detail),
+    )
+}
+
+/// the session's methodology as one informational record (syslog: one sd param per line of the disclosure)
+pub fn methodology_record(list: &[Disclosure], timestamp_ms: f64, format: ExportFormat) -> String {
+    let summary = list.iter().map(|d| format!("{}: {} [{}]", d.label, d.value, d.provenance.label())).collect::<Vec<_>>().join("; ");
+    match format {
+        ExportFormat::Syslog => {
+            let params: String = list
+                .iter()
+                .enumerate()
+                .map(|(i, d)| format!(" p{}=\"{}: {} [{}]\"", i, sd_escape(&d.label), sd_escape(&d.value), d.provenance.label()))
+                .collect();
+            format!("<{}>1 {} {} {} - methodology [methodology@32473{}] Session methodology disclosure", SYSLOG_FACILITY as u16 * 8 + 6, rfc3339(timestamp_ms), HOSTNAME, APP_NAME, params)
+        }
+        ExportFormat::Cef => format!(
+            "CEF:0|{}|{}|{}|methodology|Session methodology|0|rt={} msg={}",
+            CEF_VENDOR,
+            CEF_PRODUCT,
+            env!("CARGO_PKG_VERSION"),
+            timestamp_ms as u64,
+            cef_ext_escape(&summary),
+        ),
+    }
+}
+
+/// a presenter's timeline note as an informational record
+pub fn annotation_record(annotation: &Annotation, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Syslog => format!(
+            "<{}>1 {} {} {} - annotation [annotation@32473] {}",
+            SYSLOG_FACILITY as u16 * 8 + 6,
+            rfc3339(annotation.at_ms),
+            HOSTNAME,
+            APP_NAME,
+            msg_escape(&annotation.text),
+        ),
+        ExportFormat::Cef => format!(
+            "CEF:0|{}|{}|{}|annotation|Presenter annotation|0|rt={} msg={}",
+            CEF_VENDOR,
+            CEF_PRODUCT,
+            env!("CARGO_PKG_VERSION"),
+            annotation.at_ms as u64,
+            cef_ext_escape(&annotation.text),
+        ),
+    }
+}
+
+/// formats the whole journal, one event per line
+pub fn export(events: &[AuditEvent], format: ExportFormat) -> String {
+    events
+        .iter()
+        .map(|e| match format {
+            ExportFormat::Syslog => to_syslog(e),
+            ExportFormat::Cef => to_cef(e),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
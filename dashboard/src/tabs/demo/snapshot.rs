@@ -0,0 +1,189 @@
+// what: serde snapshot of the whole demo store, save/restore (localStorage or json file) and undo for reset
+// why: a presenter who mis-clicks Reset or loses a staged state needs it back instantly
+// relations: reads/writes every store.rs slice, terminals via terminal.rs, raft log from raft.rs; ui in snapshot_panel.rs,
+//            reset_with_undo() used by attack_controls.rs and headless/, undo cleared by handlers.rs dispatch(); exports carry methodology.rs
+//            and the timeline.rs annotations
+
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use super::journal::AuditEvent;
+use super::methodology::Disclosure;
+use super::policy::CapabilityPolicy;
+use super::raft::{ReplicatedLog, CLUSTER_SIZE};
+use super::rate_limit::RateLimit;
+use super::store::DemoStore;
+use super::timeline::Annotation;
+use super::types::{InstanceState, LogEntry};
+use super::voting::VoteStrategy;
+
+/// bumped when the snapshot layout changes; older blobs are refused rather than half-restored
+pub const SNAPSHOT_VERSION: u32 = 1;
+/// localStorage key of the quick-save slot
+pub const SNAPSHOT_STORAGE_KEY: &str = "guardian.demo.snapshot";
+
+/// stat counters as saved
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SavedStats {
+    pub python_processed: u32,
+    pub python_crashed: u32,
+    pub python_downtime_ms: u64,
+    pub wasm_processed: u32,
+    pub wasm_rejected: u32,
+    pub js_processed: u32,
+}
+
+/// everything a presenter stages; measurements, running timers and the drift/anomaly/flood scenarios are not saved
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DemoSnapshot {
+    pub version: u32,
+    /// unix epoch ms
+    pub taken_ms: f64,
+    pub python_log: Vec<LogEntry>,
+    pub wasm_log: Vec<LogEntry>,
+    pub js_log: Vec<LogEntry>,
+    pub stats: SavedStats,
+    pub instance_states: [InstanceState; 3],
+    pub faulty_instance: Option<u8>,
+    pub leader_id: u8,
+    pub raft_log: ReplicatedLog,
+    pub suspicion_scores: [f64; 3],
+    pub suspicion_threshold: f64,
+    pub python_workers: [bool; 3],
+    pub python_active_worker: u8,
+    pub selected_attack: String,
+    pub journal: Vec<AuditEvent>,
+    pub policy: CapabilityPolicy,
+    pub voter: VoteStrategy,
+    /// absent from snapshots taken before the limiter existed
+    #[serde(default)]
+    pub rate_limit: RateLimit,
+    /// how the session's numbers were made; filled on export, ignored on restore
+    #[serde(default)]
+    pub methodology: Vec<Disclosure>,
+    /// presenter notes on the incident timeline
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+}
+
+impl DemoSnapshot {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// parses a saved blob, refusing other layout versions
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let version = serde_json::from_str::<serde_json::Value>(json).map_err(|e| e.to_string())?["version"].as_u64();
+        if version != Some(SNAPSHOT_VERSION as u64) {
+            return Err(format!("snapshot version {} not supported (expected {})", version.map(|v| v.to_string()).unwrap_or_else(|| "missing".into()), SNAPSHOT_VERSION));
+        }
+        let snap: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        snap.check_indices()?;
+        Ok(snap)
+    }
+
+    /// node and worker indices must address one of the three; imports and other tabs' broadcasts are not trusted to
+    pub fn check_indices(&self) -> Result<(), String> {
+        let indices = [("leader_id", Some(self.leader_id)), ("python_active_worker", Some(self.python_active_worker)), ("faulty_instance", self.faulty_instance)];
+        match indices.into_iter().find(|(_, i)| i.is_some_and(|i| usize::from(i) >= CLUSTER_SIZE)) {
+            Some((field, Some(i))) => Err(format!("{} {} out of range (expected below {})", field, i, CLUSTER_SIZE)),
+            _ => Ok(()),
+        }
+    }
+
+    /// "12 events, 3 crashes" style label for the restore buttons
+    pub fn label(&self) -> String {
+        format!("{} events, {} python crashes, {} wasm traps", self.journal.len(), self.stats.python_crashed, self.stats.wasm_rejected)
+    }
+}
+
+impl DemoStore {
+    /// captures every saved slice
+    pub fn snapshot(&self, taken_ms: f64) -> DemoSnapshot {
+        let DemoStore { stats, cluster, pool, control, .. } = *self;
+        DemoSnapshot {
+            version: SNAPSHOT_VERSION,
+            taken_ms,
+            python_log: self.python_log.entries(),
+            wasm_log: self.wasm_log.entries(),
+            js_log: self.js_log.entries(),
+            stats: SavedStats {
+                python_processed: stats.python_processed.get_untracked(),
+                python_crashed: stats.python_crashed.get_untracked(),
+                python_downtime_ms: stats.python_downtime_ms.get_untracked(),
+                wasm_processed: stats.wasm_processed.get_untracked(),
+                wasm_rejected: stats.wasm_rejected.get_untracked(),
+                js_processed: stats.js_processed.get_untracked(),
+            },
+            instance_states: cluster.instance_states.get_untracked(),
+            faulty_instance: cluster.faulty_instance.get_untracked(),
+            leader_id: cluster.leader_id.get_untracked(),
+            raft_log: cluster.log.get_untracked(),
+            suspicion_scores: cluster.suspicion.with_untracked(|s| s.scores),
+            suspicion_threshold: cluster.suspicion.with_untracked(|s| s.threshold),
+            python_workers: pool.python_workers.get_untracked(),
+            python_active_worker: pool.python_active_worker.get_untracked(),
+            selected_attack: control.selected_attack.get_untracked(),
+            journal: self.journal.get_untracked(),
+            policy: self.policy.get_untracked(),
+            voter: self.voter.get_untracked(),
+            rate_limit: self.rate_limit.get_untracked(),
+            methodology: Vec::new(),
+            annotations: self.annotations.get_untracked(),
+        }
+    }
+
+    /// writes a snapshot back; refused while an attack is running so no timer lands on restored state
+    pub fn restore(&self, snap: &DemoSnapshot) -> Result<(), String> {
+        let DemoStore { stats, cluster, pool, control, .. } = *self;
+        if control.is_running.get_untracked() || control.running_all.get_untracked() {
+            return Err("wait for the running attack to finish".to_string());
+        }
+        snap.check_indices()?;
+        self.python_log.replace(snap.python_log.clone());
+        self.wasm_log.replace(snap.wasm_log.clone());
+        self.js_log.replace(snap.js_log.clone());
+        stats.python_processed.set(snap.stats.python_processed);
+        stats.python_crashed.set(snap.stats.python_crashed);
+        stats.python_downtime_ms.set(snap.stats.python_downtime_ms);
+        stats.wasm_processed.set(snap.stats.wasm_processed);
+        stats.wasm_rejected.set(snap.stats.wasm_rejected);
+        stats.js_processed.set(snap.stats.js_processed);
+        cluster.instance_states.set(snap.instance_states);
+        cluster.faulty_instance.set(snap.faulty_instance);
+        cluster.leader_id.set(snap.leader_id);
+        cluster.log.set(snap.raft_log.clone());
+        cluster.drift.set(None);
+        cluster.anomaly.set(None);
+        self.flood.set(None);
+        cluster.suspicion.update(|s| {
+            s.scores = snap.suspicion_scores;
+            s.threshold = snap.suspicion_threshold;
+        });
+        pool.python_workers.set(snap.python_workers);
+        pool.python_active_worker.set(snap.python_active_worker);
+        pool.python_restarting.set(false);
+        control.selected_attack.set(snap.selected_attack.clone());
+        self.journal.set(snap.journal.clone());
+        self.annotations.set(snap.annotations.clone());
+        self.policy.set(snap.policy.clone());
+        self.voter.set(snap.voter);
+        self.rate_limit.set(snap.rate_limit);
+        Ok(())
+    }
+
+    /// the reset button: keeps what it clears so it can be undone until the next attack
+    pub fn reset_with_undo(&self, now_ms: f64) {
+        self.undo.set(Some(self.snapshot(now_ms)));
+        self.reset();
+    }
+
+    /// puts back the state from before the last reset; the journal keeps its reset entry and notes added since
+    pub fn undo_reset(&self) -> Result<(), String> {
+        let mut snap = self.undo.get_untracked().ok_or_else(|| "nothing to undo".to_string())?;
+        snap.journal = self.journal.get_untracked();
+        snap.annotations = self.annotations.get_untracked();
+        self.restore(&snap)?;
+        self.undo.set(None);
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+// what: save/restore controls for demo snapshots (quick-save slot in localStorage, json export/import)
+// why: presenters stage a state before a talk and want it back in one click after a mis-click or reload
+// relations: used by component.rs, snapshot model in snapshot.rs, exports carry the methodology_panel.rs disclosure, download/copy helpers in wasm.rs
+
+use leptos::*;
+use super::methodology::Disclosure;
+use super::snapshot::{DemoSnapshot, SNAPSHOT_STORAGE_KEY};
+use super::store::DemoStore;
+use super::wasm::{copy_to_clipboard, download_text};
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+fn saved() -> Option<DemoSnapshot> {
+    storage()
+        .and_then(|s| s.get_item(SNAPSHOT_STORAGE_KEY).ok()?)
+        .and_then(|json| DemoSnapshot::from_json(&json).ok())
+}
+
+/// quick-save slot plus export/import of the full demo state
+#[component]
+pub fn SnapshotPanel(store: DemoStore, methodology: Callback<(), Vec<Disclosure>>) -> impl IntoView {
+    let control = store.control;
+    let busy = move || control.is_running.get() || control.running_all.get();
+    let (slot, set_slot) = create_signal(saved().map(|s| s.label()));
+    let (import_text, set_import_text) = create_signal(String::new());
+    let (status, set_status) = create_signal(Option::<String>::None);
+
+    let apply = move |snap: DemoSnapshot, what: &str| {
+        let label = snap.label();
+        set_status.set(Some(match store.restore(&snap) {
+            Ok(()) => format!("✅ {} restored ({})", what, label),
+            Err(e) => format!("❌ {}", e),
+        }));
+    };
+
+    let save_slot = move |_| {
+        let snap = store.snapshot(js_sys::Date::now());
+        match storage().map(|s| s.set_item(SNAPSHOT_STORAGE_KEY, &snap.to_json())) {
+            Some(Ok(())) => {
+                set_slot.set(Some(snap.label()));
+                set_status.set(Some("✅ saved to this browser".to_string()));
+            }
+            _ => set_status.set(Some("❌ localStorage unavailable".to_string())),
+        }
+    };
+    let restore_slot = move |_| match saved() {
+        Some(snap) => apply(snap, "saved state"),
+        None => set_status.set(Some("❌ no readable saved state".to_string())),
+    };
+    // exported json carries the methodology; the quick-save slot does not need it
+    let exported = move || DemoSnapshot { methodology: methodology.call(()), ..store.snapshot(js_sys::Date::now()) }.to_json();
+    let import = move |_| match DemoSnapshot::from_json(&import_text.get()) {
+        Ok(snap) => {
+            apply(snap, "imported state");
+            set_import_text.set(String::new());
+        }
+        Err(e) => set_status.set(Some(format!("❌ {}", e))),
+    };
+
+    view! {
+        <div class="audit-panel snapshot-panel">
+            <h4>"💾 Demo Snapshot" <span class="demo-badge">"Save / Restore"</span></h4>
+            <p class="section-desc">"Stage a state (stats, terminals, node health, journal, policy, voter) and bring it back instantly."</p>
+            <div class="audit-controls">
+                <button class="action-btn" disabled=busy on:click=save_slot>"💾 Save"</button>
+                <button class="action-btn" disabled=move || busy() || slot.get().is_none() on:click=restore_slot>"⏪ Restore Saved"</button>
+                <button class="action-btn" on:click=move |_| download_text("guardian-demo-snapshot.json", "application/json", &exported())>"⬇️ Export"</button>
+                <button class="action-btn" on:click=move |_| copy_to_clipboard(&exported())>"📋 Copy"</button>
+            </div>
+            {move || slot.get().map(|label| view! { <p class="metrics-note">"Saved slot: " {label}</p> })}
+            <textarea
+                class="policy-editor snapshot-import"
+                placeholder="Paste an exported snapshot"
+                prop:value=move || import_text.get()
+                on:input=move |ev| set_import_text.set(event_target_value(&ev))
+            />
+            <button class="action-btn" disabled=move || busy() || import_text.get().is_empty() on:click=import>"⬆️ Import"</button>
+            {move || status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+        </div>
+    }
+}
@@ -0,0 +1,174 @@
+// what: soak run - repeats the sensor/parse workload for a set duration, samples js heap and wasm memory, flags steady growth
+// why: an edge gateway runs for months; a leak of a few KiB per reading only shows over time, in the demo's own code as much as a runtime's
+// relations: driven by handlers.rs (start_soak, soak_tick), memory read from diagnostics/caps.rs, wasm.rs and instances.rs,
+//            config and run kept in store.rs, shown by soak_panel.rs
+
+/// one workload iteration (sensor comparison plus a ring write per live instance) per tick
+pub const SOAK_TICK_MS: u64 = 1000;
+/// windows the series is split into; a leak raises the floor of every one
+pub const TREND_WINDOWS: usize = 4;
+/// samples needed per window before a verdict is given
+pub const MIN_WINDOW_SAMPLES: usize = 2;
+/// the floor has to rise at least this share overall to count as growth
+pub const GROWTH_FRACTION: f64 = 0.05;
+/// accepted duration (minutes) and sample interval (seconds)
+pub const DURATION_RANGE_MIN: (u32, u32) = (1, 240);
+pub const SAMPLE_RANGE_S: (u32, u32) = (1, 60);
+
+/// what a soak run does
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SoakConfig {
+    pub duration_min: u32,
+    pub sample_every_s: u32,
+}
+
+impl Default for SoakConfig {
+    fn default() -> Self {
+        Self { duration_min: 30, sample_every_s: 10 }
+    }
+}
+
+impl SoakConfig {
+    /// the same config with both values in their accepted range
+    pub fn clamped(self) -> Self {
+        Self {
+            duration_min: self.duration_min.clamp(DURATION_RANGE_MIN.0, DURATION_RANGE_MIN.1),
+            sample_every_s: self.sample_every_s.clamp(SAMPLE_RANGE_S.0, SAMPLE_RANGE_S.1),
+        }
+    }
+
+    pub fn duration_ms(&self) -> f64 {
+        self.duration_min as f64 * 60_000.0
+    }
+}
+
+/// memory read at one point of the run
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SoakSample {
+    pub t_s: f64,
+    pub iterations: u32,
+    /// performance.memory usedJSHeapSize (chromium only)
+    pub js_heap_bytes: Option<f64>,
+    /// the dashboard's own wasm linear memory
+    pub module_bytes: f64,
+    /// summed linear memory of the live instances
+    pub instance_bytes: f64,
+}
+
+/// one memory series the run watches
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemorySeries {
+    JsHeap,
+    Module,
+    Instances,
+}
+
+impl MemorySeries {
+    pub const ALL: [MemorySeries; 3] = [MemorySeries::JsHeap, MemorySeries::Module, MemorySeries::Instances];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MemorySeries::JsHeap => "JS heap (page + Pyodide)",
+            MemorySeries::Module => "Dashboard WASM memory",
+            MemorySeries::Instances => "Guardian instance memory",
+        }
+    }
+
+    pub fn value(&self, sample: &SoakSample) -> Option<f64> {
+        match self {
+            MemorySeries::JsHeap => sample.js_heap_bytes,
+            MemorySeries::Module => Some(sample.module_bytes),
+            MemorySeries::Instances => Some(sample.instance_bytes),
+        }
+    }
+
+    /// (t_s, bytes) points, skipping samples the browser could not read
+    pub fn points(&self, samples: &[SoakSample]) -> Vec<(f64, f64)> {
+        samples.iter().filter_map(|s| self.value(s).map(|v| (s.t_s, v))).collect()
+    }
+}
+
+/// leak verdict for one series
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Trend {
+    /// too few samples yet
+    Insufficient,
+    /// the floor levels off (gc sawtooth, a one-off grow)
+    Stable,
+    /// the floor rose in every window
+    Growing { per_min_bytes: f64 },
+}
+
+impl Trend {
+    pub fn label(&self) -> String {
+        match self {
+            Trend::Insufficient => "collecting".to_string(),
+            Trend::Stable => "stable".to_string(),
+            Trend::Growing { per_min_bytes } => format!("growing {:.1} KiB/min", per_min_bytes / 1024.0),
+        }
+    }
+}
+
+/// lowest point of each of `TREND_WINDOWS` equal windows; peaks are gc timing, the floor is what is retained
+pub fn window_floors(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    (0..TREND_WINDOWS)
+        .filter_map(|w| {
+            let window = &points[w * points.len() / TREND_WINDOWS..(w + 1) * points.len() / TREND_WINDOWS];
+            window.iter().copied().min_by(|a, b| a.1.total_cmp(&b.1))
+        })
+        .collect()
+}
+
+/// flags monotonic growth: every window's floor above the previous one and the last at least GROWTH_FRACTION above the first
+pub fn trend(points: &[(f64, f64)]) -> Trend {
+    if points.len() < TREND_WINDOWS * MIN_WINDOW_SAMPLES {
+        return Trend::Insufficient;
+    }
+    let floors = window_floors(points);
+    let rising = floors.windows(2).all(|w| w[1].1 > w[0].1);
+    let (first, last) = (floors[0], floors[floors.len() - 1]);
+    let growth = last.1 - first.1;
+    if !rising || growth < first.1 * GROWTH_FRACTION || last.0 <= first.0 {
+        return Trend::Stable;
+    }
+    Trend::Growing { per_min_bytes: growth / ((last.0 - first.0) / 60.0) }
+}
+
+/// soak progress for the panel
+#[derive(Clone, Debug, PartialEq)]
+pub struct SoakRun {
+    pub config: SoakConfig,
+    pub started_ms: f64,
+    pub iterations: u32,
+    pub samples: Vec<SoakSample>,
+    /// series already journalled as growing, so each is reported once
+    pub flagged: Vec<MemorySeries>,
+    pub finished: bool,
+}
+
+impl SoakRun {
+    pub fn new(config: SoakConfig, started_ms: f64) -> Self {
+        Self { config, started_ms, iterations: 0, samples: Vec::new(), flagged: Vec::new(), finished: false }
+    }
+
+    /// a sample is taken at the start and every `sample_every_s` after
+    pub fn sample_due(&self, elapsed_ms: f64) -> bool {
+        elapsed_ms >= self.samples.len() as f64 * self.config.sample_every_s as f64 * 1000.0
+    }
+
+    pub fn trend(&self, series: MemorySeries) -> Trend {
+        trend(&series.points(&self.samples))
+    }
+
+    /// series growing now that have not been journalled yet
+    pub fn newly_growing(&self) -> Vec<(MemorySeries, f64)> {
+        MemorySeries::ALL
+            .into_iter()
+            .filter(|s| !self.flagged.contains(s))
+            .filter_map(|s| match self.trend(s) {
+                Trend::Growing { per_min_bytes } => Some((s, per_min_bytes)),
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,113 @@
+// what: soak run panel - duration and sample interval, start/stop, one memory chart per series with its window floors, verdict table
+// why: shows whether the demo's js heap, its own wasm memory and the guardian instances level off over a long run or keep growing
+// relations: edits the store.rs soak_config signal, fires handlers.rs start_soak/stop_soak, charts the soak.rs run; rendered by component.rs
+
+use leptos::*;
+use super::budget::format_bytes;
+use super::soak::{window_floors, MemorySeries, SoakConfig, SoakRun, Trend, DURATION_RANGE_MIN, SAMPLE_RANGE_S};
+use super::store::DemoStore;
+
+/// chart box (svg user units)
+const CHART_W: f64 = 600.0;
+const CHART_H: f64 = 80.0;
+
+fn clock(s: f64) -> String {
+    format!("{}:{:02}", (s / 60.0) as u64, s as u64 % 60)
+}
+
+/// one series against the run's duration, y from its own low to high so small growth is visible
+fn chart(run: &SoakRun, series: MemorySeries) -> View {
+    let points = series.points(&run.samples);
+    let (low, high) = points.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| (lo.min(p.1), hi.max(p.1)));
+    let span = (high - low).max(1.0);
+    let duration_s = run.config.duration_ms() / 1000.0;
+    let at = |(t, v): (f64, f64)| (t / duration_s * CHART_W, CHART_H - (v - low) / span * (CHART_H - 8.0) - 4.0);
+    let line = points.iter().map(|p| { let (x, y) = at(*p); format!("{:.1},{:.1}", x, y) }).collect::<Vec<_>>().join(" ");
+    let growing = matches!(run.trend(series), Trend::Growing { .. });
+    view! {
+        <div class="flood-chart soak-chart">
+            <h5>{series.label()}</h5>
+            <svg viewBox=format!("0 0 {} {}", CHART_W, CHART_H) preserveAspectRatio="none">
+                <polyline class="soak-line" class:soak-growing=growing points=line />
+                {window_floors(&points).into_iter().map(|p| {
+                    let (x, y) = at(p);
+                    view! { <circle class="soak-floor" cx=format!("{:.1}", x) cy=format!("{:.1}", y) r="3" /> }
+                }).collect_view()}
+            </svg>
+        </div>
+    }
+    .into_view()
+}
+
+#[component]
+pub fn SoakPanel(store: DemoStore) -> impl IntoView {
+    let DemoStore { perf, soak, soak_config, .. } = store;
+    let running = move || soak.with(|r| r.as_ref().is_some_and(|r| !r.finished));
+    let edit = move |ev: ev::Event, apply: fn(&mut SoakConfig, u32)| {
+        if let Ok(v) = event_target_value(&ev).parse::<u32>() {
+            soak_config.update(|c| {
+                apply(c, v);
+                *c = c.clamped();
+            });
+        }
+    };
+
+    let report = move || soak.get().map(|run| {
+        let last = run.samples.last().copied().unwrap_or_default();
+        let status = if run.finished { "finished" } else { "running" };
+        view! {
+            <p class="metrics-note">{format!("{} of {} {} · {} iterations · {} samples", clock(last.t_s), clock(run.config.duration_ms() / 1000.0), status, run.iterations, run.samples.len())}</p>
+            {MemorySeries::ALL.into_iter().map(|s| chart(&run, s)).collect_view()}
+            <table class="capability-matrix voting-matrix">
+                <tr><th>"Series"</th><th>"First"</th><th>"Latest"</th><th>"Verdict"</th></tr>
+                {MemorySeries::ALL.into_iter().map(|s| {
+                    let points = s.points(&run.samples);
+                    let bytes = |p: Option<&(f64, f64)>| p.map(|p| format_bytes(p.1 as u64)).unwrap_or_else(|| "not readable".to_string());
+                    let trend = run.trend(s);
+                    let growing = matches!(trend, Trend::Growing { .. });
+                    view! {
+                        <tr>
+                            <td class="world-name">{s.label()}</td>
+                            <td>{bytes(points.first())}</td>
+                            <td>{bytes(points.last())}</td>
+                            <td class:vote-halted=growing>{trend.label()}</td>
+                        </tr>
+                    }
+                }).collect_view()}
+            </table>
+        }
+    });
+
+    view! {
+        <div class="demo-section soak-section">
+            <h3>"⏱️ Soak Run"<span class="attack-badge">"Leak Detection"</span></h3>
+            <p class="section-desc">
+                "Repeats the real sensor check and a ring write into every Guardian instance once a second, and samples the JS heap, this dashboard's own WASM memory and the instances' linear memory. A series whose low point rises in every quarter of the run is flagged as a leak; garbage-collector sawtooth and one-off grows are not."
+            </p>
+            <div class="voting-controls">
+                <label class="voting-tolerance">
+                    "Duration "
+                    <input type="number" min=DURATION_RANGE_MIN.0 max=DURATION_RANGE_MIN.1 prop:value=move || soak_config.get().duration_min
+                        on:change=move |ev| edit(ev, |c, v| c.duration_min = v) />
+                    " min"
+                </label>
+                <label class="voting-tolerance">
+                    "Sample every "
+                    <input type="number" min=SAMPLE_RANGE_S.0 max=SAMPLE_RANGE_S.1 prop:value=move || soak_config.get().sample_every_s
+                        on:change=move |ev| edit(ev, |c, v| c.sample_every_s = v) />
+                    " s"
+                </label>
+                {move || if running() {
+                    view! { <button class="action-btn" on:click=move |_| store.stop_soak()>"⏹ Stop Soak"</button> }
+                } else {
+                    view! {
+                        <button class="action-btn" disabled=move || !perf.pyodide_ready.get() on:click=move |_| store.start_soak()>
+                            "⏱️ Start Soak"
+                        </button>
+                    }
+                }}
+            </div>
+            {report}
+        </div>
+    }
+}
@@ -0,0 +1,26 @@
+// what: per-runtime stat counters (processed, crashed/voted out, downtime)
+// why: same card for every runtime, cells come from the RuntimeModel
+// relations: reads runtime_model.rs, rendered by component.rs
+
+use leptos::*;
+use super::journal::Runtime;
+use super::runtime_model::model;
+use super::store::DemoStore;
+
+#[component]
+pub fn StatsPanel(kind: Runtime, store: DemoStore) -> impl IntoView {
+    let model = model(kind);
+    view! {
+        <div class=format!("stats-panel {}-stats", kind.as_str())>
+            <h4>{format!("{} {} Stats", model.icon(), model.name())}</h4>
+            <div class="stats-row">
+                {move || model.stats(&store).into_iter().map(|cell| view! {
+                    <div class="stat-item">
+                        <span class=format!("stat-value {}", cell.tone)>{cell.value}</span>
+                        <span class="stat-label">{cell.label}</span>
+                    </div>
+                }).collect_view()}
+            </div>
+        </div>
+    }
+}
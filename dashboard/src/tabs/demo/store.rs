@@ -0,0 +1,467 @@
+// what: demo state store split into fine-grained slices
+// why: ~25 loose signals made every handler capture everything and clone whole arrays on update
+// relations: owned by component.rs, holds terminal.rs logs, journal.rs events, timeline.rs annotations, snapshot.rs undo, influx.rs writer, modbus.rs slave, capture.rs frames, policy.rs policy, voting.rs strategy, election.rs replay, rate_limit.rs limiter, quiz.rs score, soak.rs run, instances.rs live instances, supervisor.rs strategy and recoveries, prefork.rs python pool strategy, node_history.rs per-node histories, component_backend.rs backend runs, host_trace.rs module traces, hardware/oled.rs hmi status, library.rs user scenarios, payloads.rs attack code; counts started attacks in analytics/ when opted in, mirrors journal events to trace/
+
+use leptos::*;
+use crate::diagnostics::{use_diagnostics, Diagnostics};
+use crate::measure::stats::{summarize, Summary};
+use crate::analytics::{use_analytics, Counters};
+use crate::settings::{NoiseProfile, SensorBackend, Settings};
+use crate::tabs::hardware::oled::{use_hmi_status, HmiStatus};
+use super::capture::Capture;
+use super::component_backend::BackendRun;
+use super::drift::DriftRun;
+use super::election::ElectionReplay;
+use super::environment::{clean_reading, EnvSample, Environment};
+use super::anomaly::AnomalyRun;
+use super::flood::FloodRun;
+use super::host_trace::{use_host_trace, HostTrace, ModuleTrace};
+use super::budget::InstanceBudget;
+use super::instances::LiveInstance;
+use super::rate_limit::RateLimit;
+use super::soak::{SoakConfig, SoakRun};
+use super::quiz::Quiz;
+use super::influx::InfluxWriter;
+use super::journal::{AuditEvent, EventKind, Runtime};
+use super::library::{ScenarioLibrary, UserScenario};
+use super::payloads::Payloads;
+use super::metrics::MetricsSnapshot;
+use super::modbus::{plc_slave, Exchange};
+use super::node_history::{NodeId, NodeStats};
+use super::policy::CapabilityPolicy;
+use super::prefork::PythonStrategy;
+use super::snapshot::DemoSnapshot;
+use super::raft::{ElectionBench, ReplicatedLog, ELECTION_SEED, ELECTION_TRIALS};
+use super::summary::SessionNumbers;
+use super::supervisor::{RecoveryLog, Supervisor};
+use super::suspicion::Suspicion;
+use super::terminal::TerminalLog;
+use super::timeline::Annotation;
+use super::types::InstanceState;
+use super::voting::{has_quorum, VoteStrategy};
+use super::wasm::play_alert;
+use modbus_sim::Slave;
+
+/// real measurements (wasm instantiate, pyodide, sensor runs)
+#[derive(Clone, Copy)]
+pub struct PerfSlice {
+    pub wasm_instantiate_ms: RwSignal<Summary>,
+    pub measurements_done: RwSignal<bool>,
+    pub pyodide_ready: RwSignal<bool>,
+    pub pyodide_failed: RwSignal<bool>,
+    /// real pyodide cold-start time
+    pub pyodide_load_ms: RwSignal<f64>,
+    pub python_exec_ms: RwSignal<f64>,
+    pub wasm_exec_ms: RwSignal<f64>,
+    /// every sensor run is kept so repeated clicks tighten the interval
+    pub python_exec_samples: RwSignal<Vec<f64>>,
+    pub wasm_exec_samples: RwSignal<Vec<f64>>,
+    /// plain-js baseline runs (only when the column is enabled)
+    pub js_exec_samples: RwSignal<Vec<f64>>,
+    pub sensor_running: RwSignal<bool>,
+    pub sensor_ran: RwSignal<bool>,
+    /// every wasm-column sensor check with the backend that ran it
+    pub backend_runs: RwSignal<Vec<BackendRun>>,
+    /// raft failover latency over many simulated crashes (computed once per store)
+    pub election: ElectionBench,
+}
+
+impl PerfSlice {
+    /// headline numbers measured so far (summary text and device handoff)
+    pub fn session_numbers(&self) -> SessionNumbers {
+        let samples = |s: RwSignal<Vec<f64>>| s.with_untracked(|v| (!v.is_empty()).then(|| summarize(v, 0)));
+        SessionNumbers {
+            wasm_instantiate: self.measurements_done.get_untracked().then(|| self.wasm_instantiate_ms.get_untracked()),
+            pyodide_load_ms: Some(self.pyodide_load_ms.get_untracked()).filter(|ms| *ms > 0.0),
+            wasm_exec: samples(self.wasm_exec_samples),
+            python_exec: samples(self.python_exec_samples),
+            js_exec: samples(self.js_exec_samples),
+        }
+    }
+}
+
+/// 2oo3 voting state (three wasm instances)
+#[derive(Clone, Copy)]
+pub struct ClusterSlice {
+    pub instance_states: RwSignal<[InstanceState; 3]>,
+    pub faulty_instance: RwSignal<Option<u8>>,
+    /// current leader (changes if leader fails)
+    pub leader_id: RwSignal<u8>,
+    /// raft log of voted telemetry commits, one per instance (persisted, survives reset)
+    pub log: RwSignal<ReplicatedLog>,
+    /// sensor drift scenario progress (None until first run)
+    pub drift: RwSignal<Option<DriftRun>>,
+    /// telemetry anomaly scenario progress (None until first run)
+    pub anomaly: RwSignal<Option<AnomalyRun>>,
+    /// leader election being replayed on the wasm node ring (None when no election is showing)
+    pub election: RwSignal<Option<ElectionReplay>>,
+    /// per-instance suspicion scores (decayed once a second by component.rs)
+    pub suspicion: RwSignal<Suspicion>,
+}
+
+/// python worker pool state
+#[derive(Clone, Copy)]
+pub struct PoolSlice {
+    pub python_workers: RwSignal<[bool; 3]>,
+    pub python_active_worker: RwSignal<u8>,
+    pub python_restarting: RwSignal<bool>,
+    /// how a crashed worker is replaced (prefork.rs)
+    pub strategy: RwSignal<PythonStrategy>,
+}
+
+/// stat counters (wasm always has 0 downtime due to 2oo3 voting)
+#[derive(Clone, Copy)]
+pub struct StatsSlice {
+    pub python_processed: RwSignal<u32>,
+    pub python_crashed: RwSignal<u32>,
+    pub python_downtime_ms: RwSignal<u64>,
+    pub wasm_processed: RwSignal<u32>,
+    pub wasm_rejected: RwSignal<u32>,
+    pub js_processed: RwSignal<u32>,
+}
+
+/// attack sequencing state
+#[derive(Clone, Copy)]
+pub struct ControlSlice {
+    pub is_running: RwSignal<bool>,
+    /// track "run all attacks" mode
+    pub running_all: RwSignal<bool>,
+    pub selected_attack: RwSignal<String>,
+    /// library entry the selected attack runs as (None for a built-in run)
+    pub scenario: RwSignal<Option<UserScenario>>,
+}
+
+/// all demo state, cheap to copy into handlers
+#[derive(Clone, Copy)]
+pub struct DemoStore {
+    pub perf: PerfSlice,
+    pub cluster: ClusterSlice,
+    pub pool: PoolSlice,
+    pub stats: StatsSlice,
+    pub control: ControlSlice,
+    pub python_log: TerminalLog,
+    pub wasm_log: TerminalLog,
+    pub js_log: TerminalLog,
+    /// structured events, survives reset
+    pub journal: RwSignal<Vec<AuditEvent>>,
+    /// presenter notes on the incident timeline, in time order; survive reset like the journal (timeline.rs)
+    pub annotations: RwSignal<Vec<Annotation>>,
+    /// optional influxdb writer fed by every telemetry reading
+    pub influx: RwSignal<InfluxWriter>,
+    /// python payloads and wit excerpt from the attack-code module (payloads.rs; None until it has loaded)
+    pub payloads: RwSignal<Option<Result<Payloads, String>>>,
+    /// capability policy (loaded from wit/policy.toml, editable live)
+    pub policy: RwSignal<CapabilityPolicy>,
+    /// simulated plc the gateway writes telemetry to (modbus.rs)
+    pub modbus: RwSignal<Slave>,
+    /// rtu frames exchanged with the plc, for the packet inspector (capture.rs)
+    pub capture: RwSignal<Capture>,
+    /// how the 2oo3 voter compares instance outputs
+    pub voter: RwSignal<VoteStrategy>,
+    /// token bucket in front of the wasm gateway (rate_limit.rs)
+    pub rate_limit: RwSignal<RateLimit>,
+    /// message flood scenario progress (None until first run)
+    pub flood: RwSignal<Option<FloodRun>>,
+    /// quiz mode predictions and score (quiz.rs)
+    pub quiz: RwSignal<Quiz>,
+    /// user-defined scenarios, persisted in indexeddb (library.rs)
+    pub library: RwSignal<ScenarioLibrary>,
+    /// soak run settings and progress (soak.rs; None until first run)
+    pub soak_config: RwSignal<SoakConfig>,
+    pub soak: RwSignal<Option<SoakRun>>,
+    /// live instance memory/table sizes (None until spawned by component.rs)
+    pub budgets: RwSignal<[Option<InstanceBudget>; 3]>,
+    /// the live instances behind `budgets` (instances.rs)
+    pub instances: StoredValue<[Option<LiveInstance>; 3]>,
+    /// how faulted instances are replaced, and the recoveries measured under each (supervisor.rs)
+    pub supervisor: RwSignal<Supervisor>,
+    pub recoveries: RwSignal<RecoveryLog>,
+    /// pre-instantiated instance held by the warm-spare strategy, and its sizes (None when not warm)
+    pub spare: StoredValue<Option<LiveInstance>>,
+    pub spare_budget: RwSignal<Option<InstanceBudget>>,
+    /// app-wide diagnostics (pyodide probe decides attack expectations)
+    pub diagnostics: RwSignal<Diagnostics>,
+    /// host calls of loaded modules, shared with the proof tab (host_trace.rs)
+    pub host_trace: RwSignal<HostTrace>,
+    /// per-node histories of the python workers and wasm instances (node_history.rs)
+    pub nodes: RwSignal<NodeStats>,
+    /// node whose history drawer is open (node_drawer.rs)
+    pub node_detail: RwSignal<Option<NodeId>>,
+    /// state cleared by the last reset, until the next attack (snapshot.rs)
+    pub undo: RwSignal<Option<DemoSnapshot>>,
+    /// simulated plant behind the sensor readings (environment.rs)
+    pub environment: StoredValue<Environment>,
+    /// what the pi's oled shows, shared with the hardware tab (hardware/oled.rs)
+    pub hmi: RwSignal<HmiStatus>,
+    settings: RwSignal<Settings>,
+    /// opt-in local counters (analytics/mod.rs)
+    analytics: RwSignal<Counters>,
+}
+
+impl DemoStore {
+    pub fn new(settings: RwSignal<Settings>) -> Self {
+        Self {
+            perf: PerfSlice {
+                wasm_instantiate_ms: create_rw_signal(Summary::default()),
+                measurements_done: create_rw_signal(false),
+                pyodide_ready: create_rw_signal(false),
+                pyodide_failed: create_rw_signal(false),
+                pyodide_load_ms: create_rw_signal(0.0),
+                python_exec_ms: create_rw_signal(0.0),
+                wasm_exec_ms: create_rw_signal(0.0),
+                python_exec_samples: create_rw_signal(Vec::new()),
+                wasm_exec_samples: create_rw_signal(Vec::new()),
+                js_exec_samples: create_rw_signal(Vec::new()),
+                sensor_running: create_rw_signal(false),
+                sensor_ran: create_rw_signal(false),
+                backend_runs: create_rw_signal(Vec::new()),
+                election: ElectionBench::run(ELECTION_TRIALS, ELECTION_SEED),
+            },
+            cluster: ClusterSlice {
+                instance_states: create_rw_signal([InstanceState::Healthy; 3]),
+                faulty_instance: create_rw_signal(None),
+                leader_id: create_rw_signal(0),
+                log: create_rw_signal(ReplicatedLog::default()),
+                drift: create_rw_signal(None),
+                anomaly: create_rw_signal(None),
+                election: create_rw_signal(None),
+                suspicion: create_rw_signal(Suspicion::default()),
+            },
+            pool: PoolSlice {
+                python_workers: create_rw_signal([true; 3]),
+                python_active_worker: create_rw_signal(0),
+                python_restarting: create_rw_signal(false),
+                strategy: create_rw_signal(PythonStrategy::default()),
+            },
+            stats: StatsSlice {
+                python_processed: create_rw_signal(0),
+                python_crashed: create_rw_signal(0),
+                python_downtime_ms: create_rw_signal(0),
+                wasm_processed: create_rw_signal(0),
+                wasm_rejected: create_rw_signal(0),
+                js_processed: create_rw_signal(0),
+            },
+            control: ControlSlice {
+                is_running: create_rw_signal(false),
+                running_all: create_rw_signal(false),
+                selected_attack: create_rw_signal("bufferOverflow".to_string()),
+                scenario: create_rw_signal(None),
+            },
+            python_log: TerminalLog::new(),
+            wasm_log: TerminalLog::new(),
+            js_log: TerminalLog::new(),
+            journal: create_rw_signal(Vec::new()),
+            annotations: create_rw_signal(Vec::new()),
+            influx: create_rw_signal(InfluxWriter {
+                url: settings.with_untracked(|s| s.endpoints.influx_write.clone()),
+                ..Default::default()
+            }),
+            payloads: create_rw_signal(None),
+            policy: create_rw_signal(CapabilityPolicy::load_default()),
+            modbus: create_rw_signal(plc_slave()),
+            capture: create_rw_signal(Capture::default()),
+            voter: create_rw_signal(VoteStrategy::default()),
+            rate_limit: create_rw_signal(RateLimit::default()),
+            flood: create_rw_signal(None),
+            quiz: create_rw_signal(Quiz::default()),
+            library: create_rw_signal(ScenarioLibrary::default()),
+            soak_config: create_rw_signal(SoakConfig::default()),
+            soak: create_rw_signal(None),
+            budgets: create_rw_signal([None; 3]),
+            instances: store_value([None, None, None]),
+            supervisor: create_rw_signal(Supervisor::default()),
+            recoveries: create_rw_signal(RecoveryLog::default()),
+            spare: store_value(None),
+            spare_budget: create_rw_signal(None),
+            diagnostics: use_diagnostics(),
+            host_trace: use_host_trace(),
+            nodes: create_rw_signal(NodeStats::new(wall_ms())),
+            node_detail: create_rw_signal(None),
+            undo: create_rw_signal(None),
+            environment: store_value(new_environment()),
+            hmi: use_hmi_status(),
+            settings,
+            analytics: use_analytics(),
+        }
+    }
+
+    /// benchmark iterations from settings
+    pub fn iterations(&self) -> u32 {
+        self.settings.with_untracked(|s| s.benchmark_iterations)
+    }
+
+    /// whether the plain-js baseline column is shown
+    pub fn js_baseline(&self) -> bool {
+        self.settings.with_untracked(|s| s.js_baseline)
+    }
+
+    /// built-in python payload of `attack`; None until the attack-code module has loaded
+    pub fn attack_code(&self, attack: &str) -> Option<String> {
+        self.payloads.with_untracked(|p| p.as_ref()?.as_ref().ok()?.code(attack).map(str::to_string))
+    }
+
+    /// whether the attack-code module has loaded; security attacks wait for it
+    pub fn payloads_ready(&self) -> bool {
+        self.payloads.with(|p| matches!(p, Some(Ok(_))))
+    }
+
+    /// which sensor-driver build the wasm column runs
+    pub fn sensor_backend(&self) -> SensorBackend {
+        self.settings.with_untracked(|s| s.sensor_backend)
+    }
+
+    /// how noisy the simulated sensors are
+    pub fn noise_profile(&self) -> NoiseProfile {
+        self.settings.with_untracked(|s| s.environment.noise)
+    }
+
+    /// the next simulated sensor reading, under the environment settings
+    pub fn environment_reading(&self) -> EnvSample {
+        let env = self.settings.with_untracked(|s| s.environment.clone());
+        let now_ms = wall_ms();
+        self.environment
+            .try_update_value(|e| e.read(&env, now_ms))
+            .unwrap_or_else(|| EnvSample { hour: 0.0, reading: clean_reading(&env, 0.0), spike: false })
+    }
+
+    /// appends a journal event (and beeps on faults when sound is on)
+    pub fn record(&self, kind: EventKind, runtime: Option<Runtime>, attack: &str, detail: String) {
+        trace_event(kind, runtime, attack, &detail, None);
+        let event = AuditEvent::new(js_sys::Date::now(), kind, runtime, attack, detail);
+        crate::headless::emit(|| crate::headless::event_message(&event));
+        self.journal.update(|j| j.push(event));
+        if kind == EventKind::AttackStarted && self.settings.with_untracked(|s| s.analytics) {
+            self.analytics.update(|c| c.count_attack(attack));
+        }
+        if self.settings.with_untracked(|s| s.sound) && matches!(kind, EventKind::Trap | EventKind::Crash | EventKind::PolicyBreach | EventKind::QuorumLost) {
+            play_alert(if kind == EventKind::Trap { 660.0 } else { 220.0 }, 150.0);
+        }
+    }
+
+    /// keeps a module load's host calls and journals one event per import it called
+    pub fn trace_module(&self, runtime: Runtime, attack: &str, trace: ModuleTrace) {
+        for (detail, ms) in trace.journal_lines() {
+            self.record_timed(EventKind::HostCall, runtime, attack, detail, ms);
+        }
+        self.host_trace.update(|t| t.load(trace));
+    }
+
+    /// captures both frames of a modbus exchange for the packet inspector
+    pub fn capture_exchange(&self, exchange: &Exchange) {
+        self.capture.update(|c| c.push_exchange(js_sys::Date::now(), exchange));
+    }
+
+    /// appends a journal event with a measured duration
+    pub fn record_timed(&self, kind: EventKind, runtime: Runtime, attack: &str, detail: String, ms: f64) {
+        trace_event(kind, Some(runtime), attack, &detail, Some(ms));
+        let event = AuditEvent::new(js_sys::Date::now(), kind, Some(runtime), attack, detail).with_duration(ms);
+        crate::headless::emit(|| crate::headless::event_message(&event));
+        self.journal.update(|j| j.push(event));
+    }
+
+    /// enough wasm instances are healthy for a 2oo3 vote
+    pub fn has_quorum(&self) -> bool {
+        self.cluster.instance_states.with(|s| has_quorum(s))
+    }
+
+    /// marks one wasm instance without cloning the whole array; its history follows the up/down change
+    pub fn set_instance(&self, idx: usize, state: InstanceState) {
+        self.cluster.instance_states.update(|s| s[idx] = state);
+        let now_ms = wall_ms();
+        self.nodes.update(|n| match state {
+            InstanceState::Healthy => n.wasm[idx].up(now_ms),
+            _ => n.wasm[idx].down(now_ms),
+        });
+    }
+
+    /// a wasm instance back in the vote after a rebuild measured at `rebuild_ms`
+    pub fn rebuilt(&self, idx: usize, rebuild_ms: f64) {
+        self.nodes.update(|n| n.wasm[idx].recovered(rebuild_ms));
+        self.set_instance(idx, InstanceState::Healthy);
+    }
+
+    /// a python worker crashed and is down until respawned
+    pub fn worker_crashed(&self, idx: usize) {
+        let now_ms = wall_ms();
+        self.nodes.update(|n| {
+            n.python[idx].trap();
+            n.python[idx].down(now_ms);
+        });
+    }
+
+    /// a python worker back after a respawn of `restart_ms`
+    pub fn worker_respawned(&self, idx: usize, restart_ms: f64) {
+        let now_ms = wall_ms();
+        self.nodes.update(|n| {
+            n.python[idx].recovered(restart_ms);
+            n.python[idx].up(now_ms);
+        });
+    }
+
+    /// current counters for the prometheus view
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            python_processed: self.stats.python_processed.get(),
+            python_crashed: self.stats.python_crashed.get(),
+            python_downtime_ms: self.stats.python_downtime_ms.get(),
+            wasm_processed: self.stats.wasm_processed.get(),
+            wasm_rejected: self.stats.wasm_rejected.get(),
+        }
+    }
+
+    /// clears stats, terminals and node state (journal and raft log survive)
+    pub fn reset(&self) {
+        self.python_log.replace(Vec::new());
+        self.wasm_log.replace(Vec::new());
+        self.js_log.replace(Vec::new());
+        self.stats.python_processed.set(0);
+        self.stats.python_crashed.set(0);
+        self.stats.python_downtime_ms.set(0);
+        self.stats.wasm_processed.set(0);
+        self.stats.wasm_rejected.set(0);
+        self.stats.js_processed.set(0);
+        self.cluster.instance_states.set([InstanceState::Healthy; 3]);
+        self.cluster.faulty_instance.set(None);
+        self.cluster.drift.set(None);
+        self.cluster.anomaly.set(None);
+        self.cluster.election.set(None);
+        self.flood.set(None);
+        self.cluster.suspicion.update(|s| *s = Suspicion { threshold: s.threshold, ..Default::default() });
+        // the raft log is persistent state (wiped from its own panel); bring crashed nodes back and keep the leader consistent with it
+        self.cluster.log.update(|l| if l.nodes.iter().any(|n| !n.up) { l.recover(); });
+        self.cluster.leader_id.set(self.cluster.log.with_untracked(|l| l.leader) as u8);
+        self.pool.python_workers.set([true; 3]);
+        self.pool.python_active_worker.set(0);
+        self.pool.python_restarting.set(false);
+        self.nodes.set(NodeStats::new(wall_ms()));
+        self.node_detail.set(None);
+        self.recoveries.set(RecoveryLog::default());
+        self.control.is_running.set(false);
+        self.control.running_all.set(false);
+    }
+}
+
+/// wall clock for node histories and terminal line stamps; both are also built by native tests, where js_sys cannot be called
+pub(super) fn wall_ms() -> f64 {
+    if cfg!(target_arch = "wasm32") { js_sys::Date::now() } else { 0.0 }
+}
+
+/// a session whose simulated day starts at the local time, with fresh noise; fixed under native tests
+fn new_environment() -> Environment {
+    if !cfg!(target_arch = "wasm32") {
+        return Environment::new(1, 12.0, 0.0);
+    }
+    let date = js_sys::Date::new_0();
+    let hour = date.get_hours() as f64 + date.get_minutes() as f64 / 60.0;
+    Environment::new((js_sys::Math::random() * u64::MAX as f64) as u64, hour, js_sys::Date::now())
+}
+
+/// mirrors a journal event into the trace buffer (crashes and breaches as warnings)
+fn trace_event(kind: EventKind, runtime: Option<Runtime>, attack: &str, detail: &str, ms: Option<f64>) {
+    let runtime = runtime.map(|r| r.as_str()).unwrap_or("-");
+    match kind {
+        EventKind::QuorumLost => tracing::error!(target: "attack", kind = kind.id(), runtime, attack, ms, "{}", detail),
+        EventKind::Crash | EventKind::PolicyBreach | EventKind::Overload => tracing::warn!(target: "attack", kind = kind.id(), runtime, attack, ms, "{}", detail),
+        _ => tracing::info!(target: "attack", kind = kind.id(), runtime, attack, ms, "{}", detail),
+    }
+}
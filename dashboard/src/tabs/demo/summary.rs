@@ -0,0 +1,205 @@
+// what: concise markdown / plain-text summary of the session (measured numbers, attack outcomes, browser) from the journal
+// why: results get pasted into tickets and emails; a summary beats a screenshot or a raw siem export
+// relations: reads journal.rs events (attack outcomes, ledger.rs incidents) and store.rs perf numbers, methodology.rs disclosure, timestamps via siem.rs rfc3339; copied/emailed by audit_panel.rs
+
+use crate::measure::stats::Summary;
+use super::journal::{AuditEvent, EventKind, Runtime};
+use super::ledger::{format_ms, incidents};
+use super::methodology::{to_lines, to_markdown, Disclosure};
+use super::siem::rfc3339;
+
+/// output flavour
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SummaryFormat {
+    /// tables, for trackers that render markdown
+    Markdown,
+    /// one line per item, for email bodies
+    Text,
+}
+
+/// measured headline numbers (None = not measured this session)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionNumbers {
+    pub wasm_instantiate: Option<Summary>,
+    pub pyodide_load_ms: Option<f64>,
+    pub wasm_exec: Option<Summary>,
+    pub python_exec: Option<Summary>,
+    pub js_exec: Option<Summary>,
+}
+
+impl SessionNumbers {
+    /// (label, value) rows for what was measured
+    fn rows(&self) -> Vec<(&'static str, String)> {
+        let timed = |label, s: &Option<Summary>, decimals| s.map(|s| (label, s.display(decimals)));
+        [
+            timed("WASM instantiate", &self.wasm_instantiate, 3),
+            self.pyodide_load_ms.map(|ms| ("Pyodide cold start", format!("{:.0}ms", ms))),
+            timed("Sensor run (WASM)", &self.wasm_exec, 3),
+            timed("Sensor run (Python)", &self.python_exec, 2),
+            timed("Sensor run (JavaScript)", &self.js_exec, 3),
+        ].into_iter().flatten().collect()
+    }
+}
+
+/// what one attack did to each runtime
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttackOutcome {
+    pub started_ms: f64,
+    pub attack: String,
+    /// display name from the AttackStarted event
+    pub name: String,
+    pub python: Option<EventKind>,
+    pub wasm: Option<EventKind>,
+    pub python_recovery_ms: Option<f64>,
+    pub wasm_recovery_ms: Option<f64>,
+}
+
+/// short verb for an outcome event
+pub fn outcome_label(kind: Option<EventKind>) -> &'static str {
+    match kind {
+        Some(EventKind::Crash) => "crashed",
+        Some(EventKind::Trap) => "trapped",
+        Some(EventKind::PolicyBreach) => "capability granted",
+        Some(EventKind::Outvoted) => "outvoted",
+        Some(EventKind::QuorumLost) => "halted (no quorum)",
+        Some(EventKind::Anomaly) => "anomaly flagged",
+        Some(EventKind::Overload) => "overloaded",
+        Some(EventKind::LoadShed) => "shed load",
+        Some(EventKind::Leak) => "memory grew",
+        _ => "no fault",
+    }
+}
+
+/// one outcome per AttackStarted; later events join the latest run of the same attack,
+/// keeping the most severe fault and the first measured recovery per runtime
+pub fn outcomes(events: &[AuditEvent]) -> Vec<AttackOutcome> {
+    let mut out: Vec<AttackOutcome> = Vec::new();
+    for event in events {
+        if event.kind == EventKind::AttackStarted {
+            out.push(AttackOutcome {
+                started_ms: event.timestamp_ms,
+                attack: event.attack.clone(),
+                name: event.detail.clone(),
+                python: None,
+                wasm: None,
+                python_recovery_ms: None,
+                wasm_recovery_ms: None,
+            });
+            continue;
+        }
+        let Some(run) = out.iter_mut().rev().find(|o| o.attack == event.attack) else { continue };
+        let (fault, recovery) = match event.runtime {
+            Some(Runtime::Python) => (&mut run.python, &mut run.python_recovery_ms),
+            Some(Runtime::Wasm) => (&mut run.wasm, &mut run.wasm_recovery_ms),
+            _ => continue,
+        };
+        match event.kind {
+            EventKind::Recovery | EventKind::Election => {
+                if recovery.is_none() {
+                    *recovery = event.duration_ms;
+                }
+            }
+            EventKind::Crash | EventKind::Trap | EventKind::PolicyBreach | EventKind::Outvoted | EventKind::QuorumLost | EventKind::Anomaly | EventKind::Overload | EventKind::LoadShed | EventKind::Leak => {
+                if fault.is_none_or(|f| event.kind.syslog_severity() < f.syslog_severity()) {
+                    *fault = Some(event.kind);
+                }
+            }
+            EventKind::AttackStarted | EventKind::HostCall | EventKind::Reset => {}
+        }
+    }
+    out
+}
+
+fn cell(kind: Option<EventKind>, recovery_ms: Option<f64>) -> String {
+    match recovery_ms {
+        Some(ms) => format!("{}, recovered in {:.1}ms", outcome_label(kind), ms),
+        None => outcome_label(kind).to_string(),
+    }
+}
+
+/// the summary text; `browser` is the user agent, `missing_apis` what the page had to do without, `methodology` closes it
+pub fn render(numbers: &SessionNumbers, events: &[AuditEvent], browser: &str, missing_apis: &[&str], methodology: &[Disclosure], generated_ms: f64, format: SummaryFormat) -> String {
+    let missing = if missing_apis.is_empty() { "none".to_string() } else { missing_apis.join(", ") };
+    let runs = outcomes(events);
+    let ledger = incidents(events);
+    let mut out = String::new();
+    match format {
+        SummaryFormat::Markdown => {
+            out.push_str("## Guardian One demo summary\n\n");
+            out.push_str(&format!("- Generated: {}\n- Browser: {}\n- Missing browser APIs: {}\n\n", rfc3339(generated_ms), browser, missing));
+            out.push_str("### Measured\n\n");
+            let rows = numbers.rows();
+            if rows.is_empty() {
+                out.push_str("Nothing measured yet.\n\n");
+            } else {
+                out.push_str("| Metric | Value |\n|---|---|\n");
+                for (label, value) in rows {
+                    out.push_str(&format!("| {} | {} |\n", label, value));
+                }
+                out.push('\n');
+            }
+            out.push_str(&format!("### Attack outcomes ({})\n\n", runs.len()));
+            if runs.is_empty() {
+                out.push_str("No attacks run.\n");
+            } else {
+                out.push_str("| Time (UTC) | Attack | Python | WASM |\n|---|---|---|---|\n");
+                for run in &runs {
+                    out.push_str(&format!(
+                        "| {} | {} | {} | {} |\n",
+                        &rfc3339(run.started_ms)[11..19], run.name, cell(run.python, run.python_recovery_ms), cell(run.wasm, run.wasm_recovery_ms),
+                    ));
+                }
+            }
+            if !ledger.is_empty() {
+                out.push_str(&format!("\n### Incidents ({})\n\n", ledger.len()));
+                out.push_str("| Incident | Cause | Runtime | Fault | Detected after | Recovery | Output lost |\n|---|---|---|---|---|---|---|\n");
+                for i in &ledger {
+                    out.push_str(&format!(
+                        "| {} | {} | {} | {} | {} | {} | {} |\n",
+                        i.label(), i.cause, i.runtime.as_str(), outcome_label(Some(i.fault)), format_ms(i.detection_ms), i.recovery_text(), format_ms(Some(i.downtime_ms())),
+                    ));
+                }
+            }
+            if !methodology.is_empty() {
+                out.push_str("\n### Methodology\n\n");
+                out.push_str(&to_markdown(methodology));
+            }
+        }
+        SummaryFormat::Text => {
+            out.push_str("Guardian One demo summary\n");
+            out.push_str(&format!("Generated: {}\nBrowser: {}\nMissing browser APIs: {}\n\n", rfc3339(generated_ms), browser, missing));
+            out.push_str("Measured:\n");
+            let rows = numbers.rows();
+            if rows.is_empty() {
+                out.push_str("  nothing measured yet\n");
+            }
+            for (label, value) in rows {
+                out.push_str(&format!("  {}: {}\n", label, value));
+            }
+            out.push_str(&format!("\nAttack outcomes ({}):\n", runs.len()));
+            if runs.is_empty() {
+                out.push_str("  no attacks run\n");
+            }
+            for run in &runs {
+                out.push_str(&format!(
+                    "  {} {} - Python: {}; WASM: {}\n",
+                    &rfc3339(run.started_ms)[11..19], run.name, cell(run.python, run.python_recovery_ms), cell(run.wasm, run.wasm_recovery_ms),
+                ));
+            }
+            if !ledger.is_empty() {
+                out.push_str(&format!("\nIncidents ({}):\n", ledger.len()));
+            }
+            for i in &ledger {
+                out.push_str(&format!(
+                    "  {} {} - {} {}, detected after {}, recovery {}, output lost {}\n",
+                    i.label(), i.cause, i.runtime.as_str(), outcome_label(Some(i.fault)), format_ms(i.detection_ms), i.recovery_text(), format_ms(Some(i.downtime_ms())),
+                ));
+            }
+            if !methodology.is_empty() {
+                out.push_str("\nMethodology:\n");
+                out.push_str(&to_lines(methodology, "  "));
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,107 @@
+// what: how a faulted wasm instance is replaced - rebuilt on demand, or swapped for a pre-instantiated warm spare - and the measured recoveries of each
+// why: a warm spare trades one extra instance's memory for a recovery that skips compile + instantiate; the log shows what that buys
+// relations: strategy and log live in store.rs, the spare and the timed swaps in instances.rs, used by handlers.rs recoveries,
+//            compared in supervisor_panel.rs
+
+use crate::measure::stats::{summarize, Summary};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Supervisor {
+    /// compile and instantiate a fresh instance when one faults
+    #[default]
+    Rebuild,
+    /// keep a fourth instance instantiated and swap it in on fault, re-warming off the recovery path
+    WarmSpare,
+}
+
+impl Supervisor {
+    pub const ALL: [Supervisor; 2] = [Supervisor::Rebuild, Supervisor::WarmSpare];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Supervisor::Rebuild => "rebuild",
+            Supervisor::WarmSpare => "warm-spare",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.id() == id)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Supervisor::Rebuild => "Rebuild on demand",
+            Supervisor::WarmSpare => "Warm spare",
+        }
+    }
+
+    pub fn note(&self) -> &'static str {
+        match self {
+            Supervisor::Rebuild => "no idle memory; every recovery pays compile + instantiate",
+            Supervisor::WarmSpare => "one idle instance's memory; recovery is a swap, the spare is rebuilt afterwards",
+        }
+    }
+
+    /// journal wording for a recovery done this way
+    pub fn verb(&self) -> &'static str {
+        match self {
+            Supervisor::Rebuild => "rebuilt",
+            Supervisor::WarmSpare => "swapped for the warm spare",
+        }
+    }
+
+    /// what actually happens on a fault: a spare already swapped in and not yet re-warmed means a rebuild
+    pub fn pick(self, spare_ready: bool) -> Supervisor {
+        match self {
+            Supervisor::WarmSpare if spare_ready => Supervisor::WarmSpare,
+            _ => Supervisor::Rebuild,
+        }
+    }
+}
+
+/// recoveries measured this session, per strategy actually used
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecoveryLog {
+    pub rebuild_ms: Vec<f64>,
+    pub swap_ms: Vec<f64>,
+    /// spare instantiations, paid off the recovery path
+    pub warm_ms: Vec<f64>,
+    /// faults under the warm-spare strategy that found no spare and rebuilt
+    pub fallbacks: u32,
+}
+
+impl RecoveryLog {
+    pub fn record(&mut self, used: Supervisor, ms: f64) {
+        match used {
+            Supervisor::Rebuild => self.rebuild_ms.push(ms),
+            Supervisor::WarmSpare => self.swap_ms.push(ms),
+        }
+    }
+
+    pub fn samples(&self, used: Supervisor) -> &[f64] {
+        match used {
+            Supervisor::Rebuild => &self.rebuild_ms,
+            Supervisor::WarmSpare => &self.swap_ms,
+        }
+    }
+
+    pub fn summary(&self, used: Supervisor) -> Summary {
+        summarize(self.samples(used), 0)
+    }
+
+    pub fn warm_summary(&self) -> Summary {
+        summarize(&self.warm_ms, 0)
+    }
+
+    /// median rebuild over median swap, once both have run. A swap faster than the timer can
+    /// resolve is counted at one tick, so the figure is a lower bound (second value true).
+    pub fn speedup(&self, resolution_ms: f64) -> Option<(f64, bool)> {
+        if self.rebuild_ms.is_empty() || self.swap_ms.is_empty() {
+            return None;
+        }
+        let swap = self.summary(Supervisor::WarmSpare).median;
+        let floor = resolution_ms.max(f64::EPSILON);
+        let bounded = swap < floor;
+        Some((self.summary(Supervisor::Rebuild).median / swap.max(floor), bounded))
+    }
+}
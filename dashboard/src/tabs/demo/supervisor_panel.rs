@@ -0,0 +1,148 @@
+// what: supervisor strategy pickers - wasm rebuild vs warm spare with the recoveries measured this session, python cold start vs pre-forked standbys
+// why: "keep a spare warm" is a real engine tradeoff, and wasm should be compared against python's best practice, not its naive deployment
+// relations: edits the store.rs supervisor signal through instances.rs set_supervisor and the pool strategy, reads supervisor.rs RecoveryLog and
+//            prefork.rs takeover(); rendered by component.rs
+
+use leptos::*;
+use crate::measure::timing::timer_resolution_ms;
+use super::budget::format_bytes;
+use super::handlers::{restart_delay_ms, LEADER_RESTART_MS};
+use super::prefork::{takeover, PythonStrategy, DEFAULT_RESYNC_MS, HEARTBEAT_MS};
+use super::store::DemoStore;
+use super::supervisor::Supervisor;
+
+#[component]
+pub fn SupervisorPanel(store: DemoStore) -> impl IntoView {
+    let DemoStore { supervisor, recoveries, spare_budget, control, pool, perf, .. } = store;
+    let resolution_ms = timer_resolution_ms();
+
+    let spare_status = move || match (supervisor.get(), spare_budget.get()) {
+        (Supervisor::Rebuild, _) => "No spare held".to_string(),
+        (Supervisor::WarmSpare, Some(b)) => format!("Spare warm · {} idle", format_bytes(b.memory_bytes())),
+        (Supervisor::WarmSpare, None) => "Spare re-warming…".to_string(),
+    };
+    let idle_memory = move |s: Supervisor| match s {
+        Supervisor::Rebuild => "none".to_string(),
+        Supervisor::WarmSpare => spare_budget.get().map_or("one instance".to_string(), |b| format_bytes(b.memory_bytes())),
+    };
+    let extra_work = move |s: Supervisor| match s {
+        Supervisor::Rebuild => "—".to_string(),
+        Supervisor::WarmSpare => recoveries.with(|l| if l.warm_ms.is_empty() {
+            "spare builds off the recovery path".to_string()
+        } else {
+            format!("{} spare build{} · median {}", l.warm_ms.len(), if l.warm_ms.len() == 1 { "" } else { "s" }, l.warm_summary().display(3))
+        }),
+    };
+
+    // expected python gaps for a crash with both standbys up, from this session's cold start and sensor run
+    let python_row = move |s: PythonStrategy| {
+        let cold_ms = restart_delay_ms(perf.pyodide_load_ms.get(), LEADER_RESTART_MS, 0);
+        let t = takeover(s, 2, cold_ms, perf.python_exec_ms.get());
+        view! {
+            <tr class:voting-selected=move || pool.strategy.get() == s title=s.note()>
+                <td class="world-name">{s.label()}</td>
+                <td class="vote-halted">{format!("{}ms", t.output_gap_ms)}</td>
+                <td>{format!("{}ms", t.pool_restored_ms)}</td>
+            </tr>
+        }
+    };
+    let resync_note = move || {
+        let run_ms = perf.python_exec_ms.get();
+        if run_ms > 0.0 {
+            format!("{}ms heartbeat + {:.0}ms re-sync (one measured Python sensor run)", HEARTBEAT_MS, run_ms.ceil())
+        } else {
+            format!("{}ms heartbeat + {}ms re-sync (run the sensor check to measure it)", HEARTBEAT_MS, DEFAULT_RESYNC_MS)
+        }
+    };
+
+    view! {
+        <div class="demo-section supervisor-section">
+            <h3>"🔁 Supervisor Strategy"<span class="attack-badge">"Recovery Tradeoffs"</span></h3>
+            <p class="section-desc">"How a trapped, drifting or evicted WASM instance is replaced: compile and instantiate a new one, or swap in a fourth instance kept instantiated for the purpose."</p>
+            <div class="voting-controls">
+                <select
+                    class="network-select"
+                    disabled=move || control.is_running.get()
+                    on:change=move |ev| {
+                        if let Some(strategy) = Supervisor::from_id(&event_target_value(&ev)) {
+                            store.set_supervisor(strategy);
+                        }
+                    }
+                >
+                    {Supervisor::ALL.into_iter().map(|s| view! {
+                        <option value=s.id() selected=move || supervisor.get() == s>{s.label()}</option>
+                    }).collect_view()}
+                </select>
+                <span class="drift-status">{spare_status}</span>
+            </div>
+            <table class="capability-matrix voting-matrix">
+                <tr>
+                    <th>"Strategy"</th>
+                    <th>"Recoveries"</th>
+                    <th>"Median recovery"</th>
+                    <th>"Idle memory"</th>
+                    <th>"Background work"</th>
+                </tr>
+                {Supervisor::ALL.into_iter().map(|s| view! {
+                    <tr class:voting-selected=move || supervisor.get() == s title=s.note()>
+                        <td class="world-name">{s.label()}</td>
+                        <td>{move || recoveries.with(|l| l.samples(s).len())}</td>
+                        <td title=move || recoveries.with(|l| l.summary(s).detail())>
+                            {move || recoveries.with(|l| if l.samples(s).is_empty() { "—".to_string() } else { l.summary(s).display(3) })}
+                        </td>
+                        <td>{move || idle_memory(s)}</td>
+                        <td>{move || extra_work(s)}</td>
+                    </tr>
+                }).collect_view()}
+            </table>
+            <p class="metrics-note">{move || recoveries.with(|l| {
+                let speedup = match l.speedup(resolution_ms) {
+                    Some((ratio, true)) => format!("The swap recovered at least {:.0}× faster than a rebuild (below the {:.3}ms timer resolution).", ratio, resolution_ms),
+                    Some((ratio, false)) => format!("The swap recovered {:.0}× faster than a rebuild.", ratio),
+                    None => "Trigger a trap under each strategy to compare them.".to_string(),
+                };
+                let fallbacks = match l.fallbacks {
+                    0 => String::new(),
+                    n => format!(" {} fault{} found the spare still re-warming and rebuilt.", n, if n == 1 { "" } else { "s" }),
+                };
+                format!("{}{} Both are timed on the main thread with performance.now, from fault to a usable instance.", speedup, fallbacks)
+            })}</p>
+
+            <h4>"🐍 Python worker pool"</h4>
+            <p class="section-desc">"The fair opponent: Python with pre-forked standbys that already hold a loaded interpreter, so a crash costs detection and a state re-sync instead of a cold start."</p>
+            <div class="voting-controls">
+                <select
+                    class="network-select"
+                    disabled=move || control.is_running.get()
+                    on:change=move |ev| {
+                        if let Some(strategy) = PythonStrategy::from_id(&event_target_value(&ev)) {
+                            pool.strategy.set(strategy);
+                        }
+                    }
+                >
+                    {PythonStrategy::ALL.into_iter().map(|s| view! {
+                        <option value=s.id() selected=move || pool.strategy.get() == s>{s.label()}</option>
+                    }).collect_view()}
+                </select>
+                <span class="drift-status">{resync_note}</span>
+            </div>
+            <table class="capability-matrix voting-matrix">
+                <tr>
+                    <th>"Strategy"</th>
+                    <th>"Output lost per crash"</th>
+                    <th>"Pool restored after"</th>
+                </tr>
+                {move || PythonStrategy::ALL.into_iter().map(python_row).collect_view()}
+                <tr>
+                    <td class="world-name">"🦀 WASM 2oo3 (either supervisor)"</td>
+                    <td class="vote-flagged">"0ms · the vote masks the trap"</td>
+                    <td>{move || recoveries.with(|l| {
+                        let used = supervisor.get();
+                        if l.samples(used).is_empty() { "sub-ms rebuild".to_string() } else { l.summary(used).display(3) }
+                    })}</td>
+                </tr>
+            </table>
+            <p class="metrics-note">"Even pre-forked, every Python crash stops output until a standby notices and re-syncs; a trapped WASM instance is outvoted by the other two and never interrupts it. Both Python figures include the session's real Pyodide cold start."</p>
+        </div>
+    }
+}
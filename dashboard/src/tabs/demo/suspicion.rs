@@ -0,0 +1,93 @@
+// what: per-node suspicion scores that rise on disagreements/timeouts and decay over time
+// why: real faults are mostly intermittent; a binary healthy/faulty flag either ignores a flapping node or evicts on one glitch
+// relations: penalized by handlers.rs (traps, leader timeouts, intermittent scenario), decayed by component.rs, drawn by suspicion_panel.rs
+
+use std::collections::VecDeque;
+
+/// seconds for a score to halve with no new offenses
+pub const SUSPICION_HALF_LIFE_S: f64 = 10.0;
+/// default score at which a node is evicted and hot-swapped
+pub const DEFAULT_EVICTION: f64 = 3.0;
+/// allowed range for the eviction threshold
+pub const MIN_EVICTION: f64 = 0.5;
+pub const MAX_EVICTION: f64 = 10.0;
+/// samples kept per node for the sparkline (one per decay tick)
+pub const HISTORY_LEN: usize = 60;
+/// readings in the intermittent-fault scenario, one per second
+pub const INTERMITTENT_READINGS: u32 = 30;
+/// chance the flaky node disagrees on any one reading
+pub const INTERMITTENT_RATE: f64 = 0.3;
+
+/// what a node did wrong
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Offense {
+    /// output outvoted (or trapped) on one reading
+    Disagreement,
+    /// missed heartbeat / unresponsive
+    Timeout,
+}
+
+impl Offense {
+    pub fn weight(&self) -> f64 {
+        match self {
+            Offense::Disagreement => 1.0,
+            Offense::Timeout => 1.5,
+        }
+    }
+}
+
+/// scores and recent history for the three instances
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suspicion {
+    pub scores: [f64; 3],
+    pub history: [VecDeque<f64>; 3],
+    pub threshold: f64,
+}
+
+impl Default for Suspicion {
+    fn default() -> Self {
+        Self { scores: [0.0; 3], history: Default::default(), threshold: DEFAULT_EVICTION }
+    }
+}
+
+impl Suspicion {
+    /// adds the offense's weight; true when the node is now at or over the eviction threshold
+    pub fn penalize(&mut self, node: usize, offense: Offense) -> bool {
+        self.scores[node] += offense.weight();
+        self.scores[node] >= self.threshold
+    }
+
+    /// exponential decay over `dt_s` seconds, then one history sample per node
+    pub fn tick(&mut self, dt_s: f64) {
+        let factor = 0.5f64.powf(dt_s / SUSPICION_HALF_LIFE_S);
+        for (score, history) in self.scores.iter_mut().zip(self.history.iter_mut()) {
+            *score *= factor;
+            history.push_back(*score);
+            if history.len() > HISTORY_LEN {
+                history.pop_front();
+            }
+        }
+    }
+
+    /// evicted node comes back fresh
+    pub fn clear(&mut self, node: usize) {
+        self.scores[node] = 0.0;
+    }
+
+    pub fn set_threshold(&mut self, threshold: f64) {
+        self.threshold = threshold.clamp(MIN_EVICTION, MAX_EVICTION);
+    }
+}
+
+/// svg polyline points for a history, scaled so `max` sits at the top of a `width` x `height` box
+pub fn sparkline_points(history: &VecDeque<f64>, max: f64, width: f64, height: f64) -> String {
+    let step = width / (HISTORY_LEN - 1) as f64;
+    // right-aligned: the newest sample is always at the right edge
+    let offset = HISTORY_LEN.saturating_sub(history.len());
+    history
+        .iter()
+        .enumerate()
+        .map(|(i, v)| format!("{:.1},{:.1}", (offset + i) as f64 * step, height - (v / max).min(1.0) * height))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
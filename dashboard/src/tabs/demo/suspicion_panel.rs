@@ -0,0 +1,75 @@
+// what: per-node suspicion scores with sparklines, the eviction threshold and the intermittent-fault control
+// why: shows a flapping node building up suspicion and being evicted, while one-off glitches decay away
+// relations: reads/edits the store.rs suspicion signal from suspicion.rs, fires handlers.rs run_intermittent_fault; rendered by component.rs
+
+use leptos::*;
+use super::store::DemoStore;
+use super::suspicion::{sparkline_points, MAX_EVICTION, MIN_EVICTION, SUSPICION_HALF_LIFE_S};
+
+/// sparkline box (svg user units)
+const SPARK_W: f64 = 120.0;
+const SPARK_H: f64 = 24.0;
+
+#[component]
+pub fn SuspicionPanel(store: DemoStore) -> impl IntoView {
+    let DemoStore { cluster, control, .. } = store;
+    let suspicion = cluster.suspicion;
+    let (target, set_target) = create_signal(1usize);
+    // the sparkline tops out at 1.5x the threshold so the threshold line sits at two thirds
+    let scale = move || suspicion.with(|s| s.threshold * 1.5);
+
+    view! {
+        <div class="demo-section suspicion-section">
+            <h3>"🔎 Node Suspicion"<span class="attack-badge">"Intermittent Faults"</span></h3>
+            <p class="section-desc">
+                {format!("Each disagreement (+1.0) or timeout (+1.5) raises a node's score; scores halve every {}s. Reaching the threshold evicts and hot-swaps the node.", SUSPICION_HALF_LIFE_S)}
+            </p>
+            <div class="suspicion-rows">
+                {(0..3usize).map(|node| view! {
+                    <div class="suspicion-row">
+                        <span class="world-name">{format!("I{}", node)}</span>
+                        <svg class="suspicion-spark" viewBox=format!("0 0 {} {}", SPARK_W, SPARK_H) preserveAspectRatio="none">
+                            <line class="suspicion-threshold" x1="0" x2=SPARK_W.to_string()
+                                y1=move || format!("{:.1}", SPARK_H - suspicion.with(|s| s.threshold) / scale() * SPARK_H)
+                                y2=move || format!("{:.1}", SPARK_H - suspicion.with(|s| s.threshold) / scale() * SPARK_H) />
+                            <polyline points=move || suspicion.with(|s| sparkline_points(&s.history[node], scale(), SPARK_W, SPARK_H)) />
+                        </svg>
+                        <span
+                            class="suspicion-score"
+                            class:hot=move || suspicion.with(|s| s.scores[node] >= s.threshold * 0.66)
+                        >
+                            {move || suspicion.with(|s| format!("{:.2}", s.scores[node]))}
+                        </span>
+                    </div>
+                }).collect_view()}
+            </div>
+            <div class="voting-controls">
+                <label class="voting-tolerance">
+                    "Evict at"
+                    <input
+                        type="number"
+                        min=MIN_EVICTION.to_string()
+                        max=MAX_EVICTION.to_string()
+                        step="0.5"
+                        prop:value=move || suspicion.with(|s| s.threshold.to_string())
+                        on:change=move |ev| {
+                            if let Ok(v) = event_target_value(&ev).parse::<f64>() {
+                                suspicion.update(|s| s.set_threshold(v));
+                            }
+                        }
+                    />
+                </label>
+                <select class="network-select" on:change=move |ev| set_target.set(event_target_value(&ev).parse().unwrap_or(1))>
+                    {(0..3usize).map(|node| view! { <option value=node.to_string() selected=node == 1>{format!("I{}", node)}</option> }).collect_view()}
+                </select>
+                <button
+                    class="action-btn"
+                    disabled=move || control.is_running.get() || !store.has_quorum()
+                    on:click=move |_| store.run_intermittent_fault(target.get())
+                >
+                    "⚡ Intermittent Fault"
+                </button>
+            </div>
+        </div>
+    }
+}
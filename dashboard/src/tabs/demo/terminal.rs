@@ -0,0 +1,217 @@
+// what: chunked, capped terminal log state and its keyed renderer; consecutive identical lines collapse into one "×N" row
+// why: re-rendering every line on each push made long run-all / chaos sessions janky, and a flood of the same line pushed
+//      the history that explains it out of retention
+// relations: used by component.rs for the python and wasm terminals, stores types.rs LogEntry; lines are stamped with store.rs wall_ms so a timeline.rs marker
+//            hovered in timeline_panel.rs can highlight the ones written around its event
+
+use std::collections::VecDeque;
+use leptos::*;
+use super::store::wall_ms;
+use super::types::LogEntry;
+
+/// lines per chunk (only the tail chunk re-renders on push)
+pub const CHUNK_LINES: usize = 32;
+/// chunks retained, older ones are dropped whole
+pub const MAX_CHUNKS: usize = 8;
+/// writes one collapsed row holds before the next repeat opens a new row
+pub const MAX_RUN: usize = 99;
+
+/// a block of consecutive rows with its own signals; a row is one line written one or more times in a row
+#[derive(Clone, Copy)]
+pub struct LogChunk {
+    pub id: u64,
+    pub lines: RwSignal<Vec<LogEntry>>,
+    /// wall clock of every write of each row (unix epoch ms), parallel to `lines`; the length is the row's repeat count
+    pub stamps: RwSignal<Vec<Vec<f64>>>,
+}
+
+/// append-only terminal history with bounded retention
+#[derive(Clone, Copy)]
+pub struct TerminalLog {
+    chunks: RwSignal<VecDeque<LogChunk>>,
+    next_chunk: StoredValue<u64>,
+    /// bumped on every change, used by auto-scroll
+    revision: RwSignal<u64>,
+    /// lines dropped by retention since the last replace
+    trimmed: RwSignal<usize>,
+    /// lines stamped inside this window are highlighted
+    highlight: RwSignal<Option<(f64, f64)>>,
+}
+
+impl TerminalLog {
+    pub fn new() -> Self {
+        Self {
+            chunks: create_rw_signal(VecDeque::new()),
+            next_chunk: store_value(0),
+            revision: create_rw_signal(0),
+            trimmed: create_rw_signal(0),
+            highlight: create_rw_signal(None),
+        }
+    }
+
+    /// appends one line stamped with the wall clock
+    pub fn push(&self, entry: LogEntry) {
+        self.push_at(entry, wall_ms());
+    }
+
+    /// appends one line: a repeat of the last row counts onto it, anything else opens a row (and a chunk, retiring the oldest, when needed)
+    pub fn push_at(&self, entry: LogEntry, at_ms: f64) {
+        let last = self.chunks.with_untracked(|c| c.back().copied());
+        let repeat = last.filter(|c| {
+            c.lines.with_untracked(|l| l.last() == Some(&entry)) && c.stamps.with_untracked(|s| s.last().is_some_and(|run| run.len() < MAX_RUN))
+        });
+        let tail = last.filter(|c| c.lines.with_untracked(|l| l.len() < CHUNK_LINES));
+        match (repeat, tail) {
+            (Some(chunk), _) => chunk.stamps.update(|s| s.last_mut().into_iter().for_each(|run| run.push(at_ms))),
+            (None, Some(chunk)) => {
+                chunk.stamps.update(|s| s.push(vec![at_ms]));
+                chunk.lines.update(|l| l.push(entry));
+            }
+            (None, None) => {
+                let id = self.next_chunk.get_value();
+                self.next_chunk.set_value(id + 1);
+                let chunk = LogChunk { id, lines: create_rw_signal(vec![entry]), stamps: create_rw_signal(vec![vec![at_ms]]) };
+                self.chunks.update(|c| {
+                    c.push_back(chunk);
+                    if c.len() > MAX_CHUNKS {
+                        if let Some(old) = c.pop_front() {
+                            let dropped = old.stamps.with_untracked(|s| s.iter().map(Vec::len).sum::<usize>());
+                            self.trimmed.update(|t| *t += dropped);
+                            old.lines.dispose();
+                            old.stamps.dispose();
+                        }
+                    }
+                });
+            }
+        }
+        self.revision.update(|r| *r += 1);
+    }
+
+    /// appends the lines written by `f` (same shape as the old Vec updates)
+    pub fn append(&self, f: impl FnOnce(&mut Vec<LogEntry>)) {
+        let mut lines = Vec::new();
+        f(&mut lines);
+        for line in lines {
+            self.push(line);
+        }
+    }
+
+    /// clears and writes the given lines
+    pub fn replace(&self, lines: Vec<LogEntry>) {
+        self.chunks.update(|c| {
+            for chunk in c.drain(..) {
+                chunk.lines.dispose();
+                chunk.stamps.dispose();
+            }
+        });
+        self.trimmed.set(0);
+        self.append(|l| *l = lines);
+        self.revision.update(|r| *r += 1);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.with_untracked(|c| c.is_empty())
+    }
+
+    /// retained line count, repeats included
+    pub fn len(&self) -> usize {
+        self.chunks.with_untracked(|c| c.iter().map(|chunk| chunk.stamps.with_untracked(|s| s.iter().map(Vec::len).sum::<usize>())).sum())
+    }
+
+    /// retained rows with their repeat counts, oldest first
+    pub fn rows(&self) -> Vec<(LogEntry, usize)> {
+        self.chunks.with_untracked(|c| {
+            c.iter()
+                .flat_map(|chunk| chunk.lines.get_untracked().into_iter().zip(chunk.stamps.with_untracked(|s| s.iter().map(Vec::len).collect::<Vec<_>>())))
+                .collect()
+        })
+    }
+
+    /// retained lines with repeats written out, oldest first (store snapshots)
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.rows().into_iter().flat_map(|(entry, count)| std::iter::repeat_n(entry, count)).collect()
+    }
+
+    /// retained messages, oldest first (headless api)
+    pub fn messages(&self) -> Vec<String> {
+        self.entries().into_iter().map(|e| e.message).collect()
+    }
+
+    /// lines dropped by retention
+    pub fn trimmed(&self) -> usize {
+        self.trimmed.get()
+    }
+
+    /// highlights the lines stamped inside `window` (None clears)
+    pub fn highlight(&self, window: Option<(f64, f64)>) {
+        self.highlight.set(window);
+    }
+
+    /// retained lines stamped inside `window`
+    pub fn count_within(&self, (from, to): (f64, f64)) -> usize {
+        self.chunks.with_untracked(|c| c.iter().map(|chunk| chunk.stamps.with_untracked(|s| s.iter().flatten().filter(|t| (from..=to).contains(*t)).count())).sum())
+    }
+
+    /// subscribes the caller to any change
+    pub fn track(&self) -> u64 {
+        self.revision.get()
+    }
+}
+
+/// terminal body rendering chunks and lines with keyed <For>
+#[component]
+pub fn TerminalView(log: TerminalLog, id: &'static str) -> impl IntoView {
+    view! {
+        <div class="terminal" id=id>
+            <Show when=move || log.chunks.with(|c| c.is_empty())>
+                <p class="terminal-line info">"$ ready"</p>
+            </Show>
+            <Show when=move || { log.trimmed() > 0 }>
+                <p class="terminal-line trimmed">{move || format!("… {} earlier lines trimmed (keeping last {})", log.trimmed(), { log.track(); log.len() })}</p>
+            </Show>
+            <For each=move || log.chunks.get() key=|chunk| chunk.id let:chunk>
+                // rows inside a chunk are append-only, so the index is a stable key
+                <For each=move || chunk.lines.get().into_iter().enumerate() key=|(i, _)| *i let:line>
+                    <LogRow log=log chunk=chunk index=line.0 entry=line.1 />
+                </For>
+            </For>
+        </div>
+    }
+}
+
+/// one row: a single line, a collapsed run with its "×N" badge, or the run written out after a click (click again to fold)
+#[component]
+fn LogRow(log: TerminalLog, chunk: LogChunk, index: usize, entry: LogEntry) -> impl IntoView {
+    let (expanded, set_expanded) = create_signal(false);
+    let count = move || chunk.stamps.with(|s| s.get(index).map_or(0, Vec::len));
+    let lit = move |write: Option<usize>| {
+        log.highlight.with(|w| {
+            w.is_some_and(|(from, to)| {
+                chunk.stamps.with(|s| s.get(index).is_some_and(|run| match write {
+                    Some(k) => run.get(k).is_some_and(|t| (from..=to).contains(t)),
+                    None => run.iter().any(|t| (from..=to).contains(t)),
+                }))
+            })
+        })
+    };
+    let class = format!("terminal-line {}", entry.level);
+    move || {
+        let n = count();
+        if n > 1 && !expanded.get() {
+            view! {
+                <p class=format!("{} collapsed", class) class:highlighted=move || lit(None) title="Repeated line - click to expand" on:click=move |_| set_expanded.set(true)>
+                    {entry.message.clone()}
+                    <span class="repeat-badge">{format!("×{}", n)}</span>
+                </p>
+            }
+            .into_view()
+        } else {
+            (0..n).map(|k| view! {
+                <p class=class.clone() class:highlighted=move || lit(Some(k)) class:expanded={n > 1} on:click=move |_| set_expanded.set(false)>
+                    {entry.message.clone()}
+                    {(k == 0 && n > 1).then(|| view! { <span class="repeat-badge">{format!("▾ ×{}", n)}</span> })}
+                </p>
+            }).collect_view()
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// what: tests for the actuator driver's thermostat and relay, and the gpio grant that decides who may switch it
+// why: a pin write is the one call in the demo that moves something physical; the grant must be the only thing that lets it through
+
+use actuator_driver::{decide, Gpio, Relay, FAN_OFF_C, FAN_ON_C, RELAY_PIN};
+use crate::tabs::demo::actuator::{attempt, control_step, push_attempt, ACTUATOR_WORLD, MAX_ATTEMPTS};
+use crate::tabs::demo::policy::{CapabilityPolicy, CapabilityRequest, WORKER_WORLD};
+
+struct Refusing;
+
+impl Gpio for Refusing {
+    fn write_pin(&mut self, pin: u8, _high: bool) -> Result<(), String> {
+        Err(format!("pin {} refused", pin))
+    }
+}
+
+#[test]
+fn thermostat_switches_with_hysteresis() {
+    // what: off stays off until FAN_ON_C, on stays on until it drops to FAN_OFF_C; between the two the fan keeps its state
+    // why: a single threshold would chatter the relay on every noisy reading around it
+    assert!(!decide(FAN_ON_C - 0.1, false));
+    assert!(decide(FAN_ON_C, false));
+    let between = (FAN_ON_C + FAN_OFF_C) / 2.0;
+    assert!(decide(between, true));
+    assert!(!decide(between, false));
+    assert!(!decide(FAN_OFF_C, true));
+}
+
+#[test]
+fn relay_only_moves_when_the_pin_write_is_accepted() {
+    // what: a refused write leaves the relay where it was and returns the host's error
+    // why: the driver must never report a fan running that the host never switched on
+    let mut relay = Relay::default();
+    assert_eq!(relay.set(&mut Refusing, true), Err(format!("pin {} refused", RELAY_PIN)));
+    assert!(!relay.on);
+}
+
+#[test]
+fn shipped_policy_grants_the_relay_pin_to_the_actuator_world_only() {
+    // what: with wit/policy.toml as shipped, the sensor world's set-relay is denied and the actuator world's goes through; granting the pin flips the outcome
+    // why: the rogue actuation demo is exactly this difference, and it has to come from config, not from which button was pressed
+    let policy = CapabilityPolicy::load_default();
+    let rogue = attempt(&policy, WORKER_WORLD, Relay::default(), true);
+    assert_eq!(rogue.result, Err(format!("capability not granted: gpio {}", RELAY_PIN)));
+    assert!(!rogue.relay.on);
+    assert!(rogue.summary().contains("❌"));
+    let allowed = attempt(&policy, ACTUATOR_WORLD, Relay::default(), true);
+    assert!(allowed.result.is_ok() && allowed.relay.on);
+    assert_eq!(CapabilityRequest::Gpio(RELAY_PIN).label(), "gpio 17");
+
+    let mut granted = policy.clone();
+    granted.worlds.iter_mut().find(|w| w.name == WORKER_WORLD).unwrap().gpio.push(RELAY_PIN);
+    assert!(attempt(&granted, WORKER_WORLD, Relay::default(), true).result.is_ok());
+}
+
+#[test]
+fn control_step_writes_only_when_the_decision_changes() {
+    // what: a hot reading switches the fan on through the actuator world, a repeat is a no-op, a cool one switches it off; the table keeps the newest attempts
+    // why: rewriting an unchanged pin every poll would flood the journal and wear a real relay
+    let policy = CapabilityPolicy::load_default();
+    let on = control_step(&policy, FAN_ON_C + 1.0, Relay::default()).unwrap();
+    assert_eq!((on.world.as_str(), on.state, on.relay.on), (ACTUATOR_WORLD, true, true));
+    assert_eq!(control_step(&policy, FAN_ON_C + 1.0, on.relay), None);
+    let off = control_step(&policy, FAN_OFF_C - 1.0, on.relay).unwrap();
+    assert!(!off.relay.on);
+
+    let mut attempts = Vec::new();
+    for _ in 0..MAX_ATTEMPTS + 2 {
+        push_attempt(&mut attempts, on.clone());
+    }
+    push_attempt(&mut attempts, off.clone());
+    assert_eq!(attempts.len(), MAX_ATTEMPTS);
+    assert_eq!(attempts[0], off);
+}
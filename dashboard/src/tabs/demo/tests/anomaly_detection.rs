@@ -0,0 +1,56 @@
+// what: tests for the ewma anomaly detector and the simulated telemetry stream
+// why: the panel's who-caught-what table and the live run both come from simulate/catches
+
+use crate::tabs::demo::anomaly::{catches, simulate, EwmaDetector, StreamFault, FAULT_AT, FAULT_LEN, GLITCHING, REBASELINE_AFTER, STREAM_SEED, WARMUP};
+use crate::tabs::demo::voting::VoteStrategy;
+
+#[test]
+fn clean_stream_raises_no_alarms() {
+    // what: neither the voter nor the detector flags anything on the fault-free stream
+    // why: a detector that cries wolf on sensor noise would be switched off in a real plant
+    let ticks = simulate(StreamFault::Clean, VoteStrategy::default(), STREAM_SEED, 1.0);
+    assert_eq!(catches(&ticks).false_alarms, 0);
+    assert!(ticks.iter().all(|t| !t.anomalous && t.outvoted.is_none()));
+}
+
+#[test]
+fn single_node_spike_is_outvoted_and_never_reaches_the_detector() {
+    // what: the glitching instance is outvoted on every fault tick and the voted value stays in band
+    // why: tmr already masks a lone bad node, so the detector must stay quiet
+    let ticks = simulate(StreamFault::SingleNode, VoteStrategy::default(), STREAM_SEED, 1.0);
+    let c = catches(&ticks);
+    assert_eq!((c.tmr, c.detector, c.false_alarms), (FAULT_LEN, 0, 0));
+    assert!(ticks[FAULT_AT..FAULT_AT + FAULT_LEN].iter().all(|t| t.outvoted == Some(GLITCHING)));
+}
+
+#[test]
+fn common_mode_spike_passes_the_voter_but_not_the_detector() {
+    // what: all three agree on the spike, so the vote is unanimous, yet every fault tick is flagged
+    // why: the gap in tmr this feature exists to close
+    let ticks = simulate(StreamFault::CommonMode, VoteStrategy::default(), STREAM_SEED, 1.0);
+    let c = catches(&ticks);
+    assert_eq!((c.tmr, c.detector, c.false_alarms), (0, FAULT_LEN, 0));
+}
+
+#[test]
+fn anomalies_stay_out_of_the_model() {
+    // what: after a short spike the band is where it was before, so the stream settles straight back
+    // why: absorbing the spike would widen the band and hide the next one
+    let mut detector = EwmaDetector::default();
+    (0..WARMUP * 3).for_each(|i| assert!(!detector.observe(22.0 + 0.01 * (i % 2) as f64)));
+    let before = detector.band();
+    assert!(detector.observe(30.0));
+    assert_eq!(detector.band(), before);
+    assert!(!detector.observe(22.0));
+}
+
+#[test]
+fn persistent_shift_becomes_the_new_baseline() {
+    // what: after REBASELINE_AFTER anomalies in a row the detector relearns, and goes quiet once warmed up again
+    // why: a real setpoint change must not alarm forever
+    let mut detector = EwmaDetector::default();
+    (0..WARMUP * 3).for_each(|_| { detector.observe(22.0); });
+    (0..REBASELINE_AFTER).for_each(|_| assert!(detector.observe(30.0)));
+    assert_eq!(detector.band(), None);
+    (0..WARMUP * 2).for_each(|_| assert!(!detector.observe(30.0)));
+}
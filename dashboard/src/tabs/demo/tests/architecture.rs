@@ -0,0 +1,65 @@
+// what: tests for the 1oo1 / 1oo2D / 2oo3 comparison over the shared fault sequence
+// why: the comparison table is only meaningful if each architecture trips and protects by its textbook rule
+
+use crate::tabs::demo::architecture::{simulate, Architecture, FaultEvent, FaultKind, FAULT_SEQUENCE, MISSION_S, RESTART_S};
+
+#[test]
+fn simplex_trips_and_goes_unprotected() {
+    // what: 1oo1 trips on its safe and detected faults and is blind during its dangerous fault
+    // why: a single channel has no one to outvote a bad output
+    let run = simulate(Architecture::OneOoOne, &FAULT_SEQUENCE);
+    assert_eq!(run.spurious_trips, 2);
+    assert_eq!(run.downtime_s, 240 + 300 + 2 * RESTART_S);
+    assert_eq!(run.unprotected_s, 600);
+}
+
+#[test]
+fn duplex_with_diagnostics_protects_but_still_trips() {
+    // what: 1oo2D stays protected throughout, rides through diagnosed faults, but trips on undetected safe faults
+    // why: the classic 1oo2 trade: safety up, availability no better
+    let run = simulate(Architecture::OneOoTwoD, &FAULT_SEQUENCE);
+    assert_eq!(run.unprotected_s, 0);
+    assert_eq!(run.spurious_trips, 2);
+    assert_eq!(run.downtime_s, 2 * (240 + RESTART_S));
+}
+
+#[test]
+fn tmr_masks_every_single_fault() {
+    // what: 2oo3 neither trips nor loses protection under the sequence
+    // why: every fault in the sequence is a single-channel fault, which is exactly what 2oo3 masks
+    let run = simulate(Architecture::TwoOoThree, &FAULT_SEQUENCE);
+    assert_eq!((run.spurious_trips, run.downtime_s, run.unprotected_s), (0, 0, 0));
+    assert_eq!(run.availability(), 1.0);
+}
+
+#[test]
+fn two_safe_faults_trip_tmr() {
+    // what: two overlapping safe faults give 2oo3 a false majority and it trips
+    // why: tmr is single-fault tolerant, not immune
+    let faults = [
+        FaultEvent { at_s: 100, channel: 0, kind: FaultKind::Safe, repair_s: 200 },
+        FaultEvent { at_s: 150, channel: 1, kind: FaultKind::Safe, repair_s: 200 },
+    ];
+    let run = simulate(Architecture::TwoOoThree, &faults);
+    assert_eq!(run.spurious_trips, 1);
+    // tripped from 150 until channel 0 is repaired at 300, then the restart
+    assert_eq!(run.downtime_s, 150 + RESTART_S);
+}
+
+#[test]
+fn timelines_cover_the_whole_mission() {
+    // what: every channel row and the process row span 0..MISSION_S without gaps
+    // why: the svg timeline draws these segments edge to edge
+    for architecture in Architecture::ALL {
+        let run = simulate(architecture, &FAULT_SEQUENCE);
+        assert_eq!(run.channels.len(), architecture.channels());
+        for row in run.channels.iter().map(|r| r.iter().map(|s| (s.from_s, s.to_s)).collect::<Vec<_>>())
+            .chain(std::iter::once(run.process.iter().map(|s| (s.from_s, s.to_s)).collect()))
+        {
+            assert_eq!(row.first().unwrap().0, 0);
+            assert_eq!(row.last().unwrap().1, MISSION_S);
+            assert!(row.windows(2).all(|w| w[0].1 == w[1].0));
+        }
+        assert_eq!(Architecture::from_id(architecture.id()), Some(architecture));
+    }
+}
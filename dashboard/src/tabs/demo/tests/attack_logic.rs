@@ -1,7 +1,9 @@
 // what: tests for attack configuration accuracy and python code validity
 // why: ensures wit modal displays correct capabilities and pyodide can parse attack code
 
-use crate::tabs::demo::attacks::{get_attack_config, ATTACK_BUFFER_OVERFLOW, ATTACK_DATA_EXFIL, ATTACK_PATH_TRAVERSAL};
+use attack_code::{attack_code, wit_excerpt, ATTACK_BUFFER_OVERFLOW, ATTACK_DATA_EXFIL, ATTACK_PATH_TRAVERSAL, ATTACK_STACK_EXHAUSTION, ATTACK_LENGTH_OVERFLOW, ATTACK_ENV_THEFT, ATTACK_LATERAL_MOVEMENT};
+use crate::tabs::demo::attacks::{get_attack_config, AVAILABILITY_ATTACKS, SECURITY_ATTACKS};
+use crate::tabs::demo::payloads::Payloads;
 
 #[test]
 fn config_buffer_overflow_has_correct_wit_func() {
@@ -31,7 +33,7 @@ fn config_path_traversal_has_filesystem_trap() {
 fn all_security_attacks_have_restart_time() {
     // what: all security attacks should have restart_ms > 500
     // why: prevents unrealistic instant respawn in demo
-    let attacks = ["bufferOverflow", "dataExfil", "pathTraversal"];
+    let attacks = ["bufferOverflow", "dataExfil", "pathTraversal", "stackExhaustion", "lengthOverflow", "envTheft", "lateralMovement"];
     for attack in attacks {
         let config = get_attack_config(attack);
         assert!(config.restart_ms > 500, "{} should have restart_ms > 500", attack);
@@ -58,7 +60,7 @@ fn empty_attack_string_handled() {
 fn all_attack_names_are_unique() {
     // what: no two attacks should have the same display name
     // why: prevents ui confusion in attack selector
-    let attacks = ["bufferOverflow", "dataExfil", "pathTraversal", "killLeader", "heartbeatTimeout"];
+    let attacks = ["bufferOverflow", "dataExfil", "pathTraversal", "stackExhaustion", "lengthOverflow", "envTheft", "lateralMovement", "killLeader", "heartbeatTimeout"];
     let names: Vec<&str> = attacks.iter().map(|a| get_attack_config(a).name).collect();
     let unique_count = names.iter().collect::<std::collections::HashSet<_>>().len();
     assert_eq!(unique_count, names.len(), "attack names must be unique");
@@ -68,9 +70,23 @@ fn all_attack_names_are_unique() {
 fn python_code_has_result_variable() {
     // what: each attack script should end with 'result' as the return value
     // why: pyodide returns last expression, we expect 'result' to be it
-    let codes = [ATTACK_BUFFER_OVERFLOW, ATTACK_DATA_EXFIL, ATTACK_PATH_TRAVERSAL];
+    let codes = [ATTACK_BUFFER_OVERFLOW, ATTACK_DATA_EXFIL, ATTACK_PATH_TRAVERSAL, ATTACK_STACK_EXHAUSTION, ATTACK_LENGTH_OVERFLOW, ATTACK_ENV_THEFT, ATTACK_LATERAL_MOVEMENT];
     for code in codes {
         let trimmed = code.trim();
         assert!(trimmed.ends_with("result"), "python code should end with 'result'");
     }
 }
+
+#[test]
+fn loaded_payloads_cover_every_security_attack() {
+    // what: payloads read the way payloads.rs reads the attack-code module give each security attack its script and no availability attack one
+    // why: the demo only runs attacks the module gave it code for; a missing id would leave that button dead
+    let payloads = Payloads::new(SECURITY_ATTACKS.iter().map(|a| (a.to_string(), attack_code(a))), wit_excerpt());
+    for attack in SECURITY_ATTACKS {
+        assert!(payloads.code(attack).is_some_and(|code| code.trim().ends_with("result")), "{} has no payload", attack);
+    }
+    for attack in AVAILABILITY_ATTACKS {
+        assert_eq!(payloads.code(attack), None, "{} runs no python", attack);
+    }
+    assert!(payloads.wit_excerpt.contains("attack-surface"));
+}
@@ -0,0 +1,60 @@
+// what: tests for the wasm-bindgen vs component sensor backend switch
+// why: the comparison is only honest if it reports exactly what differs between the two runs
+
+use leptos::*;
+use guardian_types::SensorReading;
+use crate::settings::{SensorBackend, Settings};
+use crate::tabs::demo::component_backend::{differences, BackendRun};
+use crate::tabs::demo::store::DemoStore;
+
+const DRIVER: SensorReading = SensorReading { temperature: 23.5, humidity: 45.2, pressure: 1013.25 };
+
+fn run(backend: SensorBackend, exec_ms: f64, reading: SensorReading) -> BackendRun {
+    BackendRun { backend, exec_ms, load_ms: None, reading }
+}
+
+#[test]
+fn bindgen_is_default_backend() {
+    // what: settings default to wasm-bindgen, old blobs load with it, and "component" round-trips
+    // why: the component needs build-component.sh output, so it must be opt-in
+    assert_eq!(Settings::default().sensor_backend, SensorBackend::Bindgen);
+    assert_eq!(Settings::from_json(r#"{"theme":"dark"}"#).unwrap().sensor_backend, SensorBackend::Bindgen);
+    let settings = Settings::from_json(r#"{"sensor_backend":"component"}"#).unwrap();
+    assert_eq!(settings.sensor_backend, SensorBackend::Component);
+    assert_eq!(DemoStore::new(create_rw_signal(settings)).sensor_backend(), SensorBackend::Component);
+}
+
+#[test]
+fn no_differences_until_both_ran() {
+    // what: runs from one backend only produce no comparison
+    // why: the panel must not compare against a path that never executed
+    assert!(differences(&[]).is_empty());
+    assert!(differences(&[run(SensorBackend::Bindgen, 0.01, DRIVER), run(SensorBackend::Bindgen, 0.02, DRIVER)]).is_empty());
+    assert!(differences(&[run(SensorBackend::Component, 0.05, DRIVER)]).is_empty());
+}
+
+#[test]
+fn reading_mismatch_per_field() {
+    // what: only fields that differ between the latest runs are listed, identical readings list none
+    // why: the bindgen path simulates readings, the component returns the driver's own
+    let simulated = SensorReading { temperature: 27.1, ..DRIVER };
+    let lines = differences(&[run(SensorBackend::Bindgen, 0.01, simulated), run(SensorBackend::Component, 0.02, DRIVER)]);
+    assert_eq!(lines[0], "temperature: 27.10°C (wasm-bindgen) vs 23.50°C (component)");
+    assert!(lines[1].starts_with("call time:"));
+    let same = differences(&[run(SensorBackend::Bindgen, 0.01, DRIVER), run(SensorBackend::Component, 0.02, DRIVER)]);
+    assert!(same.iter().all(|l| !l.contains("(wasm-bindgen) vs")));
+}
+
+#[test]
+fn call_time_ratio_and_load() {
+    // what: medians per backend give the ratio; the one-off load is reported separately; a zero bindgen time gives no ratio
+    // why: load cost must not be folded into the per-call comparison
+    let mut first = run(SensorBackend::Component, 0.04, DRIVER);
+    first.load_ms = Some(12.5);
+    let runs = [run(SensorBackend::Bindgen, 0.01, DRIVER), run(SensorBackend::Bindgen, 0.03, DRIVER), first, run(SensorBackend::Component, 0.06, DRIVER)];
+    let lines = differences(&runs);
+    assert_eq!(lines[0], "call time: 0.020ms vs 0.050ms median (2.5× the wasm-bindgen call)");
+    assert_eq!(lines[1], "component load (fetch + compile + instantiate, once): 12.5ms");
+    let zero = differences(&[run(SensorBackend::Bindgen, 0.0, DRIVER), run(SensorBackend::Component, 0.05, DRIVER)]);
+    assert!(zero[0].ends_with("(wasm-bindgen call below timer resolution)"));
+}
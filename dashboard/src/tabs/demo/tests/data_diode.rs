@@ -0,0 +1,53 @@
+// what: tests for southbound data diode enforcement
+// why: the compliance tab claims no unauthorized write reaches the plc, these checks back that claim
+
+use modbus_parser::build_frame;
+use modbus_parser::diode::{enforce_southbound, DiodeRejection, FC_WRITE_SINGLE_COIL, FC_WRITE_SINGLE_REGISTER};
+
+#[test]
+fn whitelisted_setpoint_passes() {
+    // what: fan setpoint 60% on register 10 is allowed
+    // why: legitimate operator commands must still work through the diode
+    let frame = build_frame(1, FC_WRITE_SINGLE_REGISTER, &[0x00, 0x0A, 0x00, 60]);
+    assert_eq!(enforce_southbound(&frame).unwrap().name, "fan-speed-setpoint");
+}
+
+#[test]
+fn coil_write_rejected_by_function() {
+    // what: write single coil is not whitelisted at all
+    // why: direct actuator forcing from zone 3 is the classic ics attack
+    let frame = build_frame(1, FC_WRITE_SINGLE_COIL, &[0x00, 0x01, 0x00, 0x00]);
+    assert_eq!(enforce_southbound(&frame), Err(DiodeRejection::FunctionNotAllowed(FC_WRITE_SINGLE_COIL)));
+}
+
+#[test]
+fn telemetry_register_is_read_only() {
+    // what: writing the temperature register is rejected by address
+    // why: telemetry flows up only, spoofing it from above must be impossible
+    let frame = build_frame(1, FC_WRITE_SINGLE_REGISTER, &[0x00, 0x04, 0x7F, 0xFF]);
+    assert_eq!(enforce_southbound(&frame), Err(DiodeRejection::AddressNotAllowed(4)));
+}
+
+#[test]
+fn setpoint_out_of_range_rejected() {
+    // what: 250% fan speed is outside 0..=100
+    // why: schema checks values, not just addresses
+    let frame = build_frame(1, FC_WRITE_SINGLE_REGISTER, &[0x00, 0x0A, 0x00, 250]);
+    assert_eq!(enforce_southbound(&frame), Err(DiodeRejection::ValueOutOfRange { address: 10, value: 250 }));
+}
+
+#[test]
+fn corrupted_crc_rejected() {
+    // what: flipping a payload byte after crc calculation is caught
+    // why: line noise or tampering must never be interpreted as a valid command
+    let mut frame = build_frame(1, FC_WRITE_SINGLE_REGISTER, &[0x00, 0x0A, 0x00, 60]);
+    frame[5] = 61;
+    assert_eq!(enforce_southbound(&frame), Err(DiodeRejection::BadCrc));
+}
+
+#[test]
+fn truncated_frame_rejected() {
+    // what: a 3-byte frame is malformed, not a panic
+    // why: parser must be total over arbitrary input
+    assert_eq!(enforce_southbound(&[0x01, 0x06, 0x00]), Err(DiodeRejection::Malformed));
+}
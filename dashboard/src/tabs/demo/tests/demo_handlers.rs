@@ -0,0 +1,44 @@
+// what: tests for the pure pieces behind the demo handlers and node strip
+// why: handlers moved onto the store; their timing rules and node roles must not drift
+
+use crate::tabs::demo::attacks::{AVAILABILITY_ATTACKS, SECURITY_ATTACKS};
+use crate::tabs::demo::handlers::{restart_delay_ms, run_all_sequence, LEADER_RESTART_MS, MIN_RESTART_MS};
+use crate::tabs::demo::node_strip::{python_nodes, wasm_nodes};
+use crate::tabs::demo::types::InstanceState;
+
+#[test]
+fn restart_uses_pyodide_time_when_known() {
+    // what: measured cold start beats the fallback, jitter is applied
+    // why: python respawn must reflect the real pyodide load time
+    assert_eq!(restart_delay_ms(1200.0, LEADER_RESTART_MS, 100), 1300);
+    assert_eq!(restart_delay_ms(0.0, LEADER_RESTART_MS, -200), 1300);
+}
+
+#[test]
+fn restart_never_below_floor() {
+    // what: negative jitter on a tiny base clamps to MIN_RESTART_MS
+    // why: a near-zero respawn would undersell python's downtime
+    assert_eq!(restart_delay_ms(100.0, LEADER_RESTART_MS, -200), MIN_RESTART_MS);
+}
+
+#[test]
+fn run_all_is_security_then_availability() {
+    // what: run-all fires every attack once, security attacks first
+    // why: availability attacks rely on the pool being restored by then
+    let seq = run_all_sequence();
+    assert_eq!(seq.len(), SECURITY_ATTACKS.len() + AVAILABILITY_ATTACKS.len());
+    assert_eq!(&seq[..SECURITY_ATTACKS.len()], &SECURITY_ATTACKS);
+    assert_eq!(&seq[SECURITY_ATTACKS.len()..], &AVAILABILITY_ATTACKS);
+}
+
+#[test]
+fn node_roles_follow_leader_and_faults() {
+    // what: leader label follows the active/elected node, down nodes are marked
+    // why: both node strips are computed here rather than inline in the view
+    let py = python_nodes([true, false, true], 2);
+    assert_eq!(py.map(|n| n.label()), ["F", "F", "L"]);
+    assert!(!py[1].up);
+    let wasm = wasm_nodes([InstanceState::Healthy, InstanceState::Faulty, InstanceState::Healthy], Some(1), 0);
+    assert_eq!(wasm.map(|n| n.label()), ["L", "F", "F"]);
+    assert_eq!(wasm.map(|n| n.up), [true, false, true]);
+}
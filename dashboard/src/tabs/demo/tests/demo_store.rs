@@ -0,0 +1,67 @@
+// what: tests for the demo state store and its slices
+// why: handlers now mutate slices in place, so reset and snapshots must cover every slice
+
+use leptos::*;
+use crate::settings::Settings;
+use crate::tabs::demo::journal::{AuditEvent, EventKind};
+use crate::tabs::demo::store::DemoStore;
+use crate::tabs::demo::types::{InstanceState, LogEntry};
+
+fn store() -> DemoStore {
+    DemoStore::new(create_rw_signal(Settings::default()))
+}
+
+#[test]
+fn set_instance_touches_one_slot() {
+    // what: marking an instance faulty leaves the other two healthy
+    // why: replaces the get-clone-set of the whole array in trap handlers
+    let store = store();
+    store.set_instance(1, InstanceState::Faulty);
+    let states = store.cluster.instance_states.get();
+    assert_eq!(states[0], InstanceState::Healthy);
+    assert_eq!(states[1], InstanceState::Faulty);
+    assert_eq!(states[2], InstanceState::Healthy);
+}
+
+#[test]
+fn reset_clears_slices_but_keeps_journal() {
+    // what: reset restores counters, nodes, pool and terminals; journal entries and the raft log stay
+    // why: the journal is the audit trail and must survive a stats reset
+    let store = store();
+    store.stats.python_crashed.set(3);
+    store.stats.wasm_rejected.set(2);
+    store.set_instance(0, InstanceState::Faulty);
+    store.cluster.leader_id.set(2);
+    store.cluster.log.update(|l| { l.append(21.0); });
+    store.pool.python_workers.update(|w| w[0] = false);
+    store.control.is_running.set(true);
+    store.wasm_log.push(LogEntry { level: "info".into(), message: "x".into() });
+    store.journal.update(|j| j.push(AuditEvent::new(0.0, EventKind::Trap, None, "bufferOverflow", "trap")));
+
+    store.reset();
+
+    assert_eq!(store.stats.python_crashed.get(), 0);
+    assert_eq!(store.stats.wasm_rejected.get(), 0);
+    assert_eq!(store.cluster.instance_states.get(), [InstanceState::Healthy; 3]);
+    assert_eq!(store.cluster.leader_id.get(), 0);
+    assert_eq!(store.cluster.log.with(|l| l.nodes[0].last_index()), 1, "raft log is persistent state");
+    assert_eq!(store.pool.python_workers.get(), [true; 3]);
+    assert!(!store.control.is_running.get());
+    assert!(store.wasm_log.is_empty());
+    assert_eq!(store.journal.with(|j| j.len()), 1);
+}
+
+#[test]
+fn metrics_snapshot_reads_stats_slice() {
+    // what: the prometheus snapshot mirrors the stat counters
+    // why: the metrics panel no longer sees the raw signals
+    let store = store();
+    store.stats.python_processed.set(7);
+    store.stats.python_downtime_ms.set(1500);
+    store.stats.wasm_processed.set(9);
+    let snap = store.metrics_snapshot();
+    assert_eq!(snap.python_processed, 7);
+    assert_eq!(snap.python_downtime_ms, 1500);
+    assert_eq!(snap.wasm_processed, 9);
+    assert_eq!(snap.wasm_rejected, 0);
+}
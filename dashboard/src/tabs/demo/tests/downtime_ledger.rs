@@ -0,0 +1,89 @@
+// what: tests for the downtime ledger built from the journal
+// why: the stat cells' downtime totals now come from these incidents, so the grouping and the outage rule must hold
+
+use crate::tabs::demo::journal::{AuditEvent, EventKind, Runtime};
+use crate::tabs::demo::ledger::{incidents, to_csv, total_downtime_ms};
+use crate::tabs::demo::summary::{render, SessionNumbers, SummaryFormat};
+
+fn event(ms: f64, kind: EventKind, runtime: Option<Runtime>, attack: &str, detail: &str) -> AuditEvent {
+    AuditEvent::new(ms, kind, runtime, attack, detail)
+}
+
+fn buffer_overflow() -> Vec<AuditEvent> {
+    vec![
+        event(1_000.0, EventKind::AttackStarted, None, "bufferOverflow", "Buffer Overflow"),
+        event(1_040.0, EventKind::Crash, Some(Runtime::Python), "bufferOverflow", "W0 crashed"),
+        event(1_002.0, EventKind::Trap, Some(Runtime::Wasm), "bufferOverflow", "I1: out of bounds"),
+        event(1_003.0, EventKind::Recovery, Some(Runtime::Wasm), "bufferOverflow", "I1 rebuilt").with_duration(0.25),
+        event(2_840.0, EventKind::Recovery, Some(Runtime::Python), "bufferOverflow", "W0 respawned").with_duration(1_800.0),
+    ]
+}
+
+#[test]
+fn crashes_cost_output_and_traps_are_masked() {
+    // what: one incident per runtime; the python crash loses its respawn, the voted-out wasm trap loses nothing
+    // why: the old counter only knew python restarts; the ledger has to say the same for the same attack
+    let ledger = incidents(&buffer_overflow());
+    assert_eq!(ledger.len(), 2);
+    let python = ledger.iter().find(|i| i.runtime == Runtime::Python).unwrap();
+    assert_eq!((python.label(), python.cause.as_str(), python.fault), ("INC-001".to_string(), "Buffer Overflow", EventKind::Crash));
+    assert_eq!((python.detection_ms, python.recovery_ms, python.downtime_ms()), (Some(40.0), Some(1_800.0), 1_800.0));
+    let wasm = ledger.iter().find(|i| i.runtime == Runtime::Wasm).unwrap();
+    assert_eq!((wasm.fault, wasm.recovery_ms, wasm.downtime_ms()), (EventKind::Trap, Some(0.25), 0.0));
+    assert_eq!(wasm.events.len(), 2);
+    assert_eq!(total_downtime_ms(&ledger, Runtime::Python), 1_800.0);
+    assert_eq!(total_downtime_ms(&ledger, Runtime::Wasm), 0.0);
+}
+
+#[test]
+fn quorum_loss_is_wasm_downtime_until_restored() {
+    // what: crash and quorum loss join one incident, open until the untimed restore, then cost the gap
+    // why: losing the majority is the one wasm fault that stops output
+    let mut events = vec![
+        event(0.0, EventKind::AttackStarted, None, "quorumLoss", "Kill Two Nodes"),
+        event(0.0, EventKind::Crash, Some(Runtime::Wasm), "quorumLoss", "I0 and I1 killed"),
+        event(0.0, EventKind::QuorumLost, Some(Runtime::Wasm), "quorumLoss", "1/3 healthy"),
+    ];
+    let open = incidents(&events);
+    assert_eq!(open.len(), 1);
+    assert_eq!((open[0].fault, open[0].outage, open[0].recovery_text()), (EventKind::QuorumLost, true, "ongoing".to_string()));
+    assert_eq!(open[0].downtime_ms(), 0.0, "open outages count once they close");
+    events.push(event(4_500.0, EventKind::Recovery, Some(Runtime::Wasm), "quorumLoss", "quorum restored"));
+    events.push(event(4_501.0, EventKind::Election, Some(Runtime::Wasm), "quorumLoss", "I2 elected"));
+    let closed = incidents(&events);
+    assert_eq!(closed[0].recovery_ms, Some(4_500.0));
+    assert_eq!(closed[0].events.len(), 4);
+    assert_eq!(total_downtime_ms(&closed, Runtime::Wasm), 4_500.0);
+}
+
+#[test]
+fn each_run_and_refault_is_its_own_incident() {
+    // what: a second run of an attack opens a new incident; a fault with no attack start has no detection time
+    // why: incidents are numbered per fault, not per attack name
+    let mut events = buffer_overflow();
+    events.extend(buffer_overflow().into_iter().map(|mut e| { e.timestamp_ms += 10_000.0; e }));
+    events.push(event(20_000.0, EventKind::Outvoted, Some(Runtime::Wasm), "suspicion", "I2 evicted"));
+    let ledger = incidents(&events);
+    assert_eq!(ledger.len(), 5);
+    assert_eq!(ledger.iter().map(|i| i.id).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(ledger[2].detection_ms, Some(40.0));
+    let evicted = &ledger[4];
+    assert_eq!((evicted.cause.as_str(), evicted.detection_ms), ("suspicion", None));
+    assert_eq!(evicted.recovery_text(), "masked");
+}
+
+#[test]
+fn ledger_is_exported() {
+    // what: csv carries one row per incident with quotes escaped; both summary formats list the incidents
+    // why: the ledger travels with tickets and spreadsheets, not just the page
+    let mut events = buffer_overflow();
+    events[0].detail = "Buffer \"Overflow\"".to_string();
+    let csv = to_csv(&incidents(&events));
+    assert_eq!(csv.lines().count(), 3);
+    assert!(csv.contains("INC-001,\"Buffer \"\"Overflow\"\"\",python,crashed,40.000,1800.000,1800.000"));
+    let markdown = render(&SessionNumbers::default(), &buffer_overflow(), "B", &[], &[], 0.0, SummaryFormat::Markdown);
+    assert!(markdown.contains("### Incidents (2)"));
+    assert!(markdown.contains("| INC-001 | Buffer Overflow | python | crashed | 40.0ms | 1800ms | 1800ms |"));
+    let text = render(&SessionNumbers::default(), &buffer_overflow(), "B", &[], &[], 0.0, SummaryFormat::Text);
+    assert!(text.contains("  INC-002 Buffer Overflow - wasm trapped, detected after 2.0ms, recovery 0.2ms, output lost 0.0ms\n"));
+}
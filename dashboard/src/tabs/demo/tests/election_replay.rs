@@ -0,0 +1,82 @@
+// what: tests for the election replay drawn on the wasm node ring
+// why: the ring claims to show the raft module's own messages, so its frames must follow the traced run
+
+use crate::tabs::demo::election::{frames, ArrowKind, ElectionReplay, RingRole};
+use crate::tabs::demo::raft::{failover, failover_traced, ElectionEvent, CLUSTER_SIZE};
+
+#[test]
+fn trace_matches_the_trial() {
+    // what: the traced run returns the same trial as failover and ends with that leader's election
+    // why: the ring replays the trial whose latency the journal records
+    for seed in 1..100u64 {
+        let crashed = (seed % CLUSTER_SIZE as u64) as usize;
+        let (trial, trace) = failover_traced(seed, crashed);
+        assert_eq!(trial, failover(seed, crashed));
+        let trial = trial.unwrap();
+        assert_eq!(trace.first().map(|s| s.event), Some(ElectionEvent::Crash { node: crashed }));
+        assert_eq!(trace.last().map(|s| s.event), Some(ElectionEvent::Elected { node: trial.leader, term: trial.terms + 1 }));
+        assert!(trace.windows(2).all(|w| w[0].at_ms <= w[1].at_ms));
+        assert!((trace.last().unwrap().at_ms - trial.latency_ms).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn ring_starts_steady_and_ends_with_the_winner() {
+    // what: the first frame is the old leader in the cluster's term, the last has the winner leading the term after the trial's rounds
+    // why: the term badge must line up with the raft log panel's term
+    let (trial, trace) = failover_traced(7, 1);
+    let trial = trial.unwrap();
+    let frames = frames(4, 1, &trace);
+    let first = &frames[0];
+    assert_eq!(first.term, 4);
+    assert_eq!(first.nodes[1].role, RingRole::Leader);
+    assert!(first.arrows.is_empty());
+    assert_eq!(frames[1].nodes[1].role, RingRole::Down);
+    let last = frames.last().unwrap();
+    assert_eq!(last.nodes[trial.leader].role, RingRole::Leader);
+    assert_eq!(last.term, 4 + trial.terms);
+    assert_eq!(last.nodes[1].role, RingRole::Down);
+    assert!(last.caption.contains(&format!("I{} wins term {}", trial.leader, 4 + trial.terms)));
+}
+
+#[test]
+fn requests_go_out_and_grants_come_back() {
+    // what: a candidate's frame carries a request to each peer, the one to the crashed leader marked lost; grants point at a candidate
+    // why: the arrows are the point of the visual
+    for seed in 1..50u64 {
+        let (_, trace) = failover_traced(seed, 0);
+        for frame in frames(1, 0, &trace) {
+            for arrow in &frame.arrows {
+                assert_ne!(arrow.from, arrow.to);
+                match arrow.kind {
+                    ArrowKind::Request => {
+                        assert_eq!(frame.nodes[arrow.from].role, RingRole::Candidate);
+                        assert_eq!(arrow.lost, arrow.to == 0);
+                    }
+                    ArrowKind::Grant => assert_eq!(frame.nodes[arrow.from].voted_for, Some(arrow.to)),
+                    ArrowKind::Deny => assert!(!arrow.lost),
+                }
+            }
+            let requests = frame.arrows.iter().filter(|a| a.kind == ArrowKind::Request).count();
+            assert!(requests == 0 || requests % (CLUSTER_SIZE - 1) == 0);
+        }
+    }
+}
+
+#[test]
+fn replay_steps_to_the_last_frame_and_stops() {
+    // what: advance walks every frame once and then reports the end
+    // why: the handler holds the ring on the last frame, then clears it
+    let (_, trace) = failover_traced(3, 2);
+    let mut replay = ElectionReplay::new(3, 1, 2, &trace);
+    let total = replay.frames.len();
+    assert!(total >= 4, "steady, crash, request, grant and elected frames");
+    let mut steps = 0;
+    while replay.advance() {
+        steps += 1;
+    }
+    assert_eq!(steps, total - 1);
+    assert!(replay.finished());
+    assert_eq!(replay.current(), replay.frames.last().unwrap());
+    assert!(!replay.advance());
+}
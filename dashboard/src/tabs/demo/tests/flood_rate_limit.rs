@@ -0,0 +1,59 @@
+// what: tests for the gateway token bucket and the message flood simulation
+// why: the flood panel's charts and the journal's deadline/shed events all come from these numbers
+
+use crate::tabs::demo::flood::{first_breach, peak_latency, simulate, DEFAULT_PYTHON_FRAME_MS, DEFAULT_WASM_FRAME_MS, FLOOD_END_MS, FLOOD_START_MS, POLL_DEADLINE_MS};
+use crate::tabs::demo::rate_limit::{RateLimit, TokenBucket};
+
+#[test]
+fn bucket_admits_the_burst_then_the_rate() {
+    // what: a full bucket admits `burst` frames at once, refuses the next, and admits again once a token has refilled
+    // why: the limiter's whole contract
+    let mut bucket = TokenBucket::new(RateLimit { rate_per_s: 1000.0, burst: 5.0 });
+    (0..5).for_each(|_| assert!(bucket.try_take(0.0).is_ok()));
+    let refusal = bucket.try_take(0.0).unwrap_err();
+    assert!((refusal.retry_after_ms - 1.0).abs() < 1e-9);
+    assert!(refusal.to_string().contains("rate limit exceeded"));
+    assert!(bucket.try_take(1.0).is_ok());
+}
+
+#[test]
+fn bucket_never_refills_past_the_burst() {
+    // what: a long idle period still leaves only `burst` tokens
+    // why: otherwise a quiet minute would let the next flood straight through
+    let mut bucket = TokenBucket::new(RateLimit { rate_per_s: 1000.0, burst: 3.0 });
+    let admitted = (0..10).filter(|_| bucket.try_take(60_000.0).is_ok()).count();
+    assert_eq!(admitted, 3);
+}
+
+#[test]
+fn python_misses_the_deadline_during_the_flood_and_wasm_does_not() {
+    // what: with the default limiter and modelled costs, only the unbounded queue breaches the poll deadline
+    // why: the headline comparison the panel draws
+    let samples = simulate(RateLimit::default(), DEFAULT_PYTHON_FRAME_MS, DEFAULT_WASM_FRAME_MS, None);
+    let breach = first_breach(&samples, |s| s.python_latency_ms).expect("python breaches");
+    assert!((FLOOD_START_MS..FLOOD_END_MS).contains(&breach), "{}", breach);
+    assert_eq!(first_breach(&samples, |s| s.wasm_latency_ms), None);
+    let (python, wasm) = peak_latency(&samples);
+    assert!(python > 5.0 * POLL_DEADLINE_MS && wasm < POLL_DEADLINE_MS / 10.0, "{} {}", python, wasm);
+}
+
+#[test]
+fn shed_frames_account_for_the_excess_over_the_limit() {
+    // what: over the run, admitted + shed equals offered, and admitted stays within burst + rate x time
+    // why: the refused count must be real refusals, not a rounding artefact
+    let limit = RateLimit::default();
+    let samples = simulate(limit, DEFAULT_PYTHON_FRAME_MS, DEFAULT_WASM_FRAME_MS, None);
+    let last = samples.last().unwrap();
+    let offered: f64 = samples.iter().map(|s| s.offered_per_s * 0.05).sum();
+    let admitted = offered.round() as u64 - last.wasm_shed;
+    assert!(last.wasm_shed > 0);
+    assert!(admitted as f64 <= limit.burst + limit.rate_per_s * last.t_ms / 1000.0 + 1.0);
+}
+
+#[test]
+fn legit_traffic_alone_is_never_refused() {
+    // what: legitimate polling alone never touches the limiter
+    // why: the limiter must cost nothing in normal operation
+    let samples = simulate(RateLimit::default(), DEFAULT_PYTHON_FRAME_MS, DEFAULT_WASM_FRAME_MS, None);
+    assert!(samples.iter().filter(|s| s.t_ms <= FLOOD_START_MS).all(|s| s.wasm_shed == 0 && s.python_latency_ms < POLL_DEADLINE_MS));
+}
@@ -0,0 +1,69 @@
+// what: tests for the per-module host call trace (strace lines, caps, journal details)
+// why: the trace is the evidence for granted and denied imports; it must count every call even when it stops listing them
+
+use crate::tabs::demo::host_trace::{args_summary, CallOutcome, HostCall, HostTrace, ModuleTrace, ARGS_CAP, MAX_MODULES, TRACE_CAP};
+use crate::tabs::demo::journal::EventKind;
+use crate::tabs::demo::quiz::Answer;
+
+fn call(import: &str, outcome: CallOutcome) -> HostCall {
+    HostCall { import: import.to_string(), args: "17".to_string(), result: "2341".to_string(), duration_ms: 0.25, outcome }
+}
+
+#[test]
+fn strace_lines_per_outcome() {
+    // what: returned, thrown and denied calls print the way strace prints syscalls
+    // why: the view reads as a syscall trace, denials as EACCES
+    assert_eq!(call("sensor.read", CallOutcome::Returned).strace_line(), "sensor.read(17) = 2341 <0.250ms>");
+    assert_eq!(call("sensor.read", CallOutcome::Threw).strace_line(), "sensor.read(17) = ! 2341 <0.250ms>");
+    let denied = HostCall { result: "capability not imported: attack-surface/open-socket".to_string(), ..call("attack-surface.open-socket", CallOutcome::Denied) };
+    assert_eq!(denied.strace_line(), "attack-surface.open-socket(17) = -1 EACCES (capability not imported: attack-surface/open-socket) <0.250ms>");
+}
+
+#[test]
+fn calls_past_the_cap_are_counted() {
+    // what: only TRACE_CAP calls are listed, totals and per-import stats cover all of them
+    // why: a linked chain makes 20 000 reads; the view must stay small without under-reporting
+    let mut trace = ModuleTrace::new("linking/filter", vec!["sensor.read".to_string()]);
+    for _ in 0..TRACE_CAP + 10 {
+        trace.push(call("sensor.read", CallOutcome::Returned));
+    }
+    assert_eq!(trace.calls.len(), TRACE_CAP);
+    assert_eq!(trace.total_calls(), (TRACE_CAP + 10) as u64);
+    assert_eq!(trace.dropped(), 10);
+    let stats = &trace.stats["sensor.read"];
+    assert_eq!((stats.calls, stats.denied), ((TRACE_CAP + 10) as u64, 0));
+    assert!((stats.total_ms - 0.25 * (TRACE_CAP + 10) as f64).abs() < 1e-9);
+}
+
+#[test]
+fn journal_lines_name_the_verdict() {
+    // what: one detail per import, marked granted, denied or partly denied, with its first arguments and total time
+    // why: the journal carries the trace to exports without one event per call
+    let mut trace = ModuleTrace::new("payload", vec![]);
+    trace.push(call("a.granted", CallOutcome::Returned));
+    trace.push(call("b.denied", CallOutcome::Denied));
+    trace.push(call("c.mixed", CallOutcome::Returned));
+    trace.push(call("c.mixed", CallOutcome::Denied));
+    let lines = trace.journal_lines();
+    assert_eq!(lines[0], ("payload: a.granted ×1 granted, first args (17)".to_string(), 0.25));
+    assert!(lines[1].0.contains("b.denied ×1 denied"));
+    assert_eq!(lines[2], ("payload: c.mixed ×2 partly denied, first args (17)".to_string(), 0.5));
+    let args: Vec<String> = (0..ARGS_CAP + 2).map(|i| i.to_string()).collect();
+    assert_eq!(args_summary(&args), "0, 1, 2, 3, … +2");
+    assert_eq!(args_summary(&args[..1]), "0");
+}
+
+#[test]
+fn oldest_loads_drop_and_host_calls_are_not_faults() {
+    // what: the trace keeps the newest MAX_MODULES loads; a HostCall event is informational and never a fault
+    // why: repeated runs must not grow the view without bound, and a traced call must not change quiz or summary outcomes
+    let mut host = HostTrace::default();
+    for i in 0..MAX_MODULES + 2 {
+        host.load(ModuleTrace::new(format!("m{}", i), vec![]));
+    }
+    assert_eq!(host.modules.len(), MAX_MODULES);
+    assert_eq!(host.modules[0].module, "m2");
+    assert_eq!(EventKind::HostCall.id(), "HOSTCALL");
+    assert_eq!(EventKind::HostCall.syslog_severity(), 6);
+    assert_eq!(Answer::from_fault(Some(EventKind::HostCall)), Answer::KeepsRunning);
+}
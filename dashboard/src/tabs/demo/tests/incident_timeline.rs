@@ -0,0 +1,102 @@
+// what: tests for the incident timeline - which journal events become markers, the time axis, the log lines a marker highlights, and annotations in exports
+// why: a marker that lights the wrong lines, or a note that silently drops out of the export, misleads exactly the audience it was made for
+
+use leptos::*;
+use crate::settings::Settings;
+use crate::tabs::demo::journal::{AuditEvent, EventKind, Runtime};
+use crate::tabs::demo::siem::{annotation_record, ExportFormat};
+use crate::tabs::demo::snapshot::DemoSnapshot;
+use crate::tabs::demo::store::DemoStore;
+use crate::tabs::demo::terminal::TerminalLog;
+use crate::tabs::demo::timeline::{insert, markers, Annotation, Axis, MarkerKind, HIGHLIGHT_SLACK_MS, MAX_ANNOTATION_CHARS, MIN_SPAN_MS};
+use crate::tabs::demo::types::LogEntry;
+
+fn line(message: &str) -> LogEntry {
+    LogEntry { level: "info".into(), message: message.into() }
+}
+
+#[test]
+fn markers_cover_attacks_traps_elections_and_recoveries() {
+    // what: attack, trap, crash, election and recovery events become markers in journal order with their journal index; host calls and resets do not
+    // why: the strip is for incidents; a marker per host call would bury the trap it sits next to
+    let events = vec![
+        AuditEvent::new(1_000.0, EventKind::AttackStarted, None, "bufferOverflow", "started"),
+        AuditEvent::new(1_100.0, EventKind::HostCall, Some(Runtime::Wasm), "bufferOverflow", "read-sensor"),
+        AuditEvent::new(1_200.0, EventKind::Trap, Some(Runtime::Wasm), "bufferOverflow", "out of bounds"),
+        AuditEvent::new(1_300.0, EventKind::Crash, Some(Runtime::Python), "bufferOverflow", "segfault"),
+        AuditEvent::new(1_400.0, EventKind::Election, Some(Runtime::Wasm), "", "node 1 leads"),
+        AuditEvent::new(2_400.0, EventKind::Recovery, Some(Runtime::Python), "bufferOverflow", "worker back").with_duration(900.0),
+        AuditEvent::new(3_000.0, EventKind::Reset, None, "", "reset"),
+    ];
+    let marks = markers(&events);
+    assert_eq!(marks.iter().map(|m| m.kind).collect::<Vec<_>>(), vec![MarkerKind::Attack, MarkerKind::Trap, MarkerKind::Trap, MarkerKind::Election, MarkerKind::Recovery]);
+    assert_eq!(marks.iter().map(|m| m.index).collect::<Vec<_>>(), vec![0, 2, 3, 4, 5]);
+    assert!(marks[1].label.contains("(wasm)") && marks[1].label.contains("bufferOverflow") && marks[1].label.contains("out of bounds"));
+    assert!(!marks[3].label.contains(" · "), "no attack name, no separator");
+}
+
+#[test]
+fn axis_spans_every_moment_and_labels_offsets() {
+    // what: the axis runs from the first to the last time, a single moment still gets MIN_SPAN_MS, percent clamps to the strip and offsets read minutes:seconds
+    // why: a zero-width axis would divide by zero and stack every marker at the left edge
+    assert_eq!(Axis::span(Vec::new()), None);
+    let single = Axis::span([5_000.0]).unwrap();
+    assert_eq!(single.end_ms - single.start_ms, MIN_SPAN_MS);
+
+    let axis = Axis::span([10_000.0, 70_000.0, 40_000.0]).unwrap();
+    assert_eq!((axis.start_ms, axis.end_ms), (10_000.0, 70_000.0));
+    assert_eq!(axis.percent(40_000.0), 50.0);
+    assert_eq!(axis.percent(0.0), 0.0);
+    assert_eq!(axis.percent(99_000.0), 100.0);
+    assert_eq!(axis.offset_label(10_000.0), "+0:00.0");
+    assert_eq!(axis.offset_label(72_500.0), "+1:02.5");
+}
+
+#[test]
+fn marker_window_selects_the_lines_written_around_its_event() {
+    // what: a trap's window is its moment plus the slack; a measured recovery reaches back over its whole duration; host-level events touch every terminal
+    // why: hovering must light the lines the incident wrote and not the quiet ones before it
+    let log = TerminalLog::new();
+    for (at, message) in [(0.0, "boot"), (1_900.0, "attack"), (2_000.0, "trap"), (2_100.0, "contained"), (5_000.0, "idle")] {
+        log.push_at(line(message), at);
+    }
+    let events = vec![
+        AuditEvent::new(2_000.0, EventKind::Trap, Some(Runtime::Wasm), "bufferOverflow", "trap"),
+        AuditEvent::new(5_000.0, EventKind::Recovery, Some(Runtime::Python), "bufferOverflow", "back").with_duration(3_200.0),
+        AuditEvent::new(6_000.0, EventKind::AttackStarted, None, "raceCondition", "started"),
+    ];
+    let marks = markers(&events);
+    assert_eq!(marks[0].window(), (2_000.0 - HIGHLIGHT_SLACK_MS, 2_000.0 + HIGHLIGHT_SLACK_MS));
+    assert_eq!(log.count_within(marks[0].window()), 3);
+    assert_eq!(log.count_within(marks[1].window()), 4, "recovery covers the outage");
+    assert!(marks[0].touches(Runtime::Wasm) && !marks[0].touches(Runtime::Python));
+    assert!(marks[2].touches(Runtime::Python) && marks[2].touches(Runtime::Js));
+}
+
+#[test]
+fn annotations_stay_ordered_and_reach_every_export() {
+    // what: notes are trimmed, capped and kept in time order; blank ones are refused; both siem formats and the snapshot carry them, older snapshots load without
+    // why: the presenter's notes are the point of an annotated export; losing them on download or restore loses the story
+    assert_eq!(Annotation::new(1.0, "   "), None);
+    assert_eq!(Annotation::new(1.0, &"x".repeat(500)).unwrap().text.len(), MAX_ANNOTATION_CHARS);
+    let mut notes = Vec::new();
+    for (at, text) in [(3_000.0, "third"), (1_000.0, "first"), (2_000.0, " second ")] {
+        insert(&mut notes, Annotation::new(at, text).unwrap());
+    }
+    assert_eq!(notes.iter().map(|n| n.text.as_str()).collect::<Vec<_>>(), vec!["first", "second", "third"]);
+
+    let syslog = annotation_record(&notes[0], ExportFormat::Syslog);
+    assert!(syslog.starts_with("<110>1 1970-01-01T00:00:01.000Z") && syslog.ends_with("[annotation@32473] first"));
+    assert_eq!(annotation_record(&Annotation::new(1_000.0, "a=b").unwrap(), ExportFormat::Cef).rsplit_once("msg=").unwrap().1, "a\\=b");
+
+    let store = DemoStore::new(create_rw_signal(Settings::default()));
+    store.annotations.set(notes.clone());
+    let snap = store.snapshot(0.0);
+    store.annotations.set(Vec::new());
+    store.restore(&DemoSnapshot::from_json(&snap.to_json()).unwrap()).unwrap();
+    assert_eq!(store.annotations.get_untracked(), notes);
+
+    let mut old: serde_json::Value = serde_json::from_str(&snap.to_json()).unwrap();
+    old.as_object_mut().unwrap().remove("annotations");
+    assert_eq!(DemoSnapshot::from_json(&old.to_string()).unwrap().annotations, Vec::new());
+}
@@ -0,0 +1,112 @@
+// what: tests for influxdb line-protocol encoding and writer buffering
+// why: a bad escape or lost batch silently corrupts the historian
+
+use crate::tabs::demo::influx::{backoff_ms, to_line, FieldValue, InfluxWriter, Point, MAX_BACKOFF_MS, MAX_BATCH, MAX_BUFFERED};
+
+fn point(value: f64) -> Point {
+    Point {
+        measurement: "bme280",
+        tags: vec![("site", "guardian-one".to_string())],
+        fields: vec![("temperature", FieldValue::Float(value))],
+        timestamp_ns: 1_000_000,
+    }
+}
+
+fn enabled_writer() -> InfluxWriter {
+    InfluxWriter { enabled: true, url: "http://influx:8086/api/v2/write".to_string(), ..Default::default() }
+}
+
+#[test]
+fn line_escapes_spaces_commas_and_equals() {
+    // what: tag keys/values escape separators, measurement escapes comma/space
+    // why: an unescaped space splits the line into garbage fields
+    let p = Point {
+        measurement: "sensor room",
+        tags: vec![("loc", "hall a,b=c".to_string())],
+        fields: vec![("t", FieldValue::Float(21.5))],
+        timestamp_ns: 42,
+    };
+    assert_eq!(to_line(&p), "sensor\\ room,loc=hall\\ a\\,b\\=c t=21.5 42");
+}
+
+#[test]
+fn field_types_are_encoded() {
+    // what: ints get an i suffix, strings are quoted with escaped quotes
+    // why: influx rejects a batch on the first field type conflict
+    let p = Point {
+        measurement: "tmr_vote",
+        tags: vec![],
+        fields: vec![("agreeing", FieldValue::Int(2)), ("status", FieldValue::Text("say \"ok\"".to_string()))],
+        timestamp_ns: 7,
+    };
+    assert_eq!(to_line(&p), "tmr_vote agreeing=2i,status=\"say \\\"ok\\\"\" 7");
+}
+
+#[test]
+fn backoff_doubles_and_caps() {
+    // what: retry delay doubles per failure up to the ceiling
+    // why: a dead endpoint must not be hammered every second
+    assert_eq!(backoff_ms(0), 0);
+    assert_eq!(backoff_ms(2), backoff_ms(1) * 2);
+    assert_eq!(backoff_ms(30), MAX_BACKOFF_MS);
+}
+
+#[test]
+fn full_buffer_drops_oldest() {
+    // what: enqueue past capacity evicts the oldest line and counts it
+    // why: memory stays bounded while the endpoint is down
+    let mut w = enabled_writer();
+    for i in 0..=MAX_BUFFERED {
+        w.enqueue(&point(i as f64));
+    }
+    assert_eq!(w.buffer.len(), MAX_BUFFERED);
+    assert_eq!(w.dropped, 1);
+    assert!(w.buffer[0].contains("temperature=1 "));
+}
+
+#[test]
+fn batches_respect_backoff_and_success() {
+    // what: no batch while backing off, success drains exactly the sent lines
+    // why: retries must resend the same points without duplicating or losing any
+    let mut w = enabled_writer();
+    for i in 0..(MAX_BATCH + 5) {
+        w.enqueue(&point(i as f64));
+    }
+    assert_eq!(w.next_batch(0.0).map(|b| b.len()), Some(MAX_BATCH));
+    w.on_failure(0.0);
+    assert!(w.next_batch(10.0).is_none());
+    assert!(w.next_batch(backoff_ms(1) as f64).is_some());
+    w.on_success(MAX_BATCH);
+    assert_eq!(w.buffer.len(), 5);
+    assert_eq!(w.written, MAX_BATCH as u32);
+    assert_eq!(w.failures, 0);
+}
+
+#[test]
+fn full_buffer_keeps_the_batch_in_flight() {
+    // what: a point arriving at capacity while a batch is in flight is dropped and counted; success then drains exactly the sent lines
+    // why: evicting the front would shift the buffer under the batch, and on_success would drain lines that were never sent
+    let mut w = enabled_writer();
+    for i in 0..MAX_BUFFERED {
+        w.enqueue(&point(i as f64));
+    }
+    let batch = w.next_batch(0.0).unwrap();
+    w.in_flight = true;
+    w.enqueue(&point(-1.0));
+    assert_eq!(w.dropped, 1);
+    assert_eq!(w.buffer[..MAX_BATCH], batch[..]);
+    w.on_success(batch.len());
+    assert_eq!(w.buffer.len(), MAX_BUFFERED - MAX_BATCH);
+    assert!(w.buffer[0].contains(&format!("temperature={} ", MAX_BATCH)));
+    assert!(!w.buffer.iter().any(|l| l.contains("temperature=-1 ")));
+}
+
+#[test]
+fn disabled_writer_ignores_points() {
+    // what: nothing is buffered until the writer is enabled
+    // why: the demo must not accumulate lines for users who never configure influx
+    let mut w = InfluxWriter::default();
+    w.enqueue(&point(1.0));
+    assert!(w.buffer.is_empty());
+    assert!(w.next_batch(0.0).is_none());
+}
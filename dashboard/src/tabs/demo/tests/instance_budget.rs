@@ -0,0 +1,55 @@
+// what: tests for the per-instance budget math behind the live memory and table readout
+// why: the node strip bars and the "Live" badge are computed from these; the engine only supplies the page and slot counts
+
+use crate::tabs::demo::budget::{format_bytes, live_badge, plan_write, InstanceBudget, WORKLOAD_BYTES};
+use crate::tabs::demo::modules::{INSTANCE_MAX_PAGES, INSTANCE_TABLE_MAX};
+
+fn budget(memory_pages: u32, used_bytes: u32) -> InstanceBudget {
+    InstanceBudget { memory_pages, max_pages: INSTANCE_MAX_PAGES, table_len: 1, table_max: INSTANCE_TABLE_MAX, used_bytes, grow_denied: 0 }
+}
+
+#[test]
+fn writes_that_fit_do_not_grow() {
+    // what: a batch that fits after the used part of the ring lands there with no grow
+    // why: growing on every write would hit the cap after a handful of readings
+    let plan = plan_write(&budget(1, 0), WORKLOAD_BYTES);
+    assert_eq!((plan.offset, plan.grow), (0, 0));
+    assert_eq!(plan_write(&budget(1, 48 * 1024), WORKLOAD_BYTES).grow, 0);
+}
+
+#[test]
+fn a_full_page_asks_for_exactly_the_pages_missing() {
+    // what: a batch crossing the end of memory asks for the pages needed to hold it, rounded up
+    // why: memory.grow takes whole 64 KiB pages
+    assert_eq!(budget(1, 64 * 1024).pages_needed(WORKLOAD_BYTES), 1);
+    assert_eq!(budget(1, 60 * 1024).pages_needed(200 * 1024), 4);
+    assert_eq!(budget(4, 0).pages_needed(WORKLOAD_BYTES), 0);
+}
+
+#[test]
+fn fraction_and_label_follow_the_cap() {
+    // what: 8 of 32 pages is a quarter of the 2 MiB budget and reads that way
+    // why: the bar width and its label must agree
+    let b = budget(8, 0);
+    assert!((b.fraction() - 0.25).abs() < 1e-9);
+    assert_eq!(b.label(), "512 KiB / 2 MiB · table 1/4");
+    assert_eq!(InstanceBudget { max_pages: 0, ..b }.fraction(), 0.0);
+}
+
+#[test]
+fn bytes_format_to_the_largest_whole_unit() {
+    // what: bytes, KiB, whole MiB and fractional MiB
+    // why: labels stay short in the node strip
+    assert_eq!(format_bytes(512), "512 B");
+    assert_eq!(format_bytes(192 * 1024), "192 KiB");
+    assert_eq!(format_bytes(2 * 1024 * 1024), "2 MiB");
+    assert_eq!(format_bytes(3 * 1024 * 1024 / 2), "1.5 MiB");
+}
+
+#[test]
+fn live_badge_sums_spawned_instances_only() {
+    // what: no badge before any instance exists; afterwards live memory and caps summed over spawned instances
+    // why: the static badge stays until the engine has something real to report
+    assert_eq!(live_badge(&[None, None, None]), None);
+    assert_eq!(live_badge(&[Some(budget(1, 0)), None, Some(budget(3, 0))]).as_deref(), Some("Live: 256 KiB / 4 MiB"));
+}
@@ -0,0 +1,46 @@
+// what: tests for the optional javascript baseline column
+// why: the runtime list, js model and store must stay consistent when a third column is shown
+
+use leptos::*;
+use crate::settings::Settings;
+use crate::tabs::demo::journal::Runtime;
+use crate::tabs::demo::runtime_model::{model, runtimes};
+use crate::tabs::demo::store::DemoStore;
+use crate::tabs::demo::types::LogEntry;
+
+#[test]
+fn baseline_column_is_opt_in() {
+    // what: js is appended after python and wasm only when enabled; off by default
+    // why: existing layouts keep two columns unless the user asks for the baseline
+    assert!(!Settings::default().js_baseline);
+    assert_eq!(runtimes(false), vec![Runtime::Python, Runtime::Wasm]);
+    assert_eq!(runtimes(true), vec![Runtime::Python, Runtime::Wasm, Runtime::Js]);
+}
+
+#[test]
+fn js_model_is_single_shared_context() {
+    // what: one always-up node, shared heap badge, own terminal id
+    // why: js has no pool or failover, the column must not imply otherwise
+    let js = model(Runtime::Js);
+    assert_eq!(js.node_count(), 1);
+    assert_eq!(js.memory_badge(), "Shared heap");
+    assert_eq!(js.terminal_id(), "js-terminal");
+}
+
+#[test]
+fn js_stats_and_reset() {
+    // what: js samples feed the stat cells and reset clears them with the other columns
+    // why: the stats model is now N-runtime aware
+    let store = DemoStore::new(create_rw_signal(Settings { js_baseline: true, ..Default::default() }));
+    assert!(store.js_baseline());
+    assert_eq!(model(Runtime::Js).stats(&store)[1].value, "—");
+    store.perf.js_exec_samples.set(vec![0.5, 0.5]);
+    store.stats.js_processed.set(2);
+    store.js_log.push(LogEntry { level: "info".into(), message: "x".into() });
+    let cells = model(Runtime::Js).stats(&store);
+    assert_eq!(cells[0].value, "2");
+    assert_ne!(cells[1].value, "—");
+    store.reset();
+    assert_eq!(store.stats.js_processed.get(), 0);
+    assert!(store.js_log.is_empty());
+}
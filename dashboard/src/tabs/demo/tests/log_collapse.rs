@@ -0,0 +1,76 @@
+// what: tests for run-length collapsing in the terminal log - which lines merge, the run cap, retention under a flood, and what snapshots see
+// why: a collapse that merges different lines hides evidence, and one that leaks into exports changes what the session recorded
+
+use leptos::*;
+use crate::settings::Settings;
+use crate::tabs::demo::store::DemoStore;
+use crate::tabs::demo::terminal::{TerminalLog, CHUNK_LINES, MAX_RUN};
+use crate::tabs::demo::types::LogEntry;
+
+fn entry(level: &str, message: &str) -> LogEntry {
+    LogEntry { level: level.into(), message: message.into() }
+}
+
+#[test]
+fn only_consecutive_identical_lines_collapse() {
+    // what: repeats of the last line count onto its row; the same message at another level, or a repeat after a different line, opens a new row
+    // why: a "×N" row must stand for N writes of exactly that line, in that place in the sequence
+    let log = TerminalLog::new();
+    for e in [entry("info", "poll"), entry("info", "poll"), entry("info", "poll"), entry("warn", "poll"), entry("info", "poll"), entry("info", "poll")] {
+        log.push(e);
+    }
+    assert_eq!(
+        log.rows(),
+        vec![(entry("info", "poll"), 3), (entry("warn", "poll"), 1), (entry("info", "poll"), 2)],
+    );
+    assert_eq!(log.len(), 6);
+}
+
+#[test]
+fn a_flood_keeps_the_history_before_it() {
+    // what: thousands of identical lines fill rows of MAX_RUN writes instead of one row each, so the line before the flood stays retained and nothing is trimmed
+    // why: during chaos and soak runs the flood used to push the attack that caused it out of the terminal
+    let log = TerminalLog::new();
+    log.push(entry("error", "attack started"));
+    let flood = MAX_RUN * (CHUNK_LINES - 1);
+    for _ in 0..flood {
+        log.push(entry("warn", "frame dropped"));
+    }
+    assert_eq!(log.trimmed(), 0);
+    let rows = log.rows();
+    assert_eq!(rows[0], (entry("error", "attack started"), 1));
+    assert_eq!(rows.len(), CHUNK_LINES);
+    assert!(rows[1..].iter().all(|(_, count)| *count == MAX_RUN));
+    assert_eq!(log.len(), flood + 1);
+}
+
+#[test]
+fn every_write_keeps_its_stamp() {
+    // what: a collapsed row keeps the time of each write, so a timeline window counts the repeats inside it and not the whole run
+    // why: hovering a marker in the middle of a flood must highlight the writes around it, and its line count must be honest
+    let log = TerminalLog::new();
+    for at in [1_000.0, 2_000.0, 3_000.0, 4_000.0] {
+        log.push_at(entry("warn", "retry"), at);
+    }
+    assert_eq!(log.rows().len(), 1);
+    assert_eq!(log.count_within((1_500.0, 3_500.0)), 2);
+    assert_eq!(log.count_within((0.0, 10_000.0)), 4);
+}
+
+#[test]
+fn snapshots_see_every_line_and_restore_recollapses() {
+    // what: entries() writes each run out in full, so a snapshot holds the lines as they were written; restoring them collapses them again
+    // why: collapsing is a display concern; saved and exported sessions must not depend on it
+    let store = DemoStore::new(create_rw_signal(Settings::default()));
+    store.wasm_log.push(entry("info", "$ wasmtime run"));
+    for _ in 0..5 {
+        store.wasm_log.push(entry("warn", "trap contained"));
+    }
+    let snap = store.snapshot(0.0);
+    assert_eq!(snap.wasm_log.len(), 6);
+    assert_eq!(snap.wasm_log[5], entry("warn", "trap contained"));
+
+    store.reset();
+    store.restore(&snap).unwrap();
+    assert_eq!(store.wasm_log.rows(), vec![(entry("info", "$ wasmtime run"), 1), (entry("warn", "trap contained"), 5)]);
+}
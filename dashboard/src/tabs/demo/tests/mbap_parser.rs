@@ -0,0 +1,50 @@
+// what: tests for the checked mbap parser and the length-overflow attack inputs
+// why: the memory-safety claim in the length-overflow scenario rides on this parser refusing the crafted field
+
+use modbus_parser::diode::FC_WRITE_MULTIPLE_REGISTERS;
+use modbus_parser::mbap::{build_adu, parse_adu, MbapError};
+use attack_code::ATTACK_LENGTH_OVERFLOW;
+use crate::tabs::demo::attacks::LENGTH_OVERFLOW_ADU;
+use crate::tabs::demo::probes::length_probe;
+
+#[test]
+fn zero_length_is_a_typed_underflow() {
+    // what: the crafted adu (length 0) returns LengthUnderflow instead of wrapping
+    // why: python's c-style port turns the same field into a ~4 GiB allocation
+    assert_eq!(parse_adu(&LENGTH_OVERFLOW_ADU), Err(MbapError::LengthUnderflow { length: 0 }));
+}
+
+#[test]
+fn well_formed_adu_round_trips() {
+    // what: build_adu writes a length parse_adu accepts, pdu intact
+    // why: the validation must not reject legitimate traffic
+    let adu = build_adu(7, 1, FC_WRITE_MULTIPLE_REGISTERS, &[0x00, 0x0A, 0x00, 0x01, 0x02, 0x00, 0x3C]);
+    let frame = parse_adu(&adu).unwrap();
+    assert_eq!((frame.transaction_id, frame.unit_id, frame.function_code), (7, 1, FC_WRITE_MULTIPLE_REGISTERS));
+    assert_eq!(frame.data, &LENGTH_OVERFLOW_ADU[8..]);
+}
+
+#[test]
+fn length_must_match_bytes_received() {
+    // what: oversized and mismatched length fields are refused before the pdu is read
+    // why: a large declared length must never drive an allocation
+    let mut adu = build_adu(1, 1, 0x03, &[0x00, 0x00, 0x00, 0x02]);
+    adu[4..6].copy_from_slice(&0xFFFFu16.to_be_bytes());
+    assert_eq!(parse_adu(&adu), Err(MbapError::LengthOutOfRange { pdu_len: 0xFFFE }));
+    adu[4..6].copy_from_slice(&9u16.to_be_bytes());
+    assert_eq!(parse_adu(&adu), Err(MbapError::LengthMismatch { declared: 8, actual: 5 }));
+    assert_eq!(parse_adu(&adu[..5]), Err(MbapError::TooShort { len: 5 }));
+}
+
+#[test]
+fn probe_and_python_share_the_crafted_adu() {
+    // what: the wasm-side probe reports the typed error and python parses the identical bytes
+    // why: both runtimes must be fed the same frame for the comparison to hold
+    let outcome = length_probe(2, &LENGTH_OVERFLOW_ADU);
+    assert_eq!(outcome.length, 0);
+    let lines = outcome.lines();
+    assert!(lines[0].message.contains("Err(LengthUnderflow { length: 0 })"));
+    assert!(lines[1].message.contains("nothing allocated"));
+    let hex: String = LENGTH_OVERFLOW_ADU.iter().map(|b| format!("{:02x}", b)).collect();
+    assert!(ATTACK_LENGTH_OVERFLOW.contains(&hex));
+}
@@ -0,0 +1,96 @@
+// what: tests for the generated methodology disclosure and the exports that carry it
+// why: a value labelled measured that was simulated, or an export that drops the list, is exactly what the disclosure exists to prevent
+
+use leptos::*;
+use crate::diagnostics::probe::PINNED_PYODIDE;
+use crate::measure::stats::Summary;
+use crate::settings::Settings;
+use crate::tabs::demo::methodology::{disclosures, to_lines, Disclosure, Provenance, SessionParams};
+use crate::tabs::demo::siem::{methodology_record, ExportFormat};
+use crate::tabs::demo::snapshot::DemoSnapshot;
+use crate::tabs::demo::store::DemoStore;
+use crate::tabs::demo::summary::{render, SessionNumbers, SummaryFormat};
+
+fn fresh() -> SessionParams {
+    SessionParams { iterations: 10, timer_resolution_ms: 0.005, election_trials: 500, ..Default::default() }
+}
+
+fn find<'a>(list: &'a [Disclosure], label: &str) -> &'a Disclosure {
+    list.iter().find(|d| d.label == label).unwrap_or_else(|| panic!("{} missing", label))
+}
+
+#[test]
+fn fresh_session_claims_no_measurements() {
+    // what: before anything runs, no timing is labelled measured and the python restart uses the fallback
+    // why: the disclosure must not borrow credibility from numbers that do not exist yet
+    let list = disclosures(&fresh());
+    for label in ["WASM instantiate", "Pyodide cold start", "Sensor run (WASM)", "Sensor run (Python)"] {
+        assert_eq!(find(&list, label).provenance, Provenance::Setting, "{}", label);
+    }
+    assert_eq!(find(&list, "Sensor run (JavaScript)").value, "baseline column off");
+    assert!(find(&list, "Pyodide").value.contains(PINNED_PYODIDE));
+    let restart = find(&list, "Python restart delay");
+    assert_eq!(restart.provenance, Provenance::Simulated);
+    assert!(restart.value.starts_with("1500ms fallback ± 200ms"), "{}", restart.value);
+    assert_eq!(find(&list, "Raft failover").value, "500 seeded simulated elections");
+    assert_eq!(find(&list, "Sensor readings").provenance, Provenance::Simulated);
+    assert!(find(&list, "Timing method").value.starts_with("each of 10 iterations"));
+}
+
+#[test]
+fn measured_session_reports_its_parameters() {
+    // what: measured timings, the probed pyodide build and a coarse timer all change the disclosure
+    // why: the point is that the statement is generated from this session, not a fixed paragraph
+    let params = SessionParams {
+        timer_resolution_ms: 0.1,
+        pyodide: Some(("0.25.0".into(), "3.11.3".into())),
+        numbers: SessionNumbers { pyodide_load_ms: Some(2150.4), wasm_exec: Some(Summary { median: 0.042, ci95: 0.003, kept: 8, ..Default::default() }), ..Default::default() },
+        js_baseline: true,
+        container_imported: true,
+        ..fresh()
+    };
+    let list = disclosures(&params);
+    assert_eq!(find(&list, "Sensor run (WASM)").provenance, Provenance::Measured);
+    assert_eq!(find(&list, "Sensor run (WASM)").value, "0.042 ± 0.003ms");
+    assert_eq!(find(&list, "Sensor run (JavaScript)").value, "not measured this session");
+    assert!(find(&list, "Pyodide").value.contains(&format!("differs from pinned {}", PINNED_PYODIDE)));
+    assert!(find(&list, "Python restart delay").value.starts_with("measured Pyodide cold start (2150ms)"));
+    assert!(find(&list, "Timing method").value.contains("fixed windows"));
+    assert!(find(&list, "Timer resolution").value.starts_with("0.100ms, cross-origin isolated: no"));
+    assert_eq!(find(&list, "Container restart").provenance, Provenance::Imported);
+}
+
+#[test]
+fn audit_and_summary_exports_carry_the_list() {
+    // what: the syslog record has one escaped sd param per line, cef folds them into msg, summaries append a section
+    // why: the disclosure is only useful if it travels with the numbers
+    let list = disclosures(&fresh());
+    let syslog = methodology_record(&list, 0.0, ExportFormat::Syslog);
+    assert!(syslog.starts_with("<110>1 1970-01-01T00:00:00.000Z guardian-one guardian-demo - methodology [methodology@32473 p0=\"Timing method: "));
+    assert!(syslog.contains(&format!(" p{}=\"", list.len() - 1)));
+    let tricky = [Disclosure { label: "a]b".into(), value: "say \"hi\"".into(), provenance: Provenance::Setting }];
+    assert!(methodology_record(&tricky, 0.0, ExportFormat::Syslog).contains(r#"p0="a\]b: say \"hi\" [setting]""#));
+    let cef = methodology_record(&list, 0.0, ExportFormat::Cef);
+    assert!(cef.contains("|methodology|Session methodology|0|rt=0 msg=Timing method: "));
+    assert!(!cef.contains('\n'));
+
+    let markdown = render(&SessionNumbers::default(), &[], "ua", &[], &list, 0.0, SummaryFormat::Markdown);
+    assert!(markdown.contains("### Methodology\n\n| Parameter | Value | Source |"));
+    assert!(markdown.contains("| Raft failover | 500 seeded simulated elections | simulated |"));
+    let text = render(&SessionNumbers::default(), &[], "ua", &[], &list, 0.0, SummaryFormat::Text);
+    assert!(text.contains(&format!("\nMethodology:\n{}", to_lines(&list, "  "))));
+}
+
+#[test]
+fn snapshot_json_carries_methodology_and_reads_old_blobs() {
+    // what: an exported snapshot round trips its methodology; a blob from before the field restores with an empty list
+    // why: snapshots exported before this change must still import
+    let store = DemoStore::new(create_rw_signal(Settings::default()));
+    let snap = DemoSnapshot { methodology: disclosures(&fresh()), ..store.snapshot(1.0) };
+    let json = snap.to_json();
+    assert!(json.contains("\"provenance\":\"simulated\""));
+    assert_eq!(DemoSnapshot::from_json(&json), Ok(snap));
+    let mut old: serde_json::Value = serde_json::from_str(&json).unwrap();
+    old.as_object_mut().unwrap().remove("methodology");
+    assert!(DemoSnapshot::from_json(&old.to_string()).unwrap().methodology.is_empty());
+}
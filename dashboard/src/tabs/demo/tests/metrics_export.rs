@@ -0,0 +1,66 @@
+// what: tests for prometheus exposition rendering
+// why: a malformed exposition makes the scrape fail and the grafana panel goes blank
+
+use crate::tabs::demo::journal::{AuditEvent, EventKind, Runtime};
+use crate::tabs::demo::metrics::{election_histogram, recovery_histogram, render, MetricsSnapshot, RECOVERY_BUCKETS_MS};
+
+fn recovery(runtime: Runtime, ms: f64) -> AuditEvent {
+    AuditEvent::new(0.0, EventKind::Recovery, Some(runtime), "x", "").with_duration(ms)
+}
+
+#[test]
+fn counters_rendered_with_runtime_label() {
+    // what: snapshot counters appear as labelled samples
+    // why: grafana queries filter on runtime="python"/"wasm"
+    let snapshot = MetricsSnapshot { python_crashed: 3, wasm_rejected: 2, ..Default::default() };
+    let body = render(&snapshot, &[]);
+    assert!(body.contains("guardian_crashes_total{runtime=\"python\"} 3\n"));
+    assert!(body.contains("guardian_crashes_total{runtime=\"wasm\"} 2\n"));
+}
+
+#[test]
+fn histogram_buckets_are_cumulative() {
+    // what: each bucket counts all samples <= le
+    // why: prometheus histogram_quantile assumes cumulative buckets
+    let events = vec![recovery(Runtime::Python, 1500.0), recovery(Runtime::Python, 1800.0), recovery(Runtime::Wasm, 0.05)];
+    let (buckets, sum, count) = recovery_histogram(&events, Runtime::Python);
+    assert_eq!(count, 2);
+    assert_eq!(sum, 3300.0);
+    assert!(buckets.windows(2).all(|w| w[0] <= w[1]), "buckets must be non-decreasing");
+    assert_eq!(*buckets.last().unwrap(), 2);
+}
+
+#[test]
+fn histogram_has_inf_bucket_equal_to_count() {
+    // what: the +Inf bucket equals _count for each runtime
+    // why: required by the exposition format
+    let events = vec![recovery(Runtime::Wasm, 0.04), recovery(Runtime::Wasm, 0.06)];
+    let body = render(&MetricsSnapshot::default(), &events);
+    assert!(body.contains("guardian_recovery_ms_bucket{runtime=\"wasm\",le=\"+Inf\"} 2\n"));
+    assert!(body.contains("guardian_recovery_ms_count{runtime=\"wasm\"} 2\n"));
+}
+
+#[test]
+fn every_metric_has_help_and_type() {
+    // what: each metric family is preceded by # HELP and # TYPE
+    // why: pushgateway rejects families with inconsistent type metadata
+    let body = render(&MetricsSnapshot::default(), &[]);
+    assert_eq!(body.matches("# HELP ").count(), body.matches("# TYPE ").count());
+    assert_eq!(body.matches("# TYPE ").count(), 5);
+    assert_eq!(body.matches("le=\"").count(), 4 * (RECOVERY_BUCKETS_MS.len() + 1));
+}
+
+#[test]
+fn elections_are_kept_out_of_recovery() {
+    // what: a timed election lands in guardian_election_ms and not in guardian_recovery_ms
+    // why: a multi-second failover counted as a recovery would drag the wasm recovery quantiles up by orders of magnitude
+    let events = vec![
+        recovery(Runtime::Wasm, 0.05),
+        AuditEvent::new(0.0, EventKind::Election, Some(Runtime::Wasm), "x", "").with_duration(150.0),
+    ];
+    assert_eq!(recovery_histogram(&events, Runtime::Wasm).1, 0.05);
+    assert_eq!(election_histogram(&events, Runtime::Wasm).1, 150.0);
+    let body = render(&MetricsSnapshot::default(), &events);
+    assert!(body.contains("guardian_recovery_ms_count{runtime=\"wasm\"} 1\n"));
+    assert!(body.contains("guardian_election_ms_count{runtime=\"wasm\"} 1\n"));
+}
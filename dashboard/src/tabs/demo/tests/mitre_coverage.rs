@@ -0,0 +1,63 @@
+// what: tests for the att&ck for ics mapping and the policy-driven coverage matrix
+// why: a dangling technique id renders an unlinked badge, and coverage that ignores the policy would overstate what is mitigated
+
+use crate::tabs::demo::attacks::get_attack_config;
+use crate::tabs::demo::mitre::{coverage, mitigation, prevented, scenarios, technique, Mitigation, TECHNIQUES};
+use crate::tabs::demo::policy::{CapabilityPolicy, WORKER_WORLD};
+
+#[test]
+fn every_scenario_maps_to_catalogued_techniques() {
+    // what: each scenario names at least one technique, all ids resolve, and no catalog entry goes unused
+    // why: the registry and the catalog are edited separately; a typo on either side must fail here
+    for attack in scenarios() {
+        let ids = get_attack_config(attack).techniques;
+        assert!(!ids.is_empty(), "{} has no technique", attack);
+        assert!(ids.iter().all(|id| technique(id).is_some()), "{}: {:?}", attack, ids);
+    }
+    assert!(TECHNIQUES.iter().all(|t| scenarios().iter().any(|a| get_attack_config(a).techniques.contains(&t.id))));
+    assert!(TECHNIQUES.windows(2).all(|w| w[0].tactics[0] <= w[1].tactics[0]), "catalog in matrix order");
+    assert_eq!(technique("T0866").unwrap().url(), "https://attack.mitre.org/techniques/T0866/");
+}
+
+#[test]
+fn default_policy_prevents_every_security_technique() {
+    // what: under the shipped policy only the availability techniques stay unprevented (failover only)
+    // why: that is the demo's claim, and the gap it admits
+    let policy = CapabilityPolicy::load_default();
+    let rows = coverage(&policy);
+    assert_eq!(prevented(&rows), (8, 11));
+    let open: Vec<&str> = rows.iter().filter(|r| !r.weakest.prevents()).map(|r| r.technique.id).collect();
+    assert_eq!(open, ["T0816", "T0804", "T0826"]);
+    assert!(rows.iter().filter(|r| !r.weakest.prevents()).all(|r| r.weakest == Mitigation::Failover));
+    assert_eq!(mitigation("stackExhaustion", &policy), Mitigation::EngineLimit);
+    assert_eq!(mitigation("lengthOverflow", &policy), Mitigation::Validation);
+}
+
+#[test]
+fn granting_a_capability_opens_its_techniques() {
+    // what: a wildcard socket grant turns the exfiltration and lateral movement techniques open
+    // why: the matrix is meant to follow the policy editor, not a fixed answer key
+    let mut policy = CapabilityPolicy::load_default();
+    policy.worlds.iter_mut().find(|w| w.name == WORKER_WORLD).unwrap().sockets.push("*".into());
+    assert_eq!(mitigation("dataExfil", &policy), Mitigation::Open);
+    let rows = coverage(&policy);
+    let weakest = |id: &str| rows.iter().find(|r| r.technique.id == id).unwrap().weakest;
+    assert_eq!(weakest("T0882"), Mitigation::Open);
+    assert_eq!(weakest("T0886"), Mitigation::Open);
+    assert!(weakest("T0893").prevents(), "filesystem and env techniques unaffected");
+    assert_eq!(prevented(&rows), (4, 11));
+}
+
+#[test]
+fn matrix_cells_line_up_with_scenarios() {
+    // what: each row has one cell per scenario, filled exactly where the scenario uses the technique
+    // why: an off-by-one here would put icons under the wrong column header
+    let policy = CapabilityPolicy::load_default();
+    let names = scenarios();
+    for row in coverage(&policy) {
+        assert_eq!(row.cells.len(), names.len());
+        for (cell, attack) in row.cells.iter().zip(&names) {
+            assert_eq!(cell.is_some(), get_attack_config(attack).techniques.contains(&row.technique.id), "{} / {}", row.technique.id, attack);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// what: tests for the mock host build.rs generates from wit/attacks.wit
+// why: the simulated denials are only as honest as their match with the contract; a stale or hand-edited mock would drift silently
+
+use crate::tabs::demo::attacks::{get_attack_config, SECURITY_ATTACKS};
+use crate::tabs::demo::mock_host::{denial, get_environment, invoke, lookup, malloc_large, open_socket, read_file, MockHost, FUNCTIONS, INTERFACE};
+use crate::tabs::demo::wit_codegen::{generate, parse_interface, rust_type};
+
+const WIT: &str = include_str!("../../../../../wit/attacks.wit");
+const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/mock_host.rs"));
+
+#[test]
+fn generated_table_matches_the_contract() {
+    // what: FUNCTIONS lists every attack-surface func with the declared params and result, in order
+    // why: a new honey-pot import must show up in the browser the moment it is added to the wit file
+    let declared = parse_interface(WIT, INTERFACE).unwrap();
+    assert_eq!(FUNCTIONS.len(), declared.len());
+    for (generated, wit) in FUNCTIONS.iter().zip(&declared) {
+        assert_eq!(generated.name, wit.name);
+        assert_eq!(generated.params.iter().map(|(n, t)| (n.to_string(), t.to_string())).collect::<Vec<_>>(), wit.params);
+        assert_eq!(generated.result.map(str::to_string), wit.result);
+    }
+    assert_eq!(
+        FUNCTIONS.iter().map(|f| f.declaration()).collect::<Vec<_>>(),
+        [
+            "malloc-large: func(size: u64) -> result<u64, string>",
+            "open-socket: func(addr: string) -> result<u32, string>",
+            "read-file: func(path: string) -> result<list<u8>, string>",
+            "get-environment: func() -> list<tuple<string, string>>",
+        ]
+    );
+    assert_eq!(GENERATED, generate(WIT, INTERFACE).unwrap(), "OUT_DIR copy is current and generation is deterministic");
+}
+
+#[test]
+fn every_call_is_denied_and_logged() {
+    // what: result-returning funcs come back Err with the denial, the environment comes back empty, and each call is logged with its arguments
+    // why: "capability denied" must be what the guest sees and what the log shows, call for call
+    let mut host = MockHost::default();
+    assert_eq!(malloc_large(&mut host, 1 << 30), Err(denial("malloc-large")));
+    assert_eq!(open_socket(&mut host, "203.0.113.7:443".into()), Err(denial("open-socket")));
+    assert_eq!(read_file(&mut host, "../../etc/passwd".into()), Err(denial("read-file")));
+    assert!(get_environment(&mut host).is_empty());
+    let calls: Vec<(&str, Vec<String>)> = host.calls.iter().map(|c| (c.func, c.args.clone())).collect();
+    assert_eq!(
+        calls,
+        [
+            ("malloc-large", vec!["size: 1073741824".to_string()]),
+            ("open-socket", vec!["addr: \"203.0.113.7:443\"".to_string()]),
+            ("read-file", vec!["path: \"../../etc/passwd\"".to_string()]),
+            ("get-environment", vec![]),
+        ]
+    );
+    assert!(host.calls.iter().all(|c| c.denial == format!("capability not imported: attack-surface/{}", c.func)));
+    assert!(!invoke(&mut host, "read-hardware-register"), "granted capabilities are not mocked");
+    assert_eq!(host.calls.len(), 4);
+}
+
+#[test]
+fn capability_attacks_name_a_declared_function() {
+    // what: every security attack's wit_func is either a declared attack-surface func or an explicit N/A
+    // why: the [WIT] log line is built from the lookup; a typo would drop it without a compile error
+    for attack in SECURITY_ATTACKS {
+        let config = get_attack_config(attack);
+        match lookup(config.wit_func) {
+            Some(func) => {
+                let mut host = MockHost::default();
+                assert!(invoke(&mut host, func.name), "{}", attack);
+                assert_eq!(host.calls.len(), 1, "{}", attack);
+            }
+            None => assert!(config.wit_func.starts_with("(N/A"), "{} names an undeclared func {}", attack, config.wit_func),
+        }
+    }
+    assert!(lookup("unknown()").is_none());
+}
+
+#[test]
+fn codegen_maps_wit_types_and_rejects_unknown_ones() {
+    // what: primitive, list, option, result and tuple types map to rust; user-defined records are an error, not a guess
+    // why: build.rs fails the build on an unmappable type instead of emitting a mock with the wrong shape
+    assert_eq!(rust_type("list<tuple<string, s32>>").unwrap(), "Vec<(String, i32)>");
+    assert_eq!(rust_type("result<option<u8>, string>").unwrap(), "Result<Option<u8>, String>");
+    assert!(rust_type("telemetry-packet").is_err());
+    assert!(generate(WIT, "tmr-logic").unwrap_err().contains("telemetry-packet"));
+    assert!(generate(WIT, "no-such-interface").is_err());
+    let swap = parse_interface(WIT, "tmr-logic").unwrap();
+    assert_eq!(swap.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(), ["consensus-2oo3", "trigger-hot-swap"]);
+    assert_eq!(swap[1].result, None);
+}
@@ -12,3 +12,159 @@ mod measurement;
 
 #[cfg(test)]
 mod state_invariants;
+
+#[cfg(test)]
+mod register_map;
+
+#[cfg(test)]
+mod data_diode;
+
+#[cfg(test)]
+mod policy;
+
+#[cfg(test)]
+mod siem_export;
+
+#[cfg(test)]
+mod metrics_export;
+
+#[cfg(test)]
+mod influx_writer;
+
+#[cfg(test)]
+mod terminal_log;
+
+#[cfg(test)]
+mod demo_store;
+
+#[cfg(test)]
+mod demo_handlers;
+
+#[cfg(test)]
+mod runtime_model;
+
+#[cfg(test)]
+mod js_baseline;
+
+#[cfg(test)]
+mod wasm_probes;
+
+#[cfg(test)]
+mod mbap_parser;
+
+#[cfg(test)]
+mod raft_election;
+
+#[cfg(test)]
+mod raft_log;
+
+#[cfg(test)]
+mod raft_persist;
+
+#[cfg(test)]
+mod quorum_loss;
+
+#[cfg(test)]
+mod vote_strategies;
+
+#[cfg(test)]
+mod sensor_drift;
+
+#[cfg(test)]
+mod suspicion;
+
+#[cfg(test)]
+mod architecture;
+
+#[cfg(test)]
+mod sensor_code;
+
+#[cfg(test)]
+mod session_summary;
+
+#[cfg(test)]
+mod store_snapshot;
+
+#[cfg(test)]
+mod modbus_slave;
+
+#[cfg(test)]
+mod packet_capture;
+
+#[cfg(test)]
+mod pcap_import;
+
+#[cfg(test)]
+mod anomaly_detection;
+
+#[cfg(test)]
+mod flood_rate_limit;
+
+#[cfg(test)]
+mod pipeline_backpressure;
+
+#[cfg(test)]
+mod instance_budget;
+
+#[cfg(test)]
+mod soak_leak;
+
+#[cfg(test)]
+mod quiz_mode;
+
+#[cfg(test)]
+mod mitre_coverage;
+
+#[cfg(test)]
+mod methodology_disclosure;
+
+#[cfg(test)]
+mod mock_host;
+
+#[cfg(test)]
+mod component_backend;
+
+#[cfg(test)]
+mod host_trace;
+
+#[cfg(test)]
+mod timing_channel;
+
+#[cfg(test)]
+mod scenario_library;
+
+#[cfg(test)]
+mod node_history;
+
+#[cfg(test)]
+mod election_replay;
+
+#[cfg(test)]
+mod downtime_ledger;
+
+#[cfg(test)]
+mod warm_spare;
+
+#[cfg(test)]
+mod python_prefork;
+
+#[cfg(test)]
+mod state_resync;
+
+#[cfg(test)]
+mod sensor_environment;
+
+#[cfg(test)]
+mod sensor_fusion;
+
+#[cfg(test)]
+mod actuator_relay;
+
+#[cfg(test)]
+mod incident_timeline;
+
+#[cfg(test)]
+mod log_collapse;
+
+#[cfg(test)]
+mod wasmtime_flags;
@@ -0,0 +1,115 @@
+// what: tests for the simulated modbus slave and the gateway/console exchanges against it
+// why: the demo now claims real request/response cycles; replies, exception codes and silences must follow the spec
+
+use guardian_types::SensorReading;
+use modbus_parser::build_frame;
+use modbus_parser::mbap::{build_adu, parse_adu};
+use modbus_parser::register_map::{decode_packet, TelemetryPacket};
+use modbus_sim::{decode_reply, request, Exception, Reply, BROADCAST_UNIT, FC_READ_COILS, FC_READ_HOLDING_REGISTERS, FC_READ_INPUT_REGISTERS, FC_WRITE_SINGLE_COIL, FC_WRITE_SINGLE_REGISTER};
+use modbus_parser::diode::DiodeRejection;
+use crate::tabs::demo::modbus::{forward, plc_slave, write_telemetry, CONSOLE_REQUESTS, FC_DIAGNOSTICS, PLC_UNIT_ID};
+
+/// sends an rtu request and decodes the reply
+fn ask(function_code: u8, address: u16, quantity: u16) -> Reply {
+    let mut slave = plc_slave();
+    let reply = slave.respond_rtu(&request(PLC_UNIT_ID, function_code, address, quantity)).expect("slave replied");
+    decode_reply(&reply, quantity).unwrap()
+}
+
+#[test]
+fn gateway_write_reads_back_as_the_same_packet() {
+    // what: the fc16 telemetry write is acknowledged and an fc03 read returns the same block
+    // why: the gateway log says the plc holds the voted value; the register table has to agree
+    let mut slave = plc_slave();
+    let packet = TelemetryPacket { timestamp: 1_700_000_000_123, value: 42.3, status: 0 };
+    assert_eq!(write_telemetry(&mut slave, &packet).1, Ok(6));
+    let reply = slave.respond_rtu(&request(PLC_UNIT_ID, FC_READ_HOLDING_REGISTERS, 0, 6)).unwrap();
+    let Ok(Reply::Registers(regs)) = decode_reply(&reply, 6) else { panic!("expected registers") };
+    let decoded = decode_packet(&regs).unwrap();
+    assert_eq!((decoded.timestamp, regs[4]), (packet.timestamp, 423));
+}
+
+#[test]
+fn exceptions_follow_the_spec() {
+    // what: unknown function → 0x01, past the table → 0x02, oversized read or bad coil value → 0x03
+    // why: masters branch on the exception code; a wrong one hides the real fault
+    assert_eq!(ask(FC_DIAGNOSTICS, 0, 0), Reply::Exception(Exception::IllegalFunction));
+    assert_eq!(ask(FC_READ_HOLDING_REGISTERS, 15, 2), Reply::Exception(Exception::IllegalDataAddress));
+    assert_eq!(ask(FC_READ_HOLDING_REGISTERS, 0, 126), Reply::Exception(Exception::IllegalDataValue));
+    assert_eq!(ask(FC_WRITE_SINGLE_COIL, 0, 0x1234), Reply::Exception(Exception::IllegalDataValue));
+    // the exception reply sets the high bit of the function code
+    let reply = plc_slave().respond_rtu(&request(PLC_UNIT_ID, FC_DIAGNOSTICS, 0, 0)).unwrap();
+    assert_eq!(&reply[1..3], &[FC_DIAGNOSTICS | 0x80, 0x01]);
+}
+
+#[test]
+fn coils_pack_lsb_first() {
+    // what: coil 1 and coil 4 on read back as 0b0000_1001 and decode to the same bits
+    // why: bit order inside the byte count is where hand-rolled masters usually go wrong
+    let mut slave = plc_slave();
+    for coil in [0, 3] {
+        slave.respond_rtu(&request(PLC_UNIT_ID, FC_WRITE_SINGLE_COIL, coil, 0xFF00)).unwrap();
+    }
+    let reply = slave.respond_rtu(&request(PLC_UNIT_ID, FC_READ_COILS, 0, 8)).unwrap();
+    assert_eq!(&reply[2..4], &[1, 0b0000_1001]);
+    assert_eq!(decode_reply(&reply, 8).unwrap(), Reply::Coils(vec![true, false, false, true, false, false, false, false]));
+}
+
+#[test]
+fn slave_stays_silent_when_it_should() {
+    // what: bad crc, another unit id and broadcast writes get no reply; the broadcast still executes
+    // why: answering a corrupted or foreign frame would collide with the addressed device on rs485
+    let mut slave = plc_slave();
+    let mut corrupted = request(PLC_UNIT_ID, FC_READ_HOLDING_REGISTERS, 0, 1);
+    *corrupted.last_mut().unwrap() ^= 0x01;
+    assert_eq!(slave.respond_rtu(&corrupted), None);
+    assert_eq!(slave.respond_rtu(&request(PLC_UNIT_ID + 1, FC_READ_HOLDING_REGISTERS, 0, 1)), None);
+    assert_eq!(slave.respond_rtu(&build_frame(BROADCAST_UNIT, 0x06, &[0x00, 0x0A, 0x00, 0x3C])), None);
+    assert_eq!(slave.holding[10], 60);
+}
+
+#[test]
+fn tcp_reply_echoes_the_transaction_id() {
+    // what: an mbap request is answered with an mbap reply carrying the same transaction id
+    // why: modbus/tcp masters match replies to requests by transaction id
+    let mut slave = plc_slave();
+    slave.load_reading(&SensorReading { temperature: 23.5, humidity: 45.2, pressure: 1013.2 });
+    let reply = slave.respond_adu(&build_adu(0x1234, PLC_UNIT_ID, FC_READ_INPUT_REGISTERS, &[0, 0, 0, 3])).unwrap();
+    let frame = parse_adu(&reply).unwrap();
+    assert_eq!(frame.transaction_id, 0x1234);
+    assert_eq!(frame.data[1..], [0x00, 0xEB, 0x01, 0xC4, 0x27, 0x94]);
+}
+
+#[test]
+fn console_requests_cover_replies_exceptions_and_silence() {
+    // what: the canned console requests produce successful replies, all three exception codes, one silence and one dropped write
+    // why: the console is how the demo shows error handling; each case should stay reachable
+    let mut slave = plc_slave();
+    let exchanges: Vec<_> = CONSOLE_REQUESTS.iter().map(|r| r.send(&mut slave)).collect();
+    assert!(exchanges.iter().any(|x| x.level == "success"));
+    for code in [0x01, 0x02, 0x03] {
+        assert!(exchanges.iter().any(|x| x.summary.starts_with(&format!("exception 0x{:02X}", code))), "no exception {:02X}", code);
+    }
+    assert_eq!(exchanges.iter().filter(|x| x.response.is_none() && x.level == "warn").count(), 1);
+    assert_eq!(exchanges.iter().filter(|x| x.summary.starts_with("dropped by the data diode")).count(), 1);
+}
+
+#[test]
+fn gateway_drops_writes_the_diode_rejects() {
+    // what: a zone 3 write to the temperature register and the console's coil write are dropped before the slave sees them;
+    //       the whitelisted fan setpoint is forwarded and acknowledged
+    // why: the compliance tab calls the gateway a data diode; the pipeline that feeds the plc has to enforce it, not just the hardware tab console
+    let mut slave = plc_slave();
+    let overwrite = request(PLC_UNIT_ID, FC_WRITE_SINGLE_REGISTER, 4, 0x7FFF);
+    assert_eq!(forward(&mut slave, &overwrite), Err(DiodeRejection::AddressNotAllowed(4)));
+    assert_eq!(slave.holding[4], 0);
+
+    let coil = CONSOLE_REQUESTS.iter().find(|r| r.function_code == FC_WRITE_SINGLE_COIL).unwrap().send(&mut slave);
+    assert_eq!((coil.response, coil.level), (None, "error"));
+    assert!(coil.summary.contains("not in southbound whitelist"), "{}", coil.summary);
+    assert!(!slave.coils[0]);
+
+    let setpoint = CONSOLE_REQUESTS.iter().find(|r| r.function_code == FC_WRITE_SINGLE_REGISTER).unwrap().send(&mut slave);
+    assert_eq!(setpoint.level, "success");
+    assert_eq!(slave.holding[10], 60);
+}
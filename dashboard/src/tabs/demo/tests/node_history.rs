@@ -0,0 +1,83 @@
+// what: tests for the per-node histories behind the node drawer
+// why: the drawer's vote tallies, uptime and availability are read as facts about each instance; they must add up
+
+use std::collections::VecDeque;
+use crate::tabs::demo::journal::Runtime;
+use crate::tabs::demo::node_history::{format_uptime, NodeHistory, NodeId, NodeStats, VALUES_KEPT};
+use crate::tabs::demo::voting::{VoteOutcome, VoteStrategy, SCENARIO_READING};
+
+#[test]
+fn votes_are_won_by_the_emitted_group() {
+    // what: a trapped instance loses, a spoofed value is outvoted, a halted vote is lost by everyone
+    // why: "votes lost" is how the drawer shows which instance the voter masked
+    let mut stats = NodeStats::new(0.0);
+    let strategy = VoteStrategy::default();
+    stats.record_vote(strategy, &[None, Some(22.0), Some(22.0)]);
+    stats.record_vote(strategy, &[Some(22.0), Some(22.0), Some(26.0)]);
+    stats.record_vote(strategy, &[Some(22.0), None, None]);
+    let tally = |i: usize| (stats.wasm[i].votes_won, stats.wasm[i].votes_lost, stats.wasm[i].produced);
+    assert_eq!(tally(0), (1, 2, 2));
+    assert_eq!(tally(1), (2, 1, 2));
+    assert_eq!(tally(2), (1, 2, 2));
+    assert_eq!(stats.wasm[2].values, VecDeque::from([22.0, 26.0]));
+}
+
+#[test]
+fn winners_match_the_vote_for_every_strategy() {
+    // what: for each strategy, the winners are exactly as many as the vote says agreed
+    // why: winners() re-derives the vote's group; the two must never disagree about who was outvoted
+    let cases: [[Option<f64>; 3]; 4] = [
+        [Some(SCENARIO_READING); 3],
+        [Some(SCENARIO_READING), Some(SCENARIO_READING + 0.03), Some(SCENARIO_READING + 4.0)],
+        [Some(SCENARIO_READING), Some(SCENARIO_READING + 0.03), Some(SCENARIO_READING + 0.35)],
+        [None, Some(SCENARIO_READING), Some(SCENARIO_READING + 4.0)],
+    ];
+    for strategy in VoteStrategy::ALL {
+        for outputs in cases {
+            let won = strategy.winners(&outputs).into_iter().filter(|w| *w).count();
+            let expected = match strategy.vote(&outputs) {
+                VoteOutcome::Consensus { agreeing, .. } => agreeing,
+                VoteOutcome::Halted { .. } => 0,
+            };
+            assert_eq!(won, expected, "{:?} on {:?}", strategy, outputs);
+        }
+    }
+}
+
+#[test]
+fn downtime_counts_once_per_outage() {
+    // what: a second down() keeps the outage start; up() adds it; availability includes an outage still open
+    // why: traps and crashes both mark a node down, sometimes twice for one outage
+    let mut node = NodeHistory::new(0.0);
+    node.down(1_000.0);
+    node.down(1_500.0);
+    assert!(!node.is_up());
+    assert_eq!(node.uptime_ms(2_000.0), 0.0);
+    node.up(3_000.0);
+    node.recovered(1_523.0);
+    assert_eq!(node.uptime_ms(10_000.0), 7_000.0);
+    assert!((node.availability(10_000.0) - 0.8).abs() < 1e-12);
+    node.down(10_000.0);
+    assert!((node.availability(12_000.0) - (1.0 - 4_000.0 / 12_000.0)).abs() < 1e-12);
+    assert_eq!(node.recovery().median, 1_523.0);
+}
+
+#[test]
+fn node_ids_labels_and_caps() {
+    // what: W/I labels, no drawer for the js baseline, values capped at VALUES_KEPT, uptime units
+    // why: the drawer title and value strip have fixed room
+    assert_eq!(NodeId::of(Runtime::Python, 0).unwrap().label(), "W0");
+    assert_eq!(NodeId::of(Runtime::Wasm, 2).unwrap().label(), "I2");
+    assert_eq!(NodeId::of(Runtime::Js, 0), None);
+    assert_eq!(NodeId::of(Runtime::Wasm, 3), None);
+    let mut node = NodeHistory::new(0.0);
+    for i in 0..(VALUES_KEPT + 5) {
+        node.produce(i as f64);
+    }
+    assert_eq!(node.values.len(), VALUES_KEPT);
+    assert_eq!(node.values.front(), Some(&5.0));
+    assert_eq!(node.produced as usize, VALUES_KEPT + 5);
+    assert_eq!(format_uptime(42_000.0), "42s");
+    assert_eq!(format_uptime(187_000.0), "3m 07s");
+    assert_eq!(format_uptime(3_900_000.0), "1h 05m");
+}
@@ -0,0 +1,82 @@
+// what: tests for the packet inspector's capture buffer, field decode and corrupt-and-replay
+// why: the inspector is meant to show exactly what the parser sees; a mislabelled byte would teach the wrong lesson
+
+use modbus_sim::{request, FC_READ_HOLDING_REGISTERS};
+use crate::tabs::demo::capture::{corrupt, decode_fields, replay, Capture, Direction, FieldKind, MAX_FRAMES};
+use crate::tabs::demo::modbus::{plc_slave, CONSOLE_REQUESTS, PLC_UNIT_ID};
+
+fn read_request() -> Vec<u8> {
+    request(PLC_UNIT_ID, FC_READ_HOLDING_REGISTERS, 0, 6)
+}
+
+fn kinds(bytes: &[u8]) -> Vec<(FieldKind, usize, usize)> {
+    decode_fields(bytes).into_iter().map(|f| (f.kind, f.start, f.end)).collect()
+}
+
+#[test]
+fn request_splits_into_address_function_data_crc() {
+    // what: an 8-byte fc03 request decodes as address [0], function [1], data [2..6], valid crc [6..8]
+    // why: the highlight colours are the inspector's whole point
+    assert_eq!(kinds(&read_request()), vec![
+        (FieldKind::Address, 0, 1),
+        (FieldKind::Function, 1, 2),
+        (FieldKind::Data, 2, 6),
+        (FieldKind::Crc, 6, 8),
+    ]);
+}
+
+#[test]
+fn exception_replies_and_runts_are_labelled() {
+    // what: a reply with the high function bit is an exception field, a 3-byte frame is malformed
+    // why: both cases are what the console's error requests and a truncated line produce
+    let mut slave = plc_slave();
+    let reply = slave.respond_rtu(&request(PLC_UNIT_ID, 0x08, 0, 0)).unwrap();
+    assert_eq!(decode_fields(&reply)[1].kind, FieldKind::Exception);
+    assert_eq!(kinds(&[1, 3, 0]), vec![(FieldKind::Malformed, 0, 3)]);
+}
+
+#[test]
+fn corrupted_byte_is_caught_by_the_crc() {
+    // what: flipping any data byte without fixing the crc marks it bad; the parser drops it and the slave stays silent
+    // why: crc-16 catches every single-byte error, which is the robustness claim being demonstrated
+    let mut slave = plc_slave();
+    for index in 0..6 {
+        let outcome = replay(&mut slave, corrupt(&read_request(), index, false));
+        assert!(outcome.parser.is_err(), "byte {} accepted", index);
+        assert_eq!(outcome.reply, None);
+        assert_eq!(decode_fields(&outcome.frame).last().unwrap().kind, FieldKind::BadCrc);
+    }
+}
+
+#[test]
+fn fixed_crc_lets_the_damage_reach_the_decoder() {
+    // what: with the crc recomputed, a flipped function byte parses and the slave answers illegal function;
+    //       a flipped address byte goes to another unit and gets silence
+    // why: shows the layer after the crc check handles garbage with a typed reply, not a crash
+    let mut slave = plc_slave();
+    let function = replay(&mut slave, corrupt(&read_request(), 1, true));
+    assert!(function.parser.is_ok());
+    let reply = function.reply.expect("exception reply");
+    assert_eq!((reply[1] & 0x80, reply[2]), (0x80, 0x01));
+    let address = replay(&mut slave, corrupt(&read_request(), 0, true));
+    assert!(address.parser.is_ok());
+    assert_eq!(address.reply, None);
+}
+
+#[test]
+fn capture_is_bounded_and_keeps_exchanges_paired() {
+    // what: an exchange adds its request and reply in order; the buffer never exceeds MAX_FRAMES
+    // why: the gateway writes on every attack, and run-all must not grow the inspector without bound
+    let mut slave = plc_slave();
+    let mut capture = Capture::default();
+    let exchange = CONSOLE_REQUESTS[0].send(&mut slave);
+    capture.push_exchange(0.0, &exchange);
+    let dirs: Vec<_> = capture.frames.iter().map(|f| f.direction).collect();
+    assert_eq!(dirs, vec![Direction::Request, Direction::Reply]);
+    for i in 0..MAX_FRAMES {
+        capture.push(i as f64, Direction::Request, "test", vec![0; 4]);
+    }
+    assert_eq!(capture.frames.len(), MAX_FRAMES);
+    assert_eq!(capture.frames.first().unwrap().seq, 2);
+    assert!(capture.get(0).is_none());
+}
@@ -0,0 +1,139 @@
+// what: tests for the pcap/pcapng reader and the modbus/tcp decode report
+// why: a practitioner's own capture is the input; container quirks must not change what the parser is shown
+
+use modbus_parser::mbap::MbapError;
+use crate::tabs::demo::pcap::{analyze, sample_capture, Format};
+
+/// link-layer frames of the sample capture
+fn sample_frames() -> Vec<Vec<u8>> {
+    let file = sample_capture();
+    let mut frames = Vec::new();
+    let mut at = 24;
+    while at < file.len() {
+        let len = u32::from_le_bytes(file[at + 8..at + 12].try_into().unwrap()) as usize;
+        frames.push(file[at + 16..at + 16 + len].to_vec());
+        at += 16 + len;
+    }
+    frames
+}
+
+/// classic pcap of ethernet frames in either byte order
+fn pcap(frames: &[Vec<u8>], big_endian: bool) -> Vec<u8> {
+    let u16b = |v: u16| if big_endian { v.to_be_bytes() } else { v.to_le_bytes() };
+    let u32b = |v: u32| if big_endian { v.to_be_bytes() } else { v.to_le_bytes() };
+    let mut file = u32b(0xa1b2c3d4).to_vec();
+    file.extend(u16b(2));
+    file.extend(u16b(4));
+    file.extend([0; 8]);
+    file.extend(u32b(65535));
+    file.extend(u32b(1));
+    for frame in frames {
+        file.extend([0; 8]);
+        file.extend(u32b(frame.len() as u32));
+        file.extend(u32b(frame.len() as u32));
+        file.extend(frame);
+    }
+    file
+}
+
+/// pcapng block with padding and the trailing length
+fn block(kind: u32, body: &[u8]) -> Vec<u8> {
+    let padded = body.len().div_ceil(4) * 4;
+    let total = (12 + padded) as u32;
+    let mut out = kind.to_le_bytes().to_vec();
+    out.extend(total.to_le_bytes());
+    out.extend(body);
+    out.resize(8 + padded, 0);
+    out.extend(total.to_le_bytes());
+    out
+}
+
+/// little-endian pcapng: section header, one ethernet interface, enhanced packet blocks
+fn pcapng(frames: &[Vec<u8>]) -> Vec<u8> {
+    let mut shb = vec![0x4D, 0x3C, 0x2B, 0x1A, 1, 0, 0, 0];
+    shb.extend([0xFF; 8]);
+    let mut file = block(0x0A0D0D0A, &shb);
+    file.extend(block(1, &[1, 0, 0, 0, 0xFF, 0xFF, 0, 0]));
+    for frame in frames {
+        let mut epb = vec![0; 12];
+        epb.extend((frame.len() as u32).to_le_bytes());
+        epb.extend((frame.len() as u32).to_le_bytes());
+        epb.extend(frame);
+        file.extend(block(6, &epb));
+    }
+    file
+}
+
+#[test]
+fn sample_capture_is_summarised() {
+    // what: the sample's 9 packets hold 8 modbus segments and 9 adus (one segment pipelines two); 2 are refused
+    // why: the counts on the panel are the first thing a practitioner checks against wireshark
+    let report = analyze(&sample_capture()).unwrap();
+    assert_eq!((report.format, report.packets, report.modbus_segments, report.adus.len()), (Format::Pcap, 9, 8, 9));
+    assert_eq!((report.accepted(), report.rejected()), (7, 2));
+    assert_eq!(report.function_counts(), vec![(0x01, 1), (0x03, 2), (0x04, 1), (0x06, 2), (0xAB, 1)]);
+    assert_eq!(report.error_counts(), vec![("bad protocol id", 1), ("length underflow", 1)]);
+}
+
+#[test]
+fn anomalies_name_the_packet() {
+    // what: the exception reply, the bad protocol id and the length-overflow adu are flagged with their packet numbers
+    // why: the anomaly list is what points a practitioner back into their capture
+    let report = analyze(&sample_capture()).unwrap();
+    let anomalies: Vec<_> = report.anomalies().into_iter().map(|a| a.packet).collect();
+    assert_eq!(anomalies, vec![6, 7, 8]);
+    assert_eq!(report.adus.last().unwrap().result, Err(MbapError::LengthUnderflow { length: 0 }));
+}
+
+#[test]
+fn byte_order_and_container_do_not_change_the_report() {
+    // what: big-endian pcap and little-endian pcapng of the same frames give the same adus
+    // why: captures come from sparc-era tools, dumpcap and everything in between
+    let frames = sample_frames();
+    let reference = analyze(&sample_capture()).unwrap().adus;
+    assert_eq!(analyze(&pcap(&frames, true)).unwrap().adus, reference);
+    let ng = analyze(&pcapng(&frames)).unwrap();
+    assert_eq!(ng.format, Format::PcapNg);
+    assert_eq!(ng.adus, reference);
+}
+
+#[test]
+fn ethernet_padding_is_not_read_as_modbus() {
+    // what: zero padding after the ip packet is trimmed by the ip total length
+    // why: short frames are padded to 60 bytes on the wire; reading the padding would invent length mismatches
+    let mut frames = sample_frames();
+    for frame in &mut frames {
+        frame.extend([0; 16]);
+    }
+    assert_eq!(analyze(&pcap(&frames, false)).unwrap().adus, analyze(&sample_capture()).unwrap().adus);
+}
+
+#[test]
+fn damaged_files_fail_or_stop_cleanly() {
+    // what: non-capture bytes are an error; a file cut inside a record keeps the packets before it
+    // why: a half-copied capture should still show what it has, never panic
+    assert!(analyze(b"this is not a capture, just some text").is_err());
+    let file = sample_capture();
+    let report = analyze(&file[..file.len() - 10]).unwrap();
+    assert!(report.truncated_file);
+    assert_eq!(report.packets, 8);
+}
+
+#[test]
+fn huge_record_lengths_are_not_followed() {
+    // what: a pcap record claiming 0xFFFF_FFF0 bytes ends the file as truncated; the same length in a pcapng packet is an error
+    // why: on wasm32 the length alone overflows usize, and a corrupt header must never panic the tab
+    let frames = sample_frames();
+    let mut file = pcap(&frames[..2], false);
+    let record = file.len();
+    file.extend(pcap(&frames[2..3], false)[24..].iter().copied());
+    file[record + 8..record + 12].copy_from_slice(&0xFFFF_FFF0u32.to_le_bytes());
+    let report = analyze(&file).unwrap();
+    assert!(report.truncated_file);
+    assert_eq!(report.packets, 2);
+
+    let mut ng = pcapng(&frames[..1]);
+    let cap_at = ng.len() - (12 + frames[0].len().div_ceil(4) * 4 + 20) + 8 + 12;
+    ng[cap_at..cap_at + 4].copy_from_slice(&0xFFFF_FFF0u32.to_le_bytes());
+    assert!(analyze(&ng).unwrap_err().contains("overruns its block"));
+}
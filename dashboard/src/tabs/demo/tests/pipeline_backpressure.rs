@@ -0,0 +1,58 @@
+// what: tests for the bounded gateway pipeline and the python oom it prevents
+// why: the stage gauges, the backpressure journal entry and the python crash in the flood run all come from these mechanics
+
+use crate::tabs::demo::flood::{simulate, DEFAULT_PYTHON_FRAME_MS, DEFAULT_WASM_FRAME_MS, PYTHON_HEAP_BYTES};
+use crate::tabs::demo::pipeline::{Pipeline, StageId, StageSignal, STAGE_CAPACITY};
+use crate::tabs::demo::rate_limit::RateLimit;
+
+#[test]
+fn slow_stage_fills_upstream_and_ingress_refuses() {
+    // what: with publish slowed, every bounded stage fills and ingress refuses, naming publish as the origin
+    // why: backpressure has to reach ingress, or the full stage would just drop frames silently
+    let mut pipeline = Pipeline::bounded(Pipeline::costs(0.05, Some(StageId::Publish)), STAGE_CAPACITY);
+    let refused: u64 = (0..40).map(|_| pipeline.step(100, 50.0).refused).sum();
+    assert!(refused > 0);
+    assert_eq!(pipeline.depths(), STAGE_CAPACITY);
+    assert_eq!(pipeline.signals()[StageId::Publish as usize], StageSignal::Full);
+    assert_eq!(pipeline.congestion().map(|b| b.origin), Some(StageId::Publish));
+    assert!(pipeline.congestion().unwrap().to_string().contains("backpressure from publish"));
+}
+
+#[test]
+fn frames_are_conserved_through_the_stages() {
+    // what: admitted = delivered + still queued, step after step
+    // why: a stage must never lose or duplicate a frame while handing on
+    let mut pipeline = Pipeline::bounded(Pipeline::costs(0.5, None), STAGE_CAPACITY);
+    let (mut admitted, mut delivered) = (0, 0);
+    for step in 0..60 {
+        let r = pipeline.step(if step < 30 { 150 } else { 0 }, 50.0);
+        admitted += r.admitted;
+        delivered += r.delivered;
+        assert_eq!(admitted, delivered + pipeline.queued());
+    }
+}
+
+#[test]
+fn unbounded_pipeline_never_refuses() {
+    // what: without capacities ingress takes everything and the queue just grows
+    // why: the python side of the comparison - no refusals, unbounded memory
+    let mut pipeline = Pipeline::unbounded(Pipeline::costs(1.25, None));
+    let refused: u64 = (0..20).map(|_| pipeline.step(250, 50.0).refused).sum();
+    assert_eq!(refused, 0);
+    let queued = pipeline.queued();
+    assert!(queued > 4000);
+    assert_eq!(pipeline.clear(), queued);
+    assert_eq!(pipeline.queued(), 0);
+}
+
+#[test]
+fn python_ooms_during_the_flood_and_wasm_memory_stays_bounded() {
+    // what: the python worker exceeds its heap and loses frames; the wasm stages never hold more than their capacity
+    // why: the supervisor's claim - one slow stage cannot take the gateway's memory with it
+    let samples = simulate(RateLimit::default(), DEFAULT_PYTHON_FRAME_MS, DEFAULT_WASM_FRAME_MS, Some(StageId::Publish));
+    let oom = samples.iter().find(|s| s.python_oom).expect("python ooms");
+    assert!(oom.python_memory_bytes > PYTHON_HEAP_BYTES && oom.python_queue == 0);
+    assert!(samples.last().unwrap().python_lost > 0);
+    assert!(samples.iter().all(|s| s.wasm_queue <= STAGE_CAPACITY.iter().sum::<u64>()));
+    assert!(samples.last().unwrap().wasm_backpressured > 0);
+}
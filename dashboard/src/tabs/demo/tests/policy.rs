@@ -0,0 +1,106 @@
+// what: tests for capability policy parsing and deny-by-default evaluation
+// why: attack outcomes in the demo are driven by this policy, so the grants must be exact
+
+use attack_code::{ATTACK_ENV_THEFT, ATTACK_LATERAL_MOVEMENT};
+use crate::tabs::demo::attacks::{GATEWAY_ENV, LATERAL_TARGETS};
+use crate::tabs::demo::policy::{attack_request, CapabilityPolicy, CapabilityRequest, WORKER_WORLD};
+
+#[test]
+fn shipped_policy_parses() {
+    // what: wit/policy.toml parses and contains the worker world
+    // why: a broken policy file would panic at startup
+    let policy = CapabilityPolicy::load_default();
+    assert!(policy.world(WORKER_WORLD).is_some());
+}
+
+#[test]
+fn shipped_policy_denies_all_security_attacks() {
+    // what: default grants block memory spray, exfil and traversal
+    // why: the demo narrative assumes every security attack traps out of the box
+    let policy = CapabilityPolicy::load_default();
+    for attack in ["bufferOverflow", "dataExfil", "pathTraversal", "envTheft", "lateralMovement"] {
+        let request = attack_request(attack).unwrap();
+        assert!(policy.evaluate(WORKER_WORLD, &request).is_err(), "{} should be denied", attack);
+    }
+}
+
+#[test]
+fn granting_socket_allows_exfil() {
+    // what: adding the attacker address to sockets lets dataExfil through
+    // why: proves the outcome is configuration-driven, not hardcoded
+    let policy = CapabilityPolicy::from_toml(
+        "[[world]]\nname = \"sensor-node\"\nsockets = [\"203.0.113.66:443\"]\n",
+    ).unwrap();
+    let request = attack_request("dataExfil").unwrap();
+    assert!(policy.evaluate(WORKER_WORLD, &request).is_ok());
+}
+
+#[test]
+fn directory_grant_covers_children_only() {
+    // what: granting /etc allows /etc/passwd, granting /dev/i2c-1 does not; sibling names sharing a prefix and ".." climbs are denied
+    // why: preopen semantics are scoped to the directory tree like wasmtime --dir, not to a string prefix
+    let policy = CapabilityPolicy::from_toml(
+        "[[world]]\nname = \"sensor-node\"\ndirectories = [{ path = \"/etc\" }]\n",
+    ).unwrap();
+    assert!(policy.evaluate(WORKER_WORLD, &CapabilityRequest::Directory("/etc/passwd")).is_ok());
+    assert!(policy.evaluate(WORKER_WORLD, &CapabilityRequest::Directory("/etcfoo")).is_err());
+    let default = CapabilityPolicy::load_default();
+    assert!(default.evaluate(WORKER_WORLD, &CapabilityRequest::Directory("/etc/passwd")).is_err());
+    assert!(default.evaluate(WORKER_WORLD, &CapabilityRequest::Directory("/dev/i2c-1")).is_ok());
+    assert!(default.evaluate(WORKER_WORLD, &CapabilityRequest::Directory("/dev/i2c-10")).is_err());
+    assert!(default.evaluate(WORKER_WORLD, &CapabilityRequest::Directory("/dev/i2c-1/../../etc/passwd")).is_err());
+}
+
+#[test]
+fn unknown_world_denied_everything() {
+    // what: evaluating a world missing from the policy returns an error
+    // why: deny-by-default must hold even for typos in world names
+    let policy = CapabilityPolicy::load_default();
+    assert!(policy.evaluate("rogue-world", &CapabilityRequest::Register(4)).is_err());
+}
+
+#[test]
+fn invalid_toml_reports_error() {
+    // what: malformed policy text returns Err instead of panicking
+    // why: the live editor feeds half-typed text into the parser
+    assert!(CapabilityPolicy::from_toml("[[world]\nname = ").is_err());
+}
+
+#[test]
+fn env_diff_shows_only_passed_config() {
+    // what: the worker world sees SENSOR_TYPE and POLL_INTERVAL_MS, none of the planted secrets
+    // why: the env-theft diff is the ambient-authority contrast with python's os.environ
+    let policy = CapabilityPolicy::load_default();
+    let diff = policy.world(WORKER_WORLD).unwrap().env_diff(&GATEWAY_ENV);
+    let passed: Vec<&str> = diff.iter().filter(|(_, p)| *p).map(|(k, _)| *k).collect();
+    assert_eq!(passed, ["SENSOR_TYPE", "POLL_INTERVAL_MS"]);
+    assert_eq!(diff.len(), GATEWAY_ENV.len());
+}
+
+#[test]
+fn passing_the_secret_lets_env_theft_through() {
+    // what: an --env grant for the secret key flips the decision, and python plants every variable
+    // why: the env outcome is policy-driven and both runtimes start from the same environment
+    let policy = CapabilityPolicy::from_toml(
+        "[[world]]\nname = \"sensor-node\"\nenv = [\"AWS_SECRET_ACCESS_KEY=x\"]\n",
+    ).unwrap();
+    assert!(policy.evaluate(WORKER_WORLD, &attack_request("envTheft").unwrap()).is_ok());
+    for (key, value) in GATEWAY_ENV {
+        assert!(ATTACK_ENV_THEFT.contains(&format!("\"{}\": \"{}\"", key, value)), "{} not planted", key);
+    }
+}
+
+#[test]
+fn lateral_targets_need_socket_grants() {
+    // what: shipped policy denies every internal host; a wildcard socket grant admits them all
+    // why: the wasm column states the wasmtime decision for the same hosts python probes for real
+    let shipped = CapabilityPolicy::load_default();
+    let open = CapabilityPolicy::from_toml("[[world]]\nname = \"sensor-node\"\nsockets = [\"*\"]\n").unwrap();
+    for (kind, addr) in LATERAL_TARGETS {
+        let request = CapabilityRequest::Socket(addr);
+        assert!(shipped.evaluate(WORKER_WORLD, &request).is_err(), "{} should be denied", addr);
+        assert!(open.evaluate(WORKER_WORLD, &request).is_ok());
+        let scheme = if kind == "websocket" { "ws" } else { "http" };
+        assert!(ATTACK_LATERAL_MOVEMENT.contains(&format!("\"{}\", \"{}://{}/\"", kind, scheme, addr)), "{} not probed", addr);
+    }
+}
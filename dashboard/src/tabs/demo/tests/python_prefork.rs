@@ -0,0 +1,43 @@
+// what: tests for the python worker pool strategies
+// why: the pre-forked figures are what wasm is compared against; they must be a real best case, not a strawman or a free lunch
+
+use crate::tabs::demo::prefork::{takeover, PythonStrategy, DEFAULT_RESYNC_MS, HEARTBEAT_MS};
+
+#[test]
+fn cold_start_loses_the_whole_restart() {
+    // what: the default strategy keeps output down for the full cold start, standbys or not
+    // why: this is the naive deployment the demo compared against before the toggle existed
+    assert_eq!(PythonStrategy::default(), PythonStrategy::ColdStart);
+    let t = takeover(PythonStrategy::ColdStart, 2, 1_500, 12.0);
+    assert_eq!((t.used, t.output_gap_ms, t.pool_restored_ms), (PythonStrategy::ColdStart, 1_500, 1_500));
+}
+
+#[test]
+fn pre_forked_gap_is_heartbeat_plus_measured_resync() {
+    // what: a standby takes over after one heartbeat and one sensor run (rounded up); the pool is whole after the cold start
+    // why: the re-sync comes from the session's measured python run, so the best case stays tied to this browser
+    let t = takeover(PythonStrategy::PreForked, 2, 1_500, 11.2);
+    assert_eq!((t.used, t.output_gap_ms, t.pool_restored_ms), (PythonStrategy::PreForked, HEARTBEAT_MS + 12, 1_500));
+    // a failed or missing sensor run (-1 / 0) uses the default re-sync
+    assert_eq!(takeover(PythonStrategy::PreForked, 1, 1_500, -1.0).output_gap_ms, HEARTBEAT_MS + DEFAULT_RESYNC_MS);
+}
+
+#[test]
+fn no_standby_left_means_a_cold_start() {
+    // what: with every other worker down, pre-forked falls back to the cold start
+    // why: back-to-back crashes exhaust the standbys; the model must not promise a takeover nobody can do
+    let t = takeover(PythonStrategy::PreForked, 0, 1_800, 10.0);
+    assert_eq!((t.used, t.output_gap_ms), (PythonStrategy::ColdStart, 1_800));
+}
+
+#[test]
+fn pool_is_never_restored_before_output_resumes() {
+    // what: restore time is at least the gap, and strategy ids round-trip through the select
+    // why: a fast machine with a tiny cold start must not show workers back before the takeover finished
+    let t = takeover(PythonStrategy::PreForked, 2, 50, 80.0);
+    assert!(t.pool_restored_ms >= t.output_gap_ms);
+    for s in PythonStrategy::ALL {
+        assert_eq!(PythonStrategy::from_id(s.id()), Some(s));
+    }
+    assert_eq!(PythonStrategy::from_id("gunicorn"), None);
+}
@@ -0,0 +1,85 @@
+// what: tests for quiz mode's question flow and scoring against journalled runs
+// why: the score is the point of the exercise; marking a right guess wrong (or scoring an older run) teaches the wrong lesson
+
+use crate::tabs::demo::journal::{AuditEvent, EventKind, Runtime};
+use crate::tabs::demo::quiz::{Answer, Quiz};
+
+const T0: f64 = 1_768_383_000_000.0;
+
+/// a buffer overflow run: python crashes, wasm traps
+fn overflow_run(at: f64) -> Vec<AuditEvent> {
+    vec![
+        AuditEvent::new(at, EventKind::AttackStarted, None, "bufferOverflow", "Buffer Overflow"),
+        AuditEvent::new(at + 10.0, EventKind::Trap, Some(Runtime::Wasm), "bufferOverflow", "I1: memory.grow denied"),
+        AuditEvent::new(at + 20.0, EventKind::Crash, Some(Runtime::Python), "bufferOverflow", "W0 crashed"),
+    ]
+}
+
+#[test]
+fn faults_map_to_answers() {
+    // what: each journalled fault kind stands for the answer a viewer would pick
+    // why: a trap filed under "goes down" would mark every correct wasm guess wrong
+    assert_eq!(Answer::from_fault(Some(EventKind::Crash)), Answer::Fails);
+    assert_eq!(Answer::from_fault(Some(EventKind::QuorumLost)), Answer::Fails);
+    assert_eq!(Answer::from_fault(Some(EventKind::Trap)), Answer::Contained);
+    assert_eq!(Answer::from_fault(Some(EventKind::Outvoted)), Answer::Contained);
+    assert_eq!(Answer::from_fault(Some(EventKind::PolicyBreach)), Answer::Breached);
+    assert_eq!(Answer::from_fault(None), Answer::KeepsRunning);
+}
+
+#[test]
+fn lock_needs_an_open_question() {
+    // what: a guess locks only while a form is open, and hands back the attack to run
+    // why: a stray lock with nothing asked must not launch an attack
+    let mut quiz = Quiz { enabled: true, ..Quiz::default() };
+    assert_eq!(quiz.lock([Answer::Fails, Answer::Contained], T0), None);
+    quiz.ask("bufferOverflow");
+    assert_eq!(quiz.lock([Answer::Fails, Answer::Contained], T0).as_deref(), Some("bufferOverflow"));
+    assert!(quiz.asking.is_none());
+    assert_eq!(quiz.pending.as_ref().map(|p| p.attack.as_str()), Some("bufferOverflow"));
+}
+
+#[test]
+fn score_uses_only_the_run_after_the_guess() {
+    // what: an earlier run of the same attack with a different outcome is ignored
+    // why: the guess is about the run it launched, not whatever the journal saw before
+    let mut events = vec![
+        AuditEvent::new(T0 - 5_000.0, EventKind::AttackStarted, None, "bufferOverflow", "Buffer Overflow"),
+        AuditEvent::new(T0 - 4_990.0, EventKind::PolicyBreach, Some(Runtime::Wasm), "bufferOverflow", "granted"),
+    ];
+    events.extend(overflow_run(T0));
+    let mut quiz = Quiz::default();
+    quiz.ask("bufferOverflow");
+    quiz.lock([Answer::Fails, Answer::Contained], T0);
+    let result = quiz.score(&events).expect("scored").clone();
+    assert_eq!(result.name, "Buffer Overflow");
+    assert_eq!(result.actual, [Answer::Fails, Answer::Contained]);
+    assert_eq!(result.right(), 2);
+    assert!(quiz.pending.is_none());
+}
+
+#[test]
+fn a_run_that_never_started_drops_the_guess() {
+    // what: with no AttackStarted after the lock, the guess is discarded unscored
+    // why: a blocked or cancelled run would otherwise count as "keeps running" and skew the score
+    let mut quiz = Quiz::default();
+    quiz.ask("bufferOverflow");
+    quiz.lock([Answer::KeepsRunning, Answer::KeepsRunning], T0 + 1.0);
+    assert!(quiz.score(&overflow_run(T0)).is_none());
+    assert!(quiz.pending.is_none());
+    assert!(quiz.results.is_empty());
+}
+
+#[test]
+fn totals_count_both_runtimes_and_restart_keeps_the_mode() {
+    // what: each question is worth two points; restart clears them but leaves quiz mode on
+    // why: a facilitator restarting for the next group should not have to re-enable it
+    let mut quiz = Quiz { enabled: true, ..Quiz::default() };
+    quiz.ask("bufferOverflow");
+    quiz.lock([Answer::Fails, Answer::Fails], T0);
+    quiz.score(&overflow_run(T0));
+    assert_eq!(quiz.total(), (1, 2));
+    quiz.restart();
+    assert_eq!(quiz.total(), (0, 0));
+    assert!(quiz.enabled);
+}
@@ -0,0 +1,67 @@
+// what: tests for the 2oo3 voter and the degraded (no quorum) safe state
+// why: losing two of three nodes must halt output and block elections, not continue on one unchecked value
+
+use leptos::*;
+use crate::settings::Settings;
+use crate::tabs::demo::raft::ReplicatedLog;
+use crate::tabs::demo::store::DemoStore;
+use crate::tabs::demo::types::InstanceState;
+use crate::tabs::demo::voting::{has_quorum, outputs, VoteOutcome, VoteStrategy};
+
+#[test]
+fn one_healthy_instance_halts_the_voter() {
+    // what: with two instances faulty the voter emits no value and reports one healthy output
+    // why: the real voter behind the one_healthy_two_faulty_fails rule
+    let states = [InstanceState::Faulty, InstanceState::Healthy, InstanceState::Faulty];
+    assert!(!has_quorum(&states));
+    assert_eq!(VoteStrategy::Exact.vote(&outputs(&states, 42.1)), VoteOutcome::Halted { healthy: 1 });
+    assert_eq!(VoteStrategy::Exact.vote(&outputs(&states, 42.1)).value(), None);
+}
+
+#[test]
+fn one_trapped_instance_is_outvoted() {
+    // what: two matching outputs beat a trapped instance
+    // why: the normal masking case must still produce the reading
+    let states = [InstanceState::Healthy, InstanceState::Faulty, InstanceState::Healthy];
+    assert_eq!(VoteStrategy::Exact.vote(&outputs(&states, 42.1)), VoteOutcome::Consensus { value: 42.1, agreeing: 2 });
+}
+
+#[test]
+fn three_different_outputs_have_no_majority() {
+    // what: three live instances that all disagree still halt
+    // why: quorum is about agreeing outputs, not live instances
+    assert_eq!(VoteStrategy::Exact.vote(&[Some(1.0), Some(2.0), Some(3.0)]), VoteOutcome::Halted { healthy: 3 });
+}
+
+#[test]
+fn lone_survivor_cannot_elect_or_commit() {
+    // what: after the leader and a follower crash, an election fails and appends are refused
+    // why: a minority must never accept writes (no split brain after the nodes return)
+    let mut log = ReplicatedLog::default();
+    log.append(21.0);
+    log.crash(0);
+    log.crash(1);
+    assert_eq!(log.elect(2, 1), None);
+    assert_eq!(log.append(22.0), None);
+    assert_eq!(log.nodes[2].commit_index, 1);
+    // restoring the nodes elects a leader again and writes resume
+    assert!(log.recover().is_some());
+    assert_eq!(log.append(22.0), Some(2));
+}
+
+#[test]
+fn reset_leaves_degraded_mode() {
+    // what: reset marks every instance healthy and restarts crashed raft nodes
+    // why: the demo must not stay halted with a log whose leader is down
+    let store = DemoStore::new(create_rw_signal(Settings::default()));
+    store.set_instance(0, InstanceState::Faulty);
+    store.set_instance(1, InstanceState::Faulty);
+    store.cluster.log.update(|l| { l.crash(0); l.crash(1); });
+    assert!(!store.has_quorum());
+
+    store.reset();
+
+    assert!(store.has_quorum());
+    assert!(store.cluster.log.with(|l| l.nodes.iter().all(|n| n.up)));
+    assert_eq!(store.cluster.leader_id.get() as usize, store.cluster.log.with(|l| l.leader));
+}
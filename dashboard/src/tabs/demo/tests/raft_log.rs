@@ -0,0 +1,89 @@
+// what: tests for the replicated telemetry log (commit, compaction, catch-up, election restriction)
+// why: the log viewer claims raft semantics, so the safety rules have to hold in the model
+
+use crate::tabs::demo::raft::{ReplicatedLog, SNAPSHOT_EVERY};
+
+fn log_with(commits: usize) -> ReplicatedLog {
+    let mut log = ReplicatedLog::default();
+    for n in 0..commits {
+        log.append(20.0 + n as f64);
+    }
+    log
+}
+
+#[test]
+fn commits_replicate_to_every_live_node() {
+    // what: each append lands on all three nodes and commits at the new index
+    // why: a healthy cluster keeps identical logs
+    let mut log = ReplicatedLog::default();
+    assert_eq!(log.append(21.5), Some(1));
+    assert_eq!(log.append(21.7), Some(2));
+    assert!(log.nodes.iter().all(|n| n.last_index() == 2 && n.commit_index == 2));
+}
+
+#[test]
+fn committed_entries_compact_into_a_snapshot() {
+    // what: after SNAPSHOT_EVERY commits the prefix becomes a snapshot holding the last applied value
+    // why: the log must not grow forever in a long session
+    let log = log_with(SNAPSHOT_EVERY as usize + 2);
+    for node in &log.nodes {
+        assert_eq!(node.snapshot.last_index, SNAPSHOT_EVERY);
+        assert_eq!(node.snapshot.applied, SNAPSHOT_EVERY);
+        assert_eq!(node.snapshot.last_value, 20.0 + (SNAPSHOT_EVERY - 1) as f64);
+        assert_eq!(node.entries.len(), 2);
+    }
+}
+
+#[test]
+fn new_leader_keeps_the_committed_prefix() {
+    // what: after the leader crashes, the elected survivor has every committed entry and keeps committing in a new term
+    // why: raft's leader completeness property is what makes failover safe
+    let mut log = log_with(3);
+    log.crash(0);
+    let leader = log.elect(2, 1).unwrap();
+    assert_eq!(leader, 2);
+    assert_eq!(log.term, 2);
+    assert_eq!(log.append(30.0), Some(4));
+    assert_eq!(log.nodes[1].commit_index, 4);
+    assert_eq!(log.nodes[0].last_index(), 3, "crashed node is frozen");
+}
+
+#[test]
+fn stale_candidate_cannot_win() {
+    // what: a node that missed commits while down loses to the up-to-date survivor even when it timed out first
+    // why: election restriction (§5.4.1) - a stale leader would overwrite committed data
+    let mut log = log_with(1);
+    log.crash(2);
+    log.append(22.0);
+    // node 2 comes back without being caught up (no leader contact yet), then the leader dies
+    log.nodes[2].up = true;
+    log.crash(0);
+    assert_eq!(log.elect(2, 1), Some(1));
+}
+
+#[test]
+fn lagging_follower_gets_the_snapshot() {
+    // what: a node down across a compaction is caught up with installsnapshot plus the tail
+    // why: the leader no longer has the compacted entries to send one by one
+    let mut log = log_with(1);
+    log.crash(2);
+    for n in 0..SNAPSHOT_EVERY + 1 {
+        log.append(30.0 + n as f64);
+    }
+    log.restart(2);
+    let (leader, rejoined) = (&log.nodes[0], &log.nodes[2]);
+    assert_eq!(log.snapshots_installed, 1);
+    assert_eq!(rejoined.snapshot, leader.snapshot);
+    assert_eq!(rejoined.entries, leader.entries);
+    assert_eq!(rejoined.commit_index, leader.commit_index);
+}
+
+#[test]
+fn no_quorum_no_leader() {
+    // what: with two of three nodes down no election succeeds
+    // why: a minority must never accept writes
+    let mut log = log_with(1);
+    log.crash(0);
+    log.crash(1);
+    assert_eq!(log.elect(2, 1), None);
+}
@@ -0,0 +1,66 @@
+// what: tests for persisting raft state (json round trip, validation, restart-from-disk recovery)
+// why: a reload must bring back the same terms, votes and logs, and recovery must still follow raft rules
+
+use crate::tabs::demo::raft::{ReplicatedLog, CLUSTER_SIZE};
+
+fn log_with(commits: usize) -> ReplicatedLog {
+    let mut log = ReplicatedLog::default();
+    for n in 0..commits {
+        log.append(20.0 + n as f64);
+    }
+    log
+}
+
+#[test]
+fn persisted_state_round_trips() {
+    // what: to_json then from_json returns an identical cluster, including terms and votes
+    // why: anything lost here is lost on every reload
+    let mut log = log_with(3);
+    log.crash(0);
+    log.elect(1, 2);
+    log.append(30.0);
+    let restored = ReplicatedLog::from_json(&log.to_json()).unwrap();
+    assert_eq!(restored, log);
+    assert_eq!(restored.nodes[1].voted_for, Some(1));
+}
+
+#[test]
+fn wrong_cluster_shape_is_rejected() {
+    // what: a saved state with the wrong node count (or garbage) is refused
+    // why: indexing nodes by id would panic on a malformed record
+    let mut log = ReplicatedLog::default();
+    log.nodes.pop();
+    assert!(ReplicatedLog::from_json(&log.to_json()).is_err());
+    assert!(ReplicatedLog::from_json("not json").is_err());
+}
+
+#[test]
+fn recovery_elects_up_to_date_node_in_a_new_term() {
+    // what: restarting every node from disk moves past the highest persisted term and the winner has the longest log
+    // why: a reload is a whole-cluster restart, so the election restriction still applies
+    let mut log = log_with(2);
+    log.crash(2);
+    log.append(25.0);
+    log.crash(0);
+    log.crash(1);
+    let persisted_term = log.term;
+    let mut restored = ReplicatedLog::from_json(&log.to_json()).unwrap();
+    let leader = restored.recover().unwrap();
+    assert_ne!(leader, 2, "stale node must not win");
+    assert!(restored.term > persisted_term);
+    assert!(restored.nodes.iter().all(|n| n.up && n.last_index() == 3 && n.term == restored.term));
+    assert_eq!(restored.nodes.len(), CLUSTER_SIZE);
+}
+
+#[test]
+fn restarted_node_adopts_leader_term() {
+    // what: a node that slept through an election takes the leader's term and clears its vote
+    // why: currentTerm must never go backwards relative to the cluster
+    let mut log = log_with(1);
+    log.crash(2);
+    log.elect(0, 3);
+    assert!(log.nodes[2].term < log.term);
+    log.restart(2);
+    assert_eq!(log.nodes[2].term, log.term);
+    assert_eq!(log.nodes[2].voted_for, None);
+}
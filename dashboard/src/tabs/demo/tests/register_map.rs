@@ -0,0 +1,57 @@
+// what: tests for telemetry to modbus holding register encoding
+// why: the plc reads these registers directly, so scaling and word order must be exact
+
+use modbus_parser::register_map::{
+    decode_packet, encode_packet, holding_address, register_def, scale_to_register, TelemetryPacket,
+};
+
+#[test]
+fn value_register_scaled_by_ten() {
+    // what: 42.3°C should encode as 423 in the value register
+    // why: s7-1200 ladder logic divides by 10, any other scale shows wrong temperature
+    let packet = TelemetryPacket { timestamp: 0, value: 42.3, status: 0 };
+    let regs = encode_packet(&packet);
+    assert_eq!(regs[4], 423);
+}
+
+#[test]
+fn negative_value_uses_twos_complement() {
+    // what: -5.0°C should encode as 0xFFCE (-50 as i16)
+    // why: cold-site deployments read signed registers
+    let regs = encode_packet(&TelemetryPacket { timestamp: 0, value: -5.0, status: 0 });
+    assert_eq!(regs[4], (-50i16) as u16);
+}
+
+#[test]
+fn out_of_range_value_saturates() {
+    // what: values beyond i16 range clamp instead of wrapping
+    // why: a wrapped register would flip a hot reading to a cold one
+    assert_eq!(scale_to_register(1_000_000.0, 10.0), i16::MAX as u16);
+    assert_eq!(scale_to_register(-1_000_000.0, 10.0), i16::MIN as u16);
+}
+
+#[test]
+fn packet_roundtrips_through_registers() {
+    // what: encode then decode returns the same packet (within scale precision)
+    // why: gateway and plc must agree on word order for the 64-bit timestamp
+    let packet = TelemetryPacket { timestamp: 1_700_000_000_123, value: 23.5, status: 2 };
+    let decoded = decode_packet(&encode_packet(&packet)).unwrap();
+    assert_eq!(decoded.timestamp, packet.timestamp);
+    assert!((decoded.value - packet.value).abs() < 0.05);
+    assert_eq!(decoded.status, packet.status);
+}
+
+#[test]
+fn short_register_block_rejected() {
+    // what: decoding fewer than 6 registers returns an error, not a panic
+    // why: partial modbus responses happen on noisy rs485 lines
+    assert!(decode_packet(&[0, 0, 0]).is_err());
+}
+
+#[test]
+fn value_register_address_is_40005() {
+    // what: the value field sits at holding register 40005
+    // why: matches the address configured in tia portal
+    let def = register_def("value").unwrap();
+    assert_eq!(holding_address(def.offset), 40005);
+}
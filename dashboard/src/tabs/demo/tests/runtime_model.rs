@@ -0,0 +1,50 @@
+// what: tests for the RuntimeModel implementations behind the shared RuntimePanel
+// why: both columns render from these models, so labels and counters must map correctly
+
+use leptos::*;
+use crate::settings::Settings;
+use crate::tabs::demo::journal::{AuditEvent, EventKind, Runtime};
+use crate::tabs::demo::runtime_model::model;
+use crate::tabs::demo::store::DemoStore;
+
+fn store() -> DemoStore {
+    DemoStore::new(create_rw_signal(Settings::default()))
+}
+
+#[test]
+fn model_matches_requested_runtime() {
+    // what: model(kind) returns the implementation for that runtime with its own terminal id
+    // why: RuntimePanel only receives the kind
+    for kind in [Runtime::Python, Runtime::Wasm] {
+        let m = model(kind);
+        assert_eq!(m.runtime(), kind);
+        assert!(m.terminal_id().starts_with(kind.as_str()));
+    }
+}
+
+#[test]
+fn memory_badge_scales_with_nodes() {
+    // what: totals are per-node memory times three nodes
+    // why: the badges used to be hard-coded strings that could drift from the tooltip
+    assert_eq!(model(Runtime::Python).memory_total_mb(), 135);
+    assert_eq!(model(Runtime::Wasm).memory_total_mb(), 6);
+}
+
+#[test]
+fn stats_and_status_read_store() {
+    // what: stat cells and the status badge reflect the store slices; downtime comes from the journal's incidents
+    // why: a respawning python pool must show as degraded, wasm never does
+    let store = store();
+    store.stats.python_crashed.set(2);
+    store.journal.set(vec![
+        AuditEvent::new(0.0, EventKind::Crash, Some(Runtime::Python), "killLeader", "W0 crashed"),
+        AuditEvent::new(1500.0, EventKind::Recovery, Some(Runtime::Python), "killLeader", "W0 respawned").with_duration(1500.0),
+    ]);
+    store.pool.python_restarting.set(true);
+    let py = model(Runtime::Python).stats(&store);
+    assert_eq!(py[1].value, "2");
+    assert_eq!(py[2].value, "1500ms");
+    assert!(model(Runtime::Python).status(&store).1);
+    assert!(!model(Runtime::Wasm).status(&store).1);
+    assert_eq!(model(Runtime::Wasm).stats(&store)[2].value, "0ms");
+}
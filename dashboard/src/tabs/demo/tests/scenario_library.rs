@@ -0,0 +1,92 @@
+// what: tests for the user scenario library (validation, json import/export, merge, run-all order, expectation check)
+// why: the library is shared as json between people; a bad file must be refused whole and a good one must run exactly as saved
+
+use attack_code::get_attack_code;
+use crate::tabs::demo::handlers::{run_all_sequence, run_all_with};
+use crate::tabs::demo::journal::{AuditEvent, EventKind, Runtime};
+use crate::tabs::demo::library::{scenario_id, ScenarioKind, ScenarioLibrary, UserScenario};
+use crate::tabs::demo::quiz::Answer;
+use crate::tabs::demo::summary::outcomes;
+
+fn library_with(names: &[(&str, &str)]) -> ScenarioLibrary {
+    let mut library = ScenarioLibrary::default();
+    for (name, base) in names {
+        let mut scenario = UserScenario::from_base(library.fresh_id(name), base, get_attack_code(base));
+        scenario.name = name.to_string();
+        library.upsert(scenario).unwrap();
+    }
+    library
+}
+
+#[test]
+fn library_round_trips_and_bad_files_are_refused() {
+    // what: to_json then from_json gives the same library; unknown bases, payloads on fault injections and repeated ids are rejected
+    // why: an export is the sharing format - it must come back identical, and a malformed entry must not half-import
+    let library = library_with(&[("Heap spray", "bufferOverflow"), ("Split brain", "killLeader")]);
+    assert_eq!(ScenarioLibrary::from_json(&library.to_json()).unwrap(), library);
+    assert_eq!(library.scenarios[0].kind(), ScenarioKind::Attack);
+    assert!(!library.scenarios[0].code.is_empty(), "an attack starts from the built-in payload");
+    assert_eq!(library.scenarios[1].kind(), ScenarioKind::FaultInjection);
+    assert!(library.scenarios[1].code.is_empty(), "a fault injection has no payload");
+
+    let mut bad = library.clone();
+    bad.scenarios[0].base = "rmRf".into();
+    assert!(ScenarioLibrary::from_json(&bad.to_json()).is_err());
+    let mut bad = library.clone();
+    bad.scenarios[1].code = "import os".into();
+    assert!(ScenarioLibrary::from_json(&bad.to_json()).is_err());
+    let mut bad = library.clone();
+    bad.scenarios[1].id = bad.scenarios[0].id.clone();
+    assert!(ScenarioLibrary::from_json(&bad.to_json()).is_err());
+    assert!(ScenarioLibrary::from_json(&library.to_json().replace("\"version\": 1", "\"version\": 9")).is_err());
+    assert!(ScenarioLibrary::from_json("not json").is_err());
+}
+
+#[test]
+fn import_merges_by_id() {
+    // what: merging replaces entries with the same id in place and appends new ones; fresh ids never collide
+    // why: re-importing a colleague's updated file must update their scenarios, not duplicate them or drop ours
+    let mut ours = library_with(&[("Heap spray", "bufferOverflow"), ("Env dump", "envTheft")]);
+    let mut theirs = library_with(&[("Heap spray", "bufferOverflow"), ("Slow leader", "heartbeatTimeout")]);
+    theirs.scenarios[0].notes = "updated payload".into();
+    assert_eq!(ours.merge(theirs), (1, 1));
+    let ids: Vec<&str> = ours.scenarios.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, ["heap-spray", "env-dump", "slow-leader"]);
+    assert_eq!(ours.scenarios[0].notes, "updated payload");
+    assert_eq!(ours.fresh_id("Heap spray!"), "heap-spray-2");
+    assert_eq!(ours.fresh_id("???"), "scenario");
+}
+
+#[test]
+fn run_all_appends_library_entries() {
+    // what: run-all fires the built-in attacks first, then each library entry by its "user:" id, which maps back to the entry
+    // why: the built-in comparison stays the same run for everyone; saved scenarios only add to it
+    let library = library_with(&[("Heap spray", "bufferOverflow"), ("Split brain", "killLeader")]);
+    let sequence = run_all_with(library.run_ids());
+    let builtin = run_all_sequence();
+    assert_eq!(sequence.len(), builtin.len() + 2);
+    assert!(sequence.iter().zip(&builtin).all(|(a, b)| a == b));
+    let tail: Vec<Option<&str>> = sequence[builtin.len()..].iter().map(|id| scenario_id(id)).collect();
+    assert_eq!(tail, [Some("heap-spray"), Some("split-brain")]);
+    assert_eq!(scenario_id("bufferOverflow"), None);
+}
+
+#[test]
+fn last_run_is_checked_against_the_expectation() {
+    // what: a scenario finds its own latest journalled run (by its journal name, not the base's) and compares both runtimes
+    // why: a plain run of the base attack must not count as the scenario's result
+    let mut scenario = UserScenario::from_base("heap-spray".into(), "bufferOverflow", get_attack_code("bufferOverflow"));
+    scenario.name = "Heap spray".into();
+    scenario.expected = [Answer::Fails, Answer::Breached];
+    let events = vec![
+        AuditEvent::new(1.0, EventKind::AttackStarted, None, "bufferOverflow", scenario.journal_name()),
+        AuditEvent::new(2.0, EventKind::Crash, Some(Runtime::Python), "bufferOverflow", "W0 crashed"),
+        AuditEvent::new(3.0, EventKind::Trap, Some(Runtime::Wasm), "bufferOverflow", "I1 trapped"),
+        AuditEvent::new(4.0, EventKind::AttackStarted, None, "bufferOverflow", "Buffer Overflow"),
+    ];
+    let runs = outcomes(&events);
+    let last = scenario.last_run(&runs).expect("the scenario's run is found");
+    assert_eq!(last.started_ms, 1.0);
+    assert_eq!(scenario.check(last), [true, false]);
+    assert!(UserScenario::from_base("other".into(), "dataExfil", get_attack_code("dataExfil")).last_run(&runs).is_none());
+}
@@ -0,0 +1,37 @@
+// what: tests for the sensor code tabs (snippet set and capability markers)
+// why: the panel claims to show what runs; markers must land on the lines the text talks about
+
+use crate::source::SENSOR_DRIVER;
+use crate::tabs::demo::sensor_code::{annotate, snippets, CodeLang, Reach, SENSOR_DRIVER_PY};
+
+#[test]
+fn snippets_are_the_executed_sources() {
+    // what: python and rust always, js only with the baseline, texts are the shared constants
+    // why: the display has to follow the same single sources the runtimes execute
+    let base = snippets(false);
+    assert_eq!(base.iter().map(|s| s.lang).collect::<Vec<_>>(), vec![CodeLang::Python, CodeLang::Rust]);
+    assert_eq!(base[0].text, SENSOR_DRIVER_PY.trim_matches('\n'));
+    assert_eq!(base[1].text, SENSOR_DRIVER.text);
+    assert_eq!(snippets(true).last().map(|s| s.lang), Some(CodeLang::JavaScript));
+}
+
+#[test]
+fn capability_lines_are_marked() {
+    // what: rust marks only explicit lines (exports, glue import); python marks its imports as ambient
+    // why: the diff gutter is the argument - wasm reaches out only through declared exports
+    for snippet in snippets(true) {
+        let lines = annotate(&snippet);
+        assert_eq!(lines.len(), snippet.text.lines().count());
+        let reaches = lines.iter().filter_map(|l| l.marker.map(|m| m.reach)).collect::<Vec<_>>();
+        assert!(!reaches.is_empty(), "{} has no marked lines", snippet.file);
+        match snippet.lang {
+            CodeLang::Rust => {
+                assert!(reaches.iter().all(|r| *r == Reach::Explicit));
+                assert!(lines.iter().any(|l| l.text.trim() == "#[wasm_bindgen]" && l.marker.is_some()));
+            }
+            _ => assert!(reaches.iter().all(|r| *r == Reach::Ambient)),
+        }
+    }
+    let python = annotate(&snippets(false)[0]);
+    assert!(python.iter().any(|l| l.text == "import time" && l.marker.map(|m| m.reach) == Some(Reach::Ambient)));
+}
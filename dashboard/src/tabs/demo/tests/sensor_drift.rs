@@ -0,0 +1,37 @@
+// what: tests for the sensor drift scenario's detection times
+// why: the panel's detection-time table and the live run both come from drift_step/detection
+
+use crate::tabs::demo::drift::{detection, drift_step, DriftRun, DriftStep, DRIFT_BANDS, DRIFT_PER_MIN};
+use crate::tabs::demo::voting::VoteStrategy;
+
+#[test]
+fn default_band_outvotes_drift_after_twelve_minutes() {
+    // what: with ±0.1°C the drifting instance agrees until minute 11 and is outvoted at minute 12
+    // why: pins the headline number the scenario log prints
+    assert_eq!(drift_step(VoteStrategy::default(), 11), DriftStep::Tracking);
+    assert_eq!(detection(VoteStrategy::default()), Some((12, DriftStep::Detected)));
+}
+
+#[test]
+fn wider_bands_detect_later() {
+    // what: detection minute increases strictly with the band
+    // why: the trade-off the table is there to show (fewer false alarms, slower detection)
+    let minutes: Vec<u32> = DRIFT_BANDS.iter().map(|b| detection(VoteStrategy::AbsoluteTolerance(*b)).unwrap().0).collect();
+    assert!(minutes.windows(2).all(|w| w[0] < w[1]), "{:?}", minutes);
+}
+
+#[test]
+fn exact_halts_and_median_never_detects() {
+    // what: exact match halts on the first tick, median select never flags the drift
+    // why: the two extremes the tolerance strategies sit between
+    assert_eq!(detection(VoteStrategy::Exact), Some((0, DriftStep::Halted)));
+    assert_eq!(detection(VoteStrategy::MedianSelect), None);
+}
+
+#[test]
+fn run_offset_follows_the_drift_rate() {
+    // what: the panel's offset is minutes times the drift rate
+    // why: status text and log lines must report the same drift
+    let run = DriftRun { minute: 12, step: DriftStep::Detected, recalibrated: false };
+    assert!((run.offset() - 12.0 * DRIFT_PER_MIN).abs() < 1e-12);
+}
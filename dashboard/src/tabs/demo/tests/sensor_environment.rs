@@ -0,0 +1,94 @@
+// what: tests for the simulated sensor environment - diurnal curve, correlated humidity, noise profiles, spikes and the session clock
+// why: sensor runs and attack telemetry read this model; a flat or runaway curve would put the old identical-looking demo back
+
+use crate::settings::{EnvironmentSettings, NoiseProfile, Settings};
+use crate::tabs::demo::environment::{clean_reading, env_hour, noise_sigmas, sample, Environment, PEAK_HOUR, SPIKE_C};
+use crate::tabs::demo::raft::Rng;
+
+fn quiet(noise: NoiseProfile) -> EnvironmentSettings {
+    EnvironmentSettings { spike_rate_pct: 0, noise, ..EnvironmentSettings::default() }
+}
+
+/// sample standard deviation of the temperature noise over `n` readings at the peak hour
+fn temperature_spread(noise: NoiseProfile, n: usize) -> f64 {
+    let env = quiet(noise);
+    let mut rng = Rng::new(7);
+    let clean = clean_reading(&env, PEAK_HOUR).temperature;
+    let sq = (0..n).map(|_| (sample(&env, PEAK_HOUR, &mut rng).reading.temperature - clean).powi(2)).fold(0.0, |a, b| a + b);
+    (sq / n as f64).sqrt()
+}
+
+#[test]
+fn clean_curve_peaks_in_the_afternoon_and_humidity_moves_against_it() {
+    // what: temperature is mean + swing at PEAK_HOUR and mean - swing twelve hours later; humidity is lowest when warmest; pressure stays near sea level
+    // why: relative humidity of the same air falls as it warms - a model where both rise together would look wrong to anyone from a plant floor
+    let env = EnvironmentSettings { base_temp_c: 20, diurnal_swing_c: 5, ..EnvironmentSettings::default() };
+    let peak = clean_reading(&env, PEAK_HOUR);
+    let trough = clean_reading(&env, PEAK_HOUR - 12.0);
+    assert!((peak.temperature - 25.0).abs() < 1e-9);
+    assert!((trough.temperature - 15.0).abs() < 1e-9);
+    assert!(peak.humidity < trough.humidity);
+    for hour in 0..24 {
+        let r = clean_reading(&env, hour as f64);
+        assert!((r.temperature - 20.0).abs() <= 5.0 + 1e-9);
+        assert!((0.0..=100.0).contains(&r.humidity));
+        assert!((1010.0..1017.0).contains(&r.pressure), "{} hPa at {}h", r.pressure, hour);
+    }
+    let flat = EnvironmentSettings { diurnal_swing_c: 0, ..env };
+    assert_eq!(clean_reading(&flat, 3.0).temperature, clean_reading(&flat, 15.0).temperature);
+}
+
+#[test]
+fn noise_follows_the_profile_and_the_seed() {
+    // what: measured temperature scatter matches each profile's sigma and grows lab < typical < harsh; one seed repeats, another differs
+    // why: the profile is the knob a presenter turns to make the anomaly detector work harder; the seed is what keeps two demos from matching
+    let spreads = NoiseProfile::ALL.map(|p| temperature_spread(p, 4000));
+    for (profile, spread) in NoiseProfile::ALL.into_iter().zip(spreads) {
+        let sigma = noise_sigmas(profile).0;
+        assert!((spread - sigma).abs() < sigma * 0.1, "{:?}: {} vs {}", profile, spread, sigma);
+    }
+    assert!(spreads[0] < spreads[1] && spreads[1] < spreads[2]);
+
+    let env = EnvironmentSettings::default();
+    let draw = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..5).map(|_| sample(&env, 9.0, &mut rng).reading.temperature).collect::<Vec<_>>()
+    };
+    assert_eq!(draw(11), draw(11));
+    assert_ne!(draw(11), draw(12));
+}
+
+#[test]
+fn spikes_come_at_the_configured_rate_and_size() {
+    // what: no spikes at 0%, roughly the configured share at 10%, each one SPIKE_C away from the clean value; humidity stays within 0-100%
+    // why: spikes are what the detector should flag; too many or too small and they stop looking like transients
+    let mut rng = Rng::new(3);
+    let none = quiet(NoiseProfile::Lab);
+    assert!((0..1000).all(|_| !sample(&none, 12.0, &mut rng).spike));
+
+    let spiky = EnvironmentSettings { spike_rate_pct: 10, noise: NoiseProfile::Lab, ..EnvironmentSettings::default() };
+    let clean = clean_reading(&spiky, 12.0).temperature;
+    let samples: Vec<_> = (0..2000).map(|_| sample(&spiky, 12.0, &mut rng)).collect();
+    let spikes: Vec<_> = samples.iter().filter(|s| s.spike).collect();
+    assert!((140..260).contains(&spikes.len()), "{} spikes", spikes.len());
+    assert!(spikes.iter().all(|s| ((s.reading.temperature - clean).abs() - SPIKE_C).abs() < 1.0));
+    assert!(spikes.iter().any(|s| s.reading.temperature < clean) && spikes.iter().any(|s| s.reading.temperature > clean));
+
+    let humid = EnvironmentSettings { base_temp_c: -20, diurnal_swing_c: 20, noise: NoiseProfile::Harsh, ..EnvironmentSettings::default() };
+    assert!((0..500).all(|i| (0.0..=100.0).contains(&sample(&humid, (i % 24) as f64, &mut rng).reading.humidity)));
+}
+
+#[test]
+fn session_clock_runs_an_hour_per_minute_and_settings_clamp() {
+    // what: the simulated day advances one hour per real minute from the start hour and wraps at midnight; imported environment knobs are clamped
+    // why: a ten-minute demo should cross most of a day, and a hand-edited settings blob must not produce a 500°C plant
+    assert_eq!(env_hour(10.0, 0.0), 10.0);
+    assert_eq!(env_hour(10.0, 90_000.0), 11.5);
+    assert_eq!(env_hour(23.0, 120_000.0), 1.0);
+    let session = Environment::new(1, 22.0, 5_000.0);
+    assert_eq!(session.hour(5_000.0 + 180_000.0), 1.0);
+
+    let settings = Settings::from_json(r#"{"environment":{"base_temp_c":500,"diurnal_swing_c":90,"noise":"harsh","spike_rate_pct":80}}"#).unwrap();
+    assert_eq!(settings.environment, EnvironmentSettings { base_temp_c: 50, diurnal_swing_c: 20, noise: NoiseProfile::Harsh, spike_rate_pct: 25 });
+    assert_eq!(Settings::from_json("{}").unwrap().environment, EnvironmentSettings::default());
+}
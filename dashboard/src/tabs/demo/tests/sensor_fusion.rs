@@ -0,0 +1,79 @@
+// what: tests for probe fusion in the sensor driver and the fused 2oo3 scenario built on it
+// why: fusion decides what the voter ever sees; a dropped flag or a wrong weight would let a broken probe through as a reading
+
+use guardian_types::SensorReading;
+use sensor_driver::fuse_temperature;
+use sensor_driver::fusion::{fuse, Probe, FLAG_DEGRADED, FLAG_RANGE, FLAG_SPREAD, FLAG_STATUS, MAX_SPREAD_C, TEMP_RANGE};
+use crate::tabs::demo::fusion::{correct, flag_labels, run, FusionFault, CORRUPTED};
+use crate::tabs::demo::voting::{VoteOutcome, VoteStrategy};
+
+const TRUTH: SensorReading = SensorReading { temperature: 22.0, humidity: 50.0, pressure: 1013.0 };
+
+fn probe(value: f64, weight: f64) -> Probe {
+    Probe { value, weight, valid: true }
+}
+
+#[test]
+fn fuse_weights_valid_probes_and_flags_the_rest() {
+    // what: weighted average of good probes; a clear status bit or an implausible value drops the probe with its flag plus degraded; disagreement or no survivor withholds the value
+    // why: the flags are how a real input card reports quality - the voter must never get a number without knowing what went into it
+    let both = fuse(&[probe(20.0, 2.0), probe(23.0, 1.0)], TEMP_RANGE, 5.0);
+    assert_eq!((both.value, both.used, both.flags), (Some(21.0), 0b11, 0));
+
+    let status = fuse(&[probe(20.0, 1.0), Probe { valid: false, ..probe(21.0, 1.0) }], TEMP_RANGE, MAX_SPREAD_C);
+    assert_eq!((status.value, status.used, status.flags), (Some(20.0), 0b01, FLAG_STATUS | FLAG_DEGRADED));
+    let range = fuse(&[probe(-242.0, 1.0), probe(21.0, 1.0), probe(f64::NAN, 1.0)], TEMP_RANGE, MAX_SPREAD_C);
+    assert_eq!((range.value, range.used, range.flags), (Some(21.0), 0b010, FLAG_RANGE | FLAG_DEGRADED));
+
+    let apart = fuse(&[probe(20.0, 1.0), probe(25.0, 1.0)], TEMP_RANGE, MAX_SPREAD_C);
+    assert_eq!((apart.value, apart.flags), (None, FLAG_SPREAD));
+    assert_eq!(fuse(&[probe(200.0, 1.0)], TEMP_RANGE, MAX_SPREAD_C).value, None);
+
+    assert_eq!(fuse_temperature(20.0, true, 21.0, true), 20.5);
+    assert_eq!(fuse_temperature(20.0, false, 21.0, true), 21.0);
+    assert!(fuse_temperature(20.0, true, 30.0, true).is_nan());
+}
+
+#[test]
+fn a_broken_probe_is_common_mode_without_fusion() {
+    // what: with T1's wire broken, every instance drops it and the fused vote is right, while voting on T1 alone agrees 3/3 on -242°C
+    // why: this is the scenario's point - the voter cannot catch a fault that all three instances see identically
+    let r = run(&TRUTH, FusionFault::ProbeOpen, VoteStrategy::default());
+    assert!(r.fused.iter().all(|f| f.has(FLAG_RANGE) && f.has(FLAG_DEGRADED) && f.used == 0b10));
+    assert_eq!(correct(&r.outcome, &TRUTH, 0.3), Some(true));
+    assert!(matches!(r.scalar, VoteOutcome::Consensus { agreeing: 3, .. }));
+    assert_eq!(correct(&r.scalar, &TRUTH, 0.3), Some(false));
+
+    let healthy = run(&TRUTH, FusionFault::None, VoteStrategy::default());
+    assert!(matches!(healthy.outcome, VoteOutcome::Consensus { agreeing: 3, .. }));
+    assert_eq!(correct(&healthy.outcome, &TRUTH, 0.1), Some(true));
+    assert!(healthy.fused.iter().all(|f| f.flags == 0) && healthy.pressure.value == Some(TRUTH.pressure));
+}
+
+#[test]
+fn status_loss_degrades_and_a_stuck_probe_halts() {
+    // what: a clear T2 status bit leaves T1 alone and still votes correctly; T2 stuck 5°C off makes every instance withhold, so the vote halts
+    // why: two disagreeing probes cannot say which is right - emitting nothing is the fail-safe answer, not averaging
+    let status = run(&TRUTH, FusionFault::ProbeStatus, VoteStrategy::default());
+    assert!(status.fused.iter().all(|f| f.flags == FLAG_STATUS | FLAG_DEGRADED));
+    assert_eq!(correct(&status.outcome, &TRUTH, 0.1), Some(true));
+
+    let stuck = run(&TRUTH, FusionFault::ProbeStuck, VoteStrategy::default());
+    assert!(stuck.fused.iter().all(|f| f.value.is_none() && f.has(FLAG_SPREAD)));
+    assert_eq!(stuck.outcome, VoteOutcome::Halted { healthy: 0 });
+    assert_eq!(correct(&stuck.outcome, &TRUTH, 0.1), None);
+}
+
+#[test]
+fn a_corrupted_instance_is_outvoted_and_flags_read_in_order() {
+    // what: one instance's corrupted fusion output loses 2oo3 on fused and scalar votes alike; flag labels list the most severe first
+    // why: fusion does not replace voting - instance faults are still the voter's job - and the panel shows the labels as given
+    let r = run(&TRUTH, FusionFault::InstanceCorrupt, VoteStrategy::default());
+    assert!(r.fused[CORRUPTED].value.unwrap() > TRUTH.temperature + 2.0);
+    assert!(matches!(r.outcome, VoteOutcome::Consensus { agreeing: 2, .. }));
+    assert!(matches!(r.scalar, VoteOutcome::Consensus { agreeing: 2, .. }));
+    assert_eq!(correct(&r.outcome, &TRUTH, 0.1), Some(true));
+
+    let flagged = fuse(&[probe(20.0, 1.0), Probe { valid: false, ..probe(0.0, 1.0) }, probe(99.0, 1.0)], TEMP_RANGE, MAX_SPREAD_C);
+    assert_eq!(flag_labels(&flagged), vec!["out of range", "status bad", "degraded"]);
+}
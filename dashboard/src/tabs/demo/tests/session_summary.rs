@@ -0,0 +1,61 @@
+// what: tests for the copy/email session summary built from the journal
+// why: a pasted summary is read without the dashboard; outcomes and numbers must be attributed correctly
+
+use crate::measure::stats::Summary;
+use crate::tabs::demo::journal::{AuditEvent, EventKind, Runtime};
+use crate::tabs::demo::summary::{outcomes, render, SessionNumbers, SummaryFormat};
+
+const T0: f64 = 1_768_383_000_000.0;
+
+fn session() -> Vec<AuditEvent> {
+    vec![
+        AuditEvent::new(T0, EventKind::AttackStarted, None, "bufferOverflow", "Buffer Overflow"),
+        AuditEvent::new(T0 + 10.0, EventKind::Trap, Some(Runtime::Wasm), "bufferOverflow", "I1: memory.grow denied"),
+        AuditEvent::new(T0 + 20.0, EventKind::Crash, Some(Runtime::Python), "bufferOverflow", "W0 crashed"),
+        AuditEvent::new(T0 + 30.0, EventKind::Recovery, Some(Runtime::Wasm), "bufferOverflow", "I1 rebuilt").with_duration(0.25),
+        AuditEvent::new(T0 + 40.0, EventKind::Recovery, Some(Runtime::Wasm), "bufferOverflow", "I1 rebuilt again").with_duration(9.0),
+        AuditEvent::new(T0 + 5_000.0, EventKind::AttackStarted, None, "envTheft", "Env Theft"),
+        AuditEvent::new(T0 + 5_010.0, EventKind::Outvoted, Some(Runtime::Wasm), "envTheft", "I2 disagreed"),
+        AuditEvent::new(T0 + 5_020.0, EventKind::PolicyBreach, Some(Runtime::Wasm), "envTheft", "worker granted env"),
+        AuditEvent::new(T0 + 5_030.0, EventKind::Reset, None, "", "reset"),
+    ]
+}
+
+#[test]
+fn outcomes_group_events_per_attack_run() {
+    // what: each AttackStarted opens a run; the worst fault and first recovery per runtime are kept
+    // why: a breach must not be hidden behind a milder outvote, and reruns must not merge
+    let runs = outcomes(&session());
+    assert_eq!(runs.len(), 2);
+    assert_eq!((runs[0].python, runs[0].wasm), (Some(EventKind::Crash), Some(EventKind::Trap)));
+    assert_eq!(runs[0].wasm_recovery_ms, Some(0.25));
+    assert_eq!(runs[1].wasm, Some(EventKind::PolicyBreach));
+    assert_eq!(runs[1].python, None);
+}
+
+#[test]
+fn markdown_summary_has_numbers_and_outcome_table() {
+    // what: measured rows, utc times and outcome cells render as markdown tables
+    // why: the markdown variant is pasted into tickets that render tables
+    let numbers = SessionNumbers { wasm_instantiate: Some(Summary { median: 0.05, kept: 1, ..Default::default() }), pyodide_load_ms: Some(2150.4), ..Default::default() };
+    let text = render(&numbers, &session(), "TestBrowser/1.0", &[], &[], T0 + 60_000.0, SummaryFormat::Markdown);
+    assert!(text.contains("- Generated: 2026-01-14T09:31:00.000Z"));
+    assert!(text.contains("- Browser: TestBrowser/1.0\n- Missing browser APIs: none"));
+    assert!(text.contains("| WASM instantiate | 0.050ms |"));
+    assert!(text.contains("| Pyodide cold start | 2150ms |"));
+    assert!(!text.contains("Sensor run"));
+    assert!(text.contains("| 09:30:00 | Buffer Overflow | crashed | trapped, recovered in 0.2ms |"));
+    assert!(text.contains("| 09:30:05 | Env Theft | no fault | capability granted |"));
+}
+
+#[test]
+fn text_summary_handles_an_empty_session() {
+    // what: plain text says nothing was measured or run and lists missing apis
+    // why: the email body must still make sense when sent before any attack
+    let text = render(&SessionNumbers::default(), &[], "ua", &["Web Workers"], &[], T0, SummaryFormat::Text);
+    assert!(text.starts_with("Guardian One demo summary\n"));
+    assert!(text.contains("Missing browser APIs: Web Workers"));
+    assert!(text.contains("  nothing measured yet\n"));
+    assert!(text.contains("Attack outcomes (0):\n  no attacks run\n"));
+    assert!(!text.contains('|'));
+}
@@ -0,0 +1,83 @@
+// what: tests for rfc 5424 syslog and cef formatting of journal events
+// why: siem parsers are strict, a malformed header silently drops the event
+
+use crate::tabs::demo::journal::{AuditEvent, EventKind, Runtime};
+use crate::tabs::demo::siem::{export, rfc3339, to_cef, to_syslog, ExportFormat};
+
+fn trap_event() -> AuditEvent {
+    AuditEvent::new(1_768_383_000_123.0, EventKind::Trap, Some(Runtime::Wasm), "dataExfil", "I1: capability not granted: network")
+}
+
+#[test]
+fn rfc3339_formats_epoch_correctly() {
+    // what: known epoch values format to the right utc date
+    // why: wrong timestamps make incident timelines useless
+    assert_eq!(rfc3339(0.0), "1970-01-01T00:00:00.000Z");
+    assert_eq!(rfc3339(951_782_400_000.0), "2000-02-29T00:00:00.000Z");
+    assert_eq!(rfc3339(1_768_383_000_123.0), "2026-01-14T09:30:00.123Z");
+}
+
+#[test]
+fn syslog_priority_uses_audit_facility() {
+    // what: trap (severity 4) with facility 13 gives <108>
+    // why: pri = facility * 8 + severity per rfc 5424
+    let line = to_syslog(&trap_event());
+    assert!(line.starts_with("<108>1 2026-01-14T09:30:00.123Z guardian-one guardian-demo - TRAP "));
+}
+
+#[test]
+fn syslog_structured_data_escaped() {
+    // what: quotes and brackets in sd values are backslash-escaped
+    // why: unescaped ] terminates the sd element early
+    let event = AuditEvent::new(0.0, EventKind::AttackStarted, None, "a\"b]", "x");
+    assert!(to_syslog(&event).contains("attack=\"a\\\"b\\]\""));
+}
+
+#[test]
+fn syslog_keeps_line_breaks_out_of_the_record() {
+    // what: cr and lf in the detail and in sd values become spaces, so the event stays one rfc 5424 record
+    // why: a crafted detail with a newline would otherwise end the record and forge a second one after it
+    let forged = "x\r\n<110>1 2026-01-14T09:30:00.000Z guardian-one guardian-demo - RESET [guardian@32473] all clear";
+    let event = AuditEvent::new(0.0, EventKind::Trap, None, "a\nb", forged);
+    let line = to_syslog(&event);
+    assert!(!line.contains('\r') && !line.contains('\n'));
+    assert!(line.contains("attack=\"a b\"") && line.ends_with("] x  <110>1 2026-01-14T09:30:00.000Z guardian-one guardian-demo - RESET [guardian@32473] all clear"));
+}
+
+#[test]
+fn cef_extension_escapes_line_breaks() {
+    // what: '\r' and '\n' in msg are written as the escapes "\\r" and "\\n"
+    // why: collectors frame cef on newlines, and a bare cr ends the line for some of them
+    let event = AuditEvent::new(0.0, EventKind::Crash, Some(Runtime::Python), "x", "a\r\nb");
+    let line = to_cef(&event);
+    assert!(line.ends_with("msg=a\\r\\nb"));
+    assert!(!line.contains('\r') && !line.contains('\n'));
+}
+
+#[test]
+fn cef_header_has_seven_fields() {
+    // what: cef line has version + 6 pipe-separated header fields before extensions
+    // why: cef parsers split on unescaped pipes
+    let line = to_cef(&trap_event());
+    let header: Vec<&str> = line.splitn(8, '|').collect();
+    assert_eq!(header.len(), 8);
+    assert_eq!(header[0], "CEF:0");
+    assert_eq!(header[4], "TRAP");
+}
+
+#[test]
+fn cef_extension_escapes_equals() {
+    // what: '=' in msg is escaped as '\='
+    // why: unescaped '=' starts a bogus key in the extension
+    let event = AuditEvent::new(0.0, EventKind::Crash, Some(Runtime::Python), "x", "a=b");
+    assert!(to_cef(&event).ends_with("msg=a\\=b"));
+}
+
+#[test]
+fn export_emits_one_line_per_event() {
+    // what: exporting n events produces n lines
+    // why: collectors frame on newlines
+    let events = vec![trap_event(), trap_event(), trap_event()];
+    assert_eq!(export(&events, ExportFormat::Syslog).lines().count(), 3);
+    assert_eq!(export(&events, ExportFormat::Cef).lines().count(), 3);
+}
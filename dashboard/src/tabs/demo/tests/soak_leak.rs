@@ -0,0 +1,63 @@
+// what: tests for the soak run's leak detector and sampling schedule
+// why: a false "leak" on gc sawtooth or a missed one on a slow climb would make the long-run stability claim meaningless
+
+use crate::tabs::demo::soak::{trend, window_floors, MemorySeries, SoakConfig, SoakRun, SoakSample, Trend};
+
+const MIB: f64 = 1024.0 * 1024.0;
+
+/// one point every 10s for `n` samples: a gc sawtooth (4 MiB peaks) on a floor climbing `per_min` bytes/min
+fn sawtooth(n: usize, per_min: f64) -> Vec<(f64, f64)> {
+    (0..n).map(|i| {
+        let t = i as f64 * 10.0;
+        (t, 40.0 * MIB + per_min * t / 60.0 + (i % 5) as f64 * MIB)
+    }).collect()
+}
+
+#[test]
+fn gc_sawtooth_on_a_flat_floor_is_stable() {
+    // what: large periodic swings with no retained growth are not a leak
+    // why: the js heap always looks like this; flagging it would cry wolf on every run
+    assert_eq!(trend(&sawtooth(60, 0.0)), Trend::Stable);
+}
+
+#[test]
+fn a_rising_floor_is_flagged_with_its_rate() {
+    // what: the same sawtooth on a floor climbing 1 MiB/min is growth, at roughly that rate
+    // why: this is the leak the soak run exists to catch
+    match trend(&sawtooth(60, MIB)) {
+        Trend::Growing { per_min_bytes } => assert!((per_min_bytes / MIB - 1.0).abs() < 0.2, "{}", per_min_bytes),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn a_one_off_grow_levels_off() {
+    // what: memory that grows once early (memory.grow never shrinks) and then stays put is stable
+    // why: wasm linear memory only ever steps up; a single step is not a leak
+    let points: Vec<(f64, f64)> = (0..40).map(|i| (i as f64 * 10.0, if i < 3 { 2.0 * MIB } else { 4.0 * MIB })).collect();
+    assert_eq!(trend(&points), Trend::Stable);
+    assert_eq!(window_floors(&points).len(), 4);
+}
+
+#[test]
+fn too_few_or_unreadable_samples_give_no_verdict() {
+    // what: fewer than two samples per window, or a browser without performance.memory, stays "collecting"
+    // why: early in a run (or outside chromium) there is nothing to judge yet
+    assert_eq!(trend(&sawtooth(7, MIB)), Trend::Insufficient);
+    let samples = vec![SoakSample { js_heap_bytes: None, ..Default::default() }; 20];
+    assert_eq!(trend(&MemorySeries::JsHeap.points(&samples)), Trend::Insufficient);
+    assert_eq!(MemorySeries::Module.points(&samples).len(), 20);
+}
+
+#[test]
+fn samples_fall_due_on_the_interval_and_config_is_clamped() {
+    // what: the first sample is due at once, the next after `sample_every_s`; out-of-range settings are pulled into range
+    // why: the tick runs every second whatever the interval, so the schedule decides the chart's resolution
+    let config = SoakConfig { duration_min: 0, sample_every_s: 500 }.clamped();
+    assert_eq!(config, SoakConfig { duration_min: 1, sample_every_s: 60 });
+    let mut run = SoakRun::new(SoakConfig::default(), 0.0);
+    assert!(run.sample_due(0.0));
+    run.samples.push(SoakSample::default());
+    assert!(!run.sample_due(9_999.0));
+    assert!(run.sample_due(10_000.0));
+}
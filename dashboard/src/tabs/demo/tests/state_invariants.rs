@@ -22,10 +22,10 @@ fn faulty_count_never_exceeds_one() {
 
 #[test]
 fn running_all_completes_in_order() {
-    // what: 5 attacks should fire sequentially
+    // what: 9 attacks should fire sequentially
     // why: no overlap corruption between attack simulations
-    let attack_order = ["bufferOverflow", "dataExfil", "pathTraversal", "killLeader", "heartbeatTimeout"];
-    assert_eq!(attack_order.len(), 5, "run_all should execute exactly 5 attacks");
+    let attack_order = ["bufferOverflow", "dataExfil", "pathTraversal", "stackExhaustion", "lengthOverflow", "envTheft", "lateralMovement", "killLeader", "heartbeatTimeout"];
+    assert_eq!(attack_order.len(), 9, "run_all should execute exactly 9 attacks");
 }
 
 #[test]
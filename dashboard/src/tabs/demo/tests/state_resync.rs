@@ -0,0 +1,57 @@
+// what: tests for the state re-sync cost model
+// why: the panel's failover totals add these costs to process start; the sizes, preloading and rates must add up
+
+use crate::tabs::demo::journal::Runtime;
+use crate::tabs::demo::prefork::PythonStrategy;
+use crate::tabs::demo::resync::{rate, resync, total_ms, Rates, Standby, StatePart, StateSize, SESSION_RECONNECT_MS, SETPOINT_BYTES};
+use crate::tabs::demo::supervisor::Supervisor;
+
+#[test]
+fn state_size_counts_every_part() {
+    // what: kib parts are 1024 bytes each, setpoints one f64 each, and the total is their sum
+    // why: the measured copy moves total_bytes; the per-part split must describe the same state
+    let size = StateSize { calibration_kib: 2, setpoints: 10, session_kib: 1 };
+    assert_eq!(size.bytes(StatePart::Calibration), 2048);
+    assert_eq!(size.bytes(StatePart::Setpoints), 10 * SETPOINT_BYTES);
+    assert_eq!(size.bytes(StatePart::Session), 1024);
+    assert_eq!(size.total_bytes(), 2048 + 80 + 1024);
+}
+
+#[test]
+fn warm_standbys_only_preload_calibration() {
+    // what: pre-forked and warm-spare standbys hold the static calibration; cold ones hold nothing; nobody holds setpoints or the session
+    // why: waiting loaded is what buys those strategies their head start, but live state can only move at failover
+    for standby in Standby::ALL {
+        let warm = matches!(standby, Standby::PythonPreForked | Standby::WasmWarmSpare);
+        assert_eq!(standby.preloaded(StatePart::Calibration), warm, "{:?}", standby);
+        assert!(!standby.preloaded(StatePart::Setpoints) && !standby.preloaded(StatePart::Session));
+    }
+    assert_eq!(Standby::python(PythonStrategy::PreForked), Standby::PythonPreForked);
+    assert_eq!(Standby::wasm(Supervisor::Rebuild).runtime(), Runtime::Wasm);
+}
+
+#[test]
+fn costs_follow_the_runtime_rate_plus_reconnect() {
+    // what: each part costs bytes / rate of its runtime; the session adds the reconnect; preloaded parts cost nothing
+    // why: the same state must cost each runtime exactly its measured copy speed, so the comparison stays apples to apples
+    let size = StateSize { calibration_kib: 100, setpoints: 0, session_kib: 0 };
+    let rates = Rates { python: 1024.0, wasm: 10_240.0 };
+    let cold = resync(Standby::PythonColdStart, size, rates);
+    assert_eq!(cold.iter().map(|c| c.part).collect::<Vec<_>>(), StatePart::ALL.to_vec());
+    assert_eq!(cold[0].ms, 100.0);
+    assert_eq!(cold[2].ms, SESSION_RECONNECT_MS);
+    assert_eq!(total_ms(&cold), 100.0 + SESSION_RECONNECT_MS);
+    assert_eq!(resync(Standby::WasmRebuild, size, rates)[0].ms, 10.0);
+    let warm = resync(Standby::WasmWarmSpare, size, rates);
+    assert!(warm[0].preloaded && warm[0].ms == 0.0);
+    assert_eq!(total_ms(&warm), SESSION_RECONNECT_MS);
+}
+
+#[test]
+fn sub_tick_copies_count_as_one_tick() {
+    // what: the rate from a copy below timer resolution uses one tick, never dividing by zero
+    // why: a 64KiB linear-memory copy often reads 0ms on a coarsened clock
+    assert_eq!(rate(1_000, 2.0, 0.1), 500.0);
+    assert_eq!(rate(1_000, 0.0, 0.1), 10_000.0);
+    assert!(rate(1_000, 0.0, 0.0).is_finite());
+}
@@ -0,0 +1,108 @@
+// what: tests for demo store snapshots, restore and undo of reset
+// why: a restore that misses a slice leaves the presenter with a half-staged demo
+
+use leptos::*;
+use crate::settings::Settings;
+use crate::tabs::demo::journal::{AuditEvent, EventKind};
+use crate::tabs::demo::snapshot::{DemoSnapshot, SNAPSHOT_VERSION};
+use crate::tabs::demo::store::DemoStore;
+use crate::tabs::demo::types::{InstanceState, LogEntry};
+use crate::tabs::demo::voting::VoteStrategy;
+
+fn staged() -> DemoStore {
+    let store = DemoStore::new(create_rw_signal(Settings::default()));
+    store.stats.python_crashed.set(2);
+    store.stats.wasm_rejected.set(4);
+    store.set_instance(1, InstanceState::Faulty);
+    store.cluster.faulty_instance.set(Some(1));
+    store.pool.python_workers.update(|w| w[0] = false);
+    store.python_log.push(LogEntry { level: "error".into(), message: "segfault".into() });
+    store.wasm_log.push(LogEntry { level: "info".into(), message: "trap contained".into() });
+    store.voter.set(VoteStrategy::default());
+    store.journal.update(|j| j.push(AuditEvent::new(1.0, EventKind::Trap, None, "bufferOverflow", "trap")));
+    store
+}
+
+#[test]
+fn snapshot_json_round_trip() {
+    // what: a snapshot survives to_json/from_json unchanged
+    // why: export and the localStorage slot both go through json
+    let snap = staged().snapshot(42.0);
+    assert_eq!(snap.version, SNAPSHOT_VERSION);
+    assert_eq!(DemoSnapshot::from_json(&snap.to_json()), Ok(snap));
+}
+
+#[test]
+fn rejects_other_versions_and_garbage() {
+    // what: blobs with another version or no json at all are refused with a message
+    // why: a half-understood snapshot must never be partially applied
+    let mut value: serde_json::Value = serde_json::from_str(&staged().snapshot(0.0).to_json()).unwrap();
+    value["version"] = serde_json::json!(SNAPSHOT_VERSION + 1);
+    assert!(DemoSnapshot::from_json(&value.to_string()).unwrap_err().contains("not supported"));
+    assert!(DemoSnapshot::from_json("{}").unwrap_err().contains("missing"));
+    assert!(DemoSnapshot::from_json("not json").is_err());
+}
+
+#[test]
+fn rejects_out_of_range_indices() {
+    // what: a leader, active worker or faulty instance of 3 or more is refused by from_json and by restore, and nothing is applied
+    // why: snapshots arrive from pasted imports and other tabs; one bad index would later panic the whole app on n.python[worker]
+    let store = staged();
+    for field in ["leader_id", "python_active_worker", "faulty_instance"] {
+        let mut value: serde_json::Value = serde_json::from_str(&store.snapshot(0.0).to_json()).unwrap();
+        value[field] = serde_json::json!(3);
+        assert!(DemoSnapshot::from_json(&value.to_string()).unwrap_err().contains(field), "{}", field);
+    }
+    let mut snap = store.snapshot(0.0);
+    snap.python_active_worker = 7;
+    store.reset();
+    assert!(store.restore(&snap).is_err());
+    assert_eq!(store.stats.python_crashed.get(), 0, "refused before any slice was written");
+}
+
+#[test]
+fn restore_brings_back_every_slice() {
+    // what: snapshot, reset, restore gives the staged counters, nodes, pool, terminals and journal
+    // why: restore is the whole point of saving a staged state
+    let store = staged();
+    let snap = store.snapshot(0.0);
+    store.reset();
+    store.journal.set(Vec::new());
+    store.restore(&snap).unwrap();
+    assert_eq!(store.stats.python_crashed.get(), 2);
+    assert_eq!(store.stats.wasm_rejected.get(), 4);
+    assert_eq!(store.cluster.instance_states.get()[1], InstanceState::Faulty);
+    assert_eq!(store.cluster.faulty_instance.get(), Some(1));
+    assert!(!store.pool.python_workers.get()[0]);
+    assert_eq!(store.python_log.messages(), vec!["segfault".to_string()]);
+    assert_eq!(store.journal.with(|j| j.len()), 1);
+    assert_eq!(store.snapshot(0.0), snap);
+}
+
+#[test]
+fn restore_refused_while_running() {
+    // what: restoring during an attack is an error and changes nothing
+    // why: pending attack timers would land on the restored state
+    let store = staged();
+    let snap = store.snapshot(0.0);
+    store.reset();
+    store.control.is_running.set(true);
+    assert!(store.restore(&snap).is_err());
+    assert_eq!(store.stats.python_crashed.get(), 0);
+}
+
+#[test]
+fn undo_reset_keeps_the_journal() {
+    // what: reset_with_undo then undo_reset restores the stats and keeps journal entries added since
+    // why: the journal is the audit trail, so undo must not erase the reset entry
+    let store = staged();
+    store.reset_with_undo(0.0);
+    store.journal.update(|j| j.push(AuditEvent::new(2.0, EventKind::Reset, None, "", "reset")));
+    assert_eq!(store.stats.python_crashed.get(), 0);
+    store.undo_reset().unwrap();
+    assert_eq!(store.stats.python_crashed.get(), 2);
+    assert_eq!(store.wasm_log.messages(), vec!["trap contained".to_string()]);
+    assert_eq!(store.journal.with(|j| j.len()), 2);
+    assert!(store.undo.get().is_none());
+    assert!(store.undo_reset().is_err());
+}
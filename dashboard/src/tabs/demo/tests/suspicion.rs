@@ -0,0 +1,54 @@
+// what: tests for per-node suspicion scoring (penalties, decay, threshold, sparkline)
+// why: eviction must follow repeated faults, not one glitch, and the sparkline must plot the same numbers
+
+use std::collections::VecDeque;
+use crate::tabs::demo::suspicion::{sparkline_points, Offense, Suspicion, HISTORY_LEN, MAX_EVICTION, SUSPICION_HALF_LIFE_S};
+
+#[test]
+fn one_glitch_decays_without_eviction() {
+    // what: a single disagreement stays below the default threshold and halves after one half-life
+    // why: an isolated fault should be tolerated and forgotten
+    let mut s = Suspicion::default();
+    assert!(!s.penalize(1, Offense::Disagreement));
+    s.tick(SUSPICION_HALF_LIFE_S);
+    assert!((s.scores[1] - 0.5).abs() < 1e-9);
+    assert_eq!(s.scores[0], 0.0);
+}
+
+#[test]
+fn repeated_faults_reach_the_threshold() {
+    // what: three quick disagreements reach the default threshold of 3.0; timeouts weigh more
+    // why: a flapping node must be evicted even though no single reading condemns it
+    let mut s = Suspicion::default();
+    assert!(!s.penalize(2, Offense::Disagreement));
+    assert!(!s.penalize(2, Offense::Disagreement));
+    assert!(s.penalize(2, Offense::Disagreement));
+    let mut t = Suspicion::default();
+    assert!(!t.penalize(0, Offense::Timeout));
+    assert!(t.penalize(0, Offense::Timeout));
+}
+
+#[test]
+fn threshold_is_clamped_and_clear_resets() {
+    // what: set_threshold clamps into range and clear zeroes one node
+    // why: the panel's number input feeds straight into these
+    let mut s = Suspicion::default();
+    s.set_threshold(99.0);
+    assert_eq!(s.threshold, MAX_EVICTION);
+    s.penalize(1, Offense::Timeout);
+    s.clear(1);
+    assert_eq!(s.scores[1], 0.0);
+}
+
+#[test]
+fn history_is_bounded_and_sparkline_right_aligned() {
+    // what: history keeps HISTORY_LEN samples; a lone sample plots at the right edge, full scale at the top
+    // why: the sparkline is a fixed-width window over the last minute
+    let mut s = Suspicion::default();
+    for _ in 0..HISTORY_LEN + 5 {
+        s.tick(1.0);
+    }
+    assert_eq!(s.history[0].len(), HISTORY_LEN);
+    let one: VecDeque<f64> = [4.0].into_iter().collect();
+    assert_eq!(sparkline_points(&one, 4.0, 120.0, 24.0), "120.0,0.0");
+}
@@ -0,0 +1,49 @@
+// what: tests for chunked, capped terminal log retention
+// why: long chaos / run-all sessions must stay bounded without losing recent lines
+
+use crate::tabs::demo::terminal::{TerminalLog, CHUNK_LINES, MAX_CHUNKS};
+use crate::tabs::demo::types::LogEntry;
+
+fn line(n: usize) -> LogEntry {
+    LogEntry { level: "info".into(), message: format!("line {}", n) }
+}
+
+#[test]
+fn append_keeps_order_and_count() {
+    // what: lines written through append are retained in order
+    // why: call sites still push into a Vec-shaped closure
+    let log = TerminalLog::new();
+    assert!(log.is_empty());
+    log.append(|l| {
+        l.push(line(0));
+        l.push(line(1));
+    });
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.trimmed(), 0);
+}
+
+#[test]
+fn retention_drops_whole_oldest_chunks() {
+    // what: exceeding MAX_CHUNKS drops the oldest chunk and counts its lines
+    // why: memory and dom size stay bounded during long sessions
+    let log = TerminalLog::new();
+    let total = CHUNK_LINES * MAX_CHUNKS + 5;
+    for n in 0..total {
+        log.push(line(n));
+    }
+    assert_eq!(log.trimmed(), CHUNK_LINES);
+    assert_eq!(log.len(), total - CHUNK_LINES);
+}
+
+#[test]
+fn replace_resets_history() {
+    // what: replace clears chunks and the trimmed counter
+    // why: the reset button must start a clean terminal
+    let log = TerminalLog::new();
+    for n in 0..(CHUNK_LINES * (MAX_CHUNKS + 1)) {
+        log.push(line(n));
+    }
+    log.replace(vec![line(0)]);
+    assert_eq!(log.len(), 1);
+    assert_eq!(log.trimmed(), 0);
+}
@@ -0,0 +1,67 @@
+// what: tests for the timing side-channel scenario (clock profiles, leak scoring, the clock probe module)
+// why: the panel's verdicts are only as honest as the arithmetic turning timings into "leaks" or "blunted"
+
+use crate::measure::binary::parse_sections;
+use crate::tabs::demo::modules::{CLOCK_PY, CLOCK_WASM, LEAK_VICTIM_JS};
+use crate::tabs::demo::timing_channel::{
+    leak_signal_ms, leak_success, leak_verdict, quantize, Clock, ClockProfile, CLOCKS, COARSE_CLOCK_MS, LEAK_SECRET, RIGHT_GUESS, WRONG_GUESS,
+};
+
+/// length-prefixed name as it appears in an import or export entry
+fn has_name(bytes: &[u8], name: &str) -> bool {
+    let mut needle = vec![name.len() as u8];
+    needle.extend_from_slice(name.as_bytes());
+    bytes.windows(needle.len()).any(|w| w == needle.as_slice())
+}
+
+#[test]
+fn probe_module_imports_its_clock() {
+    // what: CLOCK_WASM imports clock.now and victim.check and exports tick and time_check; the twins take their placeholders
+    // why: the "no clock" row relies on the module being unable to link without the clock import
+    let names: Vec<String> = parse_sections(CLOCK_WASM).unwrap().sections.into_iter().map(|s| s.name).collect();
+    assert_eq!(names, ["type", "import", "function", "export", "code"]);
+    for name in ["clock", "now", "victim", "check", "tick", "time_check"] {
+        assert!(has_name(CLOCK_WASM, name), "{}", name);
+    }
+    assert!(CLOCK_PY.contains("{steps}") && CLOCK_PY.contains("perf_counter"));
+    assert!(LEAK_VICTIM_JS.contains("secret") && LEAK_VICTIM_JS.contains("work"));
+}
+
+#[test]
+fn guesses_differ_in_the_first_byte_only_by_match() {
+    // what: the right guess shares the secret's first byte, the wrong one does not; the coarse clock floors to its step
+    // why: the leak is exactly one byte of victim work, and the coarse clock must never run ahead of the real one
+    assert_eq!(RIGHT_GUESS >> 24, LEAK_SECRET >> 24);
+    assert_ne!(WRONG_GUESS >> 24, LEAK_SECRET >> 24);
+    assert_eq!(quantize(12.999, COARSE_CLOCK_MS), 12.0);
+    assert_eq!(quantize(13.0, COARSE_CLOCK_MS), 13.0);
+    assert!((0..1000).map(|i| i as f64 * 0.37).all(|t| quantize(t, COARSE_CLOCK_MS) <= t));
+}
+
+#[test]
+fn clock_profile_from_steps() {
+    // what: resolution is the smallest step, jitter the spread; a clean quantized clock has none; zero steps are ignored
+    // why: resolution and jitter are the two numbers the panel compares across runtimes
+    let clean = ClockProfile::from_steps(&[1.0; 10]);
+    assert_eq!((clean.resolution_ms, clean.median_step_ms, clean.jitter_ms, clean.steps), (1.0, 1.0, 0.0, 10));
+    let noisy = ClockProfile::from_steps(&[0.0, 0.1, 0.3, 0.1, 0.3]);
+    assert_eq!((noisy.resolution_ms, noisy.median_step_ms, noisy.steps), (0.1, 0.2, 4));
+    assert!((noisy.jitter_ms - 0.1).abs() < 1e-9);
+    assert_eq!(ClockProfile::from_steps(&[]), ClockProfile::default());
+}
+
+#[test]
+fn leak_scoring_and_verdicts() {
+    // what: ties count half, so a clock too coarse to see the byte scores 0.5; verdict bands follow the success rate
+    // why: a coarse timer shows up as ties, which must read as "blunted", not as a failed measurement
+    assert_eq!(leak_success(&[(0.05, 0.01); 4]), 1.0);
+    assert_eq!(leak_success(&[(1.0, 1.0); 4]), 0.5);
+    assert_eq!(leak_success(&[(1.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]), 0.625);
+    assert_eq!(leak_success(&[]), 0.5);
+    assert!((leak_signal_ms(&[(0.05, 0.01), (0.03, 0.01)]) - 0.03).abs() < 1e-12);
+    assert_eq!(leak_verdict(1.0).0, "error");
+    assert_eq!(leak_verdict(0.7).0, "warning");
+    assert_eq!(leak_verdict(0.5).0, "success");
+    assert_eq!(CLOCKS.first(), Some(&Clock::Python));
+    assert_eq!(CLOCKS.last(), Some(&Clock::WasmNone));
+}
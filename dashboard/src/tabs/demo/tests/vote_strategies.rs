@@ -0,0 +1,63 @@
+// what: tests for the voter's comparison strategies against spoofing and drift
+// why: the voting panel's comparison table is computed from these, so each cell must be the real behaviour
+
+use crate::tabs::demo::voting::{evaluate, FaultType, ScenarioVerdict, VoteOutcome, VoteStrategy, DEFAULT_ABS_TOLERANCE};
+
+#[test]
+fn exact_match_halts_on_sensor_noise() {
+    // what: two healthy readings 0.03°C apart never agree bit for bit
+    // why: exact match suits digital outputs, not analog sensors
+    for fault in FaultType::ALL {
+        assert_eq!(evaluate(VoteStrategy::Exact, fault), ScenarioVerdict::Halted);
+    }
+}
+
+#[test]
+fn absolute_tolerance_outvotes_spoof_and_drift() {
+    // what: with a ±0.1°C band the noisy pair agrees and the faulty instance is flagged
+    // why: a tolerance band is what makes 2oo3 work on real readings
+    for fault in FaultType::ALL {
+        match evaluate(VoteStrategy::default(), fault) {
+            ScenarioVerdict::Flagged { value } => assert!((value - 22.015).abs() < 1e-9),
+            other => panic!("{:?} gave {:?}", fault, other),
+        }
+    }
+}
+
+#[test]
+fn band_wider_than_drift_hides_it() {
+    // what: a ±0.5°C band swallows the 0.35°C drift but still catches the spoof
+    // why: tolerance trades false alarms against missed slow faults
+    let wide = VoteStrategy::AbsoluteTolerance(0.5);
+    assert!(matches!(evaluate(wide, FaultType::Drift), ScenarioVerdict::Masked { .. }));
+    assert!(matches!(evaluate(wide, FaultType::Spoofing), ScenarioVerdict::Flagged { .. }));
+}
+
+#[test]
+fn relative_tolerance_scales_with_the_reading() {
+    // what: ±0.5% accepts a 3 hPa spread around 1013 hPa that ±0.1 absolute rejects
+    // why: one relative band fits temperature and pressure channels alike
+    let outputs = [Some(1013.0), Some(1016.0), None];
+    assert!(matches!(VoteStrategy::RelativeTolerance(0.005).vote(&outputs), VoteOutcome::Consensus { agreeing: 2, .. }));
+    assert_eq!(VoteStrategy::AbsoluteTolerance(DEFAULT_ABS_TOLERANCE).vote(&outputs), VoteOutcome::Halted { healthy: 2 });
+}
+
+#[test]
+fn median_select_masks_without_flagging() {
+    // what: median select always emits the middle value and never reports the faulty instance
+    // why: it keeps output flowing but hides a spoof from the operator
+    assert_eq!(evaluate(VoteStrategy::MedianSelect, FaultType::Spoofing), ScenarioVerdict::Masked { value: 22.03 });
+    assert_eq!(VoteStrategy::MedianSelect.vote(&[Some(1.0), None, None]), VoteOutcome::Halted { healthy: 1 });
+}
+
+#[test]
+fn picker_ids_round_trip_and_bands_update() {
+    // what: every strategy comes back from its id; with_tolerance only changes banded strategies
+    // why: the select and the band input drive the live voter through these
+    for strategy in VoteStrategy::ALL {
+        assert_eq!(VoteStrategy::from_id(strategy.id()), Some(strategy));
+    }
+    assert_eq!(VoteStrategy::default().with_tolerance(0.25), VoteStrategy::AbsoluteTolerance(0.25));
+    assert_eq!(VoteStrategy::Exact.with_tolerance(0.25), VoteStrategy::Exact);
+    assert_eq!(VoteStrategy::RelativeTolerance(0.01).with_tolerance(-1.0), VoteStrategy::RelativeTolerance(0.0));
+}
@@ -68,6 +68,8 @@ fn leader_id_wraps_around() {
 }
 
 #[test]
+// the indexed loop mirrors crashing and rebuilding instance i by its id
+#[allow(clippy::needless_range_loop)]
 fn rapid_crashes_dont_corrupt_state() {
     // what: multiple sequential crashes should result in valid final state
     // why: no race conditions or state corruption
@@ -0,0 +1,54 @@
+// what: tests for the supervisor strategies and their recovery log
+// why: the rebuild vs warm-spare comparison is only honest if fallbacks are filed under what actually ran and the speedup respects the timer
+
+use crate::tabs::demo::supervisor::{RecoveryLog, Supervisor};
+
+#[test]
+fn warm_spare_without_a_spare_rebuilds() {
+    // what: the strategy picks the swap only while a spare is held; rebuild never swaps
+    // why: two faults back to back find the spare still re-warming, and that recovery is a rebuild
+    assert_eq!(Supervisor::WarmSpare.pick(true), Supervisor::WarmSpare);
+    assert_eq!(Supervisor::WarmSpare.pick(false), Supervisor::Rebuild);
+    assert_eq!(Supervisor::Rebuild.pick(true), Supervisor::Rebuild);
+    assert_eq!(Supervisor::default(), Supervisor::Rebuild);
+}
+
+#[test]
+fn strategy_ids_round_trip() {
+    // what: every strategy is found again from its select option id, unknown ids are rejected
+    // why: the panel's select only has the id to go on
+    for s in Supervisor::ALL {
+        assert_eq!(Supervisor::from_id(s.id()), Some(s));
+    }
+    assert_eq!(Supervisor::from_id("hot-standby"), None);
+}
+
+#[test]
+fn recoveries_are_filed_per_strategy_used() {
+    // what: samples land in the rebuild or swap series by the strategy that ran, warm-ups in their own
+    // why: a swap median polluted by fallback rebuilds (or spare builds) would understate the tradeoff
+    let mut log = RecoveryLog::default();
+    log.record(Supervisor::Rebuild, 0.9);
+    log.record(Supervisor::Rebuild, 1.1);
+    log.record(Supervisor::WarmSpare, 0.01);
+    log.warm_ms.push(1.0);
+    assert_eq!(log.samples(Supervisor::Rebuild), &[0.9, 1.1]);
+    assert_eq!(log.samples(Supervisor::WarmSpare), &[0.01]);
+    assert_eq!(log.summary(Supervisor::WarmSpare).median, 0.01);
+    assert_eq!(log.warm_summary().kept, 1);
+}
+
+#[test]
+fn speedup_needs_both_and_respects_timer_resolution() {
+    // what: no figure until both strategies ran; a swap under one tick is counted as one tick and flagged as a bound
+    // why: a coarse timer reads a swap as 0ms, which would otherwise claim an infinite speedup
+    let mut log = RecoveryLog::default();
+    log.record(Supervisor::Rebuild, 1.0);
+    assert_eq!(log.speedup(0.005), None);
+    log.record(Supervisor::WarmSpare, 0.02);
+    let (ratio, bound) = log.speedup(0.005).unwrap();
+    assert!((ratio - 50.0).abs() < 1e-9 && !bound);
+    log.swap_ms = vec![0.0];
+    let (ratio, bound) = log.speedup(0.1).unwrap();
+    assert!((ratio - 10.0).abs() < 1e-9 && bound);
+}
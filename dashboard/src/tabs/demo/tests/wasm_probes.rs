@@ -0,0 +1,88 @@
+// what: tests for the real engine probes' reporting and the hand-assembled module bytes
+// why: the terminal must state the real limit and the real denial, never a made-up trap
+
+use crate::tabs::demo::modules::{BOUNDS_PAGES, GROW_MAX_PAGES, GROW_WASM, RECURSE_WASM};
+use crate::tabs::demo::probes::{BoundsOutcome, GrowOutcome, StackOutcome, OVERFLOW_ADDRESS, OVERFLOW_GROW_PAGES, STACK_CHECK_DEPTH, STACK_PROBE_DEPTH, WASM_PAGE_BYTES};
+
+#[test]
+fn denied_grow_reports_limit_and_request() {
+    // what: a -1 memory.grow is reported as denied with the limit and requested size
+    // why: the buffer-overflow attack now rests on this genuine engine result
+    let outcome = GrowOutcome { instance: 1, pages_before: 1, max_pages: GROW_MAX_PAGES, requested: OVERFLOW_GROW_PAGES, result: -1 };
+    assert!(outcome.denied());
+    let lines = outcome.lines();
+    assert!(lines[0].message.contains("limit 128 KiB (2 pages)"));
+    assert!(lines[1].message.contains("memory.grow(16) = -1"));
+    assert!(lines[1].message.contains("1024 KiB"));
+    assert_eq!(lines[1].level, "warn");
+}
+
+#[test]
+fn successful_grow_is_flagged() {
+    // what: a grow that succeeds is logged as an error, not as a denial
+    // why: a misassembled module must not silently pass as contained
+    let outcome = GrowOutcome { instance: 0, pages_before: 1, max_pages: 64, requested: 16, result: 1 };
+    assert!(!outcome.denied());
+    assert_eq!(outcome.lines()[1].level, "error");
+}
+
+#[test]
+fn grow_module_declares_its_cap() {
+    // what: GROW_WASM's memory section is min 1 / max GROW_MAX_PAGES and the request exceeds it
+    // why: the constant shown in the terminal must match the bytes the engine enforces
+    assert_eq!(&GROW_WASM[..4], b"\0asm");
+    let memory = GROW_WASM.windows(3).position(|w| w == [0x05, 0x04, 0x01]).expect("memory section");
+    assert_eq!(GROW_WASM[memory + 3..memory + 6], [0x01, 0x01, GROW_MAX_PAGES as u8]);
+    const { assert!(OVERFLOW_GROW_PAGES > GROW_MAX_PAGES) };
+    assert_eq!(WASM_PAGE_BYTES, 64 * 1024);
+}
+
+#[test]
+fn bounds_trap_shows_engine_text() {
+    // what: the trap line carries the engine's exception text verbatim plus address and memory size
+    // why: the buffer-overflow scenario displays the real RuntimeError, not a narrated one
+    let outcome = BoundsOutcome {
+        instance: 2,
+        address: OVERFLOW_ADDRESS,
+        memory_bytes: BOUNDS_PAGES * WASM_PAGE_BYTES,
+        error: Some("RuntimeError: memory access out of bounds".into()),
+    };
+    let line = outcome.trap_line();
+    assert_eq!(line.level, "warn");
+    assert!(line.message.contains("RuntimeError: memory access out of bounds"));
+    assert!(line.message.contains("@0x40000 (memory 64 KiB)"));
+    let missing = BoundsOutcome { error: None, ..outcome };
+    assert_eq!(missing.trap_line().level, "error");
+}
+
+#[test]
+fn stack_trap_reports_latency_and_blast_radius() {
+    // what: a stack trap followed by a working call is reported as contained to one call
+    // why: the stack-exhaustion scenario contrasts detection latency and blast radius with python
+    let outcome = StackOutcome {
+        instance: 0,
+        depth: STACK_PROBE_DEPTH,
+        error: Some("RangeError: Maximum call stack size exceeded".into()),
+        detect_ms: 2.5,
+        followup: Some(STACK_CHECK_DEPTH as i32),
+    };
+    assert!(outcome.contained());
+    let lines = outcome.lines();
+    assert!(lines[0].message.contains("Maximum call stack size exceeded after 2.50ms"));
+    assert!(lines[1].message.contains("blast radius: one call"));
+    let broken = StackOutcome { followup: None, ..outcome.clone() };
+    assert!(!broken.contained());
+    assert_eq!(broken.lines()[1].level, "warn");
+    let returned = StackOutcome { error: None, ..outcome };
+    assert_eq!(returned.lines()[0].level, "error");
+}
+
+#[test]
+fn recurse_module_exports_recurse_without_memory() {
+    // what: RECURSE_WASM exports "recurse" and declares no linear memory
+    // why: the trap must come from the call stack, not a memory limit
+    assert_eq!(&RECURSE_WASM[..4], b"\0asm");
+    assert!(RECURSE_WASM.windows(7).any(|w| w == b"recurse"));
+    assert!(!RECURSE_WASM.windows(2).any(|w| w == [0x05, 0x03]));
+    assert!(RECURSE_WASM.windows(2).any(|w| w == [0x10, 0x00]), "body must call itself");
+}
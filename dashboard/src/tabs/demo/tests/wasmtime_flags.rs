@@ -0,0 +1,69 @@
+// what: tests for the wasmtime command generated from the capability policy - flags per grant, their reasons, host-only grants, and the wit excerpt without commands of its own
+// why: these commands get pasted onto a pi; a flag that grants more than the matrix shows is a hole the demo claims is closed
+
+use attack_code::WIT_CODE_EXCERPT;
+use crate::tabs::demo::policy::{CapabilityPolicy, WORKER_WORLD};
+
+#[test]
+fn shipped_worker_gets_only_its_grants() {
+    // what: sensor-node preopens the i2c bus, passes its two variables, caps memory at 2MB and turns tcp and udp off
+    // why: the worker is the world every attack runs in; its command is the one the narrative promises
+    let policy = CapabilityPolicy::load_default();
+    let worker = policy.world(WORKER_WORLD).unwrap();
+    assert_eq!(
+        worker.wasmtime_args(),
+        vec![
+            "--dir=/dev/i2c-1::/dev/i2c-1",
+            "--env=SENSOR_TYPE=bme280",
+            "--env=POLL_INTERVAL_MS=1000",
+            "-W max-memory-size=2097152",
+            "-S tcp=n",
+            "-S udp=n",
+        ]
+    );
+    assert!(worker.wasmtime_command().starts_with("wasmtime run \\\n    --dir="));
+    assert!(worker.wasmtime_command().ends_with("sensor-node.wasm"));
+}
+
+#[test]
+fn listeners_and_host_only_grants() {
+    // what: the supervisor's 0.0.0.0:502 becomes a preopened listener without opening the network; read-only dirs, registers and gpio are listed as host-enforced
+    // why: wasmtime has no flag for those grants, and leaving them out silently would read as "not enforced"
+    let policy = CapabilityPolicy::load_default();
+    let supervisor = policy.world("system-supervisor").unwrap();
+    assert_eq!(supervisor.wasmtime_args(), vec!["-W max-memory-size=16777216", "--tcplisten=0.0.0.0:502"]);
+
+    let worker_notes = policy.world(WORKER_WORLD).unwrap().host_enforced();
+    assert_eq!(worker_notes.len(), 2);
+    assert!(worker_notes[0].starts_with("/dev/i2c-1 read-only"));
+    assert!(worker_notes[1].starts_with("registers [0, 1, 2, 3, 4, 5]"));
+    assert_eq!(policy.world("actuator-node").unwrap().host_enforced(), vec!["gpio [17]: checked by the host's write-pin"]);
+}
+
+#[test]
+fn every_flag_names_the_grant_it_comes_from() {
+    // what: an outbound socket opens the network and names its peers; each flag carries a reason; no memory limit means no -W flag
+    // why: the reasons are what a reviewer checks the command against, and inherit-network is wider than the grant
+    let policy = CapabilityPolicy::from_toml(
+        "[[world]]\nname = \"sensor-node\"\nsockets = [\"203.0.113.66:443\"]\nenv = [\"SENSOR_TYPE=bme280\"]\n",
+    ).unwrap();
+    let flags = policy.world(WORKER_WORLD).unwrap().wasmtime_flags();
+    assert_eq!(flags.iter().map(|f| f.flag.as_str()).collect::<Vec<_>>(), vec!["--env=SENSOR_TYPE=bme280", "-S inherit-network=y"]);
+    assert!(flags.iter().all(|f| !f.reason.is_empty()));
+    assert!(flags[0].reason.contains("SENSOR_TYPE") && !flags[0].reason.contains("bme280"));
+    assert!(flags[1].reason.contains("203.0.113.66:443"));
+}
+
+#[test]
+fn wit_viewer_leaves_the_commands_to_the_policy() {
+    // what: the wit excerpt carries no command line of its own; each world's command ends in its module and follows a policy edit
+    // why: the static examples drifted from the policy and mapped the bus to a guest path literally named "readonly"
+    assert!(!WIT_CODE_EXCERPT.contains("$ wasmtime") && !WIT_CODE_EXCERPT.contains("--dir") && !WIT_CODE_EXCERPT.contains("::readonly"));
+    let shipped = CapabilityPolicy::load_default();
+    for world in &shipped.worlds {
+        assert!(world.wasmtime_command().ends_with(&format!(" \\\n    {}.wasm", world.name)), "{}", world.name);
+    }
+
+    let edited = CapabilityPolicy::from_toml("[[world]]\nname = \"sensor-node\"\nmemory_limit_mb = 4\n").unwrap();
+    assert!(edited.world(WORKER_WORLD).unwrap().wasmtime_command().contains("-W max-memory-size=4194304"));
+}
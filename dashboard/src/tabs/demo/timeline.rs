@@ -0,0 +1,149 @@
+// what: session incident timeline - attacks, traps, elections and recoveries from the journal as markers on one time axis, plus presenter annotations
+// why: the terminals scroll away; a presenter needs to point at "the trap 42s in", show the lines it wrote and leave a note for the audience
+// relations: events from journal.rs, annotations kept in store.rs, saved by snapshot.rs and exported by siem.rs; line stamps from terminal.rs;
+//            drawn by timeline_panel.rs above the terminals
+
+use serde::{Deserialize, Serialize};
+use super::journal::{AuditEvent, EventKind, Runtime};
+
+/// terminal lines written this close to an event belong to it (ms)
+pub const HIGHLIGHT_SLACK_MS: f64 = 250.0;
+/// a session with a single moment still gets an axis this wide (ms)
+pub const MIN_SPAN_MS: f64 = 1_000.0;
+/// annotations are cut to this many characters
+pub const MAX_ANNOTATION_CHARS: usize = 140;
+
+/// what a marker stands for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarkerKind {
+    Attack,
+    /// a wasm trap or a python crash: the attack landed on a runtime
+    Trap,
+    Election,
+    Recovery,
+}
+
+impl MarkerKind {
+    /// journal events that get a marker; everything else stays in the audit export only
+    pub fn of(kind: EventKind) -> Option<Self> {
+        match kind {
+            EventKind::AttackStarted => Some(MarkerKind::Attack),
+            EventKind::Trap | EventKind::Crash => Some(MarkerKind::Trap),
+            EventKind::Election => Some(MarkerKind::Election),
+            EventKind::Recovery => Some(MarkerKind::Recovery),
+            _ => None,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            MarkerKind::Attack => "⚔️",
+            MarkerKind::Trap => "🛑",
+            MarkerKind::Election => "👑",
+            MarkerKind::Recovery => "✅",
+        }
+    }
+
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            MarkerKind::Attack => "marker-attack",
+            MarkerKind::Trap => "marker-trap",
+            MarkerKind::Election => "marker-election",
+            MarkerKind::Recovery => "marker-recovery",
+        }
+    }
+}
+
+/// one journal event on the strip
+#[derive(Clone, Debug, PartialEq)]
+pub struct Marker {
+    /// position in the journal, stable while the session lasts
+    pub index: usize,
+    pub at_ms: f64,
+    pub kind: MarkerKind,
+    pub runtime: Option<Runtime>,
+    pub duration_ms: Option<f64>,
+    pub label: String,
+}
+
+impl Marker {
+    /// stamps of the terminal lines the marker highlights; a measured recovery covers its whole duration
+    pub fn window(&self) -> (f64, f64) {
+        (self.at_ms - self.duration_ms.unwrap_or(0.0) - HIGHLIGHT_SLACK_MS, self.at_ms + HIGHLIGHT_SLACK_MS)
+    }
+
+    /// whether the terminal of `runtime` highlights for this marker (host-level events light every terminal)
+    pub fn touches(&self, runtime: Runtime) -> bool {
+        self.runtime.is_none_or(|r| r == runtime)
+    }
+}
+
+/// the journal's markers, oldest first
+pub fn markers(events: &[AuditEvent]) -> Vec<Marker> {
+    events
+        .iter()
+        .enumerate()
+        .filter_map(|(index, e)| {
+            let kind = MarkerKind::of(e.kind)?;
+            let runtime = e.runtime.map(|r| format!(" ({})", r.as_str())).unwrap_or_default();
+            let attack = if e.attack.is_empty() { String::new() } else { format!(" · {}", e.attack) };
+            Some(Marker {
+                index,
+                at_ms: e.timestamp_ms,
+                kind,
+                runtime: e.runtime,
+                duration_ms: e.duration_ms,
+                label: format!("{}{}{} - {}", e.kind.name(), runtime, attack, e.detail),
+            })
+        })
+        .collect()
+}
+
+/// a presenter's note pinned to a moment of the session
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    /// unix epoch ms
+    pub at_ms: f64,
+    pub text: String,
+}
+
+impl Annotation {
+    /// trimmed and capped; None for a blank note
+    pub fn new(at_ms: f64, text: &str) -> Option<Self> {
+        let text = text.trim();
+        (!text.is_empty()).then(|| Self { at_ms, text: text.chars().take(MAX_ANNOTATION_CHARS).collect() })
+    }
+}
+
+/// keeps the list in time order so exports read chronologically
+pub fn insert(annotations: &mut Vec<Annotation>, annotation: Annotation) {
+    let at = annotations.partition_point(|a| a.at_ms <= annotation.at_ms);
+    annotations.insert(at, annotation);
+}
+
+/// the strip's time axis, covering every marker and annotation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Axis {
+    pub start_ms: f64,
+    pub end_ms: f64,
+}
+
+impl Axis {
+    /// None for an empty session
+    pub fn span(times: impl IntoIterator<Item = f64>) -> Option<Self> {
+        let (start_ms, end_ms) = times.into_iter().fold((f64::MAX, f64::MIN), |(lo, hi), t| (lo.min(t), hi.max(t)));
+        (start_ms <= end_ms).then(|| Self { start_ms, end_ms: end_ms.max(start_ms + MIN_SPAN_MS) })
+    }
+
+    /// 0-100 along the strip
+    pub fn percent(&self, at_ms: f64) -> f64 {
+        ((at_ms - self.start_ms) / (self.end_ms - self.start_ms) * 100.0).clamp(0.0, 100.0)
+    }
+
+    /// "+1:02.5" since the first event
+    pub fn offset_label(&self, at_ms: f64) -> String {
+        let ms = (at_ms - self.start_ms).max(0.0);
+        let minutes = (ms / 60_000.0).floor();
+        format!("+{}:{:04.1}", minutes, (ms - minutes * 60_000.0) / 1000.0)
+    }
+}
@@ -0,0 +1,120 @@
+// what: incident timeline strip above the terminals - journal markers, hover to highlight their log lines, click to pin, presenter notes
+// why: a presenter walking an audience back through the session needs "this trap, these lines" without scrolling three terminals
+// relations: markers and axis from timeline.rs, journal and annotations from store.rs, terminals via runtime_model.rs / terminal.rs;
+//            rendered by component.rs; notes end up in the snapshot_panel.rs and audit_panel.rs exports
+
+use leptos::*;
+use super::journal::Runtime;
+use super::runtime_model::model;
+use super::store::DemoStore;
+use super::timeline::{insert, markers, Annotation, Axis, Marker};
+
+const RUNTIMES: [Runtime; 3] = [Runtime::Python, Runtime::Wasm, Runtime::Js];
+
+#[component]
+pub fn TimelinePanel(store: DemoStore) -> impl IntoView {
+    let journal = store.journal;
+    let annotations = store.annotations;
+    let marks = create_memo(move |_| journal.with(|j| markers(j)));
+    let axis = create_memo(move |_| {
+        let notes = annotations.with(|a| a.iter().map(|n| n.at_ms).collect::<Vec<_>>());
+        marks.with(|m| Axis::span(m.iter().map(|m| m.at_ms).chain(notes)))
+    });
+    let (hovered, set_hovered) = create_signal(Option::<Marker>::None);
+    let (pinned, set_pinned) = create_signal(Option::<Marker>::None);
+    let active = move || hovered.get().or_else(|| pinned.get());
+    let (draft, set_draft) = create_signal(String::new());
+
+    // the hovered (else pinned) marker lights its window in the terminals it touched
+    create_effect(move |_| {
+        let marker = active();
+        for runtime in RUNTIMES {
+            model(runtime).log(&store).highlight(marker.as_ref().filter(|m| m.touches(runtime)).map(Marker::window));
+        }
+    });
+    // a journal restored from a snapshot invalidates the pin
+    create_effect(move |_| {
+        marks.track();
+        set_pinned.set(None);
+    });
+
+    let detail = move || {
+        let marker = active()?;
+        let offset = axis.get().map(|a| a.offset_label(marker.at_ms)).unwrap_or_default();
+        let lines: usize = RUNTIMES.iter().filter(|r| marker.touches(**r)).map(|r| model(*r).log(&store).count_within(marker.window())).sum();
+        Some(format!("{} {} {} · {} terminal line(s) highlighted", offset, marker.kind.icon(), marker.label, lines))
+    };
+
+    let annotate = move |_| {
+        let at_ms = pinned.get_untracked().map(|m| m.at_ms).unwrap_or_else(js_sys::Date::now);
+        if let Some(note) = Annotation::new(at_ms, &draft.get_untracked()) {
+            annotations.update(|list| insert(list, note));
+            set_draft.set(String::new());
+        }
+    };
+
+    view! {
+        <div class="timeline-panel">
+            <h4>
+                "🕒 Incident Timeline"
+                <span class="demo-badge">{move || format!("{} markers · {} notes", marks.with(Vec::len), annotations.with(Vec::len))}</span>
+            </h4>
+            {move || match axis.get() {
+                None => view! { <p class="timeline-empty">"No incidents yet — run an attack and its trap, election and recovery land here."</p> }.into_view(),
+                Some(axis) => view! {
+                    <div class="timeline-strip">
+                        {marks.get().into_iter().map(|m| {
+                            let (hover, click) = (m.clone(), m.clone());
+                            let index = m.index;
+                            view! {
+                                <button
+                                    class=format!("timeline-marker {}", m.kind.css_class())
+                                    class:pinned=move || pinned.with(|p| p.as_ref().is_some_and(|p| p.index == index))
+                                    style=format!("left: {:.2}%", axis.percent(m.at_ms))
+                                    title=m.label.clone()
+                                    on:mouseenter=move |_| set_hovered.set(Some(hover.clone()))
+                                    on:mouseleave=move |_| set_hovered.set(None)
+                                    on:click=move |_| set_pinned.update(|p| *p = if p.as_ref().is_some_and(|p| p.index == index) { None } else { Some(click.clone()) })
+                                >
+                                    {m.kind.icon()}
+                                </button>
+                            }
+                        }).collect_view()}
+                        {annotations.get().into_iter().map(|n| view! {
+                            <span class="timeline-note" style=format!("left: {:.2}%", axis.percent(n.at_ms)) title=n.text.clone()>"📝"</span>
+                        }).collect_view()}
+                    </div>
+                    <div class="timeline-axis">
+                        <span>{axis.offset_label(axis.start_ms)}</span>
+                        <span>{axis.offset_label(axis.end_ms)}</span>
+                    </div>
+                }.into_view(),
+            }}
+            <p class="timeline-detail">{move || detail().unwrap_or_else(|| "Hover a marker to highlight its log lines; click to pin it.".to_string())}</p>
+            <div class="audit-controls">
+                <input
+                    class="collector-url"
+                    type="text"
+                    maxlength="140"
+                    placeholder=move || if pinned.get().is_some() { "Note on the pinned marker" } else { "Note at the current moment" }
+                    prop:value=move || draft.get()
+                    on:input=move |ev| set_draft.set(event_target_value(&ev))
+                    on:keydown=move |ev| if ev.key() == "Enter" { annotate(()) }
+                />
+                <button class="action-btn" disabled=move || draft.with(|d| d.trim().is_empty()) on:click=move |_| annotate(())>"📌 Annotate"</button>
+            </div>
+            <ul class="timeline-notes">
+                {move || {
+                    let axis = axis.get();
+                    annotations.get().into_iter().enumerate().map(|(i, n)| view! {
+                        <li>
+                            <span class="timeline-offset">{axis.map(|a| a.offset_label(n.at_ms)).unwrap_or_default()}</span>
+                            {n.text}
+                            <button class="timeline-remove" title="Remove note" on:click=move |_| annotations.update(|list| { list.remove(i); })>"✕"</button>
+                        </li>
+                    }).collect_view()
+                }}
+            </ul>
+        </div>
+    }
+}
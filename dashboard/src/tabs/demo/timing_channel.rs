@@ -0,0 +1,121 @@
+// what: timing side-channel scenario model - the clocks each runtime can read, their measured granularity and jitter, and how well a leaky compare can be read through them
+// why: a timing attack needs a fine clock; python reads the host clock directly, a wasm module only reads the clock its host chooses to import
+// relations: module and victim from modules.rs (CLOCK_WASM, LEAK_VICTIM_JS, CLOCK_PY); measured by timing_channel_panel.rs; stats from measure/stats.rs
+
+use crate::measure::stats::median;
+
+/// clock steps observed per clock
+pub const CLOCK_STEPS: usize = 200;
+/// timed (right guess, wrong guess) pairs per leak run
+pub const LEAK_TRIALS: usize = 200;
+/// granularity of the coarsened clock a host can import instead of the real one
+pub const COARSE_CLOCK_MS: f64 = 1.0;
+/// victim loop iterations per matching byte (tens of µs in a jit)
+pub const LEAK_WORK: i32 = 20_000;
+/// the value the victim compares against
+pub const LEAK_SECRET: u32 = 0x5a3c_7e11;
+/// first byte right: the victim works one byte longer before it exits
+pub const RIGHT_GUESS: u32 = 0x5a00_0000;
+/// first byte wrong: the victim exits at once
+pub const WRONG_GUESS: u32 = 0xa500_0000;
+
+/// a clock a runtime can read, and who decided it could
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Clock {
+    /// time.perf_counter() in pyodide, no grant involved
+    Python,
+    /// clock.now imported as performance.now()
+    WasmRaw,
+    /// clock.now imported as performance.now() floored to COARSE_CLOCK_MS
+    WasmCoarse,
+    /// no clock.now import: the module does not link
+    WasmNone,
+}
+
+/// every clock, in the order the panel shows them
+pub const CLOCKS: [Clock; 4] = [Clock::Python, Clock::WasmRaw, Clock::WasmCoarse, Clock::WasmNone];
+
+impl Clock {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Clock::Python => "🐍 Python time.perf_counter()",
+            Clock::WasmRaw => "🦀 WASM, host imports the real clock",
+            Clock::WasmCoarse => "🦀 WASM, host imports a coarsened clock",
+            Clock::WasmNone => "🦀 WASM, no clock import",
+        }
+    }
+
+    /// who decides what this clock can resolve
+    pub fn grant(&self) -> &'static str {
+        match self {
+            Clock::Python => "ambient: any script may read it",
+            Clock::WasmRaw => "host grant (wasi:clocks passed through)",
+            Clock::WasmCoarse => "host grant, floored before the guest sees it",
+            Clock::WasmNone => "not granted",
+        }
+    }
+}
+
+/// what the coarsened clock returns for a real reading
+pub fn quantize(ms: f64, step_ms: f64) -> f64 {
+    (ms / step_ms).floor() * step_ms
+}
+
+/// granularity of a clock from its observed steps (each the gap between two differing readings)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClockProfile {
+    /// smallest step seen
+    pub resolution_ms: f64,
+    pub median_step_ms: f64,
+    /// standard deviation of the steps; 0 for a clean quantized clock
+    pub jitter_ms: f64,
+    pub steps: usize,
+}
+
+impl ClockProfile {
+    pub fn from_steps(steps: &[f64]) -> Self {
+        let steps: Vec<f64> = steps.iter().copied().filter(|s| *s > 0.0).collect();
+        if steps.is_empty() {
+            return Self::default();
+        }
+        let mean = steps.iter().sum::<f64>() / steps.len() as f64;
+        let variance = steps.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / steps.len() as f64;
+        Self {
+            resolution_ms: steps.iter().copied().fold(f64::INFINITY, f64::min),
+            median_step_ms: median(&steps),
+            jitter_ms: variance.sqrt(),
+            steps: steps.len(),
+        }
+    }
+}
+
+/// share of (right, wrong) pairs where the right guess timed longer; a tie counts half (0.5 = coin flip)
+pub fn leak_success(pairs: &[(f64, f64)]) -> f64 {
+    if pairs.is_empty() {
+        return 0.5;
+    }
+    let score: f64 = pairs
+        .iter()
+        .map(|(right, wrong)| if right > wrong { 1.0 } else if right < wrong { 0.0 } else { 0.5 })
+        .sum();
+    score / pairs.len() as f64
+}
+
+/// mean of right minus wrong, what the clock saw of the one extra byte
+pub fn leak_signal_ms(pairs: &[(f64, f64)]) -> f64 {
+    if pairs.is_empty() {
+        return 0.0;
+    }
+    pairs.iter().map(|(right, wrong)| right - wrong).sum::<f64>() / pairs.len() as f64
+}
+
+/// how usable the leak is from one timed call per guess
+pub fn leak_verdict(success: f64) -> (&'static str, &'static str) {
+    if success >= 0.9 {
+        ("error", "leaks: one call per guess reads the byte")
+    } else if success >= 0.6 {
+        ("warning", "partial: needs many repeated calls")
+    } else {
+        ("success", "blunted: close to a coin flip")
+    }
+}
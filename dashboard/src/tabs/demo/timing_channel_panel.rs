@@ -0,0 +1,175 @@
+// what: timing side-channel scenario - measures each clock's granularity and jitter, then times a leaky compare through the wasm clocks
+// why: shows with real numbers that the timer is a capability: a coarse or absent clock import blunts the attack, python's clock is ambient
+// relations: model in timing_channel.rs, CLOCK_WASM/LEAK_VICTIM_JS/CLOCK_PY from modules.rs, python via wasm.rs runPython; mounted in component.rs
+
+use leptos::*;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use crate::measure::timing::cross_origin_isolated;
+use super::modules::{CLOCK_PY, CLOCK_WASM, LEAK_VICTIM_JS};
+use super::timing_channel::{
+    leak_signal_ms, leak_success, leak_verdict, quantize, Clock, ClockProfile, CLOCKS, CLOCK_STEPS, COARSE_CLOCK_MS, LEAK_SECRET,
+    LEAK_TRIALS, LEAK_WORK, RIGHT_GUESS, WRONG_GUESS,
+};
+use super::wasm::{ensurePyodide, now, runPython};
+
+/// a clock's profile and, when the guest could time calls, the (right, wrong) guess pairs
+type Measured = (ClockProfile, Option<Vec<(f64, f64)>>);
+
+/// one clock's measurements, or why it could not be read
+#[derive(Clone, Debug, PartialEq)]
+struct Row {
+    clock: Clock,
+    result: Result<Measured, String>,
+}
+
+fn js_error(e: JsValue) -> String {
+    e.dyn_ref::<js_sys::Error>().map(|e| String::from(e.to_string())).unwrap_or_else(|| format!("{:?}", e))
+}
+
+fn export_fn(exports: &js_sys::Object, name: &str) -> Result<js_sys::Function, String> {
+    js_sys::Reflect::get(exports, &name.into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+        .ok_or_else(|| format!("missing export {}", name))
+}
+
+/// the clock.now import a host hands the module; None links no clock at all
+fn clock_import(clock: Clock) -> Option<js_sys::Function> {
+    match clock {
+        Clock::WasmRaw => Some(js_sys::Function::new_no_args("return performance.now();")),
+        Clock::WasmCoarse => Some(Closure::<dyn Fn() -> f64>::new(|| quantize(now(), COARSE_CLOCK_MS)).into_js_value().unchecked_into()),
+        Clock::Python | Clock::WasmNone => None,
+    }
+}
+
+/// instantiates CLOCK_WASM with the given clock, reads CLOCK_STEPS steps and times LEAK_TRIALS guess pairs
+fn measure_wasm(clock: Clock) -> Result<Measured, String> {
+    let victim = js_sys::Function::new_with_args("secret, work", LEAK_VICTIM_JS)
+        .call2(&JsValue::NULL, &LEAK_SECRET.into(), &LEAK_WORK.into())
+        .map_err(js_error)?;
+    let clock_ns = js_sys::Object::new();
+    if let Some(now) = clock_import(clock) {
+        js_sys::Reflect::set(&clock_ns, &"now".into(), &now).map_err(js_error)?;
+    }
+    let victim_ns = js_sys::Object::new();
+    js_sys::Reflect::set(&victim_ns, &"check".into(), &victim).map_err(js_error)?;
+    let imports = js_sys::Object::new();
+    js_sys::Reflect::set(&imports, &"clock".into(), &clock_ns).map_err(js_error)?;
+    js_sys::Reflect::set(&imports, &"victim".into(), &victim_ns).map_err(js_error)?;
+    let module = js_sys::WebAssembly::Module::new(&js_sys::Uint8Array::from(CLOCK_WASM)).map_err(js_error)?;
+    // without clock.now the engine refuses to link: the guest never gets to run
+    let exports = js_sys::WebAssembly::Instance::new(&module, &imports).map_err(js_error)?.exports();
+    let (tick, time_check) = (export_fn(&exports, "tick")?, export_fn(&exports, "time_check")?);
+    let call = |f: &js_sys::Function, arg: &JsValue| f.call1(&JsValue::NULL, arg).map_err(js_error).map(|v| v.as_f64().unwrap_or(0.0));
+    let steps = (0..CLOCK_STEPS).map(|_| call(&tick, &JsValue::UNDEFINED)).collect::<Result<Vec<_>, _>>()?;
+    let (right, wrong) = (JsValue::from(RIGHT_GUESS as i32), JsValue::from(WRONG_GUESS as i32));
+    let mut pairs = Vec::with_capacity(LEAK_TRIALS);
+    for trial in 0..LEAK_TRIALS {
+        // alternate the order so drift or warm-up cannot favour one guess
+        let pair = if trial % 2 == 0 {
+            let r = call(&time_check, &right)?;
+            (r, call(&time_check, &wrong)?)
+        } else {
+            let w = call(&time_check, &wrong)?;
+            (call(&time_check, &right)?, w)
+        };
+        pairs.push(pair);
+    }
+    Ok((ClockProfile::from_steps(&steps), Some(pairs)))
+}
+
+async fn measure_python() -> Result<Measured, String> {
+    ensurePyodide().await.map_err(|_| "Pyodide unavailable".to_string())?;
+    let result = runPython(&CLOCK_PY.replace("{steps}", &CLOCK_STEPS.to_string())).await.map_err(js_error)?;
+    let steps: Vec<f64> = serde_json::from_str(&result.as_string().unwrap_or_default()).map_err(|e| e.to_string())?;
+    Ok((ClockProfile::from_steps(&steps), None))
+}
+
+fn ms_text(ms: f64) -> String {
+    if ms >= 1.0 { format!("{:.2}ms", ms) } else { format!("{:.1}µs", ms * 1000.0) }
+}
+
+#[component]
+pub fn TimingChannelPanel() -> impl IntoView {
+    let (running, set_running) = create_signal(false);
+    let rows = create_rw_signal(Vec::<Row>::new());
+
+    let run = move |_| {
+        if running.get() { return; }
+        set_running.set(true);
+        rows.set(Vec::new());
+        spawn_local(async move {
+            for clock in CLOCKS {
+                let result = match clock {
+                    Clock::Python => measure_python().await,
+                    _ => measure_wasm(clock),
+                };
+                tracing::info!(target: "measure", ?clock, ok = result.is_ok(), "timing channel clock");
+                rows.update(|r| r.push(Row { clock, result }));
+            }
+            set_running.set(false);
+        });
+    };
+
+    view! {
+        <div class="demo-section timing-channel">
+            <h3>"⏱️ Timing Side Channel"</h3>
+            <p class="section-desc">{format!(
+                "A victim compares a guess to a secret byte by byte and stops at the first mismatch, so a right first byte costs {} extra loop iterations. Whoever can time it precisely can read the secret one byte at a time. Each clock below is measured ({} steps), then used to time {} pairs of right and wrong guesses.",
+                LEAK_WORK, CLOCK_STEPS, LEAK_TRIALS
+            )}</p>
+            <button class="action-btn simulation-btn" disabled=move || running.get() on:click=run>
+                {move || if running.get() { "⏳ Measuring clocks..." } else { "▶️ Measure Clocks & Leak" }}
+            </button>
+            <Show when=move || rows.with(|r| !r.is_empty())>
+                <table class="capability-matrix">
+                    <tr>
+                        <th>"Clock"</th>
+                        <th>"Granted by"</th>
+                        <th>"Resolution"</th>
+                        <th>"Jitter (σ of steps)"</th>
+                        <th>"Secret byte from one call each"</th>
+                    </tr>
+                    {move || rows.get().into_iter().map(|row| match row.result {
+                        Ok((profile, pairs)) => {
+                            let leak = match pairs {
+                                Some(pairs) => {
+                                    let success = leak_success(&pairs);
+                                    let (class, verdict) = leak_verdict(success);
+                                    view! {
+                                        <td class=class title=format!("mean signal {} per extra byte", ms_text(leak_signal_ms(&pairs)))>
+                                            {format!("{:.0}% right - {}", success * 100.0, verdict)}
+                                        </td>
+                                    }.into_view()
+                                }
+                                None => view! { <td class="metrics-note">"ambient clock, no host in between to coarsen it"</td> }.into_view(),
+                            };
+                            view! {
+                                <tr>
+                                    <td>{row.clock.label()}</td>
+                                    <td class="metrics-note">{row.clock.grant()}</td>
+                                    <td title=format!("median step {}", ms_text(profile.median_step_ms))>{ms_text(profile.resolution_ms)}</td>
+                                    <td>{ms_text(profile.jitter_ms)}</td>
+                                    {leak}
+                                </tr>
+                            }
+                        }
+                        Err(reason) => view! {
+                            <tr>
+                                <td>{row.clock.label()}</td>
+                                <td class="metrics-note">{row.clock.grant()}</td>
+                                <td colspan="3" class={if row.clock == Clock::WasmNone { "success" } else { "warning" }}>
+                                    {if row.clock == Clock::WasmNone { format!("Module does not link - it cannot read time: {}", reason) } else { format!("Not measured: {}", reason) }}
+                                </td>
+                            </tr>
+                        },
+                    }).collect_view()}
+                </table>
+            </Show>
+            <p class="metrics-note">{move || format!(
+                "This page {} cross-origin isolated, so performance.now() itself is coarsened by the browser. Coarse timers blunt single-shot timing but do not close the channel: averaging many calls recovers a large enough leak. The dependable defence is the capability - a component without wasi:clocks (and without threads to build a counting timer) has no stopwatch at all. On the Pi, Python reads CLOCK_MONOTONIC at nanosecond resolution with nothing in between.",
+                if cross_origin_isolated() { "is" } else { "is not" }
+            )}</p>
+        </div>
+    }
+}
@@ -2,8 +2,10 @@
 // why: separates data structures from UI logic for better maintainability
 // relations: used by component.rs, attacks.rs; part of tabs/demo module
 
+use serde::{Deserialize, Serialize};
+
 /// log entry for terminal output display
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LogEntry {
     pub level: String,
     pub message: String,
@@ -16,10 +18,12 @@ pub struct AttackConfig {
     pub restart_ms: u32,
     pub wasm_trap: &'static str,
     pub wit_func: &'static str,
+    /// mitre att&ck for ics technique ids (catalog in mitre.rs)
+    pub techniques: &'static [&'static str],
 }
 
 /// wasm instance state for 2oo3 voting visualization
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum InstanceState {
     Healthy,
     Faulty,
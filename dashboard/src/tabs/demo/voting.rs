@@ -0,0 +1,229 @@
+// what: 2oo3 majority voter over the three wasm instances' outputs, with selectable comparison strategies
+// why: the demo's safety claim is that fewer than two agreeing outputs halts instead of guessing,
+//      and "agreeing" for analog readings is a policy choice (exact, tolerance band or median)
+// relations: used by handlers.rs (attacks, sensor run, quorum loss), attack_controls.rs (degraded banner) voting_panel.rs (strategy picker) and node_history.rs (votes won/lost)
+
+use serde::{Deserialize, Serialize};
+use super::types::InstanceState;
+
+/// agreeing outputs needed for a 2oo3 majority
+pub const QUORUM: usize = 2;
+/// default band for absolute tolerance (°C)
+pub const DEFAULT_ABS_TOLERANCE: f64 = 0.1;
+/// default band for relative tolerance (fraction of the larger reading)
+pub const DEFAULT_REL_TOLERANCE: f64 = 0.005;
+
+/// what the voter hands downstream
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VoteOutcome {
+    /// `agreeing` outputs matched on `value`
+    Consensus { value: f64, agreeing: usize },
+    /// fewer than QUORUM outputs agree: nothing is emitted (safe state)
+    Halted { healthy: usize },
+}
+
+impl VoteOutcome {
+    pub fn value(&self) -> Option<f64> {
+        match self {
+            VoteOutcome::Consensus { value, .. } => Some(*value),
+            VoteOutcome::Halted { .. } => None,
+        }
+    }
+}
+
+// ============================================================================
+// comparison strategies
+// ============================================================================
+
+/// how the voter decides that two outputs agree
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum VoteStrategy {
+    /// bit-identical values only
+    Exact,
+    /// within a fixed band, in the reading's unit
+    AbsoluteTolerance(f64),
+    /// within a fraction of the larger reading
+    RelativeTolerance(f64),
+    /// mid-value select: always emits the median, never flags anyone
+    MedianSelect,
+}
+
+impl Default for VoteStrategy {
+    fn default() -> Self {
+        VoteStrategy::AbsoluteTolerance(DEFAULT_ABS_TOLERANCE)
+    }
+}
+
+impl VoteStrategy {
+    /// every strategy at its default band
+    pub const ALL: [VoteStrategy; 4] = [
+        VoteStrategy::Exact,
+        VoteStrategy::AbsoluteTolerance(DEFAULT_ABS_TOLERANCE),
+        VoteStrategy::RelativeTolerance(DEFAULT_REL_TOLERANCE),
+        VoteStrategy::MedianSelect,
+    ];
+
+    /// stable key for the picker
+    pub fn id(&self) -> &'static str {
+        match self {
+            VoteStrategy::Exact => "exact",
+            VoteStrategy::AbsoluteTolerance(_) => "absolute",
+            VoteStrategy::RelativeTolerance(_) => "relative",
+            VoteStrategy::MedianSelect => "median",
+        }
+    }
+
+    /// strategy for a picker key at its default band
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.id() == id)
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            VoteStrategy::Exact => "Exact match".to_string(),
+            VoteStrategy::AbsoluteTolerance(t) => format!("Absolute ±{:.2}°C", t),
+            VoteStrategy::RelativeTolerance(f) => format!("Relative ±{:.2}%", f * 100.0),
+            VoteStrategy::MedianSelect => "Median select".to_string(),
+        }
+    }
+
+    /// same strategy with a new band (strategies without one are unchanged)
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        let tolerance = tolerance.max(0.0);
+        match self {
+            VoteStrategy::AbsoluteTolerance(_) => VoteStrategy::AbsoluteTolerance(tolerance),
+            VoteStrategy::RelativeTolerance(_) => VoteStrategy::RelativeTolerance(tolerance),
+            other => other,
+        }
+    }
+
+    fn agree(&self, a: f64, b: f64) -> bool {
+        match self {
+            VoteStrategy::Exact => a == b,
+            VoteStrategy::AbsoluteTolerance(t) => (a - b).abs() <= *t,
+            VoteStrategy::RelativeTolerance(f) => (a - b).abs() <= f * a.abs().max(b.abs()),
+            VoteStrategy::MedianSelect => true,
+        }
+    }
+
+    /// majority over per-instance outputs (None is a trapped or dead instance)
+    pub fn vote(&self, outputs: &[Option<f64>]) -> VoteOutcome {
+        let mut values: Vec<f64> = outputs.iter().flatten().copied().collect();
+        let healthy = values.len();
+        if healthy < QUORUM {
+            return VoteOutcome::Halted { healthy };
+        }
+        if *self == VoteStrategy::MedianSelect {
+            values.sort_by(f64::total_cmp);
+            let mid = healthy / 2;
+            let value = if healthy % 2 == 1 { values[mid] } else { (values[mid - 1] + values[mid]) / 2.0 };
+            return VoteOutcome::Consensus { value, agreeing: healthy };
+        }
+        // the largest group agreeing with one candidate; its mean is the voted value
+        let group = values
+            .iter()
+            .map(|v| values.iter().copied().filter(|w| self.agree(*v, *w)).collect::<Vec<f64>>())
+            .max_by_key(|g| g.len())
+            .unwrap_or_default();
+        if group.len() < QUORUM {
+            return VoteOutcome::Halted { healthy };
+        }
+        let value = if *self == VoteStrategy::Exact { group[0] } else { group.iter().sum::<f64>() / group.len() as f64 };
+        VoteOutcome::Consensus { value, agreeing: group.len() }
+    }
+
+    /// per output, whether it is in the group the vote emitted (all false when the vote halts)
+    pub fn winners(&self, outputs: &[Option<f64>]) -> Vec<bool> {
+        if self.vote(outputs).value().is_none() {
+            return vec![false; outputs.len()];
+        }
+        if *self == VoteStrategy::MedianSelect {
+            return outputs.iter().map(Option::is_some).collect();
+        }
+        // same candidate as vote(): the last of the largest agreeing groups
+        let values: Vec<f64> = outputs.iter().flatten().copied().collect();
+        let candidate = values
+            .iter()
+            .copied()
+            .max_by_key(|v| values.iter().filter(|w| self.agree(*v, **w)).count())
+            .unwrap_or_default();
+        outputs.iter().map(|o| o.is_some_and(|o| self.agree(candidate, o))).collect()
+    }
+}
+
+// ============================================================================
+// fault types the strategies are compared against
+// ============================================================================
+
+/// true temperature behind the comparison scenarios (°C)
+pub const SCENARIO_READING: f64 = 22.0;
+
+/// a non-trapping fault: the instance answers, but with the wrong value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FaultType {
+    /// a compromised instance reports a plausible but false value
+    Spoofing,
+    /// a slowly miscalibrating sensor, just past the default band
+    Drift,
+}
+
+impl FaultType {
+    pub const ALL: [FaultType; 2] = [FaultType::Spoofing, FaultType::Drift];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FaultType::Spoofing => "Spoofing (+4°C)",
+            FaultType::Drift => "Drift (+0.35°C)",
+        }
+    }
+
+    /// I0 and I1 read the real sensor with a little noise; I2 carries the fault
+    pub fn outputs(&self) -> [Option<f64>; 3] {
+        let faulty = match self {
+            FaultType::Spoofing => SCENARIO_READING + 4.0,
+            FaultType::Drift => SCENARIO_READING + 0.35,
+        };
+        [Some(SCENARIO_READING), Some(SCENARIO_READING + 0.03), Some(faulty)]
+    }
+}
+
+/// how a strategy handled one fault scenario
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScenarioVerdict {
+    /// a value went out and the faulty instance was outvoted
+    Flagged { value: f64 },
+    /// a value went out but nobody was flagged (the fault is masked silently)
+    Masked { value: f64 },
+    /// no majority, no output
+    Halted,
+}
+
+/// runs a strategy against a fault scenario
+pub fn evaluate(strategy: VoteStrategy, fault: FaultType) -> ScenarioVerdict {
+    let outputs = fault.outputs();
+    let live = outputs.iter().flatten().count();
+    match strategy.vote(&outputs) {
+        VoteOutcome::Consensus { value, agreeing } if agreeing < live => ScenarioVerdict::Flagged { value },
+        VoteOutcome::Consensus { value, .. } => ScenarioVerdict::Masked { value },
+        VoteOutcome::Halted { .. } => ScenarioVerdict::Halted,
+    }
+}
+
+// ============================================================================
+// instance health
+// ============================================================================
+
+/// instances still able to produce an output
+pub fn healthy_count(states: &[InstanceState]) -> usize {
+    states.iter().filter(|s| **s == InstanceState::Healthy).count()
+}
+
+/// a majority of instances is alive, so a vote can succeed
+pub fn has_quorum(states: &[InstanceState]) -> bool {
+    healthy_count(states) >= QUORUM
+}
+
+/// each instance's output for one reading: healthy instances report it, faulty ones report nothing
+pub fn outputs(states: &[InstanceState], reading: f64) -> Vec<Option<f64>> {
+    states.iter().map(|s| (*s == InstanceState::Healthy).then_some(reading)).collect()
+}
@@ -0,0 +1,130 @@
+// what: voter strategy picker, a strategies x fault types comparison table and the sensor drift scenario
+// why: "do the outputs agree" is a policy; viewers see what each choice does to spoofed and drifting readings
+// relations: edits the store.rs voter signal used by handlers.rs, renders voting.rs evaluate() and drift.rs detection(); rendered by component.rs
+
+use leptos::*;
+use super::drift::{detection, DriftRun, DriftStep, DRIFTING, DRIFT_BANDS, DRIFT_MAX_MIN, DRIFT_PER_MIN};
+use super::store::DemoStore;
+use super::voting::{evaluate, FaultType, ScenarioVerdict, VoteStrategy, SCENARIO_READING};
+
+/// one comparison cell
+fn verdict_cell(verdict: ScenarioVerdict) -> impl IntoView {
+    match verdict {
+        ScenarioVerdict::Flagged { value } => view! { <td class="vote-flagged">{format!("✅ {:.2}°C · I2 outvoted", value)}</td> },
+        ScenarioVerdict::Masked { value } => view! { <td class="vote-masked">{format!("⚠ {:.2}°C · nobody flagged", value)}</td> },
+        ScenarioVerdict::Halted => view! { <td class="vote-halted">"⛔ halted · no output"</td> },
+    }
+}
+
+/// when a strategy reacts to the drift, in simulated minutes
+fn detection_text(strategy: VoteStrategy) -> String {
+    match detection(strategy) {
+        Some((minute, DriftStep::Detected)) => format!("{} min (+{:.2}°C)", minute, DRIFT_PER_MIN * minute as f64),
+        Some((_, _)) => "halts at once".to_string(),
+        None => format!("never (>{} min)", DRIFT_MAX_MIN),
+    }
+}
+
+/// live drift scenario status
+fn drift_status(run: DriftRun) -> String {
+    let offset = run.offset();
+    match (run.step, run.recalibrated) {
+        (DriftStep::Tracking, _) => format!("t={} min · I{} +{:.2}°C · still agreeing", run.minute, DRIFTING, offset),
+        (DriftStep::Detected, false) => format!("t={} min · I{} outvoted at +{:.2}°C · recalibrating…", run.minute, DRIFTING, offset),
+        (DriftStep::Detected, true) => format!("t={} min · I{} outvoted at +{:.2}°C · recalibrated ✅", run.minute, DRIFTING, offset),
+        (DriftStep::Halted, _) => format!("t={} min · no two outputs agree · output halted", run.minute),
+    }
+}
+
+#[component]
+pub fn VotingPolicyPanel(store: DemoStore) -> impl IntoView {
+    let DemoStore { voter, cluster, control, .. } = store;
+    // the selected row uses the live band, the others their defaults
+    let rows = move || {
+        let selected = voter.get();
+        VoteStrategy::ALL.map(|s| if s.id() == selected.id() { selected } else { s })
+    };
+    let tolerance = move || match voter.get() {
+        VoteStrategy::AbsoluteTolerance(t) => Some((t, 0.01, "°C")),
+        VoteStrategy::RelativeTolerance(f) => Some((f * 100.0, 0.1, "%")),
+        _ => None,
+    };
+
+    view! {
+        <div class="demo-section voting-section">
+            <h3>"🗳️ Voting Policy"<span class="attack-badge">"2oo3 Comparison"</span></h3>
+            <p class="section-desc">
+                {format!("How the voter decides two outputs agree — true reading {:.1}°C, I0/I1 differ by 0.03°C of sensor noise, I2 is faulty", SCENARIO_READING)}
+            </p>
+            <div class="voting-controls">
+                <select
+                    class="network-select"
+                    on:change=move |ev| {
+                        if let Some(strategy) = VoteStrategy::from_id(&event_target_value(&ev)) {
+                            voter.set(strategy);
+                        }
+                    }
+                >
+                    {VoteStrategy::ALL.into_iter().map(|s| view! {
+                        <option value=s.id() selected=move || voter.get().id() == s.id()>{s.label()}</option>
+                    }).collect_view()}
+                </select>
+                {move || tolerance().map(|(value, step, unit)| view! {
+                    <label class="voting-tolerance">
+                        "Band ±"
+                        <input
+                            type="number"
+                            min="0"
+                            step=step.to_string()
+                            prop:value=format!("{}", (value * 1000.0).round() / 1000.0)
+                            on:change=move |ev| {
+                                if let Ok(v) = event_target_value(&ev).parse::<f64>() {
+                                    let band = if unit == "%" { v / 100.0 } else { v };
+                                    voter.update(|s| *s = s.with_tolerance(band));
+                                }
+                            }
+                        />
+                        {unit}
+                    </label>
+                })}
+            </div>
+            <table class="capability-matrix voting-matrix">
+                <tr>
+                    <th>"Strategy"</th>
+                    {FaultType::ALL.into_iter().map(|f| view! { <th>{f.label()}</th> }).collect_view()}
+                </tr>
+                {move || rows().into_iter().map(|s| view! {
+                    <tr class:voting-selected=s == voter.get()>
+                        <td class="world-name">{s.label()}</td>
+                        {FaultType::ALL.into_iter().map(|f| verdict_cell(evaluate(s, f))).collect_view()}
+                    </tr>
+                }).collect_view()}
+            </table>
+            
+            <h4>"📉 Sensor drift & recalibration"</h4>
+            <p class="section-desc">
+                {format!("I{} drifts +{}°C per simulated minute until the voter outvotes it, then it is hot-swapped — detection time by band:", DRIFTING, DRIFT_PER_MIN)}
+            </p>
+            <table class="capability-matrix voting-matrix">
+                <tr>
+                    {DRIFT_BANDS.map(|band| view! { <th>{format!("±{}°C", band)}</th> }).into_iter().collect_view()}
+                    <th>{move || format!("Current: {}", voter.get().label())}</th>
+                </tr>
+                <tr>
+                    {DRIFT_BANDS.map(|band| view! { <td>{detection_text(VoteStrategy::AbsoluteTolerance(band))}</td> }).into_iter().collect_view()}
+                    <td class="world-name">{move || detection_text(voter.get())}</td>
+                </tr>
+            </table>
+            <div class="voting-controls">
+                <button
+                    class="action-btn"
+                    disabled=move || control.is_running.get() || !store.has_quorum()
+                    on:click=move |_| store.run_drift_scenario()
+                >
+                    "📉 Run Drift Scenario"
+                </button>
+                {move || cluster.drift.get().map(|run| view! { <span class="drift-status">{drift_status(run)}</span> })}
+            </div>
+        </div>
+    }
+}
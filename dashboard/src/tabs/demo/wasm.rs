@@ -4,6 +4,9 @@
 
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
+use crate::measure::stats::Summary;
+use crate::measure::timing::sample;
+use crate::measure::worker;
 
 // ============================================================================
 // wasm measurement (real webassembly api calls)
@@ -29,10 +32,32 @@ extern "C" {
     // to avoid deprecated JsStatic warnings
     #[wasm_bindgen(catch, js_namespace = window)]
     pub async fn runPython(code: &str) -> Result<JsValue, JsValue>;
+    
+    // Lazily inject pyodide.js and start the runtime (defined in index.html)
+    #[wasm_bindgen(catch, js_namespace = window)]
+    pub async fn ensurePyodide() -> Result<JsValue, JsValue>;
+
+    // Import the jco-transpiled sensor-driver component once (defined in index.html)
+    #[wasm_bindgen(catch, js_namespace = window)]
+    pub async fn loadSensorComponent() -> Result<JsValue, JsValue>;
+
+    // Import the attack-code module on first visit to the demo tab (defined in index.html)
+    #[wasm_bindgen(catch, js_namespace = window)]
+    pub async fn loadAttackCode() -> Result<JsValue, JsValue>;
 }
 
-/// measure wasm instantiation time (averaged over 10 iterations)
-pub async fn measure_instantiate_time() -> f64 {
+/// measure wasm instantiation time (warm-up discarded, outliers rejected, window counting on coarse timers)
+#[tracing::instrument(target = "measure", level = "info", name = "instantiate")]
+pub async fn measure_instantiate_time(iterations: u32) -> Summary {
+    // Prefer the measurement worker so main-thread rendering doesn't pollute the numbers
+    match worker::instantiate(MINIMAL_WASM, iterations).await {
+        Ok((summary, method)) => {
+            tracing::info!(target: "measure", median_ms = summary.median, ?method, "measured in worker");
+            return summary;
+        }
+        Err(e) => tracing::warn!(target: "measure", error = %e, "worker unavailable, timing on the main thread"),
+    }
+    
     let array = js_sys::Uint8Array::from(MINIMAL_WASM);
     let compile_promise = js_sys::WebAssembly::compile(&array.buffer());
     let module: js_sys::WebAssembly::Module = wasm_bindgen_futures::JsFuture::from(compile_promise)
@@ -40,19 +65,18 @@ pub async fn measure_instantiate_time() -> f64 {
         .unwrap()
         .unchecked_into();
     
-    // Run 10 iterations and average for more accurate sub-millisecond timing
-    let iterations = 10;
-    let start = now();
-    
-    for _ in 0..iterations {
+    // Time each instantiation separately so warm-up and gc spikes can be dropped
+    let (summary, _) = sample(iterations, || {
         let instantiate_promise = js_sys::WebAssembly::instantiate_module(
             &module,
             &js_sys::Object::new()
         );
-        let _ = wasm_bindgen_futures::JsFuture::from(instantiate_promise).await;
-    }
-    
-    (now() - start) / iterations as f64
+        async move {
+            let _ = wasm_bindgen_futures::JsFuture::from(instantiate_promise).await;
+        }
+    }).await;
+    tracing::info!(target: "measure", median_ms = summary.median, "measured on main thread");
+    summary
 }
 
 // ============================================================================
@@ -69,3 +93,114 @@ pub fn set_timeout<F: FnOnce() + 'static>(cb: F, dur: std::time::Duration) {
     ).unwrap();
     closure.forget();
 }
+
+/// bytes of this module's own linear memory
+pub fn module_memory_bytes() -> f64 {
+    wasm_bindgen::memory()
+        .dyn_into::<js_sys::WebAssembly::Memory>()
+        .map(|m| m.buffer().unchecked_into::<js_sys::ArrayBuffer>().byte_length() as f64)
+        .unwrap_or(0.0)
+}
+
+/// trigger a browser download of a text file
+pub fn download_text(file_name: &str, mime: &str, contents: &str) {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime);
+    let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options) else { return };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else { return };
+    
+    let document = web_sys::window().unwrap().document().unwrap();
+    if let Ok(anchor) = document.create_element("a") {
+        let anchor: web_sys::HtmlAnchorElement = anchor.unchecked_into();
+        anchor.set_href(&url);
+        anchor.set_download(file_name);
+        anchor.click();
+    }
+    let _ = web_sys::Url::revoke_object_url(&url);
+}
+
+/// post a text body to a url, returns the http status
+pub async fn post_text(url: &str, content_type: &str, body: &str) -> Result<u16, String> {
+    post_text_with_headers(url, content_type, &[], body).await
+}
+
+/// post a text body with extra request headers (e.g. authorization)
+pub async fn post_text_with_headers(url: &str, content_type: &str, headers: &[(&str, &str)], body: &str) -> Result<u16, String> {
+    let init = web_sys::RequestInit::new();
+    init.set_method("POST");
+    init.set_body(&JsValue::from_str(body));
+    
+    let request = web_sys::Request::new_with_str_and_init(url, &init)
+        .map_err(|e| format!("{:?}", e))?;
+    request.headers().set("Content-Type", content_type)
+        .map_err(|e| format!("{:?}", e))?;
+    for (name, value) in headers {
+        request.headers().set(name, value)
+            .map_err(|e| format!("{:?}", e))?;
+    }
+    
+    let window = web_sys::window().unwrap();
+    let response = wasm_bindgen_futures::JsFuture::from(window.fetch_with_request(&request))
+        .await
+        .map_err(|e| e.as_string().unwrap_or_else(|| "network error (check CORS)".to_string()))?;
+    let response: web_sys::Response = response.unchecked_into();
+    Ok(response.status())
+}
+
+/// GET a url and return the body bytes
+pub async fn fetch_bytes(url: &str) -> Result<Vec<u8>, String> {
+    let window = web_sys::window().ok_or("no window")?;
+    let response = wasm_bindgen_futures::JsFuture::from(window.fetch_with_str(url))
+        .await
+        .map_err(|e| e.as_string().unwrap_or_else(|| "network error".to_string()))?;
+    let response: web_sys::Response = response.unchecked_into();
+    if !response.ok() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    let buffer = wasm_bindgen_futures::JsFuture::from(response.array_buffer().map_err(|e| format!("{:?}", e))?)
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+/// read a user-picked file into memory
+pub async fn read_file_bytes(file: &web_sys::File) -> Result<Vec<u8>, String> {
+    let buffer = wasm_bindgen_futures::JsFuture::from(file.array_buffer())
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+thread_local! {
+    /// one audio context for every beep; browsers cap how many can be open at once
+    static AUDIO: std::cell::RefCell<Option<web_sys::AudioContext>> = const { std::cell::RefCell::new(None) };
+}
+
+/// short alert beep via web audio (fire and forget)
+pub fn play_alert(frequency_hz: f32, duration_ms: f64) {
+    let Some(ctx) = AUDIO.with(|slot| {
+        let mut slot = slot.borrow_mut();
+        if slot.is_none() {
+            *slot = web_sys::AudioContext::new().ok();
+        }
+        slot.clone()
+    }) else { return };
+    // a context created before any user gesture starts suspended
+    let _ = ctx.resume();
+    let (Ok(osc), Ok(gain)) = (ctx.create_oscillator(), ctx.create_gain()) else { return };
+    osc.frequency().set_value(frequency_hz);
+    gain.gain().set_value(0.1);
+    let _ = osc.connect_with_audio_node(&gain);
+    let _ = gain.connect_with_audio_node(&ctx.destination());
+    let start = ctx.current_time();
+    let _ = osc.start_with_when(start);
+    let _ = osc.stop_with_when(start + duration_ms / 1000.0);
+}
+
+/// copy text to the system clipboard (fire and forget)
+pub fn copy_to_clipboard(text: &str) {
+    if let Some(window) = web_sys::window() {
+        let _ = window.navigator().clipboard().write_text(text);
+    }
+}
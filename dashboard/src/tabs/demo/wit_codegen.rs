@@ -0,0 +1,156 @@
+// what: reads a wit interface and emits the browser-side mock host for it - one rust fn per wit func that logs the call and denies it
+// why: hand-written "denied" glue drifts from the contract; generating it from wit/attacks.wit keeps names, arity and types exact
+// relations: std-only so build.rs can include it with #[path]; output written to $OUT_DIR/mock_host.rs and include!d by mock_host.rs;
+//            also compiled into the crate so tests can regenerate and compare
+
+/// one `name: func(params) -> result;` declaration
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WitFunc {
+    pub name: String,
+    /// (name, wit type) in declaration order
+    pub params: Vec<(String, String)>,
+    /// wit return type; None for a func with no result
+    pub result: Option<String>,
+}
+
+/// body between `interface {name} {` and its closing brace, line comments stripped
+fn interface_body(wit: &str, name: &str) -> Result<String, String> {
+    let code: String = wit.lines().map(|l| l.split("//").next().unwrap_or("")).collect::<Vec<_>>().join("\n");
+    let header = format!("interface {} {{", name);
+    let start = code.find(&header).ok_or_else(|| format!("interface {} not found", name))? + header.len();
+    let mut depth = 1;
+    let end = code[start..]
+        .char_indices()
+        .find(|(_, c)| {
+            depth += match c { '{' => 1, '}' => -1, _ => 0 };
+            depth == 0
+        })
+        .map(|(i, _)| i)
+        .ok_or_else(|| format!("interface {} is not closed", name))?;
+    Ok(code[start..start + end].to_string())
+}
+
+/// splits on commas that are not inside <...> or (...)
+fn split_top_level(text: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut current = String::new();
+    for c in text.chars() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+
+/// every func declared in `interface`, in order; records, types and `use` lines are skipped
+pub fn parse_interface(wit: &str, interface: &str) -> Result<Vec<WitFunc>, String> {
+    let body = interface_body(wit, interface)?;
+    let mut funcs = Vec::new();
+    for statement in body.split(';').map(|s| s.split_whitespace().collect::<Vec<_>>().join(" ")) {
+        let Some((head, signature)) = statement.split_once(": func(") else { continue };
+        // a record or variant has no ';' of its own, so it can share a statement with the func after it
+        let name = head.rsplit(' ').next().unwrap_or(head);
+        let (params, rest) = signature.rsplit_once(')').ok_or_else(|| format!("{}: unclosed parameter list", name))?;
+        let params = split_top_level(params)
+            .into_iter()
+            .map(|p| {
+                let (pname, ty) = p.split_once(':').ok_or_else(|| format!("{}: parameter \"{}\" has no type", name, p))?;
+                Ok((pname.trim().to_string(), ty.trim().to_string()))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let result = rest.trim().strip_prefix("->").map(|r| r.trim().to_string());
+        funcs.push(WitFunc { name: name.to_string(), params, result });
+    }
+    if funcs.is_empty() {
+        return Err(format!("interface {} declares no functions", interface));
+    }
+    Ok(funcs)
+}
+
+/// rust spelling of a wit type; user-defined types are rejected rather than guessed
+pub fn rust_type(wit: &str) -> Result<String, String> {
+    let wit = wit.trim();
+    let generic = |prefix: &str| wit.strip_prefix(prefix).and_then(|r| r.strip_suffix('>')).map(split_top_level);
+    let args = |list: Vec<String>| list.iter().map(|t| rust_type(t)).collect::<Result<Vec<_>, String>>();
+    Ok(match wit {
+        "u8" | "u16" | "u32" | "u64" | "f32" | "f64" | "bool" | "char" => wit.to_string(),
+        "s8" | "s16" | "s32" | "s64" => format!("i{}", &wit[1..]),
+        "string" => "String".to_string(),
+        _ => {
+            if let Some([inner]) = generic("list<").as_deref() {
+                format!("Vec<{}>", rust_type(inner)?)
+            } else if let Some([inner]) = generic("option<").as_deref() {
+                format!("Option<{}>", rust_type(inner)?)
+            } else if let Some([ok, err]) = generic("result<").as_deref() {
+                format!("Result<{}, {}>", rust_type(ok)?, rust_type(err)?)
+            } else if let Some(items) = generic("tuple<") {
+                format!("({})", args(items)?.join(", "))
+            } else {
+                return Err(format!("unsupported wit type \"{}\"", wit));
+            }
+        }
+    })
+}
+
+fn snake(kebab: &str) -> String {
+    kebab.replace('-', "_")
+}
+
+/// rust source of the mock host for `interface`: a FUNCTIONS table, one denying fn per func, and invoke()
+pub fn generate(wit: &str, interface: &str) -> Result<String, String> {
+    let funcs = parse_interface(wit, interface)?;
+    let mut out = format!("// @generated by build.rs from the wit interface {} - do not edit\n\n", interface);
+    out.push_str(&format!("/// the wit interface these functions mock\npub const INTERFACE: &str = \"{}\";\n\n", interface));
+    out.push_str("/// every func of the interface, as declared\npub const FUNCTIONS: &[WitSignature] = &[\n");
+    for f in &funcs {
+        let params: Vec<String> = f.params.iter().map(|(n, t)| format!("(\"{}\", \"{}\")", n, t)).collect();
+        let result = f.result.as_ref().map_or("None".to_string(), |r| format!("Some(\"{}\")", r));
+        out.push_str(&format!("    WitSignature {{ name: \"{}\", params: &[{}], result: {} }},\n", f.name, params.join(", "), result));
+    }
+    out.push_str("];\n");
+    for f in &funcs {
+        let params = f.params.iter().map(|(n, t)| Ok(format!(", {}: {}", snake(n), rust_type(t)?))).collect::<Result<String, String>>()?;
+        let args: Vec<String> = f.params.iter().map(|(n, _)| format!("format!(\"{}: {{:?}}\", {})", n, snake(n))).collect();
+        let wit_sig = format!(
+            "{}: func({}){}",
+            f.name,
+            f.params.iter().map(|(n, t)| format!("{}: {}", n, t)).collect::<Vec<_>>().join(", "),
+            f.result.as_ref().map_or(String::new(), |r| format!(" -> {}", r))
+        );
+        let deny = format!("host.deny(\"{}\", vec![{}])", f.name, args.join(", "));
+        out.push_str(&format!("\n/// {}.{}\n", interface, wit_sig));
+        match f.result.as_deref() {
+            // a result type carries the denial back to the guest
+            Some(r) if r.starts_with("result<") => {
+                out.push_str(&format!("pub fn {}(host: &mut MockHost{}) -> {} {{\n    Err({})\n}}\n", snake(&f.name), params, rust_type(r)?, deny));
+            }
+            // anything else can only come back empty
+            Some(r) => {
+                out.push_str(&format!("pub fn {}(host: &mut MockHost{}) -> {} {{\n    {};\n    Default::default()\n}}\n", snake(&f.name), params, rust_type(r)?, deny));
+            }
+            None => {
+                out.push_str(&format!("pub fn {}(host: &mut MockHost{}) {{\n    {};\n}}\n", snake(&f.name), params, deny));
+            }
+        }
+    }
+    out.push_str("\n/// calls `func` with default arguments, the way a payload would; false if the interface has no such func\npub fn invoke(host: &mut MockHost, func: &str) -> bool {\n    match func {\n");
+    for f in &funcs {
+        let defaults = ", Default::default()".repeat(f.params.len());
+        let discard = if f.result.is_some() { "let _ = " } else { "" };
+        out.push_str(&format!("        \"{}\" => {{\n            {}{}(host{});\n        }}\n", f.name, discard, snake(&f.name), defaults));
+    }
+    out.push_str("        _ => return false,\n    }\n    true\n}\n");
+    Ok(out)
+}
@@ -1,14 +1,9 @@
-// what: purdue model architecture visualization component with click-to-toggle tooltips
-// why: shows iec 62443 zones with actual hardware placement and mobile-friendly tooltips
-// relations: used by hardware/component.rs as one of four sub-sections
+// what: purdue model architecture visualization component with glossary notes and terms
+// why: shows iec 62443 zones with actual hardware placement and tap-to-open notes
+// relations: used by hardware/component.rs as one of four sub-sections; ⓘ text and terms from crate::glossary
 
 use leptos::*;
-
-// tooltip text constants for easy editing
-const L3_TOOLTIP: &str = "Enterprise IT zone: Stores historical data, dashboards, and analytics. Separated from control systems by network segmentation.";
-const L2_TOOLTIP: &str = "WASM Runtime with WIT Contracts: The Guardian Cluster runs WebAssembly modules in a sandboxed environment. Each capability (Modbus, GPIO, Network) must be explicitly granted via WIT contracts. In production, this layer typically runs inside a Docker container (the 'Mothership'). WASM modules are hot-swapped inside for fault isolation — combining Docker's deployment tooling with WASM's granular security.";
-const L1_TOOLTIP: &str = "Industrial PLC: Executes real-time control logic. Receives validated commands from Level 2 via Modbus RTU protocol.";
-const L0_TOOLTIP: &str = "Physical sensors and actuators: Direct hardware interface. BME280 reads temperature/humidity, relay controls the industrial fan.";
+use crate::glossary::{InfoTip, Term};
 
 /// renders the purdue model zones diagram showing hardware at each level
 #[component]
@@ -23,7 +18,7 @@ pub fn ArchitectureSection() -> impl IntoView {
                 <PurdueLevel 
                     level="L3"
                     name="Operations Management"
-                    tooltip=L3_TOOLTIP
+                    note="purdue.l3"
                     class="level-3"
                 >
                     <div class="level-components">
@@ -39,7 +34,7 @@ pub fn ArchitectureSection() -> impl IntoView {
                 <PurdueLevel 
                     level="L2"
                     name="Supervisory Control — Guardian Cluster"
-                    tooltip=L2_TOOLTIP
+                    note="purdue.l2"
                     class="level-2 guardian-zone"
                 >
                     // wit and wasm technology badges
@@ -50,7 +45,7 @@ pub fn ArchitectureSection() -> impl IntoView {
                         </div>
                         <div class="tech-badge">
                             <img src="diagrams/wit_icon.png" alt="WIT" class="tech-icon" />
-                            <span>"WIT Contract"</span>
+                            <span><Term id="wit" />" Contract"</span>
                         </div>
                     </div>
                     
@@ -59,7 +54,7 @@ pub fn ArchitectureSection() -> impl IntoView {
                         <ClusterNode status="green" name="Pi Zero" role="FOLLOWER" />
                         <ClusterNode status="green" name="Pi Zero" role="FOLLOWER" />
                     </div>
-                    <div class="cluster-label">"2oo3 TMR + Raft Consensus"</div>
+                    <div class="cluster-label"><Term id="2oo3" />" "<Term id="tmr" />" + Raft Consensus"</div>
                 </PurdueLevel>
                 
                 <div class="flow-arrow">"▼"</div>
@@ -68,7 +63,7 @@ pub fn ArchitectureSection() -> impl IntoView {
                 <PurdueLevel 
                     level="L1"
                     name="Local Control"
-                    tooltip=L1_TOOLTIP
+                    note="purdue.l1"
                     class="level-1"
                 >
                     <div class="level-components">
@@ -83,7 +78,7 @@ pub fn ArchitectureSection() -> impl IntoView {
                 <PurdueLevel 
                     level="L0"
                     name="Field Devices"
-                    tooltip=L0_TOOLTIP
+                    note="purdue.l0"
                     class="level-0"
                 >
                     <div class="level-components">
@@ -96,16 +91,15 @@ pub fn ArchitectureSection() -> impl IntoView {
     }
 }
 
-/// purdue level wrapper with click-to-toggle tooltip
+/// purdue level wrapper with its glossary note
 #[component]
 fn PurdueLevel(
     level: &'static str,
     name: &'static str,
-    tooltip: &'static str,
+    note: &'static str,
     class: &'static str,
     children: Children,
 ) -> impl IntoView {
-    let (show_tooltip, set_show_tooltip) = create_signal(false);
     let full_class = format!("purdue-level {}", class);
     
     view! {
@@ -113,28 +107,8 @@ fn PurdueLevel(
             <div class="level-header">
                 <span class="level-badge">{level}</span>
                 <span class="level-name">{name}</span>
-                <button 
-                    class="info-btn"
-                    on:click=move |_| set_show_tooltip.update(|v| *v = !*v)
-                >
-                    "ⓘ"
-                </button>
+                <InfoTip note=note />
             </div>
-            <Show when=move || show_tooltip.get()>
-                <div 
-                    class="tooltip-overlay" 
-                    on:click=move |_| set_show_tooltip.set(false)
-                />
-                <div class="tooltip-popup">
-                    <div class="tooltip-content">{tooltip}</div>
-                    <button 
-                        class="tooltip-close"
-                        on:click=move |_| set_show_tooltip.set(false)
-                    >
-                        "✕"
-                    </button>
-                </div>
-            </Show>
             {children()}
         </div>
     }
@@ -0,0 +1,196 @@
+// what: cluster api console (nodes, recent votes, wasmtime stats, fault injection)
+// why: the same view works against the in-browser mock or a real pi backend
+// relations: used by hardware/component.rs, calls crate::api client; in live mode nodes and votes follow the /events stream
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use leptos::*;
+use guardian_types::Status;
+use crate::api::mock::MockServer;
+use crate::api::types::{ApiError, FaultKind, FaultRequest, NodeStatus, VoteResult, WasmtimeStats};
+use crate::api::{ApiClient, DataSource, EventStream};
+use crate::settings::{use_settings, DataSourcePref};
+use crate::tabs::demo::siem::rfc3339;
+
+/// votes shown in the recent-votes table
+const VOTE_ROWS: usize = 5;
+
+/// cluster backend console with mock/live switch
+#[component]
+pub fn ClusterSection() -> impl IntoView {
+    let mock = store_value(Rc::new(RefCell::new(MockServer::new(js_sys::Date::now()))));
+    let prefs = use_settings().get_untracked();
+    let (live, set_live) = create_signal(prefs.data_source == DataSourcePref::Live);
+    let (base_url, set_base_url) = create_signal(prefs.endpoints.cluster_api);
+    let (nodes, set_nodes) = create_signal(Vec::<NodeStatus>::new());
+    let (votes, set_votes) = create_signal(Vec::<VoteResult>::new());
+    let (stats, set_stats) = create_signal(Option::<WasmtimeStats>::None);
+    let (fault_node, set_fault_node) = create_signal(0u8);
+    let (fault_kind, set_fault_kind) = create_signal(FaultKind::BufferOverflow);
+    let (status, set_status) = create_signal(Option::<String>::None);
+    let (streaming, set_streaming) = create_signal(false);
+    let stream = store_value(Option::<EventStream>::None);
+
+    let client = move || {
+        let source = if live.get() { DataSource::Live(base_url.get()) } else { DataSource::Mock };
+        ApiClient::new(source, mock.get_value())
+    };
+
+    let refresh = move || {
+        let api = client();
+        spawn_local(async move {
+            let result = async {
+                set_nodes.set(api.list_nodes().await?);
+                set_votes.set(api.recent_votes(VOTE_ROWS).await?);
+                match api.wasmtime_stats().await {
+                    Ok(s) => set_stats.set(Some(s)),
+                    // guardian-agent --native has no wasm instances, so no runtime counters
+                    Err(ApiError::Status(404)) => set_stats.set(None),
+                    Err(e) => return Err(e),
+                }
+                Ok::<_, ApiError>(())
+            }
+            .await;
+            if let Err(e) = result {
+                set_status.set(Some(format!("❌ {}", e.reason())));
+            }
+        });
+    };
+
+    let inject = move |_| {
+        let api = client();
+        let request = FaultRequest { node: fault_node.get(), kind: fault_kind.get() };
+        spawn_local(async move {
+            match api.trigger_fault(&request).await {
+                Ok(receipt) => {
+                    let icon = if receipt.trapped { "🛡️" } else { "💀" };
+                    set_status.set(Some(format!("{} {}", icon, receipt.message)));
+                }
+                Err(e) => set_status.set(Some(format!("❌ {}", e.reason()))),
+            }
+        });
+        refresh();
+    };
+
+    refresh();
+
+    // live mode: every tick the agent pushes replaces the node list and heads the vote table
+    create_effect(move |_| {
+        // dropping the previous stream closes its socket
+        stream.set_value(None);
+        set_streaming.set(false);
+        if !live.get() {
+            return;
+        }
+        let opened = EventStream::open(&base_url.get(), move |event| match event {
+            Ok(event) => {
+                set_streaming.set(true);
+                set_nodes.set(event.nodes);
+                set_votes.update(|votes| {
+                    votes.insert(0, event.vote);
+                    votes.truncate(VOTE_ROWS);
+                });
+            }
+            Err(e) => {
+                set_streaming.set(false);
+                set_status.set(Some(format!("❌ {}", e.reason())));
+            }
+        });
+        match opened {
+            Ok(opened) => stream.set_value(Some(opened)),
+            Err(e) => set_status.set(Some(format!("❌ {}", e.reason()))),
+        }
+    });
+
+    view! {
+        <div class="cluster-section">
+            <h3>"Cluster API"<span class="demo-badge">{move || match (live.get(), streaming.get()) {
+                (true, true) => "Live • streaming",
+                (true, false) => "Live",
+                _ => "Mock",
+            }}</span></h3>
+            <p class="section-hint">"💡 Mock runs in your browser; switch to Live to hit a Pi backend"</p>
+
+            <div class="audit-controls">
+                <select
+                    class="network-select"
+                    on:change=move |ev| set_live.set(event_target_value(&ev) == "live")
+                >
+                    <option value="mock" selected=!live.get_untracked()>"In-browser mock"</option>
+                    <option value="live" selected=live.get_untracked()>"Live backend"</option>
+                </select>
+                <Show when=move || live.get()>
+                    <input
+                        class="collector-url"
+                        type="url"
+                        prop:value=move || base_url.get()
+                        on:input=move |ev| set_base_url.set(event_target_value(&ev))
+                    />
+                </Show>
+                <button class="action-btn" on:click=move |_| refresh()>"🔄 Refresh"</button>
+            </div>
+
+            <div class="cluster-nodes">
+                {move || nodes.get().into_iter().map(|node| view! {
+                    <div class=format!("cluster-node {} {}", node.role.label().to_lowercase(), Status::from_role(node.role).css_class())>
+                        <strong>{format!("Node {}", node.id)}</strong>
+                        <span>{node.role.label()}</span>
+                        <span>{format!("{} pkts • up {}s", node.packets_processed, node.uptime_s)}</span>
+                        <span class="cluster-trap">{node.last_trap.clone().map(|t| format!("last trap: {}", t)).unwrap_or_default()}</span>
+                    </div>
+                }).collect_view()}
+            </div>
+
+            <table class="cluster-votes">
+                <thead>
+                    <tr><th>"Time"</th><th>"N0"</th><th>"N1"</th><th>"N2"</th><th>"Voted"</th></tr>
+                </thead>
+                <tbody>
+                    {move || votes.get().into_iter().map(|vote| view! {
+                        <tr class:faulty=vote.faulty_node.is_some()>
+                            <td>{rfc3339(vote.timestamp_ms)[11..19].to_string()}</td>
+                            {vote.values.iter().map(|v| view! {
+                                <td>{v.map(|x| format!("{:.2}", x)).unwrap_or_else(|| "TRAP".to_string())}</td>
+                            }).collect_view()}
+                            <td>{vote.voted.map(|x| format!("{:.2}", x)).unwrap_or_else(|| "—".to_string())}</td>
+                        </tr>
+                    }).collect_view()}
+                </tbody>
+            </table>
+
+            {move || match stats.get() {
+                None if live.get() && !nodes.get().is_empty() => Some(view! {
+                    <div class="cluster-stats">
+                        <span>"Runtime counters not reported: this backend runs its nodes natively, not under wasmtime"</span>
+                    </div>
+                }),
+                stats => stats.map(|s| view! {
+                    <div class="cluster-stats">
+                        <span>{format!("{} instances", s.instances)}</span>
+                        <span>{format!("{} instantiations", s.instantiations)}</span>
+                        <span>{format!("{} traps", s.traps)}</span>
+                        <span>{format!("{} fuel", s.fuel_consumed)}</span>
+                        <span>{format!("{:.1} MB memory", s.memory_bytes as f64 / 1_048_576.0)}</span>
+                        <span>{format!("{:.0} µs instantiate", s.avg_instantiate_us)}</span>
+                    </div>
+                }),
+            }}
+
+            <div class="audit-controls">
+                <select class="network-select" on:change=move |ev| set_fault_node.set(event_target_value(&ev).parse().unwrap_or(0))>
+                    <option value="0" selected>"Node 0"</option>
+                    <option value="1">"Node 1"</option>
+                    <option value="2">"Node 2"</option>
+                </select>
+                <select
+                    class="network-select"
+                    on:change=move |ev| if let Some(kind) = FaultKind::from_id(&event_target_value(&ev)) { set_fault_kind.set(kind) }
+                >
+                    {FaultKind::ALL.into_iter().map(|k| view! { <option value=k.id()>{k.label()}</option> }).collect_view()}
+                </select>
+                <button class="action-btn" on:click=inject>"⚡ Inject Fault"</button>
+            </div>
+            {move || status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+        </div>
+    }
+}
@@ -1,15 +1,12 @@
-// what: iec 62443 compliance visualization section with click-to-toggle tooltips
-// why: demonstrates understanding of industrial security standards with mobile-friendly tooltips
-// relations: used by hardware/component.rs, shows security architecture
+// what: iec 62443 compliance visualization section with glossary notes and terms
+// why: demonstrates understanding of industrial security standards with tap-to-open notes
+// relations: used by hardware/component.rs, shows security architecture; ⓘ text and terms from crate::glossary
 
 use leptos::*;
+use crate::glossary::{InfoTip, Term};
+use super::diode::DiodeConsole;
 
-// zone tooltip constants
-const ZONE3_TOOLTIP: &str = "Enterprise IT Zone (Low Risk): Contains monitoring and analytics systems. Fully isolated from industrial control via network segmentation.";
-const ZONE2_TOOLTIP: &str = "Guardian Cluster DMZ: The WASM runtime acts as a security boundary. Workers are compiled to WebAssembly and execute in a sandboxed environment. WASI provides capability-based security: each module must be explicitly granted access to specific resources. In production, WASM typically runs inside a Docker container — combining Docker's orchestration with WASM's instruction-level isolation.";
-const ZONE1_TOOLTIP: &str = "Industrial Control Zone (High Risk): Contains the PLC and field devices. Only validated commands from Zone 2 can reach this zone via Modbus RTU.";
-
-/// renders iec 62443 zone and conduit model diagram with click-to-toggle tooltips
+/// renders iec 62443 zone and conduit model diagram with glossary notes and terms
 #[component]
 pub fn ComplianceSection() -> impl IntoView {
     view! {
@@ -23,7 +20,7 @@ pub fn ComplianceSection() -> impl IntoView {
                     color="green" 
                     name="Zone 3: Enterprise IT" 
                     desc="Grafana, InfluxDB, QNAP NAS"
-                    tooltip=ZONE3_TOOLTIP
+                    note="zone.3"
                 />
                 
                 <div class="conduit">
@@ -35,11 +32,11 @@ pub fn ComplianceSection() -> impl IntoView {
                     color="yellow" 
                     name="Zone 2: DMZ / Guardian Cluster" 
                     desc="WASM Runtime enforces capability boundary"
-                    tooltip=ZONE2_TOOLTIP
+                    note="zone.2"
                 />
                 
                 <div class="conduit">
-                    "┃ WIT Contract (Modbus only) ┃"
+                    "┃ "<Term id="wit" />" Contract (Modbus only) ┃"
                 </div>
                 
                 // zone 1: industrial control (red - high risk)
@@ -47,7 +44,7 @@ pub fn ComplianceSection() -> impl IntoView {
                     color="red" 
                     name="Zone 1: Industrial Control" 
                     desc="S7-1200 PLC, BME280, Relays"
-                    tooltip=ZONE1_TOOLTIP
+                    note="zone.1"
                 />
             </div>
             
@@ -55,19 +52,26 @@ pub fn ComplianceSection() -> impl IntoView {
                 <strong>"Key Security Property: "</strong>
                 "The Guardian Cluster acts as a logical data diode / secure gateway. Telemetry flows UP, but no external commands can reach the PLC without WIT contract validation."
             </div>
+
+            <div class="compliance-note">
+                <strong>"Fault Model: "</strong>
+                "A worker that traps is "<Term id="fail-stop" />"; one that keeps answering with wrong values is "<Term id="byzantine" />". "
+                <Term id="2oo3" />" voting masks either kind on one node — the other two still agree."
+            </div>
+            
+            <DiodeConsole />
         </div>
     }
 }
 
-/// security zone card with click-to-toggle tooltip
+/// security zone card with its glossary note
 #[component]
 fn SecurityZone(
     color: &'static str, 
     name: &'static str, 
     desc: &'static str,
-    tooltip: &'static str,
+    note: &'static str,
 ) -> impl IntoView {
-    let (show_tooltip, set_show_tooltip) = create_signal(false);
     let badge = match color {
         "green" => "🟢",
         "yellow" => "🟡",
@@ -82,30 +86,10 @@ fn SecurityZone(
             <div class="zone-info">
                 <span class="zone-name">
                     {name}
-                    <button 
-                        class="info-btn"
-                        on:click=move |_| set_show_tooltip.update(|v| *v = !*v)
-                    >
-                        "ⓘ"
-                    </button>
+                    <InfoTip note=note />
                 </span>
                 <span class="zone-desc">{desc}</span>
             </div>
-            <Show when=move || show_tooltip.get()>
-                <div 
-                    class="tooltip-overlay" 
-                    on:click=move |_| set_show_tooltip.set(false)
-                />
-                <div class="tooltip-popup">
-                    <div class="tooltip-content">{tooltip}</div>
-                    <button 
-                        class="tooltip-close"
-                        on:click=move |_| set_show_tooltip.set(false)
-                    >
-                        "✕"
-                    </button>
-                </div>
-            </Show>
         </div>
     }
 }
@@ -1,6 +1,6 @@
 // what: main hardware tab component with section navigation
-// why: orchestrates the four hardware sub-sections with tabbed ui
-// relations: uses architecture.rs, components.rs, compliance.rs, toolchain.rs
+// why: orchestrates the hardware sub-sections with tabbed ui
+// relations: uses architecture.rs, components.rs, compliance.rs, toolchain.rs, cluster.rs, threat_model.rs, oled_panel.rs, wizard_panel.rs
 //            exported by mod.rs for use in main app tabs
 
 use leptos::*;
@@ -8,6 +8,10 @@ use super::architecture::ArchitectureSection;
 use super::components::ComponentsSection;
 use super::compliance::ComplianceSection;
 use super::toolchain::ToolchainSection;
+use super::cluster::ClusterSection;
+use super::threat_model::ThreatModelSection;
+use super::oled_panel::OledSection;
+use super::wizard_panel::WizardSection;
 
 /// main hardware tab with sub-section navigation
 #[component]
@@ -42,12 +46,36 @@ pub fn Hardware() -> impl IntoView {
                     active=active_section 
                     set_active=set_active_section 
                 />
+                <SectionButton 
+                    id="threats" 
+                    label="🧭 Threat Model" 
+                    active=active_section 
+                    set_active=set_active_section 
+                />
                 <SectionButton 
                     id="toolchain" 
                     label="⚙️ TIA Portal" 
                     active=active_section 
                     set_active=set_active_section 
                 />
+                <SectionButton 
+                    id="cluster" 
+                    label="🛰️ Cluster API" 
+                    active=active_section 
+                    set_active=set_active_section 
+                />
+                <SectionButton 
+                    id="hmi" 
+                    label="📟 HMI" 
+                    active=active_section 
+                    set_active=set_active_section 
+                />
+                <SectionButton 
+                    id="design" 
+                    label="🧭 Design" 
+                    active=active_section 
+                    set_active=set_active_section 
+                />
             </div>
 
             // section content (renders based on active section)
@@ -56,7 +84,11 @@ pub fn Hardware() -> impl IntoView {
                     "architecture" => view! { <ArchitectureSection /> }.into_view(),
                     "components" => view! { <ComponentsSection /> }.into_view(),
                     "compliance" => view! { <ComplianceSection /> }.into_view(),
+                    "threats" => view! { <ThreatModelSection set_section=set_active_section /> }.into_view(),
                     "toolchain" => view! { <ToolchainSection /> }.into_view(),
+                    "cluster" => view! { <ClusterSection /> }.into_view(),
+                    "hmi" => view! { <OledSection /> }.into_view(),
+                    "design" => view! { <WizardSection /> }.into_view(),
                     _ => view! { <ArchitectureSection /> }.into_view(),
                 }}
             </div>
@@ -1,15 +1,9 @@
-// what: hardware component showcase section with click-to-toggle tooltips
-// why: displays all physical components grouped by purdue level with mobile-friendly tooltips
-// relations: used by hardware/component.rs, lists components organized by architecture level
+// what: hardware component showcase section with glossary notes and terms
+// why: displays all physical components grouped by purdue level with tap-to-open notes
+// relations: used by hardware/component.rs, lists components organized by architecture level; ⓘ text and terms from crate::glossary
 
 use leptos::*;
-
-// category tooltip constants
-const L0_CAT_TOOLTIP: &str = "Physical sensors and actuators that interface directly with the industrial process.";
-const L1_CAT_TOOLTIP: &str = "Industrial PLC and power systems that execute real-time control logic.";
-const L2_CAT_TOOLTIP: &str = "The Guardian Cluster: 3 Raspberry Pis running WASM workers with Raft consensus. For this demo, wasmtime runs natively on Linux. In production, this would typically run inside a Docker container (Mothership pattern) for fleet orchestration.";
-const INFRA_CAT_TOOLTIP: &str = "Network infrastructure and data storage supporting the industrial stack.";
-const VISUAL_CAT_TOOLTIP: &str = "Visual indicators showing system status: TMR voting results and real-time metrics.";
+use crate::glossary::{InfoTip, Term};
 
 /// renders grid of hardware component cards grouped by purdue level
 #[component]
@@ -17,13 +11,13 @@ pub fn ComponentsSection() -> impl IntoView {
     view! {
         <div class="components-section">
             <h3>"Hardware Components"</h3>
-            <p class="section-hint">"💡 Organized by Purdue Level — tap ⓘ for details"</p>
+            <p class="section-hint">"💡 Organized by "<Term id="purdue-level" />" — tap ⓘ for details"</p>
             
             <div class="components-grid">
                 // level 0: field devices first (bottom of purdue model)
                 <ComponentCategory 
                     title="🌡️ L0: Field Devices" 
-                    tooltip=L0_CAT_TOOLTIP
+                    note="components.l0"
                 >
                     <ComponentCard 
                         name="BME280 Sensor" 
@@ -45,7 +39,7 @@ pub fn ComponentsSection() -> impl IntoView {
                 // level 1: industrial control
                 <ComponentCategory 
                     title="🏭 L1: Industrial Control" 
-                    tooltip=L1_CAT_TOOLTIP
+                    note="components.l1"
                 >
                     <ComponentCard 
                         name="Siemens S7-1200 PLC" 
@@ -62,7 +56,7 @@ pub fn ComponentsSection() -> impl IntoView {
                 // level 2: guardian cluster (the star of the show)
                 <ComponentCategory 
                     title="🖥️ L2: Guardian Cluster" 
-                    tooltip=L2_CAT_TOOLTIP
+                    note="components.l2"
                 >
                     <ComponentCard 
                         name="Raspberry Pi 4 (4GB)" 
@@ -74,12 +68,17 @@ pub fn ComponentsSection() -> impl IntoView {
                         role="Raft Followers / TMR Voters" 
                         zone="Level 2"
                     />
+                    <ComponentCard 
+                        name="RGB OLED 128×64" 
+                        role="Cabinet HMI (SPI, see 📟 HMI)" 
+                        zone="Level 2"
+                    />
                 </ComponentCategory>
                 
                 // infrastructure: level 3 + network
                 <ComponentCategory 
                     title="🌐 L3: Infrastructure" 
-                    tooltip=INFRA_CAT_TOOLTIP
+                    note="components.infra"
                 >
                     <ComponentCard 
                         name="QNAP NAS" 
@@ -96,7 +95,7 @@ pub fn ComponentsSection() -> impl IntoView {
                 // visual feedback
                 <ComponentCategory 
                     title="💡 Visual Indicators" 
-                    tooltip=VISUAL_CAT_TOOLTIP
+                    note="components.visual"
                 >
                     <ComponentCard 
                         name="WS2812B LED Strip" 
@@ -119,41 +118,20 @@ pub fn ComponentsSection() -> impl IntoView {
     }
 }
 
-/// category wrapper with click-to-toggle tooltip
+/// category wrapper with its glossary note
 #[component]
 fn ComponentCategory(
     title: &'static str, 
-    tooltip: &'static str,
+    note: &'static str,
     children: Children,
 ) -> impl IntoView {
-    let (show_tooltip, set_show_tooltip) = create_signal(false);
     
     view! {
         <div class="component-category">
             <h4>
                 {title}
-                <button 
-                    class="info-btn"
-                    on:click=move |_| set_show_tooltip.update(|v| *v = !*v)
-                >
-                    "ⓘ"
-                </button>
+                <InfoTip note=note />
             </h4>
-            <Show when=move || show_tooltip.get()>
-                <div 
-                    class="tooltip-overlay" 
-                    on:click=move |_| set_show_tooltip.set(false)
-                />
-                <div class="tooltip-popup">
-                    <div class="tooltip-content">{tooltip}</div>
-                    <button 
-                        class="tooltip-close"
-                        on:click=move |_| set_show_tooltip.set(false)
-                    >
-                        "✕"
-                    </button>
-                </div>
-            </Show>
             <div class="component-list">
                 {children()}
             </div>
@@ -0,0 +1,70 @@
+// what: interactive data diode console for southbound write attempts from zone 3
+// why: backs the compliance tab's "logical data diode" claim with the real enforcement code
+// relations: used by hardware/compliance.rs, calls modbus_parser::diode::enforce_southbound
+
+use leptos::*;
+use modbus_parser::build_frame;
+use modbus_parser::diode::{enforce_southbound, FC_WRITE_MULTIPLE_REGISTERS, FC_WRITE_SINGLE_COIL, FC_WRITE_SINGLE_REGISTER};
+
+/// plc slave id on the rs485 bus
+const PLC_DEVICE_ID: u8 = 1;
+
+/// a canned southbound request a zone 3 operator (or attacker) might send
+struct WriteAttempt {
+    label: &'static str,
+    function_code: u8,
+    data: &'static [u8],
+}
+
+const ATTEMPTS: &[WriteAttempt] = &[
+    WriteAttempt { label: "Set fan speed 60%", function_code: FC_WRITE_SINGLE_REGISTER, data: &[0x00, 0x0A, 0x00, 0x3C] },
+    WriteAttempt { label: "Force fan coil OFF", function_code: FC_WRITE_SINGLE_COIL, data: &[0x00, 0x01, 0x00, 0x00] },
+    WriteAttempt { label: "Overwrite temperature", function_code: FC_WRITE_SINGLE_REGISTER, data: &[0x00, 0x04, 0x7F, 0xFF] },
+    WriteAttempt { label: "Set fan speed 250%", function_code: FC_WRITE_SINGLE_REGISTER, data: &[0x00, 0x0A, 0x00, 0xFA] },
+    WriteAttempt { label: "Bulk write (ladder patch)", function_code: FC_WRITE_MULTIPLE_REGISTERS, data: &[0x00, 0x00, 0x00, 0x02, 0x04, 0xDE, 0xAD, 0xBE, 0xEF] },
+];
+
+/// formats a frame as space-separated hex bytes
+fn hex(frame: &[u8]) -> String {
+    frame.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" ")
+}
+
+/// zone 3 → plc write console showing diode verdicts
+#[component]
+pub fn DiodeConsole() -> impl IntoView {
+    let (verdicts, set_verdicts) = create_signal(Vec::<(bool, String)>::new());
+
+    let send = move |attempt: &'static WriteAttempt| {
+        let frame = build_frame(PLC_DEVICE_ID, attempt.function_code, attempt.data);
+        let line = match enforce_southbound(&frame) {
+            Ok(schema) => (true, format!("[PASS] {} → {} ({})", attempt.label, schema.name, hex(&frame))),
+            Err(rejection) => (false, format!("[DROP] {} → {} ({})", attempt.label, rejection.reason(), hex(&frame))),
+        };
+        set_verdicts.update(|v| v.push(line));
+    };
+
+    view! {
+        <div class="diode-console">
+            <h4>"🚧 Try It: Send a Write from Zone 3"</h4>
+            <p class="section-hint">"Frames are built and checked by the same Rust enforcement code that runs on the gateway"</p>
+            <div class="diode-buttons">
+                {ATTEMPTS.iter().map(|attempt| view! {
+                    <button class="diode-btn" on:click=move |_| send(attempt)>{attempt.label}</button>
+                }).collect_view()}
+            </div>
+            <div class="terminal diode-log">
+                {move || {
+                    let entries = verdicts.get();
+                    if entries.is_empty() {
+                        view! { <p class="terminal-line info">"$ waiting for southbound traffic"</p> }.into_view()
+                    } else {
+                        entries.into_iter().map(|(passed, msg)| {
+                            let level = if passed { "terminal-line success" } else { "terminal-line error" };
+                            view! { <p class=level>{msg}</p> }
+                        }).collect_view()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
@@ -1,12 +1,23 @@
 // what: hardware module re-exports and organization
 // why: organizes hardware tab into submodules for maintainability like demo tab
-// relations: parent module for architecture.rs, components.rs, compliance.rs, toolchain.rs, component.rs
+// relations: parent module for architecture.rs, components.rs, compliance.rs, diode.rs, toolchain.rs, cluster.rs, threats.rs (+ threat_model.rs), oled.rs (+ oled_panel.rs), wizard.rs (+ wizard_panel.rs), component.rs
 
 pub mod architecture;
 pub mod components;
 pub mod compliance;
+pub mod diode;
 pub mod toolchain;
+pub mod cluster;
+pub mod threats;
+mod threat_model;
+pub mod oled;
+mod oled_panel;
+pub mod wizard;
+mod wizard_panel;
 mod component;
 
+#[cfg(test)]
+mod tests;
+
 // re-export the hardware component for use by parent module
 pub use component::Hardware;
@@ -0,0 +1,184 @@
+// what: the pi's 128x64 rgb oled as a frame buffer - 5x7 font, the hmi layout (reading, leader, node health) and rgba export
+// why: the browser and the physical display should show the same screen from the same state, pixel for pixel
+// relations: status written by the demo tab's store.rs (use_hmi_status, provided by lib.rs App), state colours from guardian-types
+//            status_map.rs, drawn on a canvas by oled_panel.rs
+
+use leptos::*;
+use guardian_types::{SensorReading, Status};
+
+pub const OLED_WIDTH: usize = 128;
+pub const OLED_HEIGHT: usize = 64;
+/// a glyph cell: 5 columns of ink plus one of spacing, 7 rows plus one
+pub const CELL_W: usize = 6;
+pub const CELL_H: usize = 8;
+
+/// rgb565, the panel's native pixel format; state colours come from the status map
+pub const BLACK: u16 = 0x0000;
+pub const WHITE: u16 = 0xFFFF;
+pub const CYAN: u16 = 0x07FF;
+pub const YELLOW: u16 = 0xFFE0;
+pub const GREY: u16 = 0x8410;
+
+/// what the hmi shows, as the demo last left it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HmiStatus {
+    /// last reading that went through the voter (None before the first sensor run)
+    pub reading: Option<SensorReading>,
+    pub leader: u8,
+    pub healthy: [bool; 3],
+}
+
+impl HmiStatus {
+    pub fn status(&self) -> Status {
+        Status::from_healthy(self.healthy.iter().filter(|h| **h).count())
+    }
+}
+
+impl Default for HmiStatus {
+    fn default() -> Self {
+        Self { reading: None, leader: 0, healthy: [true; 3] }
+    }
+}
+
+/// shared hmi status signal (a fresh one outside the app, e.g. in tests)
+pub fn use_hmi_status() -> RwSignal<HmiStatus> {
+    use_context::<RwSignal<HmiStatus>>().unwrap_or_else(|| create_rw_signal(HmiStatus::default()))
+}
+
+/// column bitmaps of the 5x7 font, bit 0 at the top; lowercase draws as uppercase
+fn glyph(c: char) -> [u8; 5] {
+    match c.to_ascii_uppercase() {
+        ' ' => [0x00, 0x00, 0x00, 0x00, 0x00],
+        '0' => [0x3E, 0x51, 0x49, 0x45, 0x3E],
+        '1' => [0x00, 0x42, 0x7F, 0x40, 0x00],
+        '2' => [0x42, 0x61, 0x51, 0x49, 0x46],
+        '3' => [0x21, 0x41, 0x45, 0x4B, 0x31],
+        '4' => [0x18, 0x14, 0x12, 0x7F, 0x10],
+        '5' => [0x27, 0x45, 0x45, 0x45, 0x39],
+        '6' => [0x3C, 0x4A, 0x49, 0x49, 0x30],
+        '7' => [0x01, 0x71, 0x09, 0x05, 0x03],
+        '8' => [0x36, 0x49, 0x49, 0x49, 0x36],
+        '9' => [0x06, 0x49, 0x49, 0x29, 0x1E],
+        'A' => [0x7E, 0x11, 0x11, 0x11, 0x7E],
+        'B' => [0x7F, 0x49, 0x49, 0x49, 0x36],
+        'C' => [0x3E, 0x41, 0x41, 0x41, 0x22],
+        'D' => [0x7F, 0x41, 0x41, 0x22, 0x1C],
+        'E' => [0x7F, 0x49, 0x49, 0x49, 0x41],
+        'F' => [0x7F, 0x09, 0x09, 0x09, 0x01],
+        'G' => [0x3E, 0x41, 0x49, 0x49, 0x7A],
+        'H' => [0x7F, 0x08, 0x08, 0x08, 0x7F],
+        'I' => [0x00, 0x41, 0x7F, 0x41, 0x00],
+        'J' => [0x20, 0x40, 0x41, 0x3F, 0x01],
+        'K' => [0x7F, 0x08, 0x14, 0x22, 0x41],
+        'L' => [0x7F, 0x40, 0x40, 0x40, 0x40],
+        'M' => [0x7F, 0x02, 0x0C, 0x02, 0x7F],
+        'N' => [0x7F, 0x04, 0x08, 0x10, 0x7F],
+        'O' => [0x3E, 0x41, 0x41, 0x41, 0x3E],
+        'P' => [0x7F, 0x09, 0x09, 0x09, 0x06],
+        'Q' => [0x3E, 0x41, 0x51, 0x21, 0x5E],
+        'R' => [0x7F, 0x09, 0x19, 0x29, 0x46],
+        'S' => [0x46, 0x49, 0x49, 0x49, 0x31],
+        'T' => [0x01, 0x01, 0x7F, 0x01, 0x01],
+        'U' => [0x3F, 0x40, 0x40, 0x40, 0x3F],
+        'V' => [0x1F, 0x20, 0x40, 0x20, 0x1F],
+        'W' => [0x3F, 0x40, 0x38, 0x40, 0x3F],
+        'X' => [0x63, 0x14, 0x08, 0x14, 0x63],
+        'Y' => [0x07, 0x08, 0x70, 0x08, 0x07],
+        'Z' => [0x61, 0x51, 0x49, 0x45, 0x43],
+        '.' => [0x00, 0x60, 0x60, 0x00, 0x00],
+        ':' => [0x00, 0x36, 0x36, 0x00, 0x00],
+        '-' => [0x08, 0x08, 0x08, 0x08, 0x08],
+        '+' => [0x08, 0x08, 0x3E, 0x08, 0x08],
+        '%' => [0x23, 0x13, 0x08, 0x64, 0x62],
+        '/' => [0x20, 0x10, 0x08, 0x04, 0x02],
+        '!' => [0x00, 0x00, 0x5F, 0x00, 0x00],
+        '*' => [0x14, 0x08, 0x3E, 0x08, 0x14],
+        '°' => [0x00, 0x06, 0x09, 0x09, 0x06],
+        // anything the font lacks shows as a question mark, like the panel's own firmware font
+        _ => [0x02, 0x01, 0x51, 0x09, 0x06],
+    }
+}
+
+/// one full screen, row-major rgb565
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub pixels: Vec<u16>,
+}
+
+impl Default for Frame {
+    fn default() -> Self {
+        Self { pixels: vec![BLACK; OLED_WIDTH * OLED_HEIGHT] }
+    }
+}
+
+impl Frame {
+    /// pixels outside the panel are dropped, as the controller clips them
+    pub fn set(&mut self, x: usize, y: usize, color: u16) {
+        if x < OLED_WIDTH && y < OLED_HEIGHT {
+            self.pixels[y * OLED_WIDTH + x] = color;
+        }
+    }
+
+    pub fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, color: u16) {
+        for py in y..y + h {
+            for px in x..x + w {
+                self.set(px, py, color);
+            }
+        }
+    }
+
+    /// text at `scale` (1 = 6x8 cells); returns the x after the last cell
+    pub fn text(&mut self, x: usize, y: usize, text: &str, color: u16, scale: usize) -> usize {
+        let mut cx = x;
+        for c in text.chars() {
+            for (col, bits) in glyph(c).iter().enumerate() {
+                for row in 0..7 {
+                    if bits >> row & 1 == 1 {
+                        self.fill_rect(cx + col * scale, y + row * scale, scale, scale, color);
+                    }
+                }
+            }
+            cx += CELL_W * scale;
+        }
+        cx
+    }
+
+    /// rgba bytes for a canvas ImageData, expanding 5/6/5 bits to 8
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|&p| {
+            let r = (p >> 11) & 0x1F;
+            let g = (p >> 5) & 0x3F;
+            let b = p & 0x1F;
+            [(r << 3 | r >> 2) as u8, (g << 2 | g >> 4) as u8, (b << 3 | b >> 2) as u8, 0xFF]
+        }).collect()
+    }
+}
+
+/// the screen the pi draws for a status: header and state, temperature at double size, humidity and pressure, leader, one box per node
+pub fn render(status: &HmiStatus) -> Frame {
+    let mut frame = Frame::default();
+    frame.text(0, 0, "GUARDIAN ONE", CYAN, 1);
+    let state = status.status().mapping();
+    frame.text(OLED_WIDTH - state.short.len() * CELL_W, 0, state.short, state.color.rgb565(), 1);
+    frame.fill_rect(0, CELL_H + 1, OLED_WIDTH, 1, GREY);
+
+    match status.reading {
+        Some(r) => {
+            frame.text(0, 12, &format!("{:.1}°C", r.temperature), YELLOW, 2);
+            frame.text(0, 30, &format!("RH {:.1}% {:.0}HPA", r.humidity, r.pressure), WHITE, 1);
+        }
+        None => {
+            frame.text(0, 12, "--.-°C", GREY, 2);
+            frame.text(0, 30, "NO READING YET", GREY, 1);
+        }
+    }
+    frame.text(0, 40, &format!("LEADER N{}", status.leader), WHITE, 1);
+
+    for (i, healthy) in status.healthy.iter().enumerate() {
+        let x = i * 43;
+        frame.fill_rect(x, 53, 8, 8, Status::node(*healthy).mapping().color.rgb565());
+        let label = if i == status.leader as usize { format!("N{}*", i) } else { format!("N{}", i) };
+        frame.text(x + 11, 54, &label, WHITE, 1);
+    }
+    frame
+}
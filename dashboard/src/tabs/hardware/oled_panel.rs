@@ -0,0 +1,50 @@
+// what: hmi section of the hardware tab - the oled frame on a 128x64 canvas scaled up with crisp pixels, next to what each line means
+// why: links the browser demo to the physical panel on the pi: run a sensor check or an attack, then see what an operator at the cabinet would see
+// relations: renders oled.rs render() of the shared use_hmi_status signal; rendered by hardware/component.rs
+
+use leptos::*;
+use leptos::html::Canvas;
+use wasm_bindgen::{Clamped, JsCast};
+use web_sys::{CanvasRenderingContext2d, ImageData};
+use super::oled::{render, use_hmi_status, Frame, OLED_HEIGHT, OLED_WIDTH};
+
+/// blits a frame onto the canvas at native resolution (css does the scaling)
+fn draw(canvas: &web_sys::HtmlCanvasElement, frame: &Frame) -> Result<(), wasm_bindgen::JsValue> {
+    let Some(ctx) = canvas.get_context("2d")? else { return Ok(()) };
+    let ctx: CanvasRenderingContext2d = ctx.dyn_into()?;
+    let rgba = frame.to_rgba();
+    let image = ImageData::new_with_u8_clamped_array_and_sh(Clamped(&rgba), OLED_WIDTH as u32, OLED_HEIGHT as u32)?;
+    ctx.put_image_data(&image, 0.0, 0.0)
+}
+
+#[component]
+pub fn OledSection() -> impl IntoView {
+    let status = use_hmi_status();
+    let canvas = create_node_ref::<Canvas>();
+    create_effect(move |_| {
+        let frame = render(&status.get());
+        if let Some(el) = canvas.get() {
+            if let Err(e) = draw(&el, &frame) {
+                tracing::warn!(target: "hmi", error = ?e, "oled draw failed");
+            }
+        }
+    });
+
+    view! {
+        <div class="oled-section">
+            <h3>"📟 Cabinet HMI — 128×64 RGB OLED"</h3>
+            <p class="section-hint">
+                "💡 The same frame the Pi pushes over SPI, drawn from the Demo tab's state: run a sensor check or an attack there and come back"
+            </p>
+            <div class="oled-bezel">
+                <canvas node_ref=canvas class="oled-canvas" width=OLED_WIDTH height=OLED_HEIGHT aria-label="OLED preview" />
+            </div>
+            <ul class="oled-legend">
+                <li>"Header: cluster state — OK while two of three nodes agree, HALT when the voter holds its output"</li>
+                <li>"Large line: last voted temperature; below it humidity and pressure from the same BME280 read"</li>
+                <li>"Leader: the Raft leader that commits readings and writes the PLC registers"</li>
+                <li>"Boxes: node health, green healthy and red faulty; * marks the leader"</li>
+            </ul>
+        </div>
+    }
+}
@@ -0,0 +1,100 @@
+// what: tests for the design wizard - the default design's command line, the generated wit, the estimate math and issues, and the step/input helpers
+// why: the wizard hands out files people start real projects from; a wrong flag or a world that does not parse would be copied straight to a pi
+
+use crate::tabs::demo::architecture::Architecture;
+use crate::tabs::demo::policy::DirGrant;
+use crate::tabs::demo::wit_codegen::parse_interface;
+use crate::tabs::hardware::wizard::{dirs_text, parse_dirs, parse_pins, Design, Protocol, Step, TCP_IP_OVERHEAD};
+use crate::tabs::proof::ota_simulator::NetworkType;
+
+#[test]
+fn default_design_runs_with_the_sensor_node_grants() {
+    // what: the starting design grants the i2c bus read-only, the two env vars, 2MB and an outbound socket to the field device, in that order
+    // why: the default is what most people copy unchanged; it must match the sensor-node the demo itself runs
+    let command = Design::default().wasmtime_command();
+    let lines: Vec<&str> = command.split(" \\\n    ").collect();
+    assert_eq!(
+        lines,
+        vec![
+            "wasmtime run",
+            "--dir=/dev/i2c-1::/dev/i2c-1",
+            "--env=SENSOR_TYPE=bme280",
+            "--env=POLL_INTERVAL_MS=1000",
+            "-W max-memory-size=2097152",
+            "-S inherit-network=y",
+            "my-guardian.wasm",
+        ]
+    );
+
+    // a design with no protocol has nothing to reach, so it is cut off the network
+    let offline = Design { protocols: Vec::new(), ..Design::default() }.wasmtime_command();
+    assert!(offline.contains("-S tcp=n") && offline.contains("-S udp=n") && !offline.contains("inherit-network"));
+}
+
+#[test]
+fn generated_wit_parses_and_imports_what_was_chosen() {
+    // what: every chosen protocol's interface parses with the wit viewer's parser; the world imports exactly those, plus gpio only when pins are granted
+    // why: a skeleton that fails to parse, or imports a protocol nobody picked, is worse than no skeleton
+    let mut design = Design::default();
+    design.toggle(Protocol::OpcUa);
+    design.gpio = vec![17];
+    let wit = design.wit();
+    assert!(wit.contains("package guardian-one:my-guardian@0.1.0;"));
+    for p in [Protocol::Modbus, Protocol::OpcUa] {
+        let funcs = parse_interface(&wit, p.interface()).unwrap();
+        assert_eq!(funcs.len(), 2, "{}", p.label());
+        assert!(wit.contains(&format!("    import {};", p.interface())));
+    }
+    assert!(parse_interface(&wit, Protocol::Dnp3.interface()).is_err());
+    assert!(wit.contains("    import gpio;") && wit.contains("    export telemetry;"));
+    assert_eq!(parse_interface(&wit, "telemetry").unwrap()[0].result.as_deref(), Some("telemetry-packet"));
+    assert!(wit.contains("/// Modbus TCP to 10.0.0.10:502") && wit.contains("/// OPC UA to 10.0.0.10:4840"));
+}
+
+#[test]
+fn estimate_adds_adapters_and_flags_an_overloaded_link() {
+    // what: module size is the 50 KiB worker plus each adapter; polling counts two segments of headers per protocol; a bad name,
+    //       no protocol and a satellite link flooded by fast polling are all reported
+    // why: the estimate is what a designer budgets a fleet on, and the issues are the reason not to export yet
+    let design = Design::default();
+    let e = design.estimate();
+    assert_eq!(e.module_kib, 50 + Protocol::Modbus.adapter_kib());
+    let bits = f64::from((Protocol::Modbus.poll_bytes() + 2 * TCP_IP_OVERHEAD) * 8);
+    assert!((e.telemetry_bps - bits).abs() < 1e-9, "one poll a second");
+    assert_eq!(e.memory_mb, 2 * Architecture::TwoOoThree.channels() as u32);
+    assert!(design.issues().is_empty(), "{:?}", design.issues());
+
+    let mut bad = Design { name: "My Guardian".into(), protocols: Vec::new(), ..Design::default() };
+    assert_eq!(bad.estimate().telemetry_bps, 0.0);
+    assert_eq!(bad.issues().len(), 2);
+    bad.name = "my-guardian".into();
+    bad.toggle(Protocol::OpcUa);
+    bad.network = NetworkType::Satellite;
+    bad.poll_ms = 10;
+    let issues = bad.issues();
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].contains("of the link"), "{}", issues[0]);
+    assert!(bad.summary().contains("## Open issues"));
+}
+
+#[test]
+fn steps_and_inputs_round_trip() {
+    // what: steps walk forward and back without running off either end; directory and pin text parse and print back; toggling keeps protocol order
+    // why: the inputs are re-rendered from the design on every change, so parsing then printing must be stable or the text jumps under the cursor
+    assert_eq!(Step::Protocols.prev(), None);
+    assert_eq!(Step::Summary.next(), None);
+    assert_eq!(Step::Grants.next().and_then(|s| s.prev()), Some(Step::Grants));
+
+    let dirs = parse_dirs("/dev/i2c-1 ro\n\n  /var/lib/guardian  \n");
+    assert_eq!(dirs, vec![DirGrant { path: "/dev/i2c-1".into(), readonly: true }, DirGrant { path: "/var/lib/guardian".into(), readonly: false }]);
+    assert_eq!(parse_dirs(&dirs_text(&dirs)), dirs);
+    assert_eq!(parse_pins("27, 4 4,x 99 17"), vec![4, 17, 27]);
+
+    let mut design = Design { protocols: Vec::new(), ..Design::default() };
+    for p in [Protocol::OpcUa, Protocol::Modbus, Protocol::Dnp3] {
+        design.toggle(p);
+    }
+    assert_eq!(design.protocols, Protocol::ALL.to_vec());
+    design.toggle(Protocol::Dnp3);
+    assert_eq!(design.protocols, vec![Protocol::Modbus, Protocol::OpcUa]);
+}
@@ -0,0 +1,11 @@
+// what: exports all test modules for hardware tab
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod threat_model;
+
+#[cfg(test)]
+mod oled;
+
+#[cfg(test)]
+mod design_wizard;
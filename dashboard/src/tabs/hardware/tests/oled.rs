@@ -0,0 +1,103 @@
+// what: tests for the oled hmi frame - font rendering, the screen layout per status, and the rgba export the canvas draws
+// why: the preview claims to be what the cabinet panel shows, so the pixels themselves are the contract
+
+use guardian_types::{SensorReading, Status};
+use crate::tabs::hardware::oled::{render, Frame, HmiStatus, BLACK, CELL_W, CYAN, OLED_HEIGHT, OLED_WIDTH, WHITE, YELLOW};
+
+fn pixel(frame: &Frame, x: usize, y: usize) -> u16 {
+    frame.pixels[y * OLED_WIDTH + x]
+}
+
+/// pixels of `color` inside a rectangle
+fn count(frame: &Frame, x: usize, y: usize, w: usize, h: usize, color: u16) -> usize {
+    (y..y + h).flat_map(|py| (x..x + w).map(move |px| (px, py))).filter(|&(px, py)| pixel(frame, px, py) == color).count()
+}
+
+/// the status map's green and red as the panel stores them
+const GREEN: u16 = 0x07E0;
+const RED: u16 = 0xF800;
+
+const STATUS: HmiStatus = HmiStatus {
+    reading: Some(SensorReading { temperature: 24.3, humidity: 45.2, pressure: 1013.25 }),
+    leader: 1,
+    healthy: [true, true, true],
+};
+
+#[test]
+fn text_draws_5x7_glyphs_in_6px_cells_and_clips_at_the_edge() {
+    // what: "1" lights its stem column, each char advances one 6px cell, scale 2 quadruples the ink, lowercase matches uppercase, text past the edge is dropped
+    // why: a glyph off by a column or a panic at the edge would make the preview differ from the panel the moment a value gets long
+    let mut frame = Frame::default();
+    assert_eq!(frame.text(0, 0, "1", WHITE, 1), CELL_W);
+    assert_eq!((0..7).filter(|&y| pixel(&frame, 2, y) == WHITE).count(), 7);
+    assert_eq!(pixel(&frame, 2, 7), BLACK);
+
+    let (mut small, mut big) = (Frame::default(), Frame::default());
+    small.text(0, 0, "8", WHITE, 1);
+    big.text(0, 0, "8", WHITE, 2);
+    assert_eq!(count(&big, 0, 0, 12, 16, WHITE), 4 * count(&small, 0, 0, 6, 8, WHITE));
+
+    let (mut lower, mut upper) = (Frame::default(), Frame::default());
+    lower.text(0, 0, "leader", WHITE, 1);
+    upper.text(0, 0, "LEADER", WHITE, 1);
+    assert_eq!(lower, upper);
+
+    let mut edge = Frame::default();
+    edge.text(OLED_WIDTH - 3, OLED_HEIGHT - 3, "W", WHITE, 2);
+    assert_eq!(edge.pixels.len(), OLED_WIDTH * OLED_HEIGHT);
+}
+
+#[test]
+fn screen_shows_the_reading_or_a_placeholder() {
+    // what: a reading puts yellow double-size digits in the temperature band; none leaves that band without yellow and draws the grey placeholder instead
+    // why: an operator must never read a stale or made-up number as the live temperature
+    let with = render(&STATUS);
+    assert!(count(&with, 0, 12, OLED_WIDTH, 16, YELLOW) > 100);
+    assert!(count(&with, 0, 0, OLED_WIDTH, 8, CYAN) > 0, "header");
+
+    let without = render(&HmiStatus { reading: None, ..STATUS });
+    assert_eq!(count(&without, 0, 12, OLED_WIDTH, 16, YELLOW), 0);
+    assert_ne!(with, without);
+}
+
+#[test]
+fn health_boxes_and_state_follow_the_nodes() {
+    // what: each node's box is green when healthy and red when faulty; one node down turns the header state yellow (DEGR), losing quorum red (HALT)
+    // why: the cabinet panel is the first place a technician looks when the voter holds its output, in the status map's colours
+    let healthy = render(&STATUS);
+    for i in 0..3 {
+        assert_eq!(count(&healthy, i * 43, 53, 8, 8, GREEN), 64, "node {}", i);
+    }
+    let state_area = |f: &Frame, c| count(f, OLED_WIDTH - 4 * CELL_W, 0, 4 * CELL_W, 8, c);
+    assert!(state_area(&healthy, GREEN) > 0);
+
+    let degraded = render(&HmiStatus { healthy: [true, false, true], ..STATUS });
+    assert_eq!(count(&degraded, 43, 53, 8, 8, RED), 64);
+    assert!(state_area(&degraded, Status::Degraded.mapping().color.rgb565()) > 0);
+    assert_eq!(state_area(&degraded, GREEN), 0);
+
+    let halted = render(&HmiStatus { healthy: [false, true, false], ..STATUS });
+    assert_eq!(count(&halted, 0, 53, 8, 8, RED), 64);
+    assert_eq!(count(&halted, 43, 53, 8, 8, GREEN), 64);
+    assert_eq!(state_area(&halted, GREEN), 0);
+    assert!(state_area(&halted, RED) > 0);
+
+    let moved = render(&HmiStatus { leader: 2, ..STATUS });
+    assert_ne!(count(&moved, 86 + 11, 54, 3 * CELL_W, 8, WHITE), count(&healthy, 86 + 11, 54, 3 * CELL_W, 8, WHITE), "leader star moves");
+}
+
+#[test]
+fn rgba_export_expands_rgb565_to_full_range() {
+    // what: white, black and the primaries come out as 255/0 per channel with opaque alpha, four bytes per pixel
+    // why: the canvas preview has to match the panel's colours, and a truncating shift would grey out every pure colour
+    let mut frame = Frame::default();
+    frame.set(0, 0, WHITE);
+    frame.set(1, 0, RED);
+    frame.set(2, 0, GREEN);
+    let rgba = frame.to_rgba();
+    assert_eq!(rgba.len(), OLED_WIDTH * OLED_HEIGHT * 4);
+    assert_eq!(&rgba[0..4], &[255, 255, 255, 255]);
+    assert_eq!(&rgba[4..8], &[255, 0, 0, 255]);
+    assert_eq!(&rgba[8..12], &[0, 255, 0, 255]);
+    assert_eq!(&rgba[12..16], &[0, 0, 0, 255]);
+}
@@ -0,0 +1,67 @@
+// what: tests for the stride threat model data behind the hardware tab explorer
+// why: a demo link to a renamed attack would throw on click, and a mitigated threat with no demo is an unbacked claim
+
+use crate::headless::resolve_attack;
+use crate::tabs::hardware::threats::{cell, filtered, tally, Boundary, Demo, Mitigation, Stride, THREATS};
+
+#[test]
+fn every_mitigated_threat_links_to_a_real_demo() {
+    // what: wasm and segmentation threats carry a demo, wasm ones an attack the api accepts, segmentation ones the diode console
+    // why: the explorer's "run scenario" button goes through the headless api, which rejects unknown ids
+    for t in THREATS.iter().filter(|t| t.mitigation != Mitigation::Open) {
+        match (t.mitigation, t.demo) {
+            (Mitigation::Wasm, Some(Demo::Attack(attack))) => assert!(resolve_attack(attack).is_ok(), "{}: {}", t.id, attack),
+            (Mitigation::Segmentation, Some(Demo::DiodeConsole)) => {}
+            other => panic!("{} has {:?}", t.id, other),
+        }
+    }
+}
+
+#[test]
+fn ids_are_unique_and_match_their_boundary() {
+    // what: ids are unique, prefixed by their boundary, and the table runs top of the diagram down
+    // why: ids are the chip labels in the grid; a duplicate or misplaced one reads as the wrong cell
+    let mut ids: Vec<&str> = THREATS.iter().map(|t| t.id).collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), THREATS.len());
+    for t in &THREATS {
+        let prefix = match t.boundary {
+            Boundary::L3 => "L3-",
+            Boundary::Conduit32 => "C32-",
+            Boundary::L2 => "L2-",
+            Boundary::Conduit21 => "C21-",
+            Boundary::L1 => "L1-",
+            Boundary::L0 => "L0-",
+        };
+        assert!(t.id.starts_with(prefix), "{}", t.id);
+        assert!(t.id[prefix.len()..].starts_with(t.stride.letter()), "{}", t.id);
+    }
+    assert!(THREATS.windows(2).all(|w| w[0].boundary <= w[1].boundary), "table in diagram order");
+}
+
+#[test]
+fn grid_cells_and_filters_partition_the_table() {
+    // what: every threat lands in exactly one grid cell, and the mitigation filters add up to the whole table
+    // why: the grid and the tally buttons are both derived views; neither may drop or double a threat
+    let in_cells: usize = Boundary::ALL.iter().flat_map(|b| Stride::ALL.map(|s| cell(*b, s).len())).sum();
+    assert_eq!(in_cells, THREATS.len());
+    assert_eq!(tally().iter().sum::<usize>(), THREATS.len());
+    assert_eq!(filtered(None, None).len(), THREATS.len());
+    for (m, n) in Mitigation::ALL.iter().zip(tally()) {
+        assert_eq!(filtered(None, Some(*m)).len(), n);
+    }
+    let info_wasm = filtered(Some(Stride::InformationDisclosure), Some(Mitigation::Wasm));
+    assert_eq!(info_wasm.iter().map(|t| t.id).collect::<Vec<_>>(), ["L2-I1", "L2-I2", "L2-I3"]);
+}
+
+#[test]
+fn the_model_admits_what_it_leaves_open() {
+    // what: open threats exist outside l2, and every stride category appears somewhere
+    // why: a model where wasm mitigates everything would be the claim the explorer exists to qualify
+    let [wasm, segmentation, open] = tally();
+    assert!(wasm > 0 && segmentation > 0 && open > 0);
+    assert!(THREATS.iter().any(|t| t.mitigation == Mitigation::Open && t.boundary != Boundary::L2));
+    assert!(THREATS.iter().all(|t| t.mitigation != Mitigation::Wasm || t.boundary == Boundary::L2), "wasm only guards the workers");
+    assert!(Stride::ALL.iter().all(|s| THREATS.iter().any(|t| t.stride == *s)));
+}
@@ -0,0 +1,105 @@
+// what: stride threat model explorer - boundary x stride grid, filters by category and owning control, threat list with demo links
+// why: shows which threats the wasm sandbox owns, which segmentation owns, and which this design leaves open
+// relations: data from threats.rs, used by hardware/component.rs; attack links go through crate::headless, the diode link switches section
+
+use leptos::*;
+use super::threats::{cell, filtered, tally, Boundary, Demo, Mitigation, Stride, Threat};
+use crate::headless::GuardianDemo;
+
+/// css class for a mitigation chip
+fn chip_class(m: Mitigation) -> &'static str {
+    match m {
+        Mitigation::Wasm => "stride-chip wasm",
+        Mitigation::Segmentation => "stride-chip segmentation",
+        Mitigation::Open => "stride-chip open",
+    }
+}
+
+/// link to the demo that shows the threat mitigated (or not)
+fn demo_link(threat: &'static Threat, set_section: WriteSignal<&'static str>) -> impl IntoView {
+    match threat.demo {
+        Some(Demo::Attack(attack)) => view! {
+            <button class="action-btn" on:click=move |_| {
+                if let Err(e) = GuardianDemo.run_attack(attack) {
+                    tracing::warn!(target: "api", error = ?e, attack, "threat model demo link failed");
+                }
+            }>"▶ Run scenario"</button>
+        }.into_view(),
+        Some(Demo::DiodeConsole) => view! {
+            <button class="action-btn" on:click=move |_| set_section.set("compliance")>"🚧 Diode console"</button>
+        }.into_view(),
+        None => view! { <span class="section-hint">"no demo"</span> }.into_view(),
+    }
+}
+
+/// stride threat model of the four-zone deployment
+#[component]
+pub fn ThreatModelSection(set_section: WriteSignal<&'static str>) -> impl IntoView {
+    let (stride, set_stride) = create_signal(None::<Stride>);
+    let (mitigation, set_mitigation) = create_signal(None::<Mitigation>);
+    let counts = tally();
+
+    let grid = move || view! {
+        <table class="capability-matrix stride-grid">
+            <tr>
+                <th>"Level / conduit"</th>
+                {Stride::ALL.map(|s| view! {
+                    <th>
+                        <button
+                            class=move || if stride.get() == Some(s) { "section-btn active" } else { "section-btn" }
+                            title=s.label()
+                            on:click=move |_| set_stride.update(|cur| *cur = if *cur == Some(s) { None } else { Some(s) })
+                        >{s.letter()}</button>
+                    </th>
+                }).collect_view()}
+            </tr>
+            {Boundary::ALL.map(|b| view! {
+                <tr class:stride-conduit=b.is_conduit()>
+                    <td>{b.label()}</td>
+                    {Stride::ALL.map(|s| view! {
+                        <td class="stride-cell">
+                            {cell(b, s).into_iter().map(|t| {
+                                let dimmed = mitigation.get().is_some_and(|m| m != t.mitigation);
+                                view! {
+                                    <span class=chip_class(t.mitigation) class:dimmed=dimmed title=t.title>{t.id}</span>
+                                }
+                            }).collect_view()}
+                        </td>
+                    }).collect_view()}
+                </tr>
+            }).collect_view()}
+        </table>
+    };
+
+    let list = move || filtered(stride.get(), mitigation.get()).into_iter().map(|t| view! {
+        <div class="stride-threat">
+            <div class="stride-threat-head">
+                <span class=chip_class(t.mitigation)>{t.id}</span>
+                <strong>{t.title}</strong>
+                <span class="mitre-tactic">{t.stride.label()}</span>
+            </div>
+            <p class="section-hint">{t.boundary.label()}</p>
+            <p>{format!("{} {}: {}", t.mitigation.icon(), t.mitigation.label(), t.detail)}</p>
+            {demo_link(t, set_section)}
+        </div>
+    }).collect_view();
+
+    view! {
+        <div class="compliance-section threat-model-section">
+            <h3>"🧭 STRIDE Threat Model"</h3>
+            <p class="section-hint">"💡 Click a STRIDE letter or a control to filter; mitigated threats link to the scenario that demonstrates them"</p>
+
+            <div class="audit-controls">
+                {Mitigation::ALL.into_iter().zip(counts).map(|(m, n)| view! {
+                    <button
+                        class=move || if mitigation.get() == Some(m) { "section-btn active" } else { "section-btn" }
+                        on:click=move |_| set_mitigation.update(|cur| *cur = if *cur == Some(m) { None } else { Some(m) })
+                    >{format!("{} {} ({})", m.icon(), m.label(), n)}</button>
+                }).collect_view()}
+            </div>
+
+            {grid}
+            <div class="stride-threats">{list}</div>
+        </div>
+    }
+}
@@ -0,0 +1,295 @@
+// what: stride threat model of the deployment - threats per purdue level and conduit, what mitigates each, and the demo that shows it
+// why: "wasm makes it secure" is only credible next to the threats it does not touch; the model says which control owns which threat
+// relations: rendered by threat_model.rs on the hardware tab; attack ids from tabs/demo/attacks.rs, run through crate::headless
+
+/// stride category
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stride {
+    Spoofing,
+    Tampering,
+    Repudiation,
+    InformationDisclosure,
+    DenialOfService,
+    ElevationOfPrivilege,
+}
+
+impl Stride {
+    pub const ALL: [Stride; 6] = [
+        Stride::Spoofing,
+        Stride::Tampering,
+        Stride::Repudiation,
+        Stride::InformationDisclosure,
+        Stride::DenialOfService,
+        Stride::ElevationOfPrivilege,
+    ];
+
+    pub fn letter(&self) -> &'static str {
+        match self {
+            Stride::Spoofing => "S",
+            Stride::Tampering => "T",
+            Stride::Repudiation => "R",
+            Stride::InformationDisclosure => "I",
+            Stride::DenialOfService => "D",
+            Stride::ElevationOfPrivilege => "E",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Stride::Spoofing => "Spoofing",
+            Stride::Tampering => "Tampering",
+            Stride::Repudiation => "Repudiation",
+            Stride::InformationDisclosure => "Information Disclosure",
+            Stride::DenialOfService => "Denial of Service",
+            Stride::ElevationOfPrivilege => "Elevation of Privilege",
+        }
+    }
+}
+
+/// where a threat sits: a purdue level or the conduit between two, top of the diagram first
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Boundary {
+    L3,
+    /// l3 ↔ l2, the historian api over tls
+    Conduit32,
+    L2,
+    /// l2 ↔ l1, modbus rtu over rs-485
+    Conduit21,
+    L1,
+    L0,
+}
+
+impl Boundary {
+    pub const ALL: [Boundary; 6] = [Boundary::L3, Boundary::Conduit32, Boundary::L2, Boundary::Conduit21, Boundary::L1, Boundary::L0];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Boundary::L3 => "L3 Operations (Grafana, InfluxDB, NAS)",
+            Boundary::Conduit32 => "L3 ↔ L2 conduit (historian API, TLS)",
+            Boundary::L2 => "L2 Guardian Cluster (WASM workers)",
+            Boundary::Conduit21 => "L2 ↔ L1 conduit (Modbus RTU, RS-485)",
+            Boundary::L1 => "L1 PLC (S7-1200)",
+            Boundary::L0 => "L0 Field devices (BME280, relay)",
+        }
+    }
+
+    pub fn is_conduit(&self) -> bool {
+        matches!(self, Boundary::Conduit32 | Boundary::Conduit21)
+    }
+}
+
+/// which control owns the threat
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mitigation {
+    /// the wasm sandbox: capabilities not granted, engine limits, typed parsing
+    Wasm,
+    /// zones and conduits: the path does not exist or only runs one way
+    Segmentation,
+    /// nothing in this design stops it
+    Open,
+}
+
+impl Mitigation {
+    pub const ALL: [Mitigation; 3] = [Mitigation::Wasm, Mitigation::Segmentation, Mitigation::Open];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Mitigation::Wasm => "WASM capabilities",
+            Mitigation::Segmentation => "Network segmentation",
+            Mitigation::Open => "Left open",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Mitigation::Wasm => "🦀",
+            Mitigation::Segmentation => "🧱",
+            Mitigation::Open => "⚠️",
+        }
+    }
+}
+
+/// where the mitigation (or the gap) can be seen in the app
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Demo {
+    /// a demo tab attack scenario
+    Attack(&'static str),
+    /// the data diode console in the iec 62443 section
+    DiodeConsole,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Threat {
+    pub id: &'static str,
+    pub boundary: Boundary,
+    pub stride: Stride,
+    pub title: &'static str,
+    pub detail: &'static str,
+    pub mitigation: Mitigation,
+    pub demo: Option<Demo>,
+}
+
+pub const THREATS: [Threat; 16] = [
+    Threat {
+        id: "L3-S1",
+        boundary: Boundary::L3,
+        stride: Stride::Spoofing,
+        title: "Stolen dashboard login used to pose as an operator",
+        detail: "Zone 3 only reads telemetry; there is no operator command path from Grafana to the cluster or the PLC to abuse.",
+        mitigation: Mitigation::Segmentation,
+        demo: Some(Demo::DiodeConsole),
+    },
+    Threat {
+        id: "L3-I1",
+        boundary: Boundary::L3,
+        stride: Stride::InformationDisclosure,
+        title: "Historian data read by an intruder on the IT network",
+        detail: "Telemetry is meant to flow up and is stored in Zone 3; protecting it is left to IT controls.",
+        mitigation: Mitigation::Open,
+        demo: None,
+    },
+    Threat {
+        id: "L3-E1",
+        boundary: Boundary::L3,
+        stride: Stride::ElevationOfPrivilege,
+        title: "IT foothold reaches the PLC directly",
+        detail: "No route from Zone 3 to Zone 1; every southbound write has to pass the Guardian, which drops it.",
+        mitigation: Mitigation::Segmentation,
+        demo: Some(Demo::DiodeConsole),
+    },
+    Threat {
+        id: "C32-T1",
+        boundary: Boundary::Conduit32,
+        stride: Stride::Tampering,
+        title: "Write command smuggled down the historian API",
+        detail: "The conduit is one-way by design: the gateway enforces a logical data diode and rejects southbound function codes.",
+        mitigation: Mitigation::Segmentation,
+        demo: Some(Demo::DiodeConsole),
+    },
+    Threat {
+        id: "C32-R1",
+        boundary: Boundary::Conduit32,
+        stride: Stride::Repudiation,
+        title: "Configuration change with no record of who made it",
+        detail: "Sandboxing does not attribute actions; the audit journal export helps, but signed change control is out of scope.",
+        mitigation: Mitigation::Open,
+        demo: None,
+    },
+    Threat {
+        id: "L2-T1",
+        boundary: Boundary::L2,
+        stride: Stride::Tampering,
+        title: "Malformed Modbus frame corrupts the parser",
+        detail: "The Rust parser rejects an impossible MBAP length with a typed error inside the sandbox; the Python parser allocates and dies.",
+        mitigation: Mitigation::Wasm,
+        demo: Some(Demo::Attack("lengthOverflow")),
+    },
+    Threat {
+        id: "L2-I1",
+        boundary: Boundary::L2,
+        stride: Stride::InformationDisclosure,
+        title: "Compromised worker exfiltrates telemetry",
+        detail: "No socket capability is granted to the sensor world, so open-socket() is not even linked.",
+        mitigation: Mitigation::Wasm,
+        demo: Some(Demo::Attack("dataExfil")),
+    },
+    Threat {
+        id: "L2-I2",
+        boundary: Boundary::L2,
+        stride: Stride::InformationDisclosure,
+        title: "Worker reads host files outside its preopen",
+        detail: "Only /dev/i2c-1 is preopened, read-only; any other path is outside the component's filesystem.",
+        mitigation: Mitigation::Wasm,
+        demo: Some(Demo::Attack("pathTraversal")),
+    },
+    Threat {
+        id: "L2-I3",
+        boundary: Boundary::L2,
+        stride: Stride::InformationDisclosure,
+        title: "Worker harvests credentials from the environment",
+        detail: "A component sees only the variables passed with --env, not the host process environment.",
+        mitigation: Mitigation::Wasm,
+        demo: Some(Demo::Attack("envTheft")),
+    },
+    Threat {
+        id: "L2-D1",
+        boundary: Boundary::L2,
+        stride: Stride::DenialOfService,
+        title: "Unbounded recursion exhausts the worker",
+        detail: "The engine's stack limit traps the instance; the other two keep voting.",
+        mitigation: Mitigation::Wasm,
+        demo: Some(Demo::Attack("stackExhaustion")),
+    },
+    Threat {
+        id: "L2-D2",
+        boundary: Boundary::L2,
+        stride: Stride::DenialOfService,
+        title: "Cluster leader crashes or stops sending heartbeats",
+        detail: "Nothing prevents the crash; Raft elects a new leader and the demo measures how long that takes.",
+        mitigation: Mitigation::Open,
+        demo: Some(Demo::Attack("killLeader")),
+    },
+    Threat {
+        id: "L2-E1",
+        boundary: Boundary::L2,
+        stride: Stride::ElevationOfPrivilege,
+        title: "Heap spray to gain memory the worker was not given",
+        detail: "The memory limit in the world's policy caps linear memory; the oversized allocation traps.",
+        mitigation: Mitigation::Wasm,
+        demo: Some(Demo::Attack("bufferOverflow")),
+    },
+    Threat {
+        id: "L2-E2",
+        boundary: Boundary::L2,
+        stride: Stride::ElevationOfPrivilege,
+        title: "Compromised worker pivots to other hosts",
+        detail: "Without a socket grant the worker cannot reach the PLC's TCP port or other internal hosts.",
+        mitigation: Mitigation::Wasm,
+        demo: Some(Demo::Attack("lateralMovement")),
+    },
+    Threat {
+        id: "C21-S1",
+        boundary: Boundary::Conduit21,
+        stride: Stride::Spoofing,
+        title: "Rogue device injects commands on the RS-485 bus",
+        detail: "Modbus RTU has no authentication; anyone with physical access to the bus can talk to the PLC.",
+        mitigation: Mitigation::Open,
+        demo: None,
+    },
+    Threat {
+        id: "L1-T1",
+        boundary: Boundary::L1,
+        stride: Stride::Tampering,
+        title: "PLC program altered from the engineering workstation",
+        detail: "TIA Portal downloads go straight to the PLC over Ethernet, outside the Guardian's path.",
+        mitigation: Mitigation::Open,
+        demo: None,
+    },
+    Threat {
+        id: "L0-T1",
+        boundary: Boundary::L0,
+        stride: Stride::Tampering,
+        title: "Sensor physically tampered with or spoofed",
+        detail: "If all three nodes read the same tampered sensor they agree on the bad value; only the anomaly detector notices.",
+        mitigation: Mitigation::Open,
+        demo: None,
+    },
+];
+
+/// threats at one boundary in one stride category
+pub fn cell(boundary: Boundary, stride: Stride) -> Vec<&'static Threat> {
+    THREATS.iter().filter(|t| t.boundary == boundary && t.stride == stride).collect()
+}
+
+/// threats matching the optional filters, in table order
+pub fn filtered(stride: Option<Stride>, mitigation: Option<Mitigation>) -> Vec<&'static Threat> {
+    THREATS
+        .iter()
+        .filter(|t| stride.is_none_or(|s| t.stride == s) && mitigation.is_none_or(|m| t.mitigation == m))
+        .collect()
+}
+
+/// number of threats per mitigation, in Mitigation::ALL order
+pub fn tally() -> [usize; 3] {
+    Mitigation::ALL.map(|m| THREATS.iter().filter(|t| t.mitigation == m).count())
+}
@@ -1,15 +1,11 @@
-// what: tia portal integration section with click-to-toggle tooltips
+// what: tia portal integration section with glossary notes
 // why: shows professional engineering workflow using real industrial tools
-// relations: used by hardware/component.rs, demonstrates enterprise integration
+// relations: used by hardware/component.rs, demonstrates enterprise integration; ⓘ text from crate::glossary notes
 
 use leptos::*;
+use crate::glossary::InfoTip;
 
-// toolchain tooltips
-const TIA_TOOLTIP: &str = "Siemens TIA Portal is industry-standard PLC programming software. It provides ladder logic programming, device configuration, and live monitoring. Using real engineering tools (not hobbyist alternatives) demonstrates enterprise readiness.";
-const PLC_TOOLTIP: &str = "The S7-1200 receives ladder logic programs via TIA Portal over Ethernet. Once programmed, it operates autonomously, executing control logic and communicating with the Guardian Cluster via Modbus RTU.";
-const GUARDIAN_TOOLTIP: &str = "The Guardian Cluster intercepts all Modbus traffic. WIT contracts define exactly which Modbus registers can be read/written. Deny-by-default: any capability not explicitly granted is blocked. Docker containers inherit broader host privileges by default.";
-
-/// renders tia portal integration diagram with click-to-toggle tooltips
+/// renders tia portal integration diagram with glossary notes
 #[component]
 pub fn ToolchainSection() -> impl IntoView {
     view! {
@@ -24,7 +20,7 @@ pub fn ToolchainSection() -> impl IntoView {
                     icon="💻" 
                     name="TIA Portal V20" 
                     desc="(Trial License)"
-                    tooltip=TIA_TOOLTIP
+                    note="toolchain.tia"
                     features=vec![
                         "Program S7-1200 ladder logic",
                         "Configure Modbus TCP/RTU",
@@ -39,7 +35,7 @@ pub fn ToolchainSection() -> impl IntoView {
                     icon="🏭" 
                     name="Siemens S7-1200 PLC"
                     desc="Industrial Controller"
-                    tooltip=PLC_TOOLTIP
+                    note="toolchain.plc"
                     features=vec![
                         "Executes ladder logic autonomously",
                         "Modbus RTU ↔ Guardian Cluster",
@@ -53,7 +49,7 @@ pub fn ToolchainSection() -> impl IntoView {
                     icon="🛡️" 
                     name="Guardian Cluster"
                     desc="WIT Contract Validation"
-                    tooltip=GUARDIAN_TOOLTIP
+                    note="toolchain.guardian"
                     features=vec![
                         "WASM sandbox for every worker",
                         "WIT contracts: deny-by-default",
@@ -74,45 +70,24 @@ pub fn ToolchainSection() -> impl IntoView {
     }
 }
 
-/// toolchain item box with click-to-toggle tooltip
+/// toolchain item box with its glossary note
 #[component]
 fn ToolBox(
     icon: &'static str,
     name: &'static str,
     desc: &'static str,
-    tooltip: &'static str,
+    note: &'static str,
     features: Vec<&'static str>,
 ) -> impl IntoView {
-    let (show_tooltip, set_show_tooltip) = create_signal(false);
     
     view! {
         <div class="tool-box">
             <span class="tool-icon">{icon}</span>
             <span class="tool-name">
                 {name}
-                <button 
-                    class="info-btn"
-                    on:click=move |_| set_show_tooltip.update(|v| *v = !*v)
-                >
-                    "ⓘ"
-                </button>
+                <InfoTip note=note />
             </span>
             <span class="tool-desc">{desc}</span>
-            <Show when=move || show_tooltip.get()>
-                <div 
-                    class="tooltip-overlay" 
-                    on:click=move |_| set_show_tooltip.set(false)
-                />
-                <div class="tooltip-popup">
-                    <div class="tooltip-content">{tooltip}</div>
-                    <button 
-                        class="tooltip-close"
-                        on:click=move |_| set_show_tooltip.set(false)
-                    >
-                        "✕"
-                    </button>
-                </div>
-            </Show>
             <ul class="tool-features">
                 {features.into_iter().map(|f| view! {
                     <li>{f}</li>
@@ -0,0 +1,358 @@
+// what: "build your own guardian" design model - protocols, redundancy, grants and network plan in, wit world, wasmtime command
+//       and ota/bandwidth estimate out
+// why: the demo answers "does it work"; a designer's next question is "what would mine look like", and the answer should be files they can start from
+// relations: grants as a demo/policy.rs WorldPolicy (wasmtime flags from there), redundancy as a demo/architecture.rs Architecture,
+//            links and module size from proof/ota_simulator.rs; stepped through by wizard_panel.rs
+
+use crate::tabs::demo::architecture::Architecture;
+use crate::tabs::demo::policy::{DirGrant, WorldPolicy};
+use crate::tabs::proof::ota_simulator::{calc_download_time_secs, format_currency, format_time, NetworkType, WASM_UPDATE_SIZE_MB};
+
+/// per-packet tcp/ip header overhead (bytes, ipv4 + tcp without options)
+pub const TCP_IP_OVERHEAD: u32 = 40;
+/// a month of polling, for the telemetry estimate (seconds)
+pub const MONTH_S: f64 = 30.0 * 86_400.0;
+/// a link loaded beyond this share by polling alone leaves no headroom for updates and retries
+pub const MAX_LINK_SHARE: f64 = 0.5;
+
+/// field protocols the worker can speak
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Protocol {
+    Modbus,
+    Dnp3,
+    OpcUa,
+}
+
+impl Protocol {
+    pub const ALL: [Protocol; 3] = [Protocol::Modbus, Protocol::Dnp3, Protocol::OpcUa];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Protocol::Modbus => "Modbus TCP",
+            Protocol::Dnp3 => "DNP3",
+            Protocol::OpcUa => "OPC UA",
+        }
+    }
+
+    /// registered tcp port of the field device
+    pub fn port(&self) -> u16 {
+        match self {
+            Protocol::Modbus => 502,
+            Protocol::Dnp3 => 20000,
+            Protocol::OpcUa => 4840,
+        }
+    }
+
+    /// wit interface the world imports for it
+    pub fn interface(&self) -> &'static str {
+        match self {
+            Protocol::Modbus => "modbus-client",
+            Protocol::Dnp3 => "dnp3-master",
+            Protocol::OpcUa => "opcua-client",
+        }
+    }
+
+    /// body of that interface
+    fn wit_funcs(&self) -> &'static str {
+        match self {
+            Protocol::Modbus => "    // fc03: `count` holding registers from `start`\n    read-holding-registers: func(unit: u8, start: u16, count: u16) -> result<list<u16>, string>;\n    // fc06\n    write-single-register: func(unit: u8, addr: u16, value: u16) -> result<_, string>;\n",
+            Protocol::Dnp3 => "    // class 0 integrity poll, analog inputs by index\n    integrity-poll: func(outstation: u16) -> result<list<f64>, string>;\n    // select-before-operate is left to the host; this is direct operate\n    direct-operate: func(outstation: u16, index: u16, value: f64) -> result<_, string>;\n",
+            Protocol::OpcUa => "    // value attribute of one node, e.g. \"ns=2;s=Boiler.Temp\"\n    read-node: func(node-id: string) -> result<f64, string>;\n    write-node: func(node-id: string, value: f64) -> result<_, string>;\n",
+        }
+    }
+
+    /// application bytes of one poll, request plus response: a 10-register fc03, a class 0 poll of 10 analogs,
+    /// and a 10-node read on an open secure channel
+    pub fn poll_bytes(&self) -> u32 {
+        match self {
+            Protocol::Modbus => 12 + 29,
+            Protocol::Dnp3 => 27 + 110,
+            Protocol::OpcUa => 320 + 260,
+        }
+    }
+
+    /// what the protocol adapter adds to the worker module (KiB, release build, estimate)
+    pub fn adapter_kib(&self) -> u32 {
+        match self {
+            Protocol::Modbus => 8,
+            Protocol::Dnp3 => 40,
+            Protocol::OpcUa => 120,
+        }
+    }
+}
+
+/// the wizard's pages, in order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Protocols,
+    Redundancy,
+    Grants,
+    Network,
+    Summary,
+}
+
+impl Step {
+    pub const ALL: [Step; 5] = [Step::Protocols, Step::Redundancy, Step::Grants, Step::Network, Step::Summary];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Step::Protocols => "Protocols",
+            Step::Redundancy => "Redundancy",
+            Step::Grants => "Capability grants",
+            Step::Network => "Network plan",
+            Step::Summary => "Summary",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|s| s == self).unwrap_or(0)
+    }
+
+    pub fn next(&self) -> Option<Step> {
+        Self::ALL.get(self.index() + 1).copied()
+    }
+
+    pub fn prev(&self) -> Option<Step> {
+        self.index().checked_sub(1).map(|i| Self::ALL[i])
+    }
+}
+
+/// everything the wizard asks
+#[derive(Clone, Debug, PartialEq)]
+pub struct Design {
+    /// wit world and module name
+    pub name: String,
+    pub protocols: Vec<Protocol>,
+    pub architecture: Architecture,
+    pub directories: Vec<DirGrant>,
+    pub env: Vec<String>,
+    pub gpio: Vec<u8>,
+    /// per instance
+    pub memory_limit_mb: u32,
+    /// field device the protocols talk to
+    pub peer: String,
+    pub network: NetworkType,
+    pub poll_ms: u32,
+    pub fleet: u32,
+    pub updates_per_year: u32,
+}
+
+/// the bundled sensor-node, as a starting point
+impl Default for Design {
+    fn default() -> Self {
+        Self {
+            name: "my-guardian".to_string(),
+            protocols: vec![Protocol::Modbus],
+            architecture: Architecture::TwoOoThree,
+            directories: vec![DirGrant { path: "/dev/i2c-1".to_string(), readonly: true }],
+            env: vec!["SENSOR_TYPE=bme280".to_string(), "POLL_INTERVAL_MS=1000".to_string()],
+            gpio: Vec::new(),
+            memory_limit_mb: 2,
+            peer: "10.0.0.10".to_string(),
+            network: NetworkType::Cellular,
+            poll_ms: 1000,
+            fleet: 100,
+            updates_per_year: 12,
+        }
+    }
+}
+
+/// what the network plan costs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Estimate {
+    pub module_kib: u32,
+    /// every instance's linear memory together (MiB)
+    pub memory_mb: u32,
+    pub ota_secs_per_device: f64,
+    pub ota_mb_per_year: f64,
+    /// polling traffic of one device, all protocols, headers included
+    pub telemetry_bps: f64,
+    pub telemetry_mb_per_month: f64,
+    /// share of the link the polling uses
+    pub link_share: f64,
+    /// ota plus a year of telemetry, whole fleet (USD)
+    pub cost_per_year: f64,
+}
+
+/// a wit identifier: lowercase words joined by single hyphens, not starting with a digit
+pub fn is_wit_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('-').all(|word| !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()))
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// one directory per line, a trailing " ro" for read-only
+pub fn parse_dirs(text: &str) -> Vec<DirGrant> {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| match l.strip_suffix(" ro") {
+            Some(path) => DirGrant { path: path.trim().to_string(), readonly: true },
+            None => DirGrant { path: l.to_string(), readonly: false },
+        })
+        .collect()
+}
+
+/// inverse of parse_dirs
+pub fn dirs_text(dirs: &[DirGrant]) -> String {
+    dirs.iter().map(|d| if d.readonly { format!("{} ro", d.path) } else { d.path.clone() }).collect::<Vec<_>>().join("\n")
+}
+
+/// comma or space separated bcm pins; anything that is not a pin number is dropped
+pub fn parse_pins(text: &str) -> Vec<u8> {
+    let mut pins: Vec<u8> = text.split([',', ' ']).filter_map(|p| p.trim().parse().ok()).filter(|p| *p <= 27).collect();
+    pins.sort_unstable();
+    pins.dedup();
+    pins
+}
+
+impl Design {
+    pub fn toggle(&mut self, protocol: Protocol) {
+        if let Some(at) = self.protocols.iter().position(|p| *p == protocol) {
+            self.protocols.remove(at);
+        } else {
+            self.protocols.push(protocol);
+            self.protocols.sort_by_key(|p| Protocol::ALL.iter().position(|q| q == p));
+        }
+    }
+
+    /// the world's grants; one socket per protocol to the field device
+    pub fn world(&self) -> WorldPolicy {
+        WorldPolicy {
+            name: self.name.clone(),
+            directories: self.directories.clone(),
+            sockets: self.protocols.iter().map(|p| format!("{}:{}", self.peer, p.port())).collect(),
+            registers: Vec::new(),
+            gpio: self.gpio.clone(),
+            memory_limit_mb: self.memory_limit_mb,
+            env: self.env.clone(),
+        }
+    }
+
+    /// package, one interface per protocol (plus gpio and the telemetry export) and the world tying them together
+    pub fn wit(&self) -> String {
+        let mut out = format!(
+            "// generated by the guardian one design wizard: fill in the worker, keep the imports\npackage guardian-one:{}@0.1.0;\n",
+            self.name
+        );
+        for p in &self.protocols {
+            out.push_str(&format!("\n/// {} to {}:{}\ninterface {} {{\n{}}}\n", p.label(), self.peer, p.port(), p.interface(), p.wit_funcs()));
+        }
+        if !self.gpio.is_empty() {
+            out.push_str("\ninterface gpio {\n    // drive one BCM pin high or low\n    write-pin: func(pin: u8, high: bool) -> result<_, string>;\n}\n");
+        }
+        out.push_str(&format!(
+            "\n/// called by the host every {}ms; each of the {} instance(s) returns one packet for the {} voter\ninterface telemetry {{\n    record telemetry-packet {{ timestamp: u64, value: f64, status: u8 }}\n    poll: func() -> telemetry-packet;\n}}\n",
+            self.poll_ms,
+            self.architecture.channels(),
+            self.architecture.id(),
+        ));
+        out.push_str(&format!("\nworld {} {{\n", self.name));
+        if !self.directories.is_empty() {
+            out.push_str("    import wasi:filesystem/preopens@0.2.0;\n");
+        }
+        if !self.env.is_empty() {
+            out.push_str("    import wasi:cli/environment@0.2.0;\n");
+        }
+        if !self.protocols.is_empty() {
+            out.push_str("    import wasi:sockets/tcp@0.2.0;\n");
+        }
+        for p in &self.protocols {
+            out.push_str(&format!("    import {};\n", p.interface()));
+        }
+        if !self.gpio.is_empty() {
+            out.push_str("    import gpio;\n");
+        }
+        out.push_str("    export telemetry;\n}\n");
+        out
+    }
+
+    /// the command each instance runs under on the pi
+    pub fn wasmtime_command(&self) -> String {
+        self.world().wasmtime_command()
+    }
+
+    pub fn estimate(&self) -> Estimate {
+        let module_kib = (WASM_UPDATE_SIZE_MB * 1000.0) as u32 + self.protocols.iter().map(Protocol::adapter_kib).sum::<u32>();
+        let module_mb = module_kib as f64 / 1000.0;
+        // each poll is one request and one response segment per protocol
+        let bytes_per_poll: u32 = self.protocols.iter().map(|p| p.poll_bytes() + 2 * TCP_IP_OVERHEAD).sum();
+        let telemetry_bps = bytes_per_poll as f64 * 8.0 * 1000.0 / self.poll_ms.max(1) as f64;
+        let telemetry_mb_per_month = telemetry_bps / 8.0 * MONTH_S / 1_000_000.0;
+        let ota_mb_per_year = module_mb * self.updates_per_year as f64;
+        Estimate {
+            module_kib,
+            memory_mb: self.memory_limit_mb * self.architecture.channels() as u32,
+            ota_secs_per_device: calc_download_time_secs(module_mb, self.network.speed_mbps()),
+            ota_mb_per_year,
+            telemetry_bps,
+            telemetry_mb_per_month,
+            link_share: telemetry_bps / (self.network.speed_mbps() * 1_000_000.0),
+            cost_per_year: (ota_mb_per_year + telemetry_mb_per_month * 12.0) * self.fleet as f64 * self.network.cost_per_mb(),
+        }
+    }
+
+    /// what would stop the design from working as asked, worst first; empty when it is ready to export
+    pub fn issues(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+        if !is_wit_name(&self.name) {
+            issues.push(format!("\"{}\" is not a wit name (lowercase words joined by hyphens)", self.name));
+        }
+        if self.protocols.is_empty() {
+            issues.push("no protocol chosen: the worker has nothing to poll".to_string());
+        }
+        if self.memory_limit_mb == 0 {
+            issues.push("memory limit 0MB: the module cannot instantiate".to_string());
+        }
+        if self.peer.trim().is_empty() || self.peer.contains(char::is_whitespace) {
+            issues.push("field device address missing".to_string());
+        }
+        let share = self.estimate().link_share;
+        if share > MAX_LINK_SHARE {
+            issues.push(format!("polling alone uses {:.0}% of the link: poll less often or pick a faster link", share * 100.0));
+        }
+        issues
+    }
+
+    /// the whole design as one markdown artifact
+    pub fn summary(&self) -> String {
+        let e = self.estimate();
+        let protocols = self.protocols.iter().map(Protocol::label).collect::<Vec<_>>().join(", ");
+        let mut out = format!(
+            "# {}\n\n- Protocols: {}\n- Redundancy: {} ({} instance(s), {}MB linear memory together)\n- Field device: {}\n- Link: {}, {} device(s), poll every {}ms, {} update(s) a year\n",
+            self.name,
+            if protocols.is_empty() { "none".to_string() } else { protocols },
+            self.architecture.label(),
+            self.architecture.channels(),
+            e.memory_mb,
+            self.peer,
+            self.network.label(),
+            self.fleet,
+            self.poll_ms,
+            self.updates_per_year,
+        );
+        out.push_str(&format!("\n## WIT world\n\n```wit\n{}```\n", self.wit()));
+        out.push_str(&format!("\n## wasmtime\n\n```sh\n{}\n```\n", self.wasmtime_command()));
+        let host = self.world().host_enforced();
+        if !host.is_empty() {
+            out.push_str("\nEnforced by the guardian host, not by a flag:\n\n");
+            host.iter().for_each(|n| out.push_str(&format!("- {}\n", n)));
+        }
+        out.push_str(&format!(
+            "\n## OTA and bandwidth (estimate)\n\n| | |\n|---|---|\n| Module | {} KiB |\n| OTA download per device | {} |\n| OTA traffic per device per year | {:.2} MB |\n| Polling traffic per device | {:.0} bit/s ({:.1} MB/month, {:.3}% of the link) |\n| Fleet cost per year | {} |\n",
+            e.module_kib,
+            format_time(e.ota_secs_per_device),
+            e.ota_mb_per_year,
+            e.telemetry_bps,
+            e.telemetry_mb_per_month,
+            e.link_share * 100.0,
+            format_currency(e.cost_per_year),
+        ));
+        let issues = self.issues();
+        if !issues.is_empty() {
+            out.push_str("\n## Open issues\n\n");
+            issues.iter().for_each(|i| out.push_str(&format!("- {}\n", i)));
+        }
+        out
+    }
+}
@@ -0,0 +1,138 @@
+// what: design section of the hardware tab - a five-step wizard (protocols, redundancy, grants, network plan, summary) over a wizard.rs Design
+// why: turns the demo into a design tool: the last step hands over a wit world, the wasmtime command and the ota/bandwidth numbers as files
+// relations: model in wizard.rs, copy/download helpers from demo/wasm.rs; rendered by hardware/component.rs
+
+use leptos::*;
+use crate::tabs::demo::architecture::Architecture;
+use crate::tabs::demo::wasm::{copy_to_clipboard, download_text};
+use crate::tabs::proof::ota_simulator::{format_currency, format_time, NetworkType};
+use super::wizard::{dirs_text, parse_dirs, parse_pins, Design, Protocol, Step};
+
+/// number input parsed into the design, ignoring anything that does not parse
+fn number(ev: &ev::Event) -> Option<u32> {
+    event_target_value(ev).trim().parse().ok()
+}
+
+#[component]
+pub fn WizardSection() -> impl IntoView {
+    let design = create_rw_signal(Design::default());
+    let (step, set_step) = create_signal(Step::Protocols);
+    let (copied, set_copied) = create_signal(Option::<&'static str>::None);
+    let copy = move |what: &'static str, text: String| {
+        copy_to_clipboard(&text);
+        set_copied.set(Some(what));
+    };
+
+    let page = move || match step.get() {
+        Step::Protocols => view! {
+            <p class="section-hint">"Which field protocols does the worker poll? Each one becomes a WIT interface and one socket grant."</p>
+            <div class="wizard-options">
+                {Protocol::ALL.into_iter().map(|p| view! {
+                    <label class="wizard-option">
+                        <input type="checkbox" prop:checked=move || design.with(|d| d.protocols.contains(&p)) on:change=move |_| design.update(|d| d.toggle(p)) />
+                        <strong>{p.label()}</strong>
+                        <span>{format!("tcp/{} · import {} · ~{} KiB adapter", p.port(), p.interface(), p.adapter_kib())}</span>
+                    </label>
+                }).collect_view()}
+            </div>
+        }.into_view(),
+        Step::Redundancy => view! {
+            <p class="section-hint">"How many instances vote? The Demo tab's architecture panel shows what each one survives."</p>
+            <div class="wizard-options">
+                {Architecture::ALL.into_iter().map(|a| view! {
+                    <label class="wizard-option">
+                        <input type="radio" name="wizard-redundancy" prop:checked=move || design.with(|d| d.architecture == a) on:change=move |_| design.update(|d| d.architecture = a) />
+                        <strong>{a.label()}</strong>
+                        <span>{format!("{} instance(s)", a.channels())}</span>
+                    </label>
+                }).collect_view()}
+            </div>
+        }.into_view(),
+        Step::Grants => view! {
+            <p class="section-hint">"Everything not listed is denied. Sockets follow from the protocols and the field device on the next step."</p>
+            <div class="wizard-grid">
+                <label>"Directories (one per line, \" ro\" for read-only)"
+                    <textarea rows="3" prop:value=move || design.with(|d| dirs_text(&d.directories)) on:change=move |ev| design.update(|d| d.directories = parse_dirs(&event_target_value(&ev)))></textarea>
+                </label>
+                <label>"Environment (KEY=value, one per line)"
+                    <textarea rows="3" prop:value=move || design.with(|d| d.env.join("\n")) on:change=move |ev| design.update(|d| d.env = event_target_value(&ev).lines().map(str::trim).filter(|l| l.contains('=')).map(String::from).collect())></textarea>
+                </label>
+                <label>"GPIO pins (BCM, comma separated)"
+                    <input type="text" prop:value=move || design.with(|d| d.gpio.iter().map(u8::to_string).collect::<Vec<_>>().join(", ")) on:change=move |ev| design.update(|d| d.gpio = parse_pins(&event_target_value(&ev))) />
+                </label>
+                <label>"Memory limit per instance (MB)"
+                    <input type="number" min="0" prop:value=move || design.with(|d| d.memory_limit_mb) on:change=move |ev| if let Some(v) = number(&ev) { design.update(|d| d.memory_limit_mb = v) } />
+                </label>
+            </div>
+        }.into_view(),
+        Step::Network => view! {
+            <p class="section-hint">"Where the devices sit and how they reach the field device and the update server."</p>
+            <div class="wizard-grid">
+                <label>"World name"
+                    <input type="text" prop:value=move || design.with(|d| d.name.clone()) on:input=move |ev| design.update(|d| d.name = event_target_value(&ev).trim().to_string()) />
+                </label>
+                <label>"Field device address"
+                    <input type="text" prop:value=move || design.with(|d| d.peer.clone()) on:input=move |ev| design.update(|d| d.peer = event_target_value(&ev).trim().to_string()) />
+                </label>
+                <label>"Uplink"
+                    <select class="network-select" on:change=move |ev| if let Some(n) = NetworkType::ALL.get(event_target_value(&ev).parse::<usize>().unwrap_or(0)) { design.update(|d| d.network = *n) }>
+                        {NetworkType::ALL.into_iter().enumerate().map(|(i, n)| view! {
+                            <option value=i.to_string() selected=move || design.with(|d| d.network == n)>{n.label()}</option>
+                        }).collect_view()}
+                    </select>
+                </label>
+                <label>"Poll interval (ms)"
+                    <input type="number" min="10" prop:value=move || design.with(|d| d.poll_ms) on:change=move |ev| if let Some(v) = number(&ev) { design.update(|d| d.poll_ms = v.max(1)) } />
+                </label>
+                <label>"Fleet size"
+                    <input type="number" min="1" prop:value=move || design.with(|d| d.fleet) on:change=move |ev| if let Some(v) = number(&ev) { design.update(|d| d.fleet = v) } />
+                </label>
+                <label>"Updates per year"
+                    <input type="number" min="0" prop:value=move || design.with(|d| d.updates_per_year) on:change=move |ev| if let Some(v) = number(&ev) { design.update(|d| d.updates_per_year = v) } />
+                </label>
+            </div>
+        }.into_view(),
+        Step::Summary => {
+            let d = design.get();
+            let e = d.estimate();
+            let issues = d.issues();
+            let (wit, command, summary) = (d.wit(), d.wasmtime_command(), d.summary());
+            let file = format!("{}.md", d.name);
+            view! {
+                {(!issues.is_empty()).then(|| view! {
+                    <ul class="wizard-issues">{issues.into_iter().map(|i| view! { <li>{format!("⚠️ {}", i)}</li> }).collect_view()}</ul>
+                })}
+                <div class="wizard-estimate">
+                    <div><span class="ota-value">{format!("{} KiB", e.module_kib)}</span><span class="ota-label">"module"</span></div>
+                    <div><span class="ota-value">{format_time(e.ota_secs_per_device)}</span><span class="ota-label">"ota per device"</span></div>
+                    <div><span class="ota-value">{format!("{:.0} bit/s", e.telemetry_bps)}</span><span class="ota-label">{format!("polling, {:.1} MB/month", e.telemetry_mb_per_month)}</span></div>
+                    <div><span class="ota-value">{format!("{} MB", e.memory_mb)}</span><span class="ota-label">"linear memory, all instances"</span></div>
+                    <div><span class="ota-value">{format_currency(e.cost_per_year)}</span><span class="ota-label">"fleet link cost per year"</span></div>
+                </div>
+                <h4>"WIT world" <button class="action-btn" on:click={let wit = wit.clone(); move |_| copy("wit", wit.clone())}>"📋 Copy"</button></h4>
+                <pre class="wit-code">{wit}</pre>
+                <h4>"wasmtime" <button class="action-btn" on:click={let command = command.clone(); move |_| copy("command", command.clone())}>"📋 Copy"</button></h4>
+                <pre class="wit-code">{command}</pre>
+                <button class="action-btn" on:click=move |_| download_text(&file, "text/markdown", &summary)>"⬇️ Download summary"</button>
+                {move || copied.get().map(|what| view! { <span class="audit-status">{format!(" 📋 {} copied", what)}</span> })}
+            }.into_view()
+        }
+    };
+
+    view! {
+        <div class="wizard-section">
+            <h3>"🧭 Build Your Own Guardian"</h3>
+            <ol class="wizard-steps">
+                {Step::ALL.into_iter().map(|s| view! {
+                    <li class:active=move || step.get() == s class:done={move || step.get().index() > s.index()} on:click=move |_| set_step.set(s)>{s.label()}</li>
+                }).collect_view()}
+            </ol>
+            <div class="wizard-page">{page}</div>
+            <div class="wizard-nav">
+                <button class="action-btn" disabled=move || step.get().prev().is_none() on:click=move |_| if let Some(s) = step.get().prev() { set_step.set(s) }>"← Back"</button>
+                <button class="action-btn" disabled=move || step.get().next().is_none() on:click=move |_| if let Some(s) = step.get().next() { set_step.set(s) }>"Next →"</button>
+                <button class="action-btn" on:click=move |_| { design.set(Design::default()); set_step.set(Step::Protocols); }>"↺ Start over"</button>
+            </div>
+        </div>
+    }
+}
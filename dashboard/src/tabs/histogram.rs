@@ -0,0 +1,32 @@
+// what: bar histogram with log-scaled heights
+// why: a long latency tail is invisible on a linear axis next to thousands of fast ticks
+// relations: used by proof/jitter.rs, bins from measure/jitter.rs, styled by .histogram in styles.css
+
+use leptos::*;
+use crate::measure::jitter::Bin;
+
+/// one bar per bin; `tone` is a theme class (success / warning / accent)
+#[component]
+pub fn Histogram(bins: Vec<Bin>, tone: &'static str) -> impl IntoView {
+    let peak = bins.iter().map(|b| b.count).max().unwrap_or(0).max(1) as f64;
+    let max_ms = bins.last().map(|b| b.hi).unwrap_or(0.0);
+    view! {
+        <div class="histogram">
+            <div class=format!("histogram-bars {}", tone)>
+                {bins.into_iter().map(|bin| {
+                    let height = if bin.count == 0 { 0.0 } else { 4.0 + 96.0 * (bin.count as f64).ln_1p() / peak.ln_1p() };
+                    view! {
+                        <div class="histogram-bar"
+                            style:height=format!("{:.0}%", height)
+                            title=format!("{:.3}–{:.3}ms: {} ticks", bin.lo, bin.hi, bin.count)
+                        ></div>
+                    }
+                }).collect_view()}
+            </div>
+            <div class="histogram-axis">
+                <span>"0ms"</span>
+                <span>{format!("{:.3}ms", max_ms)}</span>
+            </div>
+        </div>
+    }
+}
@@ -1,8 +1,10 @@
 // what: tab module exports for all four story tabs
 // why: organizes navigation structure for story-driven demo
-// relations: used by lib.rs, contains problem, hardware, demo, proof
+// relations: used by lib.rs, contains problem, hardware, demo, proof and the shared skeleton placeholder and histogram
 
 pub mod problem;
 pub mod hardware;
 pub mod demo;
 pub mod proof;
+pub mod skeleton;
+pub mod histogram;
@@ -0,0 +1,231 @@
+// what: the sensor-node wit world next to an equivalent dockerfile + compose service, with per-capability annotations
+// why: the mothership table compares isolation in the abstract; this shows the two deployment artifacts line by line
+// relations: used by problem/component.rs before the comparison section; the wit side is cut from wit/attacks.wit
+
+use leptos::*;
+
+/// the real contract, so the wit pane cannot drift from the file
+const ATTACKS_WIT: &str = include_str!("../../../../wit/attacks.wit");
+const WIT_WORLD_START: &str = "world sensor-node {";
+
+// ============================================================================
+// capabilities and grants
+// ============================================================================
+
+/// ambient authority a sensor process could reach for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Capability {
+    Network,
+    Filesystem,
+    Environment,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 3] = [Capability::Network, Capability::Filesystem, Capability::Environment];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Capability::Network => "🌐 Network",
+            Capability::Filesystem => "📁 Filesystem",
+            Capability::Environment => "🔑 Env",
+        }
+    }
+}
+
+/// how much of a capability an artifact hands out (ordered least to most)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Grant {
+    Denied,
+    Scoped,
+    Open,
+}
+
+impl Grant {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Grant::Denied => "denied",
+            Grant::Scoped => "scoped",
+            Grant::Open => "open",
+        }
+    }
+
+    pub fn class(&self) -> &'static str {
+        match self {
+            Grant::Denied => "grant-denied",
+            Grant::Scoped => "grant-scoped",
+            Grant::Open => "grant-open",
+        }
+    }
+}
+
+/// note attached to one artifact line
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Annotation {
+    pub capability: Capability,
+    pub grant: Grant,
+    pub note: &'static str,
+}
+
+/// one displayed line and its annotation, if any
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ArtifactLine {
+    pub code: &'static str,
+    pub annotation: Option<Annotation>,
+}
+
+const fn note(capability: Capability, grant: Grant, note: &'static str) -> Option<Annotation> {
+    Some(Annotation { capability, grant, note })
+}
+
+// ============================================================================
+// the two artifacts
+// ============================================================================
+
+/// python sensor driver packaged the usual way
+pub const DOCKER_LINES: [ArtifactLine; 17] = [
+    ArtifactLine { code: "# Dockerfile", annotation: None },
+    ArtifactLine { code: "FROM python:3.12-slim", annotation: note(Capability::Filesystem, Grant::Open, "A full distro root: shell, package manager, /etc and /proc all readable by the driver.") },
+    ArtifactLine { code: "WORKDIR /app", annotation: None },
+    ArtifactLine { code: "COPY sensor_driver.py .", annotation: None },
+    ArtifactLine { code: "ENV SENSOR_TYPE=bme280", annotation: note(Capability::Environment, Grant::Open, "Baked into the image; every process in the container inherits it.") },
+    ArtifactLine { code: "CMD [\"python\", \"sensor_driver.py\"]", annotation: None },
+    ArtifactLine { code: "", annotation: None },
+    ArtifactLine { code: "# docker-compose.yml", annotation: None },
+    ArtifactLine { code: "services:", annotation: None },
+    ArtifactLine { code: "  sensor:", annotation: note(Capability::Network, Grant::Open, "No network_mode: the default bridge allows outbound connections to anywhere.") },
+    ArtifactLine { code: "    build: .", annotation: None },
+    ArtifactLine { code: "    devices:", annotation: None },
+    ArtifactLine { code: "      - /dev/i2c-1:/dev/i2c-1", annotation: note(Capability::Filesystem, Grant::Scoped, "The bus device is passed through read-write; nothing limits it to the sensor's registers.") },
+    ArtifactLine { code: "    environment:", annotation: None },
+    ArtifactLine { code: "      - INFLUX_TOKEN=${INFLUX_TOKEN}", annotation: note(Capability::Environment, Grant::Open, "The uplink secret sits in os.environ, one env dump away from exfiltration.") },
+    ArtifactLine { code: "    # no read_only: true", annotation: note(Capability::Filesystem, Grant::Open, "Root filesystem stays writable unless someone remembers to opt out.") },
+    ArtifactLine { code: "    # no cap_drop: [ALL]", annotation: note(Capability::Network, Grant::Open, "Default Linux capabilities (NET_RAW among them) stay granted.") },
+];
+
+/// wasmtime invocation that instantiates the world on the device
+const WASMTIME_LINES: [ArtifactLine; 5] = [
+    ArtifactLine { code: "", annotation: None },
+    ArtifactLine { code: "# on the Raspberry Pi", annotation: None },
+    ArtifactLine { code: "$ wasmtime run \\", annotation: note(Capability::Network, Grant::Denied, "No wasi:sockets import and no --tcplisten / -S inherit-network: there is no socket to open.") },
+    ArtifactLine { code: "    --dir=/dev/i2c-1::/dev/i2c-1 \\", annotation: note(Capability::Filesystem, Grant::Scoped, "One preopened path (the host keeps it read-only); every other path does not exist for the module.") },
+    ArtifactLine { code: "    --env=SENSOR_TYPE=bme280 sensor-node.wasm", annotation: note(Capability::Environment, Grant::Scoped, "Only variables passed with --env are visible; the host's environment is not inherited.") },
+];
+
+/// annotations for lines of the sensor-node world, matched on the trimmed line
+const WIT_NOTES: [(&str, Option<Annotation>); 3] = [
+    ("import sensor-capabilities;", note(Capability::Filesystem, Grant::Scoped, "Hardware access is a typed read-hardware-register call, not a file handle.")),
+    ("import attack-surface;", note(Capability::Network, Grant::Denied, "Browser-demo only: on the device the host never provides it, so open-socket / read-file trap.")),
+    ("export process-tick: func() -> telemetry-packet;", note(Capability::Environment, Grant::Denied, "The only entry point; no wasi:cli/environment import means no env access at all.")),
+];
+
+/// the sensor-node world block as written in wit/attacks.wit, comments and blank lines dropped
+pub fn wit_world_lines() -> Vec<ArtifactLine> {
+    let Some(start) = ATTACKS_WIT.find(WIT_WORLD_START) else {
+        return Vec::new();
+    };
+    let mut lines = Vec::new();
+    for line in ATTACKS_WIT[start..].lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("//") {
+            continue;
+        }
+        let annotation = WIT_NOTES.iter().find(|(code, _)| *code == trimmed).and_then(|(_, a)| *a);
+        lines.push(ArtifactLine { code: line, annotation });
+        if trimmed == "}" {
+            break;
+        }
+    }
+    lines
+}
+
+/// the wit pane: the world itself, then how the host instantiates it
+pub fn wit_lines() -> Vec<ArtifactLine> {
+    let mut lines = wit_world_lines();
+    lines.extend(WASMTIME_LINES);
+    lines
+}
+
+/// most permissive grant any line of the artifact makes for `capability`
+pub fn effective_grant(lines: &[ArtifactLine], capability: Capability) -> Grant {
+    lines.iter()
+        .filter_map(|l| l.annotation)
+        .filter(|a| a.capability == capability)
+        .map(|a| a.grant)
+        .max()
+        .unwrap_or(Grant::Denied)
+}
+
+// ============================================================================
+// components
+// ============================================================================
+
+/// one code pane; annotated lines light up when their capability is toggled on
+fn artifact_pane(title: &'static str, tone: &'static str, lines: Vec<ArtifactLine>, shown: ReadSignal<Vec<Capability>>) -> impl IntoView {
+    view! {
+        <div class=format!("artifact-pane {}", tone)>
+            <div class="artifact-title">{title}</div>
+            <pre class="artifact-code">
+                {lines.into_iter().map(|line| match line.annotation {
+                    Some(a) => {
+                        let visible = move || shown.with(|s| s.contains(&a.capability));
+                        view! {
+                            <div class=move || if visible() { format!("artifact-line {}", a.grant.class()) } else { "artifact-line".to_string() }>
+                                <code>{line.code}</code>
+                                <Show when=visible>
+                                    <span class="artifact-note">{format!("{} {}: {}", a.capability.label(), a.grant.label(), a.note)}</span>
+                                </Show>
+                            </div>
+                        }
+                    }
+                    None => view! { <div class="artifact-line"><code>{line.code}</code></div> },
+                }).collect_view()}
+            </pre>
+        </div>
+    }
+}
+
+/// renders the artifact comparison with capability toggles and a default-grant summary
+#[component]
+pub fn ArtifactComparison() -> impl IntoView {
+    let (shown, set_shown) = create_signal(Capability::ALL.to_vec());
+    let toggle = move |capability: Capability| set_shown.update(|s| {
+        match s.iter().position(|c| *c == capability) {
+            Some(i) => { s.remove(i); }
+            None => s.push(capability),
+        }
+    });
+    let wit = wit_lines();
+
+    view! {
+        <div class="artifact-section">
+            <h3>"📄 The Artifacts: Dockerfile vs WIT"</h3>
+            <p class="section-hint">"The same sensor driver, packaged both ways. Toggle a capability to see which lines grant it."</p>
+            <div class="artifact-toggles">
+                {Capability::ALL.into_iter().map(|c| view! {
+                    <button class="artifact-toggle" class:active=move || shown.with(|s| s.contains(&c)) on:click=move |_| toggle(c)>
+                        {c.label()}
+                    </button>
+                }).collect_view()}
+            </div>
+            <table class="artifact-summary">
+                <tr>
+                    <th>"By default"</th>
+                    {Capability::ALL.into_iter().map(|c| view! { <th>{c.label()}</th> }).collect_view()}
+                </tr>
+                {[("🐳 Docker", DOCKER_LINES.to_vec()), ("🦀 WIT + wasmtime", wit.clone())].into_iter().map(|(name, lines)| view! {
+                    <tr>
+                        <td>{name}</td>
+                        {Capability::ALL.into_iter().map(|c| {
+                            let grant = effective_grant(&lines, c);
+                            view! { <td class=grant.class()>{grant.label()}</td> }
+                        }).collect_view()}
+                    </tr>
+                }).collect_view()}
+            </table>
+            <div class="artifact-panes">
+                {artifact_pane("🐳 Dockerfile + docker-compose.yml", "docker", DOCKER_LINES.to_vec(), shown)}
+                {artifact_pane("🦀 wit/attacks.wit + wasmtime", "wasm", wit, shown)}
+            </div>
+        </div>
+    }
+}
@@ -1,12 +1,19 @@
 // what: docker + wasm complementary architecture section
 // why: shows the "mothership pattern" - how docker and wasm work together
-// relations: used by problem/component.rs as final section before CTA
+// relations: used by problem/component.rs as final section before CTA; restart claim sourced from the proof tab's container baseline (crate::handoff)
 
 use leptos::*;
+use crate::handoff::{claim_check, container_baseline, load_sessions};
 
 /// renders the complementary architecture section and CTA button
 #[component]
 pub fn ComparisonSection() -> impl IntoView {
+    // the ~1-5s claim cites an imported docker restart when there is one
+    let restart_note = match container_baseline(&load_sessions()).and_then(|b| Some((b.container_restart?, b.device.clone()))) {
+        Some((restart, device)) => format!("imported: {:.1}s on {}, {}", restart.median / 1000.0, device, claim_check(restart.median)),
+        None => "no in-app figure yet, import one on the Proof tab".to_string(),
+    };
+
     view! {
         <div class="comparison-section">
             <h3>"🚀 The Mothership Pattern: Docker + WASM"</h3>
@@ -36,6 +43,7 @@ pub fn ComparisonSection() -> impl IntoView {
                     layer="Fault Recovery"
                     docker="Container restart (~1-5s)"
                     wasm="Module TRAP + rebuild (~0.04ms)"
+                    note=restart_note
                 />
                 <MothershipRow 
                     layer="Security"
@@ -84,11 +92,17 @@ fn MothershipRow(
     layer: &'static str,
     docker: &'static str,
     wasm: &'static str,
+    /// source line under the docker cell
+    #[prop(optional)]
+    note: Option<String>,
 ) -> impl IntoView {
     view! {
         <div class="mothership-row">
             <span class="mothership-cell layer">{layer}</span>
-            <span class="mothership-cell docker">{docker}</span>
+            <span class="mothership-cell docker">
+                {docker}
+                {note.map(|n| view! { <span class="baseline-note">{n}</span> })}
+            </span>
             <span class="mothership-cell wasm">{wasm}</span>
         </div>
     }
@@ -1,11 +1,12 @@
 // what: problem tab main component orchestrating sub-sections
 // why: sets up the narrative explaining why WASM/WASI matters for ICS security
-// relations: uses quotes, vulnerabilities, and comparison sub-components
+// relations: uses quotes, vulnerabilities, artifacts and comparison sub-components
 
 use leptos::*;
 use super::quotes::QuotesSection;
 use super::vulnerabilities::VulnerabilitiesSection;
 use super::comparison::ComparisonSection;
+use super::artifacts::ArtifactComparison;
 
 /// main problem tab component with vertical story flow
 #[component]
@@ -24,7 +25,10 @@ pub fn Problem() -> impl IntoView {
             // section 2: real vulnerabilities and ICS attack data
             <VulnerabilitiesSection />
             
-            // section 3: comparison table and CTA
+            // section 3: the deployment artifacts side by side
+            <ArtifactComparison />
+            
+            // section 4: comparison table and CTA
             <ComparisonSection />
         </div>
     }
@@ -0,0 +1,219 @@
+// what: cvss v3.1 base vector parsing and scoring, plus the capability model's adjustments to a vector
+// why: the cve cards quote scores; the calculator recomputes them and shows which components the wasm model takes away
+// relations: adjustments from content/narrative.toml (Cve::adjust), rendered by cvss_panel.rs, tested in problem/tests/cvss.rs
+
+use crate::content::CvssAdjustment;
+
+/// prefix of every v3.1 vector string
+pub const PREFIX: &str = "CVSS:3.1/";
+
+/// the eight base metrics, in vector order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    AttackVector,
+    AttackComplexity,
+    PrivilegesRequired,
+    UserInteraction,
+    Scope,
+    Confidentiality,
+    Integrity,
+    Availability,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 8] = [
+        Metric::AttackVector,
+        Metric::AttackComplexity,
+        Metric::PrivilegesRequired,
+        Metric::UserInteraction,
+        Metric::Scope,
+        Metric::Confidentiality,
+        Metric::Integrity,
+        Metric::Availability,
+    ];
+
+    /// abbreviation used in the vector string
+    pub fn key(&self) -> &'static str {
+        match self {
+            Metric::AttackVector => "AV",
+            Metric::AttackComplexity => "AC",
+            Metric::PrivilegesRequired => "PR",
+            Metric::UserInteraction => "UI",
+            Metric::Scope => "S",
+            Metric::Confidentiality => "C",
+            Metric::Integrity => "I",
+            Metric::Availability => "A",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Metric::AttackVector => "Attack Vector",
+            Metric::AttackComplexity => "Attack Complexity",
+            Metric::PrivilegesRequired => "Privileges Required",
+            Metric::UserInteraction => "User Interaction",
+            Metric::Scope => "Scope",
+            Metric::Confidentiality => "Confidentiality",
+            Metric::Integrity => "Integrity",
+            Metric::Availability => "Availability",
+        }
+    }
+
+    /// (code, label) of every value the metric takes
+    pub fn options(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Metric::AttackVector => &[("N", "Network"), ("A", "Adjacent"), ("L", "Local"), ("P", "Physical")],
+            Metric::AttackComplexity => &[("L", "Low"), ("H", "High")],
+            Metric::PrivilegesRequired => &[("N", "None"), ("L", "Low"), ("H", "High")],
+            Metric::UserInteraction => &[("N", "None"), ("R", "Required")],
+            Metric::Scope => &[("U", "Unchanged"), ("C", "Changed")],
+            Metric::Confidentiality | Metric::Integrity | Metric::Availability => &[("N", "None"), ("L", "Low"), ("H", "High")],
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Metric> {
+        Metric::ALL.into_iter().find(|m| m.key() == key)
+    }
+}
+
+/// a complete base vector: one value code per metric, in Metric::ALL order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vector([&'static str; 8]);
+
+impl Vector {
+    /// parses "CVSS:3.1/AV:N/..."; every base metric must appear exactly once
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let body = text.strip_prefix(PREFIX).ok_or_else(|| format!("vector must start with {}", PREFIX))?;
+        let mut values: [Option<&'static str>; 8] = [None; 8];
+        for part in body.split('/') {
+            let (key, value) = part.split_once(':').ok_or_else(|| format!("malformed component \"{}\"", part))?;
+            let metric = Metric::from_key(key).ok_or_else(|| format!("unknown metric \"{}\"", key))?;
+            let slot = &mut values[metric as usize];
+            if slot.is_some() {
+                return Err(format!("{} given twice", key));
+            }
+            *slot = Some(code(metric, value)?);
+        }
+        let mut out = [""; 8];
+        for metric in Metric::ALL {
+            out[metric as usize] = values[metric as usize].ok_or_else(|| format!("missing {}", metric.key()))?;
+        }
+        Ok(Vector(out))
+    }
+
+    pub fn get(&self, metric: Metric) -> &'static str {
+        self.0[metric as usize]
+    }
+
+    /// sets a metric to one of its value codes
+    pub fn set(&mut self, metric: Metric, value: &str) -> Result<(), String> {
+        self.0[metric as usize] = code(metric, value)?;
+        Ok(())
+    }
+
+    /// the vector with the capability model's changes applied
+    pub fn adjusted(&self, adjustments: &[CvssAdjustment]) -> Result<Vector, String> {
+        let mut out = self.clone();
+        for adj in adjustments {
+            let metric = Metric::from_key(&adj.metric).ok_or_else(|| format!("unknown metric \"{}\"", adj.metric))?;
+            out.set(metric, &adj.value)?;
+        }
+        Ok(out)
+    }
+
+    fn scope_changed(&self) -> bool {
+        self.get(Metric::Scope) == "C"
+    }
+
+    /// impact subscore (can be negative, which scores 0)
+    pub fn impact(&self) -> f64 {
+        let iss = 1.0
+            - (1.0 - cia(self.get(Metric::Confidentiality)))
+                * (1.0 - cia(self.get(Metric::Integrity)))
+                * (1.0 - cia(self.get(Metric::Availability)));
+        if self.scope_changed() {
+            7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powi(15)
+        } else {
+            6.42 * iss
+        }
+    }
+
+    pub fn exploitability(&self) -> f64 {
+        let av = match self.get(Metric::AttackVector) {
+            "N" => 0.85,
+            "A" => 0.62,
+            "L" => 0.55,
+            _ => 0.2,
+        };
+        let ac = if self.get(Metric::AttackComplexity) == "L" { 0.77 } else { 0.44 };
+        let pr = match (self.get(Metric::PrivilegesRequired), self.scope_changed()) {
+            ("N", _) => 0.85,
+            ("L", false) => 0.62,
+            ("L", true) => 0.68,
+            (_, false) => 0.27,
+            (_, true) => 0.5,
+        };
+        let ui = if self.get(Metric::UserInteraction) == "N" { 0.85 } else { 0.62 };
+        8.22 * av * ac * pr * ui
+    }
+
+    /// base score per the v3.1 specification, section 7.1
+    pub fn base_score(&self) -> f64 {
+        let impact = self.impact();
+        if impact <= 0.0 {
+            return 0.0;
+        }
+        let sum = impact + self.exploitability();
+        if self.scope_changed() {
+            round_up((1.08 * sum).min(10.0))
+        } else {
+            round_up(sum.min(10.0))
+        }
+    }
+}
+
+impl std::fmt::Display for Vector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = Metric::ALL.iter().map(|m| format!("{}:{}", m.key(), self.get(*m))).collect();
+        write!(f, "{}{}", PREFIX, parts.join("/"))
+    }
+}
+
+/// the static code for a metric value, or an error naming the metric
+fn code(metric: Metric, value: &str) -> Result<&'static str, String> {
+    metric
+        .options()
+        .iter()
+        .map(|(c, _)| *c)
+        .find(|c| *c == value)
+        .ok_or_else(|| format!("{} cannot be \"{}\"", metric.key(), value))
+}
+
+fn cia(value: &str) -> f64 {
+    match value {
+        "H" => 0.56,
+        "L" => 0.22,
+        _ => 0.0,
+    }
+}
+
+/// the spec's roundup: smallest one-decimal number >= x, computed on integers to dodge float error (appendix a)
+pub fn round_up(x: f64) -> f64 {
+    let int_input = (x * 100_000.0).round() as i64;
+    if int_input % 10_000 == 0 {
+        int_input as f64 / 100_000.0
+    } else {
+        (int_input / 10_000 + 1) as f64 / 10.0
+    }
+}
+
+/// qualitative severity rating for a base score
+pub fn severity(score: f64) -> &'static str {
+    match score {
+        s if s <= 0.0 => "none",
+        s if s < 4.0 => "low",
+        s if s < 7.0 => "medium",
+        s if s < 9.0 => "high",
+        _ => "critical",
+    }
+}
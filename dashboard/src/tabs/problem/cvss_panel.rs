@@ -0,0 +1,78 @@
+// what: cvss v3.1 calculator opened from a cve card - editable base vector, its score, and the score with the capability model applied
+// why: "wasm removes attack surface" becomes specific vector components the viewer can inspect and argue with
+// relations: scoring in cvss.rs, cve and adjustments from content/narrative.toml, opened by vulnerabilities.rs
+
+use leptos::*;
+use crate::content::Cve;
+use super::cvss::{severity, Metric, Vector};
+
+/// score with its severity badge
+fn score_badge(label: &'static str, score: f64) -> impl IntoView {
+    view! {
+        <div class="cvss-score">
+            <span class="stat-label">{label}</span>
+            <span class=format!("severity-badge {}", severity(score))>{format!("{:.1} {}", score, severity(score))}</span>
+        </div>
+    }
+}
+
+/// calculator for one cve, pre-filled from its published vector
+#[component]
+pub fn CvssPanel(cve: &'static Cve, on_close: Callback<()>) -> impl IntoView {
+    let published = Vector::parse(&cve.vector);
+    let vector = create_rw_signal(published.clone().unwrap_or_else(|_| Vector::parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N").expect("valid fallback")));
+    let adjusted = move || vector.with(|v| v.adjusted(&cve.adjust));
+
+    let metric_row = move |metric: Metric| view! {
+        <tr>
+            <td>{metric.label()}</td>
+            <td>
+                <select
+                    class="network-select"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        vector.update(|v| { let _ = v.set(metric, &value); });
+                    }
+                >
+                    {metric.options().iter().map(|(code, label)| view! {
+                        <option value=*code selected=move || vector.with(|v| v.get(metric) == *code)>{*label}</option>
+                    }).collect_view()}
+                </select>
+            </td>
+            <td class:granted=move || adjusted().is_ok_and(|a| a.get(metric) != vector.with(|v| v.get(metric)))>
+                {move || adjusted().map(|a| a.get(metric)).unwrap_or("?")}
+            </td>
+        </tr>
+    };
+
+    view! {
+        <div class="cvss-panel">
+            <div class="cve-header">
+                <h4>{format!("🧮 CVSS v3.1 — {}", cve.id)}</h4>
+                <button class="action-btn" on:click=move |_| on_close.call(())>"✕"</button>
+            </div>
+            {published.err().map(|e| view! { <p class="audit-status">{format!("Published vector not parsed ({}); starting from a blank one", e)}</p> })}
+            <p class="section-hint">{format!("Published: {} ({})", cve.vector, cve.score)}</p>
+
+            <table class="capability-matrix cvss-table">
+                <tr><th>"Metric"</th><th>"As published (editable)"</th><th>"Under capability model"</th></tr>
+                {Metric::ALL.map(metric_row).collect_view()}
+            </table>
+
+            <div class="cvss-scores">
+                {move || score_badge("Base score", vector.with(|v| v.base_score()))}
+                {move || match adjusted() {
+                    Ok(a) => score_badge("Adjusted score", a.base_score()).into_view(),
+                    Err(e) => view! { <p class="audit-status">{e}</p> }.into_view(),
+                }}
+            </div>
+            <p class="terminal-line info">{move || adjusted().map(|a| a.to_string()).unwrap_or_default()}</p>
+
+            <ul class="cvss-reasons">
+                {cve.adjust.iter().map(|adj| view! {
+                    <li><code>{format!("{}:{}", adj.metric, adj.value)}</code>" — "{adj.why.as_str()}</li>
+                }).collect_view()}
+            </ul>
+        </div>
+    }
+}
@@ -5,6 +5,12 @@
 mod component;
 mod quotes;
 mod vulnerabilities;
+mod cvss;
+mod cvss_panel;
 mod comparison;
+mod artifacts;
 
 pub use component::Problem;
+
+#[cfg(test)]
+mod tests;
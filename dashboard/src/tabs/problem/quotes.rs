@@ -1,23 +1,9 @@
 // what: quote cards displaying influential tweets about WASM/WASI
 // why: establishes credibility with quotes from Docker founder and Mozilla engineer
-// relations: used by problem/component.rs as a sub-section
+// relations: used by problem/component.rs as a sub-section, quote text from content/narrative.toml
 
 use leptos::*;
-
-/// Solomon Hykes tweet info
-const SOLOMON_NAME: &str = "Solomon Hykes";
-const SOLOMON_ROLE: &str = "Docker Co-Founder";
-const SOLOMON_DATE: &str = "March 27, 2019";
-const SOLOMON_TWEET: &str = "So will wasm replace Docker? No, but imagine a future where Docker runs linux containers, windows containers and wasm containers side by side. Over time wasm might become the most popular container type. Docker will love them all equally, and run it all :)";
-const SOLOMON_URL: &str = "https://x.com/solomonstre/status/1111113329647325185";
-
-/// Lin Clark tweet info
-const LIN_NAME: &str = "Lin Clark";
-const LIN_ROLE: &str = "Mozilla Principal Engineer";
-const LIN_DATE: &str = "March 27, 2019";
-const LIN_TWEET: &str = "WebAssembly running outside the web has a huge future. And that future gets one giant leap closer today with... Announcing WASI: A system interface for running WebAssembly outside the web.";
-const LIN_URL: &str = "https://x.com/linclark/status/1110920999061594113";
-const LIN_ARTICLE: &str = "https://hacks.mozilla.org/2019/03/standardizing-wasi-a-webassembly-system-interface/";
+use crate::content::{content, Quote};
 
 /// renders the quote cards section with both tweets
 #[component]
@@ -28,24 +14,7 @@ pub fn QuotesSection() -> impl IntoView {
             <p class="section-hint">"Docker's founder on the coexistence of containers and WASM (2019)"</p>
             
             <div class="quote-cards">
-                <QuoteCard 
-                    name=SOLOMON_NAME
-                    role=SOLOMON_ROLE
-                    date=SOLOMON_DATE
-                    quote=SOLOMON_TWEET
-                    tweet_url=SOLOMON_URL
-                    avatar="🐳"
-                />
-                
-                <QuoteCard 
-                    name=LIN_NAME
-                    role=LIN_ROLE
-                    date=LIN_DATE
-                    quote=LIN_TWEET
-                    tweet_url=LIN_URL
-                    avatar="🦊"
-                    article_url=LIN_ARTICLE
-                />
+                {content().quotes.iter().map(|quote| view! { <QuoteCard quote=quote /> }).collect_view()}
             </div>
         </div>
     }
@@ -53,34 +22,26 @@ pub fn QuotesSection() -> impl IntoView {
 
 /// individual quote card styled like a tweet
 #[component]
-fn QuoteCard(
-    name: &'static str,
-    role: &'static str,
-    date: &'static str,
-    quote: &'static str,
-    tweet_url: &'static str,
-    avatar: &'static str,
-    #[prop(optional)] article_url: Option<&'static str>,
-) -> impl IntoView {
+fn QuoteCard(quote: &'static Quote) -> impl IntoView {
     view! {
         <div class="quote-card">
             <div class="quote-header">
-                <span class="quote-avatar">{avatar}</span>
+                <span class="quote-avatar">{quote.avatar.as_str()}</span>
                 <div class="quote-author">
-                    <span class="quote-name">{name}</span>
-                    <span class="quote-role">{role}</span>
+                    <span class="quote-name">{quote.name.as_str()}</span>
+                    <span class="quote-role">{quote.role.as_str()}</span>
                 </div>
             </div>
             
-            <p class="quote-text">"\""{ quote }"\""</p>
+            <p class="quote-text">"\""{quote.text.as_str()}"\""</p>
             
             <div class="quote-footer">
-                <span class="quote-date">{date}</span>
+                <span class="quote-date">{quote.date.as_str()}</span>
                 <div class="quote-links">
-                    <a href={tweet_url} target="_blank" rel="noopener" class="quote-link">
+                    <a href={quote.url.as_str()} target="_blank" rel="noopener" class="quote-link">
                         "View on X →"
                     </a>
-                    {article_url.map(|url| view! {
+                    {quote.article_url.as_deref().map(|url| view! {
                         <a href={url} target="_blank" rel="noopener" class="quote-link article">
                             "Read Article →"
                         </a>
@@ -0,0 +1,35 @@
+// what: tests for the dockerfile vs wit artifact comparison
+// why: the wit pane is cut from the real contract, and the summary row must follow the line annotations
+
+use crate::tabs::problem::artifacts::{effective_grant, wit_lines, wit_world_lines, Capability, Grant, DOCKER_LINES};
+
+#[test]
+fn wit_pane_is_the_real_world_block() {
+    // what: the sensor-node world is found in wit/attacks.wit, starts and ends on its braces, and every import is annotated
+    // why: an edit to the contract must not silently leave the pane empty or unannotated
+    let lines = wit_world_lines();
+    assert_eq!(lines.first().map(|l| l.code.trim()), Some("world sensor-node {"));
+    assert_eq!(lines.last().map(|l| l.code.trim()), Some("}"));
+    assert!(lines.iter().filter(|l| l.code.trim().starts_with("import ")).all(|l| l.annotation.is_some()));
+}
+
+#[test]
+fn docker_defaults_open_everything() {
+    // what: the compose service grants network, filesystem and environment openly
+    // why: that is the point of the comparison - ambient authority unless you opt out
+    for capability in Capability::ALL {
+        assert_eq!(effective_grant(&DOCKER_LINES, capability), Grant::Open);
+    }
+}
+
+#[test]
+fn wit_grants_nothing_beyond_scoped() {
+    // what: the wit pane denies network and only scopes filesystem and env
+    // why: the summary row is derived from the annotations, so they must say this
+    let wit = wit_lines();
+    assert_eq!(effective_grant(&wit, Capability::Network), Grant::Denied);
+    assert_eq!(effective_grant(&wit, Capability::Filesystem), Grant::Scoped);
+    assert_eq!(effective_grant(&wit, Capability::Environment), Grant::Scoped);
+    // unannotated capabilities read as denied
+    assert_eq!(effective_grant(&[], Capability::Network), Grant::Denied);
+}
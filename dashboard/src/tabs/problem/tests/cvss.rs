@@ -0,0 +1,68 @@
+// what: tests for the cvss v3.1 calculator behind the cve cards
+// why: the card scores are quoted from nvd; the recomputed and adjusted scores must follow the spec, not approximate it
+
+use crate::content::content;
+use crate::tabs::problem::cvss::{round_up, severity, Metric, Vector};
+
+fn score(vector: &str) -> f64 {
+    Vector::parse(vector).unwrap().base_score()
+}
+
+#[test]
+fn base_scores_match_the_specification() {
+    // what: well-known vectors score as the first.org calculator does, both scopes and the zero-impact case
+    // why: scope changes both the impact formula and the privileges weight; either slip shows up here
+    assert_eq!(score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"), 9.8);
+    assert_eq!(score("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:H/I:H/A:H"), 9.9);
+    assert_eq!(score("CVSS:3.1/AV:P/AC:H/PR:H/UI:R/S:U/C:L/I:N/A:N"), 1.6);
+    assert_eq!(score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:N/I:N/A:N"), 0.0);
+    assert_eq!(round_up(4.02), 4.1);
+    assert_eq!(round_up(4.0), 4.0);
+    assert_eq!(round_up(4.000_000_1), 4.0, "float noise below the spec's precision is not rounded up");
+    assert_eq!([0.0, 3.9, 4.0, 8.9, 9.0].map(severity), ["none", "low", "medium", "high", "critical"]);
+}
+
+#[test]
+fn parse_round_trips_and_rejects_bad_vectors() {
+    // what: a vector prints back in canonical order; wrong prefix, unknown or repeated metric, bad value and missing metric are errors
+    // why: the calculator pre-fills from narrative.toml, so a typo there must surface, not score as something else
+    let text = "CVSS:3.1/AV:L/AC:L/PR:N/UI:R/S:C/C:H/I:H/A:H";
+    assert_eq!(Vector::parse(text).unwrap().to_string(), text);
+    assert!(Vector::parse("CVSS:3.0/AV:L/AC:L/PR:N/UI:R/S:C/C:H/I:H/A:H").is_err());
+    assert!(Vector::parse("CVSS:3.1/AV:L/AC:L/PR:N/UI:R/S:C/C:H/I:H/A:H/XX:Y").unwrap_err().contains("XX"));
+    assert!(Vector::parse("CVSS:3.1/AV:L/AV:N/AC:L/PR:N/UI:R/S:C/C:H/I:H/A:H").unwrap_err().contains("twice"));
+    assert!(Vector::parse("CVSS:3.1/AV:Q/AC:L/PR:N/UI:R/S:C/C:H/I:H/A:H").unwrap_err().contains("AV"));
+    assert_eq!(Vector::parse("CVSS:3.1/AV:L/AC:L/PR:N/UI:R/S:C/C:H/I:H").unwrap_err(), "missing A");
+    let mut v = Vector::parse(text).unwrap();
+    assert!(v.set(Metric::Scope, "X").is_err());
+    assert_eq!(v.get(Metric::Scope), "C", "a rejected value leaves the metric alone");
+}
+
+#[test]
+fn card_vectors_reproduce_the_published_scores() {
+    // what: every cve card's vector parses and scores exactly what the card's badge says
+    // why: the calculator opens on the vector; a mismatch would contradict the card it was opened from
+    for cve in &content().cves {
+        let vector = Vector::parse(&cve.vector).unwrap_or_else(|e| panic!("{}: {}", cve.id, e));
+        assert_eq!(format!("{:.1}", vector.base_score()), cve.score, "{}", cve.id);
+    }
+}
+
+#[test]
+fn capability_model_lowers_every_card() {
+    // what: each card's adjustments are valid, change at least one metric, explain themselves, and lower the score
+    // why: the adjusted score is the claim; an adjustment that fails to apply or raises the score would undercut it
+    let adjusted: Vec<f64> = content()
+        .cves
+        .iter()
+        .map(|cve| {
+            let vector = Vector::parse(&cve.vector).unwrap();
+            let after = vector.adjusted(&cve.adjust).unwrap_or_else(|e| panic!("{}: {}", cve.id, e));
+            assert!(Metric::ALL.iter().any(|m| vector.get(*m) != after.get(*m)), "{} adjusts nothing", cve.id);
+            assert!(cve.adjust.iter().all(|a| !a.why.is_empty()));
+            assert!(after.base_score() < vector.base_score(), "{}", cve.id);
+            after.base_score()
+        })
+        .collect();
+    assert_eq!(adjusted, [4.4, 4.3, 0.0]);
+}
@@ -0,0 +1,8 @@
+// what: exports all test modules for the problem tab
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod artifacts;
+
+#[cfg(test)]
+mod cvss;
@@ -0,0 +1,174 @@
+// what: the proof tab's measurement suite (wasm instantiate, exported call, pyodide cold start) and its results, shared app-wide
+// why: the suite can start on page load (autobench/) and fill the proof tab before it is opened, so results outlive the tab's view
+// relations: results provided by lib.rs, shown by component.rs, started by its Run Simulation button or autobench/mod.rs; stats from measure/stats.rs
+
+use leptos::*;
+use wasm_bindgen::JsCast;
+use crate::measure::stats::{summarize, Summary};
+use crate::measure::timing::sample;
+use crate::measure::worker;
+use crate::tabs::demo::wasm::{now, MINIMAL_WASM as CALLABLE_WASM};
+
+// Minimal WASM module for instantiation timing
+const MINIMAL_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, // magic
+    0x01, 0x00, 0x00, 0x00, // version
+];
+
+/// destroys the page's pyodide (when `replace`) and times a fresh load; a background run keeps its own throwaway instance
+const COLD_START_JS: &str = "return (async () => {
+    if (replace) {
+        window.pyodide = null;
+    }
+    // Load fresh Pyodide (script is injected lazily on first use)
+    await window.ensurePyodideScript();
+    const fresh = await loadPyodide();
+    if (replace) {
+        window.pyodide = fresh;
+        window.runPython = (code) => window.pyodide.runPython(code);
+    }
+    return true;
+})();";
+
+/// one measurement of the suite, in run order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BenchStep {
+    WasmInstantiate,
+    ExportedCall,
+    PythonColdStart,
+}
+
+impl BenchStep {
+    pub const ALL: [BenchStep; 3] = [BenchStep::WasmInstantiate, BenchStep::ExportedCall, BenchStep::PythonColdStart];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BenchStep::WasmInstantiate => "WASM instantiate",
+            BenchStep::ExportedCall => "exported call (add)",
+            BenchStep::PythonColdStart => "Pyodide cold start",
+        }
+    }
+}
+
+/// everything the proof table shows, plus the run in progress
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProofResults {
+    pub wasm_instantiate_ms: Summary,
+    pub wasm_call_ms: Option<Summary>,
+    /// one cold start per run, summarized across runs
+    pub python_coldstart_samples: Vec<f64>,
+    /// completed runs
+    pub runs: u32,
+    /// step being measured, None while idle
+    pub running: Option<BenchStep>,
+    /// the current or last run was started on page load, not by the button
+    pub auto: bool,
+}
+
+impl ProofResults {
+    pub fn ran(&self) -> bool {
+        self.runs > 0
+    }
+
+    pub fn python_coldstart_ms(&self) -> Summary {
+        summarize(&self.python_coldstart_samples, 0)
+    }
+
+    /// claims the suite for a run; false while another run is going
+    pub fn begin(&mut self, auto: bool) -> bool {
+        if self.running.is_some() {
+            return false;
+        }
+        self.running = Some(BenchStep::ALL[0]);
+        self.auto = auto;
+        true
+    }
+
+    pub fn finish(&mut self) {
+        self.running = None;
+        self.runs += 1;
+    }
+
+    /// (1-based step, steps, label) of the run in progress
+    pub fn progress(&self) -> Option<(usize, usize, &'static str)> {
+        let step = self.running?;
+        let index = BenchStep::ALL.iter().position(|s| *s == step).unwrap_or(0);
+        Some((index + 1, BenchStep::ALL.len(), step.label()))
+    }
+}
+
+/// shared proof results (a fresh signal outside the app, e.g. in tests)
+pub fn use_proof_results() -> RwSignal<ProofResults> {
+    use_context::<RwSignal<ProofResults>>().unwrap_or_else(|| create_rw_signal(ProofResults::default()))
+}
+
+async fn measure_wasm_instantiate(iterations: u32) -> Summary {
+    if let Ok((summary, _)) = worker::instantiate(MINIMAL_WASM, iterations).await {
+        return summary;
+    }
+
+    let array = js_sys::Uint8Array::from(MINIMAL_WASM);
+    let compile_promise = js_sys::WebAssembly::compile(&array.buffer());
+    let module: js_sys::WebAssembly::Module = wasm_bindgen_futures::JsFuture::from(compile_promise)
+        .await
+        .unwrap()
+        .unchecked_into();
+
+    let (summary, _) = sample(iterations, || {
+        let import_object = js_sys::Object::new();
+        let instantiate_promise = js_sys::WebAssembly::instantiate_module(&module, &import_object);
+        async move {
+            let _instance: js_sys::WebAssembly::Instance = wasm_bindgen_futures::JsFuture::from(instantiate_promise)
+                .await
+                .unwrap()
+                .unchecked_into();
+        }
+    }).await;
+    summary
+}
+
+/// one real pyodide cold start in ms; `replace` swaps it in for the page's runtime (the button's crash-recovery semantics)
+async fn measure_python_coldstart(replace: bool) -> f64 {
+    let window = web_sys::window().unwrap();
+    if replace {
+        let _ = js_sys::Reflect::set(&window, &"pyodideReloading".into(), &true.into());
+    }
+    let start = now();
+    let promise = js_sys::Function::new_with_args("replace", COLD_START_JS).call1(&window, &replace.into());
+    if let Ok(js_promise) = promise.and_then(|p| p.dyn_into::<js_sys::Promise>()) {
+        let _ = wasm_bindgen_futures::JsFuture::from(js_promise).await;
+    }
+    let py_time = now() - start;
+    if replace {
+        // Update window.pyodideLoadTime with new measurement
+        let _ = js_sys::Reflect::set(&window, &"pyodideLoadTime".into(), &py_time.into());
+        let _ = js_sys::Reflect::set(&window, &"pyodideReloading".into(), &false.into());
+    }
+    py_time
+}
+
+/// runs the whole suite into `results`; a run already in progress wins and this one is dropped
+pub async fn run(results: RwSignal<ProofResults>, iterations: u32, auto: bool) {
+    if !results.try_update(|r| r.begin(auto)).unwrap_or(false) {
+        return;
+    }
+    tracing::info!(target: "measure", iterations, auto, "proof suite started");
+    // Measure WASM instantiation (fresh each time)
+    let wasm_time = measure_wasm_instantiate(iterations).await;
+    results.update(|r| {
+        r.wasm_instantiate_ms = wasm_time;
+        r.running = Some(BenchStep::ExportedCall);
+    });
+    let call = worker::call_throughput(CALLABLE_WASM, "add", iterations).await.ok();
+    results.update(|r| {
+        r.wasm_call_ms = call;
+        r.running = Some(BenchStep::PythonColdStart);
+    });
+    // a background run must not pull the runtime out from under the demo tab
+    let py_time = measure_python_coldstart(!auto).await;
+    results.update(|r| {
+        r.python_coldstart_samples.push(py_time);
+        r.finish();
+    });
+    tracing::info!(target: "measure", python_ms = py_time, "proof suite finished");
+}
@@ -0,0 +1,131 @@
+// what: debug vs release vs wasm-opt -Oz builds of sensor-driver, fetched and measured live (size, sections, instantiate time)
+// why: the ota savings argument depends on build pipeline choices; this ties each number to the step that produced it
+// relations: artifacts built by dashboard/build-variants.sh (trunk pre_build hook), parsed by measure/binary.rs,
+//            timed by measure/worker.rs, ota times from ota_simulator.rs; used by proof/component.rs
+
+use leptos::*;
+use crate::diagnostics::about::format_bytes;
+use crate::measure::binary::parse_sections;
+use crate::measure::stats::Summary;
+use crate::measure::worker;
+use crate::settings::use_settings;
+use crate::tabs::demo::wasm::fetch_bytes;
+use super::ota_simulator::{calc_download_time_secs, format_time, SATELLITE_SPEED_MBPS};
+
+/// one shipped artifact of the same crate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuildVariant {
+    pub label: &'static str,
+    /// file under dist/variants/
+    pub file: &'static str,
+    /// pipeline step that produced it
+    pub pipeline: &'static str,
+}
+
+/// in pipeline order; the first is the baseline the others are compared with
+pub const VARIANTS: [BuildVariant; 3] = [
+    BuildVariant { label: "Debug", file: "sensor_driver.debug.wasm", pipeline: "cargo build" },
+    BuildVariant { label: "Release", file: "sensor_driver.release.wasm", pipeline: "cargo build --release" },
+    BuildVariant { label: "Release + wasm-opt", file: "sensor_driver.oz.wasm", pipeline: "wasm-opt -Oz" },
+];
+
+/// what was measured for one variant
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariantMeasure {
+    pub bytes: usize,
+    pub code_bytes: usize,
+    pub custom_bytes: usize,
+    /// None when the worker could not time it
+    pub instantiate: Option<Summary>,
+}
+
+/// "12.3x smaller" against the baseline, or "baseline"
+pub fn shrink_label(baseline: Option<usize>, bytes: usize) -> String {
+    match baseline {
+        Some(base) if base == bytes => "baseline".to_string(),
+        Some(base) if bytes > 0 => format!("{:.1}x smaller", base as f64 / bytes as f64),
+        _ => "—".to_string(),
+    }
+}
+
+async fn measure(variant: BuildVariant, iterations: u32) -> Result<VariantMeasure, String> {
+    let bytes = fetch_bytes(&format!("variants/{}", variant.file))
+        .await
+        .map_err(|e| format!("not shipped in this build ({})", e))?;
+    let layout = parse_sections(&bytes)?;
+    let code_bytes = layout.sections.iter().filter(|s| s.name == "code").map(|s| s.bytes).sum();
+    let instantiate = worker::instantiate(&bytes, iterations).await.ok().map(|(summary, _)| summary);
+    Ok(VariantMeasure { bytes: bytes.len(), code_bytes, custom_bytes: layout.custom_bytes(), instantiate })
+}
+
+#[component]
+pub fn BuildVariants() -> impl IntoView {
+    let settings = use_settings();
+    let (running, set_running) = create_signal(false);
+    let results = create_rw_signal(Vec::<(BuildVariant, Result<VariantMeasure, String>)>::new());
+
+    let run = move |_| {
+        if running.get_untracked() { return; }
+        set_running.set(true);
+        results.set(Vec::new());
+        let iterations = settings.with_untracked(|s| s.benchmark_iterations);
+        wasm_bindgen_futures::spawn_local(async move {
+            for variant in VARIANTS {
+                let outcome = measure(variant, iterations).await;
+                results.update(|r| r.push((variant, outcome)));
+            }
+            set_running.set(false);
+        });
+    };
+
+    view! {
+        <div class="build-variants">
+            <h3>"🏗️ Build Pipeline: Debug vs Release vs wasm-opt"</h3>
+            <p class="section-desc">"The same sensor-driver crate, shipped three ways by this deployment's build. Sizes and instantiate times are measured on the fetched files."</p>
+            <div class="simulation-control">
+                <button class="action-btn" disabled=move || running.get() on:click=run>
+                    {move || if running.get() { "⏳ Measuring..." } else { "📦 Measure Build Variants" }}
+                </button>
+            </div>
+            <Show when=move || results.with(|r| !r.is_empty())>
+                <table class="capability-matrix size-table">
+                    <tr>
+                        <th>"Variant"</th>
+                        <th>"Pipeline step"</th>
+                        <th>"Size"</th>
+                        <th>"vs debug"</th>
+                        <th>"Code / custom"</th>
+                        <th>"Instantiate"</th>
+                        <th>"OTA @ satellite"</th>
+                    </tr>
+                    {move || results.with(|r| {
+                        let baseline = r.first().and_then(|(_, m)| m.as_ref().ok()).map(|m| m.bytes);
+                        r.iter().map(|(variant, outcome)| match outcome {
+                            Ok(m) => view! {
+                                <tr>
+                                    <td class="world-name">{variant.label}</td>
+                                    <td><code>{variant.pipeline}</code></td>
+                                    <td>{format_bytes(m.bytes as f64)}</td>
+                                    <td class="success">{shrink_label(baseline, m.bytes)}</td>
+                                    <td>{format!("{} / {}", format_bytes(m.code_bytes as f64), format_bytes(m.custom_bytes as f64))}</td>
+                                    <td title=m.instantiate.map(|s| s.detail()).unwrap_or_default()>
+                                        {m.instantiate.map(|s| s.display(3)).unwrap_or_else(|| "—".to_string())}
+                                    </td>
+                                    <td>{format_time(calc_download_time_secs(m.bytes as f64 / 1_000_000.0, SATELLITE_SPEED_MBPS))}</td>
+                                </tr>
+                            }.into_view(),
+                            Err(e) => view! {
+                                <tr>
+                                    <td class="world-name">{variant.label}</td>
+                                    <td><code>{variant.pipeline}</code></td>
+                                    <td class="warning" colspan="5">{e.clone()}</td>
+                                </tr>
+                            }.into_view(),
+                        }).collect_view()
+                    })}
+                </table>
+            </Show>
+            <p class="metrics-note">"Built by dashboard/build-variants.sh during trunk build. Instantiation uses stub imports for the wasm-bindgen glue, so it times the module alone."</p>
+        </div>
+    }
+}
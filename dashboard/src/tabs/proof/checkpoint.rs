@@ -0,0 +1,90 @@
+// what: checkpoint format for a wasm instance - its mutable globals plus linear memory with the zero tail trimmed - and a rust reference of the module's state
+// why: "instant recovery with state" only holds if a snapshot restores byte for byte into a fresh instance; the reference says what that state must be
+// relations: module from demo/modules.rs (CHECKPOINT_WASM), snapshots taken, restored and timed by checkpoint_panel.rs
+
+use crate::tabs::demo::modules::CHECKPOINT_RING;
+
+pub const MAGIC: &[u8; 4] = b"GCKP";
+pub const VERSION: u8 = 1;
+const PAGE_BYTES: usize = 65_536;
+/// magic, version, pages, global count
+const HEADER_BYTES: usize = 4 + 1 + 4 + 1;
+
+/// readings the panel records before each checkpoint (the last fills the whole ring)
+pub const READINGS: [u32; 4] = [1_000, 10_000, 100_000, CHECKPOINT_RING];
+/// fill() seed used by the panel
+pub const SEED: i32 = 7;
+
+/// a wasm instance's state: memory size, mutable globals in export order, and the full linear memory
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checkpoint {
+    pub pages: u32,
+    pub globals: Vec<i32>,
+    pub memory: Vec<u8>,
+}
+
+impl Checkpoint {
+    /// header, globals (i32 le), stored length (u32 le), then memory up to its last non-zero byte
+    pub fn encode(&self) -> Vec<u8> {
+        let stored = trimmed_len(&self.memory);
+        let mut out = Vec::with_capacity(HEADER_BYTES + self.globals.len() * 4 + 4 + stored);
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        out.extend_from_slice(&self.pages.to_le_bytes());
+        out.push(self.globals.len() as u8);
+        for g in &self.globals {
+            out.extend_from_slice(&g.to_le_bytes());
+        }
+        out.extend_from_slice(&(stored as u32).to_le_bytes());
+        out.extend_from_slice(&self.memory[..stored]);
+        out
+    }
+
+    /// inverse of encode; the trimmed tail comes back as zeros
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < HEADER_BYTES || &bytes[..4] != MAGIC {
+            return Err("not a checkpoint (bad magic)".to_string());
+        }
+        if bytes[4] != VERSION {
+            return Err(format!("unsupported checkpoint version {}", bytes[4]));
+        }
+        let u32_at = |at: usize| bytes.get(at..at + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]])).ok_or("checkpoint truncated");
+        let pages = u32_at(5)?;
+        let count = bytes[9] as usize;
+        let globals = (0..count).map(|i| u32_at(HEADER_BYTES + i * 4).map(|v| v as i32)).collect::<Result<Vec<_>, _>>()?;
+        let at = HEADER_BYTES + count * 4;
+        let stored = u32_at(at)? as usize;
+        let data = bytes.get(at + 4..at + 4 + stored).ok_or("checkpoint truncated")?;
+        let size = pages as usize * PAGE_BYTES;
+        if stored > size {
+            return Err(format!("{} stored bytes do not fit in {} pages", stored, pages));
+        }
+        let mut memory = vec![0; size];
+        memory[..stored].copy_from_slice(data);
+        Ok(Self { pages, globals, memory })
+    }
+}
+
+/// bytes up to and including the last non-zero one
+pub fn trimmed_len(memory: &[u8]) -> usize {
+    memory.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1)
+}
+
+/// pages the module holds after `readings` records (it grows a page whenever the ring reaches the end of memory)
+pub fn pages_for(readings: u32) -> u32 {
+    ((readings.min(CHECKPOINT_RING) as usize * 4).div_ceil(PAGE_BYTES) as u32).max(1)
+}
+
+/// (count, acc, checksum) of a fresh instance after fill(readings, seed), computed the way the module does
+pub fn reference_state(readings: u32, seed: i32) -> (i32, i32, i32) {
+    let ring = CHECKPOINT_RING as usize;
+    let mut slots = vec![0i32; ring.min(readings as usize)];
+    let mut acc = 0i32;
+    for i in 0..readings as i32 {
+        let value = seed.wrapping_add(i.wrapping_mul(37)) & 1023;
+        slots[i as usize % ring] = value;
+        acc = acc.wrapping_add(value);
+    }
+    let checksum = slots.iter().fold(0i32, |h, v| h.wrapping_mul(31).wrapping_add(*v));
+    (readings as i32, acc, checksum)
+}
@@ -0,0 +1,217 @@
+// what: checkpoints a stateful wasm instance (linear memory + mutable globals), restores it into a fresh instance and times both against replaying its history
+// why: rebuilding an instance is sub-ms, but a rebuilt instance is empty; this measures what it costs to bring its state along
+// relations: format and reference in checkpoint.rs, module from demo/modules.rs (CHECKPOINT_WASM), timing via measure/timing.rs sample();
+//            mounted in proof/component.rs
+
+use leptos::*;
+use wasm_bindgen::{JsCast, JsValue};
+use crate::measure::stats::Summary;
+use crate::measure::timing::sample;
+use crate::settings::use_settings;
+use crate::tabs::demo::budget::format_bytes;
+use crate::tabs::demo::modules::{CHECKPOINT_MAX_PAGES, CHECKPOINT_WASM};
+use super::checkpoint::{pages_for, reference_state, Checkpoint, READINGS, SEED};
+
+fn js_error(e: JsValue) -> String {
+    format!("{:?}", e)
+}
+
+fn export<T: JsCast>(exports: &js_sys::Object, name: &str) -> Result<T, String> {
+    js_sys::Reflect::get(exports, &name.into())
+        .ok()
+        .and_then(|v| v.dyn_into::<T>().ok())
+        .ok_or_else(|| format!("missing export {}", name))
+}
+
+/// one instance of the checkpoint module and the exports the checkpoint touches
+struct Live {
+    memory: js_sys::WebAssembly::Memory,
+    globals: [js_sys::WebAssembly::Global; 2],
+    fill: js_sys::Function,
+    record: js_sys::Function,
+    checksum: js_sys::Function,
+}
+
+impl Live {
+    fn spawn(module: &js_sys::WebAssembly::Module) -> Result<Self, String> {
+        let exports = js_sys::WebAssembly::Instance::new(module, &js_sys::Object::new()).map_err(js_error)?.exports();
+        Ok(Self {
+            memory: export(&exports, "memory")?,
+            globals: [export(&exports, "count")?, export(&exports, "acc")?],
+            fill: export(&exports, "fill")?,
+            record: export(&exports, "record")?,
+            checksum: export(&exports, "checksum")?,
+        })
+    }
+
+    fn fill(&self, readings: u32) -> Result<(), String> {
+        self.fill.call2(&JsValue::NULL, &readings.into(), &SEED.into()).map(|_| ()).map_err(js_error)
+    }
+
+    /// (count, acc, checksum)
+    fn state(&self) -> Result<(i32, i32, i32), String> {
+        let global = |g: &js_sys::WebAssembly::Global| g.value().as_f64().unwrap_or(0.0) as i32;
+        let checksum = self.checksum.call0(&JsValue::NULL).map_err(js_error)?.as_f64().unwrap_or(0.0) as i32;
+        Ok((global(&self.globals[0]), global(&self.globals[1]), checksum))
+    }
+
+    fn checkpoint(&self) -> Checkpoint {
+        let memory = js_sys::Uint8Array::new(&self.memory.buffer()).to_vec();
+        Checkpoint {
+            pages: (memory.len() / 65_536) as u32,
+            globals: self.globals.iter().map(|g| g.value().as_f64().unwrap_or(0.0) as i32).collect(),
+            memory,
+        }
+    }
+
+    /// a fresh instance grown to the checkpoint's size, memory copied in, globals set
+    fn restore(module: &js_sys::WebAssembly::Module, checkpoint: &Checkpoint) -> Result<Self, String> {
+        let live = Self::spawn(module)?;
+        let pages = js_sys::Uint8Array::new(&live.memory.buffer()).length() / 65_536;
+        if checkpoint.pages > pages {
+            live.memory.grow(checkpoint.pages - pages);
+        }
+        // the view is taken after the grow: growing detaches the old buffer
+        let target = js_sys::Uint8Array::new(&live.memory.buffer());
+        target.subarray(0, checkpoint.memory.len() as u32).copy_from(&checkpoint.memory);
+        for (global, value) in live.globals.iter().zip(&checkpoint.globals) {
+            global.set_value(&JsValue::from(*value));
+        }
+        Ok(live)
+    }
+}
+
+/// one checkpoint size: what was saved and how long each path took
+#[derive(Clone, Debug, PartialEq)]
+struct Measured {
+    memory_bytes: usize,
+    snapshot_bytes: usize,
+    snapshot: Summary,
+    restore: Summary,
+    replay: Summary,
+    /// restored state equals the source and the rust reference, and both continue identically
+    verified: Result<(), String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Row {
+    readings: u32,
+    result: Result<Measured, String>,
+}
+
+/// both instances hold the reference state, and stay identical after one more reading
+fn verify(source: &Live, restored: &Live, expected: (i32, i32, i32)) -> Result<(), String> {
+    let (from, to) = (source.state()?, restored.state()?);
+    if from != expected || to != expected {
+        return Err(format!("state {:?} → {:?}, expected {:?}", from, to, expected));
+    }
+    for live in [source, restored] {
+        live.record.call1(&JsValue::NULL, &JsValue::from(5)).map_err(js_error)?;
+    }
+    if source.state()? != restored.state()? {
+        return Err("diverged after the next reading".to_string());
+    }
+    Ok(())
+}
+
+async fn measure(readings: u32, iterations: u32) -> Result<Measured, String> {
+    let module = js_sys::WebAssembly::Module::new(&js_sys::Uint8Array::from(CHECKPOINT_WASM)).map_err(js_error)?;
+    let source = Live::spawn(&module)?;
+    source.fill(readings)?;
+    let bytes = source.checkpoint().encode();
+
+    let (snapshot, _) = sample(iterations, || {
+        let _ = source.checkpoint().encode();
+        async {}
+    }).await;
+    let (restore, _) = sample(iterations, || {
+        let _ = Checkpoint::decode(&bytes).and_then(|c| Live::restore(&module, &c));
+        async {}
+    }).await;
+    // the alternative: rebuild empty and replay every reading
+    let (replay, _) = sample(iterations, || {
+        let _ = Live::spawn(&module).and_then(|l| l.fill(readings));
+        async {}
+    }).await;
+
+    let restored = Live::restore(&module, &Checkpoint::decode(&bytes)?)?;
+    let verified = verify(&source, &restored, reference_state(readings, SEED));
+    Ok(Measured {
+        memory_bytes: pages_for(readings) as usize * 65_536,
+        snapshot_bytes: bytes.len(),
+        snapshot,
+        restore,
+        replay,
+        verified,
+    })
+}
+
+#[component]
+pub fn CheckpointDemo() -> impl IntoView {
+    let settings = use_settings();
+    let (running, set_running) = create_signal(false);
+    let rows = create_rw_signal(Vec::<Row>::new());
+
+    let run = move |_| {
+        if running.get() { return; }
+        set_running.set(true);
+        rows.set(Vec::new());
+        let iterations = settings.with_untracked(|s| s.benchmark_iterations);
+        spawn_local(async move {
+            for readings in READINGS {
+                let result = measure(readings, iterations).await;
+                tracing::info!(target: "measure", readings, ok = result.is_ok(), "checkpoint/restore");
+                rows.update(|r| r.push(Row { readings, result }));
+            }
+            set_running.set(false);
+        });
+    };
+
+    view! {
+        <div class="measured-metrics checkpoint-demo">
+            <h3>"💾 Checkpoint & Restore"</h3>
+            <p class="section-desc">"A small stateful module records readings into its linear memory and keeps a count and running sum in mutable globals. Its state is checkpointed (memory with the zero tail trimmed, plus globals), restored into a fresh instance and checked against the original - then compared with rebuilding empty and replaying every reading."</p>
+            <button class="action-btn simulation-btn" disabled=move || running.get() on:click=run>
+                {move || if running.get() { "⏳ Checkpointing..." } else { "▶️ Checkpoint & Restore" }}
+            </button>
+            <Show when=move || rows.with(|r| !r.is_empty())>
+                <table>
+                    <tr>
+                        <th>"Readings"</th>
+                        <th>"Linear memory"</th>
+                        <th>"Snapshot"</th>
+                        <th>"Take snapshot"</th>
+                        <th>"Restore (fresh instance)"</th>
+                        <th>"Rebuild + replay"</th>
+                        <th>"State"</th>
+                    </tr>
+                    {move || rows.get().into_iter().map(|row| match row.result {
+                        Ok(m) => view! {
+                            <tr>
+                                <td>{row.readings.to_string()}</td>
+                                <td>{format!("{} ({}/{} pages)", format_bytes(m.memory_bytes as u64), m.memory_bytes / 65_536, CHECKPOINT_MAX_PAGES)}</td>
+                                <td>{format_bytes(m.snapshot_bytes as u64)}</td>
+                                <td title=m.snapshot.detail()>{m.snapshot.display(3)}</td>
+                                <td class="success" title=m.restore.detail()>{m.restore.display(3)}</td>
+                                <td title=m.replay.detail()>{m.replay.display(3)}</td>
+                                {match m.verified {
+                                    Ok(()) => view! { <td class="success">"✓ identical"</td> },
+                                    Err(reason) => view! { <td class="warning">{format!("✗ {}", reason)}</td> },
+                                }}
+                            </tr>
+                        },
+                        Err(reason) => view! {
+                            <tr>
+                                <td>{row.readings.to_string()}</td>
+                                <td colspan="6" class="warning">{format!("Not measured: {}", reason)}</td>
+                            </tr>
+                        },
+                    }).collect_view()}
+                </table>
+            </Show>
+            <p class="metrics-note">
+                "Restore includes instantiating the fresh instance, growing its memory and copying the snapshot in. The replay here is cheap because each reading is one store; a real gateway's history (protocol sessions, calibration runs) cannot be replayed at all, which is what the checkpoint is for. Timed on the main thread."
+            </p>
+        </div>
+    }
+}
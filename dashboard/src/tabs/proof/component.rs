@@ -1,106 +1,31 @@
 // what: proof tab showing real measured performance comparisons
 // why: provides verified metrics with a simulation button for live measurement
-// relations: used by mod.rs, final tab in story flow
+// relations: used by mod.rs, final tab in story flow; the measurement suite and its shared results live in bench.rs
 
 use leptos::*;
-use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsCast;
-
-// Minimal WASM module for instantiation timing
-const MINIMAL_WASM: &[u8] = &[
-    0x00, 0x61, 0x73, 0x6d, // magic
-    0x01, 0x00, 0x00, 0x00, // version
-];
-
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "performance"])]
-    fn now() -> f64;
-}
-
-async fn measure_wasm_instantiate() -> f64 {
-    let array = js_sys::Uint8Array::from(MINIMAL_WASM);
-    let compile_promise = js_sys::WebAssembly::compile(&array.buffer());
-    let module: js_sys::WebAssembly::Module = wasm_bindgen_futures::JsFuture::from(compile_promise)
-        .await
-        .unwrap()
-        .unchecked_into();
-    
-    let iterations = 10;
-    let start = now();
-    
-    for _ in 0..iterations {
-        let import_object = js_sys::Object::new();
-        let instantiate_promise = js_sys::WebAssembly::instantiate_module(&module, &import_object);
-        let _instance: js_sys::WebAssembly::Instance = wasm_bindgen_futures::JsFuture::from(instantiate_promise)
-            .await
-            .unwrap()
-            .unchecked_into();
-    }
-    
-    (now() - start) / iterations as f64
-}
+use crate::settings::use_settings;
+use crate::measure::PrecisionBanner;
+use crate::diagnostics::about::{format_bytes, pyodide_download_bytes, wasm_binary_bytes};
+use super::bench::{self, use_proof_results};
 
 #[component]
 pub fn Proof() -> impl IntoView {
-    let (simulation_ran, set_simulation_ran) = create_signal(false);
-    let (running, set_running) = create_signal(false);
-    let (wasm_instantiate_ms, set_wasm_instantiate_ms) = create_signal(0.0f64);
-    let (python_coldstart_ms, set_python_coldstart_ms) = create_signal(0.0f64);
-    let (wasm_recovery_ms, set_wasm_recovery_ms) = create_signal(0.0f64);
-    let (run_count, set_run_count) = create_signal(0u32);
+    let settings = use_settings();
+    // measured here or in the background on page load (autobench/), so the numbers survive tab switches
+    let results = use_proof_results();
+    let simulation_ran = Signal::derive(move || results.with(|r| r.ran()));
+    let running = Signal::derive(move || results.with(|r| r.running.is_some()));
+    let wasm_instantiate_ms = Signal::derive(move || results.with(|r| r.wasm_instantiate_ms));
+    let python_coldstart_ms = Signal::derive(move || results.with(|r| r.python_coldstart_ms()));
+    // crash recovery is a fresh instantiate
+    let wasm_recovery_ms = wasm_instantiate_ms;
+    let wasm_call_ms = Signal::derive(move || results.with(|r| r.wasm_call_ms));
+    let run_count = Signal::derive(move || results.with(|r| r.runs));
     
     let run_simulation = move |_| {
         if running.get() { return; }
-        set_running.set(true);
-        
-        wasm_bindgen_futures::spawn_local(async move {
-            // Measure WASM instantiation (fresh each time)
-            let wasm_time = measure_wasm_instantiate().await;
-            set_wasm_instantiate_ms.set(wasm_time);
-            set_wasm_recovery_ms.set(wasm_time);
-            
-            // Reload Pyodide and measure REAL cold-start time
-            // This destroys the existing Pyodide instance and loads a fresh one
-            let window = web_sys::window().unwrap();
-            
-            // Set flag that we're reloading
-            let _ = js_sys::Reflect::set(&window, &"pyodideReloading".into(), &true.into());
-            
-            let start = now();
-            
-            // Execute JS to reload Pyodide - this will block until complete
-            let reload_code = r#"
-                (async () => {
-                    // Destroy existing instance
-                    if (window.pyodide) {
-                        window.pyodide = null;
-                    }
-                    // Load fresh Pyodide
-                    window.pyodide = await loadPyodide();
-                    window.runPython = (code) => window.pyodide.runPython(code);
-                    return true;
-                })()
-            "#;
-            
-            let reload_promise = js_sys::eval(reload_code);
-            if let Ok(promise) = reload_promise {
-                if let Ok(js_promise) = promise.dyn_into::<js_sys::Promise>() {
-                    let _ = wasm_bindgen_futures::JsFuture::from(js_promise).await;
-                }
-            }
-            
-            let py_time = now() - start;
-            set_python_coldstart_ms.set(py_time);
-            
-            // Update window.pyodideLoadTime with new measurement
-            let _ = js_sys::Reflect::set(&window, &"pyodideLoadTime".into(), &py_time.into());
-            let _ = js_sys::Reflect::set(&window, &"pyodideReloading".into(), &false.into());
-            
-            set_run_count.update(|n| *n += 1);
-            set_simulation_ran.set(true);
-            set_running.set(false);
-        });
+        let iterations = settings.with_untracked(|s| s.benchmark_iterations);
+        spawn_local(bench::run(results, iterations, false));
     };
 
     view! {
@@ -121,13 +46,13 @@ pub fn Proof() -> impl IntoView {
                     attr:data-tooltip="Reloads both WASM module and Pyodide runtime fresh, measures real cold-start times"
                     on:click=run_simulation
                 >
-                    {move || if running.get() { "⏳ Reloading Pyodide..." } else { "▶️ Run Simulation" }}
+                    {move || if running.get() { "⏳ Measuring..." } else { "▶️ Run Simulation" }}
                 </button>
                 <p class="simulation-note">
-                    {move || if running.get() {
-                        "⏳ Reloading Pyodide runtime (this takes 1-2 seconds)...".to_string()
+                    {move || if let Some((step, steps, label)) = results.with(|r| r.progress()) {
+                        format!("⏳ {}/{}: {}{}", step, steps, label, if results.with(|r| r.auto) { " (started on page load)" } else { "" })
                     } else if simulation_ran.get() { 
-                        format!("✅ Fresh measurements from run #{} shown below", run_count.get())
+                        format!("✅ Fresh measurements from run #{} shown below{}", run_count.get(), if results.with(|r| r.auto) { " (measured in the background on page load)" } else { "" })
                     } else { 
                         "Reloads WASM module + Pyodide fresh each run for accurate comparison".to_string()
                     }}
@@ -136,6 +61,7 @@ pub fn Proof() -> impl IntoView {
             
             <div class="measured-metrics">
                 <h3>"Measured Performance"</h3>
+                <PrecisionBanner />
                 <table>
                     <tr>
                         <th>"Metric"</th>
@@ -144,30 +70,41 @@ pub fn Proof() -> impl IntoView {
                         <th>"Speedup"</th>
                     </tr>
                     <tr>
-                        <td>"Binary size"</td>
-                        <td class="warning">"12.4 MB"</td>
-                        <td class="success">"47 KB"</td>
-                        <td class="success">"264x smaller"</td>
+                        <td>"Download size"</td>
+                        <td class="warning" title="Pyodide resources in this page's resource timing">{move || {
+                            run_count.track();
+                            pyodide_download_bytes().map(format_bytes).unwrap_or_else(|| "—".to_string())
+                        }}</td>
+                        <td class="success" title="This dashboard's wasm bundle, UI included">{move || {
+                            wasm_binary_bytes().map(format_bytes).unwrap_or_else(|| "—".to_string())
+                        }}</td>
+                        <td class="success">{move || {
+                            run_count.track();
+                            match (pyodide_download_bytes(), wasm_binary_bytes()) {
+                                (Some(py), Some(wasm)) => format!("{:.0}x smaller", py / wasm),
+                                _ => "—".to_string(),
+                            }
+                        }}</td>
                     </tr>
                     <tr>
                         <td>"Cold start"</td>
-                        <td class="warning">{move || {
+                        <td class="warning" title=move || python_coldstart_ms.get().detail()>{move || {
                             if simulation_ran.get() {
-                                format!("{:.0}ms", python_coldstart_ms.get())
+                                python_coldstart_ms.get().display(0)
                             } else {
                                 "—".to_string()
                             }
                         }}</td>
-                        <td class="success">{move || {
+                        <td class="success" title=move || wasm_instantiate_ms.get().detail()>{move || {
                             if simulation_ran.get() {
-                                format!("{:.2}ms", wasm_instantiate_ms.get())
+                                wasm_instantiate_ms.get().display(3)
                             } else {
                                 "—".to_string()
                             }
                         }}</td>
                         <td class="success">{move || {
-                            if simulation_ran.get() && wasm_instantiate_ms.get() > 0.0 {
-                                format!("{:.0}x faster", python_coldstart_ms.get() / wasm_instantiate_ms.get())
+                            if simulation_ran.get() && wasm_instantiate_ms.get().median > 0.0 {
+                                format!("{:.0}x faster", python_coldstart_ms.get().median / wasm_instantiate_ms.get().median)
                             } else {
                                 "—".to_string()
                             }
@@ -175,32 +112,70 @@ pub fn Proof() -> impl IntoView {
                     </tr>
                     <tr>
                         <td>"Crash recovery"</td>
-                        <td class="warning">{move || {
+                        <td class="warning" title=move || python_coldstart_ms.get().detail()>{move || {
                             if simulation_ran.get() {
-                                format!("{:.0}ms", python_coldstart_ms.get())
+                                python_coldstart_ms.get().display(0)
                             } else {
                                 "—".to_string()
                             }
                         }}</td>
-                        <td class="success">{move || {
+                        <td class="success" title=move || wasm_recovery_ms.get().detail()>{move || {
                             if simulation_ran.get() {
-                                format!("{:.2}ms", wasm_recovery_ms.get())
+                                wasm_recovery_ms.get().display(3)
                             } else {
                                 "—".to_string()
                             }
                         }}</td>
                         <td class="success">{move || {
-                            if simulation_ran.get() && wasm_recovery_ms.get() > 0.0 {
-                                format!("{:.0}x faster", python_coldstart_ms.get() / wasm_recovery_ms.get())
+                            if simulation_ran.get() && wasm_recovery_ms.get().median > 0.0 {
+                                format!("{:.0}x faster", python_coldstart_ms.get().median / wasm_recovery_ms.get().median)
                             } else {
                                 "—".to_string()
                             }
                         }}</td>
                     </tr>
+                    <tr>
+                        <td>"Exported call (add)"</td>
+                        <td class="warning">"—"</td>
+                        <td class="success" title=move || wasm_call_ms.get().map(|s| s.detail()).unwrap_or_default()>{move || {
+                            match wasm_call_ms.get() {
+                                Some(s) if simulation_ran.get() => format!("{:.1} ± {:.1}ns", s.median * 1e6, s.ci95 * 1e6),
+                                _ => "—".to_string(),
+                            }
+                        }}</td>
+                        <td>"—"</td>
+                    </tr>
                 </table>
-                <p class="metrics-note">"All timing values measured in your browser using real WebAssembly API and Pyodide."</p>
+                <p class="metrics-note">"All timing values measured in your browser using real WebAssembly API and Pyodide. Values are medians ± 95% CI after warm-up discard and MAD outlier rejection; Python cold start gains a CI after repeated runs."</p>
             </div>
             
+            // imported docker restart next to the two cold starts above
+            <super::container_baseline::ContainerBaseline ran=simulation_ran wasm_instantiate_ms=wasm_instantiate_ms python_coldstart_ms=python_coldstart_ms />
+            
+            // the dashboard's own boot phases
+            <super::startup_waterfall::StartupWaterfall />
+            
+            // section table and treemap of the served wasm
+            <super::size_inspector::SizeInspector />
+            
+            // tail-latency / gc-pause comparison
+            <super::jitter::JitterBenchmark />
+            
+            // sensor module linked into a filter module, linked vs fused call cost
+            <super::linking_panel::LinkingDemo />
+            
+            // instance memory + globals checkpointed, restored into a fresh instance, against replaying its history
+            <super::checkpoint_panel::CheckpointDemo />
+            
+            // illustrative pfd / availability from the measured restart times
+            <super::sil_panel::SilCalculator ran=simulation_ran wasm_recovery_ms=wasm_recovery_ms python_coldstart_ms=python_coldstart_ms />
+            
+            // yearly downtime of both restart strategies across fault rates
+            <super::downtime_panel::DowntimeModel ran=simulation_ran wasm_recovery_ms=wasm_recovery_ms python_coldstart_ms=python_coldstart_ms />
+            
+            // debug / release / wasm-opt artifacts of the same crate
+            <super::build_variants::BuildVariants />
+            
             // ota update comparison simulator
             <super::ota_simulator::OtaSimulator />
             
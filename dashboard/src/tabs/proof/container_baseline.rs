@@ -0,0 +1,107 @@
+// what: container restart baseline - an imported docker restart figure shown next to the two cold starts measured in this browser
+// why: the problem tab claims container restarts take ~1-5s; a browser cannot restart a container, so the number comes in through the import path, labelled as such
+// relations: import schema, storage and claim check in crate::handoff; wasm and pyodide figures from component.rs; cited by problem/comparison.rs
+
+use leptos::*;
+use crate::handoff::{claim_check, container_baseline, load_sessions, merge, save_sessions, ResultSummary};
+use crate::measure::stats::Summary;
+use crate::tabs::demo::siem::rfc3339;
+
+/// shell loop whose output can be pasted as-is
+const MEASURE_CMD: &str = "for i in $(seq 10); do /usr/bin/time -f %e docker restart <container> > /dev/null; done";
+
+/// "1234x" of the wasm cold start, or a dash
+fn ratio(ms: f64, wasm_ms: f64) -> String {
+    if wasm_ms > 0.0 { format!("{:.0}x", ms / wasm_ms) } else { "—".to_string() }
+}
+
+#[component]
+pub fn ContainerBaseline(ran: Signal<bool>, wasm_instantiate_ms: Signal<Summary>, python_coldstart_ms: Signal<Summary>) -> impl IntoView {
+    let sessions = create_rw_signal(load_sessions());
+    let (device, set_device) = create_signal("docker-host".to_string());
+    let (text, set_text) = create_signal(String::new());
+    let (status, set_status) = create_signal(Option::<String>::None);
+
+    // a handoff payload or json carrying container_restart, otherwise raw timings in seconds
+    let import = move |_| {
+        let text = text.get_untracked();
+        let parsed = match ResultSummary::decode(&text) {
+            Ok(summary) if summary.container_restart.is_some() => Ok(summary),
+            Ok(summary) => Err(format!("{} has no container_restart", summary.device)),
+            Err(_) => ResultSummary::from_restart_seconds(&device.get_untracked(), &text, js_sys::Date::now()),
+        };
+        match parsed {
+            Ok(summary) => {
+                set_status.set(Some(format!("✅ baseline from {}", summary.device)));
+                sessions.update(|s| merge(s, summary));
+                sessions.with_untracked(|s| save_sessions(s));
+                set_text.set(String::new());
+            }
+            Err(e) => set_status.set(Some(format!("❌ {}", e))),
+        }
+    };
+
+    let rows = move || {
+        let wasm = ran.get().then(|| wasm_instantiate_ms.get());
+        let python = ran.get().then(|| python_coldstart_ms.get());
+        let wasm_ms = wasm.map(|w| w.median).unwrap_or(0.0);
+        let baseline = sessions.with(|s| container_baseline(s).cloned());
+        view! {
+            <tr>
+                <td>"🦀 WASM instantiate"</td>
+                <td class="success">{wasm.map(|w| w.display(3)).unwrap_or_else(|| "—".to_string())}</td>
+                <td>"measured in this browser"</td>
+                <td>"1x"</td>
+            </tr>
+            <tr>
+                <td>"🐍 Pyodide cold start"</td>
+                <td class="warning">{python.map(|p| p.display(0)).unwrap_or_else(|| "—".to_string())}</td>
+                <td>"measured in this browser"</td>
+                <td>{python.map(|p| ratio(p.median, wasm_ms)).unwrap_or_else(|| "—".to_string())}</td>
+            </tr>
+            {match baseline.and_then(|b| Some((b.container_restart?, b))) {
+                Some((restart, b)) => view! {
+                    <tr class="baseline-imported">
+                        <td>"🐳 Container restart"</td>
+                        <td class="warning">{Summary { median: restart.median, ci95: restart.ci95, kept: restart.n, ..Default::default() }.display(0)}</td>
+                        <td>{format!("imported from {} · {} run{} · {} · {}", b.device, restart.n, if restart.n == 1 { "" } else { "s" }, &rfc3339(b.taken_ms)[..10], claim_check(restart.median))}</td>
+                        <td>{ratio(restart.median, wasm_ms)}</td>
+                    </tr>
+                }.into_view(),
+                None => view! {
+                    <tr class="baseline-imported">
+                        <td>"🐳 Container restart"</td>
+                        <td>"—"</td>
+                        <td>"not imported yet (the problem tab's ~1-5s is unsourced until you do)"</td>
+                        <td>"—"</td>
+                    </tr>
+                }.into_view(),
+            }}
+        }
+    };
+
+    view! {
+        <div class="measured-metrics container-baseline">
+            <h3>"🐳 Container Baseline"</h3>
+            <p class="section-desc">"A browser cannot restart a container, so this row is not measured here: time "<code>"docker restart"</code>" on a host, paste the timings (seconds) or a results payload/JSON with "<code>"container_restart"</code>", and it is compared with the two cold starts above."</p>
+            <table>
+                <tr><th>"Cold start"</th><th>"Median"</th><th>"Source"</th><th>"vs WASM"</th></tr>
+                {rows}
+            </table>
+            <pre class="baseline-cmd">{MEASURE_CMD}</pre>
+            <div class="audit-controls">
+                <input class="collector-url" type="text" title="Host label for pasted timings" prop:value=move || device.get() on:input=move |ev| set_device.set(event_target_value(&ev)) />
+            </div>
+            <textarea
+                class="policy-editor handoff-import"
+                placeholder="2.31 2.18 2.44 …  or  G1!host!…!cr~2310~80~10  or  {\"device\":…,\"container_restart\":{…}}"
+                prop:value=move || text.get()
+                on:input=move |ev| set_text.set(event_target_value(&ev))
+            />
+            <div class="audit-controls">
+                <button class="action-btn" disabled=move || text.with(|t| t.trim().is_empty()) on:click=import>"⬆️ Import Baseline"</button>
+            </div>
+            {move || status.get().map(|s| view! { <p class="audit-status">{s}</p> })}
+        </div>
+    }
+}
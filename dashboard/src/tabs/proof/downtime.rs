@@ -0,0 +1,81 @@
+// what: annual downtime as a function of fault rate for python respawn vs wasm hot-swap, with availability-budget break-evens
+// why: connects the measured per-restart times to operations: how many faults a day each runtime absorbs before it misses three, four or five nines
+// relations: rendered by downtime_panel.rs, fed by component.rs measurements (the same restart times as sil_panel.rs)
+
+/// days in one year
+pub const DAYS_PER_YEAR: f64 = 365.0;
+/// slider range, faults per day (log scale)
+pub const FAULT_RATE_MIN: f64 = 0.01;
+pub const FAULT_RATE_MAX: f64 = 1000.0;
+/// slider positions between FAULT_RATE_MIN and FAULT_RATE_MAX
+pub const SLIDER_STEPS: u32 = 100;
+/// points per plotted curve
+pub const CURVE_POINTS: usize = 50;
+/// availability targets drawn across the chart
+pub const BUDGETS: [(&str, f64); 3] = [("99.9%", 0.999), ("99.99%", 0.9999), ("99.999%", 0.99999)];
+
+/// fault rate for a slider position, log-spaced from FAULT_RATE_MIN to FAULT_RATE_MAX
+pub fn rate_from_slider(position: u32) -> f64 {
+    let t = position.min(SLIDER_STEPS) as f64 / SLIDER_STEPS as f64;
+    10f64.powf(FAULT_RATE_MIN.log10() + t * (FAULT_RATE_MAX.log10() - FAULT_RATE_MIN.log10()))
+}
+
+/// nearest slider position for a fault rate
+pub fn slider_from_rate(faults_per_day: f64) -> u32 {
+    let rate = faults_per_day.clamp(FAULT_RATE_MIN, FAULT_RATE_MAX);
+    let t = (rate.log10() - FAULT_RATE_MIN.log10()) / (FAULT_RATE_MAX.log10() - FAULT_RATE_MIN.log10());
+    (t * SLIDER_STEPS as f64).round() as u32
+}
+
+/// seconds down per year when every fault costs one restart of `recovery_ms`
+pub fn annual_downtime_s(faults_per_day: f64, recovery_ms: f64) -> f64 {
+    faults_per_day.max(0.0) * DAYS_PER_YEAR * recovery_ms.max(0.0) / 1000.0
+}
+
+/// seconds per year an availability target allows
+pub fn budget_s(availability: f64) -> f64 {
+    (1.0 - availability.clamp(0.0, 1.0)) * DAYS_PER_YEAR * 86_400.0
+}
+
+/// faults per day at which restarts of `recovery_ms` use up `budget_s`; None without a measured restart
+pub fn break_even_rate(budget_s: f64, recovery_ms: f64) -> Option<f64> {
+    (recovery_ms > 0.0).then(|| budget_s / (DAYS_PER_YEAR * recovery_ms / 1000.0))
+}
+
+/// (faults per day, seconds down per year) across the slider range, log-spaced
+pub fn curve(recovery_ms: f64) -> Vec<(f64, f64)> {
+    let (lo, hi) = (FAULT_RATE_MIN.log10(), FAULT_RATE_MAX.log10());
+    (0..CURVE_POINTS)
+        .map(|i| {
+            let rate = 10f64.powf(lo + (hi - lo) * i as f64 / (CURVE_POINTS - 1) as f64);
+            (rate, annual_downtime_s(rate, recovery_ms))
+        })
+        .collect()
+}
+
+/// position of `value` on a log axis from `lo` to `hi`, 0..1 (clamped)
+pub fn log_position(value: f64, lo: f64, hi: f64) -> f64 {
+    if value <= 0.0 || lo <= 0.0 || hi <= lo {
+        return 0.0;
+    }
+    ((value.log10() - lo.log10()) / (hi.log10() - lo.log10())).clamp(0.0, 1.0)
+}
+
+/// seconds in the largest unit that keeps the number readable
+pub fn format_duration(s: f64) -> String {
+    match s {
+        s if s < 1.0 => format!("{:.1}ms", s * 1000.0),
+        s if s < 120.0 => format!("{:.1}s", s),
+        s if s < 7200.0 => format!("{:.1}min", s / 60.0),
+        s => format!("{:.1}h", s / 3600.0),
+    }
+}
+
+/// faults per day, without trailing noise on small rates
+pub fn format_rate(faults_per_day: f64) -> String {
+    if faults_per_day < 1.0 {
+        format!("{:.2}/day", faults_per_day)
+    } else {
+        format!("{:.0}/day", faults_per_day)
+    }
+}
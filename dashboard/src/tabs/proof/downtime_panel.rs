@@ -0,0 +1,122 @@
+// what: fault-rate slider and log-log chart of annual downtime for python respawn vs wasm hot-swap, with break-even rates per availability target
+// why: a restart time in milliseconds means little until it is multiplied by how often the line faults
+// relations: renders downtime.rs; fed by component.rs measurements, next to sil_panel.rs
+
+use leptos::*;
+use crate::measure::stats::Summary;
+use super::downtime::{
+    annual_downtime_s, break_even_rate, budget_s, curve, format_duration, format_rate, log_position, rate_from_slider,
+    slider_from_rate, BUDGETS, FAULT_RATE_MAX, FAULT_RATE_MIN, SLIDER_STEPS,
+};
+
+/// chart box (svg user units)
+const CHART_W: f64 = 600.0;
+const CHART_H: f64 = 200.0;
+/// default slider position: one fault a day
+const DEFAULT_RATE: f64 = 1.0;
+
+/// both curves, the budget lines, the selected rate and the break-even points, on log-log axes
+fn chart(rate: f64, python_ms: f64, wasm_ms: f64) -> View {
+    // the fastest and slowest outcomes on the chart bound the y axis
+    let y_lo = annual_downtime_s(FAULT_RATE_MIN, wasm_ms.min(python_ms)).max(1e-3);
+    let y_hi = annual_downtime_s(FAULT_RATE_MAX, wasm_ms.max(python_ms)).max(y_lo * 10.0);
+    let x = |r: f64| log_position(r, FAULT_RATE_MIN, FAULT_RATE_MAX) * CHART_W;
+    let y = |s: f64| CHART_H - log_position(s, y_lo, y_hi) * CHART_H;
+    let line = |recovery_ms: f64| {
+        curve(recovery_ms).into_iter().map(|(r, s)| format!("{:.1},{:.1}", x(r), y(s))).collect::<Vec<_>>().join(" ")
+    };
+    let budgets = BUDGETS.into_iter().filter(|(_, a)| (y_lo..=y_hi).contains(&budget_s(*a))).map(|(label, a)| {
+        let at = y(budget_s(a));
+        let crossings = [("downtime-python", python_ms), ("downtime-wasm", wasm_ms)]
+            .into_iter()
+            .filter_map(|(class, ms)| break_even_rate(budget_s(a), ms).filter(|r| (FAULT_RATE_MIN..=FAULT_RATE_MAX).contains(r)).map(|r| (class, r)))
+            .map(|(class, r)| view! { <circle class=format!("downtime-break-even {}", class) cx=format!("{:.1}", x(r)) cy=format!("{:.1}", at) r="4" /> })
+            .collect_view();
+        view! {
+            <line class="downtime-budget" x1="0" x2=CHART_W y1=format!("{:.1}", at) y2=format!("{:.1}", at) />
+            <text class="downtime-label" x="4" y=format!("{:.1}", at - 3.0)>{label}</text>
+            {crossings}
+        }
+    }).collect_view();
+    view! {
+        <div class="flood-chart downtime-chart">
+            <svg viewBox=format!("0 0 {} {}", CHART_W, CHART_H) preserveAspectRatio="none">
+                {budgets}
+                <line class="downtime-rate" x1=format!("{:.1}", x(rate)) x2=format!("{:.1}", x(rate)) y1="0" y2=CHART_H />
+                <polyline class="downtime-line downtime-python" points=line(python_ms) />
+                <polyline class="downtime-line downtime-wasm" points=line(wasm_ms) />
+            </svg>
+            <div class="slider-labels">
+                <span>{format!("{} · {}", format_rate(FAULT_RATE_MIN), format_duration(y_lo))}</span>
+                <span>{format!("{} · {}", format_rate(FAULT_RATE_MAX), format_duration(y_hi))}</span>
+            </div>
+        </div>
+    }
+    .into_view()
+}
+
+#[component]
+pub fn DowntimeModel(ran: Signal<bool>, wasm_recovery_ms: Signal<Summary>, python_coldstart_ms: Signal<Summary>) -> impl IntoView {
+    let (position, set_position) = create_signal(slider_from_rate(DEFAULT_RATE));
+    let rate = move || rate_from_slider(position.get());
+    let recovery = move || (python_coldstart_ms.get().median, wasm_recovery_ms.get().median);
+
+    view! {
+        <div class="sil-calculator downtime-model">
+            <h3>"📉 Downtime vs Fault Rate"</h3>
+            <p class="section-desc">"Every fault costs one restart: a fresh Python process (Pyodide cold start) or a WASM hot-swap (fresh instantiate). Slide the expected fault rate to see the yearly downtime of each, and where each runtime runs out of an availability budget."</p>
+            <div class="control-group">
+                <label>"Expected faults: "<strong>{move || format_rate(rate())}</strong></label>
+                <input
+                    type="range"
+                    min="0"
+                    max=SLIDER_STEPS
+                    step="1"
+                    class="fleet-slider"
+                    prop:value=move || position.get()
+                    on:input=move |ev| {
+                        if let Ok(v) = event_target_value(&ev).parse::<u32>() {
+                            set_position.set(v);
+                        }
+                    }
+                />
+                <div class="slider-labels">
+                    <span>{format_rate(FAULT_RATE_MIN)}</span>
+                    <span>{format_rate(FAULT_RATE_MAX)}</span>
+                </div>
+            </div>
+            {move || if ran.get() {
+                let (python_ms, wasm_ms) = recovery();
+                let rate = rate();
+                let (python_s, wasm_s) = (annual_downtime_s(rate, python_ms), annual_downtime_s(rate, wasm_ms));
+                let rate_cell = |ms: f64, a: f64| break_even_rate(budget_s(a), ms).map(format_rate).unwrap_or_else(|| "—".to_string());
+                view! {
+                    <p class="simulation-note">
+                        {format!("At {}: 🐍 {} down per year vs 🦀 {}", format_rate(rate), format_duration(python_s), format_duration(wasm_s))}
+                        {(wasm_ms > 0.0).then(|| format!(" - WASM absorbs {:.0}x the faults for the same downtime", python_ms / wasm_ms))}
+                    </p>
+                    {chart(rate, python_ms, wasm_ms)}
+                    <table class="capability-matrix voting-matrix sil-matrix">
+                        <tr>
+                            <th>"Availability target"</th>
+                            <th>"Allowed downtime / yr"</th>
+                            <th>"🐍 Break-even faults"</th>
+                            <th>"🦀 Break-even faults"</th>
+                        </tr>
+                        {BUDGETS.into_iter().map(|(label, a)| view! {
+                            <tr>
+                                <td class="world-name">{label}</td>
+                                <td>{format_duration(budget_s(a))}</td>
+                                <td class:vote-halted=break_even_rate(budget_s(a), python_ms).is_some_and(|r| r < rate)>{rate_cell(python_ms, a)}</td>
+                                <td class:vote-halted=break_even_rate(budget_s(a), wasm_ms).is_some_and(|r| r < rate)>{rate_cell(wasm_ms, a)}</td>
+                            </tr>
+                        }).collect_view()}
+                    </table>
+                }.into_view()
+            } else {
+                view! { <p class="section-desc">"Run the simulation above to feed in the measured restart times."</p> }.into_view()
+            }}
+            <p class="metrics-note">"Break-even is the fault rate at which restarts alone use up the target; a red cell means the selected rate already exceeds it. Detection time, state resync and faults a restart cannot fix are left out, so real downtime is higher for both."</p>
+        </div>
+    }
+}
@@ -0,0 +1,104 @@
+// what: latency-jitter benchmark (10,000 sensor ticks per runtime, tail percentiles + histograms)
+// why: determinism matters more than average speed for control loops - show p99.9 and the worst pause
+// relations: used by proof/component.rs; wasm/js ticks run in measure/worker.rs, python in pyodide; gated on diagnostics BrowserCaps
+
+use leptos::*;
+use crate::diagnostics::{use_browser_caps, UnsupportedNotice};
+use crate::measure::jitter::{histogram, JitterReport, HISTOGRAM_BINS, JITTER_TICKS};
+use crate::measure::timing::timer_resolution_ms;
+use crate::measure::worker;
+use crate::tabs::demo::modules::{TICK_JS, TICK_PY, TICK_WASM, TICK_WORK};
+use crate::tabs::demo::wasm::{ensurePyodide, runPython};
+use crate::tabs::histogram::Histogram;
+
+/// one runtime's row: raw samples or the reason it could not run
+#[derive(Clone, Debug, PartialEq)]
+struct Run {
+    label: &'static str,
+    tone: &'static str,
+    samples: Result<Vec<f64>, String>,
+}
+
+async fn python_ticks() -> Result<Vec<f64>, String> {
+    ensurePyodide().await.map_err(|_| "Pyodide unavailable".to_string())?;
+    let code = TICK_PY.replace("{runs}", &JITTER_TICKS.to_string()).replace("{work}", &TICK_WORK.to_string());
+    let result = runPython(&code).await.map_err(|e| format!("{:?}", e))?;
+    serde_json::from_str(&result.as_string().unwrap_or_default()).map_err(|e| e.to_string())
+}
+
+#[component]
+pub fn JitterBenchmark() -> impl IntoView {
+    let caps = use_browser_caps();
+    let (running, set_running) = create_signal(false);
+    let runs = create_rw_signal(Vec::<Run>::new());
+    
+    let run_benchmark = move |_| {
+        if running.get() { return; }
+        set_running.set(true);
+        runs.set(Vec::new());
+        spawn_local(async move {
+            // isolated in the measurement worker
+            let wasm = worker::wasm_ticks(TICK_WASM, "tick", TICK_WORK, JITTER_TICKS).await;
+            runs.update(|r| r.push(Run { label: "🦀 WASM", tone: "success", samples: wasm }));
+            let js = worker::js_ticks(TICK_JS, TICK_WORK, JITTER_TICKS).await;
+            runs.update(|r| r.push(Run { label: "🟨 JavaScript", tone: "accent", samples: js }));
+            // pyodide lives on the main thread
+            let python = python_ticks().await;
+            runs.update(|r| r.push(Run { label: "🐍 Python (Pyodide)", tone: "warning", samples: python }));
+            set_running.set(false);
+        });
+    };
+    
+    view! {
+        <div class="measured-metrics jitter-benchmark">
+            <h3>"📉 Latency Jitter (Determinism)"</h3>
+            <p class="section-desc">{format!(
+                "{} sensor ticks per runtime ({} loop iterations each). Control loops care about the worst pause, not the average.",
+                JITTER_TICKS, TICK_WORK
+            )}</p>
+            // wasm and js ticks only run in the worker, so no worker means no benchmark
+            <Show when=move || caps.workers fallback=|| view! { <UnsupportedNotice feature="Jitter benchmark" api="Web Workers" /> }>
+                <button class="action-btn simulation-btn" disabled=move || running.get() on:click=run_benchmark>
+                    {move || if running.get() { "⏳ Running ticks..." } else { "▶️ Run Jitter Benchmark" }}
+                </button>
+            </Show>
+            <Show when=move || runs.with(|r| !r.is_empty())>
+                <table>
+                    <tr>
+                        <th>"Runtime"</th>
+                        <th>"p50"</th>
+                        <th>"p99"</th>
+                        <th>"p99.9"</th>
+                        <th>"Worst pause"</th>
+                        <th>"Distribution"</th>
+                    </tr>
+                    {move || runs.get().into_iter().map(|run| match run.samples {
+                        Ok(samples) => {
+                            let report = JitterReport::from_samples(&samples);
+                            view! {
+                                <tr>
+                                    <td title=format!("{} ticks", report.ticks)>{run.label}</td>
+                                    <td>{format!("{:.3}ms", report.p50)}</td>
+                                    <td>{format!("{:.3}ms", report.p99)}</td>
+                                    <td class=run.tone>{format!("{:.3}ms", report.p999)}</td>
+                                    <td class=run.tone title=format!("{:.0}× the median tick", report.spread())>{format!("{:.3}ms", report.max)}</td>
+                                    <td><Histogram bins=histogram(&samples, HISTOGRAM_BINS) tone=run.tone /></td>
+                                </tr>
+                            }
+                        }
+                        Err(reason) => view! {
+                            <tr>
+                                <td>{run.label}</td>
+                                <td colspan="5" class="warning">{format!("Not measured: {}", reason)}</td>
+                            </tr>
+                        },
+                    }).collect_view()}
+                </table>
+            </Show>
+            <p class="metrics-note">{move || format!(
+                "Per-tick latencies are raw (no outlier rejection - the outliers are the point). Timer resolution here ≈ {:.3}ms, so fast ticks quantize to 0; pauses above it are real. WASM and JS run in a Web Worker, Python on the main thread.",
+                timer_resolution_ms()
+            )}</p>
+        </div>
+    }
+}
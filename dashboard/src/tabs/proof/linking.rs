@@ -0,0 +1,59 @@
+// what: core-module linking demo model - a sensor module whose `read` export satisfies a filter module's `sensor.read` import, three ways
+// why: isolation says what a module can't reach; this shows wiring independently built modules together and what a call across that link costs.
+//      these are plain core modules linked through WebAssembly.instantiate imports - no wit, no canonical abi, not component composition
+// relations: modules from demo/modules.rs (SENSOR_CORE_WASM, FILTER_CORE_WASM); linked and timed by linking_panel.rs
+
+use crate::tabs::demo::modules::CHAIN_TICKS;
+
+/// how the filter's `sensor.read` import is satisfied
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Link {
+    /// sensor and filter compiled into one module: every read is an internal call
+    Fused,
+    /// the sensor instance's `read` export passed straight in as the filter's import
+    Direct,
+    /// a javascript function forwarding to `read`, the way a hand-written host shim would
+    JsGlue,
+}
+
+/// every wiring, in the order the panel shows them
+pub const LINKS: [Link; 3] = [Link::Fused, Link::Direct, Link::JsGlue];
+
+impl Link {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Link::Fused => "Fused (one module)",
+            Link::Direct => "Linked: export → import",
+            Link::JsGlue => "Linked through JS glue",
+        }
+    }
+
+    /// what one filtered reading crosses
+    pub fn path(&self) -> &'static str {
+        match self {
+            Link::Fused => "chain → read ×2, all inside one instance",
+            Link::Direct => "filter.chain → filter.filtered → sensor.read ×2, wasm to wasm",
+            Link::JsGlue => "filter.filtered → js → sensor.read ×2, two boundary hops per read",
+        }
+    }
+}
+
+/// the sensor module's `read`, in rust: centi-celsius between 2318 and 2381
+pub fn reference_read(tick: i32) -> i32 {
+    (tick.wrapping_mul(37) & 63).wrapping_add(2350).wrapping_sub(32)
+}
+
+/// what every wiring of `chain(n)` must return: the sum of two-tap averages from n down to 1
+pub fn reference_chain(n: i32) -> i32 {
+    (1..=n).fold(0i32, |acc, tick| acc.wrapping_add((reference_read(tick) + reference_read(tick - 1)) >> 1))
+}
+
+/// per filtered reading, from the time one chain(CHAIN_TICKS) call took
+pub fn per_call_ns(chain_ms: f64) -> f64 {
+    chain_ms * 1e6 / CHAIN_TICKS as f64
+}
+
+/// extra time per crossing of the module boundary: each filtered reading crosses it twice
+pub fn crossing_ns(fused_ns: f64, linked_ns: f64) -> f64 {
+    ((linked_ns - fused_ns) / 2.0).max(0.0)
+}
@@ -0,0 +1,173 @@
+// what: links the sensor core module into the filter core module in this browser and times the linked call chain against a fused build
+// why: shows module linking as a measured fact - two separately built modules wired export → import, with the cost of each wiring
+// relations: model in linking.rs, modules from demo/modules.rs, timing via measure/timing.rs sample(), imports traced by demo/host_proxy.rs;
+//            mounted in proof/component.rs
+
+use leptos::*;
+use wasm_bindgen::{JsCast, JsValue};
+use crate::measure::stats::Summary;
+use crate::measure::timing::sample;
+use crate::settings::use_settings;
+use crate::tabs::demo::host_proxy::{self, Traced};
+use crate::tabs::demo::host_trace::{use_host_trace, HostTrace};
+use crate::tabs::demo::modules::{CHAIN_TICKS, FILTER_CORE_WASM, SENSOR_CORE_WASM};
+use crate::tabs::demo::HostTracePanel;
+use super::linking::{crossing_ns, per_call_ns, reference_chain, Link, LINKS};
+
+/// module names in the shared host trace start with this
+const TRACE_PREFIX: &str = "linking/";
+
+fn js_error(e: JsValue) -> String {
+    format!("{:?}", e)
+}
+
+fn instantiate(bytes: &[u8], imports: &js_sys::Object) -> Result<js_sys::Object, String> {
+    let module = js_sys::WebAssembly::Module::new(&js_sys::Uint8Array::from(bytes)).map_err(js_error)?;
+    Ok(js_sys::WebAssembly::Instance::new(&module, imports).map_err(js_error)?.exports())
+}
+
+fn export_fn(exports: &js_sys::Object, name: &str) -> Result<js_sys::Function, String> {
+    js_sys::Reflect::get(exports, &name.into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+        .ok_or_else(|| format!("missing export {}", name))
+}
+
+/// fresh instances wired as `link` says; returns the `chain` export to call and, when traced, the host trace of the module that imports
+fn wire(link: Link, traced: bool) -> Result<(js_sys::Function, Option<Traced>), String> {
+    let load = |name: String, bytes: &[u8], imports: &js_sys::Object| -> Result<(js_sys::Object, Option<Traced>), String> {
+        if traced {
+            let instance = host_proxy::instantiate(&name, bytes, imports)?;
+            Ok((instance.exports.clone(), Some(instance)))
+        } else {
+            Ok((instantiate(bytes, imports)?, None))
+        }
+    };
+    let read = match link {
+        Link::Fused => {
+            let (sensor, trace) = load(format!("{}sensor [{}]", TRACE_PREFIX, link.label()), SENSOR_CORE_WASM, &js_sys::Object::new())?;
+            return Ok((export_fn(&sensor, "chain")?, trace));
+        }
+        Link::Direct | Link::JsGlue => export_fn(&instantiate(SENSOR_CORE_WASM, &js_sys::Object::new())?, "read")?,
+    };
+    let import: JsValue = if link == Link::JsGlue {
+        js_sys::Function::new_with_args("read", "return (tick) => read(tick);")
+            .call1(&JsValue::NULL, &read)
+            .map_err(js_error)?
+    } else {
+        read.into()
+    };
+    // { sensor: { read } } - the filter's only import
+    let namespace = js_sys::Object::new();
+    js_sys::Reflect::set(&namespace, &"read".into(), &import).map_err(js_error)?;
+    let imports = js_sys::Object::new();
+    js_sys::Reflect::set(&imports, &"sensor".into(), &namespace).map_err(js_error)?;
+    let (filter, trace) = load(format!("{}filter [{}]", TRACE_PREFIX, link.label()), FILTER_CORE_WASM, &imports)?;
+    Ok((export_fn(&filter, "chain")?, trace))
+}
+
+/// one wiring: chain(CHAIN_TICKS) timing and the value it returned
+#[derive(Clone, Debug, PartialEq)]
+struct Row {
+    link: Link,
+    result: Result<(Summary, i32), String>,
+}
+
+async fn measure(link: Link, iterations: u32, host_trace: RwSignal<HostTrace>) -> Result<(Summary, i32), String> {
+    let arg = JsValue::from(CHAIN_TICKS);
+    // one traced call checks the result and records what the module asked of its host
+    let (traced_chain, traced) = wire(link, true)?;
+    let value = traced_chain.call1(&JsValue::NULL, &arg).map_err(js_error)?.as_f64().ok_or("chain returned no number")? as i32;
+    if let Some(instance) = traced {
+        host_trace.update(|t| t.load(instance.snapshot()));
+    }
+    // timing runs on an untraced wiring so the proxies stay out of the numbers
+    let (chain, _) = wire(link, false)?;
+    let (summary, _) = sample(iterations, || {
+        let _ = chain.call1(&JsValue::NULL, &arg);
+        async {}
+    })
+    .await;
+    Ok((summary, value))
+}
+
+#[component]
+pub fn LinkingDemo() -> impl IntoView {
+    let settings = use_settings();
+    let host_trace = use_host_trace();
+    let (running, set_running) = create_signal(false);
+    let rows = create_rw_signal(Vec::<Row>::new());
+    let expected = reference_chain(CHAIN_TICKS);
+
+    let run = move |_| {
+        if running.get() { return; }
+        set_running.set(true);
+        rows.set(Vec::new());
+        let iterations = settings.with_untracked(|s| s.benchmark_iterations);
+        spawn_local(async move {
+            for link in LINKS {
+                let result = measure(link, iterations, host_trace).await;
+                tracing::info!(target: "measure", ?link, ok = result.is_ok(), "linking chain");
+                rows.update(|r| r.push(Row { link, result }));
+            }
+            set_running.set(false);
+        });
+    };
+    // per-crossing cost needs the fused row as its baseline
+    let fused_ns = move || rows.with(|r| r.iter().find(|row| row.link == Link::Fused).and_then(|row| row.result.as_ref().ok()).map(|(s, _)| per_call_ns(s.median)));
+
+    view! {
+        <div class="measured-metrics linking-demo">
+            <h3>"🧩 Core-Module Linking"</h3>
+            <p class="section-desc">{format!(
+                "A sensor module exports read(tick); a separately built filter module imports sensor.read and exports a two-tap moving average. Both are linked here, in your browser, and chain({}) runs {} filtered readings across the link.",
+                CHAIN_TICKS, CHAIN_TICKS
+            )}</p>
+            <button class="action-btn simulation-btn" disabled=move || running.get() on:click=run>
+                {move || if running.get() { "⏳ Linking and timing..." } else { "▶️ Link & Time" }}
+            </button>
+            <Show when=move || rows.with(|r| !r.is_empty())>
+                <table>
+                    <tr>
+                        <th>"Wiring"</th>
+                        <th>"Call path"</th>
+                        <th>"Per reading"</th>
+                        <th>"Per boundary crossing"</th>
+                        <th>"Result"</th>
+                    </tr>
+                    {move || rows.get().into_iter().map(|row| match row.result {
+                        Ok((summary, value)) => {
+                            let ns = per_call_ns(summary.median);
+                            let crossing = match (row.link, fused_ns()) {
+                                (Link::Fused, _) => "—".to_string(),
+                                (_, Some(fused)) => format!("+{:.1}ns", crossing_ns(fused, ns)),
+                                (_, None) => "no baseline".to_string(),
+                            };
+                            view! {
+                                <tr>
+                                    <td>{row.link.label()}</td>
+                                    <td class="metrics-note">{row.link.path()}</td>
+                                    <td title=summary.detail()>{format!("{:.1}ns", ns)}</td>
+                                    <td>{crossing}</td>
+                                    <td class={if value == expected { "success" } else { "warning" }}>
+                                        {if value == expected { "✓ matches".to_string() } else { format!("✗ {} ≠ {}", value, expected) }}
+                                    </td>
+                                </tr>
+                            }
+                        }
+                        Err(reason) => view! {
+                            <tr>
+                                <td>{row.link.label()}</td>
+                                <td colspan="4" class="warning">{format!("Not linked: {}", reason)}</td>
+                            </tr>
+                        },
+                    }).collect_view()}
+                </table>
+            </Show>
+            <HostTracePanel trace=host_trace only=TRACE_PREFIX />
+            <p class="metrics-note">
+                "Plain core modules linked through WebAssembly.instantiate imports: no WIT worlds and no canonical ABI, so this is not component composition. Only i32 crosses the link; composed components add lifting and lowering adapters, which are not measured here. Timed on the main thread, one chain call per sample."
+            </p>
+        </div>
+    }
+}
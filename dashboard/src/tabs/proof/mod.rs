@@ -1,8 +1,25 @@
 // what: proof tab module
-// why: organizes the proof, benchmarking, and ota comparison components
-// relations: exports Proof component to tabs/mod.rs, ota_simulator used internally
+// why: organizes the proof, benchmarking (bench.rs, also run on page load by autobench/), binary size, build pipeline, startup waterfall, ota comparison, sil calculator, downtime vs fault rate model, container baseline, core-module linking demo and instance checkpoint/restore
+// relations: exports Proof component to tabs/mod.rs, ota_simulator, jitter, size_inspector, build_variants, startup_waterfall, sil (+ sil_panel.rs), downtime (+ downtime_panel.rs), container_baseline, linking (+ linking_panel.rs) and checkpoint (+ checkpoint_panel.rs) used internally
 
 mod component;
-mod ota_simulator;
+pub mod bench;
+pub mod ota_simulator;
+mod jitter;
+mod size_inspector;
+mod build_variants;
+mod startup_waterfall;
+mod sil;
+mod sil_panel;
+mod downtime;
+mod downtime_panel;
+mod container_baseline;
+mod linking;
+mod linking_panel;
+mod checkpoint;
+mod checkpoint_panel;
 
 pub use component::Proof;
+
+#[cfg(test)]
+mod tests;
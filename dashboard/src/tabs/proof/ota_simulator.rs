@@ -1,6 +1,7 @@
 // what: ota update bandwidth comparison simulator
 // why: demonstrates the key business case for wasm - bandwidth savings on ota updates
-// relations: used by proof/component.rs, imported via proof/mod.rs
+// relations: used by proof/component.rs, imported via proof/mod.rs; download-time helpers shared with build_variants.rs,
+//            links and module size reused by the hardware tab's design wizard (hardware/wizard.rs)
 
 use leptos::*;
 
@@ -11,7 +12,7 @@ use leptos::*;
 // network speeds in mbps
 const ETHERNET_SPEED_MBPS: f64 = 100.0;
 const CELLULAR_SPEED_MBPS: f64 = 10.0;
-const SATELLITE_SPEED_MBPS: f64 = 1.0;
+pub(super) const SATELLITE_SPEED_MBPS: f64 = 1.0;
 
 // network costs per MB in USD
 const ETHERNET_COST_PER_MB: f64 = 0.001;
@@ -20,13 +21,13 @@ const SATELLITE_COST_PER_MB: f64 = 10.0;
 
 // update sizes in MB
 const DOCKER_UPDATE_SIZE_MB: f64 = 50.0;  // minimal alpine + python app
-const WASM_UPDATE_SIZE_MB: f64 = 0.05;    // 50KB compiled rust module
+pub const WASM_UPDATE_SIZE_MB: f64 = 0.05;    // 50KB compiled rust module
 
 // ============================================================================
 // network type enum
 // ============================================================================
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum NetworkType {
     Ethernet,
     Cellular,
@@ -34,7 +35,17 @@ pub enum NetworkType {
 }
 
 impl NetworkType {
-    fn speed_mbps(&self) -> f64 {
+    pub const ALL: [NetworkType; 3] = [NetworkType::Ethernet, NetworkType::Cellular, NetworkType::Satellite];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NetworkType::Ethernet => "Ethernet (100 Mbps)",
+            NetworkType::Cellular => "Cellular 4G (10 Mbps)",
+            NetworkType::Satellite => "Satellite (1 Mbps)",
+        }
+    }
+
+    pub fn speed_mbps(&self) -> f64 {
         match self {
             NetworkType::Ethernet => ETHERNET_SPEED_MBPS,
             NetworkType::Cellular => CELLULAR_SPEED_MBPS,
@@ -42,7 +53,7 @@ impl NetworkType {
         }
     }
     
-    fn cost_per_mb(&self) -> f64 {
+    pub fn cost_per_mb(&self) -> f64 {
         match self {
             NetworkType::Ethernet => ETHERNET_COST_PER_MB,
             NetworkType::Cellular => CELLULAR_COST_PER_MB,
@@ -56,14 +67,14 @@ impl NetworkType {
 // ============================================================================
 
 /// calculates download time in seconds for given size (MB) and speed (Mbps)
-fn calc_download_time_secs(size_mb: f64, speed_mbps: f64) -> f64 {
+pub fn calc_download_time_secs(size_mb: f64, speed_mbps: f64) -> f64 {
     // MB to Mb = multiply by 8
     let size_mbits = size_mb * 8.0;
     size_mbits / speed_mbps
 }
 
 /// formats time in human readable format
-fn format_time(secs: f64) -> String {
+pub fn format_time(secs: f64) -> String {
     if secs < 1.0 {
         format!("{:.0}ms", secs * 1000.0)
     } else if secs < 60.0 {
@@ -76,7 +87,7 @@ fn format_time(secs: f64) -> String {
 }
 
 /// formats currency
-fn format_currency(amount: f64) -> String {
+pub fn format_currency(amount: f64) -> String {
     if amount < 1.0 {
         format!("${:.2}", amount)
     } else if amount < 1000.0 {
@@ -0,0 +1,115 @@
+// what: simplified iec 61508-6 pfdavg and availability for 1oo1 and 2oo3, fed by the measured detection/recovery times
+// why: turns "recovers in microseconds" into the numbers a safety engineer asks about, and shows how little of pfd that is
+// relations: rendered by sil_panel.rs; reuses the demo tab's Architecture
+
+use crate::tabs::demo::architecture::Architecture;
+
+/// hours in one year (default proof-test interval)
+pub const HOURS_PER_YEAR: f64 = 8760.0;
+/// architectures the calculator offers (1oo2D needs its own formula set)
+pub const SIL_ARCHITECTURES: [Architecture; 2] = [Architecture::OneOoOne, Architecture::TwoOoThree];
+
+/// user-entered rates and intervals, per channel
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SilInputs {
+    /// dangerous failure rate λD (per hour)
+    pub lambda_d: f64,
+    /// safe failure rate λS (per hour)
+    pub lambda_s: f64,
+    /// diagnostic coverage of dangerous failures (0..1)
+    pub dc: f64,
+    /// common-cause factor β for undetected failures (0..1); βD is taken as β/2
+    pub beta: f64,
+    /// proof-test interval T1 (hours)
+    pub proof_test_h: f64,
+}
+
+impl Default for SilInputs {
+    fn default() -> Self {
+        Self { lambda_d: 1e-6, lambda_s: 2e-6, dc: 0.9, beta: 0.1, proof_test_h: HOURS_PER_YEAR }
+    }
+}
+
+impl SilInputs {
+    /// clamps fractions into 0..1 and rates/intervals to non-negative
+    pub fn clamped(self) -> Self {
+        Self {
+            lambda_d: self.lambda_d.max(0.0),
+            lambda_s: self.lambda_s.max(0.0),
+            dc: self.dc.clamp(0.0, 1.0),
+            beta: self.beta.clamp(0.0, 1.0),
+            proof_test_h: self.proof_test_h.max(0.0),
+        }
+    }
+}
+
+/// pfdavg, availability and sil band for one architecture and mttr
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SilResult {
+    pub pfd_avg: f64,
+    /// long-run fraction of time the process is stopped
+    pub unavailability: f64,
+    pub sil: Option<u8>,
+}
+
+impl SilResult {
+    pub fn availability(&self) -> f64 {
+        1.0 - self.unavailability
+    }
+
+    /// expected process downtime per year (seconds)
+    pub fn downtime_per_year_s(&self) -> f64 {
+        self.unavailability * HOURS_PER_YEAR * 3600.0
+    }
+}
+
+/// milliseconds to hours
+pub fn ms_to_hours(ms: f64) -> f64 {
+    ms / 3_600_000.0
+}
+
+/// low-demand sil band for a pfdavg (None when worse than sil 1; below 1e-5 still reads sil 4)
+pub fn sil_band(pfd_avg: f64) -> Option<u8> {
+    match pfd_avg {
+        p if p < 1e-4 => Some(4),
+        p if p < 1e-3 => Some(3),
+        p if p < 1e-2 => Some(2),
+        p if p < 1e-1 => Some(1),
+        _ => None,
+    }
+}
+
+/// iec 61508-6 b.3.2.2 with mrt = mttr; mttr is detection plus recovery (hours). anything but 2oo3 uses the 1oo1 formula
+pub fn compute(architecture: Architecture, inputs: SilInputs, mttr_h: f64) -> SilResult {
+    let SilInputs { lambda_d, lambda_s, dc, beta, proof_test_h: t1 } = inputs.clamped();
+    let mttr_h = mttr_h.max(0.0);
+    let lambda_dd = dc * lambda_d;
+    let lambda_du = (1.0 - dc) * lambda_d;
+    let beta_d = beta / 2.0;
+    // per-channel probability of being down at a given moment (trips and restarts after safe or detected faults)
+    let q = (lambda_s + lambda_dd) * mttr_h;
+    let (pfd_avg, unavailability) = match architecture {
+        Architecture::TwoOoThree => {
+            let (t_ce, t_ge) = if lambda_d > 0.0 {
+                (
+                    lambda_du / lambda_d * (t1 / 2.0 + mttr_h) + lambda_dd / lambda_d * mttr_h,
+                    lambda_du / lambda_d * (t1 / 3.0 + mttr_h) + lambda_dd / lambda_d * mttr_h,
+                )
+            } else {
+                (0.0, 0.0)
+            };
+            let independent = (1.0 - beta_d) * lambda_dd + (1.0 - beta) * lambda_du;
+            let pfd = 6.0 * independent.powi(2) * t_ce * t_ge
+                + beta_d * lambda_dd * mttr_h
+                + beta * lambda_du * (t1 / 2.0 + mttr_h);
+            // a single channel is outvoted; the process stops on two at once or a common cause
+            (pfd, 3.0 * q.powi(2) + beta * q)
+        }
+        _ => (lambda_du * (t1 / 2.0 + mttr_h) + lambda_dd * mttr_h, q),
+    };
+    SilResult {
+        pfd_avg,
+        unavailability: unavailability.min(1.0),
+        sil: sil_band(pfd_avg),
+    }
+}
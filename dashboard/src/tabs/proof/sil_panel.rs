@@ -0,0 +1,104 @@
+// what: illustrative sil/pfd calculator fed by the measured restart times and the simulated raft failover
+// why: shows where the fast restart lands in pfdavg and availability terms, with the assumptions spelled out
+// relations: renders sil.rs compute(); fed by component.rs measurements and demo/raft.rs ElectionBench
+
+use leptos::*;
+use crate::measure::stats::Summary;
+use crate::tabs::demo::architecture::Architecture;
+use crate::tabs::demo::raft::{ElectionBench, ELECTION_SEED, ELECTION_TRIALS};
+use super::sil::{compute, ms_to_hours, SilInputs, SilResult, SIL_ARCHITECTURES};
+
+fn sil_label(result: &SilResult) -> String {
+    match result.sil {
+        Some(level) => format!("SIL {}", level),
+        None => "below SIL 1".to_string(),
+    }
+}
+
+/// one labelled number input bound to a field of the inputs signal
+fn rate_input(label: &'static str, step: &'static str, inputs: RwSignal<SilInputs>, get: fn(&SilInputs) -> f64, set: fn(&mut SilInputs, f64)) -> impl IntoView {
+    view! {
+        <label class="voting-tolerance">
+            {label}
+            <input
+                type="number"
+                min="0"
+                step=step
+                prop:value=move || inputs.with(|i| get(i).to_string())
+                on:change=move |ev| {
+                    if let Ok(v) = event_target_value(&ev).parse::<f64>() {
+                        inputs.update(|i| {
+                            set(i, v);
+                            *i = i.clamped();
+                        });
+                    }
+                }
+            />
+        </label>
+    }
+}
+
+#[component]
+pub fn SilCalculator(ran: Signal<bool>, wasm_recovery_ms: Signal<Summary>, python_coldstart_ms: Signal<Summary>) -> impl IntoView {
+    let inputs = create_rw_signal(SilInputs::default());
+    let (architecture, set_architecture) = create_signal(Architecture::TwoOoThree);
+    // detection: the simulated raft failover p99 stands in for "noticed and a new leader is serving"
+    let detection_ms = store_value(ElectionBench::run(ELECTION_TRIALS, ELECTION_SEED).p99_ms);
+    let rows = move || {
+        let detect = detection_ms.get_value();
+        [("WASM restart", wasm_recovery_ms.get().median), ("Python restart", python_coldstart_ms.get().median)]
+            .map(|(label, recovery)| (label, detect + recovery, compute(architecture.get(), inputs.get(), ms_to_hours(detect + recovery))))
+    };
+
+    view! {
+        <div class="sil-calculator">
+            <h3>"🧮 Safety Integrity (Illustrative)"</h3>
+            <p class="sil-disclaimer">
+                "⚠️ Illustrative only. Simplified IEC 61508-6 low-demand formulas with textbook defaults and browser-measured times. Not a SIL assessment: real figures need certified failure data, a full FMEDA and an assessor."
+            </p>
+            <div class="voting-controls">
+                <select class="network-select" on:change=move |ev| if let Some(a) = Architecture::from_id(&event_target_value(&ev)) { set_architecture.set(a) }>
+                    {SIL_ARCHITECTURES.into_iter().map(|a| view! { <option value=a.id() selected=move || architecture.get() == a>{a.label()}</option> }).collect_view()}
+                </select>
+                {rate_input("λD /h", "any", inputs, |i| i.lambda_d, |i, v| i.lambda_d = v)}
+                {rate_input("λS /h", "any", inputs, |i| i.lambda_s, |i, v| i.lambda_s = v)}
+                {rate_input("DC", "0.05", inputs, |i| i.dc, |i, v| i.dc = v)}
+                {rate_input("β", "0.01", inputs, |i| i.beta, |i, v| i.beta = v)}
+                {rate_input("T1 h", "24", inputs, |i| i.proof_test_h, |i, v| i.proof_test_h = v)}
+            </div>
+            {move || if ran.get() {
+                view! {
+                    <table class="capability-matrix voting-matrix sil-matrix">
+                        <tr>
+                            <th>"Recovery"</th>
+                            <th>"MTTR (detect + restart)"</th>
+                            <th>"PFDavg"</th>
+                            <th>"Band"</th>
+                            <th>"Availability"</th>
+                            <th>"Downtime / yr"</th>
+                        </tr>
+                        {rows().into_iter().map(|(label, mttr_ms, result)| view! {
+                            <tr>
+                                <td class="world-name">{label}</td>
+                                <td>{format!("{:.1}ms", mttr_ms)}</td>
+                                <td>{format!("{:.2e}", result.pfd_avg)}</td>
+                                <td>{sil_label(&result)}</td>
+                                <td>{format!("{:.7}%", result.availability() * 100.0)}</td>
+                                <td>{format!("{:.3}s", result.downtime_per_year_s())}</td>
+                            </tr>
+                        }).collect_view()}
+                    </table>
+                }.into_view()
+            } else {
+                view! { <p class="section-desc">"Run the simulation above to feed in the measured restart times."</p> }.into_view()
+            }}
+            <p class="metrics-note">
+                {move || format!(
+                    "Detection uses the simulated Raft failover p99 ({:.0}ms). With restarts this fast, PFDavg is dominated by undetected failures waiting for the {:.0}h proof test and by common cause (β), not by recovery time.",
+                    detection_ms.get_value(),
+                    inputs.with(|i| i.proof_test_h),
+                )}
+            </p>
+        </div>
+    }
+}
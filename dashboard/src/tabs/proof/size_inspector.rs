@@ -0,0 +1,131 @@
+// what: binary size inspector - fetches the served wasm bundle (or takes an embedded demo module), parses its sections, draws a treemap
+// why: the size row in the proof table should be a measurement of this build, not a number typed into the source
+// relations: parses with measure/binary.rs, fetches via demo/wasm.rs fetch_bytes, sizes from diagnostics/about.rs; used by proof/component.rs
+
+use leptos::*;
+use crate::diagnostics::about::{format_bytes, pyodide_download_bytes, wasm_binary_url};
+use crate::measure::binary::{parse_sections, treemap, WasmLayout, WASM_HEADER_BYTES};
+use crate::tabs::demo::modules::{BOUNDS_WASM, GROW_WASM, RECURSE_WASM, TICK_WASM};
+use crate::tabs::demo::wasm::{fetch_bytes, MINIMAL_WASM};
+
+/// treemap box (svg user units)
+const MAP_W: f64 = 600.0;
+const MAP_H: f64 = 220.0;
+/// tiles narrower than this get no label
+const LABEL_MIN_W: f64 = 60.0;
+/// tile colour classes, cycled
+const TILE_CLASSES: usize = 6;
+
+/// modules the inspector can open; None is the app bundle fetched from the server
+const MODULES: [(&str, Option<&[u8]>); 6] = [
+    ("Dashboard bundle (fetched)", None),
+    ("Jitter tick module", Some(TICK_WASM)),
+    ("Callable add module", Some(MINIMAL_WASM)),
+    ("Memory grow module", Some(GROW_WASM)),
+    ("Bounds check module", Some(BOUNDS_WASM)),
+    ("Recursion module", Some(RECURSE_WASM)),
+];
+
+async fn load(index: usize) -> Result<WasmLayout, String> {
+    match MODULES[index].1 {
+        Some(bytes) => parse_sections(bytes),
+        None => {
+            let url = wasm_binary_url().ok_or("no resource timing entry for the app's wasm")?;
+            parse_sections(&fetch_bytes(&url).await?)
+        }
+    }
+}
+
+fn treemap_view(layout: &WasmLayout) -> impl IntoView {
+    let mut parts = layout.by_name();
+    parts.push(("header".to_string(), WASM_HEADER_BYTES));
+    let sizes: Vec<u64> = parts.iter().map(|(_, b)| *b as u64).collect();
+    let total = layout.total.max(1) as f64;
+    let tiles = treemap(&sizes, MAP_W, MAP_H).into_iter().map(|t| {
+        let (name, bytes) = &parts[t.index];
+        let title = format!("{}: {} bytes ({:.1}%)", name, bytes, *bytes as f64 / total * 100.0);
+        let label = (t.w >= LABEL_MIN_W && t.h >= 24.0).then(|| view! {
+            <text x=format!("{:.1}", t.x + 4.0) y=format!("{:.1}", t.y + 14.0)>{name.clone()}</text>
+            <text class="treemap-size" x=format!("{:.1}", t.x + 4.0) y=format!("{:.1}", t.y + 26.0)>{format_bytes(*bytes as f64)}</text>
+        });
+        view! {
+            <g>
+                <rect class=format!("treemap-tile treemap-{}", t.index % TILE_CLASSES)
+                    x=format!("{:.1}", t.x) y=format!("{:.1}", t.y) width=format!("{:.1}", t.w) height=format!("{:.1}", t.h)>
+                    <title>{title}</title>
+                </rect>
+                {label}
+            </g>
+        }
+    }).collect_view();
+    view! {
+        <svg class="size-treemap" viewBox=format!("0 0 {} {}", MAP_W, MAP_H) preserveAspectRatio="none">{tiles}</svg>
+        <table class="capability-matrix size-table">
+            <tr><th>"Section"</th><th>"Bytes"</th><th>"Share"</th></tr>
+            {parts.iter().map(|(name, bytes)| view! {
+                <tr>
+                    <td class="world-name">{name.clone()}</td>
+                    <td>{bytes.to_string()}</td>
+                    <td>{format!("{:.1}%", *bytes as f64 / total * 100.0)}</td>
+                </tr>
+            }).collect_view()}
+        </table>
+    }
+}
+
+#[component]
+pub fn SizeInspector() -> impl IntoView {
+    let (selected, set_selected) = create_signal(0usize);
+    let (loading, set_loading) = create_signal(false);
+    let layout = create_rw_signal(Option::<Result<WasmLayout, String>>::None);
+
+    let inspect = move |_| {
+        if loading.get_untracked() { return; }
+        set_loading.set(true);
+        let index = selected.get_untracked();
+        wasm_bindgen_futures::spawn_local(async move {
+            layout.set(Some(load(index).await));
+            set_loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="size-inspector">
+            <h3>"📦 Binary Size Inspector"</h3>
+            <p class="section-desc">"Parses the section table of the wasm this page actually loaded (or one of the demo's embedded modules) and shows where the bytes go."</p>
+            <div class="voting-controls">
+                <select class="network-select" on:change=move |ev| {
+                    set_selected.set(event_target_value(&ev).parse().unwrap_or(0));
+                    layout.set(None);
+                }>
+                    {MODULES.iter().enumerate().map(|(i, (name, _))| view! { <option value=i.to_string()>{*name}</option> }).collect_view()}
+                </select>
+                <button class="action-btn" disabled=move || loading.get() on:click=inspect>
+                    {move || if loading.get() { "⏳ Fetching..." } else { "🔍 Inspect" }}
+                </button>
+            </div>
+            {move || layout.with(|l| match l {
+                None => view! { <p class="metrics-note">"Pick a module and inspect it."</p> }.into_view(),
+                Some(Err(e)) => view! { <p class="metrics-note">{format!("⚠️ {}", e)}</p> }.into_view(),
+                Some(Ok(layout)) => {
+                    let summary = format!(
+                        "{} total · {} sections · {} in custom sections",
+                        format_bytes(layout.total as f64),
+                        layout.sections.len(),
+                        format_bytes(layout.custom_bytes() as f64),
+                    );
+                    let versus = pyodide_download_bytes().map(|py| format!(
+                        "Pyodide downloaded {} in this session: {:.0}x the size.",
+                        format_bytes(py),
+                        py / layout.total.max(1) as f64,
+                    )).unwrap_or_else(|| "Run the simulation to load Pyodide and compare against its download size.".to_string());
+                    view! {
+                        <p class="size-summary">{summary}</p>
+                        {treemap_view(layout)}
+                        <p class="metrics-note">{versus}</p>
+                    }.into_view()
+                }
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,42 @@
+// what: waterfall of this dashboard's own startup phases from performance marks and resource timing
+// why: the cold-start argument, applied to the app making it - the wasm boot next to the pyodide load it is compared with
+// relations: reads diagnostics/startup.rs collect(); used by proof/component.rs
+
+use leptos::*;
+use crate::diagnostics::startup::{collect, waterfall, StartupPhase};
+
+#[component]
+pub fn StartupWaterfall() -> impl IntoView {
+    // resource timing and marks only grow, so a manual refresh is enough
+    let (spans, set_spans) = create_signal(collect());
+    let missing = move || spans.with(|s| StartupPhase::ALL.into_iter().filter(|p| !s.iter().any(|span| span.phase == *p)).map(|p| p.label()).collect::<Vec<_>>());
+
+    view! {
+        <div class="startup-waterfall">
+            <h3>"⏱️ This Page's Own Cold Start"</h3>
+            <p class="section-desc">"Boot phases of the dashboard you are looking at, in ms since navigation start (performance marks prefixed guardian: in devtools)."</p>
+            <div class="waterfall-rows">
+                {move || spans.with(|s| waterfall(s)).into_iter().map(|row| view! {
+                    <div class="waterfall-row">
+                        <span class="waterfall-label">{row.span.phase.label()}</span>
+                        <div class="waterfall-track">
+                            <div
+                                class="waterfall-bar"
+                                class:waterfall-python=row.span.phase == StartupPhase::PyodideLoad
+                                style=format!("margin-left: {:.2}%; width: {:.2}%", row.offset_pct, row.width_pct.max(0.3))
+                            ></div>
+                        </div>
+                        <span class="waterfall-time">{format!("{:.1}ms", row.span.end_ms - row.span.start_ms)}</span>
+                    </div>
+                }).collect_view()}
+            </div>
+            <div class="voting-controls">
+                <button class="action-btn" on:click=move |_| set_spans.set(collect())>"🔄 Refresh"</button>
+                <span class="metrics-note">{move || match missing() {
+                    m if m.is_empty() => "All phases recorded.".to_string(),
+                    m => format!("Not yet: {} (visit the Demo tab to load Pyodide and take the first measurement).", m.join(", ")),
+                }}</span>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,25 @@
+// what: tests for the debug / release / wasm-opt build variants panel
+// why: the panel fetches files the build script writes, so the two lists must agree
+
+use crate::tabs::proof::build_variants::{shrink_label, VARIANTS};
+
+const BUILD_SCRIPT: &str = include_str!("../../../../build-variants.sh");
+
+#[test]
+fn every_variant_is_built_by_the_script() {
+    // what: each VARIANTS file name is written by build-variants.sh, and the names are distinct
+    // why: a renamed artifact would show "not shipped" on the live site with no build error
+    for variant in VARIANTS {
+        assert!(BUILD_SCRIPT.contains(variant.file), "{} missing from build-variants.sh", variant.file);
+        assert_eq!(VARIANTS.iter().filter(|v| v.file == variant.file).count(), 1);
+    }
+}
+
+#[test]
+fn shrink_is_relative_to_the_debug_build() {
+    // what: the baseline reads "baseline", smaller builds read as a ratio, a missing baseline reads as a dash
+    // why: the "vs debug" column is the traceable version of the ota size claim
+    assert_eq!(shrink_label(Some(1000), 1000), "baseline");
+    assert_eq!(shrink_label(Some(1000), 80), "12.5x smaller");
+    assert_eq!(shrink_label(None, 80), "—");
+}
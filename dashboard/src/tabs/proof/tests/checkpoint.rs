@@ -0,0 +1,68 @@
+// what: tests for the checkpoint format, the module's reference state and the module's layout
+// why: the panel calls a restore identical only when it matches reference_state; a lossy format or wrong reference would hide a broken restore
+
+use crate::measure::binary::parse_sections;
+use crate::tabs::demo::modules::{CHECKPOINT_MAX_PAGES, CHECKPOINT_RING, CHECKPOINT_WASM};
+use crate::tabs::proof::checkpoint::{pages_for, reference_state, trimmed_len, Checkpoint, MAGIC, READINGS, VERSION};
+
+fn sample_checkpoint() -> Checkpoint {
+    let mut memory = vec![0u8; 2 * 65_536];
+    memory[..4].copy_from_slice(&[1, 2, 3, 4]);
+    memory[70_000] = 9;
+    Checkpoint { pages: 2, globals: vec![42, -7], memory }
+}
+
+#[test]
+fn round_trip_restores_every_byte_and_trims_the_zero_tail() {
+    // what: decode(encode(c)) == c, and the encoding stores memory only up to its last non-zero byte
+    // why: the restored instance must be byte-identical, and the snapshot size the panel reports is this trimmed length
+    let checkpoint = sample_checkpoint();
+    let bytes = checkpoint.encode();
+    assert_eq!(&bytes[..4], MAGIC);
+    assert_eq!(trimmed_len(&checkpoint.memory), 70_001);
+    assert_eq!(bytes.len(), 4 + 1 + 4 + 1 + 2 * 4 + 4 + 70_001);
+    assert_eq!(Checkpoint::decode(&bytes), Ok(checkpoint));
+    let empty = Checkpoint { pages: 1, globals: vec![], memory: vec![0; 65_536] };
+    assert_eq!(trimmed_len(&empty.memory), 0);
+    assert_eq!(Checkpoint::decode(&empty.encode()), Ok(empty));
+}
+
+#[test]
+fn decode_rejects_foreign_truncated_and_oversized_input() {
+    // what: bad magic, another version, a cut-off snapshot and more stored bytes than the pages hold are errors
+    // why: restoring a damaged snapshot would silently start an instance from the wrong state
+    let bytes = sample_checkpoint().encode();
+    assert!(Checkpoint::decode(b"\0asm\x01\0\0\0\0\0").unwrap_err().contains("magic"));
+    let mut other = bytes.clone();
+    other[4] = VERSION + 1;
+    assert!(Checkpoint::decode(&other).unwrap_err().contains("version"));
+    assert!(Checkpoint::decode(&bytes[..bytes.len() - 1]).unwrap_err().contains("truncated"));
+    let mut oversized = bytes;
+    oversized[5..9].copy_from_slice(&1u32.to_le_bytes());
+    assert!(Checkpoint::decode(&oversized).unwrap_err().contains("do not fit"));
+}
+
+#[test]
+fn reference_matches_the_module_state() {
+    // what: count, running sum and ring checksum match what the module returns after fill, and pages grow with the ring
+    // why: both the source and the restored instance are checked against this; the values were read from the module in node
+    assert_eq!(reference_state(1, 0), (1, 0, 0));
+    assert_eq!(reference_state(100_000, 7), (100_000, 51_146_192, 1_294_516_368));
+    assert_eq!(reference_state(300_000, 3), (300_000, 153_447_920, 389_939_200), "ring wrapped");
+    assert_eq!([pages_for(0), pages_for(1), pages_for(100_000), pages_for(300_000)], [1, 1, 7, CHECKPOINT_MAX_PAGES]);
+    assert_eq!(pages_for(CHECKPOINT_RING), CHECKPOINT_MAX_PAGES);
+    assert_eq!(READINGS.last(), Some(&CHECKPOINT_RING));
+}
+
+#[test]
+fn module_exports_memory_and_both_globals() {
+    // what: the module is self-contained and defines its own memory and globals, so a checkpoint can read and write all of its state
+    // why: state held in an import or an unexported global would be invisible to the snapshot and lost on restore
+    let names: Vec<String> = parse_sections(CHECKPOINT_WASM).unwrap().sections.into_iter().map(|s| s.name).collect();
+    assert_eq!(names, ["type", "function", "memory", "global", "export", "code"]);
+    for export in ["memory", "count", "acc", "record", "fill", "checksum"] {
+        let mut needle = vec![export.len() as u8];
+        needle.extend_from_slice(export.as_bytes());
+        assert!(CHECKPOINT_WASM.windows(needle.len()).any(|w| w == needle.as_slice()), "missing export {}", export);
+    }
+}
@@ -0,0 +1,58 @@
+// what: tests for the downtime vs fault rate model
+// why: the chart turns measured restart times into yearly downtime and break-even rates; the arithmetic must hold on paper
+
+use crate::tabs::proof::downtime::{
+    annual_downtime_s, break_even_rate, budget_s, curve, format_duration, log_position, rate_from_slider, slider_from_rate,
+    CURVE_POINTS, FAULT_RATE_MAX, FAULT_RATE_MIN, SLIDER_STEPS,
+};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= b.abs() * 1e-9
+}
+
+#[test]
+fn downtime_is_rate_times_restart() {
+    // what: 10 faults a day with a 2s restart is 10 × 365 × 2s = 7300s a year; no restart costs nothing
+    // why: the simplest case anyone can check on paper
+    assert!(close(annual_downtime_s(10.0, 2000.0), 7300.0));
+    assert_eq!(annual_downtime_s(10.0, 0.0), 0.0);
+    assert!(close(budget_s(0.99999), 315.36));
+}
+
+#[test]
+fn break_even_uses_up_the_budget_exactly() {
+    // what: at the break-even rate, restarts cost exactly the allowed downtime; the faster restart absorbs proportionally more faults
+    // why: the table and the chart dots are read as "this many faults a day before missing the target"
+    let budget = budget_s(0.9999);
+    let python = break_even_rate(budget, 1500.0).unwrap();
+    let wasm = break_even_rate(budget, 0.5).unwrap();
+    assert!(close(annual_downtime_s(python, 1500.0), budget));
+    assert!(close(wasm / python, 3000.0));
+    assert_eq!(break_even_rate(budget, 0.0), None, "no measurement, no break-even");
+}
+
+#[test]
+fn slider_and_axes_are_logarithmic() {
+    // what: slider ends are the range ends, the middle is the geometric mean, and positions round-trip
+    // why: fault rates span five decades; a linear slider would leave the interesting low end a few pixels wide
+    assert!(close(rate_from_slider(0), FAULT_RATE_MIN));
+    assert!(close(rate_from_slider(SLIDER_STEPS), FAULT_RATE_MAX));
+    assert!(close(rate_from_slider(SLIDER_STEPS / 2), (FAULT_RATE_MIN * FAULT_RATE_MAX).sqrt()));
+    assert_eq!(slider_from_rate(rate_from_slider(37)), 37);
+    assert_eq!(slider_from_rate(1e6), SLIDER_STEPS);
+    assert!(close(log_position(10.0, 1.0, 100.0), 0.5));
+    assert_eq!(log_position(0.0, 1.0, 100.0), 0.0);
+    let points = curve(1000.0);
+    assert_eq!(points.len(), CURVE_POINTS);
+    assert!(close(points[CURVE_POINTS - 1].0, FAULT_RATE_MAX));
+}
+
+#[test]
+fn durations_pick_a_readable_unit() {
+    // what: sub-second downtime in ms, then seconds, minutes and hours
+    // why: the same chart spans microseconds of wasm restarts and hours of python ones
+    assert_eq!(format_duration(0.0123), "12.3ms");
+    assert_eq!(format_duration(45.0), "45.0s");
+    assert_eq!(format_duration(600.0), "10.0min");
+    assert_eq!(format_duration(7300.0), "2.0h");
+}
@@ -0,0 +1,56 @@
+// what: tests for the core-module linking demo - the linked modules' layout and the rust reference they are checked against
+// why: the panel marks a wiring correct only when it matches reference_chain; a wrong reference or a module missing its import would hide a broken link
+
+use crate::measure::binary::parse_sections;
+use crate::tabs::demo::modules::{CHAIN_TICKS, FILTER_CORE_WASM, SENSOR_CORE_WASM};
+use crate::tabs::proof::linking::{crossing_ns, per_call_ns, reference_chain, reference_read, Link, LINKS};
+
+fn section_names(bytes: &[u8]) -> Vec<String> {
+    parse_sections(bytes).unwrap().sections.into_iter().map(|s| s.name).collect()
+}
+
+/// length-prefixed name as it appears in an import or export entry
+fn has_name(bytes: &[u8], name: &str) -> bool {
+    let mut needle = vec![name.len() as u8];
+    needle.extend_from_slice(name.as_bytes());
+    bytes.windows(needle.len()).any(|w| w == needle.as_slice())
+}
+
+#[test]
+fn only_the_filter_imports_and_both_export_chain() {
+    // what: the sensor module is self-contained; the filter's single import is sensor.read; both export chain
+    // why: linking means the filter cannot run until something supplies read - a stray local definition would fake the link
+    assert_eq!(section_names(SENSOR_CORE_WASM), ["type", "function", "export", "code"]);
+    assert_eq!(section_names(FILTER_CORE_WASM), ["type", "import", "function", "export", "code"]);
+    assert!(has_name(SENSOR_CORE_WASM, "read") && has_name(SENSOR_CORE_WASM, "chain"));
+    assert!(has_name(FILTER_CORE_WASM, "sensor") && has_name(FILTER_CORE_WASM, "read"));
+    assert!(has_name(FILTER_CORE_WASM, "filtered") && has_name(FILTER_CORE_WASM, "chain"));
+}
+
+#[test]
+fn reference_matches_the_module_arithmetic() {
+    // what: read stays in 2318..=2381 centi-celsius, spot values match the wasm, and chain is the sum of two-tap averages
+    // why: the browser compares every wiring's chain(CHAIN_TICKS) against this number
+    assert_eq!([reference_read(0), reference_read(1), reference_read(5)], [2318, 2355, 2375]);
+    assert!((0..1000).map(reference_read).all(|v| (2318..=2381).contains(&v)));
+    assert_eq!(reference_chain(1), (2355 + 2318) >> 1);
+    assert_eq!(reference_chain(CHAIN_TICKS), 23_489_976, "value the linked modules return in node and the browser");
+}
+
+#[test]
+fn per_reading_and_crossing_costs() {
+    // what: one chain call's milliseconds spread over CHAIN_TICKS readings; each reading crosses the boundary twice; never negative
+    // why: a fused run slower than a linked one (timer noise) must not print a negative link cost
+    assert!((per_call_ns(0.1) - 0.1 * 1e6 / CHAIN_TICKS as f64).abs() < 1e-9);
+    assert_eq!(crossing_ns(8.0, 14.0), 3.0);
+    assert_eq!(crossing_ns(9.0, 8.5), 0.0);
+}
+
+#[test]
+fn wirings_are_listed_fused_first() {
+    // what: the fused baseline comes first, then the direct link, then the js shim, each with its own label
+    // why: the crossing column is computed against the fused row, which must already be there
+    assert_eq!(LINKS, [Link::Fused, Link::Direct, Link::JsGlue]);
+    let labels: Vec<&str> = LINKS.iter().map(|l| l.label()).collect();
+    assert!(labels.iter().enumerate().all(|(i, l)| !labels[..i].contains(l)));
+}
@@ -0,0 +1,17 @@
+// what: exports all test modules for the proof tab
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod sil;
+
+#[cfg(test)]
+mod build_variants;
+
+#[cfg(test)]
+mod linking;
+
+#[cfg(test)]
+mod downtime;
+
+#[cfg(test)]
+mod checkpoint;
@@ -0,0 +1,58 @@
+// what: tests for the illustrative pfdavg / availability calculator
+// why: the numbers are labelled illustrative, but they must still match the textbook formulas they claim to use
+
+use crate::tabs::demo::architecture::Architecture;
+use crate::tabs::proof::sil::{compute, ms_to_hours, sil_band, SilInputs, HOURS_PER_YEAR};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= b.abs() * 1e-9
+}
+
+#[test]
+fn simplex_matches_hand_calculation() {
+    // what: 1oo1 with instant repair is λDU·T1/2 = 1e-7 × 4380h, sil 3
+    // why: the simplest case anyone can check on paper
+    let result = compute(Architecture::OneOoOne, SilInputs::default(), 0.0);
+    assert!(close(result.pfd_avg, 1e-7 * HOURS_PER_YEAR / 2.0));
+    assert_eq!(result.sil, Some(3));
+    assert_eq!(result.availability(), 1.0);
+}
+
+#[test]
+fn tmr_is_dominated_by_common_cause() {
+    // what: 2oo3 without common cause is orders of magnitude below simplex; with β=0.1 the β·λDU·T1/2 term dominates
+    // why: the note under the table claims β, not redundancy, sets the floor
+    let independent = compute(Architecture::TwoOoThree, SilInputs { beta: 0.0, ..SilInputs::default() }, 0.0);
+    let simplex = compute(Architecture::OneOoOne, SilInputs::default(), 0.0);
+    assert!(independent.pfd_avg < simplex.pfd_avg / 100.0);
+    let tmr = compute(Architecture::TwoOoThree, SilInputs::default(), 0.0);
+    let common_cause = 0.1 * 1e-7 * HOURS_PER_YEAR / 2.0;
+    assert!(tmr.pfd_avg > common_cause && tmr.pfd_avg < common_cause * 1.02);
+    assert_eq!(tmr.sil, Some(4));
+}
+
+#[test]
+fn restart_time_moves_downtime_not_pfd() {
+    // what: going from a 1ms to a 3s restart leaves 1oo1 pfd within 0.1% but multiplies downtime by 3000
+    // why: the honest reading of the measured restart times
+    let inputs = SilInputs::default();
+    let fast = compute(Architecture::OneOoOne, inputs, ms_to_hours(1.0));
+    let slow = compute(Architecture::OneOoOne, inputs, ms_to_hours(3000.0));
+    assert!((slow.pfd_avg - fast.pfd_avg) / fast.pfd_avg < 1e-3);
+    assert!(close(slow.downtime_per_year_s() / fast.downtime_per_year_s(), 3000.0));
+    // tmr rides through single-channel restarts
+    let tmr = compute(Architecture::TwoOoThree, inputs, ms_to_hours(3000.0));
+    assert!(tmr.downtime_per_year_s() < slow.downtime_per_year_s());
+}
+
+#[test]
+fn bands_and_clamping() {
+    // what: sil band edges, and out-of-range inputs clamp before use
+    // why: the panel's number inputs feed straight into compute
+    assert_eq!(sil_band(5e-5), Some(4));
+    assert_eq!(sil_band(1e-4), Some(3));
+    assert_eq!(sil_band(5e-2), Some(1));
+    assert_eq!(sil_band(0.2), None);
+    let clamped = SilInputs { dc: 1.5, beta: -0.2, lambda_d: -1.0, ..SilInputs::default() }.clamped();
+    assert_eq!((clamped.dc, clamped.beta, clamped.lambda_d), (1.0, 0.0, 0.0));
+}
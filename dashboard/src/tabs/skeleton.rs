@@ -0,0 +1,18 @@
+// what: shimmering placeholder shown while heavy tab content loads
+// why: the demo tab fetches pyodide and the attack-code module on first visit, the layout should not jump while it does
+// relations: used by demo/sensor_comparison.rs, demo/attack_controls.rs and demo/info_box.rs, styled by .skeleton in styles.css
+
+use leptos::*;
+
+/// loading placeholder with a label and a few shimmer bars
+#[component]
+pub fn Skeleton(label: &'static str, lines: usize) -> impl IntoView {
+    view! {
+        <div class="skeleton" aria-busy="true">
+            <p class="skeleton-label">{label}</p>
+            {(0..lines).map(|i| view! {
+                <div class="skeleton-bar" style:width=format!("{}%", 90usize.saturating_sub(i * 15).max(15))></div>
+            }).collect_view()}
+        </div>
+    }
+}
@@ -0,0 +1,38 @@
+// what: header follow-mode toggle with the tab's sync role (solo, control with n followers, display following a control tab)
+// why: with two tabs open it has to be obvious at a glance which one drives and which one mirrors
+// relations: reads and toggles mod.rs use_tab_sync(); rendered by lib.rs in the header
+
+use leptos::*;
+use super::{use_tab_sync, Role};
+
+#[component]
+pub fn FollowToggle() -> impl IntoView {
+    let sync = use_tab_sync();
+    let following = sync.following;
+
+    let detail = move || {
+        let peers = sync.peers.get();
+        match sync.role() {
+            Role::Solo if peers.count() == 0 => "no other console tab open".to_string(),
+            Role::Solo => format!("{} other tab(s), none following", peers.count()),
+            Role::Control => format!("driving {} display tab(s)", peers.followers()),
+            Role::Display => match peers.leader {
+                Some(leader) => format!("mirroring control tab #{:04x}", leader & 0xffff),
+                None => "waiting for a control tab".to_string(),
+            },
+        }
+    };
+
+    view! {
+        <div class="speed-control sync-control" title="Follow mode: this tab mirrors the active tab and demo state of another console tab in this browser (open a tab with #follow to start in follow mode). Local clicks are overwritten by the control tab's next change.">
+            <button
+                class="network-select"
+                class:active=move || following.get()
+                on:click=move |_| following.update(|f| *f = !*f)
+            >
+                {move || if following.get() { "📺 Following" } else { "📺 Follow" }}
+            </button>
+            <span class="demo-badge" title=detail>{move || sync.role().label()}</span>
+        </div>
+    }
+}
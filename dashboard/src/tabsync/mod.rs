@@ -0,0 +1,264 @@
+// what: multi-tab sync over a BroadcastChannel - a control tab drives the active tab and demo state of tabs in follow mode
+// why: presenters run a presenter screen and a confidence monitor from one browser; the monitor has to mirror the clicks, not repeat them
+// relations: installed and provided by lib.rs App (active tab), demo state published and applied by tabs/demo/component.rs
+//            (snapshot.rs DemoSnapshot); follow toggle and role indicator in badge.rs (header)
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use crate::tabs::demo::snapshot::DemoSnapshot;
+
+mod badge;
+
+#[cfg(test)]
+mod tests;
+
+pub use badge::FollowToggle;
+
+/// channel every console tab of this origin joins
+pub const CHANNEL_NAME: &str = "guardian-one.sync";
+/// url fragment that opens a tab in follow mode: https://host/#follow
+pub const FOLLOW_FRAGMENT: &str = "#follow";
+/// state changes closer together than this go out as one message
+pub const STATE_DEBOUNCE_MS: u64 = 150;
+
+thread_local! {
+    static CHANNEL: RefCell<Option<web_sys::BroadcastChannel>> = const { RefCell::new(None) };
+}
+
+// ============================================================================
+// protocol (pure, shared with tests)
+// ============================================================================
+
+/// what tabs tell each other; `from` is the sending tab's random id
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SyncMessage {
+    /// sent on open and on every follow-mode change; tabs answer a fresh hello with a reply
+    Hello { from: u32, following: bool, reply: bool },
+    /// the tab is closing
+    Bye { from: u32 },
+    /// the control tab switched to this tab (lib.rs Tab::id)
+    Tab { from: u32, tab: String },
+    /// the control tab's demo state, as a snapshot.rs json blob
+    State { from: u32, snapshot: String },
+}
+
+impl SyncMessage {
+    pub fn from(&self) -> u32 {
+        match self {
+            SyncMessage::Hello { from, .. } | SyncMessage::Bye { from } | SyncMessage::Tab { from, .. } | SyncMessage::State { from, .. } => *from,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+}
+
+/// what this tab is doing relative to the others
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// no other tab follows this one
+    Solo,
+    /// at least one tab follows this one
+    Control,
+    /// this tab mirrors a control tab
+    Display,
+}
+
+impl Role {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Role::Solo => "🖥️ Solo",
+            Role::Control => "🎛️ Control",
+            Role::Display => "📺 Display",
+        }
+    }
+}
+
+/// the other open tabs as last announced
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Peers {
+    /// tab id → in follow mode
+    tabs: BTreeMap<u32, bool>,
+    /// control tab whose tab or state this one last applied
+    pub leader: Option<u32>,
+}
+
+impl Peers {
+    /// files a message from another tab; true when it is a fresh hello that needs a reply
+    pub fn observe(&mut self, me: u32, message: &SyncMessage) -> bool {
+        let from = message.from();
+        if from == me {
+            return false;
+        }
+        match message {
+            SyncMessage::Hello { following, reply, .. } => {
+                self.tabs.insert(from, *following);
+                !reply
+            }
+            SyncMessage::Bye { .. } => {
+                self.tabs.remove(&from);
+                if self.leader == Some(from) {
+                    self.leader = None;
+                }
+                false
+            }
+            // a tab that drives others is not following; this also covers tabs whose hello was missed
+            SyncMessage::Tab { .. } | SyncMessage::State { .. } => {
+                self.tabs.insert(from, false);
+                false
+            }
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.tabs.len()
+    }
+
+    pub fn followers(&self) -> usize {
+        self.tabs.values().filter(|f| **f).count()
+    }
+
+    /// whether a tab in follow mode applies this message, recording its sender as the leader
+    pub fn accept(&mut self, me: u32, following: bool, message: &SyncMessage) -> bool {
+        let from = message.from();
+        let drives = matches!(message, SyncMessage::Tab { .. } | SyncMessage::State { .. });
+        if !following || !drives || from == me || self.tabs.get(&from) == Some(&true) {
+            return false;
+        }
+        self.leader = Some(from);
+        true
+    }
+}
+
+pub fn role(following: bool, peers: &Peers) -> Role {
+    if following {
+        Role::Display
+    } else if peers.followers() > 0 {
+        Role::Control
+    } else {
+        Role::Solo
+    }
+}
+
+// ============================================================================
+// browser side
+// ============================================================================
+
+/// sync signals provided by App
+#[derive(Clone, Copy)]
+pub struct TabSync {
+    /// this tab's id in messages
+    pub id: u32,
+    pub following: RwSignal<bool>,
+    pub peers: RwSignal<Peers>,
+    /// last tab id the control tab switched to (App follows it)
+    pub tab: RwSignal<Option<String>>,
+    /// last demo state the control tab sent (the demo tab restores it, also when it mounts later)
+    pub state: RwSignal<Option<DemoSnapshot>>,
+}
+
+impl TabSync {
+    /// joins the channel and says hello; follow mode starts on when the page was opened with #follow
+    pub fn install() -> Self {
+        let following = web_sys::window()
+            .and_then(|w| w.location().hash().ok())
+            .is_some_and(|hash| hash == FOLLOW_FRAGMENT);
+        let sync = Self {
+            id: (js_sys::Math::random() * u32::MAX as f64) as u32,
+            following: create_rw_signal(following),
+            peers: create_rw_signal(Peers::default()),
+            tab: create_rw_signal(None),
+            state: create_rw_signal(None),
+        };
+        let Ok(channel) = web_sys::BroadcastChannel::new(CHANNEL_NAME) else {
+            tracing::warn!(target: "sync", "BroadcastChannel unavailable, tabs stay independent");
+            return sync;
+        };
+        let on_message = Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |event: web_sys::MessageEvent| {
+            let Some(text) = event.data().as_string() else { return };
+            let Ok(message) = SyncMessage::from_json(&text) else { return };
+            sync.receive(message);
+        });
+        channel.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        on_message.forget();
+        CHANNEL.with(|c| *c.borrow_mut() = Some(channel));
+
+        let _ = window_event_listener_untyped("pagehide", move |_| post(&SyncMessage::Bye { from: sync.id }));
+        // every change of follow mode (including the first run) is announced
+        create_effect(move |_| post(&SyncMessage::Hello { from: sync.id, following: sync.following.get(), reply: false }));
+        sync
+    }
+
+    fn receive(&self, message: SyncMessage) {
+        let following = self.following.get_untracked();
+        let mut reply = false;
+        let mut accepted = false;
+        self.peers.update(|p| {
+            reply = p.observe(self.id, &message);
+            accepted = p.accept(self.id, following, &message);
+        });
+        if reply {
+            post(&SyncMessage::Hello { from: self.id, following, reply: true });
+        }
+        if !accepted {
+            return;
+        }
+        match message {
+            SyncMessage::Tab { tab, .. } => self.tab.set(Some(tab)),
+            SyncMessage::State { snapshot, .. } => match DemoSnapshot::from_json(&snapshot) {
+                Ok(snap) => self.state.set(Some(snap)),
+                Err(e) => tracing::warn!(target: "sync", error = %e, "control tab state not applied"),
+            },
+            SyncMessage::Hello { .. } | SyncMessage::Bye { .. } => {}
+        }
+    }
+
+    /// this tab's role (tracked)
+    pub fn role(&self) -> Role {
+        let following = self.following.get();
+        self.peers.with(|p| role(following, p))
+    }
+
+    /// tabs following this one, 0 unless it is the control tab (tracked, so publishers re-send when one joins)
+    pub fn followers(&self) -> usize {
+        if self.following.get() { 0 } else { self.peers.with(Peers::followers) }
+    }
+
+    pub fn publish_tab(&self, tab: &str) {
+        post(&SyncMessage::Tab { from: self.id, tab: tab.to_string() });
+    }
+
+    pub fn publish_state(&self, snapshot: &DemoSnapshot) {
+        post(&SyncMessage::State { from: self.id, snapshot: snapshot.to_json() });
+    }
+}
+
+fn post(message: &SyncMessage) {
+    CHANNEL.with(|c| {
+        if let Some(channel) = c.borrow().as_ref() {
+            if let Err(e) = channel.post_message(&JsValue::from_str(&message.to_json())) {
+                tracing::warn!(target: "sync", error = ?e, "postMessage failed");
+            }
+        }
+    });
+}
+
+/// sync signals provided by App (a standalone, channel-less tab outside the app)
+pub fn use_tab_sync() -> TabSync {
+    use_context::<TabSync>().unwrap_or_else(|| TabSync {
+        id: 0,
+        following: create_rw_signal(false),
+        peers: create_rw_signal(Peers::default()),
+        tab: create_rw_signal(None),
+        state: create_rw_signal(None),
+    })
+}
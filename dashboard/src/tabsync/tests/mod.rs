@@ -0,0 +1,5 @@
+// what: exports all test modules for multi-tab sync
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod protocol;
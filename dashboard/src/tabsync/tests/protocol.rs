@@ -0,0 +1,78 @@
+// what: tests for the multi-tab sync protocol - message shapes, the peer table, roles and what a display applies
+// why: a display that applied its own echo or another display's state would loop or drift away from the control tab
+
+use crate::tabsync::{role, Peers, Role, SyncMessage};
+
+const ME: u32 = 1;
+const CONTROL: u32 = 2;
+const OTHER_DISPLAY: u32 = 3;
+
+fn hello(from: u32, following: bool, reply: bool) -> SyncMessage {
+    SyncMessage::Hello { from, following, reply }
+}
+
+fn state(from: u32) -> SyncMessage {
+    SyncMessage::State { from, snapshot: "{}".to_string() }
+}
+
+#[test]
+fn messages_round_trip_as_tagged_json() {
+    // what: every message survives to_json/from_json, carries a camelCase type tag, and garbage is an error
+    // why: tabs of two deployments can share a channel; an unreadable message must be dropped, not half-parsed
+    let messages = [hello(7, true, false), SyncMessage::Bye { from: 7 }, SyncMessage::Tab { from: 7, tab: "demo".to_string() }, state(7)];
+    for message in messages {
+        assert_eq!(SyncMessage::from_json(&message.to_json()), Ok(message.clone()));
+        assert_eq!(message.from(), 7);
+    }
+    assert!(hello(7, true, false).to_json().contains(r#""type":"hello""#));
+    assert!(SyncMessage::from_json(r#"{"type":"reboot","from":1}"#).is_err());
+    assert!(SyncMessage::from_json("not json").is_err());
+}
+
+#[test]
+fn peers_track_hellos_and_byes_and_answer_fresh_hellos_only() {
+    // what: hellos add or update a tab, byes remove it, own messages are ignored, and only a non-reply hello asks for an answer
+    // why: answering replies would bounce hellos between tabs forever; a closed tab must stop counting as a follower
+    let mut peers = Peers::default();
+    assert!(peers.observe(ME, &hello(OTHER_DISPLAY, true, false)));
+    assert!(!peers.observe(ME, &hello(CONTROL, false, true)));
+    assert!(!peers.observe(ME, &hello(ME, true, false)), "own echo");
+    assert_eq!((peers.count(), peers.followers()), (2, 1));
+    peers.observe(ME, &hello(OTHER_DISPLAY, false, false));
+    assert_eq!(peers.followers(), 0, "follow mode turned off");
+    peers.observe(ME, &SyncMessage::Bye { from: OTHER_DISPLAY });
+    assert_eq!(peers.count(), 1);
+    peers.observe(ME, &state(9));
+    assert_eq!((peers.count(), peers.followers()), (2, 0), "a tab that sends state is a control tab");
+}
+
+#[test]
+fn role_follows_follow_mode_and_followers() {
+    // what: a following tab is a display; otherwise it is control with at least one follower, solo without
+    // why: only the control tab serializes and sends its state, so the role decides who publishes
+    let mut peers = Peers::default();
+    assert_eq!(role(false, &peers), Role::Solo);
+    peers.observe(ME, &hello(CONTROL, false, false));
+    assert_eq!(role(false, &peers), Role::Solo, "another tab that does not follow");
+    peers.observe(ME, &hello(OTHER_DISPLAY, true, false));
+    assert_eq!(role(false, &peers), Role::Control);
+    assert_eq!(role(true, &peers), Role::Display);
+    assert!(Role::Display.label().contains("Display"));
+}
+
+#[test]
+fn displays_apply_only_control_tab_state_and_tabs() {
+    // what: only a tab in follow mode accepts tab/state messages, never its own or another display's, and remembers the sender
+    // why: two displays mirroring each other would fight; the badge names the control tab being mirrored
+    let mut peers = Peers::default();
+    peers.observe(ME, &hello(OTHER_DISPLAY, true, false));
+    assert!(!peers.accept(ME, false, &state(CONTROL)), "not following");
+    assert!(!peers.accept(ME, true, &state(ME)), "own echo");
+    assert!(!peers.accept(ME, true, &state(OTHER_DISPLAY)), "another display");
+    assert!(!peers.accept(ME, true, &hello(CONTROL, false, false)), "hellos do not drive");
+    assert_eq!(peers.leader, None);
+    assert!(peers.accept(ME, true, &SyncMessage::Tab { from: CONTROL, tab: "proof".to_string() }));
+    assert_eq!(peers.leader, Some(CONTROL));
+    peers.observe(ME, &SyncMessage::Bye { from: CONTROL });
+    assert_eq!(peers.leader, None, "control tab closed");
+}
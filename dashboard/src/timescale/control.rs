@@ -0,0 +1,29 @@
+// what: header speed picker (0.25x-10x) with a slow-motion / fast-forward badge
+// why: the speed has to be at hand mid-talk, not buried in the settings modal
+// relations: edits the scale from mod.rs use_time_scale(); rendered by lib.rs in the header
+
+use leptos::*;
+use super::{use_time_scale, TimeScale};
+
+#[component]
+pub fn SpeedControl() -> impl IntoView {
+    let scale = use_time_scale();
+
+    view! {
+        <div class="speed-control" title="Simulation speed: scales scripted waits (respawns, run-all spacing, replays). Measured times stay real.">
+            <select
+                class="network-select"
+                on:change=move |ev| {
+                    if let Ok(factor) = event_target_value(&ev).parse::<f64>() {
+                        scale.set(TimeScale::new(factor));
+                    }
+                }
+            >
+                {TimeScale::STEPS.into_iter().map(|s| view! {
+                    <option value=s.factor().to_string() selected=move || scale.get() == s>{format!("⏱️ {}", s.label())}</option>
+                }).collect_view()}
+            </select>
+            {move || scale.get().badge().map(|badge| view! { <span class="demo-badge">{badge}</span> })}
+        </div>
+    }
+}
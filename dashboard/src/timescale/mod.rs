@@ -0,0 +1,89 @@
+// what: simulation speed - a virtual clock that scales the demo's scripted waits (respawns, run-all spacing, replay ticks) by 0.25x-10x
+// why: presenters slow the fast wasm-side events down for an audience or fast-forward python respawn waits; measured times are never scaled
+// relations: scale provided by lib.rs and picked in control.rs (header); sim_timeout used by tabs/demo/handlers.rs in place of wasm.rs set_timeout
+
+use std::cell::Cell;
+use std::time::Duration;
+use leptos::*;
+use crate::tabs::demo::wasm::set_timeout;
+
+mod control;
+
+#[cfg(test)]
+mod tests;
+
+pub use control::SpeedControl;
+
+pub const MIN_SCALE: f64 = 0.25;
+pub const MAX_SCALE: f64 = 10.0;
+
+/// simulated seconds per wall-clock second
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeScale(f64);
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self::REAL
+    }
+}
+
+impl TimeScale {
+    pub const REAL: TimeScale = TimeScale(1.0);
+    /// speeds the header control offers
+    pub const STEPS: [TimeScale; 6] = [TimeScale(0.25), TimeScale(0.5), TimeScale(1.0), TimeScale(2.0), TimeScale(5.0), TimeScale(10.0)];
+
+    /// clamped to MIN_SCALE..=MAX_SCALE; anything unreadable is real time
+    pub fn new(factor: f64) -> Self {
+        if factor.is_finite() && factor > 0.0 {
+            Self(factor.clamp(MIN_SCALE, MAX_SCALE))
+        } else {
+            Self::REAL
+        }
+    }
+
+    pub fn factor(self) -> f64 {
+        self.0
+    }
+
+    /// wall-clock wait for a simulated delay
+    pub fn wall(self, sim: Duration) -> Duration {
+        Duration::from_secs_f64(sim.as_secs_f64() / self.0)
+    }
+
+    pub fn label(self) -> String {
+        format!("{}x", self.0)
+    }
+
+    /// header badge while not running in real time
+    pub fn badge(self) -> Option<&'static str> {
+        match self.0 {
+            f if f < 1.0 => Some("🐢 Slow motion"),
+            f if f > 1.0 => Some("⏩ Fast-forward"),
+            _ => None,
+        }
+    }
+}
+
+thread_local! {
+    /// scale read when a timer is scheduled; handlers run outside any reactive owner
+    static CURRENT: Cell<TimeScale> = const { Cell::new(TimeScale::REAL) };
+}
+
+/// makes `scale` the speed of every timer scheduled from now on
+pub fn apply(scale: TimeScale) {
+    CURRENT.with(|c| c.set(scale));
+}
+
+pub fn current() -> TimeScale {
+    CURRENT.with(Cell::get)
+}
+
+/// set_timeout on the virtual clock: `sim` is stretched or shrunk by the current scale
+pub fn sim_timeout<F: FnOnce() + 'static>(cb: F, sim: Duration) {
+    set_timeout(cb, current().wall(sim));
+}
+
+/// scale signal provided by App (a fresh real-time signal outside the app)
+pub fn use_time_scale() -> RwSignal<TimeScale> {
+    use_context::<RwSignal<TimeScale>>().unwrap_or_else(|| create_rw_signal(TimeScale::REAL))
+}
@@ -0,0 +1,49 @@
+// what: tests for the virtual clock's time scale
+// why: a wrong factor turns slow motion into fast-forward; a stray scale on measured numbers would falsify the demo
+
+use std::time::Duration;
+use crate::timescale::{apply, current, TimeScale, MAX_SCALE, MIN_SCALE};
+
+#[test]
+fn slow_motion_stretches_and_fast_forward_shrinks() {
+    // what: a 1.5s respawn waits 6s at 0.25x, 150ms at 10x and 1.5s in real time
+    // why: the control is read as "simulated seconds per real second"
+    let respawn = Duration::from_millis(1500);
+    assert_eq!(TimeScale::new(0.25).wall(respawn), Duration::from_secs(6));
+    assert_eq!(TimeScale::new(10.0).wall(respawn), Duration::from_millis(150));
+    assert_eq!(TimeScale::REAL.wall(respawn), respawn);
+}
+
+#[test]
+fn factors_outside_the_range_are_clamped() {
+    // what: factors clamp to 0.25x-10x; zero, negative and NaN fall back to real time
+    // why: the select's value is parsed from the dom; a bad value must not freeze or flood the demo
+    assert_eq!(TimeScale::new(100.0).factor(), MAX_SCALE);
+    assert_eq!(TimeScale::new(0.01).factor(), MIN_SCALE);
+    for bad in [0.0, -2.0, f64::NAN, f64::INFINITY] {
+        assert_eq!(TimeScale::new(bad), TimeScale::REAL);
+    }
+    assert!(TimeScale::STEPS.iter().all(|s| TimeScale::new(s.factor()) == *s), "every offered step is in range");
+}
+
+#[test]
+fn badge_and_label_follow_the_factor() {
+    // what: labels read "0.25x" / "1x" / "10x"; only a non-real speed shows a badge
+    // why: an audience must be able to tell the pace on screen is not the real one
+    assert_eq!(TimeScale::new(0.25).label(), "0.25x");
+    assert_eq!(TimeScale::REAL.label(), "1x");
+    assert_eq!(TimeScale::new(10.0).label(), "10x");
+    assert_eq!(TimeScale::REAL.badge(), None);
+    assert!(TimeScale::new(0.5).badge().unwrap().contains("Slow"));
+    assert!(TimeScale::new(2.0).badge().unwrap().contains("Fast"));
+}
+
+#[test]
+fn applied_scale_governs_later_timers() {
+    // what: the scale applied last is the one timers read
+    // why: handlers schedule waits outside any reactive owner and read this cell, not the signal
+    assert_eq!(current(), TimeScale::REAL);
+    apply(TimeScale::new(5.0));
+    assert_eq!(current().wall(Duration::from_secs(1)), Duration::from_millis(200));
+    apply(TimeScale::REAL);
+}
@@ -0,0 +1,95 @@
+// what: debug drawer listing buffered spans and events with target, level and text filters
+// why: lets a viewer (or a bug report) see the async order of an attack without opening devtools
+// relations: reads the ring buffer in mod.rs, mounted once by lib.rs
+
+use leptos::*;
+use tracing::Level;
+use crate::tabs::demo::wasm::copy_to_clipboard;
+use super::{clear, filter_records, snapshot, watch, TraceKind, TRACE_TARGETS};
+
+/// rows rendered at most (newest first); the ring holds more for copy
+const DRAWER_ROWS: usize = 200;
+
+fn level_from(value: &str) -> Level {
+    match value {
+        "error" => Level::ERROR,
+        "warn" => Level::WARN,
+        "info" => Level::INFO,
+        _ => Level::DEBUG,
+    }
+}
+
+/// bug icon bottom-right that slides the trace viewer open
+#[component]
+pub fn TraceDrawer() -> impl IntoView {
+    let revision = create_rw_signal(0u64);
+    watch(revision);
+    let (open, set_open) = create_signal(false);
+    let (target, set_target) = create_signal(Option::<&'static str>::None);
+    let (min_level, set_min_level) = create_signal(Level::DEBUG);
+    let (text, set_text) = create_signal(String::new());
+
+    let records = create_memo(move |_| {
+        revision.track();
+        snapshot()
+    });
+    let visible = move || {
+        records.with(|all| {
+            text.with(|t| {
+                filter_records(all, target.get(), min_level.get(), t)
+                    .into_iter()
+                    .rev()
+                    .take(DRAWER_ROWS)
+                    .map(|r| {
+                        let class = match (r.kind, r.level) {
+                            (_, Level::ERROR) => "trace-row trace-error",
+                            (_, Level::WARN) => "trace-row trace-warn",
+                            (TraceKind::Event, _) => "trace-row",
+                            _ => "trace-row trace-span",
+                        };
+                        view! { <div class=class>{r.line()}</div> }
+                    })
+                    .collect_view()
+            })
+        })
+    };
+    let copy = move |_| {
+        let lines = records.with(|all| {
+            text.with(|t| filter_records(all, target.get(), min_level.get(), t).iter().map(|r| r.line()).collect::<Vec<_>>().join("\n"))
+        });
+        copy_to_clipboard(&lines);
+    };
+
+    view! {
+        <button class="trace-toggle" title="Trace viewer" on:click=move |_| set_open.update(|o| *o = !*o)>"🐞"</button>
+        <Show when=move || open.get()>
+            <aside class="trace-drawer">
+                <div class="modal-header">
+                    <span class="modal-title">{move || format!("🐞 Trace ({} buffered)", records.with(Vec::len))}</span>
+                    <button class="modal-close" on:click=move |_| set_open.set(false)>"×"</button>
+                </div>
+                <div class="trace-filters">
+                    <button class=move || if target.get().is_none() { "trace-chip active" } else { "trace-chip" }
+                        on:click=move |_| set_target.set(None)>"all"</button>
+                    {TRACE_TARGETS.iter().map(|t| view! {
+                        <button class=move || if target.get() == Some(*t) { "trace-chip active" } else { "trace-chip" }
+                            on:click=move |_| set_target.set(Some(*t))>{*t}</button>
+                    }).collect_view()}
+                    <select class="network-select" on:change=move |ev| set_min_level.set(level_from(&event_target_value(&ev)))>
+                        <option value="debug">"debug+"</option>
+                        <option value="info">"info+"</option>
+                        <option value="warn">"warn+"</option>
+                        <option value="error">"error"</option>
+                    </select>
+                    <input class="trace-search" type="search" placeholder="filter text"
+                        prop:value=text on:input=move |ev| set_text.set(event_target_value(&ev)) />
+                </div>
+                <div class="trace-actions">
+                    <button class="action-btn" on:click=copy>"📋 Copy"</button>
+                    <button class="action-btn" on:click=move |_| { clear(); revision.update(|n| *n += 1); }>"🗑️ Clear"</button>
+                </div>
+                <div class="trace-list">{visible}</div>
+            </aside>
+        </Show>
+    }
+}
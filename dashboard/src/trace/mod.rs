@@ -0,0 +1,329 @@
+// what: tracing subscriber that keeps spans and events in an in-app ring buffer and mirrors them to the browser console
+// why: attack lifecycles, measurement runs and worker messages hop across timeouts and futures; spans keep that choreography readable
+// relations: installed from lib.rs main, read by drawer.rs, spans/events emitted from tabs/demo/handlers.rs, measure/worker.rs, api/client.rs
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write as _;
+use leptos::*;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+mod drawer;
+
+#[cfg(test)]
+mod tests;
+
+pub use drawer::TraceDrawer;
+
+/// records kept before the oldest are dropped
+pub const TRACE_CAPACITY: usize = 500;
+
+/// targets the app emits under (drawer filter chips)
+pub const TRACE_TARGETS: [&str; 5] = ["attack", "raft", "measure", "worker", "api"];
+
+/// what a record marks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceKind {
+    Event,
+    SpanOpen,
+    /// span closed, with how long it was open
+    SpanClose { elapsed_ms: u64 },
+}
+
+/// one line in the trace viewer
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraceRecord {
+    pub seq: u64,
+    pub at_ms: f64,
+    pub level: Level,
+    pub target: String,
+    pub kind: TraceKind,
+    /// enclosing spans, outermost first, e.g. "attack{name=dataExfil} > python"
+    pub spans: String,
+    pub message: String,
+}
+
+impl TraceRecord {
+    /// one-line rendering shared by the console and the copy button
+    pub fn line(&self) -> String {
+        let marker = match self.kind {
+            TraceKind::Event => String::new(),
+            TraceKind::SpanOpen => "→ ".into(),
+            TraceKind::SpanClose { elapsed_ms } => format!("← ({}ms) ", elapsed_ms),
+        };
+        let scope = if self.spans.is_empty() { String::new() } else { format!("{}: ", self.spans) };
+        format!("{:>9.1}ms {:<5} [{}] {}{}{}", self.at_ms, self.level, self.target, scope, marker, self.message)
+    }
+}
+
+/// records at or above `min_level` severity, optionally one target, containing `text`
+pub fn filter_records<'a>(records: &'a [TraceRecord], target: Option<&str>, min_level: Level, text: &str) -> Vec<&'a TraceRecord> {
+    let text = text.to_lowercase();
+    records
+        .iter()
+        .filter(|r| r.level <= min_level)
+        .filter(|r| target.is_none_or(|t| r.target == t))
+        .filter(|r| text.is_empty() || r.line().to_lowercase().contains(&text))
+        .collect()
+}
+
+// ============================================================================
+// ring buffer (one per thread; wasm has one)
+// ============================================================================
+
+struct SpanData {
+    label: String,
+    target: String,
+    level: Level,
+    opened_ms: f64,
+    parent: Option<u64>,
+    refs: usize,
+}
+
+thread_local! {
+    static RING: RefCell<VecDeque<TraceRecord>> = const { RefCell::new(VecDeque::new()) };
+    static SPANS: RefCell<HashMap<u64, SpanData>> = RefCell::new(HashMap::new());
+    static STACK: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+    static NEXT: Cell<u64> = const { Cell::new(1) };
+    /// bumped after new records so the drawer re-reads the ring
+    static REVISION: RefCell<Option<RwSignal<u64>>> = const { RefCell::new(None) };
+    static SCHEDULED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// copy of the buffered records, oldest first
+pub fn snapshot() -> Vec<TraceRecord> {
+    RING.with(|r| r.borrow().iter().cloned().collect())
+}
+
+/// empties the buffer
+pub fn clear() {
+    RING.with(|r| r.borrow_mut().clear());
+}
+
+/// signal bumped (from a fresh task, never inside the emitting call) whenever records arrive
+fn watch(revision: RwSignal<u64>) {
+    REVISION.with(|r| *r.borrow_mut() = Some(revision));
+}
+
+fn push(record: TraceRecord) {
+    RING.with(|r| {
+        let mut ring = r.borrow_mut();
+        ring.push_back(record);
+        while ring.len() > TRACE_CAPACITY {
+            ring.pop_front();
+        }
+    });
+    // events fire from inside signal updates; touch the reactive graph on the next tick only
+    if let Some(revision) = REVISION.with(|r| *r.borrow()) {
+        if !SCHEDULED.with(|s| s.replace(true)) {
+            set_timeout(move || {
+                SCHEDULED.with(|s| s.set(false));
+                revision.update(|n| *n += 1);
+            }, std::time::Duration::ZERO);
+        }
+    }
+}
+
+fn span_path(from: Option<u64>) -> String {
+    SPANS.with(|spans| {
+        let spans = spans.borrow();
+        let mut path = Vec::new();
+        let mut cursor = from;
+        while let Some(id) = cursor {
+            let Some(span) = spans.get(&id) else { break };
+            path.push(span.label.clone());
+            cursor = span.parent;
+        }
+        path.reverse();
+        path.join(" > ")
+    })
+}
+
+/// message plus key=value fields
+#[derive(Default)]
+struct Fields {
+    message: String,
+    rest: String,
+}
+
+impl Fields {
+    fn joined(self) -> String {
+        match (self.message.is_empty(), self.rest.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.rest,
+            (false, false) => format!("{} {}", self.message, self.rest),
+        }
+    }
+}
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            let _ = write!(self.rest, "{}{}={}", if self.rest.is_empty() { "" } else { " " }, field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            let _ = write!(self.rest, "{}{}={:?}", if self.rest.is_empty() { "" } else { " " }, field.name(), value);
+        }
+    }
+}
+
+// ============================================================================
+// subscriber
+// ============================================================================
+
+/// writes into the ring buffer; mirrors to the console when `console` is set
+pub struct RingSubscriber {
+    console: bool,
+    clock: fn() -> f64,
+    max_level: Level,
+}
+
+impl RingSubscriber {
+    pub fn new(console: bool, clock: fn() -> f64, max_level: Level) -> Self {
+        Self { console, clock, max_level }
+    }
+
+    fn emit(&self, record: TraceRecord) {
+        if self.console {
+            let line = wasm_bindgen::JsValue::from_str(&record.line());
+            match record.level {
+                Level::ERROR => web_sys::console::error_1(&line),
+                Level::WARN => web_sys::console::warn_1(&line),
+                Level::INFO => web_sys::console::info_1(&line),
+                _ => web_sys::console::debug_1(&line),
+            }
+        }
+        push(record);
+    }
+
+    fn record(&self, meta: &Metadata<'_>, kind: TraceKind, spans: String, message: String) -> TraceRecord {
+        TraceRecord {
+            seq: NEXT.with(|n| n.replace(n.get() + 1)),
+            at_ms: (self.clock)(),
+            level: *meta.level(),
+            target: meta.target().to_string(),
+            kind,
+            spans,
+            message,
+        }
+    }
+}
+
+impl Subscriber for RingSubscriber {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        *metadata.level() <= self.max_level
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let meta = span.metadata();
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+        let fields = fields.joined();
+        let label = if fields.is_empty() { meta.name().to_string() } else { format!("{}{{{}}}", meta.name(), fields) };
+        let parent = if span.is_root() {
+            None
+        } else {
+            span.parent().map(Id::into_u64).or_else(|| STACK.with(|s| s.borrow().last().copied()))
+        };
+        let id = NEXT.with(|n| n.replace(n.get() + 1));
+        let opened_ms = (self.clock)();
+        SPANS.with(|s| s.borrow_mut().insert(id, SpanData {
+            label,
+            target: meta.target().to_string(),
+            level: *meta.level(),
+            opened_ms,
+            parent,
+            refs: 1,
+        }));
+        let record = self.record(meta, TraceKind::SpanOpen, span_path(parent), SPANS.with(|s| s.borrow()[&id].label.clone()));
+        self.emit(record);
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut fields = Fields::default();
+        values.record(&mut fields);
+        let extra = fields.joined();
+        SPANS.with(|s| {
+            if let Some(data) = s.borrow_mut().get_mut(&span.into_u64()) {
+                let _ = write!(data.label, " {}", extra);
+            }
+        });
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let parent = if event.is_root() {
+            None
+        } else {
+            event.parent().map(Id::into_u64).or_else(|| STACK.with(|s| s.borrow().last().copied()))
+        };
+        let record = self.record(event.metadata(), TraceKind::Event, span_path(parent), fields.joined());
+        self.emit(record);
+    }
+
+    fn enter(&self, span: &Id) {
+        STACK.with(|s| s.borrow_mut().push(span.into_u64()));
+    }
+
+    fn exit(&self, span: &Id) {
+        STACK.with(|s| {
+            let mut stack = s.borrow_mut();
+            if let Some(pos) = stack.iter().rposition(|id| *id == span.into_u64()) {
+                stack.remove(pos);
+            }
+        });
+    }
+
+    fn clone_span(&self, id: &Id) -> Id {
+        SPANS.with(|s| {
+            if let Some(data) = s.borrow_mut().get_mut(&id.into_u64()) {
+                data.refs += 1;
+            }
+        });
+        id.clone()
+    }
+
+    fn try_close(&self, id: Id) -> bool {
+        let closed = SPANS.with(|s| {
+            let mut spans = s.borrow_mut();
+            let data = spans.get_mut(&id.into_u64())?;
+            data.refs -= 1;
+            if data.refs > 0 {
+                return None;
+            }
+            spans.remove(&id.into_u64())
+        });
+        let Some(data) = closed else { return false };
+        let elapsed_ms = ((self.clock)() - data.opened_ms).max(0.0) as u64;
+        let record = TraceRecord {
+            seq: NEXT.with(|n| n.replace(n.get() + 1)),
+            at_ms: (self.clock)(),
+            level: data.level,
+            target: data.target,
+            kind: TraceKind::SpanClose { elapsed_ms },
+            spans: span_path(data.parent),
+            message: data.label,
+        };
+        self.emit(record);
+        true
+    }
+}
+
+/// installs the ring subscriber for the whole app (debug and above, mirrored to the console)
+pub fn install() {
+    let clock = || web_sys::window().and_then(|w| w.performance()).map(|p| p.now()).unwrap_or(0.0);
+    let _ = tracing::subscriber::set_global_default(RingSubscriber::new(true, clock, Level::DEBUG));
+}
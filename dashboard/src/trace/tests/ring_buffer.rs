@@ -0,0 +1,74 @@
+// what: tests for the ring subscriber and the drawer filters
+// why: the trace viewer is only useful if spans nest correctly and the buffer stays bounded
+
+use tracing::Level;
+use crate::trace::{clear, filter_records, snapshot, RingSubscriber, TraceKind, TRACE_CAPACITY};
+
+fn with_ring(f: impl FnOnce()) -> Vec<crate::trace::TraceRecord> {
+    clear();
+    tracing::subscriber::with_default(RingSubscriber::new(false, || 0.0, Level::DEBUG), f);
+    snapshot()
+}
+
+#[test]
+fn events_carry_their_span_path() {
+    // what: an event inside attack > wasm is recorded with both spans, opened and closed around it
+    // why: the drawer reads the async choreography from this path
+    let records = with_ring(|| {
+        let attack = tracing::info_span!(target: "attack", "attack", name = "dataExfil");
+        let _a = attack.enter();
+        let wasm = tracing::info_span!(target: "attack", "wasm");
+        let _w = wasm.enter();
+        tracing::info!(target: "attack", instance = 1, "trap");
+    });
+    let event = records.iter().find(|r| r.kind == TraceKind::Event).unwrap();
+    assert_eq!(event.spans, "attack{name=dataExfil} > wasm");
+    assert_eq!(event.message, "trap instance=1");
+    assert_eq!(records.iter().filter(|r| r.kind == TraceKind::SpanOpen).count(), 2);
+    assert_eq!(records.iter().filter(|r| matches!(r.kind, TraceKind::SpanClose { .. })).count(), 2);
+}
+
+#[test]
+fn levels_below_the_max_are_skipped() {
+    // what: a subscriber capped at info drops debug events
+    // why: worker chatter is debug and must not flood an info-level buffer
+    clear();
+    tracing::subscriber::with_default(RingSubscriber::new(false, || 0.0, Level::INFO), || {
+        tracing::debug!(target: "worker", "postMessage");
+        tracing::info!(target: "measure", "measured");
+    });
+    let records = snapshot();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].target, "measure");
+}
+
+#[test]
+fn buffer_drops_oldest_past_capacity() {
+    // what: only the newest TRACE_CAPACITY records are kept
+    // why: a long chaos session must not grow memory without bound
+    let records = with_ring(|| {
+        for n in 0..TRACE_CAPACITY + 10 {
+            tracing::info!(target: "attack", n, "tick");
+        }
+    });
+    assert_eq!(records.len(), TRACE_CAPACITY);
+    assert_eq!(records[0].message, "tick n=10");
+}
+
+#[test]
+fn filters_combine_target_level_and_text() {
+    // what: target, minimum severity and text narrow the list together
+    // why: these are the drawer's three filter controls
+    let records = with_ring(|| {
+        tracing::warn!(target: "attack", "python crashed");
+        tracing::info!(target: "attack", "wasm trapped");
+        tracing::debug!(target: "worker", "postMessage");
+        tracing::warn!(target: "api", "fetch failed");
+    });
+    assert_eq!(filter_records(&records, None, Level::DEBUG, "").len(), 4);
+    assert_eq!(filter_records(&records, Some("attack"), Level::DEBUG, "").len(), 2);
+    assert_eq!(filter_records(&records, None, Level::WARN, "").len(), 2);
+    let hits = filter_records(&records, Some("attack"), Level::WARN, "CRASH");
+    assert_eq!(hits.len(), 1);
+    assert!(hits[0].line().contains("[attack] python crashed"));
+}
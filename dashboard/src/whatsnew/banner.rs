@@ -0,0 +1,78 @@
+// what: dismissible "what's new" header banner, the release notes modal, and the footer link that reopens it
+// why: the banner appears once per release bump; the notes stay reachable after it is dismissed
+// relations: releases and seen-tracking from mod.rs, hidden in kiosk attract mode like diagnostics/feedback_button.rs
+
+use leptos::*;
+use crate::kiosk::use_kiosk;
+use super::{changelog, load_seen, save_seen, Release, RELEASE};
+
+/// modal listing the given releases, newest first
+#[component]
+fn ReleaseNotes(releases: Vec<&'static Release>, on_close: Callback<()>) -> impl IntoView {
+    view! {
+        <div class="modal-overlay" on:click=move |_| on_close.call(())>
+            <div class="modal-content settings-modal" on:click=|e: web_sys::MouseEvent| e.stop_propagation()>
+                <div class="modal-header">
+                    <span class="modal-title">"✨ What's new"</span>
+                    <button class="modal-close" on:click=move |_| on_close.call(())>"×"</button>
+                </div>
+                {releases.into_iter().map(|release| view! {
+                    <fieldset class="settings-group">
+                        <legend>{format!("{} — {} ({})", release.version, release.title, release.date)}</legend>
+                        <ul class="release-items">
+                            {release.items.iter().map(|item| view! {
+                                <li>{item.kind.icon()}" "{item.text.as_str()}</li>
+                            }).collect_view()}
+                        </ul>
+                    </fieldset>
+                }).collect_view()}
+            </div>
+        </div>
+    }
+}
+
+/// header banner shown while this release's notes are unseen
+#[component]
+pub fn WhatsNewBanner() -> impl IntoView {
+    let kiosk = use_kiosk();
+    let seen = load_seen();
+    // a first visit has nothing "new": remember the release without showing the banner
+    if seen.is_none() {
+        save_seen(RELEASE);
+    }
+    let unseen = changelog().unseen(seen.as_deref(), RELEASE);
+    let count: usize = unseen.iter().map(|r| r.items.len()).sum();
+    let (visible, set_visible) = create_signal(!unseen.is_empty());
+    let (open, set_open) = create_signal(false);
+    let dismiss = Callback::new(move |_| {
+        save_seen(RELEASE);
+        set_open.set(false);
+        set_visible.set(false);
+    });
+    let unseen = store_value(unseen);
+
+    view! {
+        <Show when=move || visible.get() && !kiosk.attract.get()>
+            <p class="kiosk-banner whatsnew-banner">
+                {format!("✨ New since your last visit: {} change{} in {}", count, if count == 1 { "" } else { "s" }, RELEASE)}
+                <button class="action-btn" on:click=move |_| set_open.set(true)>"Show"</button>
+                <button class="modal-close" title="Dismiss" on:click=move |_| dismiss.call(())>"×"</button>
+            </p>
+        </Show>
+        <Show when=move || open.get()>
+            <ReleaseNotes releases=unseen.get_value() on_close=dismiss />
+        </Show>
+    }
+}
+
+/// footer link to the full release notes
+#[component]
+pub fn ReleaseNotesButton() -> impl IntoView {
+    let (open, set_open) = create_signal(false);
+    view! {
+        <button class="release-notes-link" on:click=move |_| set_open.set(true)>{format!("What's new in {}", RELEASE)}</button>
+        <Show when=move || open.get()>
+            <ReleaseNotes releases=changelog().releases.iter().collect() on_close=Callback::new(move |_| set_open.set(false)) />
+        </Show>
+    }
+}
@@ -0,0 +1,118 @@
+// what: structured release notes (changelog.toml) and the last release a visitor has seen, kept in localStorage
+// why: repeat visitors should notice new scenarios and measurements once, without being nagged on every load
+// relations: include_str! of changelog.toml; release stamped by build.rs (GUARDIAN_RELEASE); rendered by banner.rs, mounted in lib.rs header
+
+use std::sync::OnceLock;
+use serde::Deserialize;
+
+mod banner;
+
+#[cfg(test)]
+mod tests;
+
+pub use banner::{ReleaseNotesButton, WhatsNewBanner};
+
+/// the embedded changelog
+pub const CHANGELOG_TOML: &str = include_str!("changelog.toml");
+/// localStorage key holding the last release the visitor dismissed
+pub const STORAGE_KEY: &str = "guardian.whatsnew";
+/// release this build was stamped with
+pub const RELEASE: &str = env!("GUARDIAN_RELEASE");
+
+/// what a changelog item adds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemKind {
+    Scenario,
+    Measurement,
+    Feature,
+}
+
+impl ItemKind {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ItemKind::Scenario => "⚔️",
+            ItemKind::Measurement => "📏",
+            ItemKind::Feature => "✨",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Item {
+    pub kind: ItemKind,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Release {
+    /// major.minor.patch
+    pub version: String,
+    pub date: String,
+    pub title: String,
+    pub items: Vec<Item>,
+}
+
+/// everything in changelog.toml
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Changelog {
+    pub releases: Vec<Release>,
+}
+
+impl Changelog {
+    /// parses and checks that versions are valid and strictly descending
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let changelog: Changelog = toml::from_str(text).map_err(|e| e.to_string())?;
+        let mut previous: Option<(u32, u32, u32)> = None;
+        for release in &changelog.releases {
+            let version = parse_version(&release.version).ok_or_else(|| format!("bad version \"{}\"", release.version))?;
+            if release.items.is_empty() {
+                return Err(format!("{} has no items", release.version));
+            }
+            if previous.is_some_and(|p| p <= version) {
+                return Err(format!("{} is out of order (newest first)", release.version));
+            }
+            previous = Some(version);
+        }
+        Ok(changelog)
+    }
+
+    /// releases newer than the one last seen, up to the running one; nothing on a first visit
+    pub fn unseen(&self, last_seen: Option<&str>, current: &str) -> Vec<&Release> {
+        let (Some(seen), Some(current)) = (last_seen.and_then(parse_version), parse_version(current)) else {
+            return Vec::new();
+        };
+        self.releases
+            .iter()
+            .filter(|r| parse_version(&r.version).is_some_and(|v| v > seen && v <= current))
+            .collect()
+    }
+}
+
+/// "1.2.3" → (1, 2, 3)
+pub fn parse_version(text: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = text.trim().split('.').map(|p| p.parse::<u32>().ok());
+    let version = (parts.next()??, parts.next()??, parts.next()??);
+    parts.next().is_none().then_some(version)
+}
+
+/// the embedded changelog, parsed on first use
+pub fn changelog() -> &'static Changelog {
+    static CHANGELOG: OnceLock<Changelog> = OnceLock::new();
+    CHANGELOG.get_or_init(|| Changelog::from_toml(CHANGELOG_TOML).expect("whatsnew/changelog.toml must parse"))
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// last release the visitor dismissed (None on a first visit)
+pub fn load_seen() -> Option<String> {
+    storage().and_then(|s| s.get_item(STORAGE_KEY).ok()?)
+}
+
+pub fn save_seen(version: &str) {
+    if let Some(s) = storage() {
+        let _ = s.set_item(STORAGE_KEY, version);
+    }
+}
@@ -0,0 +1,52 @@
+// what: tests for changelog.toml and the once-per-release banner rule
+// why: a release bumped without notes, or a comparison that treats 0.10 as older than 0.9, would hide or repeat the banner
+
+use crate::whatsnew::{changelog, parse_version, Changelog, RELEASE};
+
+fn release(version: &str) -> String {
+    format!("[[releases]]\nversion = \"{}\"\ndate = \"2026-01-01\"\ntitle = \"t\"\n[[releases.items]]\nkind = \"feature\"\ntext = \"x\"\n", version)
+}
+
+#[test]
+fn embedded_changelog_covers_the_stamped_release() {
+    // what: the shipped file parses and its newest entry is the release build.rs stamped
+    // why: the banner compares against RELEASE; a bump without notes would show an empty banner
+    let log = changelog();
+    assert_eq!(log.releases.first().map(|r| r.version.as_str()), Some(RELEASE));
+    assert!(log.releases.iter().all(|r| !r.title.is_empty() && r.items.iter().all(|i| !i.text.is_empty())));
+}
+
+#[test]
+fn versions_compare_numerically() {
+    // what: versions parse into numeric triples, so 0.10.0 sorts after 0.9.0; anything else is rejected
+    // why: a string comparison would call 0.10.0 older and never show its notes
+    assert!(parse_version("0.10.0") > parse_version("0.9.0"));
+    assert_eq!(parse_version(" 1.2.3 "), Some((1, 2, 3)));
+    for bad in ["1.2", "1.2.3.4", "v1.2.3", "1.x.3", ""] {
+        assert_eq!(parse_version(bad), None, "{}", bad);
+    }
+}
+
+#[test]
+fn malformed_changelogs_are_rejected() {
+    // what: bad versions, empty releases and out-of-order entries fail to parse
+    // why: changelog() panics on a bad file, so the tests catch it before a deploy does
+    assert!(Changelog::from_toml(&format!("{}{}", release("0.2.0"), release("0.1.0"))).is_ok());
+    assert!(Changelog::from_toml(&format!("{}{}", release("0.1.0"), release("0.2.0"))).unwrap_err().contains("out of order"));
+    assert!(Changelog::from_toml(&format!("{}{}", release("0.1.0"), release("0.1.0"))).is_err());
+    assert!(Changelog::from_toml(&release("one")).unwrap_err().contains("bad version"));
+    assert!(Changelog::from_toml("[[releases]]\nversion = \"0.1.0\"\ndate = \"d\"\ntitle = \"t\"\nitems = []\n").unwrap_err().contains("no items"));
+}
+
+#[test]
+fn banner_shows_only_releases_since_the_last_visit() {
+    // what: nothing on a first visit or when up to date; otherwise every release after the seen one, up to the running one
+    // why: "once per version bump" - and a visitor who skipped a release still gets its notes
+    let log = Changelog::from_toml(&format!("{}{}{}", release("0.3.0"), release("0.2.0"), release("0.1.0"))).unwrap();
+    let versions = |seen: Option<&str>, current: &str| log.unseen(seen, current).iter().map(|r| r.version.as_str()).collect::<Vec<_>>();
+    assert!(versions(None, "0.3.0").is_empty());
+    assert!(versions(Some("0.3.0"), "0.3.0").is_empty());
+    assert_eq!(versions(Some("0.1.0"), "0.3.0"), ["0.3.0", "0.2.0"]);
+    assert_eq!(versions(Some("0.1.0"), "0.2.0"), ["0.2.0"], "notes newer than the build are not announced");
+    assert!(versions(Some("garbage"), "0.3.0").is_empty());
+}
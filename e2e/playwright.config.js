@@ -0,0 +1,30 @@
+// what: playwright config for the dashboard e2e suite
+// why: serves the real trunk build and gives pyodide-backed attacks enough time
+// relations: starts `trunk serve` in ../dashboard; specs in tests/ drive window.guardianDemo (dashboard/src/headless)
+
+const { defineConfig, devices } = require('@playwright/test');
+
+const PORT = Number(process.env.GUARDIAN_E2E_PORT || 8080);
+
+module.exports = defineConfig({
+  testDir: './tests',
+  // pyodide cold start plus a full run-all sequence
+  timeout: 180_000,
+  expect: { timeout: 30_000 },
+  // attacks share one page's pyodide; parallel pages would just contend for the cpu
+  workers: 1,
+  retries: process.env.CI ? 1 : 0,
+  reporter: process.env.CI ? 'github' : 'list',
+  use: {
+    baseURL: process.env.GUARDIAN_E2E_URL || `http://127.0.0.1:${PORT}`,
+    trace: 'retain-on-failure',
+  },
+  projects: [{ name: 'chromium', use: { ...devices['Desktop Chrome'] } }],
+  webServer: process.env.GUARDIAN_E2E_URL ? undefined : {
+    command: `trunk serve --port ${PORT} --address 127.0.0.1`,
+    cwd: '../dashboard',
+    url: `http://127.0.0.1:${PORT}`,
+    timeout: 600_000,
+    reuseExistingServer: !process.env.CI,
+  },
+});
@@ -0,0 +1,71 @@
+// what: runs every attack through the headless api and checks events, terminals and counters
+// why: the choreography (python crash + respawn, wasm trap or election, counters) spans timeouts and futures unit tests never see
+// relations: helpers in demo.js; attack list and metadata come from the app itself (attacks(), describe())
+
+const { test, expect } = require('@playwright/test');
+const { openDemo, runUntilIdle, eventsOf, metrics, terminal } = require('./demo');
+
+test.describe('attacks', () => {
+  let page;
+  let attacks;
+
+  test.beforeAll(async ({ browser }) => {
+    page = await browser.newPage();
+    await openDemo(page);
+    attacks = await page.evaluate(() => window.guardianDemo.attacks());
+  });
+
+  test.afterAll(async () => {
+    await page.close();
+  });
+
+  test('every attack runs to idle with the expected outcome', async () => {
+    expect(attacks.length).toBeGreaterThan(0);
+    for (const id of attacks) {
+      const info = await page.evaluate((a) => window.guardianDemo.describe(a), id);
+      const before = await metrics(page);
+      const messages = await runUntilIdle(page, 'runAttack', id);
+
+      // one start event per run, python always crashes and respawns
+      expect(eventsOf(messages, 'ATTACK').map((m) => m.attack), id).toEqual([id]);
+      expect(eventsOf(messages, 'CRASH', 'python').length, id).toBeGreaterThan(0);
+      expect(eventsOf(messages, 'RECOVERY', 'python').length, id).toBe(1);
+
+      const after = await metrics(page);
+      expect(after.counters.pythonCrashed, id).toBe(before.counters.pythonCrashed + 1);
+      expect(after.counters.wasmRejected, id).toBe(before.counters.wasmRejected + 1);
+      expect(after.busy).toBe(false);
+
+      if (info.kind === 'security') {
+        // default policy: the capability is denied, one instance traps and is rebuilt
+        expect(eventsOf(messages, 'TRAP', 'wasm').length, id).toBe(1);
+        expect(eventsOf(messages, 'BREACH', 'wasm'), id).toEqual([]);
+        const wasm = await terminal(page, 'wasm');
+        expect(wasm).toContain(`[ATTACK] Incoming: ${info.name}`);
+        expect(wasm).toContain('[OK] Zero downtime - continues with valid output');
+      } else {
+        // leader loss: raft elects a survivor, the old leader rebuilds as follower
+        expect(eventsOf(messages, 'ELECTION', 'wasm').length, id).toBe(1);
+        expect(eventsOf(messages, 'RECOVERY', 'wasm').length, id).toBe(1);
+      }
+      const python = await terminal(page, 'python');
+      expect(python.some((line) => line.includes('respawned')), id).toBe(true);
+    }
+  });
+
+  test('unknown attacks and overlapping runs are rejected', async () => {
+    await expect(page.evaluate(() => window.guardianDemo.runAttack('nope'))).rejects.toThrow(/unknown attack "nope"/);
+    const overlap = await page.evaluate(() => {
+      const api = window.guardianDemo;
+      api.runAttack('dataExfil');
+      try {
+        api.runAttack('dataExfil');
+        return 'accepted';
+      } catch (e) {
+        return String(e);
+      }
+    });
+    expect(overlap).toMatch(/still running/);
+    await page.waitForFunction(() => !window.guardianDemo.isBusy(), null, { timeout: 60_000 });
+  });
+});
@@ -0,0 +1,45 @@
+// what: helpers wrapping window.guardianDemo for the specs
+// why: every spec needs "open the demo, wait for pyodide, run something, collect events until idle"
+// relations: used by tests/*.spec.js; api defined in dashboard/src/headless/mod.rs
+
+/// loads the app, switches to the demo tab and waits until pyodide and the attack code are ready
+async function openDemo(page) {
+  await page.goto('/');
+  await page.waitForFunction(() => window.guardianDemo !== undefined);
+  await page.evaluate(() => window.guardianDemo.showDemo());
+  // runSensor() throws until pyodide is up, so poll on the flag the sensor button uses
+  await page.waitForFunction(() => !document.querySelector('.run-sensor')?.disabled, null, { timeout: 120_000 });
+  // runAttack()/runAll() throw until the attack-code module is imported; Run All is disabled until then
+  await page.waitForFunction(() => !document.querySelector('.runall')?.disabled, null, { timeout: 60_000 });
+}
+
+/// runs `action` ("runAttack", "runAll", "runSensor") and resolves with every message up to the next "idle"
+async function runUntilIdle(page, action, ...args) {
+  return page.evaluate(([action, args]) => new Promise((resolve, reject) => {
+    const api = window.guardianDemo;
+    const messages = [];
+    const id = api.on((msg) => {
+      messages.push(msg);
+      if (msg.type === 'idle') {
+        api.off(id);
+        resolve(messages);
+      }
+    });
+    try {
+      api[action](...args);
+    } catch (e) {
+      api.off(id);
+      reject(new Error(String(e)));
+    }
+  }), [action, args]);
+}
+
+/// journal events (type "event") of one kind, optionally for one runtime
+function eventsOf(messages, kind, runtime) {
+  return messages.filter((m) => m.type === 'event' && m.kind === kind && (runtime === undefined || m.runtime === runtime));
+}
+
+const metrics = (page) => page.evaluate(() => window.guardianDemo.getMetrics());
+const terminal = (page, runtime) => page.evaluate((r) => window.guardianDemo.terminal(r), runtime);
+
+module.exports = { openDemo, runUntilIdle, eventsOf, metrics, terminal };
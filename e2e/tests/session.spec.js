@@ -0,0 +1,37 @@
+// what: run-all, reset and sensor comparison through the headless api
+// why: run-all chains every attack on timers and reset must clear what it left behind
+// relations: helpers in demo.js
+
+const { test, expect } = require('@playwright/test');
+const { openDemo, runUntilIdle, eventsOf, metrics, terminal } = require('./demo');
+
+test('run all fires every attack once, in order', async ({ page }) => {
+  await openDemo(page);
+  const attacks = await page.evaluate(() => window.guardianDemo.attacks());
+  const messages = await runUntilIdle(page, 'runAll');
+  expect(eventsOf(messages, 'ATTACK').map((m) => m.attack)).toEqual(attacks);
+  expect((await metrics(page)).counters.pythonCrashed).toBe(attacks.length);
+});
+
+test('reset clears counters and terminals but keeps the journal', async ({ page }) => {
+  await openDemo(page);
+  await runUntilIdle(page, 'runAttack', 'bufferOverflow');
+  const before = await metrics(page);
+  expect(before.counters.pythonCrashed).toBe(1);
+
+  await page.evaluate(() => window.guardianDemo.reset());
+  const after = await metrics(page);
+  expect(Object.values(after.counters).every((v) => v === 0)).toBe(true);
+  expect(after.events).toBe(before.events + 1);
+  expect(await terminal(page, 'python')).toEqual([]);
+  expect(await terminal(page, 'wasm')).toEqual([]);
+});
+
+test('sensor comparison writes both terminals', async ({ page }) => {
+  await openDemo(page);
+  await page.evaluate(() => window.guardianDemo.runSensor());
+  await expect.poll(async () => (await terminal(page, 'python')).some((l) => l.startsWith('[OK] Pyodide executed in'))).toBe(true);
+  const wasm = await terminal(page, 'wasm');
+  expect(wasm).toContain('$ wasmtime sensor_driver.wasm');
+  expect(wasm.some((l) => l.startsWith('Temperature:'))).toBe(true);
+});
@@ -0,0 +1,64 @@
+// what: builds wasm-modules/sensor-driver twice for wasm32-wasip2 - the sensor-driver world (wit/sensor.wit) and the
+//       sensor-node world (wit/attacks.wit) - and copies both components into OUT_DIR
+// why: the agent and its wasmtime tests run the components the crate actually produces, not a hand-written stand-in
+// relations: same build command as dashboard/build-component.sh and its target dir; include_bytes!'d by src/wasm_host.rs (sensor_node.wasm) and src/tests/component.rs
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const CRATE: &str = "../wasm-modules/sensor-driver";
+const TARGET: &str = "wasm32-wasip2";
+/// (cargo feature, file name in OUT_DIR)
+const COMPONENTS: [(&str, &str); 2] = [("component", "sensor_driver.wasm"), ("sensor-node", "sensor_node.wasm")];
+
+/// env the outer build sets for itself that must not leak into the nested wasm build (clippy's wrapper, host rustflags)
+const OUTER_ENV: [&str; 6] = ["CARGO_ENCODED_RUSTFLAGS", "RUSTFLAGS", "RUSTC_WORKSPACE_WRAPPER", "CLIPPY_ARGS", "CARGO_TARGET_DIR", "CARGO_BUILD_TARGET"];
+
+fn target_installed(rustc: &str) -> bool {
+    Command::new(rustc)
+        .args(["--print", "sysroot"])
+        .output()
+        .ok()
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .is_some_and(|sysroot| Path::new(sysroot.trim()).join("lib/rustlib").join(TARGET).exists())
+}
+
+fn main() {
+    for path in ["src", "Cargo.toml"] {
+        println!("cargo:rerun-if-changed={}/{}", CRATE, path);
+    }
+    println!("cargo:rerun-if-changed=../guardian-types/src");
+    println!("cargo:rerun-if-changed=../wit/sensor.wit");
+    println!("cargo:rerun-if-changed=../wit/attacks.wit");
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    if !target_installed(&rustc) {
+        panic!("guardian-agent embeds the sensor components, which need the {} target: rustup target add {}", TARGET, TARGET);
+    }
+
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".into());
+    // shared with build-component.sh, and separate from the outer target dir so the two builds don't wait on each other's lock
+    let target_dir = std::env::var("CARGO_TARGET_DIR").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from("../target")).join("component");
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").expect("cargo sets OUT_DIR"));
+    for (feature, file) in COMPONENTS {
+        let mut build = Command::new(&cargo);
+        build
+            .args(["build", "--release", "--no-default-features", "--target", TARGET, "--features", feature])
+            .arg("--manifest-path")
+            .arg(Path::new(CRATE).join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(&target_dir);
+        if std::env::var("CARGO_NET_OFFLINE").is_ok_and(|v| v == "true") {
+            build.arg("--offline");
+        }
+        for var in OUTER_ENV {
+            build.env_remove(var);
+        }
+        let status = build.status().unwrap_or_else(|e| panic!("cannot run {}: {}", cargo, e));
+        if !status.success() {
+            panic!("building the {} component of sensor-driver failed", feature);
+        }
+        let built = target_dir.join(TARGET).join("release/sensor_driver.wasm");
+        std::fs::copy(&built, out_dir.join(file)).unwrap_or_else(|e| panic!("{}: {}", built.display(), e));
+    }
+}
@@ -0,0 +1,99 @@
+// what: three sensor-node instances, leader election, 2oo3 voting and fault injection on the pi
+// why: the same semantics the dashboard mock fakes, now driven by a real sensor and real instance restarts
+// relations: hosts from host.rs and wasm_host.rs, roles/election/vote from guardian-types cluster.rs (shared with dashboard/src/api/mock.rs),
+//            mutated by main.rs ticker, read by http.rs routes
+
+use guardian_types::cluster::{ClusterState, NODE_COUNT};
+use std::time::Duration;
+use guardian_types::{FaultReceipt, FaultRequest, NodeStatus, VoteResult, WasmtimeStats};
+use crate::host::{SensorHost, Trap};
+
+/// the agent's whole cluster: one host per node, roles and votes in the shared state machine
+pub struct Cluster {
+    hosts: Vec<Box<dyn SensorHost>>,
+    state: ClusterState,
+    instantiations: u64,
+    instantiate_time: Duration,
+    traps: u64,
+}
+
+impl Cluster {
+    /// instantiates every host; fails if any instance cannot start
+    pub fn new(hosts: Vec<Box<dyn SensorHost>>, now_ms: f64) -> Result<Self, String> {
+        if hosts.len() != NODE_COUNT {
+            return Err(format!("expected {} sensor nodes, got {}", NODE_COUNT, hosts.len()));
+        }
+        let mut cluster = Cluster { hosts, state: ClusterState::new(now_ms), instantiations: 0, instantiate_time: Duration::ZERO, traps: 0 };
+        for id in 0..NODE_COUNT {
+            cluster.instantiate(id)?;
+        }
+        Ok(cluster)
+    }
+
+    fn instantiate(&mut self, id: usize) -> Result<(), String> {
+        let took = self.hosts[id].instantiate()?;
+        self.instantiations += 1;
+        self.instantiate_time += took;
+        Ok(())
+    }
+
+    /// one sampling period: restart recovered nodes, run process-tick on each, vote, record;
+    /// a node that trapped gets a fresh instance for the next period
+    pub fn tick(&mut self, now_ms: f64, raw_celsius: f64) -> VoteResult {
+        for id in self.state.restarts_due(now_ms) {
+            if let Err(e) = self.instantiate(id) {
+                self.state.restart_failed(id, now_ms, &e);
+            }
+        }
+        let hosts = &mut self.hosts;
+        let mut trapped = Vec::new();
+        let record = self.state.round(now_ms, |id| {
+            hosts[id].process_tick(raw_celsius).map_err(|Trap::Denied(reason)| {
+                trapped.push(id);
+                reason
+            })
+        });
+        self.traps += trapped.len() as u64;
+        for id in trapped {
+            if let Err(e) = self.instantiate(id) {
+                self.state.restart_failed(id, now_ms, &e);
+            }
+        }
+        record
+    }
+
+    /// GET /nodes
+    pub fn list_nodes(&self, now_ms: f64) -> Vec<NodeStatus> {
+        self.state.list_nodes(now_ms)
+    }
+
+    /// GET /votes?limit=N, newest first
+    pub fn recent_votes(&self, limit: usize) -> Vec<VoteResult> {
+        self.state.recent_votes(limit)
+    }
+
+    /// GET /wasmtime/stats; None when the nodes are not wasm instances (native host)
+    pub fn wasmtime_stats(&self, now_ms: f64) -> Option<WasmtimeStats> {
+        let usage = self.hosts.iter().map(|host| host.usage()).collect::<Option<Vec<_>>>()?;
+        Some(WasmtimeStats {
+            instances: (0..NODE_COUNT).filter(|&id| !self.state.is_down(id, now_ms)).count() as u32,
+            instantiations: self.instantiations,
+            traps: self.traps,
+            fuel_consumed: usage.iter().map(|u| u.fuel_consumed).sum(),
+            memory_bytes: usage.iter().map(|u| u.memory_bytes).sum(),
+            avg_instantiate_us: self.instantiate_time.as_secs_f64() * 1e6 / self.instantiations.max(1) as f64,
+        })
+    }
+
+    /// POST /faults; Err is a rejection (unknown or recovering node)
+    pub fn inject(&mut self, now_ms: f64, request: &FaultRequest) -> Result<FaultReceipt, String> {
+        let receipt = self.state.inject(now_ms, request)?;
+        let host = &mut self.hosts[request.node as usize];
+        if receipt.trapped {
+            host.arm(request.kind);
+        } else {
+            host.terminate();
+        }
+        Ok(receipt)
+    }
+}
@@ -0,0 +1,56 @@
+// what: the /api/v1/events websocket - every tick's vote and node list pushed to each connected dashboard as a ClusterEvent
+// why: the hardware tab's live mode shows votes as they happen instead of polling /votes and /nodes
+// relations: published by main.rs ticker, upgraded by http.rs handle(), decoded by dashboard api/client.rs EventStream
+
+use std::net::TcpStream;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use guardian_types::ClusterEvent;
+use tungstenite::handshake::derive_accept_key;
+use tungstenite::protocol::Role;
+use tungstenite::{Message, WebSocket};
+
+/// everyone subscribed to the tick stream, cheap to clone
+#[derive(Clone, Default)]
+pub struct Events {
+    subscribers: Arc<Mutex<Vec<Sender<String>>>>,
+}
+
+impl Events {
+    /// receives every event published from now on, as json
+    pub fn subscribe(&self) -> Receiver<String> {
+        let (tx, rx) = channel();
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.push(tx);
+        }
+        rx
+    }
+
+    /// serializes once and hands the json to every subscriber; ones whose connection ended are dropped
+    pub fn publish(&self, event: &ClusterEvent) {
+        let Ok(json) = serde_json::to_string(event) else { return };
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.retain(|tx| tx.send(json.clone()).is_ok());
+        }
+    }
+}
+
+/// 101 reply to a websocket upgrade carrying `Sec-WebSocket-Key: key`
+pub fn handshake(key: &str) -> Vec<u8> {
+    format!(
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+        derive_accept_key(key.trim().as_bytes())
+    )
+    .into_bytes()
+}
+
+/// forwards events as text frames until the dashboard goes away; client frames are not read,
+/// a closed tab shows up as a failed write on the next tick
+pub fn stream(socket: TcpStream, events: Receiver<String>) {
+    let mut ws = WebSocket::from_raw_socket(socket, Role::Server, None);
+    for json in events {
+        if ws.send(Message::text(json)).is_err() {
+            return;
+        }
+    }
+}
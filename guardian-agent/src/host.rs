@@ -0,0 +1,153 @@
+// what: sensor-node hosts - what each of the three instances runs per tick, plus the physical bme280 reading
+// why: the cluster logic must not care whether a node is a wasm component or native code
+// relations: SensorHost implemented here by NativeHost and in wasm_host.rs by WasmtimeHost, driven by cluster.rs;
+//            reading source used by main.rs ticker
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+use guardian_types::FaultKind;
+
+/// attack-surface imports of the sensor-node world (wit/attacks.wit) and the host's answer to each
+pub const ATTACK_SURFACE: [(&str, &str); 4] = [
+    ("malloc-large", "memory limit 2MB exceeded"),
+    ("open-socket", "capability not granted: network"),
+    ("read-file", "capability not granted: filesystem"),
+    ("get-environment", "capability not granted: environment"),
+];
+
+/// attack-surface function a sandbox fault calls (kill-process is not a guest call)
+pub fn surface_call(kind: FaultKind) -> Option<&'static str> {
+    match kind {
+        FaultKind::BufferOverflow => Some("malloc-large"),
+        FaultKind::DataExfil => Some("open-socket"),
+        FaultKind::PathTraversal => Some("read-file"),
+        FaultKind::KillProcess => None,
+    }
+}
+
+/// host denial for an attack-surface import; every call is refused
+pub fn deny(func: &str) -> Trap {
+    let reason = ATTACK_SURFACE.iter().find(|(name, _)| *name == func).map(|(_, r)| *r).unwrap_or("import not provided by host");
+    Trap::Denied(reason.to_string())
+}
+
+/// why a tick produced no value
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Trap {
+    /// the host refused a capability or the guest hit a limit
+    Denied(String),
+}
+
+impl std::fmt::Display for Trap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Trap::Denied(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl std::error::Error for Trap {}
+
+/// what a sandboxed instance has used, for GET /wasmtime/stats
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// fuel burnt by every instance this host has run
+    pub fuel_consumed: u64,
+    /// linear memory of the live instance
+    pub memory_bytes: u64,
+}
+
+/// one sandboxed sensor-node instance
+pub trait SensorHost: Send {
+    /// (re)creates the instance; returns how long it took
+    fn instantiate(&mut self) -> Result<std::time::Duration, String>;
+    /// process-tick: raw celsius in, calibrated telemetry out
+    fn process_tick(&mut self, raw_celsius: f64) -> Result<f64, Trap>;
+    /// arms a fault that the next tick runs into
+    fn arm(&mut self, kind: FaultKind);
+    /// kill-process: the instance goes away until the next instantiate
+    fn terminate(&mut self) {}
+    /// runtime counters; None when there is no wasm runtime to count
+    fn usage(&self) -> Option<Usage> {
+        None
+    }
+}
+
+/// native stand-in for the wasm component: same calibration, same deny-by-default outcomes
+pub struct NativeHost {
+    armed: Option<FaultKind>,
+    /// per-node calibration offset, like three physically distinct boards
+    offset: f64,
+}
+
+impl NativeHost {
+    pub fn new(offset: f64) -> Self {
+        NativeHost { armed: None, offset }
+    }
+}
+
+impl SensorHost for NativeHost {
+    fn instantiate(&mut self) -> Result<std::time::Duration, String> {
+        let started = Instant::now();
+        self.armed = None;
+        Ok(started.elapsed())
+    }
+
+    fn process_tick(&mut self, raw_celsius: f64) -> Result<f64, Trap> {
+        match self.armed.take().and_then(surface_call) {
+            Some(func) => Err(deny(func)),
+            None => Ok(raw_celsius + self.offset),
+        }
+    }
+
+    fn arm(&mut self, kind: FaultKind) {
+        self.armed = Some(kind);
+    }
+}
+
+/// where the shared temperature reading comes from
+pub enum Reading {
+    /// kernel bme280 driver: /sys/bus/iio/devices/iio:deviceN/in_temp_input (milli-celsius)
+    Iio(PathBuf),
+    /// no sensor wired: a slow deterministic wave around 42°C
+    Simulated { tick: u64 },
+}
+
+impl Reading {
+    /// first iio device exposing a temperature channel, else simulated
+    pub fn detect() -> Self {
+        fs::read_dir("/sys/bus/iio/devices")
+            .ok()
+            .and_then(|dir| dir.flatten().map(|e| e.path().join("in_temp_input")).find(|p| p.exists()))
+            .map(Reading::Iio)
+            .unwrap_or(Reading::Simulated { tick: 0 })
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Reading::Iio(path) => format!("bme280 via {}", path.display()),
+            Reading::Simulated { .. } => "simulated bme280 (no iio device found)".to_string(),
+        }
+    }
+
+    /// celsius; an unreadable iio file falls back to the simulated wave for that tick
+    pub fn sample(&mut self) -> f64 {
+        match self {
+            Reading::Iio(path) => fs::read_to_string(path)
+                .ok()
+                .and_then(|s| s.trim().parse::<f64>().ok())
+                .map(|milli| milli / 1000.0)
+                .unwrap_or_else(|| simulated(0)),
+            Reading::Simulated { tick } => {
+                *tick += 1;
+                simulated(*tick)
+            }
+        }
+    }
+}
+
+/// 42°C ± 0.25 over a 60-tick period
+pub fn simulated(tick: u64) -> f64 {
+    42.0 + 0.25 * (tick as f64 * std::f64::consts::TAU / 60.0).sin()
+}
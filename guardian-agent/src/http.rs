@@ -0,0 +1,191 @@
+// what: minimal http/1.1 server for the cluster rest api (json, cors, one thread per connection)
+// why: the dashboard's live mode fetches /api/v1/* from the browser; std networking keeps the pi binary small
+// relations: routes into cluster.rs, hands /events upgrades to events.rs, endpoint list matches dashboard/src/api/mod.rs, started by main.rs
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use serde::Serialize;
+use guardian_types::{FaultRequest, STATUS_MAP};
+use guardian_types::cluster::VOTE_HISTORY;
+use crate::cluster::Cluster;
+use crate::events::{self, Events};
+
+/// every endpoint lives under this prefix
+pub const API_PREFIX: &str = "/api/v1/";
+/// votes returned when no ?limit= is given
+pub const DEFAULT_VOTE_LIMIT: usize = 20;
+/// request bodies above this are refused (fault requests are ~40 bytes)
+const MAX_BODY: usize = 16 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// a parsed request
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    /// path plus query, e.g. /api/v1/votes?limit=5
+    pub target: String,
+    pub body: String,
+    /// Sec-WebSocket-Key of a websocket upgrade request
+    pub websocket_key: Option<String>,
+}
+
+/// status and json body
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json<T: Serialize>(value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => Response { status: 200, body },
+            Err(e) => Response::error(500, &e.to_string()),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Response { status, body: serde_json::json!({ "error": message }).to_string() }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            204 => "No Content",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            409 => "Conflict",
+            413 => "Payload Too Large",
+            426 => "Upgrade Required",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// wire bytes; cors is open because the dashboard is served from another origin
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nAccess-Control-Allow-Origin: *\r\nAccess-Control-Allow-Methods: GET, POST, OPTIONS\r\nAccess-Control-Allow-Headers: Content-Type, Accept\r\nConnection: close\r\n",
+            self.status,
+            self.reason()
+        );
+        if self.status != 204 {
+            head.push_str(&format!("Content-Type: application/json\r\nContent-Length: {}\r\n", self.body.len()));
+        }
+        head.push_str("\r\n");
+        let mut bytes = head.into_bytes();
+        if self.status != 204 {
+            bytes.extend_from_slice(self.body.as_bytes());
+        }
+        bytes
+    }
+}
+
+/// reads request line, headers and a content-length body; notes the key of a websocket upgrade
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let bad = |m: &str| Response::error(400, m);
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(|e| bad(&e.to_string()))?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else { return Err(bad("malformed request line")) };
+    let (method, target) = (method.to_string(), target.to_string());
+    let mut length = 0usize;
+    let (mut upgrade, mut key) = (false, None);
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).map_err(|e| bad(&e.to_string()))? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let name = name.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().map_err(|_| bad("bad content-length"))?;
+            } else if name.eq_ignore_ascii_case("upgrade") {
+                upgrade = value.trim().eq_ignore_ascii_case("websocket");
+            } else if name.eq_ignore_ascii_case("sec-websocket-key") {
+                key = Some(value.trim().to_string());
+            }
+        }
+    }
+    if length > MAX_BODY {
+        return Err(Response::error(413, "body too large"));
+    }
+    let mut body = vec![0u8; length];
+    reader.read_exact(&mut body).map_err(|e| bad(&e.to_string()))?;
+    let body = String::from_utf8(body).map_err(|_| bad("body is not utf-8"))?;
+    Ok(Request { method, target, body, websocket_key: key.filter(|_| upgrade) })
+}
+
+/// ?limit=N, clamped to what the agent keeps
+fn vote_limit(query: &str) -> usize {
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("limit="))
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_VOTE_LIMIT)
+        .min(VOTE_HISTORY)
+}
+
+/// dispatches one request against the cluster
+pub fn route(cluster: &mut Cluster, now_ms: f64, request: &Request) -> Response {
+    if request.method == "OPTIONS" {
+        return Response { status: 204, body: String::new() };
+    }
+    let (path, query) = request.target.split_once('?').unwrap_or((&request.target, ""));
+    let Some(endpoint) = path.strip_prefix(API_PREFIX) else { return Response::error(404, "not found") };
+    match (request.method.as_str(), endpoint.trim_end_matches('/')) {
+        ("GET", "nodes") => Response::json(&cluster.list_nodes(now_ms)),
+        ("GET", "votes") => Response::json(&cluster.recent_votes(vote_limit(query))),
+        ("GET", "status-map") => Response::json(&STATUS_MAP),
+        // upgrades are taken over by handle() before routing
+        ("GET", "events") => Response::error(426, "websocket upgrade required"),
+        ("GET", "wasmtime/stats") => match cluster.wasmtime_stats(now_ms) {
+            Some(stats) => Response::json(&stats),
+            None => Response::error(404, "nodes run on the native host: no wasmtime counters"),
+        },
+        ("POST", "faults") => match serde_json::from_str::<FaultRequest>(&request.body) {
+            Ok(fault) => match cluster.inject(now_ms, &fault) {
+                Ok(receipt) => Response::json(&receipt),
+                Err(e) => Response::error(409, &e),
+            },
+            Err(e) => Response::error(400, &e.to_string()),
+        },
+        (_, "nodes" | "votes" | "status-map" | "wasmtime/stats" | "events" | "faults") => Response::error(405, "method not allowed"),
+        _ => Response::error(404, "not found"),
+    }
+}
+
+fn handle(stream: TcpStream, cluster: &Mutex<Cluster>, events: &Events, clock: fn() -> f64) {
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+    let Ok(mut writer) = stream.try_clone() else { return };
+    let mut reader = BufReader::new(stream);
+    let response = match read_request(&mut reader) {
+        Ok(Request { method, target, websocket_key: Some(key), .. }) if method == "GET" && target.starts_with(&format!("{}events", API_PREFIX)) => {
+            eprintln!("{} {} -> 101", method, target);
+            if writer.write_all(&events::handshake(&key)).is_ok() {
+                events::stream(writer, events.subscribe());
+            }
+            return;
+        }
+        Ok(request) => {
+            let response = match cluster.lock() {
+                Ok(mut cluster) => route(&mut cluster, clock(), &request),
+                Err(_) => Response::error(500, "cluster state poisoned"),
+            };
+            eprintln!("{} {} -> {}", request.method, request.target, response.status);
+            response
+        }
+        Err(response) => response,
+    };
+    let _ = writer.write_all(&response.to_bytes());
+}
+
+/// accepts connections until the listener fails
+pub fn serve(listener: TcpListener, cluster: Arc<Mutex<Cluster>>, events: Events, clock: fn() -> f64) {
+    for stream in listener.incoming().flatten() {
+        let (cluster, events) = (Arc::clone(&cluster), events.clone());
+        std::thread::spawn(move || handle(stream, &cluster, &events, clock));
+    }
+}
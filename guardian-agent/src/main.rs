@@ -0,0 +1,104 @@
+// what: guardian-agent entry point - three sensor nodes on a tick loop plus the rest api the dashboard's live mode reads
+// why: turns the hardware tab's "coming soon" into a binary that runs on the pi next to the bme280
+// relations: wasm_host.rs (sensor-node instances under wasmtime), host.rs (native stand-in + sensor reading), cluster.rs (voting/election/faults), http.rs (server); wire format from guardian-types
+//
+// usage: guardian-agent [--bind 0.0.0.0:8080] [--tick-ms 1000] [--native]
+//   --native runs the nodes as native code instead of wasm instances (no /wasmtime/stats); then point the dashboard's cluster data source at http://<pi>:8080
+
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+mod cluster;
+mod events;
+mod host;
+mod http;
+mod wasm_host;
+
+#[cfg(test)]
+mod tests;
+
+use cluster::Cluster;
+use events::Events;
+use guardian_types::ClusterEvent;
+use host::{NativeHost, Reading, SensorHost};
+use wasm_host::SensorRuntime;
+
+const DEFAULT_BIND: &str = "0.0.0.0:8080";
+const DEFAULT_TICK_MS: u64 = 1000;
+/// per-node calibration offsets (°C), small enough to stay inside the vote tolerance
+const NODE_OFFSETS: [f64; 3] = [0.0, 0.02, -0.02];
+
+/// command line options
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub bind: String,
+    pub tick_ms: u64,
+    /// native stand-in instead of the sensor-node component
+    pub native: bool,
+}
+
+impl Options {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options { bind: DEFAULT_BIND.to_string(), tick_ms: DEFAULT_TICK_MS, native: false };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+            match arg.as_str() {
+                "--bind" => options.bind = value()?,
+                "--tick-ms" => options.tick_ms = value()?.parse().map_err(|_| "--tick-ms must be a number of milliseconds".to_string())?,
+                "--native" => options.native = true,
+                "-h" | "--help" => return Err(format!("usage: guardian-agent [--bind {}] [--tick-ms {}] [--native]", DEFAULT_BIND, DEFAULT_TICK_MS)),
+                other => return Err(format!("unknown argument '{}'", other)),
+            }
+        }
+        if options.tick_ms == 0 {
+            return Err("--tick-ms must be at least 1".to_string());
+        }
+        Ok(options)
+    }
+}
+
+fn now_ms() -> f64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs_f64() * 1000.0).unwrap_or(0.0)
+}
+
+fn run(options: Options) -> Result<(), String> {
+    let hosts: Vec<Box<dyn SensorHost>> = if options.native {
+        NODE_OFFSETS.iter().map(|offset| Box::new(NativeHost::new(*offset)) as Box<dyn SensorHost>).collect()
+    } else {
+        let runtime = SensorRuntime::new()?;
+        NODE_OFFSETS.iter().map(|offset| Box::new(runtime.host(*offset)) as Box<dyn SensorHost>).collect()
+    };
+    let cluster = Arc::new(Mutex::new(Cluster::new(hosts, now_ms())?));
+    let mut reading = Reading::detect();
+    let kind = if options.native { "native" } else { "wasmtime sensor-node" };
+    eprintln!("guardian-agent: {} {} nodes, sensor: {}", guardian_types::cluster::NODE_COUNT, kind, reading.describe());
+
+    let events = Events::default();
+    let (ticker, publisher) = (Arc::clone(&cluster), events.clone());
+    let period = Duration::from_millis(options.tick_ms);
+    std::thread::spawn(move || loop {
+        let raw = reading.sample();
+        if let Ok(mut cluster) = ticker.lock() {
+            let now = now_ms();
+            let vote = cluster.tick(now, raw);
+            publisher.publish(&ClusterEvent { vote, nodes: cluster.list_nodes(now) });
+        }
+        std::thread::sleep(period);
+    });
+
+    let listener = TcpListener::bind(&options.bind).map_err(|e| format!("cannot bind {}: {}", options.bind, e))?;
+    let (prefix, bind) = (http::API_PREFIX, &options.bind);
+    eprintln!("guardian-agent: serving {}{{nodes,votes,faults,status-map,wasmtime/stats}} on http://{} and ws://{}{}events", prefix, bind, bind, prefix);
+    http::serve(listener, cluster, events, now_ms);
+    Ok(())
+}
+
+fn main() {
+    let result = Options::parse(std::env::args().skip(1)).and_then(run);
+    if let Err(e) = result {
+        eprintln!("guardian-agent: {}", e);
+        std::process::exit(2);
+    }
+}
@@ -0,0 +1,48 @@
+// what: tests for the agent's cluster - sensor hosts driven through the shared state machine, fault injection and recovery
+// why: the live dashboard shows exactly what these produce, so they must match the mock's story
+
+use guardian_types::{FaultKind, FaultRequest, NodeRole};
+use guardian_types::cluster::{RECOVERY_MS, VOTE_HISTORY};
+use crate::cluster::Cluster;
+use crate::host::{NativeHost, SensorHost};
+
+fn cluster() -> Cluster {
+    let hosts: Vec<Box<dyn SensorHost>> = [0.0, 0.02, -0.02].iter().map(|o| Box::new(NativeHost::new(*o)) as Box<dyn SensorHost>).collect();
+    Cluster::new(hosts, 0.0).unwrap()
+}
+
+#[test]
+fn trap_fault_voted_out_for_one_tick() {
+    // what: a sandbox fault traps the node's next tick only; the vote carries on and the trap is reported on the node
+    // why: the wasm claim is "contained, no downtime", unlike a killed process
+    let mut c = cluster();
+    let receipt = c.inject(0.0, &FaultRequest { node: 1, kind: FaultKind::DataExfil }).unwrap();
+    assert!(receipt.trapped);
+    let first = c.tick(1000.0, 42.0);
+    assert_eq!(first.values[1], None);
+    assert_eq!(first.faulty_node, Some(1));
+    assert!(first.voted.is_some());
+    assert_eq!(c.tick(2000.0, 42.0).faulty_node, None);
+    assert_eq!(c.list_nodes(2000.0)[1].last_trap.as_deref(), Some("capability not granted: network"));
+}
+
+#[test]
+fn killed_leader_fails_over_and_rejoins() {
+    // what: killing the leader elects node 1, node 0 recovers and is re-instantiated after RECOVERY_MS
+    // why: failover is what the live cluster view exists to show
+    let mut c = cluster();
+    let receipt = c.inject(0.0, &FaultRequest { node: 0, kind: FaultKind::KillProcess }).unwrap();
+    assert!(!receipt.trapped);
+    assert!(receipt.message.contains("node 1 elected leader"));
+    assert!(c.inject(10.0, &FaultRequest { node: 0, kind: FaultKind::BufferOverflow }).is_err());
+    assert!(c.inject(10.0, &FaultRequest { node: 7, kind: FaultKind::BufferOverflow }).is_err());
+    let roles = |c: &Cluster, t| c.list_nodes(t).iter().map(|n| n.role).collect::<Vec<_>>();
+    assert_eq!(roles(&c, 1000.0), [NodeRole::Recovering, NodeRole::Leader, NodeRole::Follower]);
+    c.tick(1000.0, 42.0);
+    c.tick(RECOVERY_MS + 1.0, 42.0);
+    assert_eq!(roles(&c, RECOVERY_MS + 1.0)[0], NodeRole::Follower);
+    for t in 0..VOTE_HISTORY + 5 {
+        c.tick(10_000.0 + t as f64, 42.0);
+    }
+    assert_eq!(c.recent_votes(usize::MAX).len(), VOTE_HISTORY);
+}
@@ -0,0 +1,140 @@
+// what: the components wasm-modules/sensor-driver builds (build.rs) run under wasmtime - the sensor-driver world and the sensor-node world
+// why: wit_contract.rs checks the contract as text; this checks that the component model itself refuses what the host does not provide
+// note: the sensor-node build reads its fault knob from register 1 (sensor-driver node.rs FAULT_REGISTER): n calls the n-th attack-surface import
+
+use wasmtime::component::{Component, ComponentType, Lift, Linker};
+use wasmtime::{Engine, Store, StoreContextMut};
+use crate::host::ATTACK_SURFACE;
+
+const SENSOR_DRIVER: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/sensor_driver.wasm"));
+const SENSOR_NODE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/sensor_node.wasm"));
+const DRIVER: &str = "guardian-one:sensor/driver@0.1.0";
+const CAPABILITIES: &str = "guardian-one:attacks/sensor-capabilities@0.1.0";
+const ATTACK_SURFACE_IMPORT: &str = "guardian-one:attacks/attack-surface@0.1.0";
+
+/// common-types.telemetry-packet as the host lifts it
+#[derive(ComponentType, Lift, Debug, PartialEq)]
+#[component(record)]
+struct TelemetryPacket {
+    timestamp: u64,
+    value: f64,
+    status: u8,
+}
+
+/// driver.sensor-reading as the host lifts it
+#[derive(ComponentType, Lift, Debug, PartialEq)]
+#[component(record)]
+struct SensorReading {
+    temperature: f64,
+    humidity: f64,
+    pressure: f64,
+}
+
+/// what the granted imports read and record
+#[derive(Default)]
+struct Board {
+    celsius: f64,
+    attack: u32,
+    log: Vec<String>,
+}
+
+/// grants sensor-capabilities only; attack-surface and wasi are left out, as on the pi, or stubbed with functions that trap when called
+fn capabilities_only(engine: &Engine, trap_unknown: Option<&Component>) -> Linker<Board> {
+    let mut linker = Linker::new(engine);
+    if let Some(component) = trap_unknown {
+        // stubs first, so the granted interface defined below replaces its own stubs
+        linker.allow_shadowing(true);
+        linker.define_unknown_imports_as_traps(component).unwrap();
+    }
+    let mut caps = linker.instance(CAPABILITIES).unwrap();
+    caps.func_wrap("read-hardware-register", |store: StoreContextMut<Board>, (reg,): (u32,)| {
+        let board = store.data();
+        Ok((match reg {
+            0 => board.celsius,
+            1 => f64::from(board.attack),
+            _ => 0.0,
+        },))
+    })
+    .unwrap();
+    caps.func_wrap("log-debug", |mut store: StoreContextMut<Board>, (msg,): (String,)| {
+        store.data_mut().log.push(msg);
+        Ok(())
+    })
+    .unwrap();
+    linker
+}
+
+/// one process-tick on a fresh instance
+fn tick(engine: &Engine, component: &Component, linker: &Linker<Board>, board: Board) -> (wasmtime::Result<TelemetryPacket>, Board) {
+    let mut store = Store::new(engine, board);
+    let instance = linker.instantiate(&mut store, component).unwrap();
+    let process_tick = instance.get_typed_func::<(), (TelemetryPacket,)>(&mut store, "process-tick").unwrap();
+    let packet = process_tick.call(&mut store, ()).map(|(p,)| p);
+    (packet, store.into_data())
+}
+
+#[test]
+fn sensor_driver_needs_no_imports_and_reads_like_the_native_build() {
+    // what: the sensor-driver component imports nothing - not even wasi - and instantiates on an empty linker;
+    //       init-sensor and sensor-check return what the native crate returns
+    // why: the browser's jco build transpiles this same .wasm; the reading must not depend on which build answered
+    let engine = Engine::default();
+    let component = Component::new(&engine, SENSOR_DRIVER).unwrap();
+    let ty = component.component_type();
+    assert_eq!(ty.imports(&engine).count(), 0);
+    assert_eq!(ty.exports(&engine).map(|(name, _)| name).collect::<Vec<_>>(), [DRIVER]);
+
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine).instantiate(&mut store, &component).unwrap();
+    let driver = instance.get_export_index(&mut store, None, DRIVER).unwrap();
+    let func = |store: &mut Store<()>, name: &str| instance.get_export_index(&mut *store, Some(&driver), name).unwrap();
+    let init = func(&mut store, "init-sensor");
+    let check = func(&mut store, "sensor-check");
+    let init = instance.get_typed_func::<(), (bool,)>(&mut store, init).unwrap();
+    assert_eq!(init.call(&mut store, ()).unwrap(), (true,));
+    init.post_return(&mut store).unwrap();
+    let (reading,) = instance.get_typed_func::<(), (Result<SensorReading, String>,)>(&mut store, check).unwrap().call(&mut store, ()).unwrap();
+    let native = sensor_driver::read_sensor();
+    assert_eq!(reading, Ok(SensorReading { temperature: native.temperature, humidity: native.humidity, pressure: native.pressure }));
+}
+
+#[test]
+fn process_tick_returns_a_telemetry_packet() {
+    // what: the sensor-node component imports the three wit interfaces (common-types for its types only) ahead of the wasi ones
+    //       rust's std pulls in, and exports process-tick; a tick through the granted imports returns
+    //       {timestamp: tick count, value: register 0, status: 0} and logs via log-debug
+    // why: this is the call the agent's vote consumes, checked through the canonical abi rather than read off the wit text
+    let engine = Engine::default();
+    let component = Component::new(&engine, SENSOR_NODE).unwrap();
+    let ty = component.component_type();
+    let imports: Vec<&str> = ty.imports(&engine).map(|(name, _)| name).collect();
+    assert_eq!(imports[..3], [CAPABILITIES, ATTACK_SURFACE_IMPORT, "guardian-one:attacks/common-types@0.1.0"]);
+    assert!(imports[3..].iter().all(|name| name.starts_with("wasi:cli/") || name.starts_with("wasi:io/")), "{:?}", imports);
+    assert_eq!(ty.exports(&engine).map(|(name, _)| name).collect::<Vec<_>>(), ["process-tick"]);
+
+    let (packet, board) = tick(&engine, &component, &capabilities_only(&engine, Some(&component)), Board { celsius: 23.5, ..Board::default() });
+    assert_eq!(packet.unwrap(), TelemetryPacket { timestamp: 1, value: 23.5, status: 0 });
+    assert_eq!(board.log, ["tick"]);
+}
+
+#[test]
+fn attack_surface_is_not_provided_and_traps() {
+    // what: without attack-surface the component does not instantiate at all; with its functions stubbed as traps,
+    //       each attack traps the tick before any packet or log line, and a fresh instance ticks normally again
+    // why: "every attack-surface import traps" is the sandbox claim the demo narrates; here wasmtime enforces it
+    let engine = Engine::default();
+    let component = Component::new(&engine, SENSOR_NODE).unwrap();
+    let mut store = Store::new(&engine, Board::default());
+    let refused = capabilities_only(&engine, None).instantiate(&mut store, &component).map(|_| ()).expect_err("instantiated without attack-surface");
+    assert!(format!("{:?}", refused).contains(ATTACK_SURFACE_IMPORT), "{:?}", refused);
+
+    let linker = capabilities_only(&engine, Some(&component));
+    for (n, (func, _)) in ATTACK_SURFACE.iter().enumerate() {
+        let (packet, board) = tick(&engine, &component, &linker, Board { celsius: 42.0, attack: n as u32 + 1, ..Board::default() });
+        let trap = format!("{:?}", packet.unwrap_err());
+        assert!(trap.contains(&format!("{}#{}", ATTACK_SURFACE_IMPORT, func)), "{}: {}", func, trap);
+        assert!(board.log.is_empty(), "{} ran past the trap", func);
+    }
+    let (packet, _) = tick(&engine, &component, &linker, Board { celsius: 42.0, ..Board::default() });
+    assert_eq!(packet.unwrap().value, 42.0);
+}
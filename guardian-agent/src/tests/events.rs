@@ -0,0 +1,77 @@
+// what: tests for the /api/v1/events websocket - upgrade parsing, the handshake, and ticks arriving at a connected client
+// why: the dashboard's live mode renders from this stream; a broken handshake or frame leaves the cluster view frozen
+
+use std::io::{Cursor, ErrorKind};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use guardian_types::{ClusterEvent, NodeRole};
+use crate::cluster::Cluster;
+use crate::events::{handshake, Events};
+use crate::host::{NativeHost, SensorHost};
+use crate::http::{read_request, route, serve};
+
+fn cluster() -> Cluster {
+    let hosts: Vec<Box<dyn SensorHost>> = (0..3).map(|_| Box::new(NativeHost::new(0.0)) as Box<dyn SensorHost>).collect();
+    Cluster::new(hosts, 0.0).unwrap()
+}
+
+#[test]
+fn upgrade_is_recognised_and_answered_per_rfc_6455() {
+    // what: the key is kept only when the request asks for a websocket upgrade; the 101 carries the rfc 6455 sample accept value;
+    //       a plain GET /events is 426 and any other method 405
+    // why: browsers refuse the socket unless Sec-WebSocket-Accept is exactly the derived value
+    let raw = "GET /api/v1/events HTTP/1.1\r\nHost: pi\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n";
+    let request = read_request(&mut Cursor::new(raw)).unwrap();
+    assert_eq!(request.websocket_key.as_deref(), Some("dGhlIHNhbXBsZSBub25jZQ=="));
+    let no_upgrade = read_request(&mut Cursor::new("GET /api/v1/events HTTP/1.1\r\nSec-WebSocket-Key: abc\r\n\r\n")).unwrap();
+    assert_eq!(no_upgrade.websocket_key, None);
+
+    let reply = String::from_utf8(handshake("dGhlIHNhbXBsZSBub25jZQ==")).unwrap();
+    assert!(reply.starts_with("HTTP/1.1 101 Switching Protocols\r\n"));
+    assert!(reply.contains("\r\nSec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=\r\n") && reply.ends_with("\r\n\r\n"));
+
+    let mut c = cluster();
+    assert_eq!(route(&mut c, 0.0, &no_upgrade).status, 426);
+    let post = read_request(&mut Cursor::new("POST /api/v1/events HTTP/1.1\r\n\r\n")).unwrap();
+    assert_eq!(route(&mut c, 0.0, &post).status, 405);
+}
+
+#[test]
+fn ticks_reach_a_connected_dashboard() {
+    // what: a websocket client connected to the running server receives published ticks as ClusterEvent json text frames
+    // why: this is the whole path the hardware tab's live mode takes, socket to decoded event
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let cluster = Arc::new(Mutex::new(cluster()));
+    let events = Events::default();
+    let (server_cluster, server_events) = (Arc::clone(&cluster), events.clone());
+    std::thread::spawn(move || serve(listener, server_cluster, server_events, || 1000.0));
+
+    let socket = TcpStream::connect(addr).unwrap();
+    let (mut ws, response) = tungstenite::client(format!("ws://{}/api/v1/events", addr), socket).unwrap();
+    assert_eq!(response.status(), 101);
+    ws.get_ref().set_read_timeout(Some(Duration::from_millis(50))).unwrap();
+
+    // the server subscribes just after its 101, so publish until the first tick lands
+    for attempt in 0.. {
+        assert!(attempt < 100, "no event received");
+        let event = {
+            let mut c = cluster.lock().unwrap();
+            let vote = c.tick(1000.0 + attempt as f64, 42.0);
+            ClusterEvent { vote, nodes: c.list_nodes(1000.0) }
+        };
+        events.publish(&event);
+        match ws.read() {
+            Ok(message) => {
+                let received: ClusterEvent = serde_json::from_str(message.to_text().unwrap()).unwrap();
+                assert_eq!(received, event);
+                assert_eq!(received.nodes[0].role, NodeRole::Leader);
+                assert_eq!(received.vote.voted, Some(42.0));
+                break;
+            }
+            Err(tungstenite::Error::Io(e)) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => continue,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
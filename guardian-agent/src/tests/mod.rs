@@ -0,0 +1,20 @@
+// what: exports all test modules for guardian-agent
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod cluster;
+
+#[cfg(test)]
+mod component;
+
+#[cfg(test)]
+mod routes;
+
+#[cfg(test)]
+mod wit_contract;
+
+#[cfg(test)]
+mod wasm_host;
+
+#[cfg(test)]
+mod events;
@@ -0,0 +1,69 @@
+// what: tests for request parsing, routing and the json the dashboard decodes
+// why: the dashboard's live client fails on any drift in paths, status codes or field spellings
+
+use std::io::Cursor;
+use crate::cluster::Cluster;
+use crate::host::{NativeHost, SensorHost};
+use crate::http::{read_request, route, Request, Response};
+
+fn cluster() -> Cluster {
+    let hosts: Vec<Box<dyn SensorHost>> = (0..3).map(|_| Box::new(NativeHost::new(0.0)) as Box<dyn SensorHost>).collect();
+    Cluster::new(hosts, 0.0).unwrap()
+}
+
+fn request(method: &str, target: &str, body: &str) -> Request {
+    Request { method: method.into(), target: target.into(), body: body.into(), websocket_key: None }
+}
+
+#[test]
+fn parses_request_with_body() {
+    // what: request line, case-insensitive content-length and the body are read; oversized bodies are refused
+    // why: browsers send POST /faults with a json body after the headers
+    let raw = "POST /api/v1/faults HTTP/1.1\r\nHost: pi\r\ncontent-length: 34\r\n\r\n{\"node\":1,\"kind\":\"kill-process\"}  ";
+    let parsed = read_request(&mut Cursor::new(raw)).unwrap();
+    assert_eq!(parsed, request("POST", "/api/v1/faults", "{\"node\":1,\"kind\":\"kill-process\"}  "));
+    assert_eq!(read_request(&mut Cursor::new("GARBAGE\r\n\r\n")).unwrap_err().status, 400);
+    assert_eq!(read_request(&mut Cursor::new("POST / HTTP/1.1\r\nContent-Length: 99999999\r\n\r\n")).unwrap_err().status, 413);
+}
+
+#[test]
+fn endpoints_return_dashboard_wire_format() {
+    // what: nodes, votes and the status map serialize with the field names and kebab-case enums of guardian-types (what the dashboard decodes);
+    //       wasmtime/stats is 404 on the native host
+    // why: the live client decodes with those exact types, and the native host has no wasmtime counters to report (wasm_host.rs covers the served stats)
+    let mut c = cluster();
+    c.tick(1000.0, 42.0);
+    c.tick(2000.0, 42.0);
+    let nodes: serde_json::Value = serde_json::from_str(&route(&mut c, 2000.0, &request("GET", "/api/v1/nodes", "")).body).unwrap();
+    assert_eq!(nodes[0]["role"], "leader");
+    assert_eq!(nodes[1]["role"], "follower");
+    assert_eq!(nodes[0]["packets_processed"], 2);
+    assert!(nodes[0]["last_trap"].is_null());
+    let votes: serde_json::Value = serde_json::from_str(&route(&mut c, 2000.0, &request("GET", "/api/v1/votes?limit=1", "")).body).unwrap();
+    assert_eq!(votes.as_array().map(Vec::len), Some(1));
+    assert_eq!(votes[0]["timestamp_ms"], 2000.0);
+    assert_eq!(votes[0]["voted"], 42.0);
+    assert_eq!(route(&mut c, 2000.0, &request("GET", "/api/v1/wasmtime/stats", "")).status, 404);
+    let map: serde_json::Value = serde_json::from_str(&route(&mut c, 2000.0, &request("GET", "/api/v1/status-map", "")).body).unwrap();
+    assert_eq!(map[0]["status"], "consensus");
+}
+
+#[test]
+fn faults_cors_and_errors() {
+    // what: a fault post returns a receipt, rejections are 409, bad json 400, wrong method 405, unknown 404, preflight 204 with cors
+    // why: the browser preflights the json post and surfaces these statuses in the cluster panel
+    let mut c = cluster();
+    let ok = route(&mut c, 0.0, &request("POST", "/api/v1/faults", r#"{"node":2,"kind":"path-traversal"}"#));
+    assert_eq!(ok.status, 200);
+    assert!(ok.body.contains(r#""kind":"path-traversal""#) && ok.body.contains(r#""trapped":true"#));
+    assert_eq!(route(&mut c, 0.0, &request("POST", "/api/v1/faults", r#"{"node":9,"kind":"kill-process"}"#)).status, 409);
+    assert_eq!(route(&mut c, 0.0, &request("POST", "/api/v1/faults", "{}")).status, 400);
+    assert_eq!(route(&mut c, 0.0, &request("DELETE", "/api/v1/nodes", "")).status, 405);
+    assert_eq!(route(&mut c, 0.0, &request("GET", "/api/v2/nodes", "")).status, 404);
+    let preflight = route(&mut c, 0.0, &request("OPTIONS", "/api/v1/faults", ""));
+    let wire = String::from_utf8(preflight.to_bytes()).unwrap();
+    assert!(wire.starts_with("HTTP/1.1 204 No Content\r\n"));
+    assert!(wire.contains("Access-Control-Allow-Origin: *") && wire.contains("Access-Control-Allow-Headers: Content-Type"));
+    let wire = String::from_utf8(Response { status: 200, body: "[]".into() }.to_bytes()).unwrap();
+    assert!(wire.ends_with("Content-Length: 2\r\n\r\n[]"));
+}
@@ -0,0 +1,60 @@
+// what: the cluster running on WasmtimeHost - three sensor-node component instances under wasmtime - and the stats it serves
+// why: the live dashboard's "sandboxed node" claims are about these instances; the native host only stands in for them
+// note: the component is the one build.rs builds (needs the wasm32-wasip2 target), see component.rs for the bare canonical-abi checks
+
+use guardian_types::cluster::RECOVERY_MS;
+use guardian_types::{FaultKind, FaultRequest, NodeRole};
+use crate::cluster::Cluster;
+use crate::host::SensorHost;
+use crate::http::{route, Request};
+use crate::wasm_host::{SensorRuntime, MEMORY_LIMIT_BYTES};
+
+fn cluster() -> Cluster {
+    let runtime = SensorRuntime::new().unwrap();
+    let hosts: Vec<Box<dyn SensorHost>> = [0.0, 0.02, -0.02].iter().map(|o| Box::new(runtime.host(*o)) as Box<dyn SensorHost>).collect();
+    Cluster::new(hosts, 0.0).unwrap()
+}
+
+#[test]
+fn trapped_instance_is_replaced_and_counted() {
+    // what: each node returns register 0 plus its offset; an armed fault traps inside wasmtime with the host's denial,
+    //       the node gets a fresh instance and votes again next tick; kill drops the instance until recovery
+    // why: "contained, no downtime" has to come from real instances trapping, not from the native stand-in
+    let mut c = cluster();
+    assert_eq!(c.tick(1000.0, 42.0).values, [Some(42.0), Some(42.02), Some(41.98)]);
+    c.inject(1000.0, &FaultRequest { node: 1, kind: FaultKind::PathTraversal }).unwrap();
+    let trapped = c.tick(2000.0, 42.0);
+    assert_eq!((trapped.values[1], trapped.faulty_node), (None, Some(1)));
+    assert_eq!(c.list_nodes(2000.0)[1].last_trap.as_deref(), Some("capability not granted: filesystem"));
+    assert_eq!(c.tick(3000.0, 42.0).values[1], Some(42.02));
+
+    let stats = c.wasmtime_stats(3000.0).unwrap();
+    assert_eq!((stats.instances, stats.instantiations, stats.traps), (3, 4, 1));
+    assert!(stats.fuel_consumed > 0 && stats.avg_instantiate_us > 0.0);
+    assert!(stats.memory_bytes > 0 && stats.memory_bytes <= 3 * MEMORY_LIMIT_BYTES as u64, "{}", stats.memory_bytes);
+
+    c.inject(3000.0, &FaultRequest { node: 2, kind: FaultKind::KillProcess }).unwrap();
+    let killed = c.wasmtime_stats(3001.0).unwrap();
+    assert_eq!(killed.instances, 2);
+    assert_eq!(killed.memory_bytes, stats.memory_bytes / 3 * 2);
+    c.tick(3000.0 + RECOVERY_MS, 42.0);
+    assert_eq!(c.list_nodes(3000.0 + RECOVERY_MS)[2].role, NodeRole::Follower);
+    assert_eq!(c.wasmtime_stats(3000.0 + RECOVERY_MS).unwrap().instantiations, 5);
+}
+
+#[test]
+fn stats_route_serves_the_dashboard_shape() {
+    // what: GET /wasmtime/stats returns every WasmtimeStats field the dashboard decodes; POST to it is 405
+    // why: the hardware tab's runtime counters read this route in live mode
+    let mut c = cluster();
+    c.tick(1000.0, 42.0);
+    let request = |method: &str| Request { method: method.into(), target: "/api/v1/wasmtime/stats".into(), body: String::new(), websocket_key: None };
+    let response = route(&mut c, 1000.0, &request("GET"));
+    assert_eq!(response.status, 200);
+    let stats: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+    for field in ["instances", "instantiations", "traps", "fuel_consumed", "memory_bytes", "avg_instantiate_us"] {
+        assert!(stats[field].is_number(), "{} missing from {}", field, response.body);
+    }
+    assert_eq!(stats["instances"], 3);
+    assert_eq!(route(&mut c, 1000.0, &request("POST")).status, 405);
+}
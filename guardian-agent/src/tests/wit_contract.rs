@@ -0,0 +1,71 @@
+// what: host-side checks of the sensor-node wit contract (wit/attacks.wit) against the agent's host
+// why: "every attack-surface import traps" should be checked by cargo test, not only narrated in the ui
+// note: these run against the native host; component.rs runs the same world under wasmtime
+
+use guardian_types::FaultKind;
+use crate::host::{deny, surface_call, NativeHost, SensorHost, Trap, ATTACK_SURFACE};
+
+const WIT: &str = include_str!("../../../wit/attacks.wit");
+
+/// body between `{header} {` and its closing brace
+fn block(header: &str) -> &'static str {
+    let start = WIT.find(&format!("{} {{", header)).unwrap_or_else(|| panic!("{} missing from wit", header));
+    let body = &WIT[start..];
+    let open = body.find('{').unwrap() + 1;
+    let mut depth = 1;
+    let close = body[open..]
+        .char_indices()
+        .find(|(_, c)| {
+            depth += match c { '{' => 1, '}' => -1, _ => 0 };
+            depth == 0
+        })
+        .map(|(i, _)| open + i)
+        .unwrap();
+    &body[open..close]
+}
+
+/// code lines of a block, comments stripped
+fn lines(header: &str) -> Vec<&'static str> {
+    block(header).lines().map(|l| l.split("//").next().unwrap().trim()).filter(|l| !l.is_empty()).collect()
+}
+
+/// function names declared in an interface
+fn functions(interface: &str) -> Vec<&'static str> {
+    lines(&format!("interface {}", interface)).iter().filter_map(|l| l.split_once(": func").map(|(name, _)| name)).collect()
+}
+
+#[test]
+fn every_attack_surface_import_is_denied() {
+    // what: sensor-node imports attack-surface, and each of its functions has a host denial that traps the tick
+    // why: a new honey-pot import without a denial would silently be "provided"
+    let world = lines("world sensor-node");
+    assert!(world.contains(&"import attack-surface;"));
+    assert!(world.contains(&"import sensor-capabilities;"));
+    let declared = functions("attack-surface");
+    let denied: Vec<&str> = ATTACK_SURFACE.iter().map(|(name, _)| *name).collect();
+    assert_eq!(declared, denied);
+    for func in declared {
+        assert!(matches!(deny(func), Trap::Denied(reason) if reason.starts_with("capability not granted") || reason.starts_with("memory limit")), "{}", func);
+    }
+    for kind in [FaultKind::BufferOverflow, FaultKind::DataExfil, FaultKind::PathTraversal] {
+        let mut host = NativeHost::new(0.0);
+        host.instantiate().unwrap();
+        host.arm(kind);
+        assert_eq!(host.process_tick(42.0), Err(deny(surface_call(kind).unwrap())), "{:?}", kind);
+        assert_eq!(host.process_tick(42.0), Ok(42.0), "one trapped tick, then healthy again");
+    }
+}
+
+#[test]
+fn process_tick_yields_a_telemetry_packet_value() {
+    // what: the world exports only process-tick returning telemetry-packet {timestamp: u64, value: f64, status: u8}; the host produces a finite value
+    // why: the agent's vote consumes exactly that value field
+    let exports: Vec<&str> = lines("world sensor-node").into_iter().filter(|l| l.starts_with("export")).collect();
+    assert_eq!(exports, ["export process-tick: func() -> telemetry-packet;"]);
+    let record: String = lines("record telemetry-packet").concat();
+    assert_eq!(record.replace(' ', ""), "timestamp:u64,value:f64,status:u8");
+    let mut host = NativeHost::new(0.02);
+    host.instantiate().unwrap();
+    let value = host.process_tick(23.5).unwrap();
+    assert!(value.is_finite() && (value - 23.52).abs() < 1e-9);
+}
@@ -0,0 +1,168 @@
+// what: WasmtimeHost - a sensor node that is the sensor-node component (wit/attacks.wit) running under wasmtime
+// why: the agent's 2oo3 story is about sandboxed instances; this is the sandbox, with the grants, limits and counters the dashboard shows
+// relations: component built from wasm-modules/sensor-driver (node.rs) by build.rs; SensorHost, ATTACK_SURFACE and deny from host.rs;
+//            driven by cluster.rs, whose wasmtime_stats sums usage() for GET /wasmtime/stats
+
+use std::time::{Duration, Instant};
+use guardian_types::FaultKind;
+use wasmtime::component::{Component, ComponentType, InstancePre, Lift, Linker, TypedFunc};
+use wasmtime::{Config, Engine, ResourceLimiter, Store, StoreContextMut};
+use crate::host::{deny, surface_call, SensorHost, Trap, Usage, ATTACK_SURFACE};
+
+const SENSOR_NODE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/sensor_node.wasm"));
+const CAPABILITIES: &str = "guardian-one:attacks/sensor-capabilities@0.1.0";
+const ATTACK_SURFACE_IMPORT: &str = "guardian-one:attacks/attack-surface@0.1.0";
+/// register the guest reads its temperature from (sensor-driver node.rs TEMPERATURE_REGISTER)
+const TEMPERATURE_REGISTER: u32 = 0;
+/// register that makes the guest call the n-th attack-surface import (sensor-driver node.rs FAULT_REGISTER)
+const FAULT_REGISTER: u32 = 1;
+/// linear memory granted per instance, the sensor-node limit of the capability policy (-W max-memory-size=2097152)
+pub const MEMORY_LIMIT_BYTES: usize = 2 * 1024 * 1024;
+/// fuel each instantiation and each tick may burn before wasmtime traps it
+pub const TICK_FUEL: u64 = 10_000_000;
+
+/// common-types.telemetry-packet as the host lifts it; the vote only takes the value
+#[allow(dead_code)]
+#[derive(ComponentType, Lift)]
+#[component(record)]
+struct TelemetryPacket {
+    timestamp: u64,
+    value: f64,
+    status: u8,
+}
+
+/// the sensor-node export the cluster calls once per tick
+type ProcessTick = TypedFunc<(), (TelemetryPacket,)>;
+
+/// linear memory the instance holds; growth past MEMORY_LIMIT_BYTES is refused
+#[derive(Default)]
+struct Meter {
+    memory_bytes: usize,
+}
+
+impl ResourceLimiter for Meter {
+    fn memory_growing(&mut self, current: usize, desired: usize, _maximum: Option<usize>) -> wasmtime::Result<bool> {
+        let total = self.memory_bytes - current + desired;
+        if total > MEMORY_LIMIT_BYTES {
+            return Ok(false);
+        }
+        self.memory_bytes = total;
+        Ok(true)
+    }
+
+    fn table_growing(&mut self, _current: usize, _desired: usize, _maximum: Option<usize>) -> wasmtime::Result<bool> {
+        Ok(true)
+    }
+}
+
+/// what the granted imports read, per instance
+#[derive(Default)]
+struct Board {
+    celsius: f64,
+    /// 1-based ATTACK_SURFACE index read from FAULT_REGISTER, 0 for none
+    attack: u32,
+    meter: Meter,
+}
+
+/// the compiled sensor-node component, linked once: sensor-capabilities granted, attack-surface and wasi trapping
+pub struct SensorRuntime {
+    pre: InstancePre<Board>,
+}
+
+impl SensorRuntime {
+    pub fn new() -> Result<Self, String> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(|e| e.to_string())?;
+        let component = Component::new(&engine, SENSOR_NODE).map_err(|e| format!("sensor-node component: {}", e))?;
+        let mut linker = Linker::new(&engine);
+        // wasi (pulled in by std) gets trapping stubs; the two guardian interfaces below replace their own stubs
+        linker.allow_shadowing(true);
+        linker.define_unknown_imports_as_traps(&component).map_err(|e| e.to_string())?;
+        let mut caps = linker.instance(CAPABILITIES).map_err(|e| e.to_string())?;
+        caps.func_wrap("read-hardware-register", |store: StoreContextMut<Board>, (reg,): (u32,)| {
+            let board = store.data();
+            Ok((match reg {
+                TEMPERATURE_REGISTER => board.celsius,
+                FAULT_REGISTER => f64::from(board.attack),
+                _ => 0.0,
+            },))
+        })
+        .map_err(|e| e.to_string())?;
+        caps.func_wrap("log-debug", |_: StoreContextMut<Board>, (_msg,): (String,)| Ok(())).map_err(|e| e.to_string())?;
+        let mut surface = linker.instance(ATTACK_SURFACE_IMPORT).map_err(|e| e.to_string())?;
+        for (func, _) in ATTACK_SURFACE {
+            surface.func_new(func, move |_, _, _, _| Err(wasmtime::Error::new(deny(func)))).map_err(|e| e.to_string())?;
+        }
+        let pre = linker.instantiate_pre(&component).map_err(|e| e.to_string())?;
+        Ok(SensorRuntime { pre })
+    }
+
+    /// a node with its own calibration offset; no instance until instantiate()
+    pub fn host(&self, offset: f64) -> WasmtimeHost {
+        WasmtimeHost { pre: self.pre.clone(), offset, armed: None, instance: None, fuel_consumed: 0 }
+    }
+}
+
+/// one sensor node: a store and process-tick of a live sensor-node instance
+pub struct WasmtimeHost {
+    pre: InstancePre<Board>,
+    /// per-node calibration offset, like three physically distinct boards
+    offset: f64,
+    armed: Option<FaultKind>,
+    instance: Option<(Store<Board>, ProcessTick)>,
+    fuel_consumed: u64,
+}
+
+impl WasmtimeHost {
+    /// fuel burnt since the budget was set
+    fn burn(&mut self, store: &Store<Board>) {
+        self.fuel_consumed += TICK_FUEL - store.get_fuel().unwrap_or(0);
+    }
+}
+
+impl SensorHost for WasmtimeHost {
+    fn instantiate(&mut self) -> Result<Duration, String> {
+        let started = Instant::now();
+        self.instance = None;
+        self.armed = None;
+        let mut store = Store::new(self.pre.engine(), Board::default());
+        store.limiter(|board| &mut board.meter);
+        store.set_fuel(TICK_FUEL).map_err(|e| e.to_string())?;
+        let instance = self.pre.instantiate(&mut store);
+        self.burn(&store);
+        let instance = instance.map_err(|e| e.to_string())?;
+        let process_tick = instance.get_typed_func(&mut store, "process-tick").map_err(|e| e.to_string())?;
+        self.instance = Some((store, process_tick));
+        Ok(started.elapsed())
+    }
+
+    fn process_tick(&mut self, raw_celsius: f64) -> Result<f64, Trap> {
+        let attack = self.armed.take().and_then(surface_call).and_then(|f| ATTACK_SURFACE.iter().position(|(name, _)| *name == f));
+        let Some((mut store, process_tick)) = self.instance.take() else { return Err(Trap::Denied("no instance running".to_string())) };
+        let board = store.data_mut();
+        board.celsius = raw_celsius + self.offset;
+        board.attack = attack.map_or(0, |i| i as u32 + 1);
+        store.set_fuel(TICK_FUEL).map_err(|e| Trap::Denied(e.to_string()))?;
+        let result = process_tick.call(&mut store, ());
+        self.burn(&store);
+        // a trapped instance cannot be entered again; it stays dropped until the cluster instantiates a fresh one
+        let (packet,) = result.map_err(|e| e.downcast::<Trap>().unwrap_or_else(|e| Trap::Denied(e.root_cause().to_string())))?;
+        process_tick.post_return(&mut store).map_err(|e| Trap::Denied(e.to_string()))?;
+        self.instance = Some((store, process_tick));
+        Ok(packet.value)
+    }
+
+    fn arm(&mut self, kind: FaultKind) {
+        self.armed = Some(kind);
+    }
+
+    fn terminate(&mut self) {
+        self.instance = None;
+    }
+
+    fn usage(&self) -> Option<Usage> {
+        let memory_bytes = self.instance.as_ref().map_or(0, |(store, _)| store.data().meter.memory_bytes as u64);
+        Some(Usage { fuel_consumed: self.fuel_consumed, memory_bytes })
+    }
+}
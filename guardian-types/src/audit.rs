@@ -0,0 +1,129 @@
+// what: audit events - what happened, to which runtime, when
+// why: the demo journal, its exporters and snapshots, and the headless api all carry the same event
+// relations: written by dashboard tabs/demo/store.rs, exported by siem.rs and summary.rs, saved in snapshot.rs
+
+use serde::{Deserialize, Serialize};
+
+/// runtime an event belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Runtime {
+    Python,
+    Wasm,
+    /// plain javascript baseline (sensor workload only)
+    Js,
+}
+
+impl Runtime {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Runtime::Python => "python",
+            Runtime::Wasm => "wasm",
+            Runtime::Js => "js",
+        }
+    }
+}
+
+/// what happened
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventKind {
+    AttackStarted,
+    Trap,
+    Crash,
+    Recovery,
+    Election,
+    PolicyBreach,
+    /// an instance answered but disagreed with the majority
+    Outvoted,
+    /// fewer than two instances healthy: the voter halted
+    QuorumLost,
+    /// the voted value left the telemetry's learned normal band
+    Anomaly,
+    /// a pipeline's queue pushed latency past the poll deadline
+    Overload,
+    /// the rate limiter refused frames with an explicit error
+    LoadShed,
+    /// a soak run saw memory keep growing
+    Leak,
+    /// a loaded module called one of its imports (granted or denied)
+    HostCall,
+    Reset,
+}
+
+impl EventKind {
+    /// stable id used as syslog msgid and cef signature id
+    pub fn id(&self) -> &'static str {
+        match self {
+            EventKind::AttackStarted => "ATTACK",
+            EventKind::Trap => "TRAP",
+            EventKind::Crash => "CRASH",
+            EventKind::Recovery => "RECOVERY",
+            EventKind::Election => "ELECTION",
+            EventKind::PolicyBreach => "BREACH",
+            EventKind::Outvoted => "OUTVOTED",
+            EventKind::QuorumLost => "QUORUM",
+            EventKind::Anomaly => "ANOMALY",
+            EventKind::Overload => "OVERLOAD",
+            EventKind::LoadShed => "SHED",
+            EventKind::Leak => "LEAK",
+            EventKind::HostCall => "HOSTCALL",
+            EventKind::Reset => "RESET",
+        }
+    }
+
+    /// human readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventKind::AttackStarted => "Attack started",
+            EventKind::Trap => "Capability trap",
+            EventKind::Crash => "Worker crash",
+            EventKind::Recovery => "Recovery complete",
+            EventKind::Election => "Leader elected",
+            EventKind::PolicyBreach => "Policy granted attack capability",
+            EventKind::Outvoted => "Instance outvoted",
+            EventKind::QuorumLost => "Quorum lost, output halted",
+            EventKind::Anomaly => "Telemetry anomaly",
+            EventKind::Overload => "Pipeline overloaded, deadline missed",
+            EventKind::LoadShed => "Load shed by rate limiter",
+            EventKind::Leak => "Memory growth in soak run",
+            EventKind::HostCall => "Host call from a loaded module",
+            EventKind::Reset => "Demo reset",
+        }
+    }
+
+    /// rfc 5424 severity (0 emergency .. 7 debug)
+    pub fn syslog_severity(&self) -> u8 {
+        match self {
+            EventKind::QuorumLost => 1,
+            EventKind::PolicyBreach => 2,
+            EventKind::Crash | EventKind::Overload => 3,
+            EventKind::AttackStarted | EventKind::Trap | EventKind::Outvoted | EventKind::Anomaly | EventKind::Leak => 4,
+            EventKind::Election | EventKind::Recovery | EventKind::LoadShed => 5,
+            EventKind::HostCall | EventKind::Reset => 6,
+        }
+    }
+}
+
+/// one journal entry
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    /// unix epoch milliseconds
+    pub timestamp_ms: f64,
+    pub kind: EventKind,
+    pub runtime: Option<Runtime>,
+    pub attack: String,
+    pub detail: String,
+    /// measured duration for recovery/election events
+    pub duration_ms: Option<f64>,
+}
+
+impl AuditEvent {
+    pub fn new(timestamp_ms: f64, kind: EventKind, runtime: Option<Runtime>, attack: &str, detail: impl Into<String>) -> Self {
+        Self { timestamp_ms, kind, runtime, attack: attack.to_string(), detail: detail.into(), duration_ms: None }
+    }
+
+    /// attach a measured duration
+    pub fn with_duration(mut self, ms: f64) -> Self {
+        self.duration_ms = Some(ms);
+        self
+    }
+}
@@ -0,0 +1,181 @@
+// what: the cluster state machine both backends run - node roles, leader election, kill and recovery, 2oo3 vote, vote window, fault validation
+// why: guardian-agent and the dashboard mock each carried a copy of elect/tick/inject, and a copy drifts
+// relations: wire types from node.rs and vote.rs; driven by guardian-agent/src/cluster.rs (sensor hosts produce the values)
+//            and dashboard/src/api/mock.rs (synthetic values)
+
+use std::collections::VecDeque;
+use crate::node::{FaultKind, FaultReceipt, FaultRequest, NodeRole, NodeStatus};
+use crate::vote::VoteResult;
+
+/// votes retained, on the pi and in the mock
+pub const VOTE_HISTORY: usize = 60;
+/// how long a killed node stays in recovering before it rejoins
+pub const RECOVERY_MS: f64 = 3000.0;
+/// outputs further than this from the median are outvoted
+pub const VOTE_TOLERANCE: f64 = 0.5;
+pub const NODE_COUNT: usize = 3;
+
+#[derive(Clone, Debug)]
+struct NodeState {
+    started_ms: f64,
+    packets: u64,
+    last_trap: Option<String>,
+    /// epoch ms until which the node is down
+    down_until_ms: f64,
+    /// a fresh instance is needed once recovery ends
+    restart_due: bool,
+}
+
+/// roles, counters and the vote window of the three sensor nodes; what runs on each node is the caller's
+#[derive(Clone, Debug)]
+pub struct ClusterState {
+    nodes: [NodeState; NODE_COUNT],
+    leader: u8,
+    votes: VecDeque<VoteResult>,
+}
+
+/// 2oo3 vote over per-node outputs: (voted value, first faulty node)
+pub fn vote(values: &[Option<f64>; NODE_COUNT]) -> (Option<f64>, Option<u8>) {
+    let mut present: Vec<f64> = values.iter().flatten().copied().collect();
+    present.sort_by(|a, b| a.total_cmp(b));
+    let median = present.get(present.len() / 2).copied();
+    let agrees = |v: &Option<f64>| matches!((v, median), (Some(v), Some(m)) if (v - m).abs() <= VOTE_TOLERANCE);
+    let agreeing: Vec<f64> = values.iter().filter(|v| agrees(v)).flatten().copied().collect();
+    let voted = (agreeing.len() >= 2).then(|| agreeing.iter().sum::<f64>() / agreeing.len() as f64);
+    let faulty = values.iter().position(|v| !agrees(v)).map(|i| i as u8);
+    (voted, faulty)
+}
+
+impl ClusterState {
+    /// every node up since `now_ms`, node 0 leading
+    pub fn new(now_ms: f64) -> Self {
+        let node = NodeState { started_ms: now_ms, packets: 0, last_trap: None, down_until_ms: 0.0, restart_due: false };
+        Self { nodes: [node.clone(), node.clone(), node], leader: 0, votes: VecDeque::with_capacity(VOTE_HISTORY) }
+    }
+
+    pub fn is_down(&self, id: usize, now_ms: f64) -> bool {
+        now_ms < self.nodes[id].down_until_ms
+    }
+
+    /// lowest-id node that is up becomes leader
+    fn elect(&mut self, now_ms: f64) {
+        if let Some(id) = (0..NODE_COUNT).find(|&id| !self.is_down(id, now_ms)) {
+            self.leader = id as u8;
+        }
+    }
+
+    /// killed nodes whose recovery has ended; each is handed out once and needs a fresh instance before its next round
+    pub fn restarts_due(&mut self, now_ms: f64) -> Vec<usize> {
+        let due: Vec<usize> = (0..NODE_COUNT).filter(|&id| self.nodes[id].restart_due && !self.is_down(id, now_ms)).collect();
+        for &id in &due {
+            self.nodes[id].restart_due = false;
+        }
+        due
+    }
+
+    /// the fresh instance did not start: the node recovers again and is retried after RECOVERY_MS
+    pub fn restart_failed(&mut self, id: usize, now_ms: f64, reason: &str) {
+        let node = &mut self.nodes[id];
+        node.last_trap = Some(format!("restart failed: {}", reason));
+        node.down_until_ms = now_ms + RECOVERY_MS;
+        node.started_ms = now_ms + RECOVERY_MS;
+        node.restart_due = true;
+    }
+
+    /// one sampling period: re-elect if the leader is down, run `process` on every node that is up
+    /// (Ok counts a packet, Err is the trap reason and votes the node out), vote and record
+    pub fn round(&mut self, now_ms: f64, mut process: impl FnMut(usize) -> Result<f64, String>) -> VoteResult {
+        if self.is_down(self.leader as usize, now_ms) {
+            self.elect(now_ms);
+        }
+        let mut values = [None; NODE_COUNT];
+        for (id, value) in values.iter_mut().enumerate() {
+            if self.is_down(id, now_ms) {
+                continue;
+            }
+            match process(id) {
+                Ok(v) => {
+                    self.nodes[id].packets += 1;
+                    *value = Some(v);
+                }
+                Err(reason) => self.nodes[id].last_trap = Some(reason),
+            }
+        }
+        let (voted, faulty_node) = vote(&values);
+        let record = VoteResult { timestamp_ms: now_ms, values, voted, faulty_node };
+        if self.votes.len() == VOTE_HISTORY {
+            self.votes.pop_back();
+        }
+        self.votes.push_front(record.clone());
+        record
+    }
+
+    /// GET /nodes
+    pub fn list_nodes(&self, now_ms: f64) -> Vec<NodeStatus> {
+        (0..NODE_COUNT)
+            .map(|id| {
+                let node = &self.nodes[id];
+                let role = if self.is_down(id, now_ms) {
+                    NodeRole::Recovering
+                } else if id == self.leader as usize {
+                    NodeRole::Leader
+                } else {
+                    NodeRole::Follower
+                };
+                NodeStatus {
+                    id: id as u8,
+                    role,
+                    uptime_s: if role == NodeRole::Recovering { 0 } else { ((now_ms - node.started_ms).max(0.0) / 1000.0) as u64 },
+                    packets_processed: node.packets,
+                    last_trap: node.last_trap.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// GET /votes?limit=N, newest first
+    pub fn recent_votes(&self, limit: usize) -> Vec<VoteResult> {
+        self.votes.iter().take(limit).cloned().collect()
+    }
+
+    /// POST /faults; Err is a rejection (unknown or recovering node). A kill takes the node down here;
+    /// a receipt with `trapped` asks the caller to make that node's next round trap
+    pub fn inject(&mut self, now_ms: f64, request: &FaultRequest) -> Result<FaultReceipt, String> {
+        let id = request.node as usize;
+        if id >= NODE_COUNT {
+            return Err(format!("no node {}", request.node));
+        }
+        if self.is_down(id, now_ms) {
+            return Err(format!("node {} is already recovering", request.node));
+        }
+        let receipt = match request.kind {
+            FaultKind::KillProcess => {
+                let node = &mut self.nodes[id];
+                node.down_until_ms = now_ms + RECOVERY_MS;
+                node.started_ms = now_ms + RECOVERY_MS;
+                node.restart_due = true;
+                let was_leader = self.leader == request.node;
+                if was_leader {
+                    self.elect(now_ms);
+                }
+                FaultReceipt {
+                    node: request.node,
+                    kind: request.kind,
+                    trapped: false,
+                    message: if was_leader {
+                        format!("node {} killed, node {} elected leader", request.node, self.leader)
+                    } else {
+                        format!("node {} killed, rejoining in {} ms", request.node, RECOVERY_MS)
+                    },
+                }
+            }
+            kind => FaultReceipt {
+                node: request.node,
+                kind,
+                trapped: true,
+                message: format!("node {} will trap {} on its next tick, voted out for one round", request.node, kind.id()),
+            },
+        };
+        Ok(receipt)
+    }
+}
@@ -0,0 +1,32 @@
+// what: ws2812b status strip - the strip's frame at a given time and the grb bytes on the wire
+// why: the pi's strip and the dashboard's virtual one must light the same colour in the same rhythm for the same cluster state
+// relations: colours and blink patterns from status_map.rs; drawn by the dashboard's tabs/demo/led_strip.rs,
+//            the same bytes a strip driver on the agent clocks out
+
+use crate::status_map::{Rgb, Status};
+
+/// pixels on the cabinet strip
+pub const STRIP_LEN: usize = 8;
+/// global brightness (0-255) the strip runs at indoors; full white at 255 draws ~60mA per pixel
+pub const DEFAULT_BRIGHTNESS: u8 = 64;
+/// ws2812b timing: 800kHz data, 24 bits per pixel, then the line held low to latch
+pub const BIT_NS: u32 = 1250;
+pub const BITS_PER_PIXEL: u32 = 24;
+pub const RESET_US: u32 = 50;
+
+/// every pixel of the strip `t_ms` into the pattern, at `brightness`
+pub fn strip_frame(status: Status, t_ms: u64, brightness: u8) -> [Rgb; STRIP_LEN] {
+    let mapping = status.mapping();
+    let color = if mapping.pattern.lit(t_ms) { mapping.color.scaled(brightness) } else { Rgb::OFF };
+    [color; STRIP_LEN]
+}
+
+/// bytes clocked out for a frame: green, red, blue per pixel, first pixel first
+pub fn wire_bytes(pixels: &[Rgb]) -> Vec<u8> {
+    pixels.iter().flat_map(|p| [p.g, p.r, p.b]).collect()
+}
+
+/// time to push a frame of `pixels` including the latch (µs)
+pub fn frame_time_us(pixels: usize) -> f64 {
+    (pixels as u32 * BITS_PER_PIXEL * BIT_NS) as f64 / 1000.0 + RESET_US as f64
+}
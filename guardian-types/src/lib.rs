@@ -0,0 +1,21 @@
+// what: types that cross a crate or wire boundary - telemetry, votes, node status, audit events, the status map
+// why: the wit record, the demo's reading tuples, the parser structs and the agent's copy of the api types had drifted apart
+// relations: cluster.rs (the cluster state machine the agent and the dashboard mock share), telemetry.rs (wit common-types + bme280 reading), vote.rs, node.rs (cluster rest api), audit.rs (demo journal),
+//            status_map.rs (status colours, patterns, priorities) + led.rs (ws2812b strip); used by dashboard, wasm-modules/modbus-parser, wasm-modules/sensor-driver and guardian-agent
+
+mod audit;
+pub mod cluster;
+pub mod led;
+mod status_map;
+mod node;
+mod telemetry;
+mod vote;
+
+#[cfg(test)]
+mod tests;
+
+pub use audit::{AuditEvent, EventKind, Runtime};
+pub use node::{ClusterEvent, FaultKind, FaultReceipt, FaultRequest, NodeRole, NodeStatus, WasmtimeStats};
+pub use telemetry::{SensorReading, TelemetryPacket};
+pub use vote::VoteResult;
+pub use status_map::{Pattern, Priority, Rgb, Scope, Status, StatusMapping, Tone, STATUS_MAP};
@@ -0,0 +1,107 @@
+// what: node status, fault injection, runtime counters and the per-tick event of the cluster rest api (/api/v1)
+// why: one definition for the agent that serves them and the dashboard that decodes them
+// relations: served by guardian-agent http.rs (events by events.rs), fetched by dashboard api/client.rs, faked by api/mock.rs
+
+use serde::{Deserialize, Serialize};
+use crate::vote::VoteResult;
+
+/// raft-style role of a guardian node
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NodeRole {
+    Leader,
+    Follower,
+    Recovering,
+}
+
+impl NodeRole {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NodeRole::Leader => "Leader",
+            NodeRole::Follower => "Follower",
+            NodeRole::Recovering => "Recovering",
+        }
+    }
+}
+
+/// one tmr instance (GET /nodes)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NodeStatus {
+    pub id: u8,
+    pub role: NodeRole,
+    pub uptime_s: u64,
+    pub packets_processed: u64,
+    pub last_trap: Option<String>,
+}
+
+/// controlled faults the backend is allowed to inject
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FaultKind {
+    BufferOverflow,
+    DataExfil,
+    PathTraversal,
+    KillProcess,
+}
+
+impl FaultKind {
+    pub const ALL: [FaultKind; 4] = [FaultKind::BufferOverflow, FaultKind::DataExfil, FaultKind::PathTraversal, FaultKind::KillProcess];
+
+    /// wire id, also used as the select value
+    pub fn id(&self) -> &'static str {
+        match self {
+            FaultKind::BufferOverflow => "buffer-overflow",
+            FaultKind::DataExfil => "data-exfil",
+            FaultKind::PathTraversal => "path-traversal",
+            FaultKind::KillProcess => "kill-process",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FaultKind::BufferOverflow => "💥 Buffer Overflow",
+            FaultKind::DataExfil => "🔓 Data Exfiltration",
+            FaultKind::PathTraversal => "📁 Path Traversal",
+            FaultKind::KillProcess => "💀 Kill Process",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<FaultKind> {
+        FaultKind::ALL.into_iter().find(|k| k.id() == id)
+    }
+}
+
+/// body of POST /faults
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FaultRequest {
+    pub node: u8,
+    pub kind: FaultKind,
+}
+
+/// response of POST /faults
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FaultReceipt {
+    pub node: u8,
+    pub kind: FaultKind,
+    /// true when the sandbox trapped the fault instead of the node dying
+    pub trapped: bool,
+    pub message: String,
+}
+
+/// runtime counters (GET /wasmtime/stats)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WasmtimeStats {
+    pub instances: u32,
+    pub instantiations: u64,
+    pub traps: u64,
+    pub fuel_consumed: u64,
+    pub memory_bytes: u64,
+    pub avg_instantiate_us: f64,
+}
+
+/// one message of the /events websocket: a tick's vote and the node list right after it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClusterEvent {
+    pub vote: VoteResult,
+    pub nodes: Vec<NodeStatus>,
+}
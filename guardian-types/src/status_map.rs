@@ -0,0 +1,252 @@
+// what: one table from every health status to its label, colour, blink pattern, alarm priority and ui tone
+// why: what "degraded" looks like was spread over css classes, the oled and the strip; the agent and the dashboard now read the same rows
+// relations: status strip in led.rs, node roles from node.rs; served by guardian-agent's GET /api/v1/status-map, rendered by the
+//            dashboard's node strip, node drawer, cluster panel, oled preview and status strip
+
+use serde::{Deserialize, Serialize};
+use crate::NodeRole;
+
+/// a health status anything in the system can be in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// all three nodes healthy and agreeing
+    Consensus,
+    /// one node down, the other two still outvote a fault
+    Degraded,
+    /// quorum lost, the voter holds its output
+    Fault,
+    NodeUp,
+    NodeDown,
+    /// restarting or catching up on the log
+    NodeRecovering,
+}
+
+/// whether a status describes the whole voter or one node
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scope {
+    Cluster,
+    Node,
+}
+
+/// alarm priority, lowest first (isa-18.2 style: what an operator must do about it, and how soon)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    None,
+    Low,
+    High,
+    Critical,
+}
+
+impl Priority {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Priority::None => "none",
+            Priority::Low => "low",
+            Priority::High => "high",
+            Priority::Critical => "critical",
+        }
+    }
+}
+
+/// which of the dashboard's accent colours a status takes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tone {
+    Ok,
+    Warning,
+    Danger,
+}
+
+impl Tone {
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            Tone::Ok => "status-ok",
+            Tone::Warning => "status-warning",
+            Tone::Danger => "status-danger",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const OFF: Rgb = Rgb { r: 0, g: 0, b: 0 };
+
+    /// css colour
+    pub fn css(&self) -> String {
+        format!("rgb({}, {}, {})", self.r, self.g, self.b)
+    }
+
+    /// neopixel-style global brightness: each channel times (brightness + 1) / 256
+    pub fn scaled(&self, brightness: u8) -> Rgb {
+        let s = |c: u8| ((c as u16 * (brightness as u16 + 1)) >> 8) as u8;
+        Rgb { r: s(self.r), g: s(self.g), b: s(self.b) }
+    }
+
+    /// the oled's native 5/6/5 format
+    pub fn rgb565(&self) -> u16 {
+        (self.r as u16 >> 3) << 11 | (self.g as u16 >> 2) << 5 | self.b as u16 >> 3
+    }
+}
+
+/// how a status lights an led over time
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Pattern {
+    Solid,
+    /// on for `on_ms` at the start of every `period_ms`
+    Blink { period_ms: u32, on_ms: u32 },
+}
+
+impl Pattern {
+    pub fn lit(&self, t_ms: u64) -> bool {
+        match self {
+            Pattern::Solid => true,
+            Pattern::Blink { period_ms, on_ms } => t_ms % u64::from(*period_ms) < u64::from(*on_ms),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Pattern::Solid => "solid".to_string(),
+            Pattern::Blink { period_ms, on_ms } => format!("blink {}ms on / {}ms", on_ms, period_ms),
+        }
+    }
+}
+
+/// one row of the table
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct StatusMapping {
+    pub status: Status,
+    pub scope: Scope,
+    pub label: &'static str,
+    /// at most four characters, for the oled header and other tight spots
+    pub short: &'static str,
+    pub color: Rgb,
+    pub pattern: Pattern,
+    pub priority: Priority,
+    pub tone: Tone,
+    pub meaning: &'static str,
+}
+
+const GREEN: Rgb = Rgb { r: 0, g: 255, b: 0 };
+const YELLOW: Rgb = Rgb { r: 255, g: 180, b: 0 };
+const RED: Rgb = Rgb { r: 255, g: 0, b: 0 };
+const SLOW_BLINK: Pattern = Pattern::Blink { period_ms: 1000, on_ms: 500 };
+const FAST_BLINK: Pattern = Pattern::Blink { period_ms: 250, on_ms: 125 };
+
+/// every status, in `Status` declaration order
+pub const STATUS_MAP: [StatusMapping; 6] = [
+    StatusMapping {
+        status: Status::Consensus,
+        scope: Scope::Cluster,
+        label: "Consensus",
+        short: "OK",
+        color: GREEN,
+        pattern: Pattern::Solid,
+        priority: Priority::None,
+        tone: Tone::Ok,
+        meaning: "3/3 healthy, voted output flowing",
+    },
+    StatusMapping {
+        status: Status::Degraded,
+        scope: Scope::Cluster,
+        label: "Degraded",
+        short: "DEGR",
+        color: YELLOW,
+        pattern: SLOW_BLINK,
+        priority: Priority::High,
+        tone: Tone::Warning,
+        meaning: "2/3 healthy, output still voted - replace the failed node",
+    },
+    StatusMapping {
+        status: Status::Fault,
+        scope: Scope::Cluster,
+        label: "Fault",
+        short: "HALT",
+        color: RED,
+        pattern: FAST_BLINK,
+        priority: Priority::Critical,
+        tone: Tone::Danger,
+        meaning: "quorum lost, output held - intervene now",
+    },
+    StatusMapping {
+        status: Status::NodeUp,
+        scope: Scope::Node,
+        label: "Up",
+        short: "UP",
+        color: GREEN,
+        pattern: Pattern::Solid,
+        priority: Priority::None,
+        tone: Tone::Ok,
+        meaning: "node healthy and voting",
+    },
+    StatusMapping {
+        status: Status::NodeDown,
+        scope: Scope::Node,
+        label: "Down",
+        short: "DOWN",
+        color: RED,
+        pattern: Pattern::Solid,
+        priority: Priority::High,
+        tone: Tone::Danger,
+        meaning: "node trapped or crashed, not voting",
+    },
+    StatusMapping {
+        status: Status::NodeRecovering,
+        scope: Scope::Node,
+        label: "Recovering",
+        short: "RCVR",
+        color: YELLOW,
+        pattern: SLOW_BLINK,
+        priority: Priority::Low,
+        tone: Tone::Warning,
+        meaning: "node restarting or catching up, back shortly",
+    },
+];
+
+impl Status {
+    pub const ALL: [Status; 6] = [Status::Consensus, Status::Degraded, Status::Fault, Status::NodeUp, Status::NodeDown, Status::NodeRecovering];
+
+    pub fn mapping(self) -> &'static StatusMapping {
+        &STATUS_MAP[self as usize]
+    }
+
+    /// voter status for the number of healthy nodes out of three
+    pub fn from_healthy(healthy: usize) -> Self {
+        match healthy {
+            3.. => Status::Consensus,
+            2 => Status::Degraded,
+            _ => Status::Fault,
+        }
+    }
+
+    pub fn node(up: bool) -> Self {
+        if up { Status::NodeUp } else { Status::NodeDown }
+    }
+
+    /// status of a node as the cluster api reports its role
+    pub fn from_role(role: NodeRole) -> Self {
+        match role {
+            NodeRole::Leader | NodeRole::Follower => Status::NodeUp,
+            NodeRole::Recovering => Status::NodeRecovering,
+        }
+    }
+
+    pub fn css_class(self) -> &'static str {
+        self.mapping().tone.css_class()
+    }
+
+    /// the status that most needs attention (None for no statuses)
+    pub fn worst(statuses: impl IntoIterator<Item = Status>) -> Option<Status> {
+        statuses.into_iter().max_by_key(|s| s.mapping().priority)
+    }
+}
@@ -0,0 +1,26 @@
+// what: telemetry packet (wit common-types.telemetry-packet) and the raw bme280 reading
+// why: the register map, the sensor driver and the demo's terminals all carry these
+// relations: packet encoded by modbus-parser register_map.rs, reading returned by sensor-driver and logged by dashboard handlers.rs
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm-bindgen")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// mirrors `common-types.telemetry-packet` in wit/attacks.wit
+#[cfg_attr(feature = "wasm-bindgen", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TelemetryPacket {
+    /// unix epoch milliseconds
+    pub timestamp: u64,
+    pub value: f64,
+    pub status: u8,
+}
+
+/// one bme280 read: °C, %rh, hPa
+#[cfg_attr(feature = "wasm-bindgen", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SensorReading {
+    pub temperature: f64,
+    pub humidity: f64,
+    pub pressure: f64,
+}
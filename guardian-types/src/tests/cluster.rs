@@ -0,0 +1,39 @@
+// what: tests for the cluster state machine guardian-agent and the dashboard mock share
+// why: both backends now call it, so the 2oo3 and recovery story is pinned once, here
+
+use crate::cluster::{vote, ClusterState, RECOVERY_MS};
+use crate::{FaultKind, FaultRequest, NodeRole};
+
+#[test]
+fn vote_outvotes_missing_and_divergent_nodes() {
+    // what: two agreeing outputs win, a missing or far-off node is reported faulty, one output is no quorum
+    // why: 2oo3 is the availability claim the hardware tab makes
+    assert_eq!(vote(&[Some(42.0), Some(42.0), Some(42.0)]), (Some(42.0), None));
+    assert_eq!(vote(&[Some(42.0), None, Some(42.2)]), (Some(42.1), Some(1)));
+    assert_eq!(vote(&[Some(42.0), Some(42.0), Some(99.0)]), (Some(42.0), Some(2)));
+    assert_eq!(vote(&[None, None, Some(42.0)]).0, None);
+}
+
+#[test]
+fn killed_node_is_skipped_then_handed_out_for_restart_once() {
+    // what: a killed node is not asked for a value while recovering, is due for a restart exactly once after RECOVERY_MS,
+    //       and a failed restart puts it back into recovering with the reason as its last trap
+    // why: the agent re-instantiates from restarts_due and the mock counts instantiations from it; a repeat would double both
+    let mut state = ClusterState::new(0.0);
+    state.inject(0.0, &FaultRequest { node: 2, kind: FaultKind::KillProcess }).unwrap();
+    let mut asked = Vec::new();
+    let record = state.round(1000.0, |id| {
+        asked.push(id);
+        Ok(42.0)
+    });
+    assert_eq!(asked, [0, 1]);
+    assert_eq!((record.voted, record.faulty_node), (Some(42.0), Some(2)));
+    assert!(state.restarts_due(1000.0).is_empty());
+    assert_eq!(state.restarts_due(RECOVERY_MS), [2]);
+    assert!(state.restarts_due(RECOVERY_MS).is_empty());
+    state.restart_failed(2, RECOVERY_MS, "out of memory");
+    let node = &state.list_nodes(RECOVERY_MS + 1.0)[2];
+    assert_eq!(node.role, NodeRole::Recovering);
+    assert_eq!(node.last_trap.as_deref(), Some("restart failed: out of memory"));
+    assert_eq!(state.restarts_due(2.0 * RECOVERY_MS), [2]);
+}
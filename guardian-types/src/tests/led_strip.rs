@@ -0,0 +1,69 @@
+// what: tests for the ws2812b status strip - status per healthy count, blink timing, brightness scaling and the grb wire order
+// why: the virtual strip and the cabinet strip both light from the status map; a swapped channel or a blink off by a period shows a different state than the cluster is in
+
+use serde_json::json;
+use crate::led::{frame_time_us, strip_frame, wire_bytes, STRIP_LEN};
+use crate::{Pattern, Rgb, Status};
+
+#[test]
+fn status_follows_the_healthy_count_and_the_documented_colours() {
+    // what: 3 healthy is consensus (green, solid), 2 degraded (yellow, slow blink), fewer fault (red, fast blink)
+    // why: these are the colours the hardware docs promise an operator
+    assert_eq!(Status::from_healthy(3), Status::Consensus);
+    assert_eq!(Status::from_healthy(2), Status::Degraded);
+    assert_eq!(Status::from_healthy(1), Status::Fault);
+    assert_eq!(Status::from_healthy(0), Status::Fault);
+    let green = Status::Consensus.mapping().color;
+    assert!(green.g == 255 && green.r == 0 && green.b == 0);
+    let yellow = Status::Degraded.mapping().color;
+    assert!(yellow.r == 255 && yellow.g > 0 && yellow.b == 0);
+    assert_eq!(Status::Fault.mapping().color, Rgb { r: 255, g: 0, b: 0 });
+    assert_eq!(Status::Consensus.mapping().pattern, Pattern::Solid);
+}
+
+#[test]
+fn blink_patterns_are_on_for_their_duty_and_faster_for_worse_states() {
+    // what: a blink is lit for on_ms at the start of each period and dark for the rest, solid never goes dark; fault blinks faster than degraded
+    // why: on a strip seen across a plant floor the rhythm is what tells degraded from fault, not the exact hue
+    let period = |s: Status| match s.mapping().pattern {
+        Pattern::Blink { period_ms, on_ms } => (period_ms as u64, on_ms as u64),
+        Pattern::Solid => panic!("{:?} should blink", s),
+    };
+    for status in [Status::Degraded, Status::Fault] {
+        let (p, on) = period(status);
+        let lit = (0..p * 4).filter(|&t| strip_frame(status, t, 255)[0] != Rgb::OFF).count() as u64;
+        assert_eq!(lit, on * 4);
+        assert!(strip_frame(status, p + on - 1, 255)[0] != Rgb::OFF);
+        assert_eq!(strip_frame(status, p + on, 255), [Rgb::OFF; STRIP_LEN]);
+    }
+    assert!(period(Status::Fault).0 < period(Status::Degraded).0);
+    assert!((0..5000).all(|t| Pattern::Solid.lit(t)));
+}
+
+#[test]
+fn brightness_scales_like_the_neopixel_library() {
+    // what: full brightness keeps the colour, zero turns it off, 64 gives a quarter; every pixel of a frame is scaled alike
+    // why: the strip runs dimmed in a cabinet, and the preview has to show the dimmed colour the strip really emits
+    let red = Rgb { r: 255, g: 0, b: 0 };
+    assert_eq!(red.scaled(255), red);
+    assert_eq!(red.scaled(0), Rgb { r: 0, g: 0, b: 0 });
+    assert_eq!(red.scaled(63).r, 63);
+    assert_eq!(Rgb { r: 200, g: 100, b: 40 }.scaled(127), Rgb { r: 100, g: 50, b: 20 });
+    let frame = strip_frame(Status::Consensus, 0, 64);
+    assert!(frame.iter().all(|p| *p == Rgb { r: 0, g: 64, b: 0 }));
+    assert_eq!(frame[0].css(), "rgb(0, 64, 0)");
+}
+
+#[test]
+fn wire_bytes_are_grb_and_the_table_serializes() {
+    // what: each pixel goes out green, red, blue; a frame of 8 is 24 bytes and takes 240µs plus the 50µs latch; the table's json form is stable
+    // why: ws2812b expects grb - sending rgb lights a red fault green; the json is what the agent reads to stay in step
+    let bytes = wire_bytes(&[Rgb { r: 1, g: 2, b: 3 }, Rgb { r: 4, g: 5, b: 6 }]);
+    assert_eq!(bytes, vec![2, 1, 3, 5, 4, 6]);
+    assert_eq!(wire_bytes(&strip_frame(Status::Fault, 0, 255)).len(), STRIP_LEN * 3);
+    assert_eq!(frame_time_us(STRIP_LEN), 290.0);
+
+    let row = serde_json::to_value(Status::Degraded.mapping()).unwrap();
+    assert_eq!(row["status"], json!("degraded"));
+    assert_eq!(row["pattern"], json!({ "kind": "blink", "period_ms": 1000, "on_ms": 500 }));
+}
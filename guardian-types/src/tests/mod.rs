@@ -0,0 +1,17 @@
+// what: exports all test modules for guardian-types
+// why: keeps tests organized and discoverable via cargo test
+
+#[cfg(test)]
+mod wire;
+
+#[cfg(test)]
+mod wit_record;
+
+#[cfg(test)]
+mod led_strip;
+
+#[cfg(test)]
+mod status_map;
+
+#[cfg(test)]
+mod cluster;
@@ -0,0 +1,74 @@
+// what: tests for the status map - one row per status in order, mapping from health counts and node roles, priorities, and the json the agent serves
+// why: the dashboard and the pi render from this table alone; a row out of order or a renamed field would show one status as another
+
+use serde_json::{json, Value};
+use crate::{NodeRole, Pattern, Priority, Rgb, Scope, Status, Tone, STATUS_MAP};
+
+#[test]
+fn table_has_one_row_per_status_in_order() {
+    // what: STATUS_MAP lists every status exactly once at its declaration index, so mapping() finds its own row; short labels fit four characters
+    // why: mapping() indexes by discriminant, and the oled header only has room for four
+    assert_eq!(STATUS_MAP.len(), Status::ALL.len());
+    for (i, status) in Status::ALL.into_iter().enumerate() {
+        assert_eq!(STATUS_MAP[i].status, status);
+        assert_eq!(status.mapping().status, status);
+        assert!(status.mapping().short.len() <= 4, "{:?}", status);
+    }
+    let cluster: Vec<Status> = STATUS_MAP.iter().filter(|m| m.scope == Scope::Cluster).map(|m| m.status).collect();
+    assert_eq!(cluster, vec![Status::Consensus, Status::Degraded, Status::Fault]);
+}
+
+#[test]
+fn statuses_come_from_health_counts_and_roles() {
+    // what: 3/2/<2 healthy nodes give consensus/degraded/fault; up and down map to node statuses; the api's roles map leader and follower to up, recovering to recovering
+    // why: the agent reports roles and the demo instance states - both have to land on the same rows
+    assert_eq!(Status::from_healthy(3), Status::Consensus);
+    assert_eq!(Status::from_healthy(2), Status::Degraded);
+    assert_eq!(Status::from_healthy(1), Status::Fault);
+    assert_eq!(Status::from_healthy(0), Status::Fault);
+    assert_eq!((Status::node(true), Status::node(false)), (Status::NodeUp, Status::NodeDown));
+    assert_eq!(Status::from_role(NodeRole::Leader), Status::NodeUp);
+    assert_eq!(Status::from_role(NodeRole::Follower), Status::NodeUp);
+    assert_eq!(Status::from_role(NodeRole::Recovering), Status::NodeRecovering);
+}
+
+#[test]
+fn priority_tone_and_colour_agree() {
+    // what: healthy statuses are priority none, green and ok; fault is the only critical one; worst() picks the highest priority; tones map to the css classes
+    // why: a green led on a high-priority alarm, or a warning class on a fault, would tell the operator two different things
+    for m in STATUS_MAP {
+        let healthy = m.priority == Priority::None;
+        assert_eq!(healthy, m.tone == Tone::Ok, "{:?}", m.status);
+        assert_eq!(healthy, m.color == Rgb { r: 0, g: 255, b: 0 }, "{:?}", m.status);
+        assert_eq!(m.tone == Tone::Danger, m.color == Rgb { r: 255, g: 0, b: 0 }, "{:?}", m.status);
+    }
+    assert_eq!(STATUS_MAP.iter().filter(|m| m.priority == Priority::Critical).map(|m| m.status).collect::<Vec<_>>(), vec![Status::Fault]);
+    assert_eq!(Status::worst([Status::NodeUp, Status::NodeRecovering, Status::NodeDown]), Some(Status::NodeDown));
+    assert_eq!(Status::worst([]), None);
+    assert!(Priority::Critical > Priority::High && Priority::Low > Priority::None);
+    assert_eq!(Status::Degraded.css_class(), "status-warning");
+    assert_eq!(Rgb { r: 255, g: 0, b: 0 }.rgb565(), 0xF800);
+    assert_eq!(Rgb { r: 255, g: 255, b: 255 }.rgb565(), 0xFFFF);
+}
+
+#[test]
+fn table_serializes_for_the_agent_and_firmware() {
+    // what: the whole table serializes to an array of snake_case rows with colour, pattern and priority
+    // why: GET /api/v1/status-map serves exactly this, for firmware that cannot link guardian-types
+    let value = serde_json::to_value(STATUS_MAP).unwrap();
+    let rows = value.as_array().unwrap();
+    assert_eq!(rows.len(), STATUS_MAP.len());
+    assert_eq!(rows[2], json!({
+        "status": "fault",
+        "scope": "cluster",
+        "label": "Fault",
+        "short": "HALT",
+        "color": { "r": 255, "g": 0, "b": 0 },
+        "pattern": { "kind": "blink", "period_ms": 250, "on_ms": 125 },
+        "priority": "critical",
+        "tone": "danger",
+        "meaning": "quorum lost, output held - intervene now",
+    }));
+    assert_eq!(rows[5]["status"], Value::from("node_recovering"));
+    assert_eq!(serde_json::to_value(Pattern::Solid).unwrap(), json!({ "kind": "solid" }));
+}
@@ -0,0 +1,41 @@
+// what: json shapes of the types shared over the cluster rest api and demo snapshots
+// why: the agent encodes and the dashboard decodes with these same types, so a renamed field breaks both at once
+
+use serde_json::{json, Value};
+use crate::{AuditEvent, EventKind, FaultKind, FaultRequest, NodeRole, NodeStatus, Runtime, VoteResult};
+
+#[test]
+fn node_status_uses_kebab_case_roles() {
+    // what: a node serializes with snake_case fields and a kebab-case role
+    // why: api/mock.rs fixtures and the agent's /nodes route have to agree on the wire form
+    let node = NodeStatus { id: 2, role: NodeRole::Recovering, uptime_s: 9, packets_processed: 40, last_trap: None };
+    let value = serde_json::to_value(&node).unwrap();
+    assert_eq!(value, json!({ "id": 2, "role": "recovering", "uptime_s": 9, "packets_processed": 40, "last_trap": null }));
+    assert_eq!(serde_json::from_value::<NodeStatus>(value).unwrap(), node);
+}
+
+#[test]
+fn fault_ids_match_serde_names() {
+    // what: FaultKind::id() is exactly what serde writes, and from_id inverts it
+    // why: the hardware tab builds its select from id() but posts the serde form
+    for kind in FaultKind::ALL {
+        assert_eq!(serde_json::to_value(kind).unwrap(), Value::from(kind.id()));
+        assert_eq!(FaultKind::from_id(kind.id()), Some(kind));
+    }
+    let request: FaultRequest = serde_json::from_str(r#"{"node":1,"kind":"data-exfil"}"#).unwrap();
+    assert_eq!(request, FaultRequest { node: 1, kind: FaultKind::DataExfil });
+}
+
+#[test]
+fn vote_and_audit_round_trip() {
+    // what: a vote with a missing node and an audit event with a duration survive json
+    // why: votes carry nulls for trapped nodes and snapshots save journal events verbatim
+    let vote = VoteResult { timestamp_ms: 1.0e12, values: [Some(23.5), None, Some(23.5)], voted: Some(23.5), faulty_node: Some(1) };
+    let text = serde_json::to_string(&vote).unwrap();
+    assert!(text.contains("[23.5,null,23.5]"));
+    assert_eq!(serde_json::from_str::<VoteResult>(&text).unwrap(), vote);
+
+    let event = AuditEvent::new(5.0, EventKind::Recovery, Some(Runtime::Python), "killLeader", "respawned").with_duration(1500.0);
+    let text = serde_json::to_string(&event).unwrap();
+    assert_eq!(serde_json::from_str::<AuditEvent>(&text).unwrap(), event);
+}
@@ -0,0 +1,40 @@
+// what: TelemetryPacket against the telemetry-packet record in wit/attacks.wit
+// why: the rust struct replaced three hand-written copies of the record; it should not drift from the wit either
+
+use serde_json::Value;
+use crate::TelemetryPacket;
+
+const WIT: &str = include_str!("../../../wit/attacks.wit");
+
+/// (field, type) pairs of a wit record
+fn record_fields(name: &str) -> Vec<(&'static str, &'static str)> {
+    let start = WIT.find(&format!("record {} {{", name)).unwrap_or_else(|| panic!("record {} missing from wit", name));
+    let body = &WIT[start..];
+    let body = &body[body.find('{').unwrap() + 1..body.find('}').unwrap()];
+    body.split(',')
+        .filter_map(|f| f.split_once(':'))
+        .map(|(field, ty)| (field.trim(), ty.trim()))
+        .collect()
+}
+
+#[test]
+fn telemetry_packet_matches_wit_record() {
+    // what: same field names as `record telemetry-packet`, none extra
+    // why: component bindings map wit fields by name; a rename on either side breaks the boundary
+    let packet = TelemetryPacket { timestamp: 1, value: 2.0, status: 3 };
+    let Value::Object(fields) = serde_json::to_value(packet).unwrap() else { panic!("packet is not an object") };
+    let wit: Vec<&str> = record_fields("telemetry-packet").into_iter().map(|(f, _)| f).collect();
+    assert_eq!(fields.len(), wit.len());
+    for name in wit {
+        assert!(fields.contains_key(name), "wit field {} missing from TelemetryPacket", name);
+    }
+}
+
+#[test]
+fn telemetry_packet_types_match_wit() {
+    // what: u64 timestamp, f64 value, u8 status in both places
+    // why: a wider or narrower rust type would silently truncate across the component boundary
+    assert_eq!(record_fields("telemetry-packet"), vec![("timestamp", "u64"), ("value", "f64"), ("status", "u8")]);
+    let packet: TelemetryPacket = serde_json::from_str(r#"{"timestamp":18446744073709551615,"value":0.5,"status":255}"#).unwrap();
+    assert_eq!((packet.timestamp, packet.status), (u64::MAX, u8::MAX));
+}
@@ -0,0 +1,16 @@
+// what: one 2oo3 voting round as reported by the cluster api
+// why: the agent produces it, the dashboard's live client and mock both decode it
+// relations: produced by guardian-agent cluster.rs and dashboard api/mock.rs, rendered by dashboard tabs/hardware/cluster.rs
+
+use serde::{Deserialize, Serialize};
+
+/// one 2oo3 vote (GET /votes)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VoteResult {
+    /// unix epoch milliseconds
+    pub timestamp_ms: f64,
+    /// per-node output, none when the node trapped or was down
+    pub values: [Option<f64>; 3],
+    pub voted: Option<f64>,
+    pub faulty_node: Option<u8>,
+}
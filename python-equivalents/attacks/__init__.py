@@ -13,3 +13,7 @@ that are captured and displayed in the Guardian One Console.
 from . import buffer_overflow
 from . import data_exfil
 from . import path_traversal
+from . import stack_exhaustion
+from . import length_overflow
+from . import env_theft
+# lateral_movement uses top-level await and js imports: run it via runPythonAsync, not import
@@ -0,0 +1,69 @@
+# what: credential theft from environment attack simulation for pyodide demo
+# why: demonstrates python's ambient authority over os.environ vs wasi's explicitly passed config
+# relations: executed by demo.rs via runPython(), wasi side is the env list in wit/policy.toml
+
+"""
+Environment Credential Theft Simulation
+
+Gateways commonly receive secrets through environment variables. Any code
+running in the Python process can read all of them. A WASI component only
+sees the variables the host passes explicitly (wasmtime --env KEY=VALUE).
+The secrets planted below are fake.
+"""
+
+import os
+import time
+
+# gateway process environment (fake secrets planted for the demo)
+GATEWAY_ENV = {
+    "SENSOR_TYPE": "bme280",
+    "POLL_INTERVAL_MS": "1000",
+    "AWS_SECRET_ACCESS_KEY": "FAKE-wJalrXUtnFEMI-demo",
+    "MQTT_PASSWORD": "FAKE-hunter2-demo",
+    "HISTORIAN_API_TOKEN": "FAKE-tok-0000-demo",
+}
+
+SECRET_MARKERS = ("SECRET", "PASSWORD", "TOKEN", "KEY")
+
+
+def simulate_env_theft():
+    """
+    Simulates credential theft from the process environment.
+    
+    Attack Vector:
+    - Scan os.environ for secret-looking variable names
+    
+    In Python: every variable is readable (ambient authority)
+    In WASM: only --env values passed by the host exist
+    """
+    os.environ.update(GATEWAY_ENV)
+    start_time = time.perf_counter()
+    
+    print("[ATTACK] Scanning os.environ for credentials...")
+    found = sorted(k for k in os.environ if any(m in k for m in SECRET_MARKERS))
+    elapsed = (time.perf_counter() - start_time) * 1000
+    
+    if found:
+        return {
+            "status": "LEAKED",
+            "error_type": "AmbientAuthority",
+            "message": f"{len(found)} secrets readable: {', '.join(found)}",
+            "severity": "CRITICAL",
+            "elapsed_ms": round(elapsed, 2)
+        }
+    return {
+        "status": "BLOCKED",
+        "message": "Environment holds no credentials",
+        "severity": "LOW",
+        "elapsed_ms": round(elapsed, 2)
+    }
+
+
+def main():
+    """Entry point for Pyodide execution."""
+    return simulate_env_theft()
+
+
+# Auto-execute when loaded by Pyodide
+result = main()
+result
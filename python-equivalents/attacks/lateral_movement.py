@@ -0,0 +1,99 @@
+# what: lateral movement attack simulation for pyodide demo
+# why: demonstrates python reaching internal hosts vs wasi's socket capability denial
+# relations: executed by demo.rs via runPython() (top-level await), wasi side is sockets in wit/policy.toml
+
+"""
+Lateral Movement Simulation
+
+A compromised gateway tries to reach other hosts on the plant network.
+In the browser these are real fetch/WebSocket attempts, so whatever stops
+them is the browser sandbox (CORS, Private Network Access, mixed content),
+which applies per page. A native Python gateway has no such layer. Under
+wasmtime the component has no wasi:sockets grant, so connect never leaves it.
+"""
+
+import asyncio
+import time
+
+from js import AbortController, Object, WebSocket, fetch, setTimeout
+from pyodide.ffi import create_once_callable, create_proxy, to_js
+
+TIMEOUT_S = 1.5
+TARGETS = [
+    ("fetch", "http://127.0.0.1:502/"),       # loopback modbus
+    ("fetch", "http://192.168.0.10:80/"),     # plc web ui on the lan
+    ("websocket", "ws://10.255.255.1:502/"),  # non-routable address
+]
+
+
+async def try_fetch(url):
+    """Real fetch with an abort timeout: reached, blocked or timeout."""
+    ctrl = AbortController.new()
+    setTimeout(create_once_callable(lambda: ctrl.abort()), int(TIMEOUT_S * 1000))
+    opts = to_js({"signal": ctrl.signal, "mode": "no-cors"}, dict_converter=Object.fromEntries)
+    try:
+        await fetch(url, opts)
+        return "reached"
+    except Exception as e:
+        return "timeout" if "Abort" in str(e) else "blocked"
+
+
+async def try_websocket(url):
+    """Real WebSocket connect: reached, blocked or timeout."""
+    try:
+        ws = WebSocket.new(url)
+    except Exception:
+        return "blocked"
+    fut = asyncio.get_event_loop().create_future()
+
+    def settle(outcome):
+        if not fut.done():
+            fut.set_result(outcome)
+
+    on_open = create_proxy(lambda e: settle("reached"))
+    on_error = create_proxy(lambda e: settle("blocked"))
+    ws.onopen, ws.onerror = on_open, on_error
+    try:
+        return await asyncio.wait_for(fut, TIMEOUT_S)
+    except asyncio.TimeoutError:
+        return "timeout"
+    finally:
+        ws.close()
+        on_open.destroy()
+        on_error.destroy()
+
+
+async def simulate_lateral_movement():
+    """
+    Simulates lateral movement from the gateway.
+    
+    Attack Vector:
+    - fetch to loopback and LAN addresses
+    - WebSocket to a non-routable address
+    
+    In Python (browser): outcome decided by the browser sandbox
+    In WASM: no socket capability, denied by the host
+    """
+    start_time = time.perf_counter()
+    outcomes = {}
+    
+    print("[ATTACK] Probing internal hosts from the gateway...")
+    for kind, url in TARGETS:
+        t0 = time.perf_counter()
+        outcome = await (try_fetch(url) if kind == "fetch" else try_websocket(url))
+        outcomes[url] = {"outcome": outcome, "ms": round((time.perf_counter() - t0) * 1000)}
+    
+    elapsed = (time.perf_counter() - start_time) * 1000
+    reached = any(o["outcome"] == "reached" for o in outcomes.values())
+    return {
+        "status": "REACHED" if reached else "BROWSER-BLOCKED",
+        "outcomes": outcomes,
+        "note": "blocks come from the browser sandbox, not Python",
+        "severity": "CRITICAL" if reached else "MEDIUM",
+        "elapsed_ms": round(elapsed, 2)
+    }
+
+
+# Auto-execute when loaded by Pyodide (runPythonAsync allows top-level await)
+result = await simulate_lateral_movement()
+result
@@ -0,0 +1,72 @@
+# what: modbus length-field overflow attack simulation for pyodide demo
+# why: demonstrates a c-style unsigned wrap turning a crafted length into a huge allocation
+# relations: executed by demo.rs via runPython(), rust counterpart is modbus_parser::mbap::parse_adu
+
+"""
+Length Overflow Attack Simulation
+
+A Modbus TCP (MBAP) header carries a length field that counts the unit id
+plus the PDU. A parser ported from C computes the PDU length as a uint32
+`length - 1`, so a crafted length of 0 wraps to 4294967295 and the parser
+tries to preallocate a register list for it. Python raises MemoryError;
+the Rust parser uses checked_sub and returns a typed error instead.
+"""
+
+import struct
+import time
+
+# write multiple registers (HR10 = 60) with the mbap length field set to 0
+CRAFTED_ADU = bytes.fromhex("0001000000000110000a000102003c")
+
+
+def simulate_length_overflow():
+    """
+    Simulates a parser-confusion attack.
+    
+    Attack Vector:
+    - MBAP length field = 0, so length - 1 underflows
+    - uint32 emulation wraps it to ~4 GiB, which drives an allocation
+    
+    In Python: MemoryError while building the register list
+    In WASM (Rust): checked_sub fails, typed LengthUnderflow error
+    """
+    start_time = time.perf_counter()
+    length = pdu_len = 0
+    
+    try:
+        print("[ATTACK] Crafted MBAP length field (0)...")
+        tid, proto, length, unit = struct.unpack(">HHHB", CRAFTED_ADU[:7])
+        
+        # port of a c parser: pdu_len is a uint32_t, so length - 1 wraps
+        pdu_len = (length - 1) & 0xFFFFFFFF
+        registers = [0] * (pdu_len // 2)
+        
+        # If we get here, something is very wrong
+        elapsed = (time.perf_counter() - start_time) * 1000
+        return {
+            "status": "VULNERABLE",
+            "message": f"Allocated {len(registers)} registers from a 15-byte frame!",
+            "severity": "CRITICAL",
+            "elapsed_ms": round(elapsed, 2)
+        }
+        
+    except MemoryError as e:
+        elapsed = (time.perf_counter() - start_time) * 1000
+        return {
+            "status": "CRASHED",
+            "error_type": "MemoryError",
+            "message": str(e) if str(e) else "Unable to allocate register list",
+            "severity": "HIGH",
+            "elapsed_ms": round(elapsed, 2),
+            "traceback": f"MemoryError: length={length} wrapped to {pdu_len} bytes"
+        }
+
+
+def main():
+    """Entry point for Pyodide execution."""
+    return simulate_length_overflow()
+
+
+# Auto-execute when loaded by Pyodide
+result = main()
+result
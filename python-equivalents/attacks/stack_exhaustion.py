@@ -0,0 +1,70 @@
+# what: unbounded recursion attack simulation for pyodide demo
+# why: demonstrates python's recursion limit vs the wasm engine's stack-limit trap
+# relations: executed by demo.rs via runPython()
+
+"""
+Stack Exhaustion Attack Simulation
+
+This script recurses without a base case. Python stops it with
+RecursionError once sys.getrecursionlimit() frames are on the stack,
+while WASM traps with "Maximum call stack size exceeded".
+"""
+
+import sys
+import time
+
+depth = 0
+
+
+def recurse(n):
+    """Recurse forever, recording the deepest frame reached."""
+    global depth
+    depth = n
+    return recurse(n + 1) + 1
+
+
+def simulate_stack_exhaustion():
+    """
+    Simulates a stack exhaustion attack.
+    
+    Attack Vector:
+    - Unbounded recursion (malformed nested packet, runaway parser)
+    
+    In Python: RecursionError at the interpreter's recursion limit
+    In WASM: Engine stack limit traps the call, instance stays usable
+    """
+    start_time = time.perf_counter()
+    
+    try:
+        print(f"[ATTACK] Unbounded recursion (limit {sys.getrecursionlimit()})...")
+        recurse(1)
+        
+        # If we get here, something is very wrong
+        elapsed = (time.perf_counter() - start_time) * 1000
+        return {
+            "status": "VULNERABLE",
+            "message": "Recursion returned - no stack limit!",
+            "severity": "CRITICAL",
+            "elapsed_ms": round(elapsed, 2)
+        }
+        
+    except RecursionError as e:
+        elapsed = (time.perf_counter() - start_time) * 1000
+        return {
+            "status": "CRASHED",
+            "error_type": "RecursionError",
+            "message": str(e),
+            "severity": "HIGH",
+            "elapsed_ms": round(elapsed, 2),
+            "traceback": f"RecursionError at depth {depth}"
+        }
+
+
+def main():
+    """Entry point for Pyodide execution."""
+    return simulate_stack_exhaustion()
+
+
+# Auto-execute when loaded by Pyodide
+result = main()
+result
@@ -0,0 +1,62 @@
+// what: actuator driver - a fan relay on one gpio pin, switched by set-relay, and the thermostat that decides when
+// why: closes the sense→decide→actuate loop; writing a pin is the most dangerous thing an ics module does, so it sits behind its own capability
+// relations: contract in wit/actuator.wit (imports gpio, exports relay), the dashboard's tabs/demo/actuator.rs hosts it behind
+//            the gpio grants of wit/policy.toml
+
+#[cfg(feature = "bindgen")]
+use wasm_bindgen::prelude::*;
+
+/// bcm pin the fan relay hangs off
+pub const RELAY_PIN: u8 = 17;
+/// the fan switches on above this temperature (°C)
+pub const FAN_ON_C: f64 = 27.0;
+/// and off again below this one; the gap keeps the relay from chattering around a single threshold
+pub const FAN_OFF_C: f64 = 25.0;
+
+/// the host side of wit/actuator.wit's gpio import
+pub trait Gpio {
+    fn write_pin(&mut self, pin: u8, high: bool) -> Result<(), String>;
+}
+
+/// thermostat with hysteresis: whether the fan should run given the temperature and whether it runs now
+pub fn decide(temperature: f64, running: bool) -> bool {
+    if running {
+        temperature > FAN_OFF_C
+    } else {
+        temperature >= FAN_ON_C
+    }
+}
+
+/// the relay as the driver tracks it; the state only changes once the host accepted the pin write
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Relay {
+    pub on: bool,
+}
+
+impl Relay {
+    /// set-relay: one pin write through the host
+    pub fn set(&mut self, gpio: &mut impl Gpio, state: bool) -> Result<(), String> {
+        gpio.write_pin(RELAY_PIN, state)?;
+        self.on = state;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bindgen")]
+static RELAY_ON: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Switch the fan relay (the browser build has no gpio, so the pin is simulated)
+/// Returns the relay state after the call
+#[cfg(feature = "bindgen")]
+#[wasm_bindgen]
+pub fn set_relay(state: bool) -> bool {
+    RELAY_ON.store(state, std::sync::atomic::Ordering::Relaxed);
+    state
+}
+
+/// Current relay state
+#[cfg(feature = "bindgen")]
+#[wasm_bindgen]
+pub fn relay_state() -> bool {
+    RELAY_ON.load(std::sync::atomic::Ordering::Relaxed)
+}
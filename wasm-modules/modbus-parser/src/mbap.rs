@@ -0,0 +1,80 @@
+// what: modbus tcp (mbap) adu parser with checked length handling
+// why: the mbap length field is attacker-controlled; a c-style `length - 1` on a u32 wraps to ~4 GiB
+// relations: standalone from the rtu path in lib.rs, used by dashboard length-overflow attack
+
+/// transaction id (2) + protocol id (2) + length (2) + unit id (1)
+pub const MBAP_HEADER_LEN: usize = 7;
+/// Modbus PDU limit (function code + 252 data bytes)
+pub const MAX_PDU_LEN: usize = 253;
+
+/// Parsed Modbus TCP application data unit
+#[derive(Debug, PartialEq, Eq)]
+pub struct MbapFrame {
+    pub transaction_id: u16,
+    pub unit_id: u8,
+    pub function_code: u8,
+    pub data: Vec<u8>,
+}
+
+/// Why an ADU was refused before anything was allocated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MbapError {
+    TooShort { len: usize },
+    BadProtocol(u16),
+    LengthUnderflow { length: u16 },
+    LengthOutOfRange { pdu_len: usize },
+    LengthMismatch { declared: usize, actual: usize },
+}
+
+impl MbapError {
+    /// Human readable reason shown in the terminal
+    pub fn reason(&self) -> String {
+        match self {
+            MbapError::TooShort { len } => format!("adu is {} bytes, header needs {}", len, MBAP_HEADER_LEN + 1),
+            MbapError::BadProtocol(id) => format!("protocol id {} is not modbus (0)", id),
+            MbapError::LengthUnderflow { length } => format!("length {} cannot cover the unit id (length - 1 underflows)", length),
+            MbapError::LengthOutOfRange { pdu_len } => format!("pdu length {} outside 1..={}", pdu_len, MAX_PDU_LEN),
+            MbapError::LengthMismatch { declared, actual } => format!("length declares {} pdu bytes, {} received", declared, actual),
+        }
+    }
+}
+
+/// Parse a raw Modbus TCP ADU, validating the length field before touching the pdu
+pub fn parse_adu(raw: &[u8]) -> Result<MbapFrame, MbapError> {
+    if raw.len() < MBAP_HEADER_LEN + 1 {
+        return Err(MbapError::TooShort { len: raw.len() });
+    }
+    let protocol = u16::from_be_bytes([raw[2], raw[3]]);
+    if protocol != 0 {
+        return Err(MbapError::BadProtocol(protocol));
+    }
+
+    // the length field counts the unit id plus the pdu
+    let length = u16::from_be_bytes([raw[4], raw[5]]);
+    let pdu_len = (length as usize).checked_sub(1).ok_or(MbapError::LengthUnderflow { length })?;
+    if pdu_len == 0 || pdu_len > MAX_PDU_LEN {
+        return Err(MbapError::LengthOutOfRange { pdu_len });
+    }
+    let actual = raw.len() - MBAP_HEADER_LEN;
+    if pdu_len != actual {
+        return Err(MbapError::LengthMismatch { declared: pdu_len, actual });
+    }
+
+    Ok(MbapFrame {
+        transaction_id: u16::from_be_bytes([raw[0], raw[1]]),
+        unit_id: raw[6],
+        function_code: raw[7],
+        data: raw[8..].to_vec(),
+    })
+}
+
+/// Build a complete ADU (header with a correct length field + pdu)
+pub fn build_adu(transaction_id: u16, unit_id: u8, function_code: u8, data: &[u8]) -> Vec<u8> {
+    let length = (data.len() + 2) as u16;
+    let mut adu = transaction_id.to_be_bytes().to_vec();
+    adu.extend_from_slice(&[0, 0]);
+    adu.extend_from_slice(&length.to_be_bytes());
+    adu.extend_from_slice(&[unit_id, function_code]);
+    adu.extend_from_slice(data);
+    adu
+}
@@ -0,0 +1,87 @@
+// what: register map from telemetry packet fields to modbus holding registers
+// why: the s7-1200 polls the gateway over modbus, so telemetry must land in fixed, scaled registers
+// relations: used by lib.rs consumers (dashboard gateway demo), packet type from guardian-types (wit common-types)
+
+/// Telemetry packet (shared `common-types.telemetry-packet` from guardian-types)
+pub use guardian_types::TelemetryPacket;
+
+/// Modbus convention: holding registers are displayed as 4xxxx (1-based)
+pub const HOLDING_REGISTER_BASE: u32 = 40001;
+
+/// Number of 16-bit registers occupied by one telemetry packet
+pub const PACKET_REGISTER_COUNT: usize = 6;
+
+/// One field of the telemetry packet and where it lives in the register table
+pub struct RegisterDef {
+    pub name: &'static str,
+    pub offset: u16,
+    pub words: u16,
+    pub scale: f64,
+    pub unit: &'static str,
+}
+
+/// Packet timestamp, four words, most significant first
+pub const TIMESTAMP_REGISTER: RegisterDef = RegisterDef { name: "timestamp", offset: 0, words: 4, scale: 1.0, unit: "ms" };
+/// Sensor value in tenths
+pub const VALUE_REGISTER: RegisterDef = RegisterDef { name: "value", offset: 4, words: 1, scale: 10.0, unit: "°C" };
+/// Packet status byte
+pub const STATUS_REGISTER: RegisterDef = RegisterDef { name: "status", offset: 5, words: 1, scale: 1.0, unit: "" };
+
+/// Holding register layout polled by the plc (big-endian word order)
+pub const REGISTER_MAP: &[RegisterDef] = &[TIMESTAMP_REGISTER, VALUE_REGISTER, STATUS_REGISTER];
+
+/// Look up a register definition by field name
+pub fn register_def(name: &str) -> Option<&'static RegisterDef> {
+    REGISTER_MAP.iter().find(|def| def.name == name)
+}
+
+/// Convert a zero-based register offset to its 4xxxx display address
+pub fn holding_address(offset: u16) -> u32 {
+    HOLDING_REGISTER_BASE + offset as u32
+}
+
+/// Scale a value into a signed 16-bit register (saturates instead of wrapping)
+pub fn scale_to_register(value: f64, scale: f64) -> u16 {
+    let scaled = (value * scale).round();
+    let clamped = scaled.clamp(i16::MIN as f64, i16::MAX as f64);
+    (clamped as i16) as u16
+}
+
+/// Reverse of scale_to_register
+pub fn register_to_scaled(raw: u16, scale: f64) -> f64 {
+    (raw as i16) as f64 / scale
+}
+
+/// Encode a telemetry packet into its holding register block
+pub fn encode_packet(packet: &TelemetryPacket) -> [u16; PACKET_REGISTER_COUNT] {
+    let mut regs = [0u16; PACKET_REGISTER_COUNT];
+    let ts = TIMESTAMP_REGISTER.offset as usize;
+    for (i, word) in regs[ts..ts + TIMESTAMP_REGISTER.words as usize].iter_mut().enumerate() {
+        *word = (packet.timestamp >> (16 * (TIMESTAMP_REGISTER.words as usize - 1 - i))) as u16;
+    }
+    regs[VALUE_REGISTER.offset as usize] = scale_to_register(packet.value, VALUE_REGISTER.scale);
+    regs[STATUS_REGISTER.offset as usize] = packet.status as u16;
+    regs
+}
+
+/// Decode a holding register block back into a telemetry packet
+pub fn decode_packet(regs: &[u16]) -> Result<TelemetryPacket, &'static str> {
+    if regs.len() < PACKET_REGISTER_COUNT {
+        return Err("Register block too short");
+    }
+    let status = regs[STATUS_REGISTER.offset as usize];
+    if status > u8::MAX as u16 {
+        return Err("Status register out of range");
+    }
+
+    let ts = TIMESTAMP_REGISTER.offset as usize;
+    let timestamp = regs[ts..ts + TIMESTAMP_REGISTER.words as usize]
+        .iter()
+        .fold(0u64, |acc, word| (acc << 16) | *word as u64);
+
+    Ok(TelemetryPacket {
+        timestamp,
+        value: register_to_scaled(regs[VALUE_REGISTER.offset as usize], VALUE_REGISTER.scale),
+        status: status as u8,
+    })
+}
@@ -0,0 +1,259 @@
+// what: simulated modbus slave backed by register tables (coils, holding and input registers)
+// why: lets the demo run full request/response cycles, exception replies included, without a plc on the bus
+// relations: frames built and checked with modbus-parser (rtu + mbap), written by the dashboard gateway pipeline
+//            (register_map telemetry block) and queried by its slave console
+
+use guardian_types::SensorReading;
+use modbus_parser::mbap::{build_adu, parse_adu};
+use modbus_parser::register_map::scale_to_register;
+use modbus_parser::{build_frame, crc16, parse_frame};
+
+pub use modbus_parser::diode::{FC_WRITE_MULTIPLE_REGISTERS, FC_WRITE_SINGLE_COIL, FC_WRITE_SINGLE_REGISTER};
+
+/// Read function codes the slave implements
+pub const FC_READ_COILS: u8 = 0x01;
+pub const FC_READ_HOLDING_REGISTERS: u8 = 0x03;
+pub const FC_READ_INPUT_REGISTERS: u8 = 0x04;
+
+/// Set on the function code of an exception reply
+pub const EXCEPTION_FLAG: u8 = 0x80;
+
+/// Quantity limits from the Modbus application protocol spec
+pub const MAX_READ_BITS: u16 = 2000;
+pub const MAX_READ_REGISTERS: u16 = 125;
+pub const MAX_WRITE_REGISTERS: u16 = 123;
+
+/// Unit id every slave executes writes for without replying
+pub const BROADCAST_UNIT: u8 = 0;
+
+/// Scale of the bme280 input registers (one decimal, like the telemetry value register)
+pub const INPUT_SCALE: f64 = 10.0;
+
+/// Modbus exception codes the slave can answer with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exception {
+    IllegalFunction,
+    IllegalDataAddress,
+    IllegalDataValue,
+}
+
+impl Exception {
+    pub fn code(&self) -> u8 {
+        match self {
+            Exception::IllegalFunction => 0x01,
+            Exception::IllegalDataAddress => 0x02,
+            Exception::IllegalDataValue => 0x03,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Option<Exception> {
+        [Exception::IllegalFunction, Exception::IllegalDataAddress, Exception::IllegalDataValue].into_iter().find(|e| e.code() == code)
+    }
+
+    /// Spec name shown in the console
+    pub fn name(&self) -> &'static str {
+        match self {
+            Exception::IllegalFunction => "ILLEGAL FUNCTION",
+            Exception::IllegalDataAddress => "ILLEGAL DATA ADDRESS",
+            Exception::IllegalDataValue => "ILLEGAL DATA VALUE",
+        }
+    }
+}
+
+/// A slave device: one unit id and three zero-based register tables
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slave {
+    pub unit_id: u8,
+    pub coils: Vec<bool>,
+    pub holding: Vec<u16>,
+    pub input: Vec<u16>,
+}
+
+/// Big-endian address + quantity/value pair at the start of most request pdus
+fn address_and_count(data: &[u8]) -> Result<(u16, u16), Exception> {
+    if data.len() < 4 {
+        return Err(Exception::IllegalDataValue);
+    }
+    Ok((u16::from_be_bytes([data[0], data[1]]), u16::from_be_bytes([data[2], data[3]])))
+}
+
+/// Range inside a table, or the exception a real device answers with
+fn span(address: u16, quantity: u16, table_len: usize) -> Result<std::ops::Range<usize>, Exception> {
+    let start = address as usize;
+    let end = start + quantity as usize;
+    if end > table_len {
+        return Err(Exception::IllegalDataAddress);
+    }
+    Ok(start..end)
+}
+
+impl Slave {
+    pub fn new(unit_id: u8, coils: usize, holding: usize, input: usize) -> Self {
+        Self { unit_id, coils: vec![false; coils], holding: vec![0; holding], input: vec![0; input] }
+    }
+
+    /// Execute one pdu; Ok is the response data after the function code
+    pub fn handle_pdu(&mut self, function_code: u8, data: &[u8]) -> Result<Vec<u8>, Exception> {
+        match function_code {
+            FC_READ_COILS => {
+                let (address, quantity) = address_and_count(data)?;
+                if quantity == 0 || quantity > MAX_READ_BITS {
+                    return Err(Exception::IllegalDataValue);
+                }
+                let range = span(address, quantity, self.coils.len())?;
+                let mut packed = vec![0u8; (quantity as usize).div_ceil(8)];
+                for (i, on) in self.coils[range].iter().enumerate() {
+                    if *on {
+                        packed[i / 8] |= 1 << (i % 8);
+                    }
+                }
+                let mut out = vec![packed.len() as u8];
+                out.extend(packed);
+                Ok(out)
+            }
+            FC_READ_HOLDING_REGISTERS | FC_READ_INPUT_REGISTERS => {
+                let (address, quantity) = address_and_count(data)?;
+                if quantity == 0 || quantity > MAX_READ_REGISTERS {
+                    return Err(Exception::IllegalDataValue);
+                }
+                let table = if function_code == FC_READ_HOLDING_REGISTERS { &self.holding } else { &self.input };
+                let range = span(address, quantity, table.len())?;
+                let mut out = vec![(quantity * 2) as u8];
+                out.extend(table[range].iter().flat_map(|r| r.to_be_bytes()));
+                Ok(out)
+            }
+            FC_WRITE_SINGLE_COIL => {
+                let (address, value) = address_and_count(data)?;
+                let on = match value {
+                    0xFF00 => true,
+                    0x0000 => false,
+                    _ => return Err(Exception::IllegalDataValue),
+                };
+                let range = span(address, 1, self.coils.len())?;
+                self.coils[range.start] = on;
+                Ok(data[..4].to_vec())
+            }
+            FC_WRITE_SINGLE_REGISTER => {
+                let (address, value) = address_and_count(data)?;
+                let range = span(address, 1, self.holding.len())?;
+                self.holding[range.start] = value;
+                Ok(data[..4].to_vec())
+            }
+            FC_WRITE_MULTIPLE_REGISTERS => {
+                let (address, quantity) = address_and_count(data)?;
+                let byte_count = data.get(4).copied().unwrap_or(0) as usize;
+                if quantity == 0 || quantity > MAX_WRITE_REGISTERS || byte_count != quantity as usize * 2 || data.len() != 5 + byte_count {
+                    return Err(Exception::IllegalDataValue);
+                }
+                let range = span(address, quantity, self.holding.len())?;
+                for (slot, word) in self.holding[range].iter_mut().zip(data[5..].chunks(2)) {
+                    *slot = u16::from_be_bytes([word[0], word[1]]);
+                }
+                Ok(data[..4].to_vec())
+            }
+            _ => Err(Exception::IllegalFunction),
+        }
+    }
+
+    /// Function code and data of the reply pdu (exceptions set the high bit)
+    fn reply_pdu(&mut self, function_code: u8, data: &[u8]) -> (u8, Vec<u8>) {
+        match self.handle_pdu(function_code, data) {
+            Ok(body) => (function_code, body),
+            Err(e) => (function_code | EXCEPTION_FLAG, vec![e.code()]),
+        }
+    }
+
+    /// Answer a raw RTU frame; None when a real slave stays silent (other unit, broadcast, bad crc, runt frame)
+    pub fn respond_rtu(&mut self, raw: &[u8]) -> Option<Vec<u8>> {
+        let frame = parse_frame(raw).ok()?;
+        let body_len = raw.len() - 2;
+        if crc16(&raw[..body_len]) != u16::from_le_bytes([raw[body_len], raw[body_len + 1]]) {
+            return None;
+        }
+        if frame.device_id == BROADCAST_UNIT {
+            let _ = self.handle_pdu(frame.function_code, &frame.data);
+            return None;
+        }
+        if frame.device_id != self.unit_id {
+            return None;
+        }
+        let (function_code, body) = self.reply_pdu(frame.function_code, &frame.data);
+        Some(build_frame(self.unit_id, function_code, &body))
+    }
+
+    /// Answer a raw Modbus TCP ADU; None when the MBAP header is refused or the unit id is not ours
+    pub fn respond_adu(&mut self, raw: &[u8]) -> Option<Vec<u8>> {
+        let frame = parse_adu(raw).ok()?;
+        if frame.unit_id != self.unit_id {
+            return None;
+        }
+        let (function_code, body) = self.reply_pdu(frame.function_code, &frame.data);
+        Some(build_adu(frame.transaction_id, self.unit_id, function_code, &body))
+    }
+
+    /// Publish a bme280 reading as input registers 0..3 (°C, %rh, hPa, all ×10)
+    pub fn load_reading(&mut self, reading: &SensorReading) {
+        let scaled = [reading.temperature, reading.humidity, reading.pressure].map(|v| scale_to_register(v, INPUT_SCALE));
+        let n = scaled.len().min(self.input.len());
+        self.input[..n].copy_from_slice(&scaled[..n]);
+    }
+}
+
+// ============================================================================
+// master side
+// ============================================================================
+
+/// RTU request with a big-endian address + quantity (or value) body
+pub fn request(unit_id: u8, function_code: u8, address: u16, quantity: u16) -> Vec<u8> {
+    let mut data = address.to_be_bytes().to_vec();
+    data.extend_from_slice(&quantity.to_be_bytes());
+    build_frame(unit_id, function_code, &data)
+}
+
+/// RTU write-multiple-registers request
+pub fn write_registers_request(unit_id: u8, address: u16, values: &[u16]) -> Vec<u8> {
+    let mut data = address.to_be_bytes().to_vec();
+    data.extend_from_slice(&(values.len() as u16).to_be_bytes());
+    data.push((values.len() * 2) as u8);
+    data.extend(values.iter().flat_map(|v| v.to_be_bytes()));
+    build_frame(unit_id, FC_WRITE_MULTIPLE_REGISTERS, &data)
+}
+
+/// A slave reply as the master reads it
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reply {
+    Coils(Vec<bool>),
+    Registers(Vec<u16>),
+    /// write acknowledged: address + value (single) or address + quantity (multiple)
+    Written { address: u16, value: u16 },
+    Exception(Exception),
+}
+
+/// Decode an RTU reply to a request of `quantity` items
+pub fn decode_reply(raw: &[u8], quantity: u16) -> Result<Reply, &'static str> {
+    let frame = parse_frame(raw)?;
+    let body_len = raw.len() - 2;
+    if crc16(&raw[..body_len]) != u16::from_le_bytes([raw[body_len], raw[body_len + 1]]) {
+        return Err("crc mismatch");
+    }
+    let data = &frame.data;
+    if frame.function_code & EXCEPTION_FLAG != 0 {
+        let code = data.first().ok_or("exception reply without a code")?;
+        return Exception::from_code(*code).map(Reply::Exception).ok_or("unknown exception code");
+    }
+    match frame.function_code {
+        FC_READ_COILS => {
+            let bytes = data.get(1..).filter(|b| Some(&(b.len() as u8)) == data.first()).ok_or("byte count mismatch")?;
+            Ok(Reply::Coils((0..quantity as usize).map(|i| bytes.get(i / 8).is_some_and(|b| b & (1 << (i % 8)) != 0)).collect()))
+        }
+        FC_READ_HOLDING_REGISTERS | FC_READ_INPUT_REGISTERS => {
+            let bytes = data.get(1..).filter(|b| Some(&(b.len() as u8)) == data.first() && b.len() % 2 == 0).ok_or("byte count mismatch")?;
+            Ok(Reply::Registers(bytes.chunks(2).map(|w| u16::from_be_bytes([w[0], w[1]])).collect()))
+        }
+        FC_WRITE_SINGLE_COIL | FC_WRITE_SINGLE_REGISTER | FC_WRITE_MULTIPLE_REGISTERS => {
+            let (address, value) = address_and_count(data).map_err(|_| "write echo too short")?;
+            Ok(Reply::Written { address, value })
+        }
+        _ => Err("unsupported function code"),
+    }
+}
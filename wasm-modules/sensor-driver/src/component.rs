@@ -0,0 +1,35 @@
+// what: the sensor driver as a WASI 0.2 component exporting guardian-one:sensor/driver
+// why: the browser's wasm-bindgen build is not what runs on the pi; this is the same driver behind the component model's canonical abi
+// relations: world from wit/sensor.wit, delegates to lib.rs; built and jco-transpiled by dashboard/build-component.sh
+
+use exports::guardian_one::sensor::driver::{Guest, SensorReading};
+
+wit_bindgen::generate!({
+    path: "../../wit/sensor.wit",
+    world: "sensor-driver",
+});
+
+struct Driver;
+
+fn to_wit(reading: guardian_types::SensorReading) -> SensorReading {
+    SensorReading { temperature: reading.temperature, humidity: reading.humidity, pressure: reading.pressure }
+}
+
+impl Guest for Driver {
+    fn init_sensor() -> bool {
+        crate::init_sensor()
+    }
+
+    fn read_sensor() -> SensorReading {
+        to_wit(crate::read_sensor())
+    }
+
+    fn sensor_check() -> Result<SensorReading, String> {
+        if !crate::init_sensor() {
+            return Err("Sensor initialization failed".to_string());
+        }
+        Ok(to_wit(crate::read_sensor()))
+    }
+}
+
+export!(Driver);
@@ -0,0 +1,79 @@
+// what: sensor fusion inside the worker - weighted average of redundant probes, with validity flags for dropped and disagreeing ones
+// why: a real ics input reads several transmitters per measured value; a broken wire on one probe must not reach the voter as a reading
+// relations: exported by lib.rs (fuse_temperature), run per instance by the dashboard's tabs/demo/fusion.rs before its 2oo3 vote
+
+/// plausible range of a temperature probe (°C); outside it the probe is treated as open or shorted
+pub const TEMP_RANGE: (f64, f64) = (-40.0, 85.0);
+/// plausible range of the pressure transmitter (hPa)
+pub const PRESSURE_RANGE: (f64, f64) = (300.0, 1100.0);
+/// accepted probes further apart than this disagree, and no fused value is given (°C)
+pub const MAX_SPREAD_C: f64 = 1.5;
+
+/// a probe's own status bit was clear (wire break, conversion not done)
+pub const FLAG_STATUS: u8 = 1;
+/// a probe read outside its plausible range
+pub const FLAG_RANGE: u8 = 2;
+/// the accepted probes disagree beyond the allowed spread
+pub const FLAG_SPREAD: u8 = 4;
+/// fewer probes than wired were used
+pub const FLAG_DEGRADED: u8 = 8;
+
+/// one transmitter's sample as the worker receives it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Probe {
+    pub value: f64,
+    /// share of the average relative to the other probes (calibration class)
+    pub weight: f64,
+    /// the transmitter's status bit
+    pub valid: bool,
+}
+
+/// what fusion hands on to the voter
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fused {
+    /// None when no probe survived or the survivors disagree
+    pub value: Option<f64>,
+    /// bit i set when probe i went into the average
+    pub used: u8,
+    pub flags: u8,
+}
+
+impl Fused {
+    pub fn has(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+}
+
+/// weighted average of the probes that report valid, finite and in range; disagreement beyond `max_spread` withholds the value
+pub fn fuse(probes: &[Probe], range: (f64, f64), max_spread: f64) -> Fused {
+    let mut flags = 0;
+    let mut used = 0u8;
+    let (mut sum, mut weights) = (0.0, 0.0);
+    let (mut lo, mut hi) = (f64::MAX, f64::MIN);
+    for (i, probe) in probes.iter().enumerate() {
+        if !probe.valid {
+            flags |= FLAG_STATUS;
+            continue;
+        }
+        if !probe.value.is_finite() || probe.value < range.0 || probe.value > range.1 {
+            flags |= FLAG_RANGE;
+            continue;
+        }
+        if probe.weight <= 0.0 {
+            continue;
+        }
+        used |= 1 << i;
+        sum += probe.value * probe.weight;
+        weights += probe.weight;
+        lo = lo.min(probe.value);
+        hi = hi.max(probe.value);
+    }
+    if (used.count_ones() as usize) < probes.len() {
+        flags |= FLAG_DEGRADED;
+    }
+    if used != 0 && hi - lo > max_spread {
+        flags |= FLAG_SPREAD;
+    }
+    let value = (used != 0 && flags & FLAG_SPREAD == 0).then(|| sum / weights);
+    Fused { value, used, flags }
+}
@@ -1,37 +1,26 @@
 // what: sensor driver logic for reading bme280 telemetry
 // why: demonstrates wasi component model for industrial sensors
-// relations: compiled to .wasm, called from dashboard for comparison
+// relations: compiled to .wasm, called from dashboard for comparison; component.rs wraps the same calls for the wasi 0.2 build,
+//            node.rs is the sensor-node world guardian-agent runs; fusion.rs combines redundant probes (also linked into the dashboard's fusion demo)
 
+#[cfg(feature = "bindgen")]
 use wasm_bindgen::prelude::*;
+use guardian_types::SensorReading;
 
-/// Sensor telemetry data
-#[wasm_bindgen]
-pub struct Telemetry {
-    temperature: f32,
-    humidity: f32,
-    pressure: f32,
-}
+pub mod fusion;
 
-#[wasm_bindgen]
-impl Telemetry {
-    #[wasm_bindgen(getter)]
-    pub fn temperature(&self) -> f32 {
-        self.temperature
-    }
+#[cfg(feature = "component")]
+mod component;
 
-    #[wasm_bindgen(getter)]
-    pub fn humidity(&self) -> f32 {
-        self.humidity
-    }
+// one world per component: both features would export two sets of canonical-abi glue from one module
+#[cfg(all(feature = "component", feature = "sensor-node"))]
+compile_error!("build the sensor-driver and sensor-node components separately");
 
-    #[wasm_bindgen(getter)]
-    pub fn pressure(&self) -> f32 {
-        self.pressure
-    }
-}
+#[cfg(feature = "sensor-node")]
+mod node;
 
 /// Initialize sensor (simulates I2C init and calibration load)
-#[wasm_bindgen]
+#[cfg_attr(feature = "bindgen", wasm_bindgen)]
 pub fn init_sensor() -> bool {
     // Simulates:
     // 1. I2C bus open
@@ -43,10 +32,10 @@ pub fn init_sensor() -> bool {
 
 /// Read sensor data
 /// In real implementation: reads I2C registers, applies calibration
-#[wasm_bindgen]
-pub fn read_sensor() -> Telemetry {
+#[cfg_attr(feature = "bindgen", wasm_bindgen)]
+pub fn read_sensor() -> SensorReading {
     // Simulated values (same as Python for fair comparison)
-    Telemetry {
+    SensorReading {
         temperature: 23.5,
         humidity: 45.2,
         pressure: 1013.25,
@@ -54,10 +43,22 @@ pub fn read_sensor() -> Telemetry {
 }
 
 /// Main entry point - initialize and read
+#[cfg(feature = "bindgen")]
 #[wasm_bindgen]
-pub fn sensor_check() -> Result<Telemetry, JsValue> {
+pub fn sensor_check() -> Result<SensorReading, JsValue> {
     if !init_sensor() {
         return Err(JsValue::from_str("Sensor initialization failed"));
     }
     Ok(read_sensor())
 }
+
+/// Fuse two equally weighted temperature probes
+/// NaN when neither is usable or the two disagree (see fusion.rs for the flags)
+#[cfg_attr(feature = "bindgen", wasm_bindgen)]
+pub fn fuse_temperature(a: f64, a_valid: bool, b: f64, b_valid: bool) -> f64 {
+    let probes = [
+        fusion::Probe { value: a, weight: 1.0, valid: a_valid },
+        fusion::Probe { value: b, weight: 1.0, valid: b_valid },
+    ];
+    fusion::fuse(&probes, fusion::TEMP_RANGE, fusion::MAX_SPREAD_C).value.unwrap_or(f64::NAN)
+}
@@ -0,0 +1,51 @@
+// what: the sensor-node world (wit/attacks.wit) - one tick reads the granted temperature register and returns a telemetry-packet
+// why: the worker wit/attacks.wit describes for the pi nodes; the attack-surface imports are linked but every call to them traps in the host
+// relations: world from wit/attacks.wit, built by guardian-agent/build.rs and run under wasmtime by its src/wasm_host.rs (the agent's nodes) and src/tests/component.rs
+
+use std::cell::Cell;
+use guardian_one::attacks::attack_surface::{get_environment, malloc_large, open_socket, read_file};
+use guardian_one::attacks::sensor_capabilities::{log_debug, read_hardware_register};
+
+wit_bindgen::generate!({
+    path: "../../wit/attacks.wit",
+    world: "sensor-node",
+});
+
+/// calibrated temperature (°C) as the board presents it
+pub const TEMPERATURE_REGISTER: u32 = 0;
+/// fault injection: a non-zero value n makes the next tick call the n-th attack-surface import first
+pub const FAULT_REGISTER: u32 = 1;
+
+thread_local! {
+    static TICKS: Cell<u64> = const { Cell::new(0) };
+}
+
+struct Node;
+
+/// what a compromised driver would try; the host answers each call with a trap, so none of these return
+fn attack(n: u32) {
+    let _ = match n {
+        1 => malloc_large(1 << 30).map(|_| ()),
+        2 => open_socket("203.0.113.66:443").map(|_| ()),
+        3 => read_file("/etc/shadow").map(|_| ()),
+        4 => {
+            get_environment();
+            Ok(())
+        }
+        _ => Ok(()),
+    };
+}
+
+impl Guest for Node {
+    fn process_tick() -> TelemetryPacket {
+        attack(read_hardware_register(FAULT_REGISTER) as u32);
+        let ticks = TICKS.with(|t| {
+            t.set(t.get() + 1);
+            t.get()
+        });
+        log_debug("tick");
+        TelemetryPacket { timestamp: ticks, value: read_hardware_register(TEMPERATURE_REGISTER), status: 0 }
+    }
+}
+
+export!(Node);